@@ -0,0 +1,17 @@
+-- Admin moderation: who submitted TTB times / tags and whether an admin reviewed them
+ALTER TABLE ttb_times ADD COLUMN IF NOT EXISTS submitted_by BIGINT;
+ALTER TABLE ttb_times ADD COLUMN IF NOT EXISTS reviewed_at TIMESTAMPTZ;
+ALTER TABLE game_tags ADD COLUMN IF NOT EXISTS submitted_by BIGINT;
+ALTER TABLE game_tags ADD COLUMN IF NOT EXISTS reviewed_at TIMESTAMPTZ;
+
+-- Existing data predates moderation, treat it as reviewed
+UPDATE ttb_times SET reviewed_at = NOW() WHERE reviewed_at IS NULL;
+UPDATE game_tags SET reviewed_at = NOW() WHERE reviewed_at IS NULL;
+
+-- Partial indexes for the review queues
+CREATE INDEX IF NOT EXISTS idx_ttb_times_pending ON ttb_times(last_reported_at) WHERE reviewed_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_game_tags_pending ON game_tags(appid) WHERE reviewed_at IS NULL;
+
+-- Size of each user's last cloud upload (for the admin dashboard)
+ALTER TABLE users ADD COLUMN IF NOT EXISTS last_upload_bytes BIGINT;
+ALTER TABLE users ADD COLUMN IF NOT EXISTS last_upload_at TIMESTAMPTZ;
@@ -0,0 +1,5 @@
+-- Public profile customization: showcase achievement, favorite game banner and accent color
+ALTER TABLE users ADD COLUMN IF NOT EXISTS showcase_appid BIGINT;
+ALTER TABLE users ADD COLUMN IF NOT EXISTS showcase_apiname TEXT;
+ALTER TABLE users ADD COLUMN IF NOT EXISTS banner_appid BIGINT;
+ALTER TABLE users ADD COLUMN IF NOT EXISTS accent_color TEXT;
@@ -0,0 +1,29 @@
+-- Achievement comments (previously only logged) with soft delete for moderation
+CREATE TABLE IF NOT EXISTS achievement_comments (
+    id BIGSERIAL PRIMARY KEY,
+    steam_id BIGINT NOT NULL REFERENCES users(steam_id) ON DELETE CASCADE,
+    appid BIGINT NOT NULL,
+    apiname TEXT NOT NULL,
+    comment TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- Set when an admin removes the comment; the row is kept for the report history
+    deleted_at TIMESTAMPTZ,
+    deleted_by BIGINT
+);
+
+CREATE INDEX IF NOT EXISTS idx_achievement_comments_game ON achievement_comments(appid) WHERE deleted_at IS NULL;
+
+-- User reports of abusive comments, one per reporter and comment
+CREATE TABLE IF NOT EXISTS comment_reports (
+    id BIGSERIAL PRIMARY KEY,
+    comment_id BIGINT NOT NULL REFERENCES achievement_comments(id) ON DELETE CASCADE,
+    reported_by BIGINT NOT NULL REFERENCES users(steam_id) ON DELETE CASCADE,
+    reason TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- Set when an admin dismisses the report or removes the comment
+    resolved_at TIMESTAMPTZ,
+    UNIQUE (comment_id, reported_by)
+);
+
+-- Partial index for the review queue
+CREATE INDEX IF NOT EXISTS idx_comment_reports_pending ON comment_reports(comment_id) WHERE resolved_at IS NULL;
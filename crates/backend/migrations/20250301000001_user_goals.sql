@@ -0,0 +1,8 @@
+-- Backlog goals set in the desktop app, synced with the rest of the cloud data
+CREATE TABLE IF NOT EXISTS user_goals (
+    steam_id BIGINT NOT NULL REFERENCES users(steam_id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL,
+    -- The goal as JSON (overachiever_core::Goal)
+    goal TEXT NOT NULL,
+    PRIMARY KEY (steam_id, created_at)
+);
@@ -0,0 +1,9 @@
+-- Admin-maintained tag aliases ("Rogue-like" -> "Roguelike") and parents ("Roguelite" -> "Roguelike")
+CREATE TABLE IF NOT EXISTS tag_mappings (
+    tag_name TEXT PRIMARY KEY,
+    target TEXT NOT NULL,
+    kind TEXT NOT NULL CHECK (kind IN ('alias', 'parent')),
+    created_by BIGINT,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (tag_name <> target)
+);
@@ -0,0 +1,13 @@
+-- Weekly email digest subscriptions (one per user) and when each is due
+CREATE TABLE IF NOT EXISTS email_digests (
+    steam_id BIGINT PRIMARY KEY REFERENCES users(steam_id) ON DELETE CASCADE,
+    email TEXT NOT NULL,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    -- Random token in the unsubscribe link of every digest
+    unsubscribe_token TEXT NOT NULL UNIQUE,
+    next_send_at TIMESTAMPTZ NOT NULL,
+    last_sent_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_email_digests_due ON email_digests(next_send_at) WHERE enabled;
@@ -0,0 +1,41 @@
+-- Review state for user TTB reports: implausible or outlier reports wait for an admin
+-- and only approved reports count towards the averages
+ALTER TABLE user_ttb_reports ADD COLUMN IF NOT EXISTS review_status TEXT NOT NULL DEFAULT 'approved'
+    CHECK (review_status IN ('approved', 'pending', 'rejected'));
+-- Why the report was held back (set by the outlier check)
+ALTER TABLE user_ttb_reports ADD COLUMN IF NOT EXISTS review_reason TEXT;
+ALTER TABLE user_ttb_reports ADD COLUMN IF NOT EXISTS reviewed_at TIMESTAMPTZ;
+ALTER TABLE user_ttb_reports ADD COLUMN IF NOT EXISTS reviewed_by BIGINT;
+
+-- Partial index for the review queue
+CREATE INDEX IF NOT EXISTS idx_user_ttb_reports_pending ON user_ttb_reports(reported_at) WHERE review_status = 'pending';
+
+-- Averages only count approved reports
+CREATE OR REPLACE FUNCTION update_ttb_averages(p_appid BIGINT)
+RETURNS VOID AS $$
+BEGIN
+    UPDATE user_games ug
+    SET
+        avg_user_ttb_main_seconds = (
+            SELECT ROUND(AVG(main_seconds))::INTEGER
+            FROM user_ttb_reports
+            WHERE appid = p_appid AND main_seconds IS NOT NULL AND review_status = 'approved'
+        ),
+        avg_user_ttb_extra_seconds = (
+            SELECT ROUND(AVG(extra_seconds))::INTEGER
+            FROM user_ttb_reports
+            WHERE appid = p_appid AND extra_seconds IS NOT NULL AND review_status = 'approved'
+        ),
+        avg_user_ttb_completionist_seconds = (
+            SELECT ROUND(AVG(completionist_seconds))::INTEGER
+            FROM user_ttb_reports
+            WHERE appid = p_appid AND completionist_seconds IS NOT NULL AND review_status = 'approved'
+        ),
+        user_ttb_report_count = (
+            SELECT COUNT(*)
+            FROM user_ttb_reports
+            WHERE appid = p_appid AND review_status = 'approved'
+        )
+    WHERE ug.appid = p_appid;
+END;
+$$ LANGUAGE plpgsql;
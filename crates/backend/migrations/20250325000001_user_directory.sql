@@ -0,0 +1,4 @@
+-- Public user directory: only users who opted in are listed, paged by name or last seen
+ALTER TABLE users ADD COLUMN IF NOT EXISTS listed BOOLEAN NOT NULL DEFAULT FALSE;
+CREATE INDEX IF NOT EXISTS idx_users_listed_name ON users (display_name, steam_id) WHERE listed;
+CREATE INDEX IF NOT EXISTS idx_users_listed_last_seen ON users (last_seen DESC, steam_id DESC) WHERE listed;
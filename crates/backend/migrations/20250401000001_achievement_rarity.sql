@@ -0,0 +1,14 @@
+-- Global unlock percentage of each achievement, snapshotted periodically by the rarity job
+CREATE TABLE IF NOT EXISTS achievement_rarity (
+    appid BIGINT NOT NULL,
+    apiname TEXT NOT NULL,
+    recorded_on DATE NOT NULL,
+    percent REAL NOT NULL,
+    PRIMARY KEY (appid, apiname, recorded_on)
+);
+
+-- Last time the rarity job fetched each game (also for games Steam returned nothing for)
+CREATE TABLE IF NOT EXISTS rarity_fetches (
+    appid BIGINT PRIMARY KEY,
+    fetched_on DATE NOT NULL
+);
@@ -0,0 +1,2 @@
+-- Steam's hidden flag per achievement: clients cover the description of locked hidden achievements
+ALTER TABLE achievement_schemas ADD COLUMN IF NOT EXISTS hidden BOOLEAN NOT NULL DEFAULT FALSE;
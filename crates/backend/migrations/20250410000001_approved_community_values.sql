@@ -0,0 +1,16 @@
+-- Last approved TTB times and tag votes: a new submission overwrites the published row
+-- and goes back into review, so rejecting it restores these instead of deleting the row
+ALTER TABLE ttb_times ADD COLUMN IF NOT EXISTS approved_main REAL;
+ALTER TABLE ttb_times ADD COLUMN IF NOT EXISTS approved_main_extra REAL;
+ALTER TABLE ttb_times ADD COLUMN IF NOT EXISTS approved_completionist REAL;
+-- When the approved values were approved (NULL = never approved, rejecting deletes the row)
+ALTER TABLE ttb_times ADD COLUMN IF NOT EXISTS approved_at TIMESTAMPTZ;
+ALTER TABLE game_tags ADD COLUMN IF NOT EXISTS approved_vote_count INTEGER;
+ALTER TABLE game_tags ADD COLUMN IF NOT EXISTS approved_at TIMESTAMPTZ;
+
+UPDATE ttb_times
+SET approved_main = main, approved_main_extra = main_extra, approved_completionist = completionist, approved_at = reviewed_at
+WHERE reviewed_at IS NOT NULL AND approved_at IS NULL;
+UPDATE game_tags
+SET approved_vote_count = vote_count, approved_at = reviewed_at
+WHERE reviewed_at IS NOT NULL AND approved_at IS NULL;
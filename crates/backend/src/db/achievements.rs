@@ -26,7 +26,7 @@ pub async fn get_game_achievements_by_short_id(
     let rows = client.query(
         r#"
         SELECT ua.appid, ua.apiname, s.display_name as name, s.description,
-               s.icon, s.icon_gray, ua.achieved, ua.unlocktime, ua.is_game_finishing
+               s.icon, s.icon_gray, ua.achieved, ua.unlocktime, ua.is_game_finishing, s.hidden
         FROM user_achievements ua
         LEFT JOIN achievement_schemas s ON ua.appid = s.appid AND ua.apiname = s.apiname
         WHERE ua.steam_id = $1 AND ua.appid = $2
@@ -46,6 +46,8 @@ pub async fn get_game_achievements_by_short_id(
             achieved: row.get("achieved"),
             unlocktime: row.get("unlocktime"),
             is_game_finishing: row.get::<_, Option<bool>>("is_game_finishing").unwrap_or(false),
+            global_percent: None,
+            hidden: row.get::<_, Option<bool>>("hidden").unwrap_or(false),
         }
     }).collect();
     
@@ -64,7 +66,7 @@ pub async fn get_game_achievements(
     let rows = client.query(
         r#"
         SELECT ua.appid, ua.apiname, s.display_name as name, s.description,
-               s.icon, s.icon_gray, ua.achieved, ua.unlocktime, ua.is_game_finishing
+               s.icon, s.icon_gray, ua.achieved, ua.unlocktime, ua.is_game_finishing, s.hidden
         FROM user_achievements ua
         LEFT JOIN achievement_schemas s ON ua.appid = s.appid AND ua.apiname = s.apiname
         WHERE ua.steam_id = $1 AND ua.appid = $2
@@ -84,6 +86,8 @@ pub async fn get_game_achievements(
             achieved: row.get::<_, Option<bool>>("achieved").unwrap_or(false),
             unlocktime: row.get("unlocktime"),
             is_game_finishing: row.get::<_, Option<bool>>("is_game_finishing").unwrap_or(false),
+            global_percent: None,
+            hidden: row.get::<_, Option<bool>>("hidden").unwrap_or(false),
         }
     }).collect();
     
@@ -100,13 +104,14 @@ pub async fn upsert_achievement_schema(
     
     client.execute(
         r#"
-        INSERT INTO achievement_schemas (appid, apiname, display_name, description, icon, icon_gray)
-        VALUES ($1, $2, $3, $4, $5, $6)
+        INSERT INTO achievement_schemas (appid, apiname, display_name, description, icon, icon_gray, hidden)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
         ON CONFLICT (appid, apiname) DO UPDATE SET
             display_name = EXCLUDED.display_name,
             description = EXCLUDED.description,
             icon = EXCLUDED.icon,
-            icon_gray = EXCLUDED.icon_gray
+            icon_gray = EXCLUDED.icon_gray,
+            hidden = EXCLUDED.hidden
         "#,
         &[
             &(appid as i64),
@@ -115,6 +120,7 @@ pub async fn upsert_achievement_schema(
             &schema.description,
             &schema.icon,
             &schema.icongray,
+            &(schema.hidden != 0),
         ]
     ).await?;
     
@@ -0,0 +1,270 @@
+//! Admin dashboard database operations
+
+use deadpool_postgres::Pool;
+use overachiever_core::{AdminStats, AdminUser, PendingTagSubmission, PendingTtbReport, PendingTtbSubmission};
+use crate::db::DbError;
+
+/// Get server-wide statistics
+pub async fn get_admin_stats(pool: &Pool) -> Result<AdminStats, DbError> {
+    let client = pool.get().await?;
+
+    let row = client.query_one(
+        r#"
+        SELECT
+            (SELECT COUNT(*) FROM users) AS total_users,
+            (SELECT COUNT(*) FROM users WHERE last_seen > NOW() - INTERVAL '7 days') AS active_users_7d,
+            (SELECT COUNT(*) FROM users WHERE last_seen > NOW() - INTERVAL '30 days') AS active_users_30d,
+            (SELECT COUNT(*) FROM user_games) AS total_games,
+            (SELECT COUNT(*) FROM user_achievements) AS total_achievements,
+            (SELECT COALESCE(SUM(last_upload_bytes), 0)::BIGINT FROM users) AS total_upload_bytes,
+            (SELECT COALESCE(MAX(last_upload_bytes), 0)::BIGINT FROM users) AS largest_upload_bytes,
+            pg_database_size(current_database()) AS database_bytes,
+            (SELECT COUNT(*) FROM ttb_times WHERE reviewed_at IS NULL) AS pending_ttb,
+            (SELECT COUNT(DISTINCT appid) FROM game_tags WHERE reviewed_at IS NULL) AS pending_tags,
+            (SELECT COUNT(*) FROM user_ttb_reports WHERE review_status = 'pending') AS pending_ttb_reports
+        "#,
+        &[]
+    ).await?;
+
+    Ok(AdminStats {
+        total_users: row.get("total_users"),
+        active_users_7d: row.get("active_users_7d"),
+        active_users_30d: row.get("active_users_30d"),
+        total_games: row.get("total_games"),
+        total_achievements: row.get("total_achievements"),
+        total_upload_bytes: row.get("total_upload_bytes"),
+        largest_upload_bytes: row.get("largest_upload_bytes"),
+        database_bytes: row.get("database_bytes"),
+        pending_ttb: row.get("pending_ttb"),
+        pending_tags: row.get("pending_tags"),
+        pending_ttb_reports: row.get("pending_ttb_reports"),
+    })
+}
+
+/// Get all users with their data sizes (most recently seen first)
+pub async fn get_admin_users(pool: &Pool) -> Result<Vec<AdminUser>, DbError> {
+    let client = pool.get().await?;
+
+    let rows = client.query(
+        r#"
+        SELECT u.steam_id, u.display_name, u.short_id, u.created_at, u.last_seen,
+               u.last_upload_bytes, u.last_upload_at,
+               (SELECT COUNT(*) FROM user_games g WHERE g.steam_id = u.steam_id) AS game_count,
+               (SELECT COUNT(*) FROM user_achievements a WHERE a.steam_id = u.steam_id) AS achievement_count
+        FROM users u
+        ORDER BY u.last_seen DESC NULLS LAST
+        "#,
+        &[]
+    ).await?;
+
+    Ok(rows.iter().map(|row| AdminUser {
+        steam_id: row.get::<_, i64>("steam_id").to_string(),
+        display_name: row.get("display_name"),
+        short_id: row.get("short_id"),
+        created_at: row.get("created_at"),
+        last_seen: row.get("last_seen"),
+        game_count: row.get("game_count"),
+        achievement_count: row.get("achievement_count"),
+        last_upload_bytes: row.get("last_upload_bytes"),
+        last_upload_at: row.get("last_upload_at"),
+    }).collect())
+}
+
+/// Record the size of a user's cloud upload
+pub async fn record_upload_size(pool: &Pool, steam_id: &str, bytes: i64) -> Result<(), DbError> {
+    let client = pool.get().await?;
+    let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
+
+    client.execute(
+        "UPDATE users SET last_upload_bytes = $2, last_upload_at = NOW() WHERE steam_id = $1",
+        &[&steam_id_int, &bytes]
+    ).await?;
+
+    Ok(())
+}
+
+/// Get TTB submissions not yet reviewed by an admin (newest first)
+pub async fn get_pending_ttb(pool: &Pool, limit: i64) -> Result<Vec<PendingTtbSubmission>, DbError> {
+    let client = pool.get().await?;
+
+    let rows = client.query(
+        r#"
+        SELECT appid, game_name, main, main_extra, completionist, reported_count, submitted_by, last_reported_at
+        FROM ttb_times
+        WHERE reviewed_at IS NULL
+        ORDER BY last_reported_at DESC
+        LIMIT $1
+        "#,
+        &[&limit]
+    ).await?;
+
+    Ok(rows.iter().map(|row| PendingTtbSubmission {
+        appid: row.get::<_, i64>("appid") as u64,
+        game_name: row.get("game_name"),
+        main: row.get("main"),
+        main_extra: row.get("main_extra"),
+        completionist: row.get("completionist"),
+        reported_count: row.get("reported_count"),
+        submitted_by: row.get::<_, Option<i64>>("submitted_by").map(|id| id.to_string()),
+        submitted_at: row.get("last_reported_at"),
+    }).collect())
+}
+
+/// Mark a game's TTB times as reviewed
+pub async fn approve_ttb(pool: &Pool, appid: u64) -> Result<bool, DbError> {
+    let client = pool.get().await?;
+
+    let rows_affected = client.execute(
+        r#"
+        UPDATE ttb_times
+        SET reviewed_at = NOW(), approved_main = main, approved_main_extra = main_extra,
+            approved_completionist = completionist, approved_at = NOW()
+        WHERE appid = $1 AND reviewed_at IS NULL
+        "#,
+        &[&(appid as i64)]
+    ).await?;
+
+    Ok(rows_affected > 0)
+}
+
+/// Discard a game's pending TTB times: back to the last approved times, or removed
+/// if none were ever approved
+pub async fn reject_ttb(pool: &Pool, appid: u64) -> Result<bool, DbError> {
+    let mut client = pool.get().await?;
+    let transaction = client.transaction().await?;
+
+    let restored = transaction.execute(
+        r#"
+        UPDATE ttb_times
+        SET main = approved_main, main_extra = approved_main_extra,
+            completionist = approved_completionist, reviewed_at = approved_at
+        WHERE appid = $1 AND reviewed_at IS NULL AND approved_at IS NOT NULL
+        "#,
+        &[&(appid as i64)]
+    ).await?;
+    let deleted = transaction.execute(
+        "DELETE FROM ttb_times WHERE appid = $1 AND reviewed_at IS NULL",
+        &[&(appid as i64)]
+    ).await?;
+
+    transaction.commit().await?;
+    Ok(restored + deleted > 0)
+}
+
+/// Get user TTB reports held back for review (oldest first)
+pub async fn get_pending_ttb_reports(pool: &Pool, limit: i64) -> Result<Vec<PendingTtbReport>, DbError> {
+    let client = pool.get().await?;
+
+    let rows = client.query(
+        r#"
+        SELECT r.id, r.steam_id, r.appid, t.game_name, r.main_seconds, r.extra_seconds, r.completionist_seconds,
+               r.review_reason, r.reported_at
+        FROM user_ttb_reports r
+        LEFT JOIN ttb_times t ON t.appid = r.appid
+        WHERE r.review_status = 'pending'
+        ORDER BY r.reported_at
+        LIMIT $1
+        "#,
+        &[&limit]
+    ).await?;
+
+    Ok(rows.iter().map(|row| PendingTtbReport {
+        id: row.get::<_, i32>("id") as i64,
+        steam_id: row.get::<_, Option<i64>>("steam_id").map(|id| id.to_string()).unwrap_or_default(),
+        appid: row.get::<_, i64>("appid") as u64,
+        game_name: row.get("game_name"),
+        main_seconds: row.get("main_seconds"),
+        extra_seconds: row.get("extra_seconds"),
+        completionist_seconds: row.get("completionist_seconds"),
+        reason: row.get("review_reason"),
+        reported_at: row.get("reported_at"),
+    }).collect())
+}
+
+/// Set the review status of a pending user TTB report ("approved" or "rejected").
+/// The averages trigger picks up the change.
+pub async fn review_ttb_report(pool: &Pool, id: i64, approve: bool, admin_steam_id: &str) -> Result<bool, DbError> {
+    let client = pool.get().await?;
+    let admin_id: i64 = admin_steam_id.parse().unwrap_or(0);
+    let status = if approve { "approved" } else { "rejected" };
+
+    let rows_affected = client.execute(
+        r#"
+        UPDATE user_ttb_reports
+        SET review_status = $2, reviewed_at = NOW(), reviewed_by = $3
+        WHERE id = $1 AND review_status = 'pending'
+        "#,
+        &[&(id as i32), &status, &admin_id]
+    ).await?;
+
+    Ok(rows_affected > 0)
+}
+
+/// Get tag submissions not yet reviewed by an admin, grouped per game (newest first)
+pub async fn get_pending_tags(pool: &Pool, limit: i64) -> Result<Vec<PendingTagSubmission>, DbError> {
+    let client = pool.get().await?;
+
+    let rows = client.query(
+        r#"
+        SELECT appid,
+               ARRAY_AGG(tag_name ORDER BY vote_count DESC) AS tag_names,
+               ARRAY_AGG(vote_count ORDER BY vote_count DESC) AS vote_counts,
+               MAX(submitted_by) AS submitted_by,
+               MAX(updated_at) AS submitted_at
+        FROM game_tags
+        WHERE reviewed_at IS NULL
+        GROUP BY appid
+        ORDER BY MAX(updated_at) DESC
+        LIMIT $1
+        "#,
+        &[&limit]
+    ).await?;
+
+    Ok(rows.iter().map(|row| {
+        let names: Vec<String> = row.get("tag_names");
+        let votes: Vec<i32> = row.get("vote_counts");
+        PendingTagSubmission {
+            appid: row.get::<_, i64>("appid") as u64,
+            tags: names.into_iter().zip(votes).map(|(name, votes)| (name, votes.max(0) as u32)).collect(),
+            submitted_by: row.get::<_, Option<i64>>("submitted_by").map(|id| id.to_string()),
+            submitted_at: row.get("submitted_at"),
+        }
+    }).collect())
+}
+
+/// Mark a game's pending tags as reviewed, returns the number of tags approved
+pub async fn approve_tags(pool: &Pool, appid: u64) -> Result<u64, DbError> {
+    let client = pool.get().await?;
+
+    let rows_affected = client.execute(
+        r#"
+        UPDATE game_tags SET reviewed_at = NOW(), approved_vote_count = vote_count, approved_at = NOW()
+        WHERE appid = $1 AND reviewed_at IS NULL
+        "#,
+        &[&(appid as i64)]
+    ).await?;
+
+    Ok(rows_affected)
+}
+
+/// Discard a game's pending tags: tags approved before get their approved vote count
+/// back, new ones are removed. Returns the number of tags rejected.
+pub async fn reject_tags(pool: &Pool, appid: u64) -> Result<u64, DbError> {
+    let mut client = pool.get().await?;
+    let transaction = client.transaction().await?;
+
+    let restored = transaction.execute(
+        r#"
+        UPDATE game_tags SET vote_count = approved_vote_count, reviewed_at = approved_at
+        WHERE appid = $1 AND reviewed_at IS NULL AND approved_at IS NOT NULL
+        "#,
+        &[&(appid as i64)]
+    ).await?;
+    let deleted = transaction.execute(
+        "DELETE FROM game_tags WHERE appid = $1 AND reviewed_at IS NULL",
+        &[&(appid as i64)]
+    ).await?;
+
+    transaction.commit().await?;
+    Ok(restored + deleted)
+}
@@ -0,0 +1,109 @@
+//! Third-party API keys - minting, listing, revoking and key lookup
+
+use deadpool_postgres::Pool;
+use overachiever_core::{ApiKeyInfo, ApiKeyScope, CreatedApiKey};
+use crate::db::{generate_api_key, hash_api_key, scopes_from_row, DbError, DISPLAY_PREFIX_LENGTH};
+
+/// Number of active (not revoked) keys of a user
+pub async fn count_api_keys(pool: &Pool, steam_id: &str) -> Result<i64, DbError> {
+    let client = pool.get().await?;
+    let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
+
+    let row = client.query_one(
+        "SELECT COUNT(*) FROM api_keys WHERE steam_id = $1 AND revoked_at IS NULL",
+        &[&steam_id_int]
+    ).await?;
+    Ok(row.get(0))
+}
+
+/// Mint a new key. The returned key is the only time the secret is available.
+pub async fn create_api_key(
+    pool: &Pool,
+    steam_id: &str,
+    name: &str,
+    scopes: &[ApiKeyScope],
+) -> Result<CreatedApiKey, DbError> {
+    let client = pool.get().await?;
+    let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
+
+    let key = generate_api_key();
+    let prefix = key[..DISPLAY_PREFIX_LENGTH].to_string();
+    let scope_names: Vec<&str> = scopes.iter().map(|s| s.as_str()).collect();
+
+    let row = client.query_one(
+        r#"
+        INSERT INTO api_keys (steam_id, name, key_prefix, key_hash, scopes)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id, created_at
+        "#,
+        &[&steam_id_int, &name, &prefix, &hash_api_key(&key), &scope_names]
+    ).await?;
+
+    Ok(CreatedApiKey {
+        key,
+        info: ApiKeyInfo {
+            id: row.get("id"),
+            name: name.to_string(),
+            prefix,
+            scopes: scopes.to_vec(),
+            created_at: row.get("created_at"),
+            last_used_at: None,
+        },
+    })
+}
+
+/// A user's active keys, newest first
+pub async fn get_api_keys(pool: &Pool, steam_id: &str) -> Result<Vec<ApiKeyInfo>, DbError> {
+    let client = pool.get().await?;
+    let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
+
+    let rows = client.query(
+        r#"
+        SELECT id, name, key_prefix, scopes, created_at, last_used_at
+        FROM api_keys
+        WHERE steam_id = $1 AND revoked_at IS NULL
+        ORDER BY created_at DESC
+        "#,
+        &[&steam_id_int]
+    ).await?;
+
+    Ok(rows.iter().map(|row| ApiKeyInfo {
+        id: row.get("id"),
+        name: row.get("name"),
+        prefix: row.get("key_prefix"),
+        scopes: scopes_from_row(row.get("scopes")),
+        created_at: row.get("created_at"),
+        last_used_at: row.get("last_used_at"),
+    }).collect())
+}
+
+/// Revoke one of a user's keys. Returns false if the user has no such active key.
+pub async fn revoke_api_key(pool: &Pool, steam_id: &str, key_id: i64) -> Result<bool, DbError> {
+    let client = pool.get().await?;
+    let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
+
+    let revoked = client.execute(
+        "UPDATE api_keys SET revoked_at = NOW() WHERE id = $1 AND steam_id = $2 AND revoked_at IS NULL",
+        &[&key_id, &steam_id_int]
+    ).await?;
+    Ok(revoked > 0)
+}
+
+/// Look up an active key, returning its owner and scopes (and marking it used)
+pub async fn authenticate_api_key(pool: &Pool, key: &str) -> Result<Option<(String, Vec<ApiKeyScope>)>, DbError> {
+    let client = pool.get().await?;
+
+    let row = client.query_opt(
+        r#"
+        UPDATE api_keys SET last_used_at = NOW()
+        WHERE key_hash = $1 AND revoked_at IS NULL
+        RETURNING steam_id, scopes
+        "#,
+        &[&hash_api_key(key)]
+    ).await?;
+
+    Ok(row.map(|row| (
+        row.get::<_, i64>("steam_id").to_string(),
+        scopes_from_row(row.get("scopes")),
+    )))
+}
@@ -1,7 +1,8 @@
 //! Cloud sync database operations
 
 use deadpool_postgres::Pool;
-use overachiever_core::{CloudSyncData, CloudSyncStatus};
+use overachiever_core::{game_sync_checksum, CloudSyncData, CloudSyncDelta, CloudSyncStatus, Goal, SyncAchievement, SyncManifest};
+use std::collections::{BTreeSet, HashMap};
 use chrono::Utc;
 use crate::db::DbError;
 
@@ -39,6 +40,7 @@ pub async fn get_cloud_sync_data(pool: &Pool, steam_id: &str) -> Result<CloudSyn
     let achievements = crate::db::history::get_all_user_achievements(pool, steam_id).await?;
     let run_history = crate::db::history::get_run_history(pool, steam_id).await?;
     let achievement_history = crate::db::history::get_achievement_history(pool, steam_id).await?;
+    let goals = get_user_goals(pool, steam_id).await?;
     
     Ok(CloudSyncData {
         steam_id: steam_id.to_string(),
@@ -46,10 +48,26 @@ pub async fn get_cloud_sync_data(pool: &Pool, steam_id: &str) -> Result<CloudSyn
         achievements,
         run_history,
         achievement_history,
+        goals,
         exported_at: Utc::now(),
     })
 }
 
+/// Get a user's goals, oldest first (rows that no longer parse are skipped)
+async fn get_user_goals(pool: &Pool, steam_id: &str) -> Result<Vec<Goal>, DbError> {
+    let client = pool.get().await?;
+    let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
+    
+    let rows = client.query(
+        "SELECT goal FROM user_goals WHERE steam_id = $1 ORDER BY created_at",
+        &[&steam_id_int]
+    ).await?;
+    
+    Ok(rows.iter()
+        .filter_map(|row| serde_json::from_str(row.get::<_, &str>("goal")).ok())
+        .collect())
+}
+
 /// Upload cloud sync data (overwrites all existing data for user)
 pub async fn upload_cloud_sync_data(pool: &Pool, data: &CloudSyncData) -> Result<(), DbError> {
     let mut client = pool.get().await?;
@@ -139,11 +157,177 @@ pub async fn upload_cloud_sync_data(pool: &Pool, data: &CloudSyncData) -> Result
         ).await?;
     }
     
+    // Replace goals
+    transaction.execute("DELETE FROM user_goals WHERE steam_id = $1", &[&steam_id_int]).await?;
+    for goal in &data.goals {
+        let json = serde_json::to_string(goal).unwrap_or_default();
+        transaction.execute(
+            "INSERT INTO user_goals (steam_id, created_at, goal) VALUES ($1, $2, $3) ON CONFLICT DO NOTHING",
+            &[&steam_id_int, &goal.created_at, &json]
+        ).await?;
+    }
+    
     transaction.commit().await?;
     
     Ok(())
 }
 
+/// What the server has for a user, for delta uploads: checksums per game, newest history entries
+pub async fn get_sync_manifest(pool: &Pool, steam_id: &str) -> Result<SyncManifest, DbError> {
+    let games = crate::db::games::get_user_games(pool, steam_id).await?;
+    let achievements = crate::db::history::get_all_user_achievements(pool, steam_id).await?;
+    let mut achievements_by_game: HashMap<u64, Vec<&SyncAchievement>> = HashMap::new();
+    for ach in &achievements {
+        achievements_by_game.entry(ach.appid).or_default().push(ach);
+    }
+    let game_checksums = games
+        .iter()
+        .map(|game| {
+            let achievements = achievements_by_game.get(&game.appid).map(Vec::as_slice).unwrap_or(&[]);
+            (game.appid, game_sync_checksum(game, achievements))
+        })
+        .collect();
+
+    let client = pool.get().await?;
+    let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
+    let last_run_at: Option<chrono::DateTime<Utc>> = client.query_one(
+        "SELECT MAX(run_at) FROM run_history WHERE steam_id = $1",
+        &[&steam_id_int]
+    ).await?.get(0);
+    let last_achievement_history_at: Option<chrono::DateTime<Utc>> = client.query_one(
+        "SELECT MAX(recorded_at) FROM achievement_history WHERE steam_id = $1",
+        &[&steam_id_int]
+    ).await?.get(0);
+
+    Ok(SyncManifest {
+        game_checksums,
+        last_run_at,
+        last_achievement_history_at,
+    })
+}
+
+/// Merge a delta upload into the stored data. Unlike the full upload nothing is deleted,
+/// and concurrent uploads from other devices can't undo progress: unlocks are kept (newest
+/// unlock time wins, like the desktop merge - see `merge_sync_achievement`),
+/// playtime only grows, game data from an older scrape doesn't replace a newer one, and
+/// unlock counts are recounted from the merged achievement rows.
+pub async fn merge_cloud_sync_delta(pool: &Pool, delta: &CloudSyncDelta) -> Result<(), DbError> {
+    let mut client = pool.get().await?;
+    let steam_id_int: i64 = delta.steam_id.parse().unwrap_or(0);
+
+    let transaction = client.transaction().await?;
+
+    transaction.execute(
+        "INSERT INTO users (steam_id, display_name) VALUES ($1, $2) ON CONFLICT (steam_id) DO NOTHING",
+        &[&steam_id_int, &format!("User {}", &delta.steam_id[..8.min(delta.steam_id.len())])]
+    ).await?;
+
+    for game in &delta.games {
+        transaction.execute(
+            r#"
+            INSERT INTO user_games (steam_id, appid, name, playtime_forever, rtime_last_played, img_icon_url, added_at, achievements_total, achievements_unlocked, last_sync, hidden, steam_hidden)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            ON CONFLICT (steam_id, appid) DO UPDATE SET
+                name = EXCLUDED.name,
+                img_icon_url = EXCLUDED.img_icon_url,
+                playtime_forever = GREATEST(user_games.playtime_forever, EXCLUDED.playtime_forever),
+                rtime_last_played = GREATEST(user_games.rtime_last_played, EXCLUDED.rtime_last_played),
+                achievements_total = CASE WHEN user_games.last_sync IS NULL OR EXCLUDED.last_sync >= user_games.last_sync
+                    THEN EXCLUDED.achievements_total ELSE user_games.achievements_total END,
+                achievements_unlocked = CASE WHEN user_games.last_sync IS NULL OR EXCLUDED.last_sync >= user_games.last_sync
+                    THEN EXCLUDED.achievements_unlocked ELSE user_games.achievements_unlocked END,
+                last_sync = GREATEST(user_games.last_sync, EXCLUDED.last_sync)
+            "#,
+            &[
+                &steam_id_int,
+                &(game.appid as i64),
+                &game.name,
+                &(game.playtime_forever as i32),
+                &game.rtime_last_played.map(|t| t as i32),
+                &game.img_icon_url,
+                &game.added_at,
+                &game.achievements_total,
+                &game.achievements_unlocked,
+                &game.last_achievement_scrape,
+                &game.hidden,
+                &game.steam_hidden,
+            ]
+        ).await?;
+    }
+
+    for ach in &delta.achievements {
+        transaction.execute(
+            r#"
+            INSERT INTO user_achievements (steam_id, appid, apiname, achieved, unlocktime)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (steam_id, appid, apiname) DO UPDATE SET
+                achieved = user_achievements.achieved OR EXCLUDED.achieved,
+                unlocktime = GREATEST(user_achievements.unlocktime, EXCLUDED.unlocktime)
+            "#,
+            &[
+                &steam_id_int,
+                &(ach.appid as i64),
+                &ach.apiname,
+                &ach.achieved,
+                &ach.unlocktime,
+            ]
+        ).await?;
+    }
+
+    // Unlock counts follow the merged achievement rows. Games without any rows
+    // (never scraped) keep the count picked above.
+    let touched: BTreeSet<i64> = delta.games.iter().map(|g| g.appid as i64)
+        .chain(delta.achievements.iter().map(|a| a.appid as i64))
+        .collect();
+    for appid in touched {
+        transaction.execute(
+            r#"
+            UPDATE user_games SET achievements_unlocked =
+                (SELECT COUNT(*) FROM user_achievements WHERE steam_id = $1 AND appid = $2 AND achieved)::INTEGER
+            WHERE steam_id = $1 AND appid = $2
+              AND EXISTS (SELECT 1 FROM user_achievements WHERE steam_id = $1 AND appid = $2)
+            "#,
+            &[&steam_id_int, &appid]
+        ).await?;
+    }
+
+    // History is append-only; skip entries another upload already added
+    for rh in &delta.run_history {
+        transaction.execute(
+            r#"
+            INSERT INTO run_history (steam_id, run_at, total_games, unplayed_games, unplayed_games_total)
+            SELECT $1, $2, $3, $4, $5
+            WHERE NOT EXISTS (SELECT 1 FROM run_history WHERE steam_id = $1 AND run_at = $2)
+            "#,
+            &[&steam_id_int, &rh.run_at, &rh.total_games, &rh.unplayed_games, &rh.unplayed_games_total]
+        ).await?;
+    }
+
+    for ah in &delta.achievement_history {
+        transaction.execute(
+            r#"
+            INSERT INTO achievement_history (steam_id, recorded_at, total_achievements, unlocked_achievements, games_with_achievements, avg_completion_percent)
+            SELECT $1, $2, $3, $4, $5, $6
+            WHERE NOT EXISTS (SELECT 1 FROM achievement_history WHERE steam_id = $1 AND recorded_at = $2)
+            "#,
+            &[&steam_id_int, &ah.recorded_at, &ah.total_achievements, &ah.unlocked_achievements, &ah.games_with_achievements, &(ah.avg_completion_percent as f64)]
+        ).await?;
+    }
+
+    transaction.execute("DELETE FROM user_goals WHERE steam_id = $1", &[&steam_id_int]).await?;
+    for goal in &delta.goals {
+        let json = serde_json::to_string(goal).unwrap_or_default();
+        transaction.execute(
+            "INSERT INTO user_goals (steam_id, created_at, goal) VALUES ($1, $2, $3) ON CONFLICT DO NOTHING",
+            &[&steam_id_int, &goal.created_at, &json]
+        ).await?;
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
 /// Delete all cloud data for a user
 pub async fn delete_cloud_sync_data(pool: &Pool, steam_id: &str) -> Result<(), DbError> {
     let client = pool.get().await?;
@@ -156,6 +340,7 @@ pub async fn delete_cloud_sync_data(pool: &Pool, steam_id: &str) -> Result<(), D
     client.execute("DELETE FROM achievement_history WHERE steam_id = $1", &[&steam_id_int]).await?;
     client.execute("DELETE FROM achievement_ratings WHERE steam_id = $1", &[&steam_id_int]).await?;
     client.execute("DELETE FROM game_ratings WHERE steam_id = $1", &[&steam_id_int]).await?;
+    client.execute("DELETE FROM user_goals WHERE steam_id = $1", &[&steam_id_int]).await?;
     
     Ok(())
 }
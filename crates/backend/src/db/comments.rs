@@ -0,0 +1,150 @@
+//! Achievement comments and the comment report / moderation flow
+
+use deadpool_postgres::Pool;
+use overachiever_core::{AchievementComment, ReportedComment};
+use crate::db::DbError;
+
+/// Map a row selected with the comment columns (and the author's display name)
+fn comment_from_row(row: &tokio_postgres::Row) -> AchievementComment {
+    AchievementComment {
+        id: row.get("id"),
+        steam_id: row.get::<_, i64>("steam_id").to_string(),
+        display_name: row.get("display_name"),
+        appid: row.get::<_, i64>("appid") as u64,
+        apiname: row.get("apiname"),
+        comment: row.get("comment"),
+        created_at: row.get("created_at"),
+    }
+}
+
+/// Store one comment for each of the given achievements, returns the number stored
+pub async fn insert_achievement_comments(
+    pool: &Pool,
+    steam_id: &str,
+    achievements: &[(u64, String)],
+    comment: &str,
+) -> Result<u64, DbError> {
+    let mut client = pool.get().await?;
+    let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
+
+    let transaction = client.transaction().await?;
+    let statement = transaction.prepare(
+        "INSERT INTO achievement_comments (steam_id, appid, apiname, comment) VALUES ($1, $2, $3, $4)"
+    ).await?;
+    let mut stored = 0;
+    for (appid, apiname) in achievements {
+        stored += transaction.execute(&statement, &[&steam_id_int, &(*appid as i64), apiname, &comment]).await?;
+    }
+    transaction.commit().await?;
+
+    Ok(stored)
+}
+
+/// Visible (not removed) comments on a game's achievements, newest first
+pub async fn get_game_comments(pool: &Pool, appid: u64) -> Result<Vec<AchievementComment>, DbError> {
+    let client = pool.get().await?;
+
+    let rows = client.query(
+        r#"
+        SELECT c.id, c.steam_id, u.display_name, c.appid, c.apiname, c.comment, c.created_at
+        FROM achievement_comments c
+        LEFT JOIN users u ON u.steam_id = c.steam_id
+        WHERE c.appid = $1 AND c.deleted_at IS NULL
+        ORDER BY c.created_at DESC
+        "#,
+        &[&(appid as i64)]
+    ).await?;
+
+    Ok(rows.iter().map(comment_from_row).collect())
+}
+
+/// Report a comment. Returns false if the comment doesn't exist or was already removed.
+/// Reporting the same comment twice keeps the first report.
+pub async fn report_comment(
+    pool: &Pool,
+    comment_id: i64,
+    reported_by: &str,
+    reason: Option<&str>,
+) -> Result<bool, DbError> {
+    let client = pool.get().await?;
+    let reporter_int: i64 = reported_by.parse().unwrap_or(0);
+
+    let exists = client.query_opt(
+        "SELECT 1 FROM achievement_comments WHERE id = $1 AND deleted_at IS NULL",
+        &[&comment_id]
+    ).await?.is_some();
+    if !exists {
+        return Ok(false);
+    }
+
+    client.execute(
+        r#"
+        INSERT INTO comment_reports (comment_id, reported_by, reason)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (comment_id, reported_by) DO NOTHING
+        "#,
+        &[&comment_id, &reporter_int, &reason]
+    ).await?;
+
+    Ok(true)
+}
+
+/// Comments with open reports, most reported first
+pub async fn get_reported_comments(pool: &Pool, limit: i64) -> Result<Vec<ReportedComment>, DbError> {
+    let client = pool.get().await?;
+
+    let rows = client.query(
+        r#"
+        SELECT c.id, c.steam_id, u.display_name, c.appid, c.apiname, c.comment, c.created_at,
+               COUNT(r.id) AS report_count,
+               ARRAY_REMOVE(ARRAY_AGG(NULLIF(TRIM(r.reason), '') ORDER BY r.created_at), NULL) AS reasons,
+               MIN(r.created_at) AS first_reported_at
+        FROM comment_reports r
+        JOIN achievement_comments c ON c.id = r.comment_id
+        LEFT JOIN users u ON u.steam_id = c.steam_id
+        WHERE r.resolved_at IS NULL AND c.deleted_at IS NULL
+        GROUP BY c.id, u.display_name
+        ORDER BY COUNT(r.id) DESC, MIN(r.created_at)
+        LIMIT $1
+        "#,
+        &[&limit]
+    ).await?;
+
+    Ok(rows.iter().map(|row| ReportedComment {
+        comment: comment_from_row(row),
+        report_count: row.get("report_count"),
+        reasons: row.get("reasons"),
+        first_reported_at: row.get("first_reported_at"),
+    }).collect())
+}
+
+/// Soft-delete a comment and resolve its reports. Returns false if there was no visible comment.
+pub async fn remove_comment(pool: &Pool, comment_id: i64, admin_steam_id: &str) -> Result<bool, DbError> {
+    let mut client = pool.get().await?;
+    let admin_int: i64 = admin_steam_id.parse().unwrap_or(0);
+
+    let transaction = client.transaction().await?;
+    let removed = transaction.execute(
+        "UPDATE achievement_comments SET deleted_at = NOW(), deleted_by = $2 WHERE id = $1 AND deleted_at IS NULL",
+        &[&comment_id, &admin_int]
+    ).await?;
+    transaction.execute(
+        "UPDATE comment_reports SET resolved_at = NOW() WHERE comment_id = $1 AND resolved_at IS NULL",
+        &[&comment_id]
+    ).await?;
+    transaction.commit().await?;
+
+    Ok(removed > 0)
+}
+
+/// Dismiss the open reports of a comment (it stays visible), returns the number dismissed
+pub async fn dismiss_comment_reports(pool: &Pool, comment_id: i64) -> Result<u64, DbError> {
+    let client = pool.get().await?;
+
+    let rows_affected = client.execute(
+        "UPDATE comment_reports SET resolved_at = NOW() WHERE comment_id = $1 AND resolved_at IS NULL",
+        &[&comment_id]
+    ).await?;
+
+    Ok(rows_affected)
+}
@@ -0,0 +1,31 @@
+//! PostgreSQL connection pool
+
+use deadpool_postgres::{Config, Runtime};
+pub use deadpool_postgres::Pool;
+use tokio_postgres::NoTls;
+use crate::db::DbError;
+
+/// Create the pool from the DB_HOST / DB_PORT / DB_NAME / DB_USER / DB_PASSWORD environment
+/// variables and check that the database is reachable
+pub async fn connect() -> Result<Pool, String> {
+    let mut cfg = Config::new();
+    cfg.host = std::env::var("DB_HOST").ok();
+    cfg.port = std::env::var("DB_PORT").ok().and_then(|p| p.parse().ok());
+    cfg.dbname = std::env::var("DB_NAME").ok();
+    cfg.user = std::env::var("DB_USER").ok();
+    cfg.password = std::env::var("DB_PASSWORD").ok();
+
+    let pool = cfg.create_pool(Some(Runtime::Tokio1), NoTls)
+        .map_err(|e| format!("Failed to create database pool: {}", e))?;
+
+    // Test connection
+    let _client = pool.get().await.map_err(|e| format!("Failed to connect to database: {}", e))?;
+    Ok(pool)
+}
+
+/// Run a trivial query (health check)
+pub async fn ping(pool: &Pool) -> Result<(), DbError> {
+    let client = pool.get().await?;
+    client.query_one("SELECT 1", &[]).await?;
+    Ok(())
+}
@@ -0,0 +1,179 @@
+//! Weekly email digest subscriptions and the data each digest is built from
+
+use chrono::{DateTime, Duration, Utc};
+use deadpool_postgres::Pool;
+use overachiever_core::DigestSubscription;
+use crate::db::{generate_unsubscribe_token, DbError, DigestData, DigestUnlock, DueDigest, DIGEST_INTERVAL_DAYS, MAX_LISTED_UNLOCKS};
+
+fn subscription_from_row(row: &tokio_postgres::Row) -> DigestSubscription {
+    DigestSubscription {
+        email: row.get("email"),
+        enabled: row.get("enabled"),
+        next_send_at: row.get("next_send_at"),
+        last_sent_at: row.get("last_sent_at"),
+    }
+}
+
+/// Get a user's digest subscription
+pub async fn get_digest_subscription(pool: &Pool, steam_id: &str) -> Result<Option<DigestSubscription>, DbError> {
+    let client = pool.get().await?;
+    let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
+
+    let row = client.query_opt(
+        "SELECT email, enabled, next_send_at, last_sent_at FROM email_digests WHERE steam_id = $1",
+        &[&steam_id_int]
+    ).await?;
+
+    Ok(row.as_ref().map(subscription_from_row))
+}
+
+/// Opt into the digest (or change the address). The first digest goes out a week from now.
+pub async fn subscribe_digest(pool: &Pool, steam_id: &str, email: &str) -> Result<DigestSubscription, DbError> {
+    let client = pool.get().await?;
+    let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
+    let next_send_at = Utc::now() + Duration::days(DIGEST_INTERVAL_DAYS);
+
+    let row = client.query_one(
+        r#"
+        INSERT INTO email_digests (steam_id, email, enabled, unsubscribe_token, next_send_at)
+        VALUES ($1, $2, TRUE, $3, $4)
+        ON CONFLICT (steam_id) DO UPDATE SET
+            email = EXCLUDED.email,
+            enabled = TRUE,
+            next_send_at = CASE WHEN email_digests.enabled THEN email_digests.next_send_at ELSE EXCLUDED.next_send_at END
+        RETURNING email, enabled, next_send_at, last_sent_at
+        "#,
+        &[&steam_id_int, &email, &generate_unsubscribe_token(), &next_send_at]
+    ).await?;
+
+    Ok(subscription_from_row(&row))
+}
+
+/// Remove a user's subscription, returns whether there was one
+pub async fn delete_digest_subscription(pool: &Pool, steam_id: &str) -> Result<bool, DbError> {
+    let client = pool.get().await?;
+    let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
+
+    let rows_affected = client.execute(
+        "DELETE FROM email_digests WHERE steam_id = $1",
+        &[&steam_id_int]
+    ).await?;
+
+    Ok(rows_affected > 0)
+}
+
+/// Turn the digest off from an email's unsubscribe link, returns whether the token matched
+pub async fn unsubscribe_digest(pool: &Pool, token: &str) -> Result<bool, DbError> {
+    let client = pool.get().await?;
+
+    let rows_affected = client.execute(
+        "UPDATE email_digests SET enabled = FALSE WHERE unsubscribe_token = $1",
+        &[&token]
+    ).await?;
+
+    Ok(rows_affected > 0)
+}
+
+/// Subscriptions that are due, oldest first
+pub async fn get_due_digests(pool: &Pool, limit: i64) -> Result<Vec<DueDigest>, DbError> {
+    let client = pool.get().await?;
+
+    let rows = client.query(
+        r#"
+        SELECT d.steam_id, u.display_name, d.email, d.unsubscribe_token,
+               COALESCE(d.last_sent_at, d.next_send_at - make_interval(days => $2::int)) AS since
+        FROM email_digests d
+        JOIN users u ON u.steam_id = d.steam_id
+        WHERE d.enabled AND d.next_send_at <= NOW()
+        ORDER BY d.next_send_at
+        LIMIT $1
+        "#,
+        &[&limit, &(DIGEST_INTERVAL_DAYS as i32)]
+    ).await?;
+
+    Ok(rows.iter().map(|row| DueDigest {
+        steam_id: row.get::<_, i64>("steam_id").to_string(),
+        display_name: row.get("display_name"),
+        email: row.get("email"),
+        unsubscribe_token: row.get("unsubscribe_token"),
+        since: row.get("since"),
+    }).collect())
+}
+
+/// Record a sent (or skipped) digest and schedule the next one a week later
+pub async fn mark_digest_sent(pool: &Pool, steam_id: &str, sent: bool) -> Result<(), DbError> {
+    let client = pool.get().await?;
+    let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
+
+    // Skip ahead rather than catching up on weeks missed while the server was down
+    client.execute(
+        r#"
+        UPDATE email_digests SET
+            last_sent_at = CASE WHEN $2 THEN NOW() ELSE last_sent_at END,
+            next_send_at = GREATEST(next_send_at, NOW()) + make_interval(days => $3::int)
+        WHERE steam_id = $1
+        "#,
+        &[&steam_id_int, &sent, &(DIGEST_INTERVAL_DAYS as i32)]
+    ).await?;
+
+    Ok(())
+}
+
+/// Collect the unlocks, streak days and completion change since `since`
+pub async fn get_digest_data(pool: &Pool, steam_id: &str, since: DateTime<Utc>) -> Result<DigestData, DbError> {
+    let client = pool.get().await?;
+    let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
+
+    let unlock_count: i64 = client.query_one(
+        "SELECT COUNT(*) FROM user_achievements WHERE steam_id = $1 AND achieved AND unlocktime > $2",
+        &[&steam_id_int, &since]
+    ).await?.get(0);
+
+    let unlocks = client.query(
+        r#"
+        SELECT COALESCE(g.name, 'App ' || ua.appid) AS game_name,
+               COALESCE(s.display_name, ua.apiname) AS achievement_name,
+               ua.unlocktime
+        FROM user_achievements ua
+        LEFT JOIN user_games g ON g.steam_id = ua.steam_id AND g.appid = ua.appid
+        LEFT JOIN achievement_schemas s ON s.appid = ua.appid AND s.apiname = ua.apiname
+        WHERE ua.steam_id = $1 AND ua.achieved AND ua.unlocktime > $2
+        ORDER BY ua.unlocktime DESC
+        LIMIT $3
+        "#,
+        &[&steam_id_int, &since, &MAX_LISTED_UNLOCKS]
+    ).await?;
+
+    // A year of days is plenty for any streak worth mentioning
+    let days = client.query(
+        r#"
+        SELECT DISTINCT (unlocktime AT TIME ZONE 'UTC')::date AS day
+        FROM user_achievements
+        WHERE steam_id = $1 AND achieved AND unlocktime > NOW() - INTERVAL '366 days'
+        ORDER BY day DESC
+        "#,
+        &[&steam_id_int]
+    ).await?;
+
+    let completion = |row: Option<tokio_postgres::Row>| row.map(|r| r.get::<_, f64>("avg_completion_percent"));
+    let completion_before = completion(client.query_opt(
+        "SELECT avg_completion_percent FROM achievement_history WHERE steam_id = $1 AND recorded_at <= $2 ORDER BY recorded_at DESC LIMIT 1",
+        &[&steam_id_int, &since]
+    ).await?);
+    let completion_after = completion(client.query_opt(
+        "SELECT avg_completion_percent FROM achievement_history WHERE steam_id = $1 ORDER BY recorded_at DESC LIMIT 1",
+        &[&steam_id_int]
+    ).await?);
+
+    Ok(DigestData {
+        unlocks: unlocks.iter().map(|row| DigestUnlock {
+            game_name: row.get("game_name"),
+            achievement_name: row.get("achievement_name"),
+            unlocktime: row.get("unlocktime"),
+        }).collect(),
+        unlock_count,
+        unlock_days: days.iter().map(|row| row.get("day")).collect(),
+        completion_before,
+        completion_after,
+    })
+}
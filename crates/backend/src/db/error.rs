@@ -1,30 +1,48 @@
 //! Database error types
 
+#[cfg(not(feature = "sqlite"))]
 use deadpool_postgres::PoolError;
 
 #[derive(Debug)]
 pub enum DbError {
+    #[cfg(not(feature = "sqlite"))]
     Pool(PoolError),
+    #[cfg(not(feature = "sqlite"))]
     Postgres(tokio_postgres::Error),
+    #[cfg(feature = "sqlite")]
+    Sqlite(rusqlite::Error),
 }
 
+#[cfg(not(feature = "sqlite"))]
 impl From<PoolError> for DbError {
     fn from(e: PoolError) -> Self {
         DbError::Pool(e)
     }
 }
 
+#[cfg(not(feature = "sqlite"))]
 impl From<tokio_postgres::Error> for DbError {
     fn from(e: tokio_postgres::Error) -> Self {
         DbError::Postgres(e)
     }
 }
 
+#[cfg(feature = "sqlite")]
+impl From<rusqlite::Error> for DbError {
+    fn from(e: rusqlite::Error) -> Self {
+        DbError::Sqlite(e)
+    }
+}
+
 impl std::fmt::Display for DbError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            #[cfg(not(feature = "sqlite"))]
             DbError::Pool(e) => write!(f, "Pool error: {}", e),
+            #[cfg(not(feature = "sqlite"))]
             DbError::Postgres(e) => write!(f, "Postgres error: {}", e),
+            #[cfg(feature = "sqlite")]
+            DbError::Sqlite(e) => write!(f, "SQLite error: {}", e),
         }
     }
 }
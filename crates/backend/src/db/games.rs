@@ -57,6 +57,10 @@ pub async fn get_user_games_by_short_id(pool: &Pool, short_id: &str) -> Result<O
             hidden: row.get::<_, Option<bool>>("hidden").unwrap_or(false),
             steam_hidden: row.get::<_, Option<bool>>("steam_hidden").unwrap_or(false),
             steam_private: false,  // Not stored in database yet
+            app_type: None,  // Not stored in database yet
+            abandoned: false,  // Local-only flag
+            platform_playtime: None,  // Local-only data
+            owned: true,  // Ownership is tracked locally only
         }
     }).collect();
     
@@ -105,6 +109,10 @@ pub async fn get_user_games(pool: &Pool, steam_id: &str) -> Result<Vec<Game>, Db
             hidden: row.get::<_, Option<bool>>("hidden").unwrap_or(false),
             steam_hidden: row.get::<_, Option<bool>>("steam_hidden").unwrap_or(false),
             steam_private: false,  // Not stored in database yet
+            app_type: None,  // Not stored in database yet
+            abandoned: false,  // Local-only flag
+            platform_playtime: None,  // Local-only data
+            owned: true,  // Ownership is tracked locally only
         }
     }).collect();
     
@@ -204,3 +212,69 @@ pub async fn update_game_hidden(
     
     Ok(())
 }
+
+/// Community completion stats for a set of games (players with playtime only)
+pub async fn get_completion_stats(pool: &Pool, appids: &[u64]) -> Result<Vec<overachiever_core::GameCompletionStats>, DbError> {
+    if appids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let client = pool.get().await?;
+    let appids_i64: Vec<i64> = appids.iter().map(|&id| id as i64).collect();
+
+    let rows = client.query(
+        r#"
+        SELECT appid,
+               COUNT(*) AS players,
+               AVG(achievements_unlocked::REAL * 100.0 / achievements_total)::REAL AS avg_completion,
+               COUNT(*) FILTER (WHERE achievements_unlocked >= achievements_total) AS completed
+        FROM user_games
+        WHERE appid = ANY($1) AND achievements_total > 0 AND playtime_forever > 0
+        GROUP BY appid
+        ORDER BY appid
+        "#,
+        &[&appids_i64]
+    ).await?;
+
+    Ok(rows.into_iter().map(|r| overachiever_core::GameCompletionStats {
+        appid: r.get::<_, i64>("appid") as u64,
+        players: r.get("players"),
+        avg_completion: r.get("avg_completion"),
+        completed: r.get("completed"),
+    }).collect())
+}
+
+/// Community stats are only shown once this many users played a game, so they can't
+/// be traced back to a single library
+const MIN_COMMUNITY_PLAYERS: i64 = 3;
+
+/// Anonymous completion and playtime statistics for a game across all synced libraries
+pub async fn get_community_game_stats(pool: &Pool, appid: u64) -> Result<Option<overachiever_core::CommunityGameStats>, DbError> {
+    let client = pool.get().await?;
+
+    let row = client.query_one(
+        r#"
+        SELECT COUNT(*) AS players,
+               (AVG(achievements_unlocked::REAL * 100.0 / achievements_total)
+                   FILTER (WHERE achievements_total > 0))::REAL AS avg_completion,
+               (COUNT(*) FILTER (WHERE achievements_total > 0 AND achievements_unlocked >= achievements_total)::REAL * 100.0
+                   / NULLIF(COUNT(*) FILTER (WHERE achievements_total > 0), 0))::REAL AS perfect_percent,
+               COALESCE(PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY playtime_forever), 0)::INTEGER AS median_playtime
+        FROM user_games
+        WHERE appid = $1 AND playtime_forever > 0
+        "#,
+        &[&(appid as i64)]
+    ).await?;
+
+    let players: i64 = row.get("players");
+    if players < MIN_COMMUNITY_PLAYERS {
+        return Ok(None);
+    }
+    Ok(Some(overachiever_core::CommunityGameStats {
+        appid,
+        players,
+        avg_completion: row.get("avg_completion"),
+        perfect_percent: row.get("perfect_percent"),
+        median_playtime_minutes: row.get("median_playtime"),
+    }))
+}
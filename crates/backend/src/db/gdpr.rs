@@ -0,0 +1,68 @@
+//! GDPR data export and account deletion
+
+use deadpool_postgres::Pool;
+use crate::db::{DbError, PERSONAL_TABLES, REVIEWED_TABLES, USER_TABLES};
+
+/// Export all of a user's rows as JSON arrays, one entry per table (table name, JSON)
+pub async fn export_user_data(pool: &Pool, steam_id: &str) -> Result<Vec<(String, String)>, DbError> {
+    let client = pool.get().await?;
+    let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
+
+    let mut tables = Vec::with_capacity(USER_TABLES.len());
+    for (table, column) in USER_TABLES {
+        // Table and column names are constants above, never user input
+        let query = format!(
+            "SELECT COALESCE(json_agg(t), '[]'::json)::text FROM (SELECT * FROM {} WHERE {} = $1) t",
+            table, column
+        );
+        let json: String = client.query_one(&query, &[&steam_id_int]).await?.get(0);
+        tables.push((table.to_string(), json));
+    }
+
+    Ok(tables)
+}
+
+/// Delete a user's account and everything they contributed. Returns the number of rows removed.
+///
+/// Personal rows are deleted. Community TTB times and tags the user submitted are
+/// reverted to the last approved values (or deleted if never approved) while still
+/// awaiting review, and detached from the user once approved
+/// (other users' data already depends on them). TTB blacklist entries and tag
+/// mappings are admin actions and are kept.
+pub async fn delete_user_account(pool: &Pool, steam_id: &str) -> Result<u64, DbError> {
+    let mut client = pool.get().await?;
+    let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
+
+    let transaction = client.transaction().await?;
+    let mut deleted = 0;
+
+    // Explicit deletes rather than relying on ON DELETE CASCADE, so the order is
+    // clear and the TTB report triggers recompute community averages
+    deleted += transaction.execute("DELETE FROM comment_reports WHERE reported_by = $1", &[&steam_id_int]).await?;
+    for table in PERSONAL_TABLES {
+        deleted += transaction.execute(&format!("DELETE FROM {} WHERE steam_id = $1", table), &[&steam_id_int]).await?;
+    }
+
+    for (table, restore) in REVIEWED_TABLES {
+        transaction.execute(
+            &format!(
+                "UPDATE {} SET {}, reviewed_at = approved_at WHERE submitted_by = $1 AND reviewed_at IS NULL AND approved_at IS NOT NULL",
+                table, restore
+            ),
+            &[&steam_id_int]
+        ).await?;
+        deleted += transaction.execute(
+            &format!("DELETE FROM {} WHERE submitted_by = $1 AND reviewed_at IS NULL", table),
+            &[&steam_id_int]
+        ).await?;
+        transaction.execute(
+            &format!("UPDATE {} SET submitted_by = NULL WHERE submitted_by = $1", table),
+            &[&steam_id_int]
+        ).await?;
+    }
+
+    deleted += transaction.execute("DELETE FROM users WHERE steam_id = $1", &[&steam_id_int]).await?;
+
+    transaction.commit().await?;
+    Ok(deleted)
+}
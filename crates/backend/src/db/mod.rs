@@ -1,26 +1,56 @@
-//! Database operations for the backend using tokio-postgres
+//! Database operations for the backend using tokio-postgres, or a single SQLite file
+//! with the `sqlite` feature (see `sqlite/mod.rs`). Both backends expose the same functions.
 
 mod error;
-mod users;
-mod games;
-mod achievements;
-mod history;
-mod ratings;
-mod cloud_sync;
-mod size_cache;
-mod ttb;
-mod tags;
-mod logging;
+mod shared;
+
+// PostgreSQL (default)
+#[cfg(not(feature = "sqlite"))] mod connection;
+#[cfg(not(feature = "sqlite"))] mod users;
+#[cfg(not(feature = "sqlite"))] mod games;
+#[cfg(not(feature = "sqlite"))] mod achievements;
+#[cfg(not(feature = "sqlite"))] mod history;
+#[cfg(not(feature = "sqlite"))] mod ratings;
+#[cfg(not(feature = "sqlite"))] mod cloud_sync;
+#[cfg(not(feature = "sqlite"))] mod size_cache;
+#[cfg(not(feature = "sqlite"))] mod ttb;
+#[cfg(not(feature = "sqlite"))] mod tags;
+#[cfg(not(feature = "sqlite"))] mod logging;
+#[cfg(not(feature = "sqlite"))] mod admin;
+#[cfg(not(feature = "sqlite"))] mod profile;
+#[cfg(not(feature = "sqlite"))] mod gdpr;
+#[cfg(not(feature = "sqlite"))] mod comments;
+#[cfg(not(feature = "sqlite"))] mod api_keys;
+#[cfg(not(feature = "sqlite"))] mod email_digest;
+#[cfg(not(feature = "sqlite"))] mod rarity;
+
+// SQLite (self-hosting without a database server)
+#[cfg(feature = "sqlite")]
+mod sqlite;
 
 // Re-export everything
 pub use error::*;
-pub use users::*;
-pub use games::*;
-pub use achievements::*;
-pub use history::*;
-pub use ratings::*;
-pub use cloud_sync::*;
-pub use size_cache::*;
-pub use ttb::*;
-pub use tags::*;
-pub use logging::*;
+pub use shared::*;
+#[cfg(not(feature = "sqlite"))]
+pub use {
+    connection::*,
+    users::*,
+    games::*,
+    achievements::*,
+    history::*,
+    ratings::*,
+    cloud_sync::*,
+    size_cache::*,
+    ttb::*,
+    tags::*,
+    logging::*,
+    admin::*,
+    profile::*,
+    gdpr::*,
+    comments::*,
+    api_keys::*,
+    email_digest::*,
+    rarity::*,
+};
+#[cfg(feature = "sqlite")]
+pub use sqlite::*;
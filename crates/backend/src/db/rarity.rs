@@ -0,0 +1,67 @@
+//! Global achievement rarity snapshots taken by the rarity job
+
+use chrono::NaiveDate;
+use deadpool_postgres::Pool;
+use overachiever_core::AchievementRarityHistory;
+use crate::db::{group_rarity_rows, DbError};
+
+/// Games with achievements in anyone's library that weren't fetched since `fetched_before`,
+/// never fetched ones first
+pub async fn get_apps_due_for_rarity(pool: &Pool, fetched_before: NaiveDate, limit: i64) -> Result<Vec<u64>, DbError> {
+    let client = pool.get().await?;
+
+    let rows = client.query(
+        r#"
+        SELECT g.appid
+        FROM (SELECT DISTINCT appid FROM user_games WHERE achievements_total > 0) g
+        LEFT JOIN rarity_fetches f ON f.appid = g.appid
+        WHERE f.fetched_on IS NULL OR f.fetched_on < $1
+        ORDER BY f.fetched_on NULLS FIRST, g.appid
+        LIMIT $2
+        "#,
+        &[&fetched_before, &limit]
+    ).await?;
+
+    Ok(rows.iter().map(|row| row.get::<_, i64>("appid") as u64).collect())
+}
+
+/// Store the percentages fetched for a game today (replacing today's earlier snapshot)
+/// and record the fetch, also when Steam returned nothing
+pub async fn save_rarity_snapshot(pool: &Pool, appid: u64, today: NaiveDate, percentages: &[(String, f32)]) -> Result<(), DbError> {
+    let mut client = pool.get().await?;
+    let tx = client.transaction().await?;
+    let appid = appid as i64;
+
+    let stmt = tx.prepare(
+        r#"
+        INSERT INTO achievement_rarity (appid, apiname, recorded_on, percent)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (appid, apiname, recorded_on) DO UPDATE SET percent = EXCLUDED.percent
+        "#
+    ).await?;
+    for (apiname, percent) in percentages {
+        tx.execute(&stmt, &[&appid, apiname, &today, percent]).await?;
+    }
+    tx.execute(
+        r#"
+        INSERT INTO rarity_fetches (appid, fetched_on) VALUES ($1, $2)
+        ON CONFLICT (appid) DO UPDATE SET fetched_on = EXCLUDED.fetched_on
+        "#,
+        &[&appid, &today]
+    ).await?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Rarity history of every achievement of a game, oldest snapshot first
+pub async fn get_rarity_history(pool: &Pool, appid: u64) -> Result<Vec<AchievementRarityHistory>, DbError> {
+    let client = pool.get().await?;
+
+    let rows = client.query(
+        "SELECT apiname, recorded_on, percent FROM achievement_rarity WHERE appid = $1 ORDER BY apiname, recorded_on",
+        &[&(appid as i64)]
+    ).await?;
+
+    Ok(group_rarity_rows(rows.iter().map(|row| (row.get("apiname"), row.get("recorded_on"), row.get("percent")))))
+}
@@ -1,9 +1,9 @@
 //! Game rating and achievement tip database operations
 
 use deadpool_postgres::Pool;
-use overachiever_core::{GameRating, AchievementTip};
-use chrono::Utc;
-use crate::db::DbError;
+use overachiever_core::{GameRating, AchievementTip, AchievementDifficulty, GameAchievementRatings};
+use chrono::{DateTime, Utc};
+use crate::db::{DbError, RATING_VOTE_COLUMNS};
 
 /// Get community ratings for a game
 pub async fn get_community_ratings(
@@ -151,3 +151,113 @@ pub async fn get_user_achievement_ratings(
     
     Ok(ratings)
 }
+
+/// Bulk upsert achievement ratings in one transaction.
+/// When a rating already exists the one with the newer updated_at wins.
+/// Returns the number of ratings inserted or updated.
+pub async fn upsert_achievement_ratings_batch(
+    pool: &Pool,
+    steam_id: &str,
+    ratings: &[(u64, String, u8, DateTime<Utc>)],
+) -> Result<u64, DbError> {
+    let mut client = pool.get().await?;
+    let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
+    
+    let tx = client.transaction().await?;
+    let stmt = tx.prepare(
+        r#"
+        INSERT INTO achievement_ratings (steam_id, appid, apiname, rating, created_at, updated_at)
+        VALUES ($1, $2, $3, $4, $5, $5)
+        ON CONFLICT (steam_id, appid, apiname)
+        DO UPDATE SET rating = EXCLUDED.rating, updated_at = EXCLUDED.updated_at
+        WHERE achievement_ratings.updated_at IS NULL OR achievement_ratings.updated_at < EXCLUDED.updated_at
+        "#
+    ).await?;
+    
+    let mut applied = 0;
+    for (appid, apiname, rating, updated_at) in ratings {
+        applied += tx.execute(&stmt, &[&steam_id_int, &(*appid as i64), apiname, &(*rating as i16), updated_at]).await?;
+    }
+    
+    tx.commit().await?;
+    
+    Ok(applied)
+}
+
+/// Get the community average difficulty of every rated achievement in a game
+pub async fn get_achievement_difficulty(
+    pool: &Pool,
+    appid: u64,
+) -> Result<Vec<AchievementDifficulty>, DbError> {
+    let client = pool.get().await?;
+    
+    let rows = client.query(
+        &format!(
+            r#"
+            SELECT apiname, AVG(rating)::REAL AS avg_rating, COUNT(*)::INT AS rating_count, {}
+            FROM achievement_ratings
+            WHERE appid = $1
+            GROUP BY apiname
+            ORDER BY avg_rating ASC
+            "#,
+            RATING_VOTE_COLUMNS
+        ),
+        &[&(appid as i64)]
+    ).await?;
+    
+    let curve = rows.iter().map(difficulty_from_row).collect();
+    
+    Ok(curve)
+}
+
+/// Build an AchievementDifficulty from a row selected with RATING_VOTE_COLUMNS
+fn difficulty_from_row(row: &tokio_postgres::Row) -> AchievementDifficulty {
+    let votes = |column: &str| row.get::<_, i64>(column) as i32;
+    AchievementDifficulty {
+        apiname: row.get("apiname"),
+        avg_rating: row.get("avg_rating"),
+        rating_count: row.get("rating_count"),
+        votes: [votes("votes_1"), votes("votes_2"), votes("votes_3"), votes("votes_4"), votes("votes_5")],
+    }
+}
+
+/// Get the community average difficulty of the rated achievements of several games.
+/// Every requested game is returned, games without ratings with an empty list.
+pub async fn get_achievement_difficulty_batch(
+    pool: &Pool,
+    appids: &[u64],
+) -> Result<Vec<GameAchievementRatings>, DbError> {
+    if appids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let client = pool.get().await?;
+    let appids_i64: Vec<i64> = appids.iter().map(|&id| id as i64).collect();
+
+    let rows = client.query(
+        &format!(
+            r#"
+            SELECT appid, apiname, AVG(rating)::REAL AS avg_rating, COUNT(*)::INT AS rating_count, {}
+            FROM achievement_ratings
+            WHERE appid = ANY($1)
+            GROUP BY appid, apiname
+            ORDER BY appid, avg_rating ASC
+            "#,
+            RATING_VOTE_COLUMNS
+        ),
+        &[&appids_i64]
+    ).await?;
+
+    let mut games: Vec<GameAchievementRatings> = appids
+        .iter()
+        .map(|&appid| GameAchievementRatings { appid, achievements: Vec::new() })
+        .collect();
+    for row in rows {
+        let appid = row.get::<_, i64>("appid") as u64;
+        if let Some(game) = games.iter_mut().find(|g| g.appid == appid) {
+            game.achievements.push(difficulty_from_row(&row));
+        }
+    }
+
+    Ok(games)
+}
@@ -0,0 +1,336 @@
+//! Parts of the database layer that don't depend on the storage backend: ID and key
+//! generation, TTB report checks, and the types both backends return
+
+use chrono::{DateTime, NaiveDate, Utc};
+use overachiever_core::{AchievementRarityHistory, ApiKeyScope, RarityPoint, TagMappingKind};
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+/// Characters used for generating short IDs (URL-safe, case-sensitive)
+/// Similar to YouTube's video ID format
+const SHORT_ID_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+const SHORT_ID_LENGTH: usize = 8;
+
+/// Generate a random short ID (YouTube-style)
+pub fn generate_short_id() -> String {
+    let mut rng = rand::thread_rng();
+    (0..SHORT_ID_LENGTH)
+        .map(|_| {
+            let idx = rng.gen_range(0..SHORT_ID_CHARS.len());
+            SHORT_ID_CHARS[idx] as char
+        })
+        .collect()
+}
+
+/// Columns selected (with `users u` and `achievement_schemas ps` joined) to build a ProfileCustomization
+pub(crate) const CUSTOMIZATION_COLUMNS: &str =
+    "u.showcase_appid, u.showcase_apiname, u.banner_appid, u.accent_color, u.listed, ps.display_name AS showcase_name, ps.icon AS showcase_icon";
+
+/// Join resolving the showcase achievement's name and icon
+pub(crate) const CUSTOMIZATION_JOIN: &str =
+    "LEFT JOIN achievement_schemas ps ON ps.appid = u.showcase_appid AND ps.apiname = u.showcase_apiname";
+
+/// Order of the public user directory
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UserSort {
+    /// Display name A-Z
+    #[default]
+    Name,
+    /// Most recently seen first
+    LastSeen,
+}
+
+/// Page size of the user directory when none is given, and the largest allowed
+pub const USER_PAGE_DEFAULT: i64 = 50;
+pub const USER_PAGE_MAX: i64 = 100;
+
+/// `LIKE` pattern matching names that contain `search`; its own `%` and `_` are escaped with `\`
+pub(crate) fn contains_pattern(search: &str) -> String {
+    let escaped = search.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+    format!("%{}%", escaped)
+}
+
+/// Turn `limit + 1` fetched users into a page: the extra row only means there is a next one
+pub(crate) fn page_of(mut users: Vec<overachiever_core::UserProfile>, limit: i64) -> overachiever_core::UserPage {
+    let has_more = users.len() as i64 > limit;
+    users.truncate(limit.max(0) as usize);
+    let next_cursor = if has_more { users.last().map(|u| u.steam_id.clone()) } else { None };
+    overachiever_core::UserPage { users, next_cursor }
+}
+
+/// Vote counts per rating (`votes_1` .. `votes_5`) selected next to the average difficulty
+pub(crate) const RATING_VOTE_COLUMNS: &str = "COUNT(*) FILTER (WHERE rating = 1) AS votes_1, \
+     COUNT(*) FILTER (WHERE rating = 2) AS votes_2, \
+     COUNT(*) FILTER (WHERE rating = 3) AS votes_3, \
+     COUNT(*) FILTER (WHERE rating = 4) AS votes_4, \
+     COUNT(*) FILTER (WHERE rating = 5) AS votes_5";
+
+/// App size data for caching install sizes
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AppSizeInfo {
+    pub appid: u64,
+    pub size_bytes: u64,
+}
+
+pub(crate) fn mapping_kind_to_sql(kind: TagMappingKind) -> &'static str {
+    match kind {
+        TagMappingKind::Alias => "alias",
+        TagMappingKind::Parent => "parent",
+    }
+}
+
+pub(crate) fn mapping_kind_from_sql(kind: &str) -> Option<TagMappingKind> {
+    match kind {
+        "alias" => Some(TagMappingKind::Alias),
+        "parent" => Some(TagMappingKind::Parent),
+        _ => None,
+    }
+}
+
+/// Every table holding rows about a user, with the column that identifies them.
+/// Community tables (TTB times, tags, TTB blacklist, tag mappings) only reference the submitter.
+pub(crate) const USER_TABLES: &[(&str, &str)] = &[
+    ("users", "steam_id"),
+    ("user_games", "steam_id"),
+    ("user_achievements", "steam_id"),
+    ("run_history", "steam_id"),
+    ("achievement_history", "steam_id"),
+    ("user_goals", "steam_id"),
+    ("api_keys", "steam_id"),
+    ("email_digests", "steam_id"),
+    ("sync_history", "steam_id"),
+    ("game_ratings", "steam_id"),
+    ("achievement_ratings", "steam_id"),
+    ("achievement_tips", "steam_id"),
+    ("achievement_comments", "steam_id"),
+    ("comment_reports", "reported_by"),
+    ("user_ttb_reports", "steam_id"),
+    ("ttb_times", "submitted_by"),
+    ("game_tags", "submitted_by"),
+    ("ttb_blacklist", "added_by_steam_id"),
+    ("tag_mappings", "created_by"),
+];
+
+/// Personal tables cleared when an account is deleted (after comment_reports, before users)
+pub(crate) const PERSONAL_TABLES: &[&str] = &[
+    "achievement_comments",
+    "achievement_tips",
+    "achievement_ratings",
+    "game_ratings",
+    "user_ttb_reports",
+    "user_achievements",
+    "user_games",
+    "achievement_history",
+    "user_goals",
+    "api_keys",
+    "email_digests",
+    "run_history",
+    "sync_history",
+];
+
+/// Community tables with submissions awaiting review, and how a pending row gets its
+/// last approved values back (rows that were never approved are deleted instead)
+pub(crate) const REVIEWED_TABLES: &[(&str, &str)] = &[
+    ("ttb_times", "main = approved_main, main_extra = approved_main_extra, completionist = approved_completionist"),
+    ("game_tags", "vote_count = approved_vote_count"),
+];
+
+// ============================================================================
+// Time to beat
+// ============================================================================
+
+/// Longest accepted time to beat in hours - anything above is a unit mix-up
+const MAX_TTB_HOURS: f32 = 2000.0;
+
+/// Shortest accepted user report (seconds)
+const MIN_TTB_REPORT_SECONDS: i32 = 60;
+
+/// Approved reports per category needed before outliers are judged by standard deviation
+const MIN_REPORTS_FOR_OUTLIERS: i64 = 5;
+
+/// Reports further from the mean than this many standard deviations wait for review
+const OUTLIER_SIGMA: f64 = 3.0;
+
+/// Without enough reports, a value this many times off the HLTB time waits for review
+const HLTB_OUTLIER_FACTOR: f64 = 10.0;
+
+/// Check scraped HLTB times (hours) before storing them
+pub fn validate_ttb_hours(main: Option<f32>, main_extra: Option<f32>, completionist: Option<f32>) -> Result<(), String> {
+    if main.is_none() && main_extra.is_none() && completionist.is_none() {
+        return Err("No times given".to_string());
+    }
+    for (label, hours) in [("main", main), ("main + extra", main_extra), ("completionist", completionist)] {
+        if let Some(hours) = hours {
+            if !hours.is_finite() || hours <= 0.0 || hours > MAX_TTB_HOURS {
+                return Err(format!("{} time must be between 0 and {} hours", label, MAX_TTB_HOURS));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Check a user TTB report (seconds): in range and main <= main + extra <= completionist
+pub fn validate_ttb_report(main_seconds: Option<i32>, extra_seconds: Option<i32>, completionist_seconds: Option<i32>) -> Result<(), String> {
+    let max_seconds = (MAX_TTB_HOURS * 3600.0) as i32;
+    let times = [("main", main_seconds), ("main + extra", extra_seconds), ("completionist", completionist_seconds)];
+    if times.iter().all(|(_, seconds)| seconds.is_none()) {
+        return Err("No times given".to_string());
+    }
+    for (label, seconds) in times {
+        if let Some(seconds) = seconds {
+            if !(MIN_TTB_REPORT_SECONDS..=max_seconds).contains(&seconds) {
+                return Err(format!("{} time must be between 1 minute and {} hours", label, MAX_TTB_HOURS));
+            }
+        }
+    }
+    let given: Vec<(&str, i32)> = times.iter().filter_map(|(label, seconds)| seconds.map(|s| (*label, s))).collect();
+    for pair in given.windows(2) {
+        if pair[0].1 > pair[1].1 {
+            return Err(format!("{} time can't be longer than {} time", pair[0].0, pair[1].0));
+        }
+    }
+    Ok(())
+}
+
+/// Outcome of a user TTB report
+#[derive(Debug, Clone, PartialEq)]
+pub enum TtbReportStatus {
+    /// Counted towards the averages
+    Approved,
+    /// Held back for admin review, with the reason
+    Pending(String),
+}
+
+impl TtbReportStatus {
+    /// Values of the `review_status` and `review_reason` columns
+    pub(crate) fn review_columns(&self) -> (&'static str, Option<&str>) {
+        match self {
+            TtbReportStatus::Approved => ("approved", None),
+            TtbReportStatus::Pending(reason) => ("pending", Some(reason.as_str())),
+        }
+    }
+}
+
+/// Why `seconds` looks like an outlier against the approved reports (or the HLTB time
+/// when there are too few of them), None if it looks fine
+pub(crate) fn ttb_outlier_reason(label: &str, seconds: i32, count: i64, mean: Option<f64>, stddev: Option<f64>, hltb_hours: Option<f32>) -> Option<String> {
+    let seconds = seconds as f64;
+    if count >= MIN_REPORTS_FOR_OUTLIERS {
+        let mean = mean?;
+        // A floor so a handful of identical reports doesn't flag every other value
+        let stddev = stddev.unwrap_or(0.0).max(mean * 0.1);
+        let sigma = (seconds - mean).abs() / stddev;
+        return (sigma > OUTLIER_SIGMA).then(|| {
+            format!("{} time {:.1}h is {:.1}σ from the mean of {:.1}h ({} reports)", label, seconds / 3600.0, sigma, mean / 3600.0, count)
+        });
+    }
+    let hltb = hltb_hours? as f64 * 3600.0;
+    let off = (seconds / hltb).max(hltb / seconds);
+    (off > HLTB_OUTLIER_FACTOR).then(|| {
+        format!("{} time {:.1}h is {:.0}x off the HowLongToBeat time of {:.1}h", label, seconds / 3600.0, off, hltb / 3600.0)
+    })
+}
+
+// ============================================================================
+// API keys
+// ============================================================================
+
+/// Prefix of every key, so leaked keys are recognisable
+const KEY_PREFIX: &str = "oa_";
+const KEY_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+const KEY_RANDOM_LENGTH: usize = 40;
+/// Characters of the key kept in clear text for display ("oa_" + 6)
+pub(crate) const DISPLAY_PREFIX_LENGTH: usize = 9;
+
+/// Active keys a user may have at once
+pub const MAX_API_KEYS_PER_USER: i64 = 10;
+
+/// Hex SHA-256 of a key (keys are random, so no salt is needed)
+pub fn hash_api_key(key: &str) -> String {
+    Sha256::digest(key.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+pub(crate) fn generate_api_key() -> String {
+    let mut rng = rand::thread_rng();
+    let random: String = (0..KEY_RANDOM_LENGTH)
+        .map(|_| KEY_CHARS[rng.gen_range(0..KEY_CHARS.len())] as char)
+        .collect();
+    format!("{}{}", KEY_PREFIX, random)
+}
+
+pub(crate) fn scopes_from_row(scopes: Vec<String>) -> Vec<ApiKeyScope> {
+    scopes.iter().filter_map(|s| ApiKeyScope::parse(s)).collect()
+}
+
+// ============================================================================
+// Email digests
+// ============================================================================
+
+const TOKEN_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+const TOKEN_LENGTH: usize = 32;
+
+/// Time between two digests of the same user
+pub const DIGEST_INTERVAL_DAYS: i64 = 7;
+
+/// Most unlocks listed in one digest (the total is still counted)
+pub(crate) const MAX_LISTED_UNLOCKS: i64 = 20;
+
+pub(crate) fn generate_unsubscribe_token() -> String {
+    let mut rng = rand::thread_rng();
+    (0..TOKEN_LENGTH)
+        .map(|_| TOKEN_CHARS[rng.gen_range(0..TOKEN_CHARS.len())] as char)
+        .collect()
+}
+
+/// A subscription whose digest should be sent now
+#[derive(Debug, Clone)]
+pub struct DueDigest {
+    pub steam_id: String,
+    pub display_name: String,
+    pub email: String,
+    pub unsubscribe_token: String,
+    /// Start of the period the digest covers
+    pub since: DateTime<Utc>,
+}
+
+/// An unlock listed in a digest
+#[derive(Debug, Clone)]
+pub struct DigestUnlock {
+    pub game_name: String,
+    pub achievement_name: String,
+    pub unlocktime: DateTime<Utc>,
+}
+
+/// Everything a digest reports, from the user's uploaded sync data
+#[derive(Debug, Clone, Default)]
+pub struct DigestData {
+    /// Most recent unlocks of the period (at most `MAX_LISTED_UNLOCKS`)
+    pub unlocks: Vec<DigestUnlock>,
+    pub unlock_count: i64,
+    /// Distinct UTC days with an unlock, newest first (for the streak)
+    pub unlock_days: Vec<NaiveDate>,
+    /// Average completion at the start and at the end of the period
+    pub completion_before: Option<f64>,
+    pub completion_after: Option<f64>,
+}
+
+// ============================================================================
+// Achievement rarity snapshots
+// ============================================================================
+
+/// Group snapshot rows (ordered by apiname, then date) into one history per achievement
+pub(crate) fn group_rarity_rows(rows: impl IntoIterator<Item = (String, NaiveDate, f32)>) -> Vec<AchievementRarityHistory> {
+    let mut histories: Vec<AchievementRarityHistory> = Vec::new();
+    for (apiname, date, percent) in rows {
+        let point = RarityPoint { date, percent };
+        match histories.last_mut() {
+            Some(history) if history.apiname == apiname => history.points.push(point),
+            _ => histories.push(AchievementRarityHistory { apiname, points: vec![point] }),
+        }
+    }
+    histories
+}
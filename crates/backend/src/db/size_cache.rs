@@ -1,14 +1,7 @@
 //! Size on disk cache database operations
 
 use deadpool_postgres::Pool;
-use crate::db::DbError;
-
-/// App size data for caching install sizes
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct AppSizeInfo {
-    pub appid: u64,
-    pub size_bytes: u64,
-}
+use crate::db::{AppSizeInfo, DbError};
 
 /// Upsert app size data (updates if exists, inserts if not)
 pub async fn upsert_app_sizes(pool: &Pool, sizes: &[AppSizeInfo]) -> Result<usize, DbError> {
@@ -0,0 +1,144 @@
+//! Achievement-related database operations
+
+use overachiever_core::GameAchievement;
+use rusqlite::{params, OptionalExtension};
+use super::{steam_id_int, Pool};
+use crate::db::DbError;
+
+const GAME_ACHIEVEMENTS_QUERY: &str = r#"
+    SELECT ua.appid, ua.apiname, s.display_name as name, s.description,
+           s.icon, s.icon_gray, ua.achieved, ua.unlocktime, ua.is_game_finishing, s.hidden
+    FROM user_achievements ua
+    LEFT JOIN achievement_schemas s ON ua.appid = s.appid AND ua.apiname = s.apiname
+    WHERE ua.steam_id = ?1 AND ua.appid = ?2
+    ORDER BY s.display_name
+"#;
+
+fn achievement_from_row(row: &rusqlite::Row) -> rusqlite::Result<GameAchievement> {
+    let apiname: String = row.get("apiname")?;
+    Ok(GameAchievement {
+        appid: row.get::<_, i64>("appid")? as u64,
+        name: row.get::<_, Option<String>>("name")?.unwrap_or_else(|| apiname.clone()),
+        apiname,
+        description: row.get("description")?,
+        icon: row.get::<_, Option<String>>("icon")?.unwrap_or_default(),
+        icon_gray: row.get::<_, Option<String>>("icon_gray")?.unwrap_or_default(),
+        achieved: row.get::<_, Option<bool>>("achieved")?.unwrap_or(false),
+        unlocktime: row.get("unlocktime")?,
+        is_game_finishing: row.get::<_, Option<bool>>("is_game_finishing")?.unwrap_or(false),
+        global_percent: None,
+        hidden: row.get::<_, Option<bool>>("hidden")?.unwrap_or(false),
+    })
+}
+
+/// Get achievements for a game by short_id (for guest viewing)
+pub async fn get_game_achievements_by_short_id(
+    pool: &Pool,
+    short_id: &str,
+    appid: u64,
+) -> Result<Option<Vec<GameAchievement>>, DbError> {
+    pool.with(|conn| {
+        let steam_id_int: Option<i64> = conn
+            .query_row("SELECT steam_id FROM users WHERE short_id = ?1", [short_id], |row| row.get(0))
+            .optional()?;
+        let Some(steam_id_int) = steam_id_int else {
+            return Ok(None);
+        };
+        let mut stmt = conn.prepare(GAME_ACHIEVEMENTS_QUERY)?;
+        let achievements = stmt
+            .query_map(params![steam_id_int, appid as i64], achievement_from_row)?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(Some(achievements))
+    })
+}
+
+/// Get achievements for a game by steam_id
+pub async fn get_game_achievements(
+    pool: &Pool,
+    steam_id: &str,
+    appid: u64,
+) -> Result<Vec<GameAchievement>, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    pool.with(|conn| {
+        let mut stmt = conn.prepare(GAME_ACHIEVEMENTS_QUERY)?;
+        let achievements = stmt.query_map(params![steam_id_int, appid as i64], achievement_from_row)?.collect();
+        achievements
+    })
+}
+
+/// Store achievement schema
+pub async fn upsert_achievement_schema(
+    pool: &Pool,
+    appid: u64,
+    schema: &overachiever_core::AchievementSchema,
+) -> Result<(), DbError> {
+    pool.with(|conn| {
+        conn.execute(
+            r#"
+            INSERT INTO achievement_schemas (appid, apiname, display_name, description, icon, icon_gray, hidden)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            ON CONFLICT (appid, apiname) DO UPDATE SET
+                display_name = excluded.display_name,
+                description = excluded.description,
+                icon = excluded.icon,
+                icon_gray = excluded.icon_gray,
+                hidden = excluded.hidden
+            "#,
+            params![appid as i64, schema.name, schema.display_name, schema.description, schema.icon, schema.icongray, schema.hidden != 0],
+        )?;
+        Ok(())
+    })
+}
+
+/// Store user achievement progress
+pub async fn upsert_user_achievement(
+    pool: &Pool,
+    steam_id: &str,
+    appid: u64,
+    achievement: &overachiever_core::Achievement,
+) -> Result<(), DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    let achieved = achievement.achieved == 1;
+    let unlocktime: Option<chrono::DateTime<chrono::Utc>> = if achievement.unlocktime > 0 {
+        chrono::DateTime::from_timestamp(achievement.unlocktime as i64, 0)
+    } else {
+        None
+    };
+
+    pool.with(|conn| {
+        conn.execute(
+            r#"
+            INSERT INTO user_achievements (steam_id, appid, apiname, achieved, unlocktime)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT (steam_id, appid, apiname) DO UPDATE SET
+                achieved = excluded.achieved,
+                unlocktime = COALESCE(excluded.unlocktime, user_achievements.unlocktime)
+            "#,
+            params![steam_id_int, appid as i64, achievement.apiname, achieved, unlocktime],
+        )?;
+        Ok(())
+    })
+}
+
+/// Mark an achievement as game-finishing for a user
+/// Automatically unmarks any previously marked achievement for the same game
+pub async fn mark_game_finishing(
+    pool: &Pool,
+    steam_id: &str,
+    appid: u64,
+    apiname: &str,
+) -> Result<(), DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    pool.with(|conn| {
+        let tx = conn.transaction()?;
+        tx.execute(
+            "UPDATE user_achievements SET is_game_finishing = 0 WHERE steam_id = ?1 AND appid = ?2",
+            params![steam_id_int, appid as i64],
+        )?;
+        tx.execute(
+            "UPDATE user_achievements SET is_game_finishing = 1 WHERE steam_id = ?1 AND appid = ?2 AND apiname = ?3",
+            params![steam_id_int, appid as i64, apiname],
+        )?;
+        tx.commit()
+    })
+}
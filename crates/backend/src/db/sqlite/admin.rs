@@ -0,0 +1,275 @@
+//! Admin dashboard database operations
+
+use chrono::{Duration, Utc};
+use overachiever_core::{AdminStats, AdminUser, PendingTagSubmission, PendingTtbReport, PendingTtbSubmission};
+use rusqlite::params;
+use super::Pool;
+use crate::db::DbError;
+
+/// Get server-wide statistics
+pub async fn get_admin_stats(pool: &Pool) -> Result<AdminStats, DbError> {
+    let now = Utc::now();
+    pool.with(|conn| {
+        conn.query_row(
+            r#"
+            SELECT
+                (SELECT COUNT(*) FROM users) AS total_users,
+                (SELECT COUNT(*) FROM users WHERE last_seen > ?1) AS active_users_7d,
+                (SELECT COUNT(*) FROM users WHERE last_seen > ?2) AS active_users_30d,
+                (SELECT COUNT(*) FROM user_games) AS total_games,
+                (SELECT COUNT(*) FROM user_achievements) AS total_achievements,
+                (SELECT COALESCE(SUM(last_upload_bytes), 0) FROM users) AS total_upload_bytes,
+                (SELECT COALESCE(MAX(last_upload_bytes), 0) FROM users) AS largest_upload_bytes,
+                (SELECT page_count * page_size FROM pragma_page_count(), pragma_page_size()) AS database_bytes,
+                (SELECT COUNT(*) FROM ttb_times WHERE reviewed_at IS NULL) AS pending_ttb,
+                (SELECT COUNT(DISTINCT appid) FROM game_tags WHERE reviewed_at IS NULL) AS pending_tags,
+                (SELECT COUNT(*) FROM user_ttb_reports WHERE review_status = 'pending') AS pending_ttb_reports
+            "#,
+            params![now - Duration::days(7), now - Duration::days(30)],
+            |row| {
+                Ok(AdminStats {
+                    total_users: row.get("total_users")?,
+                    active_users_7d: row.get("active_users_7d")?,
+                    active_users_30d: row.get("active_users_30d")?,
+                    total_games: row.get("total_games")?,
+                    total_achievements: row.get("total_achievements")?,
+                    total_upload_bytes: row.get("total_upload_bytes")?,
+                    largest_upload_bytes: row.get("largest_upload_bytes")?,
+                    database_bytes: row.get("database_bytes")?,
+                    pending_ttb: row.get("pending_ttb")?,
+                    pending_tags: row.get("pending_tags")?,
+                    pending_ttb_reports: row.get("pending_ttb_reports")?,
+                })
+            },
+        )
+    })
+}
+
+/// Get all users with their data sizes (most recently seen first)
+pub async fn get_admin_users(pool: &Pool) -> Result<Vec<AdminUser>, DbError> {
+    pool.with(|conn| {
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT u.steam_id, u.display_name, u.short_id, u.created_at, u.last_seen,
+                   u.last_upload_bytes, u.last_upload_at,
+                   (SELECT COUNT(*) FROM user_games g WHERE g.steam_id = u.steam_id) AS game_count,
+                   (SELECT COUNT(*) FROM user_achievements a WHERE a.steam_id = u.steam_id) AS achievement_count
+            FROM users u
+            ORDER BY u.last_seen DESC NULLS LAST
+            "#,
+        )?;
+        let users = stmt
+            .query_map([], |row| {
+                Ok(AdminUser {
+                    steam_id: row.get::<_, i64>("steam_id")?.to_string(),
+                    display_name: row.get("display_name")?,
+                    short_id: row.get("short_id")?,
+                    created_at: row.get("created_at")?,
+                    last_seen: row.get("last_seen")?,
+                    game_count: row.get("game_count")?,
+                    achievement_count: row.get("achievement_count")?,
+                    last_upload_bytes: row.get("last_upload_bytes")?,
+                    last_upload_at: row.get("last_upload_at")?,
+                })
+            })?
+            .collect();
+        users
+    })
+}
+
+/// Record the size of a user's cloud upload
+pub async fn record_upload_size(pool: &Pool, steam_id: &str, bytes: i64) -> Result<(), DbError> {
+    let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
+    let now = Utc::now();
+    pool.with(|conn| {
+        conn.execute(
+            "UPDATE users SET last_upload_bytes = ?2, last_upload_at = ?3 WHERE steam_id = ?1",
+            params![steam_id_int, bytes, now],
+        )?;
+        Ok(())
+    })
+}
+
+/// Get TTB submissions not yet reviewed by an admin (newest first)
+pub async fn get_pending_ttb(pool: &Pool, limit: i64) -> Result<Vec<PendingTtbSubmission>, DbError> {
+    pool.with(|conn| {
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT appid, game_name, main, main_extra, completionist, reported_count, submitted_by, last_reported_at
+            FROM ttb_times
+            WHERE reviewed_at IS NULL
+            ORDER BY last_reported_at DESC
+            LIMIT ?1
+            "#,
+        )?;
+        let pending = stmt
+            .query_map([limit], |row| {
+                Ok(PendingTtbSubmission {
+                    appid: row.get::<_, i64>("appid")? as u64,
+                    game_name: row.get("game_name")?,
+                    main: row.get::<_, Option<f64>>("main")?.map(|h| h as f32),
+                    main_extra: row.get::<_, Option<f64>>("main_extra")?.map(|h| h as f32),
+                    completionist: row.get::<_, Option<f64>>("completionist")?.map(|h| h as f32),
+                    reported_count: row.get("reported_count")?,
+                    submitted_by: row.get::<_, Option<i64>>("submitted_by")?.map(|id| id.to_string()),
+                    submitted_at: row.get("last_reported_at")?,
+                })
+            })?
+            .collect();
+        pending
+    })
+}
+
+/// Mark a game's TTB times as reviewed
+pub async fn approve_ttb(pool: &Pool, appid: u64) -> Result<bool, DbError> {
+    let now = Utc::now();
+    pool.with(|conn| {
+        let rows_affected = conn.execute(
+            r#"
+            UPDATE ttb_times
+            SET reviewed_at = ?2, approved_main = main, approved_main_extra = main_extra,
+                approved_completionist = completionist, approved_at = ?2
+            WHERE appid = ?1 AND reviewed_at IS NULL
+            "#,
+            params![appid as i64, now],
+        )?;
+        Ok(rows_affected > 0)
+    })
+}
+
+/// Discard a game's pending TTB times: back to the last approved times, or removed
+/// if none were ever approved
+pub async fn reject_ttb(pool: &Pool, appid: u64) -> Result<bool, DbError> {
+    pool.with(|conn| {
+        let tx = conn.transaction()?;
+        let restored = tx.execute(
+            r#"
+            UPDATE ttb_times
+            SET main = approved_main, main_extra = approved_main_extra,
+                completionist = approved_completionist, reviewed_at = approved_at
+            WHERE appid = ?1 AND reviewed_at IS NULL AND approved_at IS NOT NULL
+            "#,
+            [appid as i64],
+        )?;
+        let deleted = tx.execute("DELETE FROM ttb_times WHERE appid = ?1 AND reviewed_at IS NULL", [appid as i64])?;
+        tx.commit()?;
+        Ok(restored + deleted > 0)
+    })
+}
+
+/// Get user TTB reports held back for review (oldest first)
+pub async fn get_pending_ttb_reports(pool: &Pool, limit: i64) -> Result<Vec<PendingTtbReport>, DbError> {
+    pool.with(|conn| {
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT r.id, r.steam_id, r.appid, t.game_name, r.main_seconds, r.extra_seconds, r.completionist_seconds,
+                   r.review_reason, r.reported_at
+            FROM user_ttb_reports r
+            LEFT JOIN ttb_times t ON t.appid = r.appid
+            WHERE r.review_status = 'pending'
+            ORDER BY r.reported_at
+            LIMIT ?1
+            "#,
+        )?;
+        let reports = stmt
+            .query_map([limit], |row| {
+                Ok(PendingTtbReport {
+                    id: row.get("id")?,
+                    steam_id: row.get::<_, Option<i64>>("steam_id")?.map(|id| id.to_string()).unwrap_or_default(),
+                    appid: row.get::<_, i64>("appid")? as u64,
+                    game_name: row.get("game_name")?,
+                    main_seconds: row.get("main_seconds")?,
+                    extra_seconds: row.get("extra_seconds")?,
+                    completionist_seconds: row.get("completionist_seconds")?,
+                    reason: row.get("review_reason")?,
+                    reported_at: row.get("reported_at")?,
+                })
+            })?
+            .collect();
+        reports
+    })
+}
+
+/// Set the review status of a pending user TTB report ("approved" or "rejected").
+/// Averages are computed when games are read, so they follow automatically.
+pub async fn review_ttb_report(pool: &Pool, id: i64, approve: bool, admin_steam_id: &str) -> Result<bool, DbError> {
+    let admin_id: i64 = admin_steam_id.parse().unwrap_or(0);
+    let status = if approve { "approved" } else { "rejected" };
+    let now = Utc::now();
+
+    pool.with(|conn| {
+        let rows_affected = conn.execute(
+            r#"
+            UPDATE user_ttb_reports
+            SET review_status = ?2, reviewed_at = ?4, reviewed_by = ?3
+            WHERE id = ?1 AND review_status = 'pending'
+            "#,
+            params![id, status, admin_id, now],
+        )?;
+        Ok(rows_affected > 0)
+    })
+}
+
+/// Get tag submissions not yet reviewed by an admin, grouped per game (newest first)
+pub async fn get_pending_tags(pool: &Pool, limit: i64) -> Result<Vec<PendingTagSubmission>, DbError> {
+    pool.with(|conn| {
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT appid,
+                   json_group_array(json_array(tag_name, vote_count) ORDER BY vote_count DESC) AS tags,
+                   MAX(submitted_by) AS submitted_by,
+                   MAX(updated_at) AS submitted_at
+            FROM game_tags
+            WHERE reviewed_at IS NULL
+            GROUP BY appid
+            ORDER BY MAX(updated_at) DESC
+            LIMIT ?1
+            "#,
+        )?;
+        let pending = stmt
+            .query_map([limit], |row| {
+                let tags: Vec<(String, i64)> = serde_json::from_str(&row.get::<_, String>("tags")?).unwrap_or_default();
+                Ok(PendingTagSubmission {
+                    appid: row.get::<_, i64>("appid")? as u64,
+                    tags: tags.into_iter().map(|(name, votes)| (name, votes.max(0) as u32)).collect(),
+                    submitted_by: row.get::<_, Option<i64>>("submitted_by")?.map(|id| id.to_string()),
+                    submitted_at: row.get("submitted_at")?,
+                })
+            })?
+            .collect();
+        pending
+    })
+}
+
+/// Mark a game's pending tags as reviewed, returns the number of tags approved
+pub async fn approve_tags(pool: &Pool, appid: u64) -> Result<u64, DbError> {
+    let now = Utc::now();
+    pool.with(|conn| {
+        let rows_affected = conn.execute(
+            r#"
+            UPDATE game_tags SET reviewed_at = ?2, approved_vote_count = vote_count, approved_at = ?2
+            WHERE appid = ?1 AND reviewed_at IS NULL
+            "#,
+            params![appid as i64, now],
+        )?;
+        Ok(rows_affected as u64)
+    })
+}
+
+/// Discard a game's pending tags: tags approved before get their approved vote count
+/// back, new ones are removed. Returns the number of tags rejected.
+pub async fn reject_tags(pool: &Pool, appid: u64) -> Result<u64, DbError> {
+    pool.with(|conn| {
+        let tx = conn.transaction()?;
+        let restored = tx.execute(
+            r#"
+            UPDATE game_tags SET vote_count = approved_vote_count, reviewed_at = approved_at
+            WHERE appid = ?1 AND reviewed_at IS NULL AND approved_at IS NOT NULL
+            "#,
+            [appid as i64],
+        )?;
+        let deleted = tx.execute("DELETE FROM game_tags WHERE appid = ?1 AND reviewed_at IS NULL", [appid as i64])?;
+        tx.commit()?;
+        Ok((restored + deleted) as u64)
+    })
+}
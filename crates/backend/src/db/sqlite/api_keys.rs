@@ -0,0 +1,120 @@
+//! Third-party API keys - minting, listing, revoking and key lookup
+
+use chrono::Utc;
+use overachiever_core::{ApiKeyInfo, ApiKeyScope, CreatedApiKey};
+use rusqlite::{params, OptionalExtension};
+use super::{steam_id_int, Pool};
+use crate::db::{generate_api_key, hash_api_key, scopes_from_row, DbError, DISPLAY_PREFIX_LENGTH};
+
+/// Scopes are stored as a JSON array of names
+fn scopes_from_json(scopes: &str) -> Vec<ApiKeyScope> {
+    scopes_from_row(serde_json::from_str(scopes).unwrap_or_default())
+}
+
+/// Number of active (not revoked) keys of a user
+pub async fn count_api_keys(pool: &Pool, steam_id: &str) -> Result<i64, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    pool.with(|conn| {
+        conn.query_row(
+            "SELECT COUNT(*) FROM api_keys WHERE steam_id = ?1 AND revoked_at IS NULL",
+            [steam_id_int],
+            |row| row.get(0),
+        )
+    })
+}
+
+/// Mint a new key. The returned key is the only time the secret is available.
+pub async fn create_api_key(
+    pool: &Pool,
+    steam_id: &str,
+    name: &str,
+    scopes: &[ApiKeyScope],
+) -> Result<CreatedApiKey, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    let key = generate_api_key();
+    let prefix = key[..DISPLAY_PREFIX_LENGTH].to_string();
+    let scope_names: Vec<&str> = scopes.iter().map(|s| s.as_str()).collect();
+    let scopes_json = serde_json::to_string(&scope_names).unwrap_or_else(|_| "[]".to_string());
+    let created_at = Utc::now();
+
+    let id = pool.with(|conn| {
+        conn.execute(
+            r#"
+            INSERT INTO api_keys (steam_id, name, key_prefix, key_hash, scopes, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            "#,
+            params![steam_id_int, name, prefix, hash_api_key(&key), scopes_json, created_at],
+        )?;
+        Ok(conn.last_insert_rowid())
+    })?;
+
+    Ok(CreatedApiKey {
+        key,
+        info: ApiKeyInfo {
+            id,
+            name: name.to_string(),
+            prefix,
+            scopes: scopes.to_vec(),
+            created_at,
+            last_used_at: None,
+        },
+    })
+}
+
+/// A user's active keys, newest first
+pub async fn get_api_keys(pool: &Pool, steam_id: &str) -> Result<Vec<ApiKeyInfo>, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    pool.with(|conn| {
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT id, name, key_prefix, scopes, created_at, last_used_at
+            FROM api_keys
+            WHERE steam_id = ?1 AND revoked_at IS NULL
+            ORDER BY created_at DESC
+            "#,
+        )?;
+        let keys = stmt
+            .query_map([steam_id_int], |row| {
+                Ok(ApiKeyInfo {
+                    id: row.get("id")?,
+                    name: row.get("name")?,
+                    prefix: row.get("key_prefix")?,
+                    scopes: scopes_from_json(&row.get::<_, String>("scopes")?),
+                    created_at: row.get("created_at")?,
+                    last_used_at: row.get("last_used_at")?,
+                })
+            })?
+            .collect();
+        keys
+    })
+}
+
+/// Revoke one of a user's keys. Returns false if the user has no such active key.
+pub async fn revoke_api_key(pool: &Pool, steam_id: &str, key_id: i64) -> Result<bool, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    let now = Utc::now();
+    pool.with(|conn| {
+        let revoked = conn.execute(
+            "UPDATE api_keys SET revoked_at = ?3 WHERE id = ?1 AND steam_id = ?2 AND revoked_at IS NULL",
+            params![key_id, steam_id_int, now],
+        )?;
+        Ok(revoked > 0)
+    })
+}
+
+/// Look up an active key, returning its owner and scopes (and marking it used)
+pub async fn authenticate_api_key(pool: &Pool, key: &str) -> Result<Option<(String, Vec<ApiKeyScope>)>, DbError> {
+    let now = Utc::now();
+    pool.with(|conn| {
+        conn.query_row(
+            r#"
+            UPDATE api_keys SET last_used_at = ?2
+            WHERE key_hash = ?1 AND revoked_at IS NULL
+            RETURNING steam_id, scopes
+            "#,
+            params![hash_api_key(key), now],
+            |row| Ok((row.get::<_, i64>("steam_id")?.to_string(), scopes_from_json(&row.get::<_, String>("scopes")?))),
+        )
+        .optional()
+    })
+}
@@ -0,0 +1,378 @@
+//! Cloud sync database operations
+
+use overachiever_core::{game_sync_checksum, CloudSyncData, CloudSyncDelta, CloudSyncStatus, Goal, SyncAchievement, SyncManifest};
+use std::collections::{BTreeSet, HashMap};
+use chrono::Utc;
+use rusqlite::{params, Transaction};
+use super::{steam_id_int, Pool};
+use crate::db::DbError;
+
+/// Get cloud sync status for a user
+pub async fn get_cloud_sync_status(pool: &Pool, steam_id: &str) -> Result<CloudSyncStatus, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    pool.with(|conn| {
+        let game_count: i64 = conn.query_row("SELECT COUNT(*) FROM user_games WHERE steam_id = ?1", [steam_id_int], |row| row.get(0))?;
+        let achievement_count: i64 = conn.query_row("SELECT COUNT(*) FROM user_achievements WHERE steam_id = ?1", [steam_id_int], |row| row.get(0))?;
+        let last_sync: Option<chrono::DateTime<Utc>> =
+            conn.query_row("SELECT MAX(run_at) FROM run_history WHERE steam_id = ?1", [steam_id_int], |row| row.get(0))?;
+
+        Ok(CloudSyncStatus {
+            has_data: game_count > 0,
+            game_count: game_count as i32,
+            achievement_count: achievement_count as i32,
+            last_sync,
+        })
+    })
+}
+
+/// Get all user data for cloud download
+pub async fn get_cloud_sync_data(pool: &Pool, steam_id: &str) -> Result<CloudSyncData, DbError> {
+    let games = super::games::get_user_games(pool, steam_id).await?;
+    let achievements = super::history::get_all_user_achievements(pool, steam_id).await?;
+    let run_history = super::history::get_run_history(pool, steam_id).await?;
+    let achievement_history = super::history::get_achievement_history(pool, steam_id).await?;
+    let goals = get_user_goals(pool, steam_id).await?;
+
+    Ok(CloudSyncData {
+        steam_id: steam_id.to_string(),
+        games,
+        achievements,
+        run_history,
+        achievement_history,
+        goals,
+        exported_at: Utc::now(),
+    })
+}
+
+/// Get a user's goals, oldest first (rows that no longer parse are skipped)
+async fn get_user_goals(pool: &Pool, steam_id: &str) -> Result<Vec<Goal>, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    let rows: Vec<String> = pool.with(|conn| {
+        let mut stmt = conn.prepare("SELECT goal FROM user_goals WHERE steam_id = ?1 ORDER BY created_at")?;
+        let rows = stmt.query_map([steam_id_int], |row| row.get(0))?.collect();
+        rows
+    })?;
+
+    Ok(rows.iter().filter_map(|goal| serde_json::from_str(goal).ok()).collect())
+}
+
+/// Create the user row for an upload from a user who never logged in on the web
+fn ensure_user(tx: &Transaction, steam_id_int: i64, steam_id: &str) -> rusqlite::Result<()> {
+    tx.execute(
+        "INSERT INTO users (steam_id, display_name) VALUES (?1, ?2) ON CONFLICT (steam_id) DO NOTHING",
+        params![steam_id_int, format!("User {}", &steam_id[..8.min(steam_id.len())])],
+    )?;
+    Ok(())
+}
+
+/// Replace a user's goals
+fn replace_goals(tx: &Transaction, steam_id_int: i64, goals: &[Goal]) -> rusqlite::Result<()> {
+    tx.execute("DELETE FROM user_goals WHERE steam_id = ?1", [steam_id_int])?;
+    for goal in goals {
+        let json = serde_json::to_string(goal).unwrap_or_default();
+        tx.execute(
+            "INSERT INTO user_goals (steam_id, created_at, goal) VALUES (?1, ?2, ?3) ON CONFLICT DO NOTHING",
+            params![steam_id_int, goal.created_at, json],
+        )?;
+    }
+    Ok(())
+}
+
+/// Upload cloud sync data (overwrites all existing data for user)
+pub async fn upload_cloud_sync_data(pool: &Pool, data: &CloudSyncData) -> Result<(), DbError> {
+    let steam_id_int = steam_id_int(&data.steam_id);
+    pool.with(|conn| {
+        let tx = conn.transaction()?;
+        ensure_user(&tx, steam_id_int, &data.steam_id)?;
+
+        // Delete existing data for this user
+        tx.execute("DELETE FROM user_achievements WHERE steam_id = ?1", [steam_id_int])?;
+        // NOTE: Don't delete user_games - we'll upsert to preserve hidden status
+        tx.execute("DELETE FROM run_history WHERE steam_id = ?1", [steam_id_int])?;
+        tx.execute("DELETE FROM achievement_history WHERE steam_id = ?1", [steam_id_int])?;
+
+        for game in &data.games {
+            tx.execute(
+                r#"
+                INSERT INTO user_games (steam_id, appid, name, playtime_forever, rtime_last_played, img_icon_url, added_at, achievements_total, achievements_unlocked, last_sync, hidden, steam_hidden)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+                ON CONFLICT (steam_id, appid) DO UPDATE SET
+                    name = excluded.name,
+                    playtime_forever = excluded.playtime_forever,
+                    rtime_last_played = excluded.rtime_last_played,
+                    img_icon_url = excluded.img_icon_url,
+                    added_at = excluded.added_at,
+                    achievements_total = excluded.achievements_total,
+                    achievements_unlocked = excluded.achievements_unlocked,
+                    last_sync = excluded.last_sync
+                "#,
+                params![
+                    steam_id_int,
+                    game.appid as i64,
+                    game.name,
+                    game.playtime_forever as i64,
+                    game.rtime_last_played.map(|t| t as i64),
+                    game.img_icon_url,
+                    game.added_at,
+                    game.achievements_total,
+                    game.achievements_unlocked,
+                    game.last_achievement_scrape,
+                    game.hidden,
+                    game.steam_hidden,
+                ],
+            )?;
+        }
+
+        // Achievements (lightweight - only sync user progress, not schema)
+        for ach in &data.achievements {
+            tx.execute(
+                r#"
+                INSERT INTO user_achievements (steam_id, appid, apiname, achieved, unlocktime)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ON CONFLICT (steam_id, appid, apiname) DO UPDATE SET
+                    achieved = excluded.achieved,
+                    unlocktime = excluded.unlocktime
+                "#,
+                params![steam_id_int, ach.appid as i64, ach.apiname, ach.achieved, ach.unlocktime],
+            )?;
+        }
+
+        for rh in &data.run_history {
+            tx.execute(
+                "INSERT INTO run_history (steam_id, run_at, total_games, unplayed_games, unplayed_games_total) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![steam_id_int, rh.run_at, rh.total_games, rh.unplayed_games, rh.unplayed_games_total],
+            )?;
+        }
+
+        for ah in &data.achievement_history {
+            tx.execute(
+                "INSERT INTO achievement_history (steam_id, recorded_at, total_achievements, unlocked_achievements, games_with_achievements, avg_completion_percent) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![steam_id_int, ah.recorded_at, ah.total_achievements, ah.unlocked_achievements, ah.games_with_achievements, ah.avg_completion_percent as f64],
+            )?;
+        }
+
+        replace_goals(&tx, steam_id_int, &data.goals)?;
+        tx.commit()
+    })
+}
+
+/// What the server has for a user, for delta uploads: checksums per game, newest history entries
+pub async fn get_sync_manifest(pool: &Pool, steam_id: &str) -> Result<SyncManifest, DbError> {
+    let games = super::games::get_user_games(pool, steam_id).await?;
+    let achievements = super::history::get_all_user_achievements(pool, steam_id).await?;
+    let mut achievements_by_game: HashMap<u64, Vec<&SyncAchievement>> = HashMap::new();
+    for ach in &achievements {
+        achievements_by_game.entry(ach.appid).or_default().push(ach);
+    }
+    let game_checksums = games
+        .iter()
+        .map(|game| {
+            let achievements = achievements_by_game.get(&game.appid).map(Vec::as_slice).unwrap_or(&[]);
+            (game.appid, game_sync_checksum(game, achievements))
+        })
+        .collect();
+
+    let steam_id_int = steam_id_int(steam_id);
+    let (last_run_at, last_achievement_history_at) = pool.with(|conn| {
+        let last_run_at = conn.query_row("SELECT MAX(run_at) FROM run_history WHERE steam_id = ?1", [steam_id_int], |row| row.get(0))?;
+        let last_achievement_history_at =
+            conn.query_row("SELECT MAX(recorded_at) FROM achievement_history WHERE steam_id = ?1", [steam_id_int], |row| row.get(0))?;
+        Ok((last_run_at, last_achievement_history_at))
+    })?;
+
+    Ok(SyncManifest {
+        game_checksums,
+        last_run_at,
+        last_achievement_history_at,
+    })
+}
+
+/// Merge a delta upload into the stored data. Unlike the full upload nothing is deleted,
+/// and concurrent uploads from other devices can't undo progress: unlocks are kept (newest
+/// unlock time wins, like the desktop merge - see `merge_sync_achievement`),
+/// playtime only grows, game data from an older scrape doesn't replace a newer one, and
+/// unlock counts are recounted from the merged achievement rows.
+pub async fn merge_cloud_sync_delta(pool: &Pool, delta: &CloudSyncDelta) -> Result<(), DbError> {
+    let steam_id_int = steam_id_int(&delta.steam_id);
+    pool.with(|conn| {
+        let tx = conn.transaction()?;
+        ensure_user(&tx, steam_id_int, &delta.steam_id)?;
+
+        // MAX/MIN with more than one argument are NULL if any argument is, hence the COALESCEs
+        for game in &delta.games {
+            tx.execute(
+                r#"
+                INSERT INTO user_games (steam_id, appid, name, playtime_forever, rtime_last_played, img_icon_url, added_at, achievements_total, achievements_unlocked, last_sync, hidden, steam_hidden)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+                ON CONFLICT (steam_id, appid) DO UPDATE SET
+                    name = excluded.name,
+                    img_icon_url = excluded.img_icon_url,
+                    playtime_forever = MAX(user_games.playtime_forever, excluded.playtime_forever),
+                    rtime_last_played = MAX(COALESCE(user_games.rtime_last_played, excluded.rtime_last_played),
+                                            COALESCE(excluded.rtime_last_played, user_games.rtime_last_played)),
+                    achievements_total = CASE WHEN user_games.last_sync IS NULL OR excluded.last_sync >= user_games.last_sync
+                        THEN excluded.achievements_total ELSE user_games.achievements_total END,
+                    achievements_unlocked = CASE WHEN user_games.last_sync IS NULL OR excluded.last_sync >= user_games.last_sync
+                        THEN excluded.achievements_unlocked ELSE user_games.achievements_unlocked END,
+                    last_sync = MAX(COALESCE(user_games.last_sync, excluded.last_sync),
+                                    COALESCE(excluded.last_sync, user_games.last_sync))
+                "#,
+                params![
+                    steam_id_int,
+                    game.appid as i64,
+                    game.name,
+                    game.playtime_forever as i64,
+                    game.rtime_last_played.map(|t| t as i64),
+                    game.img_icon_url,
+                    game.added_at,
+                    game.achievements_total,
+                    game.achievements_unlocked,
+                    game.last_achievement_scrape,
+                    game.hidden,
+                    game.steam_hidden,
+                ],
+            )?;
+        }
+
+        for ach in &delta.achievements {
+            tx.execute(
+                r#"
+                INSERT INTO user_achievements (steam_id, appid, apiname, achieved, unlocktime)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ON CONFLICT (steam_id, appid, apiname) DO UPDATE SET
+                    achieved = user_achievements.achieved OR excluded.achieved,
+                    unlocktime = MAX(COALESCE(user_achievements.unlocktime, excluded.unlocktime),
+                                     COALESCE(excluded.unlocktime, user_achievements.unlocktime))
+                "#,
+                params![steam_id_int, ach.appid as i64, ach.apiname, ach.achieved, ach.unlocktime],
+            )?;
+        }
+
+        // Unlock counts follow the merged achievement rows. Games without any rows
+        // (never scraped) keep the count picked above.
+        let touched: BTreeSet<i64> = delta.games.iter().map(|g| g.appid as i64)
+            .chain(delta.achievements.iter().map(|a| a.appid as i64))
+            .collect();
+        for appid in touched {
+            tx.execute(
+                r#"
+                UPDATE user_games SET achievements_unlocked =
+                    (SELECT COUNT(*) FROM user_achievements WHERE steam_id = ?1 AND appid = ?2 AND achieved)
+                WHERE steam_id = ?1 AND appid = ?2
+                  AND EXISTS (SELECT 1 FROM user_achievements WHERE steam_id = ?1 AND appid = ?2)
+                "#,
+                params![steam_id_int, appid],
+            )?;
+        }
+
+        // History is append-only; skip entries another upload already added
+        for rh in &delta.run_history {
+            tx.execute(
+                r#"
+                INSERT INTO run_history (steam_id, run_at, total_games, unplayed_games, unplayed_games_total)
+                SELECT ?1, ?2, ?3, ?4, ?5
+                WHERE NOT EXISTS (SELECT 1 FROM run_history WHERE steam_id = ?1 AND run_at = ?2)
+                "#,
+                params![steam_id_int, rh.run_at, rh.total_games, rh.unplayed_games, rh.unplayed_games_total],
+            )?;
+        }
+
+        for ah in &delta.achievement_history {
+            tx.execute(
+                r#"
+                INSERT INTO achievement_history (steam_id, recorded_at, total_achievements, unlocked_achievements, games_with_achievements, avg_completion_percent)
+                SELECT ?1, ?2, ?3, ?4, ?5, ?6
+                WHERE NOT EXISTS (SELECT 1 FROM achievement_history WHERE steam_id = ?1 AND recorded_at = ?2)
+                "#,
+                params![steam_id_int, ah.recorded_at, ah.total_achievements, ah.unlocked_achievements, ah.games_with_achievements, ah.avg_completion_percent as f64],
+            )?;
+        }
+
+        replace_goals(&tx, steam_id_int, &delta.goals)?;
+        tx.commit()
+    })
+}
+
+/// Delete all cloud data for a user
+pub async fn delete_cloud_sync_data(pool: &Pool, steam_id: &str) -> Result<(), DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    pool.with(|conn| {
+        for table in ["user_achievements", "user_games", "run_history", "achievement_history", "achievement_ratings", "game_ratings", "user_goals"] {
+            conn.execute(&format!("DELETE FROM {} WHERE steam_id = ?1", table), [steam_id_int])?;
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use overachiever_core::{merge_sync_achievement, Game};
+
+    fn delta(achievements: Vec<SyncAchievement>) -> CloudSyncDelta {
+        let game: Game = serde_json::from_value(serde_json::json!({
+            "appid": 10,
+            "name": "Portal",
+            "playtime_forever": 60,
+            "rtime_last_played": null,
+            "img_icon_url": null,
+            "added_at": "2024-01-01T00:00:00Z",
+            "achievements_total": 3,
+            "achievements_unlocked": 2,
+            "last_achievement_scrape": null,
+            "avg_user_ttb_main_seconds": null,
+            "avg_user_ttb_extra_seconds": null,
+            "avg_user_ttb_completionist_seconds": null,
+            "user_ttb_report_count": 0,
+            "my_ttb_main_seconds": null,
+            "my_ttb_extra_seconds": null,
+            "my_ttb_completionist_seconds": null,
+            "my_ttb_reported_at": null,
+        }))
+        .unwrap();
+        CloudSyncDelta {
+            steam_id: "1".to_string(),
+            games: vec![game],
+            achievements,
+            run_history: Vec::new(),
+            achievement_history: Vec::new(),
+            goals: Vec::new(),
+            exported_at: Utc::now(),
+        }
+    }
+
+    fn ach(apiname: &str, achieved: bool, unlocktime: Option<i64>) -> SyncAchievement {
+        SyncAchievement {
+            appid: 10,
+            apiname: apiname.to_string(),
+            achieved,
+            unlocktime: unlocktime.and_then(|ts| chrono::DateTime::from_timestamp(ts, 0)),
+        }
+    }
+
+    /// The SQL merge must follow `merge_sync_achievement`, which the desktop merge is tested against
+    #[tokio::test(flavor = "multi_thread")]
+    async fn delta_merge_matches_merge_sync_achievement() {
+        let pool = super::super::in_memory();
+        let stored = vec![ach("A", true, Some(2_000)), ach("B", false, None), ach("C", true, Some(500))];
+        let incoming = vec![ach("A", true, Some(1_000)), ach("B", true, Some(3_000)), ach("C", true, None)];
+        merge_cloud_sync_delta(&pool, &delta(stored.clone())).await.unwrap();
+        merge_cloud_sync_delta(&pool, &delta(incoming.clone())).await.unwrap();
+
+        let fields = |a: &SyncAchievement| (a.apiname.clone(), a.achieved, a.unlocktime);
+        let merged: Vec<_> = super::super::get_all_user_achievements(&pool, "1").await.unwrap().iter().map(fields).collect();
+        let expected: Vec<_> = stored.iter().zip(&incoming).map(|(s, i)| fields(&merge_sync_achievement(s, i))).collect();
+        assert_eq!(merged, expected);
+    }
+
+    /// Both uploads claim 2 unlocks, the merged rows have 3
+    #[tokio::test(flavor = "multi_thread")]
+    async fn delta_merge_recounts_unlocks() {
+        let pool = super::super::in_memory();
+        merge_cloud_sync_delta(&pool, &delta(vec![ach("A", true, Some(1_000)), ach("B", true, Some(2_000)), ach("C", false, None)])).await.unwrap();
+        merge_cloud_sync_delta(&pool, &delta(vec![ach("A", true, Some(1_000)), ach("B", false, None), ach("C", true, Some(3_000))])).await.unwrap();
+
+        let game = super::super::get_user_games(&pool, "1").await.unwrap().remove(0);
+        assert_eq!(game.achievements_unlocked, Some(3));
+    }
+}
@@ -0,0 +1,161 @@
+//! Achievement comments and the comment report / moderation flow
+
+use chrono::Utc;
+use overachiever_core::{AchievementComment, ReportedComment};
+use rusqlite::{params, OptionalExtension};
+use super::{steam_id_int, Pool};
+use crate::db::DbError;
+
+/// Map a row selected with the comment columns (and the author's display name)
+fn comment_from_row(row: &rusqlite::Row) -> rusqlite::Result<AchievementComment> {
+    Ok(AchievementComment {
+        id: row.get("id")?,
+        steam_id: row.get::<_, i64>("steam_id")?.to_string(),
+        display_name: row.get("display_name")?,
+        appid: row.get::<_, i64>("appid")? as u64,
+        apiname: row.get("apiname")?,
+        comment: row.get("comment")?,
+        created_at: row.get("created_at")?,
+    })
+}
+
+/// Store one comment for each of the given achievements, returns the number stored
+pub async fn insert_achievement_comments(
+    pool: &Pool,
+    steam_id: &str,
+    achievements: &[(u64, String)],
+    comment: &str,
+) -> Result<u64, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    let now = Utc::now();
+
+    pool.with(|conn| {
+        let tx = conn.transaction()?;
+        let mut stored = 0;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT INTO achievement_comments (steam_id, appid, apiname, comment, created_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+            )?;
+            for (appid, apiname) in achievements {
+                stored += stmt.execute(params![steam_id_int, *appid as i64, apiname, comment, now])? as u64;
+            }
+        }
+        tx.commit()?;
+        Ok(stored)
+    })
+}
+
+/// Visible (not removed) comments on a game's achievements, newest first
+pub async fn get_game_comments(pool: &Pool, appid: u64) -> Result<Vec<AchievementComment>, DbError> {
+    pool.with(|conn| {
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT c.id, c.steam_id, u.display_name, c.appid, c.apiname, c.comment, c.created_at
+            FROM achievement_comments c
+            LEFT JOIN users u ON u.steam_id = c.steam_id
+            WHERE c.appid = ?1 AND c.deleted_at IS NULL
+            ORDER BY c.created_at DESC
+            "#,
+        )?;
+        let comments = stmt.query_map([appid as i64], comment_from_row)?.collect();
+        comments
+    })
+}
+
+/// Report a comment. Returns false if the comment doesn't exist or was already removed.
+/// Reporting the same comment twice keeps the first report.
+pub async fn report_comment(
+    pool: &Pool,
+    comment_id: i64,
+    reported_by: &str,
+    reason: Option<&str>,
+) -> Result<bool, DbError> {
+    let reporter_int = steam_id_int(reported_by);
+    let now = Utc::now();
+
+    pool.with(|conn| {
+        let exists = conn
+            .query_row("SELECT 1 FROM achievement_comments WHERE id = ?1 AND deleted_at IS NULL", [comment_id], |_| Ok(()))
+            .optional()?
+            .is_some();
+        if !exists {
+            return Ok(false);
+        }
+
+        conn.execute(
+            r#"
+            INSERT INTO comment_reports (comment_id, reported_by, reason, created_at)
+            VALUES (?1, ?2, ?3, ?4)
+            ON CONFLICT (comment_id, reported_by) DO NOTHING
+            "#,
+            params![comment_id, reporter_int, reason, now],
+        )?;
+        Ok(true)
+    })
+}
+
+/// Comments with open reports, most reported first
+pub async fn get_reported_comments(pool: &Pool, limit: i64) -> Result<Vec<ReportedComment>, DbError> {
+    pool.with(|conn| {
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT c.id, c.steam_id, u.display_name, c.appid, c.apiname, c.comment, c.created_at,
+                   COUNT(r.id) AS report_count,
+                   json_group_array(NULLIF(TRIM(r.reason), '') ORDER BY r.created_at) AS reasons,
+                   MIN(r.created_at) AS first_reported_at
+            FROM comment_reports r
+            JOIN achievement_comments c ON c.id = r.comment_id
+            LEFT JOIN users u ON u.steam_id = c.steam_id
+            WHERE r.resolved_at IS NULL AND c.deleted_at IS NULL
+            GROUP BY c.id
+            ORDER BY COUNT(r.id) DESC, MIN(r.created_at)
+            LIMIT ?1
+            "#,
+        )?;
+        let comments = stmt
+            .query_map([limit], |row| {
+                // Blank reasons are NULL in the array
+                let reasons: Vec<Option<String>> = serde_json::from_str(&row.get::<_, String>("reasons")?).unwrap_or_default();
+                Ok(ReportedComment {
+                    comment: comment_from_row(row)?,
+                    report_count: row.get("report_count")?,
+                    reasons: reasons.into_iter().flatten().collect(),
+                    first_reported_at: row.get("first_reported_at")?,
+                })
+            })?
+            .collect();
+        comments
+    })
+}
+
+/// Soft-delete a comment and resolve its reports. Returns false if there was no visible comment.
+pub async fn remove_comment(pool: &Pool, comment_id: i64, admin_steam_id: &str) -> Result<bool, DbError> {
+    let admin_int = steam_id_int(admin_steam_id);
+    let now = Utc::now();
+
+    pool.with(|conn| {
+        let tx = conn.transaction()?;
+        let removed = tx.execute(
+            "UPDATE achievement_comments SET deleted_at = ?3, deleted_by = ?2 WHERE id = ?1 AND deleted_at IS NULL",
+            params![comment_id, admin_int, now],
+        )?;
+        tx.execute(
+            "UPDATE comment_reports SET resolved_at = ?2 WHERE comment_id = ?1 AND resolved_at IS NULL",
+            params![comment_id, now],
+        )?;
+        tx.commit()?;
+        Ok(removed > 0)
+    })
+}
+
+/// Dismiss the open reports of a comment (it stays visible), returns the number dismissed
+pub async fn dismiss_comment_reports(pool: &Pool, comment_id: i64) -> Result<u64, DbError> {
+    let now = Utc::now();
+    pool.with(|conn| {
+        let rows_affected = conn.execute(
+            "UPDATE comment_reports SET resolved_at = ?2 WHERE comment_id = ?1 AND resolved_at IS NULL",
+            params![comment_id, now],
+        )?;
+        Ok(rows_affected as u64)
+    })
+}
@@ -0,0 +1,199 @@
+//! Weekly email digest subscriptions and the data each digest is built from
+
+use chrono::{DateTime, Duration, Utc};
+use overachiever_core::DigestSubscription;
+use rusqlite::{params, OptionalExtension};
+use super::{steam_id_int, Pool};
+use crate::db::{generate_unsubscribe_token, DbError, DigestData, DigestUnlock, DueDigest, DIGEST_INTERVAL_DAYS, MAX_LISTED_UNLOCKS};
+
+fn subscription_from_row(row: &rusqlite::Row) -> rusqlite::Result<DigestSubscription> {
+    Ok(DigestSubscription {
+        email: row.get("email")?,
+        enabled: row.get("enabled")?,
+        next_send_at: row.get("next_send_at")?,
+        last_sent_at: row.get("last_sent_at")?,
+    })
+}
+
+/// Get a user's digest subscription
+pub async fn get_digest_subscription(pool: &Pool, steam_id: &str) -> Result<Option<DigestSubscription>, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    pool.with(|conn| {
+        conn.query_row(
+            "SELECT email, enabled, next_send_at, last_sent_at FROM email_digests WHERE steam_id = ?1",
+            [steam_id_int],
+            subscription_from_row,
+        )
+        .optional()
+    })
+}
+
+/// Opt into the digest (or change the address). The first digest goes out a week from now.
+pub async fn subscribe_digest(pool: &Pool, steam_id: &str, email: &str) -> Result<DigestSubscription, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    let now = Utc::now();
+    let next_send_at = now + Duration::days(DIGEST_INTERVAL_DAYS);
+
+    pool.with(|conn| {
+        conn.query_row(
+            r#"
+            INSERT INTO email_digests (steam_id, email, enabled, unsubscribe_token, next_send_at, created_at)
+            VALUES (?1, ?2, 1, ?3, ?4, ?5)
+            ON CONFLICT (steam_id) DO UPDATE SET
+                email = excluded.email,
+                enabled = 1,
+                next_send_at = CASE WHEN email_digests.enabled THEN email_digests.next_send_at ELSE excluded.next_send_at END
+            RETURNING email, enabled, next_send_at, last_sent_at
+            "#,
+            params![steam_id_int, email, generate_unsubscribe_token(), next_send_at, now],
+            subscription_from_row,
+        )
+    })
+}
+
+/// Remove a user's subscription, returns whether there was one
+pub async fn delete_digest_subscription(pool: &Pool, steam_id: &str) -> Result<bool, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    pool.with(|conn| {
+        let rows_affected = conn.execute("DELETE FROM email_digests WHERE steam_id = ?1", [steam_id_int])?;
+        Ok(rows_affected > 0)
+    })
+}
+
+/// Turn the digest off from an email's unsubscribe link, returns whether the token matched
+pub async fn unsubscribe_digest(pool: &Pool, token: &str) -> Result<bool, DbError> {
+    pool.with(|conn| {
+        let rows_affected = conn.execute("UPDATE email_digests SET enabled = 0 WHERE unsubscribe_token = ?1", [token])?;
+        Ok(rows_affected > 0)
+    })
+}
+
+/// Subscriptions that are due, oldest first
+pub async fn get_due_digests(pool: &Pool, limit: i64) -> Result<Vec<DueDigest>, DbError> {
+    let now = Utc::now();
+    pool.with(|conn| {
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT d.steam_id, u.display_name, d.email, d.unsubscribe_token, d.last_sent_at, d.next_send_at
+            FROM email_digests d
+            JOIN users u ON u.steam_id = d.steam_id
+            WHERE d.enabled AND d.next_send_at <= ?2
+            ORDER BY d.next_send_at
+            LIMIT ?1
+            "#,
+        )?;
+        let due = stmt
+            .query_map(params![limit, now], |row| {
+                let last_sent_at: Option<DateTime<Utc>> = row.get("last_sent_at")?;
+                let next_send_at: DateTime<Utc> = row.get("next_send_at")?;
+                Ok(DueDigest {
+                    steam_id: row.get::<_, i64>("steam_id")?.to_string(),
+                    display_name: row.get("display_name")?,
+                    email: row.get("email")?,
+                    unsubscribe_token: row.get("unsubscribe_token")?,
+                    since: last_sent_at.unwrap_or(next_send_at - Duration::days(DIGEST_INTERVAL_DAYS)),
+                })
+            })?
+            .collect();
+        due
+    })
+}
+
+/// Record a sent (or skipped) digest and schedule the next one a week later
+pub async fn mark_digest_sent(pool: &Pool, steam_id: &str, sent: bool) -> Result<(), DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    let now = Utc::now();
+
+    pool.with(|conn| {
+        let next_send_at: Option<DateTime<Utc>> = conn
+            .query_row("SELECT next_send_at FROM email_digests WHERE steam_id = ?1", [steam_id_int], |row| row.get(0))
+            .optional()?;
+        let Some(next_send_at) = next_send_at else {
+            return Ok(());
+        };
+        // Skip ahead rather than catching up on weeks missed while the server was down
+        let next_send_at = next_send_at.max(now) + Duration::days(DIGEST_INTERVAL_DAYS);
+        conn.execute(
+            r#"
+            UPDATE email_digests SET
+                last_sent_at = CASE WHEN ?2 THEN ?3 ELSE last_sent_at END,
+                next_send_at = ?4
+            WHERE steam_id = ?1
+            "#,
+            params![steam_id_int, sent, now, next_send_at],
+        )?;
+        Ok(())
+    })
+}
+
+/// Collect the unlocks, streak days and completion change since `since`
+pub async fn get_digest_data(pool: &Pool, steam_id: &str, since: DateTime<Utc>) -> Result<DigestData, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    // A year of days is plenty for any streak worth mentioning
+    let year_ago = Utc::now() - Duration::days(366);
+
+    pool.with(|conn| {
+        let unlock_count: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM user_achievements WHERE steam_id = ?1 AND achieved AND unlocktime > ?2",
+            params![steam_id_int, since],
+            |row| row.get(0),
+        )?;
+
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT COALESCE(g.name, 'App ' || ua.appid) AS game_name,
+                   COALESCE(s.display_name, ua.apiname) AS achievement_name,
+                   ua.unlocktime
+            FROM user_achievements ua
+            LEFT JOIN user_games g ON g.steam_id = ua.steam_id AND g.appid = ua.appid
+            LEFT JOIN achievement_schemas s ON s.appid = ua.appid AND s.apiname = ua.apiname
+            WHERE ua.steam_id = ?1 AND ua.achieved AND ua.unlocktime > ?2
+            ORDER BY ua.unlocktime DESC
+            LIMIT ?3
+            "#,
+        )?;
+        let unlocks = stmt
+            .query_map(params![steam_id_int, since, MAX_LISTED_UNLOCKS], |row| {
+                Ok(DigestUnlock {
+                    game_name: row.get("game_name")?,
+                    achievement_name: row.get("achievement_name")?,
+                    unlocktime: row.get("unlocktime")?,
+                })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+
+        // Timestamps are stored as UTC text, so the first 10 characters are the UTC date
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT DISTINCT substr(unlocktime, 1, 10) AS day
+            FROM user_achievements
+            WHERE steam_id = ?1 AND achieved AND unlocktime > ?2
+            ORDER BY day DESC
+            "#,
+        )?;
+        let unlock_days = stmt.query_map(params![steam_id_int, year_ago], |row| row.get("day"))?.collect::<rusqlite::Result<_>>()?;
+
+        let completion_before = conn
+            .query_row(
+                "SELECT avg_completion_percent FROM achievement_history WHERE steam_id = ?1 AND recorded_at <= ?2 ORDER BY recorded_at DESC LIMIT 1",
+                params![steam_id_int, since],
+                |row| row.get(0),
+            )
+            .optional()?;
+        let completion_after = conn
+            .query_row(
+                "SELECT avg_completion_percent FROM achievement_history WHERE steam_id = ?1 ORDER BY recorded_at DESC LIMIT 1",
+                [steam_id_int],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        Ok(DigestData {
+            unlocks,
+            unlock_count,
+            unlock_days,
+            completion_before,
+            completion_after,
+        })
+    })
+}
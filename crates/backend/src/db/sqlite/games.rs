@@ -0,0 +1,250 @@
+//! Game-related database operations
+
+use overachiever_core::Game;
+use chrono::{DateTime, Utc};
+use rusqlite::{params, OptionalExtension};
+use super::{appids_json, steam_id_int, Pool};
+use crate::db::DbError;
+
+/// A user's games with the community TTB averages (approved reports) and their own report
+const USER_GAMES_QUERY: &str = r#"
+    SELECT g.appid, g.name, g.playtime_forever, g.rtime_last_played, g.img_icon_url,
+           g.added_at, g.achievements_total, g.achievements_unlocked, g.last_sync,
+           CAST(ROUND(avg.main) AS INTEGER) AS avg_user_ttb_main_seconds,
+           CAST(ROUND(avg.extra) AS INTEGER) AS avg_user_ttb_extra_seconds,
+           CAST(ROUND(avg.completionist) AS INTEGER) AS avg_user_ttb_completionist_seconds,
+           avg.report_count AS user_ttb_report_count,
+           mine.main_seconds AS my_ttb_main_seconds, mine.extra_seconds AS my_ttb_extra_seconds,
+           mine.completionist_seconds AS my_ttb_completionist_seconds, mine.reported_at AS my_ttb_reported_at,
+           g.hidden, g.steam_hidden
+    FROM user_games g
+    LEFT JOIN (
+        SELECT appid, AVG(main_seconds) AS main, AVG(extra_seconds) AS extra,
+               AVG(completionist_seconds) AS completionist, COUNT(*) AS report_count
+        FROM user_ttb_reports
+        WHERE review_status = 'approved'
+        GROUP BY appid
+    ) avg ON avg.appid = g.appid
+    LEFT JOIN user_ttb_reports mine ON mine.steam_id = g.steam_id AND mine.appid = g.appid
+    WHERE g.steam_id = ?1
+    ORDER BY g.name
+"#;
+
+fn game_from_row(row: &rusqlite::Row) -> rusqlite::Result<Game> {
+    Ok(Game {
+        appid: row.get::<_, i64>("appid")? as u64,
+        name: row.get("name")?,
+        playtime_forever: row.get::<_, i64>("playtime_forever")? as u32,
+        rtime_last_played: row.get::<_, Option<i64>>("rtime_last_played")?.map(|t| t as u32),
+        img_icon_url: row.get("img_icon_url")?,
+        added_at: row.get::<_, Option<DateTime<Utc>>>("added_at")?.unwrap_or_else(Utc::now),
+        achievements_total: row.get("achievements_total")?,
+        achievements_unlocked: row.get("achievements_unlocked")?,
+        last_achievement_scrape: row.get("last_sync")?,
+        avg_user_ttb_main_seconds: row.get("avg_user_ttb_main_seconds")?,
+        avg_user_ttb_extra_seconds: row.get("avg_user_ttb_extra_seconds")?,
+        avg_user_ttb_completionist_seconds: row.get("avg_user_ttb_completionist_seconds")?,
+        user_ttb_report_count: row.get::<_, Option<i32>>("user_ttb_report_count")?.unwrap_or(0),
+        my_ttb_main_seconds: row.get("my_ttb_main_seconds")?,
+        my_ttb_extra_seconds: row.get("my_ttb_extra_seconds")?,
+        my_ttb_completionist_seconds: row.get("my_ttb_completionist_seconds")?,
+        my_ttb_reported_at: row.get("my_ttb_reported_at")?,
+        hidden: row.get::<_, Option<bool>>("hidden")?.unwrap_or(false),
+        steam_hidden: row.get::<_, Option<bool>>("steam_hidden")?.unwrap_or(false),
+        steam_private: false,  // Not stored in database yet
+        app_type: None,  // Not stored in database yet
+        abandoned: false,  // Local-only flag
+        platform_playtime: None,  // Local-only data
+        owned: true,  // Ownership is tracked locally only
+    })
+}
+
+/// Get games for a user by their short_id
+pub async fn get_user_games_by_short_id(pool: &Pool, short_id: &str) -> Result<Option<Vec<Game>>, DbError> {
+    pool.with(|conn| {
+        let steam_id_int: Option<i64> = conn
+            .query_row("SELECT steam_id FROM users WHERE short_id = ?1", [short_id], |row| row.get(0))
+            .optional()?;
+        let Some(steam_id_int) = steam_id_int else {
+            return Ok(None);
+        };
+        let mut stmt = conn.prepare(USER_GAMES_QUERY)?;
+        let games = stmt.query_map([steam_id_int], game_from_row)?.collect::<rusqlite::Result<_>>()?;
+        Ok(Some(games))
+    })
+}
+
+/// Get games for a user by steam_id
+pub async fn get_user_games(pool: &Pool, steam_id: &str) -> Result<Vec<Game>, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    pool.with(|conn| {
+        let mut stmt = conn.prepare(USER_GAMES_QUERY)?;
+        let games = stmt.query_map([steam_id_int], game_from_row)?.collect();
+        games
+    })
+}
+
+/// Insert or update games for a user
+pub async fn upsert_games(
+    pool: &Pool,
+    steam_id: &str,
+    games: &[overachiever_core::SteamGame],
+) -> Result<usize, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    let now = Utc::now();
+
+    pool.with(|conn| {
+        let tx = conn.transaction()?;
+        {
+            let mut stmt = tx.prepare(
+                r#"
+                INSERT INTO user_games (steam_id, appid, name, playtime_forever, rtime_last_played, img_icon_url, added_at)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                ON CONFLICT (steam_id, appid) DO UPDATE SET
+                    name = excluded.name,
+                    playtime_forever = excluded.playtime_forever,
+                    rtime_last_played = excluded.rtime_last_played,
+                    img_icon_url = excluded.img_icon_url
+                "#,
+            )?;
+            for game in games {
+                stmt.execute(params![
+                    steam_id_int,
+                    game.appid as i64,
+                    game.name,
+                    game.playtime_forever as i64,
+                    game.rtime_last_played.map(|t| t as i64),
+                    game.img_icon_url,
+                    now,
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(games.len())
+    })
+}
+
+/// Update achievement counts for a game
+pub async fn update_game_achievements(
+    pool: &Pool,
+    steam_id: &str,
+    appid: u64,
+    total: i32,
+    unlocked: i32,
+) -> Result<(), DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    let now = Utc::now();
+
+    pool.with(|conn| {
+        conn.execute(
+            r#"
+            UPDATE user_games
+            SET achievements_total = ?3, achievements_unlocked = ?4, last_sync = ?5
+            WHERE steam_id = ?1 AND appid = ?2
+            "#,
+            params![steam_id_int, appid as i64, total, unlocked, now],
+        )?;
+        Ok(())
+    })
+}
+
+/// Update hidden status for a game
+pub async fn update_game_hidden(
+    pool: &Pool,
+    steam_id: &str,
+    appid: u64,
+    hidden: bool,
+) -> Result<(), DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    pool.with(|conn| {
+        conn.execute(
+            "UPDATE user_games SET hidden = ?3 WHERE steam_id = ?1 AND appid = ?2",
+            params![steam_id_int, appid as i64, hidden],
+        )?;
+        Ok(())
+    })
+}
+
+/// Community completion stats for a set of games (players with playtime only)
+pub async fn get_completion_stats(pool: &Pool, appids: &[u64]) -> Result<Vec<overachiever_core::GameCompletionStats>, DbError> {
+    if appids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let appids = appids_json(appids);
+    pool.with(|conn| {
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT appid,
+                   COUNT(*) AS players,
+                   AVG(achievements_unlocked * 100.0 / achievements_total) AS avg_completion,
+                   SUM(achievements_unlocked >= achievements_total) AS completed
+            FROM user_games
+            WHERE appid IN (SELECT value FROM json_each(?1)) AND achievements_total > 0 AND playtime_forever > 0
+            GROUP BY appid
+            ORDER BY appid
+            "#,
+        )?;
+        let stats = stmt
+            .query_map([appids], |r| {
+                Ok(overachiever_core::GameCompletionStats {
+                    appid: r.get::<_, i64>("appid")? as u64,
+                    players: r.get("players")?,
+                    avg_completion: r.get::<_, f64>("avg_completion")? as f32,
+                    completed: r.get("completed")?,
+                })
+            })?
+            .collect();
+        stats
+    })
+}
+
+/// Community stats are only shown once this many users played a game, so they can't
+/// be traced back to a single library
+const MIN_COMMUNITY_PLAYERS: i64 = 3;
+
+/// Anonymous completion and playtime statistics for a game across all synced libraries
+pub async fn get_community_game_stats(pool: &Pool, appid: u64) -> Result<Option<overachiever_core::CommunityGameStats>, DbError> {
+    let rows: Vec<(i64, i64, i64)> = pool.with(|conn| {
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT playtime_forever, COALESCE(achievements_total, 0), COALESCE(achievements_unlocked, 0)
+            FROM user_games
+            WHERE appid = ?1 AND playtime_forever > 0
+            ORDER BY playtime_forever
+            "#,
+        )?;
+        let rows = stmt.query_map([appid as i64], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)))?.collect();
+        rows
+    })?;
+
+    let players = rows.len() as i64;
+    if players < MIN_COMMUNITY_PLAYERS {
+        return Ok(None);
+    }
+
+    // Averages over the games with achievements, median playtime over all players
+    let with_achievements: Vec<&(i64, i64, i64)> = rows.iter().filter(|(_, total, _)| *total > 0).collect();
+    let (avg_completion, perfect_percent) = if with_achievements.is_empty() {
+        (None, None)
+    } else {
+        let count = with_achievements.len() as f64;
+        let completion: f64 = with_achievements.iter().map(|(_, total, unlocked)| *unlocked as f64 * 100.0 / *total as f64).sum();
+        let perfect = with_achievements.iter().filter(|(_, total, unlocked)| unlocked >= total).count() as f64;
+        (Some((completion / count) as f32), Some((perfect * 100.0 / count) as f32))
+    };
+    let mid = rows.len() / 2;
+    let median_playtime = if rows.len().is_multiple_of(2) {
+        ((rows[mid - 1].0 + rows[mid].0) as f64 / 2.0).round() as i32
+    } else {
+        rows[mid].0 as i32
+    };
+
+    Ok(Some(overachiever_core::CommunityGameStats {
+        appid,
+        players,
+        avg_completion,
+        perfect_percent,
+        median_playtime_minutes: median_playtime,
+    }))
+}
@@ -0,0 +1,77 @@
+//! GDPR data export and account deletion
+
+use rusqlite::types::ValueRef;
+use super::{steam_id_int, Pool};
+use crate::db::{DbError, PERSONAL_TABLES, REVIEWED_TABLES, USER_TABLES};
+
+/// A column value as JSON (SQLite has no row-to-JSON aggregate like json_agg)
+fn json_value(value: ValueRef) -> serde_json::Value {
+    match value {
+        ValueRef::Null => serde_json::Value::Null,
+        ValueRef::Integer(i) => i.into(),
+        ValueRef::Real(f) => f.into(),
+        ValueRef::Text(t) => String::from_utf8_lossy(t).into_owned().into(),
+        ValueRef::Blob(b) => b.iter().map(|byte| format!("{:02x}", byte)).collect::<String>().into(),
+    }
+}
+
+/// Export all of a user's rows as JSON arrays, one entry per table (table name, JSON)
+pub async fn export_user_data(pool: &Pool, steam_id: &str) -> Result<Vec<(String, String)>, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    pool.with(|conn| {
+        let mut tables = Vec::with_capacity(USER_TABLES.len());
+        for (table, column) in USER_TABLES {
+            // Table and column names are constants, never user input
+            let mut stmt = conn.prepare(&format!("SELECT * FROM {} WHERE {} = ?1", table, column))?;
+            let names: Vec<String> = stmt.column_names().into_iter().map(String::from).collect();
+            let rows: Vec<serde_json::Value> = stmt
+                .query_map([steam_id_int], |row| {
+                    let mut object = serde_json::Map::new();
+                    for (i, name) in names.iter().enumerate() {
+                        object.insert(name.clone(), json_value(row.get_ref(i)?));
+                    }
+                    Ok(serde_json::Value::Object(object))
+                })?
+                .collect::<rusqlite::Result<_>>()?;
+            tables.push((table.to_string(), serde_json::Value::Array(rows).to_string()));
+        }
+        Ok(tables)
+    })
+}
+
+/// Delete a user's account and everything they contributed. Returns the number of rows removed.
+///
+/// Personal rows are deleted. Community TTB times and tags the user submitted are
+/// reverted to the last approved values (or deleted if never approved) while still
+/// awaiting review, and detached from the user once approved
+/// (other users' data already depends on them). TTB blacklist entries and tag
+/// mappings are admin actions and are kept.
+pub async fn delete_user_account(pool: &Pool, steam_id: &str) -> Result<u64, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    pool.with(|conn| {
+        let tx = conn.transaction()?;
+        let mut deleted = 0;
+
+        deleted += tx.execute("DELETE FROM comment_reports WHERE reported_by = ?1", [steam_id_int])?;
+        for table in PERSONAL_TABLES {
+            deleted += tx.execute(&format!("DELETE FROM {} WHERE steam_id = ?1", table), [steam_id_int])?;
+        }
+
+        for (table, restore) in REVIEWED_TABLES {
+            tx.execute(
+                &format!(
+                    "UPDATE {} SET {}, reviewed_at = approved_at WHERE submitted_by = ?1 AND reviewed_at IS NULL AND approved_at IS NOT NULL",
+                    table, restore
+                ),
+                [steam_id_int],
+            )?;
+            deleted += tx.execute(&format!("DELETE FROM {} WHERE submitted_by = ?1 AND reviewed_at IS NULL", table), [steam_id_int])?;
+            tx.execute(&format!("UPDATE {} SET submitted_by = NULL WHERE submitted_by = ?1", table), [steam_id_int])?;
+        }
+
+        deleted += tx.execute("DELETE FROM users WHERE steam_id = ?1", [steam_id_int])?;
+
+        tx.commit()?;
+        Ok(deleted as u64)
+    })
+}
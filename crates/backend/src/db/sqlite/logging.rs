@@ -0,0 +1,29 @@
+//! API request logging
+
+use chrono::Utc;
+use rusqlite::params;
+use super::Pool;
+use crate::db::DbError;
+
+/// Log an API request
+pub async fn log_api_request(
+    pool: &Pool,
+    endpoint: &str,
+    client_ip: Option<&str>,
+    user_agent: Option<&str>,
+    referer: Option<&str>,
+    query_params: Option<&str>,
+    app_ids: Option<&str>,
+) -> Result<(), DbError> {
+    let now = Utc::now();
+    pool.with(|conn| {
+        conn.execute(
+            r#"
+            INSERT INTO api_request_log (endpoint, client_ip, user_agent, referer, query_params, app_ids, requested_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            "#,
+            params![endpoint, client_ip, user_agent, referer, query_params, app_ids, now],
+        )?;
+        Ok(())
+    })
+}
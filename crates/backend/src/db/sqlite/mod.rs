@@ -0,0 +1,153 @@
+//! SQLite storage: the same functions as the PostgreSQL modules over one database file,
+//! for a self-hosted single binary without a database server.
+//!
+//! Built with `--features sqlite`. The file is `DATABASE_PATH` (default `overachiever.db`
+//! in the working directory) and the schema is created on startup. All queries share one
+//! connection and run one at a time, which is plenty for a personal or small group server.
+
+mod users;
+mod games;
+mod achievements;
+mod history;
+mod ratings;
+mod cloud_sync;
+mod size_cache;
+mod ttb;
+mod tags;
+mod logging;
+mod admin;
+mod profile;
+mod gdpr;
+mod comments;
+mod api_keys;
+mod email_digest;
+mod rarity;
+
+pub use users::*;
+pub use games::*;
+pub use achievements::*;
+pub use history::*;
+pub use ratings::*;
+pub use cloud_sync::*;
+pub use size_cache::*;
+pub use ttb::*;
+pub use tags::*;
+pub use logging::*;
+pub use admin::*;
+pub use profile::*;
+pub use gdpr::*;
+pub use comments::*;
+pub use api_keys::*;
+pub use email_digest::*;
+pub use rarity::*;
+
+use std::sync::{Arc, Mutex};
+
+use rusqlite::Connection;
+use crate::db::DbError;
+
+/// Default database file when `DATABASE_PATH` isn't set
+const DEFAULT_DATABASE_PATH: &str = "overachiever.db";
+
+/// Tables and indexes (idempotent)
+const SCHEMA: &str = include_str!("schema.sql");
+
+/// Schema version stored in `PRAGMA user_version`
+const SCHEMA_VERSION: i32 = 4;
+
+/// Changes to databases created by an older version, run before `SCHEMA`
+/// (entry N upgrades version N + 1 to N + 2; a new file starts at 0 and needs none)
+const UPGRADES: &[&str] = &[
+    // 1 -> 2: public user directory opt-in
+    "ALTER TABLE users ADD COLUMN listed INTEGER NOT NULL DEFAULT 0;",
+    // 2 -> 3: hidden (spoiler) achievements
+    "ALTER TABLE achievement_schemas ADD COLUMN hidden INTEGER NOT NULL DEFAULT 0;",
+    // 3 -> 4: last approved TTB times and tag votes, restored when a submission is rejected
+    r#"
+    ALTER TABLE ttb_times ADD COLUMN approved_main REAL;
+    ALTER TABLE ttb_times ADD COLUMN approved_main_extra REAL;
+    ALTER TABLE ttb_times ADD COLUMN approved_completionist REAL;
+    ALTER TABLE ttb_times ADD COLUMN approved_at TEXT;
+    ALTER TABLE game_tags ADD COLUMN approved_vote_count INTEGER;
+    ALTER TABLE game_tags ADD COLUMN approved_at TEXT;
+    UPDATE ttb_times SET approved_main = main, approved_main_extra = main_extra,
+        approved_completionist = completionist, approved_at = reviewed_at
+    WHERE reviewed_at IS NOT NULL;
+    UPDATE game_tags SET approved_vote_count = vote_count, approved_at = reviewed_at WHERE reviewed_at IS NOT NULL;
+    "#,
+];
+
+/// The shared connection (cheap to clone, like the PostgreSQL pool)
+#[derive(Clone)]
+pub struct Pool {
+    conn: Arc<Mutex<Connection>>,
+}
+
+/// Connection usage for the /metrics endpoint, shaped like deadpool's `Status`
+pub struct PoolStatus {
+    pub max_size: usize,
+    pub size: usize,
+    pub available: usize,
+    pub waiting: usize,
+}
+
+impl Pool {
+    pub fn status(&self) -> PoolStatus {
+        let available = usize::from(self.conn.try_lock().is_ok());
+        PoolStatus { max_size: 1, size: 1, available, waiting: 0 }
+    }
+
+    /// Run `f` on the connection. Queries are short, so the worker thread just blocks
+    /// (`block_in_place` lets the runtime move its other tasks elsewhere meanwhile).
+    pub(crate) fn with<T>(&self, f: impl FnOnce(&mut Connection) -> rusqlite::Result<T>) -> Result<T, DbError> {
+        tokio::task::block_in_place(|| {
+            let mut conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+            f(&mut conn).map_err(DbError::from)
+        })
+    }
+}
+
+/// Open (or create) the database file from `DATABASE_PATH` and bring the schema up to date
+pub async fn connect() -> Result<Pool, String> {
+    let path = std::env::var("DATABASE_PATH").unwrap_or_else(|_| DEFAULT_DATABASE_PATH.to_string());
+    let conn = Connection::open(&path).map_err(|e| format!("Failed to open {}: {}", path, e))?;
+    conn.execute_batch("PRAGMA journal_mode = WAL; PRAGMA foreign_keys = ON; PRAGMA busy_timeout = 5000;")
+        .map_err(|e| format!("Failed to configure {}: {}", path, e))?;
+    let version: i32 = conn.pragma_query_value(None, "user_version", |row| row.get(0))
+        .map_err(|e| format!("Failed to read the schema version: {}", e))?;
+    if version > 0 {
+        for upgrade in UPGRADES.iter().skip(version as usize - 1) {
+            conn.execute_batch(upgrade).map_err(|e| format!("Failed to upgrade the schema in {}: {}", path, e))?;
+        }
+    }
+    conn.execute_batch(SCHEMA).map_err(|e| format!("Failed to create the schema in {}: {}", path, e))?;
+    conn.pragma_update(None, "user_version", SCHEMA_VERSION)
+        .map_err(|e| format!("Failed to set the schema version: {}", e))?;
+    tracing::info!("Using SQLite database {}", path);
+
+    Ok(Pool { conn: Arc::new(Mutex::new(conn)) })
+}
+
+/// A fresh in-memory database with the current schema
+#[cfg(test)]
+pub(crate) fn in_memory() -> Pool {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute_batch(SCHEMA).unwrap();
+    Pool { conn: Arc::new(Mutex::new(conn)) }
+}
+
+/// Run a trivial query (health check)
+pub async fn ping(pool: &Pool) -> Result<(), DbError> {
+    pool.with(|conn| conn.query_row("SELECT 1", [], |_| Ok(())))
+}
+
+/// Steam IDs are stored as integers
+fn steam_id_int(steam_id: &str) -> i64 {
+    steam_id.parse().unwrap_or(0)
+}
+
+/// App IDs as a JSON array, for `appid IN (SELECT value FROM json_each(?))`
+fn appids_json(appids: &[u64]) -> String {
+    serde_json::to_string(appids).unwrap_or_else(|_| "[]".to_string())
+}
+
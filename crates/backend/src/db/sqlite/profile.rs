@@ -0,0 +1,85 @@
+//! Public profile customization database operations
+
+use overachiever_core::ProfileCustomization;
+use rusqlite::{params, OptionalExtension};
+use super::{steam_id_int, Pool};
+use crate::db::{DbError, CUSTOMIZATION_COLUMNS, CUSTOMIZATION_JOIN};
+
+/// Build a ProfileCustomization from a row selected with CUSTOMIZATION_COLUMNS
+pub(crate) fn customization_from_row(row: &rusqlite::Row) -> rusqlite::Result<ProfileCustomization> {
+    Ok(ProfileCustomization {
+        showcase_appid: row.get::<_, Option<i64>>("showcase_appid")?.map(|id| id as u64),
+        showcase_apiname: row.get("showcase_apiname")?,
+        banner_appid: row.get::<_, Option<i64>>("banner_appid")?.map(|id| id as u64),
+        accent_color: row.get("accent_color")?,
+        showcase_name: row.get("showcase_name")?,
+        showcase_icon: row.get("showcase_icon")?,
+        listed: row.get("listed")?,
+    })
+}
+
+/// Get a user's profile customization
+pub async fn get_profile_customization(pool: &Pool, steam_id: &str) -> Result<ProfileCustomization, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    pool.with(|conn| {
+        let customization = conn
+            .query_row(
+                &format!("SELECT {} FROM users u {} WHERE u.steam_id = ?1", CUSTOMIZATION_COLUMNS, CUSTOMIZATION_JOIN),
+                [steam_id_int],
+                customization_from_row,
+            )
+            .optional()?;
+        Ok(customization.unwrap_or_default())
+    })
+}
+
+/// Save a user's profile customization (display fields are ignored)
+pub async fn set_profile_customization(pool: &Pool, steam_id: &str, customization: &ProfileCustomization) -> Result<(), DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    let showcase_appid = customization.showcase_appid.map(|id| id as i64);
+    let banner_appid = customization.banner_appid.map(|id| id as i64);
+
+    pool.with(|conn| {
+        conn.execute(
+            r#"
+            UPDATE users SET
+                showcase_appid = ?2,
+                showcase_apiname = ?3,
+                banner_appid = ?4,
+                accent_color = ?5,
+                listed = ?6
+            WHERE steam_id = ?1
+            "#,
+            params![steam_id_int, showcase_appid, customization.showcase_apiname, banner_appid, customization.accent_color, customization.listed],
+        )?;
+        Ok(())
+    })
+}
+
+/// Check that a user has unlocked an achievement
+pub async fn user_has_unlocked(pool: &Pool, steam_id: &str, appid: u64, apiname: &str) -> Result<bool, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    pool.with(|conn| {
+        conn.query_row(
+            "SELECT 1 FROM user_achievements WHERE steam_id = ?1 AND appid = ?2 AND apiname = ?3 AND achieved = 1",
+            params![steam_id_int, appid as i64, apiname],
+            |_| Ok(()),
+        )
+        .optional()
+        .map(|row| row.is_some())
+    })
+}
+
+/// Check that a game is in a user's library
+pub async fn user_owns_game(pool: &Pool, steam_id: &str, appid: u64) -> Result<bool, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    pool.with(|conn| {
+        conn.query_row(
+            "SELECT 1 FROM user_games WHERE steam_id = ?1 AND appid = ?2",
+            params![steam_id_int, appid as i64],
+            |_| Ok(()),
+        )
+        .optional()
+        .map(|row| row.is_some())
+    })
+}
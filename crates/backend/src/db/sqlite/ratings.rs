@@ -0,0 +1,244 @@
+//! Game rating and achievement tip database operations
+
+use overachiever_core::{GameRating, AchievementTip, AchievementDifficulty, GameAchievementRatings};
+use chrono::{DateTime, Utc};
+use rusqlite::params;
+use super::{appids_json, steam_id_int, Pool};
+use crate::db::{DbError, RATING_VOTE_COLUMNS};
+
+/// Get community ratings for a game
+pub async fn get_community_ratings(
+    pool: &Pool,
+    appid: u64,
+) -> Result<Vec<GameRating>, DbError> {
+    pool.with(|conn| {
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT id, steam_id, appid, rating, comment, created_at, updated_at
+            FROM game_ratings
+            WHERE appid = ?1
+            ORDER BY created_at DESC
+            "#,
+        )?;
+        let ratings = stmt
+            .query_map([appid as i64], |row| {
+                Ok(GameRating {
+                    id: Some(row.get("id")?),
+                    steam_id: row.get::<_, i64>("steam_id")?.to_string(),
+                    appid: row.get::<_, i64>("appid")? as u64,
+                    rating: row.get::<_, i64>("rating")? as u8,
+                    comment: row.get("comment")?,
+                    created_at: row.get("created_at")?,
+                    updated_at: row.get("updated_at")?,
+                })
+            })?
+            .collect();
+        ratings
+    })
+}
+
+/// Upsert a game rating
+pub async fn upsert_rating(
+    pool: &Pool,
+    rating: &GameRating,
+) -> Result<(), DbError> {
+    let steam_id_int = steam_id_int(&rating.steam_id);
+    let now = Utc::now();
+
+    pool.with(|conn| {
+        conn.execute(
+            r#"
+            INSERT INTO game_ratings (steam_id, appid, rating, comment, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?5)
+            ON CONFLICT (steam_id, appid) DO UPDATE SET
+                rating = excluded.rating,
+                comment = excluded.comment,
+                updated_at = excluded.updated_at
+            "#,
+            params![steam_id_int, rating.appid as i64, rating.rating, rating.comment, now],
+        )?;
+        Ok(())
+    })
+}
+
+/// Get achievement tips
+pub async fn get_achievement_tips(
+    pool: &Pool,
+    appid: u64,
+    apiname: &str,
+) -> Result<Vec<AchievementTip>, DbError> {
+    pool.with(|conn| {
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT id, steam_id, appid, apiname, difficulty, tip, created_at
+            FROM achievement_tips
+            WHERE appid = ?1 AND apiname = ?2
+            ORDER BY created_at DESC
+            "#,
+        )?;
+        let tips = stmt
+            .query_map(params![appid as i64, apiname], |row| {
+                Ok(AchievementTip {
+                    id: Some(row.get("id")?),
+                    steam_id: row.get::<_, i64>("steam_id")?.to_string(),
+                    appid: row.get::<_, i64>("appid")? as u64,
+                    apiname: row.get("apiname")?,
+                    difficulty: row.get::<_, i64>("difficulty")? as u8,
+                    tip: row.get("tip")?,
+                    created_at: row.get("created_at")?,
+                })
+            })?
+            .collect();
+        tips
+    })
+}
+
+/// Upsert an achievement rating for a user
+pub async fn upsert_achievement_rating(
+    pool: &Pool,
+    steam_id: &str,
+    appid: u64,
+    apiname: &str,
+    rating: u8,
+) -> Result<(), DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    let now = Utc::now();
+
+    pool.with(|conn| {
+        conn.execute(
+            r#"
+            INSERT INTO achievement_ratings (steam_id, appid, apiname, rating, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?5)
+            ON CONFLICT (steam_id, appid, apiname)
+            DO UPDATE SET rating = excluded.rating, updated_at = excluded.updated_at
+            "#,
+            params![steam_id_int, appid as i64, apiname, rating, now],
+        )?;
+        Ok(())
+    })
+}
+
+/// Get all achievement ratings for a user
+pub async fn get_user_achievement_ratings(
+    pool: &Pool,
+    steam_id: &str,
+) -> Result<Vec<(u64, String, u8)>, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    pool.with(|conn| {
+        let mut stmt = conn.prepare("SELECT appid, apiname, rating FROM achievement_ratings WHERE steam_id = ?1")?;
+        let ratings = stmt
+            .query_map([steam_id_int], |row| {
+                Ok((row.get::<_, i64>("appid")? as u64, row.get("apiname")?, row.get::<_, i64>("rating")? as u8))
+            })?
+            .collect();
+        ratings
+    })
+}
+
+/// Bulk upsert achievement ratings in one transaction.
+/// When a rating already exists the one with the newer updated_at wins.
+/// Returns the number of ratings inserted or updated.
+pub async fn upsert_achievement_ratings_batch(
+    pool: &Pool,
+    steam_id: &str,
+    ratings: &[(u64, String, u8, DateTime<Utc>)],
+) -> Result<u64, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    pool.with(|conn| {
+        let tx = conn.transaction()?;
+        let mut applied = 0;
+        {
+            let mut stmt = tx.prepare(
+                r#"
+                INSERT INTO achievement_ratings (steam_id, appid, apiname, rating, created_at, updated_at)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?5)
+                ON CONFLICT (steam_id, appid, apiname)
+                DO UPDATE SET rating = excluded.rating, updated_at = excluded.updated_at
+                WHERE achievement_ratings.updated_at IS NULL OR achievement_ratings.updated_at < excluded.updated_at
+                "#,
+            )?;
+            for (appid, apiname, rating, updated_at) in ratings {
+                applied += stmt.execute(params![steam_id_int, *appid as i64, apiname, rating, updated_at])? as u64;
+            }
+        }
+        tx.commit()?;
+        Ok(applied)
+    })
+}
+
+/// Build an AchievementDifficulty from a row selected with RATING_VOTE_COLUMNS
+fn difficulty_from_row(row: &rusqlite::Row) -> rusqlite::Result<AchievementDifficulty> {
+    Ok(AchievementDifficulty {
+        apiname: row.get("apiname")?,
+        avg_rating: row.get::<_, f64>("avg_rating")? as f32,
+        rating_count: row.get("rating_count")?,
+        votes: [
+            row.get("votes_1")?,
+            row.get("votes_2")?,
+            row.get("votes_3")?,
+            row.get("votes_4")?,
+            row.get("votes_5")?,
+        ],
+    })
+}
+
+/// Get the community average difficulty of every rated achievement in a game
+pub async fn get_achievement_difficulty(
+    pool: &Pool,
+    appid: u64,
+) -> Result<Vec<AchievementDifficulty>, DbError> {
+    pool.with(|conn| {
+        let mut stmt = conn.prepare(&format!(
+            r#"
+            SELECT apiname, AVG(rating) AS avg_rating, COUNT(*) AS rating_count, {}
+            FROM achievement_ratings
+            WHERE appid = ?1
+            GROUP BY apiname
+            ORDER BY avg_rating ASC
+            "#,
+            RATING_VOTE_COLUMNS
+        ))?;
+        let curve = stmt.query_map([appid as i64], difficulty_from_row)?.collect();
+        curve
+    })
+}
+
+/// Get the community average difficulty of the rated achievements of several games.
+/// Every requested game is returned, games without ratings with an empty list.
+pub async fn get_achievement_difficulty_batch(
+    pool: &Pool,
+    appids: &[u64],
+) -> Result<Vec<GameAchievementRatings>, DbError> {
+    if appids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let rows: Vec<(u64, AchievementDifficulty)> = pool.with(|conn| {
+        let mut stmt = conn.prepare(&format!(
+            r#"
+            SELECT appid, apiname, AVG(rating) AS avg_rating, COUNT(*) AS rating_count, {}
+            FROM achievement_ratings
+            WHERE appid IN (SELECT value FROM json_each(?1))
+            GROUP BY appid, apiname
+            ORDER BY appid, avg_rating ASC
+            "#,
+            RATING_VOTE_COLUMNS
+        ))?;
+        let rows = stmt
+            .query_map([appids_json(appids)], |row| Ok((row.get::<_, i64>("appid")? as u64, difficulty_from_row(row)?)))?
+            .collect();
+        rows
+    })?;
+
+    let mut games: Vec<GameAchievementRatings> = appids
+        .iter()
+        .map(|&appid| GameAchievementRatings { appid, achievements: Vec::new() })
+        .collect();
+    for (appid, difficulty) in rows {
+        if let Some(game) = games.iter_mut().find(|g| g.appid == appid) {
+            game.achievements.push(difficulty);
+        }
+    }
+
+    Ok(games)
+}
@@ -0,0 +1,277 @@
+-- SQLite schema for self-hosted servers: the PostgreSQL migrations in one file.
+-- Timestamps are UTC text ("YYYY-MM-DD HH:MM:SS.fff+00:00"), booleans 0/1, arrays JSON.
+-- Community TTB averages are computed when games are read instead of by triggers.
+-- Applied on every start; changes to existing tables need an ALTER TABLE guarded by
+-- PRAGMA user_version in sqlite/mod.rs.
+
+CREATE TABLE IF NOT EXISTS users (
+    steam_id INTEGER PRIMARY KEY,
+    display_name TEXT NOT NULL,
+    avatar_url TEXT,
+    created_at TEXT,
+    last_seen TEXT,
+    short_id TEXT UNIQUE,
+    last_upload_bytes INTEGER,
+    last_upload_at TEXT,
+    showcase_appid INTEGER,
+    showcase_apiname TEXT,
+    banner_appid INTEGER,
+    accent_color TEXT,
+    listed INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE IF NOT EXISTS user_games (
+    steam_id INTEGER REFERENCES users(steam_id) ON DELETE CASCADE,
+    appid INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    playtime_forever INTEGER NOT NULL DEFAULT 0,
+    rtime_last_played INTEGER,
+    img_icon_url TEXT,
+    added_at TEXT,
+    achievements_total INTEGER,
+    achievements_unlocked INTEGER,
+    last_sync TEXT,
+    hidden INTEGER DEFAULT 0,
+    steam_hidden INTEGER DEFAULT 0,
+    PRIMARY KEY (steam_id, appid)
+);
+
+CREATE TABLE IF NOT EXISTS user_achievements (
+    steam_id INTEGER REFERENCES users(steam_id) ON DELETE CASCADE,
+    appid INTEGER NOT NULL,
+    apiname TEXT NOT NULL,
+    achieved INTEGER DEFAULT 0,
+    unlocktime TEXT,
+    is_game_finishing INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (steam_id, appid, apiname)
+);
+
+CREATE TABLE IF NOT EXISTS achievement_schemas (
+    appid INTEGER NOT NULL,
+    apiname TEXT NOT NULL,
+    display_name TEXT NOT NULL,
+    description TEXT,
+    icon TEXT NOT NULL,
+    icon_gray TEXT NOT NULL,
+    cached_at TEXT,
+    hidden INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (appid, apiname)
+);
+
+CREATE TABLE IF NOT EXISTS game_ratings (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    steam_id INTEGER REFERENCES users(steam_id) ON DELETE CASCADE,
+    appid INTEGER NOT NULL,
+    rating INTEGER CHECK (rating >= 1 AND rating <= 5),
+    comment TEXT,
+    created_at TEXT,
+    updated_at TEXT,
+    UNIQUE (steam_id, appid)
+);
+
+CREATE TABLE IF NOT EXISTS achievement_tips (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    steam_id INTEGER REFERENCES users(steam_id) ON DELETE CASCADE,
+    appid INTEGER NOT NULL,
+    apiname TEXT NOT NULL,
+    difficulty INTEGER CHECK (difficulty >= 1 AND difficulty <= 5),
+    tip TEXT NOT NULL,
+    created_at TEXT,
+    UNIQUE (steam_id, appid, apiname)
+);
+
+CREATE TABLE IF NOT EXISTS sync_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    steam_id INTEGER REFERENCES users(steam_id) ON DELETE CASCADE,
+    synced_at TEXT,
+    total_games INTEGER NOT NULL,
+    total_achievements INTEGER,
+    unlocked_achievements INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS run_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    steam_id INTEGER REFERENCES users(steam_id) ON DELETE CASCADE,
+    run_at TEXT,
+    total_games INTEGER NOT NULL,
+    unplayed_games INTEGER NOT NULL DEFAULT 0,
+    unplayed_games_total INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE IF NOT EXISTS achievement_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    steam_id INTEGER REFERENCES users(steam_id) ON DELETE CASCADE,
+    recorded_at TEXT,
+    total_achievements INTEGER NOT NULL,
+    unlocked_achievements INTEGER NOT NULL,
+    games_with_achievements INTEGER NOT NULL,
+    avg_completion_percent REAL NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS achievement_ratings (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    steam_id INTEGER REFERENCES users(steam_id) ON DELETE CASCADE,
+    appid INTEGER NOT NULL,
+    apiname TEXT NOT NULL,
+    rating INTEGER NOT NULL CHECK (rating >= 1 AND rating <= 5),
+    created_at TEXT,
+    updated_at TEXT,
+    UNIQUE (steam_id, appid, apiname)
+);
+
+CREATE TABLE IF NOT EXISTS app_size_on_disk (
+    appid INTEGER PRIMARY KEY,
+    size_bytes INTEGER NOT NULL,
+    reported_count INTEGER NOT NULL DEFAULT 1,
+    first_reported_at TEXT NOT NULL,
+    last_reported_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS api_request_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    endpoint TEXT NOT NULL,
+    client_ip TEXT,
+    user_agent TEXT,
+    referer TEXT,
+    query_params TEXT,
+    app_ids TEXT,
+    requested_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS ttb_times (
+    appid INTEGER PRIMARY KEY,
+    game_name TEXT NOT NULL,
+    main REAL,
+    main_extra REAL,
+    completionist REAL,
+    reported_count INTEGER NOT NULL DEFAULT 1,
+    first_reported_at TEXT NOT NULL,
+    last_reported_at TEXT NOT NULL,
+    submitted_by INTEGER,
+    reviewed_at TEXT,
+    approved_main REAL,
+    approved_main_extra REAL,
+    approved_completionist REAL,
+    approved_at TEXT
+);
+
+CREATE TABLE IF NOT EXISTS ttb_blacklist (
+    appid INTEGER PRIMARY KEY,
+    game_name TEXT NOT NULL,
+    reason TEXT,
+    added_by_steam_id INTEGER NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS game_tags (
+    appid INTEGER NOT NULL,
+    tag_name TEXT NOT NULL,
+    vote_count INTEGER NOT NULL,
+    updated_at TEXT NOT NULL,
+    submitted_by INTEGER,
+    reviewed_at TEXT,
+    approved_vote_count INTEGER,
+    approved_at TEXT,
+    PRIMARY KEY (appid, tag_name)
+);
+
+CREATE TABLE IF NOT EXISTS user_ttb_reports (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    steam_id INTEGER REFERENCES users(steam_id) ON DELETE CASCADE,
+    appid INTEGER NOT NULL,
+    main_seconds INTEGER,
+    extra_seconds INTEGER,
+    completionist_seconds INTEGER,
+    reported_at TEXT NOT NULL,
+    review_status TEXT NOT NULL DEFAULT 'approved' CHECK (review_status IN ('approved', 'pending', 'rejected')),
+    review_reason TEXT,
+    reviewed_at TEXT,
+    reviewed_by INTEGER,
+    UNIQUE (steam_id, appid)
+);
+
+CREATE TABLE IF NOT EXISTS achievement_comments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    steam_id INTEGER NOT NULL REFERENCES users(steam_id) ON DELETE CASCADE,
+    appid INTEGER NOT NULL,
+    apiname TEXT NOT NULL,
+    comment TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    deleted_at TEXT,
+    deleted_by INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS comment_reports (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    comment_id INTEGER NOT NULL REFERENCES achievement_comments(id) ON DELETE CASCADE,
+    reported_by INTEGER NOT NULL REFERENCES users(steam_id) ON DELETE CASCADE,
+    reason TEXT,
+    created_at TEXT NOT NULL,
+    resolved_at TEXT,
+    UNIQUE (comment_id, reported_by)
+);
+
+CREATE TABLE IF NOT EXISTS user_goals (
+    steam_id INTEGER NOT NULL REFERENCES users(steam_id) ON DELETE CASCADE,
+    created_at TEXT NOT NULL,
+    goal TEXT NOT NULL,
+    PRIMARY KEY (steam_id, created_at)
+);
+
+CREATE TABLE IF NOT EXISTS api_keys (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    steam_id INTEGER NOT NULL REFERENCES users(steam_id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    key_prefix TEXT NOT NULL,
+    key_hash TEXT NOT NULL UNIQUE,
+    -- JSON array of scope names
+    scopes TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    last_used_at TEXT,
+    revoked_at TEXT
+);
+
+CREATE TABLE IF NOT EXISTS tag_mappings (
+    tag_name TEXT PRIMARY KEY,
+    target TEXT NOT NULL,
+    kind TEXT NOT NULL CHECK (kind IN ('alias', 'parent')),
+    created_by INTEGER,
+    updated_at TEXT NOT NULL,
+    CHECK (tag_name <> target)
+);
+
+CREATE TABLE IF NOT EXISTS email_digests (
+    steam_id INTEGER PRIMARY KEY REFERENCES users(steam_id) ON DELETE CASCADE,
+    email TEXT NOT NULL,
+    enabled INTEGER NOT NULL DEFAULT 1,
+    unsubscribe_token TEXT NOT NULL UNIQUE,
+    next_send_at TEXT NOT NULL,
+    last_sent_at TEXT,
+    created_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS achievement_rarity (
+    appid INTEGER NOT NULL,
+    apiname TEXT NOT NULL,
+    recorded_on TEXT NOT NULL,
+    percent REAL NOT NULL,
+    PRIMARY KEY (appid, apiname, recorded_on)
+);
+
+CREATE TABLE IF NOT EXISTS rarity_fetches (
+    appid INTEGER PRIMARY KEY,
+    fetched_on TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_games_appid ON user_games(appid);
+CREATE INDEX IF NOT EXISTS idx_achievement_ratings_appid_apiname ON achievement_ratings(appid, apiname);
+CREATE INDEX IF NOT EXISTS idx_run_history_steam_id ON run_history(steam_id);
+CREATE INDEX IF NOT EXISTS idx_users_listed_name ON users(display_name, steam_id) WHERE listed;
+CREATE INDEX IF NOT EXISTS idx_users_listed_last_seen ON users(last_seen DESC, steam_id DESC) WHERE listed;
+CREATE INDEX IF NOT EXISTS idx_achievement_history_steam_id ON achievement_history(steam_id);
+CREATE INDEX IF NOT EXISTS idx_game_tags_tag_name ON game_tags(tag_name);
+CREATE INDEX IF NOT EXISTS idx_user_ttb_reports_appid ON user_ttb_reports(appid);
+CREATE INDEX IF NOT EXISTS idx_achievement_comments_game ON achievement_comments(appid) WHERE deleted_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_comment_reports_pending ON comment_reports(comment_id) WHERE resolved_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_api_keys_user ON api_keys(steam_id) WHERE revoked_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_email_digests_due ON email_digests(next_send_at) WHERE enabled;
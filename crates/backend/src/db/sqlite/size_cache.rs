@@ -0,0 +1,56 @@
+//! Size on disk cache database operations
+
+use chrono::Utc;
+use rusqlite::params;
+use super::{appids_json, Pool};
+use crate::db::{AppSizeInfo, DbError};
+
+/// Upsert app size data (updates if exists, inserts if not)
+pub async fn upsert_app_sizes(pool: &Pool, sizes: &[AppSizeInfo]) -> Result<usize, DbError> {
+    if sizes.is_empty() {
+        return Ok(0);
+    }
+
+    let now = Utc::now();
+    pool.with(|conn| {
+        let tx = conn.transaction()?;
+        let mut count = 0;
+        {
+            let mut stmt = tx.prepare(
+                r#"
+                INSERT INTO app_size_on_disk (appid, size_bytes, reported_count, first_reported_at, last_reported_at)
+                VALUES (?1, ?2, 1, ?3, ?3)
+                ON CONFLICT (appid) DO UPDATE SET
+                    size_bytes = excluded.size_bytes,
+                    reported_count = app_size_on_disk.reported_count + 1,
+                    last_reported_at = excluded.last_reported_at
+                "#,
+            )?;
+            for size in sizes {
+                count += stmt.execute(params![size.appid as i64, size.size_bytes as i64, now])?;
+            }
+        }
+        tx.commit()?;
+        Ok(count)
+    })
+}
+
+/// Get sizes for a list of app IDs
+pub async fn get_app_sizes(pool: &Pool, appids: &[u64]) -> Result<Vec<AppSizeInfo>, DbError> {
+    if appids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    pool.with(|conn| {
+        let mut stmt = conn.prepare("SELECT appid, size_bytes FROM app_size_on_disk WHERE appid IN (SELECT value FROM json_each(?1))")?;
+        let sizes = stmt
+            .query_map([appids_json(appids)], |row| {
+                Ok(AppSizeInfo {
+                    appid: row.get::<_, i64>("appid")? as u64,
+                    size_bytes: row.get::<_, i64>("size_bytes")? as u64,
+                })
+            })?
+            .collect();
+        sizes
+    })
+}
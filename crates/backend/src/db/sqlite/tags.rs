@@ -0,0 +1,142 @@
+//! Game tags database operations
+
+use chrono::Utc;
+use overachiever_core::TagMapping;
+use rusqlite::params;
+use super::{appids_json, Pool};
+use crate::db::{mapping_kind_from_sql, mapping_kind_to_sql, DbError};
+
+fn tag_from_row(r: &rusqlite::Row) -> rusqlite::Result<overachiever_core::GameTag> {
+    Ok(overachiever_core::GameTag {
+        appid: r.get::<_, i64>("appid")? as u64,
+        tag_name: r.get("tag_name")?,
+        vote_count: r.get::<_, i64>("vote_count")? as u32,
+    })
+}
+
+/// Get all unique tag names (for dropdown filter), aliases left out
+pub async fn get_all_tag_names(pool: &Pool) -> Result<Vec<String>, DbError> {
+    pool.with(|conn| {
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT DISTINCT tag_name FROM game_tags
+            WHERE tag_name NOT IN (SELECT tag_name FROM tag_mappings WHERE kind = 'alias')
+            ORDER BY tag_name
+            "#,
+        )?;
+        let tags = stmt.query_map([], |r| r.get(0))?.collect();
+        tags
+    })
+}
+
+/// Get tags for a list of games
+pub async fn get_tags_for_games(pool: &Pool, appids: &[u64]) -> Result<Vec<overachiever_core::GameTag>, DbError> {
+    if appids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    pool.with(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT appid, tag_name, vote_count FROM game_tags WHERE appid IN (SELECT value FROM json_each(?1)) ORDER BY appid, tag_name",
+        )?;
+        let tags = stmt.query_map([appids_json(appids)], tag_from_row)?.collect();
+        tags
+    })
+}
+
+/// Get tags for a single game
+pub async fn get_tags_for_game(pool: &Pool, appid: u64) -> Result<Vec<overachiever_core::GameTag>, DbError> {
+    pool.with(|conn| {
+        let mut stmt = conn.prepare("SELECT appid, tag_name, vote_count FROM game_tags WHERE appid = ?1 ORDER BY vote_count DESC")?;
+        let tags = stmt.query_map([appid as i64], tag_from_row)?.collect();
+        tags
+    })
+}
+
+/// Upsert tags for a game (from SteamSpy)
+/// New tags go into the admin review queue unless submitted by an admin.
+pub async fn upsert_game_tags(
+    pool: &Pool,
+    appid: u64,
+    tags: &[(String, u32)], // (tag_name, vote_count)
+    submitted_by: &str,
+    reviewed: bool,
+) -> Result<usize, DbError> {
+    if tags.is_empty() {
+        return Ok(0);
+    }
+
+    let steam_id_int: i64 = submitted_by.parse().unwrap_or(0);
+    let now = Utc::now();
+    let reviewed_at = reviewed.then_some(now);
+
+    pool.with(|conn| {
+        let tx = conn.transaction()?;
+        {
+            let mut stmt = tx.prepare(
+                r#"
+                INSERT INTO game_tags (appid, tag_name, vote_count, updated_at, submitted_by, reviewed_at, approved_vote_count, approved_at)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, CASE WHEN ?6 IS NOT NULL THEN ?3 END, ?6)
+                ON CONFLICT (appid, tag_name) DO UPDATE SET
+                    vote_count = excluded.vote_count,
+                    updated_at = excluded.updated_at,
+                    reviewed_at = CASE WHEN ?6 IS NOT NULL THEN COALESCE(game_tags.reviewed_at, ?6) ELSE game_tags.reviewed_at END,
+                    approved_vote_count = CASE WHEN ?6 IS NOT NULL THEN excluded.vote_count ELSE game_tags.approved_vote_count END,
+                    approved_at = COALESCE(?6, game_tags.approved_at)
+                "#,
+            )?;
+            for (tag_name, vote_count) in tags {
+                stmt.execute(params![appid as i64, tag_name, vote_count, now, steam_id_int, reviewed_at])?;
+            }
+        }
+        tx.commit()?;
+        Ok(tags.len())
+    })
+}
+
+/// Get all tag aliases and parents
+pub async fn get_tag_mappings(pool: &Pool) -> Result<Vec<TagMapping>, DbError> {
+    let rows: Vec<(String, String, String)> = pool.with(|conn| {
+        let mut stmt = conn.prepare("SELECT tag_name, target, kind FROM tag_mappings ORDER BY tag_name")?;
+        let rows = stmt.query_map([], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)))?.collect();
+        rows
+    })?;
+
+    Ok(rows.into_iter().filter_map(|(tag, target, kind)| {
+        Some(TagMapping {
+            tag,
+            target,
+            kind: mapping_kind_from_sql(&kind)?,
+        })
+    }).collect())
+}
+
+/// Add or replace the mapping of a tag
+pub async fn upsert_tag_mapping(pool: &Pool, mapping: &TagMapping, created_by: &str) -> Result<(), DbError> {
+    let steam_id_int: i64 = created_by.parse().unwrap_or(0);
+    let now = Utc::now();
+
+    pool.with(|conn| {
+        conn.execute(
+            r#"
+            INSERT INTO tag_mappings (tag_name, target, kind, created_by, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT (tag_name) DO UPDATE SET
+                target = excluded.target,
+                kind = excluded.kind,
+                created_by = excluded.created_by,
+                updated_at = excluded.updated_at
+            "#,
+            params![mapping.tag, mapping.target, mapping_kind_to_sql(mapping.kind), steam_id_int, now],
+        )?;
+        Ok(())
+    })
+}
+
+/// Remove the mapping of a tag, returns whether one existed
+pub async fn delete_tag_mapping(pool: &Pool, tag_name: &str) -> Result<bool, DbError> {
+    pool.with(|conn| {
+        let rows_affected = conn.execute("DELETE FROM tag_mappings WHERE tag_name = ?1", [tag_name])?;
+        Ok(rows_affected > 0)
+    })
+}
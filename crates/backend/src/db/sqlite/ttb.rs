@@ -0,0 +1,261 @@
+//! Time to beat (TTB) database operations
+
+use chrono::Utc;
+use rusqlite::{params, OptionalExtension};
+use super::{appids_json, steam_id_int, Pool};
+use crate::db::{ttb_outlier_reason, DbError, TtbReportStatus};
+
+/// Upsert TTB times for a game (from desktop scraper).
+/// Changed times go back into the admin review queue unless submitted by an admin;
+/// the last approved times are kept so a rejection can restore them.
+#[allow(clippy::too_many_arguments)]
+pub async fn upsert_ttb_times(
+    pool: &Pool,
+    appid: u64,
+    game_name: &str,
+    main: Option<f32>,
+    main_extra: Option<f32>,
+    completionist: Option<f32>,
+    submitted_by: &str,
+    reviewed: bool,
+) -> Result<(), DbError> {
+    let steam_id_int = steam_id_int(submitted_by);
+    let now = Utc::now();
+
+    pool.with(|conn| {
+        conn.execute(
+            r#"
+            INSERT INTO ttb_times (appid, game_name, main, main_extra, completionist, reported_count, first_reported_at, last_reported_at, submitted_by, reviewed_at,
+                                   approved_main, approved_main_extra, approved_completionist, approved_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, 1, ?8, ?8, ?6, CASE WHEN ?7 THEN ?8 END,
+                    CASE WHEN ?7 THEN ?3 END, CASE WHEN ?7 THEN ?4 END, CASE WHEN ?7 THEN ?5 END, CASE WHEN ?7 THEN ?8 END)
+            ON CONFLICT (appid) DO UPDATE SET
+                game_name = excluded.game_name,
+                main = COALESCE(excluded.main, ttb_times.main),
+                main_extra = COALESCE(excluded.main_extra, ttb_times.main_extra),
+                completionist = COALESCE(excluded.completionist, ttb_times.completionist),
+                reported_count = ttb_times.reported_count + 1,
+                last_reported_at = ?8,
+                submitted_by = excluded.submitted_by,
+                reviewed_at = CASE
+                    WHEN ?7 THEN ?8
+                    WHEN ttb_times.main IS NOT COALESCE(excluded.main, ttb_times.main)
+                      OR ttb_times.main_extra IS NOT COALESCE(excluded.main_extra, ttb_times.main_extra)
+                      OR ttb_times.completionist IS NOT COALESCE(excluded.completionist, ttb_times.completionist)
+                        THEN NULL
+                    ELSE ttb_times.reviewed_at
+                END,
+                approved_main = CASE WHEN ?7 THEN COALESCE(excluded.main, ttb_times.main) ELSE ttb_times.approved_main END,
+                approved_main_extra = CASE WHEN ?7 THEN COALESCE(excluded.main_extra, ttb_times.main_extra) ELSE ttb_times.approved_main_extra END,
+                approved_completionist = CASE WHEN ?7 THEN COALESCE(excluded.completionist, ttb_times.completionist) ELSE ttb_times.approved_completionist END,
+                approved_at = CASE WHEN ?7 THEN ?8 ELSE ttb_times.approved_at END
+            "#,
+            params![appid as i64, game_name, main, main_extra, completionist, steam_id_int, reviewed, now],
+        )?;
+        Ok(())
+    })
+}
+
+fn ttb_times_from_row(r: &rusqlite::Row) -> rusqlite::Result<overachiever_core::TtbTimes> {
+    Ok(overachiever_core::TtbTimes {
+        appid: r.get::<_, i64>("appid")? as u64,
+        main: r.get::<_, Option<f64>>("main")?.map(|h| h as f32),
+        main_extra: r.get::<_, Option<f64>>("main_extra")?.map(|h| h as f32),
+        completionist: r.get::<_, Option<f64>>("completionist")?.map(|h| h as f32),
+        updated_at: r.get("last_reported_at")?,
+    })
+}
+
+/// Get TTB times for a single game
+pub async fn get_ttb_times(pool: &Pool, appid: u64) -> Result<Option<overachiever_core::TtbTimes>, DbError> {
+    pool.with(|conn| {
+        conn.query_row(
+            "SELECT appid, main, main_extra, completionist, last_reported_at FROM ttb_times WHERE appid = ?1",
+            [appid as i64],
+            ttb_times_from_row,
+        )
+        .optional()
+    })
+}
+
+/// Get TTB times for multiple games
+pub async fn get_ttb_times_batch(pool: &Pool, appids: &[u64]) -> Result<Vec<overachiever_core::TtbTimes>, DbError> {
+    if appids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    pool.with(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT appid, main, main_extra, completionist, last_reported_at FROM ttb_times WHERE appid IN (SELECT value FROM json_each(?1)) ORDER BY appid",
+        )?;
+        let times = stmt.query_map([appids_json(appids)], ttb_times_from_row)?.collect();
+        times
+    })
+}
+
+/// Add a game to the TTB blacklist
+pub async fn add_to_ttb_blacklist(
+    pool: &Pool,
+    appid: u64,
+    game_name: &str,
+    reason: Option<&str>,
+    added_by_steam_id: &str,
+) -> Result<(), DbError> {
+    let steam_id_int = steam_id_int(added_by_steam_id);
+    let now = Utc::now();
+
+    pool.with(|conn| {
+        conn.execute(
+            r#"
+            INSERT INTO ttb_blacklist (appid, game_name, reason, added_by_steam_id, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT (appid) DO UPDATE SET
+                game_name = excluded.game_name,
+                reason = excluded.reason,
+                added_by_steam_id = excluded.added_by_steam_id,
+                created_at = excluded.created_at
+            "#,
+            params![appid as i64, game_name, reason, steam_id_int, now],
+        )?;
+        Ok(())
+    })
+}
+
+/// Remove a game from the TTB blacklist
+pub async fn remove_from_ttb_blacklist(pool: &Pool, appid: u64) -> Result<bool, DbError> {
+    pool.with(|conn| {
+        let rows_affected = conn.execute("DELETE FROM ttb_blacklist WHERE appid = ?1", [appid as i64])?;
+        Ok(rows_affected > 0)
+    })
+}
+
+/// Get all games in the TTB blacklist (returns list of appids)
+pub async fn get_ttb_blacklist(pool: &Pool) -> Result<Vec<u64>, DbError> {
+    pool.with(|conn| {
+        let mut stmt = conn.prepare("SELECT appid FROM ttb_blacklist ORDER BY created_at DESC")?;
+        let appids = stmt.query_map([], |r| Ok(r.get::<_, i64>(0)? as u64))?.collect();
+        appids
+    })
+}
+
+/// Get ALL TTB times from the database
+pub async fn get_all_ttb_times(pool: &Pool) -> Result<Vec<overachiever_core::TtbTimes>, DbError> {
+    pool.with(|conn| {
+        let mut stmt = conn.prepare("SELECT appid, main, main_extra, completionist, last_reported_at FROM ttb_times")?;
+        let times = stmt.query_map([], ttb_times_from_row)?.collect();
+        times
+    })
+}
+
+/// Check if a game is in the TTB blacklist
+#[allow(dead_code)]
+pub async fn is_in_ttb_blacklist(pool: &Pool, appid: u64) -> Result<bool, DbError> {
+    pool.with(|conn| {
+        conn.query_row("SELECT 1 FROM ttb_blacklist WHERE appid = ?1", [appid as i64], |_| Ok(()))
+            .optional()
+            .map(|row| row.is_some())
+    })
+}
+
+/// Count, mean and sample standard deviation of one report column, from
+/// `COUNT(x)`, `AVG(x)` and `SUM(x * x)` (SQLite has no STDDEV_SAMP)
+fn column_stats(row: &rusqlite::Row, column: &str) -> rusqlite::Result<(i64, Option<f64>, Option<f64>)> {
+    let count: i64 = row.get(format!("{}_count", column).as_str())?;
+    let mean: Option<f64> = row.get(format!("{}_mean", column).as_str())?;
+    let sum_squares: Option<f64> = row.get(format!("{}_squares", column).as_str())?;
+    let stddev = match (mean, sum_squares) {
+        (Some(mean), Some(sum_squares)) if count > 1 => {
+            let n = count as f64;
+            Some(((sum_squares - n * mean * mean) / (n - 1.0)).max(0.0).sqrt())
+        }
+        _ => None,
+    };
+    Ok((count, mean, stddev))
+}
+
+/// Report user's TTB times for a game (validate with `validate_ttb_report` first).
+/// Reports far off the existing data wait for admin review; admins' reports are always approved.
+pub async fn report_ttb(
+    pool: &Pool,
+    steam_id: &str,
+    appid: u64,
+    main_seconds: Option<i32>,
+    extra_seconds: Option<i32>,
+    completionist_seconds: Option<i32>,
+    trusted: bool,
+) -> Result<TtbReportStatus, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    let now = Utc::now();
+
+    pool.with(|conn| {
+        let status = if trusted {
+            TtbReportStatus::Approved
+        } else {
+            // Approved reports of other users and the reviewed HLTB times
+            let stats = conn.query_row(
+                r#"
+                SELECT
+                    COUNT(r.main_seconds) AS main_count,
+                    AVG(r.main_seconds) AS main_mean,
+                    SUM(CAST(r.main_seconds AS REAL) * r.main_seconds) AS main_squares,
+                    COUNT(r.extra_seconds) AS extra_count,
+                    AVG(r.extra_seconds) AS extra_mean,
+                    SUM(CAST(r.extra_seconds AS REAL) * r.extra_seconds) AS extra_squares,
+                    COUNT(r.completionist_seconds) AS completionist_count,
+                    AVG(r.completionist_seconds) AS completionist_mean,
+                    SUM(CAST(r.completionist_seconds AS REAL) * r.completionist_seconds) AS completionist_squares,
+                    (SELECT main FROM ttb_times WHERE appid = ?1 AND reviewed_at IS NOT NULL) AS hltb_main,
+                    (SELECT main_extra FROM ttb_times WHERE appid = ?1 AND reviewed_at IS NOT NULL) AS hltb_extra,
+                    (SELECT completionist FROM ttb_times WHERE appid = ?1 AND reviewed_at IS NOT NULL) AS hltb_completionist
+                FROM user_ttb_reports r
+                WHERE r.appid = ?1 AND r.steam_id <> ?2 AND r.review_status = 'approved'
+                "#,
+                params![appid as i64, steam_id_int],
+                |row| {
+                    let mut reasons = Vec::new();
+                    for (label, seconds, column) in [
+                        ("main", main_seconds, "main"),
+                        ("main + extra", extra_seconds, "extra"),
+                        ("completionist", completionist_seconds, "completionist"),
+                    ] {
+                        let Some(seconds) = seconds else { continue };
+                        let (count, mean, stddev) = column_stats(row, column)?;
+                        let hltb: Option<f64> = row.get(format!("hltb_{}", column).as_str())?;
+                        if let Some(reason) = ttb_outlier_reason(label, seconds, count, mean, stddev, hltb.map(|h| h as f32)) {
+                            reasons.push(reason);
+                        }
+                    }
+                    Ok(reasons)
+                },
+            )?;
+
+            if stats.is_empty() {
+                TtbReportStatus::Approved
+            } else {
+                TtbReportStatus::Pending(stats.join("; "))
+            }
+        };
+
+        let (review_status, review_reason) = status.review_columns();
+
+        // Community averages are computed when games are read, so nothing else to update
+        conn.execute(
+            r#"
+            INSERT INTO user_ttb_reports (steam_id, appid, main_seconds, extra_seconds, completionist_seconds, reported_at, review_status, review_reason)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            ON CONFLICT (steam_id, appid) DO UPDATE SET
+                main_seconds = excluded.main_seconds,
+                extra_seconds = excluded.extra_seconds,
+                completionist_seconds = excluded.completionist_seconds,
+                reported_at = excluded.reported_at,
+                review_status = excluded.review_status,
+                review_reason = excluded.review_reason,
+                reviewed_at = NULL,
+                reviewed_by = NULL
+            "#,
+            params![steam_id_int, appid as i64, main_seconds, extra_seconds, completionist_seconds, now, review_status, review_reason],
+        )?;
+
+        Ok(status)
+    })
+}
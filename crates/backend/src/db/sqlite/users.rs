@@ -0,0 +1,135 @@
+//! User-related database operations
+
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension};
+use super::{steam_id_int, Pool};
+use overachiever_core::UserPage;
+use crate::db::{contains_pattern, generate_short_id, page_of, DbError, UserSort, CUSTOMIZATION_COLUMNS, CUSTOMIZATION_JOIN};
+use crate::db::customization_from_row;
+
+/// Generate a unique short_id by checking for collisions
+fn generate_unique_short_id(conn: &Connection) -> rusqlite::Result<String> {
+    loop {
+        let short_id = generate_short_id();
+        let exists = conn
+            .query_row("SELECT 1 FROM users WHERE short_id = ?1", [&short_id], |_| Ok(()))
+            .optional()?;
+        if exists.is_none() {
+            return Ok(short_id);
+        }
+    }
+}
+
+fn profile_from_row(row: &rusqlite::Row) -> rusqlite::Result<overachiever_core::UserProfile> {
+    Ok(overachiever_core::UserProfile {
+        steam_id: row.get::<_, i64>("steam_id")?.to_string(),
+        display_name: row.get("display_name")?,
+        avatar_url: row.get("avatar_url")?,
+        short_id: row.get("short_id")?,
+        customization: customization_from_row(row)?,
+    })
+}
+
+/// Get user info (steam_id, display_name, avatar_url) by short_id
+pub async fn get_user_by_short_id(pool: &Pool, short_id: &str) -> Result<Option<overachiever_core::UserProfile>, DbError> {
+    pool.with(|conn| {
+        conn.query_row(
+            &format!(
+                "SELECT u.steam_id, u.display_name, u.avatar_url, u.short_id, {} FROM users u {} WHERE u.short_id = ?1",
+                CUSTOMIZATION_COLUMNS, CUSTOMIZATION_JOIN
+            ),
+            [short_id],
+            profile_from_row,
+        )
+        .optional()
+    })
+}
+
+/// Get or create user, returns short_id
+pub async fn get_or_create_user(
+    pool: &Pool,
+    steam_id: &str,
+    display_name: &str,
+    avatar_url: Option<&str>,
+) -> Result<String, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    let now = Utc::now();
+
+    pool.with(|conn| {
+        let existing: Option<Option<String>> = conn
+            .query_row("SELECT short_id FROM users WHERE steam_id = ?1", [steam_id_int], |row| row.get(0))
+            .optional()?;
+
+        if let Some(short_id) = existing {
+            conn.execute(
+                "UPDATE users SET display_name = ?2, avatar_url = ?3, last_seen = ?4 WHERE steam_id = ?1",
+                params![steam_id_int, display_name, avatar_url, now],
+            )?;
+            if let Some(short_id) = short_id {
+                return Ok(short_id);
+            }
+            let short_id = generate_unique_short_id(conn)?;
+            conn.execute("UPDATE users SET short_id = ?2 WHERE steam_id = ?1", params![steam_id_int, short_id])?;
+            return Ok(short_id);
+        }
+
+        let short_id = generate_unique_short_id(conn)?;
+        conn.execute(
+            r#"
+            INSERT INTO users (steam_id, display_name, avatar_url, short_id, created_at, last_seen)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?5)
+            "#,
+            params![steam_id_int, display_name, avatar_url, short_id, now],
+        )?;
+        Ok(short_id)
+    })
+}
+
+/// One page of the users who opted into the public directory, optionally only those whose
+/// display name contains `search`. `cursor` is the steam_id of the last user of the previous page.
+pub async fn list_users(
+    pool: &Pool,
+    search: Option<&str>,
+    sort: UserSort,
+    cursor: Option<i64>,
+    limit: i64,
+) -> Result<UserPage, DbError> {
+    let (sort_key, after, order) = match sort {
+        UserSort::Name => ("u.display_name", ">", "ASC"),
+        UserSort::LastSeen => ("COALESCE(u.last_seen, '')", "<", "DESC"),
+    };
+    let pattern = search.map(contains_pattern);
+
+    pool.with(|conn| {
+        // One extra row tells whether there is a next page
+        let mut stmt = conn.prepare(&format!(
+            r#"
+            SELECT u.steam_id, u.display_name, u.avatar_url, u.short_id, {columns}
+            FROM users u
+            {join}
+            WHERE u.short_id IS NOT NULL AND u.listed
+              AND (?1 IS NULL OR u.display_name LIKE ?1 ESCAPE '\')
+              AND (?2 IS NULL OR ({key}, u.steam_id) {after} (SELECT {key}, u.steam_id FROM users u WHERE u.steam_id = ?2))
+            ORDER BY {key} {order}, u.steam_id {order}
+            LIMIT ?3
+            "#,
+            columns = CUSTOMIZATION_COLUMNS, join = CUSTOMIZATION_JOIN, key = sort_key, after = after, order = order
+        ))?;
+        let users = stmt.query_map(params![pattern, cursor, limit + 1], profile_from_row)?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(page_of(users, limit))
+    })
+}
+
+/// Display name and avatar of a user, as shown to others (presence)
+pub async fn get_user_display(pool: &Pool, steam_id: &str) -> Result<Option<(String, Option<String>)>, DbError> {
+    let steam_id_int = steam_id_int(steam_id);
+    pool.with(|conn| {
+        conn.query_row(
+            "SELECT display_name, avatar_url FROM users WHERE steam_id = ?1",
+            [steam_id_int],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()
+    })
+}
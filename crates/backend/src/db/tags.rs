@@ -1,14 +1,19 @@
 //! Game tags database operations
 
 use deadpool_postgres::Pool;
-use crate::db::DbError;
+use overachiever_core::TagMapping;
+use crate::db::{mapping_kind_from_sql, mapping_kind_to_sql, DbError};
 
-/// Get all unique tag names (for dropdown filter)
+/// Get all unique tag names (for dropdown filter), aliases left out
 pub async fn get_all_tag_names(pool: &Pool) -> Result<Vec<String>, DbError> {
     let client = pool.get().await?;
 
     let rows = client.query(
-        "SELECT DISTINCT tag_name FROM game_tags ORDER BY tag_name",
+        r#"
+        SELECT DISTINCT tag_name FROM game_tags
+        WHERE tag_name NOT IN (SELECT tag_name FROM tag_mappings WHERE kind = 'alias')
+        ORDER BY tag_name
+        "#,
         &[]
     ).await?;
 
@@ -29,7 +34,7 @@ pub async fn get_tags_for_games(pool: &Pool, appids: &[u64]) -> Result<Vec<overa
     let appids_i64: Vec<i64> = appids.iter().map(|&id| id as i64).collect();
 
     let rows = client.query(
-        "SELECT appid, tag_name, vote_count FROM game_tags WHERE appid = ANY($1)",
+        "SELECT appid, tag_name, vote_count FROM game_tags WHERE appid = ANY($1) ORDER BY appid, tag_name",
         &[&appids_i64]
     ).await?;
 
@@ -61,31 +66,89 @@ pub async fn get_tags_for_game(pool: &Pool, appid: u64) -> Result<Vec<overachiev
 }
 
 /// Upsert tags for a game (from SteamSpy)
+/// New tags go into the admin review queue unless submitted by an admin.
 pub async fn upsert_game_tags(
     pool: &Pool,
     appid: u64,
     tags: &[(String, u32)], // (tag_name, vote_count)
+    submitted_by: &str,
+    reviewed: bool,
 ) -> Result<usize, DbError> {
     if tags.is_empty() {
         return Ok(0);
     }
 
     let client = pool.get().await?;
+    let steam_id_int: i64 = submitted_by.parse().unwrap_or(0);
     let mut count = 0;
 
     for (tag_name, vote_count) in tags {
         client.execute(
             r#"
-            INSERT INTO game_tags (appid, tag_name, vote_count, updated_at)
-            VALUES ($1, $2, $3, NOW())
+            INSERT INTO game_tags (appid, tag_name, vote_count, updated_at, submitted_by, reviewed_at, approved_vote_count, approved_at)
+            VALUES ($1, $2, $3, NOW(), $4, CASE WHEN $5 THEN NOW() END, CASE WHEN $5 THEN $3 END, CASE WHEN $5 THEN NOW() END)
             ON CONFLICT (appid, tag_name) DO UPDATE SET
                 vote_count = EXCLUDED.vote_count,
-                updated_at = NOW()
+                updated_at = NOW(),
+                reviewed_at = CASE WHEN $5 THEN COALESCE(game_tags.reviewed_at, NOW()) ELSE game_tags.reviewed_at END,
+                approved_vote_count = CASE WHEN $5 THEN EXCLUDED.vote_count ELSE game_tags.approved_vote_count END,
+                approved_at = CASE WHEN $5 THEN NOW() ELSE game_tags.approved_at END
             "#,
-            &[&(appid as i64), tag_name, &(*vote_count as i32)]
+            &[&(appid as i64), tag_name, &(*vote_count as i32), &steam_id_int, &reviewed]
         ).await?;
         count += 1;
     }
 
     Ok(count)
 }
+
+/// Get all tag aliases and parents
+pub async fn get_tag_mappings(pool: &Pool) -> Result<Vec<TagMapping>, DbError> {
+    let client = pool.get().await?;
+
+    let rows = client.query(
+        "SELECT tag_name, target, kind FROM tag_mappings ORDER BY tag_name",
+        &[]
+    ).await?;
+
+    Ok(rows.into_iter().filter_map(|r| {
+        Some(TagMapping {
+            tag: r.get("tag_name"),
+            target: r.get("target"),
+            kind: mapping_kind_from_sql(r.get("kind"))?,
+        })
+    }).collect())
+}
+
+/// Add or replace the mapping of a tag
+pub async fn upsert_tag_mapping(pool: &Pool, mapping: &TagMapping, created_by: &str) -> Result<(), DbError> {
+    let client = pool.get().await?;
+    let steam_id_int: i64 = created_by.parse().unwrap_or(0);
+
+    client.execute(
+        r#"
+        INSERT INTO tag_mappings (tag_name, target, kind, created_by, updated_at)
+        VALUES ($1, $2, $3, $4, NOW())
+        ON CONFLICT (tag_name) DO UPDATE SET
+            target = EXCLUDED.target,
+            kind = EXCLUDED.kind,
+            created_by = EXCLUDED.created_by,
+            updated_at = NOW()
+        "#,
+        &[&mapping.tag, &mapping.target, &mapping_kind_to_sql(mapping.kind), &steam_id_int]
+    ).await?;
+
+    Ok(())
+}
+
+/// Remove the mapping of a tag, returns whether one existed
+pub async fn delete_tag_mapping(pool: &Pool, tag_name: &str) -> Result<bool, DbError> {
+    let client = pool.get().await?;
+
+    let rows_affected = client.execute(
+        "DELETE FROM tag_mappings WHERE tag_name = $1",
+        &[&tag_name]
+    ).await?;
+
+    Ok(rows_affected > 0)
+}
@@ -1,9 +1,12 @@
 //! Time to beat (TTB) database operations
 
 use deadpool_postgres::Pool;
-use crate::db::DbError;
+use crate::db::{ttb_outlier_reason, DbError, TtbReportStatus};
 
-/// Upsert TTB times for a game (from desktop scraper)
+/// Upsert TTB times for a game (from desktop scraper).
+/// Changed times go back into the admin review queue unless submitted by an admin;
+/// the last approved times are kept so a rejection can restore them.
+#[allow(clippy::too_many_arguments)]
 pub async fn upsert_ttb_times(
     pool: &Pool,
     appid: u64,
@@ -11,20 +14,37 @@ pub async fn upsert_ttb_times(
     main: Option<f32>,
     main_extra: Option<f32>,
     completionist: Option<f32>,
+    submitted_by: &str,
+    reviewed: bool,
 ) -> Result<(), DbError> {
     let client = pool.get().await?;
+    let steam_id_int: i64 = submitted_by.parse().unwrap_or(0);
 
     client.execute(
         r#"
-        INSERT INTO ttb_times (appid, game_name, main, main_extra, completionist, reported_count, first_reported_at, last_reported_at)
-        VALUES ($1, $2, $3, $4, $5, 1, NOW(), NOW())
+        INSERT INTO ttb_times (appid, game_name, main, main_extra, completionist, reported_count, first_reported_at, last_reported_at, submitted_by, reviewed_at,
+                               approved_main, approved_main_extra, approved_completionist, approved_at)
+        VALUES ($1, $2, $3, $4, $5, 1, NOW(), NOW(), $6, CASE WHEN $7 THEN NOW() END,
+                CASE WHEN $7 THEN $3 END, CASE WHEN $7 THEN $4 END, CASE WHEN $7 THEN $5 END, CASE WHEN $7 THEN NOW() END)
         ON CONFLICT (appid) DO UPDATE SET
             game_name = EXCLUDED.game_name,
             main = COALESCE(EXCLUDED.main, ttb_times.main),
             main_extra = COALESCE(EXCLUDED.main_extra, ttb_times.main_extra),
             completionist = COALESCE(EXCLUDED.completionist, ttb_times.completionist),
             reported_count = ttb_times.reported_count + 1,
-            last_reported_at = NOW()
+            last_reported_at = NOW(),
+            submitted_by = EXCLUDED.submitted_by,
+            reviewed_at = CASE
+                WHEN $7 THEN NOW()
+                WHEN (ttb_times.main, ttb_times.main_extra, ttb_times.completionist) IS DISTINCT FROM
+                     (COALESCE(EXCLUDED.main, ttb_times.main), COALESCE(EXCLUDED.main_extra, ttb_times.main_extra), COALESCE(EXCLUDED.completionist, ttb_times.completionist))
+                    THEN NULL
+                ELSE ttb_times.reviewed_at
+            END,
+            approved_main = CASE WHEN $7 THEN COALESCE(EXCLUDED.main, ttb_times.main) ELSE ttb_times.approved_main END,
+            approved_main_extra = CASE WHEN $7 THEN COALESCE(EXCLUDED.main_extra, ttb_times.main_extra) ELSE ttb_times.approved_main_extra END,
+            approved_completionist = CASE WHEN $7 THEN COALESCE(EXCLUDED.completionist, ttb_times.completionist) ELSE ttb_times.approved_completionist END,
+            approved_at = CASE WHEN $7 THEN NOW() ELSE ttb_times.approved_at END
         "#,
         &[
             &(appid as i64),
@@ -32,6 +52,8 @@ pub async fn upsert_ttb_times(
             &main,
             &main_extra,
             &completionist,
+            &steam_id_int,
+            &reviewed,
         ]
     ).await?;
 
@@ -66,7 +88,7 @@ pub async fn get_ttb_times_batch(pool: &Pool, appids: &[u64]) -> Result<Vec<over
     let appids_i64: Vec<i64> = appids.iter().map(|&id| id as i64).collect();
 
     let rows = client.query(
-        "SELECT appid, main, main_extra, completionist, last_reported_at FROM ttb_times WHERE appid = ANY($1)",
+        "SELECT appid, main, main_extra, completionist, last_reported_at FROM ttb_times WHERE appid = ANY($1) ORDER BY appid",
         &[&appids_i64]
     ).await?;
 
@@ -174,7 +196,8 @@ pub async fn is_in_ttb_blacklist(pool: &Pool, appid: u64) -> Result<bool, DbErro
     Ok(row.is_some())
 }
 
-/// Report user's TTB times for a game
+/// Report user's TTB times for a game (validate with `validate_ttb_report` first).
+/// Reports far off the existing data wait for admin review; admins' reports are always approved.
 pub async fn report_ttb(
     pool: &Pool,
     steam_id: &str,
@@ -182,24 +205,81 @@ pub async fn report_ttb(
     main_seconds: Option<i32>,
     extra_seconds: Option<i32>,
     completionist_seconds: Option<i32>,
-) -> Result<(), DbError> {
+    trusted: bool,
+) -> Result<TtbReportStatus, DbError> {
     let client = pool.get().await?;
     let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
-    
+
+    let status = if trusted {
+        TtbReportStatus::Approved
+    } else {
+        // Approved reports of other users and the reviewed HLTB times
+        let stats = client.query_one(
+            r#"
+            SELECT
+                COUNT(r.main_seconds) AS main_count,
+                AVG(r.main_seconds)::FLOAT8 AS main_mean,
+                STDDEV_SAMP(r.main_seconds)::FLOAT8 AS main_stddev,
+                COUNT(r.extra_seconds) AS extra_count,
+                AVG(r.extra_seconds)::FLOAT8 AS extra_mean,
+                STDDEV_SAMP(r.extra_seconds)::FLOAT8 AS extra_stddev,
+                COUNT(r.completionist_seconds) AS completionist_count,
+                AVG(r.completionist_seconds)::FLOAT8 AS completionist_mean,
+                STDDEV_SAMP(r.completionist_seconds)::FLOAT8 AS completionist_stddev,
+                (SELECT main FROM ttb_times WHERE appid = $1 AND reviewed_at IS NOT NULL) AS hltb_main,
+                (SELECT main_extra FROM ttb_times WHERE appid = $1 AND reviewed_at IS NOT NULL) AS hltb_extra,
+                (SELECT completionist FROM ttb_times WHERE appid = $1 AND reviewed_at IS NOT NULL) AS hltb_completionist
+            FROM user_ttb_reports r
+            WHERE r.appid = $1 AND r.steam_id <> $2 AND r.review_status = 'approved'
+            "#,
+            &[&(appid as i64), &steam_id_int]
+        ).await?;
+
+        let reasons: Vec<String> = [
+            ("main", main_seconds, "main"),
+            ("main + extra", extra_seconds, "extra"),
+            ("completionist", completionist_seconds, "completionist"),
+        ]
+        .into_iter()
+        .filter_map(|(label, seconds, column)| {
+            ttb_outlier_reason(
+                label,
+                seconds?,
+                stats.get(format!("{}_count", column).as_str()),
+                stats.get(format!("{}_mean", column).as_str()),
+                stats.get(format!("{}_stddev", column).as_str()),
+                stats.get(format!("hltb_{}", column).as_str()),
+            )
+        })
+        .collect();
+
+        if reasons.is_empty() {
+            TtbReportStatus::Approved
+        } else {
+            TtbReportStatus::Pending(reasons.join("; "))
+        }
+    };
+
+    let (review_status, review_reason) = status.review_columns();
+
     // Insert or update the report
     // The trigger will automatically update averages
     client.execute(
         r#"
-        INSERT INTO user_ttb_reports (steam_id, appid, main_seconds, extra_seconds, completionist_seconds, reported_at)
-        VALUES ($1, $2, $3, $4, $5, NOW())
+        INSERT INTO user_ttb_reports (steam_id, appid, main_seconds, extra_seconds, completionist_seconds, reported_at, review_status, review_reason)
+        VALUES ($1, $2, $3, $4, $5, NOW(), $6, $7)
         ON CONFLICT (steam_id, appid) DO UPDATE SET
             main_seconds = EXCLUDED.main_seconds,
             extra_seconds = EXCLUDED.extra_seconds,
             completionist_seconds = EXCLUDED.completionist_seconds,
-            reported_at = NOW()
+            reported_at = NOW(),
+            review_status = EXCLUDED.review_status,
+            review_reason = EXCLUDED.review_reason,
+            reviewed_at = NULL,
+            reviewed_by = NULL
         "#,
-        &[&steam_id_int, &(appid as i64), &main_seconds, &extra_seconds, &completionist_seconds]
+        &[&steam_id_int, &(appid as i64), &main_seconds, &extra_seconds, &completionist_seconds, &review_status, &review_reason]
     ).await?;
-    
-    Ok(())
+
+    Ok(status)
 }
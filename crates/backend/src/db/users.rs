@@ -2,24 +2,9 @@
 
 use deadpool_postgres::Pool;
 use chrono::Utc;
-use rand::Rng;
 use crate::db::DbError;
-
-/// Characters used for generating short IDs (URL-safe, case-sensitive)
-/// Similar to YouTube's video ID format
-const SHORT_ID_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
-const SHORT_ID_LENGTH: usize = 8;
-
-/// Generate a random short ID (YouTube-style)
-pub fn generate_short_id() -> String {
-    let mut rng = rand::thread_rng();
-    (0..SHORT_ID_LENGTH)
-        .map(|_| {
-            let idx = rng.gen_range(0..SHORT_ID_CHARS.len());
-            SHORT_ID_CHARS[idx] as char
-        })
-        .collect()
-}
+use overachiever_core::UserPage;
+use crate::db::{contains_pattern, customization_from_row, generate_short_id, page_of, UserSort, CUSTOMIZATION_COLUMNS, CUSTOMIZATION_JOIN};
 
 /// Generate a unique short_id by checking for collisions
 async fn generate_unique_short_id(client: &deadpool_postgres::Client) -> Result<String, DbError> {
@@ -42,11 +27,15 @@ pub async fn get_user_by_short_id(pool: &Pool, short_id: &str) -> Result<Option<
     let client = pool.get().await?;
     
     let row = client.query_opt(
-        r#"
-        SELECT steam_id, display_name, avatar_url, short_id
-        FROM users
-        WHERE short_id = $1
-        "#,
+        &format!(
+            r#"
+            SELECT u.steam_id, u.display_name, u.avatar_url, u.short_id, {}
+            FROM users u
+            {}
+            WHERE u.short_id = $1
+            "#,
+            CUSTOMIZATION_COLUMNS, CUSTOMIZATION_JOIN
+        ),
         &[&short_id]
     ).await?;
     
@@ -56,6 +45,7 @@ pub async fn get_user_by_short_id(pool: &Pool, short_id: &str) -> Result<Option<
             display_name: row.get("display_name"),
             avatar_url: row.get("avatar_url"),
             short_id: row.get("short_id"),
+            customization: customization_from_row(&row),
         }
     }))
 }
@@ -119,26 +109,61 @@ pub async fn get_or_create_user(
     Ok(short_id)
 }
 
-/// Get all users with their public profiles (short_id, display_name, avatar_url)
-pub async fn get_all_users(pool: &Pool) -> Result<Vec<overachiever_core::UserProfile>, DbError> {
+/// One page of the users who opted into the public directory, optionally only those whose
+/// display name contains `search`. `cursor` is the steam_id of the last user of the previous page.
+pub async fn list_users(
+    pool: &Pool,
+    search: Option<&str>,
+    sort: UserSort,
+    cursor: Option<i64>,
+    limit: i64,
+) -> Result<UserPage, DbError> {
     let client = pool.get().await?;
-    
+    let (sort_key, after, order) = match sort {
+        UserSort::Name => ("u.display_name", ">", "ASC"),
+        UserSort::LastSeen => ("COALESCE(u.last_seen, 'epoch'::timestamptz)", "<", "DESC"),
+    };
+    let pattern = search.map(contains_pattern);
+
+    // One extra row tells whether there is a next page
     let rows = client.query(
-        r#"
-        SELECT steam_id, display_name, avatar_url, short_id
-        FROM users
-        WHERE short_id IS NOT NULL
-        ORDER BY display_name
-        "#,
-        &[]
+        &format!(
+            r#"
+            SELECT u.steam_id, u.display_name, u.avatar_url, u.short_id, {columns}
+            FROM users u
+            {join}
+            WHERE u.short_id IS NOT NULL AND u.listed
+              AND ($1::text IS NULL OR u.display_name ILIKE $1)
+              AND ($2::bigint IS NULL OR ({key}, u.steam_id) {after} (SELECT {key}, u.steam_id FROM users u WHERE u.steam_id = $2))
+            ORDER BY {key} {order}, u.steam_id {order}
+            LIMIT $3
+            "#,
+            columns = CUSTOMIZATION_COLUMNS, join = CUSTOMIZATION_JOIN, key = sort_key, after = after, order = order
+        ),
+        &[&pattern, &cursor, &(limit + 1)]
     ).await?;
-    
-    Ok(rows.iter().map(|row| {
+
+    let users: Vec<overachiever_core::UserProfile> = rows.iter().map(|row| {
         overachiever_core::UserProfile {
             steam_id: row.get::<_, i64>("steam_id").to_string(),
             display_name: row.get("display_name"),
             avatar_url: row.get("avatar_url"),
             short_id: row.get("short_id"),
+            customization: customization_from_row(row),
         }
-    }).collect())
+    }).collect();
+    Ok(page_of(users, limit))
+}
+
+/// Display name and avatar of a user, as shown to others (presence)
+pub async fn get_user_display(pool: &Pool, steam_id: &str) -> Result<Option<(String, Option<String>)>, DbError> {
+    let client = pool.get().await?;
+    let steam_id_int: i64 = steam_id.parse().unwrap_or(0);
+    
+    let row = client.query_opt(
+        "SELECT display_name, avatar_url FROM users WHERE steam_id = $1",
+        &[&steam_id_int]
+    ).await?;
+    
+    Ok(row.map(|row| (row.get("display_name"), row.get("avatar_url"))))
 }
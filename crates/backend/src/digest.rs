@@ -0,0 +1,189 @@
+//! Weekly email digest: rendering and the background task sending due digests
+
+use chrono::{NaiveDate, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+use crate::db::{DigestData, DueDigest};
+use crate::email::Email;
+use crate::AppState;
+
+/// How often the scheduler looks for due digests
+const CHECK_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Digests sent per check (the rest go out on the next one)
+const BATCH_SIZE: i64 = 50;
+
+/// Escape text for HTML element content and attribute values
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// Consecutive unlock days ending today or yesterday (`days` newest first)
+fn current_streak(days: &[NaiveDate], today: NaiveDate) -> u32 {
+    let Some(&latest) = days.first() else {
+        return 0;
+    };
+    if latest != today && latest.succ_opt() != Some(today) {
+        return 0;
+    }
+    let mut streak = 1;
+    for pair in days.windows(2) {
+        if pair[1].succ_opt() != Some(pair[0]) {
+            break;
+        }
+        streak += 1;
+    }
+    streak
+}
+
+/// A rendered digest email
+pub struct RenderedDigest {
+    pub subject: String,
+    pub html: String,
+    pub text: String,
+}
+
+/// Render the digest of one user, None if nothing happened worth an email
+pub fn render_digest(display_name: &str, data: &DigestData, unsubscribe_url: &str, today: NaiveDate) -> Option<RenderedDigest> {
+    let streak = current_streak(&data.unlock_days, today);
+    let delta = data.completion_before.zip(data.completion_after).map(|(before, after)| after - before);
+    if data.unlock_count == 0 && streak == 0 {
+        return None;
+    }
+
+    let subject = match data.unlock_count {
+        0 => "Your week in achievements".to_string(),
+        1 => "Your week in achievements: 1 new unlock".to_string(),
+        n => format!("Your week in achievements: {} new unlocks", n),
+    };
+
+    let mut summary = vec![format!(
+        "{} new achievement{} this week",
+        data.unlock_count,
+        if data.unlock_count == 1 { "" } else { "s" }
+    )];
+    if streak > 0 {
+        summary.push(format!("{} day unlock streak", streak));
+    }
+    if let (Some(delta), Some(after)) = (delta, data.completion_after) {
+        summary.push(format!("average completion {:.1}% ({:+.1})", after, delta));
+    }
+
+    // Plain text part
+    let mut text = format!("Hi {},\n\n", display_name);
+    for line in &summary {
+        text.push_str(&format!("- {}\n", line));
+    }
+    if !data.unlocks.is_empty() {
+        text.push_str("\nRecent unlocks:\n");
+        for unlock in &data.unlocks {
+            text.push_str(&format!(
+                "- {} ({}, {})\n",
+                unlock.achievement_name,
+                unlock.game_name,
+                unlock.unlocktime.format("%a %b %-d")
+            ));
+        }
+        if data.unlock_count > data.unlocks.len() as i64 {
+            text.push_str(&format!("...and {} more\n", data.unlock_count - data.unlocks.len() as i64));
+        }
+    }
+    text.push_str(&format!("\nDon't want these emails? Unsubscribe: {}\n", unsubscribe_url));
+
+    // HTML part
+    let mut html = String::from(
+        "<!DOCTYPE html><html><body style=\"font-family:sans-serif;color:#222;max-width:560px;margin:auto\">",
+    );
+    html.push_str(&format!("<h2>Hi {},</h2><ul>", escape_html(display_name)));
+    for line in &summary {
+        html.push_str(&format!("<li>{}</li>", escape_html(line)));
+    }
+    html.push_str("</ul>");
+    if !data.unlocks.is_empty() {
+        html.push_str("<h3>Recent unlocks</h3><table style=\"border-collapse:collapse\">");
+        for unlock in &data.unlocks {
+            html.push_str(&format!(
+                "<tr><td style=\"padding:2px 8px 2px 0\"><b>{}</b></td><td style=\"padding:2px 8px;color:#555\">{}</td><td style=\"padding:2px 0;color:#888\">{}</td></tr>",
+                escape_html(&unlock.achievement_name),
+                escape_html(&unlock.game_name),
+                unlock.unlocktime.format("%a %b %-d")
+            ));
+        }
+        html.push_str("</table>");
+        if data.unlock_count > data.unlocks.len() as i64 {
+            html.push_str(&format!("<p>...and {} more</p>", data.unlock_count - data.unlocks.len() as i64));
+        }
+    }
+    html.push_str(&format!(
+        "<p style=\"font-size:12px;color:#888;margin-top:24px\">Don't want these emails? <a href=\"{}\">Unsubscribe</a></p></body></html>",
+        escape_html(unsubscribe_url)
+    ));
+
+    Some(RenderedDigest { subject, html, text })
+}
+
+/// Link that turns the digest off without logging in
+pub fn unsubscribe_url(state: &AppState, token: &str) -> String {
+    format!("{}/api/digest/unsubscribe?token={}", state.public_url, urlencoding::encode(token))
+}
+
+async fn send_digest(state: &AppState, due: &DueDigest) -> Result<bool, String> {
+    let Some(email_client) = &state.email else {
+        return Ok(false);
+    };
+    let data = crate::db::get_digest_data(&state.db_pool, &due.steam_id, due.since)
+        .await
+        .map_err(|e| format!("Failed to load digest data: {:?}", e))?;
+    let unsubscribe_url = unsubscribe_url(state, &due.unsubscribe_token);
+    let Some(digest) = render_digest(&due.display_name, &data, &unsubscribe_url, Utc::now().date_naive()) else {
+        // Quiet week, skip the email
+        return Ok(false);
+    };
+
+    email_client.send(&Email {
+        to: &due.email,
+        subject: &digest.subject,
+        html: &digest.html,
+        text: &digest.text,
+        unsubscribe_url: Some(&unsubscribe_url),
+    }).await?;
+    Ok(true)
+}
+
+/// Send all due digests
+async fn send_due_digests(state: &AppState) {
+    let due = match crate::db::get_due_digests(&state.db_pool, BATCH_SIZE).await {
+        Ok(due) => due,
+        Err(e) => {
+            tracing::error!("Failed to load due digests: {:?}", e);
+            return;
+        }
+    };
+
+    for digest in due {
+        let sent = match send_digest(state, &digest).await {
+            Ok(sent) => sent,
+            Err(e) => {
+                // Retried on the next check
+                tracing::warn!(steam_id = %digest.steam_id, "Failed to send digest: {}", e);
+                continue;
+            }
+        };
+        if let Err(e) = crate::db::mark_digest_sent(&state.db_pool, &digest.steam_id, sent).await {
+            tracing::error!(steam_id = %digest.steam_id, "Failed to reschedule digest: {:?}", e);
+        }
+    }
+}
+
+/// Background task sending due digests (only started when email is configured)
+pub async fn run_digest_scheduler(state: Arc<AppState>) {
+    let mut interval = tokio::time::interval(CHECK_INTERVAL);
+    loop {
+        interval.tick().await;
+        send_due_digests(&state).await;
+    }
+}
@@ -0,0 +1,73 @@
+//! Outgoing email through an HTTP email API
+//!
+//! Configured with `EMAIL_API_KEY` and `EMAIL_FROM`. The API defaults to Resend
+//! (`EMAIL_API_URL` overrides it for any provider accepting the same JSON body).
+//! Without a key, email features are disabled.
+
+use serde_json::json;
+
+const DEFAULT_EMAIL_API_URL: &str = "https://api.resend.com/emails";
+
+pub struct EmailClient {
+    client: reqwest::Client,
+    api_url: String,
+    api_key: String,
+    from: String,
+}
+
+/// One email to send
+pub struct Email<'a> {
+    pub to: &'a str,
+    pub subject: &'a str,
+    pub html: &'a str,
+    pub text: &'a str,
+    /// Link for the List-Unsubscribe header (one-click unsubscribe in mail clients)
+    pub unsubscribe_url: Option<&'a str>,
+}
+
+impl EmailClient {
+    /// Email client from the environment, None if no API key is configured
+    pub fn from_env() -> Option<Self> {
+        let api_key = std::env::var("EMAIL_API_KEY").ok().filter(|k| !k.is_empty())?;
+        let from = std::env::var("EMAIL_FROM")
+            .unwrap_or_else(|_| "Overachiever <digest@overachiever.space>".to_string());
+        let api_url = std::env::var("EMAIL_API_URL")
+            .unwrap_or_else(|_| DEFAULT_EMAIL_API_URL.to_string());
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .unwrap_or_default();
+        Some(Self { client, api_url, api_key, from })
+    }
+
+    pub async fn send(&self, email: &Email<'_>) -> Result<(), String> {
+        let mut body = json!({
+            "from": self.from,
+            "to": [email.to],
+            "subject": email.subject,
+            "html": email.html,
+            "text": email.text,
+        });
+        if let Some(url) = email.unsubscribe_url {
+            body["headers"] = json!({
+                "List-Unsubscribe": format!("<{}>", url),
+                "List-Unsubscribe-Post": "List-Unsubscribe=One-Click",
+            });
+        }
+
+        let response = self.client
+            .post(&self.api_url)
+            .bearer_auth(&self.api_key)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {}", e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(format!("Email API error {}: {}", status, text));
+        }
+        Ok(())
+    }
+}
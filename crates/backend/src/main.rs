@@ -4,20 +4,30 @@
 //! - WebSocket API for real-time sync
 //! - REST API for initial data load
 //! - Steam API proxy for WASM clients
-//! - PostgreSQL storage for user data
+//! - PostgreSQL storage for user data (or a single SQLite file with the `sqlite` feature)
+//! - Health and Prometheus metrics endpoints for monitoring
+//! - Weekly email digests
+//! - "Currently playing" presence shared between opted-in users
+//! - Periodic global achievement rarity snapshots
+//! - Per-IP and per-user rate limits (strictest on community submissions)
 
 mod db;
 mod steam_api;
 mod ws_handler;
 mod auth;
 mod routes;
+mod monitoring;
+mod email;
+mod digest;
+mod presence;
+mod rarity;
+mod rate_limit;
 
 use axum::{
-    routing::{get, post, delete},
+    middleware,
+    routing::{get, post, put, delete},
     Router,
 };
-use deadpool_postgres::{Config, Runtime, Pool};
-use tokio_postgres::NoTls;
 use tower_http::cors::{CorsLayer, Any};
 use tower_http::trace::TraceLayer;
 use axum::extract::DefaultBodyLimit;
@@ -25,9 +35,16 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 use std::sync::Arc;
 
 pub struct AppState {
-    pub db_pool: Pool,
+    pub db_pool: db::Pool,
     pub jwt_secret: String,
     pub steam_api_key: Option<String>,
+    pub image_proxy: routes::ImageProxy,
+    pub metrics: metrics_exporter_prometheus::PrometheusHandle,
+    pub email: Option<email::EmailClient>,
+    /// Public base URL of this server (for links in emails)
+    pub public_url: String,
+    pub presence: presence::PresenceRegistry,
+    pub rate_limits: rate_limit::RateLimiter,
 }
 
 #[tokio::main]
@@ -42,19 +59,11 @@ async fn main() {
         .with(tracing_subscriber::fmt::layer())
         .init();
     
-    // Database connection pool
-    let mut cfg = Config::new();
-    cfg.host = std::env::var("DB_HOST").ok();
-    cfg.port = std::env::var("DB_PORT").ok().and_then(|p| p.parse().ok());
-    cfg.dbname = std::env::var("DB_NAME").ok();
-    cfg.user = std::env::var("DB_USER").ok();
-    cfg.password = std::env::var("DB_PASSWORD").ok();
-    
-    let db_pool = cfg.create_pool(Some(Runtime::Tokio1), NoTls)
-        .expect("Failed to create database pool");
+    // Prometheus recorder (must be installed before any metric is recorded)
+    let metrics = monitoring::install_metrics();
     
-    // Test connection
-    let _ = db_pool.get().await.expect("Failed to connect to database");
+    // Database connection pool
+    let db_pool = db::connect().await.expect("Failed to connect to database");
     tracing::info!("Connected to database");
     
     let jwt_secret = std::env::var("JWT_SECRET")
@@ -65,32 +74,78 @@ async fn main() {
         tracing::warn!("STEAM_API_KEY not set - Steam sync will be disabled");
     }
     
+    let email = email::EmailClient::from_env();
+    if email.is_none() {
+        tracing::warn!("EMAIL_API_KEY not set - email digests will be disabled");
+    }
+    let public_url = std::env::var("PUBLIC_URL")
+        .unwrap_or_else(|_| "http://localhost:8080".to_string())
+        .trim_end_matches('/')
+        .to_string();
+    
     let state = Arc::new(AppState {
         db_pool,
         jwt_secret,
         steam_api_key,
+        image_proxy: routes::ImageProxy::new(),
+        metrics,
+        email,
+        public_url,
+        presence: Default::default(),
+        rate_limits: Default::default(),
     });
     
+    // Weekly email digests
+    if state.email.is_some() {
+        tokio::spawn(digest::run_digest_scheduler(state.clone()));
+    }
+    
+    // Global achievement rarity snapshots (the Steam endpoint needs no API key)
+    tokio::spawn(rarity::run_rarity_scheduler(state.clone()));
+    
+    // Read-only endpoints for third-party integrations, authenticated with an API key
+    let api_key_routes = Router::new()
+        .route("/api/v1/stats", get(routes::api_key_stats))
+        .route("/api/v1/sync", get(routes::api_key_sync_data))
+        .route_layer(middleware::from_fn_with_state(state.clone(), routes::require_api_key));
+    
     // Build router
     let app = Router::new()
-        // Health check
-        .route("/health", get(|| async { "OK" }))
+        // Health check and Prometheus metrics
+        .route("/health", get(monitoring::health))
+        .route("/metrics", get(monitoring::metrics_handler))
         // WebSocket endpoint
         .route("/ws", get(ws_handler::ws_handler))
         // REST API
         .route("/api/games", get(routes::get_games))
         .route("/api/games/{appid}/achievements", get(routes::get_achievements))
+        .route("/api/games/completion/batch", post(routes::get_completion_batch))
+        .route("/api/community/game-stats/{appid}", get(routes::get_community_game_stats))
         .route("/api/community/ratings/{appid}", get(routes::get_ratings))
         .route("/api/community/ratings", post(routes::submit_rating))
+        .route("/api/community/ratings/batch", post(routes::get_ratings_batch))
         // Achievement rating/comment endpoints
         .route("/api/achievement/rating", post(routes::submit_achievement_rating))
         .route("/api/achievement/ratings", get(routes::get_user_achievement_ratings))
+        .route("/api/achievement/ratings/batch", post(routes::submit_achievement_ratings_batch))
+        .route("/api/achievement/difficulty/{appid}", get(routes::get_achievement_difficulty))
         .route("/api/achievement/comment", post(routes::submit_achievement_comment))
+        .route("/api/achievement/comments/{appid}", get(routes::get_game_comments))
+        .route("/api/report", post(routes::report_comment))
+        // Global rarity history (snapshots taken by the rarity job)
+        .route("/api/rarity/{appid}", get(routes::get_rarity))
+        
+        // Steam image proxy for the web client
+        .route("/api/image-proxy", get(routes::image_proxy))
+        
         // Cloud sync endpoints
         .route("/api/sync/status", get(routes::get_sync_status))
         .route("/api/sync/download", get(routes::download_sync_data))
         .route("/api/sync/upload", post(routes::upload_sync_data)
             .layer(DefaultBodyLimit::max(routes::UPLOAD_BODY_LIMIT)))
+        .route("/api/sync/manifest", get(routes::get_sync_manifest))
+        .route("/api/sync/delta", post(routes::upload_sync_delta)
+            .layer(DefaultBodyLimit::max(routes::UPLOAD_BODY_LIMIT)))
         .route("/api/sync/data", delete(routes::delete_sync_data))
         // Size on disk endpoints
         .route("/size-on-disk", get(routes::get_size_on_disk))
@@ -107,13 +162,47 @@ async fn main() {
         // Game tags endpoints (SteamSpy data)
         .route("/api/tags", get(routes::get_all_tag_names))
         .route("/api/tags", post(routes::submit_tags))
+        .route("/api/tags/mappings", get(routes::get_tag_mappings))
         .route("/api/tags/{appid}", get(routes::get_tags_for_game))
         .route("/api/tags/batch", post(routes::get_tags_batch))
+        // Admin dashboard (admin only)
+        .route("/api/admin/stats", get(routes::get_admin_stats))
+        .route("/api/admin/users", get(routes::get_admin_users))
+        .route("/api/admin/ttb/pending", get(routes::get_pending_ttb))
+        .route("/api/admin/ttb/{appid}/approve", post(routes::approve_ttb))
+        .route("/api/admin/ttb/{appid}", delete(routes::reject_ttb))
+        .route("/api/admin/ttb/reports/pending", get(routes::get_pending_ttb_reports))
+        .route("/api/admin/ttb/reports/{id}/approve", post(routes::approve_ttb_report))
+        .route("/api/admin/ttb/reports/{id}/reject", post(routes::reject_ttb_report))
+        .route("/api/admin/tags/pending", get(routes::get_pending_tags))
+        .route("/api/admin/tags/{appid}/approve", post(routes::approve_tags))
+        .route("/api/admin/tags/{appid}", delete(routes::reject_tags))
+        .route("/api/admin/tags/mappings", put(routes::set_tag_mapping))
+        .route("/api/admin/tags/mappings/{tag}", delete(routes::delete_tag_mapping))
+        .route("/api/admin/comments/reported", get(routes::get_reported_comments))
+        .route("/api/admin/comments/{id}/dismiss", post(routes::dismiss_comment_reports))
+        .route("/api/admin/comments/{id}", delete(routes::remove_comment))
         // Auth
         .route("/auth/steam", get(auth::steam_login))
         .route("/auth/steam/callback", get(auth::steam_callback))
         // User list
-        .route("/api/users", get(routes::get_all_users))
+        .route("/api/users", get(routes::list_users))
+        // Profile customization
+        .route("/api/profile/customization", get(routes::get_profile_customization).put(routes::set_profile_customization))
+        // GDPR: export or delete everything stored about the user
+        .route("/api/gdpr/export", get(routes::export_user_data))
+        .route("/api/gdpr/account", delete(routes::delete_account))
+        // API keys for third-party integrations (managed with the user's JWT)
+        .route("/api/keys", get(routes::list_api_keys).post(routes::create_api_key))
+        .route("/api/keys/{id}", delete(routes::revoke_api_key))
+        // Weekly email digest opt-in, and the unsubscribe link in every digest
+        .route("/api/digest", get(routes::get_digest_subscription).put(routes::subscribe_digest).delete(routes::delete_digest_subscription))
+        .route("/api/digest/unsubscribe", get(routes::unsubscribe_digest).post(routes::unsubscribe_digest))
+        .merge(api_key_routes)
+        // Token buckets per client IP and signed-in user (429 with Retry-After when empty)
+        .route_layer(middleware::from_fn_with_state(state.clone(), rate_limit::limit_requests))
+        // Request counts and latency per route (route_layer so the matched route is known)
+        .route_layer(middleware::from_fn(monitoring::track_metrics))
         .with_state(state)
         .layer(CorsLayer::new()
             .allow_origin(Any)
@@ -128,5 +217,8 @@ async fn main() {
     tracing::info!("Starting server on {}", addr);
     
     let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
-    axum::serve(listener, app).await.unwrap();
+    // Peer address is needed for the per-IP rate limits
+    axum::serve(listener, app.into_make_service_with_connect_info::<std::net::SocketAddr>())
+        .await
+        .unwrap();
 }
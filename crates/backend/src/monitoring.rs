@@ -0,0 +1,160 @@
+//! Health and Prometheus metrics endpoints for running the server behind monitoring
+//!
+//! `/metrics` exports request counts and latency histograms per route, DB pool usage
+//! and the number of open WebSocket connections. `/health` checks the database and
+//! whether the Steam Web API is reachable.
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+use serde_json::json;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use crate::AppState;
+
+const HTTP_REQUESTS_TOTAL: &str = "http_requests_total";
+const HTTP_REQUEST_DURATION: &str = "http_request_duration_seconds";
+const WS_CONNECTIONS: &str = "ws_connections";
+
+/// Latency histogram buckets in seconds
+const LATENCY_BUCKETS: &[f64] = &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// How often histogram data is drained into the exported buckets
+const UPKEEP_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long each /health check may take before it counts as failed
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Cheap Steam Web API call that doesn't need a key
+const STEAM_HEALTH_URL: &str = "https://api.steampowered.com/ISteamWebAPIUtil/GetServerInfo/v1/";
+
+/// Install the global Prometheus recorder and start its upkeep task
+pub fn install_metrics() -> PrometheusHandle {
+    let handle = PrometheusBuilder::new()
+        .set_buckets_for_metric(Matcher::Full(HTTP_REQUEST_DURATION.to_string()), LATENCY_BUCKETS)
+        .and_then(|builder| builder.install_recorder())
+        .expect("Failed to install Prometheus recorder");
+
+    let upkeep = handle.clone();
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(UPKEEP_INTERVAL).await;
+            upkeep.run_upkeep();
+        }
+    });
+
+    handle
+}
+
+/// Count and time every routed request, labelled by route template (not the raw path)
+pub async fn track_metrics(request: Request, next: Next) -> Response {
+    let path = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|p| p.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+    let method = request.method().to_string();
+
+    let start = Instant::now();
+    let response = next.run(request).await;
+    let elapsed = start.elapsed().as_secs_f64();
+
+    let status = response.status().as_u16().to_string();
+    metrics::counter!(HTTP_REQUESTS_TOTAL, "method" => method.clone(), "path" => path.clone(), "status" => status)
+        .increment(1);
+    metrics::histogram!(HTTP_REQUEST_DURATION, "method" => method, "path" => path).record(elapsed);
+
+    response
+}
+
+/// Keeps the open WebSocket connection gauge up to date for as long as it's alive
+pub struct WsConnectionGuard;
+
+impl WsConnectionGuard {
+    pub fn open() -> Self {
+        metrics::gauge!(WS_CONNECTIONS).increment(1.0);
+        Self
+    }
+}
+
+impl Drop for WsConnectionGuard {
+    fn drop(&mut self) {
+        metrics::gauge!(WS_CONNECTIONS).decrement(1.0);
+    }
+}
+
+/// Prometheus scrape endpoint
+/// GET /metrics
+pub async fn metrics_handler(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    // Pool usage is sampled at scrape time
+    let pool = state.db_pool.status();
+    metrics::gauge!("db_pool_max_size").set(pool.max_size as f64);
+    metrics::gauge!("db_pool_size").set(pool.size as f64);
+    metrics::gauge!("db_pool_available").set(pool.available as f64);
+    metrics::gauge!("db_pool_waiting").set(pool.waiting as f64);
+
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        state.metrics.render(),
+    )
+}
+
+/// Run a health check, returning its JSON report and whether it passed
+async fn timed_check<F>(check: F) -> (serde_json::Value, bool)
+where
+    F: std::future::Future<Output = Result<(), String>>,
+{
+    let start = Instant::now();
+    let result = match tokio::time::timeout(HEALTH_CHECK_TIMEOUT, check).await {
+        Ok(result) => result,
+        Err(_) => Err("timed out".to_string()),
+    };
+    let latency_ms = start.elapsed().as_millis() as u64;
+    match result {
+        Ok(()) => (json!({"ok": true, "latency_ms": latency_ms}), true),
+        Err(error) => (json!({"ok": false, "latency_ms": latency_ms, "error": error}), false),
+    }
+}
+
+async fn check_database(state: &AppState) -> Result<(), String> {
+    crate::db::ping(&state.db_pool).await.map_err(|e| e.to_string())
+}
+
+async fn check_steam_api() -> Result<(), String> {
+    let response = reqwest::Client::new()
+        .get(STEAM_HEALTH_URL)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!("HTTP {}", response.status()))
+    }
+}
+
+/// Deep health check: 503 if the database is down, "degraded" if only Steam is unreachable
+/// GET /health
+pub async fn health(State(state): State<Arc<AppState>>) -> Response {
+    let ((database, db_ok), (steam_api, steam_ok)) = tokio::join!(
+        timed_check(check_database(&state)),
+        timed_check(check_steam_api()),
+    );
+
+    let (status, label) = match (db_ok, steam_ok) {
+        (true, true) => (StatusCode::OK, "ok"),
+        (true, false) => (StatusCode::OK, "degraded"),
+        (false, _) => (StatusCode::SERVICE_UNAVAILABLE, "unavailable"),
+    };
+
+    (status, Json(json!({
+        "status": label,
+        "database": database,
+        "steam_api": steam_api,
+    }))).into_response()
+}
@@ -0,0 +1,105 @@
+//! Soft realtime presence: who is playing what right now
+//!
+//! Opted-in clients report their current game over the WebSocket every minute or so.
+//! Entries live in memory only and expire when the updates stop (or the user's last socket
+//! closes - the desktop app and a browser tab can be connected at the same time).
+
+use chrono::{Duration, Utc};
+use overachiever_core::{PresenceGame, UserPresence};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Presence without an update for this long is dropped
+const PRESENCE_TTL_SECS: i64 = 3 * 60;
+
+/// Most users returned in one presence list (most recently updated first)
+const MAX_PRESENCE_USERS: usize = 50;
+
+#[derive(Default)]
+pub struct PresenceRegistry {
+    users: Mutex<HashMap<String, UserPresence>>,
+    /// Open authenticated sockets per user
+    connections: Mutex<HashMap<String, usize>>,
+}
+
+impl PresenceRegistry {
+    /// A socket authenticated as this user
+    pub fn connect(&self, steam_id: &str) {
+        *self.connections.lock().unwrap_or_else(|e| e.into_inner()).entry(steam_id.to_string()).or_default() += 1;
+    }
+
+    /// A socket of this user closed; presence ends with the last one
+    pub fn disconnect(&self, steam_id: &str) {
+        let mut connections = self.connections.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(count) = connections.get_mut(steam_id) else {
+            return;
+        };
+        *count -= 1;
+        if *count == 0 {
+            connections.remove(steam_id);
+            self.remove(steam_id);
+        }
+    }
+
+    /// Record what a user is playing (None = sharing, but nothing running)
+    pub fn update(&self, steam_id: &str, display_name: String, avatar_url: Option<String>, game: Option<PresenceGame>) {
+        let mut users = self.users.lock().unwrap_or_else(|e| e.into_inner());
+        match game {
+            Some(game) => {
+                users.insert(steam_id.to_string(), UserPresence {
+                    steam_id: steam_id.to_string(),
+                    display_name,
+                    avatar_url,
+                    game,
+                    updated_at: Utc::now(),
+                });
+            }
+            None => {
+                users.remove(steam_id);
+            }
+        }
+    }
+
+    pub fn remove(&self, steam_id: &str) {
+        self.users.lock().unwrap_or_else(|e| e.into_inner()).remove(steam_id);
+    }
+
+    /// Everyone else currently playing, dropping expired entries
+    pub fn others(&self, steam_id: &str) -> Vec<UserPresence> {
+        let cutoff = Utc::now() - Duration::seconds(PRESENCE_TTL_SECS);
+        let mut users = self.users.lock().unwrap_or_else(|e| e.into_inner());
+        users.retain(|_, presence| presence.updated_at >= cutoff);
+
+        let mut others: Vec<UserPresence> = users
+            .values()
+            .filter(|presence| presence.steam_id != steam_id)
+            .cloned()
+            .collect();
+        others.sort_by_key(|presence| std::cmp::Reverse(presence.updated_at));
+        others.truncate(MAX_PRESENCE_USERS);
+        others
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn playing(registry: &PresenceRegistry, steam_id: &str) {
+        let game = PresenceGame { appid: 620, name: "Portal 2".to_string(), achievements_unlocked: None, achievements_total: None };
+        registry.update(steam_id, steam_id.to_string(), None, Some(game));
+    }
+
+    #[test]
+    fn presence_ends_with_the_last_connection() {
+        let registry = PresenceRegistry::default();
+        registry.connect("1");
+        registry.connect("1");
+        playing(&registry, "1");
+
+        registry.disconnect("1");
+        assert_eq!(registry.others("2").len(), 1);
+        registry.disconnect("1");
+        assert!(registry.others("2").is_empty());
+    }
+}
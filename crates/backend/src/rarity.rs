@@ -0,0 +1,65 @@
+//! Rarity job: snapshots the global unlock percentages of every game with achievements
+//! in anyone's library, so clients without a Steam API key get rarity data and the drift
+//! of an achievement's rarity can be charted
+
+use chrono::{Duration as ChronoDuration, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+use crate::AppState;
+
+/// How often the job looks for games due for a snapshot
+const CHECK_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// Games fetched per check (the rest are picked up by the next one)
+const BATCH_SIZE: i64 = 100;
+
+/// Pause between two Steam requests
+const REQUEST_DELAY: Duration = Duration::from_millis(1500);
+
+/// Days between two snapshots of the same game unless `RARITY_SNAPSHOT_DAYS` is set
+const DEFAULT_SNAPSHOT_DAYS: i64 = 7;
+
+/// Days between two snapshots of the same game
+fn snapshot_days() -> i64 {
+    std::env::var("RARITY_SNAPSHOT_DAYS")
+        .ok()
+        .and_then(|days| days.parse().ok())
+        .filter(|days| *days > 0)
+        .unwrap_or(DEFAULT_SNAPSHOT_DAYS)
+}
+
+/// Take a snapshot of every due game, forever
+pub async fn run_rarity_scheduler(state: Arc<AppState>) {
+    let client = reqwest::Client::new();
+    let days = snapshot_days();
+    let mut interval = tokio::time::interval(CHECK_INTERVAL);
+    loop {
+        interval.tick().await;
+        snapshot_due_games(&state, &client, days).await;
+    }
+}
+
+async fn snapshot_due_games(state: &AppState, client: &reqwest::Client, days: i64) {
+    let today = Utc::now().date_naive();
+    let fetched_before = today - ChronoDuration::days(days - 1);
+    let due = match crate::db::get_apps_due_for_rarity(&state.db_pool, fetched_before, BATCH_SIZE).await {
+        Ok(due) => due,
+        Err(e) => {
+            tracing::error!("Failed to list games due for a rarity snapshot: {:?}", e);
+            return;
+        }
+    };
+
+    for appid in due {
+        match crate::steam_api::fetch_global_percentages(client, appid).await {
+            Ok(percentages) => {
+                if let Err(e) = crate::db::save_rarity_snapshot(&state.db_pool, appid, today, &percentages).await {
+                    tracing::error!("Failed to save the rarity snapshot of {}: {:?}", appid, e);
+                }
+            }
+            // Not recorded as fetched, so the game is retried on the next check
+            Err(e) => tracing::warn!("Failed to fetch global achievement percentages of {}: {}", appid, e),
+        }
+        tokio::time::sleep(REQUEST_DELAY).await;
+    }
+}
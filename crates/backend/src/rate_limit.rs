@@ -0,0 +1,277 @@
+//! Per-IP and per-user rate limits, so a script can't flood the community database
+//!
+//! Every limited request takes a token from the bucket of its IP and, when signed in,
+//! from the bucket of its user. Buckets refill continuously; an empty one answers
+//! 429 with `Retry-After`. Submissions to the community data (TTB, tags, ratings,
+//! comments) get the smallest buckets. Health, metrics and the WebSocket aren't limited.
+
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::{
+    extract::{ConnectInfo, MatchedPath, Request, State},
+    http::{header, HeaderMap, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::routes::{auth::extract_user, error::ApiError};
+use crate::AppState;
+
+const HTTP_RATE_LIMITED_TOTAL: &str = "http_rate_limited_total";
+
+/// Offenders are logged at most this often per bucket
+const LOG_INTERVAL: Duration = Duration::from_secs(60);
+/// How often full (idle) buckets are dropped
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Submissions that end up in data shown to every user
+const COMMUNITY_WRITES: &[&str] = &[
+    "/api/ttb",
+    "/api/tags",
+    "/api/community/ratings",
+    "/api/achievement/rating",
+    "/api/achievement/ratings/batch",
+    "/api/achievement/comment",
+    "/api/report",
+    "/api/size-on-disk",
+];
+
+/// Lookups sent as POST because the list of appids goes in the body
+const POST_LOOKUPS: &[&str] = &[
+    "/api/games/completion/batch",
+    "/api/community/ratings/batch",
+    "/api/ttb/batch",
+    "/api/tags/batch",
+];
+
+/// Routes that aren't limited (monitoring, and the WebSocket which is one long request)
+const UNLIMITED: &[&str] = &["/health", "/metrics", "/ws"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Tier {
+    Read,
+    Write,
+    Community,
+}
+
+impl Tier {
+    /// Tier of a request by method and route template (None = not limited)
+    fn for_route(method: &Method, route: &str) -> Option<Self> {
+        if UNLIMITED.contains(&route) {
+            return None;
+        }
+        let read = matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS);
+        Some(if read || POST_LOOKUPS.contains(&route) {
+            Tier::Read
+        } else if COMMUNITY_WRITES.contains(&route) {
+            Tier::Community
+        } else {
+            Tier::Write
+        })
+    }
+
+    /// Bucket size and refill rate (tokens per second). Reads are generous since the
+    /// web client loads every game icon through the image proxy. Community submissions
+    /// refill faster than the desktop's tags scan posts (one every 5 s by default).
+    fn limit(self) -> (f64, f64) {
+        match self {
+            Tier::Read => (600.0, 10.0),
+            Tier::Write => (30.0, 0.5),
+            Tier::Community => (10.0, 15.0 / 60.0),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Tier::Read => "read",
+            Tier::Write => "write",
+            Tier::Community => "community",
+        }
+    }
+}
+
+/// Who a bucket belongs to
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Client {
+    Ip(IpAddr),
+    User(String),
+}
+
+impl std::fmt::Display for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Client::Ip(ip) => write!(f, "ip {}", ip),
+            Client::User(steam_id) => write!(f, "user {}", steam_id),
+        }
+    }
+}
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+    logged_at: Option<Instant>,
+}
+
+impl Bucket {
+    fn refill(&mut self, tier: Tier, now: Instant) {
+        let (burst, per_second) = tier.limit();
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * per_second).min(burst);
+        self.updated = now;
+    }
+}
+
+/// A request that was turned away
+#[derive(Debug)]
+struct Limited {
+    retry_after: Duration,
+    client: Client,
+    /// Not logged for this client within the log interval yet
+    log: bool,
+}
+
+struct Buckets {
+    buckets: HashMap<(Tier, Client), Bucket>,
+    last_prune: Instant,
+}
+
+/// Token buckets of all clients seen recently (cheap to clone)
+#[derive(Clone)]
+pub struct RateLimiter {
+    inner: Arc<Mutex<Buckets>>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Buckets { buckets: HashMap::new(), last_prune: Instant::now() })),
+        }
+    }
+}
+
+impl RateLimiter {
+    /// Take a token from every client's bucket, or none when one of them is empty
+    fn check(&self, tier: Tier, clients: &[Client], now: Instant) -> Result<(), Limited> {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        if now.saturating_duration_since(inner.last_prune) >= PRUNE_INTERVAL {
+            inner.buckets.retain(|(tier, _), bucket| {
+                bucket.refill(*tier, now);
+                bucket.tokens < tier.limit().0
+            });
+            inner.last_prune = now;
+        }
+
+        let (burst, per_second) = tier.limit();
+        for client in clients {
+            let bucket = inner
+                .buckets
+                .entry((tier, client.clone()))
+                .or_insert(Bucket { tokens: burst, updated: now, logged_at: None });
+            bucket.refill(tier, now);
+            if bucket.tokens < 1.0 {
+                let log = bucket.logged_at.is_none_or(|at| now.saturating_duration_since(at) >= LOG_INTERVAL);
+                if log {
+                    bucket.logged_at = Some(now);
+                }
+                return Err(Limited {
+                    retry_after: Duration::from_secs_f64((1.0 - bucket.tokens) / per_second),
+                    client: client.clone(),
+                    log,
+                });
+            }
+        }
+        for client in clients {
+            if let Some(bucket) = inner.buckets.get_mut(&(tier, client.clone())) {
+                bucket.tokens -= 1.0;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The client's address. Behind the nginx proxy (a loopback peer) it's in X-Real-IP;
+/// from anywhere else the header could be forged, so the peer address is used.
+fn client_ip(headers: &HeaderMap, peer: Option<IpAddr>) -> Option<IpAddr> {
+    let forwarded = || {
+        headers
+            .get("x-real-ip")
+            .and_then(|h| h.to_str().ok())
+            .and_then(|s| s.trim().parse().ok())
+    };
+    match peer {
+        Some(ip) if ip.is_loopback() => forwarded().or(Some(ip)),
+        Some(ip) => Some(ip),
+        None => forwarded(),
+    }
+}
+
+/// Apply the rate limits to a routed request (route_layer, so the route template is known)
+pub async fn limit_requests(State(state): State<Arc<AppState>>, request: Request, next: Next) -> Response {
+    let route = request.extensions().get::<MatchedPath>().map(|p| p.as_str()).unwrap_or("");
+    let Some(tier) = Tier::for_route(request.method(), route) else {
+        return next.run(request).await;
+    };
+
+    let peer = request.extensions().get::<ConnectInfo<SocketAddr>>().map(|c| c.0.ip());
+    let mut clients = Vec::new();
+    if let Some(ip) = client_ip(request.headers(), peer) {
+        clients.push(Client::Ip(ip));
+    }
+    if let Ok(claims) = extract_user(request.headers(), &state.jwt_secret) {
+        clients.push(Client::User(claims.steam_id));
+    }
+
+    let limited = match state.rate_limits.check(tier, &clients, Instant::now()) {
+        Ok(()) => return next.run(request).await,
+        Err(limited) => limited,
+    };
+    if limited.log {
+        tracing::warn!("Rate limited {} on {} {} ({} tier)", limited.client, request.method(), route, tier.name());
+    }
+    metrics::counter!(HTTP_RATE_LIMITED_TOTAL, "tier" => tier.name()).increment(1);
+
+    let retry_after = limited.retry_after.as_secs_f64().ceil().max(1.0) as u64;
+    let mut response = ApiError::new(
+        StatusCode::TOO_MANY_REQUESTS,
+        format!("Too many requests, try again in {} s", retry_after),
+    )
+    .into_response();
+    response.headers_mut().insert(header::RETRY_AFTER, HeaderValue::from(retry_after));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn community_submissions_get_the_strict_tier() {
+        assert_eq!(Tier::for_route(&Method::POST, "/api/ttb"), Some(Tier::Community));
+        assert_eq!(Tier::for_route(&Method::POST, "/api/ttb/batch"), Some(Tier::Read));
+        assert_eq!(Tier::for_route(&Method::GET, "/api/ttb/{appid}"), Some(Tier::Read));
+        assert_eq!(Tier::for_route(&Method::POST, "/api/sync/upload"), Some(Tier::Write));
+        assert_eq!(Tier::for_route(&Method::GET, "/ws"), None);
+    }
+
+    #[test]
+    fn empty_bucket_is_refused_until_it_refills() {
+        let limiter = RateLimiter::default();
+        let start = Instant::now();
+        let clients = [Client::Ip("10.0.0.1".parse().unwrap()), Client::User("1".to_string())];
+        for _ in 0..10 {
+            assert!(limiter.check(Tier::Community, &clients, start).is_ok());
+        }
+
+        let limited = limiter.check(Tier::Community, &clients, start).unwrap_err();
+        assert_eq!(limited.retry_after.as_secs(), 4);
+        assert!(limited.log);
+        // Logged once, and other clients are unaffected
+        assert!(!limiter.check(Tier::Community, &clients, start).unwrap_err().log);
+        assert!(limiter.check(Tier::Community, &[Client::User("2".to_string())], start).is_ok());
+
+        assert!(limiter.check(Tier::Community, &clients, start + Duration::from_secs(4)).is_ok());
+    }
+}
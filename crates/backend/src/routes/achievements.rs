@@ -2,13 +2,15 @@
 
 use axum::{
     extract::{Path, State},
-    http::{StatusCode, HeaderMap},
+    http::HeaderMap,
     Json,
 };
 use std::sync::Arc;
-use overachiever_core::GameAchievement;
+use overachiever_core::{AchievementDifficulty, GameAchievement};
+use validator::Validate;
 use crate::AppState;
 use super::auth::extract_user;
+use super::error::{not_blank, ApiError, ValidatedJson};
 
 pub async fn get_achievements(
     State(_state): State<Arc<AppState>>,
@@ -18,10 +20,19 @@ pub async fn get_achievements(
     Json(vec![])
 }
 
-#[derive(serde::Deserialize)]
+/// Longest accepted achievement API name (characters)
+const MAX_APINAME_LENGTH: u64 = 256;
+
+/// Longest accepted achievement comment (characters)
+const MAX_COMMENT_LENGTH: u64 = 2000;
+
+#[derive(serde::Deserialize, Validate)]
 pub struct AchievementRatingRequest {
+    #[validate(range(min = 1))]
     pub appid: u64,
+    #[validate(length(min = 1, max = MAX_APINAME_LENGTH))]
     pub apiname: String,
+    #[validate(range(min = 1, max = 5))]
     pub rating: u8,
 }
 
@@ -35,18 +46,10 @@ pub struct AchievementRatingResponse {
 pub async fn submit_achievement_rating(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
-    Json(body): Json<AchievementRatingRequest>,
-) -> Result<Json<AchievementRatingResponse>, (StatusCode, Json<serde_json::Value>)> {
+    ValidatedJson(body): ValidatedJson<AchievementRatingRequest>,
+) -> Result<Json<AchievementRatingResponse>, ApiError> {
     let claims = extract_user(&headers, &state.jwt_secret)?;
     
-    // Validate rating is 1-5
-    if body.rating < 1 || body.rating > 5 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(serde_json::json!({"error": "Rating must be between 1 and 5"}))
-        ));
-    }
-    
     tracing::info!(
         steam_id = %claims.steam_id,
         appid = %body.appid,
@@ -64,10 +67,7 @@ pub async fn submit_achievement_rating(
         body.rating,
     ).await {
         tracing::error!("Failed to store achievement rating: {:?}", e);
-        return Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({"error": "Failed to store rating"}))
-        ));
+        return Err(ApiError::internal("Failed to store rating"));
     }
     
     Ok(Json(AchievementRatingResponse {
@@ -94,7 +94,7 @@ pub struct AchievementRatingEntry {
 pub async fn get_user_achievement_ratings(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
-) -> Result<Json<UserAchievementRatingsResponse>, (StatusCode, Json<serde_json::Value>)> {
+) -> Result<Json<UserAchievementRatingsResponse>, ApiError> {
     let claims = extract_user(&headers, &state.jwt_secret)?;
     
     match crate::db::get_user_achievement_ratings(&state.db_pool, &claims.steam_id).await {
@@ -107,18 +107,91 @@ pub async fn get_user_achievement_ratings(
         }
         Err(e) => {
             tracing::error!("Failed to fetch user achievement ratings: {:?}", e);
-            Err((
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(serde_json::json!({"error": "Failed to fetch ratings"}))
-            ))
+            Err(ApiError::internal("Failed to fetch ratings"))
         }
     }
 }
 
-#[derive(serde::Deserialize)]
+/// Maximum number of ratings accepted in one batch
+const MAX_RATINGS_BATCH: u64 = 10_000;
+
+/// Out-of-range ratings in a batch are skipped, not rejected (see the response counts)
+#[derive(serde::Deserialize, Validate)]
+pub struct AchievementRatingsBatchRequest {
+    #[validate(length(max = MAX_RATINGS_BATCH))]
+    pub ratings: Vec<AchievementRatingsBatchItem>,
+}
+
+#[derive(serde::Deserialize, serde::Serialize)]
+pub struct AchievementRatingsBatchItem {
+    pub appid: u64,
+    pub apiname: String,
+    pub rating: u8,
+    /// When the rating was last changed locally (newer wins over the server copy)
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(serde::Serialize)]
+pub struct AchievementRatingsBatchResponse {
+    /// Ratings inserted or updated
+    pub applied: u64,
+    /// Ratings ignored (invalid, or older than the server copy)
+    pub skipped: u64,
+    /// The user's full set of ratings after the merge
+    pub ratings: Vec<AchievementRatingEntry>,
+}
+
+/// Bulk upload achievement ratings (e.g. local ratings made before linking an account)
+/// POST /api/achievement/ratings/batch
+pub async fn submit_achievement_ratings_batch(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    ValidatedJson(body): ValidatedJson<AchievementRatingsBatchRequest>,
+) -> Result<Json<AchievementRatingsBatchResponse>, ApiError> {
+    let claims = extract_user(&headers, &state.jwt_secret)?;
+    
+    let total = body.ratings.len() as u64;
+    let valid: Vec<(u64, String, u8, chrono::DateTime<chrono::Utc>)> = body.ratings
+        .into_iter()
+        .filter(|r| (1..=5).contains(&r.rating))
+        .map(|r| (r.appid, r.apiname, r.rating, r.updated_at))
+        .collect();
+    
+    let applied = crate::db::upsert_achievement_ratings_batch(&state.db_pool, &claims.steam_id, &valid).await
+        .map_err(|e| {
+            tracing::error!("Failed to store achievement ratings batch: {:?}", e);
+            ApiError::internal("Failed to store ratings")
+        })?;
+    
+    tracing::info!(
+        steam_id = %claims.steam_id,
+        total = %total,
+        applied = %applied,
+        "Achievement ratings batch submitted"
+    );
+    
+    let ratings = crate::db::get_user_achievement_ratings(&state.db_pool, &claims.steam_id).await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch user achievement ratings: {:?}", e);
+            ApiError::internal("Failed to fetch ratings")
+        })?;
+    
+    Ok(Json(AchievementRatingsBatchResponse {
+        applied,
+        skipped: total - applied,
+        ratings: ratings
+            .into_iter()
+            .map(|(appid, apiname, rating)| AchievementRatingEntry { appid, apiname, rating })
+            .collect(),
+    }))
+}
+
+#[derive(serde::Deserialize, Validate)]
 pub struct AchievementCommentRequest {
     /// List of (appid, apiname) pairs
+    #[validate(length(min = 1))]
     pub achievements: Vec<(u64, String)>,
+    #[validate(length(max = MAX_COMMENT_LENGTH), custom(function = "not_blank"))]
     pub comment: String,
 }
 
@@ -131,24 +204,10 @@ pub struct AchievementCommentResponse {
 pub async fn submit_achievement_comment(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
-    Json(body): Json<AchievementCommentRequest>,
-) -> Result<Json<AchievementCommentResponse>, (StatusCode, Json<serde_json::Value>)> {
+    ValidatedJson(body): ValidatedJson<AchievementCommentRequest>,
+) -> Result<Json<AchievementCommentResponse>, ApiError> {
     let claims = extract_user(&headers, &state.jwt_secret)?;
     
-    if body.achievements.is_empty() {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(serde_json::json!({"error": "No achievements specified"}))
-        ));
-    }
-    
-    if body.comment.trim().is_empty() {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(serde_json::json!({"error": "Comment cannot be empty"}))
-        ));
-    }
-    
     tracing::info!(
         steam_id = %claims.steam_id,
         achievements = ?body.achievements,
@@ -156,11 +215,28 @@ pub async fn submit_achievement_comment(
         "Achievement comment submitted via REST"
     );
     
-    // TODO: Store comment in database
-    // For now, just log and return success
+    let stored = crate::db::insert_achievement_comments(&state.db_pool, &claims.steam_id, &body.achievements, body.comment.trim()).await
+        .map_err(|e| {
+            tracing::error!("Failed to store achievement comment: {:?}", e);
+            ApiError::internal("Failed to store comment")
+        })?;
     
     Ok(Json(AchievementCommentResponse {
         success: true,
-        count: body.achievements.len(),
+        count: stored as usize,
     }))
 }
+
+/// Community difficulty of each rated achievement in a game (easiest first)
+/// GET /api/achievement/difficulty/{appid}
+pub async fn get_achievement_difficulty(
+    State(state): State<Arc<AppState>>,
+    Path(appid): Path<u64>,
+) -> Result<Json<Vec<AchievementDifficulty>>, ApiError> {
+    crate::db::get_achievement_difficulty(&state.db_pool, appid).await
+        .map(Json)
+        .map_err(|e| {
+            tracing::error!("Failed to fetch achievement difficulty: {:?}", e);
+            ApiError::internal("Failed to fetch achievement difficulty")
+        })
+}
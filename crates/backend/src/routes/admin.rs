@@ -0,0 +1,296 @@
+//! Admin dashboard route handlers (admin only)
+
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    Json,
+};
+use std::sync::Arc;
+use overachiever_core::{AdminStats, AdminUser, PendingTagSubmission, PendingTtbReport, PendingTtbSubmission, ReportedComment, TagMapping};
+use crate::AppState;
+use super::auth::require_admin;
+use super::error::{ApiError, ApiJson};
+use super::tags::MAX_TAG_LENGTH;
+
+/// Maximum number of pending submissions returned per request
+const PENDING_LIMIT: i64 = 200;
+
+fn internal_error(what: &str, e: crate::db::DbError) -> ApiError {
+    tracing::error!("Failed to {}: {:?}", what, e);
+    ApiError::internal(format!("Failed to {}", what))
+}
+
+#[derive(serde::Serialize)]
+pub struct ModerationResponse {
+    pub success: bool,
+    pub appid: u64,
+}
+
+/// Server-wide statistics
+/// GET /api/admin/stats
+pub async fn get_admin_stats(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<AdminStats>, ApiError> {
+    require_admin(&headers, &state.jwt_secret)?;
+
+    crate::db::get_admin_stats(&state.db_pool).await
+        .map(Json)
+        .map_err(|e| internal_error("get admin stats", e))
+}
+
+/// All users with data sizes
+/// GET /api/admin/users
+pub async fn get_admin_users(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<AdminUser>>, ApiError> {
+    require_admin(&headers, &state.jwt_secret)?;
+
+    crate::db::get_admin_users(&state.db_pool).await
+        .map(Json)
+        .map_err(|e| internal_error("get users", e))
+}
+
+/// TTB submissions awaiting review
+/// GET /api/admin/ttb/pending
+pub async fn get_pending_ttb(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<PendingTtbSubmission>>, ApiError> {
+    require_admin(&headers, &state.jwt_secret)?;
+
+    crate::db::get_pending_ttb(&state.db_pool, PENDING_LIMIT).await
+        .map(Json)
+        .map_err(|e| internal_error("get pending TTB", e))
+}
+
+/// Approve a game's pending TTB times
+/// POST /api/admin/ttb/{appid}/approve
+pub async fn approve_ttb(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(appid): Path<u64>,
+) -> Result<Json<ModerationResponse>, ApiError> {
+    let claims = require_admin(&headers, &state.jwt_secret)?;
+    tracing::info!(steam_id = %claims.steam_id, appid = %appid, "Admin approving TTB times");
+
+    match crate::db::approve_ttb(&state.db_pool, appid).await {
+        Ok(true) => Ok(Json(ModerationResponse { success: true, appid })),
+        Ok(false) => Err(ApiError::not_found("No pending TTB times for this game")),
+        Err(e) => Err(internal_error("approve TTB", e)),
+    }
+}
+
+/// Reject (delete) a game's pending TTB times
+/// DELETE /api/admin/ttb/{appid}
+pub async fn reject_ttb(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(appid): Path<u64>,
+) -> Result<Json<ModerationResponse>, ApiError> {
+    let claims = require_admin(&headers, &state.jwt_secret)?;
+    tracing::info!(steam_id = %claims.steam_id, appid = %appid, "Admin rejecting TTB times");
+
+    match crate::db::reject_ttb(&state.db_pool, appid).await {
+        Ok(true) => Ok(Json(ModerationResponse { success: true, appid })),
+        Ok(false) => Err(ApiError::not_found("No pending TTB times for this game")),
+        Err(e) => Err(internal_error("reject TTB", e)),
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct TtbReportModerationResponse {
+    pub success: bool,
+    pub report_id: i64,
+}
+
+/// User TTB reports held back by the outlier check
+/// GET /api/admin/ttb/reports/pending
+pub async fn get_pending_ttb_reports(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<PendingTtbReport>>, ApiError> {
+    require_admin(&headers, &state.jwt_secret)?;
+
+    crate::db::get_pending_ttb_reports(&state.db_pool, PENDING_LIMIT).await
+        .map(Json)
+        .map_err(|e| internal_error("get pending TTB reports", e))
+}
+
+/// Approve a held-back user TTB report (it then counts towards the averages)
+/// POST /api/admin/ttb/reports/{id}/approve
+pub async fn approve_ttb_report(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(report_id): Path<i64>,
+) -> Result<Json<TtbReportModerationResponse>, ApiError> {
+    let claims = require_admin(&headers, &state.jwt_secret)?;
+    tracing::info!(steam_id = %claims.steam_id, report_id = %report_id, "Admin approving TTB report");
+
+    match crate::db::review_ttb_report(&state.db_pool, report_id, true, &claims.steam_id).await {
+        Ok(true) => Ok(Json(TtbReportModerationResponse { success: true, report_id })),
+        Ok(false) => Err(ApiError::not_found("No pending TTB report with this id")),
+        Err(e) => Err(internal_error("approve TTB report", e)),
+    }
+}
+
+/// Reject a held-back user TTB report (kept, but never counted)
+/// POST /api/admin/ttb/reports/{id}/reject
+pub async fn reject_ttb_report(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(report_id): Path<i64>,
+) -> Result<Json<TtbReportModerationResponse>, ApiError> {
+    let claims = require_admin(&headers, &state.jwt_secret)?;
+    tracing::info!(steam_id = %claims.steam_id, report_id = %report_id, "Admin rejecting TTB report");
+
+    match crate::db::review_ttb_report(&state.db_pool, report_id, false, &claims.steam_id).await {
+        Ok(true) => Ok(Json(TtbReportModerationResponse { success: true, report_id })),
+        Ok(false) => Err(ApiError::not_found("No pending TTB report with this id")),
+        Err(e) => Err(internal_error("reject TTB report", e)),
+    }
+}
+
+/// Tag submissions awaiting review
+/// GET /api/admin/tags/pending
+pub async fn get_pending_tags(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<PendingTagSubmission>>, ApiError> {
+    require_admin(&headers, &state.jwt_secret)?;
+
+    crate::db::get_pending_tags(&state.db_pool, PENDING_LIMIT).await
+        .map(Json)
+        .map_err(|e| internal_error("get pending tags", e))
+}
+
+/// Approve a game's pending tags
+/// POST /api/admin/tags/{appid}/approve
+pub async fn approve_tags(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(appid): Path<u64>,
+) -> Result<Json<ModerationResponse>, ApiError> {
+    let claims = require_admin(&headers, &state.jwt_secret)?;
+    tracing::info!(steam_id = %claims.steam_id, appid = %appid, "Admin approving tags");
+
+    match crate::db::approve_tags(&state.db_pool, appid).await {
+        Ok(0) => Err(ApiError::not_found("No pending tags for this game")),
+        Ok(_) => Ok(Json(ModerationResponse { success: true, appid })),
+        Err(e) => Err(internal_error("approve tags", e)),
+    }
+}
+
+/// Reject (delete) a game's pending tags
+/// DELETE /api/admin/tags/{appid}
+pub async fn reject_tags(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(appid): Path<u64>,
+) -> Result<Json<ModerationResponse>, ApiError> {
+    let claims = require_admin(&headers, &state.jwt_secret)?;
+    tracing::info!(steam_id = %claims.steam_id, appid = %appid, "Admin rejecting tags");
+
+    match crate::db::reject_tags(&state.db_pool, appid).await {
+        Ok(0) => Err(ApiError::not_found("No pending tags for this game")),
+        Ok(_) => Ok(Json(ModerationResponse { success: true, appid })),
+        Err(e) => Err(internal_error("reject tags", e)),
+    }
+}
+
+/// Add or replace a tag alias/parent
+/// PUT /api/admin/tags/mappings {"tag": "Rogue-like", "target": "Roguelike", "kind": "alias"}
+pub async fn set_tag_mapping(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    ApiJson(mut mapping): ApiJson<TagMapping>,
+) -> Result<Json<TagMapping>, ApiError> {
+    let claims = require_admin(&headers, &state.jwt_secret)?;
+
+    mapping.tag = mapping.tag.trim().to_string();
+    mapping.target = mapping.target.trim().to_string();
+    let valid_length = |name: &str| !name.is_empty() && name.chars().count() <= MAX_TAG_LENGTH;
+    if !valid_length(&mapping.tag) || !valid_length(&mapping.target) {
+        return Err(ApiError::bad_request(format!("Tag names must be 1-{} characters", MAX_TAG_LENGTH)));
+    }
+    if mapping.tag == mapping.target {
+        return Err(ApiError::bad_request("A tag can't map to itself"));
+    }
+
+    tracing::info!(steam_id = %claims.steam_id, tag = %mapping.tag, target = %mapping.target, kind = ?mapping.kind, "Admin setting tag mapping");
+
+    crate::db::upsert_tag_mapping(&state.db_pool, &mapping, &claims.steam_id).await
+        .map(|_| Json(mapping))
+        .map_err(|e| internal_error("save tag mapping", e))
+}
+
+/// Remove a tag alias/parent
+/// DELETE /api/admin/tags/mappings/{tag}
+pub async fn delete_tag_mapping(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(tag): Path<String>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let claims = require_admin(&headers, &state.jwt_secret)?;
+    tracing::info!(steam_id = %claims.steam_id, tag = %tag, "Admin removing tag mapping");
+
+    match crate::db::delete_tag_mapping(&state.db_pool, &tag).await {
+        Ok(true) => Ok(Json(serde_json::json!({"success": true, "tag": tag}))),
+        Ok(false) => Err(ApiError::not_found("No mapping for this tag")),
+        Err(e) => Err(internal_error("remove tag mapping", e)),
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct CommentModerationResponse {
+    pub success: bool,
+    pub comment_id: i64,
+}
+
+/// Reported comments awaiting review
+/// GET /api/admin/comments/reported
+pub async fn get_reported_comments(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<ReportedComment>>, ApiError> {
+    require_admin(&headers, &state.jwt_secret)?;
+
+    crate::db::get_reported_comments(&state.db_pool, PENDING_LIMIT).await
+        .map(Json)
+        .map_err(|e| internal_error("get reported comments", e))
+}
+
+/// Dismiss the reports of a comment and keep it visible
+/// POST /api/admin/comments/{id}/dismiss
+pub async fn dismiss_comment_reports(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(comment_id): Path<i64>,
+) -> Result<Json<CommentModerationResponse>, ApiError> {
+    let claims = require_admin(&headers, &state.jwt_secret)?;
+    tracing::info!(steam_id = %claims.steam_id, comment_id = %comment_id, "Admin dismissing comment reports");
+
+    match crate::db::dismiss_comment_reports(&state.db_pool, comment_id).await {
+        Ok(0) => Err(ApiError::not_found("No open reports for this comment")),
+        Ok(_) => Ok(Json(CommentModerationResponse { success: true, comment_id })),
+        Err(e) => Err(internal_error("dismiss comment reports", e)),
+    }
+}
+
+/// Remove (soft-delete) a comment and resolve its reports
+/// DELETE /api/admin/comments/{id}
+pub async fn remove_comment(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(comment_id): Path<i64>,
+) -> Result<Json<CommentModerationResponse>, ApiError> {
+    let claims = require_admin(&headers, &state.jwt_secret)?;
+    tracing::info!(steam_id = %claims.steam_id, comment_id = %comment_id, "Admin removing comment");
+
+    match crate::db::remove_comment(&state.db_pool, comment_id, &claims.steam_id).await {
+        Ok(true) => Ok(Json(CommentModerationResponse { success: true, comment_id })),
+        Ok(false) => Err(ApiError::not_found("Comment not found or already removed")),
+        Err(e) => Err(internal_error("remove comment", e)),
+    }
+}
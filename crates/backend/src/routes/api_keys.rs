@@ -0,0 +1,183 @@
+//! Per-user API keys for third-party integrations (Discord bots, OBS overlays)
+//!
+//! Keys are managed with the user's JWT under `/api/keys`. The read-only
+//! `/api/v1/...` endpoints accept a key in the `X-Api-Key` header instead,
+//! checked by the `require_api_key` middleware.
+
+use axum::{
+    extract::{Path, Request, State},
+    http::{HeaderMap, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Extension, Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+use overachiever_core::{AchievementHistory, ApiKeyInfo, ApiKeyScope, CloudSyncData, CreatedApiKey};
+use validator::Validate;
+use crate::AppState;
+use super::auth::extract_user;
+use super::error::{not_blank, ApiError, ValidatedJson};
+
+/// Header carrying the API key
+const API_KEY_HEADER: &str = "X-Api-Key";
+
+const MAX_KEY_NAME_LENGTH: u64 = 64;
+
+fn internal_error(context: &str, e: impl std::fmt::Debug) -> ApiError {
+    tracing::error!("{}: {:?}", context, e);
+    ApiError::internal(context)
+}
+
+#[derive(Deserialize, Validate)]
+pub struct CreateApiKeyRequest {
+    #[validate(length(max = MAX_KEY_NAME_LENGTH), custom(function = "not_blank"))]
+    pub name: String,
+    #[validate(length(min = 1))]
+    pub scopes: Vec<ApiKeyScope>,
+}
+
+/// List the user's active keys
+/// GET /api/keys
+pub async fn list_api_keys(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<ApiKeyInfo>>, ApiError> {
+    let claims = extract_user(&headers, &state.jwt_secret)?;
+
+    crate::db::get_api_keys(&state.db_pool, &claims.steam_id)
+        .await
+        .map(Json)
+        .map_err(|e| internal_error("Failed to list API keys", e))
+}
+
+/// Mint a new key (the response is the only time the full key is shown)
+/// POST /api/keys {"name": "Discord bot", "scopes": ["stats_read"]}
+pub async fn create_api_key(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    ValidatedJson(request): ValidatedJson<CreateApiKeyRequest>,
+) -> Result<Json<CreatedApiKey>, ApiError> {
+    let claims = extract_user(&headers, &state.jwt_secret)?;
+
+    let name = request.name.trim();
+    let mut scopes = request.scopes;
+    scopes.sort_by_key(|s| s.as_str());
+    scopes.dedup();
+
+    let active = crate::db::count_api_keys(&state.db_pool, &claims.steam_id)
+        .await
+        .map_err(|e| internal_error("Failed to create API key", e))?;
+    if active >= crate::db::MAX_API_KEYS_PER_USER {
+        return Err(ApiError::new(StatusCode::CONFLICT, format!("At most {} active keys - revoke one first", crate::db::MAX_API_KEYS_PER_USER)));
+    }
+
+    let created = crate::db::create_api_key(&state.db_pool, &claims.steam_id, name, &scopes)
+        .await
+        .map_err(|e| internal_error("Failed to create API key", e))?;
+    tracing::info!(steam_id = %claims.steam_id, key_id = created.info.id, "API key created");
+    Ok(Json(created))
+}
+
+/// Revoke a key
+/// DELETE /api/keys/{id}
+pub async fn revoke_api_key(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let claims = extract_user(&headers, &state.jwt_secret)?;
+
+    match crate::db::revoke_api_key(&state.db_pool, &claims.steam_id, id).await {
+        Ok(true) => Ok(Json(json!({"success": true}))),
+        Ok(false) => Err(ApiError::not_found("API key not found")),
+        Err(e) => Err(internal_error("Failed to revoke API key", e)),
+    }
+}
+
+/// Owner and scopes of the API key a request was made with
+#[derive(Clone)]
+pub struct ApiKeyAuth {
+    pub steam_id: String,
+    pub scopes: Vec<ApiKeyScope>,
+}
+
+impl ApiKeyAuth {
+    fn require(&self, scope: ApiKeyScope) -> Result<(), ApiError> {
+        if self.scopes.contains(&scope) {
+            Ok(())
+        } else {
+            Err(ApiError::forbidden(format!("API key lacks the {} scope", scope.as_str())))
+        }
+    }
+}
+
+/// Middleware: authenticate the `X-Api-Key` header and attach `ApiKeyAuth` to the request
+pub async fn require_api_key(
+    State(state): State<Arc<AppState>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let Some(key) = request.headers().get(API_KEY_HEADER).and_then(|h| h.to_str().ok()) else {
+        return ApiError::unauthorized("Missing X-Api-Key header").into_response();
+    };
+
+    match crate::db::authenticate_api_key(&state.db_pool, key).await {
+        Ok(Some((steam_id, scopes))) => {
+            request.extensions_mut().insert(ApiKeyAuth { steam_id, scopes });
+            next.run(request).await
+        }
+        Ok(None) => ApiError::unauthorized("Invalid or revoked API key").into_response(),
+        Err(e) => internal_error("Failed to check API key", e).into_response(),
+    }
+}
+
+/// Summary numbers for overlays and bots
+#[derive(Serialize)]
+pub struct ApiKeyStats {
+    pub steam_id: String,
+    pub game_count: i32,
+    pub achievement_count: i32,
+    pub last_sync: Option<chrono::DateTime<chrono::Utc>>,
+    /// Most recent achievement history entry (unlocked totals, average completion)
+    pub latest: Option<AchievementHistory>,
+}
+
+/// Summary stats of the key's owner (scope: stats_read)
+/// GET /api/v1/stats
+pub async fn api_key_stats(
+    State(state): State<Arc<AppState>>,
+    Extension(auth): Extension<ApiKeyAuth>,
+) -> Result<Json<ApiKeyStats>, ApiError> {
+    auth.require(ApiKeyScope::StatsRead)?;
+
+    let status = crate::db::get_cloud_sync_status(&state.db_pool, &auth.steam_id)
+        .await
+        .map_err(|e| internal_error("Failed to load stats", e))?;
+    let history = crate::db::get_achievement_history(&state.db_pool, &auth.steam_id)
+        .await
+        .map_err(|e| internal_error("Failed to load stats", e))?;
+
+    Ok(Json(ApiKeyStats {
+        steam_id: auth.steam_id,
+        game_count: status.game_count,
+        achievement_count: status.achievement_count,
+        last_sync: status.last_sync,
+        latest: history.into_iter().max_by_key(|h| h.recorded_at),
+    }))
+}
+
+/// Full sync data of the key's owner (scope: sync_read)
+/// GET /api/v1/sync
+pub async fn api_key_sync_data(
+    State(state): State<Arc<AppState>>,
+    Extension(auth): Extension<ApiKeyAuth>,
+) -> Result<Json<CloudSyncData>, ApiError> {
+    auth.require(ApiKeyScope::SyncRead)?;
+
+    crate::db::get_cloud_sync_data(&state.db_pool, &auth.steam_id)
+        .await
+        .map(Json)
+        .map_err(|e| internal_error("Failed to load sync data", e))
+}
@@ -1,8 +1,8 @@
 //! Authentication helpers for routes
 
-use axum::http::{StatusCode, HeaderMap};
-use axum::Json;
+use axum::http::HeaderMap;
 use crate::auth::{verify_jwt, Claims};
+use super::error::ApiError;
 
 /// List of admin Steam IDs (can mark games as "not for TTB")
 const ADMIN_STEAM_IDS: &[&str] = &[
@@ -15,21 +15,30 @@ pub fn is_admin(steam_id: &str) -> bool {
 }
 
 /// Extract authenticated user from Authorization header
-pub fn extract_user(headers: &HeaderMap, jwt_secret: &str) -> Result<Claims, (StatusCode, Json<serde_json::Value>)> {
+pub fn extract_user(headers: &HeaderMap, jwt_secret: &str) -> Result<Claims, ApiError> {
     let auth_header = headers
         .get("Authorization")
         .and_then(|h| h.to_str().ok())
         .ok_or_else(|| {
-            (StatusCode::UNAUTHORIZED, Json(serde_json::json!({"error": "Missing Authorization header"})))
+            ApiError::unauthorized("Missing Authorization header")
         })?;
     
     let token = auth_header
         .strip_prefix("Bearer ")
         .ok_or_else(|| {
-            (StatusCode::UNAUTHORIZED, Json(serde_json::json!({"error": "Invalid Authorization header format"})))
+            ApiError::unauthorized("Invalid Authorization header format")
         })?;
     
     verify_jwt(token, jwt_secret).map_err(|e| {
-        (StatusCode::UNAUTHORIZED, Json(serde_json::json!({"error": format!("Invalid token: {}", e)})))
+        ApiError::unauthorized(format!("Invalid token: {}", e))
     })
 }
+
+/// Extract authenticated user and require admin rights
+pub fn require_admin(headers: &HeaderMap, jwt_secret: &str) -> Result<Claims, ApiError> {
+    let claims = extract_user(headers, jwt_secret)?;
+    if !is_admin(&claims.steam_id) {
+        return Err(ApiError::forbidden("Admin access required"));
+    }
+    Ok(claims)
+}
@@ -2,13 +2,14 @@
 
 use axum::{
     extract::State,
-    http::{StatusCode, HeaderMap},
+    http::HeaderMap,
     Json,
 };
 use std::sync::Arc;
-use overachiever_core::{CloudSyncData, CloudSyncStatus};
+use overachiever_core::{CloudSyncData, CloudSyncDelta, CloudSyncStatus, SyncManifest};
 use crate::AppState;
 use super::auth::extract_user;
+use super::error::{ApiError, ApiJson};
 
 /// Body limit for large uploads (100MB)
 pub const UPLOAD_BODY_LIMIT: usize = 100 * 1024 * 1024;
@@ -17,15 +18,15 @@ pub const UPLOAD_BODY_LIMIT: usize = 100 * 1024 * 1024;
 pub async fn get_sync_status(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
-) -> Result<Json<CloudSyncStatus>, (StatusCode, Json<serde_json::Value>)> {
+) -> Result<Json<CloudSyncStatus>, ApiError> {
     let claims = extract_user(&headers, &state.jwt_secret)?;
     
     match crate::db::get_cloud_sync_status(&state.db_pool, &claims.steam_id).await {
         Ok(status) => Ok(Json(status)),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({"error": format!("Failed to get sync status: {:?}", e)}))
-        ))
+        Err(e) => {
+            tracing::error!("Failed to get sync status: {:?}", e);
+            Err(ApiError::internal("Failed to get sync status"))
+        }
     }
 }
 
@@ -33,15 +34,15 @@ pub async fn get_sync_status(
 pub async fn download_sync_data(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
-) -> Result<Json<CloudSyncData>, (StatusCode, Json<serde_json::Value>)> {
+) -> Result<Json<CloudSyncData>, ApiError> {
     let claims = extract_user(&headers, &state.jwt_secret)?;
     
     match crate::db::get_cloud_sync_data(&state.db_pool, &claims.steam_id).await {
         Ok(data) => Ok(Json(data)),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({"error": format!("Failed to download data: {:?}", e)}))
-        ))
+        Err(e) => {
+            tracing::error!("Failed to download data: {:?}", e);
+            Err(ApiError::internal("Failed to download data"))
+        }
     }
 }
 
@@ -49,20 +50,22 @@ pub async fn download_sync_data(
 pub async fn upload_sync_data(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
-    Json(data): Json<CloudSyncData>,
-) -> Result<Json<serde_json::Value>, (StatusCode, Json<serde_json::Value>)> {
+    ApiJson(data): ApiJson<CloudSyncData>,
+) -> Result<Json<serde_json::Value>, ApiError> {
     let claims = extract_user(&headers, &state.jwt_secret)?;
     
     // Verify the uploaded data belongs to the authenticated user
     if data.steam_id != claims.steam_id {
-        return Err((
-            StatusCode::FORBIDDEN,
-            Json(serde_json::json!({"error": "Cannot upload data for a different user"}))
-        ));
+        return Err(ApiError::forbidden("Cannot upload data for a different user"));
     }
     
     match crate::db::upload_cloud_sync_data(&state.db_pool, &data).await {
         Ok(_) => {
+            // Track upload size for the admin dashboard (re-serialized size approximates the request body)
+            let upload_bytes = serde_json::to_vec(&data).map(|v| v.len() as i64).unwrap_or(0);
+            if let Err(e) = crate::db::record_upload_size(&state.db_pool, &claims.steam_id, upload_bytes).await {
+                tracing::warn!("Failed to record upload size: {:?}", e);
+            }
             tracing::info!(
                 steam_id = %claims.steam_id,
                 games = data.games.len(),
@@ -75,10 +78,63 @@ pub async fn upload_sync_data(
                 "achievements_uploaded": data.achievements.len()
             })))
         }
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({"error": format!("Failed to upload data: {:?}", e)}))
-        ))
+        Err(e) => {
+            tracing::error!("Failed to upload data: {:?}", e);
+            Err(ApiError::internal("Failed to upload data"))
+        }
+    }
+}
+
+/// Checksums and latest history timestamps of the stored data, for delta uploads
+pub async fn get_sync_manifest(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<SyncManifest>, ApiError> {
+    let claims = extract_user(&headers, &state.jwt_secret)?;
+
+    match crate::db::get_sync_manifest(&state.db_pool, &claims.steam_id).await {
+        Ok(manifest) => Ok(Json(manifest)),
+        Err(e) => {
+            tracing::error!("Failed to get sync manifest: {:?}", e);
+            Err(ApiError::internal("Failed to get sync manifest"))
+        }
+    }
+}
+
+/// Upload only the changes since the manifest (merged, nothing is overwritten)
+pub async fn upload_sync_delta(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    ApiJson(delta): ApiJson<CloudSyncDelta>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let claims = extract_user(&headers, &state.jwt_secret)?;
+
+    if delta.steam_id != claims.steam_id {
+        return Err(ApiError::forbidden("Cannot upload data for a different user"));
+    }
+
+    match crate::db::merge_cloud_sync_delta(&state.db_pool, &delta).await {
+        Ok(_) => {
+            let upload_bytes = serde_json::to_vec(&delta).map(|v| v.len() as i64).unwrap_or(0);
+            if let Err(e) = crate::db::record_upload_size(&state.db_pool, &claims.steam_id, upload_bytes).await {
+                tracing::warn!("Failed to record upload size: {:?}", e);
+            }
+            tracing::info!(
+                steam_id = %claims.steam_id,
+                games = delta.games.len(),
+                achievements = delta.achievements.len(),
+                "Cloud sync delta merged"
+            );
+            Ok(Json(serde_json::json!({
+                "success": true,
+                "games_uploaded": delta.games.len(),
+                "achievements_uploaded": delta.achievements.len()
+            })))
+        }
+        Err(e) => {
+            tracing::error!("Failed to merge data: {:?}", e);
+            Err(ApiError::internal("Failed to merge data"))
+        }
     }
 }
 
@@ -86,7 +142,7 @@ pub async fn upload_sync_data(
 pub async fn delete_sync_data(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
-) -> Result<Json<serde_json::Value>, (StatusCode, Json<serde_json::Value>)> {
+) -> Result<Json<serde_json::Value>, ApiError> {
     let claims = extract_user(&headers, &state.jwt_secret)?;
     
     match crate::db::delete_cloud_sync_data(&state.db_pool, &claims.steam_id).await {
@@ -94,9 +150,9 @@ pub async fn delete_sync_data(
             tracing::info!(steam_id = %claims.steam_id, "Cloud sync data deleted");
             Ok(Json(serde_json::json!({"success": true})))
         }
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({"error": format!("Failed to delete data: {:?}", e)}))
-        ))
+        Err(e) => {
+            tracing::error!("Failed to delete data: {:?}", e);
+            Err(ApiError::internal("Failed to delete data"))
+        }
     }
 }
@@ -0,0 +1,66 @@
+//! Achievement comment and report route handlers
+
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    Json,
+};
+use std::sync::Arc;
+use overachiever_core::AchievementComment;
+use validator::Validate;
+use crate::AppState;
+use super::auth::extract_user;
+use super::error::{ApiError, ValidatedJson};
+
+/// Longest accepted report reason (characters)
+const MAX_REPORT_REASON_LEN: u64 = 500;
+
+/// Visible comments on a game's achievements (newest first)
+/// GET /api/achievement/comments/{appid}
+pub async fn get_game_comments(
+    State(state): State<Arc<AppState>>,
+    Path(appid): Path<u64>,
+) -> Result<Json<Vec<AchievementComment>>, ApiError> {
+    crate::db::get_game_comments(&state.db_pool, appid).await
+        .map(Json)
+        .map_err(|e| {
+            tracing::error!("Failed to get comments for {}: {:?}", appid, e);
+            ApiError::internal("Failed to get comments")
+        })
+}
+
+#[derive(serde::Deserialize, Validate)]
+pub struct ReportRequest {
+    pub comment_id: i64,
+    #[validate(length(max = MAX_REPORT_REASON_LEN))]
+    pub reason: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+pub struct ReportResponse {
+    pub success: bool,
+}
+
+/// Flag a comment as abusive for admin review
+/// POST /api/report
+pub async fn report_comment(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    ValidatedJson(body): ValidatedJson<ReportRequest>,
+) -> Result<Json<ReportResponse>, ApiError> {
+    let claims = extract_user(&headers, &state.jwt_secret)?;
+
+    let reason = body.reason.as_deref().map(str::trim).filter(|r| !r.is_empty());
+
+    match crate::db::report_comment(&state.db_pool, body.comment_id, &claims.steam_id, reason).await {
+        Ok(true) => {
+            tracing::info!(steam_id = %claims.steam_id, comment_id = %body.comment_id, "Comment reported");
+            Ok(Json(ReportResponse { success: true }))
+        }
+        Ok(false) => Err(ApiError::not_found("Comment not found")),
+        Err(e) => {
+            tracing::error!("Failed to report comment {}: {:?}", body.comment_id, e);
+            Err(ApiError::internal("Failed to report comment"))
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! Weekly email digest opt-in and unsubscribe
+
+use axum::{
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode},
+    response::Html,
+    Json,
+};
+use serde::Deserialize;
+use serde_json::json;
+use std::sync::Arc;
+use overachiever_core::DigestSubscription;
+use crate::AppState;
+use super::auth::extract_user;
+use super::error::{ApiError, ApiJson};
+
+const MAX_EMAIL_LENGTH: usize = 254;
+
+fn internal_error(context: &str, e: impl std::fmt::Debug) -> ApiError {
+    tracing::error!("{}: {:?}", context, e);
+    ApiError::internal(context)
+}
+
+/// Loose sanity check - the email API rejects what this lets through
+fn looks_like_email(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && email.len() <= MAX_EMAIL_LENGTH
+        && !email.chars().any(|c| c.is_whitespace() || c.is_control())
+}
+
+#[derive(Deserialize)]
+pub struct SubscribeDigestRequest {
+    pub email: String,
+}
+
+/// The user's digest subscription (null if never subscribed)
+/// GET /api/digest
+pub async fn get_digest_subscription(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<Option<DigestSubscription>>, ApiError> {
+    let claims = extract_user(&headers, &state.jwt_secret)?;
+
+    crate::db::get_digest_subscription(&state.db_pool, &claims.steam_id)
+        .await
+        .map(Json)
+        .map_err(|e| internal_error("Failed to load digest subscription", e))
+}
+
+/// Opt into the weekly digest, or change its address
+/// PUT /api/digest {"email": "me@example.com"}
+pub async fn subscribe_digest(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    ApiJson(request): ApiJson<SubscribeDigestRequest>,
+) -> Result<Json<DigestSubscription>, ApiError> {
+    let claims = extract_user(&headers, &state.jwt_secret)?;
+
+    if state.email.is_none() {
+        return Err(ApiError::new(StatusCode::SERVICE_UNAVAILABLE, "Email digests are not available on this server"));
+    }
+    let email = request.email.trim();
+    if !looks_like_email(email) {
+        return Err(ApiError::bad_request("Invalid email address"));
+    }
+
+    tracing::info!(steam_id = %claims.steam_id, "Email digest subscribed");
+
+    crate::db::subscribe_digest(&state.db_pool, &claims.steam_id, email)
+        .await
+        .map(Json)
+        .map_err(|e| internal_error("Failed to save digest subscription", e))
+}
+
+/// Opt out and forget the address
+/// DELETE /api/digest
+pub async fn delete_digest_subscription(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let claims = extract_user(&headers, &state.jwt_secret)?;
+
+    crate::db::delete_digest_subscription(&state.db_pool, &claims.steam_id)
+        .await
+        .map(|deleted| Json(json!({"success": deleted})))
+        .map_err(|e| internal_error("Failed to delete digest subscription", e))
+}
+
+#[derive(Deserialize)]
+pub struct UnsubscribeQuery {
+    pub token: String,
+}
+
+/// Unsubscribe link from a digest email (GET from the link, POST from one-click mail clients)
+/// GET/POST /api/digest/unsubscribe?token=...
+pub async fn unsubscribe_digest(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<UnsubscribeQuery>,
+) -> (StatusCode, Html<&'static str>) {
+    match crate::db::unsubscribe_digest(&state.db_pool, &query.token).await {
+        Ok(true) => (StatusCode::OK, Html("<p>You won't receive the weekly Overachiever digest anymore.</p>")),
+        Ok(false) => (StatusCode::NOT_FOUND, Html("<p>This unsubscribe link is no longer valid.</p>")),
+        Err(e) => {
+            tracing::error!("Failed to unsubscribe digest: {:?}", e);
+            (StatusCode::INTERNAL_SERVER_ERROR, Html("<p>Something went wrong, please try again later.</p>"))
+        }
+    }
+}
@@ -0,0 +1,269 @@
+//! Shared error type, JSON body extractors and validation helpers for route handlers
+//!
+//! Every error is answered with the same envelope:
+//! `{"code": "validation_failed", "message": "...", "details": {...}}`.
+//! `code` is stable for clients to match on, `details` is only set for validation errors.
+
+use axum::{
+    extract::{rejection::JsonRejection, FromRequest, Request},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::de::DeserializeOwned;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+#[derive(Debug)]
+pub struct ApiError {
+    status: StatusCode,
+    code: &'static str,
+    message: String,
+    details: Option<serde_json::Value>,
+}
+
+impl ApiError {
+    /// Error with the code that goes with the status (e.g. 404 -> "not_found")
+    pub fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            code: code_for_status(status),
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, message)
+    }
+
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::UNAUTHORIZED, message)
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::FORBIDDEN, message)
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::NOT_FOUND, message)
+    }
+
+    /// 500 with a message for the client (log the cause at the call site, it isn't sent)
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::INTERNAL_SERVER_ERROR, message)
+    }
+
+    /// 422 listing the failed checks per field (`__all__` for checks across fields)
+    pub fn validation(errors: ValidationErrors) -> Self {
+        let details: serde_json::Map<String, serde_json::Value> = errors
+            .field_errors()
+            .into_iter()
+            .map(|(field, errors)| {
+                let checks = errors
+                    .iter()
+                    .map(|error| {
+                        // The rejected value is left out, it may be a whole list
+                        let params: serde_json::Map<String, serde_json::Value> = error
+                            .params
+                            .iter()
+                            .filter(|(name, _)| *name != "value")
+                            .map(|(name, value)| (name.to_string(), value.clone()))
+                            .collect();
+                        serde_json::json!({"code": error.code, "message": error.message, "params": params})
+                    })
+                    .collect();
+                (field.to_string(), serde_json::Value::Array(checks))
+            })
+            .collect();
+        Self {
+            details: Some(details.into()),
+            ..Self::new(StatusCode::UNPROCESSABLE_ENTITY, "Request body failed validation")
+        }
+    }
+}
+
+fn code_for_status(status: StatusCode) -> &'static str {
+    match status {
+        StatusCode::BAD_REQUEST => "bad_request",
+        StatusCode::UNAUTHORIZED => "unauthorized",
+        StatusCode::FORBIDDEN => "forbidden",
+        StatusCode::NOT_FOUND => "not_found",
+        StatusCode::CONFLICT => "conflict",
+        StatusCode::PAYLOAD_TOO_LARGE => "payload_too_large",
+        StatusCode::UNSUPPORTED_MEDIA_TYPE => "unsupported_media_type",
+        StatusCode::UNPROCESSABLE_ENTITY => "validation_failed",
+        StatusCode::TOO_MANY_REQUESTS => "rate_limited",
+        StatusCode::NOT_IMPLEMENTED => "not_implemented",
+        StatusCode::BAD_GATEWAY => "bad_gateway",
+        StatusCode::SERVICE_UNAVAILABLE => "unavailable",
+        status if status.is_server_error() => "internal_error",
+        _ => "error",
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let mut body = serde_json::json!({"code": self.code, "message": self.message});
+        if let Some(details) = self.details {
+            body["details"] = details;
+        }
+        (self.status, Json(body)).into_response()
+    }
+}
+
+impl From<JsonRejection> for ApiError {
+    fn from(rejection: JsonRejection) -> Self {
+        match rejection {
+            // Unparsable JSON is as unprocessable as JSON of the wrong shape (missing field, wrong type)
+            JsonRejection::JsonSyntaxError(_) | JsonRejection::JsonDataError(_) => Self {
+                code: "invalid_body",
+                ..Self::new(StatusCode::UNPROCESSABLE_ENTITY, rejection.body_text())
+            },
+            _ => Self::new(rejection.status(), rejection.body_text()),
+        }
+    }
+}
+
+/// JSON body whose rejection (malformed JSON, wrong content type) uses the error envelope
+pub struct ApiJson<T>(pub T);
+
+impl<T: DeserializeOwned, S: Send + Sync> FromRequest<S> for ApiJson<T> {
+    type Rejection = ApiError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(req, state).await?;
+        Ok(Self(value))
+    }
+}
+
+/// JSON body that is also checked against its `Validate` rules, answering 422 when it fails
+pub struct ValidatedJson<T>(pub T);
+
+impl<T: DeserializeOwned + Validate, S: Send + Sync> FromRequest<S> for ValidatedJson<T> {
+    type Rejection = ApiError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let ApiJson(value) = ApiJson::<T>::from_request(req, state).await?;
+        value.validate().map_err(ApiError::validation)?;
+        Ok(Self(value))
+    }
+}
+
+/// `custom` validation rule: text with something besides whitespace
+pub fn not_blank(text: &str) -> Result<(), ValidationError> {
+    if text.trim().is_empty() {
+        return Err(ValidationError::new("blank").with_message("must not be empty".into()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::header;
+    use crate::routes::achievements::AchievementRatingRequest;
+    use crate::routes::tags::SubmitTagsRequest;
+    use crate::routes::ttb::SubmitTtbRequest;
+
+    fn json_request(body: &str) -> Request {
+        Request::post("/")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    /// Run a body through the extractor, returning the status and envelope of the rejection
+    async fn reject<T: DeserializeOwned + Validate>(body: &str) -> (StatusCode, serde_json::Value) {
+        let Err(error) = ValidatedJson::<T>::from_request(json_request(body), &()).await else {
+            panic!("accepted {}", body);
+        };
+        let response = error.into_response();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    async fn accepts<T: DeserializeOwned + Validate>(body: &str) -> bool {
+        ValidatedJson::<T>::from_request(json_request(body), &()).await.is_ok()
+    }
+
+    #[tokio::test]
+    async fn accepts_valid_submissions() {
+        assert!(accepts::<SubmitTtbRequest>(r#"{"appid": 620, "game_name": "Portal 2", "main": 8.5, "main_extra": null, "completionist": 22.0}"#).await);
+        assert!(accepts::<SubmitTagsRequest>(r#"{"appid": 620, "tags": [["Puzzle", 1200], ["Co-op", 800]]}"#).await);
+        assert!(accepts::<AchievementRatingRequest>(r#"{"appid": 620, "apiname": "ACH_WAKE_UP", "rating": 3}"#).await);
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_ttb_times() {
+        for body in [
+            r#"{"appid": 620, "game_name": "Portal 2", "main": -1.0, "main_extra": null, "completionist": null}"#,
+            r#"{"appid": 620, "game_name": "Portal 2", "main": null, "main_extra": null, "completionist": 99999.0}"#,
+            r#"{"appid": 620, "game_name": "Portal 2", "main": null, "main_extra": null, "completionist": null}"#,
+        ] {
+            let (status, envelope) = reject::<SubmitTtbRequest>(body).await;
+            assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{}", body);
+            assert_eq!(envelope["code"], "validation_failed");
+            assert_eq!(envelope["details"]["__all__"][0]["code"], "ttb_hours");
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_ttb_with_bad_fields() {
+        let (status, envelope) = reject::<SubmitTtbRequest>(r#"{"appid": 0, "game_name": "", "main": 8.0, "main_extra": null, "completionist": null}"#).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(envelope["details"]["appid"][0]["code"], "range");
+        assert_eq!(envelope["details"]["game_name"][0]["code"], "length");
+    }
+
+    #[tokio::test]
+    async fn rejects_malformed_json() {
+        for body in [r#"{"appid": 620, "game_name": "#, r#"{"appid": "Portal", "game_name": "Portal 2"}"#, "[]"] {
+            let (status, envelope) = reject::<SubmitTtbRequest>(body).await;
+            assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{}", body);
+            assert_eq!(envelope["code"], "invalid_body");
+            assert!(envelope["message"].as_str().is_some_and(|m| !m.is_empty()));
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_tags() {
+        let (status, envelope) = reject::<SubmitTagsRequest>(r#"{"appid": 620, "tags": [["Puzzle", 1200], ["  ", 5]]}"#).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(envelope["details"]["__all__"][0]["code"], "tag_name");
+
+        let too_long = format!(r#"{{"appid": 620, "tags": [["{}", 1]]}}"#, "x".repeat(crate::routes::tags::MAX_TAG_LENGTH + 1));
+        assert_eq!(reject::<SubmitTagsRequest>(&too_long).await.0, StatusCode::UNPROCESSABLE_ENTITY);
+
+        let many: Vec<String> = (0..100).map(|i| format!(r#"["Tag {}", 1]"#, i)).collect();
+        let (status, envelope) = reject::<SubmitTagsRequest>(&format!(r#"{{"appid": 620, "tags": [{}]}}"#, many.join(","))).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(envelope["details"]["tags"][0]["code"], "length");
+        // The rejected list isn't echoed back
+        assert!(envelope["details"]["tags"][0]["params"].get("value").is_none());
+
+        let (status, envelope) = reject::<SubmitTagsRequest>(r#"{"appid": 620, "tags": [["Puzzle"]]}"#).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(envelope["code"], "invalid_body");
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_ratings() {
+        for rating in [0, 6] {
+            let body = format!(r#"{{"appid": 620, "apiname": "ACH_WAKE_UP", "rating": {}}}"#, rating);
+            let (status, envelope) = reject::<AchievementRatingRequest>(&body).await;
+            assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+            assert_eq!(envelope["details"]["rating"][0]["code"], "range");
+        }
+
+        let (status, envelope) = reject::<AchievementRatingRequest>(r#"{"appid": 620, "apiname": "", "rating": 3}"#).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(envelope["details"]["apiname"][0]["code"], "length");
+
+        // u8 overflow is a deserialization error, not a range check
+        let (status, envelope) = reject::<AchievementRatingRequest>(r#"{"appid": 620, "apiname": "ACH_WAKE_UP", "rating": 300}"#).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(envelope["code"], "invalid_body");
+    }
+}
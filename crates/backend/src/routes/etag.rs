@@ -0,0 +1,60 @@
+//! ETag / If-None-Match support for cacheable batch responses
+//!
+//! The ETag is a hash of the serialized response, so clients asking for the same
+//! appids get a 304 with no body until the underlying data changes.
+
+use axum::{
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/// Strong ETag for a response body
+fn etag_for(body: &[u8]) -> String {
+    let mut hasher = DefaultHasher::new();
+    body.hash(&mut hasher);
+    format!("\"{:016x}\"", hasher.finish())
+}
+
+/// Check whether the request's If-None-Match header matches the ETag
+fn if_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim().trim_start_matches("W/"))
+        .any(|tag| tag == "*" || tag == etag)
+}
+
+/// Serialize a JSON response with an ETag, or answer 304 Not Modified if the client already has it
+pub fn json_with_etag<T: serde::Serialize>(headers: &HeaderMap, value: &T) -> Response {
+    let body = match serde_json::to_vec(value) {
+        Ok(body) => body,
+        Err(e) => {
+            tracing::error!("Failed to serialize response: {:?}", e);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let etag = etag_for(&body);
+    let etag_header = HeaderValue::from_str(&etag).expect("hex ETag is a valid header value");
+    // Always revalidate: the body is cheap to skip, stale tags are not
+    let cache_control = HeaderValue::from_static("no-cache");
+
+    if if_none_match(headers, &etag) {
+        return (
+            StatusCode::NOT_MODIFIED,
+            [(header::ETAG, etag_header), (header::CACHE_CONTROL, cache_control)],
+        ).into_response();
+    }
+
+    (
+        [
+            (header::CONTENT_TYPE, HeaderValue::from_static("application/json")),
+            (header::ETAG, etag_header),
+            (header::CACHE_CONTROL, cache_control),
+        ],
+        body,
+    ).into_response()
+}
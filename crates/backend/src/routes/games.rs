@@ -1,12 +1,16 @@
 //! Game-related route handlers
 
 use axum::{
-    extract::State,
+    extract::{Path, State},
+    http::HeaderMap,
+    response::{IntoResponse, Response},
     Json,
 };
 use std::sync::Arc;
-use overachiever_core::Game;
+use overachiever_core::{Game, GameCompletionStats};
 use crate::AppState;
+use super::error::ApiJson;
+use super::etag::json_with_etag;
 
 pub async fn get_games(
     State(_state): State<Arc<AppState>>,
@@ -14,3 +18,49 @@ pub async fn get_games(
     // TODO: Get authenticated user and fetch their games
     Json(vec![])
 }
+
+#[derive(serde::Deserialize)]
+pub struct CompletionBatchRequest {
+    pub appids: Vec<u64>,
+}
+
+#[derive(serde::Serialize)]
+pub struct CompletionBatchResponse {
+    pub stats: Vec<GameCompletionStats>,
+}
+
+/// Community completion averages for multiple games (supports If-None-Match)
+/// POST /api/games/completion/batch
+pub async fn get_completion_batch(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    ApiJson(body): ApiJson<CompletionBatchRequest>,
+) -> Response {
+    // Limit to 500 IDs per request
+    let appids: Vec<u64> = body.appids.into_iter().take(500).collect();
+
+    match crate::db::get_completion_stats(&state.db_pool, &appids).await {
+        Ok(stats) => json_with_etag(&headers, &CompletionBatchResponse { stats }),
+        Err(e) => {
+            tracing::error!("Failed to get completion batch: {:?}", e);
+            Json(CompletionBatchResponse { stats: vec![] }).into_response()
+        }
+    }
+}
+
+/// Anonymous completion statistics for a game among Overachiever users (None below a minimum
+/// number of players, supports If-None-Match)
+/// GET /api/community/game-stats/{appid}
+pub async fn get_community_game_stats(
+    State(state): State<Arc<AppState>>,
+    Path(appid): Path<u64>,
+    headers: HeaderMap,
+) -> Response {
+    match crate::db::get_community_game_stats(&state.db_pool, appid).await {
+        Ok(stats) => json_with_etag(&headers, &stats),
+        Err(e) => {
+            tracing::error!("Failed to get community game stats: {:?}", e);
+            Json(None::<overachiever_core::CommunityGameStats>).into_response()
+        }
+    }
+}
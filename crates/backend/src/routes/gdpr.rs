@@ -0,0 +1,81 @@
+//! GDPR route handlers: export everything the server stores about a user, or delete it
+
+use axum::{
+    extract::State,
+    http::{header, HeaderMap},
+    response::{IntoResponse, Response},
+    Json,
+};
+use std::io::{Cursor, Write};
+use std::sync::Arc;
+use zip::{write::SimpleFileOptions, ZipWriter};
+use crate::AppState;
+use super::auth::extract_user;
+use super::error::ApiError;
+
+fn internal_error(what: &str, e: impl std::fmt::Debug) -> ApiError {
+    tracing::error!("Failed to {}: {:?}", what, e);
+    ApiError::internal(format!("Failed to {}", what))
+}
+
+/// Zip one JSON file per table, plus a small manifest
+fn build_export_zip(steam_id: &str, tables: &[(String, String)]) -> zip::result::ZipResult<Vec<u8>> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default();
+
+    let manifest = serde_json::json!({
+        "steam_id": steam_id,
+        "exported_at": chrono::Utc::now(),
+        "tables": tables.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+    });
+    zip.start_file("manifest.json", options)?;
+    zip.write_all(manifest.to_string().as_bytes())?;
+
+    for (table, json) in tables {
+        zip.start_file(format!("{}.json", table), options)?;
+        zip.write_all(json.as_bytes())?;
+    }
+
+    Ok(zip.finish()?.into_inner())
+}
+
+/// Download everything stored about the authenticated user as a zip of JSON files
+/// GET /api/gdpr/export
+pub async fn export_user_data(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let claims = extract_user(&headers, &state.jwt_secret)?;
+
+    let tables = crate::db::export_user_data(&state.db_pool, &claims.steam_id).await
+        .map_err(|e| internal_error("export user data", e))?;
+    let body = build_export_zip(&claims.steam_id, &tables)
+        .map_err(|e| internal_error("build export archive", e))?;
+
+    tracing::info!(steam_id = %claims.steam_id, bytes = body.len(), "GDPR data export");
+
+    let disposition = format!("attachment; filename=\"overachiever-{}.zip\"", claims.steam_id);
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/zip".to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+            (header::CACHE_CONTROL, "no-store".to_string()),
+        ],
+        body,
+    ).into_response())
+}
+
+/// Delete the authenticated user's account and all associated data
+/// DELETE /api/gdpr/account
+pub async fn delete_account(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let claims = extract_user(&headers, &state.jwt_secret)?;
+
+    let rows_deleted = crate::db::delete_user_account(&state.db_pool, &claims.steam_id).await
+        .map_err(|e| internal_error("delete account", e))?;
+
+    tracing::info!(steam_id = %claims.steam_id, rows_deleted, "GDPR account deletion");
+    Ok(Json(serde_json::json!({"success": true, "rows_deleted": rows_deleted})))
+}
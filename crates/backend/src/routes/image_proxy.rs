@@ -0,0 +1,190 @@
+//! Steam image proxy for the WASM client
+//!
+//! Browsers can't always load Steam CDN images directly (CORS, mixed content), so the
+//! web client requests `/api/image-proxy?url=...` instead. Only Steam image hosts are
+//! fetched, and responses are kept in a bounded in-memory cache.
+
+use axum::{
+    body::Bytes,
+    extract::{Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use crate::AppState;
+use super::error::ApiError;
+
+/// Hosts the proxy is allowed to fetch from
+const ALLOWED_HOSTS: &[&str] = &[
+    "steamcdn-a.akamaihd.net",
+    "media.steampowered.com",
+    "cdn.akamai.steamstatic.com",
+    "cdn.cloudflare.steamstatic.com",
+    "shared.akamai.steamstatic.com",
+    "shared.cloudflare.steamstatic.com",
+    "avatars.steamstatic.com",
+    "avatars.akamai.steamstatic.com",
+    "avatars.cloudflare.steamstatic.com",
+];
+
+/// Largest single image the proxy will pass through
+const MAX_IMAGE_BYTES: usize = 2 * 1024 * 1024;
+
+/// Memory budget for cached images (oldest entries are evicted beyond it)
+const CACHE_MAX_BYTES: usize = 64 * 1024 * 1024;
+
+/// Browser cache lifetime for proxied images (Steam image URLs are content-addressed)
+const BROWSER_CACHE_SECS: u32 = 30 * 24 * 3600;
+
+/// Parse a URL and check that it points at an allowed Steam image host
+fn allowed_image_url(url: &str) -> Option<reqwest::Url> {
+    let url = reqwest::Url::parse(url).ok()?;
+    let scheme_ok = matches!(url.scheme(), "http" | "https");
+    let host_ok = url.host_str().is_some_and(|host| ALLOWED_HOSTS.contains(&host));
+    (scheme_ok && host_ok).then_some(url)
+}
+
+struct CachedImage {
+    content_type: String,
+    bytes: Bytes,
+}
+
+#[derive(Default)]
+struct ImageCache {
+    images: HashMap<String, CachedImage>,
+    /// Insertion order, oldest first
+    order: VecDeque<String>,
+    total_bytes: usize,
+}
+
+/// HTTP client and image cache shared by all proxy requests
+pub struct ImageProxy {
+    client: reqwest::Client,
+    cache: Mutex<ImageCache>,
+}
+
+impl ImageProxy {
+    pub fn new() -> Self {
+        // Redirects are only followed to other allowed hosts
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::custom(|attempt| {
+                if attempt.previous().len() < 5 && allowed_image_url(attempt.url().as_str()).is_some() {
+                    attempt.follow()
+                } else {
+                    attempt.stop()
+                }
+            }))
+            .timeout(std::time::Duration::from_secs(15))
+            .build()
+            .unwrap_or_default();
+        Self {
+            client,
+            cache: Mutex::new(ImageCache::default()),
+        }
+    }
+
+    fn cached(&self, url: &str) -> Option<(String, Bytes)> {
+        let cache = self.cache.lock().ok()?;
+        cache.images.get(url).map(|image| (image.content_type.clone(), image.bytes.clone()))
+    }
+
+    fn store(&self, url: String, content_type: String, bytes: Bytes) {
+        let Ok(mut cache) = self.cache.lock() else {
+            return;
+        };
+        if cache.images.contains_key(&url) {
+            return;
+        }
+        while cache.total_bytes + bytes.len() > CACHE_MAX_BYTES {
+            let Some(oldest) = cache.order.pop_front() else {
+                break;
+            };
+            if let Some(evicted) = cache.images.remove(&oldest) {
+                cache.total_bytes -= evicted.bytes.len();
+            }
+        }
+        cache.total_bytes += bytes.len();
+        cache.order.push_back(url.clone());
+        cache.images.insert(url, CachedImage { content_type, bytes });
+    }
+}
+
+impl Default for ImageProxy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(serde::Deserialize)]
+pub struct ImageProxyQuery {
+    pub url: String,
+}
+
+fn image_response(content_type: String, bytes: Bytes) -> Response {
+    (
+        [
+            (header::CONTENT_TYPE, content_type),
+            (header::CACHE_CONTROL, format!("public, max-age={}", BROWSER_CACHE_SECS)),
+        ],
+        bytes,
+    ).into_response()
+}
+
+fn proxy_error(status: StatusCode, message: &str) -> Response {
+    ApiError::new(status, message).into_response()
+}
+
+/// Fetch a Steam CDN image on behalf of the web client
+/// GET /api/image-proxy?url=https://media.steampowered.com/...
+pub async fn image_proxy(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ImageProxyQuery>,
+) -> Response {
+    let Some(url) = allowed_image_url(&query.url) else {
+        return proxy_error(StatusCode::BAD_REQUEST, "URL is not an allowed Steam image host");
+    };
+    let proxy = &state.image_proxy;
+
+    if let Some((content_type, bytes)) = proxy.cached(url.as_str()) {
+        return image_response(content_type, bytes);
+    }
+
+    let response = match proxy.client.get(url.clone()).send().await {
+        Ok(response) => response,
+        Err(e) => {
+            tracing::warn!("Image proxy fetch failed for {}: {:?}", url, e);
+            return proxy_error(StatusCode::BAD_GATEWAY, "Failed to fetch image");
+        }
+    };
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return proxy_error(StatusCode::NOT_FOUND, "Image not found");
+    }
+    if !response.status().is_success() {
+        return proxy_error(StatusCode::BAD_GATEWAY, "Upstream returned an error");
+    }
+
+    let content_type = response.headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    if !content_type.starts_with("image/") {
+        return proxy_error(StatusCode::BAD_GATEWAY, "Upstream response is not an image");
+    }
+    if response.content_length().is_some_and(|len| len as usize > MAX_IMAGE_BYTES) {
+        return proxy_error(StatusCode::PAYLOAD_TOO_LARGE, "Image too large");
+    }
+
+    let bytes = match response.bytes().await {
+        Ok(bytes) if bytes.len() <= MAX_IMAGE_BYTES => bytes,
+        Ok(_) => return proxy_error(StatusCode::PAYLOAD_TOO_LARGE, "Image too large"),
+        Err(e) => {
+            tracing::warn!("Image proxy read failed for {}: {:?}", url, e);
+            return proxy_error(StatusCode::BAD_GATEWAY, "Failed to read image");
+        }
+    };
+
+    proxy.store(url.to_string(), content_type.clone(), bytes.clone());
+    image_response(content_type, bytes)
+}
@@ -1,6 +1,8 @@
 //! REST API routes
 
 pub mod auth;
+pub mod error;
+pub mod etag;
 pub mod games;
 pub mod achievements;
 pub mod ratings;
@@ -9,6 +11,14 @@ pub mod size_cache;
 pub mod users;
 pub mod ttb;
 pub mod tags;
+pub mod admin;
+pub mod profile;
+pub mod gdpr;
+pub mod comments;
+pub mod image_proxy;
+pub mod api_keys;
+pub mod email_digest;
+pub mod rarity;
 
 // Re-export all route handlers
 pub use games::*;
@@ -19,3 +29,11 @@ pub use size_cache::*;
 pub use users::*;
 pub use ttb::*;
 pub use tags::*;
+pub use admin::*;
+pub use profile::*;
+pub use gdpr::*;
+pub use comments::*;
+pub use image_proxy::*;
+pub use api_keys::*;
+pub use email_digest::*;
+pub use rarity::*;
@@ -0,0 +1,81 @@
+//! Public profile customization route handlers
+
+use axum::{
+    extract::State,
+    http::HeaderMap,
+    Json,
+};
+use std::sync::Arc;
+use overachiever_core::{parse_hex_color, ProfileCustomization};
+use crate::AppState;
+use super::auth::extract_user;
+use super::error::{ApiError, ApiJson};
+
+fn bad_request(message: &str) -> ApiError {
+    ApiError::bad_request(message)
+}
+
+fn internal_error(what: &str, e: crate::db::DbError) -> ApiError {
+    tracing::error!("Failed to {}: {:?}", what, e);
+    ApiError::internal(format!("Failed to {}", what))
+}
+
+/// Get the authenticated user's profile customization
+/// GET /api/profile/customization
+pub async fn get_profile_customization(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<ProfileCustomization>, ApiError> {
+    let claims = extract_user(&headers, &state.jwt_secret)?;
+
+    crate::db::get_profile_customization(&state.db_pool, &claims.steam_id).await
+        .map(Json)
+        .map_err(|e| internal_error("get profile customization", e))
+}
+
+/// Update the authenticated user's profile customization
+/// PUT /api/profile/customization
+pub async fn set_profile_customization(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    ApiJson(body): ApiJson<ProfileCustomization>,
+) -> Result<Json<ProfileCustomization>, ApiError> {
+    let claims = extract_user(&headers, &state.jwt_secret)?;
+    let steam_id = &claims.steam_id;
+
+    if let Some(color) = &body.accent_color {
+        if parse_hex_color(color).is_none() {
+            return Err(bad_request("Accent color must be in #rrggbb format"));
+        }
+    }
+
+    match (body.showcase_appid, &body.showcase_apiname) {
+        (Some(appid), Some(apiname)) => {
+            let unlocked = crate::db::user_has_unlocked(&state.db_pool, steam_id, appid, apiname).await
+                .map_err(|e| internal_error("check showcase achievement", e))?;
+            if !unlocked {
+                return Err(bad_request("Showcase achievement must be one you have unlocked"));
+            }
+        }
+        (None, None) => {}
+        _ => return Err(bad_request("Showcase achievement needs both appid and apiname")),
+    }
+
+    if let Some(appid) = body.banner_appid {
+        let owned = crate::db::user_owns_game(&state.db_pool, steam_id, appid).await
+            .map_err(|e| internal_error("check banner game", e))?;
+        if !owned {
+            return Err(bad_request("Banner game must be in your library"));
+        }
+    }
+
+    crate::db::set_profile_customization(&state.db_pool, steam_id, &body).await
+        .map_err(|e| internal_error("save profile customization", e))?;
+
+    tracing::info!(steam_id = %steam_id, "Profile customization updated");
+
+    // Return the stored version with showcase name/icon resolved
+    crate::db::get_profile_customization(&state.db_pool, steam_id).await
+        .map(Json)
+        .map_err(|e| internal_error("get profile customization", e))
+}
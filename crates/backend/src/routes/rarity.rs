@@ -0,0 +1,25 @@
+//! Achievement rarity history route handlers
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use std::sync::Arc;
+use overachiever_core::AchievementRarityHistory;
+use crate::AppState;
+use super::error::ApiError;
+
+/// Global unlock percentage snapshots of each achievement in a game (oldest first).
+/// Empty until the rarity job has fetched the game.
+/// GET /api/rarity/{appid}
+pub async fn get_rarity(
+    State(state): State<Arc<AppState>>,
+    Path(appid): Path<u64>,
+) -> Result<Json<Vec<AchievementRarityHistory>>, ApiError> {
+    crate::db::get_rarity_history(&state.db_pool, appid).await
+        .map(Json)
+        .map_err(|e| {
+            tracing::error!("Failed to fetch rarity history: {:?}", e);
+            ApiError::internal("Failed to fetch rarity history")
+        })
+}
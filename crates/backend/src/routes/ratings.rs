@@ -2,11 +2,19 @@
 
 use axum::{
     extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
     Json,
 };
 use std::sync::Arc;
-use overachiever_core::GameRating;
+use overachiever_core::{GameAchievementRatings, GameRating};
+use validator::Validate;
 use crate::AppState;
+use super::etag::json_with_etag;
+use super::error::{ApiError, ApiJson, ValidatedJson};
+
+/// Most games per batch ratings request
+const MAX_RATINGS_BATCH_APPIDS: usize = 500;
 
 pub async fn get_ratings(
     State(state): State<Arc<AppState>>,
@@ -18,18 +26,55 @@ pub async fn get_ratings(
     }
 }
 
+/// Longest accepted rating comment (characters)
+const MAX_RATING_COMMENT_LENGTH: u64 = 2000;
+
 #[allow(dead_code)]
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, Validate)]
 pub struct SubmitRatingRequest {
+    #[validate(range(min = 1))]
     pub appid: u64,
+    #[validate(range(min = 1, max = 5))]
     pub rating: u8,
+    #[validate(length(max = MAX_RATING_COMMENT_LENGTH))]
     pub comment: Option<String>,
 }
 
 pub async fn submit_rating(
     State(_state): State<Arc<AppState>>,
-    Json(_body): Json<SubmitRatingRequest>,
-) -> Json<serde_json::Value> {
+    ValidatedJson(_body): ValidatedJson<SubmitRatingRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
     // TODO: Get authenticated user and submit rating
-    Json(serde_json::json!({"error": "Not implemented"}))
+    Err(ApiError::new(StatusCode::NOT_IMPLEMENTED, "Not implemented"))
+}
+
+#[derive(serde::Deserialize)]
+pub struct RatingsBatchRequest {
+    pub appids: Vec<u64>,
+}
+
+#[derive(serde::Serialize)]
+pub struct RatingsBatchResponse {
+    pub games: Vec<GameAchievementRatings>,
+}
+
+/// Average achievement ratings and rating counts for multiple games (supports If-None-Match)
+/// POST /api/community/ratings/batch
+pub async fn get_ratings_batch(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    ApiJson(body): ApiJson<RatingsBatchRequest>,
+) -> Response {
+    let mut appids: Vec<u64> = body.appids.into_iter().take(MAX_RATINGS_BATCH_APPIDS).collect();
+    appids.sort_unstable();
+    appids.dedup();
+
+    match crate::db::get_achievement_difficulty_batch(&state.db_pool, &appids).await {
+        Ok(games) => json_with_etag(&headers, &RatingsBatchResponse { games }),
+        Err(e) => {
+            // An error, not an empty list - clients cache what they get
+            tracing::error!("Failed to get ratings batch: {:?}", e);
+            ApiError::internal("Failed to fetch ratings").into_response()
+        }
+    }
 }
@@ -2,12 +2,14 @@
 
 use axum::{
     extract::{Query, State},
-    http::{StatusCode, HeaderMap},
+    http::HeaderMap,
     Json,
 };
 use std::sync::Arc;
+use validator::Validate;
 use crate::AppState;
 use super::auth::extract_user;
+use super::error::{ApiError, ValidatedJson};
 
 #[derive(serde::Deserialize)]
 pub struct SizeOnDiskQuery {
@@ -72,8 +74,12 @@ pub async fn get_size_on_disk(
     }
 }
 
-#[derive(serde::Deserialize)]
+/// Most sizes accepted per request
+const MAX_SUBMITTED_SIZES: u64 = 1000;
+
+#[derive(serde::Deserialize, Validate)]
 pub struct SubmitSizesRequest {
+    #[validate(length(max = MAX_SUBMITTED_SIZES))]
     pub sizes: Vec<crate::db::AppSizeInfo>,
 }
 
@@ -88,19 +94,11 @@ pub struct SubmitSizesResponse {
 pub async fn submit_size_on_disk(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
-    Json(body): Json<SubmitSizesRequest>,
-) -> Result<Json<SubmitSizesResponse>, (StatusCode, Json<serde_json::Value>)> {
+    ValidatedJson(body): ValidatedJson<SubmitSizesRequest>,
+) -> Result<Json<SubmitSizesResponse>, ApiError> {
     // Require authentication to submit sizes
     let claims = extract_user(&headers, &state.jwt_secret)?;
 
-    // Limit to 1000 sizes per request
-    if body.sizes.len() > 1000 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(serde_json::json!({"error": "Too many sizes in request (max 1000)"}))
-        ));
-    }
-
     match crate::db::upsert_app_sizes(&state.db_pool, &body.sizes).await {
         Ok(count) => {
             tracing::info!(
@@ -113,9 +111,9 @@ pub async fn submit_size_on_disk(
                 count,
             }))
         }
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({"error": format!("Failed to save sizes: {:?}", e)}))
-        ))
+        Err(e) => {
+            tracing::error!("Failed to save sizes: {:?}", e);
+            Err(ApiError::internal("Failed to save sizes"))
+        }
     }
 }
@@ -2,13 +2,23 @@
 
 use axum::{
     extract::{Path, State},
-    http::{StatusCode, HeaderMap},
+    http::HeaderMap,
+    response::{IntoResponse, Response},
     Json,
 };
 use std::sync::Arc;
-use overachiever_core::GameTag;
+use overachiever_core::{GameTag, TagMapping};
+use validator::{Validate, ValidationError};
 use crate::AppState;
-use super::auth::extract_user;
+use super::auth::{extract_user, is_admin};
+use super::etag::json_with_etag;
+use super::error::{ApiError, ApiJson, ValidatedJson};
+
+/// Longest accepted tag name (characters)
+pub(super) const MAX_TAG_LENGTH: usize = 64;
+
+/// Most tags accepted for one game (SteamSpy lists up to 20)
+const MAX_TAGS_PER_GAME: u64 = 50;
 
 #[derive(serde::Serialize)]
 pub struct TagNamesResponse {
@@ -29,6 +39,21 @@ pub async fn get_all_tag_names(
     }
 }
 
+/// Get all tag aliases and parents (clients apply them to filters and statistics)
+/// GET /api/tags/mappings
+pub async fn get_tag_mappings(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Response {
+    match crate::db::get_tag_mappings(&state.db_pool).await {
+        Ok(mappings) => json_with_etag(&headers, &mappings),
+        Err(e) => {
+            tracing::error!("Failed to get tag mappings: {:?}", e);
+            Json(Vec::<TagMapping>::new()).into_response()
+        }
+    }
+}
+
 #[derive(serde::Deserialize)]
 pub struct TagsBatchRequest {
     pub appids: Vec<u64>,
@@ -39,20 +64,21 @@ pub struct TagsBatchResponse {
     pub tags: Vec<GameTag>,
 }
 
-/// Get tags for multiple games
+/// Get tags for multiple games (supports If-None-Match, answers 304 when unchanged)
 /// POST /api/tags/batch
 pub async fn get_tags_batch(
     State(state): State<Arc<AppState>>,
-    Json(body): Json<TagsBatchRequest>,
-) -> Json<TagsBatchResponse> {
+    headers: HeaderMap,
+    ApiJson(body): ApiJson<TagsBatchRequest>,
+) -> Response {
     // Limit to 500 IDs per request
     let appids: Vec<u64> = body.appids.into_iter().take(500).collect();
 
     match crate::db::get_tags_for_games(&state.db_pool, &appids).await {
-        Ok(tags) => Json(TagsBatchResponse { tags }),
+        Ok(tags) => json_with_etag(&headers, &TagsBatchResponse { tags }),
         Err(e) => {
             tracing::error!("Failed to get tags batch: {:?}", e);
-            Json(TagsBatchResponse { tags: vec![] })
+            Json(TagsBatchResponse { tags: vec![] }).into_response()
         }
     }
 }
@@ -72,12 +98,25 @@ pub async fn get_tags_for_game(
     }
 }
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, Validate)]
+#[validate(schema(function = "validate_tag_names"))]
 pub struct SubmitTagsRequest {
+    #[validate(range(min = 1))]
     pub appid: u64,
+    #[validate(length(max = MAX_TAGS_PER_GAME))]
     pub tags: Vec<(String, u32)>, // (tag_name, vote_count)
 }
 
+fn validate_tag_names(body: &SubmitTagsRequest) -> Result<(), ValidationError> {
+    let valid = |name: &str| !name.trim().is_empty() && name.chars().count() <= MAX_TAG_LENGTH;
+    if body.tags.iter().all(|(name, _)| valid(name)) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("tag_name")
+            .with_message(format!("Tag names must be 1-{} characters", MAX_TAG_LENGTH).into()))
+    }
+}
+
 #[derive(serde::Serialize)]
 pub struct SubmitTagsResponse {
     pub success: bool,
@@ -89,8 +128,8 @@ pub struct SubmitTagsResponse {
 pub async fn submit_tags(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
-    Json(body): Json<SubmitTagsRequest>,
-) -> Result<Json<SubmitTagsResponse>, (StatusCode, Json<serde_json::Value>)> {
+    ValidatedJson(body): ValidatedJson<SubmitTagsRequest>,
+) -> Result<Json<SubmitTagsResponse>, ApiError> {
     // Require authentication
     let claims = extract_user(&headers, &state.jwt_secret)?;
 
@@ -101,11 +140,11 @@ pub async fn submit_tags(
         "Tags submitted"
     );
 
-    match crate::db::upsert_game_tags(&state.db_pool, body.appid, &body.tags).await {
+    match crate::db::upsert_game_tags(&state.db_pool, body.appid, &body.tags, &claims.steam_id, is_admin(&claims.steam_id)).await {
         Ok(count) => Ok(Json(SubmitTagsResponse { success: true, count })),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({"error": format!("Failed to save tags: {:?}", e)}))
-        ))
+        Err(e) => {
+            tracing::error!("Failed to save tags: {:?}", e);
+            Err(ApiError::internal("Failed to save tags"))
+        }
     }
 }
@@ -2,23 +2,41 @@
 
 use axum::{
     extract::{Path, State},
-    http::{StatusCode, HeaderMap},
+    http::HeaderMap,
+    response::{IntoResponse, Response},
     Json,
 };
 use std::sync::Arc;
 use overachiever_core::TtbTimes;
+use validator::{Validate, ValidationError};
 use crate::AppState;
 use super::auth::{extract_user, is_admin};
+use super::etag::json_with_etag;
+use super::error::{ApiError, ApiJson, ValidatedJson};
 
-#[derive(serde::Deserialize)]
+/// Longest accepted game name (characters)
+const MAX_GAME_NAME_LENGTH: u64 = 256;
+
+/// Longest accepted blacklist reason (characters)
+const MAX_BLACKLIST_REASON_LENGTH: u64 = 500;
+
+#[derive(serde::Deserialize, Validate)]
+#[validate(schema(function = "validate_ttb_times"))]
 pub struct SubmitTtbRequest {
+    #[validate(range(min = 1))]
     pub appid: u64,
+    #[validate(length(min = 1, max = MAX_GAME_NAME_LENGTH))]
     pub game_name: String,
     pub main: Option<f32>,
     pub main_extra: Option<f32>,
     pub completionist: Option<f32>,
 }
 
+fn validate_ttb_times(body: &SubmitTtbRequest) -> Result<(), ValidationError> {
+    crate::db::validate_ttb_hours(body.main, body.main_extra, body.completionist)
+        .map_err(|e| ValidationError::new("ttb_hours").with_message(e.into()))
+}
+
 #[derive(serde::Serialize)]
 pub struct TtbResponse {
     pub success: bool,
@@ -29,8 +47,8 @@ pub struct TtbResponse {
 pub async fn submit_ttb(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
-    Json(body): Json<SubmitTtbRequest>,
-) -> Result<Json<TtbResponse>, (StatusCode, Json<serde_json::Value>)> {
+    ValidatedJson(body): ValidatedJson<SubmitTtbRequest>,
+) -> Result<Json<TtbResponse>, ApiError> {
     // Require authentication to submit
     let claims = extract_user(&headers, &state.jwt_secret)?;
 
@@ -48,12 +66,14 @@ pub async fn submit_ttb(
         body.main,
         body.main_extra,
         body.completionist,
+        &claims.steam_id,
+        is_admin(&claims.steam_id),
     ).await {
         Ok(_) => Ok(Json(TtbResponse { success: true })),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({"error": format!("Failed to save TTB times: {:?}", e)}))
-        ))
+        Err(e) => {
+            tracing::error!("Failed to save TTB times: {:?}", e);
+            Err(ApiError::internal("Failed to save TTB times"))
+        }
     }
 }
 
@@ -77,28 +97,32 @@ pub struct TtbBatchRequest {
     pub appids: Vec<u64>,
 }
 
-/// Get TTB times for multiple games
+/// Get TTB times for multiple games (supports If-None-Match, answers 304 when unchanged)
 /// POST /api/ttb/batch
 pub async fn get_ttb_batch(
     State(state): State<Arc<AppState>>,
-    Json(body): Json<TtbBatchRequest>,
-) -> Json<Vec<TtbTimes>> {
+    headers: HeaderMap,
+    ApiJson(body): ApiJson<TtbBatchRequest>,
+) -> Response {
     // Limit to 500 IDs per request
     let appids: Vec<u64> = body.appids.into_iter().take(500).collect();
 
     match crate::db::get_ttb_times_batch(&state.db_pool, &appids).await {
-        Ok(times) => Json(times),
+        Ok(times) => json_with_etag(&headers, &times),
         Err(e) => {
             tracing::error!("Failed to get TTB times batch: {:?}", e);
-            Json(vec![])
+            Json(Vec::<TtbTimes>::new()).into_response()
         }
     }
 }
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, Validate)]
 pub struct TtbBlacklistRequest {
+    #[validate(range(min = 1))]
     pub appid: u64,
+    #[validate(length(min = 1, max = MAX_GAME_NAME_LENGTH))]
     pub game_name: String,
+    #[validate(length(max = MAX_BLACKLIST_REASON_LENGTH))]
     pub reason: Option<String>,
 }
 
@@ -113,16 +137,13 @@ pub struct TtbBlacklistResponse {
 pub async fn add_to_ttb_blacklist(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
-    Json(body): Json<TtbBlacklistRequest>,
-) -> Result<Json<TtbBlacklistResponse>, (StatusCode, Json<serde_json::Value>)> {
+    ValidatedJson(body): ValidatedJson<TtbBlacklistRequest>,
+) -> Result<Json<TtbBlacklistResponse>, ApiError> {
     let claims = extract_user(&headers, &state.jwt_secret)?;
 
     // Check if user is admin
     if !is_admin(&claims.steam_id) {
-        return Err((
-            StatusCode::FORBIDDEN,
-            Json(serde_json::json!({"error": "Admin access required"}))
-        ));
+        return Err(ApiError::forbidden("Admin access required"));
     }
 
     tracing::info!(
@@ -144,10 +165,10 @@ pub async fn add_to_ttb_blacklist(
             success: true,
             appid: body.appid,
         })),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({"error": format!("Failed to add to blacklist: {:?}", e)}))
-        ))
+        Err(e) => {
+            tracing::error!("Failed to add to blacklist: {:?}", e);
+            Err(ApiError::internal("Failed to add to blacklist"))
+        }
     }
 }
 
@@ -157,15 +178,12 @@ pub async fn remove_from_ttb_blacklist(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
     Path(appid): Path<u64>,
-) -> Result<Json<TtbBlacklistResponse>, (StatusCode, Json<serde_json::Value>)> {
+) -> Result<Json<TtbBlacklistResponse>, ApiError> {
     let claims = extract_user(&headers, &state.jwt_secret)?;
 
     // Check if user is admin
     if !is_admin(&claims.steam_id) {
-        return Err((
-            StatusCode::FORBIDDEN,
-            Json(serde_json::json!({"error": "Admin access required"}))
-        ));
+        return Err(ApiError::forbidden("Admin access required"));
     }
 
     tracing::info!(
@@ -182,16 +200,13 @@ pub async fn remove_from_ttb_blacklist(
                     appid,
                 }))
             } else {
-                Err((
-                    StatusCode::NOT_FOUND,
-                    Json(serde_json::json!({"error": "Game not in blacklist"}))
-                ))
+                Err(ApiError::not_found("Game not in blacklist"))
             }
         }
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({"error": format!("Failed to remove from blacklist: {:?}", e)}))
-        ))
+        Err(e) => {
+            tracing::error!("Failed to remove from blacklist: {:?}", e);
+            Err(ApiError::internal("Failed to remove from blacklist"))
+        }
     }
 }
 
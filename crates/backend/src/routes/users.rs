@@ -1,25 +1,49 @@
-//! User list route handler
+//! User directory route handler
 
 use axum::{
-    extract::State,
-    http::StatusCode,
+    extract::{Query, State},
     Json,
 };
 use std::sync::Arc;
+use overachiever_core::UserPage;
 use crate::AppState;
+use crate::db::{UserSort, USER_PAGE_DEFAULT, USER_PAGE_MAX};
+use super::error::ApiError;
 
-/// Get all users with public profiles
-pub async fn get_all_users(
+#[derive(serde::Deserialize)]
+pub struct UsersQuery {
+    /// Only users whose display name contains this (case-insensitive)
+    pub search: Option<String>,
+    #[serde(default)]
+    pub sort: UserSort,
+    /// `next_cursor` of the previous page
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+/// List the users who opted into the public directory, a page at a time
+/// GET /api/users?search=&sort=name|last_seen&cursor=&limit=
+pub async fn list_users(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<overachiever_core::UserProfile>>, (StatusCode, Json<serde_json::Value>)> {
-    match crate::db::get_all_users(&state.db_pool).await {
-        Ok(users) => Ok(Json(users)),
+    Query(query): Query<UsersQuery>,
+) -> Result<Json<UserPage>, ApiError> {
+    let cursor = match query.cursor.as_deref().filter(|c| !c.is_empty()) {
+        Some(cursor) => match cursor.parse::<i64>() {
+            Ok(steam_id) => Some(steam_id),
+            Err(_) => {
+                return Err(ApiError::bad_request("Invalid cursor"));
+            }
+        },
+        None => None,
+    };
+    let search = query.search.as_deref().map(str::trim).filter(|s| !s.is_empty());
+    let limit = query.limit.unwrap_or(USER_PAGE_DEFAULT).clamp(1, USER_PAGE_MAX);
+
+    match crate::db::list_users(&state.db_pool, search, query.sort, cursor, limit).await {
+        Ok(page) => Ok(Json(page)),
         Err(e) => {
             tracing::error!("Failed to fetch users: {:?}", e);
-            Err((
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(serde_json::json!({"error": "Failed to fetch users"}))
-            ))
+            Err(ApiError::internal("Failed to fetch users"))
         }
     }
 }
@@ -6,6 +6,7 @@ const API_OWNED_GAMES: &str = "https://api.steampowered.com/IPlayerService/GetOw
 const API_RECENTLY_PLAYED: &str = "https://api.steampowered.com/IPlayerService/GetRecentlyPlayedGames/v1/";
 const API_ACHIEVEMENTS: &str = "http://api.steampowered.com/ISteamUserStats/GetPlayerAchievements/v0001/";
 const API_SCHEMA: &str = "http://api.steampowered.com/ISteamUserStats/GetSchemaForGame/v2/";
+const API_GLOBAL_PERCENTAGES: &str = "https://api.steampowered.com/ISteamUserStats/GetGlobalAchievementPercentagesForApp/v0002/";
 
 pub async fn fetch_owned_games(
     steam_key: &str,
@@ -123,3 +124,35 @@ pub async fn fetch_achievement_schema(
     
     Ok(schema)
 }
+
+/// Global unlock percentage of every achievement of a game (no API key needed).
+/// Games without achievements, or unknown to Steam, return an empty list.
+pub async fn fetch_global_percentages(
+    client: &reqwest::Client,
+    appid: u64,
+) -> Result<Vec<(String, f32)>, Box<dyn std::error::Error + Send + Sync>> {
+    let url = format!("{}?gameid={}&format=json", API_GLOBAL_PERCENTAGES, appid);
+    let response = client.get(&url).send().await?;
+    // Steam answers 403/404 for apps without achievement stats
+    if !response.status().is_success() {
+        return Ok(Vec::new());
+    }
+    let body: serde_json::Value = response.json().await?;
+    
+    let percentages = body["achievementpercentages"]["achievements"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|a| {
+                    let name = a["name"].as_str()?;
+                    // The percent is a number or a string depending on the app
+                    let percent = a["percent"].as_f64()
+                        .or_else(|| a["percent"].as_str().and_then(|p| p.parse().ok()))?;
+                    Some((name.to_string(), percent as f32))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    
+    Ok(percentages)
+}
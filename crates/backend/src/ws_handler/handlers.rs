@@ -1,18 +1,40 @@
 //! WebSocket message handlers
 
-use axum::extract::ws::Message;
+use axum::extract::ws::{CloseFrame, Message};
 use futures_util::{SinkExt, StreamExt};
 use std::sync::Arc;
-use overachiever_core::{ClientMessage, ServerMessage};
+use std::time::Duration;
+use overachiever_core::{
+    games_delta, ClientMessage, CloseReason, ServerMessage, HEARTBEAT_INTERVAL_SECS, HEARTBEAT_TIMEOUT_SECS,
+    MIN_PROTOCOL_VERSION, PROTOCOL_VERSION,
+};
 use crate::AppState;
 
 pub async fn handle_socket(socket: axum::extract::ws::WebSocket, state: Arc<AppState>) {
+    let _connection = crate::monitoring::WsConnectionGuard::open();
     let (mut sender, mut receiver) = socket.split();
     
     // Track authenticated user
     let mut authenticated_steam_id: Option<String> = None;
+    // Set by `Hello` - only clients that know the heartbeat are timed out
+    let mut protocol_version: Option<u32> = None;
+    let idle_timeout = Duration::from_secs(HEARTBEAT_TIMEOUT_SECS);
     
-    while let Some(msg) = receiver.next().await {
+    loop {
+        let next = if protocol_version.is_some() {
+            match tokio::time::timeout(idle_timeout, receiver.next()).await {
+                Ok(next) => next,
+                Err(_) => {
+                    close(&mut sender, CloseReason::IdleTimeout).await;
+                    break;
+                }
+            }
+        } else {
+            receiver.next().await
+        };
+        let Some(msg) = next else {
+            break;
+        };
         let msg = match msg {
             Ok(Message::Text(text)) => text,
             Ok(Message::Close(_)) => break,
@@ -20,7 +42,8 @@ pub async fn handle_socket(socket: axum::extract::ws::WebSocket, state: Arc<AppS
                 let _ = sender.send(Message::Pong(data)).await;
                 continue;
             }
-            _ => continue,
+            Ok(_) => continue,
+            Err(_) => break,
         };
         
         // Parse client message
@@ -35,14 +58,37 @@ pub async fn handle_socket(socket: axum::extract::ws::WebSocket, state: Arc<AppS
             }
         };
         
-        // Handle message
-        let response = handle_client_message(client_msg, &mut sender, &state, &mut authenticated_steam_id).await;
+        // Handshake, answered here since an outdated client gets disconnected
+        let response = if let ClientMessage::Hello { version } = client_msg {
+            if version < MIN_PROTOCOL_VERSION {
+                close(&mut sender, CloseReason::UnsupportedVersion { min_version: MIN_PROTOCOL_VERSION }).await;
+                break;
+            }
+            protocol_version = Some(version);
+            ServerMessage::Welcome { version: PROTOCOL_VERSION, heartbeat_secs: HEARTBEAT_INTERVAL_SECS }
+        } else {
+            handle_client_message(client_msg, &mut sender, &state, &mut authenticated_steam_id).await
+        };
         
         let response_text = serde_json::to_string(&response).unwrap();
         if sender.send(Message::Text(response_text.into())).await.is_err() {
             break;
         }
     }
+    
+    // Presence ends with the user's last connection
+    if let Some(steam_id) = authenticated_steam_id {
+        state.presence.disconnect(&steam_id);
+    }
+}
+
+/// Tell the client why, then close the connection
+async fn close(sender: &mut futures_util::stream::SplitSink<axum::extract::ws::WebSocket, Message>, reason: CloseReason) {
+    tracing::debug!("Closing WebSocket: {}", reason);
+    let closing = ServerMessage::Closing { reason: reason.clone() };
+    let _ = sender.send(Message::Text(serde_json::to_string(&closing).unwrap().into())).await;
+    let frame = CloseFrame { code: reason.close_code(), reason: reason.to_string().into() };
+    let _ = sender.send(Message::Close(Some(frame))).await;
 }
 
 async fn handle_client_message(
@@ -55,13 +101,19 @@ async fn handle_client_message(
         ClientMessage::Authenticate { token } => {
             match crate::auth::verify_jwt(&token, &state.jwt_secret) {
                 Ok(claims) => {
-                    *authenticated_steam_id = Some(claims.steam_id.clone());
+                    state.presence.connect(&claims.steam_id);
+                    if let Some(previous) = authenticated_steam_id.replace(claims.steam_id.clone()) {
+                        state.presence.disconnect(&previous);
+                    }
+                    let customization = crate::db::get_profile_customization(&state.db_pool, &claims.steam_id).await
+                        .unwrap_or_default();
                     ServerMessage::Authenticated {
                         user: overachiever_core::UserProfile {
                             steam_id: claims.steam_id,
                             display_name: claims.display_name,
                             avatar_url: claims.avatar_url,
                             short_id: claims.short_id,
+                            customization,
                         }
                     }
                 }
@@ -71,6 +123,49 @@ async fn handle_client_message(
         
         ClientMessage::Ping => ServerMessage::Pong,
         
+        // Answered in handle_socket
+        ClientMessage::Hello { .. } => ServerMessage::Error { message: "Unexpected Hello".to_string() },
+        
+        ClientMessage::Resync { games } => {
+            if let Some(ref steam_id) = authenticated_steam_id {
+                match crate::db::get_user_games(&state.db_pool, steam_id).await {
+                    Ok(current) => {
+                        let (changed, removed) = games_delta(current, &games);
+                        tracing::debug!("Resync for {}: {} changed, {} removed", steam_id, changed.len(), removed.len());
+                        ServerMessage::GamesDelta { changed, removed }
+                    }
+                    Err(e) => ServerMessage::Error { message: format!("Database error: {:?}", e) }
+                }
+            } else {
+                ServerMessage::AuthError { reason: "Not authenticated".to_string() }
+            }
+        }
+        
+        ClientMessage::UpdatePresence { game } => {
+            if let Some(ref steam_id) = authenticated_steam_id {
+                // Name and avatar come from the users table, not the client
+                match crate::db::get_user_display(&state.db_pool, steam_id).await {
+                    Ok(Some((display_name, avatar_url))) => {
+                        state.presence.update(steam_id, display_name, avatar_url, game);
+                        ServerMessage::Presence { users: state.presence.others(steam_id) }
+                    }
+                    Ok(None) => ServerMessage::Error { message: "User not found".to_string() },
+                    Err(e) => ServerMessage::Error { message: e.to_string() }
+                }
+            } else {
+                ServerMessage::AuthError { reason: "Not authenticated".to_string() }
+            }
+        }
+        
+        ClientMessage::ClearPresence => {
+            if let Some(ref steam_id) = authenticated_steam_id {
+                state.presence.remove(steam_id);
+                ServerMessage::Presence { users: Vec::new() }
+            } else {
+                ServerMessage::AuthError { reason: "Not authenticated".to_string() }
+            }
+        }
+        
         ClientMessage::FetchGames => {
             if let Some(ref steam_id) = authenticated_steam_id {
                 tracing::debug!("Fetching games for steam_id: {}", steam_id);
@@ -235,8 +330,14 @@ async fn handle_client_message(
         ClientMessage::SubmitAchievementComment { achievements, comment } => {
             if let Some(ref steam_id) = authenticated_steam_id {
                 tracing::info!(steam_id = %steam_id, achievements = ?achievements, comment = %comment, "Achievement comment submitted");
-                // TODO: Store comment in database
-                ServerMessage::AchievementCommentSubmitted { count: achievements.len() }
+                if comment.trim().is_empty() {
+                    ServerMessage::Error { message: "Comment cannot be empty".to_string() }
+                } else {
+                    match crate::db::insert_achievement_comments(&state.db_pool, steam_id, &achievements, comment.trim()).await {
+                        Ok(count) => ServerMessage::AchievementCommentSubmitted { count: count as usize },
+                        Err(e) => ServerMessage::Error { message: format!("Failed to store comment: {:?}", e) },
+                    }
+                }
             } else {
                 ServerMessage::AuthError { reason: "Not authenticated".to_string() }
             }
@@ -245,8 +346,18 @@ async fn handle_client_message(
         ClientMessage::ReportTtb { appid, main_seconds, extra_seconds, completionist_seconds } => {
             if let Some(ref steam_id) = authenticated_steam_id {
                 tracing::info!(steam_id = %steam_id, appid = %appid, "TTB report submitted");
-                match crate::db::report_ttb(&state.db_pool, steam_id, appid, main_seconds, extra_seconds, completionist_seconds).await {
-                    Ok(()) => {
+                let trusted = crate::routes::auth::is_admin(steam_id);
+                let result = match crate::db::validate_ttb_report(main_seconds, extra_seconds, completionist_seconds) {
+                    Ok(()) => crate::db::report_ttb(&state.db_pool, steam_id, appid, main_seconds, extra_seconds, completionist_seconds, trusted)
+                        .await
+                        .map_err(|e| e.to_string()),
+                    Err(e) => Err(e),
+                };
+                match result {
+                    Ok(status) => {
+                        if let crate::db::TtbReportStatus::Pending(reason) = &status {
+                            tracing::info!(steam_id = %steam_id, appid = %appid, reason = %reason, "TTB report held for review");
+                        }
                         // Fetch updated game data to return
                         match crate::db::get_user_games(&state.db_pool, steam_id).await {
                             Ok(games) => {
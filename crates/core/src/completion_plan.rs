@@ -0,0 +1,212 @@
+//! Completion planner: a game's remaining achievements ordered from easiest to hardest,
+//! each with a rough time estimate, as a checklist that can be exported and printed
+
+use std::collections::HashSet;
+
+use crate::year_review::escape_html;
+use crate::{AchievementDifficulty, GameAchievement};
+
+/// Assumed effort per unit of weight when there is no completionist time to spread out
+pub const MINUTES_PER_WEIGHT: f32 = 20.0;
+
+/// One remaining achievement in the plan
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanStep {
+    pub apiname: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// Community average rating, 1 (very easy) to 5 (extreme); None when nobody rated it
+    pub difficulty: Option<f32>,
+    /// Percentage of players who unlocked it
+    pub global_percent: Option<f32>,
+    pub estimated_minutes: f32,
+}
+
+/// Remaining achievements of a game in the suggested order
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompletionPlan {
+    pub steps: Vec<PlanStep>,
+    /// Whether the estimates split the remaining completionist time (otherwise `MINUTES_PER_WEIGHT`)
+    pub from_time_to_beat: bool,
+}
+
+impl CompletionPlan {
+    pub fn total_minutes(&self) -> f32 {
+        self.steps.iter().map(|s| s.estimated_minutes).sum()
+    }
+
+    /// Estimated time of the steps not in `done`
+    pub fn remaining_minutes(&self, done: &HashSet<String>) -> f32 {
+        self.steps.iter().filter(|s| !done.contains(&s.apiname)).map(|s| s.estimated_minutes).sum()
+    }
+}
+
+/// Difficulty used for ordering: the community rating, or a guess from rarity when unrated
+fn effective_difficulty(difficulty: Option<f32>, global_percent: Option<f32>) -> f32 {
+    if let Some(rating) = difficulty {
+        return rating.clamp(1.0, 5.0);
+    }
+    match global_percent {
+        Some(p) if p >= 50.0 => 1.0,
+        Some(p) if p >= 20.0 => 2.0,
+        Some(p) if p >= 5.0 => 3.0,
+        Some(p) if p >= 1.0 => 4.0,
+        Some(_) => 5.0,
+        None => 3.0,
+    }
+}
+
+/// Relative effort of a difficulty: doubles with every rating step (1 → 1, 5 → 16)
+fn difficulty_weight(difficulty: f32) -> f32 {
+    2f32.powf(difficulty - 1.0)
+}
+
+/// Plan the locked achievements, easiest first (ties broken by the most common first).
+/// `remaining_hours` is the completionist time still to go; it is split between the steps
+/// by difficulty weight. Without it every weight unit counts as `MINUTES_PER_WEIGHT`.
+pub fn build_completion_plan(
+    achievements: &[GameAchievement],
+    ratings: &[AchievementDifficulty],
+    remaining_hours: Option<f32>,
+) -> CompletionPlan {
+    let mut steps: Vec<(f32, PlanStep)> = achievements
+        .iter()
+        .filter(|a| !a.achieved)
+        .map(|a| {
+            let difficulty = ratings
+                .iter()
+                .find(|r| r.apiname == a.apiname && r.rating_count > 0)
+                .map(|r| r.avg_rating);
+            let step = PlanStep {
+                apiname: a.apiname.clone(),
+                name: a.name.clone(),
+                description: a.description.clone(),
+                difficulty,
+                global_percent: a.global_percent,
+                estimated_minutes: 0.0,
+            };
+            (effective_difficulty(difficulty, a.global_percent), step)
+        })
+        .collect();
+
+    steps.sort_by(|(a_diff, a), (b_diff, b)| {
+        a_diff
+            .total_cmp(b_diff)
+            .then_with(|| b.global_percent.unwrap_or(-1.0).total_cmp(&a.global_percent.unwrap_or(-1.0)))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    let total_weight: f32 = steps.iter().map(|(d, _)| difficulty_weight(*d)).sum();
+    let remaining = remaining_hours.filter(|h| *h > 0.0 && total_weight > 0.0);
+    let minutes_per_weight = remaining.map(|h| h * 60.0 / total_weight).unwrap_or(MINUTES_PER_WEIGHT);
+
+    CompletionPlan {
+        steps: steps
+            .into_iter()
+            .map(|(difficulty, mut step)| {
+                step.estimated_minutes = difficulty_weight(difficulty) * minutes_per_weight;
+                step
+            })
+            .collect(),
+        from_time_to_beat: remaining.is_some(),
+    }
+}
+
+/// "~45m" / "~2.5h"
+pub fn format_plan_minutes(minutes: f32) -> String {
+    if minutes < 60.0 {
+        format!("~{:.0}m", minutes.max(1.0))
+    } else {
+        format!("~{:.1}h", minutes / 60.0)
+    }
+}
+
+/// Difficulty and rarity of a step ("difficulty 2.4/5, 31.2% of players")
+fn step_details(step: &PlanStep) -> String {
+    let mut details = Vec::new();
+    if let Some(rating) = step.difficulty {
+        details.push(format!("difficulty {:.1}/5", rating));
+    }
+    if let Some(percent) = step.global_percent {
+        details.push(format!("{:.1}% of players", percent));
+    }
+    details.join(", ")
+}
+
+/// The plan as a Markdown checklist (steps in `done` are ticked)
+pub fn completion_plan_markdown(game_name: &str, plan: &CompletionPlan, done: &HashSet<String>) -> String {
+    let mut text = format!(
+        "# {} - completion plan\n\n{} achievements left, {} estimated\n\n",
+        game_name,
+        plan.steps.len(),
+        format_plan_minutes(plan.total_minutes())
+    );
+    for step in &plan.steps {
+        let check = if done.contains(&step.apiname) { "x" } else { " " };
+        let details = step_details(step);
+        text.push_str(&format!("- [{}] **{}** {}", check, step.name, format_plan_minutes(step.estimated_minutes)));
+        if !details.is_empty() {
+            text.push_str(&format!(" ({})", details));
+        }
+        if let Some(description) = step.description.as_deref().filter(|d| !d.is_empty()) {
+            text.push_str(&format!("\n  {}", description));
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// The plan as a standalone, printable HTML checklist (steps in `done` are ticked)
+pub fn completion_plan_html(game_name: &str, plan: &CompletionPlan, done: &HashSet<String>) -> String {
+    let rows: String = plan
+        .steps
+        .iter()
+        .map(|step| {
+            format!(
+                "<li><input type=\"checkbox\"{}><div><b>{}</b> <span class=\"time\">{}</span><p>{}</p><small>{}</small></div></li>\n",
+                if done.contains(&step.apiname) { " checked" } else { "" },
+                escape_html(&step.name),
+                format_plan_minutes(step.estimated_minutes),
+                escape_html(step.description.as_deref().unwrap_or("")),
+                escape_html(&step_details(step)),
+            )
+        })
+        .collect();
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{title} - completion plan</title>
+<style>
+body {{ font-family: system-ui, sans-serif; color: #1b1d23; margin: 0; padding: 32px; }}
+main {{ max-width: 720px; margin: 0 auto; }}
+h1 {{ margin-bottom: 4px; }}
+.summary {{ color: #555; margin-top: 0; }}
+ol {{ list-style: none; padding: 0; }}
+li {{ display: flex; gap: 12px; padding: 8px 0; border-bottom: 1px solid #ddd; break-inside: avoid; }}
+li input {{ width: 18px; height: 18px; margin-top: 2px; }}
+li p {{ margin: 2px 0; font-size: 14px; }}
+.time {{ color: #b07a10; font-size: 13px; }}
+small {{ color: #777; }}
+footer {{ margin-top: 32px; font-size: 12px; color: #888; }}
+</style>
+</head>
+<body>
+<main>
+<h1>{title}</h1>
+<p class="summary">{count} achievements left, {total} estimated</p>
+<ol>
+{rows}</ol>
+<footer>Generated by Overachiever</footer>
+</main>
+</body>
+</html>
+"#,
+        title = escape_html(game_name),
+        count = plan.steps.len(),
+        total = format_plan_minutes(plan.total_minutes()),
+        rows = rows,
+    )
+}
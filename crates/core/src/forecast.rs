@@ -0,0 +1,151 @@
+//! Completion forecast - projects achievement progress from the recent unlock pace
+//!
+//! Fits a least-squares trend to `achievement_history` and turns it into "you'll
+//! reach X% in <month>" estimates, optionally for a different hours-per-week pace.
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::{AchievementHistory, Game};
+
+/// Only history from this many days before the latest entry is used for the trend
+pub const FORECAST_WINDOW_DAYS: i64 = 90;
+
+/// History must span at least this many days before a forecast is made
+pub const MIN_FORECAST_SPAN_DAYS: f64 = 7.0;
+
+/// Projections further out than this are reported as out of reach
+const MAX_FORECAST_WEEKS: f64 = 52.0 * 50.0;
+
+/// Recent achievement pace and the current state it projects from
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompletionTrend {
+    /// Unlocks per week over the forecast window (least-squares slope)
+    pub unlocks_per_week: f64,
+    /// Average completion percentage points gained per unlock
+    pub completion_per_unlock: f64,
+    /// Lifetime unlocks per played hour (None without playtime data)
+    pub unlocks_per_hour: Option<f64>,
+    /// Days of history the trend is based on
+    pub span_days: f64,
+    pub unlocked: i32,
+    pub total: i32,
+    pub avg_completion: f32,
+}
+
+/// When a target will be reached
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForecastEta {
+    /// Already at or above the target
+    Reached,
+    /// Projected date
+    At(DateTime<Utc>),
+    /// No progress at this pace, or further out than 50 years
+    OutOfReach,
+}
+
+/// Least-squares slope of y over x
+fn linear_slope(points: &[(f64, f64)]) -> Option<f64> {
+    let n = points.len() as f64;
+    if points.len() < 2 {
+        return None;
+    }
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let covariance: f64 = points.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    let variance: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    (variance > 0.0).then(|| covariance / variance)
+}
+
+/// Fit the recent unlock trend. None if there isn't enough history yet.
+pub fn completion_trend(history: &[AchievementHistory], games: &[Game]) -> Option<CompletionTrend> {
+    let latest = history.iter().max_by_key(|h| h.recorded_at)?;
+    let window_start = latest.recorded_at - Duration::days(FORECAST_WINDOW_DAYS);
+    let window: Vec<&AchievementHistory> = history.iter().filter(|h| h.recorded_at >= window_start).collect();
+    let earliest = window.iter().min_by_key(|h| h.recorded_at)?;
+
+    let span_days = (latest.recorded_at - earliest.recorded_at).num_seconds() as f64 / 86400.0;
+    if span_days < MIN_FORECAST_SPAN_DAYS {
+        return None;
+    }
+
+    let weeks = |h: &AchievementHistory| (h.recorded_at - earliest.recorded_at).num_seconds() as f64 / (7.0 * 86400.0);
+    let unlock_points: Vec<(f64, f64)> = window.iter().map(|h| (weeks(h), h.unlocked_achievements as f64)).collect();
+    let unlocks_per_week = linear_slope(&unlock_points)?.max(0.0);
+
+    // Average completion moves by roughly 100 / total per unlock; prefer the observed ratio
+    let unlocked_delta = (latest.unlocked_achievements - earliest.unlocked_achievements) as f64;
+    let completion_delta = (latest.avg_completion_percent - earliest.avg_completion_percent) as f64;
+    let completion_per_unlock = if unlocked_delta > 0.0 && completion_delta > 0.0 {
+        completion_delta / unlocked_delta
+    } else {
+        100.0 / latest.total_achievements.max(1) as f64
+    };
+
+    let (played_unlocks, played_minutes) = games
+        .iter()
+        .filter(|g| g.playtime_forever > 0 && g.achievements_total.unwrap_or(0) > 0)
+        .fold((0i64, 0i64), |(unlocks, minutes), g| {
+            (unlocks + g.achievements_unlocked.unwrap_or(0) as i64, minutes + g.playtime_forever as i64)
+        });
+    let unlocks_per_hour = (played_unlocks > 0 && played_minutes > 0).then(|| played_unlocks as f64 / (played_minutes as f64 / 60.0));
+
+    Some(CompletionTrend {
+        unlocks_per_week,
+        completion_per_unlock,
+        unlocks_per_hour,
+        span_days,
+        unlocked: latest.unlocked_achievements,
+        total: latest.total_achievements,
+        avg_completion: latest.avg_completion_percent,
+    })
+}
+
+impl CompletionTrend {
+    /// Hours per week the current pace corresponds to
+    pub fn implied_hours_per_week(&self) -> Option<f64> {
+        self.unlocks_per_hour.filter(|rate| *rate > 0.0).map(|rate| self.unlocks_per_week / rate)
+    }
+
+    /// Unlocks per week when playing `hours_per_week` (None = current pace)
+    pub fn unlocks_per_week_at(&self, hours_per_week: Option<f64>) -> f64 {
+        match (hours_per_week, self.unlocks_per_hour) {
+            (Some(hours), Some(rate)) => hours * rate,
+            _ => self.unlocks_per_week,
+        }
+    }
+
+    /// Overall achievement percentage (unlocked of all achievements)
+    pub fn overall_percent(&self) -> f64 {
+        if self.total > 0 {
+            self.unlocked as f64 / self.total as f64 * 100.0
+        } else {
+            0.0
+        }
+    }
+
+    /// When average game completion reaches `target` percent
+    pub fn avg_completion_eta(&self, target: f32, unlocks_per_week: f64, now: DateTime<Utc>) -> ForecastEta {
+        let unlocks_needed = (target - self.avg_completion) as f64 / self.completion_per_unlock;
+        eta(unlocks_needed, unlocks_per_week, now)
+    }
+
+    /// When the overall achievement percentage reaches `target` percent (library size unchanged)
+    pub fn overall_eta(&self, target: f32, unlocks_per_week: f64, now: DateTime<Utc>) -> ForecastEta {
+        let unlocks_needed = target as f64 / 100.0 * self.total as f64 - self.unlocked as f64;
+        eta(unlocks_needed, unlocks_per_week, now)
+    }
+}
+
+fn eta(unlocks_needed: f64, unlocks_per_week: f64, now: DateTime<Utc>) -> ForecastEta {
+    if unlocks_needed <= 0.0 {
+        return ForecastEta::Reached;
+    }
+    if unlocks_per_week <= 0.0 {
+        return ForecastEta::OutOfReach;
+    }
+    let weeks = unlocks_needed / unlocks_per_week;
+    if weeks > MAX_FORECAST_WEEKS {
+        return ForecastEta::OutOfReach;
+    }
+    ForecastEta::At(now + Duration::seconds((weeks * 7.0 * 86400.0) as i64))
+}
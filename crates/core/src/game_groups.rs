@@ -0,0 +1,209 @@
+//! Grouping of the games table: the filtered, sorted games split into groups
+//! (by first letter, last played year or completion) with per-group totals
+
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::Datelike;
+use serde::{Deserialize, Serialize};
+
+use crate::{tr, Game, TimeDisplaySettings};
+
+/// How the games table is grouped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupBy {
+    #[default]
+    None,
+    /// First letter of the name, A-Z (digits and symbols under #)
+    Letter,
+    /// Year the game was last played, newest first
+    LastPlayedYear,
+    /// Completion bucket, 100% first
+    Completion,
+}
+
+impl GroupBy {
+    pub fn label(&self) -> &'static str {
+        match self {
+            GroupBy::None => tr("group.none"),
+            GroupBy::Letter => tr("group.letter"),
+            GroupBy::LastPlayedYear => tr("group.last_played_year"),
+            GroupBy::Completion => tr("group.completion"),
+        }
+    }
+
+    pub fn all() -> &'static [GroupBy] {
+        &[GroupBy::None, GroupBy::Letter, GroupBy::LastPlayedYear, GroupBy::Completion]
+    }
+}
+
+/// Games sharing a group key, in table order, with their totals
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameGroup {
+    pub label: String,
+    /// Positions in the filtered list
+    pub members: Vec<usize>,
+    pub playtime_minutes: u64,
+    pub unlocked: i64,
+    pub total: i64,
+    /// Mean completion of the games with achievements
+    pub avg_completion: Option<f32>,
+    /// Games at 100%
+    pub perfect: usize,
+}
+
+/// A table row once grouping is applied
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GroupedRow {
+    /// Header of a group (index into the groups)
+    Header(usize),
+    /// A game (position in the filtered list)
+    Game(usize),
+}
+
+/// Sort rank and label of the group a game falls into
+fn group_key(game: &Game, group_by: GroupBy, time: &TimeDisplaySettings) -> (i64, String) {
+    match group_by {
+        GroupBy::None => (0, String::new()),
+        GroupBy::Letter => match game.name.trim().chars().next() {
+            Some(c) if c.is_alphabetic() => {
+                let upper: String = c.to_uppercase().collect();
+                (upper.chars().next().map_or(0, |u| u as i64), upper)
+            }
+            _ => (0, "#".to_string()),
+        },
+        GroupBy::LastPlayedYear => {
+            let played = game
+                .rtime_last_played
+                .filter(|ts| *ts > 0)
+                .and_then(|ts| chrono::DateTime::from_timestamp(ts as i64, 0));
+            match played {
+                Some(at) => {
+                    let year = time.to_display_time(at).year();
+                    (-(year as i64), year.to_string())
+                }
+                None => (i64::MAX, tr("group.never_played").to_string()),
+            }
+        }
+        GroupBy::Completion => match game.completion_percent() {
+            Some(pct) if pct >= 100.0 => (0, "100%".to_string()),
+            Some(pct) if pct >= 75.0 => (1, "75–99%".to_string()),
+            Some(pct) if pct >= 50.0 => (2, "50–74%".to_string()),
+            Some(pct) if pct >= 25.0 => (3, "25–49%".to_string()),
+            Some(pct) if pct > 0.0 => (4, tr("group.under_25").to_string()),
+            Some(_) => (5, "0%".to_string()),
+            None => (6, tr("group.no_achievements").to_string()),
+        },
+    }
+}
+
+/// Split the filtered games (`filtered` indexes `games`) into groups.
+/// Games keep their table order within a group; groups come in their natural order.
+pub fn group_games(games: &[Game], filtered: &[usize], group_by: GroupBy, time: &TimeDisplaySettings) -> Vec<GameGroup> {
+    let mut groups: BTreeMap<(i64, String), (GameGroup, f32, usize)> = BTreeMap::new();
+    for (position, &idx) in filtered.iter().enumerate() {
+        let game = &games[idx];
+        let key = group_key(game, group_by, time);
+        let (group, completion_sum, with_achievements) = groups.entry(key.clone()).or_insert_with(|| {
+            let group = GameGroup {
+                label: key.1,
+                members: Vec::new(),
+                playtime_minutes: 0,
+                unlocked: 0,
+                total: 0,
+                avg_completion: None,
+                perfect: 0,
+            };
+            (group, 0.0, 0)
+        });
+        group.members.push(position);
+        group.playtime_minutes += game.playtime_forever as u64;
+        if let Some(pct) = game.completion_percent() {
+            group.unlocked += game.achievements_unlocked.unwrap_or(0) as i64;
+            group.total += game.achievements_total.unwrap_or(0) as i64;
+            *completion_sum += pct;
+            *with_achievements += 1;
+            if pct >= 100.0 {
+                group.perfect += 1;
+            }
+        }
+    }
+    groups
+        .into_values()
+        .map(|(mut group, completion_sum, with_achievements)| {
+            group.avg_completion = (with_achievements > 0).then(|| completion_sum / with_achievements as f32);
+            group
+        })
+        .collect()
+}
+
+/// Table rows: each group's header followed by its games, unless the group is collapsed
+pub fn grouped_rows(groups: &[GameGroup], collapsed: &HashSet<String>) -> Vec<GroupedRow> {
+    let mut rows = Vec::new();
+    for (i, group) in groups.iter().enumerate() {
+        rows.push(GroupedRow::Header(i));
+        if !collapsed.contains(&group.label) {
+            rows.extend(group.members.iter().map(|&position| GroupedRow::Game(position)));
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(name: &str, unlocked: Option<i32>, total: Option<i32>) -> Game {
+        serde_json::from_value(serde_json::json!({
+            "appid": 1,
+            "name": name,
+            "playtime_forever": 90,
+            "rtime_last_played": null,
+            "img_icon_url": null,
+            "added_at": "2024-01-01T00:00:00Z",
+            "achievements_total": total,
+            "achievements_unlocked": unlocked,
+            "last_achievement_scrape": null,
+            "avg_user_ttb_main_seconds": null,
+            "avg_user_ttb_extra_seconds": null,
+            "avg_user_ttb_completionist_seconds": null,
+            "user_ttb_report_count": 0,
+            "my_ttb_main_seconds": null,
+            "my_ttb_extra_seconds": null,
+            "my_ttb_completionist_seconds": null,
+            "my_ttb_reported_at": null,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn groups_by_completion_with_totals() {
+        let games = vec![
+            game("Portal", Some(10), Some(10)),
+            game("Braid", Some(1), Some(10)),
+            game("Celeste", Some(20), Some(20)),
+            game("Tool", None, None),
+        ];
+        let groups = group_games(&games, &[0, 1, 2, 3], GroupBy::Completion, &TimeDisplaySettings::default());
+        let labels: Vec<&str> = groups.iter().map(|g| g.label.as_str()).collect();
+        assert_eq!(labels, ["100%", "Under 25%", "No achievements"]);
+        assert_eq!(groups[0].members, [0, 2]);
+        assert_eq!((groups[0].unlocked, groups[0].total, groups[0].perfect), (30, 30, 2));
+        assert_eq!(groups[0].playtime_minutes, 180);
+        assert_eq!(groups[2].avg_completion, None);
+    }
+
+    #[test]
+    fn collapsed_groups_keep_only_their_header() {
+        let games = vec![game("beta", None, None), game("Alpha", None, None), game("2064", None, None)];
+        let groups = group_games(&games, &[0, 1, 2], GroupBy::Letter, &TimeDisplaySettings::default());
+        let labels: Vec<&str> = groups.iter().map(|g| g.label.as_str()).collect();
+        assert_eq!(labels, ["#", "A", "B"]);
+
+        let collapsed: HashSet<String> = ["A".to_string()].into();
+        assert_eq!(
+            grouped_rows(&groups, &collapsed),
+            [GroupedRow::Header(0), GroupedRow::Game(2), GroupedRow::Header(1), GroupedRow::Header(2), GroupedRow::Game(0)]
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Backlog goals ("finish 2 games per month", "reach 75% average completion by June")
+//! and how far along the schedule they are
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{tr, tr_args, TimeDisplaySettings};
+
+/// What a goal measures
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum GoalKind {
+    /// Perfect `count` games every calendar month
+    GamesPerMonth { count: u32 },
+    /// Reach `target` percent average game completion by `deadline`
+    AvgCompletionBy {
+        target: f32,
+        deadline: NaiveDate,
+        /// Average completion when the goal was set (the schedule starts there)
+        start: f32,
+    },
+}
+
+/// A user goal, identified by when it was created
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Goal {
+    pub kind: GoalKind,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Goal {
+    /// Human readable description, e.g. "Finish 2 games per month"
+    pub fn label(&self) -> String {
+        match &self.kind {
+            GoalKind::GamesPerMonth { count: 1 } => tr("goal.one_game_per_month").to_string(),
+            GoalKind::GamesPerMonth { count } => tr_args("goal.games_per_month", &[("count", count)]),
+            GoalKind::AvgCompletionBy { target, deadline, .. } => tr_args(
+                "goal.avg_completion_by",
+                &[("target", &format!("{:.0}", target)), ("date", &deadline.format("%b %-d, %Y"))],
+            ),
+        }
+    }
+}
+
+/// Progress of a goal at a point in time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GoalProgress {
+    pub current: f32,
+    pub target: f32,
+    /// Where the schedule says the user should be by now
+    pub expected: f32,
+    /// Deadline passed (deadline goals only)
+    pub expired: bool,
+}
+
+impl GoalProgress {
+    pub fn is_done(&self) -> bool {
+        self.current >= self.target
+    }
+
+    /// Progress towards the target as 0.0-1.0
+    pub fn fraction(&self) -> f32 {
+        if self.target > 0.0 {
+            (self.current / self.target).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Not done and noticeably behind the schedule (counts need a whole game, percentages half a point)
+    pub fn is_behind(&self, kind: &GoalKind) -> bool {
+        if self.is_done() {
+            return false;
+        }
+        match kind {
+            GoalKind::GamesPerMonth { .. } => self.current < self.expected.floor(),
+            GoalKind::AvgCompletionBy { .. } => self.expired || self.current < self.expected - 0.5,
+        }
+    }
+}
+
+fn days_in_month(date: NaiveDate) -> u32 {
+    let (year, month) = if date.month() == 12 { (date.year() + 1, 1) } else { (date.year(), date.month() + 1) };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|next| next.pred_opt())
+        .map(|last| last.day())
+        .unwrap_or(30)
+}
+
+/// Progress of `goal` as of `now`.
+/// `perfect_dates` are the days games were perfected (see `AchievementRecords`),
+/// `avg_completion` the current average game completion in percent.
+pub fn goal_progress(
+    goal: &Goal,
+    perfect_dates: &[DateTime<Utc>],
+    avg_completion: f32,
+    now: DateTime<Utc>,
+    time: &TimeDisplaySettings,
+) -> GoalProgress {
+    let today = time.display_date(now);
+    match &goal.kind {
+        GoalKind::GamesPerMonth { count } => {
+            let finished = perfect_dates
+                .iter()
+                .map(|d| time.display_date(*d))
+                .filter(|d| d.year() == today.year() && d.month() == today.month())
+                .count();
+            let month_elapsed = today.day() as f32 / days_in_month(today) as f32;
+            GoalProgress {
+                current: finished as f32,
+                target: *count as f32,
+                expected: *count as f32 * month_elapsed,
+                expired: false,
+            }
+        }
+        GoalKind::AvgCompletionBy { target, deadline, start } => {
+            let created = time.display_date(goal.created_at);
+            let total_days = (*deadline - created).num_days().max(1) as f32;
+            let elapsed = ((today - created).num_days() as f32 / total_days).clamp(0.0, 1.0);
+            GoalProgress {
+                current: avg_completion,
+                target: *target,
+                expected: start + (target - start) * elapsed,
+                expired: today > *deadline,
+            }
+        }
+    }
+}
@@ -0,0 +1,1022 @@
+//! German strings
+
+pub(super) fn lookup(key: &str) -> Option<&'static str> {
+    Some(match key {
+        // Shared
+        "common.all" => "Alle",
+        "common.any" => "Beliebig",
+        "common.yes" => "Ja",
+        "common.no" => "Nein",
+        "common.clear" => "Zurücksetzen",
+        "common.remove" => "Entfernen",
+        "common.dismiss" => "Ausblenden",
+        "common.ok" => "OK",
+        "common.cancel" => "Abbrechen",
+
+        // Toolbar
+        "toolbar.build" => "Build #{number}\n{date}",
+        "toolbar.update" => "Aktualisieren",
+        "toolbar.update_tooltip" => "Erfolge für kürzlich gespielte Spiele und alle Spiele mit geänderter Spielzeit abrufen.
+Letzte Aktualisierung: {when}",
+        "toolbar.never" => "nie",
+        "toolbar.configure_first" => "Bitte Steam-API-Schlüssel und Steam-ID in den Einstellungen (⚙) eintragen",
+        "toolbar.full_scan" => "Vollständiger Scan",
+        "toolbar.force" => "Erzwingen",
+        "toolbar.refresh_stale" => "Veraltete aktualisieren ({count})",
+        "toolbar.refresh_stale_tooltip" => "Erfolge von Spielen neu abrufen, deren Daten älter als {days} Tage sind",
+        "toolbar.report_times" => "Zeiten melden ({count})",
+        "toolbar.report_times_tooltip" => "Spielzeiten für abgeschlossene Spiele melden - geht deine zu 100% abgeschlossenen Spiele nacheinander durch",
+        "toolbar.settings" => "Einstellungen",
+        "toolbar.final_stretch" => "Endspurt",
+        "toolbar.stalled_review" => "Liegengebliebene Spiele prüfen",
+        "toolbar.rival" => "Rivalitäts-Übersicht",
+        "toolbar.year_review" => "Jahresrückblick",
+        "toolbar.compare_snapshots" => "Snapshots vergleichen",
+        "toolbar.behind_schedule" => "Hinter dem Zeitplan:\n{goals}",
+        "toolbar.profile_menu" => "Profilmenü",
+        "toolbar.login" => "Mit Steam anmelden",
+        "toolbar.power_full" => "Hintergrund-Scans laufen mit voller Geschwindigkeit (manuell)",
+        "toolbar.power_slow" => "Hintergrund-Scans verlangsamt",
+        "toolbar.power_paused" => "Hintergrund-Scans pausiert",
+        "toolbar.paused_playing" => "{game} läuft, die Scans gehen weiter, sobald du aufhörst",
+        "toolbar.power_click_auto" => "Klicken, um sich wieder nach Akku und Last zu richten",
+        "toolbar.power_click_full" => "Klicken, um trotzdem mit voller Geschwindigkeit zu scannen",
+        "toolbar.stop_ttb" => "TTB stoppen",
+        "toolbar.ttb_scan" => "TTB-Scan",
+        "toolbar.ttb_scan_count" => "TTB-Scan ({count})",
+        "toolbar.ttb_scan_tooltip" => "Spielzeiten auf HowLongToBeat abrufen (1 Spiel/Minute)",
+        "toolbar.ttb_download" => "TTB laden",
+        "toolbar.ttb_download_tooltip" => "Alle TTB-Zeiten vom Server herunterladen",
+        "toolbar.stop_tags" => "Tags stoppen",
+        "toolbar.tags_scan" => "Tag-Scan",
+        "toolbar.tags_scan_count" => "Tag-Scan ({count})",
+        "toolbar.tags_scan_tooltip" => "Spiel-Tags von SteamSpy abrufen (1 Spiel/{secs}s)",
+        "toolbar.queued_refreshes" => "Ausstehende Aktualisierungen:\n{games}",
+        "toolbar.admin_on" => "Admin-Modus: AN\nKlicken, um TTB-Scans zu deaktivieren",
+        "toolbar.admin_off" => "Admin-Modus: AUS\nKlicken, um TTB-Scans zu aktivieren",
+        "toolbar.admin_dashboard" => "Admin-Dashboard",
+
+        // Settings
+        "settings.title" => "Einstellungen",
+        "settings.tab_general" => "Allgemein",
+        "settings.tab_steam" => "Steam",
+        "settings.tab_debug" => "Debug",
+        "settings.language" => "Sprache",
+        "settings.language_label" => "Sprache:",
+        "settings.appearance" => "Darstellung",
+        "settings.font_source" => "Schriftart:",
+        "settings.startup" => "Programmstart",
+        "settings.check_for_updates" => "Beim Start nach neuen Versionen suchen",
+        "settings.show_changelog" => "Änderungsprotokoll anzeigen",
+        "settings.current_version" => "Version {version}",
+        "settings.notifications" => "Benachrichtigungen",
+        "settings.statistics" => "Statistiken",
+        "settings.games_table" => "Spieletabelle",
+        "settings.network" => "Netzwerk",
+        "settings.stream_overlay" => "Stream-Overlay",
+        "settings.cloud_sync" => "Cloud-Synchronisierung",
+        "settings.presence" => "Gerade am Spielen",
+        "settings.icon_cache" => "Icon-Cache",
+        "settings.download_all_icons" => "Alle Icons herunterladen",
+        "settings.download_all_icons_tooltip" => "Lädt alle Erfolgs- und Spiel-Icons, damit aufgeklappte Zeilen und das Log offline funktionieren. Bereits geladene Icons werden übersprungen, ein abgebrochener Lauf macht also dort weiter. Stoppt, sobald der Cache seine maximale Größe erreicht, und wird wie die Hintergrund-Scans verlangsamt oder pausiert.",
+        "settings.icons_downloaded" => "{count} Icons heruntergeladen ({cached} waren bereits vorhanden)",
+        "settings.icons_failed" => ", {count} fehlgeschlagen",
+        "settings.icons_stopped" => " - vorzeitig beendet, zum Fortsetzen erneut starten",
+        "settings.dates_times" => "Datum & Uhrzeit",
+        "settings.data_location" => "Speicherort",
+        "settings.library_file" => "Bibliotheksdatei",
+        "settings.steam_credentials" => "Steam-Zugangsdaten",
+        "settings.font_builtin" => "Eingebaut (egui-Standard)",
+        "settings.font_cjk" => "CJK (Source Han Sans)",
+        "settings.font_system" => "Windows-Schriftart",
+        "settings.font_not_downloaded" => "Schriftart nicht heruntergeladen",
+        "settings.font_download" => "Source Han Sans herunterladen (~100 MB)",
+        "settings.font_license" => "Lizenz anzeigen",
+        "settings.font_starting_download" => "Download startet...",
+        "settings.font_downloading_of" => "Lade herunter: {done} / {total} MB",
+        "settings.font_downloading" => "Lade herunter: {done} MB",
+        "settings.font_extracting" => "Schriftart wird entpackt...",
+        "settings.font_download_complete" => "Download abgeschlossen!",
+        "settings.font_download_failed" => "Download fehlgeschlagen: {error}",
+        "settings.font_downloaded" => "Schriftart heruntergeladen",
+        "settings.font_weight" => "Schriftstärke:",
+        "settings.font" => "Schriftart:",
+        "settings.font_select" => "Auswählen...",
+        "settings.font_size" => "Schriftgröße:",
+        "settings.font_size_save" => "Schriftgröße speichern",
+        "settings.unsaved" => "(nicht gespeichert)",
+        "settings.startup_view" => "Beim Start öffnen:",
+        "settings.startup_sidebar" => "Seitenleiste beim Start aufklappen",
+        "settings.streak_reminder" => "Erinnern, wenn meine Freischalt-Serie abzubrechen droht",
+        "settings.streak_reminder_from" => "Erinnern ab:",
+        "settings.exclude_non_games" => "Nicht-Spiele aus den Statistiken ausschließen",
+        "settings.exclude_non_games_tooltip" => "Soundtracks, Tools, Demos usw. ({count} in deiner Bibliothek). App-Typen werden beim vollständigen Scan abgerufen.",
+        "settings.include_unowned" => "Nicht besessene Spiele in die Statistiken einbeziehen",
+        "settings.include_unowned_tooltip" => "Spiele aus Gratis-Wochenenden oder der Familienbibliothek ({count} in deiner Bibliothek)",
+        "settings.snapshot_interval" => "Verlaufs-Snapshot aufzeichnen alle",
+        "settings.days_suffix" => " Tage",
+        "settings.snapshot_interval_tooltip" => "Hält die Verlaufsgrafiken mit der gespeicherten Bibliothek regelmäßig, auch wenn du eine Weile nicht aktualisierst. 0 = aus.",
+        "settings.sort_ties" => "Gleichstände sortieren nach:",
+        "settings.sort_ties_tooltip" => "Reihenfolge von Spielen, die in der Sortierspalte gleich sind, z. B. alle Spiele mit 0 %. Automatisch: % dann Name, Spielzeit dann zuletzt gespielt.",
+        "settings.deck_column" => "Spalte für Steam-Deck-Spielzeit anzeigen",
+        "settings.deck_column_tooltip" => "Auf einem Steam Deck gespielte Zeit, aus der Spielzeit pro Plattform, die Steam bei jeder Aktualisierung meldet",
+        "settings.reveal_spoilers" => "Beschreibungen versteckter Erfolge anzeigen",
+        "settings.reveal_spoilers_tooltip" => "Steam versteckt manche Erfolge, bis sie freigeschaltet sind. Wenn aus, bleiben ihre Beschreibungen verdeckt, bis du sie anklickst.",
+        "settings.overlay_enabled" => "Stream-Overlay auf diesem Computer bereitstellen",
+        "settings.overlay_enabled_tooltip" => "Zeigt das aktuelle Spiel, seinen Fortschritt und die letzten Freischaltungen. Füge die URL in OBS als Browserquelle hinzu.",
+        "settings.overlay_port" => "Port:",
+        "settings.copy_url" => "URL kopieren",
+        "settings.auto_upload" => "Nach Aktualisierung / vollständigem Scan automatisch hochladen",
+        "settings.auto_upload_tooltip" => "Hält die Webversion aktuell. Fehlgeschlagene Uploads werden im Hintergrund wiederholt.",
+        "settings.link_first" => "Verknüpfe dein Konto mit der Cloud, um dies zu nutzen.",
+        "settings.share_presence" => "Anderen verknüpften Nutzern zeigen, was ich spiele",
+        "settings.share_presence_tooltip" => "Dein Steam-Name, das Spiel und dein Erfolgsfortschritt werden in der Seitenleiste anderer angezeigt, die ebenfalls teilen. Nichts wird gespeichert.",
+        "settings.icon_cache_max" => "Maximale Größe:",
+        "settings.icon_cache_usage" => "{mb} MB belegt - am längsten nicht genutzte Icons werden über dem Limit entfernt",
+        "settings.icon_memory_max" => "Speicherlimit:",
+        "settings.icon_memory_max_tooltip" => "Am längsten nicht angezeigte Icons werden darüber aus dem Speicher entfernt und bei Bedarf von der Festplatte neu geladen",
+        "settings.open_data_dir" => "Datenverzeichnis öffnen",
+        "settings.data_dir_default" => "Konfiguration, Datenbank und Icon-Cache liegen im App-Datenverzeichnis",
+        "settings.data_dir_portable" => "Portabler Modus - Daten liegen neben der Programmdatei",
+        "settings.data_dir_custom" => "Datenverzeichnis in den Einstellungen verschoben",
+        "settings.data_dir_restart" => "Daten kopiert - starte Overachiever neu, um den neuen Ort zu verwenden",
+        "settings.data_dir_busy" => "Warte, bis die laufende Aktualisierung oder der Scan fertig ist",
+        "settings.portable_off" => "Portablen Modus ausschalten",
+        "settings.portable_off_tooltip" => "Daten zurück ins App-Datenverzeichnis kopieren, den Ordner neben der Programmdatei nicht mehr verwenden und neu starten",
+        "settings.portable_on" => "Portablen Modus verwenden",
+        "settings.portable_on_tooltip" => "Daten in einen Ordner neben der Programmdatei kopieren, dort behalten (zum Starten von einem USB-Stick) und neu starten.\nWie der Start mit --portable.",
+        "settings.relocate_to" => "Verschieben nach:",
+        "settings.relocate_hint" => "Neuer Ordner",
+        "settings.relocate" => "Kopieren & verschieben",
+        "settings.relocate_tooltip" => "Konfiguration, Datenbank und Icon-Cache in diesen Ordner kopieren und neu starten, um ihn zu verwenden.\nDas aktuelle Verzeichnis bleibt unverändert.",
+        "settings.export_library" => "Bibliothek exportieren",
+        "settings.export_library_tooltip" => "Spiele, Erfolge, Verlauf und Ziele als .{ext}-Datei im Download-Ordner speichern",
+        "settings.import_library_hint" => "Öffne eine .{ext}-Datei mit Overachiever oder ziehe sie auf das Fenster, um sie anzusehen und zusammenzuführen",
+        "settings.time_zone" => "Zeitzone:",
+        "settings.timestamp_absolute" => "Datum (15.03.2024)",
+        "settings.timestamp_relative" => "Relativ (vor 3 Tagen)",
+        "settings.timestamps_log" => "Aktivitätslog:",
+        "settings.timestamps_achievements" => "Erfolge:",
+        "settings.show_time_of_day" => "Uhrzeit bei Datumsangaben anzeigen",
+        "settings.theme" => "Design:",
+        "settings.accent_color" => "Akzentfarbe:",
+        "settings.reset" => "Zurücksetzen",
+        "settings.complete_threshold" => "Fertig ab:",
+        "settings.complete_threshold_tooltip" => "Spiele ab diesem Fortschritt gelten in der %-Spalte, den Statistiken und im Endspurt als fertig",
+        "settings.near_complete_threshold" => "Fast fertig ab:",
+        "settings.near_complete_threshold_tooltip" => "Spiele ab diesem Fortschritt (und unter fertig) werden als fast fertig hervorgehoben und im Endspurt aufgeführt",
+        "settings.preview" => "Vorschau:",
+        "settings.credentials_checking" => "Prüfe bei Steam...",
+        "settings.profile_not_public" => "Dieses Profil ist nicht öffentlich: Steam listet seine Spiele nur für einen API-Schlüssel desselben Kontos.",
+        "settings.credentials_check" => "Zugangsdaten prüfen",
+        "settings.steam_id" => "Steam-ID:",
+        "settings.steam_id_tooltip" => "Deine 64-Bit-Steam-ID, Profil-URL oder eigener Profilname (wird beim Verlassen des Feldes aufgelöst)",
+        "settings.api_key" => "API-Schlüssel:",
+        "settings.api_key_hint" => "Dein Steam-API-Schlüssel",
+        "settings.get_api_key" => "API-Schlüssel holen",
+        "settings.no_affiliation" => "(Keine Verbindung)",
+        "settings.find_steam_id" => "Steam-ID herausfinden",
+        "settings.community_import_intro" => "Kein API-Schlüssel? Importiere deine Spieleliste aus einem öffentlichen Steam-Community-Profil:",
+        "settings.community_import" => "Aus Steam Community importieren",
+        "settings.community_import_tooltip" => "Die Spieldetails deines Profils müssen öffentlich sein",
+        "settings.community_import_file" => "Oder speichere steamcommunity.com/profiles/<id>/games?tab=all&xml=1 und ziehe die Datei auf das Fenster",
+        "settings.rival_intro" => "Rivale zum Vergleich der Erfolge (öffentliches Profil, nur gelesen):",
+        "settings.rival_hint" => "Steam-ID des Rivalen",
+        "settings.rival_scrape" => "Rivalen abrufen",
+        "settings.rival_scrape_tooltip" => "Ruft die Spiele und Erfolge des Rivalen für die Spiele ab, die ihr beide besitzt",
+        "settings.session_tracking" => "Sitzungserfassung:",
+        "settings.session_tracking_tooltip" => "Alle N Sekunden prüfen, welches Spiel du spielst, um Spielsitzungen aufzuzeichnen (0 = aus)",
+        "settings.power_mode" => "Hintergrund-Scans:",
+        "settings.power_currently" => "Aktuell: {status}",
+        "settings.pause_while_playing" => "Scans während des Spielens pausieren",
+        "settings.pause_while_playing_tooltip" => "Vollständige, TTB- und Tag-Scans warten, solange Steam dich im Spiel zeigt, und laufen danach weiter",
+        "settings.pause_needs_sessions" => "Benötigt die Sitzungserfassung, um das laufende Spiel zu erkennen",
+        "settings.local_unlocks" => "Freischaltungen über den Steam-Client erkennen (experimentell)",
+        "settings.local_unlocks_tooltip" => "Die vom Steam-Client auf diesem Computer gespeicherten Statistiken beobachten und Freischaltungen in Sekunden erfassen, ohne auf die nächste Aktualisierung zu warten",
+        "settings.local_unlocks_hint" => "Funktioniert nur für Spiele, die auf diesem Computer gespielt werden",
+        "settings.stale_after" => "Veraltet nach:",
+        "settings.stale_after_tooltip" => "Spiele, die länger nicht abgerufen wurden, erhalten ein Warnsymbol und werden von \"Veraltete aktualisieren\" erfasst",
+        "settings.credentials_required" => "Steam-ID und API-Schlüssel werden benötigt",
+        "settings.credentials_valid" => "Konfiguration gültig",
+        "settings.proxy" => "Proxy:",
+        "settings.proxy_tooltip" => "HTTP(S)- oder SOCKS5-Proxy für alle Anfragen. Leer verwendet die Proxy-Einstellungen des Systems.",
+        "settings.steam_api_url" => "Steam-API-URL:",
+        "settings.server_url" => "Server-URL:",
+        "settings.server_url_tooltip" => "Backend für Cloud-Sync, Bewertungen und Community-Daten",
+        "settings.debug" => "Debug",
+        "settings.debug_recently_played" => "Antwort zu kürzlich gespielten Spielen protokollieren",
+        "settings.debug_recently_played_tooltip" => "Beim Aktualisieren die API-Antwort zu kürzlich gespielten Spielen in recently_played_debug.txt schreiben",
+        "settings.config_files" => "Konfigurationsdateien:",
+        "settings.open_config_file" => "Konfigurationsdatei öffnen",
+        "settings.open_config_dir" => "Konfigurationsordner öffnen",
+        "settings.bug_reports" => "Fehlerberichte:",
+        "settings.export_anonymized" => "Anonymisierte Datenbank exportieren",
+        "settings.export_anonymized_tooltip" => "Eine Kopie der Datenbank mit ersetzten Steam-IDs und Namen speichern, um sie an Fehlerberichte anzuhängen.\nDeine Konfigurationsdatei (API-Schlüssel, Tokens) ist nicht enthalten.",
+        "settings.performance" => "Leistung:",
+        "settings.run_benchmark" => "Benchmark starten",
+        "settings.run_benchmark_tooltip" => "Misst Laden der Datenbank, Filtern, Sortieren und das Zeichnen einer Tabelle mit 5000 Zeilen auf diesem Rechner",
+        "settings.copy_report" => "Bericht kopieren",
+        "settings.icon_cache_stats" => "Icon-Cache:",
+        "settings.icons_in_memory" => "Im Speicher:",
+        "settings.icons_in_memory_value" => "{count} Icons, {mb} / {max} MB",
+        "settings.icons_memory_hits" => "Speichertreffer / Ladevorgänge:",
+        "settings.icons_evicted" => "Aus dem Speicher entfernt:",
+        "settings.icons_on_disk" => "Auf der Festplatte:",
+        "settings.icons_disk_hits" => "Festplattentreffer / Fehlgriffe:",
+        "settings.backups" => "Datenbanksicherungen:",
+        "settings.backups_keep" => "Behalte",
+        "settings.backups_keep_tooltip" => "Sicherungen werden vor Cloud-Importen, Aktualisierungen und Änderungen an Erfolgsschemata erstellt",
+        "settings.backups_copies" => "Kopien",
+        "settings.backup_now" => "Jetzt sichern",
+        "settings.open_backups" => "Sicherungsordner öffnen",
+        "settings.no_backups" => "Noch keine Sicherungen",
+        "settings.restore_backup" => "Aus Sicherung wiederherstellen",
+        "settings.restore_confirm" => "Die aktuelle Datenbank durch diese Sicherung ersetzen? Sie wird vorher gesichert.",
+        "settings.restore" => "Wiederherstellen",
+        "settings.icons_paused" => "Pausiert ({reason})",
+        "settings.icons_budget_reached" => " - bei der maximalen Größe angehalten, erhöhe sie, um den Rest zu laden",
+        "settings.icons_over_budget" => "{count} Icons brauchen etwa {mb} MB, unter der maximalen Größe sind aber nur noch {free} MB frei.",
+        "settings.icons_download_to_limit" => "Bis zum Limit herunterladen",
+
+        // Filter bar
+        "filter.search_hint" => "Nach Name suchen...",
+        "filter.search_tags_hint" => "Tags suchen...",
+        "filter.tags_all" => "Alle Tags",
+        "filter.tags_any" => "Ein Tag",
+        "filter.tags_all_tooltip" => "Zeigt Spiele mit allen einbezogenen Tags, klicken für Spiele mit mindestens einem",
+        "filter.tags_any_tooltip" => "Zeigt Spiele mit mindestens einem einbezogenen Tag, klicken, um alle zu verlangen",
+        "filter.tag_included_hint" => "Einbezogen - klicken, um Spiele mit diesem Tag auszuschließen",
+        "filter.tag_excluded_hint" => "Ausgeschlossen - klicken, um ihn stattdessen einzubeziehen",
+        "filter.achievements" => "Erfolge",
+        "filter.played" => "Gespielt",
+        "filter.hours_short" => "Std",
+        "filter.hours_played" => "Gespielte Stunden",
+        "filter.installed" => "Installiert",
+        "filter.games" => "Spiele",
+        "filter.other" => "Andere",
+        "filter.app_type_tooltip" => "Spiele (statt Soundtracks, Tools, Demos)",
+        "filter.ttb_tooltip" => "Spieldauer",
+        "filter.ttb_range_tooltip" => "Spieldauer (Stunden Hauptstory)",
+        "filter.hidden" => "Versteckt",
+        "filter.visible" => "Sichtbar",
+        "filter.hidden_tooltip" => "Versteckte Spiele (in Overachiever oder in Steam)",
+        "filter.private" => "Privat",
+        "filter.public" => "Öffentlich",
+        "filter.private_tooltip" => "In Steam als privat markiert",
+        "filter.min" => "min",
+        "filter.max" => "max",
+        "group.none" => "Keine Gruppierung",
+        "group.letter" => "A–Z",
+        "group.last_played_year" => "Zuletzt gespielt (Jahr)",
+        "group.completion" => "Fortschritt",
+        "group.never_played" => "Nie gespielt",
+        "group.under_25" => "Unter 25%",
+        "group.no_achievements" => "Keine Erfolge",
+        "group.tooltip" => "Spiele in einklappbare Abschnitte gruppieren",
+
+        // Games table
+        "table.name" => "Name",
+        "table.last_played" => "Zuletzt gespielt",
+        "table.playtime" => "Spielzeit",
+        "table.deck_playtime" => "Deck",
+        "table.deck_playtime_tooltip" => "Auf dem Steam Deck gespielt",
+        "table.played_on" => "Gespielt auf:",
+        "table.achievements" => "Erfolge",
+        "table.votes" => "Stimmen",
+        "table.ttb_tooltip" => "Spieldauer",
+        "table.votes_tooltip" => "Tag-Stimmen von SteamSpy",
+        "table.playing_now" => "Wird gerade gespielt",
+        "table.private_on_steam" => "In Steam privat",
+        "table.hidden_both" => "In Overachiever und in Steam versteckt",
+        "table.hidden_app" => "In Overachiever versteckt",
+        "table.hidden_steam" => "In Steam versteckt",
+        "table.abandoned" => "Aufgegeben - zählt nicht zu Durchschnitten und Restzeit",
+        "table.abandon" => "Als aufgegeben markieren (bleibt sichtbar, zählt aber nicht zum Durchschnitt)",
+        "table.unabandon" => "Wieder aufnehmen (zählt wieder zum Abschluss)",
+        "table.head_to_head" => "du {mine}/{total}, Rivale {theirs}/{total}",
+        "table.head_to_head_tooltip" => "Von dir und deinem Rivalen freigeschaltete Erfolge",
+        "table.community" => "Community:",
+        "table.community_avg" => "Ø {percent}%",
+        "table.community_perfect" => "{percent}% perfekt",
+        "table.community_median" => "Median {hours}h",
+        "table.community_tooltip" => "Unter {players} Overachiever-Nutzern, die dieses Spiel gespielt haben",
+
+        "palette.hint" => "Spiele, Befehle und Erfolge durchsuchen",
+        "palette.command" => "Befehl",
+        "palette.upload" => "In die Cloud hochladen",
+        "palette.no_results" => "Keine Treffer",
+        "stalled.title" => "Liegengebliebene Spiele",
+        "stalled.description" => "Spiele, die seit einem Jahr nicht gespielt wurden und zu weniger als der Hälfte abgeschlossen sind. Aufgegebene Spiele bleiben in der Bibliothek, zählen aber nicht mehr zum Abschlussdurchschnitt.",
+        "stalled.none_left" => "Keine liegengebliebenen Spiele mehr zu prüfen.",
+        "stalled.remaining" => "{count} Spiele zu prüfen",
+        "stalled.last_played" => "Zuletzt gespielt {when}, {hours} h gespielt",
+        "stalled.abandon" => "Aufgeben",
+        "stalled.keep" => "Behalten",
+        "stalled.keep_tooltip" => "Soll noch abgeschlossen werden - nicht mehr vorschlagen",
+        "stalled.skip" => "Überspringen",
+        "stalled.skip_tooltip" => "Später entscheiden",
+        "whats_new.title" => "Neuigkeiten",
+        "whats_new.update_available" => "Version {version} ist verfügbar",
+        "whats_new.download" => "Herunterladen",
+        "whats_new.empty" => "Keine Einträge im Änderungsprotokoll.",
+        "whats_new.close" => "Schließen",
+        "rival.title" => "Rivalität",
+        "rival.account" => "Rivale: {steam_id}",
+        "rival.refresh" => "Aktualisieren",
+        "rival.no_common_games" => "Noch keine gemeinsamen Spiele mit Erfolgsdaten. Zuerst den Rivalen abrufen.",
+        "rival.you" => "Du",
+        "rival.rival" => "Rivale",
+        "rival.games_ahead" => "Spiele vorne",
+        "rival.achievements" => "Erfolge",
+        "rival.perfect_games" => "Perfekte Spiele",
+        "rival.common_games" => "{count} gemeinsame Spiele, {tied} gleichauf",
+        "rival.rival_leads" => "Wo dein Rivale vorne liegt",
+        "rival.you_lead" => "Wo du vorne liegst",
+        "rival.head_to_head" => "{mine} gegen {theirs} / {total}",
+        "table.refresh_cooldown" => "Gerade aktualisiert - bitte in einer Minute erneut versuchen",
+        "table.refresh" => "Erfolge dieses Spiels aktualisieren",
+        "table.refresh_queued" => "In der Warteschlange - wird nach den vorherigen Spielen aktualisiert",
+        "table.launching" => "Wird gestartet...",
+        "table.launch" => "Spiel in Steam starten",
+        "table.install" => "Spiel über Steam installieren",
+        "table.sessions" => "Spielsitzungen anzeigen",
+        "table.plan" => "Verbleibende Erfolge planen",
+        "table.unlock_timeline" => "Freischaltreihenfolge abspielen",
+        "table.unowned_played" => "Gespielt, nicht im Besitz",
+        "table.unowned_hint" => "Während Gratis-Wochenenden oder über die Familienbibliothek gespielt, aber nicht in deiner Bibliothek. Zählt nur in den Statistiken, wenn in den Einstellungen aktiviert.",
+        "table.filter_achievements" => "Erfolge filtern...",
+        "table.achievement_state_tooltip" => "Freigeschaltet / gesperrt",
+        "table.unlocked" => "Freigeschaltet",
+        "table.locked" => "Gesperrt",
+        "table.no_matching_achievements" => "Keine passenden Erfolge",
+        "table.group_games" => "{count} Spiele",
+        "table.group_perfect" => "{count} perfekt",
+        "table.group_avg_tooltip" => "Durchschnittlicher Fortschritt der Spiele mit Erfolgen",
+        "table.spoiler_hidden" => "Versteckter Erfolg - zum Aufdecken klicken",
+        "table.reveal_spoilers_tooltip" => "Beschreibungen versteckter Erfolge anzeigen",
+        "table.hide_spoilers" => "Spoiler verbergen",
+        "table.show_spoilers" => "Spoiler anzeigen",
+        "table.copy_achievement_name" => "Erfolgsnamen kopieren",
+        "table.global_percent_tooltip" => "Von {percent}% der Spieler freigeschaltet",
+        "table.rarity_drift" => "{drift} Punkte seit {since}",
+        "table.ttb_main" => "Story: {time}",
+        "table.ttb_extra" => "+Extras: {time}",
+        "table.ttb_no_data" => "<keine Daten>",
+        "table.ttb_completionist" => "100%: {time}",
+        "table.ttb_yours" => "Deine Zeit:",
+        "table.ttb_report" => "TTB melden",
+        "table.ttb_report_tooltip" => "Melde deine Spielzeit für dieses Spiel",
+        "table.ttb_allow" => "TTB erlauben",
+        "table.ttb_allow_tooltip" => "Von der TTB-Sperrliste entfernen (im Scan erlauben)",
+        "table.ttb_excluded" => "Spiel ist vom TTB-Scan ausgeschlossen",
+        "table.ttb_exclude" => "Nicht für TTB",
+        "table.ttb_exclude_tooltip" => "Als ungeeignet für TTB markieren (z. B. reine Mehrspielerspiele)",
+        "table.ttb_not_available" => "k. A.",
+        "table.never_scraped" => "nie abgerufen",
+        "table.scraped_today" => "heute abgerufen",
+        "table.scraped_yesterday" => "gestern abgerufen",
+        "table.scraped_days_ago" => "vor {days} Tagen abgerufen",
+        "table.stale_tooltip" => "Erfolgsdaten, die älter als {days} Tage sind, gelten als veraltet",
+        "table.owned_by" => "Im Besitz von {owner}",
+        "table.cloud_saves" => "Steam-Cloud-Spielstände",
+        "table.cloud_last_synced" => "Zuletzt synchronisiert {when}",
+        "table.cloud_never_synced" => "Nie synchronisiert",
+        "table.cloud_files" => "{count} Spielstanddateien erfasst",
+        "table.ttb" => "TTB",
+        "table.ttb_label" => "Spielzeit:",
+        "table.fetch_ttb" => "Spielzeit von HowLongToBeat abrufen",
+        "table.refetch_ttb" => "Spielzeit erneut von HowLongToBeat abrufen",
+        "table.fetch_tags" => "Tags von SteamSpy abrufen",
+        "table.refetch_tags" => "Tags erneut von SteamSpy abrufen",
+        "table.global_percent" => "Global %",
+        "achievement_sort.unlocked_first" => "Freigeschaltete zuerst",
+        "achievement_sort.locked_first" => "Gesperrte zuerst",
+        "achievement_sort.unlock_date" => "Freischaltdatum",
+        "achievement_sort.rarity" => "Seltenheit",
+        "achievement_sort.name" => "Name",
+        "achievement_sort.difficulty" => "Schwierigkeit",
+        "tie_break.auto" => "Automatisch",
+        "tie_break.name" => "Name",
+        "tie_break.last_played" => "Zuletzt gespielt",
+        "tie_break.playtime" => "Spielzeit",
+        "tie_break.completion" => "Abschluss %",
+        "achievements.loading" => "Lade Erfolge...",
+        "comments.reported" => "Gemeldet",
+        "comments.report" => "Als missbräuchlich melden",
+        "difficulty.very_easy" => "Sehr leicht",
+        "difficulty.easy" => "Leicht",
+        "difficulty.moderate" => "Mittel",
+        "difficulty.hard" => "Schwer",
+        "difficulty.extreme" => "Extrem",
+        "difficulty.curve" => "Schwierigkeitskurve der Community",
+        "difficulty.curve_rated" => "({count} bewertet)",
+        "difficulty.spike" => "Schwierigkeitssprung bei #{index} {name}",
+        "difficulty.votes" => "{name} ({count} Stimmen)",
+        "difficulty.series" => "Schwierigkeit",
+        "difficulty.spike_marker" => "Schwierigkeitssprung",
+        "unlock_graph.title" => "Freischaltungsverlauf",
+        "unlock_graph.summary" => "({unlocked} von {total} freigeschaltet, {from} – {to})",
+        "unlock_graph.per_month" => "In dem Monat freigeschaltet",
+        "unlock_graph.cumulative" => "Insgesamt freigeschaltet",
+        "unlock_graph.total" => "Erfolge",
+        "cost.all_unlocked" => "Alle {total} Erfolge freigeschaltet",
+        "cost.remaining" => "{remaining} von {total} Erfolgen übrig",
+        "cost.ttb_left" => "~{time} übrig (Spielzeit für 100 %)",
+        "cost.no_ttb" => "Noch keine Spielzeit für 100 %",
+        "cost.pace_left" => "~{time} übrig bei deinem bisherigen Tempo",
+        "cost.expand_hint" => "Klappe das Spiel auf für Schwierigkeit und verpassbare Erfolge",
+        "cost.hardest_left" => "Schwierigster übrig:",
+        "cost.missable" => "Möglicherweise verpassbar ({count}):",
+        "cost.and_more" => "und {count} weitere",
+        "ttb.dialog_title" => "Spielzeit melden",
+        "ttb.dialog_game" => "Spiel: {game}",
+        "ttb.dialog_intro" => "Gib deine Spielzeiten ein (leer lassen, wenn du den Modus nicht abgeschlossen hast):",
+        "ttb.main_story" => "Hauptgeschichte:",
+        "ttb.main_extras" => "Haupt + Extras:",
+        "ttb.completionist" => "100 % Komplettierung:",
+        "ttb.submit" => "Absenden",
+        "ttb.hours" => "{hours} Std.",
+        "ttb.hours_minutes" => "{hours} Std. {minutes} Min.",
+        "ttb.minutes" => "{minutes} Min.",
+        "ttb.hours_unit" => "Std.",
+        "ttb.minutes_unit" => "Min.",
+
+        // Presence (sidebar)
+        "presence.title" => "Spielt gerade",
+        "presence.playing" => "{name} spielt {game}",
+        "presence.achievements" => "{unlocked}/{total} Erfolge",
+
+        // Stats panel
+        "stats.games_over_time" => "Spiele im Zeitverlauf",
+        "stats.no_history" => "Noch kein Verlauf. Führe einen vollständigen Scan durch, um die Aufzeichnung zu starten!",
+        "stats.achievement_progress" => "Erfolgsfortschritt",
+        "stats.no_achievement_data" => "Noch keine Erfolgsdaten. Führe einen vollständigen Scan durch, um die Aufzeichnung zu starten!",
+        "stats.forecast" => "Prognose",
+        "stats.forecast_needs_history" => "Eine Prognose braucht mindestens eine Woche Erfolgsverlauf.",
+        "stats.breakdown" => "Übersicht",
+        "stats.sync_first" => "Synchronisiere deine Spiele, um Statistiken zu sehen.",
+        "stats.total_achievements" => "Erfolge gesamt:",
+        "stats.avg_completion" => "Ø Spielfortschritt:",
+        "stats.unplayed_games" => "Ungespielte Spiele:",
+        "stats.total_games" => "Spiele gesamt:",
+        "stats.games_with_achievements" => "Spiele mit Erfolgen:",
+        "stats.needs_scanning" => "Noch zu scannen:",
+        "stats.by_tag" => "Nach Tag",
+        "stats.records" => "Rekorde",
+        "stats.no_records" => "Schalte Erfolge frei, um Rekorde aufzustellen.",
+        "stats.goals" => "Ziele",
+        "stats.tab_total_games" => "Spiele gesamt",
+        "stats.tab_unplayed_games" => "Ungespielte Spiele",
+        "stats.data_points" => "{count} Datenpunkte",
+        "stats.tab_avg_completion" => "Ø Spielfortschritt %",
+        "stats.tab_overall" => "Erfolge gesamt %",
+        "stats.range_30d" => "30 T",
+        "stats.range_90d" => "90 T",
+        "stats.range_year" => "1 J",
+        "stats.current_pace" => "Aktuelles Tempo:",
+        "stats.unlocks_per_week" => "{count} Freischaltungen/Woche",
+        "stats.pace_hours" => "(~{hours} h/Woche, letzte {days} Tage)",
+        "stats.pace_days" => "(letzte {days} Tage)",
+        "stats.target_avg" => "Ziel-Ø-Fortschritt:",
+        "stats.what_if" => "Was, wenn ich spiele",
+        "stats.hours_per_week_suffix" => " h/Woche",
+        "stats.pace_at_hours" => "Mit {hours} h/Woche",
+        "stats.pace_current" => "Mit deinem aktuellen Tempo",
+        "stats.eta_reached" => "Du hast {goal} bereits erreicht",
+        "stats.eta_at" => "{pace} erreichst du {goal} im {date}",
+        "stats.eta_out_of_reach" => "{pace} erreichst du {goal} nicht",
+        "stats.goal_avg" => "{percent} % durchschnittlichen Fortschritt",
+        "stats.goal_overall" => "{percent} % aller Erfolge",
+        "stats.include_unplayed" => "Ungespielte einbeziehen",
+        "stats.perfect_games" => "100 % abgeschlossen:",
+        "stats.done_games" => "Fertig ({percent} %+):",
+        "stats.near_complete_games" => "Fast fertig ({percent} %+):",
+        "stats.add_tag" => "Tag hinzufügen...",
+        "stats.pick_tags" => "Wähle Tags, um sie zu vergleichen.",
+        "stats.first_achievement" => "Erster Erfolg:",
+        "stats.busiest_day" => "Aktivster Tag:",
+        "stats.busiest_day_date" => "Freischaltungen am {date}",
+        "stats.current_streak" => "Aktuelle Serie:",
+        "stats.days" => "{count} Tage",
+        "stats.streak_at_risk" => "Heute noch keine Freischaltung - deine {days}-Tage-Serie endet um Mitternacht",
+        "stats.longest_streak" => "Längste Serie:",
+        "stats.rarest_unlock" => "Seltenste Freischaltung:",
+        "stats.rarest_unlock_detail" => "({game}, {percent} % der Spieler)",
+        "stats.oldest_unfinished" => "Ältestes unfertiges Spiel:",
+        "stats.oldest_unfinished_detail" => "(begonnen {date}, {percent} %)",
+        "stats.milestones" => "Meilensteine ({count})",
+        "stats.no_goals" => "Noch keine Ziele - lege unten eins fest.",
+        "stats.remove_goal" => "Ziel entfernen",
+        "stats.goal_this_month" => "{current} / {target} diesen Monat",
+        "stats.goal_reached" => "Erreicht",
+        "stats.goal_expired" => "Frist abgelaufen",
+        "stats.goal_games" => "{count} Spiele",
+        "stats.goal_behind" => "Im Rückstand - du solltest jetzt bei {expected} sein",
+        "stats.goal_on_track" => "Im Plan",
+        "stats.add_goal" => "Ziel hinzufügen",
+        "stats.goal_kind_games" => "Spiele pro Monat",
+        "stats.goal_kind_avg" => "Ø-Fortschritt bis Datum",
+        "stats.goal_finish" => "Beende",
+        "stats.goal_games_per_month" => "Spiele pro Monat",
+        "stats.goal_reach" => "Erreiche",
+        "stats.goal_by" => "bis",
+        "stats.goal_currently" => "Aktuell bei {percent} %",
+        "stats.goal_add" => "Hinzufügen",
+
+        // Stats dashboard
+        "dashboard.tab" => "Statistik",
+        "dashboard.completion_distribution" => "Verteilung des Fortschritts",
+        "dashboard.no_completion_data" => "Noch keine Spiele mit Erfolgen. Starte einen vollständigen Scan, um den Fortschritt zu sehen.",
+        "dashboard.games" => "Spiele",
+        "dashboard.hours_by_tag" => "Stunden nach Tag",
+        "dashboard.no_tag_data" => "Noch keine Tags zwischengespeichert. Lade Tags, um zu sehen, wohin deine Stunden gingen.",
+        "dashboard.other" => "Sonstige",
+        "dashboard.unlocks_per_month" => "Freischaltungen pro Monat",
+        "dashboard.no_unlocks" => "Noch keine Freischaltungen aufgezeichnet.",
+        "dashboard.library_growth" => "Wachstum der Bibliothek",
+        "dashboard.games_added" => "Hinzugefügte Spiele",
+        "dashboard.library_size" => "Größe der Bibliothek",
+
+        // Log panel
+        "log.activity_log" => "Aktivitätsprotokoll",
+        "log.empty" => "Noch keine Aktivität. Synchronisiere und scanne, um die Aufzeichnung zu starten!",
+        "log.no_matches" => "Keine Aktivität für dieses Spiel oder diesen Zeitraum.",
+        "log.all_games" => "Alle Spiele",
+        "log.from" => "Von",
+        "log.to" => "bis",
+        "log.date_hint" => "Tage in deiner Anzeige-Zeitzone (JJJJ-MM-TT), leer lassen für keine Grenze",
+        "log.clear_filter" => "Filter zurücksetzen",
+        "log.first_play" => "zum ersten Mal gespielt!",
+        "log.schema_added_one" => "1 neuen Erfolg hinzugefügt",
+        "log.schema_added" => "{count} neue Erfolge hinzugefügt",
+        "log.schema_removed_one" => "1 Erfolg entfernt",
+        "log.schema_removed" => "{count} Erfolge entfernt",
+        "log.schema_changed" => "{added} Erfolg(e) hinzugefügt und {removed} entfernt",
+        "log.schema_added_list" => "Hinzugefügt:",
+        "log.schema_removed_list" => "Entfernt:",
+        "log.comment_title" => "{count} Erfolg(e) kommentieren",
+        "log.clear_selection" => "Auswahl aufheben",
+        "log.selected" => "Ausgewählt:",
+        "log.and_more" => "und {count} weitere...",
+        "log.comment_hint" => "Schreibe einen Kommentar zu diesen Erfolgen...",
+        "log.comment_submit" => "Absenden",
+
+        // Profile menu
+        "profile.title" => "Profil",
+        "profile.not_set" => "Nicht festgelegt",
+        "profile.link" => "Verknüpfe dein Konto, um Cloud-Sync zu aktivieren",
+        "profile.your_profile" => "Dein Profil:",
+        "profile.copy_link" => "Link kopieren",
+        "profile.open" => "Öffnen",
+        "profile.upload" => "Online veröffentlichen",
+        "profile.upload_tooltip" => "Deine lokalen Daten auf overachiever.space hochladen",
+        "profile.download" => "Aus der Cloud importieren",
+        "profile.download_tooltip" => "Daten von overachiever.space herunterladen (Sicherung, falls lokal entfernt)",
+        "profile.delete" => "Online-Daten löschen",
+        "profile.delete_tooltip" => "Deine Daten von overachiever.space entfernen",
+        "profile.logout" => "Abmelden",
+        "profile.logout_tooltip" => "Verknüpfung deines Kontos mit Cloud-Sync aufheben",
+        "profile.linking" => "Warte auf Steam-Anmeldung... (sieh in deinem Browser nach)",
+        "profile.uploading" => "Lade {mb} MB hoch...",
+        "profile.preparing_upload" => "Bereite Upload vor...",
+        "profile.downloading" => "Lade herunter...",
+        "profile.deleting" => "Lösche...",
+        "profile.other_profiles" => "Andere Profile",
+        "profile.no_other_profiles" => "Noch keine anderen Profile mit lokalen Daten",
+        "profile.open_window" => "In neuem Fenster öffnen",
+        "profile.steam_id_hint" => "Steam-ID",
+        "profile.merged" => "Zusammengeführte Bibliothek",
+        "profile.merged_needs_two" => "Benötigt lokale Daten für mindestens zwei Profile",
+        "profile.merged_dedupe" => "Spiele mehrerer Konten nur einmal anzeigen",
+        "profile.merged_open" => "Zusammengeführte Ansicht öffnen",
+        "profile.mini_window" => "Minifenster",
+        "profile.mini_window_tooltip" => "Kleines Fenster im Vordergrund mit Fortschritt, heutigen Freischaltungen und deiner Serie",
+        "profile.private_games" => "Private Spiele",
+        "profile.hide_private" => "Private Spiele ausblenden",
+        "profile.hide_private_tooltip" => "In Steam als privat markierte Spiele in der Spieletabelle und im Online-Profil ausblenden",
+        "profile.hidden_count" => "In der Bibliothek ausgeblendet: {count} Spiele",
+        "profile.steam_private" => "Private Spiele in Steam:",
+        "profile.import_private" => "Private Spiele aus Steam importieren",
+        "profile.import_private_tooltip" => "Liest die lokale Steam-Konfiguration oder die Spieleliste deines öffentlichen Profils, wenn Steam hier nicht installiert ist",
+        "profile.private_count" => "{count} private Spiele",
+        "profile.hide_tags" => "Spiele mit Tag ausblenden:",
+        "profile.hide_tags_hint" => "Auszublendende Tags suchen...",
+
+        // Cloud dialogs
+        "cloud.upload_title" => "In die Cloud hochladen",
+        "cloud.upload_message" => "Damit werden alle deine lokalen Daten auf overachiever.space hochgeladen.\nVorhandene Cloud-Daten werden ersetzt.",
+        "cloud.upload" => "Hochladen",
+        "cloud.download_title" => "Aus der Cloud herunterladen",
+        "cloud.download_message" => "Damit werden Daten von overachiever.space heruntergeladen und mit deinen lokalen Daten zusammengeführt.\nDu siehst zuerst eine Übersicht der Änderungen.",
+        "cloud.download" => "Herunterladen",
+        "cloud.delete_title" => "Aus der Cloud entfernen",
+        "cloud.delete_message" => "Damit werden alle deine Daten dauerhaft von overachiever.space gelöscht.\nDeine lokalen Daten bleiben unverändert.",
+        "cloud.delete" => "Löschen",
+        "cloud.merge_title" => "Cloud-Daten zusammenführen",
+        "cloud.merge_nothing" => "Deine lokalen Daten enthalten bereits alles aus der Cloud.",
+        "cloud.merge_unlocks" => "Die Cloud hat {cloud} Freischaltungen, die lokal fehlen; lokal gibt es {local}, die der Cloud fehlen.",
+        "cloud.merge_games" => "{cloud} Spiele sind nur in der Cloud, {local} nur lokal.",
+        "cloud.merge_playtime" => "{count} Spiele haben in der Cloud mehr Spielzeit.",
+        "cloud.merge_history" => "{count} Verlaufs-Snapshots werden hinzugefügt.",
+        "cloud.merge_rules" => "Lokal wird nichts entfernt: Freischaltungen behalten die neueste Zeit, die höchste Spielzeit gewinnt, der Verlauf wird kombiniert.",
+        "cloud.merge" => "Zusammenführen",
+
+        // Privacy dialog
+        "gdpr.title" => "Datenschutz & Datennutzung",
+        "gdpr.intro" => "Diese Anwendung verarbeitet personenbezogene Daten, um ihre Dienste bereitzustellen:",
+        "gdpr.data" => "Verarbeitete Daten",
+        "gdpr.data_steam_id" => "Deine Steam-ID (öffentliche Kennung)",
+        "gdpr.data_name" => "Dein Steam-Anzeigename",
+        "gdpr.data_library" => "Deine Spielebibliothek (über die Steam-API)",
+        "gdpr.data_achievements" => "Erfolgsdaten deiner Spiele",
+        "gdpr.data_ratings" => "Von dir eingereichte Community-Bewertungen/Tipps",
+        "gdpr.purpose" => "Zweck",
+        "gdpr.purpose_text" => "Deine persönlichen Spieldaten bleiben lokal auf deinem Computer. Nur Community-Bewertungen und Tipps, die du einreichst, werden mit overachiever.space synchronisiert.",
+        "gdpr.third_parties" => "Dritte",
+        "gdpr.third_parties_text" => "Wir verwenden die Steam-Web-API, um deine öffentlichen Spiel- und Erfolgsdaten abzurufen. Es werden keine Daten an andere Dritte weitergegeben.",
+        "gdpr.accepted" => "Aktuell: Akzeptiert",
+        "gdpr.declined" => "Aktuell: Abgelehnt",
+        "gdpr.accept" => "Akzeptieren",
+        "gdpr.accept_tooltip" => "Datenverarbeitung akzeptieren und fortfahren",
+        "gdpr.decline" => "Ablehnen",
+        "gdpr.decline_tooltip" => "Ablehnen - Serverfunktionen werden deaktiviert",
+        "gdpr.close" => "Schließen",
+        "gdpr.close_tooltip" => "Ohne Änderungen schließen",
+
+        // Milestones
+        "milestone.title" => "Meilenstein erreicht!",
+        "milestone.achievements" => "{n}. Erfolg",
+        "milestone.perfect_games" => "{n}. perfektes Spiel",
+        "milestone.streak" => "{n}-Tage-Serie",
+        "milestone.dismiss" => "Super!",
+
+        // Goals and records
+        "goal.one_game_per_month" => "1 Spiel pro Monat abschließen",
+        "goal.games_per_month" => "{count} Spiele pro Monat abschließen",
+        "goal.avg_completion_by" => "{target}% durchschnittlichen Abschluss bis {date} erreichen",
+        "tag_stats.tag" => "Tag",
+        "tag_stats.owned" => "Im Besitz",
+        "tag_stats.hours" => "Stunden",
+        "tag_stats.completion" => "Ø %",
+        "tag_stats.ttb_left" => "Restzeit",
+        "time.local" => "Ortszeit",
+
+        // Admin dashboard
+        "admin.title" => "Admin-Dashboard",
+        "admin.tab_overview" => "Übersicht",
+        "admin.tab_users" => "Benutzer",
+        "admin.tab_ttb" => "Spielzeit-Prüfung ({count})",
+        "admin.tab_tags" => "Tag-Prüfung ({count})",
+        "admin.tab_tag_aliases" => "Tag-Aliase",
+        "admin.tab_reports" => "Meldungen ({count})",
+        "admin.reload" => "Neu laden",
+        "admin.loading" => "Wird geladen...",
+        "admin.users" => "Benutzer:",
+        "admin.users_detail" => "{total} ({week} aktiv in 7 Tagen, {month} in 30 Tagen)",
+        "admin.games_stored" => "Gespeicherte Spiele:",
+        "admin.achievements_stored" => "Gespeicherte Erfolge:",
+        "admin.cloud_uploads" => "Cloud-Uploads:",
+        "admin.cloud_uploads_detail" => "{total} gesamt, größter {largest}",
+        "admin.database_size" => "Datenbankgröße:",
+        "admin.pending_review" => "Zu prüfen:",
+        "admin.pending_review_detail" => "{ttb} Spielzeiten, {tags} Tag-Einreichungen, {reports} Spielzeit-Meldungen",
+        "admin.user" => "Benutzer",
+        "admin.games" => "Spiele",
+        "admin.achievements" => "Erfolge",
+        "admin.last_upload" => "Letzter Upload",
+        "admin.last_seen" => "Zuletzt gesehen",
+        "admin.no_ttb_submissions" => "Keine Spielzeiten zu prüfen.",
+        "admin.appid" => "AppID {appid}",
+        "admin.unknown_user" => "unbekannt",
+        "admin.submitted_by" => "Eingereicht von {user} ({count} Meldungen)",
+        "admin.approve" => "Annehmen",
+        "admin.reject" => "Ablehnen (löschen)",
+        "admin.no_tag_submissions" => "Keine Tag-Einreichungen zu prüfen.",
+        "admin.by_on" => "von {user} am {date}",
+        "admin.tag_aliases_hint" => "Aliase ersetzen doppelte Tags durch einen kanonischen. Übergeordnete Tags listen ein Spiel zusätzlich unter einem allgemeineren Tag. Clients übernehmen Änderungen beim nächsten Start.",
+        "admin.tag" => "Tag",
+        "admin.is_alias_of" => "ist ein Alias von",
+        "admin.is_kind_of" => "ist eine Art von",
+        "admin.canonical_tag" => "Kanonischer Tag",
+        "admin.save" => "Speichern",
+        "admin.no_tag_aliases" => "Noch keine Tag-Aliase.",
+        "admin.alias_of" => "Alias von",
+        "admin.kind_of" => "Art von",
+        "admin.no_reports" => "Keine gemeldeten Kommentare.",
+        "admin.comment_by_on" => "von {user} am {date} · {count} Meldung(en)",
+        "admin.remove_comment" => "Kommentar entfernen",
+        "admin.dismiss_reports" => "Meldungen verwerfen (Kommentar behalten)",
+        "admin.reasons" => "Gründe: {reasons}",
+
+        // Wishlist
+        "wishlist.refresh" => "Aktualisieren",
+        "wishlist.refresh_tooltip" => "Wunschliste von Steam abrufen",
+        "wishlist.fetching" => "Wunschliste wird abgerufen...",
+        "wishlist.empty" => "Deine Wunschliste ist leer oder nicht öffentlich. Steam teilt nur öffentliche Wunschlisten.",
+        "wishlist.backlog_impact" => "Auswirkung auf den Rückstand: ~{main} Std. zum Durchspielen, ~{completionist} Std. bis 100% ({known} von {total} Spielen haben Spielzeitdaten)",
+        "wishlist.game" => "Spiel",
+        "wishlist.added" => "Hinzugefügt",
+        "wishlist.main" => "Hauptstory",
+        "wishlist.community_completion" => "Abschluss der Community",
+        "wishlist.tags" => "Tags",
+        "wishlist.open_store" => "Shop-Seite öffnen",
+        "wishlist.owned" => "im Besitz",
+        "wishlist.completion" => "Ø {avg}% · {completed}/{players} bei 100%",
+        "wishlist.no_players" => "noch keine Spieler",
+
+        // Play sessions
+        "sessions.title" => "Sitzungen: {game}",
+        "sessions.summary" => "{count} Sitzungen, {time} erfasst",
+        "sessions.reload" => "Neu laden",
+        "sessions.disabled" => "Sitzungserfassung ist in Einstellungen → Steam deaktiviert",
+        "sessions.empty" => "Noch keine Sitzungen erfasst. Sitzungen werden erfasst, während Overachiever läuft.",
+        "sessions.date" => "Datum",
+        "sessions.started" => "Begonnen",
+        "sessions.duration" => "Dauer",
+        "sessions.playing" => "{time} (läuft)",
+
+        // Final stretch
+        "final_stretch.very_hard" => "Sehr schwer",
+        "final_stretch.games_at_least" => "Spiele mindestens",
+        "final_stretch.complete" => "abgeschlossen",
+        "final_stretch.reset_threshold" => "Schwelle für „fast abgeschlossen“ verwenden",
+        "final_stretch.summary" => "{count} Erfolge übrig in {games} Spielen, die leichtesten zuerst",
+        "final_stretch.empty" => "Keine fast abgeschlossenen Spiele. Weiterspielen!",
+        "final_stretch.players" => "{percent}% der Spieler",
+
+        // Year in review
+        "year_review.heading" => "{year} im Rückblick",
+        "year_review.empty" => "In diesem Jahr wurden keine Freischaltungen, ersten Spielstarts oder Sitzungen erfasst.",
+        "year_review.export_html" => "HTML exportieren",
+        "year_review.save_png" => "PNG speichern",
+        "year_review.load_failed" => "Jahresrückblick konnte nicht geladen werden: {error}",
+        "year_review.exported" => "Jahresrückblick nach {path} exportiert",
+        "year_review.export_failed" => "Jahresrückblick konnte nicht exportiert werden: {error}",
+        "year_review.image_saved" => "Bild des Jahresrückblicks unter {path} gespeichert",
+        "year_review.image_failed" => "Bild des Jahresrückblicks konnte nicht gespeichert werden: {error}",
+        "year_review.achievements_unlocked" => "Erfolge freigeschaltet",
+        "year_review.games_started" => "Spiele begonnen",
+        "year_review.games_perfected" => "Spiele perfektioniert",
+        "year_review.hours_played" => "Stunden gespielt (erfasste Sitzungen)",
+        "year_review.busiest_month" => "aktivster Monat ({count} Freischaltungen)",
+        "year_review.unlocks" => "{count} Freischaltungen",
+        "year_review.most_unlocks" => "Meiste Freischaltungen:",
+        "year_review.most_unlocks_detail" => "({count} Erfolge)",
+        "year_review.perfected" => "Perfektioniert:",
+        "year_review.html_rarest" => "Seltenste Freischaltung: {achievement} in {game} ({percent}% der Spieler)",
+        "year_review.html_most_unlocks" => "Meiste Freischaltungen: {game} ({count} Erfolge)",
+        "year_review.html_perfected" => "Perfektioniert: {games}",
+        "year_review.html_footer" => "Erstellt mit Overachiever",
+        "year_review.html_lang" => "de",
+        "month.january" => "Januar",
+        "month.february" => "Februar",
+        "month.march" => "März",
+        "month.april" => "April",
+        "month.may" => "Mai",
+        "month.june" => "Juni",
+        "month.july" => "Juli",
+        "month.august" => "August",
+        "month.september" => "September",
+        "month.october" => "Oktober",
+        "month.november" => "November",
+        "month.december" => "Dezember",
+        "month.jan" => "Jan",
+        "month.feb" => "Feb",
+        "month.mar" => "Mär",
+        "month.apr" => "Apr",
+        "month.may_short" => "Mai",
+        "month.jun" => "Jun",
+        "month.jul" => "Jul",
+        "month.aug" => "Aug",
+        "month.sep" => "Sep",
+        "month.oct" => "Okt",
+        "month.nov" => "Nov",
+        "month.dec" => "Dez",
+
+        // Snapshot comparison
+        "snapshots.load_failed" => "Snapshots konnten nicht geladen werden: {error}",
+        "snapshots.need_two" => "Snapshots werden am Ende jedes Scans erstellt. Führe ein weiteres Update aus, um zwei davon zu vergleichen.",
+        "snapshots.from" => "Von:",
+        "snapshots.to" => "bis:",
+        "snapshots.wrong_order" => "Wähle für „Von“ einen früheren Snapshot als für „bis“.",
+        "snapshots.no_changes" => "Zwischen diesen Snapshots hat sich nichts geändert.",
+        "snapshots.unlocks" => "{count} Freischaltungen",
+        "snapshots.new_games" => "{count} neue Spiele",
+        "snapshots.played" => "{time} gespielt",
+        "snapshots.avg_completion" => "Ø Abschluss {before}% → {after}% ({delta})",
+        "snapshots.changed_games" => "Geänderte Spiele ({count})",
+        "snapshots.game" => "Spiel",
+        "snapshots.playtime" => "Spielzeit",
+        "snapshots.unlocks_column" => "Freischaltungen",
+        "snapshots.completion" => "Abschluss",
+        "snapshots.unlocks_header" => "Freischaltungen ({count})",
+        "snapshots.new_games_header" => "Neue Spiele ({count})",
+        "snapshots.removed_games_header" => "Entfernte Spiele ({count})",
+
+        // Bulk time reports
+        "ttb_bulk.title" => "Zeiten für abgeschlossene Spiele melden",
+        "ttb_bulk.progress" => "Spiel {position} von {total} - {reported} gemeldet",
+        "ttb_bulk.suggestion" => "Du hast dieses Spiel mit {hours} Std. Spielzeit zu 100% abgeschlossen. Der Vorschlag unten ist deine Spielzeit - passe ihn an, falls das Spiel im Leerlauf lief.",
+        "ttb_bulk.local_only" => "Nicht mit der Cloud verbunden - Zeiten werden nur lokal gespeichert",
+        "ttb_bulk.submit_next" => "Senden & weiter",
+
+        // Side panel and achievement search
+        "history.open_stats" => "Statistik öffnen",
+        "history.open_log" => "Protokoll öffnen",
+        "history.close" => "Seitenleiste schließen",
+        "history.stats" => "Statistik",
+        "history.log" => "Protokoll",
+        "achievement_search.clear" => "Suche leeren",
+        "achievement_search.hint" => "Erfolge suchen...",
+        "achievement_search.tooltip" => "Erfolgsnamen und -beschreibungen in allen Spielen durchsuchen",
+        "achievement_search.no_matches" => "Keine passenden Erfolge",
+        "achievement_search.first_matches" => "Erste {count} passende Erfolge",
+        "achievement_search.matches" => "{count} passende Erfolge",
+
+        // Library view and profile windows
+        "library.title" => "Spielebibliothek ({count} Spiele)",
+        "library.wishlist_tab" => "Wunschliste ({count})",
+        "library.full_scan_needed" => "Ein vollständiger Scan muss abgeschlossen sein, bevor die Statistik-Erfassung beginnen kann.",
+        "library.full_scan_resumable" => "Du kannst die App jederzeit schließen und die Synchronisierung später fortsetzen — der Fortschritt wird gespeichert.",
+        "library.no_games" => "Keine Spiele geladen. Klicke auf „Aktualisieren“, um deine Steam-Bibliothek zu laden.",
+        "library.showing" => "{shown} von {total} Spielen angezeigt",
+        "mini.completion" => "Abschluss",
+        "mini.today" => "Heute",
+        "mini.streak" => "Serie",
+        "mini.streak_days" => "{days} T",
+        "mini.streak_at_risk" => "Schalte heute einen frei, um deine Serie zu halten",
+        "profile_window.load_failed" => "Spiele konnten nicht geladen werden: {error}",
+        "profile_window.no_data" => "Keine lokalen Daten für Steam-ID {steam_id}",
+        "profile_window.no_data_merged" => "Keine lokalen Daten für die ausgewählten Profile",
+        "profile_window.merged_summary" => "{accounts} Konten · {games} Spiele im Besitz ({unique} verschiedene) · {unlocked} Erfolge freigeschaltet · {perfect} perfekte Spiele",
+        "profile_window.merged_summary_deduped" => "{accounts} Konten · {games} Spiele im Besitz ({unique} verschiedene) · {unlocked} Erfolge freigeschaltet · {perfect} perfekte Spiele (beste Kopie gemeinsamer Spiele)",
+        "profile_window.merged_title" => "Zusammengeführte Bibliothek ({names})",
+        "profile_window.already_open" => "Dieses Profil wird bereits im Hauptfenster angezeigt",
+        "profile_window.db_failed" => "Datenbank konnte nicht geöffnet werden: {error}",
+
+        // Status messages
+        "status.ready" => "Bereit",
+        "hltb.search_title" => "HowLongToBeat durchsuchen",
+        "hltb.searching_for" => "Suche nach: {game}",
+        "hltb.search_query" => "Suchbegriff:",
+        "hltb.english" => "EN",
+        "hltb.english_tooltip" => "Englischen Namen von Steam abrufen",
+        "status.url_scheme_failed" => "{scheme}://-Links konnten nicht registriert werden: {error}",
+        "status.file_type_failed" => ".{extension}-Dateien konnten nicht registriert werden: {error}",
+        "status.cjk_font_downloaded" => "CJK-Schrift erfolgreich heruntergeladen!",
+        "status.font_download_failed" => "Download der Schrift fehlgeschlagen: {error}",
+        "admin.login_required" => "Melde dich mit Steam an, um das Admin-Dashboard zu nutzen",
+        "auto_upload.last_failed" => "Letzter Upload fehlgeschlagen ({error}), neuer Versuch in {wait}",
+        "auto_upload.minutes" => "{count} Min.",
+        "auto_upload.seconds" => "{count} s",
+        "auto_upload.toast" => "Cloud: {message}",
+        "auto_upload.failed_toast" => "Cloud-Upload fehlgeschlagen, neuer Versuch in {wait}",
+        "backups.restored" => "Datenbank aus {path} wiederhergestellt",
+        "backups.reload_failed" => "Datenbank konnte nicht neu geladen werden: {error}",
+        "cloud.linked" => "Erfolgreich mit der Cloud verbunden!",
+        "cloud.login_cancelled" => "Anmeldung abgebrochen",
+        "cloud.ratings_read_failed" => "Bewertungen konnten nicht gelesen werden: {error}",
+        "cloud.linked_ratings" => "Mit der Cloud verbunden, {count} Erfolgsbewertungen synchronisiert",
+        "cloud.linked_ratings_failed" => "Verbunden, aber der Upload der Bewertungen ist fehlgeschlagen: {error}",
+        "cloud.up_to_date" => "Cloud-Daten sind bereits aktuell",
+        "cloud.uploaded_changed" => "{count} geänderte Spiele hochgeladen",
+        "cloud.uploaded" => "Daten erfolgreich hochgeladen!",
+        "cloud.compare_failed" => "Vergleich mit lokalen Daten fehlgeschlagen: {error}",
+        "cloud.deleted" => "Cloud-Daten erfolgreich gelöscht!",
+        "cloud.upload_stopped" => "Upload unerwartet abgebrochen",
+        "cloud.operation_failed" => "Vorgang unerwartet fehlgeschlagen",
+        "cloud.import_failed" => "Daten konnten nicht importiert werden: {error}",
+        "cloud.merged" => "{games} Spiele und {achievements} Erfolge aus der Cloud zusammengeführt!",
+        "cloud.achievements_failed" => "Erfolge konnten nicht abgerufen werden: {error}",
+        "community_import.need_steam_id" => "Gib deine Steam-ID ein, um aus der Steam-Community zu importieren",
+        "community_import.importing" => "Spiele werden aus der Steam-Community importiert...",
+        "community_import.failed" => "Import fehlgeschlagen: {error}",
+        "community_import.read_failed" => "{path} konnte nicht gelesen werden: {error}",
+        "community_import.wrong_account" => "Import fehlgeschlagen: Die XML-Datei gehört zur Steam-ID {xml}, nicht zu {configured}",
+        "community_import.steam_id_first" => "Import fehlgeschlagen: Gib zuerst deine Steam-ID ein",
+        "community_import.done" => "{count} Spiele aus der Steam-Community importiert ({new} neu)",
+        "deep_link.not_in_library" => "Spiel {appid} aus dem Link ist nicht in deiner Bibliothek",
+        "local_unlocks.one" => "{name} in {game} freigeschaltet",
+        "local_unlocks.many" => "{count} Erfolge in {game} freigeschaltet",
+        "overlay.listen_failed" => "Port {port} konnte nicht geöffnet werden: {error}",
+        "power.unknown_app" => "App {appid}",
+        "power.playing" => "{game} läuft",
+        "power.on_battery_percent" => "Akkubetrieb ({percent}%)",
+        "power.on_battery" => "Akkubetrieb",
+        "power.high_load" => "Hohe CPU-Last ({percent}%)",
+        "power.plugged_in" => "Am Netz",
+        "progress.starting_fetch" => "Abruf wird gestartet...",
+        "progress.starting_scrape" => "Erfolgsabruf wird gestartet...",
+        "progress.starting_update" => "Update wird gestartet...",
+        "progress.refreshing" => "Spiel {appid} wird aktualisiert...",
+        "progress.refreshing_queued" => "Spiel {appid} wird aktualisiert... ({queued} in der Warteschlange)",
+        "progress.requesting" => "Anfrage läuft...",
+        "progress.downloading" => "Wird heruntergeladen...",
+        "progress.processing" => "Wird verarbeitet...",
+        "progress.saving" => "Wird in der Datenbank gespeichert...",
+        "progress.fetched" => "{count} Spiele abgerufen!",
+        "progress.error" => "Fehler: {error}",
+        "progress.fetching_games" => "Spiele werden abgerufen...",
+        "progress.scrape_start" => "Abruf über die Steam-API: 0 / {total} Spiele...",
+        "progress.scrape" => "Abruf über die Steam-API: {current} / {total}: {game}",
+        "progress.scrape_paused" => "Abruf über die Steam-API: pausiert bei {current} / {total} ({reason})",
+        "progress.full_scan_done" => "Vollständiger Scan abgeschlossen!",
+        "progress.fetching_recent" => "Zuletzt gespielte Spiele werden abgerufen...",
+        "progress.updating" => "Aktualisiere {current} / {total}: {game}",
+        "progress.update_done" => "Update abgeschlossen! {count} Spiele aktualisiert.",
+        "progress.refresh_done" => "Aktualisierung abgeschlossen!",
+        "progress.refresh_error" => "Fehler bei der Aktualisierung: {error}",
+        "records.streak_reminder_title" => "Deine Freischalt-Serie ist in Gefahr",
+        "records.streak_reminder_body" => "Heute noch kein Erfolg freigeschaltet - schalte vor Mitternacht einen frei, um deine {days}-Tage-Serie zu halten.",
+        "rival.fetching_games" => "Spiele des Rivalen werden abgerufen...",
+        "rival.scraping" => "Rivale wird abgerufen {current}/{total}: {game}",
+        "rival.scrape_failed" => "Abruf des Rivalen fehlgeschlagen: {error}",
+        "steam_visibility.synced" => "{count} ausgeblendete/private Spiele von Steam synchronisiert",
+        "steam_visibility.sync_failed" => "Ausgeblendete Steam-Spiele konnten nicht synchronisiert werden: {error}",
+        "steam_visibility.private_found" => "{count} private Spiele in deinem Steam-Profil gefunden",
+        "steam_visibility.store_failed" => "Private Spiele konnten nicht gespeichert werden: {error}",
+        "steam_visibility.read_failed" => "Private Spiele konnten nicht aus deinem Steam-Profil gelesen werden: {error}",
+        "tags.scan_done" => "Tag-Scan abgeschlossen!",
+        "tags.loaded" => "Tags für App {appid} geladen",
+        "tags.error" => "Tag-Fehler: {error}",
+        "tags.scan_paused" => "Tag-Scan: pausiert ({reason})",
+        "tags.scan_progress" => "Tag-Scan: {current} / {total} Spiele",
+        "tags.fetching" => "Tags für App {appid} werden abgerufen...",
+        "tags.scan_cancelled" => "Tag-Scan abgebrochen",
+        "ttb.report_saved" => "Spielzeit-Meldung gespeichert",
+        "ttb.report_failed" => "Spielzeit-Meldung konnte nicht gespeichert werden: {error}",
+        "ttb.none_to_report" => "Keine abgeschlossenen Spiele mehr zu melden",
+        "ttb.bulk_done" => "Zeiten für {reported} von {total} abgeschlossenen Spielen gemeldet",
+        "ttb.scan_start" => "Spielzeit-Scan: 0 / {total} Spiele",
+        "ttb.scan_cancelled" => "Spielzeit-Scan abgebrochen",
+        "ttb.scan_done_downloading" => "Spielzeit-Scan abgeschlossen! Download vom Server...",
+        "ttb.loaded" => "Spielzeit für {game} geladen",
+        "ttb.error" => "Spielzeit-Fehler: {error}",
+        "ttb.scan_paused" => "Spielzeit: pausiert ({reason})",
+        "ttb.scan_progress" => "Spielzeit: {current} / {total} - {game}",
+        "ttb.fetching" => "Spielzeit für {game} wird abgerufen...",
+        "ttb.downloading_all" => "Alle Spielzeiten werden vom Server heruntergeladen...",
+        "ttb.scan_done_downloaded" => "Spielzeit-Scan abgeschlossen! {count} Einträge vom Server heruntergeladen",
+        "ttb.scan_done" => "Spielzeit-Scan abgeschlossen!",
+
+        // Unlock timeline
+        "timeline.title" => "Freischalt-Verlauf: {game}",
+        "timeline.empty" => "Keine Freischaltungen mit Datum zum Abspielen.",
+        "timeline.first" => "Erste Freischaltung",
+        "timeline.last" => "Letzte Freischaltung",
+        "timeline.pause" => "Pause",
+        "timeline.replay" => "Abspielen",
+        "timeline.of_total" => " von {total}",
+        "timeline.after_break" => "{break} vorbei",
+        "timeline.summary" => "{count} Freischaltungen, {first} – {last}",
+        "timeline.saved_png" => "Bild des Freischalt-Verlaufs unter {path} gespeichert",
+        "timeline.save_failed" => "Bild des Freischalt-Verlaufs konnte nicht gespeichert werden: {error}",
+        "timeline.break_year" => "1 Jahr Pause",
+        "timeline.break_years" => "{count} Jahre Pause",
+        "timeline.break_month" => "1 Monat Pause",
+        "timeline.break_months" => "{count} Monate Pause",
+        "timeline.break_week" => "1 Woche Pause",
+        "timeline.break_weeks" => "{count} Wochen Pause",
+        "timeline.break_day" => "1 Tag Pause",
+        "timeline.break_days" => "{count} Tage Pause",
+
+        // Completion planner
+        "planner.title" => "Plan: {game}",
+        "planner.summary" => "{left} von {total} offen, {time} verbleibend",
+        "planner.export" => "Exportieren",
+        "planner.export_tooltip" => "Als druckbare HTML-Seite speichern",
+        "planner.copy" => "Kopieren",
+        "planner.copy_tooltip" => "Als Markdown-Checkliste kopieren",
+        "planner.source_ttb" => "Die Schätzungen teilen die verbleibende HowLongToBeat-Komplettierungszeit nach Schwierigkeit auf",
+        "planner.source_rough" => "Grobe Schätzungen - rufe die Spielzeit ab, um Schätzungen auf Basis der Komplettierungszeit zu erhalten",
+        "planner.loading_ratings" => "Community-Schwierigkeit wird geladen, vorerst nach Seltenheit sortiert",
+        "planner.nothing_left" => "Nichts mehr freizuschalten.",
+        "planner.difficulty_tooltip" => "Community-Schwierigkeit (1-5)",
+        "planner.percent_tooltip" => "Spieler, die ihn freigeschaltet haben",
+        "planner.exported" => "Komplettierungsplan nach {path} exportiert",
+        "planner.export_failed" => "Komplettierungsplan konnte nicht exportiert werden: {error}",
+
+        // Library files
+        "library_file.import_title" => "Bibliothek importieren",
+        "library_file.exported_at" => "Exportiert",
+        "library_file.app_version" => "App-Version",
+        "library_file.unknown_version" => "unbekannt",
+        "library_file.steam_id" => "Steam-ID",
+        "library_file.games" => "Spiele",
+        "library_file.achievements" => "Erfolge",
+        "library_file.unlocked_of" => "{unlocked} von {total} freigeschaltet",
+        "library_file.history" => "Verlauf",
+        "library_file.history_counts" => "{runs} Durchläufe, {snapshots} Fortschritts-Snapshots, {goals} Ziele",
+        "library_file.other_account" => "Diese Datei gehört zu einem anderen Steam-Konto als deinem ({steam_id})",
+        "library_file.nothing_new" => "Deine lokalen Daten enthalten bereits alles aus dieser Datei.",
+        "library_file.diff_unlocks" => "Die Datei hat {file} Freischaltungen, die lokal fehlen; lokal gibt es {local}, die der Datei fehlen.",
+        "library_file.diff_games" => "{count} Spiele werden hinzugefügt.",
+        "library_file.diff_playtime" => "{count} Spiele haben in der Datei mehr Spielzeit.",
+        "library_file.merge_rules" => "Lokal wird nichts entfernt: Freischaltungen behalten die neueste Zeit, die höchste Spielzeit gewinnt, der Verlauf wird kombiniert. Vorher wird ein Backup erstellt.",
+        "library_file.exported" => "Bibliothek nach {path} exportiert",
+        "library_file.merged" => "{games} Spiele und {achievements} Erfolge aus {path} zusammengeführt",
+        "library_file.import_failed" => "Bibliothek konnte nicht importiert werden: {error}",
+
+        _ => return None,
+    })
+}
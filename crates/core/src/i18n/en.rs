@@ -0,0 +1,1022 @@
+//! English strings (the fallback for every other language)
+
+pub(super) fn lookup(key: &str) -> Option<&'static str> {
+    Some(match key {
+        // Shared
+        "common.all" => "All",
+        "common.any" => "Any",
+        "common.yes" => "Yes",
+        "common.no" => "No",
+        "common.clear" => "Clear",
+        "common.remove" => "Remove",
+        "common.dismiss" => "Dismiss",
+        "common.ok" => "OK",
+        "common.cancel" => "Cancel",
+
+        // Toolbar
+        "toolbar.build" => "Build #{number}\n{date}",
+        "toolbar.update" => "Update",
+        "toolbar.update_tooltip" => "Scrape achievements for recently played games and for every game whose playtime changed.
+Last update: {when}",
+        "toolbar.never" => "never",
+        "toolbar.configure_first" => "Please configure Steam API Key and Steam ID in Settings (⚙)",
+        "toolbar.full_scan" => "Full Scan",
+        "toolbar.force" => "Force",
+        "toolbar.refresh_stale" => "Refresh stale ({count})",
+        "toolbar.refresh_stale_tooltip" => "Re-scrape achievements for games last scraped more than {days} days ago",
+        "toolbar.report_times" => "Report times ({count})",
+        "toolbar.report_times_tooltip" => "Report times for finished games - walks through your 100% completed games one by one",
+        "toolbar.settings" => "Settings",
+        "toolbar.final_stretch" => "Final Stretch",
+        "toolbar.stalled_review" => "Review Stalled Games",
+        "toolbar.rival" => "Rivalry summary",
+        "toolbar.year_review" => "Year in Review",
+        "toolbar.compare_snapshots" => "Compare Snapshots",
+        "toolbar.behind_schedule" => "Behind schedule:\n{goals}",
+        "toolbar.profile_menu" => "Profile Menu",
+        "toolbar.login" => "Login with Steam",
+        "toolbar.power_full" => "Background scans running at full speed (override)",
+        "toolbar.power_slow" => "Background scans slowed down",
+        "toolbar.power_paused" => "Background scans paused",
+        "toolbar.paused_playing" => "Playing {game}, scans resume once you stop",
+        "toolbar.power_click_auto" => "Click to adapt to battery & load",
+        "toolbar.power_click_full" => "Click to run at full speed anyway",
+        "toolbar.stop_ttb" => "Stop TTB",
+        "toolbar.ttb_scan" => "TTB Scan",
+        "toolbar.ttb_scan_count" => "TTB Scan ({count})",
+        "toolbar.ttb_scan_tooltip" => "Scan HowLongToBeat for game completion times (1 game/minute)",
+        "toolbar.ttb_download" => "DL TTB",
+        "toolbar.ttb_download_tooltip" => "Download all TTB times from the server",
+        "toolbar.stop_tags" => "Stop Tags",
+        "toolbar.tags_scan" => "Tags Scan",
+        "toolbar.tags_scan_count" => "Tags Scan ({count})",
+        "toolbar.tags_scan_tooltip" => "Fetch game tags from SteamSpy (1 game/{secs}s)",
+        "toolbar.queued_refreshes" => "Queued refreshes:\n{games}",
+        "toolbar.admin_on" => "Admin Mode: ON\nClick to disable TTB scanning",
+        "toolbar.admin_off" => "Admin Mode: OFF\nClick to enable TTB scanning",
+        "toolbar.admin_dashboard" => "Admin Dashboard",
+
+        // Settings
+        "settings.title" => "Settings",
+        "settings.tab_general" => "General",
+        "settings.tab_steam" => "Steam",
+        "settings.tab_debug" => "Debug",
+        "settings.language" => "Language",
+        "settings.language_label" => "Language:",
+        "settings.appearance" => "Appearance",
+        "settings.font_source" => "Font Source:",
+        "settings.startup" => "Startup",
+        "settings.check_for_updates" => "Check for new versions on startup",
+        "settings.show_changelog" => "Show changelog",
+        "settings.current_version" => "Version {version}",
+        "settings.notifications" => "Notifications",
+        "settings.statistics" => "Statistics",
+        "settings.games_table" => "Games Table",
+        "settings.network" => "Network",
+        "settings.stream_overlay" => "Stream Overlay",
+        "settings.cloud_sync" => "Cloud Sync",
+        "settings.presence" => "Playing Now",
+        "settings.icon_cache" => "Icon Cache",
+        "settings.download_all_icons" => "Download all icons",
+        "settings.download_all_icons_tooltip" => "Fetch every achievement and game icon so expanded rows and the log work offline. Already downloaded icons are skipped, so a stopped run continues where it left off. Stops once the cache reaches its maximum size and slows down or pauses like the background scans.",
+        "settings.icons_downloaded" => "Downloaded {count} icons ({cached} were already cached)",
+        "settings.icons_failed" => ", {count} failed",
+        "settings.icons_stopped" => " - stopped early, run again to continue",
+        "settings.dates_times" => "Dates & Times",
+        "settings.data_location" => "Data Location",
+        "settings.library_file" => "Library File",
+        "settings.steam_credentials" => "Steam Credentials",
+        "settings.font_builtin" => "Built-in (egui default)",
+        "settings.font_cjk" => "CJK (Source Han Sans)",
+        "settings.font_system" => "Windows Font",
+        "settings.font_not_downloaded" => "Font not downloaded",
+        "settings.font_download" => "Download Source Han Sans (~100 MB)",
+        "settings.font_license" => "View License",
+        "settings.font_starting_download" => "Starting download...",
+        "settings.font_downloading_of" => "Downloading: {done} / {total} MB",
+        "settings.font_downloading" => "Downloading: {done} MB",
+        "settings.font_extracting" => "Extracting font...",
+        "settings.font_download_complete" => "Download complete!",
+        "settings.font_download_failed" => "Download failed: {error}",
+        "settings.font_downloaded" => "Font downloaded",
+        "settings.font_weight" => "Font Weight:",
+        "settings.font" => "Font:",
+        "settings.font_select" => "Select...",
+        "settings.font_size" => "Font Size:",
+        "settings.font_size_save" => "Save Font Size",
+        "settings.unsaved" => "(unsaved)",
+        "settings.startup_view" => "Open at startup:",
+        "settings.startup_sidebar" => "Expand sidebar at startup",
+        "settings.streak_reminder" => "Remind me when my unlock streak is about to break",
+        "settings.streak_reminder_from" => "Remind from:",
+        "settings.exclude_non_games" => "Exclude non-game apps from statistics",
+        "settings.exclude_non_games_tooltip" => "Soundtracks, tools, demos etc. ({count} in your library). App types are fetched during full scans.",
+        "settings.include_unowned" => "Include games you don't own in statistics",
+        "settings.include_unowned_tooltip" => "Games played during free weekends or through Family Sharing ({count} in your library)",
+        "settings.snapshot_interval" => "Record a history snapshot every",
+        "settings.days_suffix" => " days",
+        "settings.snapshot_interval_tooltip" => "Keeps the history graphs on a regular cadence when you don't update for a while, using the cached library. 0 = off.",
+        "settings.sort_ties" => "Sort ties by:",
+        "settings.sort_ties_tooltip" => "Order of games the sort column ranks equal, e.g. all 0% games. Automatic: % then name, playtime then last played.",
+        "settings.deck_column" => "Show Steam Deck playtime column",
+        "settings.deck_column_tooltip" => "Time played on a Steam Deck, from the per-platform playtime Steam reports on each update",
+        "settings.reveal_spoilers" => "Show descriptions of hidden achievements",
+        "settings.reveal_spoilers_tooltip" => "Steam hides some achievements until they're unlocked. When off, their descriptions stay covered until you click them.",
+        "settings.overlay_enabled" => "Serve a stream overlay on this computer",
+        "settings.overlay_enabled_tooltip" => "Shows the current game, its completion and recent unlocks. Add the URL as a browser source in OBS.",
+        "settings.overlay_port" => "Port:",
+        "settings.copy_url" => "Copy URL",
+        "settings.auto_upload" => "Upload automatically after Update / Full Scan",
+        "settings.auto_upload_tooltip" => "Keeps the web version up to date. Failed uploads are retried in the background.",
+        "settings.link_first" => "Link your account to the cloud to use this.",
+        "settings.share_presence" => "Share what I'm playing with other linked users",
+        "settings.share_presence_tooltip" => "Your Steam name, the game and its achievement progress are shown in the sidebar of others who share too. Nothing is stored.",
+        "settings.icon_cache_max" => "Maximum size:",
+        "settings.icon_cache_usage" => "Using {mb} MB - least recently used icons are removed beyond the limit",
+        "settings.icon_memory_max" => "Memory limit:",
+        "settings.icon_memory_max_tooltip" => "Icons shown least recently are dropped from memory beyond this and reloaded from disk when needed",
+        "settings.open_data_dir" => "Open data directory",
+        "settings.data_dir_default" => "Config, database and icon cache are in the app data directory",
+        "settings.data_dir_portable" => "Portable mode - data is stored next to the executable",
+        "settings.data_dir_custom" => "Data directory relocated from settings",
+        "settings.data_dir_restart" => "Data copied - restart Overachiever to use the new location",
+        "settings.data_dir_busy" => "Wait for the running update or scan to finish",
+        "settings.portable_off" => "Turn off portable mode",
+        "settings.portable_off_tooltip" => "Copy the data back to the app data directory, stop using the folder next to the executable and restart",
+        "settings.portable_on" => "Use portable mode",
+        "settings.portable_on_tooltip" => "Copy the data into a folder next to the executable, keep it there (for running off a USB stick) and restart.\nThe same as starting with --portable.",
+        "settings.relocate_to" => "Move to:",
+        "settings.relocate_hint" => "New folder",
+        "settings.relocate" => "Copy & relocate",
+        "settings.relocate_tooltip" => "Copy the config, database and icon cache to this folder and restart to use it.\nThe current directory is left untouched.",
+        "settings.export_library" => "Export library",
+        "settings.export_library_tooltip" => "Save games, achievements, history and goals as a .{ext} file to your Downloads folder",
+        "settings.import_library_hint" => "Open a .{ext} file with Overachiever or drop it onto the window to preview and merge it",
+        "settings.time_zone" => "Time zone:",
+        "settings.timestamp_absolute" => "Date (2024-03-15)",
+        "settings.timestamp_relative" => "Relative (3 days ago)",
+        "settings.timestamps_log" => "Activity log:",
+        "settings.timestamps_achievements" => "Achievements:",
+        "settings.show_time_of_day" => "Show time of day in dates",
+        "settings.theme" => "Theme:",
+        "settings.accent_color" => "Accent Color:",
+        "settings.reset" => "Reset",
+        "settings.complete_threshold" => "Done at:",
+        "settings.complete_threshold_tooltip" => "Games at or above this completion count as done in the % column, stats and Final Stretch",
+        "settings.near_complete_threshold" => "Near-complete at:",
+        "settings.near_complete_threshold_tooltip" => "Games at or above this completion (and below done) are highlighted as near complete and listed in Final Stretch",
+        "settings.preview" => "Preview:",
+        "settings.credentials_checking" => "Checking with Steam...",
+        "settings.profile_not_public" => "This profile isn't public: Steam only lists its games for an API key registered to the same account.",
+        "settings.credentials_check" => "Check credentials",
+        "settings.steam_id" => "Steam ID:",
+        "settings.steam_id_tooltip" => "Your 64-bit Steam ID, profile URL or custom profile name (resolved when you leave the field)",
+        "settings.api_key" => "API Key:",
+        "settings.api_key_hint" => "Your Steam API key",
+        "settings.get_api_key" => "Get API Key",
+        "settings.no_affiliation" => "(No affiliation)",
+        "settings.find_steam_id" => "Figure out Steam ID",
+        "settings.community_import_intro" => "No API key? Import your games list from a public Steam Community profile:",
+        "settings.community_import" => "Import from Steam Community",
+        "settings.community_import_tooltip" => "Requires your profile's game details to be public",
+        "settings.community_import_file" => "Or save steamcommunity.com/profiles/<id>/games?tab=all&xml=1 and drop the file onto the window",
+        "settings.rival_intro" => "Rival to compare achievements against (public profile, scraped read-only):",
+        "settings.rival_hint" => "Rival's Steam ID",
+        "settings.rival_scrape" => "Scrape rival",
+        "settings.rival_scrape_tooltip" => "Fetches the rival's games and achievements for the games you both own",
+        "settings.session_tracking" => "Session tracking:",
+        "settings.session_tracking_tooltip" => "Check which game you're playing every N seconds to record play sessions (0 = off)",
+        "settings.power_mode" => "Background scans:",
+        "settings.power_currently" => "Currently: {status}",
+        "settings.pause_while_playing" => "Pause scans while playing",
+        "settings.pause_while_playing_tooltip" => "Full, TTB and tag scans wait while Steam shows you in a game and resume once you stop",
+        "settings.pause_needs_sessions" => "Needs session tracking to see which game is running",
+        "settings.local_unlocks" => "Detect unlocks from the Steam client (experimental)",
+        "settings.local_unlocks_tooltip" => "Watch the stats the Steam client caches on this computer and pick up unlocks within seconds, without waiting for the next Update",
+        "settings.local_unlocks_hint" => "Only works for games played on this computer",
+        "settings.stale_after" => "Stale after:",
+        "settings.stale_after_tooltip" => "Games last scraped longer ago than this get a warning badge and are picked up by \"Refresh stale\"",
+        "settings.credentials_required" => "Steam ID and API Key are required",
+        "settings.credentials_valid" => "Configuration valid",
+        "settings.proxy" => "Proxy:",
+        "settings.proxy_tooltip" => "HTTP(S) or SOCKS5 proxy for all requests. Empty uses the system proxy settings.",
+        "settings.steam_api_url" => "Steam API URL:",
+        "settings.server_url" => "Server URL:",
+        "settings.server_url_tooltip" => "Backend for cloud sync, ratings and community data",
+        "settings.debug" => "Debug",
+        "settings.debug_recently_played" => "Log recently played response",
+        "settings.debug_recently_played_tooltip" => "When running Update, write the recently played API response to recently_played_debug.txt",
+        "settings.config_files" => "Configuration Files:",
+        "settings.open_config_file" => "Open Config File",
+        "settings.open_config_dir" => "Open Config Directory",
+        "settings.bug_reports" => "Bug reports:",
+        "settings.export_anonymized" => "Export anonymized database",
+        "settings.export_anonymized_tooltip" => "Save a copy of the database with Steam IDs and names replaced, to attach to bug reports.\nYour config file (API key, tokens) is not included.",
+        "settings.performance" => "Performance:",
+        "settings.run_benchmark" => "Run benchmark",
+        "settings.run_benchmark_tooltip" => "Time database load, filtering, sorting and a 5000-row table render on this machine",
+        "settings.copy_report" => "Copy report",
+        "settings.icon_cache_stats" => "Icon cache:",
+        "settings.icons_in_memory" => "In memory:",
+        "settings.icons_in_memory_value" => "{count} icons, {mb} / {max} MB",
+        "settings.icons_memory_hits" => "Memory hits / loads:",
+        "settings.icons_evicted" => "Evicted from memory:",
+        "settings.icons_on_disk" => "On disk:",
+        "settings.icons_disk_hits" => "Disk hits / misses:",
+        "settings.backups" => "Database backups:",
+        "settings.backups_keep" => "Keep",
+        "settings.backups_keep_tooltip" => "Backups are taken before cloud imports, updates and achievement schema changes",
+        "settings.backups_copies" => "copies",
+        "settings.backup_now" => "Back up now",
+        "settings.open_backups" => "Open backups folder",
+        "settings.no_backups" => "No backups yet",
+        "settings.restore_backup" => "Restore from backup",
+        "settings.restore_confirm" => "Replace the current database with this backup? It is backed up first.",
+        "settings.restore" => "Restore",
+        "settings.icons_paused" => "Paused ({reason})",
+        "settings.icons_budget_reached" => " - stopped at the maximum size, raise it to download the rest",
+        "settings.icons_over_budget" => "{count} icons need about {mb} MB, but only {free} MB are left below the maximum size.",
+        "settings.icons_download_to_limit" => "Download until the limit",
+
+        // Filter bar
+        "filter.search_hint" => "Search by name...",
+        "filter.search_tags_hint" => "Search tags...",
+        "filter.tags_all" => "All tags",
+        "filter.tags_any" => "Any tag",
+        "filter.tags_all_tooltip" => "Showing games with every included tag, click to match any of them",
+        "filter.tags_any_tooltip" => "Showing games with at least one included tag, click to require all of them",
+        "filter.tag_included_hint" => "Included - click to exclude games with this tag",
+        "filter.tag_excluded_hint" => "Excluded - click to include it instead",
+        "filter.achievements" => "Achievements",
+        "filter.played" => "Played",
+        "filter.hours_short" => "Hrs",
+        "filter.hours_played" => "Hours played",
+        "filter.installed" => "Installed",
+        "filter.games" => "Games",
+        "filter.other" => "Other",
+        "filter.app_type_tooltip" => "Games (vs soundtracks, tools, demos)",
+        "filter.ttb_tooltip" => "Time to Beat",
+        "filter.ttb_range_tooltip" => "Time to Beat (main story hours)",
+        "filter.hidden" => "Hidden",
+        "filter.visible" => "Visible",
+        "filter.hidden_tooltip" => "Hidden games (in Overachiever or in Steam)",
+        "filter.private" => "Private",
+        "filter.public" => "Public",
+        "filter.private_tooltip" => "Marked private on Steam",
+        "filter.min" => "min",
+        "filter.max" => "max",
+        "group.none" => "No grouping",
+        "group.letter" => "A–Z",
+        "group.last_played_year" => "Last played year",
+        "group.completion" => "Completion",
+        "group.never_played" => "Never played",
+        "group.under_25" => "Under 25%",
+        "group.no_achievements" => "No achievements",
+        "group.tooltip" => "Group the games into collapsible sections",
+
+        // Games table
+        "table.name" => "Name",
+        "table.last_played" => "Last Played",
+        "table.playtime" => "Playtime",
+        "table.deck_playtime" => "Deck",
+        "table.deck_playtime_tooltip" => "Time played on a Steam Deck",
+        "table.played_on" => "Played on:",
+        "table.achievements" => "Achievements",
+        "table.votes" => "Votes",
+        "table.ttb_tooltip" => "Time to Beat",
+        "table.votes_tooltip" => "Tag votes from SteamSpy",
+        "table.playing_now" => "Playing now",
+        "table.private_on_steam" => "Private on Steam",
+        "table.hidden_both" => "Hidden in Overachiever and in Steam",
+        "table.hidden_app" => "Hidden in Overachiever",
+        "table.hidden_steam" => "Hidden in Steam",
+        "table.abandoned" => "Abandoned - left out of completion averages and time left",
+        "table.abandon" => "Mark as abandoned (keeps it listed, but out of completion averages)",
+        "table.unabandon" => "Take back up (count towards completion again)",
+        "table.head_to_head" => "you {mine}/{total}, rival {theirs}/{total}",
+        "table.head_to_head_tooltip" => "Achievements unlocked by you and your rival",
+        "table.community" => "Community:",
+        "table.community_avg" => "avg {percent}%",
+        "table.community_perfect" => "{percent}% perfect",
+        "table.community_median" => "median {hours}h",
+        "table.community_tooltip" => "Among {players} Overachiever users who played this game",
+
+        "palette.hint" => "Search games, commands and achievements",
+        "palette.command" => "Command",
+        "palette.upload" => "Upload to cloud",
+        "palette.no_results" => "Nothing matches",
+        "stalled.title" => "Stalled Games",
+        "stalled.description" => "Games not played for a year and less than half complete. Abandoned games stay in the library but no longer count towards completion averages.",
+        "stalled.none_left" => "No stalled games left to review.",
+        "stalled.remaining" => "{count} games to review",
+        "stalled.last_played" => "Last played {when}, {hours}h played",
+        "stalled.abandon" => "Abandon",
+        "stalled.keep" => "Keep",
+        "stalled.keep_tooltip" => "Still planning to finish it - don't suggest it again",
+        "stalled.skip" => "Skip",
+        "stalled.skip_tooltip" => "Decide later",
+        "whats_new.title" => "What's New",
+        "whats_new.update_available" => "Version {version} is available",
+        "whats_new.download" => "Download",
+        "whats_new.empty" => "No changelog entries.",
+        "whats_new.close" => "Close",
+        "rival.title" => "Rivalry",
+        "rival.account" => "Rival: {steam_id}",
+        "rival.refresh" => "Refresh",
+        "rival.no_common_games" => "No games in common with achievement data yet. Scrape the rival first.",
+        "rival.you" => "You",
+        "rival.rival" => "Rival",
+        "rival.games_ahead" => "Games ahead",
+        "rival.achievements" => "Achievements",
+        "rival.perfect_games" => "Perfect games",
+        "rival.common_games" => "{count} games in common, {tied} tied",
+        "rival.rival_leads" => "Where your rival is ahead",
+        "rival.you_lead" => "Where you are ahead",
+        "rival.head_to_head" => "{mine} vs {theirs} / {total}",
+        "table.refresh_cooldown" => "Just refreshed - try again in a minute",
+        "table.refresh" => "Refresh achievements for this game",
+        "table.refresh_queued" => "Queued - refreshes after the games before it",
+        "table.launching" => "Launching...",
+        "table.launch" => "Launch game in Steam",
+        "table.install" => "Install game from Steam",
+        "table.sessions" => "Show play session history",
+        "table.plan" => "Plan the remaining achievements",
+        "table.unlock_timeline" => "Replay the unlock order",
+        "table.unowned_played" => "Unowned played",
+        "table.unowned_hint" => "Played during free weekends or through Family Sharing, but not in your library. Not counted in statistics unless enabled in Settings.",
+        "table.filter_achievements" => "Filter achievements...",
+        "table.achievement_state_tooltip" => "Unlocked / locked",
+        "table.unlocked" => "Unlocked",
+        "table.locked" => "Locked",
+        "table.no_matching_achievements" => "No matching achievements",
+        "table.group_games" => "{count} games",
+        "table.group_perfect" => "{count} perfect",
+        "table.group_avg_tooltip" => "Average completion of the games with achievements",
+        "table.spoiler_hidden" => "Hidden achievement - click to reveal",
+        "table.reveal_spoilers_tooltip" => "Reveal hidden achievement descriptions",
+        "table.hide_spoilers" => "Hide spoilers",
+        "table.show_spoilers" => "Show spoilers",
+        "table.copy_achievement_name" => "Copy achievement name",
+        "table.global_percent_tooltip" => "Unlocked by {percent}% of players",
+        "table.rarity_drift" => "{drift} points since {since}",
+        "table.ttb_main" => "Main: {time}",
+        "table.ttb_extra" => "+Extra: {time}",
+        "table.ttb_no_data" => "<no data>",
+        "table.ttb_completionist" => "100%: {time}",
+        "table.ttb_yours" => "Your time:",
+        "table.ttb_report" => "Report TTB",
+        "table.ttb_report_tooltip" => "Report your time to beat for this game",
+        "table.ttb_allow" => "Allow TTB",
+        "table.ttb_allow_tooltip" => "Remove from TTB blacklist (allow in scan)",
+        "table.ttb_excluded" => "Game is excluded from TTB scan",
+        "table.ttb_exclude" => "Not for TTB",
+        "table.ttb_exclude_tooltip" => "Mark as not suitable for TTB (e.g., multiplayer-only games)",
+        "table.ttb_not_available" => "n/a",
+        "table.never_scraped" => "never scraped",
+        "table.scraped_today" => "scraped today",
+        "table.scraped_yesterday" => "scraped yesterday",
+        "table.scraped_days_ago" => "scraped {days} days ago",
+        "table.stale_tooltip" => "Achievement data older than {days} days counts as stale",
+        "table.owned_by" => "Owned by {owner}",
+        "table.cloud_saves" => "Steam Cloud saves",
+        "table.cloud_last_synced" => "Last synced {when}",
+        "table.cloud_never_synced" => "Never synced",
+        "table.cloud_files" => "{count} save files tracked",
+        "table.ttb" => "TTB",
+        "table.ttb_label" => "Time to Beat:",
+        "table.fetch_ttb" => "Fetch Time To Beat from HowLongToBeat",
+        "table.refetch_ttb" => "Re-fetch Time To Beat from HowLongToBeat",
+        "table.fetch_tags" => "Fetch tags from SteamSpy",
+        "table.refetch_tags" => "Re-fetch tags from SteamSpy",
+        "table.global_percent" => "Global %",
+        "achievement_sort.unlocked_first" => "Unlocked first",
+        "achievement_sort.locked_first" => "Locked first",
+        "achievement_sort.unlock_date" => "Unlock date",
+        "achievement_sort.rarity" => "Rarity",
+        "achievement_sort.name" => "Name",
+        "achievement_sort.difficulty" => "Difficulty",
+        "tie_break.auto" => "Automatic",
+        "tie_break.name" => "Name",
+        "tie_break.last_played" => "Last played",
+        "tie_break.playtime" => "Playtime",
+        "tie_break.completion" => "Completion %",
+        "achievements.loading" => "Loading achievements...",
+        "comments.reported" => "Reported",
+        "comments.report" => "Report as abusive",
+        "difficulty.very_easy" => "Very easy",
+        "difficulty.easy" => "Easy",
+        "difficulty.moderate" => "Moderate",
+        "difficulty.hard" => "Hard",
+        "difficulty.extreme" => "Extreme",
+        "difficulty.curve" => "Community difficulty curve",
+        "difficulty.curve_rated" => "({count} rated)",
+        "difficulty.spike" => "Difficulty spike at #{index} {name}",
+        "difficulty.votes" => "{name} ({count} votes)",
+        "difficulty.series" => "Difficulty",
+        "difficulty.spike_marker" => "Difficulty spike",
+        "unlock_graph.title" => "Unlock history",
+        "unlock_graph.summary" => "({unlocked} of {total} unlocked, {from} – {to})",
+        "unlock_graph.per_month" => "Unlocked that month",
+        "unlock_graph.cumulative" => "Unlocked in total",
+        "unlock_graph.total" => "Achievements",
+        "cost.all_unlocked" => "All {total} achievements unlocked",
+        "cost.remaining" => "{remaining} of {total} achievements left",
+        "cost.ttb_left" => "~{time} left (completionist time to beat)",
+        "cost.no_ttb" => "No completionist time to beat yet",
+        "cost.pace_left" => "~{time} left at your pace so far",
+        "cost.expand_hint" => "Expand the game for difficulty and missable info",
+        "cost.hardest_left" => "Hardest left:",
+        "cost.missable" => "Possibly missable ({count}):",
+        "cost.and_more" => "and {count} more",
+        "ttb.dialog_title" => "Report Time to Beat",
+        "ttb.dialog_game" => "Game: {game}",
+        "ttb.dialog_intro" => "Enter your completion times (leave blank if you haven't completed that mode):",
+        "ttb.main_story" => "Main Story:",
+        "ttb.main_extras" => "Main + Extras:",
+        "ttb.completionist" => "100% Completionist:",
+        "ttb.submit" => "Submit",
+        "ttb.hours" => "{hours}h",
+        "ttb.hours_minutes" => "{hours}h {minutes}m",
+        "ttb.minutes" => "{minutes}m",
+        "ttb.hours_unit" => "h",
+        "ttb.minutes_unit" => "m",
+
+        // Presence (sidebar)
+        "presence.title" => "Playing now",
+        "presence.playing" => "{name} is playing {game}",
+        "presence.achievements" => "{unlocked}/{total} achievements",
+
+        // Stats panel
+        "stats.games_over_time" => "Games Over Time",
+        "stats.no_history" => "No history yet. Complete a full scan to start tracking!",
+        "stats.achievement_progress" => "Achievement Progress",
+        "stats.no_achievement_data" => "No achievement data yet. Run a full scan to start tracking!",
+        "stats.forecast" => "Forecast",
+        "stats.forecast_needs_history" => "A forecast needs at least a week of achievement history.",
+        "stats.breakdown" => "Breakdown",
+        "stats.sync_first" => "Sync your games to see stats.",
+        "stats.total_achievements" => "Total achievements:",
+        "stats.avg_completion" => "Avg. game completion:",
+        "stats.unplayed_games" => "Unplayed games:",
+        "stats.total_games" => "Total games:",
+        "stats.games_with_achievements" => "Games with achievements:",
+        "stats.needs_scanning" => "Needs scanning:",
+        "stats.by_tag" => "By tag",
+        "stats.records" => "Records",
+        "stats.no_records" => "Unlock some achievements to set records.",
+        "stats.goals" => "Goals",
+        "stats.tab_total_games" => "Total Games",
+        "stats.tab_unplayed_games" => "Unplayed Games",
+        "stats.data_points" => "{count} data points",
+        "stats.tab_avg_completion" => "Avg Game Completion %",
+        "stats.tab_overall" => "Overall Achievement %",
+        "stats.range_30d" => "30d",
+        "stats.range_90d" => "90d",
+        "stats.range_year" => "1y",
+        "stats.current_pace" => "Current pace:",
+        "stats.unlocks_per_week" => "{count} unlocks/week",
+        "stats.pace_hours" => "(~{hours} h/week, last {days} days)",
+        "stats.pace_days" => "(last {days} days)",
+        "stats.target_avg" => "Target avg completion:",
+        "stats.what_if" => "What if I play",
+        "stats.hours_per_week_suffix" => " h/week",
+        "stats.pace_at_hours" => "At {hours} h/week",
+        "stats.pace_current" => "At your current pace",
+        "stats.eta_reached" => "You've already reached {goal}",
+        "stats.eta_at" => "{pace} you'll reach {goal} in {date}",
+        "stats.eta_out_of_reach" => "{pace} you won't reach {goal}",
+        "stats.goal_avg" => "{percent}% average completion",
+        "stats.goal_overall" => "{percent}% of all achievements",
+        "stats.include_unplayed" => "Include unplayed",
+        "stats.perfect_games" => "100% completed:",
+        "stats.done_games" => "Done ({percent}%+):",
+        "stats.near_complete_games" => "Near complete ({percent}%+):",
+        "stats.add_tag" => "Add tag...",
+        "stats.pick_tags" => "Pick tags to compare them.",
+        "stats.first_achievement" => "First achievement:",
+        "stats.busiest_day" => "Busiest day:",
+        "stats.busiest_day_date" => "unlocks on {date}",
+        "stats.current_streak" => "Current streak:",
+        "stats.days" => "{count} days",
+        "stats.streak_at_risk" => "No unlock today yet - your {days}-day streak ends at midnight",
+        "stats.longest_streak" => "Longest streak:",
+        "stats.rarest_unlock" => "Rarest unlock:",
+        "stats.rarest_unlock_detail" => "({game}, {percent}% of players)",
+        "stats.oldest_unfinished" => "Oldest unfinished:",
+        "stats.oldest_unfinished_detail" => "(started {date}, {percent}%)",
+        "stats.milestones" => "Milestones ({count})",
+        "stats.no_goals" => "No goals yet - set one below.",
+        "stats.remove_goal" => "Remove goal",
+        "stats.goal_this_month" => "{current} / {target} this month",
+        "stats.goal_reached" => "Reached",
+        "stats.goal_expired" => "Deadline passed",
+        "stats.goal_games" => "{count} games",
+        "stats.goal_behind" => "Behind schedule - you'd be at {expected} by now",
+        "stats.goal_on_track" => "On track",
+        "stats.add_goal" => "Add goal",
+        "stats.goal_kind_games" => "Games per month",
+        "stats.goal_kind_avg" => "Avg completion by date",
+        "stats.goal_finish" => "Finish",
+        "stats.goal_games_per_month" => "games per month",
+        "stats.goal_reach" => "Reach",
+        "stats.goal_by" => "by",
+        "stats.goal_currently" => "Currently at {percent}%",
+        "stats.goal_add" => "Add",
+
+        // Stats dashboard
+        "dashboard.tab" => "Stats",
+        "dashboard.completion_distribution" => "Completion distribution",
+        "dashboard.no_completion_data" => "No games with achievements yet. Run a full scan to see how far along they are.",
+        "dashboard.games" => "Games",
+        "dashboard.hours_by_tag" => "Hours by tag",
+        "dashboard.no_tag_data" => "No tags cached yet. Fetch tags to see where your hours went.",
+        "dashboard.other" => "Other",
+        "dashboard.unlocks_per_month" => "Unlocks per month",
+        "dashboard.no_unlocks" => "No unlocks recorded yet.",
+        "dashboard.library_growth" => "Library growth",
+        "dashboard.games_added" => "Games added",
+        "dashboard.library_size" => "Library size",
+
+        // Log panel
+        "log.activity_log" => "Activity Log",
+        "log.empty" => "No activity yet. Sync and scan to start tracking!",
+        "log.no_matches" => "No activity for this game or period.",
+        "log.all_games" => "All games",
+        "log.from" => "From",
+        "log.to" => "to",
+        "log.date_hint" => "Days in your display time zone (YYYY-MM-DD), leave empty for no limit",
+        "log.clear_filter" => "Clear filter",
+        "log.first_play" => "played for the first time!",
+        "log.schema_added_one" => "added 1 new achievement",
+        "log.schema_added" => "added {count} new achievements",
+        "log.schema_removed_one" => "removed 1 achievement",
+        "log.schema_removed" => "removed {count} achievements",
+        "log.schema_changed" => "added {added} and removed {removed} achievement(s)",
+        "log.schema_added_list" => "Added:",
+        "log.schema_removed_list" => "Removed:",
+        "log.comment_title" => "Comment on {count} achievement(s)",
+        "log.clear_selection" => "Clear selection",
+        "log.selected" => "Selected:",
+        "log.and_more" => "and {count} more...",
+        "log.comment_hint" => "Add a comment about these achievements...",
+        "log.comment_submit" => "Submit",
+
+        // Profile menu
+        "profile.title" => "Profile",
+        "profile.not_set" => "Not set",
+        "profile.link" => "Link your account to enable cloud sync",
+        "profile.your_profile" => "Your profile:",
+        "profile.copy_link" => "Copy Link",
+        "profile.open" => "Open",
+        "profile.upload" => "Publish online",
+        "profile.upload_tooltip" => "Upload your local data to overachiever.space",
+        "profile.download" => "Import from cloud",
+        "profile.download_tooltip" => "Download data from overachiever.space (backup if removed locally)",
+        "profile.delete" => "Erase online data",
+        "profile.delete_tooltip" => "Remove your data from overachiever.space",
+        "profile.logout" => "Logout",
+        "profile.logout_tooltip" => "Unlink your account from cloud sync",
+        "profile.linking" => "Waiting for Steam login... (check your browser)",
+        "profile.uploading" => "Uploading {mb} MB...",
+        "profile.preparing_upload" => "Preparing upload...",
+        "profile.downloading" => "Downloading...",
+        "profile.deleting" => "Deleting...",
+        "profile.other_profiles" => "Other profiles",
+        "profile.no_other_profiles" => "No other profiles have local data yet",
+        "profile.open_window" => "Open in new window",
+        "profile.steam_id_hint" => "Steam ID",
+        "profile.merged" => "Merged library",
+        "profile.merged_needs_two" => "Needs local data for at least two profiles",
+        "profile.merged_dedupe" => "Show games owned by several accounts once",
+        "profile.merged_open" => "Open merged view",
+        "profile.mini_window" => "Mini window",
+        "profile.mini_window_tooltip" => "Small always-on-top window with completion, today's unlocks and your streak",
+        "profile.private_games" => "Private Games",
+        "profile.hide_private" => "Hide private games",
+        "profile.hide_private_tooltip" => "Hide games marked as private in Steam from the games table and online profile",
+        "profile.hidden_count" => "Hidden from library: {count} games",
+        "profile.steam_private" => "Private games in Steam:",
+        "profile.import_private" => "Import Private Games from Steam",
+        "profile.import_private_tooltip" => "Reads Steam's local config, or your public profile games list when Steam isn't installed here",
+        "profile.private_count" => "{count} private games",
+        "profile.hide_tags" => "Hide games with tag:",
+        "profile.hide_tags_hint" => "Search tags to hide...",
+
+        // Cloud dialogs
+        "cloud.upload_title" => "Upload to Cloud",
+        "cloud.upload_message" => "This will upload all your local data to overachiever.space.\nAny existing cloud data will be replaced.",
+        "cloud.upload" => "Upload",
+        "cloud.download_title" => "Download from Cloud",
+        "cloud.download_message" => "This will download data from overachiever.space and merge it with your local data.\nYou'll see a summary of the changes first.",
+        "cloud.download" => "Download",
+        "cloud.delete_title" => "Remove from Cloud",
+        "cloud.delete_message" => "This will permanently delete all your data from overachiever.space.\nYour local data will not be affected.",
+        "cloud.delete" => "Delete",
+        "cloud.merge_title" => "Merge Cloud Data",
+        "cloud.merge_nothing" => "Your local data already has everything in the cloud.",
+        "cloud.merge_unlocks" => "The cloud has {cloud} unlocks you don't have locally; local has {local} the cloud lacks.",
+        "cloud.merge_games" => "{cloud} games are only in the cloud, {local} only locally.",
+        "cloud.merge_playtime" => "{count} games have more playtime in the cloud.",
+        "cloud.merge_history" => "{count} history snapshots will be added.",
+        "cloud.merge_rules" => "Nothing local is removed: unlocks keep the newest unlock time, the highest playtime wins, history is combined.",
+        "cloud.merge" => "Merge",
+
+        // Privacy dialog
+        "gdpr.title" => "Privacy & Data Usage",
+        "gdpr.intro" => "This application processes personal data to provide its services:",
+        "gdpr.data" => "Data We Process",
+        "gdpr.data_steam_id" => "Your Steam ID (public identifier)",
+        "gdpr.data_name" => "Your Steam display name",
+        "gdpr.data_library" => "Your game library (via Steam API)",
+        "gdpr.data_achievements" => "Achievement data for your games",
+        "gdpr.data_ratings" => "Community ratings/tips you submit",
+        "gdpr.purpose" => "Purpose",
+        "gdpr.purpose_text" => "Your personal game data stays local on your computer. Only community ratings and tips you choose to submit are synced to overachiever.space.",
+        "gdpr.third_parties" => "Third Parties",
+        "gdpr.third_parties_text" => "We use the Steam Web API to fetch your public game and achievement data. No data is shared with other third parties.",
+        "gdpr.accepted" => "Currently: Accepted",
+        "gdpr.declined" => "Currently: Declined",
+        "gdpr.accept" => "Accept",
+        "gdpr.accept_tooltip" => "Accept data processing and continue",
+        "gdpr.decline" => "Decline",
+        "gdpr.decline_tooltip" => "Decline - server features will be disabled",
+        "gdpr.close" => "Close",
+        "gdpr.close_tooltip" => "Close without changes",
+
+        // Milestones
+        "milestone.title" => "Milestone reached!",
+        "milestone.achievements" => "{nth} achievement",
+        "milestone.perfect_games" => "{nth} perfect game",
+        "milestone.streak" => "{n}-day unlock streak",
+        "milestone.dismiss" => "Nice!",
+
+        // Goals and records
+        "goal.one_game_per_month" => "Finish 1 game per month",
+        "goal.games_per_month" => "Finish {count} games per month",
+        "goal.avg_completion_by" => "Reach {target}% avg completion by {date}",
+        "tag_stats.tag" => "Tag",
+        "tag_stats.owned" => "Owned",
+        "tag_stats.hours" => "Hours",
+        "tag_stats.completion" => "Avg %",
+        "tag_stats.ttb_left" => "TTB left",
+        "time.local" => "Local time",
+
+        // Admin dashboard
+        "admin.title" => "Admin Dashboard",
+        "admin.tab_overview" => "Overview",
+        "admin.tab_users" => "Users",
+        "admin.tab_ttb" => "TTB review ({count})",
+        "admin.tab_tags" => "Tag review ({count})",
+        "admin.tab_tag_aliases" => "Tag aliases",
+        "admin.tab_reports" => "Reports ({count})",
+        "admin.reload" => "Reload",
+        "admin.loading" => "Loading...",
+        "admin.users" => "Users:",
+        "admin.users_detail" => "{total} ({week} active in 7 days, {month} in 30 days)",
+        "admin.games_stored" => "Games stored:",
+        "admin.achievements_stored" => "Achievements stored:",
+        "admin.cloud_uploads" => "Cloud uploads:",
+        "admin.cloud_uploads_detail" => "{total} total, largest {largest}",
+        "admin.database_size" => "Database size:",
+        "admin.pending_review" => "Pending review:",
+        "admin.pending_review_detail" => "{ttb} TTB, {tags} tag submissions, {reports} user TTB reports",
+        "admin.user" => "User",
+        "admin.games" => "Games",
+        "admin.achievements" => "Achievements",
+        "admin.last_upload" => "Last upload",
+        "admin.last_seen" => "Last seen",
+        "admin.no_ttb_submissions" => "No TTB submissions awaiting review.",
+        "admin.appid" => "AppID {appid}",
+        "admin.unknown_user" => "unknown",
+        "admin.submitted_by" => "Submitted by {user} ({count} reports)",
+        "admin.approve" => "Approve",
+        "admin.reject" => "Reject (delete)",
+        "admin.no_tag_submissions" => "No tag submissions awaiting review.",
+        "admin.by_on" => "by {user} on {date}",
+        "admin.tag_aliases_hint" => "Aliases replace duplicate tags with a canonical one. Parents also list a game under a broader tag. Clients pick up changes on their next start.",
+        "admin.tag" => "Tag",
+        "admin.is_alias_of" => "is an alias of",
+        "admin.is_kind_of" => "is a kind of",
+        "admin.canonical_tag" => "Canonical tag",
+        "admin.save" => "Save",
+        "admin.no_tag_aliases" => "No tag aliases yet.",
+        "admin.alias_of" => "alias of",
+        "admin.kind_of" => "kind of",
+        "admin.no_reports" => "No reported comments.",
+        "admin.comment_by_on" => "by {user} on {date} · {count} report(s)",
+        "admin.remove_comment" => "Remove comment",
+        "admin.dismiss_reports" => "Dismiss reports (keep comment)",
+        "admin.reasons" => "Reasons: {reasons}",
+
+        // Wishlist
+        "wishlist.refresh" => "Refresh",
+        "wishlist.refresh_tooltip" => "Fetch your wishlist from Steam",
+        "wishlist.fetching" => "Fetching wishlist...",
+        "wishlist.empty" => "Your wishlist is empty, or it isn't public. Steam only shares public wishlists.",
+        "wishlist.backlog_impact" => "Backlog impact: ~{main}h to beat, ~{completionist}h to 100% ({known} of {total} games have time-to-beat data)",
+        "wishlist.game" => "Game",
+        "wishlist.added" => "Added",
+        "wishlist.main" => "Main",
+        "wishlist.community_completion" => "Community completion",
+        "wishlist.tags" => "Tags",
+        "wishlist.open_store" => "Open store page",
+        "wishlist.owned" => "owned",
+        "wishlist.completion" => "{avg}% avg · {completed}/{players} at 100%",
+        "wishlist.no_players" => "no players yet",
+
+        // Play sessions
+        "sessions.title" => "Sessions: {game}",
+        "sessions.summary" => "{count} sessions, {time} tracked",
+        "sessions.reload" => "Reload",
+        "sessions.disabled" => "Session tracking is disabled in Settings → Steam",
+        "sessions.empty" => "No sessions recorded yet. Sessions are tracked while Overachiever is running.",
+        "sessions.date" => "Date",
+        "sessions.started" => "Started",
+        "sessions.duration" => "Duration",
+        "sessions.playing" => "{time} (playing)",
+
+        // Final stretch
+        "final_stretch.very_hard" => "Very hard",
+        "final_stretch.games_at_least" => "Games at least",
+        "final_stretch.complete" => "complete",
+        "final_stretch.reset_threshold" => "Use the near-complete threshold",
+        "final_stretch.summary" => "{count} achievements left across {games} games, easiest first",
+        "final_stretch.empty" => "No nearly completed games. Keep playing!",
+        "final_stretch.players" => "{percent}% of players",
+
+        // Year in review
+        "year_review.heading" => "{year} in review",
+        "year_review.empty" => "No unlocks, first plays or sessions recorded this year.",
+        "year_review.export_html" => "Export HTML",
+        "year_review.save_png" => "Save PNG",
+        "year_review.load_failed" => "Failed to load year in review: {error}",
+        "year_review.exported" => "Exported year in review to {path}",
+        "year_review.export_failed" => "Failed to export year in review: {error}",
+        "year_review.image_saved" => "Saved year in review image to {path}",
+        "year_review.image_failed" => "Failed to save year in review image: {error}",
+        "year_review.achievements_unlocked" => "achievements unlocked",
+        "year_review.games_started" => "games started",
+        "year_review.games_perfected" => "games perfected",
+        "year_review.hours_played" => "hours played (tracked sessions)",
+        "year_review.busiest_month" => "busiest month ({count} unlocks)",
+        "year_review.unlocks" => "{count} unlocks",
+        "year_review.most_unlocks" => "Most unlocks:",
+        "year_review.most_unlocks_detail" => "({count} achievements)",
+        "year_review.perfected" => "Perfected:",
+        "year_review.html_rarest" => "Rarest unlock: {achievement} in {game} ({percent}% of players)",
+        "year_review.html_most_unlocks" => "Most unlocks: {game} ({count} achievements)",
+        "year_review.html_perfected" => "Perfected: {games}",
+        "year_review.html_footer" => "Generated by Overachiever",
+        "year_review.html_lang" => "en",
+        "month.january" => "January",
+        "month.february" => "February",
+        "month.march" => "March",
+        "month.april" => "April",
+        "month.may" => "May",
+        "month.june" => "June",
+        "month.july" => "July",
+        "month.august" => "August",
+        "month.september" => "September",
+        "month.october" => "October",
+        "month.november" => "November",
+        "month.december" => "December",
+        "month.jan" => "Jan",
+        "month.feb" => "Feb",
+        "month.mar" => "Mar",
+        "month.apr" => "Apr",
+        "month.may_short" => "May",
+        "month.jun" => "Jun",
+        "month.jul" => "Jul",
+        "month.aug" => "Aug",
+        "month.sep" => "Sep",
+        "month.oct" => "Oct",
+        "month.nov" => "Nov",
+        "month.dec" => "Dec",
+
+        // Snapshot comparison
+        "snapshots.load_failed" => "Failed to load snapshots: {error}",
+        "snapshots.need_two" => "Snapshots are taken at the end of every scan. Run another update to compare two of them.",
+        "snapshots.from" => "From:",
+        "snapshots.to" => "to:",
+        "snapshots.wrong_order" => "Pick an earlier \"from\" snapshot than \"to\" snapshot.",
+        "snapshots.no_changes" => "Nothing changed between these snapshots.",
+        "snapshots.unlocks" => "{count} unlocks",
+        "snapshots.new_games" => "{count} new games",
+        "snapshots.played" => "{time} played",
+        "snapshots.avg_completion" => "avg completion {before}% → {after}% ({delta})",
+        "snapshots.changed_games" => "Changed games ({count})",
+        "snapshots.game" => "Game",
+        "snapshots.playtime" => "Playtime",
+        "snapshots.unlocks_column" => "Unlocks",
+        "snapshots.completion" => "Completion",
+        "snapshots.unlocks_header" => "Unlocks ({count})",
+        "snapshots.new_games_header" => "New games ({count})",
+        "snapshots.removed_games_header" => "Removed games ({count})",
+
+        // Bulk time reports
+        "ttb_bulk.title" => "Report times for finished games",
+        "ttb_bulk.progress" => "Game {position} of {total} - {reported} reported",
+        "ttb_bulk.suggestion" => "You 100%'d this game with {hours} h played. The suggestion below is your playtime - adjust it if the game ran idle.",
+        "ttb_bulk.local_only" => "Not linked to the cloud - times are only saved locally",
+        "ttb_bulk.submit_next" => "Submit & next",
+
+        // Side panel and achievement search
+        "history.open_stats" => "Open Stats Panel",
+        "history.open_log" => "Open Log Panel",
+        "history.close" => "Close Panel",
+        "history.stats" => "Stats",
+        "history.log" => "Log",
+        "achievement_search.clear" => "Clear search",
+        "achievement_search.hint" => "Search achievements...",
+        "achievement_search.tooltip" => "Search achievement names and descriptions across all games",
+        "achievement_search.no_matches" => "No achievements match",
+        "achievement_search.first_matches" => "First {count} matching achievements",
+        "achievement_search.matches" => "{count} matching achievements",
+
+        // Library view and profile windows
+        "library.title" => "Games Library ({count} games)",
+        "library.wishlist_tab" => "Wishlist ({count})",
+        "library.full_scan_needed" => "A full scan needs to be completed before stats tracking can begin.",
+        "library.full_scan_resumable" => "You can close the app at any time and continue the sync later — progress will be saved.",
+        "library.no_games" => "No games loaded. Click 'Update' to load your Steam library.",
+        "library.showing" => "Showing {shown} of {total} games",
+        "mini.completion" => "Completion",
+        "mini.today" => "Today",
+        "mini.streak" => "Streak",
+        "mini.streak_days" => "{days}d",
+        "mini.streak_at_risk" => "Unlock one today to keep your streak",
+        "profile_window.load_failed" => "Failed to load games: {error}",
+        "profile_window.no_data" => "No local data for Steam ID {steam_id}",
+        "profile_window.no_data_merged" => "No local data for the selected profiles",
+        "profile_window.merged_summary" => "{accounts} accounts · {games} games owned ({unique} unique) · {unlocked} achievements unlocked · {perfect} perfect games",
+        "profile_window.merged_summary_deduped" => "{accounts} accounts · {games} games owned ({unique} unique) · {unlocked} achievements unlocked · {perfect} perfect games (best copy of shared games)",
+        "profile_window.merged_title" => "Merged library ({names})",
+        "profile_window.already_open" => "That profile is already shown in the main window",
+        "profile_window.db_failed" => "Failed to open database: {error}",
+
+        // Status messages
+        "status.ready" => "Ready",
+        "hltb.search_title" => "Search HowLongToBeat",
+        "hltb.searching_for" => "Searching for: {game}",
+        "hltb.search_query" => "Search query:",
+        "hltb.english" => "EN",
+        "hltb.english_tooltip" => "Fetch English name from Steam",
+        "status.url_scheme_failed" => "Could not register {scheme}:// links: {error}",
+        "status.file_type_failed" => "Could not register .{extension} files: {error}",
+        "status.cjk_font_downloaded" => "CJK font downloaded successfully!",
+        "status.font_download_failed" => "Font download failed: {error}",
+        "admin.login_required" => "Log in with Steam to use the admin dashboard",
+        "auto_upload.last_failed" => "Last upload failed ({error}), retrying in {wait}",
+        "auto_upload.minutes" => "{count} min",
+        "auto_upload.seconds" => "{count} s",
+        "auto_upload.toast" => "Cloud: {message}",
+        "auto_upload.failed_toast" => "Cloud upload failed, retrying in {wait}",
+        "backups.restored" => "Restored database from {path}",
+        "backups.reload_failed" => "Failed to reload the database: {error}",
+        "cloud.linked" => "Linked to cloud successfully!",
+        "cloud.login_cancelled" => "Login cancelled",
+        "cloud.ratings_read_failed" => "Failed to read ratings: {error}",
+        "cloud.linked_ratings" => "Linked to cloud, {count} achievement ratings synced",
+        "cloud.linked_ratings_failed" => "Linked, but rating upload failed: {error}",
+        "cloud.up_to_date" => "Cloud data already up to date",
+        "cloud.uploaded_changed" => "Uploaded {count} changed games",
+        "cloud.uploaded" => "Data uploaded successfully!",
+        "cloud.compare_failed" => "Failed to compare with local data: {error}",
+        "cloud.deleted" => "Cloud data deleted successfully!",
+        "cloud.upload_stopped" => "upload stopped unexpectedly",
+        "cloud.operation_failed" => "Operation failed unexpectedly",
+        "cloud.import_failed" => "Failed to import data: {error}",
+        "cloud.merged" => "Merged {games} games, {achievements} achievements from the cloud!",
+        "cloud.achievements_failed" => "Failed to get achievements: {error}",
+        "community_import.need_steam_id" => "Enter your Steam ID to import from Steam Community",
+        "community_import.importing" => "Importing games from Steam Community...",
+        "community_import.failed" => "Import failed: {error}",
+        "community_import.read_failed" => "Failed to read {path}: {error}",
+        "community_import.wrong_account" => "Import failed: XML belongs to Steam ID {xml}, not {configured}",
+        "community_import.steam_id_first" => "Import failed: enter your Steam ID first",
+        "community_import.done" => "Imported {count} games from Steam Community ({new} new)",
+        "deep_link.not_in_library" => "Game {appid} from the link is not in your library",
+        "local_unlocks.one" => "Unlocked {name} in {game}",
+        "local_unlocks.many" => "Unlocked {count} achievements in {game}",
+        "overlay.listen_failed" => "Could not listen on port {port}: {error}",
+        "power.unknown_app" => "app {appid}",
+        "power.playing" => "playing {game}",
+        "power.on_battery_percent" => "On battery ({percent}%)",
+        "power.on_battery" => "On battery",
+        "power.high_load" => "High CPU load ({percent}%)",
+        "power.plugged_in" => "Plugged in",
+        "progress.starting_fetch" => "Starting fetch...",
+        "progress.starting_scrape" => "Starting achievement scrape...",
+        "progress.starting_update" => "Starting update...",
+        "progress.refreshing" => "Refreshing game {appid}...",
+        "progress.refreshing_queued" => "Refreshing game {appid}... ({queued} queued)",
+        "progress.requesting" => "Requesting...",
+        "progress.downloading" => "Downloading...",
+        "progress.processing" => "Processing...",
+        "progress.saving" => "Saving to database...",
+        "progress.fetched" => "Fetched {count} games!",
+        "progress.error" => "Error: {error}",
+        "progress.fetching_games" => "Fetching games...",
+        "progress.scrape_start" => "Fetching from Steam Api: 0 / {total} games...",
+        "progress.scrape" => "Fetching from Steam Api: {current} / {total}: {game}",
+        "progress.scrape_paused" => "Fetching from Steam Api: paused at {current} / {total} ({reason})",
+        "progress.full_scan_done" => "Full scan complete!",
+        "progress.fetching_recent" => "Fetching recently played games...",
+        "progress.updating" => "Updating {current} / {total}: {game}",
+        "progress.update_done" => "Update complete! {count} games updated.",
+        "progress.refresh_done" => "Refresh complete!",
+        "progress.refresh_error" => "Refresh error: {error}",
+        "records.streak_reminder_title" => "Your unlock streak is at risk",
+        "records.streak_reminder_body" => "No achievement unlocked today yet - unlock one before midnight to keep your {days}-day streak.",
+        "rival.fetching_games" => "Fetching rival's games...",
+        "rival.scraping" => "Scraping rival {current}/{total}: {game}",
+        "rival.scrape_failed" => "Rival scrape failed: {error}",
+        "steam_visibility.synced" => "Synced {count} hidden/private games from Steam",
+        "steam_visibility.sync_failed" => "Failed to sync Steam hidden games: {error}",
+        "steam_visibility.private_found" => "Found {count} private games on your Steam profile",
+        "steam_visibility.store_failed" => "Failed to store private games: {error}",
+        "steam_visibility.read_failed" => "Couldn't read private games from your Steam profile: {error}",
+        "tags.scan_done" => "Tags scan complete!",
+        "tags.loaded" => "Tags loaded for appid {appid}",
+        "tags.error" => "Tags error: {error}",
+        "tags.scan_paused" => "Tags Scan: paused ({reason})",
+        "tags.scan_progress" => "Tags Scan: {current} / {total} games",
+        "tags.fetching" => "Fetching tags for appid {appid}...",
+        "tags.scan_cancelled" => "Tags scan cancelled",
+        "ttb.report_saved" => "TTB report saved successfully",
+        "ttb.report_failed" => "Failed to save TTB report: {error}",
+        "ttb.none_to_report" => "No finished games left to report",
+        "ttb.bulk_done" => "Reported times for {reported} of {total} finished games",
+        "ttb.scan_start" => "TTB Scan: 0 / {total} games",
+        "ttb.scan_cancelled" => "TTB scan cancelled",
+        "ttb.scan_done_downloading" => "TTB scan complete! Downloading from server...",
+        "ttb.loaded" => "TTB loaded for {game}",
+        "ttb.error" => "TTB error: {error}",
+        "ttb.scan_paused" => "TTB: paused ({reason})",
+        "ttb.scan_progress" => "TTB: {current} / {total} - {game}",
+        "ttb.fetching" => "Fetching TTB for {game}...",
+        "ttb.downloading_all" => "Downloading all TTB times from server...",
+        "ttb.scan_done_downloaded" => "TTB scan complete! Downloaded {count} entries from server",
+        "ttb.scan_done" => "TTB scan complete!",
+
+        // Unlock timeline
+        "timeline.title" => "Unlock timeline: {game}",
+        "timeline.empty" => "No unlocks with a date to replay.",
+        "timeline.first" => "First unlock",
+        "timeline.last" => "Last unlock",
+        "timeline.pause" => "Pause",
+        "timeline.replay" => "Replay",
+        "timeline.of_total" => " of {total}",
+        "timeline.after_break" => "after a {break}",
+        "timeline.summary" => "{count} unlocks, {first} – {last}",
+        "timeline.saved_png" => "Saved unlock timeline image to {path}",
+        "timeline.save_failed" => "Failed to save unlock timeline image: {error}",
+        "timeline.break_year" => "1 year break",
+        "timeline.break_years" => "{count} year break",
+        "timeline.break_month" => "1 month break",
+        "timeline.break_months" => "{count} month break",
+        "timeline.break_week" => "1 week break",
+        "timeline.break_weeks" => "{count} week break",
+        "timeline.break_day" => "1 day break",
+        "timeline.break_days" => "{count} day break",
+
+        // Completion planner
+        "planner.title" => "Plan: {game}",
+        "planner.summary" => "{left} of {total} left, {time} remaining",
+        "planner.export" => "Export",
+        "planner.export_tooltip" => "Save as a printable HTML page",
+        "planner.copy" => "Copy",
+        "planner.copy_tooltip" => "Copy as a Markdown checklist",
+        "planner.source_ttb" => "Estimates split the remaining HowLongToBeat completionist time by difficulty",
+        "planner.source_rough" => "Rough estimates - fetch Time To Beat for estimates based on the completionist time",
+        "planner.loading_ratings" => "Loading community difficulty, ordering by rarity for now",
+        "planner.nothing_left" => "Nothing left to unlock.",
+        "planner.difficulty_tooltip" => "Community difficulty (1-5)",
+        "planner.percent_tooltip" => "Players who unlocked it",
+        "planner.exported" => "Exported completion plan to {path}",
+        "planner.export_failed" => "Failed to export completion plan: {error}",
+
+        // Library files
+        "library_file.import_title" => "Import Library",
+        "library_file.exported_at" => "Exported",
+        "library_file.app_version" => "App version",
+        "library_file.unknown_version" => "unknown",
+        "library_file.steam_id" => "Steam ID",
+        "library_file.games" => "Games",
+        "library_file.achievements" => "Achievements",
+        "library_file.unlocked_of" => "{unlocked} unlocked of {total}",
+        "library_file.history" => "History",
+        "library_file.history_counts" => "{runs} runs, {snapshots} progress snapshots, {goals} goals",
+        "library_file.other_account" => "This file belongs to a different Steam account than yours ({steam_id})",
+        "library_file.nothing_new" => "Your local data already has everything in this file.",
+        "library_file.diff_unlocks" => "The file has {file} unlocks you don't have locally; local has {local} the file lacks.",
+        "library_file.diff_games" => "{count} games will be added.",
+        "library_file.diff_playtime" => "{count} games have more playtime in the file.",
+        "library_file.merge_rules" => "Nothing local is removed: unlocks keep the newest unlock time, the highest playtime wins, history is combined. A backup is taken first.",
+        "library_file.exported" => "Exported library to {path}",
+        "library_file.merged" => "Merged {games} games, {achievements} achievements from {path}",
+        "library_file.import_failed" => "Failed to import library: {error}",
+
+        _ => return None,
+    })
+}
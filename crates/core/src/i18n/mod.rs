@@ -0,0 +1,76 @@
+//! UI translations - a small key-based string lookup
+//!
+//! Strings are looked up by key with `tr("toolbar.update")`. A key missing from the
+//! current language falls back to English; a key missing from English shows the key
+//! itself so it's easy to spot. Placeholders are written `{name}` and filled in with
+//! `tr_args`. The language is global and can be switched at any time - the next frame
+//! is drawn in the new language.
+
+mod de;
+mod en;
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+/// UI language
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Language {
+    #[default]
+    English,
+    German,
+}
+
+impl Language {
+    pub fn all() -> &'static [Language] {
+        &[Language::English, Language::German]
+    }
+
+    /// Name of the language in the language itself (for the picker)
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::German => "Deutsch",
+        }
+    }
+
+    fn lookup(&self, key: &str) -> Option<&'static str> {
+        match self {
+            Language::English => en::lookup(key),
+            Language::German => de::lookup(key),
+        }
+    }
+}
+
+static CURRENT_LANGUAGE: AtomicU8 = AtomicU8::new(0);
+
+/// Switch the UI language
+pub fn set_language(language: Language) {
+    CURRENT_LANGUAGE.store(language as u8, Ordering::Relaxed);
+}
+
+/// The current UI language
+pub fn language() -> Language {
+    Language::all()
+        .get(CURRENT_LANGUAGE.load(Ordering::Relaxed) as usize)
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Translated string for `key`
+pub fn tr(key: &'static str) -> &'static str {
+    language()
+        .lookup(key)
+        .or_else(|| Language::English.lookup(key))
+        .unwrap_or(key)
+}
+
+/// Translated string for `key` with `{name}` placeholders filled in
+pub fn tr_args(key: &'static str, args: &[(&str, &dyn std::fmt::Display)]) -> String {
+    let mut text = tr(key).to_string();
+    for (name, value) in args {
+        text = text.replace(&format!("{{{}}}", name), &value.to_string());
+    }
+    text
+}
@@ -4,12 +4,40 @@
 //! - Data models shared between desktop, WASM, and backend
 //! - WebSocket message types for client-server communication
 //! - Error types
+//! - Personal records and milestones
+//! - Completion forecasts from achievement history
+//! - Per-tag library statistics
+//! - Library-wide chart data (completion distribution, hours by tag, per-month counts)
+//! - Tag aliases and hierarchy
+//! - Include/exclude tag filtering
+//! - Backlog goals and their schedule
+//! - Year in review summaries
+//! - Completion plans ordered by achievement difficulty
+//! - Scan snapshot comparisons
+//! - Delta cloud sync manifests and checksums
+//! - Time zone aware timestamp display settings
+//! - UI translations
 //! - Shared UI components (with `ui` feature)
 
 pub mod constants;
 pub mod models;
 pub mod messages;
 pub mod error;
+pub mod records;
+pub mod forecast;
+pub mod tag_stats;
+pub mod library_charts;
+pub mod tag_mappings;
+pub mod tag_filter;
+pub mod game_groups;
+pub mod goals;
+pub mod year_review;
+pub mod completion_plan;
+pub mod unlock_timeline;
+pub mod snapshot_diff;
+pub mod sync_delta;
+pub mod time_display;
+pub mod i18n;
 
 #[cfg(feature = "ui")]
 pub mod ui;
@@ -18,6 +46,21 @@ pub use constants::*;
 pub use models::*;
 pub use messages::*;
 pub use error::*;
+pub use records::*;
+pub use forecast::*;
+pub use tag_stats::*;
+pub use library_charts::*;
+pub use tag_mappings::*;
+pub use tag_filter::*;
+pub use game_groups::*;
+pub use goals::*;
+pub use year_review::*;
+pub use completion_plan::*;
+pub use unlock_timeline::*;
+pub use snapshot_diff::*;
+pub use sync_delta::*;
+pub use time_display::*;
+pub use i18n::*;
 
 #[cfg(feature = "ui")]
 pub use ui::*;
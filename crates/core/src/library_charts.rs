@@ -0,0 +1,119 @@
+//! Library-wide chart data: completion distribution, hours by tag and per-month counts
+
+use chrono::{DateTime, Datelike, Utc};
+
+use crate::{Game, TimeDisplaySettings};
+
+/// Completion histogram buckets: 0-9%, 10-19%, ..., 90-99%, and 100% on its own
+pub const COMPLETION_BUCKETS: usize = 11;
+
+/// Number of games with achievements in each completion bucket
+pub fn completion_distribution(games: &[Game]) -> [u32; COMPLETION_BUCKETS] {
+    let mut buckets = [0; COMPLETION_BUCKETS];
+    for game in games.iter().filter(|g| !g.abandoned) {
+        if let (Some(unlocked), Some(total @ 1..)) = (game.achievements_unlocked, game.achievements_total) {
+            // Integer math so 99.9% doesn't round into the 100% bucket
+            let bucket = (unlocked.clamp(0, total) * 10 / total) as usize;
+            buckets[bucket] += 1;
+        }
+    }
+    buckets
+}
+
+/// Label of a completion bucket ("0-9%", ..., "100%")
+pub fn completion_bucket_label(bucket: usize) -> String {
+    if bucket + 1 >= COMPLETION_BUCKETS {
+        "100%".to_string()
+    } else {
+        format!("{}-{}%", bucket * 10, bucket * 10 + 9)
+    }
+}
+
+/// Playtime of the games whose most-voted tag is `tag` (None collects the rest)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagHours {
+    pub tag: Option<String>,
+    pub hours: f32,
+}
+
+/// Playtime grouped by each game's most-voted tag, largest first. Tags past `max_slices`
+/// and games without cached tags are folded into one trailing `None` entry.
+pub fn hours_by_top_tag<'a>(
+    games: &[Game],
+    game_tags: impl Fn(u64) -> Option<&'a [(String, u32)]>,
+    max_slices: usize,
+) -> Vec<TagHours> {
+    let mut by_tag: Vec<TagHours> = Vec::new();
+    let mut other = 0.0;
+    for game in games.iter().filter(|g| g.playtime_forever > 0) {
+        let hours = game.playtime_forever as f32 / 60.0;
+        let top_tag = game_tags(game.appid).and_then(|tags| tags.iter().max_by_key(|(_, votes)| *votes));
+        match top_tag {
+            Some((tag, _)) => match by_tag.iter_mut().find(|t| t.tag.as_deref() == Some(tag.as_str())) {
+                Some(entry) => entry.hours += hours,
+                None => by_tag.push(TagHours { tag: Some(tag.clone()), hours }),
+            },
+            None => other += hours,
+        }
+    }
+
+    by_tag.sort_by(|a, b| b.hours.total_cmp(&a.hours));
+    if by_tag.len() > max_slices {
+        other += by_tag.drain(max_slices..).map(|t| t.hours).sum::<f32>();
+    }
+    if other > 0.0 {
+        by_tag.push(TagHours { tag: None, hours: other });
+    }
+    by_tag
+}
+
+/// Number of events in one calendar month
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonthCount {
+    pub year: i32,
+    /// 1-12
+    pub month: u32,
+    pub count: u32,
+}
+
+impl MonthCount {
+    /// Months since year 0, so consecutive months are one apart on a chart axis
+    pub fn index(&self) -> i32 {
+        self.year * 12 + self.month as i32 - 1
+    }
+
+    /// "Mar 2024"
+    pub fn label(&self) -> String {
+        month_label(self.index())
+    }
+}
+
+/// Label for a month index (see `MonthCount::index`)
+pub fn month_label(index: i32) -> String {
+    let year = index.div_euclid(12);
+    let month = index.rem_euclid(12) as u32 + 1;
+    chrono::NaiveDate::from_ymd_opt(year, month, 1)
+        .map(|d| d.format("%b %Y").to_string())
+        .unwrap_or_default()
+}
+
+/// Count timestamps per calendar month in the display time zone, oldest first.
+/// Months without events between the first and last one are included with a count of 0.
+pub fn count_per_month(times: impl IntoIterator<Item = DateTime<Utc>>, time: &TimeDisplaySettings) -> Vec<MonthCount> {
+    let mut counts: std::collections::BTreeMap<i32, u32> = std::collections::BTreeMap::new();
+    for at in times {
+        let date = time.display_date(at);
+        *counts.entry(date.year() * 12 + date.month0() as i32).or_default() += 1;
+    }
+
+    let (Some(&first), Some(&last)) = (counts.keys().next(), counts.keys().next_back()) else {
+        return Vec::new();
+    };
+    (first..=last)
+        .map(|index| MonthCount {
+            year: index.div_euclid(12),
+            month: index.rem_euclid(12) as u32 + 1,
+            count: counts.get(&index).copied().unwrap_or(0),
+        })
+        .collect()
+}
@@ -1,18 +1,54 @@
 //! WebSocket message types for client-server communication
+//!
+//! Every message is a JSON text frame tagged with `type`. The protocol:
+//!
+//! - **Handshake**: clients send `Hello` with their `PROTOCOL_VERSION` first and get `Welcome`
+//!   back. Versions below `MIN_PROTOCOL_VERSION` get `Closing { UnsupportedVersion }`.
+//!   Clients that skip `Hello` are treated as version 1 and are never timed out.
+//! - **Heartbeat**: after the handshake, clients send `Ping` (answered with `Pong`) whenever
+//!   they have been quiet for `heartbeat_secs`. The server closes connections that send
+//!   nothing for `HEARTBEAT_TIMEOUT_SECS`, and clients treat a connection that received nothing
+//!   for as long as stale.
+//! - **Close**: before closing a connection itself the server sends `Closing` with the reason,
+//!   then a close frame with `CloseReason::close_code`.
+//! - **Reconnect**: after reconnecting and authenticating, clients that still hold the games
+//!   list send `Resync` with a `game_row_checksum` per game instead of `FetchGames`, and get
+//!   only the changed rows back in `GamesDelta`.
+
+use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 use crate::models::*;
 
+/// Version of the WebSocket protocol spoken by this build
+pub const PROTOCOL_VERSION: u32 = 2;
+
+/// Oldest client version the server still accepts
+pub const MIN_PROTOCOL_VERSION: u32 = 1;
+
+/// Quiet time after which clients send `Ping`
+pub const HEARTBEAT_INTERVAL_SECS: u64 = 30;
+
+/// Silence after which a connection counts as stale, on both sides
+pub const HEARTBEAT_TIMEOUT_SECS: u64 = 90;
+
 /// Messages sent from client to server
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum ClientMessage {
+    /// First message of a connection: the client's `PROTOCOL_VERSION`, answered with `Welcome`
+    Hello { version: u32 },
+    
     /// Authenticate with JWT token
     Authenticate { token: String },
     
     /// Request user's games list
     FetchGames,
     
+    /// Request the games that changed since the client's copy (appid -> `game_row_checksum`),
+    /// answered with `GamesDelta`
+    Resync { games: HashMap<u64, u64> },
+    
     /// Request achievements for a specific game
     FetchAchievements { appid: u64 },
     
@@ -92,6 +128,13 @@ pub enum ClientMessage {
         hidden: bool,
     },
     
+    /// Share what we're playing (None = nothing right now); answered with `Presence`.
+    /// Sent again every minute or so - presence expires without updates.
+    UpdatePresence { game: Option<PresenceGame> },
+    
+    /// Stop sharing presence
+    ClearPresence,
+    
     /// Ping to keep connection alive
     Ping,
 }
@@ -100,6 +143,17 @@ pub enum ClientMessage {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum ServerMessage {
+    /// Handshake accepted: the server's protocol version and the heartbeat interval to use
+    Welcome {
+        version: u32,
+        heartbeat_secs: u64,
+    },
+    
+    /// The server is about to close the connection
+    Closing {
+        reason: CloseReason,
+    },
+    
     /// Authentication successful
     Authenticated { 
         user: UserProfile 
@@ -115,6 +169,12 @@ pub enum ServerMessage {
         games: Vec<Game> 
     },
     
+    /// Answer to `Resync`: new or changed games, and appids the client should drop
+    GamesDelta {
+        changed: Vec<Game>,
+        removed: Vec<u64>,
+    },
+    
     /// Achievements for a game
     Achievements { 
         appid: u64, 
@@ -206,10 +266,49 @@ pub enum ServerMessage {
         message: String 
     },
     
+    /// Other users currently sharing what they're playing
+    Presence { users: Vec<UserPresence> },
+    
     /// Pong response
     Pong,
 }
 
+/// Why the server closed a connection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "reason")]
+pub enum CloseReason {
+    /// Nothing received for `HEARTBEAT_TIMEOUT_SECS`
+    IdleTimeout,
+    /// The client's protocol version is too old
+    UnsupportedVersion { min_version: u32 },
+}
+
+impl CloseReason {
+    /// WebSocket close code sent with the close frame (4000-4999 is reserved for applications)
+    pub fn close_code(&self) -> u16 {
+        match self {
+            CloseReason::IdleTimeout => 4000,
+            CloseReason::UnsupportedVersion { .. } => 4001,
+        }
+    }
+
+    /// Whether reconnecting can help (an outdated client has to be updated first)
+    pub fn should_reconnect(&self) -> bool {
+        matches!(self, CloseReason::IdleTimeout)
+    }
+}
+
+impl std::fmt::Display for CloseReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CloseReason::IdleTimeout => write!(f, "connection idle for too long"),
+            CloseReason::UnsupportedVersion { min_version } => {
+                write!(f, "client too old, protocol version {} or newer required", min_version)
+            }
+        }
+    }
+}
+
 /// Sync state for progress reporting
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "state")]
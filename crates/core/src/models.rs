@@ -1,6 +1,6 @@
 //! Shared data models used across all platforms
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
 /// Raw game data from Steam API
@@ -17,6 +17,32 @@ pub struct SteamGame {
     pub img_icon_url: Option<String>,
 }
 
+/// Playtime per platform in minutes, as reported by GetOwnedGames
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlatformPlaytime {
+    pub windows: u32,
+    pub mac: u32,
+    pub linux: u32,
+    pub deck: u32,
+}
+
+impl PlatformPlaytime {
+    /// Platforms with playtime as (label, minutes), most played first
+    pub fn breakdown(&self) -> Vec<(&'static str, u32)> {
+        let mut platforms: Vec<(&'static str, u32)> = [
+            ("Windows", self.windows),
+            ("macOS", self.mac),
+            ("Linux", self.linux),
+            ("Steam Deck", self.deck),
+        ]
+        .into_iter()
+        .filter(|(_, minutes)| *minutes > 0)
+        .collect();
+        platforms.sort_by_key(|(_, minutes)| std::cmp::Reverse(*minutes));
+        platforms
+    }
+}
+
 /// Game with tracked data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Game {
@@ -49,6 +75,27 @@ pub struct Game {
     pub steam_hidden: bool, // Hidden in Steam library
     #[serde(default)]
     pub steam_private: bool, // Marked as private in Steam
+
+    /// Store app type ("game", "music", "demo", "application", ...), None until fetched
+    #[serde(default)]
+    pub app_type: Option<String>,
+
+    /// Given up on by the user: still listed, but left out of completion averages and time-to-beat totals
+    #[serde(default)]
+    pub abandoned: bool,
+
+    /// Playtime per platform, None until fetched from the Steam API
+    #[serde(default)]
+    pub platform_playtime: Option<PlatformPlaytime>,
+
+    /// In the account's library. False for games only seen as recently played (free weekends,
+    /// Family Sharing); those are listed separately and left out of statistics by default.
+    #[serde(default = "default_owned")]
+    pub owned: bool,
+}
+
+fn default_owned() -> bool {
+    true
 }
 
 impl Game {
@@ -66,8 +113,36 @@ impl Game {
             _ => None,
         }
     }
+
+    /// Minutes played on a Steam Deck (0 when unknown)
+    pub fn deck_playtime(&self) -> u32 {
+        self.platform_playtime.map(|p| p.deck).unwrap_or(0)
+    }
+
+    /// Whether this is a game rather than a soundtrack, tool, demo etc. (unknown types count as games)
+    pub fn is_game_app(&self) -> bool {
+        self.app_type.as_deref().is_none_or(|t| t == "game")
+    }
+
+    /// Candidate for the stalled games review: not played for a year and less than half complete
+    pub fn is_stalled(&self, now: DateTime<Utc>) -> bool {
+        if self.abandoned {
+            return false;
+        }
+        let Some(last_played) = self.rtime_last_played.filter(|&t| t > 0) else {
+            return false;
+        };
+        let idle_days = (now.timestamp() - last_played as i64) / 86400;
+        idle_days >= STALLED_AFTER_DAYS && self.completion_percent().is_some_and(|pct| pct < STALLED_MAX_PERCENT)
+    }
 }
 
+/// Days without playing after which an unfinished game counts as stalled
+pub const STALLED_AFTER_DAYS: i64 = 365;
+
+/// Completion (percent) below which an idle game counts as stalled
+pub const STALLED_MAX_PERCENT: f32 = 50.0;
+
 /// Achievement progress from Steam API
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Achievement {
@@ -86,6 +161,9 @@ pub struct AchievementSchema {
     pub description: Option<String>,
     pub icon: String,
     pub icongray: String,
+    /// 1 when Steam hides the achievement until it's unlocked
+    #[serde(default)]
+    pub hidden: u8,
 }
 
 /// Achievement stored in database with display info
@@ -102,6 +180,12 @@ pub struct GameAchievement {
     /// Whether this achievement marks game completion for the user
     #[serde(default)]
     pub is_game_finishing: bool,
+    /// Percentage of players who unlocked it (None = not fetched)
+    #[serde(default)]
+    pub global_percent: Option<f32>,
+    /// Hidden in the Steam schema - the description is a spoiler until it's unlocked
+    #[serde(default)]
+    pub hidden: bool,
 }
 
 /// Run history entry
@@ -149,6 +233,95 @@ pub struct FirstPlay {
     pub game_icon_url: Option<String>,
 }
 
+/// A play session detected by polling the user's "currently playing" status
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameSession {
+    pub appid: u64,
+    pub started_at: DateTime<Utc>,
+    /// Last time the game was seen running (end of session once closed)
+    pub last_seen_at: DateTime<Utc>,
+    /// Whether the session has been closed (game no longer running)
+    pub ended: bool,
+}
+
+impl GameSession {
+    /// Duration of the session in minutes (based on the last time it was seen)
+    pub fn duration_minutes(&self) -> i64 {
+        (self.last_seen_at - self.started_at).num_minutes().max(0)
+    }
+}
+
+/// A game on the user's Steam wishlist
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WishlistItem {
+    pub appid: u64,
+    pub name: String,
+    /// User-set wishlist order (0 = top)
+    pub priority: u32,
+    pub date_added: Option<DateTime<Utc>>,
+}
+
+/// Community achievement completion for a game across all synced users
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameCompletionStats {
+    pub appid: u64,
+    /// Users who have played the game (and it has achievements)
+    pub players: i64,
+    /// Average unlocked percentage among those players
+    pub avg_completion: f32,
+    /// Players who unlocked every achievement
+    pub completed: i64,
+}
+
+/// Anonymous per-game statistics across all Overachiever users who synced the game
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommunityGameStats {
+    pub appid: u64,
+    /// Users who have played the game
+    pub players: i64,
+    /// Average unlocked percentage among players (None if the game has no achievements)
+    pub avg_completion: Option<f32>,
+    /// Share of players who unlocked every achievement, 0-100
+    pub perfect_percent: Option<f32>,
+    /// Median playtime among players
+    pub median_playtime_minutes: i32,
+}
+
+/// Steam Cloud save state of an installed game, read from Steam's local remotecache.vdf
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloudSaveStatus {
+    pub appid: u64,
+    /// Number of save files Steam tracks for the game
+    pub file_count: u32,
+    /// Files changed locally after their last upload
+    pub unsynced_files: u32,
+    /// Most recent upload/download of any save file
+    pub last_synced: Option<DateTime<Utc>>,
+}
+
+impl CloudSaveStatus {
+    /// Days without a sync after which resuming elsewhere deserves a warning
+    pub const STALE_SYNC_DAYS: i64 = 7;
+
+    /// Warning to show before playing, if any
+    pub fn warning(&self, now: DateTime<Utc>) -> Option<String> {
+        if self.unsynced_files > 0 {
+            return Some(format!(
+                "{} of {} save files changed locally but were not uploaded to Steam Cloud",
+                self.unsynced_files, self.file_count
+            ));
+        }
+        match self.last_synced {
+            Some(synced) if (now - synced).num_days() >= Self::STALE_SYNC_DAYS => Some(format!(
+                "Cloud saves last synced {} days ago - if you played on another machine since, let Steam sync before resuming",
+                (now - synced).num_days()
+            )),
+            None if self.file_count > 0 => Some("Cloud saves have never synced on this machine".to_string()),
+            _ => None,
+        }
+    }
+}
+
 /// A log entry that can be either an achievement or first play
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -168,6 +341,15 @@ pub enum LogEntry {
         timestamp: DateTime<Utc>,
         game_icon_url: Option<String>,
     },
+    /// Developer added/removed achievements (detected on rescrape)
+    SchemaChange {
+        appid: u64,
+        game_name: String,
+        added: Vec<String>,
+        removed: Vec<String>,
+        timestamp: DateTime<Utc>,
+        game_icon_url: Option<String>,
+    },
 }
 
 impl LogEntry {
@@ -175,10 +357,34 @@ impl LogEntry {
         match self {
             LogEntry::Achievement { timestamp, .. } => *timestamp,
             LogEntry::FirstPlay { timestamp, .. } => *timestamp,
+            LogEntry::SchemaChange { timestamp, .. } => *timestamp,
         }
     }
 }
 
+/// Narrows the activity log to one game and/or a period (whole days in the display time zone)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LogFilter {
+    pub appid: Option<u64>,
+    /// First day shown
+    pub from: Option<NaiveDate>,
+    /// Last day shown
+    pub to: Option<NaiveDate>,
+}
+
+impl LogFilter {
+    pub fn is_active(&self) -> bool {
+        self.appid.is_some() || self.from.is_some() || self.to.is_some()
+    }
+
+    /// The period as UTC bounds: entries at or after the first and before the second
+    pub fn utc_range(&self, time: &crate::TimeDisplaySettings) -> (Option<DateTime<Utc>>, Option<DateTime<Utc>>) {
+        let since = self.from.map(|day| time.start_of_day(day));
+        let until = self.to.and_then(|day| day.succ_opt()).map(|day| time.start_of_day(day));
+        (since, until)
+    }
+}
+
 // ============================================================================
 // Community features (for Hybrid and Remote modes)
 // ============================================================================
@@ -218,17 +424,6 @@ pub struct AchievementRating {
     pub created_at: DateTime<Utc>,
 }
 
-/// Achievement comment that can tag multiple achievements
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AchievementComment {
-    pub id: Option<i64>,
-    pub steam_id: String,
-    /// List of (appid, apiname) tuples for tagged achievements
-    pub achievements: Vec<(u64, String)>,
-    pub comment: String,
-    pub created_at: DateTime<Utc>,
-}
-
 /// Aggregated community rating for a game
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommunityGameRating {
@@ -238,6 +433,67 @@ pub struct CommunityGameRating {
     pub ratings: Vec<GameRating>,
 }
 
+/// Community average difficulty of a single achievement
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AchievementDifficulty {
+    pub apiname: String,
+    pub avg_rating: f32,
+    pub rating_count: i32,
+    /// Votes per rating, `votes[0]` for 1 (very easy) to `votes[4]` for 5 (extreme).
+    /// All zero when the server (or an old cache) didn't send the distribution.
+    #[serde(default)]
+    pub votes: [i32; 5],
+}
+
+/// Global unlock percentage of an achievement on one day (server rarity snapshot)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RarityPoint {
+    pub date: NaiveDate,
+    pub percent: f32,
+}
+
+/// Rarity snapshots of one achievement, oldest first
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AchievementRarityHistory {
+    pub apiname: String,
+    pub points: Vec<RarityPoint>,
+}
+
+impl AchievementRarityHistory {
+    /// Most recent global unlock percentage
+    pub fn latest(&self) -> Option<f32> {
+        self.points.last().map(|p| p.percent)
+    }
+
+    /// Change from the first to the latest snapshot in percentage points
+    pub fn drift(&self) -> Option<f32> {
+        match (self.points.first(), self.points.last()) {
+            (Some(first), Some(last)) if self.points.len() > 1 => Some(last.percent - first.percent),
+            _ => None,
+        }
+    }
+}
+
+/// Community difficulty of every rated achievement in one game (batch ratings response)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameAchievementRatings {
+    pub appid: u64,
+    pub achievements: Vec<AchievementDifficulty>,
+}
+
+/// Community comment on an achievement (a comment tagging several achievements is stored once per achievement)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AchievementComment {
+    pub id: i64,
+    pub steam_id: String,
+    /// Author's Steam display name (None if unknown)
+    pub display_name: Option<String>,
+    pub appid: u64,
+    pub apiname: String,
+    pub comment: String,
+    pub created_at: DateTime<Utc>,
+}
+
 /// User profile from Steam
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UserProfile {
@@ -246,6 +502,59 @@ pub struct UserProfile {
     pub avatar_url: Option<String>,
     /// Short ID for shareable profile URLs (YouTube-style)
     pub short_id: Option<String>,
+    /// Public profile customization (showcase achievement, banner, accent color)
+    #[serde(default)]
+    pub customization: ProfileCustomization,
+}
+
+/// One page of the public user directory (`GET /api/users`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UserPage {
+    pub users: Vec<UserProfile>,
+    /// Pass as `cursor` to get the next page (None on the last page)
+    pub next_cursor: Option<String>,
+}
+
+/// User-chosen look of the public profile page
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProfileCustomization {
+    /// Showcase achievement (must be unlocked by the user)
+    pub showcase_appid: Option<u64>,
+    pub showcase_apiname: Option<String>,
+    /// Favorite game shown as the profile banner
+    pub banner_appid: Option<u64>,
+    /// Accent color as "#rrggbb"
+    pub accent_color: Option<String>,
+    /// Showcase achievement display name and icon (filled in by the server)
+    #[serde(default)]
+    pub showcase_name: Option<String>,
+    #[serde(default)]
+    pub showcase_icon: Option<String>,
+    /// Listed in the public user directory (opt-in)
+    #[serde(default)]
+    pub listed: bool,
+}
+
+impl ProfileCustomization {
+    /// Whether anything changes the look of the profile page (the directory listing doesn't)
+    pub fn is_customized(&self) -> bool {
+        self.showcase_appid.is_some() || self.banner_appid.is_some() || self.accent_color.is_some()
+    }
+
+    /// Parse the accent color into RGB
+    pub fn accent_rgb(&self) -> Option<[u8; 3]> {
+        parse_hex_color(self.accent_color.as_deref()?)
+    }
+}
+
+/// Parse a "#rrggbb" color string
+pub fn parse_hex_color(color: &str) -> Option<[u8; 3]> {
+    let hex = color.strip_prefix('#')?;
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
 }
 
 /// Sync result after updating from Steam
@@ -286,6 +595,9 @@ pub struct CloudSyncData {
     pub achievements: Vec<SyncAchievement>,
     pub run_history: Vec<RunHistory>,
     pub achievement_history: Vec<AchievementHistory>,
+    /// Backlog goals (missing in uploads from older clients)
+    #[serde(default)]
+    pub goals: Vec<crate::Goal>,
     pub exported_at: DateTime<Utc>,
 }
 
@@ -343,3 +655,179 @@ pub struct GameTag {
     pub tag_name: String,
     pub vote_count: u32,
 }
+
+// ============================================================================
+// Admin Dashboard
+// ============================================================================
+
+/// Server-wide statistics for the admin dashboard
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdminStats {
+    pub total_users: i64,
+    /// Users seen in the last 7 days
+    pub active_users_7d: i64,
+    /// Users seen in the last 30 days
+    pub active_users_30d: i64,
+    pub total_games: i64,
+    pub total_achievements: i64,
+    /// Total size of the last cloud upload of every user (bytes)
+    pub total_upload_bytes: i64,
+    /// Largest single cloud upload (bytes)
+    pub largest_upload_bytes: i64,
+    /// On-disk size of the whole database (bytes)
+    pub database_bytes: i64,
+    pub pending_ttb: i64,
+    pub pending_tags: i64,
+    /// User TTB reports held back by validation or the outlier check
+    #[serde(default)]
+    pub pending_ttb_reports: i64,
+}
+
+/// A user as listed in the admin dashboard
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdminUser {
+    pub steam_id: String,
+    pub display_name: String,
+    pub short_id: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub last_seen: Option<DateTime<Utc>>,
+    pub game_count: i64,
+    pub achievement_count: i64,
+    /// Size of the user's last cloud upload (bytes)
+    pub last_upload_bytes: Option<i64>,
+    pub last_upload_at: Option<DateTime<Utc>>,
+}
+
+/// TTB submission awaiting admin review
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingTtbSubmission {
+    pub appid: u64,
+    pub game_name: String,
+    pub main: Option<f32>,
+    pub main_extra: Option<f32>,
+    pub completionist: Option<f32>,
+    pub reported_count: i32,
+    /// Steam ID of the last submitter (None for submissions before moderation existed)
+    pub submitted_by: Option<String>,
+    pub submitted_at: DateTime<Utc>,
+}
+
+/// User TTB report held back for admin review (outlier or implausible)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingTtbReport {
+    pub id: i64,
+    pub steam_id: String,
+    pub appid: u64,
+    /// Game name from the HLTB data, if the game has any
+    pub game_name: Option<String>,
+    pub main_seconds: Option<i32>,
+    pub extra_seconds: Option<i32>,
+    pub completionist_seconds: Option<i32>,
+    /// Why the report was held back
+    pub reason: Option<String>,
+    pub reported_at: DateTime<Utc>,
+}
+
+/// Tag submission (all tags of one game) awaiting admin review
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingTagSubmission {
+    pub appid: u64,
+    /// Tags with vote counts, most votes first
+    pub tags: Vec<(String, u32)>,
+    pub submitted_by: Option<String>,
+    pub submitted_at: DateTime<Utc>,
+}
+
+/// Reported comment awaiting admin review
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportedComment {
+    pub comment: AchievementComment,
+    /// Number of open reports
+    pub report_count: i64,
+    /// Reasons given by reporters (empty reasons left out)
+    pub reasons: Vec<String>,
+    pub first_reported_at: DateTime<Utc>,
+}
+
+/// What a third-party API key may read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiKeyScope {
+    /// Full cloud sync data (games, achievements, history)
+    SyncRead,
+    /// Summary numbers (game and achievement counts, average completion)
+    StatsRead,
+}
+
+impl ApiKeyScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiKeyScope::SyncRead => "sync_read",
+            ApiKeyScope::StatsRead => "stats_read",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "sync_read" => Some(ApiKeyScope::SyncRead),
+            "stats_read" => Some(ApiKeyScope::StatsRead),
+            _ => None,
+        }
+    }
+}
+
+/// A user's API key as listed (the secret itself is only shown once, at creation)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKeyInfo {
+    pub id: i64,
+    pub name: String,
+    /// First characters of the key, to tell keys apart
+    pub prefix: String,
+    pub scopes: Vec<ApiKeyScope>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+/// Response to creating an API key
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatedApiKey {
+    /// The full key - store it now, it can't be retrieved later
+    pub key: String,
+    pub info: ApiKeyInfo,
+}
+
+// ============================================================================
+// Email Digest
+// ============================================================================
+
+/// A user's weekly email digest subscription
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DigestSubscription {
+    pub email: String,
+    pub enabled: bool,
+    pub next_send_at: DateTime<Utc>,
+    pub last_sent_at: Option<DateTime<Utc>>,
+}
+
+// ============================================================================
+// Presence
+// ============================================================================
+
+/// The game a user is playing right now, as reported by their client
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PresenceGame {
+    pub appid: u64,
+    pub name: String,
+    pub achievements_unlocked: Option<i32>,
+    pub achievements_total: Option<i32>,
+}
+
+/// A user who opted into sharing what they're playing
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserPresence {
+    pub steam_id: String,
+    pub display_name: String,
+    pub avatar_url: Option<String>,
+    pub game: PresenceGame,
+    pub updated_at: DateTime<Utc>,
+}
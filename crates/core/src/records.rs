@@ -0,0 +1,278 @@
+//! Personal records and milestones computed from unlocked achievements
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{tr_args, Game, TimeDisplaySettings};
+
+/// Achievement counts that count as milestones
+pub const ACHIEVEMENT_MILESTONES: &[u32] = &[1, 100, 500, 1000, 2500, 5000, 10000, 25000];
+
+/// Perfect game counts that count as milestones
+pub const PERFECT_GAME_MILESTONES: &[u32] = &[1, 10, 25, 50, 100, 250, 500];
+
+/// Unlock streak lengths (days) that count as milestones
+pub const STREAK_MILESTONES: &[u32] = &[7, 30, 100, 365];
+
+/// A single unlocked achievement used for record calculations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnlockRecord {
+    pub appid: u64,
+    pub game_name: String,
+    pub apiname: String,
+    pub achievement_name: String,
+    pub unlocktime: DateTime<Utc>,
+    /// Global unlock percentage (None if unknown)
+    pub global_percent: Option<f32>,
+}
+
+/// A run of consecutive days with at least one unlock
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnlockStreak {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub days: u32,
+}
+
+/// Where the most recent unlock streak stands on a given day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StreakStatus {
+    /// Days in the streak that is still alive (0 = no running streak)
+    pub current: u32,
+    /// Longest streak ever
+    pub best: u32,
+    /// Whether there's already an unlock today
+    pub unlocked_today: bool,
+}
+
+impl StreakStatus {
+    /// The streak is alive but breaks at midnight without an unlock today
+    pub fn at_risk(&self) -> bool {
+        self.current > 0 && !self.unlocked_today
+    }
+}
+
+/// Most unlocks in a single day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BusiestDay {
+    pub date: NaiveDate,
+    pub unlocks: u32,
+}
+
+/// Started-but-unfinished game with the earliest first unlock
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnfinishedGame {
+    pub appid: u64,
+    pub name: String,
+    pub started_at: DateTime<Utc>,
+    pub completion_percent: f32,
+}
+
+/// Kind of milestone reached
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MilestoneKind {
+    /// N-th achievement unlocked
+    Achievements(u32),
+    /// N-th game completed to 100%
+    PerfectGames(u32),
+    /// Unlock streak of N days
+    Streak(u32),
+}
+
+impl MilestoneKind {
+    /// Human readable label, e.g. "1000th achievement"
+    pub fn label(&self) -> String {
+        match self {
+            MilestoneKind::Achievements(n) => tr_args("milestone.achievements", &[("n", n), ("nth", &ordinal(*n))]),
+            MilestoneKind::PerfectGames(n) => tr_args("milestone.perfect_games", &[("n", n), ("nth", &ordinal(*n))]),
+            MilestoneKind::Streak(n) => tr_args("milestone.streak", &[("n", n)]),
+        }
+    }
+}
+
+/// A milestone with the date it was reached
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Milestone {
+    pub kind: MilestoneKind,
+    pub reached_at: DateTime<Utc>,
+    /// Game/achievement that reached the milestone
+    pub detail: String,
+}
+
+/// Personal records shown in the stats panel
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AchievementRecords {
+    pub first_achievement: Option<UnlockRecord>,
+    pub busiest_day: Option<BusiestDay>,
+    pub longest_streak: Option<UnlockStreak>,
+    /// Most recent streak (may still be running)
+    #[serde(default)]
+    pub latest_streak: Option<UnlockStreak>,
+    pub rarest_unlock: Option<UnlockRecord>,
+    pub oldest_unfinished: Option<UnfinishedGame>,
+    /// Reached milestones, oldest first
+    pub milestones: Vec<Milestone>,
+    /// When each 100% game was perfected (its last unlock), oldest first
+    #[serde(default)]
+    pub perfect_game_dates: Vec<DateTime<Utc>>,
+}
+
+impl AchievementRecords {
+    /// Current and best streak as of `today` (a calendar day in the display time zone)
+    pub fn streak_status(&self, today: NaiveDate) -> StreakStatus {
+        let best = self.longest_streak.map(|s| s.days).unwrap_or(0);
+        let Some(latest) = self.latest_streak else {
+            return StreakStatus { best, ..Default::default() };
+        };
+        let unlocked_today = latest.end == today;
+        // A streak ending yesterday is still alive until today is over
+        let alive = unlocked_today || latest.end.succ_opt() == Some(today);
+        StreakStatus {
+            current: if alive { latest.days } else { 0 },
+            best,
+            unlocked_today,
+        }
+    }
+}
+
+/// Format a number as an English ordinal (1st, 2nd, 3rd, 4th, 11th, ...)
+pub fn ordinal(n: u32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+/// Find all runs of consecutive unlock days (sorted by start date)
+/// Days are calendar days in the display time zone
+pub fn unlock_streaks(unlocks: &[UnlockRecord], time: &TimeDisplaySettings) -> Vec<UnlockStreak> {
+    let mut days: Vec<NaiveDate> = unlocks.iter().map(|u| time.display_date(u.unlocktime)).collect();
+    days.sort();
+    days.dedup();
+
+    let mut streaks = Vec::new();
+    let mut iter = days.into_iter();
+    let Some(first) = iter.next() else {
+        return streaks;
+    };
+    let mut current = UnlockStreak { start: first, end: first, days: 1 };
+    for day in iter {
+        if current.end.succ_opt() == Some(day) {
+            current.end = day;
+            current.days += 1;
+        } else {
+            streaks.push(current);
+            current = UnlockStreak { start: day, end: day, days: 1 };
+        }
+    }
+    streaks.push(current);
+    streaks
+}
+
+/// Compute personal records and milestones from games and unlocked achievements
+pub fn compute_records(games: &[Game], unlocks: &[UnlockRecord], time: &TimeDisplaySettings) -> AchievementRecords {
+    let mut sorted: Vec<&UnlockRecord> = unlocks.iter().collect();
+    sorted.sort_by_key(|u| u.unlocktime);
+
+    let mut records = AchievementRecords {
+        first_achievement: sorted.first().map(|u| (*u).clone()),
+        ..Default::default()
+    };
+
+    // Busiest day (ties go to the earliest day)
+    let mut per_day: HashMap<NaiveDate, u32> = HashMap::new();
+    for u in &sorted {
+        *per_day.entry(time.display_date(u.unlocktime)).or_default() += 1;
+    }
+    records.busiest_day = per_day
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))
+        .map(|(date, unlocks)| BusiestDay { date, unlocks });
+
+    // Longest streak (ties go to the earliest streak)
+    let streaks = unlock_streaks(unlocks, time);
+    records.longest_streak = streaks.iter().copied().fold(None, |best: Option<UnlockStreak>, s| match best {
+        Some(b) if b.days >= s.days => Some(b),
+        _ => Some(s),
+    });
+    records.latest_streak = streaks.last().copied();
+
+    // Rarest unlock (lowest global percentage)
+    records.rarest_unlock = sorted
+        .iter()
+        .filter(|u| u.global_percent.is_some())
+        .min_by(|a, b| a.global_percent.partial_cmp(&b.global_percent).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|u| (*u).clone());
+
+    // First and last unlock per game
+    let mut first_unlock: HashMap<u64, DateTime<Utc>> = HashMap::new();
+    let mut last_unlock: HashMap<u64, &UnlockRecord> = HashMap::new();
+    for u in &sorted {
+        first_unlock.entry(u.appid).or_insert(u.unlocktime);
+        last_unlock.insert(u.appid, u);
+    }
+
+    // Oldest unfinished game: started (has unlocks) but not at 100%
+    records.oldest_unfinished = games
+        .iter()
+        .filter_map(|g| {
+            let pct = g.completion_percent()?;
+            if pct >= 100.0 {
+                return None;
+            }
+            let started_at = *first_unlock.get(&g.appid)?;
+            Some(UnfinishedGame { appid: g.appid, name: g.name.clone(), started_at, completion_percent: pct })
+        })
+        .min_by_key(|g| g.started_at);
+
+    // Achievement count milestones
+    for &n in ACHIEVEMENT_MILESTONES {
+        if let Some(u) = sorted.get(n as usize - 1) {
+            records.milestones.push(Milestone {
+                kind: MilestoneKind::Achievements(n),
+                reached_at: u.unlocktime,
+                detail: format!("{} ({})", u.achievement_name, u.game_name),
+            });
+        }
+    }
+
+    // Perfect game milestones: a game is perfected at its last unlock
+    let mut perfected: Vec<&UnlockRecord> = games
+        .iter()
+        .filter(|g| g.completion_percent().map(|p| p >= 100.0).unwrap_or(false))
+        .filter_map(|g| last_unlock.get(&g.appid).copied())
+        .collect();
+    perfected.sort_by_key(|u| u.unlocktime);
+    records.perfect_game_dates = perfected.iter().map(|u| u.unlocktime).collect();
+    for &n in PERFECT_GAME_MILESTONES {
+        if let Some(u) = perfected.get(n as usize - 1) {
+            records.milestones.push(Milestone {
+                kind: MilestoneKind::PerfectGames(n),
+                reached_at: u.unlocktime,
+                detail: u.game_name.clone(),
+            });
+        }
+    }
+
+    // Streak milestones: first streak to reach each length
+    for &n in STREAK_MILESTONES {
+        if let Some(s) = streaks.iter().find(|s| s.days >= n) {
+            let reached = s.start + chrono::Days::new(n as u64 - 1);
+            if let Some(reached_at) = reached.and_hms_opt(23, 59, 59).map(|dt| dt.and_utc()) {
+                records.milestones.push(Milestone {
+                    kind: MilestoneKind::Streak(n),
+                    reached_at,
+                    detail: format!("started {}", s.start.format("%Y-%m-%d")),
+                });
+            }
+        }
+    }
+
+    records.milestones.sort_by_key(|m| m.reached_at);
+    records
+}
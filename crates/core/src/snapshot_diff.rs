@@ -0,0 +1,148 @@
+//! Comparing two scan snapshots: new games, unlocks, playtime deltas and completion changes
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{AchievementHistory, Game, UnlockRecord};
+
+/// Per-game state captured at the end of a scan
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameSnapshot {
+    pub appid: u64,
+    pub name: String,
+    pub playtime_forever: u32,
+    pub achievements_unlocked: Option<i32>,
+    pub achievements_total: Option<i32>,
+}
+
+impl GameSnapshot {
+    pub fn from_game(game: &Game) -> Self {
+        Self {
+            appid: game.appid,
+            name: game.name.clone(),
+            playtime_forever: game.playtime_forever,
+            achievements_unlocked: game.achievements_unlocked,
+            achievements_total: game.achievements_total,
+        }
+    }
+
+    pub fn completion_percent(&self) -> Option<f32> {
+        match (self.achievements_unlocked, self.achievements_total) {
+            (Some(unlocked), Some(total)) if total > 0 => Some(unlocked as f32 / total as f32 * 100.0),
+            _ => None,
+        }
+    }
+}
+
+/// How a game present in both snapshots changed
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameChange {
+    pub appid: u64,
+    pub name: String,
+    /// Minutes played in between
+    pub playtime_delta: i64,
+    pub unlocked_delta: i32,
+    pub percent_before: Option<f32>,
+    pub percent_after: Option<f32>,
+}
+
+/// Everything that changed between two snapshots
+#[derive(Debug, Clone)]
+pub struct SnapshotDiff {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    /// Games in the later snapshot only
+    pub new_games: Vec<GameSnapshot>,
+    /// Games in the earlier snapshot only (refunded, removed from the account)
+    pub removed_games: Vec<GameSnapshot>,
+    /// Games with more playtime or a different achievement count, most played first
+    pub changed_games: Vec<GameChange>,
+    /// Unlocks after `from` up to `to`, oldest first
+    pub unlocks: Vec<UnlockRecord>,
+    /// Average completion (from achievement history) at `from` and at `to`
+    pub avg_completion: Option<(f32, f32)>,
+}
+
+impl SnapshotDiff {
+    /// Minutes played in between, over all games (new games count from zero)
+    pub fn total_playtime_delta(&self) -> i64 {
+        self.changed_games.iter().map(|c| c.playtime_delta).sum::<i64>()
+            + self.new_games.iter().map(|g| g.playtime_forever as i64).sum::<i64>()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.new_games.is_empty() && self.removed_games.is_empty() && self.changed_games.is_empty() && self.unlocks.is_empty()
+    }
+}
+
+/// Latest history entry recorded by `at` (history is written right before the snapshot)
+fn history_at(history: &[AchievementHistory], at: DateTime<Utc>) -> Option<&AchievementHistory> {
+    history
+        .iter()
+        .filter(|h| h.recorded_at <= at + Duration::minutes(1))
+        .max_by_key(|h| h.recorded_at)
+}
+
+/// Compare the snapshot taken at `from_at` with the one taken at `to_at`
+pub fn snapshot_diff(
+    from_at: DateTime<Utc>,
+    from: &[GameSnapshot],
+    to_at: DateTime<Utc>,
+    to: &[GameSnapshot],
+    unlocks: &[UnlockRecord],
+    history: &[AchievementHistory],
+) -> SnapshotDiff {
+    let before: HashMap<u64, &GameSnapshot> = from.iter().map(|g| (g.appid, g)).collect();
+    let after: HashMap<u64, &GameSnapshot> = to.iter().map(|g| (g.appid, g)).collect();
+
+    let mut new_games: Vec<GameSnapshot> = to.iter().filter(|g| !before.contains_key(&g.appid)).cloned().collect();
+    new_games.sort_by_key(|g| g.name.to_lowercase());
+    let mut removed_games: Vec<GameSnapshot> = from.iter().filter(|g| !after.contains_key(&g.appid)).cloned().collect();
+    removed_games.sort_by_key(|g| g.name.to_lowercase());
+
+    let mut changed_games: Vec<GameChange> = to
+        .iter()
+        .filter_map(|now| {
+            let then = before.get(&now.appid)?;
+            let playtime_delta = now.playtime_forever as i64 - then.playtime_forever as i64;
+            let unlocked_delta = now.achievements_unlocked.unwrap_or(0) - then.achievements_unlocked.unwrap_or(0);
+            let percent_before = then.completion_percent();
+            let percent_after = now.completion_percent();
+            if playtime_delta == 0 && unlocked_delta == 0 && percent_before == percent_after {
+                return None;
+            }
+            Some(GameChange {
+                appid: now.appid,
+                name: now.name.clone(),
+                playtime_delta,
+                unlocked_delta,
+                percent_before,
+                percent_after,
+            })
+        })
+        .collect();
+    changed_games.sort_by(|a, b| b.playtime_delta.cmp(&a.playtime_delta).then(b.unlocked_delta.cmp(&a.unlocked_delta)));
+
+    let mut unlocks: Vec<UnlockRecord> = unlocks
+        .iter()
+        .filter(|u| u.unlocktime > from_at && u.unlocktime <= to_at)
+        .cloned()
+        .collect();
+    unlocks.sort_by_key(|u| u.unlocktime);
+
+    let avg_completion = history_at(history, from_at)
+        .zip(history_at(history, to_at))
+        .map(|(a, b)| (a.avg_completion_percent, b.avg_completion_percent));
+
+    SnapshotDiff {
+        from: from_at,
+        to: to_at,
+        new_games,
+        removed_games,
+        changed_games,
+        unlocks,
+        avg_completion,
+    }
+}
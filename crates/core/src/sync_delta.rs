@@ -0,0 +1,253 @@
+//! Delta cloud sync: the server describes what it has (a checksum per game, the newest
+//! history entries) and clients upload only the games and history that changed since
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{AchievementHistory, CloudSyncData, Game, Goal, RunHistory, SyncAchievement};
+
+/// What the server has stored for a user
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyncManifest {
+    /// appid -> checksum of the game row and its achievements (see `game_sync_checksum`)
+    pub game_checksums: HashMap<u64, u64>,
+    /// Newest run history entry
+    pub last_run_at: Option<DateTime<Utc>>,
+    /// Newest achievement history entry
+    pub last_achievement_history_at: Option<DateTime<Utc>>,
+}
+
+/// Changes to upload on top of the server's manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloudSyncDelta {
+    pub steam_id: String,
+    /// New or changed games
+    pub games: Vec<Game>,
+    /// All achievements of the changed games
+    pub achievements: Vec<SyncAchievement>,
+    /// History entries newer than the manifest's
+    pub run_history: Vec<RunHistory>,
+    pub achievement_history: Vec<AchievementHistory>,
+    /// Goals are few, they're always sent in full
+    pub goals: Vec<Goal>,
+    pub exported_at: DateTime<Utc>,
+}
+
+impl CloudSyncDelta {
+    /// Everything in `data` the server doesn't have yet according to `manifest`
+    pub fn from_data(data: &CloudSyncData, manifest: &SyncManifest) -> Self {
+        let mut achievements_by_game: HashMap<u64, Vec<&SyncAchievement>> = HashMap::new();
+        for ach in &data.achievements {
+            achievements_by_game.entry(ach.appid).or_default().push(ach);
+        }
+
+        let games: Vec<Game> = data
+            .games
+            .iter()
+            .filter(|game| {
+                let achievements = achievements_by_game.get(&game.appid).map(Vec::as_slice).unwrap_or(&[]);
+                manifest.game_checksums.get(&game.appid) != Some(&game_sync_checksum(game, achievements))
+            })
+            .cloned()
+            .collect();
+        let achievements = games
+            .iter()
+            .flat_map(|game| achievements_by_game.get(&game.appid).into_iter().flatten())
+            .map(|ach| (*ach).clone())
+            .collect();
+
+        Self {
+            steam_id: data.steam_id.clone(),
+            games,
+            achievements,
+            run_history: data
+                .run_history
+                .iter()
+                .filter(|rh| is_newer(rh.run_at, manifest.last_run_at))
+                .cloned()
+                .collect(),
+            achievement_history: data
+                .achievement_history
+                .iter()
+                .filter(|ah| is_newer(ah.recorded_at, manifest.last_achievement_history_at))
+                .cloned()
+                .collect(),
+            goals: data.goals.clone(),
+            exported_at: data.exported_at,
+        }
+    }
+
+    /// Nothing but the goals would be uploaded
+    pub fn is_empty(&self) -> bool {
+        self.games.is_empty() && self.run_history.is_empty() && self.achievement_history.is_empty()
+    }
+}
+
+/// Compared in microseconds - the server doesn't keep nanoseconds
+fn is_newer(time: DateTime<Utc>, last: Option<DateTime<Utc>>) -> bool {
+    last.is_none_or(|last| time.timestamp_micros() > last.timestamp_micros())
+}
+
+/// How an achievement merges with the stored copy from another device: unlocked on either
+/// side stays unlocked, and the newest unlock time wins.
+///
+/// The desktop and server databases implement this in SQL. Both must agree, or the merged
+/// rows checksum differently on each side and the game is uploaded again on every sync.
+pub fn merge_sync_achievement(stored: &SyncAchievement, incoming: &SyncAchievement) -> SyncAchievement {
+    let unlocktime = match (stored.unlocktime, incoming.unlocktime) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    };
+    SyncAchievement {
+        appid: stored.appid,
+        apiname: stored.apiname.clone(),
+        achieved: stored.achieved || incoming.achieved,
+        unlocktime,
+    }
+}
+
+/// Checksum of the synced fields of a game and its achievements (in any order).
+///
+/// Computed the same way by client and server, so it must only cover fields the server stores
+/// and must not depend on the Rust version (FNV-1a instead of `DefaultHasher`).
+pub fn game_sync_checksum(game: &Game, achievements: &[&SyncAchievement]) -> u64 {
+    let mut hash = Fnv1a::new();
+    hash.write(&game.appid.to_le_bytes());
+    hash.write(game.name.as_bytes());
+    hash.write(&game.playtime_forever.to_le_bytes());
+    hash.write(&game.rtime_last_played.unwrap_or(0).to_le_bytes());
+    hash.write(game.img_icon_url.as_deref().unwrap_or("").as_bytes());
+    hash.write(&game.achievements_total.unwrap_or(-1).to_le_bytes());
+    hash.write(&game.achievements_unlocked.unwrap_or(-1).to_le_bytes());
+
+    let mut sorted: Vec<&&SyncAchievement> = achievements.iter().collect();
+    sorted.sort_by(|a, b| a.apiname.cmp(&b.apiname));
+    for ach in sorted {
+        hash.write(ach.apiname.as_bytes());
+        hash.write(&[ach.achieved as u8]);
+        hash.write(&ach.unlocktime.map(|t| t.timestamp()).unwrap_or(0).to_le_bytes());
+    }
+    hash.finish()
+}
+
+/// Checksum of everything the server sends about a game row, for the WebSocket `Resync`.
+///
+/// Both sides hash the `Game` as serialized on the wire, so a client only has to keep
+/// the rows it was sent. A client built against other `Game` fields just sees more changes.
+pub fn game_row_checksum(game: &Game) -> u64 {
+    let mut hash = Fnv1a::new();
+    hash.write(&serde_json::to_vec(game).unwrap_or_default());
+    hash.finish()
+}
+
+/// Games the client is missing or has outdated (by `game_row_checksum`), and the appids it
+/// has that are gone from `games`
+pub fn games_delta(games: Vec<Game>, client_checksums: &HashMap<u64, u64>) -> (Vec<Game>, Vec<u64>) {
+    let mut removed: Vec<u64> = client_checksums
+        .keys()
+        .filter(|appid| !games.iter().any(|g| g.appid == **appid))
+        .copied()
+        .collect();
+    removed.sort_unstable();
+    let changed = games
+        .into_iter()
+        .filter(|game| client_checksums.get(&game.appid) != Some(&game_row_checksum(game)))
+        .collect();
+    (changed, removed)
+}
+
+/// 64-bit FNV-1a, with a separator after each field so ("ab", "c") != ("a", "bc")
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter().chain(std::iter::once(&0xff)) {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(appid: u64, unlocked: i32) -> Game {
+        let mut game: Game = serde_json::from_value(serde_json::json!({
+            "appid": appid,
+            "name": format!("Game {}", appid),
+            "playtime_forever": 60,
+            "rtime_last_played": null,
+            "img_icon_url": null,
+            "added_at": "2024-01-01T00:00:00Z",
+            "achievements_total": 10,
+            "achievements_unlocked": 0,
+            "last_achievement_scrape": null,
+            "avg_user_ttb_main_seconds": null,
+            "avg_user_ttb_extra_seconds": null,
+            "avg_user_ttb_completionist_seconds": null,
+            "user_ttb_report_count": 0,
+            "my_ttb_main_seconds": null,
+            "my_ttb_extra_seconds": null,
+            "my_ttb_completionist_seconds": null,
+            "my_ttb_reported_at": null,
+        }))
+        .unwrap();
+        game.achievements_unlocked = Some(unlocked);
+        game
+    }
+
+    #[test]
+    fn games_delta_sends_only_changes() {
+        let client: HashMap<u64, u64> = [
+            (1, game_row_checksum(&game(1, 3))),
+            (2, game_row_checksum(&game(2, 5))),
+            (3, game_row_checksum(&game(3, 0))),
+        ]
+        .into();
+        let (changed, removed) = games_delta(vec![game(1, 3), game(2, 6), game(4, 0)], &client);
+        let changed: Vec<u64> = changed.iter().map(|g| g.appid).collect();
+        assert_eq!(changed, [2, 4]);
+        assert_eq!(removed, [3]);
+    }
+
+    #[test]
+    fn merging_in_either_direction_gives_equal_checksums() {
+        let unlocked_at = |ts| DateTime::from_timestamp(ts, 0);
+        let ach = |apiname: &str, achieved, unlocktime| SyncAchievement {
+            appid: 1,
+            apiname: apiname.to_string(),
+            achieved,
+            unlocktime,
+        };
+        let desktop = [ach("A", true, unlocked_at(2_000)), ach("B", false, None), ach("C", true, unlocked_at(500))];
+        let server = [ach("A", true, unlocked_at(1_000)), ach("B", true, unlocked_at(3_000)), ach("C", true, None)];
+
+        let into_desktop: Vec<SyncAchievement> = desktop.iter().zip(&server).map(|(d, s)| merge_sync_achievement(d, s)).collect();
+        let into_server: Vec<SyncAchievement> = server.iter().zip(&desktop).map(|(s, d)| merge_sync_achievement(s, d)).collect();
+        assert_eq!(into_desktop[0].unlocktime, unlocked_at(2_000));
+        assert!(into_desktop[1].achieved);
+        assert_eq!(into_desktop[2].unlocktime, unlocked_at(500));
+        assert_eq!(
+            game_sync_checksum(&game(1, 3), &into_desktop.iter().collect::<Vec<_>>()),
+            game_sync_checksum(&game(1, 3), &into_server.iter().collect::<Vec<_>>())
+        );
+    }
+
+    #[test]
+    fn games_delta_without_checksums_sends_everything() {
+        let (changed, removed) = games_delta(vec![game(1, 0), game(2, 0)], &HashMap::new());
+        assert_eq!(changed.len(), 2);
+        assert!(removed.is_empty());
+    }
+}
@@ -0,0 +1,91 @@
+//! Tag filter for the games list: tags a game must have (all or any of them)
+//! and tags it must not have
+
+use serde::{Deserialize, Serialize};
+
+/// How the included tags of a filter combine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TagMatchMode {
+    /// The game needs every included tag (AND)
+    #[default]
+    All,
+    /// One included tag is enough (OR)
+    Any,
+}
+
+impl TagMatchMode {
+    pub fn toggled(self) -> Self {
+        match self {
+            TagMatchMode::All => TagMatchMode::Any,
+            TagMatchMode::Any => TagMatchMode::All,
+        }
+    }
+}
+
+/// Whether a game passes the tag filter, `has_tag` tells if the game carries a tag.
+///
+/// Excluded tags always win: a game with any of them is filtered out, whatever it includes.
+/// Without included tags every game that isn't excluded passes.
+pub fn matches_tag_filter(include: &[String], exclude: &[String], mode: TagMatchMode, has_tag: impl Fn(&str) -> bool) -> bool {
+    if exclude.iter().any(|tag| has_tag(tag)) {
+        return false;
+    }
+    if include.is_empty() {
+        return true;
+    }
+    match mode {
+        TagMatchMode::All => include.iter().all(|tag| has_tag(tag)),
+        TagMatchMode::Any => include.iter().any(|tag| has_tag(tag)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    fn matches(game: &[&str], include: &[&str], exclude: &[&str], mode: TagMatchMode) -> bool {
+        matches_tag_filter(&tags(include), &tags(exclude), mode, |tag| game.contains(&tag))
+    }
+
+    #[test]
+    fn empty_filter_matches_everything() {
+        assert!(matches(&["Roguelike"], &[], &[], TagMatchMode::All));
+        assert!(matches(&[], &[], &[], TagMatchMode::Any));
+    }
+
+    #[test]
+    fn all_mode_needs_every_included_tag() {
+        assert!(matches(&["Roguelike", "Deckbuilder", "Indie"], &["Roguelike", "Deckbuilder"], &[], TagMatchMode::All));
+        assert!(!matches(&["Roguelike", "Indie"], &["Roguelike", "Deckbuilder"], &[], TagMatchMode::All));
+        assert!(!matches(&[], &["Roguelike"], &[], TagMatchMode::All));
+    }
+
+    #[test]
+    fn any_mode_needs_one_included_tag() {
+        assert!(matches(&["Roguelike", "Indie"], &["Roguelike", "Deckbuilder"], &[], TagMatchMode::Any));
+        assert!(matches(&["Deckbuilder"], &["Roguelike", "Deckbuilder"], &[], TagMatchMode::Any));
+        assert!(!matches(&["Puzzle"], &["Roguelike", "Deckbuilder"], &[], TagMatchMode::Any));
+    }
+
+    #[test]
+    fn excluded_tags_filter_out() {
+        assert!(!matches(&["Roguelike", "Early Access"], &[], &["Early Access"], TagMatchMode::All));
+        assert!(matches(&["Roguelike"], &[], &["Early Access"], TagMatchMode::All));
+        // Games without tags can't carry an excluded one
+        assert!(matches(&[], &[], &["Early Access"], TagMatchMode::Any));
+    }
+
+    #[test]
+    fn exclusion_wins_over_inclusion() {
+        for mode in [TagMatchMode::All, TagMatchMode::Any] {
+            assert!(!matches(&["Roguelike", "Early Access"], &["Roguelike"], &["Early Access"], mode));
+            assert!(matches(&["Roguelike"], &["Roguelike"], &["Early Access"], mode));
+        }
+        assert!(!matches(&["Roguelike", "Early Access"], &["Roguelike", "Puzzle"], &["Early Access"], TagMatchMode::Any));
+    }
+}
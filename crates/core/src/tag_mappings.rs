@@ -0,0 +1,125 @@
+//! Tag aliases and hierarchy: collapsing noisy SteamSpy tags into canonical ones
+//!
+//! Mappings are maintained by admins on the backend. An alias ("Rogue-like" -> "Roguelike")
+//! replaces the tag everywhere; a parent ("Roguelite" -> "Roguelike") keeps the tag but
+//! also counts the game under its parent, so filtering or grouping by the parent includes
+//! the children.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+/// How a tag relates to its target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TagMappingKind {
+    /// The tag is a duplicate of the target and is replaced by it
+    #[default]
+    Alias,
+    /// The tag is a sub-genre of the target
+    Parent,
+}
+
+/// One admin-maintained tag mapping (each tag has at most one)
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TagMapping {
+    pub tag: String,
+    pub target: String,
+    pub kind: TagMappingKind,
+}
+
+/// Longest alias/parent chain that is followed (guards against cycles)
+const MAX_CHAIN: usize = 8;
+
+/// Lookup built from the mappings fetched at startup
+#[derive(Debug, Clone, Default)]
+pub struct TagMap {
+    aliases: HashMap<String, String>,
+    parents: HashMap<String, String>,
+}
+
+impl TagMap {
+    pub fn new(mappings: &[TagMapping]) -> Self {
+        let mut map = Self::default();
+        for mapping in mappings {
+            let table = match mapping.kind {
+                TagMappingKind::Alias => &mut map.aliases,
+                TagMappingKind::Parent => &mut map.parents,
+            };
+            table.insert(mapping.tag.clone(), mapping.target.clone());
+        }
+        map
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.aliases.is_empty() && self.parents.is_empty()
+    }
+
+    /// The canonical name of a tag (itself unless it's an alias)
+    pub fn canonical<'a>(&'a self, tag: &'a str) -> &'a str {
+        let mut current = tag;
+        for _ in 0..MAX_CHAIN {
+            match self.aliases.get(current) {
+                Some(target) if target != current => current = target,
+                _ => break,
+            }
+        }
+        current
+    }
+
+    /// Canonical parents of a canonical tag, nearest first
+    fn ancestors(&self, tag: &str) -> Vec<&str> {
+        let mut ancestors = Vec::new();
+        let mut current = tag;
+        for _ in 0..MAX_CHAIN {
+            let Some(parent) = self.parents.get(current) else {
+                break;
+            };
+            let parent = self.canonical(parent);
+            if parent == tag || ancestors.contains(&parent) {
+                break;
+            }
+            ancestors.push(parent);
+            current = parent;
+        }
+        ancestors
+    }
+
+    /// Apply the mappings to one game's tags
+    ///
+    /// Aliases are renamed (merged duplicates keep the higher vote count) and parents are
+    /// added with the votes of their best-voted child. Most votes first, like SteamSpy.
+    pub fn apply(&self, tags: &[(String, u32)]) -> Vec<(String, u32)> {
+        if self.is_empty() {
+            return tags.to_vec();
+        }
+
+        let mut merged: HashMap<&str, u32> = HashMap::new();
+        for (name, votes) in tags {
+            let canonical = self.canonical(name);
+            let entry = merged.entry(canonical).or_insert(0);
+            *entry = (*entry).max(*votes);
+            for parent in self.ancestors(canonical) {
+                let entry = merged.entry(parent).or_insert(0);
+                *entry = (*entry).max(*votes);
+            }
+        }
+
+        let mut result: Vec<(String, u32)> = merged.into_iter().map(|(name, votes)| (name.to_string(), votes)).collect();
+        result.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        result
+    }
+
+    /// Canonical tag names for a filter dropdown (aliases dropped, sorted)
+    pub fn apply_names(&self, names: &[String]) -> Vec<String> {
+        let mut seen = HashSet::new();
+        let mut result: Vec<String> = names
+            .iter()
+            .map(|name| self.canonical(name))
+            .filter(|name| seen.insert(*name))
+            .map(str::to_string)
+            .collect();
+        result.sort();
+        result
+    }
+}
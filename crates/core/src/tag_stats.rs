@@ -0,0 +1,123 @@
+//! Per-tag library statistics (owned games, hours, completion, remaining time to beat)
+
+use crate::{tr, Game};
+
+/// Aggregated numbers for the games carrying one tag
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagStats {
+    pub tag: String,
+    /// Owned games with the tag
+    pub owned: u32,
+    /// Total playtime in hours
+    pub hours: f32,
+    /// Average completion of the tagged games that have achievements (None if none do)
+    pub avg_completion: Option<f32>,
+    /// Completionist hours left across the tagged games that aren't at 100%
+    pub unfinished_ttb_hours: f32,
+}
+
+/// Column a tag stats table can be sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TagStatsColumn {
+    Tag,
+    #[default]
+    Owned,
+    Hours,
+    Completion,
+    UnfinishedTtb,
+}
+
+impl TagStatsColumn {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TagStatsColumn::Tag => tr("tag_stats.tag"),
+            TagStatsColumn::Owned => tr("tag_stats.owned"),
+            TagStatsColumn::Hours => tr("tag_stats.hours"),
+            TagStatsColumn::Completion => tr("tag_stats.completion"),
+            TagStatsColumn::UnfinishedTtb => tr("tag_stats.ttb_left"),
+        }
+    }
+}
+
+impl TagStats {
+    /// Numeric value of a column (the tag name column charts owned games)
+    pub fn value(&self, column: TagStatsColumn) -> f64 {
+        match column {
+            TagStatsColumn::Tag | TagStatsColumn::Owned => self.owned as f64,
+            TagStatsColumn::Hours => self.hours as f64,
+            TagStatsColumn::Completion => self.avg_completion.unwrap_or(0.0) as f64,
+            TagStatsColumn::UnfinishedTtb => self.unfinished_ttb_hours as f64,
+        }
+    }
+}
+
+/// Compute stats for each of `tags`.
+/// `game_tags` returns the cached (tag, votes) list of a game, `completionist_hours`
+/// the completionist time to beat from whatever TTB source the platform has.
+pub fn tag_stats<'a>(
+    games: &[Game],
+    tags: &[String],
+    game_tags: impl Fn(u64) -> Option<&'a [(String, u32)]>,
+    completionist_hours: impl Fn(u64) -> Option<f32>,
+) -> Vec<TagStats> {
+    tags.iter()
+        .map(|tag| {
+            let mut stats = TagStats {
+                tag: tag.clone(),
+                owned: 0,
+                hours: 0.0,
+                avg_completion: None,
+                unfinished_ttb_hours: 0.0,
+            };
+            let mut completion_sum = 0.0;
+            let mut completion_count = 0;
+            for game in games {
+                let has_tag = game_tags(game.appid).is_some_and(|t| t.iter().any(|(name, _)| name == tag));
+                if !has_tag {
+                    continue;
+                }
+                stats.owned += 1;
+                let played_hours = game.playtime_forever as f32 / 60.0;
+                stats.hours += played_hours;
+                // Abandoned games count as owned and played, not towards completion or time left
+                if game.abandoned {
+                    continue;
+                }
+
+                let completion = game.completion_percent();
+                if let Some(pct) = completion {
+                    completion_sum += pct;
+                    completion_count += 1;
+                }
+                if completion.is_some_and(|pct| pct >= 100.0) {
+                    continue;
+                }
+                // User reports preferred over HLTB, like the completion cost tooltip
+                let ttb = game
+                    .my_ttb_completionist_seconds
+                    .or(game.avg_user_ttb_completionist_seconds)
+                    .map(|s| s as f32 / 3600.0)
+                    .or_else(|| completionist_hours(game.appid));
+                if let Some(ttb) = ttb {
+                    stats.unfinished_ttb_hours += (ttb - played_hours).max(0.0);
+                }
+            }
+            if completion_count > 0 {
+                stats.avg_completion = Some(completion_sum / completion_count as f32);
+            }
+            stats
+        })
+        .collect()
+}
+
+/// Sort tag stats by a column (ties broken by tag name)
+pub fn sort_tag_stats(stats: &mut [TagStats], column: TagStatsColumn, descending: bool) {
+    stats.sort_by(|a, b| {
+        let ord = match column {
+            TagStatsColumn::Tag => a.tag.to_lowercase().cmp(&b.tag.to_lowercase()),
+            _ => a.value(column).partial_cmp(&b.value(column)).unwrap_or(std::cmp::Ordering::Equal),
+        };
+        let ord = if descending { ord.reverse() } else { ord };
+        ord.then_with(|| a.tag.cmp(&b.tag))
+    });
+}
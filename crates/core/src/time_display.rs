@@ -0,0 +1,143 @@
+//! Time zone aware display of unlock times and other timestamps
+//!
+//! Timestamps are stored in UTC everywhere; these settings only decide how they
+//! are shown (and which calendar day an unlock counts towards).
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+/// Time zone used to display timestamps
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DisplayTimeZone {
+    /// Coordinated Universal Time (how timestamps are stored)
+    #[default]
+    Utc,
+    /// The system's local time zone
+    Local,
+    /// An IANA time zone name, e.g. "Europe/Stockholm"
+    Named(String),
+}
+
+impl DisplayTimeZone {
+    /// Short label for settings menus
+    pub fn label(&self) -> String {
+        match self {
+            DisplayTimeZone::Utc => "UTC".to_string(),
+            DisplayTimeZone::Local => crate::tr("time.local").to_string(),
+            DisplayTimeZone::Named(name) => name.clone(),
+        }
+    }
+}
+
+/// How a timestamp is written out
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimestampStyle {
+    /// Calendar date, e.g. "2024-03-15"
+    #[default]
+    Absolute,
+    /// Age relative to now, e.g. "3 days ago"
+    Relative,
+}
+
+/// User preferences for displaying timestamps
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TimeDisplaySettings {
+    #[serde(default)]
+    pub timezone: DisplayTimeZone,
+    /// Style used in the activity log
+    #[serde(default)]
+    pub log_style: TimestampStyle,
+    /// Style used in expanded achievement rows and the games table
+    #[serde(default)]
+    pub achievement_style: TimestampStyle,
+    /// Include the time of day in absolute timestamps
+    #[serde(default)]
+    pub show_time_of_day: bool,
+}
+
+impl TimeDisplaySettings {
+    /// Wall-clock time of a timestamp in the chosen time zone
+    /// (unknown zone names fall back to UTC)
+    pub fn to_display_time(&self, ts: DateTime<Utc>) -> NaiveDateTime {
+        match &self.timezone {
+            DisplayTimeZone::Utc => ts.naive_utc(),
+            DisplayTimeZone::Local => ts.with_timezone(&chrono::Local).naive_local(),
+            DisplayTimeZone::Named(name) => match name.parse::<Tz>() {
+                Ok(tz) => ts.with_timezone(&tz).naive_local(),
+                Err(_) => ts.naive_utc(),
+            },
+        }
+    }
+
+    /// Calendar day a timestamp falls on in the chosen time zone
+    pub fn display_date(&self, ts: DateTime<Utc>) -> NaiveDate {
+        self.to_display_time(ts).date()
+    }
+
+    /// Moment a calendar day starts in the chosen time zone
+    /// (unknown zone names fall back to UTC)
+    pub fn start_of_day(&self, date: NaiveDate) -> DateTime<Utc> {
+        let midnight = date.and_time(NaiveTime::MIN);
+        let start = match &self.timezone {
+            DisplayTimeZone::Utc => None,
+            DisplayTimeZone::Local => chrono::Local.from_local_datetime(&midnight).earliest().map(|t| t.with_timezone(&Utc)),
+            DisplayTimeZone::Named(name) => name
+                .parse::<Tz>()
+                .ok()
+                .and_then(|tz| tz.from_local_datetime(&midnight).earliest())
+                .map(|t| t.with_timezone(&Utc)),
+        };
+        start.unwrap_or_else(|| midnight.and_utc())
+    }
+
+    /// Absolute date (and time of day if enabled) in the chosen time zone
+    pub fn format_absolute(&self, ts: DateTime<Utc>) -> String {
+        let local = self.to_display_time(ts);
+        if self.show_time_of_day {
+            local.format("%Y-%m-%d %H:%M").to_string()
+        } else {
+            local.format("%Y-%m-%d").to_string()
+        }
+    }
+
+    /// Format a timestamp in the given style
+    pub fn format(&self, ts: DateTime<Utc>, style: TimestampStyle) -> String {
+        match style {
+            TimestampStyle::Absolute => self.format_absolute(ts),
+            TimestampStyle::Relative => format_relative(ts, Utc::now()),
+        }
+    }
+
+    /// Format a timestamp for the activity log
+    pub fn format_log(&self, ts: DateTime<Utc>) -> String {
+        self.format(ts, self.log_style)
+    }
+
+    /// Format a timestamp for achievement rows and the games table
+    pub fn format_achievement(&self, ts: DateTime<Utc>) -> String {
+        self.format(ts, self.achievement_style)
+    }
+}
+
+/// Human readable age of a timestamp, e.g. "3 days ago"
+pub fn format_relative(ts: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let seconds = (now - ts).num_seconds();
+    if seconds < 0 {
+        return "in the future".to_string();
+    }
+    let (amount, unit) = match seconds {
+        0..=59 => return "just now".to_string(),
+        60..=3_599 => (seconds / 60, "minute"),
+        3_600..=86_399 => (seconds / 3_600, "hour"),
+        86_400..=2_591_999 => (seconds / 86_400, "day"),
+        2_592_000..=31_535_999 => (seconds / 2_592_000, "month"),
+        _ => (seconds / 31_536_000, "year"),
+    };
+    format!("{} {}{} ago", amount, unit, if amount == 1 { "" } else { "s" })
+}
+
+/// All IANA time zone names known to chrono-tz (for settings pickers)
+pub fn time_zone_names() -> impl Iterator<Item = &'static str> {
+    chrono_tz::TZ_VARIANTS.iter().map(|tz| tz.name())
+}
@@ -1,13 +1,32 @@
 //! Achievement list rendering for expanded game rows
 
 use egui::{self, Color32, RichText, Ui};
+use egui_phosphor::regular;
+use std::collections::{HashMap, HashSet};
 use super::platform::GamesTablePlatform;
+use super::helpers::sort_achievements;
+use super::types::{AchievementSort, TriFilter};
+use crate::{AchievementComment, AchievementRarityHistory};
+use super::ratings::render_difficulty_curve;
+use super::unlock_graph::render_unlock_graph;
+use super::rarity::render_global_percent;
 use super::super::instant_tooltip;
+use crate::tr;
+
+/// Per-game filter of the achievement list (kept in egui memory while the row is open)
+#[derive(Clone, Default)]
+struct AchievementListFilter {
+    query: String,
+    /// With = unlocked only, Without = locked only
+    state: TriFilter,
+}
 
 /// Render the achievements list for an expanded game row
 pub fn render_achievements_list<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P, appid: u64) {
     // Check if we have a navigation target for this game
     let nav_target = platform.get_navigation_target();
+    let theme = platform.theme_colors();
+    let time = platform.time_display();
     let target_apiname = nav_target
         .as_ref()
         .filter(|(nav_appid, _)| *nav_appid == appid)
@@ -20,20 +39,90 @@ pub fn render_achievements_list<P: GamesTablePlatform>(ui: &mut Ui, platform: &m
     let ach_icon_size = 48.0 * font_scale;
     let ach_scroll_height = 300.0 * font_scale;
 
+    if platform.get_difficulty_curve(appid).is_none() {
+        platform.request_difficulty_curve(appid);
+    }
+    if platform.get_rarity_history(appid).is_none() {
+        platform.request_rarity_history(appid);
+    }
+
     if let Some(achievements) = platform.get_cached_achievements(appid) {
         ui.add_space(4.0);
         ui.separator();
 
-        // Sort achievements: unlocked first (by unlock time desc), then locked
-        let mut sorted_achs: Vec<_> = achievements.iter().collect();
-        sorted_achs.sort_by(|a, b| {
-            match (a.achieved, b.achieved) {
-                (true, false) => std::cmp::Ordering::Less,
-                (false, true) => std::cmp::Ordering::Greater,
-                (true, true) => b.unlocktime.cmp(&a.unlocktime),
-                (false, false) => a.name.cmp(&b.name),
+        if let Some(curve) = platform.get_difficulty_curve(appid) {
+            render_difficulty_curve(ui, appid, curve, achievements);
+        }
+        render_unlock_graph(ui, appid, achievements, theme.accent);
+
+        // Sort selector - shown per game, but the choice is shared by all expanded rows
+        let mut sort = platform.achievement_sort();
+        let filter_id = egui::Id::new(("achievement_filter", appid));
+        let mut filter: AchievementListFilter = ui.data_mut(|d| d.get_temp(filter_id)).unwrap_or_default();
+        // Spoilers revealed one by one (kept in egui memory while the row is open)
+        let revealed_id = egui::Id::new(("revealed_spoilers", appid));
+        let mut revealed: HashSet<String> = ui.data_mut(|d| d.get_temp(revealed_id)).unwrap_or_default();
+        let mut reveal_all = platform.reveal_spoilers();
+        let has_spoilers = achievements.iter().any(|ach| ach.hidden && !ach.achieved);
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(regular::SORT_ASCENDING.to_string()).weak());
+            egui::ComboBox::from_id_salt(("achievement_sort", appid))
+                .selected_text(sort.label())
+                .show_ui(ui, |ui| {
+                    for option in AchievementSort::all() {
+                        ui.selectable_value(&mut sort, *option, option.label());
+                    }
+                });
+
+            ui.add_space(8.0);
+            ui.label(RichText::new(regular::MAGNIFYING_GLASS.to_string()).weak());
+            ui.add(egui::TextEdit::singleline(&mut filter.query)
+                .hint_text(tr("table.filter_achievements"))
+                .desired_width(160.0));
+            let state_btn = ui.button(filter.state.label(tr("table.unlocked"), tr("table.locked")));
+            if state_btn.clicked() {
+                filter.state = filter.state.cycle();
+            }
+            instant_tooltip(&state_btn, tr("table.achievement_state_tooltip"));
+
+            if has_spoilers {
+                let label = if reveal_all {
+                    format!("{} {}", regular::EYE_SLASH, tr("table.hide_spoilers"))
+                } else {
+                    format!("{} {}", regular::EYE, tr("table.show_spoilers"))
+                };
+                let spoiler_btn = ui.button(label);
+                if spoiler_btn.clicked() {
+                    reveal_all = !reveal_all;
+                }
+                instant_tooltip(&spoiler_btn, tr("table.reveal_spoilers_tooltip"));
             }
         });
+        // Locked hidden achievements keep their description to themselves until revealed
+        let conceals = |ach: &crate::GameAchievement| {
+            ach.hidden && !ach.achieved && !reveal_all && !revealed.contains(&ach.apiname)
+        };
+
+        // Match display name, description or API name, case-insensitive
+        let query = filter.query.trim().to_lowercase();
+        let mut sorted_achs: Vec<_> = achievements
+            .iter()
+            .filter(|ach| match filter.state {
+                TriFilter::All => true,
+                TriFilter::With => ach.achieved,
+                TriFilter::Without => !ach.achieved,
+            })
+            .filter(|ach| {
+                query.is_empty()
+                    || ach.name.to_lowercase().contains(&query)
+                    || ach.apiname.to_lowercase().contains(&query)
+                    || (!conceals(ach) && ach.description.as_deref().is_some_and(|d| d.to_lowercase().contains(&query)))
+            })
+            .collect();
+        ui.data_mut(|d| d.insert_temp(filter_id, filter));
+        sort_achievements(&mut sorted_achs, sort, |apiname| {
+            platform.get_achievement_avg_rating(appid, apiname).map(|(avg, _)| avg)
+        });
 
         // Collect data we need to avoid borrow issues
         let ach_data: Vec<_> = sorted_achs.iter().map(|ach| {
@@ -44,13 +133,38 @@ pub fn render_achievements_list<P: GamesTablePlatform>(ui: &mut Ui, platform: &m
                 if ach.achieved { ach.icon.clone() } else { ach.icon_gray.clone() },
                 ach.description.clone(),
                 ach.unlocktime,
+                ach.global_percent,
+                conceals(ach),
             )
         }).collect();
+        if sort != platform.achievement_sort() {
+            platform.set_achievement_sort(sort);
+        }
+        if reveal_all != platform.reveal_spoilers() {
+            platform.set_reveal_spoilers(reveal_all);
+        }
+
+        // Community comments grouped by achievement
+        let mut comments: HashMap<String, Vec<AchievementComment>> = HashMap::new();
+        for comment in platform.get_achievement_comments(appid).unwrap_or_default() {
+            comments.entry(comment.apiname.clone()).or_default().push(comment.clone());
+        }
+        let mut report: Option<i64> = None;
+        let mut reveal: Option<String> = None;
+        let rarity: HashMap<String, AchievementRarityHistory> = platform
+            .get_rarity_history(appid)
+            .unwrap_or_default()
+            .iter()
+            .map(|h| (h.apiname.clone(), h.clone()))
+            .collect();
 
         egui::ScrollArea::vertical().max_height(ach_scroll_height).show(ui, |ui| {
             ui.set_width(ui.available_width());
             let is_authenticated = platform.is_authenticated();
-            for (i, (apiname, name, achieved, icon_url, description, unlocktime)) in ach_data.iter().enumerate() {
+            if ach_data.is_empty() {
+                ui.label(RichText::new(tr("table.no_matching_achievements")).weak());
+            }
+            for (i, (apiname, name, achieved, icon_url, description, unlocktime, global_percent, concealed)) in ach_data.iter().enumerate() {
                 // Check if this is the navigation target
                 let is_target = target_apiname.as_ref().map(|t| t == apiname).unwrap_or(false);
 
@@ -63,6 +177,8 @@ pub fn render_achievements_list<P: GamesTablePlatform>(ui: &mut Ui, platform: &m
                 };
                 // Get community average rating
                 let avg_rating_data = platform.get_achievement_avg_rating(appid, apiname);
+                let rating_votes = platform.get_achievement_rating_votes(appid, apiname);
+                let ach_comments = comments.get(apiname);
 
                 // Alternate row background, or highlight if target
                 let row_rect = ui.available_rect_before_wrap();
@@ -71,16 +187,16 @@ pub fn render_achievements_list<P: GamesTablePlatform>(ui: &mut Ui, platform: &m
                     egui::vec2(row_rect.width(), ach_row_height)
                 );
                 if is_target {
-                    // Highlight the target achievement with a golden border
+                    // Highlight the target achievement with an accent border
                     ui.painter().rect_filled(
                         row_rect,
                         4.0,
-                        Color32::from_rgba_unmultiplied(255, 215, 0, 40) // Gold highlight
+                        theme.accent_alpha(40) // Accent highlight
                     );
                     ui.painter().rect_stroke(
                         row_rect,
                         4.0,
-                        egui::Stroke::new(2.0, Color32::from_rgb(255, 215, 0)),
+                        egui::Stroke::new(2.0, theme.accent),
                         egui::epaint::StrokeKind::Inside,
                     );
                     // Scroll to this row only if we haven't scrolled yet
@@ -95,6 +211,15 @@ pub fn render_achievements_list<P: GamesTablePlatform>(ui: &mut Ui, platform: &m
                         ui.visuals().faint_bg_color
                     );
                 }
+
+                // Right-click anywhere on the row (sensed below the row's own widgets)
+                ui.interact(row_rect, ui.id().with(("achievement_row", apiname)), egui::Sense::click())
+                    .context_menu(|ui| {
+                        if ui.button(format!("{} {}", regular::COPY, tr("table.copy_achievement_name"))).clicked() {
+                            ui.ctx().copy_text(name.clone());
+                            ui.close();
+                        }
+                    });
                 
                 // Add top padding for the row content
                 ui.add_space(2.0);
@@ -110,11 +235,11 @@ pub fn render_achievements_list<P: GamesTablePlatform>(ui: &mut Ui, platform: &m
                     
                     // Show unlock date on hover (instant, no delay)
                     if let Some(unlock_dt) = unlocktime {
-                        instant_tooltip(&icon_response, unlock_dt.format("%Y-%m-%d").to_string());
+                        instant_tooltip(&icon_response, time.format_achievement(*unlock_dt));
                     }
                     
                     let name_text = if *achieved {
-                        RichText::new(name).color(Color32::WHITE)
+                        RichText::new(name).color(ui.visuals().strong_text_color())
                     } else {
                         RichText::new(name).color(Color32::DARK_GRAY)
                     };
@@ -134,24 +259,63 @@ pub fn render_achievements_list<P: GamesTablePlatform>(ui: &mut Ui, platform: &m
                             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                                 // Show compact average rating (read-only)
                                 // Use average if available, otherwise show user's own rating
-                                let (display_rating, count) = if let Some((avg, cnt)) = avg_rating_data {
-                                    (Some(avg.round() as u8), Some(cnt))
+                                let (display_rating, count, votes) = if let Some((avg, cnt)) = avg_rating_data {
+                                    (Some(avg.round() as u8), Some(cnt), rating_votes.as_ref())
                                 } else {
-                                    (user_rating, None)
+                                    (user_rating, None, None)
                                 };
-                                super::ratings::render_compact_avg_rating(ui, display_rating, count);
+                                super::ratings::render_compact_avg_rating(ui, display_rating, count, votes);
+                                render_global_percent(ui, appid, *global_percent, rarity.get(apiname));
+                                if let Some(ach_comments) = ach_comments {
+                                    let button = ui.small_button(format!("{} {}", regular::CHAT_CIRCLE, ach_comments.len()));
+                                    egui::Popup::from_toggle_button_response(&button).show(|ui| {
+                                        ui.set_max_width(360.0);
+                                        for comment in ach_comments {
+                                            ui.horizontal(|ui| {
+                                                ui.label(RichText::new(comment.display_name.as_deref().unwrap_or(&comment.steam_id)).strong());
+                                                ui.label(RichText::new(time.format_achievement(comment.created_at)).weak().small());
+                                                if is_authenticated {
+                                                    if platform.is_comment_reported(comment.id) {
+                                                        ui.label(RichText::new(tr("comments.reported")).weak().small());
+                                                    } else if ui.small_button(regular::FLAG.to_string()).on_hover_text(tr("comments.report")).clicked() {
+                                                        report = Some(comment.id);
+                                                    }
+                                                }
+                                            });
+                                            ui.label(&comment.comment);
+                                            ui.separator();
+                                        }
+                                    });
+                                }
                             });
                         });
-                        // Description below, full width
-                        if !description_text.is_empty() {
+                        // Description below, full width (a placeholder to click for spoilers)
+                        if !description_text.is_empty() && *concealed {
+                            let placeholder = RichText::new(format!("{} {}", regular::EYE_SLASH, tr("table.spoiler_hidden")))
+                                .italics()
+                                .color(desc_color);
+                            let response = ui.add(egui::Label::new(placeholder).sense(egui::Sense::click()))
+                                .on_hover_cursor(egui::CursorIcon::PointingHand);
+                            if response.clicked() {
+                                reveal = Some(apiname.clone());
+                            }
+                        } else if !description_text.is_empty() {
                             ui.label(RichText::new(description_text).color(desc_color));
                         }
                     });
                 });
             }
         });
+
+        if let Some(comment_id) = report {
+            platform.report_comment(comment_id);
+        }
+        if let Some(apiname) = reveal {
+            revealed.insert(apiname);
+        }
+        ui.data_mut(|d| d.insert_temp(revealed_id, revealed));
     } else {
         ui.spinner();
-        ui.label("Loading achievements...");
+        ui.label(tr("achievements.loading"));
     }
 }
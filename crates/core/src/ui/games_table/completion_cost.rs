@@ -0,0 +1,138 @@
+//! "What does 100% cost?" breakdown shown when hovering the % column
+
+use egui::{Color32, RichText, Ui};
+use egui_phosphor::regular;
+use super::platform::GamesTablePlatform;
+use super::ratings::{difficulty_color, difficulty_label};
+use crate::{tr, tr_args, Game};
+
+/// Description phrases that hint an achievement can be permanently missed
+const MISSABLE_HINTS: &[&str] = &[
+    "missable",
+    "point of no return",
+    "before finishing",
+    "before completing",
+    "before the end",
+    "single playthrough",
+    "one playthrough",
+    "without dying",
+];
+
+/// Everything known about what it takes to reach 100% in a game
+#[derive(Debug, Clone, Default)]
+pub struct CompletionCost {
+    pub remaining: i32,
+    pub total: i32,
+    /// Completionist hours left according to TTB data (user reports preferred over HLTB)
+    pub ttb_hours_left: Option<f32>,
+    /// Hours left at the user's own pace so far (playtime per unlocked achievement)
+    pub pace_hours_left: Option<f32>,
+    /// Hardest locked achievement by community rating (name, avg rating)
+    pub hardest_remaining: Option<(String, f32)>,
+    /// Locked achievements whose description suggests they can be missed
+    pub missable: Vec<String>,
+    /// Whether the achievement list was loaded (rating/missable info needs it)
+    pub achievements_loaded: bool,
+}
+
+/// Assemble the completion cost from the game row and whatever the platform has cached
+pub fn completion_cost<P: GamesTablePlatform>(platform: &P, game: &Game) -> Option<CompletionCost> {
+    let (Some(unlocked), Some(total)) = (game.achievements_unlocked, game.achievements_total) else {
+        return None;
+    };
+    if total <= 0 {
+        return None;
+    }
+    let remaining = (total - unlocked).max(0);
+    let played_hours = game.playtime_forever as f32 / 60.0;
+
+    let completionist_hours = game
+        .my_ttb_completionist_seconds
+        .or(game.avg_user_ttb_completionist_seconds)
+        .map(|s| s as f32 / 3600.0)
+        .or_else(|| platform.get_ttb_times(game.appid).and_then(|t| t.completionist));
+    let ttb_hours_left = completionist_hours.map(|h| (h - played_hours).max(0.0));
+
+    let pace_hours_left = (unlocked > 0 && game.playtime_forever > 0)
+        .then(|| played_hours / unlocked as f32 * remaining as f32);
+
+    let mut cost = CompletionCost {
+        remaining,
+        total,
+        ttb_hours_left,
+        pace_hours_left,
+        ..Default::default()
+    };
+
+    if let Some(achievements) = platform.get_cached_achievements(game.appid) {
+        cost.achievements_loaded = true;
+        let locked = achievements.iter().filter(|a| !a.achieved);
+        for ach in locked {
+            if let Some((avg, _)) = platform.get_achievement_avg_rating(game.appid, &ach.apiname) {
+                if cost.hardest_remaining.as_ref().map(|(_, best)| avg > *best).unwrap_or(true) {
+                    cost.hardest_remaining = Some((ach.name.clone(), avg));
+                }
+            }
+            let description = ach.description.as_deref().unwrap_or("").to_lowercase();
+            if MISSABLE_HINTS.iter().any(|hint| description.contains(hint)) {
+                cost.missable.push(ach.name.clone());
+            }
+        }
+    }
+
+    Some(cost)
+}
+
+/// Format hours compactly ("45m", "12h", "120h")
+fn format_hours(hours: f32) -> String {
+    if hours < 1.0 {
+        format!("{:.0}m", hours * 60.0)
+    } else {
+        format!("{:.0}h", hours)
+    }
+}
+
+/// Render the completion cost breakdown (tooltip contents)
+pub fn render_completion_cost(ui: &mut Ui, cost: &CompletionCost) {
+    if cost.remaining == 0 {
+        ui.label(RichText::new(format!("{} {}", regular::TROPHY, tr_args("cost.all_unlocked", &[("total", &cost.total)]))).strong());
+        return;
+    }
+
+    ui.label(RichText::new(tr_args("cost.remaining", &[("remaining", &cost.remaining), ("total", &cost.total)])).strong());
+
+    match cost.ttb_hours_left {
+        Some(hours) => ui.label(format!("{} {}", regular::HOURGLASS, tr_args("cost.ttb_left", &[("time", &format_hours(hours))]))),
+        None => ui.label(RichText::new(format!("{} {}", regular::HOURGLASS, tr("cost.no_ttb"))).weak()),
+    };
+    if let Some(hours) = cost.pace_hours_left {
+        ui.label(format!("{} {}", regular::TIMER, tr_args("cost.pace_left", &[("time", &format_hours(hours))])));
+    }
+
+    if !cost.achievements_loaded {
+        ui.label(RichText::new(tr("cost.expand_hint")).weak().small());
+        return;
+    }
+
+    if let Some((name, avg)) = &cost.hardest_remaining {
+        let level = avg.round().clamp(1.0, 5.0) as u8;
+        ui.horizontal(|ui| {
+            ui.label(format!("{} {}", regular::MOUNTAINS, tr("cost.hardest_left")));
+            ui.label(RichText::new(name).strong());
+            ui.label(RichText::new(format!("{}  ({:.1})", difficulty_label(level), avg)).color(difficulty_color(level)));
+        });
+    }
+
+    if !cost.missable.is_empty() {
+        ui.label(
+            RichText::new(format!("{} {}", regular::WARNING, tr_args("cost.missable", &[("count", &cost.missable.len())])))
+                .color(Color32::from_rgb(230, 140, 50)),
+        );
+        for name in cost.missable.iter().take(5) {
+            ui.label(format!("  • {}", name));
+        }
+        if cost.missable.len() > 5 {
+            ui.label(RichText::new(format!("  {}", tr_args("cost.and_more", &[("count", &(cost.missable.len() - 5))]))).weak());
+        }
+    }
+}
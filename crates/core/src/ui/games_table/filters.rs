@@ -3,8 +3,50 @@
 use egui::{self, Color32, RichText, Ui};
 use egui_phosphor::regular;
 use super::platform::GamesTablePlatform;
-use super::types::TriFilter;
+use super::types::{RangeFilter, TriFilter};
 use super::super::instant_tooltip;
+use super::super::{render_tag_dropdown, render_tag_filter_chips, TagChipAction};
+use crate::{tr, GroupBy, TagMatchMode};
+
+/// Upper end of the range filter sliders in hours (a max at this value means no max)
+const RANGE_SLIDER_MAX_HOURS: f32 = 200.0;
+
+/// Compact range filter: a button showing the current range that opens min/max sliders.
+/// Returns the new range when it changed.
+fn render_range_filter(ui: &mut Ui, short_label: &str, tooltip: &str, range: RangeFilter) -> Option<RangeFilter> {
+    let response = ui.menu_button(format!("{}: {}", short_label, range.label()), |ui| {
+        ui.label(RichText::new(tooltip).strong());
+        let mut min = range.min.unwrap_or(0.0);
+        let mut max = range.max.unwrap_or(RANGE_SLIDER_MAX_HOURS);
+        let min_changed = ui.add(egui::Slider::new(&mut min, 0.0..=RANGE_SLIDER_MAX_HOURS)
+            .logarithmic(true)
+            .step_by(1.0)
+            .custom_formatter(|v, _| if v <= 0.0 { tr("common.any").to_string() } else { format!("{}h", v) })
+            .text(tr("filter.min"))).changed();
+        let max_changed = ui.add(egui::Slider::new(&mut max, 0.0..=RANGE_SLIDER_MAX_HOURS)
+            .logarithmic(true)
+            .step_by(1.0)
+            .custom_formatter(|v, _| if v >= RANGE_SLIDER_MAX_HOURS as f64 { tr("common.any").to_string() } else { format!("{}h", v) })
+            .text(tr("filter.max"))).changed();
+        // Keep min <= max by moving the other handle
+        if min_changed && min > max {
+            max = min;
+        } else if max_changed && max < min {
+            min = max;
+        }
+
+        let mut new_range = RangeFilter {
+            min: (min > 0.0).then_some(min),
+            max: (max < RANGE_SLIDER_MAX_HOURS).then_some(max),
+        };
+        if ui.add_enabled(range.is_active(), egui::Button::new(tr("common.any"))).clicked() {
+            new_range = RangeFilter::default();
+        }
+        (new_range != range).then_some(new_range)
+    });
+    instant_tooltip(&response.response, tooltip);
+    response.inner.flatten()
+}
 
 /// Render the filter bar above the games table
 pub fn render_filter_bar<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P) {
@@ -12,7 +54,7 @@ pub fn render_filter_bar<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P) {
     ui.horizontal(|ui| {
         let mut filter_name = platform.filter_name().to_string();
         let response = ui.add(egui::TextEdit::singleline(&mut filter_name)
-            .hint_text("Search by name...")
+            .hint_text(tr("filter.search_hint"))
             .desired_width(150.0));
         if response.changed() {
             platform.set_filter_name(filter_name);
@@ -21,79 +63,134 @@ pub fn render_filter_bar<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P) {
         ui.add_space(10.0);
 
         // Achievements filter - tri-state toggle button (short label)
-        let ach_label = format!("A: {}", platform.filter_achievements().label("Yes", "No"));
+        let ach_label = format!("A: {}", platform.filter_achievements().label(tr("common.yes"), tr("common.no")));
         let ach_btn = ui.button(&ach_label);
         if ach_btn.clicked() {
             let next = platform.filter_achievements().cycle();
             platform.set_filter_achievements(next);
         }
-        instant_tooltip(&ach_btn, "Achievements");
+        instant_tooltip(&ach_btn, tr("filter.achievements"));
 
         // Playtime filter - tri-state toggle button (short label)
-        let play_label = format!("P: {}", platform.filter_playtime().label("Yes", "No"));
+        let play_label = format!("P: {}", platform.filter_playtime().label(tr("common.yes"), tr("common.no")));
         let play_btn = ui.button(&play_label);
         if play_btn.clicked() {
             let next = platform.filter_playtime().cycle();
             platform.set_filter_playtime(next);
         }
-        instant_tooltip(&play_btn, "Played");
+        instant_tooltip(&play_btn, tr("filter.played"));
+        if let Some(range) = render_range_filter(ui, tr("filter.hours_short"), tr("filter.hours_played"), platform.filter_playtime_range()) {
+            platform.set_filter_playtime_range(range);
+        }
 
         // Installed filter - only show on desktop (platform that can detect installed games)
         if platform.can_detect_installed() {
-            let inst_label = format!("I: {}", platform.filter_installed().label("Yes", "No"));
+            let inst_label = format!("I: {}", platform.filter_installed().label(tr("common.yes"), tr("common.no")));
             let inst_btn = ui.button(&inst_label);
             if inst_btn.clicked() {
                 let next = platform.filter_installed().cycle();
                 platform.set_filter_installed(next);
             }
-            instant_tooltip(&inst_btn, "Installed");
+            instant_tooltip(&inst_btn, tr("filter.installed"));
+        }
+
+        // App type filter - games vs soundtracks, tools and demos (platforms that fetch app types)
+        if platform.has_app_types() {
+            let type_label = format!("G: {}", platform.filter_app_type().label(tr("filter.games"), tr("filter.other")));
+            let type_btn = ui.button(&type_label);
+            if type_btn.clicked() {
+                let next = platform.filter_app_type().cycle();
+                platform.set_filter_app_type(next);
+            }
+            instant_tooltip(&type_btn, tr("filter.app_type_tooltip"));
         }
 
         // TTB filter - only show if platform shows TTB column
         if platform.show_ttb_column() {
-            let ttb_label = format!("T: {}", platform.filter_ttb().label("Yes", "No"));
+            let ttb_label = format!("T: {}", platform.filter_ttb().label(tr("common.yes"), tr("common.no")));
             let ttb_btn = ui.button(&ttb_label);
             if ttb_btn.clicked() {
                 let next = platform.filter_ttb().cycle();
                 platform.set_filter_ttb(next);
             }
-            instant_tooltip(&ttb_btn, "Time to Beat");
+            instant_tooltip(&ttb_btn, tr("filter.ttb_tooltip"));
+            if let Some(range) = render_range_filter(ui, "TTB", tr("filter.ttb_range_tooltip"), platform.filter_ttb_range()) {
+                platform.set_filter_ttb_range(range);
+            }
         }
 
         // Hidden filter - tri-state toggle (All, Show Hidden, Hide Hidden)
-        let hidden_label = format!("H: {}", platform.filter_hidden().label("Hidden", "Visible"));
+        let hidden_label = format!("H: {}", platform.filter_hidden().label(tr("filter.hidden"), tr("filter.visible")));
         let hidden_btn = ui.button(&hidden_label);
         if hidden_btn.clicked() {
             let next = platform.filter_hidden().cycle();
             platform.set_filter_hidden(next);
         }
-        instant_tooltip(&hidden_btn, "Private Games");
+        instant_tooltip(&hidden_btn, tr("filter.hidden_tooltip"));
+
+        // Private filter - games marked private on Steam (unless the config hides them entirely)
+        if !platform.hide_private_games() {
+            let private_label = format!("P: {}", platform.filter_private().label(tr("filter.private"), tr("filter.public")));
+            let private_btn = ui.button(&private_label);
+            if private_btn.clicked() {
+                let next = platform.filter_private().cycle();
+                platform.set_filter_private(next);
+            }
+            instant_tooltip(&private_btn, tr("filter.private_tooltip"));
+        }
 
         // Clear filters button
         let has_filters = !platform.filter_name().is_empty()
             || platform.filter_achievements() != TriFilter::All
             || platform.filter_playtime() != TriFilter::All
+            || platform.filter_playtime_range().is_active()
             || (platform.can_detect_installed() && platform.filter_installed() != TriFilter::All)
+            || (platform.has_app_types() && platform.filter_app_type() != TriFilter::All)
             || (platform.show_ttb_column() && platform.filter_ttb() != TriFilter::All)
+            || (platform.show_ttb_column() && platform.filter_ttb_range().is_active())
             || platform.filter_hidden() != TriFilter::Without  // Default is "Without" (hide hidden)
-            || !platform.filter_tags().is_empty();
+            || (!platform.hide_private_games() && platform.filter_private() != TriFilter::All)
+            || !platform.filter_tags().is_empty()
+            || !platform.filter_excluded_tags().is_empty();
 
         if !has_filters {
-            ui.add_enabled(false, egui::Button::new("Clear"));
-        } else if ui.button("Clear").clicked() {
+            ui.add_enabled(false, egui::Button::new(tr("common.clear")));
+        } else if ui.button(tr("common.clear")).clicked() {
             platform.set_filter_name(String::new());
             platform.set_filter_achievements(TriFilter::All);
             platform.set_filter_playtime(TriFilter::All);
+            platform.set_filter_playtime_range(RangeFilter::default());
             if platform.can_detect_installed() {
                 platform.set_filter_installed(TriFilter::All);
             }
+            if platform.has_app_types() {
+                platform.set_filter_app_type(TriFilter::All);
+            }
             if platform.show_ttb_column() {
                 platform.set_filter_ttb(TriFilter::All);
+                platform.set_filter_ttb_range(RangeFilter::default());
             }
             platform.set_filter_hidden(TriFilter::Without);  // Reset to default: hide hidden
+            platform.set_filter_private(TriFilter::All);
             platform.set_filter_tags(Vec::new());
+            platform.set_filter_excluded_tags(Vec::new());
             platform.set_tag_search_input(String::new());
         }
+
+        // Grouping isn't a filter, so Clear leaves it alone
+        ui.separator();
+        let mut group_by = platform.group_by();
+        let combo = egui::ComboBox::from_id_salt("games_group_by")
+            .selected_text(format!("{} {}", regular::STACK, group_by.label()))
+            .show_ui(ui, |ui| {
+                for option in GroupBy::all() {
+                    ui.selectable_value(&mut group_by, *option, option.label());
+                }
+            });
+        instant_tooltip(&combo.response, tr("group.tooltip"));
+        if group_by != platform.group_by() {
+            platform.set_group_by(group_by);
+        }
     });
 
     // Second row: Tags filter with searchable dropdown and selected tag chips
@@ -105,102 +202,47 @@ pub fn render_filter_bar<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P) {
         ui.horizontal(|ui| {
             // Searchable tag dropdown
             let mut search_input = platform.tag_search_input().to_string();
-            let mut current_tags: Vec<String> = platform.filter_tags().to_vec();
-
-            // Filter available tags based on search input and exclude already selected
-            let search_lower = search_input.to_lowercase();
-            let filtered_tags: Vec<String> = available_tags.iter()
-                .filter(|tag| !current_tags.contains(tag))
-                .filter(|tag| search_lower.is_empty() || tag.to_lowercase().contains(&search_lower))
-                .take(20)
-                .cloned()
-                .collect();
-
-            // Popup state management
-            let popup_open_id = egui::Id::new("tag_popup_open_state");
-            let mut popup_open = ui.ctx().memory(|mem| mem.data.get_temp::<bool>(popup_open_id).unwrap_or(false));
-
-            // Text input with dropdown button
-            let text_response = ui.add(
-                egui::TextEdit::singleline(&mut search_input)
-                    .hint_text("Search tags...")
-                    .desired_width(120.0)
-            );
+            let mut selected_index = platform.tag_search_selected_index();
+            let current_tags: Vec<String> = platform.filter_tags().to_vec();
+            let excluded_tags: Vec<String> = platform.filter_excluded_tags().to_vec();
+            // Tags already in the filter either way aren't offered again
+            let used_tags: Vec<String> = current_tags.iter().chain(&excluded_tags).cloned().collect();
 
-            // Toggle button to open/close dropdown
-            let toggle_btn = ui.button(if popup_open { regular::CARET_UP } else { regular::CARET_DOWN });
-            if toggle_btn.clicked() {
-                popup_open = !popup_open;
+            let picked = render_tag_dropdown(
+                ui,
+                "tag_filter_search",
+                &mut search_input,
+                &mut selected_index,
+                &available_tags,
+                &used_tags,
+                tr("filter.search_tags_hint"),
+            );
+            if search_input != platform.tag_search_input() {
+                platform.set_tag_search_input(search_input);
             }
-
-            // Open popup when text field gains focus
-            if text_response.gained_focus() {
-                popup_open = true;
+            if selected_index != platform.tag_search_selected_index() {
+                platform.set_tag_search_selected_index(selected_index);
             }
-
-            // Update search input in platform
-            if text_response.changed() {
-                platform.set_tag_search_input(search_input.clone());
-                popup_open = true; // Open when typing
-            }
-
-            // Track if we selected a tag this frame
-            let mut selected_tag: Option<String> = None;
-
-            // Show dropdown as Area when open
-            if popup_open && !filtered_tags.is_empty() {
-                let area_response = egui::Area::new(egui::Id::new("tag_dropdown_area"))
-                    .order(egui::Order::Foreground)
-                    .fixed_pos(text_response.rect.left_bottom())
-                    .show(ui.ctx(), |ui| {
-                        egui::Frame::popup(ui.style()).show(ui, |ui| {
-                            ui.set_min_width(340.0);
-                            ui.style_mut().interaction.selectable_labels = true;
-                            egui::ScrollArea::vertical()
-                                .max_height(450.0)
-                                .show(ui, |ui| {
-                                    ui.with_layout(egui::Layout::top_down_justified(egui::Align::LEFT), |ui| {
-                                        for tag in &filtered_tags {
-                                            let response = ui.selectable_label(false, tag);
-                                            if response.clicked() {
-                                                selected_tag = Some(tag.clone());
-                                            }
-                                        }
-                                    });
-                                });
-                        });
-                    });
-
-                // Close popup if clicked outside both text field and dropdown area
-                if ui.input(|i| i.pointer.any_click()) {
-                    let click_pos = ui.input(|i| i.pointer.interact_pos());
-                    if let Some(pos) = click_pos {
-                        let in_text = text_response.rect.contains(pos);
-                        let in_toggle = toggle_btn.rect.contains(pos);
-                        let in_area = area_response.response.rect.contains(pos);
-                        if !in_text && !in_toggle && !in_area {
-                            popup_open = false;
-                        }
-                    }
-                }
-            } else if popup_open && filtered_tags.is_empty() {
-                // Close if no tags match
-                popup_open = false;
+            if let Some(tag) = picked {
+                let mut new_tags = current_tags.clone();
+                new_tags.push(tag);
+                platform.set_filter_tags(new_tags);
             }
 
-            // Handle tag selection
-            if let Some(tag) = selected_tag {
-                if !current_tags.contains(&tag) {
-                    current_tags.push(tag);
-                    platform.set_filter_tags(current_tags.clone());
+            // AND/OR only matters with more than one included tag
+            if current_tags.len() > 1 {
+                let mode = platform.tag_match_mode();
+                let (label, hint) = match mode {
+                    TagMatchMode::All => (tr("filter.tags_all"), tr("filter.tags_all_tooltip")),
+                    TagMatchMode::Any => (tr("filter.tags_any"), tr("filter.tags_any_tooltip")),
+                };
+                let response = ui.button(label);
+                instant_tooltip(&response, hint);
+                if response.clicked() {
+                    platform.set_tag_match_mode(mode.toggled());
                 }
-                platform.set_tag_search_input(String::new());
-                popup_open = false;
             }
 
-            // Store popup state
-            ui.ctx().memory_mut(|mem| mem.data.insert_temp(popup_open_id, popup_open));
-
             ui.add_space(8.0);
 
             // Pills area with subtle background - match height of text input
@@ -211,30 +253,29 @@ pub fn render_filter_bar<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P) {
                 Color32::from_rgba_unmultiplied(40, 40, 50, 100)
             );
 
-            // Display selected tags as removable chips (inline)
-            let mut tags_to_remove: Vec<String> = Vec::new();
-
+            // Display selected tags as chips (inline), click flips include/exclude
             ui.spacing_mut().item_spacing.x = 4.0;
-            for tag in &current_tags {
-                // Tag chip with X button combined
-                let chip_text = format!("{} ×", tag);
-                let chip_response = ui.add(
-                    egui::Button::new(RichText::new(&chip_text).size(11.0))
-                        .small()
-                        .fill(Color32::from_rgb(60, 80, 100))
-                );
-
-                if chip_response.clicked() || chip_response.secondary_clicked() {
-                    tags_to_remove.push(tag.clone());
+            match render_tag_filter_chips(ui, &current_tags, &excluded_tags) {
+                Some(TagChipAction::Toggle(tag)) => {
+                    let (mut include, mut exclude) = (current_tags, excluded_tags);
+                    if include.contains(&tag) {
+                        include.retain(|t| *t != tag);
+                        exclude.push(tag);
+                    } else {
+                        exclude.retain(|t| *t != tag);
+                        include.push(tag);
+                    }
+                    platform.set_filter_tags(include);
+                    platform.set_filter_excluded_tags(exclude);
                 }
-            }
-
-            // Remove tags that were clicked
-            if !tags_to_remove.is_empty() {
-                let new_tags: Vec<String> = current_tags.into_iter()
-                    .filter(|t| !tags_to_remove.contains(t))
-                    .collect();
-                platform.set_filter_tags(new_tags);
+                Some(TagChipAction::Remove(tag)) => {
+                    if current_tags.contains(&tag) {
+                        platform.set_filter_tags(current_tags.into_iter().filter(|t| *t != tag).collect());
+                    } else {
+                        platform.set_filter_excluded_tags(excluded_tags.into_iter().filter(|t| *t != tag).collect());
+                    }
+                }
+                None => {}
             }
         });
     }
@@ -1,8 +1,8 @@
 //! Helper functions for games table
 
 use super::platform::GamesTablePlatform;
-use super::types::{SortColumn, SortOrder};
-use crate::Game;
+use super::types::{AchievementSort, SortColumn, SortOrder, SortTieBreaker};
+use crate::{matches_tag_filter, tr, tr_args, Game, GameAchievement};
 
 /// Format a Unix timestamp as YYYY-MM-DD
 pub fn format_timestamp(ts: u32) -> String {
@@ -15,16 +15,16 @@ pub fn format_timestamp(ts: u32) -> String {
 pub fn format_ttb_times(ttb: &crate::TtbTimes) -> String {
     let mut parts = Vec::new();
     if let Some(main) = ttb.main {
-        parts.push(format!("Main: {:.0}h", main));
+        parts.push(tr_args("table.ttb_main", &[("time", &format!("{:.0}h", main))]));
     }
     if let Some(extra) = ttb.main_extra {
-        parts.push(format!("+Extra: {:.0}h", extra));
+        parts.push(tr_args("table.ttb_extra", &[("time", &format!("{:.0}h", extra))]));
     }
     if let Some(comp) = ttb.completionist {
-        parts.push(format!("100%: {:.0}h", comp));
+        parts.push(tr_args("table.ttb_completionist", &[("time", &format!("{:.0}h", comp))]));
     }
     if parts.is_empty() {
-        "<no data>".to_string()
+        tr("table.ttb_no_data").to_string()
     } else {
         parts.join(" | ")
     }
@@ -46,6 +46,8 @@ pub fn sort_indicator(platform: &impl GamesTablePlatform, column: SortColumn) ->
 pub fn get_filtered_indices(platform: &impl GamesTablePlatform) -> Vec<usize> {
     let filter_text = platform.filter_name();
     let filter_tags = platform.filter_tags();
+    let excluded_tags = platform.filter_excluded_tags();
+    let tag_match_mode = platform.tag_match_mode();
 
     // Check if filtering by appid (starts with #)
     let appid_filter: Option<u64> = if filter_text.starts_with('#') {
@@ -58,6 +60,10 @@ pub fn get_filtered_indices(platform: &impl GamesTablePlatform) -> Vec<usize> {
     platform.games().iter()
         .enumerate()
         .filter(|(_, g)| {
+            // Games played without owning them are listed in their own section
+            if !g.owned {
+                return false;
+            }
             // Name or AppID filter
             if let Some(appid) = appid_filter {
                 // Filter by appid - must match exactly or be a prefix
@@ -83,6 +89,11 @@ pub fn get_filtered_indices(platform: &impl GamesTablePlatform) -> Vec<usize> {
                 super::types::TriFilter::With => if !has_playtime { return false; }
                 super::types::TriFilter::Without => if has_playtime { return false; }
             }
+            // Playtime range filter (hours)
+            let playtime_range = platform.filter_playtime_range();
+            if playtime_range.is_active() && !playtime_range.contains(g.playtime_forever as f32 / 60.0) {
+                return false;
+            }
             // Installed filter (desktop only - if platform can detect installed games)
             if platform.can_detect_installed() {
                 let is_installed = platform.is_game_installed(g.appid);
@@ -92,6 +103,14 @@ pub fn get_filtered_indices(platform: &impl GamesTablePlatform) -> Vec<usize> {
                     super::types::TriFilter::Without => if is_installed { return false; }
                 }
             }
+            // App type filter (games vs soundtracks, tools, demos)
+            if platform.has_app_types() {
+                match platform.filter_app_type() {
+                    super::types::TriFilter::All => {}
+                    super::types::TriFilter::With => if !g.is_game_app() { return false; }
+                    super::types::TriFilter::Without => if g.is_game_app() { return false; }
+                }
+            }
             // TTB filter (desktop only - if platform shows TTB column)
             if platform.show_ttb_column() {
                 let has_ttb = platform.get_ttb_times(g.appid).is_some();
@@ -100,19 +119,32 @@ pub fn get_filtered_indices(platform: &impl GamesTablePlatform) -> Vec<usize> {
                     super::types::TriFilter::With => if !has_ttb { return false; }
                     super::types::TriFilter::Without => if has_ttb { return false; }
                 }
-            }
-            // Tag filter - only show games that have ALL selected tags
-            if !filter_tags.is_empty() {
-                for tag in filter_tags {
-                    if platform.get_tag_vote_count(g.appid, tag).is_none() {
+                // TTB range filter - games without a main story time don't match
+                let ttb_range = platform.filter_ttb_range();
+                if ttb_range.is_active() {
+                    let main = platform.get_ttb_times(g.appid).and_then(|t| t.main);
+                    if !main.is_some_and(|hours| ttb_range.contains(hours)) {
                         return false;
                     }
                 }
             }
+            // Tag filter - included tags (all or any of them), never an excluded one
+            let has_tag = |tag: &str| platform.get_tag_vote_count(g.appid, tag).is_some();
+            if !matches_tag_filter(filter_tags, excluded_tags, tag_match_mode, has_tag) {
+                return false;
+            }
 
             // Hide private games if the config setting is enabled (independent of hidden filter)
-            if platform.hide_private_games() && g.steam_private {
-                return false;
+            if platform.hide_private_games() {
+                if g.steam_private {
+                    return false;
+                }
+            } else {
+                match platform.filter_private() {
+                    super::types::TriFilter::All => {}
+                    super::types::TriFilter::With => if !g.steam_private { return false; }
+                    super::types::TriFilter::Without => if g.steam_private { return false; }
+                }
             }
 
             // Hidden filter - hide games that are hidden (manually or from Steam)
@@ -129,48 +161,191 @@ pub fn get_filtered_indices(platform: &impl GamesTablePlatform) -> Vec<usize> {
         .collect()
 }
 
-/// Sort games in place based on current sort settings
-pub fn sort_games(games: &mut [Game], sort_column: SortColumn, sort_order: SortOrder) {
-    match sort_column {
-        SortColumn::Name => {
-            games.sort_by(|a, b| {
-                let cmp = a.name.to_lowercase().cmp(&b.name.to_lowercase());
-                if sort_order == SortOrder::Descending { cmp.reverse() } else { cmp }
-            });
-        }
-        SortColumn::LastPlayed => {
-            games.sort_by(|a, b| {
-                let cmp = b.rtime_last_played.cmp(&a.rtime_last_played);
-                if sort_order == SortOrder::Descending { cmp.reverse() } else { cmp }
-            });
-        }
-        SortColumn::Playtime => {
-            games.sort_by(|a, b| {
-                let cmp = b.playtime_forever.cmp(&a.playtime_forever);
-                if sort_order == SortOrder::Descending { cmp.reverse() } else { cmp }
-            });
-        }
-        SortColumn::AchievementsTotal => {
-            games.sort_by(|a, b| {
-                let cmp = b.achievements_total.cmp(&a.achievements_total);
-                if sort_order == SortOrder::Descending { cmp.reverse() } else { cmp }
-            });
-        }
+/// Compare two games by a sort column, then by the tie-breaker so equal rows keep a
+/// stable order (TTB and Votes compare equal - they need platform caches)
+pub fn compare_games(
+    a: &Game,
+    b: &Game,
+    sort_column: SortColumn,
+    sort_order: SortOrder,
+    tie_breaker: SortTieBreaker,
+) -> std::cmp::Ordering {
+    let cmp = match sort_column {
+        SortColumn::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        SortColumn::LastPlayed => b.rtime_last_played.cmp(&a.rtime_last_played),
+        SortColumn::Playtime => b.playtime_forever.cmp(&a.playtime_forever),
+        SortColumn::DeckPlaytime => b.deck_playtime().cmp(&a.deck_playtime()),
+        SortColumn::AchievementsTotal => b.achievements_total.cmp(&a.achievements_total),
         SortColumn::AchievementsPercent => {
-            games.sort_by(|a, b| {
-                let a_pct = a.completion_percent().unwrap_or(-1.0);
-                let b_pct = b.completion_percent().unwrap_or(-1.0);
-                let cmp = b_pct.partial_cmp(&a_pct).unwrap_or(std::cmp::Ordering::Equal);
-                if sort_order == SortOrder::Descending { cmp.reverse() } else { cmp }
-            });
+            let a_pct = a.completion_percent().unwrap_or(-1.0);
+            let b_pct = b.completion_percent().unwrap_or(-1.0);
+            b_pct.partial_cmp(&a_pct).unwrap_or(std::cmp::Ordering::Equal)
+        }
+        // Handled by platform-specific code; desktop overrides set_sort for these
+        SortColumn::TimeToBeat | SortColumn::Votes => std::cmp::Ordering::Equal,
+    };
+    let cmp = if sort_order == SortOrder::Descending { cmp.reverse() } else { cmp };
+    cmp.then_with(|| compare_tie_break(a, b, sort_column, tie_breaker))
+}
+
+/// Order of games the sort column ranks equal: the tie-breaker (Auto picks one per column),
+/// then name, then appid so the result never depends on the previous order
+pub fn compare_tie_break(a: &Game, b: &Game, sort_column: SortColumn, tie_breaker: SortTieBreaker) -> std::cmp::Ordering {
+    let key = match tie_breaker {
+        SortTieBreaker::Auto => match sort_column {
+            SortColumn::Playtime | SortColumn::DeckPlaytime => SortTieBreaker::LastPlayed,
+            SortColumn::LastPlayed => SortTieBreaker::Playtime,
+            SortColumn::AchievementsTotal => SortTieBreaker::Completion,
+            _ => SortTieBreaker::Name,
+        },
+        key => key,
+    };
+    let cmp = match key {
+        SortTieBreaker::Auto | SortTieBreaker::Name => std::cmp::Ordering::Equal,
+        SortTieBreaker::LastPlayed => b.rtime_last_played.cmp(&a.rtime_last_played),
+        SortTieBreaker::Playtime => b.playtime_forever.cmp(&a.playtime_forever),
+        SortTieBreaker::Completion => {
+            let a_pct = a.completion_percent().unwrap_or(-1.0);
+            let b_pct = b.completion_percent().unwrap_or(-1.0);
+            b_pct.partial_cmp(&a_pct).unwrap_or(std::cmp::Ordering::Equal)
         }
-        SortColumn::TimeToBeat => {
-            // TTB sorting requires access to platform cache, handled by platform-specific code
-            // This is a no-op here; desktop overrides set_sort to handle TTB
+    };
+    cmp.then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+        .then_with(|| a.appid.cmp(&b.appid))
+}
+
+/// Sort games in place based on current sort settings
+pub fn sort_games(games: &mut [Game], sort_column: SortColumn, sort_order: SortOrder, tie_breaker: SortTieBreaker) {
+    if matches!(sort_column, SortColumn::TimeToBeat | SortColumn::Votes) {
+        return;
+    }
+    games.sort_by(|a, b| compare_games(a, b, sort_column, sort_order, tie_breaker));
+}
+
+/// Compare optional values with `None` last
+fn cmp_none_last(a: Option<f32>, b: Option<f32>) -> std::cmp::Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    }
+}
+
+/// Sort an achievement list for an expanded row.
+/// `difficulty` returns the community average rating of an achievement by apiname.
+pub fn sort_achievements(
+    achievements: &mut [&GameAchievement],
+    sort: AchievementSort,
+    difficulty: impl Fn(&str) -> Option<f32>,
+) {
+    use std::cmp::Ordering;
+    achievements.sort_by(|a, b| {
+        match sort {
+            AchievementSort::UnlockedFirst | AchievementSort::LockedFirst | AchievementSort::UnlockDate => {
+                let locked_first = sort == AchievementSort::LockedFirst;
+                match (a.achieved, b.achieved) {
+                    (true, false) => if locked_first { Ordering::Greater } else { Ordering::Less },
+                    (false, true) => if locked_first { Ordering::Less } else { Ordering::Greater },
+                    (true, true) if sort == AchievementSort::UnlockDate => a.unlocktime.cmp(&b.unlocktime),
+                    (true, true) => b.unlocktime.cmp(&a.unlocktime),
+                    (false, false) => a.name.cmp(&b.name),
+                }
+            }
+            AchievementSort::Rarity => cmp_none_last(a.global_percent, b.global_percent)
+                .then_with(|| a.name.cmp(&b.name)),
+            AchievementSort::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            // Hardest first: negate so the None-last comparison sorts descending
+            AchievementSort::Difficulty => cmp_none_last(difficulty(&a.apiname).map(|d| -d), difficulty(&b.apiname).map(|d| -d))
+                .then_with(|| a.name.cmp(&b.name)),
         }
-        SortColumn::Votes => {
-            // Votes sorting requires access to tags cache, handled by platform-specific code
-            // This is a no-op here; desktop overrides set_sort to handle Votes
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn named(appid: u64, name: &str, unlocked: i32, playtime: u32, last_played: Option<u32>) -> Game {
+        Game {
+            appid,
+            name: name.to_string(),
+            playtime_forever: playtime,
+            rtime_last_played: last_played,
+            img_icon_url: None,
+            added_at: chrono::Utc::now(),
+            achievements_total: Some(100),
+            achievements_unlocked: Some(unlocked),
+            last_achievement_scrape: None,
+            avg_user_ttb_main_seconds: None,
+            avg_user_ttb_extra_seconds: None,
+            avg_user_ttb_completionist_seconds: None,
+            user_ttb_report_count: 0,
+            my_ttb_main_seconds: None,
+            my_ttb_extra_seconds: None,
+            my_ttb_completionist_seconds: None,
+            my_ttb_reported_at: None,
+            hidden: false,
+            steam_hidden: false,
+            steam_private: false,
+            app_type: None,
+            abandoned: false,
+            platform_playtime: None,
+            owned: true,
         }
     }
+
+    fn names(games: &[Game]) -> Vec<&str> {
+        games.iter().map(|g| g.name.as_str()).collect()
+    }
+
+    #[test]
+    fn equal_percent_sorts_by_name_in_both_orders() {
+        let mut games = vec![
+            named(1, "charlie", 0, 0, None),
+            named(2, "Alpha", 0, 0, None),
+            named(3, "Done", 100, 0, None),
+            named(4, "bravo", 0, 0, None),
+        ];
+        sort_games(&mut games, SortColumn::AchievementsPercent, SortOrder::Ascending, SortTieBreaker::Auto);
+        assert_eq!(names(&games), ["Done", "Alpha", "bravo", "charlie"]);
+        sort_games(&mut games, SortColumn::AchievementsPercent, SortOrder::Descending, SortTieBreaker::Auto);
+        assert_eq!(names(&games), ["Alpha", "bravo", "charlie", "Done"]);
+    }
+
+    #[test]
+    fn equal_playtime_sorts_by_last_played() {
+        let mut games = vec![
+            named(1, "Old", 0, 60, Some(1_000)),
+            named(2, "Recent", 0, 60, Some(2_000)),
+            named(3, "Never", 0, 60, None),
+            named(4, "Most played", 0, 600, Some(500)),
+        ];
+        sort_games(&mut games, SortColumn::Playtime, SortOrder::Ascending, SortTieBreaker::Auto);
+        assert_eq!(names(&games), ["Most played", "Recent", "Old", "Never"]);
+    }
+
+    #[test]
+    fn configured_tie_breaker_overrides_auto() {
+        let mut games = vec![
+            named(1, "Alpha", 0, 10, None),
+            named(2, "Bravo", 0, 30, None),
+            named(3, "Charlie", 0, 20, None),
+        ];
+        sort_games(&mut games, SortColumn::AchievementsPercent, SortOrder::Ascending, SortTieBreaker::Playtime);
+        assert_eq!(names(&games), ["Bravo", "Charlie", "Alpha"]);
+        sort_games(&mut games, SortColumn::AchievementsPercent, SortOrder::Ascending, SortTieBreaker::Name);
+        assert_eq!(names(&games), ["Alpha", "Bravo", "Charlie"]);
+    }
+
+    #[test]
+    fn sort_is_independent_of_previous_order() {
+        let library: Vec<Game> = (0..50).map(|appid| named(appid, &format!("Same {}", appid % 5), 0, 0, None)).collect();
+        let mut forward = library.clone();
+        let mut reversed: Vec<Game> = library.into_iter().rev().collect();
+        sort_games(&mut forward, SortColumn::AchievementsPercent, SortOrder::Descending, SortTieBreaker::Auto);
+        sort_games(&mut reversed, SortColumn::AchievementsPercent, SortOrder::Descending, SortTieBreaker::Auto);
+        let order = |games: &[Game]| games.iter().map(|g| g.appid).collect::<Vec<_>>();
+        assert_eq!(order(&forward), order(&reversed));
+    }
 }
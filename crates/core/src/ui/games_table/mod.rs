@@ -10,11 +10,19 @@ mod filters;
 mod table;
 mod achievements;
 mod ratings;
+mod unlock_graph;
+mod completion_cost;
+mod unowned;
+mod rarity;
 
-pub use types::{SortColumn, SortOrder, TriFilter};
+pub use types::{AchievementSort, RangeFilter, SortColumn, SortOrder, SortTieBreaker, TriFilter};
 pub use platform::GamesTablePlatform;
-pub use helpers::{format_timestamp, format_ttb_times, sort_indicator, get_filtered_indices, sort_games};
+pub use helpers::{format_timestamp, format_ttb_times, sort_indicator, get_filtered_indices, sort_games, compare_games, compare_tie_break, sort_achievements};
 pub use filters::render_filter_bar;
 pub use table::render_games_table;
 pub use achievements::render_achievements_list;
-pub use ratings::{difficulty_label, difficulty_icon, difficulty_color, render_compact_avg_rating};
+pub use completion_cost::{completion_cost, render_completion_cost, CompletionCost};
+pub use ratings::{difficulty_label, difficulty_icon, difficulty_color, render_compact_avg_rating, render_difficulty_curve, has_difficulty_curve};
+pub use unlock_graph::{render_unlock_graph, has_unlock_graph};
+pub use unowned::render_unowned_section;
+pub use rarity::render_global_percent;
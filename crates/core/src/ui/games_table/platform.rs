@@ -1,8 +1,8 @@
 //! Platform trait for games table rendering
 
-use super::types::{SortColumn, SortOrder, TriFilter};
+use super::types::{AchievementSort, RangeFilter, SortColumn, SortOrder, TriFilter};
 use super::super::StatsPanelPlatform;
-use crate::{Game, GameAchievement, TtbTimes};
+use crate::{AchievementComment, AchievementDifficulty, AchievementRarityHistory, CloudSaveStatus, CommunityGameStats, Game, GameAchievement, GroupBy, TagMatchMode, TtbTimes};
 
 /// Platform abstraction for the games table
 /// 
@@ -37,6 +37,12 @@ pub trait GamesTablePlatform: StatsPanelPlatform {
     /// Set playtime filter state
     fn set_filter_playtime(&mut self, filter: TriFilter);
     
+    /// Get playtime range filter (hours played)
+    fn filter_playtime_range(&self) -> RangeFilter { RangeFilter::default() }
+    
+    /// Set playtime range filter
+    fn set_filter_playtime_range(&mut self, _range: RangeFilter) {}
+    
     /// Check if a game row is expanded
     fn is_expanded(&self, appid: u64) -> bool;
     
@@ -49,6 +55,24 @@ pub trait GamesTablePlatform: StatsPanelPlatform {
     /// Request achievements to be loaded for a game
     fn request_achievements(&mut self, appid: u64);
     
+    /// Get the sort order of achievement lists in expanded rows
+    fn achievement_sort(&self) -> AchievementSort { AchievementSort::default() }
+    
+    /// Set the sort order of achievement lists (shared by all expanded rows)
+    fn set_achievement_sort(&mut self, _sort: AchievementSort) {}
+    
+    /// How the games table is grouped into collapsible sections
+    fn group_by(&self) -> GroupBy { GroupBy::None }
+    
+    /// Set how the games table is grouped
+    fn set_group_by(&mut self, _group_by: GroupBy) {}
+    
+    /// Whether descriptions of hidden (spoiler) achievements are shown without clicking them
+    fn reveal_spoilers(&self) -> bool { false }
+    
+    /// Set whether hidden achievement descriptions are shown (shared by all expanded rows)
+    fn set_reveal_spoilers(&mut self, _reveal: bool) {}
+    
     /// Get flash intensity for a row (for highlighting recently updated games)
     /// Returns 0.0-1.0 intensity, or None if not flashing
     fn get_flash_intensity(&self, _appid: u64) -> Option<f32> {
@@ -79,6 +103,15 @@ pub trait GamesTablePlatform: StatsPanelPlatform {
     
     /// Check if a single game refresh is in progress
     fn is_single_game_refreshing(&self, _appid: u64) -> bool { false }
+
+    /// Check if a single game refresh is waiting for the ones before it
+    fn is_single_game_queued(&self, _appid: u64) -> bool { false }
+    
+    /// Check if a game was refreshed too recently to be refreshed again
+    fn is_refresh_on_cooldown(&self, _appid: u64) -> bool { false }
+    
+    /// Days after which achievement data counts as stale (None = don't show scrape age)
+    fn stale_scrape_days(&self) -> Option<u32> { None }
     
     /// Check if this platform supports launching a Steam game
     fn can_launch_game(&self) -> bool { false }
@@ -104,6 +137,48 @@ pub trait GamesTablePlatform: StatsPanelPlatform {
     /// Set installed games filter state
     fn set_filter_installed(&mut self, _filter: TriFilter) {}
     
+    /// Check if this platform knows store app types (games vs soundtracks, tools, demos)
+    fn has_app_types(&self) -> bool { false }
+    
+    /// Get app type filter state (With = games only, Without = non-game apps only)
+    fn filter_app_type(&self) -> TriFilter { TriFilter::All }
+    
+    /// Set app type filter state
+    fn set_filter_app_type(&mut self, _filter: TriFilter) {}
+    
+    /// Get the Steam Cloud save state of an installed game (None = unknown or no cloud saves)
+    fn cloud_save_status(&self, _appid: u64) -> Option<&CloudSaveStatus> { None }
+    
+    /// Read the Steam Cloud save state for a game (no-op if already read)
+    fn request_cloud_save_status(&mut self, _appid: u64) {}
+    
+    /// Check if this platform tracks play sessions (desktop only)
+    fn can_show_sessions(&self) -> bool { false }
+    
+    /// Show the play session history for a game
+    fn show_sessions(&mut self, _appid: u64, _game_name: &str) {}
+    
+    /// Check if this platform can plan the remaining achievements of a game (desktop only)
+    fn can_plan_completion(&self) -> bool { false }
+    
+    /// Open the completion planner for a game
+    fn open_completion_plan(&mut self, _appid: u64, _game_name: &str) {}
+    
+    /// Check if this platform can replay the unlock order of a game (desktop only)
+    fn can_show_unlock_timeline(&self) -> bool { false }
+    
+    /// Open the unlock order timeline for a game
+    fn open_unlock_timeline(&mut self, _appid: u64, _game_name: &str) {}
+    
+    /// Check if the user is currently playing this game
+    fn is_currently_playing(&self, _appid: u64) -> bool { false }
+
+    /// Owner badge for a row in a merged multi-account library (`index` into `games()`)
+    fn row_owner(&self, _index: usize) -> Option<&str> { None }
+
+    /// Rival's (unlocked, total) achievements for a game, for the head-to-head badge
+    fn rival_progress(&self, _appid: u64) -> Option<(i32, i32)> { None }
+    
     // ============================================================================
     // TTB (Time To Beat) Methods
     // ============================================================================
@@ -111,6 +186,9 @@ pub trait GamesTablePlatform: StatsPanelPlatform {
     /// Check if TTB column and data should be displayed (always true for desktop)
     fn show_ttb_column(&self) -> bool { false }
 
+    /// Check if the Steam Deck playtime column should be displayed
+    fn show_deck_playtime_column(&self) -> bool { false }
+
     /// Check if this platform supports TTB fetching (requires admin mode on desktop)
     fn can_fetch_ttb(&self) -> bool { false }
 
@@ -129,6 +207,12 @@ pub trait GamesTablePlatform: StatsPanelPlatform {
     /// Set TTB filter state
     fn set_filter_ttb(&mut self, _filter: TriFilter) {}
 
+    /// Get TTB range filter (main story hours)
+    fn filter_ttb_range(&self) -> RangeFilter { RangeFilter::default() }
+
+    /// Set TTB range filter
+    fn set_filter_ttb_range(&mut self, _range: RangeFilter) {}
+
     /// Check if a game is in the TTB blacklist
     fn is_ttb_blacklisted(&self, _appid: u64) -> bool { false }
 
@@ -157,12 +241,30 @@ pub trait GamesTablePlatform: StatsPanelPlatform {
     /// Set the tag filters
     fn set_filter_tags(&mut self, _tags: Vec<String>) {}
 
+    /// Get the excluded tags (games with any of them are hidden)
+    fn filter_excluded_tags(&self) -> &[String] { &[] }
+
+    /// Set the excluded tags
+    fn set_filter_excluded_tags(&mut self, _tags: Vec<String>) {}
+
+    /// Whether games need all included tags or any of them
+    fn tag_match_mode(&self) -> TagMatchMode { TagMatchMode::All }
+
+    /// Set how included tags combine
+    fn set_tag_match_mode(&mut self, _mode: TagMatchMode) {}
+
     /// Get the tag search input text
     fn tag_search_input(&self) -> &str { "" }
 
     /// Set the tag search input text
     fn set_tag_search_input(&mut self, _input: String) {}
 
+    /// Keyboard-highlighted row of the tag search dropdown
+    fn tag_search_selected_index(&self) -> Option<usize> { None }
+
+    /// Set the keyboard-highlighted row of the tag search dropdown
+    fn set_tag_search_selected_index(&mut self, _index: Option<usize>) {}
+
     /// Get available tags for dropdown
     fn available_tags(&self) -> &[String] { &[] }
 
@@ -181,6 +283,41 @@ pub trait GamesTablePlatform: StatsPanelPlatform {
     /// Check if currently fetching tags for a game
     fn is_fetching_tags(&self, _appid: u64) -> bool { false }
 
+    // ============================================================================
+    // Community Difficulty Curve
+    // ============================================================================
+
+    /// Get the community difficulty ratings for a game's achievements (None = not loaded)
+    fn get_difficulty_curve(&self, _appid: u64) -> Option<&[AchievementDifficulty]> { None }
+
+    /// Request the community difficulty ratings for a game (no-op if already requested)
+    fn request_difficulty_curve(&mut self, _appid: u64) {}
+
+    /// Anonymous completion stats of a game among all users (None = not loaded or too few players)
+    fn community_game_stats(&self, _appid: u64) -> Option<&CommunityGameStats> { None }
+
+    /// Request a game's community stats (no-op if already requested)
+    fn request_community_game_stats(&mut self, _appid: u64) {}
+
+    /// Global rarity snapshots of a game's achievements (None = not loaded)
+    fn get_rarity_history(&self, _appid: u64) -> Option<&[AchievementRarityHistory]> { None }
+
+    /// Request a game's rarity history (no-op if already requested)
+    fn request_rarity_history(&mut self, _appid: u64) {}
+
+    // ============================================================================
+    // Community Comments
+    // ============================================================================
+
+    /// Get the community comments on a game's achievements (None = not loaded)
+    fn get_achievement_comments(&self, _appid: u64) -> Option<&[AchievementComment]> { None }
+
+    /// Report a comment as abusive
+    fn report_comment(&mut self, _comment_id: i64) {}
+
+    /// Check if the user already reported a comment
+    fn is_comment_reported(&self, _comment_id: i64) -> bool { false }
+
     // ============================================================================
     // Hidden Games Methods
     // ============================================================================
@@ -194,9 +331,21 @@ pub trait GamesTablePlatform: StatsPanelPlatform {
     /// Set hidden games filter state
     fn set_filter_hidden(&mut self, _filter: TriFilter) {}
 
+    /// Get the filter for games marked private on Steam (only applies while they're not hidden by config)
+    fn filter_private(&self) -> TriFilter { TriFilter::All }
+
+    /// Set the private games filter state
+    fn set_filter_private(&mut self, _filter: TriFilter) {}
+
     /// Toggle manual hidden status for a game
     fn toggle_game_hidden(&mut self, _appid: u64) {}
 
-    /// Sync steam_hidden from Steam's sharedconfig.vdf
+    /// Whether games can be marked abandoned from the table
+    fn can_abandon_games(&self) -> bool { false }
+
+    /// Toggle the abandoned status of a game
+    fn toggle_game_abandoned(&mut self, _appid: u64) {}
+
+    /// Sync steam_hidden/steam_private from Steam (local config or public profile)
     fn sync_steam_hidden(&mut self) {}
 }
@@ -0,0 +1,67 @@
+//! Global rarity of an achievement row: the unlock percentage, with its drift over time on hover
+
+use egui::{self, Color32, RichText, Ui};
+use egui_plot::{Line, Plot, PlotPoints};
+use crate::AchievementRarityHistory;
+use crate::i18n::{tr, tr_args};
+use super::super::instant_tooltip_ui;
+
+/// Render the global unlock percentage of an achievement as a small label.
+///
+/// `percent` is the percentage stored with the achievement; without one the latest
+/// server snapshot is shown. Hovering charts the snapshots once there are two or more.
+pub fn render_global_percent(ui: &mut Ui, appid: u64, percent: Option<f32>, history: Option<&AchievementRarityHistory>) {
+    let Some(percent) = percent.or_else(|| history.and_then(|h| h.latest())) else {
+        return;
+    };
+
+    let response = ui.label(RichText::new(format!("{:.1}%", percent)).color(Color32::GRAY).small());
+    instant_tooltip_ui(&response, |ui| {
+        ui.label(tr_args("table.global_percent_tooltip", &[("percent", &format!("{:.1}", percent))]));
+        let Some(history) = history.filter(|h| h.points.len() > 1) else {
+            return;
+        };
+        if let (Some(drift), Some(first)) = (history.drift(), history.points.first()) {
+            ui.label(
+                RichText::new(tr_args("table.rarity_drift", &[
+                    ("drift", &format!("{:+.1}", drift)),
+                    ("since", &first.date.format("%Y-%m-%d")),
+                ]))
+                .weak()
+                .small(),
+            );
+        }
+        render_rarity_chart(ui, appid, history);
+    });
+}
+
+/// Global unlock percentage per snapshot, x = days since the first snapshot
+fn render_rarity_chart(ui: &mut Ui, appid: u64, history: &AchievementRarityHistory) {
+    let Some(first) = history.points.first().map(|p| p.date) else {
+        return;
+    };
+    let points: PlotPoints = history
+        .points
+        .iter()
+        .map(|p| [(p.date - first).num_days() as f64, p.percent as f64])
+        .collect();
+
+    Plot::new(format!("rarity_drift_{}_{}", appid, history.apiname))
+        .width(220.0)
+        .height(80.0)
+        .show_axes([false, true])
+        .allow_drag(false)
+        .allow_zoom(false)
+        .allow_scroll(false)
+        .include_y(0.0)
+        .x_axis_formatter(move |mark, _| {
+            (first + chrono::Duration::days(mark.value.round() as i64)).format("%b %d").to_string()
+        })
+        .label_formatter(move |_, point| {
+            let date = first + chrono::Duration::days(point.x.round() as i64);
+            format!("{}\n{:.1}%", date.format("%Y-%m-%d"), point.y)
+        })
+        .show(ui, |plot_ui| {
+            plot_ui.line(Line::new(tr("table.global_percent"), points).color(Color32::GRAY));
+        });
+}
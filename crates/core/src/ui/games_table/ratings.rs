@@ -1,15 +1,17 @@
 //! Achievement difficulty rating display functions
 
 use egui::{self, Color32, RichText, Ui};
+use egui_plot::{Line, Plot, PlotPoints, Points, VLine};
+use crate::{tr, tr_args, AchievementDifficulty, GameAchievement};
 
 /// Get difficulty label for rating (with trailing space to avoid border clipping)
 pub fn difficulty_label(rating: u8) -> &'static str {
     match rating {
-        1 => "Very easy  ",
-        2 => "Easy  ",
-        3 => "Moderate  ",
-        4 => "Hard  ",
-        5 => "Extreme  ",
+        1 => tr("difficulty.very_easy"),
+        2 => tr("difficulty.easy"),
+        3 => tr("difficulty.moderate"),
+        4 => tr("difficulty.hard"),
+        5 => tr("difficulty.extreme"),
         _ => "",
     }
 }
@@ -39,22 +41,157 @@ pub fn difficulty_color(rating: u8) -> Color32 {
 }
 
 /// Render compact average rating display (read-only, no interaction)
-/// Shows a single difficulty icon with label and vote count
-pub fn render_compact_avg_rating(ui: &mut Ui, avg_rating: Option<u8>, rating_count: Option<i32>) {
+/// Shows a single difficulty icon with label and vote count.
+/// With `votes` (counts for ratings 1-5), hovering shows the vote distribution.
+pub fn render_compact_avg_rating(ui: &mut Ui, avg_rating: Option<u8>, rating_count: Option<i32>, votes: Option<&[i32; 5]>) {
     let Some(rating) = avg_rating else {
         return; // Don't show anything if no rating
     };
     
     // Add count in parentheses first (since we're right-to-left)
+    let mut count_label = None;
     if let Some(count) = rating_count {
-        ui.label(RichText::new(format!("({})", count)).color(Color32::GRAY).size(10.0));
+        count_label = Some(ui.label(RichText::new(format!("({})", count)).color(Color32::GRAY).size(10.0)));
         ui.add_space(4.0);
     }
     
     // Add difficulty label with gradient color
-    ui.label(RichText::new(difficulty_label(rating)).color(difficulty_color(rating)).size(10.0));
+    let label = ui.label(RichText::new(format!("{}  ", difficulty_label(rating))).color(difficulty_color(rating)).size(10.0));
     ui.add_space(4.0);
     
     // Single difficulty icon
-    ui.label(RichText::new(difficulty_icon(rating)).color(difficulty_color(rating)).size(12.0));
+    let icon = ui.label(RichText::new(difficulty_icon(rating)).color(difficulty_color(rating)).size(12.0));
+
+    if let Some(votes) = votes {
+        let mut badge = label | icon;
+        if let Some(count_label) = count_label {
+            badge |= count_label;
+        }
+        badge.on_hover_ui(|ui| render_rating_histogram(ui, votes));
+    }
+}
+
+/// Width of the longest bar in the vote distribution tooltip
+const HISTOGRAM_BAR_WIDTH: f32 = 80.0;
+
+/// Vote distribution as one bar per difficulty level, hardest on top
+fn render_rating_histogram(ui: &mut Ui, votes: &[i32; 5]) {
+    let max = votes.iter().copied().max().unwrap_or(0).max(1);
+    egui::Grid::new("rating_histogram").num_columns(3).spacing([6.0, 2.0]).show(ui, |ui| {
+        for level in (1..=5u8).rev() {
+            let count = votes[level as usize - 1];
+            let color = difficulty_color(level);
+            ui.label(RichText::new(format!("{} {}", difficulty_icon(level), difficulty_label(level))).color(color).size(10.0));
+            let (rect, _) = ui.allocate_exact_size(egui::vec2(HISTOGRAM_BAR_WIDTH, 8.0), egui::Sense::hover());
+            let filled = egui::Rect::from_min_size(rect.min, egui::vec2(HISTOGRAM_BAR_WIDTH * count as f32 / max as f32, rect.height()));
+            ui.painter().rect_filled(rect, 2.0, ui.visuals().faint_bg_color);
+            ui.painter().rect_filled(filled, 2.0, color);
+            ui.label(RichText::new(count.to_string()).color(Color32::GRAY).size(10.0));
+            ui.end_row();
+        }
+    });
+}
+
+/// Minimum jump in average rating between neighbouring achievements to call it a spike
+const DIFFICULTY_SPIKE_THRESHOLD: f32 = 0.75;
+
+/// Height of the difficulty curve section (header + plot) at font scale 1.0
+pub const DIFFICULTY_CURVE_HEIGHT: f32 = 120.0;
+
+/// Rated achievements that still exist in the schema as (name, avg rating, votes), easiest first
+fn difficulty_curve_points<'a>(curve: &[AchievementDifficulty], achievements: &'a [GameAchievement]) -> Vec<(&'a str, f32, i32)> {
+    let mut points: Vec<(&str, f32, i32)> = curve
+        .iter()
+        .filter(|d| d.rating_count > 0)
+        .filter_map(|d| {
+            achievements
+                .iter()
+                .find(|a| a.apiname == d.apiname)
+                .map(|a| (a.name.as_str(), d.avg_rating, d.rating_count))
+        })
+        .collect();
+    points.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+    points
+}
+
+/// Whether there are enough rated achievements to draw a curve
+pub fn has_difficulty_curve(curve: &[AchievementDifficulty], achievements: &[GameAchievement]) -> bool {
+    difficulty_curve_points(curve, achievements).len() >= 2
+}
+
+/// Render the community difficulty curve for a game (achievements ordered easiest → hardest)
+/// Marks the largest jump between neighbouring achievements as the difficulty spike
+pub fn render_difficulty_curve(ui: &mut Ui, appid: u64, curve: &[AchievementDifficulty], achievements: &[GameAchievement]) {
+    let points = difficulty_curve_points(curve, achievements);
+    if points.len() < 2 {
+        return;
+    }
+
+    // Largest step up between neighbours
+    let spike = points
+        .windows(2)
+        .enumerate()
+        .map(|(i, w)| (i + 1, w[1].1 - w[0].1))
+        .filter(|(_, jump)| *jump >= DIFFICULTY_SPIKE_THRESHOLD)
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+
+    ui.horizontal(|ui| {
+        ui.label(RichText::new(tr("difficulty.curve")).strong().size(12.0));
+        ui.label(RichText::new(tr_args("difficulty.curve_rated", &[("count", &points.len())])).color(Color32::GRAY).size(10.0));
+        if let Some((index, _)) = spike {
+            ui.label(
+                RichText::new(tr_args("difficulty.spike", &[("index", &(index + 1)), ("name", &points[index].0)]))
+                    .color(difficulty_color(4))
+                    .size(10.0),
+            );
+        }
+    });
+
+    let names: Vec<String> = points.iter().map(|(name, _, count)| tr_args("difficulty.votes", &[("name", name), ("count", count)])).collect();
+    Plot::new(format!("difficulty_curve_{}", appid))
+        .height(90.0)
+        .show_axes([false, true])
+        .show_x(false)
+        .allow_drag(false)
+        .allow_zoom(false)
+        .allow_scroll(false)
+        .include_y(1.0)
+        .include_y(5.0)
+        .label_formatter(move |_, point| {
+            let index = point.x.round();
+            if index < 0.0 {
+                return String::new();
+            }
+            names
+                .get(index as usize)
+                .map(|name| format!("{}\n{:.1}", name, point.y))
+                .unwrap_or_default()
+        })
+        .show(ui, |plot_ui| {
+            let line: PlotPoints = points.iter().enumerate().map(|(i, p)| [i as f64, p.1 as f64]).collect();
+            plot_ui.line(Line::new(tr("difficulty.series"), line).color(Color32::GRAY));
+
+            // One point series per difficulty level so each gets its color
+            for level in 1..=5u8 {
+                let series: PlotPoints = points
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, p)| p.1.round().clamp(1.0, 5.0) as u8 == level)
+                    .map(|(i, p)| [i as f64, p.1 as f64])
+                    .collect();
+                if series.points().is_empty() {
+                    continue;
+                }
+                plot_ui.points(
+                    Points::new(difficulty_label(level), series)
+                        .color(difficulty_color(level))
+                        .radius(3.0)
+                        .filled(true),
+                );
+            }
+
+            if let Some((index, _)) = spike {
+                plot_ui.vline(VLine::new(tr("difficulty.spike_marker"), index as f64 - 0.5).color(difficulty_color(4)));
+            }
+        });
 }
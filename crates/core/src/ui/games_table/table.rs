@@ -1,13 +1,19 @@
 //! Table rendering for games
 
+use std::collections::HashSet;
+
 use egui::{Color32, RichText, Ui};
-use egui_extras::{Column, TableBuilder};
+use egui_extras::{Column, TableBuilder, TableRow};
 use egui_phosphor::regular;
 
 use super::platform::GamesTablePlatform;
 use super::helpers::{format_timestamp, sort_indicator};
+use super::ratings::{has_difficulty_curve, DIFFICULTY_CURVE_HEIGHT};
+use super::unlock_graph::{has_unlock_graph, UNLOCK_GRAPH_HEIGHT};
 use super::types::SortColumn;
-use super::super::instant_tooltip;
+use super::completion_cost::{completion_cost, render_completion_cost};
+use super::super::{instant_tooltip, instant_tooltip_ui};
+use crate::{group_games, grouped_rows, tr, tr_args, GameGroup, GroupBy, GroupedRow, ThemeColors};
 
 /// Render the games table
 ///
@@ -30,15 +36,59 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
     let expanded_ach_height = text_height + 330.0 * font_scale;   // Extra height for achievement list
     let expanded_ttb_height = text_height + 60.0 * font_scale;    // Just TTB row, no achievements
     let expanded_empty_height = text_height + 40.0 * font_scale;  // Expanded but no content yet
+    let platforms_height = 20.0 * font_scale; // Per-platform playtime line
+    let difficulty_curve_height = DIFFICULTY_CURVE_HEIGHT * font_scale;
+    let unlock_graph_height = UNLOCK_GRAPH_HEIGHT * font_scale;
+
+    // Theme colors for highlights and completion
+    let theme = platform.theme_colors();
+    let time = platform.time_display();
+
+    // Grouping sits between the filtered list and the table: header rows followed by
+    // the games of each open group (positions in the filtered list)
+    let group_by = platform.group_by();
+    let collapsed_id = egui::Id::new("games_table_collapsed_groups");
+    let mut collapsed: HashSet<String> = ui.data_mut(|d| d.get_temp(collapsed_id)).unwrap_or_default();
+    let groups = if group_by == GroupBy::None {
+        Vec::new()
+    } else {
+        group_games(platform.games(), &filtered_indices, group_by, &time)
+    };
+    // Open the group of a navigation target so there is a row to scroll to
+    if let Some((nav_appid, _)) = platform.get_navigation_target().filter(|_| platform.needs_scroll_to_target()) {
+        let games = platform.games();
+        if let Some(group) = groups.iter().find(|g| g.members.iter().any(|&pos| games[filtered_indices[pos]].appid == nav_appid)) {
+            collapsed.remove(&group.label);
+        }
+    }
+    let rows: Vec<GroupedRow> = if group_by == GroupBy::None {
+        (0..filtered_indices.len()).map(GroupedRow::Game).collect()
+    } else {
+        grouped_rows(&groups, &collapsed)
+    };
 
-    let row_heights: Vec<f32> = filtered_indices.iter().map(|&idx| {
+    let game_row_height = |idx: usize| {
         let game = &platform.games()[idx];
         let appid = game.appid;
         if platform.is_expanded(appid) {
             let has_achievements = game.achievements_total.map(|t| t > 0).unwrap_or(false);
             let has_ttb = platform.get_ttb_times(appid).is_some();
-            if has_achievements {
-                expanded_ach_height
+            let extra = if has_platform_breakdown(game) { platforms_height } else { 0.0 };
+            extra + if has_achievements {
+                let achievements = platform.get_cached_achievements(appid);
+                let has_curve = match (platform.get_difficulty_curve(appid), achievements) {
+                    (Some(curve), Some(achievements)) => has_difficulty_curve(curve, achievements),
+                    _ => false,
+                };
+                let has_graph = achievements.is_some_and(|achievements| has_unlock_graph(achievements));
+                let mut height = expanded_ach_height;
+                if has_curve {
+                    height += difficulty_curve_height;
+                }
+                if has_graph {
+                    height += unlock_graph_height;
+                }
+                height
             } else if has_ttb {
                 expanded_ttb_height
             } else {
@@ -47,6 +97,10 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
         } else {
             text_height
         }
+    };
+    let row_heights: Vec<f32> = rows.iter().map(|row| match row {
+        GroupedRow::Header(_) => text_height,
+        GroupedRow::Game(pos) => game_row_height(filtered_indices[*pos]),
     }).collect();
     
     // Track which rows need achievement fetch
@@ -60,13 +114,14 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
     // Find navigation target row index if any (only if we need to scroll)
     let nav_row_index = if platform.needs_scroll_to_target() {
         platform.get_navigation_target().and_then(|(nav_appid, _)| {
-            games.iter().position(|g| g.appid == nav_appid)
+            rows.iter().position(|row| matches!(row, GroupedRow::Game(pos) if games[*pos].appid == nav_appid))
         })
     } else {
         None
     };
     
     let show_ttb_column = platform.show_ttb_column();
+    let show_deck_column = platform.show_deck_playtime_column();
     let name_col_width = platform.name_column_width();
     let filter_tags: Vec<String> = platform.filter_tags().to_vec();
     let show_votes_column = !filter_tags.is_empty();
@@ -77,6 +132,7 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
     let achievements_width = (100.0 * font_scale).max(100.0);
     let percent_width = (60.0 * font_scale).max(60.0);
     let ttb_width = (60.0 * font_scale).max(60.0);
+    let deck_width = (60.0 * font_scale).max(60.0);
     let votes_width = (60.0 * font_scale).max(60.0);
 
     let mut table_builder = TableBuilder::new(ui)
@@ -90,6 +146,11 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
         .column(Column::exact(achievements_width)) // Achievements - scaled
         .column(Column::exact(percent_width));     // Percent - scaled
 
+    // Add Deck playtime column if enabled
+    if show_deck_column {
+        table_builder = table_builder.column(Column::exact(deck_width)); // Deck - scaled
+    }
+
     // Add TTB column if platform supports it
     if show_ttb_column {
         table_builder = table_builder.column(Column::exact(ttb_width)); // TTB - scaled
@@ -113,6 +174,9 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
     
     // Track the actual column width for persistence
     let mut actual_name_col_width = name_col_width;
+    // Group header clicked this frame
+    let mut toggle_group: Option<String> = None;
+    let extra_columns = [show_deck_column, show_ttb_column, show_votes_column].iter().filter(|&&shown| shown).count();
 
     table_builder.header(header_height, |mut header| {
             header.col(|ui| {
@@ -120,28 +184,28 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
                 actual_name_col_width = ui.available_width();
 
                 let indicator = sort_indicator(platform, SortColumn::Name);
-                let label = if indicator.is_empty() { "Name".to_string() } else { format!("Name {}", indicator) };
+                let label = if indicator.is_empty() { tr("table.name").to_string() } else { format!("{} {}", tr("table.name"), indicator) };
                 if ui.selectable_label(platform.sort_column() == SortColumn::Name, label).clicked() {
                     platform.set_sort(SortColumn::Name);
                 }
             });
             header.col(|ui| {
                 let indicator = sort_indicator(platform, SortColumn::LastPlayed);
-                let label = if indicator.is_empty() { "Last Played".to_string() } else { format!("Last Played {}", indicator) };
+                let label = if indicator.is_empty() { tr("table.last_played").to_string() } else { format!("{} {}", tr("table.last_played"), indicator) };
                 if ui.selectable_label(platform.sort_column() == SortColumn::LastPlayed, label).clicked() {
                     platform.set_sort(SortColumn::LastPlayed);
                 }
             });
             header.col(|ui| {
                 let indicator = sort_indicator(platform, SortColumn::Playtime);
-                let label = if indicator.is_empty() { "Playtime".to_string() } else { format!("Playtime {}", indicator) };
+                let label = if indicator.is_empty() { tr("table.playtime").to_string() } else { format!("{} {}", tr("table.playtime"), indicator) };
                 if ui.selectable_label(platform.sort_column() == SortColumn::Playtime, label).clicked() {
                     platform.set_sort(SortColumn::Playtime);
                 }
             });
             header.col(|ui| {
                 let indicator = sort_indicator(platform, SortColumn::AchievementsTotal);
-                let label = if indicator.is_empty() { "Achievements".to_string() } else { format!("Achievements {}", indicator) };
+                let label = if indicator.is_empty() { tr("table.achievements").to_string() } else { format!("{} {}", tr("table.achievements"), indicator) };
                 if ui.selectable_label(platform.sort_column() == SortColumn::AchievementsTotal, label).clicked() {
                     platform.set_sort(SortColumn::AchievementsTotal);
                 }
@@ -153,26 +217,37 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
                     platform.set_sort(SortColumn::AchievementsPercent);
                 }
             });
+            if show_deck_column {
+                header.col(|ui| {
+                    let indicator = sort_indicator(platform, SortColumn::DeckPlaytime);
+                    let label = if indicator.is_empty() { tr("table.deck_playtime").to_string() } else { format!("{} {}", tr("table.deck_playtime"), indicator) };
+                    let response = ui.selectable_label(platform.sort_column() == SortColumn::DeckPlaytime, label);
+                    if response.clicked() {
+                        platform.set_sort(SortColumn::DeckPlaytime);
+                    }
+                    instant_tooltip(&response, tr("table.deck_playtime_tooltip"));
+                });
+            }
             if show_ttb_column {
                 header.col(|ui| {
                     let indicator = sort_indicator(platform, SortColumn::TimeToBeat);
-                    let label = if indicator.is_empty() { "TTB".to_string() } else { format!("TTB {}", indicator) };
+                    let label = if indicator.is_empty() { tr("table.ttb").to_string() } else { format!("{} {}", tr("table.ttb"), indicator) };
                     let response = ui.selectable_label(platform.sort_column() == SortColumn::TimeToBeat, label);
                     if response.clicked() {
                         platform.set_sort(SortColumn::TimeToBeat);
                     }
-                    instant_tooltip(&response, "Time to Beat");
+                    instant_tooltip(&response, tr("table.ttb_tooltip"));
                 });
             }
             if show_votes_column {
                 header.col(|ui| {
                     let indicator = sort_indicator(platform, SortColumn::Votes);
-                    let label = if indicator.is_empty() { "Votes".to_string() } else { format!("Votes {}", indicator) };
+                    let label = if indicator.is_empty() { tr("table.votes").to_string() } else { format!("{} {}", tr("table.votes"), indicator) };
                     let response = ui.selectable_label(platform.sort_column() == SortColumn::Votes, label);
                     if response.clicked() {
                         platform.set_sort(SortColumn::Votes);
                     }
-                    instant_tooltip(&response, "Tag votes from SteamSpy");
+                    instant_tooltip(&response, tr("table.votes_tooltip"));
                 });
             }
         })
@@ -180,20 +255,25 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
             body.heterogeneous_rows(row_heights.into_iter(), |mut row| {
                 use crate::ui::ttb_dialog::{get_ttb_display, TtbTimeType};
                 
-                let row_idx = row.index();
+                let row_idx = match rows[row.index()] {
+                    GroupedRow::Header(group) => {
+                        let group = &groups[group];
+                        if render_group_header(&mut row, group, collapsed.contains(&group.label), &theme, extra_columns) {
+                            toggle_group = Some(group.label.clone());
+                        }
+                        return;
+                    }
+                    GroupedRow::Game(pos) => pos,
+                };
                 let game = &games[row_idx];
+                let owner = platform.row_owner(filtered_indices[row_idx]).map(str::to_string);
                 let appid = game.appid;
                 let is_expanded = platform.is_expanded(appid);
                 let has_achievements = game.achievements_total.map(|t| t > 0).unwrap_or(false);
                 
                 // Check if this game should be flashing
                 let flash_color = platform.get_flash_intensity(appid).map(|intensity| {
-                    egui::Color32::from_rgba_unmultiplied(
-                        255,  // R
-                        215,  // G (gold)
-                        0,    // B
-                        (intensity * 100.0) as u8
-                    )
+                    theme.accent_alpha((intensity * 100.0) as u8)
                 });
                 
                 // Name column with expand/collapse toggle
@@ -231,20 +311,57 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
                                     }
                                 }
                                 ui.label(RichText::new(&game.name).strong());
+                                if let Some(owner) = &owner {
+                                    render_owner_badge(ui, owner);
+                                }
+                                render_visibility_badges(ui, game);
+                                if platform.is_currently_playing(appid) {
+                                    let playing = ui.label(RichText::new(regular::PLAY.to_string()).color(theme.complete));
+                                    super::super::instant_tooltip(&playing, tr("table.playing_now"));
+                                }
+                                if let Some(stale_days) = platform.stale_scrape_days() {
+                                    render_scrape_age_badge(ui, game.last_achievement_scrape, stale_days);
+                                }
+                                if let (Some(mine), Some((theirs, total))) = (game.achievements_unlocked, platform.rival_progress(appid)) {
+                                    render_head_to_head(ui, mine, theirs, total);
+                                }
                                 
                                 // Right-align the action buttons
                                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                    // Abandon / take back up
+                                    if platform.can_abandon_games() {
+                                        let (icon, tooltip) = if game.abandoned {
+                                            (regular::ARROW_U_UP_LEFT, tr("table.unabandon"))
+                                        } else {
+                                            (regular::ARCHIVE, tr("table.abandon"))
+                                        };
+                                        let btn = ui.small_button(icon.to_string());
+                                        if btn.clicked() {
+                                            platform.toggle_game_abandoned(appid);
+                                        }
+                                        super::super::instant_tooltip(&btn, tooltip);
+                                    }
+
                                     // Refresh button for single game update
                                     if platform.can_refresh_single_game() {
                                         let is_refreshing = platform.is_single_game_refreshing(appid);
+                                        let is_queued = platform.is_single_game_queued(appid);
+                                        let on_cooldown = platform.is_refresh_on_cooldown(appid);
                                         let btn = ui.add_enabled(
-                                            !is_refreshing,
+                                            !is_refreshing && !is_queued && !on_cooldown,
                                             egui::Button::new(regular::ARROWS_CLOCKWISE.to_string()).small()
                                         );
                                         if btn.clicked() {
                                             platform.request_single_game_refresh(appid);
                                         }
-                                        super::super::instant_tooltip(&btn, "Refresh achievements for this game");
+                                        let tooltip = if is_queued {
+                                            tr("table.refresh_queued")
+                                        } else if on_cooldown {
+                                            tr("table.refresh_cooldown")
+                                        } else {
+                                            tr("table.refresh")
+                                        };
+                                        super::super::instant_tooltip(&btn, tooltip);
                                     }
                                     
                                     // Launch/Install button (desktop only)
@@ -276,16 +393,53 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
                                             if btn.clicked() && !is_launching {
                                                 platform.launch_game(appid);
                                             }
-                                            let tooltip = if is_launching { "Launching..." } else { "Launch game in Steam" };
+                                            let tooltip = if is_launching { tr("table.launching") } else { tr("table.launch") };
                                             super::super::instant_tooltip(&btn, tooltip);
+                                            
+                                            // Pre-play check: warn if cloud saves may be out of date
+                                            platform.request_cloud_save_status(appid);
+                                            if let Some(cloud) = platform.cloud_save_status(appid) {
+                                                render_cloud_save_check(ui, cloud);
+                                            }
                                         } else {
                                             // Install button for non-installed games
                                             let btn = ui.add(egui::Button::new(regular::DOWNLOAD_SIMPLE.to_string()).small());
                                             if btn.clicked() {
                                                 platform.install_game(appid);
                                             }
-                                            super::super::instant_tooltip(&btn, "Install game from Steam");
+                                            super::super::instant_tooltip(&btn, tr("table.install"));
+                                        }
+                                    }
+                                    
+                                    // Session history button (desktop only)
+                                    if platform.can_show_sessions() {
+                                        let btn = ui.add(egui::Button::new(regular::CLOCK_COUNTER_CLOCKWISE.to_string()).small());
+                                        if btn.clicked() {
+                                            platform.show_sessions(appid, &game.name);
                                         }
+                                        super::super::instant_tooltip(&btn, tr("table.sessions"));
+                                    }
+                                    
+                                    // Completion planner button, only while achievements are left (desktop only)
+                                    let has_locked = matches!(
+                                        (game.achievements_unlocked, game.achievements_total),
+                                        (Some(unlocked), Some(total)) if unlocked < total
+                                    );
+                                    if has_locked && platform.can_plan_completion() {
+                                        let btn = ui.add(egui::Button::new(regular::LIST_CHECKS.to_string()).small());
+                                        if btn.clicked() {
+                                            platform.open_completion_plan(appid, &game.name);
+                                        }
+                                        super::super::instant_tooltip(&btn, tr("table.plan"));
+                                    }
+                                    
+                                    // Unlock timeline button, once something is unlocked (desktop only)
+                                    if game.achievements_unlocked.unwrap_or(0) > 0 && platform.can_show_unlock_timeline() {
+                                        let btn = ui.add(egui::Button::new(regular::FILM_STRIP.to_string()).small());
+                                        if btn.clicked() {
+                                            platform.open_unlock_timeline(appid, &game.name);
+                                        }
+                                        super::super::instant_tooltip(&btn, tr("table.unlock_timeline"));
                                     }
                                     
                                     if platform.can_fetch_ttb() {
@@ -299,9 +453,9 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
                                                 platform.fetch_ttb(appid, &game.name);
                                             }
                                             let tooltip = if platform.get_ttb_times(appid).is_some() {
-                                                "Re-fetch Time To Beat from HowLongToBeat"
+                                                tr("table.refetch_ttb")
                                             } else {
-                                                "Fetch Time To Beat from HowLongToBeat"
+                                                tr("table.fetch_ttb")
                                             };
                                             super::super::instant_tooltip(&btn, tooltip);
                                         }
@@ -318,9 +472,9 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
                                                 platform.fetch_tags(appid);
                                             }
                                             let tooltip = if platform.has_cached_tags(appid) {
-                                                "Re-fetch tags from SteamSpy"
+                                                tr("table.refetch_tags")
                                             } else {
-                                                "Fetch tags from SteamSpy"
+                                                tr("table.fetch_tags")
                                             };
                                             super::super::instant_tooltip(&btn, tooltip);
                                         }
@@ -328,9 +482,22 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
                                 });
                             } else {
                                 ui.label(&game.name);
+                                if let Some(owner) = &owner {
+                                    render_owner_badge(ui, owner);
+                                }
+                                render_visibility_badges(ui, game);
+                                if platform.is_currently_playing(appid) {
+                                    let playing = ui.label(RichText::new(regular::PLAY.to_string()).color(theme.complete));
+                                    super::super::instant_tooltip(&playing, tr("table.playing_now"));
+                                }
                             }
                         });
 
+                        // Where the game was played
+                        if is_expanded && has_platform_breakdown(game) {
+                            render_platform_breakdown(ui, game);
+                        }
+
                         // Show TTB data row if expanded and platform shows TTB column
                         if is_expanded && platform.show_ttb_column() {
                             use crate::ui::ttb_dialog::format_ttb_time;
@@ -342,39 +509,37 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
                             // Dedicated TTB row with subtle background
                             ui.add_space(6.0);
                             ui.horizontal(|ui| {
-                                ui.label(RichText::new("⏱ Time to Beat:").strong());
+                                ui.label(RichText::new(format!("⏱ {}", tr("table.ttb_label"))).strong());
                                 
-                                // Check if we have user-reported data (show gold when report_count > 0)
+                                // Check if we have user-reported data (show accent color when report_count > 0)
                                 let has_user_data = game.user_ttb_report_count > 0;
                                 
                                 if has_user_data {
-                                    // Show average user TTB (prioritize user's own report) in gold
-                                    let gold = egui::Color32::from_rgb(255, 215, 0);
+                                    // Show average user TTB (prioritize user's own report) in the accent color
                                     if let Some((text, _)) = get_ttb_display(&game, TtbTimeType::Main) {
-                                        ui.label(RichText::new(format!("Main: {}", text)).color(gold));
+                                        ui.label(RichText::new(tr_args("table.ttb_main", &[("time", &text)])).color(theme.accent));
                                     }
                                     if let Some((text, _)) = get_ttb_display(&game, TtbTimeType::Extra) {
-                                        ui.label(RichText::new(format!("| +Extra: {}", text)).color(gold));
+                                        ui.label(RichText::new(format!("| {}", tr_args("table.ttb_extra", &[("time", &text)]))).color(theme.accent));
                                     }
                                     if let Some((text, _)) = get_ttb_display(&game, TtbTimeType::Completionist) {
-                                        ui.label(RichText::new(format!("| 100%: {}", text)).color(gold));
+                                        ui.label(RichText::new(format!("| 100%: {}", text)).color(theme.accent));
                                     }
                                 } else if let Some(ttb) = platform.get_ttb_times(appid) {
-                                    // Fall back to HLTB scraped data in light blue
-                                    let light_blue = egui::Color32::from_rgb(120, 180, 255);
+                                    // Fall back to HLTB scraped data in the secondary color
                                     let has_data = ttb.main.is_some() || ttb.main_extra.is_some() || ttb.completionist.is_some();
                                     if has_data {
                                         if let Some(main_hours) = ttb.main {
-                                            ui.label(RichText::new(format!("Main: {:.0}h", main_hours)).color(light_blue));
+                                            ui.label(RichText::new(tr_args("table.ttb_main", &[("time", &format!("{:.0}h", main_hours))])).color(theme.secondary));
                                         }
                                         if let Some(extra_hours) = ttb.main_extra {
-                                            ui.label(RichText::new(format!("| +Extra: {:.0}h", extra_hours)).color(light_blue));
+                                            ui.label(RichText::new(format!("| {}", tr_args("table.ttb_extra", &[("time", &format!("{:.0}h", extra_hours))]))).color(theme.secondary));
                                         }
                                         if let Some(comp_hours) = ttb.completionist {
-                                            ui.label(RichText::new(format!("| 100%: {:.0}h", comp_hours)).color(light_blue));
+                                            ui.label(RichText::new(format!("| 100%: {:.0}h", comp_hours)).color(theme.secondary));
                                         }
                                     } else {
-                                        ui.label(RichText::new("<no data>").weak());
+                                        ui.label(RichText::new(tr("table.ttb_no_data")).weak());
                                     }
                                 } else {
                                     ui.label(RichText::new("—").weak());
@@ -383,27 +548,35 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
                                 // Show "Your TTB" if user has reported different from average
                                 if game.my_ttb_main_seconds.is_some() && game.avg_user_ttb_main_seconds.is_some() {
                                     ui.separator();
-                                    ui.label(RichText::new("Your time:").strong());
+                                    ui.label(RichText::new(tr("table.ttb_yours")).strong());
                                     if let Some(seconds) = game.my_ttb_main_seconds {
-                                        ui.label(format!("Main: {}", format_ttb_time(seconds)));
+                                        ui.label(tr_args("table.ttb_main", &[("time", &format_ttb_time(seconds))]));
                                     }
                                     if let Some(seconds) = game.my_ttb_extra_seconds {
-                                        ui.label(format!("| +Extra: {}", format_ttb_time(seconds)));
+                                        ui.label(format!("| {}", tr_args("table.ttb_extra", &[("time", &format_ttb_time(seconds))])));
                                     }
                                     if let Some(seconds) = game.my_ttb_completionist_seconds {
                                         ui.label(format!("| 100%: {}", format_ttb_time(seconds)));
                                     }
                                 }
+
+                                // How other Overachiever users fare with this game
+                                if let Some(stats) = platform.community_game_stats(appid) {
+                                    ui.separator();
+                                    render_community_stats(ui, stats);
+                                } else {
+                                    platform.request_community_game_stats(appid);
+                                }
                                 
                                 // "Report TTB" button on the right
                                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                                     let btn = ui.add(egui::Button::new(
-                                        RichText::new(format!("{} Report TTB", regular::CLOCK))
+                                        RichText::new(format!("{} {}", regular::CLOCK, tr("table.ttb_report")))
                                     ).small());
                                     if btn.clicked() {
                                         platform.request_ttb_dialog(appid, &game.name, Some(&game), None);
                                     }
-                                    instant_tooltip(&btn, "Report your time to beat for this game");
+                                    instant_tooltip(&btn, tr("table.ttb_report_tooltip"));
                                 });
                             });
 
@@ -417,24 +590,24 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
                                     if is_blacklisted {
                                         // Game is blacklisted - offer to remove from blacklist
                                         let btn = ui.add(egui::Button::new(
-                                            RichText::new(format!("{} Allow TTB", regular::CHECK))
+                                            RichText::new(format!("{} {}", regular::CHECK, tr("table.ttb_allow")))
                                                 .color(egui::Color32::from_rgb(100, 180, 100))
                                         ).small());
                                         if btn.clicked() {
                                             platform.remove_from_ttb_blacklist(appid);
                                         }
-                                        instant_tooltip(&btn, "Remove from TTB blacklist (allow in scan)");
-                                        ui.label(RichText::new("Game is excluded from TTB scan").weak().italics());
+                                        instant_tooltip(&btn, tr("table.ttb_allow_tooltip"));
+                                        ui.label(RichText::new(tr("table.ttb_excluded")).weak().italics());
                                     } else {
                                         // Game not blacklisted and has no TTB - offer to blacklist
                                         let btn = ui.add(egui::Button::new(
-                                            RichText::new(format!("{} Not for TTB", regular::PROHIBIT))
+                                            RichText::new(format!("{} {}", regular::PROHIBIT, tr("table.ttb_exclude")))
                                                 .color(egui::Color32::from_rgb(180, 100, 100))
                                         ).small());
                                         if btn.clicked() {
                                             platform.add_to_ttb_blacklist(appid, &game.name);
                                         }
-                                        instant_tooltip(&btn, "Mark as not suitable for TTB (e.g., multiplayer-only games)");
+                                        instant_tooltip(&btn, tr("table.ttb_exclude_tooltip"));
                                     }
                                 });
                             }
@@ -455,7 +628,10 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
                     if !is_expanded {
                         if let Some(ts) = game.rtime_last_played {
                             if ts > 0 {
-                                ui.label(format_timestamp(ts));
+                                let label = chrono::DateTime::from_timestamp(ts as i64, 0)
+                                    .map(|dt| time.format_achievement(dt))
+                                    .unwrap_or_else(|| format_timestamp(ts));
+                                ui.label(label);
                             } else {
                                 ui.label("—");
                             }
@@ -494,19 +670,35 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
                     }
                     if !is_expanded {
                         if let Some(pct) = game.completion_percent() {
-                            // Green for 100%, gray otherwise
-                            let color = if pct >= 100.0 {
-                                Color32::from_rgb(100, 255, 100)
-                            } else {
-                                Color32::GRAY
-                            };
-                            ui.label(RichText::new(format!("{:.0}%", pct)).color(color));
+                            // Complete / near-complete / incomplete colors from the theme
+                            let color = theme.completion_color(pct);
+                            let response = ui.label(RichText::new(format!("{:.0}%", pct)).color(color));
+                            instant_tooltip_ui(&response, |ui| {
+                                if let Some(cost) = completion_cost(platform, game) {
+                                    render_completion_cost(ui, &cost);
+                                }
+                            });
                         } else {
                             ui.label("—");
                         }
                     }
                 });
 
+                // Deck playtime column (only if enabled)
+                if show_deck_column {
+                    row.col(|ui| {
+                        if let Some(color) = flash_color {
+                            ui.painter().rect_filled(ui.available_rect_before_wrap(), 0.0, color);
+                        }
+                        if !is_expanded {
+                            match game.platform_playtime {
+                                Some(p) if p.deck > 0 => ui.label(format!("{:.1}h", p.deck as f64 / 60.0)),
+                                _ => ui.label("—"),
+                            };
+                        }
+                    });
+                }
+
                 // TTB column (only if platform supports it)
                 if show_ttb_column {
                     row.col(|ui| {
@@ -514,29 +706,27 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
                             ui.painter().rect_filled(ui.available_rect_before_wrap(), 0.0, color);
                         }
                         if !is_expanded {
-                            // Check if we have user-reported data (gold) or HLTB data (light blue)
-                            // Show gold when we have at least 1 user report (my_ttb or avg_user_ttb with count > 0)
+                            // Check if we have user-reported data (accent) or HLTB data (secondary)
+                            // Show accent when we have at least 1 user report (my_ttb or avg_user_ttb with count > 0)
                             let has_user_data = game.user_ttb_report_count > 0;
                             
                             if has_user_data {
-                                // Show user-reported data in gold
-                                let gold = egui::Color32::from_rgb(255, 215, 0);
+                                // Show user-reported data in the accent color
                                 if let Some((text, _)) = get_ttb_display(&game, TtbTimeType::Main) {
-                                    ui.label(RichText::new(text).color(gold));
+                                    ui.label(RichText::new(text).color(theme.accent));
                                 } else {
                                     ui.label("—");
                                 }
                             } else if let Some(ttb) = platform.get_ttb_times(appid) {
-                                // Show HLTB data in light blue
-                                let light_blue = egui::Color32::from_rgb(120, 180, 255);
+                                // Show HLTB data in the secondary color
                                 if let Some(main) = ttb.main {
-                                    ui.label(RichText::new(format!("{:.0}h", main)).color(light_blue));
+                                    ui.label(RichText::new(format!("{:.0}h", main)).color(theme.secondary));
                                 } else if ttb.main_extra.is_some() || ttb.completionist.is_some() {
                                     // Has some other data, just not main
                                     ui.label("—");
                                 } else {
                                     // Scraped but HLTB has no data for this game
-                                    ui.label(RichText::new("n/a").weak());
+                                    ui.label(RichText::new(tr("table.ttb_not_available")).weak());
                                 }
                             } else {
                                 // Not yet scraped
@@ -568,6 +758,13 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
             });
         });
 
+    if let Some(label) = toggle_group {
+        if !collapsed.remove(&label) {
+            collapsed.insert(label);
+        }
+    }
+    ui.data_mut(|d| d.insert_temp(collapsed_id, collapsed));
+
     // Persist column width if it changed significantly (more than 1px difference)
     if (actual_name_col_width - name_col_width).abs() > 1.0 {
         platform.set_name_column_width(actual_name_col_width);
@@ -575,3 +772,171 @@ pub fn render_games_table<P: GamesTablePlatform>(ui: &mut Ui, platform: &mut P,
 
     needs_fetch
 }
+
+/// Header row of a group: name, game count and perfect games in the name column, totals in
+/// the playtime and achievement columns. Returns true when clicked (to collapse or open it).
+fn render_group_header(row: &mut TableRow, group: &GameGroup, is_collapsed: bool, theme: &ThemeColors, extra_columns: usize) -> bool {
+    let mut clicked = false;
+    row.col(|ui| {
+        let icon = if is_collapsed { regular::CARET_RIGHT } else { regular::CARET_DOWN };
+        clicked |= ui.small_button(icon.to_string()).clicked();
+        let label = ui.add(egui::Label::new(RichText::new(&group.label).strong().color(theme.accent)).sense(egui::Sense::click()));
+        clicked |= label.clicked();
+        ui.label(RichText::new(tr_args("table.group_games", &[("count", &group.members.len())])).weak());
+        if group.perfect > 0 {
+            ui.label(RichText::new(format!("{} {}", regular::TROPHY, tr_args("table.group_perfect", &[("count", &group.perfect)]))).weak());
+        }
+    });
+    row.col(|_| {});
+    row.col(|ui| {
+        ui.label(RichText::new(format!("{:.1}h", group.playtime_minutes as f64 / 60.0)).strong());
+    });
+    row.col(|ui| {
+        if group.total > 0 {
+            ui.label(RichText::new(format!("{} / {}", group.unlocked, group.total)).strong());
+        }
+    });
+    row.col(|ui| {
+        if let Some(avg) = group.avg_completion {
+            let response = ui.label(RichText::new(format!("{:.0}%", avg)).strong().color(theme.completion_color(avg)));
+            instant_tooltip(&response, tr("table.group_avg_tooltip"));
+        }
+    });
+    for _ in 0..extra_columns {
+        row.col(|_| {});
+    }
+    clicked
+}
+
+/// Whether the game was played on more than one platform or on a Steam Deck
+fn has_platform_breakdown(game: &crate::Game) -> bool {
+    game.platform_playtime.is_some_and(|p| {
+        let platforms = p.breakdown();
+        platforms.len() > 1 || p.deck > 0
+    })
+}
+
+/// "Played on: Windows 12.5h (80%) | Steam Deck 3.1h (20%)"
+fn render_platform_breakdown(ui: &mut Ui, game: &crate::Game) {
+    let Some(playtime) = game.platform_playtime else {
+        return;
+    };
+    let platforms = playtime.breakdown();
+    let total: u32 = platforms.iter().map(|(_, minutes)| minutes).sum();
+    ui.horizontal(|ui| {
+        ui.add_space(24.0); // Indent to align with name
+        ui.label(RichText::new(tr("table.played_on")).strong());
+        for (i, (label, minutes)) in platforms.iter().enumerate() {
+            if i > 0 {
+                ui.label(RichText::new("|").weak());
+            }
+            let share = *minutes as f32 / total.max(1) as f32 * 100.0;
+            ui.label(format!("{} {:.1}h", label, *minutes as f64 / 60.0));
+            ui.label(RichText::new(format!("({:.0}%)", share)).weak());
+        }
+    });
+}
+
+/// Small "scraped N days ago" badge, colored by how stale the data is
+fn render_scrape_age_badge(ui: &mut Ui, last_scrape: Option<chrono::DateTime<chrono::Utc>>, stale_days: u32) {
+    let Some(last_scrape) = last_scrape else {
+        ui.label(RichText::new(tr("table.never_scraped")).small().color(Color32::from_rgb(220, 80, 80)));
+        return;
+    };
+    let days = (chrono::Utc::now() - last_scrape).num_days().max(0);
+    let (text, color) = match days {
+        0 => (tr("table.scraped_today").to_string(), Color32::from_rgb(100, 180, 100)),
+        1 => (tr("table.scraped_yesterday").to_string(), Color32::from_rgb(100, 180, 100)),
+        d => {
+            let color = if d >= 2 * stale_days as i64 {
+                Color32::from_rgb(220, 80, 80)
+            } else if d >= stale_days as i64 {
+                Color32::from_rgb(230, 160, 60)
+            } else {
+                Color32::from_rgb(100, 180, 100)
+            };
+            (tr_args("table.scraped_days_ago", &[("days", &d)]), color)
+        }
+    };
+    let badge = ui.label(RichText::new(text).small().color(color));
+    instant_tooltip(&badge, tr_args("table.stale_tooltip", &[("days", &stale_days)]));
+}
+
+/// "you 23/50, rival 41/50" next to the name of an expanded row, colored by who is ahead
+fn render_head_to_head(ui: &mut Ui, mine: i32, theirs: i32, total: i32) {
+    let color = match mine.cmp(&theirs) {
+        std::cmp::Ordering::Greater => Color32::from_rgb(100, 180, 100),
+        std::cmp::Ordering::Less => Color32::from_rgb(220, 80, 80),
+        std::cmp::Ordering::Equal => ui.visuals().weak_text_color(),
+    };
+    let text = tr_args("table.head_to_head", &[("mine", &mine), ("theirs", &theirs), ("total", &total)]);
+    let badge = ui.label(RichText::new(format!("{} {}", regular::SWORD, text)).small().color(color));
+    instant_tooltip(&badge, tr("table.head_to_head_tooltip"));
+}
+
+/// "Community: avg 42% | 12% perfect | median 18.5h" in the TTB row of an expanded game
+fn render_community_stats(ui: &mut Ui, stats: &crate::CommunityGameStats) {
+    let mut parts = Vec::new();
+    if let Some(avg) = stats.avg_completion {
+        parts.push(tr_args("table.community_avg", &[("percent", &format!("{:.0}", avg))]));
+    }
+    if let Some(perfect) = stats.perfect_percent {
+        parts.push(tr_args("table.community_perfect", &[("percent", &format!("{:.0}", perfect))]));
+    }
+    let hours = format!("{:.1}", stats.median_playtime_minutes as f32 / 60.0);
+    parts.push(tr_args("table.community_median", &[("hours", &hours)]));
+
+    let response = ui.label(RichText::new(format!("{} {} {}", regular::USERS_THREE, tr("table.community"), parts.join(" | "))).weak());
+    instant_tooltip(&response, tr_args("table.community_tooltip", &[("players", &stats.players)]));
+}
+
+/// Owner badge for rows of a merged multi-account library
+fn render_owner_badge(ui: &mut Ui, owner: &str) {
+    let badge = ui.label(RichText::new(format!("{} {}", regular::USER, owner)).small().weak());
+    instant_tooltip(&badge, tr_args("table.owned_by", &[("owner", &owner)]));
+}
+
+/// Lock for games marked private on Steam, crossed-out eye for hidden ones (in the app or in Steam),
+/// archive box for abandoned ones
+fn render_visibility_badges(ui: &mut Ui, game: &crate::Game) {
+    if game.abandoned {
+        let badge = ui.label(RichText::new(regular::ARCHIVE.to_string()).small().weak());
+        instant_tooltip(&badge, tr("table.abandoned"));
+    }
+    if game.steam_private {
+        let badge = ui.label(RichText::new(regular::LOCK_SIMPLE.to_string()).small().weak());
+        instant_tooltip(&badge, tr("table.private_on_steam"));
+    }
+    let hidden_reason = match (game.hidden, game.steam_hidden) {
+        (true, true) => Some(tr("table.hidden_both")),
+        (true, false) => Some(tr("table.hidden_app")),
+        (false, true) => Some(tr("table.hidden_steam")),
+        (false, false) => None,
+    };
+    if let Some(reason) = hidden_reason {
+        let badge = ui.label(RichText::new(regular::EYE_SLASH.to_string()).small().weak());
+        instant_tooltip(&badge, reason);
+    }
+}
+
+/// Cloud save icon next to the play button - orange warning when saves may be out of date
+fn render_cloud_save_check(ui: &mut Ui, cloud: &crate::CloudSaveStatus) {
+    let now = chrono::Utc::now();
+    let warning = cloud.warning(now);
+    let (icon, color) = match warning {
+        Some(_) => (regular::CLOUD_WARNING, Color32::from_rgb(230, 140, 50)),
+        None => (regular::CLOUD_CHECK, ui.visuals().weak_text_color()),
+    };
+    let response = ui.label(RichText::new(icon.to_string()).color(color));
+    instant_tooltip_ui(&response, |ui| {
+        ui.label(RichText::new(tr("table.cloud_saves")).strong());
+        if let Some(warning) = &warning {
+            ui.label(RichText::new(warning).color(color));
+        }
+        match cloud.last_synced {
+            Some(synced) => ui.label(tr_args("table.cloud_last_synced", &[("when", &crate::format_relative(synced, now))])),
+            None => ui.label(tr("table.cloud_never_synced")),
+        };
+        ui.label(RichText::new(tr_args("table.cloud_files", &[("count", &cloud.file_count)])).weak().small());
+    });
+}
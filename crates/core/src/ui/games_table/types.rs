@@ -1,5 +1,9 @@
 //! Type definitions for games table
 
+use serde::{Deserialize, Serialize};
+
+use crate::tr;
+
 #[derive(Clone, Copy, PartialEq, Default)]
 pub enum SortColumn {
     #[default]
@@ -10,6 +14,7 @@ pub enum SortColumn {
     AchievementsPercent,
     TimeToBeat,
     Votes,
+    DeckPlaytime,
 }
 
 #[derive(Clone, Copy, PartialEq, Default)]
@@ -28,6 +33,46 @@ impl SortOrder {
     }
 }
 
+/// Secondary sort key for games the sort column ranks equal (e.g. all 0% games).
+/// Always applied in its natural direction, whatever the column's sort order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SortTieBreaker {
+    /// Per column: % then name, playtime then last played, last played then playtime,
+    /// achievements then %
+    #[default]
+    Auto,
+    /// Alphabetical
+    Name,
+    /// Most recently played first
+    LastPlayed,
+    /// Most played first
+    Playtime,
+    /// Highest completion first
+    Completion,
+}
+
+impl SortTieBreaker {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SortTieBreaker::Auto => tr("tie_break.auto"),
+            SortTieBreaker::Name => tr("tie_break.name"),
+            SortTieBreaker::LastPlayed => tr("tie_break.last_played"),
+            SortTieBreaker::Playtime => tr("tie_break.playtime"),
+            SortTieBreaker::Completion => tr("tie_break.completion"),
+        }
+    }
+
+    pub fn all() -> &'static [SortTieBreaker] {
+        &[
+            SortTieBreaker::Auto,
+            SortTieBreaker::Name,
+            SortTieBreaker::LastPlayed,
+            SortTieBreaker::Playtime,
+            SortTieBreaker::Completion,
+        ]
+    }
+}
+
 /// Tri-state filter: All, Only With, Only Without
 #[derive(Clone, Copy, PartialEq, Default)]
 pub enum TriFilter {
@@ -48,9 +93,77 @@ impl TriFilter {
     
     pub fn label(&self, with_text: &str, without_text: &str) -> String {
         match self {
-            TriFilter::All => "All".to_string(),
+            TriFilter::All => crate::tr("common.all").to_string(),
             TriFilter::With => with_text.to_string(),
             TriFilter::Without => without_text.to_string(),
         }
     }
 }
+
+/// Numeric range filter in hours: `None` bounds are open
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct RangeFilter {
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+}
+
+impl RangeFilter {
+    pub fn is_active(&self) -> bool {
+        self.min.is_some() || self.max.is_some()
+    }
+
+    pub fn contains(&self, hours: f32) -> bool {
+        self.min.is_none_or(|min| hours >= min) && self.max.is_none_or(|max| hours <= max)
+    }
+
+    pub fn label(&self) -> String {
+        match (self.min, self.max) {
+            (None, None) => tr("common.all").to_string(),
+            (Some(min), None) => format!("≥{}h", min),
+            (None, Some(max)) => format!("≤{}h", max),
+            (Some(min), Some(max)) => format!("{}-{}h", min, max),
+        }
+    }
+}
+
+/// Order of the achievement list in expanded game rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AchievementSort {
+    /// Unlocked first (newest unlock first), then locked by name
+    #[default]
+    UnlockedFirst,
+    /// Locked first by name, then unlocked (newest unlock first)
+    LockedFirst,
+    /// Unlocked in the order they were earned, then locked by name
+    UnlockDate,
+    /// Rarest (lowest global unlock percentage) first
+    Rarity,
+    /// Alphabetical by display name
+    Name,
+    /// Hardest community difficulty rating first
+    Difficulty,
+}
+
+impl AchievementSort {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AchievementSort::UnlockedFirst => tr("achievement_sort.unlocked_first"),
+            AchievementSort::LockedFirst => tr("achievement_sort.locked_first"),
+            AchievementSort::UnlockDate => tr("achievement_sort.unlock_date"),
+            AchievementSort::Rarity => tr("achievement_sort.rarity"),
+            AchievementSort::Name => tr("achievement_sort.name"),
+            AchievementSort::Difficulty => tr("achievement_sort.difficulty"),
+        }
+    }
+
+    pub fn all() -> &'static [AchievementSort] {
+        &[
+            AchievementSort::UnlockedFirst,
+            AchievementSort::LockedFirst,
+            AchievementSort::UnlockDate,
+            AchievementSort::Rarity,
+            AchievementSort::Name,
+            AchievementSort::Difficulty,
+        ]
+    }
+}
@@ -0,0 +1,167 @@
+//! Unlock history chart for expanded game rows: my unlocks per month plus the running total
+
+use chrono::{Datelike, NaiveDate};
+use egui::{self, Color32, RichText, Ui};
+use egui_plot::{Bar, BarChart, HLine, Line, Plot, PlotPoints};
+use crate::{tr, tr_args, GameAchievement};
+
+/// Height of the unlock graph section (header + plot) at font scale 1.0
+pub const UNLOCK_GRAPH_HEIGHT: f32 = 120.0;
+
+/// Unlocks bucketed by month, from the first to the last month with an unlock (gaps included)
+#[derive(Clone)]
+struct UnlockHistogram {
+    /// Achievements in the schema when computed (also part of the cache key)
+    total: usize,
+    /// Newest unlock time when computed - a new unlock invalidates the cached buckets
+    newest_unlock: i64,
+    /// First day of each month
+    months: Vec<NaiveDate>,
+    counts: Vec<u32>,
+}
+
+impl UnlockHistogram {
+    fn compute(achievements: &[GameAchievement]) -> Self {
+        let mut dates: Vec<NaiveDate> = achievements
+            .iter()
+            .filter(|a| a.achieved)
+            .filter_map(|a| a.unlocktime)
+            .filter_map(|t| t.date_naive().with_day(1))
+            .collect();
+        dates.sort();
+
+        let mut months = Vec::new();
+        let mut counts = Vec::new();
+        if let (Some(first), Some(last)) = (dates.first().copied(), dates.last().copied()) {
+            let mut month = first;
+            while month <= last {
+                months.push(month);
+                counts.push(dates.iter().filter(|d| **d == month).count() as u32);
+                let Some(next) = month.checked_add_months(chrono::Months::new(1)) else {
+                    break;
+                };
+                month = next;
+            }
+        }
+
+        Self {
+            total: achievements.len(),
+            newest_unlock: newest_unlock(achievements),
+            months,
+            counts,
+        }
+    }
+}
+
+fn newest_unlock(achievements: &[GameAchievement]) -> i64 {
+    achievements
+        .iter()
+        .filter(|a| a.achieved)
+        .filter_map(|a| a.unlocktime)
+        .map(|t| t.timestamp())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Whether the game has any unlocks with a date to chart
+pub fn has_unlock_graph(achievements: &[GameAchievement]) -> bool {
+    achievements.iter().any(|a| a.achieved && a.unlocktime.is_some())
+}
+
+/// Buckets for `appid`, kept in egui memory so re-expanding the row doesn't recompute them
+fn cached_histogram(ui: &Ui, appid: u64, achievements: &[GameAchievement]) -> UnlockHistogram {
+    let id = egui::Id::new(("unlock_histogram", appid));
+    let cached: Option<UnlockHistogram> = ui.data(|d| d.get_temp(id));
+    match cached {
+        Some(histogram)
+            if histogram.total == achievements.len() && histogram.newest_unlock == newest_unlock(achievements) =>
+        {
+            histogram
+        }
+        _ => {
+            let histogram = UnlockHistogram::compute(achievements);
+            ui.data_mut(|d| d.insert_temp(id, histogram.clone()));
+            histogram
+        }
+    }
+}
+
+/// Render my unlocks per month for a game, with the cumulative unlock count
+/// climbing towards the game's total achievement count
+pub fn render_unlock_graph(ui: &mut Ui, appid: u64, achievements: &[GameAchievement], accent: Color32) {
+    let histogram = cached_histogram(ui, appid, achievements);
+    if histogram.months.is_empty() {
+        return;
+    }
+
+    let unlocked: u32 = histogram.counts.iter().sum();
+    ui.horizontal(|ui| {
+        ui.label(RichText::new(tr("unlock_graph.title")).strong().size(12.0));
+        ui.label(
+            RichText::new(tr_args(
+                "unlock_graph.summary",
+                &[
+                    ("unlocked", &unlocked),
+                    ("total", &histogram.total),
+                    ("from", &histogram.months[0].format("%b %Y")),
+                    ("to", &histogram.months[histogram.months.len() - 1].format("%b %Y")),
+                ],
+            ))
+            .color(Color32::GRAY)
+            .size(10.0),
+        );
+    });
+
+    let labels: Vec<String> = histogram.months.iter().map(|m| m.format("%b %Y").to_string()).collect();
+    let axis_labels = labels.clone();
+    let bars: Vec<Bar> = histogram
+        .counts
+        .iter()
+        .enumerate()
+        .map(|(i, count)| Bar::new(i as f64, *count as f64).width(0.8))
+        .collect();
+    let cumulative: PlotPoints = histogram
+        .counts
+        .iter()
+        .scan(0u32, |sum, count| {
+            *sum += count;
+            Some(*sum)
+        })
+        .enumerate()
+        .map(|(i, sum)| [i as f64, sum as f64])
+        .collect();
+    let total = histogram.total as f64;
+
+    Plot::new(format!("unlock_graph_{}", appid))
+        .height(90.0)
+        .show_axes([true, true])
+        .allow_drag(false)
+        .allow_zoom(false)
+        .allow_scroll(false)
+        .include_y(0.0)
+        .include_y(total)
+        .x_axis_formatter(move |mark, _| {
+            let index = mark.value.round();
+            if index < 0.0 || (mark.value - index).abs() > f64::EPSILON {
+                return String::new();
+            }
+            axis_labels.get(index as usize).cloned().unwrap_or_default()
+        })
+        .label_formatter(move |name, point| {
+            let index = point.x.round();
+            if index < 0.0 {
+                return String::new();
+            }
+            let month = labels.get(index as usize).cloned().unwrap_or_default();
+            if name.is_empty() {
+                month
+            } else {
+                format!("{}\n{}: {:.0}", month, name, point.y)
+            }
+        })
+        .show(ui, |plot_ui| {
+            plot_ui.bar_chart(BarChart::new(tr("unlock_graph.per_month"), bars).color(accent.gamma_multiply(0.6)));
+            plot_ui.line(Line::new(tr("unlock_graph.cumulative"), cumulative).color(accent));
+            plot_ui.hline(HLine::new(tr("unlock_graph.total"), total).color(Color32::GRAY));
+        });
+}
@@ -0,0 +1,37 @@
+//! "Unowned played" section - games seen in recently played but missing from the library
+//! (free weekends, Family Sharing), listed apart from the games table
+
+use egui::{RichText, Ui};
+use egui_phosphor::regular;
+
+use crate::{tr, Game};
+use super::helpers::format_timestamp;
+
+/// Render the collapsible list of played games the account doesn't own (nothing when there are none)
+pub fn render_unowned_section(ui: &mut Ui, games: &[Game]) {
+    let unowned: Vec<&Game> = games.iter().filter(|g| !g.owned).collect();
+    if unowned.is_empty() {
+        return;
+    }
+
+    egui::CollapsingHeader::new(format!("{} {} ({})", regular::TICKET, tr("table.unowned_played"), unowned.len()))
+        .id_salt("unowned_played")
+        .show(ui, |ui| {
+            ui.label(RichText::new(tr("table.unowned_hint")).weak().small());
+            egui::Grid::new("unowned_played_grid").striped(true).num_columns(4).show(ui, |ui| {
+                ui.label(RichText::new(tr("table.name")).strong());
+                ui.label(RichText::new(tr("table.playtime")).strong());
+                ui.label(RichText::new(tr("table.achievements")).strong());
+                ui.label(RichText::new(tr("table.last_played")).strong());
+                ui.end_row();
+
+                for game in unowned {
+                    ui.label(&game.name);
+                    ui.label(format!("{:.1}h", game.playtime_forever as f32 / 60.0));
+                    ui.label(game.achievements_display());
+                    ui.label(game.rtime_last_played.filter(|&t| t > 0).map(format_timestamp).unwrap_or_else(|| "—".to_string()));
+                    ui.end_row();
+                }
+            });
+        });
+}
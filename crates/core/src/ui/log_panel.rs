@@ -1,12 +1,12 @@
 //! Log panel - shared between desktop and WASM
 //! 
 //! Renders: Activity log (achievements and first plays)
-//! Features: Star ratings, achievement selection, batch commenting
+//! Features: Star ratings, achievement selection, batch commenting, game and date filter
 
 use egui::{self, Color32, RichText, Ui, Sense, Response};
 use egui_phosphor::regular;
 
-use crate::LogEntry;
+use crate::{tr, tr_args, LogEntry, LogFilter};
 use super::{StatsPanelPlatform, instant_tooltip};
 
 // ============================================================================
@@ -25,11 +25,11 @@ fn flame_color_hover() -> Color32 {
 /// Get difficulty label for rating
 fn difficulty_label(rating: u8) -> &'static str {
     match rating {
-        1 => "Very easy",
-        2 => "Easy",
-        3 => "Moderate",
-        4 => "Hard",
-        5 => "Extreme",
+        1 => tr("difficulty.very_easy"),
+        2 => tr("difficulty.easy"),
+        3 => tr("difficulty.moderate"),
+        4 => tr("difficulty.hard"),
+        5 => tr("difficulty.extreme"),
         _ => "",
     }
 }
@@ -141,7 +141,10 @@ fn star_rating_widget(ui: &mut Ui, current_rating: Option<u8>) -> Option<u8> {
 
 /// Render the complete log panel content (inside a scroll area)
 pub fn render_log_content<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P) {
-    ui.heading(format!("{} Activity Log", regular::SCROLL));
+    ui.heading(format!("{} {}", regular::SCROLL, tr("log.activity_log")));
+    if let Some(filter) = platform.log_filter().cloned() {
+        render_log_filter(ui, platform, filter);
+    }
     ui.separator();
     
     render_log(ui, platform);
@@ -154,16 +157,103 @@ pub fn render_log_content<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P)
     }
 }
 
+/// Text typed into the log filter's date fields (kept while it isn't a valid date yet)
+#[derive(Clone, Default)]
+struct LogDateInput {
+    from: String,
+    to: String,
+}
+
+/// Render the game dropdown and date range above the log
+fn render_log_filter<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P, current: LogFilter) {
+    let input_id = ui.id().with("log_date_input");
+    let mut input: LogDateInput = ui.data_mut(|d| d.get_temp(input_id)).unwrap_or_else(|| LogDateInput {
+        from: current.from.map(|d| d.to_string()).unwrap_or_default(),
+        to: current.to.map(|d| d.to_string()).unwrap_or_default(),
+    });
+    let mut filter = current.clone();
+    
+    ui.horizontal_wrapped(|ui| {
+        // Only games that can have log entries (played or with unlocks), by name
+        let mut games: Vec<(u64, &str)> = platform
+            .games()
+            .iter()
+            .filter(|g| g.playtime_forever > 0 || g.achievements_unlocked.is_some_and(|u| u > 0))
+            .map(|g| (g.appid, g.name.as_str()))
+            .collect();
+        games.sort_by_cached_key(|(_, name)| name.to_lowercase());
+        let selected = filter
+            .appid
+            .and_then(|appid| games.iter().find(|(id, _)| *id == appid))
+            .map(|(_, name)| name.to_string())
+            .unwrap_or_else(|| tr("log.all_games").to_string());
+        
+        egui::ComboBox::from_id_salt("log_game_filter")
+            .selected_text(selected)
+            .width(160.0)
+            .height(300.0)
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut filter.appid, None, tr("log.all_games"));
+                for (appid, name) in &games {
+                    ui.selectable_value(&mut filter.appid, Some(*appid), *name);
+                }
+            });
+        
+        ui.label(format!("{} {}", regular::CALENDAR_BLANK, tr("log.from")));
+        date_field(ui, &mut input.from, &mut filter.from);
+        ui.label(tr("log.to"));
+        date_field(ui, &mut input.to, &mut filter.to);
+        
+        if filter.is_active() {
+            let btn = ui.small_button(regular::X.to_string());
+            if btn.clicked() {
+                filter = LogFilter::default();
+                input = LogDateInput::default();
+            }
+            instant_tooltip(&btn, tr("log.clear_filter"));
+        }
+    });
+    
+    ui.data_mut(|d| d.insert_temp(input_id, input));
+    if filter != current {
+        platform.set_log_filter(filter);
+    }
+}
+
+/// A YYYY-MM-DD text field; the date changes once the text parses (empty clears it)
+fn date_field(ui: &mut Ui, text: &mut String, date: &mut Option<chrono::NaiveDate>) {
+    let valid = text.trim().is_empty() || chrono::NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d").is_ok();
+    let mut edit = egui::TextEdit::singleline(text).hint_text("YYYY-MM-DD").desired_width(80.0);
+    if !valid {
+        edit = edit.text_color(Color32::from_rgb(230, 90, 90));
+    }
+    let response = ui.add(edit).on_hover_text(tr("log.date_hint"));
+    if response.changed() {
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            *date = None;
+        } else if let Ok(parsed) = chrono::NaiveDate::parse_from_str(trimmed, "%Y-%m-%d") {
+            *date = Some(parsed);
+        }
+    }
+}
+
 /// Render the activity log (achievements and first plays)
 pub fn render_log<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P) {
-    let achievement_color = Color32::from_rgb(255, 215, 0);
-    let game_color = Color32::from_rgb(100, 180, 255);
+    let theme = platform.theme_colors();
+    let achievement_color = theme.accent;
+    let game_color = theme.secondary;
     let alt_bg = Color32::from_rgba_unmultiplied(255, 255, 255, 8);
     
+    let time = platform.time_display();
     let log_entries = platform.log_entries().to_vec(); // Clone to avoid borrow issues
     
     if log_entries.is_empty() {
-        ui.label("No activity yet. Sync and scan to start tracking!");
+        if platform.log_filter().is_some_and(|f| f.is_active()) {
+            ui.label(tr("log.no_matches"));
+        } else {
+            ui.label(tr("log.empty"));
+        }
         return;
     }
     
@@ -202,7 +292,7 @@ pub fn render_log<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P) {
                                 .corner_radius(2.0)
                                 .sense(Sense::click())
                         );
-                        instant_tooltip(&response, timestamp.format("%Y-%m-%d").to_string());
+                        instant_tooltip(&response, time.format_log(*timestamp));
                         icon_response = Some(response);
                     }
                     
@@ -264,7 +354,7 @@ pub fn render_log<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P) {
                                     .fit_to_exact_size(egui::vec2(18.0, 18.0))
                                     .corner_radius(2.0)
                             );
-                            instant_tooltip(&response, timestamp.format("%Y-%m-%d").to_string());
+                            instant_tooltip(&response, time.format_log(*timestamp));
                         } else {
                             ui.add_space(22.0);
                         }
@@ -273,11 +363,51 @@ pub fn render_log<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P) {
                     }
                     
                     ui.label(RichText::new(game_name).color(game_color));
-                    ui.label(RichText::new("played for the first time!").small());
+                    ui.label(RichText::new(tr("log.first_play")).small());
                     
                     // No star rating for first plays - just fill the space
                 });
             }
+            LogEntry::SchemaChange { appid, game_name, added, removed, timestamp, game_icon_url } => {
+                ui.horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = 4.0;
+                    
+                    // Game icon - tooltip shows date
+                    match game_icon_url.as_deref().filter(|h| !h.is_empty()) {
+                        Some(icon_hash) => {
+                            let img_source = platform.game_icon_source(ui, *appid, icon_hash);
+                            let response = ui.add(
+                                egui::Image::new(img_source)
+                                    .fit_to_exact_size(egui::vec2(18.0, 18.0))
+                                    .corner_radius(2.0)
+                            );
+                            instant_tooltip(&response, time.format_log(*timestamp));
+                        }
+                        None => ui.add_space(22.0),
+                    }
+                    
+                    let summary = match (added.len(), removed.len()) {
+                        (1, 0) => tr("log.schema_added_one").to_string(),
+                        (a, 0) => tr_args("log.schema_added", &[("count", &a)]),
+                        (0, 1) => tr("log.schema_removed_one").to_string(),
+                        (0, r) => tr_args("log.schema_removed", &[("count", &r)]),
+                        (a, r) => tr_args("log.schema_changed", &[("added", &a), ("removed", &r)]),
+                    };
+                    
+                    ui.label(RichText::new(game_name).color(game_color));
+                    let response = ui.label(RichText::new(summary).small());
+                    
+                    // Tooltip lists the affected achievements
+                    let mut details = Vec::new();
+                    if !added.is_empty() {
+                        details.push(format!("{}\n{}", tr("log.schema_added_list"), added.join("\n")));
+                    }
+                    if !removed.is_empty() {
+                        details.push(format!("{}\n{}", tr("log.schema_removed_list"), removed.join("\n")));
+                    }
+                    instant_tooltip(&response, details.join("\n\n"));
+                });
+            }
         }
     }
     
@@ -302,25 +432,26 @@ fn render_comment_panel<P: StatsPanelPlatform>(
     platform: &mut P,
     selected: &[(u64, String, String)],
 ) {
+    let accent = platform.theme_colors().accent;
     ui.separator();
     
     // Panel header
     ui.horizontal(|ui| {
-        ui.label(RichText::new(format!("{} Comment on {} achievement(s)", regular::CHAT_CIRCLE, selected.len())).strong());
-        if ui.button(format!("{} Clear selection", regular::X)).clicked() {
+        ui.label(RichText::new(format!("{} {}", regular::CHAT_CIRCLE, tr_args("log.comment_title", &[("count", &selected.len())]))).strong());
+        if ui.button(format!("{} {}", regular::X, tr("log.clear_selection"))).clicked() {
             platform.clear_achievement_selections();
         }
     });
     
     // Show selected achievements
     ui.horizontal_wrapped(|ui| {
-        ui.label("Selected:");
+        ui.label(tr("log.selected"));
         for (_, _, name) in selected.iter().take(5) {
-            ui.label(RichText::new(name).color(Color32::from_rgb(255, 215, 0)).small());
+            ui.label(RichText::new(name).color(accent).small());
             ui.label("•");
         }
         if selected.len() > 5 {
-            ui.label(RichText::new(format!("and {} more...", selected.len() - 5)).small().italics());
+            ui.label(RichText::new(tr_args("log.and_more", &[("count", &(selected.len() - 5))])).small().italics());
         }
     });
     
@@ -329,7 +460,7 @@ fn render_comment_panel<P: StatsPanelPlatform>(
     let mut comment = platform.pending_comment().to_string();
     
     let text_edit = egui::TextEdit::multiline(&mut comment)
-        .hint_text("Add a comment about these achievements...")
+        .hint_text(tr("log.comment_hint"))
         .desired_rows(2);
     
     if ui.add(text_edit).changed() {
@@ -338,7 +469,7 @@ fn render_comment_panel<P: StatsPanelPlatform>(
     
     ui.horizontal(|ui| {
         let can_submit = !comment.trim().is_empty();
-        if ui.add_enabled(can_submit, egui::Button::new(format!("{} Submit", regular::PAPER_PLANE_TILT))).clicked() {
+        if ui.add_enabled(can_submit, egui::Button::new(format!("{} {}", regular::PAPER_PLANE_TILT, tr("log.comment_submit")))).clicked() {
             platform.submit_achievement_comment(comment.clone());
             platform.set_pending_comment(String::new());
             platform.clear_achievement_selections();
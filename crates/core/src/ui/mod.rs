@@ -4,28 +4,37 @@
 //! Platform-specific details (like image loading) are abstracted via traits.
 
 mod stats_panel;
+mod stats_dashboard;
 mod log_panel;
 mod games_table;
 mod ttb_dialog;
 mod tag_search;
+mod theme;
 
 pub use stats_panel::*;
+pub use stats_dashboard::*;
 pub use log_panel::*;
 pub use games_table::*;
 pub use ttb_dialog::*;
 pub use tag_search::*;
+pub use theme::*;
 
 use egui::{Response, RectAlign};
 use egui::containers::Popup;
 
 /// Show a tooltip immediately (no delay) positioned to the left
 pub fn instant_tooltip(response: &Response, text: impl Into<String>) {
+    let text = text.into();
+    instant_tooltip_ui(response, |ui| { ui.label(&text); });
+}
+
+/// Like `instant_tooltip`, but with arbitrary contents
+pub fn instant_tooltip_ui(response: &Response, add_contents: impl FnOnce(&mut egui::Ui)) {
     if response.hovered() {
-        let text = text.into();
         Popup::from_response(response)
             .align(RectAlign::LEFT_START)
             .gap(4.0)
-            .show(|ui| { ui.label(&text); });
+            .show(add_contents);
     }
 }
 
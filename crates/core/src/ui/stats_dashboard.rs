@@ -0,0 +1,260 @@
+//! Stats dashboard - full-page charts shared between desktop and WASM
+//!
+//! Renders: completion distribution, hours by tag, unlocks per month, library growth.
+//! Each chart is its own function so a platform can place them individually.
+
+use egui::{self, Color32, RichText, Ui};
+use egui_plot::{Bar, BarChart, Line, Plot, PlotPoints};
+use egui_phosphor::regular;
+
+use crate::{completion_bucket_label, completion_distribution, count_per_month, hours_by_top_tag, month_label, MonthCount, tr, COMPLETION_BUCKETS};
+use super::{StatsPanelConfig, StatsPanelPlatform};
+
+/// Tags shown as their own pie slice, the rest go to "Other"
+const PIE_MAX_SLICES: usize = 8;
+
+/// Slice colors, one per tag ("Other" is always gray)
+const PIE_COLORS: [Color32; PIE_MAX_SLICES] = [
+    Color32::from_rgb(100, 180, 255),
+    Color32::from_rgb(255, 150, 100),
+    Color32::from_rgb(120, 210, 120),
+    Color32::from_rgb(230, 120, 200),
+    Color32::from_rgb(255, 210, 90),
+    Color32::from_rgb(150, 130, 255),
+    Color32::from_rgb(90, 210, 200),
+    Color32::from_rgb(220, 100, 100),
+];
+const PIE_OTHER_COLOR: Color32 = Color32::from_gray(130);
+
+/// Plot height when the config doesn't fix one
+const DASHBOARD_PLOT_HEIGHT: f32 = 220.0;
+
+/// Below this width the charts are stacked instead of laid out in two columns
+const TWO_COLUMN_MIN_WIDTH: f32 = 800.0;
+
+/// Render all dashboard charts (inside a scroll area), two per row when there is room
+pub fn render_stats_dashboard<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P, config: &StatsPanelConfig) {
+    if platform.games().is_empty() {
+        ui.label(tr("stats.sync_first"));
+        return;
+    }
+
+    if ui.available_width() >= TWO_COLUMN_MIN_WIDTH {
+        ui.columns(2, |columns| {
+            render_completion_histogram(&mut columns[0], platform, config);
+            render_hours_by_tag(&mut columns[1], platform);
+        });
+        ui.add_space(16.0);
+        ui.columns(2, |columns| {
+            render_unlocks_per_month(&mut columns[0], platform, config);
+            render_library_growth(&mut columns[1], platform, config);
+        });
+    } else {
+        render_completion_histogram(ui, platform, config);
+        ui.add_space(16.0);
+        render_hours_by_tag(ui, platform);
+        ui.add_space(16.0);
+        render_unlocks_per_month(ui, platform, config);
+        ui.add_space(16.0);
+        render_library_growth(ui, platform, config);
+    }
+}
+
+/// Bar chart plot with fixed bounds (no drag/zoom) and the given x axis labels
+fn bar_plot<'a>(plot_id: &'a str, ui: &Ui, config: &StatsPanelConfig, x_label: fn(f64) -> String) -> Plot<'a> {
+    let mut plot = Plot::new(plot_id)
+        .height(config.plot_height.unwrap_or(DASHBOARD_PLOT_HEIGHT))
+        .width(ui.available_width())
+        .include_y(0.0)
+        .allow_drag(false)
+        .allow_zoom(false)
+        .allow_scroll(false)
+        .x_axis_formatter(move |mark, _range| x_label(mark.value));
+    if !config.show_plot_axes {
+        plot = plot.show_axes([false, true]);
+    }
+    plot
+}
+
+/// Render the completion distribution: games with achievements per 10% completion bucket
+pub fn render_completion_histogram<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P, config: &StatsPanelConfig) {
+    ui.heading(format!("{} {}", regular::CHART_BAR, tr("dashboard.completion_distribution")));
+    ui.separator();
+
+    let buckets = completion_distribution(&platform.stats_games());
+    if buckets.iter().all(|count| *count == 0) {
+        ui.label(RichText::new(tr("dashboard.no_completion_data")).weak());
+        return;
+    }
+
+    let accent = platform.theme_colors().accent;
+    let bars: Vec<Bar> = buckets
+        .iter()
+        .enumerate()
+        .map(|(i, count)| Bar::new(i as f64, *count as f64).name(completion_bucket_label(i)).width(0.8))
+        .collect();
+    bar_plot("dashboard_completion", ui, config, |x| {
+        let bucket = x.round();
+        if (0.0..COMPLETION_BUCKETS as f64).contains(&bucket) && (x - bucket).abs() < 0.01 {
+            completion_bucket_label(bucket as usize)
+        } else {
+            String::new()
+        }
+    })
+    .show(ui, |plot_ui| {
+        plot_ui.bar_chart(BarChart::new(tr("dashboard.games"), bars).color(accent));
+    });
+}
+
+/// Render a pie chart of playtime by each game's most-voted tag, with a legend
+pub fn render_hours_by_tag<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P) {
+    ui.heading(format!("{} {}", regular::CHART_PIE, tr("dashboard.hours_by_tag")));
+    ui.separator();
+
+    let slices = hours_by_top_tag(&platform.stats_games(), |appid| platform.tags_for_game(appid), PIE_MAX_SLICES);
+    // Without any tagged game the pie would be a single "Other" slice
+    if slices.iter().all(|s| s.tag.is_none()) {
+        ui.label(RichText::new(tr("dashboard.no_tag_data")).weak());
+        return;
+    }
+
+    let total: f32 = slices.iter().map(|s| s.hours).sum();
+    let colored: Vec<(String, f32, Color32)> = slices
+        .iter()
+        .enumerate()
+        .map(|(i, slice)| match &slice.tag {
+            Some(tag) => (tag.clone(), slice.hours, PIE_COLORS[i % PIE_COLORS.len()]),
+            None => (tr("dashboard.other").to_string(), slice.hours, PIE_OTHER_COLOR),
+        })
+        .collect();
+
+    ui.horizontal(|ui| {
+        let diameter = DASHBOARD_PLOT_HEIGHT.min(ui.available_width() / 2.0);
+        let (rect, response) = ui.allocate_exact_size(egui::vec2(diameter, diameter), egui::Sense::hover());
+        let hovered = paint_pie(ui, rect, &colored, total, response.hover_pos());
+        if let Some(i) = hovered {
+            let (tag, hours, _) = &colored[i];
+            response.on_hover_text(format!("{}\n{:.0}h ({:.1}%)", tag, hours, hours / total * 100.0));
+        }
+
+        ui.add_space(12.0);
+        ui.vertical(|ui| {
+            for (tag, hours, color) in &colored {
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(regular::SQUARE).color(*color));
+                    ui.label(tag);
+                    ui.label(RichText::new(format!("{:.0}h", hours)).weak());
+                });
+            }
+        });
+    });
+}
+
+/// Paint the pie slices (a triangle fan per slice) and return the slice under the pointer
+fn paint_pie(ui: &Ui, rect: egui::Rect, slices: &[(String, f32, Color32)], total: f32, pointer: Option<egui::Pos2>) -> Option<usize> {
+    let center = rect.center();
+    let radius = rect.width().min(rect.height()) / 2.0 - 2.0;
+    let pointer_angle = pointer
+        .map(|p| p - center)
+        .filter(|offset| offset.length() <= radius)
+        .map(|offset| offset.y.atan2(offset.x).rem_euclid(std::f32::consts::TAU));
+
+    let sweeps: Vec<f32> = slices.iter().map(|(_, hours, _)| hours / total * std::f32::consts::TAU).collect();
+    let hovered = pointer_angle.and_then(|angle| {
+        let mut end = 0.0;
+        sweeps.iter().position(|sweep| {
+            end += sweep;
+            angle < end
+        })
+    });
+
+    // The hovered slice stays bright, the others dim
+    let mut mesh = egui::Mesh::default();
+    let mut start = 0.0;
+    for (i, ((_, _, color), sweep)) in slices.iter().zip(sweeps).enumerate() {
+        let color = if hovered.is_some_and(|h| h != i) { color.gamma_multiply(0.5) } else { *color };
+
+        // Roughly one segment per 3 degrees keeps the edge smooth
+        let segments = ((sweep / 0.05).ceil() as usize).max(1);
+        let center_index = mesh.vertices.len() as u32;
+        mesh.colored_vertex(center, color);
+        for s in 0..=segments {
+            let angle = start + sweep * s as f32 / segments as f32;
+            mesh.colored_vertex(center + radius * egui::vec2(angle.cos(), angle.sin()), color);
+            if s > 0 {
+                let last = mesh.vertices.len() as u32 - 1;
+                mesh.add_triangle(center_index, last - 1, last);
+            }
+        }
+        start += sweep;
+    }
+    ui.painter().add(egui::Shape::mesh(mesh));
+    hovered
+}
+
+/// Render achievement unlocks per calendar month
+pub fn render_unlocks_per_month<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P, config: &StatsPanelConfig) {
+    ui.heading(format!("{} {}", regular::TROPHY, tr("dashboard.unlocks_per_month")));
+    ui.separator();
+
+    let time = platform.time_display();
+    let months = count_per_month(platform.unlock_times().iter().copied(), &time);
+    if months.is_empty() {
+        ui.label(RichText::new(tr("dashboard.no_unlocks")).weak());
+        return;
+    }
+
+    let accent = platform.theme_colors().accent;
+    bar_plot("dashboard_unlocks", ui, config, month_axis_label)
+        .show(ui, |plot_ui| {
+            plot_ui.bar_chart(BarChart::new(tr("dashboard.unlocks_per_month"), month_bars(&months)).color(accent));
+        });
+}
+
+/// Render library growth: games added per month as bars, the running total as a line
+pub fn render_library_growth<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P, config: &StatsPanelConfig) {
+    ui.heading(format!("{} {}", regular::BOOKS, tr("dashboard.library_growth")));
+    ui.separator();
+
+    let time = platform.time_display();
+    let months = count_per_month(platform.stats_games().iter().map(|g| g.added_at), &time);
+    if months.is_empty() {
+        ui.label(RichText::new(tr("stats.no_history")).weak());
+        return;
+    }
+
+    let mut total = 0.0;
+    let running_total: Vec<[f64; 2]> = months
+        .iter()
+        .map(|m| {
+            total += m.count as f64;
+            [m.index() as f64, total]
+        })
+        .collect();
+
+    let total_color = Color32::from_rgb(100, 180, 255);
+    let added_color = Color32::from_rgb(255, 150, 100);
+    bar_plot("dashboard_library_growth", ui, config, month_axis_label)
+        .legend(egui_plot::Legend::default())
+        .show(ui, |plot_ui| {
+            plot_ui.bar_chart(BarChart::new(tr("dashboard.games_added"), month_bars(&months)).color(added_color));
+            plot_ui.line(Line::new(tr("dashboard.library_size"), PlotPoints::from(running_total)).color(total_color));
+        });
+}
+
+/// One bar per month, named after the month for the hover label
+fn month_bars(months: &[MonthCount]) -> Vec<Bar> {
+    months
+        .iter()
+        .map(|m| Bar::new(m.index() as f64, m.count as f64).name(m.label()).width(0.8))
+        .collect()
+}
+
+/// Month name on whole-number axis marks, nothing in between
+fn month_axis_label(x: f64) -> String {
+    if (x - x.round()).abs() < 0.01 {
+        month_label(x.round() as i32)
+    } else {
+        String::new()
+    }
+}
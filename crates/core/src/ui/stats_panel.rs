@@ -1,18 +1,40 @@
 //! Stats panel - shared between desktop and WASM
 //! 
-//! Renders: Games over time graph, achievement progress, breakdown stats
+//! Renders: Games over time graph, achievement progress, completion forecast, breakdown stats, per-tag stats, goals
+
+use std::borrow::Cow;
 
 use egui::{self, Color32, RichText, Ui};
-use egui_plot::{Line, Plot, PlotPoints};
+use egui_plot::{Bar, BarChart, Line, Plot, PlotPoints, Points};
 use egui_phosphor::regular;
 
-use crate::{Game, RunHistory, AchievementHistory, LogEntry};
+use crate::{Game, RunHistory, AchievementHistory, LogEntry, LogFilter, AchievementRecords, TimeDisplaySettings, completion_trend, ForecastEta, FORECAST_WINDOW_DAYS, tag_stats, sort_tag_stats, TagStatsColumn, Goal, GoalKind, goal_progress, tr, tr_args};
+use super::{render_tag_search, TagSearchState};
+use super::ThemeColors;
 
 /// Platform-specific operations needed for the stats panel
 pub trait StatsPanelPlatform {
     /// Get the list of games
     fn games(&self) -> &[Game];
     
+    /// Whether soundtracks, tools, demos etc. are left out of the statistics
+    fn exclude_non_games_from_stats(&self) -> bool { false }
+    
+    /// Whether games played without owning them (free weekends, Family Sharing) count in the statistics
+    fn include_unowned_in_stats(&self) -> bool { false }
+    
+    /// Games counted in the statistics
+    fn stats_games(&self) -> Cow<'_, [Game]> {
+        let exclude_non_games = self.exclude_non_games_from_stats();
+        let include_unowned = self.include_unowned_in_stats();
+        let counts = |g: &Game| (!exclude_non_games || g.is_game_app()) && (include_unowned || g.owned);
+        if self.games().iter().all(counts) {
+            Cow::Borrowed(self.games())
+        } else {
+            Cow::Owned(self.games().iter().filter(|g| counts(g)).cloned().collect())
+        }
+    }
+    
     /// Get run history data
     fn run_history(&self) -> &[RunHistory];
     
@@ -22,6 +44,12 @@ pub trait StatsPanelPlatform {
     /// Get log entries
     fn log_entries(&self) -> &[LogEntry];
     
+    /// Current activity log filter (None = the platform can't filter the log)
+    fn log_filter(&self) -> Option<&LogFilter> { None }
+    
+    /// Change the activity log filter (the platform reloads the entries)
+    fn set_log_filter(&mut self, _filter: LogFilter) {}
+    
     /// Whether to include unplayed games in average calculation
     fn include_unplayed_in_avg(&self) -> bool;
     
@@ -35,6 +63,12 @@ pub trait StatsPanelPlatform {
     /// Resolve an achievement icon URL to an ImageSource
     fn achievement_icon_source(&self, ui: &Ui, icon_url: &str) -> egui::ImageSource<'static>;
     
+    /// Colors for highlights and completion states (default: dark palette)
+    fn theme_colors(&self) -> ThemeColors { ThemeColors::default() }
+    
+    /// Time zone and timestamp style preferences (default: UTC dates)
+    fn time_display(&self) -> TimeDisplaySettings { TimeDisplaySettings::default() }
+    
     // ========================================================================
     // Graph tab state (for switching between different graph views)
     // ========================================================================
@@ -113,6 +147,62 @@ pub trait StatsPanelPlatform {
     fn get_achievement_avg_rating(&self, _appid: u64, _apiname: &str) -> Option<(f32, i32)> {
         None
     }
+
+    /// Get the community vote distribution for an achievement (counts for ratings 1-5)
+    /// Returns None if no distribution is known
+    fn get_achievement_rating_votes(&self, _appid: u64, _apiname: &str) -> Option<[i32; 5]> {
+        None
+    }
+    
+    // ========================================================================
+    // Records (personal bests and milestones)
+    // ========================================================================
+    
+    /// Get computed personal records (None = records section is hidden)
+    fn achievement_records(&self) -> Option<&AchievementRecords> { None }
+    
+    // ========================================================================
+    // Goals (backlog goals with progress bars)
+    // ========================================================================
+    
+    /// The user's goals (None = goals section is hidden)
+    fn goals(&self) -> Option<&[Goal]> { None }
+    
+    /// Add a new goal
+    fn add_goal(&mut self, _goal: Goal) {}
+    
+    /// Remove the goal created at `created_at`
+    fn remove_goal(&mut self, _created_at: chrono::DateTime<chrono::Utc>) {}
+    
+    // ========================================================================
+    // Tags (per-tag breakdown, hidden when no tags are known)
+    // ========================================================================
+    
+    /// All known tag names
+    fn tag_names(&self) -> &[String] { &[] }
+    
+    /// Cached (tag, votes) list for a game
+    fn tags_for_game(&self, _appid: u64) -> Option<&[(String, u32)]> { None }
+    
+    /// Completionist time to beat in hours from the platform's TTB cache
+    fn ttb_completionist_hours(&self, _appid: u64) -> Option<f32> { None }
+    
+    // ========================================================================
+    // Stats dashboard
+    // ========================================================================
+    
+    /// Unlock time of every achievement (defaults to the unlocks in the activity log)
+    fn unlock_times(&self) -> Cow<'_, [chrono::DateTime<chrono::Utc>]> {
+        Cow::Owned(
+            self.log_entries()
+                .iter()
+                .filter_map(|entry| match entry {
+                    LogEntry::Achievement { timestamp, .. } => Some(*timestamp),
+                    _ => None,
+                })
+                .collect(),
+        )
+    }
 }
 
 /// Configuration for how the stats panel should render
@@ -170,7 +260,21 @@ pub fn render_stats_content<P: StatsPanelPlatform>(
     ui.add_space(16.0);
     render_achievement_progress(ui, platform, config);
     ui.add_space(16.0);
+    render_forecast(ui, platform);
+    ui.add_space(16.0);
     render_breakdown(ui, platform);
+    if platform.goals().is_some() {
+        ui.add_space(16.0);
+        render_goals(ui, platform);
+    }
+    if !platform.tag_names().is_empty() {
+        ui.add_space(16.0);
+        render_tag_breakdown(ui, platform, config);
+    }
+    if platform.achievement_records().is_some() {
+        ui.add_space(16.0);
+        render_records(ui, platform);
+    }
 }
 
 /// Calculate Y-axis bounds with padding for unbounded values (e.g. game counts)
@@ -187,28 +291,172 @@ fn calc_y_bounds_unbounded(values: &[f64]) -> (f64, f64) {
     ((min_y - padding).max(0.0), max_y + padding)
 }
 
+/// Time window shown by the history graphs (kept in egui memory per graph)
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+enum HistoryRange {
+    Days30,
+    Days90,
+    Year,
+    #[default]
+    All,
+}
+
+impl HistoryRange {
+    const ALL: [HistoryRange; 4] = [HistoryRange::Days30, HistoryRange::Days90, HistoryRange::Year, HistoryRange::All];
+
+    fn label(self) -> &'static str {
+        match self {
+            HistoryRange::Days30 => tr("stats.range_30d"),
+            HistoryRange::Days90 => tr("stats.range_90d"),
+            HistoryRange::Year => tr("stats.range_year"),
+            HistoryRange::All => tr("common.all"),
+        }
+    }
+
+    fn days(self) -> Option<i64> {
+        match self {
+            HistoryRange::Days30 => Some(30),
+            HistoryRange::Days90 => Some(90),
+            HistoryRange::Year => Some(365),
+            HistoryRange::All => None,
+        }
+    }
+}
+
+/// History graphs use days since the Unix epoch as x values, so uneven snapshot intervals keep their real spacing
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+fn plot_x(at: chrono::DateTime<chrono::Utc>) -> f64 {
+    at.timestamp() as f64 / SECONDS_PER_DAY
+}
+
+fn plot_date(x: f64) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::from_timestamp((x * SECONDS_PER_DAY) as i64, 0)
+}
+
+/// Axis label for a plot x value: coarser the wider the visible range
+fn format_axis_date(x: f64, visible_days: f64) -> String {
+    let Some(date) = plot_date(x) else {
+        return String::new();
+    };
+    let format = if visible_days > 3.0 * 365.0 {
+        "%Y"
+    } else if visible_days > 120.0 {
+        "%b %Y"
+    } else {
+        "%b %d"
+    };
+    date.format(format).to_string()
+}
+
+/// Range preset buttons (right-aligned); returns the selected range and whether it changed this frame
+fn render_history_range_buttons(ui: &mut Ui, plot_id: &str) -> (HistoryRange, bool) {
+    let memory_id = egui::Id::new((plot_id, "range"));
+    let current = ui.data(|d| d.get_temp::<HistoryRange>(memory_id)).unwrap_or_default();
+    let mut selected = current;
+    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+        for range in HistoryRange::ALL.iter().rev() {
+            if ui.selectable_label(selected == *range, range.label()).clicked() {
+                selected = *range;
+            }
+        }
+    });
+    if selected != current {
+        ui.data_mut(|d| d.insert_temp(memory_id, selected));
+    }
+    (selected, selected != current)
+}
+
+/// Points of a history series inside the selected range
+fn history_points<T>(
+    history: &[T],
+    range: HistoryRange,
+    at: impl Fn(&T) -> chrono::DateTime<chrono::Utc>,
+    value: impl Fn(&T) -> f64,
+) -> Vec<[f64; 2]> {
+    let since = range.days().map(|days| plot_x(chrono::Utc::now()) - days as f64);
+    history
+        .iter()
+        .map(|h| [plot_x(at(h)), value(h)])
+        .filter(|[x, _]| since.is_none_or(|since| *x >= since))
+        .collect()
+}
+
+/// Plot with a date x axis, hover labels (series, date, value) and the config's size/interaction settings
+fn history_plot<'a>(
+    plot_id: &'a str,
+    ui: &Ui,
+    config: &StatsPanelConfig,
+    (y_min, y_max): (f64, f64),
+    reset: bool,
+    format_value: fn(f64) -> String,
+) -> Plot<'a> {
+    let mut plot = Plot::new(plot_id)
+        .auto_bounds(egui::Vec2b::new(true, true))
+        .include_y(y_min)
+        .include_y(y_max)
+        .x_axis_formatter(|mark, range| format_axis_date(mark.value, range.end() - range.start()))
+        .label_formatter(move |name, value| {
+            let date = plot_date(value.x).map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default();
+            if name.is_empty() {
+                format!("{}\n{}", date, format_value(value.y))
+            } else {
+                format!("{}\n{}: {}", name, date, format_value(value.y))
+            }
+        });
+    
+    if reset {
+        plot = plot.reset();
+    }
+    
+    if let Some(height) = config.plot_height {
+        plot = plot.height(height).width(ui.available_width());
+    } else {
+        plot = plot.view_aspect(2.0);
+    }
+    
+    if !config.show_plot_axes {
+        plot = plot.show_axes([false, true]);
+    }
+    
+    if config.allow_plot_interaction {
+        // Zoom and pan along the time axis only, the y range follows the data
+        plot = plot.allow_zoom([true, false]).allow_drag([true, false]).allow_scroll([true, false]);
+    } else {
+        plot = plot
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false);
+    }
+    
+    plot
+}
+
 /// Render the "Games Over Time" graph
 pub fn render_games_over_time<P: StatsPanelPlatform>(
     ui: &mut Ui,
     platform: &mut P,
     config: &StatsPanelConfig,
 ) {
-    ui.heading("Games Over Time");
+    ui.heading(tr("stats.games_over_time"));
     ui.separator();
     
     // Get current tab before any borrows
     let current_tab = platform.games_graph_tab();
     
-    // Tab buttons for switching between graph views
+    // Tab buttons for switching between graph views, range presets on the right
     let mut new_tab = current_tab;
+    let mut range = (HistoryRange::All, false);
     ui.horizontal(|ui| {
-        if ui.selectable_label(current_tab == 0, "Total Games").clicked() {
+        if ui.selectable_label(current_tab == 0, tr("stats.tab_total_games")).clicked() {
             new_tab = 0;
         }
-        if ui.selectable_label(current_tab == 1, "Unplayed Games").clicked() {
+        if ui.selectable_label(current_tab == 1, tr("stats.tab_unplayed_games")).clicked() {
             new_tab = 1;
         }
+        range = render_history_range_buttons(ui, "games_history");
     });
+    let (range, range_changed) = range;
     
     // Apply tab change if needed
     if new_tab != current_tab {
@@ -220,66 +468,34 @@ pub fn render_games_over_time<P: StatsPanelPlatform>(
     ui.add_space(4.0);
     
     // Build data for the selected tab
-    let (points, y_min, y_max, line_name, line_color) = if run_history.is_empty() {
-        // Empty plot - still need to show it for WASM layout
-        (PlotPoints::default(), 0.0, 100.0, "Total Games", Color32::from_rgb(100, 180, 255))
-    } else if new_tab == 0 {
-        // Total Games graph
-        let values: Vec<f64> = run_history.iter().map(|h| h.total_games as f64).collect();
-        let pts: PlotPoints = run_history.iter().enumerate()
-            .map(|(i, h)| [i as f64, h.total_games as f64]).collect();
-        let (y_min, y_max) = calc_y_bounds_unbounded(&values);
-        (pts, y_min, y_max, "Total Games", Color32::from_rgb(100, 180, 255))
+    let (points, line_name, line_color) = if new_tab == 0 {
+        let pts = history_points(run_history, range, |h| h.run_at, |h| h.total_games as f64);
+        (pts, tr("stats.tab_total_games"), Color32::from_rgb(100, 180, 255))
     } else {
-        // Unplayed Games graph
-        let values: Vec<f64> = run_history.iter().map(|h| h.unplayed_games as f64).collect();
-        let pts: PlotPoints = run_history.iter().enumerate()
-            .map(|(i, h)| [i as f64, h.unplayed_games as f64]).collect();
-        let (y_min, y_max) = calc_y_bounds_unbounded(&values);
-        (pts, y_min, y_max, "Unplayed Games", Color32::from_rgb(255, 150, 100))
+        let pts = history_points(run_history, range, |h| h.run_at, |h| h.unplayed_games as f64);
+        (pts, tr("stats.tab_unplayed_games"), Color32::from_rgb(255, 150, 100))
     };
+    let values: Vec<f64> = points.iter().map(|[_, y]| *y).collect();
+    let bounds = if values.is_empty() { (0.0, 100.0) } else { calc_y_bounds_unbounded(&values) };
+    let point_count = points.len();
     
-    let line = Line::new(line_name, points).color(line_color);
+    let line = Line::new(line_name, PlotPoints::from(points.clone())).color(line_color);
+    let markers = Points::new(line_name, PlotPoints::from(points)).color(line_color).radius(2.0);
     
-    // Reset zoom when switching tabs so the new line fits in view
-    let tab_changed = new_tab != current_tab;
+    // Reset zoom when switching tabs or ranges so the new data fits in view
+    let reset = new_tab != current_tab || range_changed;
     
     // Use consistent plot ID - changing IDs can cause WASM layout issues
-    let mut plot = Plot::new("games_history")
-        .auto_bounds(egui::Vec2b::new(true, true))
-        .include_y(y_min)
-        .include_y(y_max);
-    
-    if tab_changed {
-        plot = plot.reset();
-    }
-    
-    if let Some(height) = config.plot_height {
-        plot = plot.height(height).width(ui.available_width());
-    } else {
-        plot = plot.view_aspect(2.0);
-    }
-    
-    if !config.show_plot_axes {
-        plot = plot.show_axes([false, true]);
-    }
-    
-    if !config.allow_plot_interaction {
-        plot = plot
-            .allow_drag(false)
-            .allow_zoom(false)
-            .allow_scroll(false);
-    }
-    
+    let plot = history_plot("games_history", ui, config, bounds, reset, |y| format!("{:.0}", y));
     plot.show(ui, |plot_ui| {
         plot_ui.line(line);
+        plot_ui.points(markers);
     });
     
     if run_history.is_empty() {
-        ui.label("No history yet. Complete a full scan to start tracking!");
+        ui.label(tr("stats.no_history"));
     } else {
-        // Debug: show data point count
-        ui.small(format!("{} data points", run_history.len()));
+        ui.small(tr_args("stats.data_points", &[("count", &point_count)]));
     }
 }
 
@@ -303,22 +519,25 @@ pub fn render_achievement_progress<P: StatsPanelPlatform>(
     platform: &mut P,
     config: &StatsPanelConfig,
 ) {
-    ui.heading("Achievement Progress");
+    ui.heading(tr("stats.achievement_progress"));
     ui.separator();
     
     // Get current tab before any borrows
     let current_tab = platform.achievements_graph_tab();
     
-    // Tab buttons for switching between graph views
+    // Tab buttons for switching between graph views, range presets on the right
     let mut new_tab = current_tab;
+    let mut range = (HistoryRange::All, false);
     ui.horizontal(|ui| {
-        if ui.selectable_label(current_tab == 0, "Avg Game Completion %").clicked() {
+        if ui.selectable_label(current_tab == 0, tr("stats.tab_avg_completion")).clicked() {
             new_tab = 0;
         }
-        if ui.selectable_label(current_tab == 1, "Overall Achievement %").clicked() {
+        if ui.selectable_label(current_tab == 1, tr("stats.tab_overall")).clicked() {
             new_tab = 1;
         }
+        range = render_history_range_buttons(ui, "achievements_history");
     });
+    let (range, range_changed) = range;
     
     // Apply tab change if needed
     if new_tab != current_tab {
@@ -330,77 +549,124 @@ pub fn render_achievement_progress<P: StatsPanelPlatform>(
     ui.add_space(4.0);
     
     // Build data for the selected tab
-    let (points, y_min, y_max, line_name, line_color) = if achievement_history.is_empty() {
-        // Empty plot - still need to show it for WASM layout
-        (PlotPoints::default(), 0.0, 100.0, "Avg Game Completion %", Color32::from_rgb(100, 200, 100))
-    } else if new_tab == 0 {
-        // Avg Game Completion % graph
-        let values: Vec<f64> = achievement_history.iter().map(|h| h.avg_completion_percent as f64).collect();
-        let pts: PlotPoints = achievement_history.iter().enumerate()
-            .map(|(i, h)| [i as f64, h.avg_completion_percent as f64]).collect();
-        let (y_min, y_max) = calc_y_bounds(&values);
-        (pts, y_min, y_max, "Avg Game Completion %", Color32::from_rgb(100, 200, 100))
+    let (points, line_name, line_color) = if new_tab == 0 {
+        let pts = history_points(achievement_history, range, |h| h.recorded_at, |h| h.avg_completion_percent as f64);
+        (pts, tr("stats.tab_avg_completion"), Color32::from_rgb(100, 200, 100))
     } else {
-        // Overall Achievement % graph
-        let values: Vec<f64> = achievement_history.iter().map(|h| {
+        let pts = history_points(achievement_history, range, |h| h.recorded_at, |h| {
             if h.total_achievements > 0 {
                 h.unlocked_achievements as f64 / h.total_achievements as f64 * 100.0
             } else { 0.0 }
-        }).collect();
-        let pts: PlotPoints = achievement_history.iter().enumerate().map(|(i, h)| {
-            let pct = if h.total_achievements > 0 {
-                h.unlocked_achievements as f64 / h.total_achievements as f64 * 100.0
-            } else { 0.0 };
-            [i as f64, pct]
-        }).collect();
-        let (y_min, y_max) = calc_y_bounds(&values);
-        (pts, y_min, y_max, "Overall Achievement %", Color32::from_rgb(100, 150, 255))
+        });
+        (pts, tr("stats.tab_overall"), Color32::from_rgb(100, 150, 255))
     };
+    let values: Vec<f64> = points.iter().map(|[_, y]| *y).collect();
+    let bounds = calc_y_bounds(&values);
     
-    let line = Line::new(line_name, points).color(line_color);
+    let line = Line::new(line_name, PlotPoints::from(points.clone())).color(line_color);
+    let markers = Points::new(line_name, PlotPoints::from(points)).color(line_color).radius(2.0);
     
-    // Reset zoom when switching tabs so the new line fits in view
-    let tab_changed = new_tab != current_tab;
+    // Reset zoom when switching tabs or ranges so the new data fits in view
+    let reset = new_tab != current_tab || range_changed;
     
     // Use consistent plot ID - changing IDs can cause WASM layout issues
-    let mut plot = Plot::new("achievements_history")
-        .auto_bounds(egui::Vec2b::new(true, true))
-        .include_y(y_min)
-        .include_y(y_max);
-    
-    if tab_changed {
-        plot = plot.reset();
-    }
-    
-    if let Some(height) = config.plot_height {
-        plot = plot.height(height).width(ui.available_width());
-    } else {
-        plot = plot.view_aspect(2.0);
-    }
-    
-    if !config.show_plot_axes {
-        plot = plot.show_axes([false, true]);
-    }
-    
-    if !config.allow_plot_interaction {
-        plot = plot
-            .allow_drag(false)
-            .allow_zoom(false)
-            .allow_scroll(false);
-    }
-    
+    let plot = history_plot("achievements_history", ui, config, bounds, reset, |y| format!("{:.2}%", y));
     plot.show(ui, |plot_ui| {
         plot_ui.line(line);
+        plot_ui.points(markers);
     });
     
     if achievement_history.is_empty() {
-        ui.label("No achievement data yet. Run a full scan to start tracking!");
+        ui.label(tr("stats.no_achievement_data"));
     }
 }
 
+/// Scenario slider state for the forecast section (kept in egui memory)
+#[derive(Clone, Copy)]
+struct ForecastScenario {
+    target: f32,
+    use_hours: bool,
+    hours_per_week: f32,
+}
+
+/// Render the completion forecast: when the current (or a what-if) pace reaches a target
+pub fn render_forecast<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P) {
+    ui.heading(format!("{} {}", regular::TREND_UP, tr("stats.forecast")));
+    ui.separator();
+
+    let Some(trend) = completion_trend(platform.achievement_history(), &platform.stats_games()) else {
+        ui.label(tr("stats.forecast_needs_history"));
+        return;
+    };
+    let accent = platform.theme_colors().accent;
+    let time = platform.time_display();
+    let now = chrono::Utc::now();
+
+    let scenario_id = ui.id().with("forecast_scenario");
+    let mut scenario = ui.ctx().memory(|mem| mem.data.get_temp::<ForecastScenario>(scenario_id)).unwrap_or(ForecastScenario {
+        // Next multiple of 10 above the current average
+        target: ((trend.avg_completion / 10.0).floor() * 10.0 + 10.0).min(100.0),
+        use_hours: false,
+        hours_per_week: trend.implied_hours_per_week().map(|h| h.round() as f32).unwrap_or(5.0).clamp(1.0, 40.0),
+    });
+
+    ui.horizontal_wrapped(|ui| {
+        ui.label(tr("stats.current_pace"));
+        ui.label(RichText::new(tr_args("stats.unlocks_per_week", &[("count", &format!("{:.1}", trend.unlocks_per_week))])).color(accent).strong());
+        match trend.implied_hours_per_week() {
+            Some(hours) => ui.label(tr_args(
+                "stats.pace_hours",
+                &[("hours", &format!("{:.1}", hours)), ("days", &format!("{:.0}", trend.span_days.min(FORECAST_WINDOW_DAYS as f64)))],
+            )),
+            None => ui.label(tr_args("stats.pace_days", &[("days", &format!("{:.0}", trend.span_days.min(FORECAST_WINDOW_DAYS as f64)))])),
+        };
+    });
+
+    ui.horizontal(|ui| {
+        ui.label(tr("stats.target_avg"));
+        ui.add(egui::Slider::new(&mut scenario.target, 10.0..=100.0).step_by(5.0).suffix("%"));
+    });
+    ui.add_enabled_ui(trend.unlocks_per_hour.is_some(), |ui| {
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut scenario.use_hours, tr("stats.what_if"));
+            ui.add_enabled(scenario.use_hours, egui::Slider::new(&mut scenario.hours_per_week, 1.0..=40.0).step_by(1.0).suffix(tr("stats.hours_per_week_suffix")));
+        });
+    });
+
+    let hours = (scenario.use_hours && trend.unlocks_per_hour.is_some()).then_some(scenario.hours_per_week as f64);
+    let unlocks_per_week = trend.unlocks_per_week_at(hours);
+    let pace = match hours {
+        Some(h) => tr_args("stats.pace_at_hours", &[("hours", &format!("{:.0}", h))]),
+        None => tr("stats.pace_current").to_string(),
+    };
+    let describe = |eta: ForecastEta, goal: String| match eta {
+        ForecastEta::Reached => tr_args("stats.eta_reached", &[("goal", &goal)]),
+        ForecastEta::At(date) => tr_args(
+            "stats.eta_at",
+            &[("pace", &pace), ("goal", &goal), ("date", &time.display_date(date).format("%B %Y"))],
+        ),
+        ForecastEta::OutOfReach => tr_args("stats.eta_out_of_reach", &[("pace", &pace), ("goal", &goal)]),
+    };
+
+    ui.add_space(4.0);
+    ui.label(RichText::new(describe(
+        trend.avg_completion_eta(scenario.target, unlocks_per_week, now),
+        tr_args("stats.goal_avg", &[("percent", &format!("{:.0}", scenario.target))]),
+    )).color(accent));
+
+    // Overall achievement % toward the next 10% step
+    let overall_target = ((trend.overall_percent() / 10.0).floor() * 10.0 + 10.0).min(100.0) as f32;
+    ui.label(describe(
+        trend.overall_eta(overall_target, unlocks_per_week, now),
+        tr_args("stats.goal_overall", &[("percent", &format!("{:.0}", overall_target))]),
+    ));
+
+    ui.ctx().memory_mut(|mem| mem.data.insert_temp(scenario_id, scenario));
+}
+
 /// Render the breakdown section with game counts and current stats
 pub fn render_breakdown<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P) {
-    ui.heading(format!("{} Breakdown", regular::GAME_CONTROLLER));
+    ui.heading(format!("{} {}", regular::GAME_CONTROLLER, tr("stats.breakdown")));
     ui.separator();
     
     // Collect all data we need from games upfront to avoid borrow issues
@@ -413,12 +679,14 @@ pub fn render_breakdown<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P) {
         completion_percents_with_unplayed,
         completion_percents_played_only,
         completed_count,
+        near_complete_count,
         needs_scan,
     ) = {
-        let games = platform.games();
+        let theme = platform.theme_colors();
+        let games = platform.stats_games();
         
         if games.is_empty() {
-            ui.label("Sync your games to see stats.");
+            ui.label(tr("stats.sync_first"));
             return;
         }
         
@@ -433,11 +701,13 @@ pub fn render_breakdown<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P) {
             .filter_map(|g| g.achievements_unlocked)
             .sum();
         
+        // Abandoned games stay in the counts but not in the averages
         let percents_with_unplayed: Vec<f32> = games_with_ach.iter()
+            .filter(|g| !g.abandoned)
             .filter_map(|g| g.completion_percent())
             .collect();
         let percents_played_only: Vec<f32> = games_with_ach.iter()
-            .filter(|g| g.playtime_forever > 0)
+            .filter(|g| g.playtime_forever > 0 && !g.abandoned)
             .filter_map(|g| g.completion_percent())
             .collect();
         
@@ -446,7 +716,10 @@ pub fn render_breakdown<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P) {
             .count();
         
         let completed = games.iter()
-            .filter(|g| g.completion_percent().map(|p| p >= 100.0).unwrap_or(false))
+            .filter(|g| g.completion_percent().map(|p| theme.is_complete(p)).unwrap_or(false))
+            .count();
+        let near_complete = games.iter()
+            .filter(|g| g.completion_percent().map(|p| theme.is_near_complete(p)).unwrap_or(false))
             .count();
         let needs = games.iter().filter(|g| g.achievements_total.is_none()).count();
         
@@ -459,11 +732,13 @@ pub fn render_breakdown<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P) {
             percents_with_unplayed,
             percents_played_only,
             completed,
+            near_complete,
             needs,
         )
     };
     
-    let yellow = Color32::from_rgb(255, 215, 0);
+    let theme = platform.theme_colors();
+    let yellow = theme.accent;
     
     // === Current stats (Total achievements, Avg completion, Unplayed) ===
     
@@ -474,7 +749,7 @@ pub fn render_breakdown<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P) {
     };
     
     ui.horizontal(|ui| {
-        ui.label("Total achievements:");
+        ui.label(tr("stats.total_achievements"));
         ui.label(RichText::new(format!("{}", unlocked_achievements)).color(yellow).strong());
         ui.label("/");
         ui.label(RichText::new(format!("{}", total_achievements)).color(yellow).strong());
@@ -504,17 +779,17 @@ pub fn render_breakdown<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P) {
     };
     
     ui.horizontal(|ui| {
-        ui.label("Avg. game completion:");
-        ui.label(RichText::new(format!("{:.1}%", current_avg)).color(yellow).strong());
+        ui.label(tr("stats.avg_completion"));
+        ui.label(RichText::new(format!("{:.1}%", current_avg)).color(theme.completion_color(current_avg)).strong());
         let mut include = include_unplayed;
-        if ui.checkbox(&mut include, "Include unplayed").changed() {
+        if ui.checkbox(&mut include, tr("stats.include_unplayed")).changed() {
             platform.set_include_unplayed_in_avg(include);
         }
     });
     
     // Show unplayed games count and percentage
     ui.horizontal(|ui| {
-        ui.label("Unplayed games:");
+        ui.label(tr("stats.unplayed_games"));
         ui.label(RichText::new(format!("{}", unplayed_count)).color(yellow).strong());
         ui.label("(");
         ui.label(RichText::new(format!("{:.1}%", unplayed_pct)).color(yellow).strong());
@@ -522,24 +797,365 @@ pub fn render_breakdown<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P) {
     });
     
     ui.horizontal(|ui| {
-        ui.label("Total games:");
+        ui.label(tr("stats.total_games"));
         ui.label(RichText::new(format!("{}", games_len)).color(yellow).strong());
     });
     
     ui.horizontal(|ui| {
-        ui.label("Games with achievements:");
+        ui.label(tr("stats.games_with_achievements"));
         ui.label(RichText::new(format!("{}", total_with_ach)).color(yellow).strong());
     });
     
     ui.horizontal(|ui| {
-        ui.label(format!("{} 100% completed:", regular::MEDAL));
-        ui.label(RichText::new(format!("{}", completed_count)).color(yellow).strong());
+        if theme.complete_threshold >= 100.0 {
+            ui.label(format!("{} {}", regular::MEDAL, tr("stats.perfect_games")));
+        } else {
+            ui.label(format!("{} {}", regular::MEDAL, tr_args("stats.done_games", &[("percent", &format!("{:.0}", theme.complete_threshold))])));
+        }
+        ui.label(RichText::new(format!("{}", completed_count)).color(theme.complete).strong());
+    });
+    
+    ui.horizontal(|ui| {
+        ui.label(format!("{} {}", regular::FLAG_CHECKERED, tr_args("stats.near_complete_games", &[("percent", &format!("{:.0}", theme.near_complete_threshold))])));
+        ui.label(RichText::new(format!("{}", near_complete_count)).color(theme.near_complete).strong());
     });
     
     if needs_scan > 0 {
         ui.horizontal(|ui| {
-            ui.label("Needs scanning:");
+            ui.label(tr("stats.needs_scanning"));
             ui.label(RichText::new(format!("{}", needs_scan)).color(Color32::LIGHT_GRAY));
         });
     }
 }
+
+/// Sort state for the "By tag" table (kept in egui memory)
+#[derive(Clone, Copy)]
+struct TagTableSort {
+    column: TagStatsColumn,
+    descending: bool,
+}
+
+/// Render the "By tag" breakdown: stats for each selected tag as a sortable table and bar chart
+pub fn render_tag_breakdown<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P, config: &StatsPanelConfig) {
+    ui.heading(format!("{} {}", regular::TAG, tr("stats.by_tag")));
+    ui.separator();
+
+    let accent = platform.theme_colors().accent;
+    let search_id = ui.id().with("tag_breakdown_search");
+    let sort_id = ui.id().with("tag_breakdown_sort");
+    let mut search = ui.ctx().memory(|mem| mem.data.get_temp::<TagSearchState>(search_id)).unwrap_or_default();
+    let mut sort = ui.ctx().memory(|mem| mem.data.get_temp::<TagTableSort>(sort_id))
+        .unwrap_or(TagTableSort { column: TagStatsColumn::Owned, descending: true });
+
+    ui.horizontal(|ui| {
+        render_tag_search(ui, "tag_breakdown_dropdown", &mut search, platform.tag_names(), tr("stats.add_tag"), false);
+    });
+
+    if search.selected_tags.is_empty() {
+        ui.label(RichText::new(tr("stats.pick_tags")).weak());
+    } else {
+        let mut stats = tag_stats(
+            &platform.stats_games(),
+            &search.selected_tags,
+            |appid| platform.tags_for_game(appid),
+            |appid| platform.ttb_completionist_hours(appid),
+        );
+        sort_tag_stats(&mut stats, sort.column, sort.descending);
+
+        ui.add_space(4.0);
+        egui::Grid::new("tag_breakdown_grid").striped(true).num_columns(5).spacing([16.0, 4.0]).show(ui, |ui| {
+            for column in [
+                TagStatsColumn::Tag,
+                TagStatsColumn::Owned,
+                TagStatsColumn::Hours,
+                TagStatsColumn::Completion,
+                TagStatsColumn::UnfinishedTtb,
+            ] {
+                let arrow = match (sort.column == column, sort.descending) {
+                    (true, true) => regular::CARET_DOWN,
+                    (true, false) => regular::CARET_UP,
+                    _ => "",
+                };
+                if ui.selectable_label(sort.column == column, RichText::new(format!("{} {}", column.label(), arrow)).strong()).clicked() {
+                    if sort.column == column {
+                        sort.descending = !sort.descending;
+                    } else {
+                        sort.column = column;
+                        sort.descending = column != TagStatsColumn::Tag;
+                    }
+                }
+            }
+            ui.end_row();
+
+            for row in &stats {
+                ui.label(RichText::new(&row.tag).color(accent));
+                ui.label(format!("{}", row.owned));
+                ui.label(format!("{:.0}h", row.hours));
+                match row.avg_completion {
+                    Some(pct) => ui.label(format!("{:.1}%", pct)),
+                    None => ui.label("-"),
+                };
+                ui.label(format!("{:.0}h", row.unfinished_ttb_hours));
+                ui.end_row();
+            }
+        });
+
+        // Bar chart of the sorted column, in table order
+        let chart_column = if sort.column == TagStatsColumn::Tag { TagStatsColumn::Owned } else { sort.column };
+        let bars: Vec<Bar> = stats
+            .iter()
+            .enumerate()
+            .map(|(i, row)| Bar::new(i as f64, row.value(chart_column)).name(&row.tag).width(0.7))
+            .collect();
+        ui.add_space(8.0);
+        // Tag names are in the bar tooltips, so the x axis is hidden
+        Plot::new("tag_breakdown_chart")
+            .height(config.plot_height.unwrap_or(120.0))
+            .width(ui.available_width())
+            .show_axes([false, true])
+            .include_y(0.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show(ui, |plot_ui| {
+                plot_ui.bar_chart(BarChart::new(chart_column.label(), bars).color(accent));
+            });
+    }
+
+    ui.ctx().memory_mut(|mem| {
+        mem.data.insert_temp(search_id, search);
+        mem.data.insert_temp(sort_id, sort);
+    });
+}
+
+/// Render the records section (personal bests and milestones)
+pub fn render_records<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P) {
+    let accent = platform.theme_colors().accent;
+    let time = platform.time_display();
+    let Some(records) = platform.achievement_records() else {
+        return;
+    };
+    
+    ui.heading(format!("{} {}", regular::TROPHY, tr("stats.records")));
+    ui.separator();
+    
+    if records.first_achievement.is_none() {
+        ui.label(tr("stats.no_records"));
+        return;
+    }
+    
+    if let Some(first) = &records.first_achievement {
+        ui.horizontal_wrapped(|ui| {
+            ui.label(tr("stats.first_achievement"));
+            ui.label(RichText::new(&first.achievement_name).color(accent).strong());
+            ui.label(format!("({}, {})", first.game_name, time.format_absolute(first.unlocktime)));
+        });
+    }
+    
+    if let Some(day) = &records.busiest_day {
+        ui.horizontal(|ui| {
+            ui.label(tr("stats.busiest_day"));
+            ui.label(RichText::new(format!("{}", day.unlocks)).color(accent).strong());
+            ui.label(tr_args("stats.busiest_day_date", &[("date", &day.date.format("%Y-%m-%d"))]));
+        });
+    }
+    
+    let streak_status = records.streak_status(time.display_date(chrono::Utc::now()));
+    ui.horizontal(|ui| {
+        ui.label(format!("{} {}", regular::LIGHTNING, tr("stats.current_streak")));
+        ui.label(RichText::new(tr_args("stats.days", &[("count", &streak_status.current)])).color(accent).strong());
+    });
+    if streak_status.at_risk() {
+        ui.label(
+            RichText::new(format!(
+                "{} {}",
+                regular::WARNING,
+                tr_args("stats.streak_at_risk", &[("days", &streak_status.current)])
+            ))
+            .color(Color32::from_rgb(230, 140, 50)),
+        );
+    }
+    
+    if let Some(streak) = &records.longest_streak {
+        ui.horizontal(|ui| {
+            ui.label(format!("{} {}", regular::FIRE, tr("stats.longest_streak")));
+            ui.label(RichText::new(tr_args("stats.days", &[("count", &streak.days)])).color(accent).strong());
+            ui.label(format!("({} – {})", streak.start.format("%Y-%m-%d"), streak.end.format("%Y-%m-%d")));
+        });
+    }
+    
+    if let Some(rarest) = &records.rarest_unlock {
+        ui.horizontal_wrapped(|ui| {
+            ui.label(format!("{} {}", regular::DIAMOND, tr("stats.rarest_unlock")));
+            ui.label(RichText::new(&rarest.achievement_name).color(accent).strong());
+            let percent = rarest.global_percent.unwrap_or(0.0);
+            ui.label(tr_args("stats.rarest_unlock_detail", &[("game", &rarest.game_name), ("percent", &format!("{:.1}", percent))]));
+        });
+    }
+    
+    if let Some(game) = &records.oldest_unfinished {
+        ui.horizontal_wrapped(|ui| {
+            ui.label(format!("{} {}", regular::HOURGLASS, tr("stats.oldest_unfinished")));
+            ui.label(RichText::new(&game.name).color(accent).strong());
+            ui.label(tr_args(
+                "stats.oldest_unfinished_detail",
+                &[("date", &time.format_absolute(game.started_at)), ("percent", &format!("{:.0}", game.completion_percent))],
+            ));
+        });
+    }
+    
+    if !records.milestones.is_empty() {
+        ui.add_space(4.0);
+        egui::CollapsingHeader::new(format!("{} {}", regular::FLAG, tr_args("stats.milestones", &[("count", &records.milestones.len())])))
+            .id_salt("records_milestones")
+            .show(ui, |ui| {
+                for milestone in records.milestones.iter().rev() {
+                    ui.horizontal_wrapped(|ui| {
+                        ui.label(RichText::new(milestone.kind.label()).color(accent).strong());
+                        ui.label(time.format_absolute(milestone.reached_at));
+                        ui.label(RichText::new(&milestone.detail).weak());
+                    });
+                }
+            });
+    }
+}
+
+/// Kind of goal picked in the "add goal" form
+#[derive(Clone, Copy, PartialEq)]
+enum NewGoalKind {
+    GamesPerMonth,
+    AvgCompletionBy,
+}
+
+/// "Add goal" form state (kept in egui memory)
+#[derive(Clone)]
+struct NewGoalForm {
+    kind: NewGoalKind,
+    count: u32,
+    target: f32,
+    deadline: chrono::NaiveDate,
+}
+
+impl Default for NewGoalForm {
+    fn default() -> Self {
+        let in_three_months = chrono::Utc::now().date_naive() + chrono::Days::new(90);
+        Self {
+            kind: NewGoalKind::GamesPerMonth,
+            count: 2,
+            target: 75.0,
+            deadline: in_three_months,
+        }
+    }
+}
+
+/// Render the goals section (progress bars and an "add goal" form)
+pub fn render_goals<P: StatsPanelPlatform>(ui: &mut Ui, platform: &mut P) {
+    let Some(goals) = platform.goals() else {
+        return;
+    };
+    let goals = goals.to_vec();
+    let time = platform.time_display();
+    let colors = platform.theme_colors();
+    let perfect_dates = platform.achievement_records()
+        .map(|r| r.perfect_game_dates.clone())
+        .unwrap_or_default();
+    let avg_completion = platform.achievement_history().last()
+        .map(|h| h.avg_completion_percent)
+        .unwrap_or(0.0);
+    let now = chrono::Utc::now();
+    let behind_color = Color32::from_rgb(230, 140, 50);
+    
+    ui.heading(format!("{} {}", regular::TARGET, tr("stats.goals")));
+    ui.separator();
+    
+    if goals.is_empty() {
+        ui.label(RichText::new(tr("stats.no_goals")).weak());
+    }
+    
+    let mut remove = None;
+    for goal in &goals {
+        let progress = goal_progress(goal, &perfect_dates, avg_completion, now, &time);
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(goal.label()).strong());
+            if ui.small_button(regular::X).on_hover_text(tr("stats.remove_goal")).clicked() {
+                remove = Some(goal.created_at);
+            }
+        });
+        let text = match goal.kind {
+            GoalKind::GamesPerMonth { .. } => tr_args(
+                "stats.goal_this_month",
+                &[("current", &format!("{:.0}", progress.current)), ("target", &format!("{:.0}", progress.target))],
+            ),
+            GoalKind::AvgCompletionBy { .. } => format!("{:.1}% / {:.0}%", progress.current, progress.target),
+        };
+        ui.add(egui::ProgressBar::new(progress.fraction()).text(text).fill(colors.accent));
+        let status = if progress.is_done() {
+            RichText::new(format!("{} {}", regular::CHECK_CIRCLE, tr("stats.goal_reached"))).color(colors.complete)
+        } else if progress.expired {
+            RichText::new(format!("{} {}", regular::WARNING, tr("stats.goal_expired"))).color(behind_color)
+        } else if progress.is_behind(&goal.kind) {
+            let expected = match goal.kind {
+                GoalKind::GamesPerMonth { .. } => tr_args("stats.goal_games", &[("count", &progress.expected.floor())]),
+                GoalKind::AvgCompletionBy { .. } => format!("{:.1}%", progress.expected),
+            };
+            RichText::new(format!("{} {}", regular::WARNING, tr_args("stats.goal_behind", &[("expected", &expected)]))).color(behind_color)
+        } else {
+            RichText::new(tr("stats.goal_on_track")).weak()
+        };
+        ui.label(status.small());
+        ui.add_space(4.0);
+    }
+    if let Some(created_at) = remove {
+        platform.remove_goal(created_at);
+    }
+    
+    let form_id = ui.id().with("new_goal_form");
+    let mut form: NewGoalForm = ui.data_mut(|d| d.get_temp(form_id)).unwrap_or_default();
+    let mut add = None;
+    egui::CollapsingHeader::new(format!("{} {}", regular::PLUS, tr("stats.add_goal")))
+        .id_salt("goals_add")
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.radio_value(&mut form.kind, NewGoalKind::GamesPerMonth, tr("stats.goal_kind_games"));
+                ui.radio_value(&mut form.kind, NewGoalKind::AvgCompletionBy, tr("stats.goal_kind_avg"));
+            });
+            match form.kind {
+                NewGoalKind::GamesPerMonth => {
+                    ui.horizontal(|ui| {
+                        ui.label(tr("stats.goal_finish"));
+                        ui.add(egui::DragValue::new(&mut form.count).range(1..=50));
+                        ui.label(tr("stats.goal_games_per_month"));
+                    });
+                }
+                NewGoalKind::AvgCompletionBy => {
+                    ui.horizontal(|ui| {
+                        ui.label(tr("stats.goal_reach"));
+                        ui.add(egui::DragValue::new(&mut form.target).range(1.0..=100.0).suffix("%"));
+                        ui.label(tr("stats.goal_by"));
+                        let mut date = form.deadline.format("%Y-%m-%d").to_string();
+                        if ui.add(egui::TextEdit::singleline(&mut date).desired_width(90.0)).changed() {
+                            if let Ok(parsed) = chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d") {
+                                form.deadline = parsed;
+                            }
+                        }
+                    });
+                    ui.label(RichText::new(tr_args("stats.goal_currently", &[("percent", &format!("{:.1}", avg_completion))])).weak().small());
+                }
+            }
+            if ui.button(tr("stats.goal_add")).clicked() {
+                let kind = match form.kind {
+                    NewGoalKind::GamesPerMonth => GoalKind::GamesPerMonth { count: form.count },
+                    NewGoalKind::AvgCompletionBy => GoalKind::AvgCompletionBy {
+                        target: form.target,
+                        deadline: form.deadline,
+                        start: avg_completion,
+                    },
+                };
+                add = Some(Goal { kind, created_at: chrono::Utc::now() });
+            }
+        });
+    ui.data_mut(|d| d.insert_temp(form_id, form));
+    if let Some(goal) = add {
+        platform.add_goal(goal);
+    }
+}
@@ -1,7 +1,17 @@
 //! Reusable tag search component with searchable dropdown
+//!
+//! Tags are fuzzy-matched (best match first), the dropdown only lays out the rows
+//! in view, and it can be driven from the keyboard: arrows move the highlight,
+//! Enter picks it, Escape closes the dropdown.
 
-use egui::{self, Color32, RichText, Ui};
+use egui::{self, Color32, Key, Modifiers, RichText, Ui};
 use egui_phosphor::regular;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+use crate::tr;
+
+/// Max height of the dropdown list
+const DROPDOWN_MAX_HEIGHT: f32 = 450.0;
 
 /// State for tag search dropdown
 #[derive(Clone, Default)]
@@ -12,6 +22,8 @@ pub struct TagSearchState {
     pub popup_open: bool,
     /// Selected tags
     pub selected_tags: Vec<String>,
+    /// Keyboard-highlighted row in the dropdown
+    pub selected_index: Option<usize>,
 }
 
 impl TagSearchState {
@@ -20,39 +32,104 @@ impl TagSearchState {
     }
 }
 
-/// Render a searchable tag dropdown with selected tag chips
-/// 
-/// Returns true if the selection changed this frame
-pub fn render_tag_search(
+/// Tags matching `query` (fuzzy, best match first), without the `exclude`d ones.
+/// An empty query keeps the original order.
+pub fn fuzzy_filter_tags<'a>(available_tags: &'a [String], query: &str, exclude: &[String]) -> Vec<&'a String> {
+    let candidates = available_tags.iter().filter(|tag| !exclude.contains(tag));
+    let query = query.trim();
+    if query.is_empty() {
+        return candidates.collect();
+    }
+
+    let matcher = SkimMatcherV2::default().ignore_case();
+    let mut scored: Vec<(i64, &String)> = candidates
+        .filter_map(|tag| matcher.fuzzy_match(tag, query).map(|score| (score, tag)))
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.len().cmp(&b.1.len())).then_with(|| a.1.cmp(b.1)));
+    scored.into_iter().map(|(_, tag)| tag).collect()
+}
+
+/// Items whose `key` fuzzy-matches `query`, best match first (shorter keys win ties).
+/// An empty query matches nothing.
+pub fn fuzzy_rank<'a, T>(items: impl IntoIterator<Item = &'a T>, query: &str, key: impl Fn(&T) -> &str) -> Vec<&'a T> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let matcher = SkimMatcherV2::default().ignore_case();
+    let mut scored: Vec<(i64, &T)> = items
+        .into_iter()
+        .filter_map(|item| matcher.fuzzy_match(key(item), query).map(|score| (score, item)))
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| key(a.1).len().cmp(&key(b.1).len())));
+    scored.into_iter().map(|(_, item)| item).collect()
+}
+
+/// Searchable tag dropdown: a text field, a toggle button and the (virtualized) match list.
+///
+/// `selected_index` is the keyboard highlight. Returns the tag picked this frame.
+pub fn render_tag_dropdown(
     ui: &mut Ui,
     id_source: impl std::hash::Hash,
-    state: &mut TagSearchState,
+    search_text: &mut String,
+    selected_index: &mut Option<usize>,
     available_tags: &[String],
+    selected_tags: &[String],
     placeholder: &str,
-    show_pills_bg: bool,
-) -> bool {
-    let mut selection_changed = false;
-    
-    // Filter available tags based on search input and exclude already selected
-    let search_lower = state.search_text.to_lowercase();
-    let filtered_tags: Vec<String> = available_tags.iter()
-        .filter(|tag| !state.selected_tags.contains(tag))
-        .filter(|tag| search_lower.is_empty() || tag.to_lowercase().contains(&search_lower))
-        .take(20)
-        .cloned()
-        .collect();
+) -> Option<String> {
+    let filtered_tags = fuzzy_filter_tags(available_tags, search_text, selected_tags);
 
     // Popup state management
     let popup_open_id = ui.make_persistent_id(id_source);
+    let scroll_offset_id = popup_open_id.with("scroll_offset");
+    let text_id = popup_open_id.with("text");
     let mut popup_open = ui.ctx().memory(|mem| mem.data.get_temp::<bool>(popup_open_id).unwrap_or(false));
 
+    // Keyboard navigation - consumed before the text field sees the arrow keys
+    let mut picked: Option<String> = None;
+    let mut keyboard_moved = false;
+    if ui.memory(|mem| mem.has_focus(text_id)) {
+        let (down, up, escape) = ui.input_mut(|i| (
+            i.consume_key(Modifiers::NONE, Key::ArrowDown),
+            i.consume_key(Modifiers::NONE, Key::ArrowUp),
+            i.consume_key(Modifiers::NONE, Key::Escape),
+        ));
+        if down && !filtered_tags.is_empty() {
+            popup_open = true;
+            *selected_index = Some(selected_index.map_or(0, |i| (i + 1).min(filtered_tags.len() - 1)));
+            keyboard_moved = true;
+        }
+        if up {
+            *selected_index = selected_index.and_then(|i| i.checked_sub(1));
+            keyboard_moved = true;
+        }
+        if escape {
+            popup_open = false;
+            *selected_index = None;
+        }
+    }
+    if selected_index.is_some_and(|i| i >= filtered_tags.len()) {
+        *selected_index = filtered_tags.len().checked_sub(1);
+    }
+
     // Text input with dropdown button
     let text_response = ui.add(
-        egui::TextEdit::singleline(&mut state.search_text)
+        egui::TextEdit::singleline(search_text)
+            .id(text_id)
             .hint_text(placeholder)
             .desired_width(120.0)
     );
 
+    // Enter picks the highlighted tag (or the best match) and keeps the field focused for the next one
+    if text_response.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter)) {
+        let index = selected_index.unwrap_or(0);
+        if popup_open || !search_text.is_empty() {
+            picked = filtered_tags.get(index).map(|tag| (*tag).clone());
+        }
+        text_response.request_focus();
+    }
+
     // Toggle button to open/close dropdown
     let toggle_btn = ui.button(if popup_open { regular::CARET_UP } else { regular::CARET_DOWN });
     if toggle_btn.clicked() {
@@ -64,16 +141,30 @@ pub fn render_tag_search(
         popup_open = true;
     }
 
-    // Update search input
+    // Open when typing, highlight the best match
     if text_response.changed() {
-        popup_open = true; // Open when typing
+        popup_open = true;
+        *selected_index = if search_text.is_empty() { None } else { Some(0) };
     }
 
-    // Track if we selected a tag this frame
-    let mut selected_tag: Option<String> = None;
-
     // Show dropdown as Area when open
     if popup_open && !filtered_tags.is_empty() {
+        let row_height = ui.spacing().interact_size.y;
+        let row_stride = row_height + ui.spacing().item_spacing.y;
+        let mut scroll = egui::ScrollArea::vertical()
+            .id_salt(popup_open_id.with("scroll"))
+            .max_height(DROPDOWN_MAX_HEIGHT);
+        // Keep the highlighted row in view (using last frame's scroll position)
+        if let (true, Some(index)) = (keyboard_moved, *selected_index) {
+            let offset = ui.ctx().memory(|mem| mem.data.get_temp::<f32>(scroll_offset_id).unwrap_or(0.0));
+            let row_top = index as f32 * row_stride;
+            if row_top < offset {
+                scroll = scroll.vertical_scroll_offset(row_top);
+            } else if row_top + row_stride > offset + DROPDOWN_MAX_HEIGHT {
+                scroll = scroll.vertical_scroll_offset(row_top + row_stride - DROPDOWN_MAX_HEIGHT);
+            }
+        }
+
         let area_response = egui::Area::new(ui.make_persistent_id((popup_open_id, "dropdown_area")))
             .order(egui::Order::Foreground)
             .fixed_pos(text_response.rect.left_bottom())
@@ -81,18 +172,18 @@ pub fn render_tag_search(
                 egui::Frame::popup(ui.style()).show(ui, |ui| {
                     ui.set_min_width(340.0);
                     ui.style_mut().interaction.selectable_labels = true;
-                    egui::ScrollArea::vertical()
-                        .max_height(450.0)
-                        .show(ui, |ui| {
-                            ui.with_layout(egui::Layout::top_down_justified(egui::Align::LEFT), |ui| {
-                                for tag in &filtered_tags {
-                                    let response = ui.selectable_label(false, tag);
-                                    if response.clicked() {
-                                        selected_tag = Some(tag.clone());
-                                    }
+                    let output = scroll.show_rows(ui, row_height, filtered_tags.len(), |ui, rows| {
+                        ui.with_layout(egui::Layout::top_down_justified(egui::Align::LEFT), |ui| {
+                            for index in rows {
+                                let tag = filtered_tags[index];
+                                let response = ui.selectable_label(*selected_index == Some(index), tag.as_str());
+                                if response.clicked() {
+                                    picked = Some(tag.clone());
                                 }
-                            });
+                            }
                         });
+                    });
+                    ui.ctx().memory_mut(|mem| mem.data.insert_temp(scroll_offset_id, output.state.offset.y));
                 });
             });
 
@@ -113,19 +204,124 @@ pub fn render_tag_search(
         popup_open = false;
     }
 
-    // Handle tag selection
-    if let Some(tag) = selected_tag {
-        if !state.selected_tags.contains(&tag) {
-            state.selected_tags.push(tag);
-            selection_changed = true;
-        }
-        state.search_text.clear();
+    if picked.is_some() {
+        search_text.clear();
+        *selected_index = None;
         popup_open = false;
     }
 
     // Store popup state
     ui.ctx().memory_mut(|mem| mem.data.insert_temp(popup_open_id, popup_open));
 
+    picked
+}
+
+/// Selected tags as chips, each with its own remove button. Returns the tag removed this frame.
+pub fn render_tag_chips(ui: &mut Ui, tags: &[String]) -> Option<String> {
+    let mut removed = None;
+    for tag in tags {
+        egui::Frame::new()
+            .fill(Color32::from_rgb(60, 80, 100))
+            .corner_radius(3.0)
+            .inner_margin(egui::Margin::symmetric(4, 0))
+            .show(ui, |ui| {
+                ui.spacing_mut().item_spacing.x = 2.0;
+                ui.horizontal(|ui| {
+                    let label = ui.label(RichText::new(tag).size(11.0).color(Color32::WHITE));
+                    let remove = ui.add(
+                        egui::Button::new(RichText::new(regular::X).size(10.0).color(Color32::WHITE))
+                            .small()
+                            .frame(false)
+                    ).on_hover_text(tr("common.remove"));
+                    // Right-click anywhere on the chip removes it too
+                    if remove.clicked() || remove.secondary_clicked() || label.secondary_clicked() {
+                        removed = Some(tag.clone());
+                    }
+                });
+            });
+    }
+    removed
+}
+
+/// What happened to a chip of the include/exclude tag filter this frame
+#[derive(Clone, Debug, PartialEq)]
+pub enum TagChipAction {
+    /// Flip the tag between included and excluded
+    Toggle(String),
+    Remove(String),
+}
+
+/// Tag filter chips: included tags first, then excluded ones (red, struck through).
+/// Clicking a chip flips it between include and exclude, its X (or a right-click) removes it.
+pub fn render_tag_filter_chips(ui: &mut Ui, include: &[String], exclude: &[String]) -> Option<TagChipAction> {
+    let mut action = None;
+    let chips = include.iter().map(|tag| (tag, false)).chain(exclude.iter().map(|tag| (tag, true)));
+    for (tag, excluded) in chips {
+        let (fill, icon) = if excluded {
+            (Color32::from_rgb(120, 50, 50), regular::MINUS_CIRCLE)
+        } else {
+            (Color32::from_rgb(60, 80, 100), regular::PLUS_CIRCLE)
+        };
+        egui::Frame::new()
+            .fill(fill)
+            .corner_radius(3.0)
+            .inner_margin(egui::Margin::symmetric(4, 0))
+            .show(ui, |ui| {
+                ui.spacing_mut().item_spacing.x = 2.0;
+                ui.horizontal(|ui| {
+                    let mut text = RichText::new(format!("{} {}", icon, tag)).size(11.0).color(Color32::WHITE);
+                    if excluded {
+                        text = text.strikethrough();
+                    }
+                    let label = ui.add(egui::Label::new(text).sense(egui::Sense::click()))
+                        .on_hover_text(tr(if excluded { "filter.tag_excluded_hint" } else { "filter.tag_included_hint" }));
+                    if label.hovered() {
+                        ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+                    }
+                    let remove = ui.add(
+                        egui::Button::new(RichText::new(regular::X).size(10.0).color(Color32::WHITE))
+                            .small()
+                            .frame(false)
+                    ).on_hover_text(tr("common.remove"));
+                    if remove.clicked() || remove.secondary_clicked() || label.secondary_clicked() {
+                        action = Some(TagChipAction::Remove(tag.clone()));
+                    } else if label.clicked() {
+                        action = Some(TagChipAction::Toggle(tag.clone()));
+                    }
+                });
+            });
+    }
+    action
+}
+
+/// Render a searchable tag dropdown with selected tag chips
+///
+/// Returns true if the selection changed this frame
+pub fn render_tag_search(
+    ui: &mut Ui,
+    id_source: impl std::hash::Hash,
+    state: &mut TagSearchState,
+    available_tags: &[String],
+    placeholder: &str,
+    show_pills_bg: bool,
+) -> bool {
+    let mut selection_changed = false;
+
+    if let Some(tag) = render_tag_dropdown(
+        ui,
+        id_source,
+        &mut state.search_text,
+        &mut state.selected_index,
+        available_tags,
+        &state.selected_tags,
+        placeholder,
+    ) {
+        if !state.selected_tags.contains(&tag) {
+            state.selected_tags.push(tag);
+            selection_changed = true;
+        }
+    }
+
     ui.add_space(8.0);
 
     // Pills area with optional subtle background
@@ -140,31 +336,14 @@ pub fn render_tag_search(
     }
 
     // Display selected tags as removable chips (inline, with wrapping)
-    let mut tags_to_remove: Vec<String> = Vec::new();
-
     ui.horizontal_wrapped(|ui| {
         ui.spacing_mut().item_spacing.x = 4.0;
         ui.spacing_mut().item_spacing.y = 4.0;
-        for tag in &state.selected_tags {
-            // Tag chip with X button combined
-            let chip_text = format!("{} ×", tag);
-            let chip_response = ui.add(
-                egui::Button::new(RichText::new(&chip_text).size(11.0))
-                    .small()
-                    .fill(Color32::from_rgb(60, 80, 100))
-            );
-
-            if chip_response.clicked() || chip_response.secondary_clicked() {
-                tags_to_remove.push(tag.clone());
-            }
+        if let Some(tag) = render_tag_chips(ui, &state.selected_tags) {
+            state.selected_tags.retain(|t| *t != tag);
+            selection_changed = true;
         }
     });
 
-    // Remove tags that were clicked
-    if !tags_to_remove.is_empty() {
-        state.selected_tags.retain(|t| !tags_to_remove.contains(t));
-        selection_changed = true;
-    }
-    
     selection_changed
 }
@@ -0,0 +1,94 @@
+//! Theme colors - shared between desktop and WASM
+//!
+//! Panels read highlight and completion colors from here instead of
+//! hardcoding them, so platforms can offer light/dark themes and custom accents.
+
+use egui::Color32;
+
+/// Default accent (gold) used for highlighted values
+pub const DEFAULT_ACCENT: Color32 = Color32::from_rgb(255, 215, 0);
+
+/// Default completion percent at which a game counts as done
+pub const DEFAULT_COMPLETE_THRESHOLD: f32 = 100.0;
+
+/// Default completion percent at which a game is shown as "near complete"
+pub const DEFAULT_NEAR_COMPLETE_THRESHOLD: f32 = 80.0;
+
+/// Colors used by the shared panels
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThemeColors {
+    /// Accent for highlighted values (stats numbers, user TTB, achievement names)
+    pub accent: Color32,
+    /// Secondary color for external data (HLTB times, game names in the log)
+    pub secondary: Color32,
+    /// Completion color for games at or above `complete_threshold`
+    pub complete: Color32,
+    /// Completion color for games at or above `near_complete_threshold`
+    pub near_complete: Color32,
+    /// Completion color for everything else
+    pub incomplete: Color32,
+    /// Completion percent (0-100) where a game counts as done
+    pub complete_threshold: f32,
+    /// Completion percent (0-100) where a game counts as near complete
+    pub near_complete_threshold: f32,
+}
+
+impl Default for ThemeColors {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl ThemeColors {
+    /// Palette for dark backgrounds (the original look)
+    pub fn dark() -> Self {
+        Self {
+            accent: DEFAULT_ACCENT,
+            secondary: Color32::from_rgb(120, 180, 255),
+            complete: Color32::from_rgb(100, 255, 100),
+            near_complete: Color32::from_rgb(200, 200, 60),
+            incomplete: Color32::GRAY,
+            complete_threshold: DEFAULT_COMPLETE_THRESHOLD,
+            near_complete_threshold: DEFAULT_NEAR_COMPLETE_THRESHOLD,
+        }
+    }
+
+    /// Palette for light backgrounds (darker tones so text stays readable)
+    pub fn light() -> Self {
+        Self {
+            accent: Color32::from_rgb(176, 128, 0),
+            secondary: Color32::from_rgb(30, 100, 200),
+            complete: Color32::from_rgb(30, 150, 30),
+            near_complete: Color32::from_rgb(160, 120, 0),
+            incomplete: Color32::from_gray(110),
+            complete_threshold: DEFAULT_COMPLETE_THRESHOLD,
+            near_complete_threshold: DEFAULT_NEAR_COMPLETE_THRESHOLD,
+        }
+    }
+
+    /// Whether a completion percentage falls in the "done" band
+    pub fn is_complete(&self, percent: f32) -> bool {
+        percent >= self.complete_threshold
+    }
+
+    /// Whether a completion percentage falls in the "near complete" band (below done)
+    pub fn is_near_complete(&self, percent: f32) -> bool {
+        !self.is_complete(percent) && percent >= self.near_complete_threshold
+    }
+
+    /// Color for a completion percentage based on the configured thresholds
+    pub fn completion_color(&self, percent: f32) -> Color32 {
+        if self.is_complete(percent) {
+            self.complete
+        } else if self.is_near_complete(percent) {
+            self.near_complete
+        } else {
+            self.incomplete
+        }
+    }
+
+    /// Accent with the given alpha (for row flashes and highlights)
+    pub fn accent_alpha(&self, alpha: u8) -> Color32 {
+        Color32::from_rgba_unmultiplied(self.accent.r(), self.accent.g(), self.accent.b(), alpha)
+    }
+}
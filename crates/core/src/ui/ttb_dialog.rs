@@ -1,6 +1,7 @@
 /// TTB formatting utilities and dialog components
 
 use crate::models::Game;
+use crate::{tr, tr_args};
 
 /// Format seconds into hours and minutes display
 pub fn format_ttb_time(seconds: i32) -> String {
@@ -8,9 +9,9 @@ pub fn format_ttb_time(seconds: i32) -> String {
     let minutes = (seconds % 3600) / 60;
     
     if minutes > 0 {
-        format!("{}h {}m", hours, minutes)
+        tr_args("ttb.hours_minutes", &[("hours", &hours), ("minutes", &minutes)])
     } else {
-        format!("{}h", hours)
+        tr_args("ttb.hours", &[("hours", &hours)])
     }
 }
 
@@ -150,6 +151,16 @@ impl TtbDialogState {
         }
     }
     
+    /// Prefill for a game finished at 100%: existing reports first, otherwise the
+    /// playtime is suggested as the completionist time rather than the main story
+    pub fn prefill_completed_game(&mut self, game: &Game) {
+        self.prefill_from_game(game);
+        if game.my_ttb_main_seconds.is_none() && game.my_ttb_completionist_seconds.is_none() {
+            self.completionist_hours = std::mem::take(&mut self.main_hours);
+            self.completionist_minutes = std::mem::take(&mut self.main_minutes);
+        }
+    }
+    
     /// Get the reported times in seconds (returns None if field is empty)
     pub fn get_times(&self) -> (Option<i32>, Option<i32>, Option<i32>) {
         let main = self.parse_time_field(&self.main_hours, &self.main_minutes);
@@ -176,7 +187,7 @@ pub fn show_ttb_dialog(ui: &mut egui::Ui, state: &mut TtbDialogState) -> Option<
     let mut result = None;
     let mut is_open = state.is_open;
     
-    egui::Window::new("Report Time to Beat")
+    egui::Window::new(tr("ttb.dialog_title"))
         .open(&mut is_open)
         .resizable(false)
         .collapsible(false)
@@ -189,10 +200,10 @@ pub fn show_ttb_dialog(ui: &mut egui::Ui, state: &mut TtbDialogState) -> Option<
                 ui.add_space(8.0);
             }
             
-            ui.label(format!("Game: {}", state.game_name));
+            ui.label(tr_args("ttb.dialog_game", &[("game", &state.game_name)]));
             ui.add_space(8.0);
             
-            ui.label("Enter your completion times (leave blank if you haven't completed that mode):");
+            ui.label(tr("ttb.dialog_intro"));
             ui.add_space(8.0);
             
             // Use a grid for aligned inputs
@@ -201,27 +212,27 @@ pub fn show_ttb_dialog(ui: &mut egui::Ui, state: &mut TtbDialogState) -> Option<
                 .spacing([8.0, 8.0])
                 .show(ui, |ui| {
                     // Main story
-                    ui.label("Main Story:");
+                    ui.label(tr("ttb.main_story"));
                     ui.add(egui::TextEdit::singleline(&mut state.main_hours).desired_width(50.0));
-                    ui.label("h");
+                    ui.label(tr("ttb.hours_unit"));
                     ui.add(egui::TextEdit::singleline(&mut state.main_minutes).desired_width(50.0));
-                    ui.label("m");
+                    ui.label(tr("ttb.minutes_unit"));
                     ui.end_row();
                     
                     // Main + Extras
-                    ui.label("Main + Extras:");
+                    ui.label(tr("ttb.main_extras"));
                     ui.add(egui::TextEdit::singleline(&mut state.extra_hours).desired_width(50.0));
-                    ui.label("h");
+                    ui.label(tr("ttb.hours_unit"));
                     ui.add(egui::TextEdit::singleline(&mut state.extra_minutes).desired_width(50.0));
-                    ui.label("m");
+                    ui.label(tr("ttb.minutes_unit"));
                     ui.end_row();
             
                     // 100% Completionist
-                    ui.label("100% Completionist:");
+                    ui.label(tr("ttb.completionist"));
                     ui.add(egui::TextEdit::singleline(&mut state.completionist_hours).desired_width(50.0));
-                    ui.label("h");
+                    ui.label(tr("ttb.hours_unit"));
                     ui.add(egui::TextEdit::singleline(&mut state.completionist_minutes).desired_width(50.0));
-                    ui.label("m");
+                    ui.label(tr("ttb.minutes_unit"));
                     ui.end_row();
                 });
             
@@ -229,10 +240,10 @@ pub fn show_ttb_dialog(ui: &mut egui::Ui, state: &mut TtbDialogState) -> Option<
             
             // Buttons
             ui.horizontal(|ui| {
-                if ui.button("Submit").clicked() {
+                if ui.button(tr("ttb.submit")).clicked() {
                     result = Some(true);
                 }
-                if ui.button("Cancel").clicked() {
+                if ui.button(tr("common.cancel")).clicked() {
                     result = Some(false);
                 }
             });
@@ -0,0 +1,189 @@
+//! Unlock order replay: a game's unlocked achievements in the order they were earned,
+//! with long breaks between sessions of unlocking called out
+
+use chrono::{DateTime, Utc};
+
+use crate::{tr, tr_args, GameAchievement};
+
+/// Pauses of at least this many days between two unlocks count as a break
+pub const TIMELINE_BREAK_DAYS: i64 = 30;
+
+/// Width a break takes on the timeline, in days - long breaks would squash everything else
+const BREAK_WIDTH_DAYS: f64 = 7.0;
+
+/// One unlock on the timeline
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineUnlock {
+    pub apiname: String,
+    pub name: String,
+    pub icon: String,
+    pub at: DateTime<Utc>,
+}
+
+/// A break between the unlocks at `after` and `after + 1`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimelineBreak {
+    pub after: usize,
+    pub days: i64,
+}
+
+impl TimelineBreak {
+    /// Annotation like "6 month break"
+    pub fn label(&self) -> String {
+        format_break(self.days)
+    }
+}
+
+/// A game's unlocks in chronological order
+#[derive(Debug, Clone, Default)]
+pub struct UnlockTimeline {
+    pub unlocks: Vec<TimelineUnlock>,
+    pub breaks: Vec<TimelineBreak>,
+    /// Position of each unlock along the timeline, 0.0 (first) to 1.0 (last).
+    /// Time-proportional, except that breaks are drawn at a fixed width.
+    pub positions: Vec<f32>,
+}
+
+impl UnlockTimeline {
+    pub fn is_empty(&self) -> bool {
+        self.unlocks.is_empty()
+    }
+
+    /// Break that starts right after the unlock at `index`
+    pub fn break_after(&self, index: usize) -> Option<&TimelineBreak> {
+        self.breaks.iter().find(|b| b.after == index)
+    }
+
+    /// Unlock closest to a timeline position (0.0 - 1.0)
+    pub fn nearest(&self, position: f32) -> Option<usize> {
+        self.positions
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (*a - position).abs().total_cmp(&(*b - position).abs()))
+            .map(|(i, _)| i)
+    }
+}
+
+/// Order a game's unlocked achievements by unlock time (achievements without one are skipped)
+pub fn build_unlock_timeline(achievements: &[GameAchievement]) -> UnlockTimeline {
+    let mut unlocks: Vec<TimelineUnlock> = achievements
+        .iter()
+        .filter(|a| a.achieved)
+        .filter_map(|a| {
+            Some(TimelineUnlock {
+                apiname: a.apiname.clone(),
+                name: a.name.clone(),
+                icon: a.icon.clone(),
+                at: a.unlocktime?,
+            })
+        })
+        .collect();
+    unlocks.sort_by(|a, b| a.at.cmp(&b.at).then_with(|| a.name.cmp(&b.name)));
+
+    let mut breaks = Vec::new();
+    let mut offsets = Vec::with_capacity(unlocks.len());
+    let mut offset = 0.0;
+    for (i, unlock) in unlocks.iter().enumerate() {
+        if i > 0 {
+            let seconds = (unlock.at - unlocks[i - 1].at).num_seconds();
+            let days = seconds / 86_400;
+            if days >= TIMELINE_BREAK_DAYS {
+                breaks.push(TimelineBreak { after: i - 1, days });
+                offset += BREAK_WIDTH_DAYS;
+            } else {
+                offset += seconds as f64 / 86_400.0;
+            }
+        }
+        offsets.push(offset);
+    }
+    let positions = offsets
+        .iter()
+        .map(|o| if offset > 0.0 { (o / offset) as f32 } else { 0.5 })
+        .collect();
+
+    UnlockTimeline { unlocks, breaks, positions }
+}
+
+/// "3 week break", "6 month break", "2 year break"
+pub fn format_break(days: i64) -> String {
+    let (count, one, many) = if days >= 365 {
+        (days / 365, "timeline.break_year", "timeline.break_years")
+    } else if days >= 60 {
+        (days / 30, "timeline.break_month", "timeline.break_months")
+    } else if days >= 14 {
+        (days / 7, "timeline.break_week", "timeline.break_weeks")
+    } else {
+        (days, "timeline.break_day", "timeline.break_days")
+    };
+    if count == 1 {
+        tr(one).to_string()
+    } else {
+        tr_args(many, &[("count", &count)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn unlock(apiname: &str, day: u32, month: u32, year: i32) -> GameAchievement {
+        GameAchievement {
+            appid: 1,
+            apiname: apiname.to_string(),
+            name: apiname.to_uppercase(),
+            description: None,
+            icon: String::new(),
+            icon_gray: String::new(),
+            achieved: true,
+            unlocktime: Some(Utc.with_ymd_and_hms(year, month, day, 12, 0, 0).unwrap()),
+            is_game_finishing: false,
+            global_percent: None,
+            hidden: false,
+        }
+    }
+
+    #[test]
+    fn orders_unlocks_and_skips_locked() {
+        let mut locked = unlock("locked", 1, 1, 2024);
+        locked.achieved = false;
+        let mut undated = unlock("undated", 1, 1, 2024);
+        undated.unlocktime = None;
+        let timeline = build_unlock_timeline(&[unlock("b", 5, 1, 2024), locked, unlock("a", 2, 1, 2024), undated]);
+        let order: Vec<_> = timeline.unlocks.iter().map(|u| u.apiname.as_str()).collect();
+        assert_eq!(order, ["a", "b"]);
+        assert_eq!(timeline.positions, [0.0, 1.0]);
+        assert!(timeline.breaks.is_empty());
+    }
+
+    #[test]
+    fn long_pauses_become_breaks() {
+        let timeline = build_unlock_timeline(&[
+            unlock("a", 1, 1, 2024),
+            unlock("b", 8, 1, 2024),
+            unlock("c", 8, 7, 2024),
+        ]);
+        assert_eq!(timeline.breaks.len(), 1);
+        let gap = timeline.break_after(1).unwrap();
+        assert_eq!(gap.label(), "6 month break");
+        // The half-year break is drawn as wide as the week before it
+        assert!((timeline.positions[1] - 0.5).abs() < 0.01);
+        assert_eq!(timeline.nearest(0.9), Some(2));
+    }
+
+    #[test]
+    fn single_unlock_sits_in_the_middle() {
+        let timeline = build_unlock_timeline(&[unlock("a", 1, 1, 2024)]);
+        assert_eq!(timeline.positions, [0.5]);
+        assert_eq!(timeline.nearest(0.0), Some(0));
+    }
+
+    #[test]
+    fn break_labels() {
+        assert_eq!(format_break(30), "4 week break");
+        assert_eq!(format_break(45), "6 week break");
+        assert_eq!(format_break(61), "2 month break");
+        assert_eq!(format_break(400), "1 year break");
+        assert_eq!(format_break(800), "2 year break");
+    }
+}
@@ -0,0 +1,289 @@
+//! "Year in review" summary: games started and finished, unlocks, rarest unlock,
+//! tracked hours and the busiest month of a calendar year
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Datelike, Utc};
+
+use crate::{tr, tr_args, FirstPlay, Game, GameSession, TimeDisplaySettings, UnlockRecord};
+
+const MONTH_NAMES: [&str; 12] = [
+    "month.january", "month.february", "month.march", "month.april", "month.may", "month.june",
+    "month.july", "month.august", "month.september", "month.october", "month.november", "month.december",
+];
+
+const MONTH_SHORT_NAMES: [&str; 12] = [
+    "month.jan", "month.feb", "month.mar", "month.apr", "month.may_short", "month.jun",
+    "month.jul", "month.aug", "month.sep", "month.oct", "month.nov", "month.dec",
+];
+
+/// A game started or finished during the year
+#[derive(Debug, Clone, PartialEq)]
+pub struct YearReviewGame {
+    pub appid: u64,
+    pub name: String,
+    pub at: DateTime<Utc>,
+}
+
+/// Summary of one calendar year (in the display time zone)
+#[derive(Debug, Clone)]
+pub struct YearReview {
+    pub year: i32,
+    /// Games first played this year (first play event, or first unlock for games
+    /// played before first plays were tracked)
+    pub games_started: Vec<YearReviewGame>,
+    /// Games perfected this year (their last unlock fell in the year)
+    pub games_finished: Vec<YearReviewGame>,
+    pub achievements_unlocked: u32,
+    /// Unlock with the lowest global percentage
+    pub rarest_unlock: Option<UnlockRecord>,
+    /// Hours from detected play sessions started this year
+    pub session_hours: f32,
+    /// Unlocks per month, January first
+    pub unlocks_per_month: [u32; 12],
+    /// Game with the most unlocks this year (appid, name, unlocks)
+    pub top_game: Option<(u64, String, u32)>,
+}
+
+impl YearReview {
+    /// Month with the most unlocks as (1-based month, unlocks), ties go to the earlier month
+    pub fn busiest_month(&self) -> Option<(u32, u32)> {
+        self.unlocks_per_month
+            .iter()
+            .enumerate()
+            .filter(|(_, &n)| n > 0)
+            .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(&a.0)))
+            .map(|(i, &n)| (i as u32 + 1, n))
+    }
+
+    /// Headline numbers as (value, label) pairs, e.g. ("42", "achievements unlocked")
+    pub fn headline_stats(&self) -> Vec<(String, String)> {
+        let mut stats = vec![
+            (self.achievements_unlocked.to_string(), tr("year_review.achievements_unlocked").to_string()),
+            (self.games_started.len().to_string(), tr("year_review.games_started").to_string()),
+            (self.games_finished.len().to_string(), tr("year_review.games_perfected").to_string()),
+            (format!("{:.0}", self.session_hours), tr("year_review.hours_played").to_string()),
+        ];
+        if let Some((month, unlocks)) = self.busiest_month() {
+            stats.push((month_name(month).to_string(), tr_args("year_review.busiest_month", &[("count", &unlocks)])));
+        }
+        stats
+    }
+
+    /// Nothing happened this year
+    pub fn is_empty(&self) -> bool {
+        self.achievements_unlocked == 0 && self.games_started.is_empty() && self.session_hours == 0.0
+    }
+}
+
+/// Month name for a 1-based month
+pub fn month_name(month: u32) -> &'static str {
+    MONTH_NAMES.get(month.wrapping_sub(1) as usize).map(|key| tr(key)).unwrap_or("")
+}
+
+/// Abbreviated month name for a 1-based month
+pub fn month_short_name(month: u32) -> &'static str {
+    MONTH_SHORT_NAMES.get(month.wrapping_sub(1) as usize).map(|key| tr(key)).unwrap_or("")
+}
+
+/// Years with any unlock, first play or session, newest first
+pub fn review_years(
+    unlocks: &[UnlockRecord],
+    first_plays: &[FirstPlay],
+    sessions: &[GameSession],
+    time: &TimeDisplaySettings,
+) -> Vec<i32> {
+    let mut years: Vec<i32> = unlocks
+        .iter()
+        .map(|u| u.unlocktime)
+        .chain(first_plays.iter().map(|f| f.played_at))
+        .chain(sessions.iter().map(|s| s.started_at))
+        .map(|ts| time.display_date(ts).year())
+        .collect();
+    years.sort_unstable_by(|a, b| b.cmp(a));
+    years.dedup();
+    years
+}
+
+/// Build the review of `year`
+pub fn year_review(
+    year: i32,
+    games: &[Game],
+    unlocks: &[UnlockRecord],
+    first_plays: &[FirstPlay],
+    sessions: &[GameSession],
+    time: &TimeDisplaySettings,
+) -> YearReview {
+    let in_year = |ts: DateTime<Utc>| time.display_date(ts).year() == year;
+    let game_name = |appid: u64| games.iter().find(|g| g.appid == appid).map(|g| g.name.clone());
+
+    let mut sorted: Vec<&UnlockRecord> = unlocks.iter().collect();
+    sorted.sort_by_key(|u| u.unlocktime);
+
+    let mut first_unlock: HashMap<u64, &UnlockRecord> = HashMap::new();
+    let mut last_unlock: HashMap<u64, &UnlockRecord> = HashMap::new();
+    for u in &sorted {
+        first_unlock.entry(u.appid).or_insert(u);
+        last_unlock.insert(u.appid, u);
+    }
+
+    // Started: first play event, falling back to the first unlock
+    let mut games_started: Vec<YearReviewGame> = first_plays
+        .iter()
+        .filter(|f| in_year(f.played_at))
+        .map(|f| YearReviewGame { appid: f.appid, name: f.game_name.clone(), at: f.played_at })
+        .collect();
+    for (appid, u) in &first_unlock {
+        let tracked = first_plays.iter().any(|f| f.appid == *appid);
+        if !tracked && in_year(u.unlocktime) {
+            games_started.push(YearReviewGame { appid: *appid, name: u.game_name.clone(), at: u.unlocktime });
+        }
+    }
+    games_started.sort_by_key(|g| g.at);
+
+    // Finished: a perfected game is finished at its last unlock
+    let mut games_finished: Vec<YearReviewGame> = games
+        .iter()
+        .filter(|g| g.completion_percent().is_some_and(|p| p >= 100.0))
+        .filter_map(|g| last_unlock.get(&g.appid))
+        .filter(|u| in_year(u.unlocktime))
+        .map(|u| YearReviewGame { appid: u.appid, name: u.game_name.clone(), at: u.unlocktime })
+        .collect();
+    games_finished.sort_by_key(|g| g.at);
+
+    let year_unlocks: Vec<&UnlockRecord> = sorted.into_iter().filter(|u| in_year(u.unlocktime)).collect();
+    let mut unlocks_per_month = [0u32; 12];
+    let mut per_game: HashMap<u64, u32> = HashMap::new();
+    for u in &year_unlocks {
+        unlocks_per_month[time.display_date(u.unlocktime).month0() as usize] += 1;
+        *per_game.entry(u.appid).or_default() += 1;
+    }
+    let top_game = per_game
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))
+        .map(|(appid, n)| {
+            let name = game_name(appid)
+                .or_else(|| first_unlock.get(&appid).map(|u| u.game_name.clone()))
+                .unwrap_or_default();
+            (appid, name, n)
+        });
+
+    let rarest_unlock = year_unlocks
+        .iter()
+        .filter(|u| u.global_percent.is_some())
+        .min_by(|a, b| a.global_percent.partial_cmp(&b.global_percent).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|u| (*u).clone());
+
+    let session_minutes: i64 = sessions
+        .iter()
+        .filter(|s| in_year(s.started_at))
+        .map(|s| s.duration_minutes())
+        .sum();
+
+    YearReview {
+        year,
+        games_started,
+        games_finished,
+        achievements_unlocked: year_unlocks.len() as u32,
+        rarest_unlock,
+        session_hours: session_minutes as f32 / 60.0,
+        unlocks_per_month,
+        top_game,
+    }
+}
+
+pub(crate) fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Render the review as a standalone, shareable HTML page
+pub fn year_review_html(review: &YearReview) -> String {
+    let stat_cards: String = review
+        .headline_stats()
+        .iter()
+        .map(|(value, label)| format!("<div class=\"stat\"><b>{}</b><span>{}</span></div>", escape_html(value), escape_html(label)))
+        .collect();
+
+    let max_month = review.unlocks_per_month.iter().copied().max().unwrap_or(0).max(1);
+    let month_bars: String = review
+        .unlocks_per_month
+        .iter()
+        .enumerate()
+        .map(|(i, &n)| {
+            format!(
+                "<div class=\"bar\" title=\"{}\"><div style=\"height:{}%\"></div><span>{}</span></div>",
+                escape_html(&tr_args("year_review.unlocks", &[("count", &n)])),
+                n * 100 / max_month,
+                escape_html(month_short_name(i as u32 + 1))
+            )
+        })
+        .collect();
+
+    let mut highlights = String::new();
+    if let Some(rarest) = &review.rarest_unlock {
+        highlights.push_str(&format!(
+            "<p>{}</p>",
+            tr_args(
+                "year_review.html_rarest",
+                &[
+                    ("achievement", &format!("<b>{}</b>", escape_html(&rarest.achievement_name))),
+                    ("game", &escape_html(&rarest.game_name)),
+                    ("percent", &format!("{:.1}", rarest.global_percent.unwrap_or(0.0))),
+                ],
+            )
+        ));
+    }
+    if let Some((_, name, unlocks)) = &review.top_game {
+        highlights.push_str(&format!(
+            "<p>{}</p>",
+            tr_args("year_review.html_most_unlocks", &[("game", &format!("<b>{}</b>", escape_html(name))), ("count", unlocks)])
+        ));
+    }
+    if !review.games_finished.is_empty() {
+        let names: Vec<String> = review.games_finished.iter().map(|g| escape_html(&g.name)).collect();
+        highlights.push_str(&format!("<p>{}</p>", tr_args("year_review.html_perfected", &[("games", &names.join(", "))])));
+    }
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="{lang}">
+<head>
+<meta charset="utf-8">
+<title>{title} - Overachiever</title>
+<style>
+body {{ font-family: system-ui, sans-serif; background: #1b1d23; color: #e6e6e6; margin: 0; padding: 32px; }}
+main {{ max-width: 720px; margin: 0 auto; }}
+h1 {{ color: #f0b43c; margin-bottom: 24px; }}
+.stats {{ display: flex; flex-wrap: wrap; gap: 12px; }}
+.stat {{ background: #262932; border-radius: 8px; padding: 12px 16px; min-width: 120px; }}
+.stat b {{ display: block; font-size: 28px; color: #f0b43c; }}
+.stat span {{ font-size: 13px; color: #a0a4ad; }}
+.months {{ display: flex; align-items: flex-end; gap: 6px; height: 140px; margin: 28px 0 8px; }}
+.bar {{ flex: 1; display: flex; flex-direction: column; justify-content: flex-end; height: 100%; text-align: center; }}
+.bar div {{ background: #f0b43c; border-radius: 3px 3px 0 0; min-height: 2px; }}
+.bar span {{ font-size: 11px; color: #a0a4ad; margin-top: 4px; }}
+footer {{ margin-top: 32px; font-size: 12px; color: #70747d; }}
+</style>
+</head>
+<body>
+<main>
+<h1>{title}</h1>
+<div class="stats">{stat_cards}</div>
+<div class="months">{month_bars}</div>
+{highlights}
+<footer>{footer}</footer>
+</main>
+</body>
+</html>
+"#,
+        lang = tr("year_review.html_lang"),
+        title = escape_html(&tr_args("year_review.heading", &[("year", &review.year)])),
+        footer = escape_html(tr("year_review.html_footer")),
+        stat_cards = stat_cards,
+        month_bars = month_bars,
+        highlights = highlights,
+    )
+}
@@ -1,23 +1,27 @@
 //! Main application module
 
 pub mod panels;
+mod profile_window;
 mod state;
 
 use crate::cloud_sync::{AuthResult, CloudOpResult, CloudSyncState};
 use crate::config::Config;
+use state::LOG_ENTRIES_LIMIT;
 use crate::db::{
     ensure_user, finalize_migration, get_achievement_history, get_all_achievement_ratings,
     get_all_games, get_last_update, get_log_entries, get_run_history,
-    migrate_initial_scan_flag, record_synced_private_games, open_connection,
+    migrate_initial_scan_flag, open_connection,
 };
 use crate::icon_cache::IconCache;
 use crate::steam_library::get_installed_games;
-use crate::ui::{AppState, ProgressReceiver, SortColumn, SortOrder, TriFilter};
-use overachiever_core::{AchievementHistory, CloudSyncStatus, Game, GameAchievement, LogEntry, RunHistory, SidebarPanel, TtbTimes};
+use crate::ui::{AppState, ProgressReceiver, RangeFilter, SortColumn, SortOrder, TriFilter};
+use overachiever_core::{tr, tr_args, AchievementHistory, AchievementRecords, CloudSyncStatus, Milestone, Game, GameAchievement, LogEntry, RunHistory, SidebarPanel, TtbTimes};
 
 use eframe::egui;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::AtomicBool;
 use std::sync::mpsc::Receiver;
+use std::sync::Arc;
 use std::time::Instant;
 
 pub struct SteamOverachieverApp {
@@ -26,6 +30,9 @@ pub struct SteamOverachieverApp {
     pub(crate) run_history: Vec<RunHistory>,
     pub(crate) achievement_history: Vec<AchievementHistory>,
     pub(crate) log_entries: Vec<LogEntry>,
+    // Activity log: game and period it's filtered to, and the matching entries (None = no filter)
+    pub(crate) log_filter: overachiever_core::LogFilter,
+    pub(crate) filtered_log_entries: Option<Vec<LogEntry>>,
     pub(crate) status: String,
     pub(crate) state: AppState,
     pub(crate) receiver: Option<ProgressReceiver>,
@@ -33,6 +40,8 @@ pub struct SteamOverachieverApp {
     pub(crate) sort_order: SortOrder,
     // Track recently updated games: appid -> time of update
     pub(crate) updated_games: HashMap<u64, Instant>,
+    // Batches the re-sorts requested by game updates during scans
+    pub(crate) resort_throttle: state::ResortThrottle,
     // Track last update time for 2-week warning
     pub(crate) last_update_time: Option<chrono::DateTime<chrono::Utc>>,
     // Force full scan even when all games have been scraped
@@ -45,14 +54,31 @@ pub struct SteamOverachieverApp {
     pub(crate) expanded_rows: HashSet<u64>,
     // Cache loaded achievements for expanded games
     pub(crate) achievements_cache: HashMap<u64, Vec<GameAchievement>>,
+    // Achievement loads running on the DB worker, moved into the cache when they arrive
+    pub(crate) achievement_loads: HashMap<u64, Receiver<rusqlite::Result<Vec<GameAchievement>>>>,
+    // Database thread for queries and writes triggered from the UI
+    pub(crate) db_worker: crate::db::DbWorker,
+    // Finished `db_query` results waiting to be applied on the UI thread
+    pub(crate) db_results_tx: std::sync::mpsc::Sender<state::DbResult>,
+    pub(crate) db_results_rx: Receiver<state::DbResult>,
+    // Cloned into every `db_query` job, so the count tells whether any are still out
+    pub(crate) db_jobs_token: Arc<()>,
+    // Jobs were out at the start of this frame (their results may arrive any moment)
+    pub(crate) db_jobs_were_running: bool,
     // Icon cache for achievement icons
-    pub(crate) icon_cache: IconCache,
+    pub(crate) icon_cache: Arc<IconCache>,
+    // "Download all icons" maintenance run (kept after finishing to show the result)
+    pub(crate) icon_download: Option<Arc<crate::icon_cache::BulkDownload>>,
+    // Download that would exceed the icon cache budget, waiting for confirmation
+    pub(crate) icon_download_plan: Option<crate::icon_cache::DownloadPlan>,
     // User achievement ratings: (appid, apiname) -> rating
     pub(crate) user_achievement_ratings: HashMap<(u64, String), u8>,
     // Filters
     pub(crate) filter_name: String,
     pub(crate) filter_achievements: TriFilter,
     pub(crate) filter_playtime: TriFilter,
+    // Hours played range filter
+    pub(crate) filter_playtime_range: RangeFilter,
     // Settings window
     pub(crate) show_settings: bool,
     // GDPR dialog window
@@ -70,10 +96,18 @@ pub struct SteamOverachieverApp {
     pub(crate) cloud_status: Option<CloudSyncStatus>,
     // OAuth callback receiver (for Steam login)
     pub(crate) auth_receiver: Option<Receiver<Result<AuthResult, String>>>,
+    // Batch upload of local achievement ratings after linking (returns the merged server set)
+    pub(crate) ratings_upload_receiver: Option<Receiver<Result<crate::cloud_sync::RatingTriples, String>>>,
     // Cloud operation receiver (for async upload/download/delete)
     pub(crate) cloud_op_receiver: Option<Receiver<Result<CloudOpResult, String>>>,
     // Pending cloud action (for confirmation dialog)
     pub(crate) pending_cloud_action: Option<CloudAction>,
+    // Downloaded cloud data waiting for the merge summary to be confirmed
+    pub(crate) pending_cloud_import: Option<(overachiever_core::CloudSyncData, crate::db::CloudMergeDiff)>,
+    // Upload queued after Update / Full Scan (auto_upload_after_scan)
+    pub(crate) auto_upload: state::AutoUploadQueue,
+    // Notice in the bottom-right corner and when it was shown
+    pub(crate) toast: Option<(String, std::time::Instant)>,
     // Navigation target for scrolling to an achievement
     pub(crate) navigation_target: Option<(u64, String)>, // (appid, apiname)
     // Whether we need to scroll to the navigation target (one-time scroll)
@@ -82,12 +116,20 @@ pub struct SteamOverachieverApp {
     pub(crate) log_selected_achievement: Option<(u64, String)>, // (appid, apiname)
     // Single game refresh state: appid of game being refreshed
     pub(crate) single_game_refreshing: Option<u64>,
+    // Single game refreshes requested while another one runs, oldest first
+    pub(crate) single_game_refresh_queue: VecDeque<u64>,
     // Track game launch times for cooldown (disable button for 7s)
     pub(crate) game_launch_times: HashMap<u64, Instant>,
+    // Games launched with Play that get a single-game refresh when their session ends
+    pub(crate) launch_watches: HashMap<u64, LaunchWatch>,
     // Installed games (detected from Steam library folders)
     pub(crate) installed_games: HashSet<u64>,
+    // Steam Cloud save state of installed games: appid -> (read at, status)
+    pub(crate) cloud_save_cache: HashMap<u64, (Instant, Option<overachiever_core::CloudSaveStatus>)>,
     // Filter for installed games
     pub(crate) filter_installed: TriFilter,
+    // Filter for store app type (games vs soundtracks, tools, demos)
+    pub(crate) filter_app_type: TriFilter,
     // TTB (Time To Beat) cache: appid -> TtbTimes
     pub(crate) ttb_cache: HashMap<u64, TtbTimes>,
     // TTB scan: list of (appid, name) pairs to scan
@@ -104,8 +146,12 @@ pub struct SteamOverachieverApp {
     pub(crate) english_name_receiver: Option<Receiver<Option<String>>>,
     // Filter for TTB (Time to Beat)
     pub(crate) filter_ttb: TriFilter,
+    // TTB main story hours range filter
+    pub(crate) filter_ttb_range: RangeFilter,
     // Filter for hidden games
     pub(crate) filter_hidden: TriFilter,
+    // Filter for games marked private on Steam
+    pub(crate) filter_private: TriFilter,
     // Settings tab selection
     pub(crate) settings_tab: SettingsTab,
     // Available system fonts (lazily loaded on first settings open)
@@ -122,6 +168,8 @@ pub struct SteamOverachieverApp {
     pub(crate) ttb_batch_receiver: Option<Receiver<Result<Vec<overachiever_core::TtbTimes>, String>>>,
     // Tag filters - currently selected tags (empty = show all games)
     pub(crate) filter_tags: Vec<String>,
+    // Tags a game must not have to be listed
+    pub(crate) filter_excluded_tags: Vec<String>,
     // Tag search input text for searchable dropdown
     pub(crate) tag_search_input: String,
     // Available tags for dropdown (loaded from backend)
@@ -130,7 +178,9 @@ pub struct SteamOverachieverApp {
     pub(crate) hidden_tags: Vec<String>,
     // Hidden tags search state (for profile menu)
     pub(crate) hidden_tags_search: Option<overachiever_core::TagSearchState>,
-    // Tags cache: appid -> Vec<(tag_name, vote_count)>
+    // Tag aliases/parents from the backend (applied to everything put in tags_cache)
+    pub(crate) tag_map: overachiever_core::TagMap,
+    // Tags cache: appid -> Vec<(tag_name, vote_count)>, with tag_map applied
     pub(crate) tags_cache: HashMap<u64, Vec<(String, u32)>>,
     // Tags fetch queue: list of appids to fetch tags for
     pub(crate) tags_fetch_queue: Vec<u64>,
@@ -142,22 +192,193 @@ pub struct SteamOverachieverApp {
     pub(crate) tags_scan_total: i32,
     // Last time we fetched tags (for rate limiting)
     pub(crate) tags_last_fetch: Option<Instant>,
+    // Fetched tags waiting to be posted to the backend, oldest first
+    pub(crate) tags_submit_queue: VecDeque<(u64, Vec<(String, u32)>)>,
+    // Receiver for the post in flight
+    pub(crate) tags_submit_receiver: Option<Receiver<Result<usize, crate::cloud_sync::SubmitTagsError>>>,
+    // Rate limited by the backend: don't post again before this
+    pub(crate) tags_submit_retry_at: Option<Instant>,
     // Tag search dropdown keyboard navigation - selected index
-    #[allow(dead_code)]
     pub(crate) tag_search_selected_index: Option<usize>,
     // Tag filter mode: AND (all tags required) or OR (any tag matches)
-    #[allow(dead_code)]
-    pub(crate) tag_filter_mode_and: bool,
+    pub(crate) tag_match_mode: overachiever_core::TagMatchMode,
     // Selected tag index for vote column display (default 0 = first tag)
     pub(crate) selected_vote_tag_index: Option<usize>,
     // TTB reporting dialog state
     pub(crate) ttb_dialog_state: Option<overachiever_core::TtbDialogState>,
+    // Bulk TTB reporting for 100% completed games (None = not running)
+    pub(crate) ttb_bulk_report: Option<TtbBulkReport>,
+    // Year in review window (None = closed)
+    pub(crate) year_review: Option<YearReviewState>,
+    // Snapshot comparison window (None = closed)
+    pub(crate) snapshot_compare: Option<SnapshotCompareState>,
+    // Completion planner window (None = closed)
+    pub(crate) completion_plan: Option<CompletionPlanState>,
+    // Unlock order replay window (None = closed)
+    pub(crate) unlock_timeline: Option<UnlockTimelineState>,
+    // Local stream overlay server (None = disabled or failed to start)
+    pub(crate) overlay_server: Option<crate::overlay_server::OverlayServer>,
+    // Why the overlay server could not start (shown in settings)
+    pub(crate) overlay_server_error: Option<String>,
+    // Presence sharing connection (only while opted in and linked to the cloud)
+    pub(crate) presence_client: Option<crate::presence_client::PresenceClient>,
+    // Watcher over the Steam client's stats cache (only while local_unlock_watch is on)
+    pub(crate) local_stats_watcher: Option<crate::steam_local_stats::LocalStatsWatcher>,
+    // Why the stats cache watcher could not start (shown in settings)
+    pub(crate) local_stats_error: Option<String>,
+    // Other users currently playing, from the presence connection
+    pub(crate) presence_users: Vec<overachiever_core::UserPresence>,
+    // New data directory typed into settings
+    pub(crate) data_dir_input: String,
+    // Data was copied to a new location but restarting failed (takes effect after a manual restart)
+    pub(crate) data_dir_restart_needed: bool,
     // CJK font download progress
     pub(crate) cjk_font_download_progress: Option<crate::cjk_font::DownloadProgress>,
     // CJK font download receiver (for completion)
     pub(crate) cjk_font_download_receiver: Option<Receiver<Result<(), String>>>,
     // CJK font download progress receiver (for real-time updates)
     pub(crate) cjk_font_progress_receiver: Option<Receiver<crate::cjk_font::DownloadProgress>>,
+    // Personal records computed from unlocked achievements
+    pub(crate) achievement_records: Option<AchievementRecords>,
+    // Unlocks changed; records are recomputed once the running scan or refreshes are done
+    pub(crate) achievement_records_dirty: bool,
+    // When each achievement was unlocked (today's count in the mini window)
+    pub(crate) unlock_times: Vec<chrono::DateTime<chrono::Utc>>,
+    // Backlog goals (finish N games per month, reach X% by a date)
+    pub(crate) goals: Vec<overachiever_core::Goal>,
+    // Milestones reached during this session (shown in a celebration popup)
+    pub(crate) new_milestones: Vec<Milestone>,
+    // Day the streak reminder notification was last shown (once per day)
+    pub(crate) streak_reminded_on: Option<chrono::NaiveDate>,
+    // Final stretch window: remaining achievements of nearly completed games
+    pub(crate) show_final_stretch: bool,
+    pub(crate) final_stretch: Vec<crate::db::RemainingAchievement>,
+    pub(crate) final_stretch_min_percent: f32,
+    // Stalled games review: games still to decide on (first one is shown)
+    pub(crate) show_stalled_review: bool,
+    pub(crate) stalled_review: Vec<u64>,
+    // "What's new" dialog: changelog entries to show (None = closed)
+    pub(crate) whats_new: Option<Vec<crate::changelog::ChangelogEntry>>,
+    // Newer release found on GitHub, and the running check
+    pub(crate) latest_release: Option<crate::changelog::LatestRelease>,
+    pub(crate) update_check_receiver: Option<Receiver<Result<Option<crate::changelog::LatestRelease>, String>>>,
+    // Steam credentials check: the running lookup, its last result, and whether the fields
+    // were edited since; plus the persona name and avatar URL resolved for the configured account
+    pub(crate) credentials_check_receiver: Option<Receiver<Result<crate::steam_api::PlayerSummary, String>>>,
+    pub(crate) credentials_check: Option<Result<crate::steam_api::PlayerSummary, String>>,
+    pub(crate) credentials_edited: bool,
+    pub(crate) steam_persona: Option<(String, Option<String>)>,
+    // Rival account: their stored games, the running scrape and its status
+    pub(crate) rival_games: Vec<Game>,
+    pub(crate) rival_receiver: Option<Receiver<crate::steam_api::RivalScrapeProgress>>,
+    pub(crate) rival_status: Option<String>,
+    pub(crate) show_rival_summary: bool,
+    // Session tracking: receiver for "currently playing" poll result
+    pub(crate) session_poll_receiver: Option<Receiver<Result<Option<u64>, String>>>,
+    // Session tracking: last poll time
+    pub(crate) session_last_poll: Option<Instant>,
+    // Session tracking: game currently running (from last poll)
+    pub(crate) currently_playing: Option<u64>,
+    // Session history window: (appid, game_name, sessions)
+    pub(crate) sessions_window: Option<(u64, String, Vec<overachiever_core::GameSession>)>,
+    // Other local profiles open side by side in their own OS windows
+    pub(crate) profile_windows: Vec<profile_window::ProfileWindow>,
+    // Profile menu: Steam ID typed into "open in new window", and the local profiles
+    // (steam_id, display name) read when the menu opens
+    pub(crate) profile_window_input: String,
+    pub(crate) local_profiles: Option<Vec<(String, Option<String>)>>,
+    // Profiles ticked for the merged library view, and whether shared games are shown once
+    pub(crate) merge_selection: HashSet<String>,
+    pub(crate) merge_dedupe: bool,
+    // Power management: last read battery/load state
+    pub(crate) power_status: crate::power::PowerStatus,
+    // Power management: last time the power state was read
+    pub(crate) power_last_check: Option<Instant>,
+    // Set while background work is paused, checked by the full scan thread between games
+    pub(crate) scan_paused: Arc<AtomicBool>,
+    // Last check whether a scheduled history snapshot is due
+    pub(crate) snapshot_last_check: Option<Instant>,
+    // Steam Community XML import result (games list without API key)
+    pub(crate) community_import_receiver: Option<Receiver<Result<crate::steam_community::CommunityGamesList, String>>>,
+    // Public profile games list fetch (private game detection without a local Steam install)
+    pub(crate) steam_visibility_receiver: Option<Receiver<Result<HashSet<u64>, String>>>,
+    // Admin dashboard window
+    pub(crate) show_admin_window: bool,
+    // Admin dashboard tab selection
+    pub(crate) admin_tab: AdminTab,
+    // Admin dashboard data (None until first load)
+    pub(crate) admin_dashboard: Option<crate::cloud_sync::AdminDashboard>,
+    // Admin dashboard fetch/moderation in progress
+    pub(crate) admin_receiver: Option<Receiver<Result<crate::cloud_sync::AdminDashboard, String>>>,
+    // Last admin dashboard error
+    pub(crate) admin_error: Option<String>,
+    // New tag alias/parent being entered in the admin window
+    pub(crate) admin_tag_mapping_input: overachiever_core::TagMapping,
+    // Community difficulty curves per game (apiname, avg rating, vote count)
+    pub(crate) difficulty_curves: HashMap<u64, Vec<overachiever_core::AchievementDifficulty>>,
+    // Games whose difficulty curve was already requested this session
+    pub(crate) difficulty_requested: HashSet<u64>,
+    // Difficulty curve fetch in progress
+    pub(crate) difficulty_receiver: Option<Receiver<crate::cloud_sync::DifficultyResult>>,
+    // Global rarity snapshots per game from the server, fetched when a row is expanded
+    pub(crate) rarity_history: HashMap<u64, Vec<overachiever_core::AchievementRarityHistory>>,
+    // Games whose rarity history was already requested this session
+    pub(crate) rarity_requested: HashSet<u64>,
+    // Rarity history fetch in progress
+    pub(crate) rarity_receiver: Option<Receiver<crate::cloud_sync::RarityResult>>,
+    // Community completion stats per game (None = too few players), fetched when a row is expanded
+    pub(crate) community_game_stats: HashMap<u64, Option<overachiever_core::CommunityGameStats>>,
+    // Games whose community stats were already requested this session
+    pub(crate) community_stats_requested: HashSet<u64>,
+    // Community stats fetches in progress
+    pub(crate) community_stats_receiver: Option<Receiver<crate::cloud_sync::CommunityStatsResult>>,
+    // Startup batch fetch of community ratings for all owned games (one message per batch)
+    pub(crate) ratings_batch_receiver: Option<Receiver<Result<Vec<overachiever_core::GameAchievementRatings>, String>>>,
+    // Community comments per game (fetched with the difficulty curve, removed comments excluded)
+    pub(crate) achievement_comments: HashMap<u64, Vec<overachiever_core::AchievementComment>>,
+    // Comments reported this session
+    pub(crate) reported_comments: HashSet<i64>,
+    // Central panel tab (library table or wishlist)
+    pub(crate) central_tab: CentralTab,
+    // Steam wishlist (stored locally, refreshed from Steam)
+    pub(crate) wishlist: Vec<overachiever_core::WishlistItem>,
+    // Community completion averages for wishlisted games
+    pub(crate) wishlist_completion: HashMap<u64, overachiever_core::GameCompletionStats>,
+    // Wishlist fetch in progress
+    pub(crate) wishlist_receiver: Option<Receiver<Result<crate::steam_wishlist::WishlistDetails, String>>>,
+    // Whether the wishlist was fetched this session (fetched on first visit to the tab)
+    pub(crate) wishlist_fetched: bool,
+    // Last wishlist fetch error
+    pub(crate) wishlist_error: Option<String>,
+    // Result of the last benchmark run (Settings > Debug)
+    pub(crate) benchmark_report: Option<String>,
+    // Backup picked in the Debug tab, and whether the restore is awaiting confirmation
+    pub(crate) selected_backup: Option<std::path::PathBuf>,
+    pub(crate) confirm_restore: bool,
+    // Library-wide achievement search: input text, last executed query, its results and the running query
+    pub(crate) achievement_search: String,
+    pub(crate) achievement_search_ran: String,
+    pub(crate) achievement_search_hits: Vec<crate::db::AchievementSearchHit>,
+    pub(crate) achievement_search_receiver: Option<Receiver<rusqlite::Result<Vec<crate::db::AchievementSearchHit>>>>,
+    // Ctrl+K command palette, Some while open
+    pub(crate) command_palette: Option<panels::CommandPalette>,
+    // overachiever:// link from the command line, opened on the first frame
+    pub(crate) pending_deep_link: Option<crate::deep_link::DeepLink>,
+    // Links and focus requests handed over by other instances
+    pub(crate) instance_receiver: Option<Receiver<crate::deep_link::InstanceMessage>>,
+    // .overach file from the command line, previewed on the first frame
+    pub(crate) pending_library_file: Option<std::path::PathBuf>,
+    // Library file awaiting confirmation in the import preview
+    pub(crate) library_import: Option<LibraryImportPreview>,
+}
+
+/// Central panel tab selection
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CentralTab {
+    #[default]
+    Library,
+    Wishlist,
+    Stats,
 }
 
 /// Settings tab selection
@@ -169,6 +390,106 @@ pub enum SettingsTab {
     Debug,
 }
 
+/// Admin dashboard tab selection
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AdminTab {
+    #[default]
+    Overview,
+    Users,
+    Ttb,
+    Tags,
+    TagMappings,
+    Comments,
+}
+
+/// Moderation action sent before the dashboard is reloaded
+#[derive(Debug, Clone)]
+pub enum AdminAction {
+    /// Approve or reject a pending submission: (kind "ttb"/"tags", appid, approve)
+    Submission(&'static str, u64, bool),
+    /// Remove a reported comment, or dismiss its reports: (comment id, remove)
+    Comment(i64, bool),
+    /// Add or replace a tag alias/parent
+    SetTagMapping(overachiever_core::TagMapping),
+    /// Remove the alias/parent of a tag
+    DeleteTagMapping(String),
+}
+
+/// A game launched with Play, waiting for its session to end so it can be refreshed
+#[derive(Debug, Clone, Copy)]
+pub struct LaunchWatch {
+    pub launched_at: Instant,
+    /// Playtime (minutes) when the game was launched
+    pub playtime_at_launch: u32,
+    /// Whether a session poll has seen the game running
+    pub seen_running: bool,
+}
+
+/// Walk-through of the "Report times for finished games" flow
+pub(crate) struct TtbBulkReport {
+    /// Games after the current one (appids)
+    pub(crate) queue: VecDeque<u64>,
+    pub(crate) total: usize,
+    pub(crate) submitted: usize,
+    /// Inputs for the current game
+    pub(crate) dialog: overachiever_core::TtbDialogState,
+}
+
+/// Year in review window: the data it's built from and the selected year
+pub(crate) struct YearReviewState {
+    /// Years with any activity, newest first
+    pub(crate) years: Vec<i32>,
+    pub(crate) review: overachiever_core::YearReview,
+    pub(crate) unlocks: Vec<overachiever_core::UnlockRecord>,
+    pub(crate) first_plays: Vec<overachiever_core::FirstPlay>,
+    pub(crate) sessions: Vec<overachiever_core::GameSession>,
+    /// Screen area of the rendered report (cropped out of the screenshot for PNG export)
+    pub(crate) report_rect: Option<egui::Rect>,
+}
+
+/// Completion planner window: a game's remaining achievements as an ordered checklist
+pub(crate) struct CompletionPlanState {
+    pub(crate) appid: u64,
+    pub(crate) game_name: String,
+    pub(crate) achievements: Vec<overachiever_core::GameAchievement>,
+    pub(crate) plan: overachiever_core::CompletionPlan,
+    /// Whether the plan used the community ratings (it's rebuilt once they arrive)
+    pub(crate) rated: bool,
+    /// Steps ticked off in the checklist (apinames)
+    pub(crate) done: HashSet<String>,
+}
+
+/// Unlock order replay window for one game
+pub(crate) struct UnlockTimelineState {
+    pub(crate) appid: u64,
+    pub(crate) game_name: String,
+    pub(crate) timeline: overachiever_core::UnlockTimeline,
+    /// Index of the unlock the replay has reached
+    pub(crate) position: usize,
+    /// Time of the last replay step while playing (None = paused)
+    pub(crate) last_step: Option<f64>,
+    /// Screen area of the rendered timeline (cropped out of the screenshot for PNG export)
+    pub(crate) chart_rect: Option<egui::Rect>,
+}
+
+/// State of the "compare snapshots" window
+pub(crate) struct SnapshotCompareState {
+    /// When snapshots were taken, oldest first
+    pub(crate) times: Vec<chrono::DateTime<chrono::Utc>>,
+    /// Selected snapshots (indices into `times`)
+    pub(crate) from: usize,
+    pub(crate) to: usize,
+    pub(crate) unlocks: Vec<overachiever_core::UnlockRecord>,
+    pub(crate) diff: Option<overachiever_core::SnapshotDiff>,
+}
+
+/// A `.overach` library file read for import, shown in the preview dialog until confirmed
+pub(crate) struct LibraryImportPreview {
+    pub(crate) path: std::path::PathBuf,
+    pub(crate) file: crate::library_file::LibraryFile,
+    pub(crate) diff: crate::db::CloudMergeDiff,
+}
+
 /// Cloud action pending confirmation
 #[derive(Debug, Clone, PartialEq)]
 pub enum CloudAction {
@@ -183,7 +504,9 @@ impl SteamOverachieverApp {
         let show_settings = !config.is_valid(); // Show settings on first run if not configured
         let steam_id = config.steam_id.as_str();
         let initial_font_size = config.font_size;
+        let near_complete_threshold = config.near_complete_threshold;
         let conn = open_connection().expect("Failed to open database");
+        let (db_results_tx, db_results_rx) = std::sync::mpsc::channel();
 
         // Finalize any pending migrations with the user's steam_id
         if !steam_id.is_empty() {
@@ -194,23 +517,18 @@ impl SteamOverachieverApp {
         // Migrate existing users: mark initial scan complete if they already have tracking data
         let _ = migrate_initial_scan_flag(&conn);
 
-        let mut games = get_all_games(&conn, steam_id).unwrap_or_default();
-
-        // Auto-sync private/hidden games from Steam on each startup
-        if !steam_id.is_empty() {
-            if let Ok(count) = crate::steam_config::sync_steam_hidden_games(&conn, steam_id) {
-                if count > 0 {
-                    // Reload games to pick up the private/hidden flags
-                    games = get_all_games(&conn, steam_id).unwrap_or_default();
-                }
-            }
-            let _ = record_synced_private_games(&conn);
-        }
+        let games = get_all_games(&conn, steam_id).unwrap_or_default();
 
         let run_history = get_run_history(&conn, steam_id).unwrap_or_default();
         let achievement_history = get_achievement_history(&conn, steam_id).unwrap_or_default();
-        let log_entries = get_log_entries(&conn, steam_id, 30).unwrap_or_default();
+        let log_entries = get_log_entries(&conn, steam_id, LOG_ENTRIES_LIMIT, None, None, None).unwrap_or_default();
+        let wishlist = crate::db::get_wishlist(&conn, steam_id).unwrap_or_default();
+        let goals = crate::db::get_goals(&conn, steam_id).unwrap_or_default();
         let last_update_time = get_last_update(&conn).unwrap_or(None);
+        let steam_persona = match crate::db::get_user_profile(&conn, steam_id) {
+            Ok((Some(name), avatar_url)) => Some((name, avatar_url)),
+            _ => None,
+        };
         let is_cloud_linked = config.cloud_token.is_some();
 
         // Load user achievement ratings - prefer server data if authenticated, fallback to local
@@ -251,23 +569,35 @@ impl SteamOverachieverApp {
             run_history,
             achievement_history,
             log_entries,
-            status: "Ready".to_string(),
+            log_filter: Default::default(),
+            filtered_log_entries: None,
+            status: tr("status.ready").to_string(),
             state: AppState::Idle,
             receiver: None,
             sort_column: SortColumn::Name,
             sort_order: SortOrder::Ascending,
             updated_games: HashMap::new(),
+            resort_throttle: Default::default(),
             last_update_time,
             force_full_scan: false,
             auto_scrape_attempted: false,
             include_unplayed_in_avg: false,
             expanded_rows: HashSet::new(),
             achievements_cache: HashMap::new(),
-            icon_cache: IconCache::new(),
+            achievement_loads: HashMap::new(),
+            db_worker: crate::db::DbWorker::spawn(),
+            db_results_tx,
+            db_results_rx,
+            db_jobs_token: Arc::new(()),
+            db_jobs_were_running: false,
+            icon_cache: Arc::new(IconCache::new()),
+            icon_download: None,
+            icon_download_plan: None,
             user_achievement_ratings,
             filter_name: String::new(),
             filter_achievements: TriFilter::All,
             filter_playtime: TriFilter::All,
+            filter_playtime_range: RangeFilter::default(),
             show_settings,
             show_gdpr_dialog: false,
             show_profile_menu: false,
@@ -278,15 +608,23 @@ impl SteamOverachieverApp {
             cloud_sync_state: if is_cloud_linked { CloudSyncState::Idle } else { CloudSyncState::NotLinked },
             cloud_status: None,
             auth_receiver: None,
+            ratings_upload_receiver: None,
             cloud_op_receiver: None,
             pending_cloud_action: None,
+            pending_cloud_import: None,
+            auto_upload: state::AutoUploadQueue::default(),
+            toast: None,
             navigation_target: None,
             needs_scroll_to_target: false,
             log_selected_achievement: None,
             single_game_refreshing: None,
+            single_game_refresh_queue: VecDeque::new(),
             game_launch_times: HashMap::new(),
+            launch_watches: HashMap::new(),
             installed_games,
+            cloud_save_cache: HashMap::new(),
             filter_installed: TriFilter::All,
+            filter_app_type: TriFilter::All,
             ttb_cache: HashMap::new(),
             ttb_scan_queue: Vec::new(),
             ttb_last_fetch: None,
@@ -295,7 +633,9 @@ impl SteamOverachieverApp {
             ttb_search_pending: None,
             english_name_receiver: None,
             filter_ttb: TriFilter::All,
+            filter_ttb_range: RangeFilter::default(),
             filter_hidden: TriFilter::Without, // Default: hide hidden games
+            filter_private: TriFilter::All,
             settings_tab: SettingsTab::default(),
             available_fonts: None,
             pending_font_size: initial_font_size,
@@ -304,28 +644,131 @@ impl SteamOverachieverApp {
             ttb_blacklist: HashSet::new(),
             ttb_batch_receiver: None,
             filter_tags: Vec::new(),
+            filter_excluded_tags: Vec::new(),
             tag_search_input: String::new(),
             available_tags: Vec::new(),
+            tag_map: overachiever_core::TagMap::default(),
             tags_cache: HashMap::new(),
             tags_fetch_queue: Vec::new(),
             tags_fetching: None,
             tags_receiver: None,
             tags_scan_total: 0,
             tags_last_fetch: None,
+            tags_submit_queue: VecDeque::new(),
+            tags_submit_receiver: None,
+            tags_submit_retry_at: None,
             tag_search_selected_index: None,
-            tag_filter_mode_and: true,
+            tag_match_mode: overachiever_core::TagMatchMode::All,
             selected_vote_tag_index: None,
             ttb_dialog_state: None,
+            ttb_bulk_report: None,
+            year_review: None,
+            snapshot_compare: None,
+            completion_plan: None,
+            unlock_timeline: None,
+            overlay_server: None,
+            overlay_server_error: None,
+            presence_client: None,
+            local_stats_watcher: None,
+            local_stats_error: None,
+            presence_users: Vec::new(),
+            data_dir_input: String::new(),
+            data_dir_restart_needed: false,
             hidden_tags: Vec::new(),
             hidden_tags_search: None,
             cjk_font_download_progress: None,
             cjk_font_download_receiver: None,
             cjk_font_progress_receiver: None,
+            achievement_records: None,
+            achievement_records_dirty: false,
+            unlock_times: Vec::new(),
+            goals,
+            new_milestones: Vec::new(),
+            streak_reminded_on: None,
+            show_final_stretch: false,
+            final_stretch: Vec::new(),
+            final_stretch_min_percent: near_complete_threshold,
+            show_stalled_review: false,
+            stalled_review: Vec::new(),
+            whats_new: None,
+            latest_release: None,
+            update_check_receiver: None,
+            credentials_check_receiver: None,
+            credentials_check: None,
+            credentials_edited: false,
+            steam_persona,
+            rival_games: Vec::new(),
+            rival_receiver: None,
+            rival_status: None,
+            show_rival_summary: false,
+            session_poll_receiver: None,
+            session_last_poll: None,
+            currently_playing: None,
+            sessions_window: None,
+            profile_windows: Vec::new(),
+            profile_window_input: String::new(),
+            local_profiles: None,
+            merge_selection: HashSet::new(),
+            merge_dedupe: true,
+            power_status: crate::power::PowerStatus::default(),
+            power_last_check: None,
+            scan_paused: Arc::new(AtomicBool::new(false)),
+            snapshot_last_check: None,
+            community_import_receiver: None,
+            steam_visibility_receiver: None,
+            show_admin_window: false,
+            admin_tab: AdminTab::default(),
+            admin_dashboard: None,
+            admin_receiver: None,
+            admin_error: None,
+            admin_tag_mapping_input: overachiever_core::TagMapping::default(),
+            difficulty_curves: HashMap::new(),
+            difficulty_requested: HashSet::new(),
+            difficulty_receiver: None,
+            community_game_stats: HashMap::new(),
+            community_stats_requested: HashSet::new(),
+            rarity_history: HashMap::new(),
+            rarity_requested: HashSet::new(),
+            rarity_receiver: None,
+            community_stats_receiver: None,
+            ratings_batch_receiver: None,
+            achievement_comments: HashMap::new(),
+            reported_comments: HashSet::new(),
+            central_tab: CentralTab::default(),
+            wishlist,
+            wishlist_completion: HashMap::new(),
+            wishlist_receiver: None,
+            wishlist_fetched: false,
+            wishlist_error: None,
+            benchmark_report: None,
+            selected_backup: None,
+            confirm_restore: false,
+            achievement_search: String::new(),
+            achievement_search_ran: String::new(),
+            achievement_search_hits: Vec::new(),
+            achievement_search_receiver: None,
+            command_palette: None,
+            pending_deep_link: None,
+            instance_receiver: None,
+            pending_library_file: None,
+            library_import: None,
         };
 
+        app.icon_cache.set_max_mb(app.config.icon_cache_max_mb);
+        app.icon_cache.set_max_memory_mb(app.config.icon_memory_max_mb);
+
+        // Auto-sync private/hidden games from Steam on each startup
+        app.sync_steam_visibility();
+
+        // Open the configured startup view (may change sorting and filters)
+        app.apply_startup_view();
+
         // Apply consistent sorting after loading from database
         app.sort_games();
 
+        // Compute personal records (no celebration for milestones reached before this session)
+        app.refresh_achievement_records(false);
+
         // Helper to log to ttb_log.txt
         fn init_log(msg: &str) {
             use std::io::Write;
@@ -342,12 +785,29 @@ impl SteamOverachieverApp {
         init_log("Loading TTB blacklist...");
         app.load_ttb_blacklist();
 
+        // Load tag aliases first so tags collapse into canonical ones as they load
+        init_log("Loading tag mappings...");
+        app.load_tag_mappings();
+
         // Load available tags and tags for games from backend
         init_log("Loading available tags...");
         app.load_available_tags();
         init_log(&format!("Loading tags for {} games...", app.games.len()));
         app.load_tags_for_games();
-        init_log("Tags loaded, starting update...");
+
+        // Community achievement ratings from the local cache, refreshing expired ones
+        init_log("Loading community ratings...");
+        app.load_community_ratings();
+        init_log("Ratings loaded, starting update...");
+
+        // Rival's games for the head-to-head comparison
+        app.load_rival_games();
+
+        // Show what changed since the last start, look for a newer release
+        app.open_whats_new_after_update();
+        if app.config.check_for_updates {
+            app.start_update_check();
+        }
 
         // Auto-start update on launch
         app.start_update();
@@ -360,12 +820,38 @@ impl SteamOverachieverApp {
 impl eframe::App for SteamOverachieverApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         self.check_progress();
+        self.db_jobs_tick(); // Pick up achievement loads from the DB worker
+        self.single_game_refresh_tick(); // Start the next queued single game refresh
         self.cleanup_expired_flashes();
         self.check_auth_callback();
+        self.ratings_upload_tick();
         self.check_cloud_operation();
+        self.auto_upload_tick(); // Start a queued automatic cloud upload
         self.check_cjk_font_download(); // Check CJK font download progress
+        self.power_tick(); // Re-read battery/load state for scan throttling
         self.ttb_scan_tick(); // Process TTB scan queue
         self.tags_fetch_tick(); // Process tags fetch queue
+        self.session_poll_tick(); // Poll "currently playing" for session tracking
+        self.overlay_tick(); // Keep the stream overlay server in sync
+        self.presence_tick(); // Share what we're playing, if opted in
+        self.local_unlocks_tick(); // Pick up unlocks from the Steam client's stats cache
+        self.launch_refresh_tick(); // Refresh launched games once their session ends
+        self.community_import_tick(); // Process Steam Community XML import
+        self.steam_visibility_tick(); // Apply private games from the public profile
+        self.admin_tick(); // Process admin dashboard responses
+        self.difficulty_tick(); // Process community difficulty curve fetches
+        self.community_stats_tick(); // Process community completion stats fetches
+        self.rarity_tick(); // Process rarity history fetches
+        self.ratings_batch_tick(); // Apply batch-fetched community ratings
+        self.update_check_tick(); // Pick up the GitHub release check
+        self.credentials_check_tick(); // Pick up the Steam API key / Steam ID check
+        self.rival_tick(); // Process rival scrape progress
+        self.wishlist_tick(); // Process wishlist fetches
+        self.streak_reminder_tick(); // Evening reminder when the unlock streak is at risk
+        self.snapshot_tick(); // Scheduled history snapshot when no update ran for a while
+        self.records_tick(); // Recompute records once a scan has finished
+        self.deep_link_tick(ctx); // Open overachiever:// links from the command line or other instances
+        self.handle_dropped_import_files(ctx); // Import games XML / library files dropped onto the window
 
         let is_busy = self.state.is_busy();
 
@@ -385,18 +871,36 @@ impl eframe::App for SteamOverachieverApp {
         }
 
         let has_flashing = !self.updated_games.is_empty();
-        let is_linking = self.auth_receiver.is_some();
+        let is_linking = self.auth_receiver.is_some() || self.ratings_upload_receiver.is_some();
         let is_cloud_op = self.cloud_op_receiver.is_some();
         let has_launch_cooldowns = !self.game_launch_times.is_empty();
         let is_ttb_scanning = !self.ttb_scan_queue.is_empty();
         let is_ttb_fetching = self.ttb_receiver.is_some();
         let is_ttb_batch = self.ttb_batch_receiver.is_some();
+        let is_importing = self.community_import_receiver.is_some();
+        let is_admin_loading = self.admin_receiver.is_some();
+        let is_difficulty_loading = self.difficulty_receiver.is_some() || self.ratings_batch_receiver.is_some()
+            || self.community_stats_receiver.is_some() || self.rarity_receiver.is_some();
+        let is_wishlist_loading = self.wishlist_receiver.is_some();
+        let is_icon_download = self.icon_download.as_ref().is_some_and(|job| !job.is_finished());
+        let is_update_check = self.update_check_receiver.is_some() || self.credentials_check_receiver.is_some();
+        let is_rival_scraping = self.rival_receiver.is_some();
+        let is_refreshing_game = self.single_game_refreshing.is_some() || !self.single_game_refresh_queue.is_empty();
+        let is_db_loading = !self.achievement_loads.is_empty() || self.achievement_search_receiver.is_some()
+            || self.db_jobs_were_running || self.db_jobs_running();
 
         // Request repaint while busy or while animations are active
-        if is_busy || has_flashing || is_linking || is_cloud_op || has_launch_cooldowns || is_ttb_scanning || is_ttb_fetching || is_ttb_batch {
+        if is_busy || has_flashing || is_linking || is_cloud_op || has_launch_cooldowns || is_ttb_scanning || is_ttb_fetching || is_ttb_batch || is_importing || is_admin_loading || is_difficulty_loading || is_wishlist_loading || is_icon_download || is_update_check || is_rival_scraping || is_refreshing_game || is_db_loading {
             ctx.request_repaint();
         }
 
+        // Wake up for the next session poll even when idle
+        if self.config.session_poll_interval_secs > 0 {
+            ctx.request_repaint_after(std::time::Duration::from_secs(self.config.session_poll_interval_secs));
+        } else if !self.launch_watches.is_empty() {
+            ctx.request_repaint_after(std::time::Duration::from_secs(60));
+        }
+
         // Track window state for persistence (only when not maximized to preserve restore size)
         ctx.input(|i| {
             let maximized = i.viewport().maximized.unwrap_or(false);
@@ -417,6 +921,9 @@ impl eframe::App for SteamOverachieverApp {
         // Clean up expired launch cooldowns
         self.cleanup_expired_launch_cooldowns();
 
+        // Keyboard shortcuts (before panels so text fields don't see them)
+        self.command_palette_shortcut(ctx);
+
         // Render panels
         self.render_top_panel(ctx);
         self.render_history_panel(ctx);
@@ -430,6 +937,55 @@ impl eframe::App for SteamOverachieverApp {
 
         // Show TTB reporting dialog if open
         self.render_ttb_reporting_dialog(ctx);
+        self.render_ttb_bulk_window(ctx);
+
+        // Show admin dashboard if open
+        self.render_admin_window(ctx);
+
+        // Show session history window if open
+        self.render_sessions_window(ctx);
+
+        // Show final stretch window if open
+        self.render_final_stretch_window(ctx);
+
+        // Show stalled games review if open
+        self.render_stalled_review_window(ctx);
+
+        // Show what's new / changelog if open
+        self.render_whats_new_window(ctx);
+
+        // Show rivalry summary if open
+        self.render_rival_window(ctx);
+
+        // Show command palette if open
+        self.render_command_palette(ctx);
+
+        // Show year in review window if open
+        self.render_year_review_window(ctx);
+
+        // Show snapshot comparison window if open
+        self.render_snapshot_compare_window(ctx);
+
+        // Show completion planner if open
+        self.render_completion_plan_window(ctx);
+        self.render_unlock_timeline_window(ctx);
+
+        // Show library file import preview if a file was opened
+        self.render_library_import_window(ctx);
+
+        // Celebrate newly reached milestones
+        self.render_milestone_popup(ctx);
+
+        // Always-on-top quick stats window
+        self.render_mini_window(ctx);
+
+        // Secondary profile windows
+        for window in &mut self.profile_windows {
+            window.show(ctx);
+        }
+        self.profile_windows.retain(|w| w.open);
+
+        self.render_toast(ctx);
     }
 
     fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
@@ -437,6 +993,7 @@ impl eframe::App for SteamOverachieverApp {
         // Note: We save the last known state before exit
         // The actual window rect is obtained via raw_window_handle integration
         // For simplicity, we save periodically during update and trust that state
+        self.remember_last_view();
         let _ = self.config.save();
     }
 }
@@ -465,18 +1022,18 @@ impl SteamOverachieverApp {
             }
         }
 
-        egui::Window::new("Search HowLongToBeat")
+        egui::Window::new(tr("hltb.search_title"))
             .collapsible(false)
             .resizable(false)
             .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
             .fixed_size([400.0, 0.0])
             .show(ctx, |ui| {
                 ui.add_space(8.0);
-                ui.label(format!("Searching for: {}", game_name));
+                ui.label(tr_args("hltb.searching_for", &[("game", &game_name)]));
                 ui.add_space(8.0);
 
                 ui.horizontal(|ui| {
-                    ui.label("Search query:");
+                    ui.label(tr("hltb.search_query"));
                     let response = ui.add(egui::TextEdit::singleline(&mut search_query).desired_width(220.0));
                     // Focus the text field and select all on first show
                     if response.gained_focus() {
@@ -494,7 +1051,7 @@ impl SteamOverachieverApp {
                     // English name fetch button
                     if is_fetching_english {
                         ui.spinner();
-                    } else if ui.button("EN").on_hover_text("Fetch English name from Steam").clicked() {
+                    } else if ui.button(tr("hltb.english")).on_hover_text(tr("hltb.english_tooltip")).clicked() {
                         // Spawn background thread to fetch English name
                         let (tx, rx) = std::sync::mpsc::channel();
                         self.english_name_receiver = Some(rx);
@@ -508,10 +1065,10 @@ impl SteamOverachieverApp {
                 ui.add_space(12.0);
 
                 ui.horizontal(|ui| {
-                    if ui.button("Cancel").clicked() {
+                    if ui.button(tr("common.cancel")).clicked() {
                         cancelled = true;
                     }
-                    if ui.button("OK").clicked() {
+                    if ui.button(tr("common.ok")).clicked() {
                         confirmed = true;
                     }
                 });
@@ -545,45 +1102,45 @@ impl SteamOverachieverApp {
         let mut submitted = false;
         let mut cancelled = false;
 
-        egui::Window::new("Report Time to Beat").resizable(false).collapsible(false).show(ctx, |ui| {
+        egui::Window::new(tr("ttb.dialog_title")).resizable(false).collapsible(false).show(ctx, |ui| {
             ui.set_min_width(400.0);
 
             // Show completion message if present
             if let Some(ref msg) = dialog_state.completion_message {
-                ui.label(egui::RichText::new(msg).strong().color(egui::Color32::from_rgb(100, 255, 100)));
+                ui.label(egui::RichText::new(msg).strong().color(crate::app::panels::top::theme::theme_colors(&self.config).complete));
                 ui.add_space(8.0);
             }
 
-            ui.label(egui::RichText::new(format!("Game: {}", dialog_state.game_name)).strong());
+            ui.label(egui::RichText::new(tr_args("ttb.dialog_game", &[("game", &dialog_state.game_name)])).strong());
             ui.add_space(8.0);
 
-            ui.label("Enter your completion times (leave blank if you haven't completed that mode):");
+            ui.label(tr("ttb.dialog_intro"));
             ui.add_space(8.0);
 
             // Use a grid for aligned inputs
             egui::Grid::new("ttb_input_grid").num_columns(5).spacing([8.0, 8.0]).show(ui, |ui| {
                 // Main story
-                ui.label("Main Story:");
+                ui.label(tr("ttb.main_story"));
                 ui.add(egui::TextEdit::singleline(&mut dialog_state.main_hours).desired_width(50.0));
-                ui.label("h");
+                ui.label(tr("ttb.hours_unit"));
                 ui.add(egui::TextEdit::singleline(&mut dialog_state.main_minutes).desired_width(50.0));
-                ui.label("m");
+                ui.label(tr("ttb.minutes_unit"));
                 ui.end_row();
 
                 // Main + Extras
-                ui.label("Main + Extras:");
+                ui.label(tr("ttb.main_extras"));
                 ui.add(egui::TextEdit::singleline(&mut dialog_state.extra_hours).desired_width(50.0));
-                ui.label("h");
+                ui.label(tr("ttb.hours_unit"));
                 ui.add(egui::TextEdit::singleline(&mut dialog_state.extra_minutes).desired_width(50.0));
-                ui.label("m");
+                ui.label(tr("ttb.minutes_unit"));
                 ui.end_row();
 
                 // 100% Completionist
-                ui.label("100% Completionist:");
+                ui.label(tr("ttb.completionist"));
                 ui.add(egui::TextEdit::singleline(&mut dialog_state.completionist_hours).desired_width(50.0));
-                ui.label("h");
+                ui.label(tr("ttb.hours_unit"));
                 ui.add(egui::TextEdit::singleline(&mut dialog_state.completionist_minutes).desired_width(50.0));
-                ui.label("m");
+                ui.label(tr("ttb.minutes_unit"));
                 ui.end_row();
             });
 
@@ -591,10 +1148,10 @@ impl SteamOverachieverApp {
 
             // Buttons
             ui.horizontal(|ui| {
-                if ui.button("Submit").clicked() {
+                if ui.button(tr("ttb.submit")).clicked() {
                     submitted = true;
                 }
-                if ui.button("Cancel").clicked() {
+                if ui.button(tr("common.cancel")).clicked() {
                     cancelled = true;
                 }
             });
@@ -607,34 +1164,8 @@ impl SteamOverachieverApp {
             let appid = dialog_state.appid;
 
             // Save to local database
-            if let Ok(conn) = crate::db::open_connection() {
-                let (main_secs, extra_secs, comp_secs) = dialog_state.get_times();
-                let timestamp = chrono::Utc::now();
-
-                // Update the game record with TTB data
-                // Set user_ttb_report_count to 1 to indicate we have a user report (changes color from blue to gold)
-                let result = conn.execute(
-                    "UPDATE games SET 
-                        my_ttb_main_seconds = ?1,
-                        my_ttb_extra_seconds = ?2,
-                        my_ttb_completionist_seconds = ?3,
-                        my_ttb_reported_at = ?4,
-                        user_ttb_report_count = 1
-                    WHERE appid = ?5 AND steam_id = ?6",
-                    rusqlite::params![main_secs, extra_secs, comp_secs, timestamp.to_rfc3339(), appid as i64, &self.config.steam_id,],
-                );
-
-                if let Err(e) = result {
-                    eprintln!("Failed to save TTB report: {}", e);
-                    self.status = format!("Failed to save TTB report: {}", e);
-                } else {
-                    // Reload games to get updated TTB data
-                    if let Ok(games) = crate::db::get_all_games(&conn, &self.config.steam_id) {
-                        self.games = games;
-                    }
-                    self.status = "TTB report saved successfully".to_string();
-                }
-            }
+            let times = dialog_state.get_times();
+            self.save_ttb_report(appid, times);
 
             // Close dialog
             self.ttb_dialog_state = None;
@@ -678,13 +1209,13 @@ impl SteamOverachieverApp {
                 match result {
                     Ok(()) => {
                         self.cjk_font_download_progress = Some(crate::cjk_font::DownloadProgress::Complete);
-                        self.status = "CJK font downloaded successfully!".to_string();
+                        self.status = tr("status.cjk_font_downloaded").to_string();
                         // Trigger immediate font reload
                         self.fonts_need_update = true;
                     }
                     Err(e) => {
                         self.cjk_font_download_progress = Some(crate::cjk_font::DownloadProgress::Error(e.clone()));
-                        self.status = format!("Font download failed: {}", e);
+                        self.status = tr_args("status.font_download_failed", &[("error", &e)]);
                     }
                 }
             }
@@ -0,0 +1,120 @@
+//! Library-wide achievement search - names and descriptions across all games
+
+use std::sync::mpsc::TryRecvError;
+
+use eframe::egui;
+use egui_phosphor::regular;
+use overachiever_core::{instant_tooltip, tr, tr_args, StatsPanelPlatform};
+
+use crate::app::{CentralTab, SteamOverachieverApp};
+use crate::db::search_achievements;
+
+/// Maximum number of results shown
+const SEARCH_RESULT_LIMIT: usize = 200;
+/// Height of the results list above the games table
+const SEARCH_RESULTS_HEIGHT: f32 = 260.0;
+
+impl SteamOverachieverApp {
+    /// Search box shown in the central panel header, next to the tabs
+    pub(crate) fn render_achievement_search_box(&mut self, ui: &mut egui::Ui) {
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            if !self.achievement_search.is_empty() && ui.small_button(regular::X).on_hover_text(tr("achievement_search.clear")).clicked() {
+                self.achievement_search.clear();
+            }
+            ui.add(
+                egui::TextEdit::singleline(&mut self.achievement_search)
+                    .hint_text(format!("{} {}", regular::MAGNIFYING_GLASS, tr("achievement_search.hint")))
+                    .desired_width(220.0),
+            )
+            .on_hover_text(tr("achievement_search.tooltip"));
+        });
+
+        // Pick up the running query, a failed one shows no results
+        if let Some(rx) = &self.achievement_search_receiver {
+            match rx.try_recv() {
+                Ok(result) => {
+                    self.achievement_search_hits = result.unwrap_or_default();
+                    self.achievement_search_receiver = None;
+                }
+                Err(TryRecvError::Disconnected) => {
+                    self.achievement_search_hits.clear();
+                    self.achievement_search_receiver = None;
+                }
+                Err(TryRecvError::Empty) => {}
+            }
+        }
+
+        // Re-run the query on the DB worker when the text changed, one at a time so fast
+        // typing doesn't queue up a search per keystroke
+        let query = self.achievement_search.trim();
+        if query != self.achievement_search_ran && self.achievement_search_receiver.is_none() {
+            self.achievement_search_ran = query.to_string();
+            let steam_id = self.config.steam_id.clone();
+            let text = query.to_string();
+            self.achievement_search_receiver = Some(
+                self.db_worker.query(move |conn| search_achievements(conn, &steam_id, &text, SEARCH_RESULT_LIMIT)),
+            );
+        }
+    }
+
+    /// Flat list of matching achievements, clicking one jumps to it in the games table
+    pub(crate) fn render_achievement_search_results(&mut self, ui: &mut egui::Ui) {
+        if self.achievement_search_ran.is_empty() {
+            return;
+        }
+
+        let theme = self.theme_colors();
+        let mut navigate_to: Option<(u64, String)> = None;
+
+        ui.label(
+            egui::RichText::new(match self.achievement_search_hits.len() {
+                0 => tr("achievement_search.no_matches").to_string(),
+                n if n >= SEARCH_RESULT_LIMIT => tr_args("achievement_search.first_matches", &[("count", &n)]),
+                n => tr_args("achievement_search.matches", &[("count", &n)]),
+            })
+            .weak(),
+        );
+
+        egui::ScrollArea::vertical()
+            .id_salt("achievement_search_results")
+            .max_height(SEARCH_RESULTS_HEIGHT)
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                for hit in &self.achievement_search_hits {
+                    ui.horizontal(|ui| {
+                        let icon_url = if hit.achieved { &hit.icon } else { &hit.icon_gray };
+                        let icon = ui.add(
+                            egui::Image::new(self.achievement_icon_source(ui, icon_url))
+                                .fit_to_exact_size(egui::vec2(24.0, 24.0))
+                                .corner_radius(3.0),
+                        );
+                        if let Some(description) = &hit.description {
+                            instant_tooltip(&icon, description.clone());
+                        }
+
+                        let color = if hit.achieved { theme.complete } else { theme.accent };
+                        let name = ui.add(
+                            egui::Label::new(egui::RichText::new(&hit.name).color(color).strong())
+                                .sense(egui::Sense::click()),
+                        );
+                        if name.hovered() {
+                            ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+                        }
+                        if name.clicked() {
+                            navigate_to = Some((hit.appid, hit.apiname.clone()));
+                        }
+                        ui.label(egui::RichText::new(&hit.game_name).small().weak());
+                        if let Some(description) = &hit.description {
+                            ui.add(egui::Label::new(egui::RichText::new(description).small()).truncate());
+                        }
+                    });
+                }
+            });
+        ui.separator();
+
+        if let Some((appid, apiname)) = navigate_to {
+            self.central_tab = CentralTab::Library;
+            self.navigate_to_achievement(appid, apiname);
+        }
+    }
+}
@@ -0,0 +1,307 @@
+//! Admin dashboard window - server stats, users, submission and comment moderation, tag aliases
+
+use eframe::egui;
+use egui_phosphor::regular;
+
+use overachiever_core::{tr, tr_args, TagMappingKind};
+
+use crate::app::{AdminAction, AdminTab, SteamOverachieverApp};
+
+/// Format a byte count as KB/MB/GB
+fn format_bytes(bytes: i64) -> String {
+    let bytes = bytes as f64;
+    if bytes >= 1024.0 * 1024.0 * 1024.0 {
+        format!("{:.2} GB", bytes / (1024.0 * 1024.0 * 1024.0))
+    } else if bytes >= 1024.0 * 1024.0 {
+        format!("{:.1} MB", bytes / (1024.0 * 1024.0))
+    } else {
+        format!("{:.0} KB", bytes / 1024.0)
+    }
+}
+
+/// Format optional hours like the TTB columns
+fn format_hours(hours: Option<f32>) -> String {
+    hours.map(|h| format!("{:.1}h", h)).unwrap_or_else(|| "-".to_string())
+}
+
+impl SteamOverachieverApp {
+    /// Render the admin dashboard window
+    pub(crate) fn render_admin_window(&mut self, ctx: &egui::Context) {
+        if !self.show_admin_window {
+            return;
+        }
+
+        let mut open = self.show_admin_window;
+        let mut refresh = false;
+        let mut moderation: Option<AdminAction> = None;
+        let is_loading = self.admin_receiver.is_some();
+
+        egui::Window::new(format!("{} {}", regular::SHIELD_STAR, tr("admin.title")))
+            .open(&mut open)
+            .default_size([640.0, 480.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    let pending_ttb = self.admin_dashboard.as_ref().map(|d| d.pending_ttb.len()).unwrap_or(0);
+                    let pending_tags = self.admin_dashboard.as_ref().map(|d| d.pending_tags.len()).unwrap_or(0);
+                    let reported_comments = self.admin_dashboard.as_ref().map(|d| d.reported_comments.len()).unwrap_or(0);
+                    ui.selectable_value(&mut self.admin_tab, AdminTab::Overview, format!("{} {}", regular::CHART_BAR, tr("admin.tab_overview")));
+                    ui.selectable_value(&mut self.admin_tab, AdminTab::Users, format!("{} {}", regular::USERS, tr("admin.tab_users")));
+                    ui.selectable_value(&mut self.admin_tab, AdminTab::Ttb, format!("{} {}", regular::TIMER, tr_args("admin.tab_ttb", &[("count", &pending_ttb)])));
+                    ui.selectable_value(&mut self.admin_tab, AdminTab::Tags, format!("{} {}", regular::TAG, tr_args("admin.tab_tags", &[("count", &pending_tags)])));
+                    ui.selectable_value(&mut self.admin_tab, AdminTab::TagMappings, format!("{} {}", regular::TREE_STRUCTURE, tr("admin.tab_tag_aliases")));
+                    ui.selectable_value(&mut self.admin_tab, AdminTab::Comments, format!("{} {}", regular::FLAG, tr_args("admin.tab_reports", &[("count", &reported_comments)])));
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if is_loading {
+                            ui.spinner();
+                        } else if ui.small_button(regular::ARROWS_CLOCKWISE.to_string()).on_hover_text(tr("admin.reload")).clicked() {
+                            refresh = true;
+                        }
+                    });
+                });
+                ui.separator();
+
+                if let Some(error) = &self.admin_error {
+                    ui.colored_label(egui::Color32::from_rgb(255, 100, 100), format!("{} {}", regular::WARNING, error));
+                    ui.add_space(4.0);
+                }
+
+                let Some(dashboard) = &self.admin_dashboard else {
+                    if is_loading {
+                        ui.label(tr("admin.loading"));
+                    }
+                    return;
+                };
+
+                egui::ScrollArea::vertical().auto_shrink([false, false]).show(ui, |ui| match self.admin_tab {
+                    AdminTab::Overview => {
+                        let stats = &dashboard.stats;
+                        egui::Grid::new("admin_stats_grid").num_columns(2).spacing([24.0, 4.0]).show(ui, |ui| {
+                            ui.label(tr("admin.users"));
+                            ui.label(tr_args(
+                                "admin.users_detail",
+                                &[("total", &stats.total_users), ("week", &stats.active_users_7d), ("month", &stats.active_users_30d)],
+                            ));
+                            ui.end_row();
+                            ui.label(tr("admin.games_stored"));
+                            ui.label(stats.total_games.to_string());
+                            ui.end_row();
+                            ui.label(tr("admin.achievements_stored"));
+                            ui.label(stats.total_achievements.to_string());
+                            ui.end_row();
+                            ui.label(tr("admin.cloud_uploads"));
+                            ui.label(tr_args(
+                                "admin.cloud_uploads_detail",
+                                &[("total", &format_bytes(stats.total_upload_bytes)), ("largest", &format_bytes(stats.largest_upload_bytes))],
+                            ));
+                            ui.end_row();
+                            ui.label(tr("admin.database_size"));
+                            ui.label(format_bytes(stats.database_bytes));
+                            ui.end_row();
+                            ui.label(tr("admin.pending_review"));
+                            ui.label(tr_args(
+                                "admin.pending_review_detail",
+                                &[("ttb", &stats.pending_ttb), ("tags", &stats.pending_tags), ("reports", &stats.pending_ttb_reports)],
+                            ));
+                            ui.end_row();
+                        });
+                    }
+                    AdminTab::Users => {
+                        egui::Grid::new("admin_users_grid").striped(true).num_columns(5).show(ui, |ui| {
+                            ui.label(egui::RichText::new(tr("admin.user")).strong());
+                            ui.label(egui::RichText::new(tr("admin.games")).strong());
+                            ui.label(egui::RichText::new(tr("admin.achievements")).strong());
+                            ui.label(egui::RichText::new(tr("admin.last_upload")).strong());
+                            ui.label(egui::RichText::new(tr("admin.last_seen")).strong());
+                            ui.end_row();
+                            for user in &dashboard.users {
+                                ui.label(&user.display_name).on_hover_text(&user.steam_id);
+                                ui.label(user.game_count.to_string());
+                                ui.label(user.achievement_count.to_string());
+                                ui.label(user.last_upload_bytes.map(format_bytes).unwrap_or_else(|| "-".to_string()));
+                                ui.label(
+                                    user.last_seen
+                                        .map(|t| t.with_timezone(&chrono::Local).format("%Y-%m-%d").to_string())
+                                        .unwrap_or_else(|| "-".to_string()),
+                                );
+                                ui.end_row();
+                            }
+                        });
+                    }
+                    AdminTab::Ttb => {
+                        if dashboard.pending_ttb.is_empty() {
+                            ui.label(tr("admin.no_ttb_submissions"));
+                        }
+                        egui::Grid::new("admin_ttb_grid").striped(true).num_columns(6).show(ui, |ui| {
+                            for submission in &dashboard.pending_ttb {
+                                ui.label(&submission.game_name).on_hover_text(tr_args("admin.appid", &[("appid", &submission.appid)]));
+                                ui.label(format_hours(submission.main));
+                                ui.label(format_hours(submission.main_extra));
+                                ui.label(format_hours(submission.completionist));
+                                ui.label(
+                                    egui::RichText::new(submission.submitted_at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string()).weak(),
+                                )
+                                .on_hover_text(tr_args(
+                                    "admin.submitted_by",
+                                    &[
+                                        ("user", &submission.submitted_by.as_deref().unwrap_or(tr("admin.unknown_user"))),
+                                        ("count", &submission.reported_count),
+                                    ],
+                                ));
+                                ui.horizontal(|ui| {
+                                    ui.add_enabled_ui(!is_loading, |ui| {
+                                        if ui.small_button(regular::CHECK.to_string()).on_hover_text(tr("admin.approve")).clicked() {
+                                            moderation = Some(AdminAction::Submission("ttb", submission.appid, true));
+                                        }
+                                        if ui.small_button(regular::X.to_string()).on_hover_text(tr("admin.reject")).clicked() {
+                                            moderation = Some(AdminAction::Submission("ttb", submission.appid, false));
+                                        }
+                                    });
+                                });
+                                ui.end_row();
+                            }
+                        });
+                    }
+                    AdminTab::Tags => {
+                        if dashboard.pending_tags.is_empty() {
+                            ui.label(tr("admin.no_tag_submissions"));
+                        }
+                        for submission in &dashboard.pending_tags {
+                            let game_name = self
+                                .games
+                                .iter()
+                                .find(|g| g.appid == submission.appid)
+                                .map(|g| g.name.clone())
+                                .unwrap_or_else(|| tr_args("admin.appid", &[("appid", &submission.appid)]));
+                            ui.horizontal(|ui| {
+                                ui.label(egui::RichText::new(game_name).strong());
+                                ui.label(
+                                    egui::RichText::new(tr_args(
+                                        "admin.by_on",
+                                        &[
+                                            ("user", &submission.submitted_by.as_deref().unwrap_or(tr("admin.unknown_user"))),
+                                            ("date", &submission.submitted_at.with_timezone(&chrono::Local).format("%Y-%m-%d")),
+                                        ],
+                                    ))
+                                    .weak(),
+                                );
+                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                    ui.add_enabled_ui(!is_loading, |ui| {
+                                        if ui.small_button(regular::X.to_string()).on_hover_text(tr("admin.reject")).clicked() {
+                                            moderation = Some(AdminAction::Submission("tags", submission.appid, false));
+                                        }
+                                        if ui.small_button(regular::CHECK.to_string()).on_hover_text(tr("admin.approve")).clicked() {
+                                            moderation = Some(AdminAction::Submission("tags", submission.appid, true));
+                                        }
+                                    });
+                                });
+                            });
+                            let tags: Vec<String> = submission.tags.iter().map(|(name, votes)| format!("{} ({})", name, votes)).collect();
+                            ui.label(egui::RichText::new(tags.join(", ")).small());
+                            ui.separator();
+                        }
+                    }
+                    AdminTab::TagMappings => {
+                        ui.label(
+                            egui::RichText::new(tr("admin.tag_aliases_hint"))
+                            .small()
+                            .weak(),
+                        );
+                        ui.add_space(4.0);
+                        ui.horizontal(|ui| {
+                            let input = &mut self.admin_tag_mapping_input;
+                            ui.add(egui::TextEdit::singleline(&mut input.tag).hint_text(tr("admin.tag")).desired_width(140.0));
+                            egui::ComboBox::from_id_salt("admin_tag_mapping_kind")
+                                .selected_text(match input.kind {
+                                    TagMappingKind::Alias => tr("admin.is_alias_of"),
+                                    TagMappingKind::Parent => tr("admin.is_kind_of"),
+                                })
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(&mut input.kind, TagMappingKind::Alias, tr("admin.is_alias_of"));
+                                    ui.selectable_value(&mut input.kind, TagMappingKind::Parent, tr("admin.is_kind_of"));
+                                });
+                            ui.add(egui::TextEdit::singleline(&mut input.target).hint_text(tr("admin.canonical_tag")).desired_width(140.0));
+                            let valid = !input.tag.trim().is_empty() && !input.target.trim().is_empty() && input.tag.trim() != input.target.trim();
+                            if ui.add_enabled(valid && !is_loading, egui::Button::new(format!("{} {}", regular::PLUS, tr("admin.save")))).clicked() {
+                                moderation = Some(AdminAction::SetTagMapping(std::mem::take(input)));
+                            }
+                        });
+                        ui.separator();
+
+                        if dashboard.tag_mappings.is_empty() {
+                            ui.label(tr("admin.no_tag_aliases"));
+                        }
+                        egui::Grid::new("admin_tag_mappings_grid").striped(true).num_columns(4).show(ui, |ui| {
+                            for mapping in &dashboard.tag_mappings {
+                                ui.label(&mapping.tag);
+                                ui.label(
+                                    egui::RichText::new(match mapping.kind {
+                                        TagMappingKind::Alias => tr("admin.alias_of"),
+                                        TagMappingKind::Parent => tr("admin.kind_of"),
+                                    })
+                                    .weak(),
+                                );
+                                ui.label(egui::RichText::new(&mapping.target).strong());
+                                ui.add_enabled_ui(!is_loading, |ui| {
+                                    if ui.small_button(regular::TRASH.to_string()).on_hover_text(tr("common.remove")).clicked() {
+                                        moderation = Some(AdminAction::DeleteTagMapping(mapping.tag.clone()));
+                                    }
+                                });
+                                ui.end_row();
+                            }
+                        });
+                    }
+                    AdminTab::Comments => {
+                        if dashboard.reported_comments.is_empty() {
+                            ui.label(tr("admin.no_reports"));
+                        }
+                        for reported in &dashboard.reported_comments {
+                            let comment = &reported.comment;
+                            let game_name = self
+                                .games
+                                .iter()
+                                .find(|g| g.appid == comment.appid)
+                                .map(|g| g.name.clone())
+                                .unwrap_or_else(|| tr_args("admin.appid", &[("appid", &comment.appid)]));
+                            ui.horizontal(|ui| {
+                                ui.label(egui::RichText::new(format!("{} · {}", game_name, comment.apiname)).strong());
+                                ui.label(
+                                    egui::RichText::new(tr_args(
+                                        "admin.comment_by_on",
+                                        &[
+                                            ("user", &comment.display_name.as_deref().unwrap_or(&comment.steam_id)),
+                                            ("date", &comment.created_at.with_timezone(&chrono::Local).format("%Y-%m-%d")),
+                                            ("count", &reported.report_count),
+                                        ],
+                                    ))
+                                    .weak(),
+                                );
+                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                    ui.add_enabled_ui(!is_loading, |ui| {
+                                        if ui.small_button(regular::TRASH.to_string()).on_hover_text(tr("admin.remove_comment")).clicked() {
+                                            moderation = Some(AdminAction::Comment(comment.id, true));
+                                        }
+                                        if ui.small_button(regular::CHECK.to_string()).on_hover_text(tr("admin.dismiss_reports")).clicked() {
+                                            moderation = Some(AdminAction::Comment(comment.id, false));
+                                        }
+                                    });
+                                });
+                            });
+                            ui.label(&comment.comment);
+                            if !reported.reasons.is_empty() {
+                                ui.label(egui::RichText::new(tr_args("admin.reasons", &[("reasons", &reported.reasons.join("; "))])).small().weak());
+                            }
+                            ui.separator();
+                        }
+                    }
+                });
+            });
+
+        self.show_admin_window = open;
+
+        if let Some(action) = moderation {
+            self.moderate(action);
+        } else if refresh {
+            self.refresh_admin_dashboard();
+        }
+    }
+}
@@ -0,0 +1,273 @@
+//! Ctrl+K command palette - fuzzy search over games, commands and achievements,
+//! driven entirely from the keyboard
+
+use eframe::egui::{self, Key, Modifiers};
+use egui_phosphor::regular;
+use overachiever_core::{fuzzy_rank, tr, StatsPanelPlatform};
+
+use crate::app::{CentralTab, CloudAction, SteamOverachieverApp};
+use crate::db::{search_achievements, AchievementSearchHit};
+
+/// Games shown for a query
+const MAX_GAMES: usize = 8;
+/// Achievements shown for a query
+const MAX_ACHIEVEMENTS: usize = 8;
+
+/// Open palette state
+#[derive(Default)]
+pub(crate) struct CommandPalette {
+    query: String,
+    // Query the achievement hits belong to (re-searched when the text changes)
+    achievements_query: String,
+    achievements: Vec<AchievementSearchHit>,
+    // Keyboard-highlighted entry
+    selected: usize,
+    // Focus the text field on the first frame
+    focus_requested: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum PaletteCommand {
+    Update,
+    FullScan,
+    UploadToCloud,
+    OpenSettings,
+}
+
+impl PaletteCommand {
+    const ALL: [PaletteCommand; 4] = [
+        PaletteCommand::Update,
+        PaletteCommand::FullScan,
+        PaletteCommand::UploadToCloud,
+        PaletteCommand::OpenSettings,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            PaletteCommand::Update => tr("toolbar.update"),
+            PaletteCommand::FullScan => tr("toolbar.full_scan"),
+            PaletteCommand::UploadToCloud => tr("palette.upload"),
+            PaletteCommand::OpenSettings => tr("toolbar.settings"),
+        }
+    }
+
+    fn icon(&self) -> &'static str {
+        match self {
+            PaletteCommand::Update => regular::ARROWS_CLOCKWISE,
+            PaletteCommand::FullScan => regular::GAME_CONTROLLER,
+            PaletteCommand::UploadToCloud => regular::CLOUD_ARROW_UP,
+            PaletteCommand::OpenSettings => regular::GEAR,
+        }
+    }
+}
+
+/// What picking an entry does
+enum PaletteAction {
+    Game(u64),
+    Achievement(u64, String),
+    Command(PaletteCommand),
+}
+
+struct PaletteEntry {
+    icon: &'static str,
+    label: String,
+    detail: String,
+    action: PaletteAction,
+}
+
+impl SteamOverachieverApp {
+    /// Open the palette on Ctrl+K (Cmd+K on macOS), close it if already open
+    pub(crate) fn command_palette_shortcut(&mut self, ctx: &egui::Context) {
+        if ctx.input_mut(|i| i.consume_key(Modifiers::COMMAND, Key::K)) {
+            self.command_palette = match self.command_palette {
+                Some(_) => None,
+                None => Some(CommandPalette { focus_requested: true, ..Default::default() }),
+            };
+        }
+    }
+
+    fn command_available(&self, command: PaletteCommand) -> bool {
+        let ready = !self.state.is_busy() && self.config.is_valid();
+        match command {
+            PaletteCommand::Update => ready,
+            PaletteCommand::FullScan => ready && (self.games_needing_scrape() > 0 || self.force_full_scan),
+            PaletteCommand::UploadToCloud => self.config.cloud_token.is_some() && self.cloud_op_receiver.is_none(),
+            PaletteCommand::OpenSettings => true,
+        }
+    }
+
+    /// Commands, then games, then achievements matching the query (only commands without one)
+    fn palette_entries(&mut self) -> Vec<PaletteEntry> {
+        let Some(palette) = &mut self.command_palette else {
+            return Vec::new();
+        };
+        let query = palette.query.trim().to_string();
+
+        let query_changed = query != palette.achievements_query;
+        if query_changed {
+            palette.achievements_query = query.clone();
+            let steam_id = self.config.steam_id.clone();
+            let (search, searched) = (query.clone(), query.clone());
+            self.db_query(
+                move |conn| search_achievements(conn, &steam_id, &search, MAX_ACHIEVEMENTS),
+                move |app, found| {
+                    // Dropped when the palette was closed or the query changed again meanwhile
+                    let Some(palette) = app.command_palette.as_mut().filter(|p| p.achievements_query == searched) else {
+                        return;
+                    };
+                    match found {
+                        Ok(found) => palette.achievements = found,
+                        Err(e) => eprintln!("Achievement search failed: {}", e),
+                    }
+                },
+            );
+        }
+
+        let available: Vec<PaletteCommand> =
+            PaletteCommand::ALL.into_iter().filter(|c| self.command_available(*c)).collect();
+        let commands = if query.is_empty() {
+            available.iter().collect()
+        } else {
+            fuzzy_rank(&available, &query, |c| c.label())
+        };
+        let mut entries: Vec<PaletteEntry> = commands
+            .into_iter()
+            .map(|command| PaletteEntry {
+                icon: command.icon(),
+                label: command.label().to_string(),
+                detail: tr("palette.command").to_string(),
+                action: PaletteAction::Command(*command),
+            })
+            .collect();
+
+        let visible_games = self.games.iter().filter(|g| !g.hidden);
+        entries.extend(fuzzy_rank(visible_games, &query, |g| g.name.as_str()).into_iter().take(MAX_GAMES).map(|game| {
+            PaletteEntry {
+                icon: regular::GAME_CONTROLLER,
+                label: game.name.clone(),
+                detail: game.achievements_display(),
+                action: PaletteAction::Game(game.appid),
+            }
+        }));
+
+        let Some(palette) = &self.command_palette else {
+            return entries;
+        };
+        entries.extend(palette.achievements.iter().map(|hit| PaletteEntry {
+            icon: if hit.achieved { regular::TROPHY } else { regular::LOCK_SIMPLE },
+            label: hit.name.clone(),
+            detail: hit.game_name.clone(),
+            action: PaletteAction::Achievement(hit.appid, hit.apiname.clone()),
+        }));
+        entries
+    }
+
+    fn run_palette_action(&mut self, action: PaletteAction) {
+        match action {
+            PaletteAction::Game(appid) => {
+                self.central_tab = CentralTab::Library;
+                // Empty apiname: scroll to the row without highlighting an achievement
+                self.navigate_to_achievement(appid, String::new());
+            }
+            PaletteAction::Achievement(appid, apiname) => {
+                self.central_tab = CentralTab::Library;
+                self.navigate_to_achievement(appid, apiname);
+            }
+            PaletteAction::Command(PaletteCommand::Update) => self.start_update(),
+            PaletteAction::Command(PaletteCommand::FullScan) => self.start_scrape(),
+            PaletteAction::Command(PaletteCommand::UploadToCloud) => {
+                self.pending_cloud_action = Some(CloudAction::Upload);
+            }
+            PaletteAction::Command(PaletteCommand::OpenSettings) => self.show_settings = true,
+        }
+    }
+
+    /// Render the command palette if open
+    pub(crate) fn render_command_palette(&mut self, ctx: &egui::Context) {
+        if self.command_palette.is_none() {
+            return;
+        }
+        let entries = self.palette_entries();
+        let Some(palette) = &mut self.command_palette else {
+            return;
+        };
+
+        // Keyboard navigation - consumed before the text field sees the keys
+        let (down, up, enter, escape) = ctx.input_mut(|i| (
+            i.consume_key(Modifiers::NONE, Key::ArrowDown),
+            i.consume_key(Modifiers::NONE, Key::ArrowUp),
+            i.consume_key(Modifiers::NONE, Key::Enter),
+            i.consume_key(Modifiers::NONE, Key::Escape),
+        ));
+        if down {
+            palette.selected = (palette.selected + 1).min(entries.len().saturating_sub(1));
+        }
+        if up {
+            palette.selected = palette.selected.saturating_sub(1);
+        }
+        palette.selected = palette.selected.min(entries.len().saturating_sub(1));
+
+        let mut picked = enter.then_some(palette.selected).filter(|i| *i < entries.len());
+        let theme = self.theme_colors();
+        let Some(palette) = &mut self.command_palette else {
+            return;
+        };
+
+        egui::Window::new("command_palette")
+            .title_bar(false)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 80.0])
+            .fixed_size([480.0, 0.0])
+            .show(ctx, |ui| {
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut palette.query)
+                        .hint_text(format!("{} {}", regular::MAGNIFYING_GLASS, tr("palette.hint")))
+                        .desired_width(f32::INFINITY),
+                );
+                if palette.focus_requested {
+                    response.request_focus();
+                    palette.focus_requested = false;
+                }
+                if response.changed() {
+                    palette.selected = 0;
+                }
+                ui.separator();
+
+                if entries.is_empty() {
+                    ui.label(egui::RichText::new(tr("palette.no_results")).weak());
+                    return;
+                }
+                egui::ScrollArea::vertical().max_height(360.0).show(ui, |ui| {
+                    for (i, entry) in entries.iter().enumerate() {
+                        let is_selected = i == palette.selected;
+                        let row = ui.horizontal(|ui| {
+                            let label = egui::RichText::new(format!("{} {}", entry.icon, entry.label));
+                            let label = if is_selected { label.color(theme.accent).strong() } else { label };
+                            let response = ui.selectable_label(is_selected, label);
+                            ui.label(egui::RichText::new(&entry.detail).small().weak());
+                            response
+                        });
+                        if is_selected && (up || down) {
+                            row.response.scroll_to_me(None);
+                        }
+                        if row.inner.clicked() {
+                            picked = Some(i);
+                        }
+                    }
+                });
+            });
+
+        let close_clicked = ctx.input(|i| i.pointer.any_click())
+            && !ctx.is_pointer_over_area()
+            && picked.is_none();
+        if let Some(i) = picked {
+            self.command_palette = None;
+            if let Some(entry) = entries.into_iter().nth(i) {
+                self.run_palette_action(entry.action);
+            }
+        } else if escape || close_clicked {
+            self.command_palette = None;
+        }
+    }
+}
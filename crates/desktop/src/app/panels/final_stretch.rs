@@ -0,0 +1,182 @@
+//! "Final stretch" window - remaining achievements of nearly completed games
+
+use eframe::egui;
+use egui_phosphor::regular;
+use overachiever_core::{instant_tooltip, tr, tr_args, StatsPanelPlatform};
+
+use crate::app::SteamOverachieverApp;
+use crate::db::{get_remaining_achievements, RemainingAchievement};
+
+/// Estimate effort (0-100, higher = harder) from global rarity and the user's own difficulty rating
+fn estimate_effort(ach: &RemainingAchievement, user_rating: Option<u8>) -> f32 {
+    let rarity_effort = ach.global_percent.map(|p| 100.0 - p.clamp(0.0, 100.0));
+    let rating_effort = user_rating.map(|r| (r.clamp(1, 5) - 1) as f32 * 25.0);
+    match (rarity_effort, rating_effort) {
+        (Some(rarity), Some(rating)) => (rarity + rating) / 2.0,
+        (Some(rarity), None) => rarity,
+        (None, Some(rating)) => rating,
+        (None, None) => 50.0, // Unknown: sort into the middle
+    }
+}
+
+/// Short label for an effort score
+fn effort_label(effort: f32) -> &'static str {
+    if effort < 50.0 {
+        tr("difficulty.easy")
+    } else if effort < 80.0 {
+        tr("difficulty.moderate")
+    } else if effort < 95.0 {
+        tr("difficulty.hard")
+    } else {
+        tr("final_stretch.very_hard")
+    }
+}
+
+impl SteamOverachieverApp {
+    /// Reload remaining achievements for games above the final stretch threshold (on the DB worker)
+    pub(crate) fn load_final_stretch(&mut self) {
+        let steam_id = self.config.steam_id.clone();
+        let min_percent = self.final_stretch_min_percent;
+        self.db_query(
+            move |conn| get_remaining_achievements(conn, &steam_id, min_percent),
+            |app, remaining| match remaining {
+                Ok(remaining) => app.apply_final_stretch(remaining),
+                Err(e) => eprintln!("Failed to load remaining achievements: {}", e),
+            },
+        );
+    }
+
+    fn apply_final_stretch(&mut self, mut remaining: Vec<RemainingAchievement>) {
+
+        // Respect hidden/private games like the games table does
+        let hide_private = self.config.hide_private_games;
+        remaining.retain(|ach| {
+            self.games
+                .iter()
+                .find(|g| g.appid == ach.appid)
+                .map(|g| !g.hidden && (!hide_private || !g.steam_private))
+                .unwrap_or(false)
+        });
+
+        // Easiest first
+        let ratings = &self.user_achievement_ratings;
+        remaining.sort_by(|a, b| {
+            let effort_a = estimate_effort(a, ratings.get(&(a.appid, a.apiname.clone())).copied());
+            let effort_b = estimate_effort(b, ratings.get(&(b.appid, b.apiname.clone())).copied());
+            effort_a.partial_cmp(&effort_b).unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        self.final_stretch = remaining;
+    }
+
+    /// Render the final stretch window
+    pub(crate) fn render_final_stretch_window(&mut self, ctx: &egui::Context) {
+        if !self.show_final_stretch {
+            return;
+        }
+
+        let mut open = self.show_final_stretch;
+        let mut reload = false;
+        let mut navigate_to: Option<(u64, String)> = None;
+        let theme = self.theme_colors();
+
+        egui::Window::new(format!("{} {}", regular::FLAG_CHECKERED, tr("toolbar.final_stretch")))
+            .open(&mut open)
+            .default_size([520.0, 480.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(tr("final_stretch.games_at_least"));
+                    let slider = ui.add(egui::Slider::new(&mut self.final_stretch_min_percent, 1.0..=99.0).step_by(1.0).suffix("%"));
+                    ui.label(tr("final_stretch.complete"));
+                    if slider.changed() {
+                        reload = true;
+                    }
+                    // Back to the near-complete band from settings
+                    if self.final_stretch_min_percent != theme.near_complete_threshold
+                        && ui.small_button(regular::ARROW_COUNTER_CLOCKWISE).on_hover_text(tr("final_stretch.reset_threshold")).clicked()
+                    {
+                        self.final_stretch_min_percent = theme.near_complete_threshold;
+                        reload = true;
+                    }
+                });
+
+                let game_count = {
+                    let mut appids: Vec<u64> = self.final_stretch.iter().map(|a| a.appid).collect();
+                    appids.sort_unstable();
+                    appids.dedup();
+                    appids.len()
+                };
+                ui.label(
+                    egui::RichText::new(tr_args("final_stretch.summary", &[("count", &self.final_stretch.len()), ("games", &game_count)]))
+                        .weak(),
+                );
+                ui.separator();
+
+                if self.final_stretch.is_empty() {
+                    ui.label(tr("final_stretch.empty"));
+                    return;
+                }
+
+                egui::ScrollArea::vertical().auto_shrink([false, false]).show(ui, |ui| {
+                    for ach in &self.final_stretch {
+                        let user_rating = self.user_achievement_ratings.get(&(ach.appid, ach.apiname.clone())).copied();
+                        let effort = estimate_effort(ach, user_rating);
+
+                        ui.horizontal(|ui| {
+                            let icon = ui.add(
+                                egui::Image::new(self.achievement_icon_source(ui, &ach.icon_gray))
+                                    .fit_to_exact_size(egui::vec2(32.0, 32.0))
+                                    .corner_radius(4.0),
+                            );
+                            if let Some(description) = &ach.description {
+                                instant_tooltip(&icon, description.clone());
+                            }
+
+                            ui.vertical(|ui| {
+                                let name = ui.add(
+                                    egui::Label::new(egui::RichText::new(&ach.name).color(theme.accent).strong())
+                                        .sense(egui::Sense::click()),
+                                );
+                                if name.hovered() {
+                                    ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+                                }
+                                if name.clicked() {
+                                    navigate_to = Some((ach.appid, ach.apiname.clone()));
+                                }
+                                ui.horizontal(|ui| {
+                                    ui.label(
+                                        egui::RichText::new(format!("{} ({} / {})", ach.game_name, ach.game_unlocked, ach.game_total))
+                                            .small()
+                                            .weak(),
+                                    );
+                                    let game_percent = ach.game_unlocked as f32 / ach.game_total.max(1) as f32 * 100.0;
+                                    ui.label(
+                                        egui::RichText::new(format!("{:.0}%", game_percent))
+                                            .small()
+                                            .color(theme.completion_color(game_percent)),
+                                    );
+                                });
+                            });
+
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                ui.label(effort_label(effort));
+                                if let Some(percent) = ach.global_percent {
+                                    ui.label(egui::RichText::new(tr_args("final_stretch.players", &[("percent", &format!("{:.1}", percent))])).color(theme.secondary));
+                                }
+                            });
+                        });
+                        ui.separator();
+                    }
+                });
+            });
+
+        self.show_final_stretch = open;
+
+        if let Some((appid, apiname)) = navigate_to {
+            self.navigate_to_achievement(appid, apiname);
+        }
+        if reload {
+            self.load_final_stretch();
+        }
+    }
+}
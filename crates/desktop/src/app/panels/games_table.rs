@@ -3,10 +3,13 @@
 
 use eframe::egui;
 
-use crate::app::SteamOverachieverApp;
-use crate::db::{open_connection, get_game_achievements, get_all_games};
-use crate::ui::{SortColumn, SortOrder, TriFilter};
-use overachiever_core::{Game, GamesTablePlatform, GameAchievement, sort_games, get_filtered_indices, render_filter_bar, render_games_table};
+use crate::app::{CentralTab, SteamOverachieverApp};
+use crate::db::set_game_hidden;
+use crate::ui::{AchievementSort, RangeFilter, SortColumn, SortOrder, TriFilter};
+use overachiever_core::{AchievementDifficulty, AchievementRarityHistory, CloudSaveStatus, Game, GamesTablePlatform, GameAchievement, GroupBy, TagMatchMode, compare_tie_break, sort_games, get_filtered_indices, render_filter_bar, render_games_table, render_unowned_section, tr, tr_args};
+
+/// How long a read of Steam's cloud save state stays valid
+const CLOUD_SAVE_RECHECK: std::time::Duration = std::time::Duration::from_secs(60);
 
 /// Implement GamesTablePlatform for the desktop app
 impl GamesTablePlatform for SteamOverachieverApp {
@@ -25,6 +28,7 @@ impl GamesTablePlatform for SteamOverachieverApp {
             self.sort_column = column;
             self.sort_order = SortOrder::Ascending;
         }
+        let tie_breaker = self.config.sort_tie_breaker;
         // TTB sorting needs access to cache, handle it specially
         if column == SortColumn::TimeToBeat {
             let order = self.sort_order;
@@ -33,7 +37,8 @@ impl GamesTablePlatform for SteamOverachieverApp {
                 let a_ttb = cache.get(&a.appid).and_then(|t| t.main).unwrap_or(-1.0);
                 let b_ttb = cache.get(&b.appid).and_then(|t| t.main).unwrap_or(-1.0);
                 let cmp = a_ttb.partial_cmp(&b_ttb).unwrap_or(std::cmp::Ordering::Equal);
-                if order == SortOrder::Descending { cmp.reverse() } else { cmp }
+                let cmp = if order == SortOrder::Descending { cmp.reverse() } else { cmp };
+                cmp.then_with(|| compare_tie_break(a, b, column, tie_breaker))
             });
         } else if column == SortColumn::Votes {
             // Votes sorting needs access to tags cache and current filter tags
@@ -61,10 +66,11 @@ impl GamesTablePlatform for SteamOverachieverApp {
                     None => -1,
                 };
                 let cmp = a_votes.cmp(&b_votes);
-                if order == SortOrder::Descending { cmp.reverse() } else { cmp }
+                let cmp = if order == SortOrder::Descending { cmp.reverse() } else { cmp };
+                cmp.then_with(|| compare_tie_break(a, b, column, tie_breaker))
             });
         } else {
-            sort_games(&mut self.games, self.sort_column, self.sort_order);
+            sort_games(&mut self.games, self.sort_column, self.sort_order, tie_breaker);
         }
     }
     
@@ -92,6 +98,14 @@ impl GamesTablePlatform for SteamOverachieverApp {
         self.filter_playtime = filter;
     }
     
+    fn filter_playtime_range(&self) -> RangeFilter {
+        self.filter_playtime_range
+    }
+    
+    fn set_filter_playtime_range(&mut self, range: RangeFilter) {
+        self.filter_playtime_range = range;
+    }
+    
     fn is_expanded(&self, appid: u64) -> bool {
         self.expanded_rows.contains(&appid)
     }
@@ -109,14 +123,35 @@ impl GamesTablePlatform for SteamOverachieverApp {
     }
     
     fn request_achievements(&mut self, appid: u64) {
-        // Desktop loads achievements synchronously from local SQLite
-        if !self.achievements_cache.contains_key(&appid) {
-            if let Ok(conn) = open_connection() {
-                if let Ok(achs) = get_game_achievements(&conn, &self.config.steam_id, appid) {
-                    self.achievements_cache.insert(appid, achs);
-                }
-            }
-        }
+        // Loaded on the DB worker, the row shows a spinner until the result arrives
+        self.load_achievements(appid);
+    }
+    
+    fn achievement_sort(&self) -> AchievementSort {
+        self.config.achievement_sort
+    }
+    
+    fn set_achievement_sort(&mut self, sort: AchievementSort) {
+        self.config.achievement_sort = sort;
+        let _ = self.config.save();
+    }
+    
+    fn group_by(&self) -> GroupBy {
+        self.config.group_by
+    }
+    
+    fn set_group_by(&mut self, group_by: GroupBy) {
+        self.config.group_by = group_by;
+        let _ = self.config.save();
+    }
+    
+    fn reveal_spoilers(&self) -> bool {
+        self.config.reveal_spoilers
+    }
+    
+    fn set_reveal_spoilers(&mut self, reveal: bool) {
+        self.config.reveal_spoilers = reveal;
+        let _ = self.config.save();
     }
     
     fn get_flash_intensity(&self, appid: u64) -> Option<f32> {
@@ -147,12 +182,24 @@ impl GamesTablePlatform for SteamOverachieverApp {
     }
     
     fn request_single_game_refresh(&mut self, appid: u64) -> bool {
-        self.start_single_game_refresh(appid)
+        self.queue_single_game_refresh(appid)
     }
     
     fn is_single_game_refreshing(&self, appid: u64) -> bool {
         self.single_game_refreshing == Some(appid)
     }
+
+    fn is_single_game_queued(&self, appid: u64) -> bool {
+        self.single_game_refresh_queue.contains(&appid)
+    }
+    
+    fn is_refresh_on_cooldown(&self, appid: u64) -> bool {
+        SteamOverachieverApp::is_refresh_on_cooldown(self, appid)
+    }
+    
+    fn stale_scrape_days(&self) -> Option<u32> {
+        Some(self.config.stale_scrape_days)
+    }
     
     fn can_launch_game(&self) -> bool {
         true
@@ -165,6 +212,7 @@ impl GamesTablePlatform for SteamOverachieverApp {
         } else {
             // Record launch time for cooldown
             self.game_launch_times.insert(appid, std::time::Instant::now());
+            self.watch_launched_game(appid);
         }
     }
     
@@ -180,6 +228,49 @@ impl GamesTablePlatform for SteamOverachieverApp {
         self.installed_games.contains(&appid)
     }
     
+    fn cloud_save_status(&self, appid: u64) -> Option<&CloudSaveStatus> {
+        self.cloud_save_cache.get(&appid).and_then(|(_, status)| status.as_ref())
+    }
+    
+    fn request_cloud_save_status(&mut self, appid: u64) {
+        // Steam syncs while we're running, so re-read the (small) file now and then
+        let fresh = self.cloud_save_cache
+            .get(&appid)
+            .is_some_and(|(read_at, _)| read_at.elapsed() < CLOUD_SAVE_RECHECK);
+        if !fresh {
+            let status = crate::steam_cloud::read_cloud_save_status(&self.config.steam_id, appid);
+            self.cloud_save_cache.insert(appid, (std::time::Instant::now(), status));
+        }
+    }
+    
+    fn can_show_sessions(&self) -> bool {
+        true
+    }
+    
+    fn show_sessions(&mut self, appid: u64, game_name: &str) {
+        self.open_sessions_window(appid, game_name.to_string());
+    }
+    
+    fn can_plan_completion(&self) -> bool {
+        true
+    }
+    
+    fn open_completion_plan(&mut self, appid: u64, game_name: &str) {
+        SteamOverachieverApp::open_completion_plan(self, appid, game_name.to_string());
+    }
+    
+    fn can_show_unlock_timeline(&self) -> bool {
+        true
+    }
+    
+    fn open_unlock_timeline(&mut self, appid: u64, game_name: &str) {
+        SteamOverachieverApp::open_unlock_timeline(self, appid, game_name.to_string());
+    }
+    
+    fn is_currently_playing(&self, appid: u64) -> bool {
+        self.currently_playing == Some(appid)
+    }
+    
     fn install_game(&self, appid: u64) {
         let url = format!("steam://install/{}", appid);
         if let Err(e) = open::that(&url) {
@@ -187,6 +278,18 @@ impl GamesTablePlatform for SteamOverachieverApp {
         }
     }
     
+    fn has_app_types(&self) -> bool {
+        true
+    }
+    
+    fn filter_app_type(&self) -> TriFilter {
+        self.filter_app_type
+    }
+    
+    fn set_filter_app_type(&mut self, filter: TriFilter) {
+        self.filter_app_type = filter;
+    }
+    
     fn filter_installed(&self) -> TriFilter {
         self.filter_installed
     }
@@ -204,6 +307,10 @@ impl GamesTablePlatform for SteamOverachieverApp {
         true
     }
 
+    fn show_deck_playtime_column(&self) -> bool {
+        self.config.show_deck_playtime_column
+    }
+
     fn can_fetch_ttb(&self) -> bool {
         // Only allow fetching in admin mode
         self.admin_mode
@@ -232,6 +339,14 @@ impl GamesTablePlatform for SteamOverachieverApp {
         self.filter_ttb = filter;
     }
 
+    fn filter_ttb_range(&self) -> RangeFilter {
+        self.filter_ttb_range
+    }
+
+    fn set_filter_ttb_range(&mut self, range: RangeFilter) {
+        self.filter_ttb_range = range;
+    }
+
     fn is_ttb_blacklisted(&self, appid: u64) -> bool {
         self.ttb_blacklist.contains(&appid)
     }
@@ -274,6 +389,22 @@ impl GamesTablePlatform for SteamOverachieverApp {
         self.filter_tags = tags;
     }
 
+    fn filter_excluded_tags(&self) -> &[String] {
+        &self.filter_excluded_tags
+    }
+
+    fn set_filter_excluded_tags(&mut self, tags: Vec<String>) {
+        self.filter_excluded_tags = tags;
+    }
+
+    fn tag_match_mode(&self) -> TagMatchMode {
+        self.tag_match_mode
+    }
+
+    fn set_tag_match_mode(&mut self, mode: TagMatchMode) {
+        self.tag_match_mode = mode;
+    }
+
     fn tag_search_input(&self) -> &str {
         &self.tag_search_input
     }
@@ -282,6 +413,14 @@ impl GamesTablePlatform for SteamOverachieverApp {
         self.tag_search_input = input;
     }
 
+    fn tag_search_selected_index(&self) -> Option<usize> {
+        self.tag_search_selected_index
+    }
+
+    fn set_tag_search_selected_index(&mut self, index: Option<usize>) {
+        self.tag_search_selected_index = index;
+    }
+
     fn available_tags(&self) -> &[String] {
         &self.available_tags
     }
@@ -311,6 +450,46 @@ impl GamesTablePlatform for SteamOverachieverApp {
         self.tags_fetching == Some(appid)
     }
 
+    // ============================================================================
+    // Community Difficulty Curve
+    // ============================================================================
+
+    fn get_difficulty_curve(&self, appid: u64) -> Option<&[AchievementDifficulty]> {
+        self.difficulty_curves.get(&appid).map(|c| c.as_slice())
+    }
+
+    fn request_difficulty_curve(&mut self, appid: u64) {
+        self.start_difficulty_fetch(appid);
+    }
+
+    fn community_game_stats(&self, appid: u64) -> Option<&overachiever_core::CommunityGameStats> {
+        self.community_game_stats.get(&appid).and_then(Option::as_ref)
+    }
+
+    fn request_community_game_stats(&mut self, appid: u64) {
+        self.start_community_stats_fetch(appid);
+    }
+
+    fn get_rarity_history(&self, appid: u64) -> Option<&[AchievementRarityHistory]> {
+        self.rarity_history.get(&appid).map(|h| h.as_slice())
+    }
+
+    fn request_rarity_history(&mut self, appid: u64) {
+        self.start_rarity_fetch(appid);
+    }
+
+    fn get_achievement_comments(&self, appid: u64) -> Option<&[overachiever_core::AchievementComment]> {
+        self.achievement_comments.get(&appid).map(|c| c.as_slice())
+    }
+
+    fn report_comment(&mut self, comment_id: i64) {
+        self.report_achievement_comment(comment_id);
+    }
+
+    fn is_comment_reported(&self, comment_id: i64) -> bool {
+        self.reported_comments.contains(&comment_id)
+    }
+
     // ============================================================================
     // Hidden Games Methods
     // ============================================================================
@@ -327,51 +506,66 @@ impl GamesTablePlatform for SteamOverachieverApp {
         self.filter_hidden = filter;
     }
 
+    fn filter_private(&self) -> TriFilter {
+        self.filter_private
+    }
+
+    fn set_filter_private(&mut self, filter: TriFilter) {
+        self.filter_private = filter;
+    }
+
     fn toggle_game_hidden(&mut self, appid: u64) {
         // Toggle the manual hidden status
         if let Some(game) = self.games.iter_mut().find(|g| g.appid == appid) {
             game.hidden = !game.hidden;
             
             // Update in database
-            let steam_id = &self.config.steam_id;
-            if let Ok(conn) = open_connection() {
-                if let Err(e) = conn.execute(
-                    "UPDATE games SET hidden = ?1 WHERE steam_id = ?2 AND appid = ?3",
-                    (if game.hidden { 1 } else { 0 }, steam_id, appid as i64),
-                ) {
-                    eprintln!("Failed to update hidden status: {}", e);
-                }
-            }
+            let steam_id = self.config.steam_id.clone();
+            let hidden = game.hidden;
+            self.db_worker.execute("update hidden status", move |conn| set_game_hidden(conn, &steam_id, appid, hidden));
         }
     }
 
-    fn sync_steam_hidden(&mut self) {
-        // Import hidden games from Steam's sharedconfig.vdf
-        let steam_id = &self.config.steam_id;
-        if let Ok(conn) = open_connection() {
-            match crate::steam_config::sync_steam_hidden_games(&conn, steam_id) {
-                Ok(count) => {
-                    self.status = format!("Synced {} hidden games from Steam", count);
-                    // Reload games from database to get updated steam_hidden flags
-                    if let Ok(games) = get_all_games(&conn, steam_id) {
-                        self.games = games;
-                        self.sort_games();
-                    }
-                }
-                Err(e) => {
-                    self.status = format!("Failed to sync Steam hidden games: {}", e);
-                    eprintln!("Failed to sync Steam hidden games: {}", e);
-                }
-            }
+    fn rival_progress(&self, appid: u64) -> Option<(i32, i32)> {
+        SteamOverachieverApp::rival_progress(self, appid)
+    }
+
+    fn can_abandon_games(&self) -> bool {
+        true
+    }
+
+    fn toggle_game_abandoned(&mut self, appid: u64) {
+        if let Some(abandoned) = self.games.iter().find(|g| g.appid == appid).map(|g| !g.abandoned) {
+            self.set_game_abandoned(appid, abandoned);
         }
     }
+
+    fn sync_steam_hidden(&mut self) {
+        self.sync_steam_visibility();
+    }
 }
 
 impl SteamOverachieverApp {
     pub(crate) fn render_games_table_panel(&mut self, ctx: &egui::Context) {
         egui::CentralPanel::default().show(ctx, |ui| {
-            ui.heading(format!("Games Library ({} games)", self.games.len()));
+            let owned_count = self.games.iter().filter(|g| g.owned).count();
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut self.central_tab, CentralTab::Library, egui::RichText::new(tr_args("library.title", &[("count", &owned_count)])).heading());
+                ui.selectable_value(&mut self.central_tab, CentralTab::Wishlist, egui::RichText::new(tr_args("library.wishlist_tab", &[("count", &self.wishlist.len())])).heading());
+                ui.selectable_value(&mut self.central_tab, CentralTab::Stats, egui::RichText::new(tr("dashboard.tab")).heading());
+                self.render_achievement_search_box(ui);
+            });
             ui.separator();
+            self.render_achievement_search_results(ui);
+
+            if self.central_tab == CentralTab::Wishlist {
+                self.render_wishlist_tab(ui);
+                return;
+            }
+            if self.central_tab == CentralTab::Stats {
+                self.render_stats_dashboard_tab(ui);
+                return;
+            }
 
             // Show alert if there are games that haven't been scraped yet (full scan incomplete)
             if !self.games.is_empty() && self.games_needing_scrape() > 0 {
@@ -387,12 +581,12 @@ impl SteamOverachieverApp {
                                     .size(16.0),
                             );
                             ui.label(
-                                egui::RichText::new("A full scan needs to be completed before stats tracking can begin.")
+                                egui::RichText::new(tr("library.full_scan_needed"))
                                     .color(egui::Color32::from_rgb(220, 220, 220))
                                     .strong(),
                             );
                             ui.label(
-                                egui::RichText::new("You can close the app at any time and continue the sync later \u{2014} progress will be saved.")
+                                egui::RichText::new(tr("library.full_scan_resumable"))
                                     .color(egui::Color32::from_rgb(180, 180, 180)),
                             );
                         });
@@ -401,7 +595,7 @@ impl SteamOverachieverApp {
             }
 
             if self.games.is_empty() {
-                ui.label("No games loaded. Click 'Update' to load your Steam library.");
+                ui.label(tr("library.no_games"));
                 return;
             }
 
@@ -411,9 +605,10 @@ impl SteamOverachieverApp {
             let filtered_indices = get_filtered_indices(self);
             let filtered_count = filtered_indices.len();
             
-            if filtered_count != self.games.len() {
-                ui.label(format!("Showing {} of {} games", filtered_count, self.games.len()));
+            if filtered_count != owned_count {
+                ui.label(tr_args("library.showing", &[("shown", &filtered_count), ("total", &owned_count)]));
             }
+            render_unowned_section(ui, &self.games);
             
             let needs_fetch = render_games_table(ui, self, filtered_indices);
             
@@ -2,7 +2,7 @@
 
 use eframe::egui;
 use egui_phosphor::regular;
-use overachiever_core::{render_stats_content, render_log_content, StatsPanelConfig, SidebarPanel};
+use overachiever_core::{render_stats_content, render_log_content, tr, tr_args, StatsPanelConfig, SidebarPanel};
 
 use crate::app::SteamOverachieverApp;
 
@@ -28,7 +28,7 @@ impl SteamOverachieverApp {
                     ui.add_space(4.0);
                     // Stats button
                     if ui.button(regular::CHART_LINE.to_string())
-                        .on_hover_text("Open Stats Panel")
+                        .on_hover_text(tr("history.open_stats"))
                         .clicked() 
                     {
                         self.sidebar_panel = SidebarPanel::Stats;
@@ -36,7 +36,7 @@ impl SteamOverachieverApp {
                     }
                     // Log button
                     if ui.button(regular::SCROLL.to_string())
-                        .on_hover_text("Open Log Panel")
+                        .on_hover_text(tr("history.open_log"))
                         .clicked()
                     {
                         self.sidebar_panel = SidebarPanel::Log;
@@ -54,7 +54,7 @@ impl SteamOverachieverApp {
                 ui.horizontal(|ui| {
                     // Close button (chevron right to collapse)
                     if ui.small_button(regular::CARET_RIGHT.to_string())
-                        .on_hover_text("Close Panel")
+                        .on_hover_text(tr("history.close"))
                         .clicked() 
                     {
                         self.show_stats_panel = false;
@@ -66,15 +66,20 @@ impl SteamOverachieverApp {
                     let stats_selected = self.sidebar_panel == SidebarPanel::Stats;
                     let log_selected = self.sidebar_panel == SidebarPanel::Log;
                     
-                    if ui.selectable_label(stats_selected, format!("{} Stats", regular::CHART_LINE)).clicked() {
+                    if ui.selectable_label(stats_selected, format!("{} {}", regular::CHART_LINE, tr("history.stats"))).clicked() {
                         self.sidebar_panel = SidebarPanel::Stats;
                     }
-                    if ui.selectable_label(log_selected, format!("{} Log", regular::SCROLL)).clicked() {
+                    if ui.selectable_label(log_selected, format!("{} {}", regular::SCROLL, tr("history.log"))).clicked() {
                         self.sidebar_panel = SidebarPanel::Log;
                     }
                 });
                 ui.separator();
 
+                if !self.presence_users.is_empty() {
+                    self.render_presence_list(ui);
+                    ui.separator();
+                }
+
                 egui::ScrollArea::vertical().show(ui, |ui| {
                     match self.sidebar_panel {
                         SidebarPanel::Stats => {
@@ -88,4 +93,19 @@ impl SteamOverachieverApp {
                 });
             });
     }
+
+    /// Other users sharing what they're playing ("Alice is playing Hades - 43/49 achievements")
+    fn render_presence_list(&self, ui: &mut egui::Ui) {
+        ui.label(egui::RichText::new(format!("{} {}", regular::GAME_CONTROLLER, tr("presence.title"))).strong());
+        for presence in &self.presence_users {
+            let mut line = tr_args("presence.playing", &[("name", &presence.display_name), ("game", &presence.game.name)]);
+            if let (Some(unlocked), Some(total)) = (presence.game.achievements_unlocked, presence.game.achievements_total) {
+                if total > 0 {
+                    line.push_str(" — ");
+                    line.push_str(&tr_args("presence.achievements", &[("unlocked", &unlocked), ("total", &total)]));
+                }
+            }
+            ui.label(egui::RichText::new(line).small());
+        }
+    }
 }
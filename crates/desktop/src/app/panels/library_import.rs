@@ -0,0 +1,193 @@
+//! `.overach` library files - export, and the preview shown before an import is merged
+
+use eframe::egui;
+use egui_phosphor::regular;
+use overachiever_core::{tr, tr_args};
+
+use crate::app::{LibraryImportPreview, SteamOverachieverApp};
+use crate::db::{diff_cloud_sync_data, merge_cloud_sync_data};
+use crate::library_file;
+
+impl SteamOverachieverApp {
+    /// Write the local library to a `.overach` file in the export folder and reveal it
+    pub(crate) fn export_library_file(&mut self) {
+        self.gather_sync_data(|app, data| {
+            let data = match data {
+                Ok(data) => data,
+                Err(e) => {
+                    app.status = e;
+                    return;
+                }
+            };
+            let dir = super::year_review::export_dir();
+            let dest = dir.join(format!(
+                "overachiever_library_{}.{}",
+                chrono::Local::now().format("%Y-%m-%d"),
+                library_file::EXTENSION
+            ));
+            match library_file::write(&dest, data) {
+                Ok(()) => {
+                    app.status = tr_args("library_file.exported", &[("path", &dest.display())]);
+                    let _ = open::that(&dir);
+                }
+                Err(e) => app.status = e,
+            }
+        });
+    }
+
+    /// Read a library file and open the import preview
+    pub(crate) fn open_library_file(&mut self, path: &std::path::Path) {
+        let file = match library_file::read(path) {
+            Ok(file) => file,
+            Err(e) => {
+                self.status = e;
+                return;
+            }
+        };
+        let path = path.to_path_buf();
+        self.db_query(
+            move |conn| diff_cloud_sync_data(conn, &file.data).map(|diff| LibraryImportPreview { path, file, diff }),
+            |app, preview| match preview {
+                Ok(preview) => app.library_import = Some(preview),
+                Err(e) => app.status = tr_args("cloud.compare_failed", &[("error", &e)]),
+            },
+        );
+    }
+
+    /// Merge the previewed library file into the local database and reload
+    fn apply_library_import(&mut self) {
+        let Some(preview) = self.library_import.take() else {
+            return;
+        };
+        let data = preview.file.data;
+
+        // A fresh install adopts the file's account
+        if self.config.steam_id.is_empty() {
+            self.config.steam_id = data.steam_id.clone();
+            let _ = self.config.save();
+        }
+
+        let merged = tr_args(
+            "library_file.merged",
+            &[
+                ("games", &data.games.len()),
+                ("achievements", &data.achievements.len()),
+                ("path", &preview.path.display()),
+            ],
+        );
+        self.db_query(
+            move |conn| {
+                crate::backup::backup_or_log("library-import");
+                merge_cloud_sync_data(conn, &data)
+            },
+            |app, result| match result {
+                Ok(()) => {
+                    app.reload_from_database();
+                    app.status = merged;
+                }
+                Err(e) => app.status = tr_args("library_file.import_failed", &[("error", &e)]),
+            },
+        );
+    }
+
+    /// Render the import preview for an opened library file
+    pub(crate) fn render_library_import_window(&mut self, ctx: &egui::Context) {
+        let Some(preview) = &self.library_import else {
+            return;
+        };
+        let file = &preview.file;
+        let diff = &preview.diff;
+        let other_account = !self.config.steam_id.is_empty() && self.config.steam_id != file.data.steam_id;
+        let unlocked = file.data.achievements.iter().filter(|a| a.achieved).count();
+
+        let mut confirmed = false;
+        let mut cancelled = false;
+
+        egui::Window::new(format!("{} {}", regular::FILE_ARROW_DOWN, tr("library_file.import_title")))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(egui::RichText::new(preview.path.display().to_string()).monospace().small());
+                ui.add_space(8.0);
+
+                egui::Grid::new("library_import_info").num_columns(2).spacing([12.0, 4.0]).show(ui, |ui| {
+                    ui.label(tr("library_file.exported_at"));
+                    ui.label(file.exported_at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string());
+                    ui.end_row();
+                    ui.label(tr("library_file.app_version"));
+                    ui.label(if file.app_version.is_empty() { tr("library_file.unknown_version") } else { file.app_version.as_str() });
+                    ui.end_row();
+                    ui.label(tr("library_file.steam_id"));
+                    ui.label(&file.data.steam_id);
+                    ui.end_row();
+                    ui.label(tr("library_file.games"));
+                    ui.label(file.data.games.len().to_string());
+                    ui.end_row();
+                    ui.label(tr("library_file.achievements"));
+                    ui.label(tr_args("library_file.unlocked_of", &[("unlocked", &unlocked), ("total", &file.data.achievements.len())]));
+                    ui.end_row();
+                    ui.label(tr("library_file.history"));
+                    ui.label(tr_args(
+                        "library_file.history_counts",
+                        &[
+                            ("runs", &file.data.run_history.len()),
+                            ("snapshots", &file.data.achievement_history.len()),
+                            ("goals", &file.data.goals.len()),
+                        ],
+                    ));
+                    ui.end_row();
+                });
+                ui.add_space(8.0);
+
+                if other_account {
+                    ui.colored_label(
+                        egui::Color32::from_rgb(255, 100, 100),
+                        format!(
+                            "{} {}",
+                            regular::WARNING,
+                            tr_args("library_file.other_account", &[("steam_id", &self.config.steam_id)])
+                        ),
+                    );
+                } else if diff.is_empty() {
+                    ui.label(tr("library_file.nothing_new"));
+                } else {
+                    ui.label(tr_args(
+                        "library_file.diff_unlocks",
+                        &[("file", &diff.cloud_only_unlocks), ("local", &diff.local_only_unlocks)],
+                    ));
+                    if diff.cloud_only_games > 0 {
+                        ui.label(tr_args("library_file.diff_games", &[("count", &diff.cloud_only_games)]));
+                    }
+                    if diff.cloud_more_playtime > 0 {
+                        ui.label(tr_args("library_file.diff_playtime", &[("count", &diff.cloud_more_playtime)]));
+                    }
+                    if diff.cloud_only_history > 0 {
+                        ui.label(tr_args("cloud.merge_history", &[("count", &diff.cloud_only_history)]));
+                    }
+                }
+                ui.add_space(4.0);
+                ui.label(
+                    egui::RichText::new(tr("library_file.merge_rules"))
+                        .weak(),
+                );
+                ui.add_space(16.0);
+
+                ui.horizontal(|ui| {
+                    if ui.button(tr("common.cancel")).clicked() {
+                        cancelled = true;
+                    }
+                    if ui.add_enabled(!other_account && !diff.is_empty(), egui::Button::new(tr("cloud.merge"))).clicked() {
+                        confirmed = true;
+                    }
+                });
+            });
+
+        if cancelled {
+            self.library_import = None;
+        }
+        if confirmed {
+            self.apply_library_import();
+        }
+    }
+}
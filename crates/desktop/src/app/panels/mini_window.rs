@@ -0,0 +1,83 @@
+//! Mini window - a small always-on-top OS window with completion, today's unlocks and the streak
+
+use chrono::Utc;
+use eframe::egui;
+use egui_phosphor::regular;
+use overachiever_core::{tr, tr_args};
+
+use crate::app::SteamOverachieverApp;
+
+/// Time between repaints while nothing else wakes the app (today's count rolls over at midnight)
+const MINI_WINDOW_REFRESH_SECS: u64 = 60;
+
+impl SteamOverachieverApp {
+    /// Show or hide the mini window and remember the choice
+    pub(crate) fn toggle_mini_window(&mut self) {
+        self.config.mini_window = !self.config.mini_window;
+        let _ = self.config.save();
+    }
+
+    /// Render the mini window in its own OS window (immediate viewport)
+    pub(crate) fn render_mini_window(&mut self, ctx: &egui::Context) {
+        if !self.config.mini_window {
+            return;
+        }
+
+        let time = &self.config.time_display;
+        let today = time.display_date(Utc::now());
+        let avg_completion = self.achievement_history.last().map(|h| h.avg_completion_percent);
+        let unlocks_today = self.unlock_times.iter().filter(|t| time.display_date(**t) == today).count();
+        let streak = self.achievement_records.as_ref().map(|r| r.streak_status(today)).unwrap_or_default();
+
+        let builder = egui::ViewportBuilder::default()
+            .with_title("Overachiever")
+            .with_inner_size([260.0, 90.0])
+            .with_resizable(false)
+            .with_window_level(egui::WindowLevel::AlwaysOnTop);
+
+        let mut close = false;
+        ctx.show_viewport_immediate(egui::ViewportId::from_hash_of("mini_window"), builder, |ctx, _class| {
+            if ctx.input(|i| i.viewport().close_requested()) {
+                close = true;
+                return;
+            }
+
+            egui::CentralPanel::default().show(ctx, |ui| {
+                ui.columns(3, |columns| {
+                    let completion = avg_completion.map(|p| format!("{:.1}%", p)).unwrap_or_else(|| "-".to_string());
+                    mini_stat(&mut columns[0], regular::CHART_PIE, &completion, tr("mini.completion"), None);
+                    mini_stat(&mut columns[1], regular::TROPHY, &unlocks_today.to_string(), tr("mini.today"), None);
+                    let (streak_hint, streak_color) = if streak.at_risk() {
+                        (Some(tr("mini.streak_at_risk")), Some(egui::Color32::from_rgb(255, 170, 60)))
+                    } else {
+                        (None, None)
+                    };
+                    let response = mini_stat(&mut columns[2], regular::FIRE, &tr_args("mini.streak_days", &[("days", &streak.current)]), tr("mini.streak"), streak_color);
+                    if let Some(hint) = streak_hint {
+                        response.on_hover_text(hint);
+                    }
+                });
+            });
+        });
+
+        if close {
+            self.config.mini_window = false;
+            let _ = self.config.save();
+        } else {
+            ctx.request_repaint_after(std::time::Duration::from_secs(MINI_WINDOW_REFRESH_SECS));
+        }
+    }
+}
+
+/// One centered value with an icon and a caption below
+fn mini_stat(ui: &mut egui::Ui, icon: &str, value: &str, caption: &str, color: Option<egui::Color32>) -> egui::Response {
+    ui.vertical_centered(|ui| {
+        let mut text = egui::RichText::new(format!("{} {}", icon, value)).size(20.0).strong();
+        if let Some(color) = color {
+            text = text.color(color);
+        }
+        ui.label(text);
+        ui.label(egui::RichText::new(caption).weak().small());
+    })
+    .response
+}
@@ -2,5 +2,25 @@
 
 pub mod top;
 mod history;
+mod rival;
 mod games_table;
 mod stats_impl;
+mod final_stretch;
+mod stalled_review;
+mod whats_new;
+mod sessions;
+mod admin;
+mod wishlist;
+mod achievement_search;
+mod command_palette;
+mod ttb_bulk;
+mod year_review;
+mod unlock_timeline;
+mod snapshot_compare;
+mod library_import;
+mod mini_window;
+mod stats_dashboard;
+mod planner;
+mod toast;
+
+pub(crate) use command_palette::CommandPalette;
@@ -0,0 +1,165 @@
+//! Completion planner window - remaining achievements ordered by difficulty, as a checklist
+//! with Markdown copy and printable HTML export
+
+use eframe::egui;
+use egui_phosphor::regular;
+use overachiever_core::{build_completion_plan, completion_plan_html, completion_plan_markdown, format_plan_minutes, tr, tr_args};
+
+use super::year_review::export_dir;
+use crate::app::{CompletionPlanState, SteamOverachieverApp};
+use crate::db::get_game_achievements;
+
+impl SteamOverachieverApp {
+    /// Load a game's achievements (on the DB worker) and open the planner on them
+    pub(crate) fn open_completion_plan(&mut self, appid: u64, game_name: String) {
+        let steam_id = self.config.steam_id.clone();
+        self.db_query(
+            move |conn| get_game_achievements(conn, &steam_id, appid),
+            move |app, achievements| {
+                let mut state = CompletionPlanState {
+                    appid,
+                    game_name,
+                    achievements: achievements.unwrap_or_else(|e| {
+                        eprintln!("Failed to load achievements for {}: {}", appid, e);
+                        Vec::new()
+                    }),
+                    plan: Default::default(),
+                    rated: false,
+                    done: Default::default(),
+                };
+                app.rebuild_completion_plan(&mut state);
+                app.completion_plan = Some(state);
+            },
+        );
+    }
+
+    /// Order the locked achievements and spread the remaining completionist time over them
+    fn rebuild_completion_plan(&self, state: &mut CompletionPlanState) {
+        let ratings = self.difficulty_curves.get(&state.appid);
+        let played_hours = self
+            .games
+            .iter()
+            .find(|g| g.appid == state.appid)
+            .map(|g| g.playtime_forever as f32 / 60.0)
+            .unwrap_or(0.0);
+        let remaining_hours = self
+            .ttb_cache
+            .get(&state.appid)
+            .and_then(|ttb| ttb.completionist)
+            .map(|hours| hours - played_hours);
+        state.plan = build_completion_plan(&state.achievements, ratings.map(|r| r.as_slice()).unwrap_or(&[]), remaining_hours);
+        state.rated = ratings.is_some();
+    }
+
+    /// Render the completion planner window (if open)
+    pub(crate) fn render_completion_plan_window(&mut self, ctx: &egui::Context) {
+        let Some(mut state) = self.completion_plan.take() else {
+            return;
+        };
+        // Ratings are fetched in the background (one game at a time), the plan is reordered when they arrive
+        if !state.rated {
+            if self.difficulty_curves.contains_key(&state.appid) {
+                self.rebuild_completion_plan(&mut state);
+            } else {
+                self.start_difficulty_fetch(state.appid);
+            }
+        }
+        let loading_ratings = !state.rated && self.difficulty_receiver.is_some();
+
+        let mut open = true;
+        let mut export = false;
+        egui::Window::new(format!("{} {}", regular::LIST_CHECKS, tr_args("planner.title", &[("game", &state.game_name)])))
+            .id(egui::Id::new("completion_plan_window"))
+            .open(&mut open)
+            .default_size([460.0, 480.0])
+            .show(ctx, |ui| {
+                let plan = &state.plan;
+                ui.horizontal(|ui| {
+                    ui.label(tr_args(
+                        "planner.summary",
+                        &[
+                            ("left", &(plan.steps.len() - state.done.len())),
+                            ("total", &plan.steps.len()),
+                            ("time", &format_plan_minutes(plan.remaining_minutes(&state.done))),
+                        ],
+                    ));
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.small_button(format!("{} {}", regular::EXPORT, tr("planner.export"))).on_hover_text(tr("planner.export_tooltip")).clicked() {
+                            export = true;
+                        }
+                        if ui.small_button(format!("{} {}", regular::COPY, tr("planner.copy"))).on_hover_text(tr("planner.copy_tooltip")).clicked() {
+                            ctx.copy_text(completion_plan_markdown(&state.game_name, plan, &state.done));
+                        }
+                    });
+                });
+                let source = if plan.from_time_to_beat {
+                    tr("planner.source_ttb")
+                } else {
+                    tr("planner.source_rough")
+                };
+                ui.label(egui::RichText::new(source).weak().small());
+                if loading_ratings {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(egui::RichText::new(tr("planner.loading_ratings")).weak().small());
+                    });
+                }
+                ui.separator();
+
+                if plan.steps.is_empty() {
+                    ui.label(tr("planner.nothing_left"));
+                    return;
+                }
+
+                egui::ScrollArea::vertical().auto_shrink([false, false]).show(ui, |ui| {
+                    for (i, step) in plan.steps.iter().enumerate() {
+                        let mut checked = state.done.contains(&step.apiname);
+                        ui.horizontal(|ui| {
+                            if ui.checkbox(&mut checked, format!("{}. {}", i + 1, step.name)).changed() {
+                                if checked {
+                                    state.done.insert(step.apiname.clone());
+                                } else {
+                                    state.done.remove(&step.apiname);
+                                }
+                            }
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                ui.label(format_plan_minutes(step.estimated_minutes));
+                                if let Some(rating) = step.difficulty {
+                                    ui.label(egui::RichText::new(format!("{} {:.1}", regular::GAUGE, rating)).weak())
+                                        .on_hover_text(tr("planner.difficulty_tooltip"));
+                                }
+                                if let Some(percent) = step.global_percent {
+                                    ui.label(egui::RichText::new(format!("{:.1}%", percent)).weak())
+                                        .on_hover_text(tr("planner.percent_tooltip"));
+                                }
+                            });
+                        });
+                        if let Some(description) = step.description.as_deref().filter(|d| !d.is_empty()) {
+                            ui.indent(("plan_step", i), |ui| {
+                                ui.label(egui::RichText::new(description).weak().small());
+                            });
+                        }
+                    }
+                });
+            });
+
+        if export {
+            self.export_completion_plan(&state);
+        }
+        if open {
+            self.completion_plan = Some(state);
+        }
+    }
+
+    /// Write the plan as a printable HTML checklist and open it
+    fn export_completion_plan(&mut self, state: &CompletionPlanState) {
+        let dest = export_dir().join(format!("overachiever_plan_{}.html", state.appid));
+        match std::fs::write(&dest, completion_plan_html(&state.game_name, &state.plan, &state.done)) {
+            Ok(()) => {
+                self.status = tr_args("planner.exported", &[("path", &dest.display())]);
+                let _ = open::that(&dest);
+            }
+            Err(e) => self.status = tr_args("planner.export_failed", &[("error", &e)]),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Rivalry summary window - head-to-head achievement progress against the rival account
+
+use eframe::egui;
+use egui_phosphor::regular;
+use overachiever_core::{tr, tr_args, StatsPanelPlatform};
+
+use crate::app::SteamOverachieverApp;
+
+/// Games listed per "who is ahead" section
+const MAX_LISTED_GAMES: usize = 10;
+
+/// Highlight for games the rival leads in
+const BEHIND_COLOR: egui::Color32 = egui::Color32::from_rgb(255, 100, 100);
+
+/// A game both accounts have achievement data for
+struct HeadToHead<'a> {
+    name: &'a str,
+    mine: i32,
+    theirs: i32,
+    total: i32,
+}
+
+impl SteamOverachieverApp {
+    /// Render the rivalry summary window
+    pub(crate) fn render_rival_window(&mut self, ctx: &egui::Context) {
+        if !self.show_rival_summary {
+            return;
+        }
+
+        let mut open = self.show_rival_summary;
+        let mut refresh = false;
+        let theme = self.theme_colors();
+
+        let mut common: Vec<HeadToHead> = self
+            .games
+            .iter()
+            .filter_map(|game| {
+                let (theirs, total) = self.rival_progress(game.appid)?;
+                Some(HeadToHead { name: &game.name, mine: game.achievements_unlocked?, theirs, total })
+            })
+            .collect();
+        // Biggest leads first (either way)
+        common.sort_by_key(|h| std::cmp::Reverse((h.mine - h.theirs).abs()));
+
+        egui::Window::new(format!("{} {}", regular::SWORD, tr("rival.title")))
+            .open(&mut open)
+            .default_width(440.0)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(tr_args("rival.account", &[("steam_id", &self.config.rival_steam_id)])).weak());
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        let busy = self.rival_receiver.is_some();
+                        if ui.add_enabled(!busy, egui::Button::new(format!("{} {}", regular::ARROWS_CLOCKWISE, tr("rival.refresh")))).clicked() {
+                            refresh = true;
+                        }
+                        if busy {
+                            ui.spinner();
+                        }
+                    });
+                });
+                if let Some(status) = &self.rival_status {
+                    ui.label(egui::RichText::new(status).small());
+                }
+                ui.separator();
+
+                if common.is_empty() {
+                    ui.label(tr("rival.no_common_games"));
+                    return;
+                }
+
+                let ahead = common.iter().filter(|h| h.mine > h.theirs).count();
+                let behind = common.iter().filter(|h| h.mine < h.theirs).count();
+                let tied = common.len() - ahead - behind;
+                let my_total: i32 = common.iter().map(|h| h.mine).sum();
+                let their_total: i32 = common.iter().map(|h| h.theirs).sum();
+                let my_perfect = common.iter().filter(|h| h.mine == h.total).count();
+                let their_perfect = common.iter().filter(|h| h.theirs == h.total).count();
+
+                egui::Grid::new("rival_summary_grid").num_columns(3).spacing([24.0, 4.0]).show(ui, |ui| {
+                    ui.label("");
+                    ui.label(egui::RichText::new(tr("rival.you")).strong());
+                    ui.label(egui::RichText::new(tr("rival.rival")).strong());
+                    ui.end_row();
+
+                    ui.label(tr("rival.games_ahead"));
+                    ui.label(egui::RichText::new(ahead.to_string()).color(theme.complete));
+                    ui.label(egui::RichText::new(behind.to_string()).color(BEHIND_COLOR));
+                    ui.end_row();
+
+                    ui.label(tr("rival.achievements"));
+                    ui.label(my_total.to_string());
+                    ui.label(their_total.to_string());
+                    ui.end_row();
+
+                    ui.label(tr("rival.perfect_games"));
+                    ui.label(my_perfect.to_string());
+                    ui.label(their_perfect.to_string());
+                    ui.end_row();
+                });
+                ui.label(
+                    egui::RichText::new(tr_args("rival.common_games", &[("count", &common.len()), ("tied", &tied)]))
+                        .small()
+                        .weak(),
+                );
+
+                ui.separator();
+                egui::ScrollArea::vertical().max_height(320.0).show(ui, |ui| {
+                    for (heading, rival_leads) in [(tr("rival.rival_leads"), true), (tr("rival.you_lead"), false)] {
+                        ui.label(egui::RichText::new(heading).strong());
+                        let games = common
+                            .iter()
+                            .filter(|h| if rival_leads { h.theirs > h.mine } else { h.mine > h.theirs })
+                            .take(MAX_LISTED_GAMES);
+                        for h in games {
+                            ui.horizontal(|ui| {
+                                ui.label(h.name);
+                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                    ui.label(
+                                        egui::RichText::new(tr_args("rival.head_to_head", &[
+                                            ("mine", &h.mine),
+                                            ("theirs", &h.theirs),
+                                            ("total", &h.total),
+                                        ]))
+                                        .color(if rival_leads { BEHIND_COLOR } else { theme.complete }),
+                                    );
+                                });
+                            });
+                        }
+                        ui.add_space(8.0);
+                    }
+                });
+            });
+
+        self.show_rival_summary = open;
+        if refresh {
+            self.start_rival_scrape();
+        }
+    }
+}
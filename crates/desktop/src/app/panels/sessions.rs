@@ -0,0 +1,81 @@
+//! Session history window - per-game play sessions from "currently playing" polling
+
+use eframe::egui;
+use egui_phosphor::regular;
+use overachiever_core::{tr, tr_args};
+
+use crate::app::SteamOverachieverApp;
+
+/// Format a duration in minutes as "1h 25m" / "25m"
+fn format_minutes(minutes: i64) -> String {
+    if minutes >= 60 {
+        tr_args("ttb.hours_minutes", &[("hours", &(minutes / 60)), ("minutes", &(minutes % 60))])
+    } else {
+        tr_args("ttb.minutes", &[("minutes", &minutes)])
+    }
+}
+
+impl SteamOverachieverApp {
+    /// Render the session history window (if open)
+    pub(crate) fn render_sessions_window(&mut self, ctx: &egui::Context) {
+        let Some((appid, game_name, sessions)) = &self.sessions_window else {
+            return;
+        };
+
+        let mut open = true;
+        let mut refresh = false;
+        let (appid, game_name) = (*appid, game_name.clone());
+
+        egui::Window::new(format!("{} {}", regular::CLOCK_COUNTER_CLOCKWISE, tr_args("sessions.title", &[("game", &game_name)])))
+            .id(egui::Id::new("sessions_window"))
+            .open(&mut open)
+            .default_size([360.0, 320.0])
+            .show(ctx, |ui| {
+                let total: i64 = sessions.iter().map(|s| s.duration_minutes()).sum();
+                ui.horizontal(|ui| {
+                    ui.label(tr_args("sessions.summary", &[("count", &sessions.len()), ("time", &format_minutes(total))]));
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.small_button(regular::ARROWS_CLOCKWISE.to_string()).on_hover_text(tr("sessions.reload")).clicked() {
+                            refresh = true;
+                        }
+                    });
+                });
+                if self.config.session_poll_interval_secs == 0 {
+                    ui.label(egui::RichText::new(tr("sessions.disabled")).weak());
+                }
+                ui.separator();
+
+                if sessions.is_empty() {
+                    ui.label(tr("sessions.empty"));
+                    return;
+                }
+
+                egui::ScrollArea::vertical().auto_shrink([false, false]).show(ui, |ui| {
+                    egui::Grid::new("sessions_grid").striped(true).num_columns(3).show(ui, |ui| {
+                        ui.label(egui::RichText::new(tr("sessions.date")).strong());
+                        ui.label(egui::RichText::new(tr("sessions.started")).strong());
+                        ui.label(egui::RichText::new(tr("sessions.duration")).strong());
+                        ui.end_row();
+
+                        for session in sessions {
+                            let started = session.started_at.with_timezone(&chrono::Local);
+                            ui.label(started.format("%Y-%m-%d").to_string());
+                            ui.label(started.format("%H:%M").to_string());
+                            if session.ended {
+                                ui.label(format_minutes(session.duration_minutes()));
+                            } else {
+                                ui.label(format!("{} {}", regular::PLAY, tr_args("sessions.playing", &[("time", &format_minutes(session.duration_minutes()))])));
+                            }
+                            ui.end_row();
+                        }
+                    });
+                });
+            });
+
+        if !open {
+            self.sessions_window = None;
+        } else if refresh {
+            self.open_sessions_window(appid, game_name);
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//! "Compare snapshots" window - what changed between two scans
+
+use eframe::egui;
+use egui_phosphor::regular;
+use overachiever_core::{snapshot_diff, tr, tr_args, SnapshotDiff, TimeDisplaySettings};
+
+use crate::app::{SnapshotCompareState, SteamOverachieverApp};
+use crate::db::{get_scan_snapshot, get_scan_snapshot_times, get_unlock_records};
+
+/// Format a signed duration in minutes as "+1h 25m" / "-25m"
+fn format_minutes_delta(minutes: i64) -> String {
+    let sign = if minutes < 0 { "-" } else { "+" };
+    let minutes = minutes.abs();
+    if minutes >= 60 {
+        format!("{}{}", sign, tr_args("ttb.hours_minutes", &[("hours", &(minutes / 60)), ("minutes", &(minutes % 60))]))
+    } else {
+        format!("{}{}", sign, tr_args("ttb.minutes", &[("minutes", &minutes)]))
+    }
+}
+
+impl SteamOverachieverApp {
+    /// Open the window comparing the latest snapshot with the one before it (loaded on the DB worker)
+    pub(crate) fn open_snapshot_compare(&mut self) {
+        let steam_id = self.config.steam_id.clone();
+        self.db_query(
+            move |conn| Ok((get_scan_snapshot_times(conn, &steam_id)?, get_unlock_records(conn, &steam_id)?)),
+            |app, loaded| match loaded {
+                Ok((times, unlocks)) => {
+                    let to = times.len().saturating_sub(1);
+                    let state = SnapshotCompareState {
+                        from: to.saturating_sub(1),
+                        to,
+                        times,
+                        unlocks,
+                        diff: None,
+                    };
+                    app.load_snapshot_diff(&state);
+                    app.snapshot_compare = Some(state);
+                }
+                Err(e) => app.status = tr_args("snapshots.load_failed", &[("error", &e)]),
+            },
+        );
+    }
+
+    /// Recompute the diff for the selected pair of snapshots (the window shows it once loaded)
+    fn load_snapshot_diff(&self, state: &SnapshotCompareState) {
+        let (Some(&from_at), Some(&to_at)) = (state.times.get(state.from), state.times.get(state.to)) else {
+            return;
+        };
+        let steam_id = self.config.steam_id.clone();
+        let selected = (state.from, state.to);
+        self.db_query(
+            move |conn| Ok((get_scan_snapshot(conn, &steam_id, from_at)?, get_scan_snapshot(conn, &steam_id, to_at)?)),
+            move |app, loaded| {
+                // Dropped when the window was closed or another pair was picked meanwhile
+                let Some(state) = app.snapshot_compare.as_mut().filter(|s| (s.from, s.to) == selected) else {
+                    return;
+                };
+                match loaded {
+                    Ok((from, to)) => {
+                        state.diff = Some(snapshot_diff(from_at, &from, to_at, &to, &state.unlocks, &app.achievement_history));
+                    }
+                    Err(e) => eprintln!("Failed to load snapshots: {}", e),
+                }
+            },
+        );
+    }
+
+    /// Render the snapshot comparison window
+    pub(crate) fn render_snapshot_compare_window(&mut self, ctx: &egui::Context) {
+        let Some(mut state) = self.snapshot_compare.take() else {
+            return;
+        };
+
+        let time = self.config.time_display.clone();
+        let mut open = true;
+        let (mut from, mut to) = (state.from, state.to);
+
+        egui::Window::new(format!("{} {}", regular::GIT_DIFF, tr("toolbar.compare_snapshots")))
+            .open(&mut open)
+            .default_size([520.0, 480.0])
+            .show(ctx, |ui| {
+                if state.times.len() < 2 {
+                    ui.label(tr("snapshots.need_two"));
+                    return;
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label(tr("snapshots.from"));
+                    snapshot_combo(ui, "snapshot_from", &state.times, &mut from, &time);
+                    ui.label(tr("snapshots.to"));
+                    snapshot_combo(ui, "snapshot_to", &state.times, &mut to, &time);
+                });
+                ui.separator();
+
+                if from >= to {
+                    ui.label(tr("snapshots.wrong_order"));
+                } else if let Some(diff) = &state.diff {
+                    render_diff(ui, diff, &time);
+                }
+            });
+
+        if (from, to) != (state.from, state.to) {
+            state.from = from;
+            state.to = to;
+            state.diff = None;
+            self.load_snapshot_diff(&state);
+        }
+
+        if open {
+            self.snapshot_compare = Some(state);
+        }
+    }
+}
+
+fn snapshot_combo(ui: &mut egui::Ui, id: &str, times: &[chrono::DateTime<chrono::Utc>], selected: &mut usize, time: &TimeDisplaySettings) {
+    egui::ComboBox::from_id_salt(id)
+        .selected_text(times.get(*selected).map(|t| time.format_absolute(*t)).unwrap_or_default())
+        .show_ui(ui, |ui| {
+            // Newest first
+            for (i, t) in times.iter().enumerate().rev() {
+                ui.selectable_value(selected, i, time.format_absolute(*t));
+            }
+        });
+}
+
+fn render_diff(ui: &mut egui::Ui, diff: &SnapshotDiff, time: &TimeDisplaySettings) {
+    if diff.is_empty() {
+        ui.label(tr("snapshots.no_changes"));
+        return;
+    }
+
+    ui.horizontal_wrapped(|ui| {
+        ui.label(tr_args("snapshots.unlocks", &[("count", &diff.unlocks.len())]));
+        ui.label("·");
+        ui.label(tr_args("snapshots.new_games", &[("count", &diff.new_games.len())]));
+        ui.label("·");
+        ui.label(tr_args("snapshots.played", &[("time", &format_minutes_delta(diff.total_playtime_delta()))]));
+        if let Some((before, after)) = diff.avg_completion {
+            ui.label("·");
+            ui.label(tr_args(
+                "snapshots.avg_completion",
+                &[("before", &format!("{:.1}", before)), ("after", &format!("{:.1}", after)), ("delta", &format!("{:+.1}", after - before))],
+            ));
+        }
+    });
+    ui.add_space(6.0);
+
+    egui::ScrollArea::vertical().auto_shrink([false, true]).show(ui, |ui| {
+        if !diff.changed_games.is_empty() {
+            egui::CollapsingHeader::new(tr_args("snapshots.changed_games", &[("count", &diff.changed_games.len())]))
+                .default_open(true)
+                .show(ui, |ui| {
+                    egui::Grid::new("snapshot_changed_games").striped(true).num_columns(4).show(ui, |ui| {
+                        ui.strong(tr("snapshots.game"));
+                        ui.strong(tr("snapshots.playtime"));
+                        ui.strong(tr("snapshots.unlocks_column"));
+                        ui.strong(tr("snapshots.completion"));
+                        ui.end_row();
+                        for change in &diff.changed_games {
+                            ui.label(&change.name);
+                            ui.label(if change.playtime_delta != 0 { format_minutes_delta(change.playtime_delta) } else { String::new() });
+                            ui.label(if change.unlocked_delta != 0 { format!("{:+}", change.unlocked_delta) } else { String::new() });
+                            match (change.percent_before, change.percent_after) {
+                                (Some(before), Some(after)) if before != after => {
+                                    ui.label(format!("{:.0}% → {:.0}%", before, after));
+                                }
+                                (_, Some(after)) => {
+                                    ui.label(format!("{:.0}%", after));
+                                }
+                                _ => {
+                                    ui.label("");
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+                });
+        }
+
+        if !diff.unlocks.is_empty() {
+            egui::CollapsingHeader::new(tr_args("snapshots.unlocks_header", &[("count", &diff.unlocks.len())])).show(ui, |ui| {
+                for unlock in diff.unlocks.iter().rev() {
+                    ui.horizontal_wrapped(|ui| {
+                        ui.label(egui::RichText::new(time.format_absolute(unlock.unlocktime)).weak());
+                        ui.strong(&unlock.achievement_name);
+                        ui.label(format!("({})", unlock.game_name));
+                    });
+                }
+            });
+        }
+
+        if !diff.new_games.is_empty() {
+            egui::CollapsingHeader::new(tr_args("snapshots.new_games_header", &[("count", &diff.new_games.len())])).show(ui, |ui| {
+                for game in &diff.new_games {
+                    ui.label(format!("{} {}", regular::PLUS, game.name));
+                }
+            });
+        }
+
+        if !diff.removed_games.is_empty() {
+            egui::CollapsingHeader::new(tr_args("snapshots.removed_games_header", &[("count", &diff.removed_games.len())])).show(ui, |ui| {
+                for game in &diff.removed_games {
+                    ui.label(format!("{} {}", regular::MINUS, game.name));
+                }
+            });
+        }
+    });
+}
@@ -0,0 +1,148 @@
+//! "Review stalled games" window - walks through games untouched for a year at low completion
+//! and asks whether to abandon them
+
+use chrono::{TimeZone, Utc};
+use eframe::egui;
+use egui_phosphor::regular;
+use overachiever_core::{format_relative, tr, tr_args, StatsPanelPlatform};
+
+use crate::app::SteamOverachieverApp;
+use crate::db::set_game_abandoned;
+
+/// What to do with the game under review
+enum ReviewDecision {
+    Abandon,
+    Keep,
+    Skip,
+}
+
+impl SteamOverachieverApp {
+    /// Mark a game as abandoned (or take it back up), in memory and in the database
+    pub(crate) fn set_game_abandoned(&mut self, appid: u64, abandoned: bool) {
+        let Some(game) = self.games.iter_mut().find(|g| g.appid == appid) else {
+            return;
+        };
+        game.abandoned = abandoned;
+        let steam_id = self.config.steam_id.clone();
+        self.db_worker.execute("update abandoned status", move |conn| set_game_abandoned(conn, &steam_id, appid, abandoned));
+    }
+
+    /// Collect the stalled games to review, longest untouched first
+    pub(crate) fn open_stalled_review(&mut self) {
+        let now = Utc::now();
+        let kept = &self.config.stalled_review_kept;
+        let mut stalled: Vec<(u32, u64)> = self
+            .games
+            .iter()
+            .filter(|g| g.is_stalled(now) && !g.hidden && !kept.contains(&g.appid))
+            .map(|g| (g.rtime_last_played.unwrap_or(0), g.appid))
+            .collect();
+        stalled.sort_unstable();
+        self.stalled_review = stalled.into_iter().map(|(_, appid)| appid).collect();
+        self.show_stalled_review = true;
+    }
+
+    /// Render the stalled games review window
+    pub(crate) fn render_stalled_review_window(&mut self, ctx: &egui::Context) {
+        if !self.show_stalled_review {
+            return;
+        }
+
+        // Games may have been abandoned or played from elsewhere meanwhile
+        let now = Utc::now();
+        let games = &self.games;
+        self.stalled_review
+            .retain(|appid| games.iter().any(|g| g.appid == *appid && g.is_stalled(now)));
+
+        let mut open = self.show_stalled_review;
+        let mut decision: Option<ReviewDecision> = None;
+        let theme = self.theme_colors();
+
+        egui::Window::new(format!("{} {}", regular::ARCHIVE, tr("stalled.title")))
+            .open(&mut open)
+            .default_width(420.0)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.label(egui::RichText::new(tr("stalled.description")).weak());
+                ui.separator();
+
+                let Some(game) = self
+                    .stalled_review
+                    .first()
+                    .and_then(|appid| self.games.iter().find(|g| g.appid == *appid))
+                else {
+                    ui.label(tr("stalled.none_left"));
+                    return;
+                };
+
+                ui.label(tr_args("stalled.remaining", &[("count", &self.stalled_review.len())]));
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    if let Some(icon_hash) = game.img_icon_url.as_deref().filter(|h| !h.is_empty()) {
+                        ui.add(
+                            egui::Image::new(self.game_icon_source(ui, game.appid, icon_hash))
+                                .fit_to_exact_size(egui::vec2(48.0, 48.0))
+                                .corner_radius(4.0),
+                        );
+                    }
+                    ui.vertical(|ui| {
+                        ui.label(egui::RichText::new(&game.name).color(theme.accent).strong());
+                        ui.label(format!(
+                            "{} ({:.0}%)",
+                            game.achievements_display(),
+                            game.completion_percent().unwrap_or(0.0)
+                        ));
+                        let last_played = game
+                            .rtime_last_played
+                            .and_then(|t| Utc.timestamp_opt(t as i64, 0).single())
+                            .map(|t| format_relative(t, now))
+                            .unwrap_or_default();
+                        ui.label(
+                            egui::RichText::new(tr_args("stalled.last_played", &[
+                                ("when", &last_played),
+                                ("hours", &format!("{:.1}", game.playtime_forever as f32 / 60.0)),
+                            ]))
+                            .small()
+                            .weak(),
+                        );
+                    });
+                });
+                ui.add_space(8.0);
+
+                ui.horizontal(|ui| {
+                    if ui.button(format!("{} {}", regular::ARCHIVE, tr("stalled.abandon")))
+                        .on_hover_text(tr("table.abandon"))
+                        .clicked()
+                    {
+                        decision = Some(ReviewDecision::Abandon);
+                    }
+                    if ui.button(format!("{} {}", regular::HEART, tr("stalled.keep")))
+                        .on_hover_text(tr("stalled.keep_tooltip"))
+                        .clicked()
+                    {
+                        decision = Some(ReviewDecision::Keep);
+                    }
+                    if ui.button(format!("{} {}", regular::SKIP_FORWARD, tr("stalled.skip")))
+                        .on_hover_text(tr("stalled.skip_tooltip"))
+                        .clicked()
+                    {
+                        decision = Some(ReviewDecision::Skip);
+                    }
+                });
+            });
+
+        self.show_stalled_review = open;
+
+        if let Some(decision) = decision {
+            let appid = self.stalled_review.remove(0);
+            match decision {
+                ReviewDecision::Abandon => self.set_game_abandoned(appid, true),
+                ReviewDecision::Keep => {
+                    self.config.stalled_review_kept.push(appid);
+                    let _ = self.config.save();
+                }
+                ReviewDecision::Skip => {}
+            }
+        }
+    }
+}
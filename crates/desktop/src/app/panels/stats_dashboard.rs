@@ -0,0 +1,15 @@
+//! Stats tab - full-page dashboard charts from overachiever-core
+
+use eframe::egui;
+use overachiever_core::{render_stats_dashboard, StatsPanelConfig};
+
+use crate::app::SteamOverachieverApp;
+
+impl SteamOverachieverApp {
+    pub(crate) fn render_stats_dashboard_tab(&mut self, ui: &mut egui::Ui) {
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            let config = StatsPanelConfig::desktop();
+            render_stats_dashboard(ui, self, &config);
+        });
+    }
+}
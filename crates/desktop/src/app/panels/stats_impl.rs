@@ -1,10 +1,12 @@
 //! Platform implementation for shared stats panel
 
+use std::borrow::Cow;
+
 use eframe::egui::{self, Ui};
-use overachiever_core::{Game, RunHistory, AchievementHistory, LogEntry, StatsPanelPlatform};
+use overachiever_core::{Game, RunHistory, AchievementHistory, AchievementRecords, Goal, LogEntry, LogFilter, StatsPanelPlatform, ThemeColors, TimeDisplaySettings};
 
 use crate::app::SteamOverachieverApp;
-use crate::db::{open_connection, set_achievement_rating};
+use crate::db::set_achievement_rating;
 use crate::cloud_sync::submit_achievement_rating;
 
 impl StatsPanelPlatform for SteamOverachieverApp {
@@ -12,6 +14,14 @@ impl StatsPanelPlatform for SteamOverachieverApp {
         &self.games
     }
     
+    fn exclude_non_games_from_stats(&self) -> bool {
+        self.config.exclude_non_games_from_stats
+    }
+    
+    fn include_unowned_in_stats(&self) -> bool {
+        self.config.include_unowned_in_stats
+    }
+    
     fn run_history(&self) -> &[RunHistory] {
         &self.run_history
     }
@@ -21,7 +31,15 @@ impl StatsPanelPlatform for SteamOverachieverApp {
     }
     
     fn log_entries(&self) -> &[LogEntry] {
-        &self.log_entries
+        self.filtered_log_entries.as_deref().unwrap_or(&self.log_entries)
+    }
+    
+    fn log_filter(&self) -> Option<&LogFilter> {
+        Some(&self.log_filter)
+    }
+    
+    fn set_log_filter(&mut self, filter: LogFilter) {
+        self.apply_log_filter(filter);
     }
     
     fn include_unplayed_in_avg(&self) -> bool {
@@ -32,28 +50,64 @@ impl StatsPanelPlatform for SteamOverachieverApp {
         self.include_unplayed_in_avg = value;
     }
     
+    fn achievement_records(&self) -> Option<&AchievementRecords> {
+        self.achievement_records.as_ref()
+    }
+    
+    fn goals(&self) -> Option<&[Goal]> {
+        Some(&self.goals)
+    }
+    
+    fn add_goal(&mut self, goal: Goal) {
+        SteamOverachieverApp::add_goal(self, goal);
+    }
+    
+    fn remove_goal(&mut self, created_at: chrono::DateTime<chrono::Utc>) {
+        SteamOverachieverApp::remove_goal(self, created_at);
+    }
+    
+    fn tag_names(&self) -> &[String] {
+        &self.available_tags
+    }
+    
+    fn tags_for_game(&self, appid: u64) -> Option<&[(String, u32)]> {
+        self.tags_cache.get(&appid).map(Vec::as_slice)
+    }
+    
+    fn ttb_completionist_hours(&self, appid: u64) -> Option<f32> {
+        self.ttb_cache.get(&appid).and_then(|t| t.completionist)
+    }
+    
+    fn unlock_times(&self) -> Cow<'_, [chrono::DateTime<chrono::Utc>]> {
+        Cow::Borrowed(&self.unlock_times)
+    }
+    
+    fn theme_colors(&self) -> ThemeColors {
+        crate::app::panels::top::theme::theme_colors(&self.config)
+    }
+    
+    fn time_display(&self) -> TimeDisplaySettings {
+        self.config.time_display.clone()
+    }
+    
     fn game_icon_source(&self, ui: &Ui, appid: u64, icon_hash: &str) -> egui::ImageSource<'static> {
         let game_icon_url = format!(
             "https://media.steampowered.com/steamcommunity/public/images/apps/{}/{}.jpg",
             appid, icon_hash
         );
         
-        if let Some(bytes) = self.icon_cache.get_icon_bytes(&game_icon_url) {
-            let cache_uri = format!("bytes://log_game/{}", appid);
-            ui.ctx().include_bytes(cache_uri.clone(), bytes);
-            egui::ImageSource::Uri(cache_uri.into())
-        } else {
-            egui::ImageSource::Uri(game_icon_url.into())
+        let cache_uri = format!("bytes://log_game/{}", appid);
+        match self.icon_cache.image_uri(ui.ctx(), &game_icon_url, cache_uri) {
+            Some(uri) => egui::ImageSource::Uri(uri.into()),
+            None => egui::ImageSource::Uri(game_icon_url.into()),
         }
     }
     
     fn achievement_icon_source(&self, ui: &Ui, icon_url: &str) -> egui::ImageSource<'static> {
-        if let Some(bytes) = self.icon_cache.get_icon_bytes(icon_url) {
-            let cache_uri = format!("bytes://log_ach/{}", icon_url.replace(['/', ':', '.'], "_"));
-            ui.ctx().include_bytes(cache_uri.clone(), bytes);
-            egui::ImageSource::Uri(cache_uri.into())
-        } else {
-            egui::ImageSource::Uri(icon_url.to_string().into())
+        let cache_uri = format!("bytes://log_ach/{}", icon_url.replace(['/', ':', '.'], "_"));
+        match self.icon_cache.image_uri(ui.ctx(), icon_url, cache_uri) {
+            Some(uri) => egui::ImageSource::Uri(uri.into()),
+            None => egui::ImageSource::Uri(icon_url.to_string().into()),
         }
     }
     
@@ -87,9 +141,8 @@ impl StatsPanelPlatform for SteamOverachieverApp {
         
         // Persist to local database (for offline/quick access)
         let steam_id = self.config.steam_id.clone();
-        if let Ok(conn) = open_connection() {
-            let _ = set_achievement_rating(&conn, &steam_id, appid, &apiname, rating);
-        }
+        let rated = apiname.clone();
+        self.db_worker.execute("save achievement rating", move |conn| set_achievement_rating(conn, &steam_id, appid, &rated, rating));
         
         // Submit to remote server if authenticated
         if let Some(token) = &self.config.cloud_token {
@@ -106,20 +159,31 @@ impl StatsPanelPlatform for SteamOverachieverApp {
         // Expand the game row
         self.expanded_rows.insert(appid);
         
-        // Load achievements if not cached
-        if !self.achievements_cache.contains_key(&appid) {
-            if let Ok(conn) = open_connection() {
-                if let Ok(achs) = crate::db::get_game_achievements(&conn, &self.config.steam_id, appid) {
-                    self.achievements_cache.insert(appid, achs);
-                }
-            }
-        }
+        // Load achievements if not cached, the scroll happens once they arrive
+        self.load_achievements(appid);
         
         // Set navigation target for scroll-to behavior and enable one-time scroll
         self.navigation_target = Some((appid, apiname));
         self.needs_scroll_to_target = true;
     }
     
+    fn get_achievement_avg_rating(&self, appid: u64, apiname: &str) -> Option<(f32, i32)> {
+        self.difficulty_curves
+            .get(&appid)?
+            .iter()
+            .find(|d| d.apiname == apiname)
+            .map(|d| (d.avg_rating, d.rating_count))
+    }
+
+    fn get_achievement_rating_votes(&self, appid: u64, apiname: &str) -> Option<[i32; 5]> {
+        self.difficulty_curves
+            .get(&appid)?
+            .iter()
+            .find(|d| d.apiname == apiname)
+            .map(|d| d.votes)
+            .filter(|votes| votes.iter().any(|&v| v > 0))
+    }
+    
     fn get_log_selected_achievement(&self) -> Option<(u64, String)> {
         self.log_selected_achievement.clone()
     }
@@ -0,0 +1,44 @@
+//! Toast - a short notice in the bottom-right corner that fades out on its own
+
+use std::time::{Duration, Instant};
+
+use eframe::egui;
+
+use crate::app::SteamOverachieverApp;
+
+/// How long a toast stays up, including the fade
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+const TOAST_FADE: Duration = Duration::from_secs(1);
+
+impl SteamOverachieverApp {
+    /// Show a toast, replacing the current one
+    pub(crate) fn show_toast(&mut self, text: impl Into<String>) {
+        self.toast = Some((text.into(), Instant::now()));
+    }
+
+    /// Render the current toast (if any) and drop it once it has faded out
+    pub(crate) fn render_toast(&mut self, ctx: &egui::Context) {
+        let Some((text, shown)) = &self.toast else {
+            return;
+        };
+        let elapsed = shown.elapsed();
+        if elapsed >= TOAST_DURATION {
+            self.toast = None;
+            return;
+        }
+        let remaining = TOAST_DURATION - elapsed;
+        let opacity = (remaining.as_secs_f32() / TOAST_FADE.as_secs_f32()).min(1.0);
+
+        egui::Area::new(egui::Id::new("toast"))
+            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-12.0, -12.0))
+            .order(egui::Order::Foreground)
+            .interactable(false)
+            .show(ctx, |ui| {
+                ui.multiply_opacity(opacity);
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.label(text.as_str());
+                });
+            });
+        ctx.request_repaint_after(if remaining > TOAST_FADE { remaining - TOAST_FADE } else { Duration::from_millis(30) });
+    }
+}
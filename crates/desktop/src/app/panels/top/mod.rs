@@ -4,4 +4,5 @@ mod toolbar;
 mod settings;
 mod modals;
 pub mod fonts;
+pub mod theme;
 mod profile_menu;
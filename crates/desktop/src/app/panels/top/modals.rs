@@ -2,7 +2,7 @@
 
 use eframe::egui;
 use egui_phosphor::regular;
-use overachiever_core::GdprConsent;
+use overachiever_core::{tr, tr_args, GdprConsent};
 
 use crate::app::{CloudAction, SteamOverachieverApp};
 
@@ -17,19 +17,19 @@ impl SteamOverachieverApp {
         
         let (title, message, confirm_text) = match &action {
             CloudAction::Upload => (
-                "Upload to Cloud",
-                "This will upload all your local data to overachiever.space.\nAny existing cloud data will be replaced.",
-                "Upload"
+                tr("cloud.upload_title"),
+                tr("cloud.upload_message"),
+                tr("cloud.upload")
             ),
             CloudAction::Download => (
-                "Download from Cloud", 
-                "This will download data from overachiever.space and replace your local data.",
-                "Download"
+                tr("cloud.download_title"),
+                tr("cloud.download_message"),
+                tr("cloud.download")
             ),
             CloudAction::Delete => (
-                "Remove from Cloud",
-                "This will permanently delete all your data from overachiever.space.\nYour local data will not be affected.",
-                "Delete"
+                tr("cloud.delete_title"),
+                tr("cloud.delete_message"),
+                tr("cloud.delete")
             ),
         };
         
@@ -46,7 +46,7 @@ impl SteamOverachieverApp {
                 ui.add_space(16.0);
                 
                 ui.horizontal(|ui| {
-                    if ui.button("Cancel").clicked() {
+                    if ui.button(tr("common.cancel")).clicked() {
                         cancelled = true;
                     }
                     if ui.button(confirm_text).clicked() {
@@ -68,6 +68,67 @@ impl SteamOverachieverApp {
         }
     }
     
+    /// Render the pre-import summary for a cloud download
+    pub(crate) fn render_cloud_import_dialog(&mut self, ctx: &egui::Context) {
+        let Some((_, diff)) = &self.pending_cloud_import else {
+            return;
+        };
+        let diff = diff.clone();
+
+        let mut confirmed = false;
+        let mut cancelled = false;
+
+        egui::Window::new(format!("{} {}", regular::CLOUD_ARROW_DOWN, tr("cloud.merge_title")))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.add_space(8.0);
+                if diff.is_empty() {
+                    ui.label(tr("cloud.merge_nothing"));
+                } else {
+                    ui.label(tr_args(
+                        "cloud.merge_unlocks",
+                        &[("cloud", &diff.cloud_only_unlocks), ("local", &diff.local_only_unlocks)],
+                    ));
+                    if diff.cloud_only_games > 0 || diff.local_only_games > 0 {
+                        ui.label(tr_args(
+                            "cloud.merge_games",
+                            &[("cloud", &diff.cloud_only_games), ("local", &diff.local_only_games)],
+                        ));
+                    }
+                    if diff.cloud_more_playtime > 0 {
+                        ui.label(tr_args("cloud.merge_playtime", &[("count", &diff.cloud_more_playtime)]));
+                    }
+                    if diff.cloud_only_history > 0 {
+                        ui.label(tr_args("cloud.merge_history", &[("count", &diff.cloud_only_history)]));
+                    }
+                }
+                ui.add_space(4.0);
+                ui.label(
+                    egui::RichText::new(tr("cloud.merge_rules"))
+                        .weak(),
+                );
+                ui.add_space(16.0);
+
+                ui.horizontal(|ui| {
+                    if ui.button(tr("common.cancel")).clicked() {
+                        cancelled = true;
+                    }
+                    if ui.button(tr("cloud.merge")).clicked() {
+                        confirmed = true;
+                    }
+                });
+            });
+
+        if cancelled {
+            self.pending_cloud_import = None;
+        }
+        if confirmed {
+            self.apply_cloud_import();
+        }
+    }
+
     /// Render GDPR modal
     pub(crate) fn render_gdpr_modal(&mut self, ctx: &egui::Context) {
         // If consent is already set and dialog not explicitly opened, don't show
@@ -89,7 +150,7 @@ impl SteamOverachieverApp {
             });
         
         // Modal window
-        egui::Window::new(format!("{} Privacy & Data Usage", regular::SHIELD_CHECK))
+        egui::Window::new(format!("{} {}", regular::SHIELD_CHECK, tr("gdpr.title")))
             .collapsible(false)
             .resizable(false)
             .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
@@ -98,12 +159,12 @@ impl SteamOverachieverApp {
                 ui.vertical(|ui| {
                     ui.add_space(8.0);
                     
-                    ui.label("This application processes personal data to provide its services:");
+                    ui.label(tr("gdpr.intro"));
                     
                     ui.add_space(12.0);
                     
                     // Data we collect section
-                    ui.heading("Data We Process");
+                    ui.heading(tr("gdpr.data"));
                     ui.add_space(4.0);
                     
                     egui::Frame::new()
@@ -111,26 +172,26 @@ impl SteamOverachieverApp {
                         .corner_radius(4.0)
                         .inner_margin(8.0)
                         .show(ui, |ui| {
-                            ui.label("• Your Steam ID (public identifier)");
-                            ui.label("• Your Steam display name");
-                            ui.label("• Your game library (via Steam API)");
-                            ui.label("• Achievement data for your games");
-                            ui.label("• Community ratings/tips you submit");
+                            ui.label(format!("• {}", tr("gdpr.data_steam_id")));
+                            ui.label(format!("• {}", tr("gdpr.data_name")));
+                            ui.label(format!("• {}", tr("gdpr.data_library")));
+                            ui.label(format!("• {}", tr("gdpr.data_achievements")));
+                            ui.label(format!("• {}", tr("gdpr.data_ratings")));
                         });
                     
                     ui.add_space(12.0);
                     
                     // Purpose section
-                    ui.heading("Purpose");
+                    ui.heading(tr("gdpr.purpose"));
                     ui.add_space(4.0);
-                    ui.label("Your personal game data stays local on your computer. Only community ratings and tips you choose to submit are synced to overachiever.space.");
+                    ui.label(tr("gdpr.purpose_text"));
                     
                     ui.add_space(12.0);
                     
                     // Third party section
-                    ui.heading("Third Parties");
+                    ui.heading(tr("gdpr.third_parties"));
                     ui.add_space(4.0);
-                    ui.label("We use the Steam Web API to fetch your public game and achievement data. No data is shared with other third parties.");
+                    ui.label(tr("gdpr.third_parties_text"));
                     
                     ui.add_space(16.0);
                     ui.separator();
@@ -139,9 +200,9 @@ impl SteamOverachieverApp {
                     // Show current status if already set
                     if self.config.gdpr_consent.is_set() {
                         let status = if self.config.gdpr_consent.is_accepted() {
-                            egui::RichText::new(format!("{} Currently: Accepted", regular::CHECK)).color(egui::Color32::GREEN)
+                            egui::RichText::new(format!("{} {}", regular::CHECK, tr("gdpr.accepted"))).color(egui::Color32::GREEN)
                         } else {
-                            egui::RichText::new(format!("{} Currently: Declined", regular::X)).color(egui::Color32::YELLOW)
+                            egui::RichText::new(format!("{} {}", regular::X, tr("gdpr.declined"))).color(egui::Color32::YELLOW)
                         };
                         ui.label(status);
                         ui.add_space(8.0);
@@ -150,8 +211,8 @@ impl SteamOverachieverApp {
                     // Buttons
                     ui.horizontal(|ui| {
                         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                            if ui.button(format!("{} Accept", regular::CHECK))
-                                .on_hover_text("Accept data processing and continue")
+                            if ui.button(format!("{} {}", regular::CHECK, tr("gdpr.accept")))
+                                .on_hover_text(tr("gdpr.accept_tooltip"))
                                 .clicked() 
                             {
                                 self.config.gdpr_consent = GdprConsent::Accepted;
@@ -159,8 +220,8 @@ impl SteamOverachieverApp {
                                 self.show_gdpr_dialog = false;
                             }
                             
-                            if ui.button(format!("{} Decline", regular::X))
-                                .on_hover_text("Decline - server features will be disabled")
+                            if ui.button(format!("{} {}", regular::X, tr("gdpr.decline")))
+                                .on_hover_text(tr("gdpr.decline_tooltip"))
                                 .clicked() 
                             {
                                 self.config.gdpr_consent = GdprConsent::Declined;
@@ -170,8 +231,8 @@ impl SteamOverachieverApp {
                             
                             // Close button if already set (reviewing settings)
                             if self.config.gdpr_consent.is_set() {
-                                if ui.button(format!("{} Close", regular::X_CIRCLE))
-                                    .on_hover_text("Close without changes")
+                                if ui.button(format!("{} {}", regular::X_CIRCLE, tr("gdpr.close")))
+                                    .on_hover_text(tr("gdpr.close_tooltip"))
                                     .clicked() 
                                 {
                                     self.show_gdpr_dialog = false;
@@ -185,3 +246,37 @@ impl SteamOverachieverApp {
             });
     }
 }
+
+impl SteamOverachieverApp {
+    /// Render celebration popup for milestones reached during this session
+    pub(crate) fn render_milestone_popup(&mut self, ctx: &egui::Context) {
+        if self.new_milestones.is_empty() {
+            return;
+        }
+
+        let accent = crate::app::panels::top::theme::theme_colors(&self.config).accent;
+        let mut dismissed = false;
+
+        egui::Window::new(format!("{} {}", regular::CONFETTI, tr("milestone.title")))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.add_space(8.0);
+                for milestone in &self.new_milestones {
+                    ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new(milestone.kind.label()).color(accent).strong());
+                        ui.label(egui::RichText::new(&milestone.detail).weak());
+                    });
+                }
+                ui.add_space(12.0);
+                if ui.button(tr("milestone.dismiss")).clicked() {
+                    dismissed = true;
+                }
+            });
+
+        if dismissed {
+            self.new_milestones.clear();
+        }
+    }
+}
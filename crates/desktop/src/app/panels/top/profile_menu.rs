@@ -3,17 +3,30 @@
 use crate::app::SteamOverachieverApp;
 use eframe::egui;
 use egui_phosphor::regular;
-use overachiever_core::{render_tag_search, TagSearchState};
+use overachiever_core::{render_tag_search, tr, tr_args, TagSearchState};
 
 impl SteamOverachieverApp {
     pub(in crate::app) fn render_profile_menu(&mut self, ctx: &egui::Context) {
         if !self.show_profile_menu {
+            // Read again the next time the menu opens
+            self.local_profiles = None;
             return;
         }
+        if self.local_profiles.is_none() {
+            self.local_profiles = Some(Vec::new());
+            self.db_query(
+                |conn| crate::db::get_local_profiles(conn),
+                |app, profiles| match profiles {
+                    Ok(profiles) => app.local_profiles = Some(profiles),
+                    Err(e) => eprintln!("Failed to load local profiles: {}", e),
+                },
+            );
+        }
+        let profiles = self.local_profiles.clone().unwrap_or_default();
 
         let mut keep_open = true;
 
-        egui::Window::new(format!("{} Profile", regular::USER))
+        egui::Window::new(format!("{} {}", regular::USER, tr("profile.title")))
             .collapsible(false)
             .resizable(true)
             .default_width(500.0)
@@ -22,12 +35,23 @@ impl SteamOverachieverApp {
                 ui.vertical(|ui| {
                     ui.add_space(4.0);
 
+                    // Persona name and avatar, once the credentials were checked
+                    if let Some((persona_name, avatar_url)) = self.steam_persona.clone() {
+                        ui.horizontal(|ui| {
+                            if let Some(avatar_url) = &avatar_url {
+                                ui.add(egui::Image::new(self.steam_avatar_source(ui.ctx(), avatar_url)).fit_to_exact_size(egui::vec2(48.0, 48.0)));
+                            }
+                            ui.heading(persona_name);
+                        });
+                        ui.add_space(8.0);
+                    }
+
                     // 1. Steam ID (read-only)
                     ui.horizontal(|ui| {
-                        ui.label("Steam ID:");
+                        ui.label(tr("settings.steam_id"));
                         ui.add_space(8.0);
                         let steam_id_display = if self.config.steam_id.is_empty() {
-                            "Not set".to_string()
+                            tr("profile.not_set").to_string()
                         } else {
                             self.config.steam_id.clone()
                         };
@@ -65,7 +89,7 @@ impl SteamOverachieverApp {
                             .frame(false);
                         
                         if ui.add(image_button)
-                            .on_hover_text("Link your account to enable cloud sync")
+                            .on_hover_text(tr("profile.link"))
                             .clicked()
                         {
                             self.start_cloud_link();
@@ -73,14 +97,14 @@ impl SteamOverachieverApp {
                     } else {
                         // Logged in - show profile link and cloud sync buttons
                         if let Some(short_id) = self.config.get_short_id() {
-                            let profile_url = format!("https://overachiever.space/{}", short_id);
+                            let profile_url = format!("{}/{}", crate::http::server_url(), short_id);
 
                             ui.horizontal(|ui| {
-                                ui.label("Your profile:");
-                                if ui.button(format!("{} Copy Link", regular::COPY)).on_hover_text(&profile_url).clicked() {
+                                ui.label(tr("profile.your_profile"));
+                                if ui.button(format!("{} {}", regular::COPY, tr("profile.copy_link"))).on_hover_text(&profile_url).clicked() {
                                     ui.ctx().copy_text(profile_url.clone());
                                 }
-                                if ui.button(format!("{} Open", regular::ARROW_SQUARE_OUT)).on_hover_text(&profile_url).clicked() {
+                                if ui.button(format!("{} {}", regular::ARROW_SQUARE_OUT, tr("profile.open"))).on_hover_text(&profile_url).clicked() {
                                     let _ = open::that(&profile_url);
                                 }
                             });
@@ -90,24 +114,24 @@ impl SteamOverachieverApp {
 
                         // Cloud sync buttons
                         if ui
-                            .add_enabled(!is_busy, egui::Button::new(format!("{} Publish online", regular::CLOUD_ARROW_UP)))
-                            .on_hover_text("Upload your local data to overachiever.space")
+                            .add_enabled(!is_busy, egui::Button::new(format!("{} {}", regular::CLOUD_ARROW_UP, tr("profile.upload"))))
+                            .on_hover_text(tr("profile.upload_tooltip"))
                             .clicked()
                         {
                             self.pending_cloud_action = Some(crate::app::CloudAction::Upload);
                         }
 
                         if ui
-                            .add_enabled(!is_busy, egui::Button::new(format!("{} Import from cloud", regular::CLOUD_ARROW_DOWN)))
-                            .on_hover_text("Download data from overachiever.space (backup if removed locally)")
+                            .add_enabled(!is_busy, egui::Button::new(format!("{} {}", regular::CLOUD_ARROW_DOWN, tr("profile.download"))))
+                            .on_hover_text(tr("profile.download_tooltip"))
                             .clicked()
                         {
                             self.pending_cloud_action = Some(crate::app::CloudAction::Download);
                         }
 
                         if ui
-                            .add_enabled(!is_busy, egui::Button::new(format!("{} Erase online data", regular::TRASH)))
-                            .on_hover_text("Remove your data from overachiever.space")
+                            .add_enabled(!is_busy, egui::Button::new(format!("{} {}", regular::TRASH, tr("profile.delete"))))
+                            .on_hover_text(tr("profile.delete_tooltip"))
                             .clicked()
                         {
                             self.pending_cloud_action = Some(crate::app::CloudAction::Delete);
@@ -116,8 +140,8 @@ impl SteamOverachieverApp {
                         ui.add_space(4.0);
 
                         if ui
-                            .add_enabled(!is_busy, egui::Button::new(format!("{} Logout", regular::SIGN_OUT)))
-                            .on_hover_text("Unlink your account from cloud sync")
+                            .add_enabled(!is_busy, egui::Button::new(format!("{} {}", regular::SIGN_OUT, tr("profile.logout"))))
+                            .on_hover_text(tr("profile.logout_tooltip"))
                             .clicked()
                         {
                             self.unlink_cloud();
@@ -130,7 +154,7 @@ impl SteamOverachieverApp {
                         crate::cloud_sync::CloudSyncState::Linking => {
                             ui.horizontal(|ui| {
                                 ui.spinner();
-                                ui.label("Waiting for Steam login... (check your browser)");
+                                ui.label(tr("profile.linking"));
                             });
                         }
                         crate::cloud_sync::CloudSyncState::Uploading(progress) => {
@@ -138,9 +162,9 @@ impl SteamOverachieverApp {
                                 ui.spinner();
                                 if progress.total_bytes > 0 {
                                     let mb_total = progress.total_bytes as f64 / (1024.0 * 1024.0);
-                                    ui.label(format!("Uploading {:.2} MB...", mb_total));
+                                    ui.label(tr_args("profile.uploading", &[("mb", &format!("{:.2}", mb_total))]));
                                 } else {
-                                    ui.label("Preparing upload...");
+                                    ui.label(tr("profile.preparing_upload"));
                                 }
                             });
                             if progress.total_bytes > 0 {
@@ -151,13 +175,13 @@ impl SteamOverachieverApp {
                         crate::cloud_sync::CloudSyncState::Downloading => {
                             ui.horizontal(|ui| {
                                 ui.spinner();
-                                ui.label("Downloading...");
+                                ui.label(tr("profile.downloading"));
                             });
                         }
                         crate::cloud_sync::CloudSyncState::Deleting => {
                             ui.horizontal(|ui| {
                                 ui.spinner();
-                                ui.label("Deleting...");
+                                ui.label(tr("profile.deleting"));
                             });
                         }
                         crate::cloud_sync::CloudSyncState::Success(msg) => {
@@ -173,12 +197,87 @@ impl SteamOverachieverApp {
                     ui.separator();
                     ui.add_space(8.0);
 
+                    // Other local profiles, opened side by side in their own windows
+                    let mut open_profile = None;
+                    ui.collapsing(format!("{} {}", regular::USERS, tr("profile.other_profiles")), |ui| {
+                        let others: Vec<_> = profiles.iter().filter(|(id, _)| *id != self.config.steam_id).cloned().collect();
+                        if others.is_empty() {
+                            ui.label(egui::RichText::new(tr("profile.no_other_profiles")).weak());
+                        }
+                        for (steam_id, display_name) in others {
+                            ui.horizontal(|ui| {
+                                ui.label(display_name.as_deref().unwrap_or(&steam_id));
+                                if ui.small_button(format!("{} {}", regular::ARROW_SQUARE_OUT, tr("profile.open_window"))).clicked() {
+                                    open_profile = Some((steam_id.clone(), display_name.clone()));
+                                }
+                            });
+                        }
+                        ui.horizontal(|ui| {
+                            ui.add(
+                                egui::TextEdit::singleline(&mut self.profile_window_input)
+                                    .hint_text(tr("profile.steam_id_hint"))
+                                    .desired_width(180.0),
+                            );
+                            let steam_id = self.profile_window_input.trim().to_string();
+                            if ui.add_enabled(!steam_id.is_empty(), egui::Button::new(tr("profile.open"))).clicked() {
+                                open_profile = Some((steam_id, None));
+                            }
+                        });
+                    });
+                    if let Some((steam_id, display_name)) = open_profile {
+                        self.open_profile_window(&steam_id, display_name);
+                    }
+
+                    // Several local profiles (e.g. a Steam family) merged into one library
+                    let mut open_merged = None;
+                    ui.collapsing(format!("{} {}", regular::USERS_THREE, tr("profile.merged")), |ui| {
+                        if profiles.len() < 2 {
+                            ui.label(egui::RichText::new(tr("profile.merged_needs_two")).weak());
+                            return;
+                        }
+                        for (steam_id, display_name) in &profiles {
+                            let mut selected = self.merge_selection.contains(steam_id);
+                            if ui.checkbox(&mut selected, display_name.as_deref().unwrap_or(steam_id)).changed() {
+                                if selected {
+                                    self.merge_selection.insert(steam_id.clone());
+                                } else {
+                                    self.merge_selection.remove(steam_id);
+                                }
+                            }
+                        }
+                        ui.checkbox(&mut self.merge_dedupe, tr("profile.merged_dedupe"));
+                        let selected: Vec<_> = profiles.into_iter().filter(|(id, _)| self.merge_selection.contains(id)).collect();
+                        if ui
+                            .add_enabled(selected.len() >= 2, egui::Button::new(format!("{} {}", regular::ARROW_SQUARE_OUT, tr("profile.merged_open"))))
+                            .clicked()
+                        {
+                            open_merged = Some(selected);
+                        }
+                    });
+                    if let Some(profiles) = open_merged {
+                        self.open_merged_window(profiles, self.merge_dedupe);
+                    }
+
+                    ui.add_space(4.0);
+                    let mut mini_window = self.config.mini_window;
+                    if ui
+                        .toggle_value(&mut mini_window, format!("{} {}", regular::PICTURE_IN_PICTURE, tr("profile.mini_window")))
+                        .on_hover_text(tr("profile.mini_window_tooltip"))
+                        .clicked()
+                    {
+                        self.toggle_mini_window();
+                    }
+
+                    ui.add_space(8.0);
+                    ui.separator();
+                    ui.add_space(8.0);
+
                     // 3. Private Games section
-                    ui.heading("Private Games");
+                    ui.heading(tr("profile.private_games"));
 
                     if ui
-                        .checkbox(&mut self.config.hide_private_games, "Hide private games")
-                        .on_hover_text("Hide games marked as private in Steam from the games table and online profile")
+                        .checkbox(&mut self.config.hide_private_games, tr("profile.hide_private"))
+                        .on_hover_text(tr("profile.hide_private_tooltip"))
                         .changed()
                     {
                         let _ = self.config.save();
@@ -189,30 +288,23 @@ impl SteamOverachieverApp {
                     // Show counts
                     let hidden_count = self.games.iter().filter(|g| g.hidden).count();
                     let private_count = self.games.iter().filter(|g| g.steam_private).count();
-                    ui.label(format!("Hidden from library: {} games", hidden_count));
+                    ui.label(tr_args("profile.hidden_count", &[("count", &hidden_count)]));
                     ui.add_space(4.0);
 
                     ui.horizontal(|ui| {
                         // Left column: Import button and info
                         ui.vertical(|ui| {
-                            ui.label("Private games in Steam:");
-                            if ui.button(format!("{} Import Private Games from Steam", regular::DOWNLOAD_SIMPLE)).clicked() {
-                                if let Ok(conn) = crate::db::open_connection() {
-                                    match crate::steam_config::sync_steam_hidden_games(&conn, &self.config.steam_id) {
-                                        Ok(count) => {
-                                            self.status = format!("Imported {} private games from Steam", count);
-                                            // Reload games from database
-                                            if let Ok(games) = crate::db::get_all_games(&conn, &self.config.steam_id) {
-                                                self.games = games;
-                                                self.sort_games();
-                                            }
-                                        }
-                                        Err(e) => {
-                                            self.status = format!("Failed to import Steam private games: {}", e);
-                                            eprintln!("Failed to import Steam private games: {}", e);
-                                        }
-                                    }
-                                }
+                            ui.label(tr("profile.steam_private"));
+                            let syncing = self.steam_visibility_receiver.is_some();
+                            let button = ui.add_enabled(
+                                !syncing,
+                                egui::Button::new(format!("{} {}", regular::DOWNLOAD_SIMPLE, tr("profile.import_private"))),
+                            );
+                            if button
+                                .on_hover_text(tr("profile.import_private_tooltip"))
+                                .clicked()
+                            {
+                                self.sync_steam_visibility();
                             }
                         });
 
@@ -220,7 +312,7 @@ impl SteamOverachieverApp {
 
                         // Right column: List of private games
                         ui.vertical(|ui| {
-                            ui.label(format!("{} private games", private_count));
+                            ui.label(tr_args("profile.private_count", &[("count", &private_count)]));
                             let private_games: Vec<_> = self.games.iter()
                                 .filter(|g| g.steam_private)
                                 .map(|g| g.name.clone())
@@ -241,7 +333,7 @@ impl SteamOverachieverApp {
                     ui.add_space(8.0);
 
                     // 5. Hide games with tag section
-                    ui.heading("Hide games with tag:");
+                    ui.heading(tr("profile.hide_tags"));
                     ui.add_space(4.0);
 
                     // Initialize tag search state if needed
@@ -250,6 +342,7 @@ impl SteamOverachieverApp {
                             search_text: String::new(),
                             popup_open: false,
                             selected_tags: self.hidden_tags.clone(),
+                            selected_index: None,
                         });
                     }
 
@@ -258,7 +351,7 @@ impl SteamOverachieverApp {
                             // Sync selected tags from persistent state
                             tag_state.selected_tags = self.hidden_tags.clone();
 
-                            let changed = render_tag_search(ui, "hidden_tags_search", tag_state, &self.available_tags, "Search tags to hide...", false);
+                            let changed = render_tag_search(ui, "hidden_tags_search", tag_state, &self.available_tags, tr("profile.hide_tags_hint"), false);
 
                             if changed {
                                 // Update persistent hidden tags
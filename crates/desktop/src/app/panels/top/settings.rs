@@ -2,8 +2,10 @@
 
 use eframe::egui;
 use egui_phosphor::regular;
+use overachiever_core::{tr, tr_args, SortTieBreaker};
 
 use super::fonts::apply_font_settings;
+use super::theme::apply_theme_settings;
 use crate::app::SteamOverachieverApp;
 
 impl SteamOverachieverApp {
@@ -12,7 +14,7 @@ impl SteamOverachieverApp {
 
         let mut show_settings = self.show_settings;
 
-        egui::Window::new(format!("{} Settings", regular::GEAR))
+        egui::Window::new(format!("{} {}", regular::GEAR, tr("settings.title")))
             .open(&mut show_settings)
             .resizable(false)
             .collapsible(false)
@@ -21,14 +23,16 @@ impl SteamOverachieverApp {
             .show(ctx, |ui| {
                 // Tab bar
                 ui.horizontal(|ui| {
-                    ui.selectable_value(&mut self.settings_tab, SettingsTab::General, format!("{} General", regular::SLIDERS));
-                    ui.selectable_value(&mut self.settings_tab, SettingsTab::Steam, format!("{} Steam", regular::STEAM_LOGO));
-                    ui.selectable_value(&mut self.settings_tab, SettingsTab::Debug, format!("{} Debug", regular::BUG));
+                    ui.selectable_value(&mut self.settings_tab, SettingsTab::General, format!("{} {}", regular::SLIDERS, tr("settings.tab_general")));
+                    ui.selectable_value(&mut self.settings_tab, SettingsTab::Steam, format!("{} {}", regular::STEAM_LOGO, tr("settings.tab_steam")));
+                    ui.selectable_value(&mut self.settings_tab, SettingsTab::Debug, format!("{} {}", regular::BUG, tr("settings.tab_debug")));
                 });
 
                 ui.separator();
                 ui.add_space(8.0);
 
+                self.render_config_issues(ui);
+
                 match self.settings_tab {
                     SettingsTab::General => self.render_settings_general_tab(ui, ctx),
                     SettingsTab::Steam => self.render_settings_steam_tab(ui),
@@ -40,30 +44,70 @@ impl SteamOverachieverApp {
 
         // Render cloud action confirmation dialog
         self.render_cloud_confirm_dialog(ctx);
+        self.render_cloud_import_dialog(ctx);
+    }
+
+    /// Config load errors and invalid values, shown above every settings tab
+    fn render_config_issues(&mut self, ui: &mut egui::Ui) {
+        let issues = self.config.validate();
+        if issues.is_empty() && self.config.load_error.is_none() {
+            return;
+        }
+        let warning = ui.visuals().warn_fg_color;
+        let mut dismiss = false;
+        egui::Frame::group(ui.style()).stroke(egui::Stroke::new(1.0, warning)).show(ui, |ui| {
+            ui.set_width(ui.available_width());
+            if let Some(error) = &self.config.load_error {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(regular::WARNING).color(warning));
+                    ui.label(error);
+                });
+                if ui.small_button(tr("common.dismiss")).clicked() {
+                    dismiss = true;
+                }
+            }
+            for issue in &issues {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(regular::WARNING).color(warning));
+                    ui.label(egui::RichText::new(format!("{}:", issue.field)).strong());
+                    ui.label(&issue.message);
+                });
+            }
+        });
+        if dismiss {
+            self.config.load_error = None;
+        }
+        ui.add_space(8.0);
     }
 
     fn render_settings_general_tab(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
         use crate::config::FontSource;
 
-        ui.heading("Appearance");
+        self.render_language_settings(ui);
+
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(8.0);
+
+        ui.heading(tr("settings.appearance"));
         ui.add_space(8.0);
 
         // Font source selection (radio buttons)
-        ui.label("Font Source:");
+        ui.label(tr("settings.font_source"));
         ui.add_space(4.0);
 
         let mut font_source_changed = false;
 
         ui.horizontal(|ui| {
-            if ui.radio(self.config.font_source == FontSource::BuiltIn, "Built-in (egui default)").clicked() {
+            if ui.radio(self.config.font_source == FontSource::BuiltIn, tr("settings.font_builtin")).clicked() {
                 self.config.font_source = FontSource::BuiltIn;
                 font_source_changed = true;
             }
-            if ui.radio(self.config.font_source == FontSource::Cjk, "CJK (Source Han Sans)").clicked() {
+            if ui.radio(self.config.font_source == FontSource::Cjk, tr("settings.font_cjk")).clicked() {
                 self.config.font_source = FontSource::Cjk;
                 font_source_changed = true;
             }
-            if ui.radio(self.config.font_source == FontSource::System, "Windows Font").clicked() {
+            if ui.radio(self.config.font_source == FontSource::System, tr("settings.font_system")).clicked() {
                 self.config.font_source = FontSource::System;
                 font_source_changed = true;
             }
@@ -78,15 +122,15 @@ impl SteamOverachieverApp {
 
             if !is_downloaded && !is_downloading {
                 ui.horizontal(|ui| {
-                    ui.colored_label(egui::Color32::YELLOW, format!("{} Font not downloaded", regular::WARNING));
+                    ui.colored_label(egui::Color32::YELLOW, format!("{} {}", regular::WARNING, tr("settings.font_not_downloaded")));
                 });
                 ui.add_space(4.0);
-                if ui.button(format!("{} Download Source Han Sans (~100 MB)", regular::DOWNLOAD_SIMPLE)).clicked() {
+                if ui.button(format!("{} {}", regular::DOWNLOAD_SIMPLE, tr("settings.font_download"))).clicked() {
                     self.start_cjk_font_download();
                 }
                 ui.add_space(4.0);
                 ui.hyperlink_to(
-                    format!("{} View License", regular::LINK),
+                    format!("{} {}", regular::LINK, tr("settings.font_license")),
                     crate::cjk_font::get_license_url()
                 );
             } else if is_downloading {
@@ -95,7 +139,7 @@ impl SteamOverachieverApp {
                         crate::cjk_font::DownloadProgress::Starting => {
                             ui.horizontal(|ui| {
                                 ui.spinner();
-                                ui.label("Starting download...");
+                                ui.label(tr("settings.font_starting_download"));
                             });
                         }
                         crate::cjk_font::DownloadProgress::Downloading { bytes_downloaded, total_bytes } => {
@@ -105,10 +149,10 @@ impl SteamOverachieverApp {
                                     if let Some(total) = total_bytes {
                                         let mb_downloaded = *bytes_downloaded as f64 / (1024.0 * 1024.0);
                                         let mb_total = *total as f64 / (1024.0 * 1024.0);
-                                        ui.label(format!("Downloading: {:.1} / {:.1} MB", mb_downloaded, mb_total));
+                                        ui.label(tr_args("settings.font_downloading_of", &[("done", &format!("{:.1}", mb_downloaded)), ("total", &format!("{:.1}", mb_total))]));
                                     } else {
                                         let mb_downloaded = *bytes_downloaded as f64 / (1024.0 * 1024.0);
-                                        ui.label(format!("Downloading: {:.1} MB", mb_downloaded));
+                                        ui.label(tr_args("settings.font_downloading", &[("done", &format!("{:.1}", mb_downloaded))]));
                                     }
                                 });
                                 
@@ -129,11 +173,11 @@ impl SteamOverachieverApp {
                         crate::cjk_font::DownloadProgress::Extracting => {
                             ui.horizontal(|ui| {
                                 ui.spinner();
-                                ui.label("Extracting font...");
+                                ui.label(tr("settings.font_extracting"));
                             });
                         }
                         crate::cjk_font::DownloadProgress::Complete => {
-                            ui.colored_label(egui::Color32::GREEN, format!("{} Download complete!", regular::CHECK));
+                            ui.colored_label(egui::Color32::GREEN, format!("{} {}", regular::CHECK, tr("settings.font_download_complete")));
                             // Clear the progress after showing for a bit
                             if self.cjk_font_download_receiver.is_none() {
                                 self.cjk_font_download_progress = None;
@@ -141,12 +185,12 @@ impl SteamOverachieverApp {
                             }
                         }
                         crate::cjk_font::DownloadProgress::Error(e) => {
-                            ui.colored_label(egui::Color32::RED, format!("{} Download failed: {}", regular::WARNING, e));
+                            ui.colored_label(egui::Color32::RED, format!("{} {}", regular::WARNING, tr_args("settings.font_download_failed", &[("error", e)])));
                         }
                     }
                 }
             } else {
-                ui.colored_label(egui::Color32::GREEN, format!("{} Font downloaded", regular::CHECK));
+                ui.colored_label(egui::Color32::GREEN, format!("{} {}", regular::CHECK, tr("settings.font_downloaded")));
             }
 
             ui.add_space(8.0);
@@ -154,7 +198,7 @@ impl SteamOverachieverApp {
             // Show weight selector if font is downloaded
             if is_downloaded {
                 ui.horizontal(|ui| {
-                    ui.label("Font Weight:");
+                    ui.label(tr("settings.font_weight"));
                     ui.add_space(16.0);
                     
                     use crate::config::CjkFontWeight;
@@ -183,10 +227,10 @@ impl SteamOverachieverApp {
             }
 
             ui.horizontal(|ui| {
-                ui.label("Font:");
+                ui.label(tr("settings.font"));
                 ui.add_space(16.0);
 
-                let current_font = self.config.system_font_name.clone().unwrap_or_else(|| "Select...".to_string());
+                let current_font = self.config.system_font_name.clone().unwrap_or_else(|| tr("settings.font_select").to_string());
 
                 egui::ComboBox::from_id_salt("system_font_name")
                     .selected_text(&current_font)
@@ -210,7 +254,7 @@ impl SteamOverachieverApp {
 
         // Font size with pending value (only applied on Save)
         ui.horizontal(|ui| {
-            ui.label("Font Size:");
+            ui.label(tr("settings.font_size"));
             ui.add(egui::DragValue::new(&mut self.pending_font_size).range(8.0..=32.0).speed(0.5).suffix(" pt"));
         });
 
@@ -220,7 +264,7 @@ impl SteamOverachieverApp {
         let size_changed = (self.pending_font_size - self.config.font_size).abs() > 0.01;
         ui.horizontal(|ui| {
             if ui
-                .add_enabled(size_changed, egui::Button::new(format!("{} Save Font Size", regular::FLOPPY_DISK)))
+                .add_enabled(size_changed, egui::Button::new(format!("{} {}", regular::FLOPPY_DISK, tr("settings.font_size_save"))))
                 .clicked()
             {
                 self.config.font_size = self.pending_font_size;
@@ -228,7 +272,7 @@ impl SteamOverachieverApp {
                 self.fonts_need_update = true;
             }
             if size_changed {
-                ui.label(egui::RichText::new("(unsaved)").color(egui::Color32::YELLOW).small());
+                ui.label(egui::RichText::new(tr("settings.unsaved")).color(egui::Color32::YELLOW).small());
             }
         });
 
@@ -238,101 +282,1244 @@ impl SteamOverachieverApp {
             apply_font_settings(ctx, &self.config);
             let _ = self.config.save();
         }
+
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(8.0);
+
+        self.render_theme_settings(ui, ctx);
+
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(8.0);
+
+        self.render_time_settings(ui);
+
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(8.0);
+
+        self.render_startup_settings(ui);
+
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(8.0);
+
+        self.render_notification_settings(ui);
+
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(8.0);
+
+        self.render_statistics_settings(ui);
+
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(8.0);
+
+        self.render_table_settings(ui);
+
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(8.0);
+
+        self.render_overlay_settings(ui);
+
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(8.0);
+
+        self.render_cloud_sync_settings(ui);
+
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(8.0);
+
+        self.render_presence_settings(ui);
+
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(8.0);
+
+        self.render_icon_cache_settings(ui);
+
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(8.0);
+
+        self.render_data_dir_settings(ui);
+
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(8.0);
+
+        self.render_library_file_settings(ui);
     }
 
-    fn render_settings_steam_tab(&mut self, ui: &mut egui::Ui) {
-        ui.heading("Steam Credentials");
+    fn render_language_settings(&mut self, ui: &mut egui::Ui) {
+        use overachiever_core::{set_language, Language};
+
+        ui.heading(tr("settings.language"));
         ui.add_space(8.0);
 
         ui.horizontal(|ui| {
-            ui.label("Steam ID:");
-            ui.add_space(20.0);
-            if ui
-                .add(
-                    egui::TextEdit::singleline(&mut self.config.steam_id)
-                        .desired_width(180.0)
-                        .hint_text("12345678901234567"),
-                )
-                .changed()
-            {
+            ui.label(tr("settings.language_label"));
+            ui.add_space(8.0);
+            egui::ComboBox::from_id_salt("ui_language")
+                .selected_text(self.config.language.native_name())
+                .width(180.0)
+                .show_ui(ui, |ui| {
+                    for language in Language::all() {
+                        if ui.selectable_label(self.config.language == *language, language.native_name()).clicked() {
+                            // Takes effect on the next frame, no restart needed
+                            self.config.language = *language;
+                            set_language(*language);
+                            let _ = self.config.save();
+                        }
+                    }
+                });
+        });
+    }
+
+    fn render_startup_settings(&mut self, ui: &mut egui::Ui) {
+        use crate::config::StartupView;
+
+        ui.heading(tr("settings.startup"));
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            ui.label(tr("settings.startup_view"));
+            ui.add_space(8.0);
+            egui::ComboBox::from_id_salt("startup_view")
+                .selected_text(self.config.startup_view.display_name())
+                .width(180.0)
+                .show_ui(ui, |ui| {
+                    for view in StartupView::all() {
+                        if ui.selectable_label(self.config.startup_view == *view, view.display_name()).clicked() {
+                            self.config.startup_view = *view;
+                            let _ = self.config.save();
+                        }
+                    }
+                });
+        });
+
+        // Stats and Log live in the sidebar, and last-used restores the sidebar as it was
+        let sidebar_forced = matches!(
+            self.config.startup_view,
+            StartupView::Stats | StartupView::Log | StartupView::LastUsed
+        );
+        ui.add_space(4.0);
+        ui.add_enabled_ui(!sidebar_forced, |ui| {
+            if ui.checkbox(&mut self.config.startup_sidebar_expanded, tr("settings.startup_sidebar")).changed() {
+                let _ = self.config.save();
+            }
+        });
+
+        ui.add_space(4.0);
+        ui.horizontal(|ui| {
+            if ui.checkbox(&mut self.config.check_for_updates, tr("settings.check_for_updates")).changed() {
                 let _ = self.config.save();
+                if self.config.check_for_updates {
+                    self.start_update_check();
+                } else {
+                    self.latest_release = None;
+                }
+            }
+            if ui.button(tr("settings.show_changelog")).clicked() {
+                self.open_changelog();
             }
         });
+        ui.label(
+            egui::RichText::new(tr_args("settings.current_version", &[("version", &crate::changelog::CURRENT_VERSION)]))
+                .small()
+                .weak(),
+        );
+    }
+
+    fn render_notification_settings(&mut self, ui: &mut egui::Ui) {
+        ui.heading(tr("settings.notifications"));
+        ui.add_space(8.0);
+
+        if ui.checkbox(&mut self.config.streak_reminder, tr("settings.streak_reminder")).changed() {
+            let _ = self.config.save();
+        }
+        ui.add_enabled_ui(self.config.streak_reminder, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(tr("settings.streak_reminder_from"));
+                if ui.add(egui::Slider::new(&mut self.config.streak_reminder_hour, 12..=23).suffix(":00")).changed() {
+                    let _ = self.config.save();
+                }
+            });
+        });
+    }
 
+    fn render_statistics_settings(&mut self, ui: &mut egui::Ui) {
+        ui.heading(tr("settings.statistics"));
         ui.add_space(8.0);
 
+        let non_games = self.games.iter().filter(|g| !g.is_game_app()).count();
+        if ui.checkbox(&mut self.config.exclude_non_games_from_stats, tr("settings.exclude_non_games"))
+            .on_hover_text(tr_args("settings.exclude_non_games_tooltip", &[("count", &non_games)]))
+            .changed()
+        {
+            let _ = self.config.save();
+            self.refresh_achievement_records(false);
+        }
+
+        let unowned = self.games.iter().filter(|g| !g.owned).count();
+        if ui.checkbox(&mut self.config.include_unowned_in_stats, tr("settings.include_unowned"))
+            .on_hover_text(tr_args("settings.include_unowned_tooltip", &[("count", &unowned)]))
+            .changed()
+        {
+            let _ = self.config.save();
+            self.refresh_achievement_records(false);
+        }
+
         ui.horizontal(|ui| {
-            ui.label("API Key:");
-            ui.add_space(28.0);
+            ui.label(tr("settings.snapshot_interval"));
             if ui
-                .add(
-                    egui::TextEdit::singleline(&mut self.config.steam_web_api_key)
-                        .desired_width(180.0)
-                        .password(true)
-                        .hint_text("Your Steam API key"),
-                )
+                .add(egui::DragValue::new(&mut self.config.snapshot_interval_days).range(0..=90).suffix(tr("settings.days_suffix")))
+                .on_hover_text(tr("settings.snapshot_interval_tooltip"))
                 .changed()
             {
                 let _ = self.config.save();
+                self.snapshot_last_check = None;
             }
         });
+    }
 
+    fn render_table_settings(&mut self, ui: &mut egui::Ui) {
+        ui.heading(tr("settings.games_table"));
         ui.add_space(8.0);
 
+        let mut tie_breaker = self.config.sort_tie_breaker;
         ui.horizontal(|ui| {
-            ui.hyperlink_to(format!("{} Get API Key", regular::LINK), "https://steamcommunity.com/dev/apikey");
-            ui.label(egui::RichText::new("(No affiliation)").color(egui::Color32::GRAY));
-        });
-
-        ui.horizontal(|ui| {
-            ui.hyperlink_to(format!("{} Figure out Steam ID", regular::LINK), "https://steamid.io");
-            ui.label(egui::RichText::new("(No affiliation)").color(egui::Color32::GRAY));
+            ui.label(tr("settings.sort_ties"))
+                .on_hover_text(tr("settings.sort_ties_tooltip"));
+            egui::ComboBox::from_id_salt("sort_tie_breaker")
+                .selected_text(tie_breaker.label())
+                .show_ui(ui, |ui| {
+                    for option in SortTieBreaker::all() {
+                        ui.selectable_value(&mut tie_breaker, *option, option.label());
+                    }
+                });
         });
+        if tie_breaker != self.config.sort_tie_breaker {
+            self.config.sort_tie_breaker = tie_breaker;
+            let _ = self.config.save();
+            self.sort_games();
+        }
 
-        ui.add_space(12.0);
+        if ui.checkbox(&mut self.config.show_deck_playtime_column, tr("settings.deck_column"))
+            .on_hover_text(tr("settings.deck_column_tooltip"))
+            .changed()
+        {
+            let _ = self.config.save();
+        }
 
-        // Validation status
-        if !self.config.is_valid() {
-            ui.colored_label(egui::Color32::YELLOW, format!("{} Steam ID and API Key are required", regular::WARNING));
-        } else {
-            ui.colored_label(egui::Color32::GREEN, format!("{} Configuration valid", regular::CHECK));
+        if ui.checkbox(&mut self.config.reveal_spoilers, tr("settings.reveal_spoilers"))
+            .on_hover_text(tr("settings.reveal_spoilers_tooltip"))
+            .changed()
+        {
+            let _ = self.config.save();
         }
     }
 
-    fn render_settings_debug_tab(&mut self, ui: &mut egui::Ui) {
-        ui.heading(format!("{} Debug", regular::BUG));
+    fn render_overlay_settings(&mut self, ui: &mut egui::Ui) {
+        ui.heading(tr("settings.stream_overlay"));
         ui.add_space(8.0);
 
-        if ui
-            .checkbox(&mut self.config.debug_recently_played, "Log recently played response")
-            .on_hover_text("When running Update, write the recently played API response to recently_played_debug.txt")
+        if ui.checkbox(&mut self.config.overlay_server_enabled, tr("settings.overlay_enabled"))
+            .on_hover_text(tr("settings.overlay_enabled_tooltip"))
             .changed()
         {
+            self.overlay_server_error = None;
             let _ = self.config.save();
         }
+        ui.add_enabled_ui(self.config.overlay_server_enabled, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(tr("settings.overlay_port"));
+                let response = ui.add(egui::DragValue::new(&mut self.config.overlay_server_port).range(1024..=65535));
+                // Restart on the new port once editing is done
+                if response.drag_stopped() || response.lost_focus() {
+                    self.overlay_server_error = None;
+                    let _ = self.config.save();
+                }
+            });
+        });
 
-        ui.add_space(12.0);
-        ui.separator();
-        ui.add_space(12.0);
+        if let Some(error) = &self.overlay_server_error {
+            ui.colored_label(egui::Color32::RED, format!("{} {}", regular::WARNING, error));
+        } else if let Some(server) = &self.overlay_server {
+            let url = server.url();
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(&url).monospace());
+                if ui.small_button(regular::COPY).on_hover_text(tr("settings.copy_url")).clicked() {
+                    ui.ctx().copy_text(url.clone());
+                }
+            });
+        }
+    }
 
-        ui.label("Configuration Files:");
-        ui.add_space(4.0);
+    fn render_cloud_sync_settings(&mut self, ui: &mut egui::Ui) {
+        ui.heading(tr("settings.cloud_sync"));
+        ui.add_space(8.0);
 
-        ui.horizontal(|ui| {
-            if ui.button("Open Config File").clicked() {
-                let config_path = crate::config::Config::get_config_file_path();
-                if let Err(e) = open::that(&config_path) {
-                    eprintln!("Failed to open config file: {}", e);
+        let linked = self.config.cloud_token.is_some();
+        ui.add_enabled_ui(linked, |ui| {
+            if ui.checkbox(&mut self.config.auto_upload_after_scan, tr("settings.auto_upload"))
+                .on_hover_text(tr("settings.auto_upload_tooltip"))
+                .changed()
+            {
+                if !self.config.auto_upload_after_scan {
+                    self.auto_upload.clear();
                 }
+                let _ = self.config.save();
             }
+        });
+        if !linked {
+            ui.label(egui::RichText::new(tr("settings.link_first")).weak());
+        } else if let Some(status) = self.auto_upload.describe() {
+            ui.label(egui::RichText::new(status).small().weak());
+        }
+    }
 
-            if ui.button("Open Config Directory").clicked() {
-                if let Some(config_dir) = crate::config::Config::get_config_dir() {
-                    if let Err(e) = open::that(&config_dir) {
-                        eprintln!("Failed to open config directory: {}", e);
+    fn render_presence_settings(&mut self, ui: &mut egui::Ui) {
+        ui.heading(tr("settings.presence"));
+        ui.add_space(8.0);
+
+        let linked = self.config.cloud_token.is_some();
+        ui.add_enabled_ui(linked, |ui| {
+            if ui.checkbox(&mut self.config.share_presence, tr("settings.share_presence"))
+                .on_hover_text(tr("settings.share_presence_tooltip"))
+                .changed()
+            {
+                let _ = self.config.save();
+            }
+        });
+        if !linked {
+            ui.label(egui::RichText::new(tr("settings.link_first")).weak());
+        } else if let Some(error) = self.presence_client.as_ref().and_then(|c| c.error()) {
+            ui.colored_label(egui::Color32::RED, format!("{} {}", regular::WARNING, error));
+        }
+    }
+
+    fn render_icon_cache_settings(&mut self, ui: &mut egui::Ui) {
+        ui.heading(tr("settings.icon_cache"));
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            ui.label(tr("settings.icon_cache_max"));
+            let response = ui.add(
+                egui::DragValue::new(&mut self.config.icon_cache_max_mb)
+                    .range(16..=4096)
+                    .speed(4.0)
+                    .suffix(" MB")
+            );
+            // Apply once dragging/typing is done - shrinking the budget deletes files
+            if response.drag_stopped() || response.lost_focus() {
+                self.icon_cache.set_max_mb(self.config.icon_cache_max_mb);
+                let _ = self.config.save();
+            }
+        });
+        ui.label(
+            egui::RichText::new(tr_args(
+                "settings.icon_cache_usage",
+                &[("mb", &format!("{:.1}", self.icon_cache.disk_usage() as f64 / (1024.0 * 1024.0)))],
+            ))
+            .small()
+            .weak(),
+        );
+
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            ui.label(tr("settings.icon_memory_max"));
+            let response = ui.add(
+                egui::Slider::new(&mut self.config.icon_memory_max_mb, 8..=1024)
+                    .logarithmic(true)
+                    .suffix(" MB"),
+            );
+            overachiever_core::instant_tooltip(&response, tr("settings.icon_memory_max_tooltip"));
+            if response.changed() {
+                self.icon_cache.set_max_memory_mb(self.config.icon_memory_max_mb);
+            }
+            if response.drag_stopped() || response.lost_focus() {
+                let _ = self.config.save();
+            }
+        });
+
+        ui.add_space(8.0);
+        self.render_icon_download(ui);
+    }
+
+    /// "Download all icons" button, or the progress of a running download
+    fn render_icon_download(&mut self, ui: &mut egui::Ui) {
+        if let Some(job) = self.icon_download.clone() {
+            let (done, failed) = job.progress();
+            let processed = done + failed;
+            if !job.is_finished() {
+                ui.horizontal(|ui| {
+                    let fraction = if job.total > 0 { processed as f32 / job.total as f32 } else { 1.0 };
+                    let text = if job.is_paused() {
+                        tr_args("settings.icons_paused", &[("reason", &self.scan_pause_reason())])
+                    } else {
+                        format!("{} / {}", processed, job.total)
+                    };
+                    ui.add(egui::ProgressBar::new(fraction).desired_width(240.0).text(text));
+                    if ui.add_enabled(!job.is_cancelled(), egui::Button::new(tr("common.cancel"))).clicked() {
+                        job.cancel();
+                    }
+                });
+                return;
+            }
+            let mut summary = tr_args("settings.icons_downloaded", &[("count", &done), ("cached", &job.already_cached)]);
+            if failed > 0 {
+                summary.push_str(&tr_args("settings.icons_failed", &[("count", &failed)]));
+            }
+            if job.budget_reached() {
+                summary.push_str(tr("settings.icons_budget_reached"));
+            } else if processed < job.total {
+                summary.push_str(tr("settings.icons_stopped"));
+            }
+            ui.label(egui::RichText::new(summary).small().weak());
+        }
+
+        // The icons would not fit into the cache: download until the limit, or raise it first
+        if let Some(plan) = &self.icon_download_plan {
+            const MB: f64 = 1024.0 * 1024.0;
+            ui.colored_label(
+                egui::Color32::from_rgb(255, 200, 100),
+                format!(
+                    "{} {}",
+                    regular::WARNING,
+                    tr_args(
+                        "settings.icons_over_budget",
+                        &[
+                            ("count", &plan.len()),
+                            ("mb", &format!("{:.0}", plan.estimated_bytes() as f64 / MB)),
+                            ("free", &format!("{:.0}", self.icon_cache.free_bytes() as f64 / MB)),
+                        ],
+                    )
+                ),
+            );
+            ui.horizontal(|ui| {
+                if ui.button(tr("settings.icons_download_to_limit")).clicked() {
+                    if let Some(plan) = self.icon_download_plan.take() {
+                        self.icon_download = Some(self.icon_cache.download_all(plan));
                     }
                 }
+                if ui.button(tr("common.cancel")).clicked() {
+                    self.icon_download_plan = None;
+                }
+            });
+            return;
+        }
+
+        let button = ui.button(format!("{} {}", regular::DOWNLOAD_SIMPLE, tr("settings.download_all_icons")))
+            .on_hover_text(tr("settings.download_all_icons_tooltip"));
+        if button.clicked() {
+            let steam_id = self.config.steam_id.clone();
+            self.db_query(
+                move |conn| crate::db::get_all_icon_urls(conn, &steam_id),
+                |app, urls| match urls {
+                    Ok(urls) => {
+                        let plan = app.icon_cache.plan_download(urls);
+                        if plan.estimated_bytes() > app.icon_cache.free_bytes() {
+                            app.icon_download_plan = Some(plan);
+                        } else {
+                            app.icon_download = Some(app.icon_cache.download_all(plan));
+                        }
+                    }
+                    Err(e) => eprintln!("Failed to list icons: {}", e),
+                },
+            );
+        }
+    }
+
+    fn render_data_dir_settings(&mut self, ui: &mut egui::Ui) {
+        use crate::data_dir::{self, DataDirMode};
+
+        ui.heading(tr("settings.data_location"));
+        ui.add_space(8.0);
+
+        let mode = data_dir::data_dir_mode();
+        let current = data_dir::data_dir();
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new(current.display().to_string()).monospace());
+            if ui.small_button(regular::FOLDER_OPEN).on_hover_text(tr("settings.open_data_dir")).clicked() {
+                if let Err(e) = open::that(&current) {
+                    eprintln!("Failed to open data directory: {}", e);
+                }
             }
         });
+        ui.label(
+            egui::RichText::new(match mode {
+                DataDirMode::Default => tr("settings.data_dir_default"),
+                DataDirMode::Portable => tr("settings.data_dir_portable"),
+                DataDirMode::Custom => tr("settings.data_dir_custom"),
+            })
+            .small()
+            .weak(),
+        );
+        ui.add_space(4.0);
+
+        if self.data_dir_restart_needed {
+            ui.colored_label(
+                egui::Color32::from_rgb(255, 200, 100),
+                format!("{} {}", regular::INFO, tr("settings.data_dir_restart")),
+            );
+            return;
+        }
+
+        // A scan writes to the database all along, those writes would miss the copy
+        let idle = !self.state.is_busy();
+        let busy_hint = tr("settings.data_dir_busy");
+
+        if mode == DataDirMode::Portable {
+            if ui.add_enabled(idle, egui::Button::new(tr("settings.portable_off")))
+                .on_hover_text(tr("settings.portable_off_tooltip"))
+                .on_disabled_hover_text(busy_hint)
+                .clicked()
+            {
+                match data_dir::disable_portable_mode() {
+                    Ok(()) => self.restart_for_data_dir(ui.ctx()),
+                    Err(e) => self.status = e,
+                }
+            }
+            return;
+        }
+
+        if ui.add_enabled(idle, egui::Button::new(tr("settings.portable_on")))
+            .on_hover_text(tr("settings.portable_on_tooltip"))
+            .on_disabled_hover_text(busy_hint)
+            .clicked()
+        {
+            match data_dir::enable_portable_mode() {
+                Ok(()) => self.restart_for_data_dir(ui.ctx()),
+                Err(e) => self.status = e,
+            }
+        }
+
+        ui.horizontal(|ui| {
+            ui.label(tr("settings.relocate_to"));
+            ui.add(egui::TextEdit::singleline(&mut self.data_dir_input).hint_text(tr("settings.relocate_hint")).desired_width(240.0));
+            let target = self.data_dir_input.trim();
+            if ui.add_enabled(idle && !target.is_empty(), egui::Button::new(tr("settings.relocate")))
+                .on_hover_text(tr("settings.relocate_tooltip"))
+                .on_disabled_hover_text(busy_hint)
+                .clicked()
+            {
+                match data_dir::relocate_data_dir(std::path::Path::new(target)) {
+                    Ok(()) => self.restart_for_data_dir(ui.ctx()),
+                    Err(e) => self.status = e,
+                }
+            }
+        });
+    }
+
+    /// Restart right after the data was copied, so nothing more goes to the old directory
+    fn restart_for_data_dir(&mut self, ctx: &egui::Context) {
+        match crate::data_dir::restart() {
+            Ok(()) => ctx.send_viewport_cmd(egui::ViewportCommand::Close),
+            Err(e) => {
+                // The user has to restart by hand
+                self.status = e;
+                self.data_dir_restart_needed = true;
+            }
+        }
+    }
+
+    fn render_library_file_settings(&mut self, ui: &mut egui::Ui) {
+        ui.heading(tr("settings.library_file"));
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            if ui
+                .button(format!("{} {}", regular::EXPORT, tr("settings.export_library")))
+                .on_hover_text(tr_args("settings.export_library_tooltip", &[("ext", &crate::library_file::EXTENSION)]))
+                .clicked()
+            {
+                self.export_library_file();
+            }
+        });
+        ui.label(
+            egui::RichText::new(tr_args("settings.import_library_hint", &[("ext", &crate::library_file::EXTENSION)]))
+            .small()
+            .weak(),
+        );
+    }
+
+    fn render_time_settings(&mut self, ui: &mut egui::Ui) {
+        use overachiever_core::{time_zone_names, DisplayTimeZone, TimestampStyle};
+
+        ui.heading(tr("settings.dates_times"));
+        ui.add_space(8.0);
+
+        let mut zone_changed = false;
+        let mut changed = false;
+        let time = &mut self.config.time_display;
+
+        ui.horizontal(|ui| {
+            ui.label(tr("settings.time_zone"));
+            ui.add_space(16.0);
+            egui::ComboBox::from_id_salt("time_display_zone")
+                .selected_text(time.timezone.label())
+                .width(220.0)
+                .height(300.0)
+                .show_ui(ui, |ui| {
+                    for zone in [DisplayTimeZone::Utc, DisplayTimeZone::Local] {
+                        if ui.selectable_label(time.timezone == zone, zone.label()).clicked() {
+                            time.timezone = zone;
+                            zone_changed = true;
+                        }
+                    }
+                    ui.separator();
+                    for name in time_zone_names() {
+                        let selected = matches!(&time.timezone, DisplayTimeZone::Named(n) if n == name);
+                        if ui.selectable_label(selected, name).clicked() {
+                            time.timezone = DisplayTimeZone::Named(name.to_string());
+                            zone_changed = true;
+                        }
+                    }
+                });
+        });
+
+        ui.add_space(8.0);
+
+        let style_label = |style: TimestampStyle| match style {
+            TimestampStyle::Absolute => tr("settings.timestamp_absolute"),
+            TimestampStyle::Relative => tr("settings.timestamp_relative"),
+        };
+        for (label, id, style) in [
+            (tr("settings.timestamps_log"), "time_display_log_style", &mut time.log_style),
+            (tr("settings.timestamps_achievements"), "time_display_achievement_style", &mut time.achievement_style),
+        ] {
+            ui.horizontal(|ui| {
+                ui.label(label);
+                ui.add_space(8.0);
+                egui::ComboBox::from_id_salt(id)
+                    .selected_text(style_label(*style))
+                    .width(180.0)
+                    .show_ui(ui, |ui| {
+                        for option in [TimestampStyle::Absolute, TimestampStyle::Relative] {
+                            if ui.selectable_label(*style == option, style_label(option)).clicked() {
+                                *style = option;
+                                changed = true;
+                            }
+                        }
+                    });
+            });
+        }
+
+        ui.add_space(4.0);
+        if ui.checkbox(&mut time.show_time_of_day, tr("settings.show_time_of_day")).changed() {
+            changed = true;
+        }
+
+        if zone_changed || changed {
+            let _ = self.config.save();
+        }
+        // Streaks and busiest day depend on which calendar day unlocks fall on
+        if zone_changed {
+            self.refresh_achievement_records(false);
+        }
+    }
+
+    fn render_theme_settings(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
+        use crate::config::ThemeMode;
+
+        let mut theme_changed = false;
+
+        ui.horizontal(|ui| {
+            ui.label(tr("settings.theme"));
+            ui.add_space(16.0);
+            egui::ComboBox::from_id_salt("theme_mode")
+                .selected_text(self.config.theme_mode.display_name())
+                .width(150.0)
+                .show_ui(ui, |ui| {
+                    for mode in ThemeMode::all() {
+                        if ui.selectable_label(self.config.theme_mode == *mode, mode.display_name()).clicked() {
+                            self.config.theme_mode = *mode;
+                            theme_changed = true;
+                        }
+                    }
+                });
+        });
+
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            ui.label(tr("settings.accent_color"));
+            let mut accent = super::theme::theme_colors(&self.config).accent;
+            let mut rgb = [accent.r(), accent.g(), accent.b()];
+            if ui.color_edit_button_srgb(&mut rgb).changed() {
+                accent = egui::Color32::from_rgb(rgb[0], rgb[1], rgb[2]);
+                self.config.accent_color = Some([accent.r(), accent.g(), accent.b()]);
+                theme_changed = true;
+            }
+            if self.config.accent_color.is_some() && ui.small_button(format!("{} {}", regular::ARROW_COUNTER_CLOCKWISE, tr("settings.reset"))).clicked() {
+                self.config.accent_color = None;
+                theme_changed = true;
+            }
+        });
+
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            ui.label(tr("settings.complete_threshold"));
+            let response = ui.add(egui::Slider::new(&mut self.config.complete_threshold, 50.0..=100.0).step_by(1.0).suffix("%"));
+            overachiever_core::instant_tooltip(&response, tr("settings.complete_threshold_tooltip"));
+            if response.changed() {
+                // Keep the near-complete band below done
+                self.config.near_complete_threshold = self.config.near_complete_threshold.min(self.config.complete_threshold - 1.0);
+                self.final_stretch_min_percent = self.config.near_complete_threshold;
+                theme_changed = true;
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label(tr("settings.near_complete_threshold"));
+            let max = (self.config.complete_threshold - 1.0).max(1.0);
+            let response = ui.add(egui::Slider::new(&mut self.config.near_complete_threshold, 1.0..=max).step_by(1.0).suffix("%"));
+            overachiever_core::instant_tooltip(&response, tr("settings.near_complete_threshold_tooltip"));
+            if response.changed() {
+                self.final_stretch_min_percent = self.config.near_complete_threshold;
+                theme_changed = true;
+            }
+        });
+
+        // Live preview of the bands with the colors the % column will use
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new(tr("settings.preview")).weak());
+            let colors = super::theme::theme_colors(&self.config);
+            let near = colors.near_complete_threshold;
+            let done = colors.complete_threshold;
+            // Values just below and at each threshold (ascending, so dedup drops repeats)
+            let mut samples = vec![0.0, (near / 2.0).floor(), near - 1.0, near, done - 1.0, done, 100.0];
+            samples.retain(|percent| *percent >= 0.0);
+            samples.dedup();
+            for percent in samples {
+                ui.label(egui::RichText::new(format!("{:.0}%", percent)).color(colors.completion_color(percent)));
+            }
+        });
+
+        if theme_changed {
+            apply_theme_settings(ctx, &self.config);
+            let _ = self.config.save();
+        }
+    }
+
+    /// Save an edited credentials field, and check the credentials once editing is done
+    fn credentials_field_edited(&mut self, response: &egui::Response) {
+        if response.changed() {
+            self.credentials_edited = true;
+            let _ = self.config.save();
+        }
+        if response.lost_focus() && self.credentials_edited {
+            self.credentials_edited = false;
+            // Pasted values often carry a trailing space or newline
+            self.config.steam_id = self.config.steam_id.trim().to_string();
+            self.config.steam_web_api_key = self.config.steam_web_api_key.trim().to_string();
+            let _ = self.config.save();
+            self.start_credentials_check();
+        }
+    }
+
+    /// Result of the last credentials check: the resolved account, or what is wrong
+    fn render_credentials_check(&mut self, ui: &mut egui::Ui) {
+        if self.credentials_check_receiver.is_some() {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(tr("settings.credentials_checking"));
+            });
+            return;
+        }
+        match &self.credentials_check {
+            Some(Ok(summary)) => {
+                ui.horizontal(|ui| {
+                    if let Some(avatar_url) = &summary.avatar_url {
+                        ui.add(egui::Image::new(self.steam_avatar_source(ui.ctx(), avatar_url)).fit_to_exact_size(egui::vec2(32.0, 32.0)));
+                    }
+                    ui.label(egui::RichText::new(format!("{} {}", regular::CHECK_CIRCLE, summary.persona_name)).color(egui::Color32::from_rgb(110, 200, 110)));
+                });
+                if !summary.public_profile {
+                    ui.label(
+                        egui::RichText::new(format!("{} {}", regular::WARNING, tr("settings.profile_not_public")))
+                        .color(egui::Color32::from_rgb(255, 200, 60)),
+                    );
+                }
+            }
+            Some(Err(e)) => {
+                ui.label(egui::RichText::new(format!("{} {}", regular::X_CIRCLE, e)).color(egui::Color32::from_rgb(230, 100, 100)));
+            }
+            None => {
+                if self.config.has_steam_credentials() && ui.small_button(tr("settings.credentials_check")).clicked() {
+                    self.start_credentials_check();
+                }
+            }
+        }
+    }
+
+    fn render_settings_steam_tab(&mut self, ui: &mut egui::Ui) {
+        ui.heading(tr("settings.steam_credentials"));
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            ui.label(tr("settings.steam_id"));
+            ui.add_space(20.0);
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.config.steam_id)
+                    .desired_width(180.0)
+                    .hint_text("12345678901234567"),
+            );
+            overachiever_core::instant_tooltip(&response, tr("settings.steam_id_tooltip"));
+            self.credentials_field_edited(&response);
+        });
+
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            ui.label(tr("settings.api_key"));
+            ui.add_space(28.0);
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.config.steam_web_api_key)
+                    .desired_width(180.0)
+                    .password(true)
+                    .hint_text(tr("settings.api_key_hint")),
+            );
+            self.credentials_field_edited(&response);
+        });
+
+        ui.add_space(4.0);
+        self.render_credentials_check(ui);
+
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            ui.hyperlink_to(format!("{} {}", regular::LINK, tr("settings.get_api_key")), "https://steamcommunity.com/dev/apikey");
+            ui.label(egui::RichText::new(tr("settings.no_affiliation")).color(egui::Color32::GRAY));
+        });
+
+        ui.horizontal(|ui| {
+            ui.hyperlink_to(format!("{} {}", regular::LINK, tr("settings.find_steam_id")), "https://steamid.io");
+            ui.label(egui::RichText::new(tr("settings.no_affiliation")).color(egui::Color32::GRAY));
+        });
+
+        ui.add_space(12.0);
+
+        ui.label(tr("settings.community_import_intro"));
+        ui.horizontal(|ui| {
+            let is_importing = self.community_import_receiver.is_some();
+            if ui
+                .add_enabled(!is_importing && self.config.steam_id_u64().is_some(), egui::Button::new(format!("{} {}", regular::DOWNLOAD_SIMPLE, tr("settings.community_import"))))
+                .on_hover_text(tr("settings.community_import_tooltip"))
+                .clicked()
+            {
+                self.start_community_import();
+            }
+            if is_importing {
+                ui.spinner();
+            }
+        });
+        ui.label(
+            egui::RichText::new(tr("settings.community_import_file"))
+                .small()
+                .weak(),
+        );
+
+        ui.add_space(12.0);
+
+        ui.label(tr("settings.rival_intro"));
+        ui.horizontal(|ui| {
+            if ui
+                .add(
+                    egui::TextEdit::singleline(&mut self.config.rival_steam_id)
+                        .desired_width(180.0)
+                        .hint_text(tr("settings.rival_hint")),
+                )
+                .changed()
+            {
+                let _ = self.config.save();
+                self.load_rival_games();
+            }
+            let is_scraping = self.rival_receiver.is_some();
+            if ui
+                .add_enabled(!is_scraping && !self.config.rival_steam_id.trim().is_empty(), egui::Button::new(format!("{} {}", regular::SWORD, tr("settings.rival_scrape"))))
+                .on_hover_text(tr("settings.rival_scrape_tooltip"))
+                .clicked()
+            {
+                self.start_rival_scrape();
+            }
+            if is_scraping {
+                ui.spinner();
+            }
+        });
+        if let Some(status) = &self.rival_status {
+            ui.label(egui::RichText::new(status).small().weak());
+        }
+
+        ui.add_space(12.0);
+
+        ui.horizontal(|ui| {
+            ui.label(tr("settings.session_tracking"));
+            let response = ui.add(
+                egui::DragValue::new(&mut self.config.session_poll_interval_secs)
+                    .range(0..=3600)
+                    .speed(5.0)
+                    .suffix(" s"),
+            );
+            overachiever_core::instant_tooltip(&response, tr("settings.session_tracking_tooltip"));
+            if response.changed() {
+                let _ = self.config.save();
+            }
+        });
+
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            use crate::config::PowerMode;
+
+            ui.label(tr("settings.power_mode"));
+            egui::ComboBox::from_id_salt("power_mode")
+                .selected_text(self.config.power_mode.display_name())
+                .width(180.0)
+                .show_ui(ui, |ui| {
+                    for mode in PowerMode::all() {
+                        if ui.selectable_label(self.config.power_mode == *mode, mode.display_name()).clicked() {
+                            self.config.power_mode = *mode;
+                            let _ = self.config.save();
+                        }
+                    }
+                });
+        });
+        ui.label(egui::RichText::new(tr_args("settings.power_currently", &[("status", &self.power_status.describe())])).small().weak());
+
+        let response = ui.checkbox(&mut self.config.pause_scans_while_playing, tr("settings.pause_while_playing"));
+        overachiever_core::instant_tooltip(&response, tr("settings.pause_while_playing_tooltip"));
+        if response.changed() {
+            let _ = self.config.save();
+        }
+        if self.config.pause_scans_while_playing && self.config.session_poll_interval_secs == 0 {
+            ui.label(egui::RichText::new(tr("settings.pause_needs_sessions")).small().weak());
+        }
+
+        let response = ui.checkbox(&mut self.config.local_unlock_watch, tr("settings.local_unlocks"));
+        overachiever_core::instant_tooltip(&response, tr("settings.local_unlocks_tooltip"));
+        if response.changed() {
+            let _ = self.config.save();
+        }
+        if self.config.local_unlock_watch {
+            if let Some(error) = &self.local_stats_error {
+                ui.colored_label(egui::Color32::YELLOW, format!("{} {}", regular::WARNING, error));
+            } else if self.local_stats_watcher.is_some() {
+                ui.label(egui::RichText::new(tr("settings.local_unlocks_hint")).small().weak());
+            }
+        }
+
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            ui.label(tr("settings.stale_after"));
+            if ui
+                .add(egui::DragValue::new(&mut self.config.stale_scrape_days).range(1..=365).suffix(tr("settings.days_suffix")))
+                .on_hover_text(tr("settings.stale_after_tooltip"))
+                .changed()
+            {
+                let _ = self.config.save();
+            }
+        });
+
+        ui.add_space(12.0);
+        ui.separator();
+        self.render_network_settings(ui);
+        ui.add_space(12.0);
+
+        // Validation status
+        if !self.config.is_valid() {
+            ui.colored_label(egui::Color32::YELLOW, format!("{} {}", regular::WARNING, tr("settings.credentials_required")));
+        } else {
+            ui.colored_label(egui::Color32::GREEN, format!("{} {}", regular::CHECK, tr("settings.credentials_valid")));
+        }
+    }
+
+    fn render_network_settings(&mut self, ui: &mut egui::Ui) {
+        ui.heading(tr("settings.network"));
+        ui.add_space(8.0);
+
+        let mut changed = false;
+        egui::Grid::new("network_settings").num_columns(2).spacing([8.0, 6.0]).show(ui, |ui| {
+            ui.label(tr("settings.proxy"));
+            changed |= ui
+                .add(
+                    egui::TextEdit::singleline(&mut self.config.proxy_url)
+                        .desired_width(260.0)
+                        .hint_text("socks5://127.0.0.1:1080"),
+                )
+                .on_hover_text(tr("settings.proxy_tooltip"))
+                .changed();
+            ui.end_row();
+
+            ui.label(tr("settings.steam_api_url"));
+            changed |= ui
+                .add(
+                    egui::TextEdit::singleline(&mut self.config.steam_api_url)
+                        .desired_width(260.0)
+                        .hint_text(crate::http::DEFAULT_STEAM_API_URL),
+                )
+                .changed();
+            ui.end_row();
+
+            ui.label(tr("settings.server_url"));
+            changed |= ui
+                .add(
+                    egui::TextEdit::singleline(&mut self.config.server_url)
+                        .desired_width(260.0)
+                        .hint_text(crate::http::DEFAULT_SERVER_URL),
+                )
+                .on_hover_text(tr("settings.server_url_tooltip"))
+                .changed();
+            ui.end_row();
+        });
+
+        if changed {
+            let _ = self.config.save();
+            crate::http::configure(&self.config);
+        }
+    }
+
+    fn render_settings_debug_tab(&mut self, ui: &mut egui::Ui) {
+        ui.heading(format!("{} {}", regular::BUG, tr("settings.debug")));
+        ui.add_space(8.0);
+
+        if ui
+            .checkbox(&mut self.config.debug_recently_played, tr("settings.debug_recently_played"))
+            .on_hover_text(tr("settings.debug_recently_played_tooltip"))
+            .changed()
+        {
+            let _ = self.config.save();
+        }
+
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(12.0);
+
+        ui.label(tr("settings.config_files"));
+        ui.add_space(4.0);
+
+        ui.horizontal(|ui| {
+            if ui.button(tr("settings.open_config_file")).clicked() {
+                let config_path = crate::config::Config::get_config_file_path();
+                if let Err(e) = open::that(&config_path) {
+                    eprintln!("Failed to open config file: {}", e);
+                }
+            }
+
+            if ui.button(tr("settings.open_config_dir")).clicked() {
+                if let Some(config_dir) = crate::config::Config::get_config_dir() {
+                    if let Err(e) = open::that(&config_dir) {
+                        eprintln!("Failed to open config directory: {}", e);
+                    }
+                }
+            }
+        });
+
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(12.0);
+
+        ui.label(tr("settings.bug_reports"));
+        ui.add_space(4.0);
+
+        if ui
+            .button(format!("{} {}", regular::DATABASE, tr("settings.export_anonymized")))
+            .on_hover_text(tr("settings.export_anonymized_tooltip"))
+            .clicked()
+        {
+            self.export_anonymized_database();
+        }
+
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(12.0);
+
+        self.render_backup_settings(ui);
+
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(12.0);
+
+        ui.label(tr("settings.performance"));
+        ui.add_space(4.0);
+
+        ui.horizontal(|ui| {
+            if ui
+                .button(format!("{} {}", regular::GAUGE, tr("settings.run_benchmark")))
+                .on_hover_text(tr("settings.run_benchmark_tooltip"))
+                .clicked()
+            {
+                self.run_benchmark();
+            }
+            if let Some(report) = &self.benchmark_report {
+                if ui.button(format!("{} {}", regular::COPY, tr("settings.copy_report"))).clicked() {
+                    ui.ctx().copy_text(report.clone());
+                }
+            }
+        });
+        if let Some(report) = &self.benchmark_report {
+            ui.add_space(4.0);
+            ui.label(egui::RichText::new(report).monospace().small());
+        }
+
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(12.0);
+
+        self.render_icon_memory_stats(ui);
+    }
+
+    /// Icon cache numbers: in-memory LRU and disk usage
+    fn render_icon_memory_stats(&self, ui: &mut egui::Ui) {
+        const MB: f64 = 1024.0 * 1024.0;
+        let memory = self.icon_cache.memory_stats();
+        let (disk_hits, disk_misses) = self.icon_cache.hit_stats();
+
+        ui.label(tr("settings.icon_cache_stats"));
+        ui.add_space(4.0);
+        egui::Grid::new("icon_memory_stats").num_columns(2).spacing([12.0, 2.0]).show(ui, |ui| {
+            ui.label(tr("settings.icons_in_memory"));
+            ui.label(tr_args(
+                "settings.icons_in_memory_value",
+                &[
+                    ("count", &memory.icons),
+                    ("mb", &format!("{:.1}", memory.bytes as f64 / MB)),
+                    ("max", &format!("{:.0}", memory.max_bytes as f64 / MB)),
+                ],
+            ));
+            ui.end_row();
+            ui.label(tr("settings.icons_memory_hits"));
+            ui.label(format!("{} / {}", memory.hits, memory.loads));
+            ui.end_row();
+            ui.label(tr("settings.icons_evicted"));
+            ui.label(memory.evictions.to_string());
+            ui.end_row();
+            ui.label(tr("settings.icons_on_disk"));
+            ui.label(format!("{:.1} MB", self.icon_cache.disk_usage() as f64 / MB));
+            ui.end_row();
+            ui.label(tr("settings.icons_disk_hits"));
+            ui.label(format!("{} / {}", disk_hits, disk_misses));
+            ui.end_row();
+        });
+    }
+
+    /// Database backups: how many to keep, manual backup, restore picker
+    fn render_backup_settings(&mut self, ui: &mut egui::Ui) {
+        ui.label(tr("settings.backups"));
+        ui.add_space(4.0);
+
+        ui.horizontal(|ui| {
+            ui.label(tr("settings.backups_keep"));
+            if ui
+                .add(egui::DragValue::new(&mut self.config.backups_to_keep).range(1..=100))
+                .on_hover_text(tr("settings.backups_keep_tooltip"))
+                .changed()
+            {
+                let _ = self.config.save();
+            }
+            ui.label(tr("settings.backups_copies"));
+            if ui.button(format!("{} {}", regular::FLOPPY_DISK, tr("settings.backup_now"))).clicked() {
+                self.status = match crate::backup::backup_database("manual") {
+                    Ok(Some(path)) => format!("Database backed up to {}", path.display()),
+                    Ok(None) => "No database to back up yet".to_string(),
+                    Err(e) => e,
+                };
+            }
+            if ui.button(format!("{} {}", regular::FOLDER_OPEN, tr("settings.open_backups"))).clicked() {
+                let dir = crate::backup::backup_dir();
+                let _ = std::fs::create_dir_all(&dir);
+                if let Err(e) = open::that(&dir) {
+                    eprintln!("Failed to open backups folder: {}", e);
+                }
+            }
+        });
+
+        let backups = crate::backup::list_backups();
+        if backups.is_empty() {
+            ui.label(egui::RichText::new(tr("settings.no_backups")).weak());
+            return;
+        }
+        let describe = |backup: &crate::backup::BackupFile| {
+            format!(
+                "{} - {} ({:.1} MB)",
+                backup.created.format("%Y-%m-%d %H:%M:%S"),
+                backup.reason,
+                backup.size as f64 / (1024.0 * 1024.0)
+            )
+        };
+        if self.selected_backup.as_ref().is_none_or(|path| !backups.iter().any(|b| b.path == *path)) {
+            self.selected_backup = Some(backups[0].path.clone());
+            self.confirm_restore = false;
+        }
+
+        ui.horizontal(|ui| {
+            let selected_text = backups
+                .iter()
+                .find(|b| Some(&b.path) == self.selected_backup.as_ref())
+                .map(describe)
+                .unwrap_or_default();
+            egui::ComboBox::from_id_salt("restore_backup")
+                .selected_text(selected_text)
+                .width(320.0)
+                .show_ui(ui, |ui| {
+                    for backup in &backups {
+                        let selected = Some(&backup.path) == self.selected_backup.as_ref();
+                        if ui.selectable_label(selected, describe(backup)).clicked() {
+                            self.selected_backup = Some(backup.path.clone());
+                            self.confirm_restore = false;
+                        }
+                    }
+                });
+            if ui.button(format!("{} {}", regular::CLOCK_COUNTER_CLOCKWISE, tr("settings.restore_backup"))).clicked() {
+                self.confirm_restore = true;
+            }
+        });
+
+        if self.confirm_restore {
+            ui.horizontal(|ui| {
+                ui.colored_label(
+                    egui::Color32::YELLOW,
+                    format!("{} {}", regular::WARNING, tr("settings.restore_confirm")),
+                );
+                if ui.button(tr("settings.restore")).clicked() {
+                    if let Some(path) = self.selected_backup.clone() {
+                        self.restore_database_backup(&path);
+                    }
+                    self.confirm_restore = false;
+                }
+                if ui.button(tr("common.cancel")).clicked() {
+                    self.confirm_restore = false;
+                }
+            });
+        }
+    }
+
+    /// Write an anonymized database copy to the Downloads folder and reveal it
+    fn export_anonymized_database(&mut self) {
+        let dir = directories::UserDirs::new()
+            .and_then(|dirs| dirs.download_dir().map(|d| d.to_path_buf()))
+            .unwrap_or_else(|| std::path::PathBuf::from("."));
+        let dest = dir.join(format!("overachiever_anonymized_{}.db", chrono::Local::now().format("%Y%m%d_%H%M%S")));
+
+        let target = dest.clone();
+        self.db_query(
+            move |conn| crate::db::export_anonymized(conn, &target),
+            move |app, result| match result {
+                Ok(profiles) => {
+                    app.status = format!("Exported anonymized database ({} profiles) to {}", profiles, dest.display());
+                    let _ = open::that(&dir);
+                }
+                Err(e) => app.status = format!("Failed to export anonymized database: {}", e),
+            },
+        );
     }
 }
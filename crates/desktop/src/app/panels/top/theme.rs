@@ -0,0 +1,37 @@
+//! Theme settings application
+
+use eframe::egui;
+use overachiever_core::ThemeColors;
+
+use crate::config::{Config, ThemeMode};
+
+/// Build the shared panel colors from the config
+pub fn theme_colors(config: &Config) -> ThemeColors {
+    let mut colors = match config.theme_mode {
+        ThemeMode::Dark => ThemeColors::dark(),
+        ThemeMode::Light => ThemeColors::light(),
+    };
+    if let Some([r, g, b]) = config.accent_color {
+        colors.accent = egui::Color32::from_rgb(r, g, b);
+    }
+    colors.complete_threshold = config.complete_threshold.clamp(0.0, 100.0);
+    // The near-complete band sits below done, a hand-edited config may have them crossed
+    colors.near_complete_threshold = config.near_complete_threshold.clamp(0.0, colors.complete_threshold);
+    colors
+}
+
+/// Apply theme settings (dark/light visuals + accent overrides) to the egui context
+pub fn apply_theme_settings(ctx: &egui::Context, config: &Config) {
+    let colors = theme_colors(config);
+
+    let mut visuals = match config.theme_mode {
+        ThemeMode::Dark => egui::Visuals::dark(),
+        ThemeMode::Light => egui::Visuals::light(),
+    };
+    visuals.hyperlink_color = colors.accent;
+    visuals.selection.stroke = egui::Stroke::new(1.0, colors.accent);
+    visuals.selection.bg_fill = colors.accent_alpha(if visuals.dark_mode { 90 } else { 120 });
+
+    // Apply to both egui themes so the OS light/dark preference doesn't override ours
+    ctx.all_styles_mut(|style| style.visuals = visuals.clone());
+}
@@ -2,7 +2,7 @@
 
 use eframe::egui;
 use egui_phosphor::regular;
-use overachiever_core::ENABLE_ADMIN_MODE;
+use overachiever_core::{tr, tr_args, StatsPanelPlatform, ENABLE_ADMIN_MODE};
 
 use crate::ui::AppState;
 use crate::app::SteamOverachieverApp;
@@ -18,28 +18,22 @@ impl SteamOverachieverApp {
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
             ui.horizontal(|ui| {
                 let heading = ui.heading("Overachiever");
-                heading.on_hover_text(format!(
-                    "Build #{}\n{}",
-                    BUILD_NUMBER,
-                    BUILD_DATETIME
-                ));
+                heading.on_hover_text(tr_args("toolbar.build", &[("number", &BUILD_NUMBER), ("date", &BUILD_DATETIME)]));
                 ui.separator();
                 
-                // Update button - for recently played games
-                let update_button = egui::Button::new(format!("{} Update", regular::ARROWS_CLOCKWISE));
+                // Update button - for recently played games and games whose playtime changed
+                let update_button = egui::Button::new(format!("{} {}", regular::ARROWS_CLOCKWISE, tr("toolbar.update")));
                 let update_response = ui.add_enabled(!is_busy && self.config.is_valid(), update_button);
                 
-                // Show warning if update is stale
-                if self.is_update_stale() && !is_busy {
-                    update_response.clone().on_hover_text(
-                        "⚠️ Last update was more than 2 weeks ago.\nThe recently played API only shows games from the last 2 weeks.\nConsider running a Full Scan instead."
-                    );
+                if self.config.is_valid() && !is_busy {
+                    let last_update = self.last_update_time
+                        .map(|t| overachiever_core::format_relative(t, chrono::Utc::now()))
+                        .unwrap_or_else(|| tr("toolbar.never").to_string());
+                    update_response.clone().on_hover_text(tr_args("toolbar.update_tooltip", &[("when", &last_update)]));
                 }
                 
                 if !self.config.is_valid() {
-                    update_response.clone().on_hover_text(
-                        "Please configure Steam API Key and Steam ID in Settings (⚙)"
-                    );
+                    update_response.clone().on_hover_text(tr("toolbar.configure_first"));
                 }
                 
                 if update_response.clicked() {
@@ -49,16 +43,37 @@ impl SteamOverachieverApp {
                 // Full Scan button - scrapes achievements for all games not yet scraped
                 let needs_scrape = self.games_needing_scrape();
                 let full_scan_label = if needs_scrape > 0 {
-                    format!("{} Full Scan ({})", regular::GAME_CONTROLLER, needs_scrape)
+                    format!("{} {} ({})", regular::GAME_CONTROLLER, tr("toolbar.full_scan"), needs_scrape)
                 } else {
-                    format!("{} Full Scan", regular::GAME_CONTROLLER)
+                    format!("{} {}", regular::GAME_CONTROLLER, tr("toolbar.full_scan"))
                 };
                 let can_scan = (needs_scrape > 0 || self.force_full_scan) && self.config.is_valid();
                 if ui.add_enabled(!is_busy && can_scan, egui::Button::new(full_scan_label)).clicked() {
                     self.start_scrape();
                 }
                 
-                ui.checkbox(&mut self.force_full_scan, "Force");
+                ui.checkbox(&mut self.force_full_scan, tr("toolbar.force"));
+
+                // Refresh stale button - re-scrapes games whose data is older than the threshold
+                let stale = self.games_with_stale_scrape();
+                if stale > 0
+                    && ui.add_enabled(!is_busy && self.config.is_valid(), egui::Button::new(format!("{} {}", regular::CLOCK_COUNTER_CLOCKWISE, tr_args("toolbar.refresh_stale", &[("count", &stale)]))))
+                        .on_hover_text(tr_args("toolbar.refresh_stale_tooltip", &[("days", &self.config.stale_scrape_days)]))
+                        .clicked()
+                {
+                    self.start_stale_refresh();
+                }
+
+                // Bulk personal TTB reports for games finished at 100%
+                let unreported = self.finished_games_without_ttb_report().len();
+                if unreported > 0
+                    && self.ttb_bulk_report.is_none()
+                    && ui.button(format!("{} {}", regular::TIMER, tr_args("toolbar.report_times", &[("count", &unreported)])))
+                        .on_hover_text(tr("toolbar.report_times_tooltip"))
+                        .clicked()
+                {
+                    self.start_ttb_bulk_report();
+                }
 
                 // TTB Scan button - only show if admin_mode is enabled
                 if self.admin_mode {
@@ -67,18 +82,18 @@ impl SteamOverachieverApp {
 
                     if is_ttb_scanning {
                         // Show stop button during scan
-                        if ui.button(format!("{} Stop TTB", regular::X_CIRCLE)).clicked() {
+                        if ui.button(format!("{} {}", regular::X_CIRCLE, tr("toolbar.stop_ttb"))).clicked() {
                             self.stop_ttb_scan();
                         }
                     } else {
                         let ttb_label = if needs_ttb > 0 {
-                            format!("{} TTB Scan ({})", regular::CLOCK, needs_ttb)
+                            format!("{} {}", regular::CLOCK, tr_args("toolbar.ttb_scan_count", &[("count", &needs_ttb)]))
                         } else {
-                            format!("{} TTB Scan", regular::CLOCK)
+                            format!("{} {}", regular::CLOCK, tr("toolbar.ttb_scan"))
                         };
                         let can_ttb = needs_ttb > 0 && self.config.is_valid();
                         if ui.add_enabled(!is_busy && can_ttb, egui::Button::new(ttb_label))
-                            .on_hover_text("Scan HowLongToBeat for game completion times (1 game/minute)")
+                            .on_hover_text(tr("toolbar.ttb_scan_tooltip"))
                             .clicked()
                         {
                             self.start_ttb_scan();
@@ -86,8 +101,8 @@ impl SteamOverachieverApp {
 
                         // Download all TTB from backend button
                         let is_ttb_downloading = self.ttb_batch_receiver.is_some();
-                        if ui.add_enabled(!is_busy && !is_ttb_downloading, egui::Button::new(format!("{} DL TTB", regular::CLOUD_ARROW_DOWN)))
-                            .on_hover_text("Download all TTB times from the server")
+                        if ui.add_enabled(!is_busy && !is_ttb_downloading, egui::Button::new(format!("{} {}", regular::CLOUD_ARROW_DOWN, tr("toolbar.ttb_download"))))
+                            .on_hover_text(tr("toolbar.ttb_download_tooltip"))
                             .clicked()
                         {
                             self.start_ttb_full_download();
@@ -100,17 +115,17 @@ impl SteamOverachieverApp {
 
                     if is_tags_scanning {
                         // Show stop button during scan
-                        if ui.button(format!("{} Stop Tags", regular::X_CIRCLE)).clicked() {
+                        if ui.button(format!("{} {}", regular::X_CIRCLE, tr("toolbar.stop_tags"))).clicked() {
                             self.stop_tags_scan();
                         }
                     } else {
                         let tags_label = if needs_tags > 0 {
-                            format!("{} Tags Scan ({})", regular::TAG, needs_tags)
+                            format!("{} {}", regular::TAG, tr_args("toolbar.tags_scan_count", &[("count", &needs_tags)]))
                         } else {
-                            format!("{} Tags Scan", regular::TAG)
+                            format!("{} {}", regular::TAG, tr("toolbar.tags_scan"))
                         };
                         let can_tags = needs_tags > 0 && self.config.is_valid();
-                        let tags_tooltip = format!("Fetch game tags from SteamSpy (1 game/{}s)", self.config.tags_scan_delay_secs);
+                        let tags_tooltip = tr_args("toolbar.tags_scan_tooltip", &[("secs", &self.config.tags_scan_delay_secs)]);
                         if ui.add_enabled(!is_busy && can_tags, egui::Button::new(tags_label))
                             .on_hover_text(tags_tooltip)
                             .clicked()
@@ -123,7 +138,7 @@ impl SteamOverachieverApp {
                 ui.separator();
 
                 // Reserve space for right-side buttons (settings, privacy, profile, admin)
-                let right_buttons_width = 180.0;
+                let right_buttons_width = 250.0;
                 let available_for_status = (ui.available_width() - right_buttons_width).max(100.0);
 
                 if is_busy {
@@ -133,19 +148,92 @@ impl SteamOverachieverApp {
                         .desired_width(available_for_status - 20.0) // 20px for spinner
                         .animate(true));
                 } else {
+                    if !self.single_game_refresh_queue.is_empty() {
+                        let queued: Vec<&str> = self
+                            .single_game_refresh_queue
+                            .iter()
+                            .map(|appid| {
+                                self.games.iter().find(|g| g.appid == *appid).map(|g| g.name.as_str()).unwrap_or("?")
+                            })
+                            .collect();
+                        ui.label(format!("{} {}", regular::QUEUE, queued.len()))
+                            .on_hover_text(tr_args("toolbar.queued_refreshes", &[("games", &queued.join("\n"))]));
+                    }
                     ui.add(egui::Label::new(&self.status).truncate());
                 }
                 
                 // Settings cog button on the right
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                    if ui.button(regular::GEAR).on_hover_text("Settings").clicked() {
+                    if ui.button(regular::GEAR).on_hover_text(tr("toolbar.settings")).clicked() {
                         self.show_settings = true;
                     }
                     
+                    // Final stretch - remaining achievements of nearly completed games
+                    if ui.button(regular::FLAG_CHECKERED).on_hover_text(tr("toolbar.final_stretch")).clicked() {
+                        self.show_final_stretch = !self.show_final_stretch;
+                        if self.show_final_stretch {
+                            self.load_final_stretch();
+                        }
+                    }
+                    
+                    // Review stalled games - abandon or keep games untouched for a year
+                    if ui.button(regular::ARCHIVE).on_hover_text(tr("toolbar.stalled_review")).clicked() {
+                        if self.show_stalled_review {
+                            self.show_stalled_review = false;
+                        } else {
+                            self.open_stalled_review();
+                        }
+                    }
+                    
+                    // Rivalry summary - only once a rival account is set
+                    if !self.config.rival_steam_id.trim().is_empty()
+                        && ui.button(regular::SWORD).on_hover_text(tr("toolbar.rival")).clicked()
+                    {
+                        self.show_rival_summary = !self.show_rival_summary;
+                    }
+                    
+                    // Year in review - annual summary with HTML/PNG export
+                    if ui.button(regular::CALENDAR).on_hover_text(tr("toolbar.year_review")).clicked() {
+                        if self.year_review.is_some() {
+                            self.year_review = None;
+                        } else {
+                            self.open_year_review();
+                        }
+                    }
+                    
+                    // Compare two scan snapshots
+                    if ui.button(regular::GIT_DIFF).on_hover_text(tr("toolbar.compare_snapshots")).clicked() {
+                        if self.snapshot_compare.is_some() {
+                            self.snapshot_compare = None;
+                        } else {
+                            self.open_snapshot_compare();
+                        }
+                    }
+                    
+                    // Newer release available - opens the changelog with the download link
+                    if let Some(release) = &self.latest_release {
+                        let label = egui::RichText::new(regular::ARROW_CIRCLE_UP).color(self.theme_colors().accent);
+                        let tooltip = tr_args("whats_new.update_available", &[("version", &release.version)]);
+                        if ui.button(label).on_hover_text(tooltip).clicked() {
+                            self.open_changelog();
+                        }
+                    }
+                    
+                    // Power status indicator - only shown on battery or under load; click to override
+                    self.render_power_indicator(ui);
+                    
+                    // Gentle reminder when a goal is behind schedule
+                    let behind: Vec<String> = self.goals_behind_schedule().iter().map(|g| g.label()).collect();
+                    if !behind.is_empty() {
+                        let warning = egui::Color32::from_rgb(230, 140, 50);
+                        ui.label(egui::RichText::new(regular::TARGET).color(warning))
+                            .on_hover_text(tr_args("toolbar.behind_schedule", &[("goals", &behind.join("\n"))]));
+                    }
+                    
                     // User profile button - opens profile menu if cloud linked
                     if let Some(_short_id) = self.config.get_short_id() {
                         if ui.button(regular::USER)
-                            .on_hover_text("Profile Menu")
+                            .on_hover_text(tr("toolbar.profile_menu"))
                             .clicked()
                         {
                             self.show_profile_menu = !self.show_profile_menu;
@@ -156,7 +244,7 @@ impl SteamOverachieverApp {
                         let steam_button = egui::Button::new(regular::STEAM_LOGO)
                             .fill(green);
                         if ui.add(steam_button)
-                            .on_hover_text("Login with Steam")
+                            .on_hover_text(tr("toolbar.login"))
                             .clicked()
                         {
                             self.start_cloud_link();
@@ -167,9 +255,9 @@ impl SteamOverachieverApp {
                     if ENABLE_ADMIN_MODE {
                         let admin_icon = if self.admin_mode { regular::SHIELD_STAR } else { regular::SHIELD };
                         let admin_tooltip = if self.admin_mode {
-                            "Admin Mode: ON\nClick to disable TTB scanning"
+                            tr("toolbar.admin_on")
                         } else {
-                            "Admin Mode: OFF\nClick to enable TTB scanning"
+                            tr("toolbar.admin_off")
                         };
                         if ui.button(admin_icon)
                             .on_hover_text(admin_tooltip)
@@ -177,6 +265,15 @@ impl SteamOverachieverApp {
                         {
                             self.admin_mode = !self.admin_mode;
                         }
+
+                        // Admin dashboard - stats, users and moderation queues
+                        if self.admin_mode && ui.button(regular::CHART_BAR).on_hover_text(tr("toolbar.admin_dashboard")).clicked() {
+                            if self.show_admin_window {
+                                self.show_admin_window = false;
+                            } else {
+                                self.open_admin_window();
+                            }
+                        }
                     }
                 });
             });
@@ -188,4 +285,39 @@ impl SteamOverachieverApp {
         // Profile menu window
         self.render_profile_menu(ctx);
     }
+
+    /// Battery/load indicator for background scan throttling
+    fn render_power_indicator(&mut self, ui: &mut egui::Ui) {
+        use crate::config::PowerMode;
+        use crate::power::Throttle;
+
+        if self.paused_for_game() {
+            let game = self.playing_game_label().unwrap_or_default();
+            let tooltip = format!("{}\n{}", tr("toolbar.power_paused"), tr_args("toolbar.paused_playing", &[("game", &game)]));
+            ui.label(regular::PAUSE_CIRCLE).on_hover_text(tooltip);
+            return;
+        }
+
+        let auto_throttle = self.power_status.throttle();
+        if auto_throttle == Throttle::Full {
+            return;
+        }
+
+        let overridden = self.config.power_mode == PowerMode::FullSpeed;
+        let (icon, state) = match self.background_throttle() {
+            Throttle::Full => (regular::LIGHTNING, tr("toolbar.power_full")),
+            Throttle::Slow => (regular::BATTERY_MEDIUM, tr("toolbar.power_slow")),
+            Throttle::Paused => (regular::PAUSE_CIRCLE, tr("toolbar.power_paused")),
+        };
+        let tooltip = format!(
+            "{}\n{}\n{}",
+            self.power_status.describe(),
+            state,
+            if overridden { tr("toolbar.power_click_auto") } else { tr("toolbar.power_click_full") }
+        );
+        if ui.button(icon).on_hover_text(tooltip).clicked() {
+            self.config.power_mode = if overridden { PowerMode::Auto } else { PowerMode::FullSpeed };
+            let _ = self.config.save();
+        }
+    }
 }
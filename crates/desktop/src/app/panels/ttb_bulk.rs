@@ -0,0 +1,77 @@
+//! "Report times for finished games" window - bulk personal TTB reports
+
+use eframe::egui;
+use egui_phosphor::regular;
+use overachiever_core::{tr, tr_args};
+
+use crate::app::SteamOverachieverApp;
+
+impl SteamOverachieverApp {
+    /// Render the bulk TTB report window (one finished game at a time)
+    pub(crate) fn render_ttb_bulk_window(&mut self, ctx: &egui::Context) {
+        let is_cloud_linked = self.config.cloud_token.is_some();
+        let Some(report) = self.ttb_bulk_report.as_mut() else {
+            return;
+        };
+
+        let mut open = true;
+        let mut submit = false;
+        let mut skip = false;
+        let position = report.total - report.queue.len();
+        let playtime_hours = self.games.iter()
+            .find(|g| g.appid == report.dialog.appid)
+            .map(|g| g.playtime_forever as f32 / 60.0)
+            .unwrap_or(0.0);
+
+        egui::Window::new(format!("{} {}", regular::CLOCK, tr("ttb_bulk.title")))
+            .open(&mut open)
+            .resizable(false)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                ui.set_min_width(400.0);
+                ui.label(egui::RichText::new(tr_args("ttb_bulk.progress", &[("position", &position), ("total", &report.total), ("reported", &report.submitted)])).weak());
+                ui.add_space(4.0);
+                ui.label(egui::RichText::new(&report.dialog.game_name).strong());
+                ui.label(tr_args("ttb_bulk.suggestion", &[("hours", &format!("{:.1}", playtime_hours))]));
+                ui.add_space(8.0);
+
+                let dialog = &mut report.dialog;
+                egui::Grid::new("ttb_bulk_input_grid").num_columns(5).spacing([8.0, 8.0]).show(ui, |ui| {
+                    for (label, hours, minutes) in [
+                        (tr("ttb.main_story"), &mut dialog.main_hours, &mut dialog.main_minutes),
+                        (tr("ttb.main_extras"), &mut dialog.extra_hours, &mut dialog.extra_minutes),
+                        (tr("ttb.completionist"), &mut dialog.completionist_hours, &mut dialog.completionist_minutes),
+                    ] {
+                        ui.label(label);
+                        ui.add(egui::TextEdit::singleline(hours).desired_width(50.0));
+                        ui.label(tr("ttb.hours_unit"));
+                        ui.add(egui::TextEdit::singleline(minutes).desired_width(50.0));
+                        ui.label(tr("ttb.minutes_unit"));
+                        ui.end_row();
+                    }
+                });
+
+                if !is_cloud_linked {
+                    ui.add_space(4.0);
+                    ui.label(egui::RichText::new(tr("ttb_bulk.local_only")).weak().small());
+                }
+                ui.add_space(12.0);
+
+                ui.horizontal(|ui| {
+                    let has_times = dialog.get_times() != (None, None, None);
+                    if ui.add_enabled(has_times, egui::Button::new(tr("ttb_bulk.submit_next"))).clicked() {
+                        submit = true;
+                    }
+                    if ui.button(tr("stalled.skip")).clicked() {
+                        skip = true;
+                    }
+                });
+            });
+
+        if !open {
+            self.stop_ttb_bulk_report();
+        } else if submit || skip {
+            self.advance_ttb_bulk_report(submit);
+        }
+    }
+}
@@ -0,0 +1,284 @@
+//! Unlock timeline window - replays a game's unlocks in the order they were earned,
+//! with long breaks annotated and PNG export
+
+use eframe::egui;
+use egui_phosphor::regular;
+use overachiever_core::{build_unlock_timeline, tr, tr_args, StatsPanelPlatform, UnlockTimeline};
+
+use super::year_review::{export_dir, save_screenshot_region};
+use crate::app::{SteamOverachieverApp, UnlockTimelineState};
+use crate::db::get_game_achievements;
+
+/// Marker passed with the screenshot request so the reply can be recognised
+struct UnlockTimelineScreenshot;
+
+/// Seconds between two unlocks while the replay plays
+const REPLAY_STEP_SECS: f64 = 0.6;
+
+/// Height of the painted timeline strip
+const CHART_HEIGHT: f32 = 120.0;
+
+/// Size of the small icons above reached unlocks, and of the current unlock's icon
+const ICON_SIZE: f32 = 20.0;
+const CURRENT_ICON_SIZE: f32 = 40.0;
+
+impl SteamOverachieverApp {
+    /// Load a game's achievements (on the DB worker) and open the timeline at its last unlock
+    pub(crate) fn open_unlock_timeline(&mut self, appid: u64, game_name: String) {
+        let steam_id = self.config.steam_id.clone();
+        self.db_query(
+            move |conn| get_game_achievements(conn, &steam_id, appid),
+            move |app, achievements| {
+                let achievements = achievements.unwrap_or_else(|e| {
+                    eprintln!("Failed to load achievements for {}: {}", appid, e);
+                    Vec::new()
+                });
+                let timeline = build_unlock_timeline(&achievements);
+                app.unlock_timeline = Some(UnlockTimelineState {
+                    appid,
+                    game_name,
+                    position: timeline.unlocks.len().saturating_sub(1),
+                    timeline,
+                    last_step: None,
+                    chart_rect: None,
+                });
+            },
+        );
+    }
+
+    /// Crop the timeline out of a window screenshot and save it as PNG
+    fn save_unlock_timeline_png(&mut self, ctx: &egui::Context, screenshot: &egui::ColorImage, rect: egui::Rect, appid: u64) {
+        let dir = export_dir();
+        let dest = dir.join(format!("overachiever_{}_unlock_timeline.png", appid));
+        match save_screenshot_region(ctx, screenshot, rect, &dest) {
+            Ok(()) => {
+                self.status = tr_args("timeline.saved_png", &[("path", &dest.display())]);
+                let _ = open::that(&dir);
+            }
+            Err(e) => self.status = tr_args("timeline.save_failed", &[("error", &e)]),
+        }
+    }
+
+    /// Render the unlock timeline window (if open)
+    pub(crate) fn render_unlock_timeline_window(&mut self, ctx: &egui::Context) {
+        let Some(mut state) = self.unlock_timeline.take() else {
+            return;
+        };
+
+        // Screenshot requested by the PNG button in an earlier frame
+        let screenshot = ctx.input(|i| {
+            i.events.iter().find_map(|event| match event {
+                egui::Event::Screenshot { user_data, image, .. }
+                    if user_data.data.as_ref().is_some_and(|d| d.is::<UnlockTimelineScreenshot>()) =>
+                {
+                    Some(image.clone())
+                }
+                _ => None,
+            })
+        });
+
+        // Step the replay forward, stopping at the last unlock
+        let now = ctx.input(|i| i.time);
+        let last = state.timeline.unlocks.len().saturating_sub(1);
+        if let Some(last_step) = state.last_step {
+            if state.position >= last {
+                state.last_step = None;
+            } else if now - last_step >= REPLAY_STEP_SECS {
+                state.position += 1;
+                state.last_step = Some(now);
+            }
+            ctx.request_repaint_after(std::time::Duration::from_secs_f64(REPLAY_STEP_SECS));
+        }
+
+        let theme = self.theme_colors();
+        let time = self.config.time_display.clone();
+        let mut open = true;
+        let mut export_png = false;
+
+        egui::Window::new(format!("{} {}", regular::FILM_STRIP, tr_args("timeline.title", &[("game", &state.game_name)])))
+            .id(egui::Id::new("unlock_timeline_window"))
+            .open(&mut open)
+            .default_width(620.0)
+            .show(ctx, |ui| {
+                if state.timeline.is_empty() {
+                    ui.label(tr("timeline.empty"));
+                    return;
+                }
+
+                ui.horizontal(|ui| {
+                    if ui.small_button(regular::SKIP_BACK.to_string()).on_hover_text(tr("timeline.first")).clicked() {
+                        state.position = 0;
+                        state.last_step = None;
+                    }
+                    let playing = state.last_step.is_some();
+                    let play_icon = if playing { regular::PAUSE } else { regular::PLAY };
+                    if ui.small_button(play_icon.to_string()).on_hover_text(if playing { tr("timeline.pause") } else { tr("timeline.replay") }).clicked() {
+                        if playing {
+                            state.last_step = None;
+                        } else {
+                            // Replaying from the end starts over
+                            if state.position >= last {
+                                state.position = 0;
+                            }
+                            state.last_step = Some(now);
+                        }
+                    }
+                    if ui.small_button(regular::SKIP_FORWARD.to_string()).on_hover_text(tr("timeline.last")).clicked() {
+                        state.position = last;
+                        state.last_step = None;
+                    }
+                    let mut position = state.position + 1;
+                    let slider = egui::Slider::new(&mut position, 1..=last + 1).suffix(tr_args("timeline.of_total", &[("total", &(last + 1))]));
+                    if ui.add(slider).changed() {
+                        state.position = position - 1;
+                        state.last_step = None;
+                    }
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.small_button(format!("{} {}", regular::IMAGE, tr("year_review.save_png"))).clicked() {
+                            export_png = true;
+                        }
+                    });
+                });
+                ui.separator();
+
+                let chart = ui.scope(|ui| {
+                    ui.label(egui::RichText::new(&state.game_name).color(theme.accent).strong().size(16.0));
+                    if let Some(index) = render_timeline(ui, self, &state.timeline, state.position, theme.accent) {
+                        state.position = index;
+                        state.last_step = None;
+                    }
+                    render_current_unlock(ui, self, &state.timeline, state.position, &time);
+                });
+                state.chart_rect = Some(chart.response.rect);
+            });
+
+        if export_png {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot(egui::UserData::new(UnlockTimelineScreenshot)));
+        }
+        if let (Some(image), Some(rect)) = (screenshot, state.chart_rect) {
+            self.save_unlock_timeline_png(ctx, &image, rect, state.appid);
+        }
+
+        if open {
+            self.unlock_timeline = Some(state);
+        }
+    }
+}
+
+/// Paint the unlocks along a horizontal axis - reached ones in the accent color with their
+/// icons, later ones dimmed. Returns the unlock clicked or dragged to, if any.
+fn render_timeline(
+    ui: &mut egui::Ui,
+    app: &SteamOverachieverApp,
+    timeline: &UnlockTimeline,
+    position: usize,
+    accent: egui::Color32,
+) -> Option<usize> {
+    let width = ui.available_width().max(360.0);
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(width, CHART_HEIGHT), egui::Sense::click_and_drag());
+    let painter = ui.painter_at(rect);
+    let axis = rect.shrink2(egui::vec2(CURRENT_ICON_SIZE / 2.0 + 4.0, 0.0));
+    let axis_y = rect.top() + rect.height() * 0.6;
+    let x_of = |index: usize| axis.left() + axis.width() * timeline.positions[index];
+    let dim = ui.visuals().weak_text_color();
+
+    painter.line_segment([egui::pos2(axis.left(), axis_y), egui::pos2(axis.right(), axis_y)], egui::Stroke::new(1.0, dim));
+
+    // Breaks: a dashed stretch of the axis with the length of the pause below it
+    for gap in &timeline.breaks {
+        let (from, to) = (x_of(gap.after), x_of(gap.after + 1));
+        painter.rect_filled(
+            egui::Rect::from_min_max(egui::pos2(from, axis_y - 3.0), egui::pos2(to, axis_y + 3.0)),
+            0.0,
+            ui.visuals().extreme_bg_color,
+        );
+        painter.extend(egui::Shape::dashed_line(
+            &[egui::pos2(from, axis_y), egui::pos2(to, axis_y)],
+            egui::Stroke::new(1.0, dim),
+            4.0,
+            3.0,
+        ));
+        painter.text(
+            egui::pos2((from + to) / 2.0, axis_y + 8.0),
+            egui::Align2::CENTER_TOP,
+            gap.label(),
+            egui::FontId::proportional(10.0),
+            dim,
+        );
+    }
+
+    // Small icons of reached unlocks, skipping those that would overlap the previous one
+    let mut last_icon_x = f32::NEG_INFINITY;
+    for (i, unlock) in timeline.unlocks.iter().enumerate().take(position) {
+        let x = x_of(i);
+        if x - last_icon_x < ICON_SIZE {
+            continue;
+        }
+        last_icon_x = x;
+        let icon_rect = egui::Rect::from_center_size(egui::pos2(x, axis_y - 8.0 - ICON_SIZE / 2.0), egui::Vec2::splat(ICON_SIZE));
+        egui::Image::new(app.achievement_icon_source(ui, &unlock.icon))
+            .corner_radius(2.0)
+            .paint_at(ui, icon_rect);
+    }
+
+    for i in 0..timeline.unlocks.len() {
+        let color = if i <= position { accent } else { dim.gamma_multiply(0.5) };
+        painter.circle_filled(egui::pos2(x_of(i), axis_y), if i == position { 5.0 } else { 3.0 }, color);
+    }
+
+    // The current unlock stands out above the others
+    let current = &timeline.unlocks[position];
+    let x = x_of(position);
+    let icon_rect = egui::Rect::from_center_size(
+        egui::pos2(x, axis_y - 8.0 - CURRENT_ICON_SIZE / 2.0),
+        egui::Vec2::splat(CURRENT_ICON_SIZE),
+    );
+    painter.line_segment([egui::pos2(x, icon_rect.bottom()), egui::pos2(x, axis_y)], egui::Stroke::new(1.0, accent));
+    egui::Image::new(app.achievement_icon_source(ui, &current.icon))
+        .corner_radius(4.0)
+        .paint_at(ui, icon_rect);
+    painter.rect_stroke(icon_rect, 4.0, egui::Stroke::new(2.0, accent), egui::epaint::StrokeKind::Outside);
+
+    let nearest = |pos: egui::Pos2| timeline.nearest(((pos.x - axis.left()) / axis.width()).clamp(0.0, 1.0));
+    if let Some(hover) = response.hover_pos().and_then(nearest) {
+        let unlock = &timeline.unlocks[hover];
+        response.clone().on_hover_text_at_pointer(format!("{}\n{}", unlock.name, app.config.time_display.format_achievement(unlock.at)));
+    }
+    if response.clicked() || response.dragged() {
+        return response.interact_pointer_pos().and_then(nearest);
+    }
+    None
+}
+
+/// Name and unlock date of the current unlock, and the break it ended
+fn render_current_unlock(
+    ui: &mut egui::Ui,
+    app: &SteamOverachieverApp,
+    timeline: &UnlockTimeline,
+    position: usize,
+    time: &overachiever_core::TimeDisplaySettings,
+) {
+    let current = &timeline.unlocks[position];
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new(format!("#{}", position + 1)).weak());
+        ui.label(egui::RichText::new(&current.name).strong());
+        ui.label(egui::RichText::new(time.format_achievement(current.at)).weak());
+        if let Some(gap) = position.checked_sub(1).and_then(|i| timeline.break_after(i)) {
+            ui.label(egui::RichText::new(format!("{} {}", regular::HOURGLASS, tr_args("timeline.after_break", &[("break", &gap.label())]))).color(app.theme_colors().accent));
+        }
+    });
+    let first = &timeline.unlocks[0];
+    let last = &timeline.unlocks[timeline.unlocks.len() - 1];
+    ui.label(
+        egui::RichText::new(tr_args(
+            "timeline.summary",
+            &[
+                ("count", &timeline.unlocks.len()),
+                ("first", &time.format_absolute(first.at)),
+                ("last", &time.format_absolute(last.at)),
+            ],
+        ))
+        .weak()
+        .small(),
+    );
+}
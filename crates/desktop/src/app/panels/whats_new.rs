@@ -0,0 +1,71 @@
+//! "What's new" window - changelog entries, plus a download link when a newer release exists
+
+use eframe::egui;
+use egui_phosphor::regular;
+use overachiever_core::{tr, tr_args, StatsPanelPlatform};
+
+use crate::app::SteamOverachieverApp;
+
+impl SteamOverachieverApp {
+    /// Render the what's new window
+    pub(crate) fn render_whats_new_window(&mut self, ctx: &egui::Context) {
+        let Some(entries) = &self.whats_new else {
+            return;
+        };
+
+        let mut open = true;
+        let mut close = false;
+        let theme = self.theme_colors();
+
+        egui::Window::new(format!("{} {}", regular::SPARKLE, tr("whats_new.title")))
+            .open(&mut open)
+            .default_size([480.0, 420.0])
+            .collapsible(false)
+            .show(ctx, |ui| {
+                if let Some(release) = &self.latest_release {
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            egui::RichText::new(tr_args("whats_new.update_available", &[("version", &release.version)]))
+                                .color(theme.accent)
+                                .strong(),
+                        );
+                        if ui.button(format!("{} {}", regular::DOWNLOAD_SIMPLE, tr("whats_new.download"))).clicked() {
+                            if let Err(e) = open::that(&release.url) {
+                                eprintln!("Failed to open release page: {}", e);
+                            }
+                        }
+                    });
+                    ui.separator();
+                }
+
+                egui::ScrollArea::vertical().auto_shrink([false, true]).max_height(360.0).show(ui, |ui| {
+                    for entry in entries {
+                        let heading = match &entry.date {
+                            Some(date) => format!("{} - {}", entry.version, date),
+                            None => entry.version.clone(),
+                        };
+                        ui.label(egui::RichText::new(heading).heading());
+                        for item in &entry.items {
+                            ui.horizontal_wrapped(|ui| {
+                                ui.label("•");
+                                ui.label(item);
+                            });
+                        }
+                        ui.add_space(8.0);
+                    }
+                    if entries.is_empty() {
+                        ui.label(tr("whats_new.empty"));
+                    }
+                });
+
+                ui.separator();
+                if ui.button(tr("whats_new.close")).clicked() {
+                    close = true;
+                }
+            });
+
+        if !open || close {
+            self.whats_new = None;
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Wishlist tab - wishlisted games with TTB, tags and community completion
+
+use eframe::egui;
+use egui_phosphor::regular;
+use overachiever_core::{tr, tr_args};
+
+use crate::app::SteamOverachieverApp;
+
+/// Tags shown per wishlisted game
+const WISHLIST_TAGS_SHOWN: usize = 3;
+
+/// Format optional hours like the TTB columns
+fn format_hours(hours: Option<f32>) -> String {
+    hours.map(|h| format!("{:.1}h", h)).unwrap_or_else(|| "-".to_string())
+}
+
+impl SteamOverachieverApp {
+    /// Render the wishlist tab inside the central panel
+    pub(crate) fn render_wishlist_tab(&mut self, ui: &mut egui::Ui) {
+        // Fetch once per session when the tab is first opened
+        if !self.wishlist_fetched {
+            self.start_wishlist_fetch();
+        }
+
+        let is_loading = self.wishlist_receiver.is_some();
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!is_loading && self.config.is_valid(), egui::Button::new(format!("{} {}", regular::ARROWS_CLOCKWISE, tr("wishlist.refresh"))))
+                .on_hover_text(tr("wishlist.refresh_tooltip"))
+                .clicked()
+            {
+                self.start_wishlist_fetch();
+            }
+            if is_loading {
+                ui.spinner();
+                ui.label(tr("wishlist.fetching"));
+            }
+            if let Some(e) = &self.wishlist_error {
+                ui.colored_label(egui::Color32::RED, format!("{} {}", regular::WARNING, e));
+            }
+        });
+
+        if self.wishlist.is_empty() {
+            ui.add_space(8.0);
+            if !is_loading {
+                ui.label(tr("wishlist.empty"));
+            }
+            return;
+        }
+
+        // Backlog impact: how much play time the wishlist would add
+        let (main_total, completionist_total, known) = self.wishlist.iter().fold((0.0, 0.0, 0), |(main, full, known), item| {
+            match self.ttb_cache.get(&item.appid) {
+                Some(ttb) => (main + ttb.main.unwrap_or(0.0), full + ttb.completionist.unwrap_or(0.0), known + 1),
+                None => (main, full, known),
+            }
+        });
+        ui.add_space(4.0);
+        ui.label(format!(
+            "{} {}",
+            regular::HOURGLASS,
+            tr_args(
+                "wishlist.backlog_impact",
+                &[
+                    ("main", &format!("{:.0}", main_total)),
+                    ("completionist", &format!("{:.0}", completionist_total)),
+                    ("known", &known),
+                    ("total", &self.wishlist.len()),
+                ],
+            )
+        ));
+        ui.add_space(4.0);
+
+        let owned: std::collections::HashSet<u64> = self.games.iter().map(|g| g.appid).collect();
+        let time = self.config.time_display.clone();
+
+        egui::ScrollArea::vertical().auto_shrink([false, false]).show(ui, |ui| {
+            egui::Grid::new("wishlist_grid").striped(true).num_columns(6).spacing([16.0, 6.0]).show(ui, |ui| {
+                ui.label(egui::RichText::new(tr("wishlist.game")).strong());
+                ui.label(egui::RichText::new(tr("wishlist.added")).strong());
+                ui.label(egui::RichText::new(tr("wishlist.main")).strong());
+                ui.label(egui::RichText::new("100%").strong());
+                ui.label(egui::RichText::new(tr("wishlist.community_completion")).strong());
+                ui.label(egui::RichText::new(tr("wishlist.tags")).strong());
+                ui.end_row();
+
+                for item in &self.wishlist {
+                    ui.horizontal(|ui| {
+                        let link = ui.link(&item.name).on_hover_text(tr("wishlist.open_store"));
+                        if link.clicked() {
+                            let _ = open::that(format!("https://store.steampowered.com/app/{}", item.appid));
+                        }
+                        if owned.contains(&item.appid) {
+                            ui.label(egui::RichText::new(tr("wishlist.owned")).small().weak());
+                        }
+                    });
+
+                    match item.date_added {
+                        Some(added) => ui.label(time.format_absolute(added)),
+                        None => ui.label("-"),
+                    };
+
+                    let ttb = self.ttb_cache.get(&item.appid);
+                    ui.label(format_hours(ttb.and_then(|t| t.main)));
+                    ui.label(format_hours(ttb.and_then(|t| t.completionist)));
+
+                    match self.wishlist_completion.get(&item.appid) {
+                        Some(stats) => ui.label(tr_args(
+                            "wishlist.completion",
+                            &[("avg", &format!("{:.0}", stats.avg_completion)), ("completed", &stats.completed), ("players", &stats.players)],
+                        )),
+                        None => ui.label(egui::RichText::new(tr("wishlist.no_players")).weak()),
+                    };
+
+                    let tags = self.tags_cache.get(&item.appid).map(|tags| {
+                        let mut tags = tags.clone();
+                        tags.sort_by_key(|(_, votes)| std::cmp::Reverse(*votes));
+                        tags.into_iter().take(WISHLIST_TAGS_SHOWN).map(|(name, _)| name).collect::<Vec<_>>().join(", ")
+                    });
+                    ui.label(egui::RichText::new(tags.unwrap_or_default()).weak());
+                    ui.end_row();
+                }
+            });
+        });
+    }
+}
@@ -0,0 +1,243 @@
+//! "Year in review" window - annual summary with HTML and PNG export
+
+use chrono::Datelike;
+use eframe::egui;
+use egui_phosphor::regular;
+use overachiever_core::{
+    month_short_name, review_years, tr, tr_args, year_review, year_review_html, FirstPlay, GameSession, StatsPanelPlatform, UnlockRecord, YearReview,
+};
+
+use crate::app::{SteamOverachieverApp, YearReviewState};
+use crate::db::{get_all_sessions, get_recent_first_plays, get_unlock_records};
+
+/// Marker passed with the screenshot request so the reply can be recognised
+struct YearReviewScreenshot;
+
+/// Folder exports are written to (Downloads, falling back to the working directory)
+pub(super) fn export_dir() -> std::path::PathBuf {
+    directories::UserDirs::new()
+        .and_then(|dirs| dirs.download_dir().map(|d| d.to_path_buf()))
+        .unwrap_or_else(|| std::path::PathBuf::from("."))
+}
+
+/// Crop `rect` out of a window screenshot and write it to `dest` as PNG
+pub(super) fn save_screenshot_region(
+    ctx: &egui::Context,
+    screenshot: &egui::ColorImage,
+    rect: egui::Rect,
+    dest: &std::path::Path,
+) -> image::ImageResult<()> {
+    let image = screenshot.region(&rect, Some(ctx.pixels_per_point()));
+    let bytes: Vec<u8> = image.pixels.iter().flat_map(|c| c.to_array()).collect();
+    image::save_buffer(
+        dest,
+        &bytes,
+        image.width() as u32,
+        image.height() as u32,
+        image::ExtendedColorType::Rgba8,
+    )
+}
+
+impl SteamOverachieverApp {
+    /// Load unlocks, first plays and sessions (on the DB worker) and open the window on the latest year
+    pub(crate) fn open_year_review(&mut self) {
+        let steam_id = self.config.steam_id.clone();
+        self.db_query(
+            move |conn| {
+                Ok((
+                    get_unlock_records(conn, &steam_id)?,
+                    get_recent_first_plays(conn, &steam_id, i32::MAX, None, None, None)?,
+                    get_all_sessions(conn, &steam_id)?,
+                ))
+            },
+            |app, loaded| match loaded {
+                Ok((unlocks, first_plays, sessions)) => app.show_year_review(unlocks, first_plays, sessions),
+                Err(e) => app.status = tr_args("year_review.load_failed", &[("error", &e)]),
+            },
+        );
+    }
+
+    fn show_year_review(&mut self, unlocks: Vec<UnlockRecord>, first_plays: Vec<FirstPlay>, sessions: Vec<GameSession>) {
+        let time = &self.config.time_display;
+        let mut years = review_years(&unlocks, &first_plays, &sessions, time);
+        if years.is_empty() {
+            years.push(time.display_date(chrono::Utc::now()).year());
+        }
+        let review = year_review(years[0], &self.stats_games(), &unlocks, &first_plays, &sessions, time);
+        self.year_review = Some(YearReviewState {
+            years,
+            review,
+            unlocks,
+            first_plays,
+            sessions,
+            report_rect: None,
+        });
+    }
+
+    /// Write the review as a standalone HTML page and reveal it
+    fn export_year_review_html(&mut self, review: &YearReview) {
+        let dir = export_dir();
+        let dest = dir.join(format!("overachiever_{}_in_review.html", review.year));
+        match std::fs::write(&dest, year_review_html(review)) {
+            Ok(()) => {
+                self.status = tr_args("year_review.exported", &[("path", &dest.display())]);
+                let _ = open::that(&dest);
+            }
+            Err(e) => self.status = tr_args("year_review.export_failed", &[("error", &e)]),
+        }
+    }
+
+    /// Crop the report out of a window screenshot and save it as PNG
+    fn save_year_review_png(&mut self, ctx: &egui::Context, screenshot: &egui::ColorImage, rect: egui::Rect, year: i32) {
+        let dir = export_dir();
+        let dest = dir.join(format!("overachiever_{}_in_review.png", year));
+        match save_screenshot_region(ctx, screenshot, rect, &dest) {
+            Ok(()) => {
+                self.status = tr_args("year_review.image_saved", &[("path", &dest.display())]);
+                let _ = open::that(&dir);
+            }
+            Err(e) => self.status = tr_args("year_review.image_failed", &[("error", &e)]),
+        }
+    }
+
+    /// Render the year in review window
+    pub(crate) fn render_year_review_window(&mut self, ctx: &egui::Context) {
+        let Some(mut state) = self.year_review.take() else {
+            return;
+        };
+
+        // Screenshot requested by the PNG button in an earlier frame
+        let screenshot = ctx.input(|i| {
+            i.events.iter().find_map(|event| match event {
+                egui::Event::Screenshot { user_data, image, .. }
+                    if user_data.data.as_ref().is_some_and(|d| d.is::<YearReviewScreenshot>()) =>
+                {
+                    Some(image.clone())
+                }
+                _ => None,
+            })
+        });
+
+        let theme = self.theme_colors();
+        let time = self.config.time_display.clone();
+        let mut open = true;
+        let mut selected_year = state.review.year;
+        let mut export_html = false;
+        let mut export_png = false;
+
+        egui::Window::new(format!("{} {}", regular::CALENDAR, tr("toolbar.year_review")))
+            .open(&mut open)
+            .default_width(460.0)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_salt("year_review_year")
+                        .selected_text(selected_year.to_string())
+                        .show_ui(ui, |ui| {
+                            for year in &state.years {
+                                ui.selectable_value(&mut selected_year, *year, year.to_string());
+                            }
+                        });
+                    if ui.button(format!("{} {}", regular::EXPORT, tr("year_review.export_html"))).clicked() {
+                        export_html = true;
+                    }
+                    if ui.button(format!("{} {}", regular::IMAGE, tr("year_review.save_png"))).clicked() {
+                        export_png = true;
+                    }
+                });
+                ui.separator();
+
+                let report = ui.scope(|ui| render_report(ui, &state.review, &theme, &time)).response;
+                state.report_rect = Some(report.rect);
+            });
+
+        if selected_year != state.review.year {
+            state.review = year_review(selected_year, &self.stats_games(), &state.unlocks, &state.first_plays, &state.sessions, &time);
+        }
+        if export_html {
+            self.export_year_review_html(&state.review);
+        }
+        if export_png {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot(egui::UserData::new(YearReviewScreenshot)));
+        }
+        if let (Some(image), Some(rect)) = (screenshot, state.report_rect) {
+            self.save_year_review_png(ctx, &image, rect, state.review.year);
+        }
+
+        if open {
+            self.year_review = Some(state);
+        }
+    }
+}
+
+/// The shareable part of the window (also what the PNG export captures)
+fn render_report(ui: &mut egui::Ui, review: &YearReview, theme: &overachiever_core::ThemeColors, time: &overachiever_core::TimeDisplaySettings) {
+    ui.add_space(4.0);
+    ui.heading(egui::RichText::new(tr_args("year_review.heading", &[("year", &review.year)])).color(theme.accent).size(24.0));
+    ui.add_space(8.0);
+
+    if review.is_empty() {
+        ui.label(tr("year_review.empty"));
+        return;
+    }
+
+    egui::Grid::new("year_review_stats").num_columns(2).spacing([16.0, 6.0]).show(ui, |ui| {
+        for (value, label) in review.headline_stats() {
+            ui.label(egui::RichText::new(value).color(theme.accent).strong().size(20.0));
+            ui.label(label);
+            ui.end_row();
+        }
+    });
+
+    // Unlocks per month
+    ui.add_space(12.0);
+    let max = review.unlocks_per_month.iter().copied().max().unwrap_or(0).max(1) as f32;
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(ui.available_width().max(360.0), 100.0), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    let slot = rect.width() / 12.0;
+    let label_height = 14.0;
+    for (i, &n) in review.unlocks_per_month.iter().enumerate() {
+        let x = rect.left() + slot * i as f32;
+        let height = (rect.height() - label_height) * n as f32 / max;
+        let bar = egui::Rect::from_min_max(
+            egui::pos2(x + 3.0, rect.bottom() - label_height - height.max(1.0)),
+            egui::pos2(x + slot - 3.0, rect.bottom() - label_height),
+        );
+        painter.rect_filled(bar, 2.0, theme.accent);
+        painter.text(
+            egui::pos2(x + slot / 2.0, rect.bottom()),
+            egui::Align2::CENTER_BOTTOM,
+            month_short_name(i as u32 + 1),
+            egui::FontId::proportional(10.0),
+            ui.visuals().weak_text_color(),
+        );
+    }
+
+    ui.add_space(8.0);
+    if let Some(rarest) = &review.rarest_unlock {
+        ui.horizontal_wrapped(|ui| {
+            ui.label(format!("{} {}", regular::DIAMOND, tr("stats.rarest_unlock")));
+            ui.label(egui::RichText::new(&rarest.achievement_name).color(theme.accent).strong());
+            ui.label(tr_args(
+                "stats.rarest_unlock_detail",
+                &[("game", &rarest.game_name), ("percent", &format!("{:.1}", rarest.global_percent.unwrap_or(0.0)))],
+            ));
+        });
+    }
+    if let Some((_, name, unlocks)) = &review.top_game {
+        ui.horizontal_wrapped(|ui| {
+            ui.label(format!("{} {}", regular::TROPHY, tr("year_review.most_unlocks")));
+            ui.label(egui::RichText::new(name).color(theme.accent).strong());
+            ui.label(tr_args("year_review.most_unlocks_detail", &[("count", unlocks)]));
+        });
+    }
+    if !review.games_finished.is_empty() {
+        ui.add_space(4.0);
+        ui.label(format!("{} {}", regular::CHECK_CIRCLE, tr("year_review.perfected")));
+        for game in &review.games_finished {
+            ui.label(format!("   {} ({})", game.name, time.format_absolute(game.at)));
+        }
+    }
+    ui.add_space(4.0);
+    ui.label(egui::RichText::new("Overachiever").weak().small());
+}
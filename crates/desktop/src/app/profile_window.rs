@@ -0,0 +1,486 @@
+//! Secondary profile windows - another local Steam profile's library in its own OS window
+//!
+//! Each window owns its own games, expanded rows, sorting and filters so it never
+//! touches the main window's state. Data is read from the local database only.
+//! A window can also show a merged library of several profiles (e.g. a Steam family).
+
+use std::collections::{HashMap, HashSet};
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+
+use rusqlite::Connection;
+
+use eframe::egui::{self, Ui};
+use egui_phosphor::regular;
+use overachiever_core::{
+    compare_games, get_filtered_indices, render_filter_bar, render_games_table, render_stats_content, render_unowned_section, sort_games, tr, tr_args,
+    AchievementHistory, Game, GameAchievement, GamesTablePlatform, LogEntry, RunHistory,
+    StatsPanelConfig, StatsPanelPlatform, ThemeColors, TimeDisplaySettings,
+};
+
+use crate::app::SteamOverachieverApp;
+use crate::app::state::LOG_ENTRIES_LIMIT;
+use crate::db::{get_achievement_history, get_all_games, get_game_achievements, get_log_entries, get_run_history, DbWorker};
+use crate::icon_cache::IconCache;
+use crate::ui::{SortColumn, SortOrder, SortTieBreaker, TriFilter};
+
+/// Which account a row of a merged library belongs to
+struct RowOwner {
+    // Account whose achievements are shown when the row is expanded
+    steam_id: String,
+    // Badge text - all owners when duplicates were merged
+    label: String,
+}
+
+/// State for one secondary profile window
+pub(crate) struct ProfileWindow {
+    pub(crate) steam_id: String,
+    pub(crate) title: String,
+    // Set to false when the OS window is closed
+    pub(crate) open: bool,
+    // Bring the window to front on the next frame (opened again while already open)
+    focus_requested: bool,
+    games: Vec<Game>,
+    // Parallel to `games` for merged libraries, empty for a single profile
+    row_owners: Vec<RowOwner>,
+    // Combined statistics line for merged libraries
+    merged_summary: Option<String>,
+    run_history: Vec<RunHistory>,
+    achievement_history: Vec<AchievementHistory>,
+    log_entries: Vec<LogEntry>,
+    expanded_rows: HashSet<u64>,
+    achievements_cache: HashMap<u64, Vec<GameAchievement>>,
+    // Achievement loads running on the shared DB worker
+    achievement_loads: HashMap<u64, Receiver<rusqlite::Result<Vec<GameAchievement>>>>,
+    db_worker: DbWorker,
+    sort_column: SortColumn,
+    sort_order: SortOrder,
+    filter_name: String,
+    filter_achievements: TriFilter,
+    filter_playtime: TriFilter,
+    include_unplayed_in_avg: bool,
+    show_stats: bool,
+    // Shared with the main window (same disk cache)
+    icon_cache: Arc<IconCache>,
+    // Copied from the main window's config when opened
+    theme: ThemeColors,
+    time_display: TimeDisplaySettings,
+}
+
+impl ProfileWindow {
+    /// Load a profile from the local database
+    pub(crate) fn load(
+        conn: &Connection,
+        steam_id: &str,
+        display_name: Option<String>,
+        icon_cache: Arc<IconCache>,
+        db_worker: DbWorker,
+        theme: ThemeColors,
+        time_display: TimeDisplaySettings,
+    ) -> Result<Self, String> {
+        let mut games = get_all_games(conn, steam_id).map_err(|e| tr_args("profile_window.load_failed", &[("error", &e)]))?;
+        if games.is_empty() {
+            return Err(tr_args("profile_window.no_data", &[("steam_id", &steam_id)]));
+        }
+        sort_games(&mut games, SortColumn::Name, SortOrder::Ascending, SortTieBreaker::default());
+
+        let title = display_name.unwrap_or_else(|| steam_id.to_string());
+        let mut window = Self::with_games(steam_id.to_string(), title, games, icon_cache, db_worker, theme, time_display);
+        window.run_history = get_run_history(conn, steam_id).unwrap_or_default();
+        window.achievement_history = get_achievement_history(conn, steam_id).unwrap_or_default();
+        window.log_entries = get_log_entries(conn, steam_id, LOG_ENTRIES_LIMIT, None, None, None).unwrap_or_default();
+        Ok(window)
+    }
+
+    /// Load several profiles into one merged library with per-row owner badges.
+    /// With `dedupe`, a game owned by several accounts is shown once, using the copy with the most progress.
+    pub(crate) fn load_merged(
+        conn: &Connection,
+        profiles: &[(String, Option<String>)],
+        dedupe: bool,
+        icon_cache: Arc<IconCache>,
+        db_worker: DbWorker,
+        theme: ThemeColors,
+        time_display: TimeDisplaySettings,
+    ) -> Result<Self, String> {
+        let mut rows: Vec<(Game, RowOwner)> = Vec::new();
+        for (steam_id, display_name) in profiles {
+            let label = display_name.clone().unwrap_or_else(|| steam_id.clone());
+            let games = get_all_games(conn, steam_id).map_err(|e| tr_args("profile_window.load_failed", &[("error", &e)]))?;
+            rows.extend(games.into_iter().map(|game| (game, RowOwner { steam_id: steam_id.clone(), label: label.clone() })));
+        }
+        if rows.is_empty() {
+            return Err(tr("profile_window.no_data_merged").to_string());
+        }
+
+        let total_rows = rows.len();
+        let unique_games = rows.iter().map(|(game, _)| game.appid).collect::<HashSet<_>>().len();
+        if dedupe {
+            let progress = |game: &Game| (game.achievements_unlocked.unwrap_or(0), game.playtime_forever);
+            let mut index_by_appid: HashMap<u64, usize> = HashMap::new();
+            let mut deduped: Vec<(Game, RowOwner)> = Vec::with_capacity(unique_games);
+            for (game, owner) in rows {
+                match index_by_appid.get(&game.appid) {
+                    Some(&i) => {
+                        let (kept, kept_owner) = &mut deduped[i];
+                        kept_owner.label = format!("{}, {}", kept_owner.label, owner.label);
+                        if progress(&game) > progress(kept) {
+                            kept_owner.steam_id = owner.steam_id;
+                            *kept = game;
+                        }
+                    }
+                    None => {
+                        index_by_appid.insert(game.appid, deduped.len());
+                        deduped.push((game, owner));
+                    }
+                }
+            }
+            rows = deduped;
+        }
+        rows.sort_by(|a, b| compare_games(&a.0, &b.0, SortColumn::Name, SortOrder::Ascending, SortTieBreaker::default()));
+        let (games, row_owners): (Vec<Game>, Vec<RowOwner>) = rows.into_iter().unzip();
+
+        let unlocked: i64 = games.iter().map(|g| g.achievements_unlocked.unwrap_or(0) as i64).sum();
+        let perfect = games.iter().filter(|g| g.achievements_total.unwrap_or(0) > 0 && g.achievements_unlocked == g.achievements_total).count();
+        let summary = tr_args(
+            if dedupe { "profile_window.merged_summary_deduped" } else { "profile_window.merged_summary" },
+            &[
+                ("accounts", &profiles.len()),
+                ("games", &total_rows),
+                ("unique", &unique_games),
+                ("unlocked", &unlocked),
+                ("perfect", &perfect),
+            ],
+        );
+
+        let names: Vec<String> = profiles.iter().map(|(id, name)| name.clone().unwrap_or_else(|| id.clone())).collect();
+        let key = format!("merged:{}", profiles.iter().map(|(id, _)| id.as_str()).collect::<Vec<_>>().join(","));
+        let mut window = Self::with_games(key, tr_args("profile_window.merged_title", &[("names", &names.join(", "))]), games, icon_cache, db_worker, theme, time_display);
+        window.row_owners = row_owners;
+        window.merged_summary = Some(summary);
+        Ok(window)
+    }
+
+    fn with_games(
+        steam_id: String,
+        title: String,
+        games: Vec<Game>,
+        icon_cache: Arc<IconCache>,
+        db_worker: DbWorker,
+        theme: ThemeColors,
+        time_display: TimeDisplaySettings,
+    ) -> Self {
+        Self {
+            steam_id,
+            title,
+            open: true,
+            focus_requested: false,
+            games,
+            row_owners: Vec::new(),
+            merged_summary: None,
+            run_history: Vec::new(),
+            achievement_history: Vec::new(),
+            log_entries: Vec::new(),
+            expanded_rows: HashSet::new(),
+            achievements_cache: HashMap::new(),
+            achievement_loads: HashMap::new(),
+            db_worker,
+            sort_column: SortColumn::Name,
+            sort_order: SortOrder::Ascending,
+            filter_name: String::new(),
+            filter_achievements: TriFilter::All,
+            filter_playtime: TriFilter::All,
+            include_unplayed_in_avg: false,
+            show_stats: false,
+            icon_cache,
+            theme,
+            time_display,
+        }
+    }
+
+    /// Account whose achievements are shown for a game (the row owner in merged libraries)
+    fn achievements_owner(&self, appid: u64) -> &str {
+        self.games
+            .iter()
+            .position(|g| g.appid == appid)
+            .and_then(|i| self.row_owners.get(i))
+            .map(|owner| owner.steam_id.as_str())
+            .unwrap_or(&self.steam_id)
+    }
+
+    /// Sort games, keeping merged row owners aligned
+    fn sort_rows(&mut self) {
+        if self.row_owners.is_empty() {
+            sort_games(&mut self.games, self.sort_column, self.sort_order, SortTieBreaker::default());
+            return;
+        }
+        let mut rows: Vec<(Game, RowOwner)> = std::mem::take(&mut self.games).into_iter().zip(std::mem::take(&mut self.row_owners)).collect();
+        rows.sort_by(|a, b| compare_games(&a.0, &b.0, self.sort_column, self.sort_order, SortTieBreaker::default()));
+        (self.games, self.row_owners) = rows.into_iter().unzip();
+    }
+
+    fn viewport_id(&self) -> egui::ViewportId {
+        egui::ViewportId::from_hash_of(("profile_window", &self.steam_id))
+    }
+
+    /// Show this profile in its own OS window (immediate viewport)
+    pub(crate) fn show(&mut self, ctx: &egui::Context) {
+        let builder = egui::ViewportBuilder::default()
+            .with_title(format!("Overachiever - {}", self.title))
+            .with_inner_size([1000.0, 700.0]);
+
+        if std::mem::take(&mut self.focus_requested) {
+            ctx.send_viewport_cmd_to(self.viewport_id(), egui::ViewportCommand::Focus);
+        }
+
+        ctx.show_viewport_immediate(self.viewport_id(), builder, |ctx, _class| {
+            if ctx.input(|i| i.viewport().close_requested()) {
+                self.open = false;
+                return;
+            }
+
+            egui::TopBottomPanel::top("profile_window_top").show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(format!("{} {}", regular::USER, self.title)).strong());
+                    ui.label(egui::RichText::new(&self.steam_id).weak().small());
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        ui.toggle_value(&mut self.show_stats, format!("{} {}", regular::CHART_LINE, tr("history.stats")));
+                    });
+                });
+            });
+
+            if self.show_stats {
+                egui::SidePanel::right("profile_window_stats")
+                    .default_width(380.0)
+                    .show(ctx, |ui| {
+                        egui::ScrollArea::vertical().show(ui, |ui| {
+                            render_stats_content(ui, self, &StatsPanelConfig::desktop());
+                        });
+                    });
+            }
+
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let owned_count = self.games.iter().filter(|g| g.owned).count();
+                ui.heading(tr_args("library.title", &[("count", &owned_count)]));
+                if let Some(summary) = &self.merged_summary {
+                    ui.label(egui::RichText::new(summary).weak());
+                }
+                ui.separator();
+
+                render_filter_bar(ui, self);
+                ui.add_space(4.0);
+
+                let filtered_indices = get_filtered_indices(self);
+                if filtered_indices.len() != owned_count {
+                    ui.label(tr_args("library.showing", &[("shown", &filtered_indices.len()), ("total", &owned_count)]));
+                }
+                render_unowned_section(ui, &self.games);
+
+                let needs_fetch = render_games_table(ui, self, filtered_indices);
+                for appid in needs_fetch {
+                    self.request_achievements(appid);
+                }
+            });
+        });
+
+        self.receive_achievement_loads();
+        if !self.achievement_loads.is_empty() {
+            ctx.request_repaint();
+        }
+    }
+
+    /// Move finished achievement loads into the cache
+    fn receive_achievement_loads(&mut self) {
+        let mut finished = Vec::new();
+        for (&appid, rx) in &self.achievement_loads {
+            match rx.try_recv() {
+                Ok(result) => finished.push((appid, Some(result))),
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => finished.push((appid, None)),
+                Err(std::sync::mpsc::TryRecvError::Empty) => {}
+            }
+        }
+        for (appid, result) in finished {
+            self.achievement_loads.remove(&appid);
+            match result {
+                Some(Ok(achs)) => {
+                    self.icon_cache.prefetch(achs.iter().flat_map(|a| [a.icon.clone(), a.icon_gray.clone()]));
+                    self.achievements_cache.insert(appid, achs);
+                }
+                Some(Err(e)) => eprintln!("Failed to load achievements for {}: {}", appid, e),
+                None => {}
+            }
+        }
+    }
+}
+
+impl SteamOverachieverApp {
+    /// Open (or focus) a window for another local profile
+    pub(crate) fn open_profile_window(&mut self, steam_id: &str, display_name: Option<String>) {
+        if steam_id == self.config.steam_id {
+            self.status = tr("profile_window.already_open").to_string();
+            return;
+        }
+        if let Some(window) = self.profile_windows.iter_mut().find(|w| w.steam_id == steam_id) {
+            window.focus_requested = true;
+            return;
+        }
+
+        let theme = crate::app::panels::top::theme::theme_colors(&self.config);
+        let (steam_id, icon_cache, db_worker, time_display) =
+            (steam_id.to_string(), self.icon_cache.clone(), self.db_worker.clone(), self.config.time_display.clone());
+        self.db_query(
+            move |conn| Ok(ProfileWindow::load(conn, &steam_id, display_name, icon_cache, db_worker, theme, time_display)),
+            |app, loaded| match loaded {
+                // Opened twice before the first one finished loading
+                Ok(Ok(window)) if app.profile_windows.iter().any(|w| w.steam_id == window.steam_id) => {}
+                Ok(Ok(window)) => app.profile_windows.push(window),
+                Ok(Err(e)) => app.status = e,
+                Err(e) => app.status = tr_args("profile_window.db_failed", &[("error", &e)]),
+            },
+        );
+    }
+
+    /// Open a merged library window for several local profiles
+    pub(crate) fn open_merged_window(&mut self, profiles: Vec<(String, Option<String>)>, dedupe: bool) {
+        let theme = crate::app::panels::top::theme::theme_colors(&self.config);
+        let (icon_cache, db_worker, time_display) = (self.icon_cache.clone(), self.db_worker.clone(), self.config.time_display.clone());
+        self.db_query(
+            move |conn| Ok(ProfileWindow::load_merged(conn, &profiles, dedupe, icon_cache, db_worker, theme, time_display)),
+            |app, loaded| match loaded {
+                Ok(Ok(window)) => {
+                    // Replace an open merged window for the same accounts (the dedupe option may differ)
+                    app.profile_windows.retain(|w| w.steam_id != window.steam_id);
+                    app.profile_windows.push(window);
+                }
+                Ok(Err(e)) => app.status = e,
+                Err(e) => app.status = tr_args("profile_window.db_failed", &[("error", &e)]),
+            },
+        );
+    }
+}
+
+impl StatsPanelPlatform for ProfileWindow {
+    fn games(&self) -> &[Game] {
+        &self.games
+    }
+
+    fn run_history(&self) -> &[RunHistory] {
+        &self.run_history
+    }
+
+    fn achievement_history(&self) -> &[AchievementHistory] {
+        &self.achievement_history
+    }
+
+    fn log_entries(&self) -> &[LogEntry] {
+        &self.log_entries
+    }
+
+    fn include_unplayed_in_avg(&self) -> bool {
+        self.include_unplayed_in_avg
+    }
+
+    fn set_include_unplayed_in_avg(&mut self, value: bool) {
+        self.include_unplayed_in_avg = value;
+    }
+
+    fn theme_colors(&self) -> ThemeColors {
+        self.theme
+    }
+
+    fn time_display(&self) -> TimeDisplaySettings {
+        self.time_display.clone()
+    }
+
+    fn game_icon_source(&self, ui: &Ui, appid: u64, icon_hash: &str) -> egui::ImageSource<'static> {
+        let game_icon_url = format!(
+            "https://media.steampowered.com/steamcommunity/public/images/apps/{}/{}.jpg",
+            appid, icon_hash
+        );
+
+        let cache_uri = format!("bytes://log_game/{}", appid);
+        match self.icon_cache.image_uri(ui.ctx(), &game_icon_url, cache_uri) {
+            Some(uri) => egui::ImageSource::Uri(uri.into()),
+            None => egui::ImageSource::Uri(game_icon_url.into()),
+        }
+    }
+
+    fn achievement_icon_source(&self, ui: &Ui, icon_url: &str) -> egui::ImageSource<'static> {
+        let cache_uri = format!("bytes://log_ach/{}", icon_url.replace(['/', ':', '.'], "_"));
+        match self.icon_cache.image_uri(ui.ctx(), icon_url, cache_uri) {
+            Some(uri) => egui::ImageSource::Uri(uri.into()),
+            None => egui::ImageSource::Uri(icon_url.to_string().into()),
+        }
+    }
+}
+
+impl GamesTablePlatform for ProfileWindow {
+    fn sort_column(&self) -> SortColumn {
+        self.sort_column
+    }
+
+    fn sort_order(&self) -> SortOrder {
+        self.sort_order
+    }
+
+    fn set_sort(&mut self, column: SortColumn) {
+        if self.sort_column == column {
+            self.sort_order = self.sort_order.toggle();
+        } else {
+            self.sort_column = column;
+            self.sort_order = SortOrder::Ascending;
+        }
+        self.sort_rows();
+    }
+
+    fn filter_name(&self) -> &str {
+        &self.filter_name
+    }
+
+    fn set_filter_name(&mut self, name: String) {
+        self.filter_name = name;
+    }
+
+    fn filter_achievements(&self) -> TriFilter {
+        self.filter_achievements
+    }
+
+    fn set_filter_achievements(&mut self, filter: TriFilter) {
+        self.filter_achievements = filter;
+    }
+
+    fn filter_playtime(&self) -> TriFilter {
+        self.filter_playtime
+    }
+
+    fn set_filter_playtime(&mut self, filter: TriFilter) {
+        self.filter_playtime = filter;
+    }
+
+    fn row_owner(&self, index: usize) -> Option<&str> {
+        self.row_owners.get(index).map(|owner| owner.label.as_str())
+    }
+
+    fn is_expanded(&self, appid: u64) -> bool {
+        self.expanded_rows.contains(&appid)
+    }
+
+    fn toggle_expanded(&mut self, appid: u64) {
+        if !self.expanded_rows.remove(&appid) {
+            self.expanded_rows.insert(appid);
+        }
+    }
+
+    fn get_cached_achievements(&self, appid: u64) -> Option<&Vec<GameAchievement>> {
+        self.achievements_cache.get(&appid)
+    }
+
+    fn request_achievements(&mut self, appid: u64) {
+        if self.achievements_cache.contains_key(&appid) || self.achievement_loads.contains_key(&appid) {
+            return;
+        }
+        let owner = self.achievements_owner(appid).to_string();
+        let rx = self.db_worker.query(move |conn| get_game_achievements(conn, &owner, appid));
+        self.achievement_loads.insert(appid, rx);
+    }
+}
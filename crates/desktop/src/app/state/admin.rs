@@ -0,0 +1,77 @@
+//! Admin dashboard - stats, users, TTB/tag/comment moderation and tag aliases (admin only)
+
+use std::sync::mpsc::channel;
+use std::thread;
+
+use overachiever_core::tr;
+
+use crate::app::{AdminAction, SteamOverachieverApp};
+use crate::cloud_sync;
+
+impl SteamOverachieverApp {
+    /// Open the admin window and load fresh data
+    pub(crate) fn open_admin_window(&mut self) {
+        self.show_admin_window = true;
+        self.refresh_admin_dashboard();
+    }
+
+    /// Fetch stats, users and review queues in the background
+    pub(crate) fn refresh_admin_dashboard(&mut self) {
+        self.spawn_admin_request(None);
+    }
+
+    /// Apply a moderation action, then reload the dashboard
+    pub(crate) fn moderate(&mut self, action: AdminAction) {
+        self.spawn_admin_request(Some(action));
+    }
+
+    fn spawn_admin_request(&mut self, action: Option<AdminAction>) {
+        if self.admin_receiver.is_some() {
+            return;
+        }
+        let Some(token) = self.config.cloud_token.clone() else {
+            self.admin_error = Some(tr("admin.login_required").to_string());
+            return;
+        };
+
+        let (tx, rx) = channel();
+        self.admin_receiver = Some(rx);
+        self.admin_error = None;
+
+        thread::spawn(move || {
+            let result = action
+                .map(|action| match action {
+                    AdminAction::Submission(kind, appid, approve) => cloud_sync::moderate_submission(&token, kind, appid, approve),
+                    AdminAction::Comment(comment_id, remove) => cloud_sync::moderate_comment(&token, comment_id, remove),
+                    AdminAction::SetTagMapping(mapping) => cloud_sync::set_tag_mapping(&token, &mapping),
+                    AdminAction::DeleteTagMapping(tag) => cloud_sync::delete_tag_mapping(&token, &tag),
+                })
+                .unwrap_or(Ok(()))
+                .and_then(|_| cloud_sync::fetch_admin_dashboard(&token));
+            let _ = tx.send(result);
+        });
+    }
+
+    /// Process admin dashboard responses
+    pub(crate) fn admin_tick(&mut self) {
+        let Some(ref receiver) = self.admin_receiver else {
+            return;
+        };
+        match receiver.try_recv() {
+            Ok(Ok(dashboard)) => {
+                // Newly added mappings apply right away, removed ones after a restart
+                self.set_tag_map(overachiever_core::TagMap::new(&dashboard.tag_mappings));
+                self.admin_dashboard = Some(dashboard);
+                self.admin_receiver = None;
+            }
+            Ok(Err(e)) => {
+                self.admin_error = Some(e);
+                self.admin_receiver = None;
+            }
+            Err(std::sync::mpsc::TryRecvError::Empty) => {}
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                self.admin_receiver = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Automatic cloud upload after Update and Full Scan, retried with backoff when it fails
+
+use std::time::{Duration, Instant};
+
+use overachiever_core::tr_args;
+
+use crate::app::SteamOverachieverApp;
+
+/// Waits before retrying a failed automatic upload (the last one repeats)
+const RETRY_DELAYS: [Duration; 4] = [
+    Duration::from_secs(60),
+    Duration::from_secs(5 * 60),
+    Duration::from_secs(15 * 60),
+    Duration::from_secs(60 * 60),
+];
+
+/// Pending automatic upload. Every upload sends the whole library, so scans finishing
+/// while one is queued or in flight just keep it queued instead of stacking more.
+#[derive(Default)]
+pub(crate) struct AutoUploadQueue {
+    pending: bool,
+    /// The running cloud operation is an automatic upload
+    in_flight: bool,
+    /// Failed attempts in a row
+    failures: usize,
+    retry_at: Option<Instant>,
+    last_error: Option<String>,
+}
+
+impl AutoUploadQueue {
+    pub(crate) fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Whether the running cloud operation was started by the queue
+    pub(crate) fn is_in_flight(&self) -> bool {
+        self.in_flight
+    }
+
+    /// Settings line while an upload is waiting for a retry
+    pub(crate) fn describe(&self) -> Option<String> {
+        let error = self.last_error.as_ref()?;
+        let wait = self.retry_at?.saturating_duration_since(Instant::now());
+        Some(tr_args("auto_upload.last_failed", &[("error", error), ("wait", &format_wait(wait))]))
+    }
+
+    fn retry_delay(&self) -> Duration {
+        RETRY_DELAYS[self.failures.saturating_sub(1).min(RETRY_DELAYS.len() - 1)]
+    }
+}
+
+fn format_wait(wait: Duration) -> String {
+    let secs = wait.as_secs();
+    if secs >= 60 {
+        tr_args("auto_upload.minutes", &[("count", &secs.div_ceil(60))])
+    } else {
+        tr_args("auto_upload.seconds", &[("count", &secs)])
+    }
+}
+
+impl SteamOverachieverApp {
+    /// Queue an upload after a successful Update or Full Scan (if enabled and linked)
+    pub(crate) fn queue_auto_upload(&mut self) {
+        if !self.config.auto_upload_after_scan || self.config.cloud_token.is_none() {
+            return;
+        }
+        self.auto_upload.pending = true;
+    }
+
+    /// Start the queued upload once nothing else is running and any retry wait has passed
+    pub(crate) fn auto_upload_tick(&mut self) {
+        let queue = &self.auto_upload;
+        if !queue.pending || queue.in_flight || queue.retry_at.is_some_and(|at| Instant::now() < at) {
+            return;
+        }
+        if self.config.cloud_token.is_none() {
+            self.auto_upload.clear();
+            return;
+        }
+        if self.state.is_busy() || self.cloud_op_receiver.is_some() || self.pending_cloud_action.is_some() {
+            return;
+        }
+
+        self.auto_upload.pending = false;
+        self.auto_upload.in_flight = true;
+        // A failure before anything is sent (database unreadable) is reported by upload_to_cloud
+        self.upload_to_cloud();
+    }
+
+    /// The automatic upload went through
+    pub(crate) fn auto_upload_succeeded(&mut self, message: &str) {
+        let queue = &mut self.auto_upload;
+        queue.in_flight = false;
+        queue.failures = 0;
+        queue.retry_at = None;
+        queue.last_error = None;
+        self.show_toast(tr_args("auto_upload.toast", &[("message", &message)]));
+    }
+
+    /// The automatic upload failed, queue it again after a backoff
+    pub(crate) fn auto_upload_failed(&mut self, error: String) {
+        let queue = &mut self.auto_upload;
+        queue.in_flight = false;
+        queue.pending = true;
+        queue.failures += 1;
+        let delay = queue.retry_delay();
+        queue.retry_at = Some(Instant::now() + delay);
+        self.show_toast(tr_args("auto_upload.failed_toast", &[("wait", &format_wait(delay))]));
+        self.auto_upload.last_error = Some(error);
+    }
+}
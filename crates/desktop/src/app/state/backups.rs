@@ -0,0 +1,65 @@
+//! Restoring the database from a backup
+
+use std::path::Path;
+use overachiever_core::{tr_args, AchievementHistory, Game, Goal, RunHistory, WishlistItem};
+
+use crate::app::SteamOverachieverApp;
+use crate::db::{get_achievement_history, get_all_games, get_goals, get_run_history, get_wishlist};
+
+/// What the main window shows of a profile, read in one job
+type ProfileData = (Vec<Game>, Vec<RunHistory>, Vec<AchievementHistory>, Vec<Goal>, Vec<WishlistItem>);
+
+impl SteamOverachieverApp {
+    /// Replace the database with a backup and reload everything shown from it.
+    /// The DB worker closes its connection while the file is swapped.
+    pub(crate) fn restore_database_backup(&mut self, backup: &Path) {
+        let backup = backup.to_path_buf();
+        let source = backup.clone();
+        self.db_worker.replace_database(
+            move || crate::backup::restore_backup(&source),
+            self.apply_in_next_frame(move |app: &mut Self, result: Result<(), String>| match result {
+                Ok(()) => {
+                    // The filtered game may not be in the reloaded library
+                    app.log_filter = Default::default();
+                    app.reload_from_database();
+                    app.load_rival_games();
+                    app.status = tr_args("backups.restored", &[("path", &backup.display())]);
+                }
+                Err(e) => app.status = e,
+            }),
+        );
+    }
+
+    /// Reload the library, history, log, goals and wishlist of the profile from the database
+    /// (after a restore or merge)
+    pub(crate) fn reload_from_database(&mut self) {
+        let steam_id = self.config.steam_id.clone();
+        self.db_query(
+            move |conn| -> rusqlite::Result<ProfileData> {
+                Ok((
+                    get_all_games(conn, &steam_id)?,
+                    get_run_history(conn, &steam_id)?,
+                    get_achievement_history(conn, &steam_id)?,
+                    get_goals(conn, &steam_id)?,
+                    get_wishlist(conn, &steam_id)?,
+                ))
+            },
+            |app, result| match result {
+                Ok((games, run_history, achievement_history, goals, wishlist)) => {
+                    app.games = games;
+                    app.run_history = run_history;
+                    app.achievement_history = achievement_history;
+                    app.goals = goals;
+                    app.wishlist = wishlist;
+                    app.achievements_cache.clear();
+                    app.achievement_loads.clear();
+                    app.sort_games();
+                    // Reload TTB cache from database (in case user had cached TTB data before)
+                    app.load_ttb_cache();
+                }
+                Err(e) => app.status = tr_args("backups.reload_failed", &[("error", &e)]),
+            },
+        );
+        self.reload_log_entries();
+    }
+}
@@ -0,0 +1,144 @@
+//! Built-in benchmark - times common operations and produces a copyable report
+//!
+//! Runs synchronously on the UI thread (a second or two) so it measures the same
+//! code paths the app uses, on this machine.
+
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+use eframe::egui;
+use overachiever_core::{get_filtered_indices, render_games_table, sort_games, Game};
+
+use crate::app::SteamOverachieverApp;
+use crate::app::state::LOG_ENTRIES_LIMIT;
+use crate::db::{get_all_games, get_log_entries};
+use crate::ui::{SortColumn, SortOrder, SortTieBreaker};
+
+/// Rows in the synthetic library used for the render benchmark
+const RENDER_ROWS: usize = 5000;
+/// Frames rendered (after one warm-up frame) for the render benchmark
+const RENDER_FRAMES: u32 = 20;
+/// Repetitions for the fast operations
+const REPEAT: u32 = 10;
+
+/// Run `f` `times` times and return the average duration
+fn time_avg(times: u32, mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..times {
+        f();
+    }
+    start.elapsed() / times
+}
+
+fn ms(d: Duration) -> String {
+    format!("{:.2} ms", d.as_secs_f64() * 1000.0)
+}
+
+/// A library of at least `rows` games, made by repeating the real one with fresh appids
+fn synthetic_library(games: &[Game], rows: usize) -> Vec<Game> {
+    if games.is_empty() {
+        return Vec::new();
+    }
+    (0..rows)
+        .map(|i| {
+            let mut game = games[i % games.len()].clone();
+            game.appid = 1_000_000_000 + i as u64;
+            game
+        })
+        .collect()
+}
+
+impl SteamOverachieverApp {
+    /// Run all benchmarks and store the report for the Debug tab
+    pub(crate) fn run_benchmark(&mut self) {
+        let mut report = String::new();
+        let _ = writeln!(report, "Overachiever benchmark - build #{} ({})", env!("BUILD_NUMBER"), env!("BUILD_DATETIME"));
+        let _ = writeln!(
+            report,
+            "{} {}, {} threads, {} games, {} achievements loaded",
+            std::env::consts::OS,
+            std::env::consts::ARCH,
+            std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+            self.games.len(),
+            self.achievements_cache.values().map(Vec::len).sum::<usize>(),
+        );
+        let _ = writeln!(report);
+
+        // Database load (what startup does), timed on the DB worker the UI queries go through
+        let steam_id = self.config.steam_id.clone();
+        let db_load = self
+            .db_worker
+            .query(move |conn| {
+                Ok(time_avg(REPEAT, || {
+                    let _ = get_all_games(conn, &steam_id);
+                    let _ = get_log_entries(conn, &steam_id, LOG_ENTRIES_LIMIT, None, None, None);
+                }))
+            })
+            .recv()
+            .ok()
+            .and_then(Result::ok)
+            .unwrap_or_default();
+        let _ = writeln!(report, "DB load (games + log):        {}", ms(db_load));
+
+        // Filtering with the current filters
+        let filter = time_avg(REPEAT, || {
+            let _ = get_filtered_indices(self);
+        });
+        let _ = writeln!(report, "Filter (current filters):     {}", ms(filter));
+
+        // Sorting by each column
+        for (column, label) in [
+            (SortColumn::Name, "name"),
+            (SortColumn::LastPlayed, "last played"),
+            (SortColumn::Playtime, "playtime"),
+            (SortColumn::AchievementsPercent, "achievement %"),
+        ] {
+            let mut games = self.games.clone();
+            let sort = time_avg(REPEAT, || sort_games(&mut games, column, SortOrder::Descending, SortTieBreaker::default()));
+            let _ = writeln!(report, "Sort by {:<22} {}", format!("{}:", label), ms(sort));
+        }
+
+        // Render frame time for a large library, in an offscreen egui context
+        let synthetic = synthetic_library(&self.games, RENDER_ROWS);
+        if synthetic.is_empty() {
+            let _ = writeln!(report, "Render ({} rows):            skipped (no games)", RENDER_ROWS);
+        } else {
+            let real_games = std::mem::replace(&mut self.games, synthetic);
+            let ctx = egui::Context::default();
+            let frame = |app: &mut Self| {
+                let input = egui::RawInput {
+                    screen_rect: Some(egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(1600.0, 1000.0))),
+                    ..Default::default()
+                };
+                let _ = ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        let indices = (0..app.games.len()).collect();
+                        let _ = render_games_table(ui, app, indices);
+                    });
+                });
+            };
+            frame(self); // warm-up (layout caches, font atlas)
+            let start = Instant::now();
+            for _ in 0..RENDER_FRAMES {
+                frame(self);
+            }
+            let render = start.elapsed() / RENDER_FRAMES;
+            self.games = real_games;
+            let _ = writeln!(report, "Render ({} rows) per frame:  {}", RENDER_ROWS, ms(render));
+        }
+
+        // Icon cache effectiveness since startup
+        let (hits, misses) = self.icon_cache.hit_stats();
+        let rate = if hits + misses > 0 { hits as f64 * 100.0 / (hits + misses) as f64 } else { 0.0 };
+        let _ = writeln!(
+            report,
+            "Icon cache hit rate:          {:.1}% ({} hits, {} misses, {:.1} MB on disk)",
+            rate,
+            hits,
+            misses,
+            self.icon_cache.disk_usage() as f64 / (1024.0 * 1024.0)
+        );
+
+        self.benchmark_report = Some(report);
+    }
+}
@@ -2,12 +2,11 @@
 
 use crate::cloud_sync::{CloudSyncState, CloudOpResult, start_steam_login};
 use crate::db::{
-    import_cloud_sync_data, get_all_achievements_for_export, get_all_games, 
-    get_run_history, get_achievement_history, get_log_entries, open_connection,
-    cache_ttb_times
+    diff_cloud_sync_data, merge_cloud_sync_data, get_all_achievements_for_export, open_connection,
+    get_all_achievement_ratings_with_times, set_achievement_rating
 };
 use crate::steam_library::get_installed_games_with_sizes;
-use overachiever_core::CloudSyncData;
+use overachiever_core::{tr, tr_args, CloudSyncData};
 
 use crate::app::SteamOverachieverApp;
 
@@ -35,8 +34,9 @@ impl SteamOverachieverApp {
                     self.config.cloud_token = Some(result.token);
                     self.config.steam_id = result.steam_id;
                     let _ = self.config.save();
-                    self.cloud_sync_state = CloudSyncState::Success("Linked to cloud successfully!".to_string());
+                    self.cloud_sync_state = CloudSyncState::Success(tr("cloud.linked").to_string());
                     self.auth_receiver = None;
+                    self.start_ratings_batch_upload();
                 }
                 Ok(Err(e)) => {
                     self.cloud_sync_state = CloudSyncState::Error(e);
@@ -46,16 +46,73 @@ impl SteamOverachieverApp {
                     // Still waiting
                 }
                 Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    self.cloud_sync_state = CloudSyncState::Error("Login cancelled".to_string());
+                    self.cloud_sync_state = CloudSyncState::Error(tr("cloud.login_cancelled").to_string());
                     self.auth_receiver = None;
                 }
             }
         }
     }
     
+    /// Upload achievement ratings made before linking, in one batch
+    pub(crate) fn start_ratings_batch_upload(&mut self) {
+        let Some(token) = self.config.cloud_token.clone() else {
+            return;
+        };
+        let steam_id = self.config.steam_id.clone();
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.ratings_upload_receiver = Some(rx);
+        
+        std::thread::spawn(move || {
+            let result = open_connection()
+                .map_err(|e| tr_args("profile_window.db_failed", &[("error", &e)]))
+                .and_then(|conn| get_all_achievement_ratings_with_times(&conn, &steam_id)
+                    .map_err(|e| tr_args("cloud.ratings_read_failed", &[("error", &e)])))
+                .and_then(|ratings| crate::cloud_sync::upload_achievement_ratings_batch(&token, &ratings));
+            let _ = tx.send(result);
+        });
+    }
+    
+    /// Apply the merged ratings returned by the batch upload
+    pub(crate) fn ratings_upload_tick(&mut self) {
+        let Some(ref receiver) = self.ratings_upload_receiver else {
+            return;
+        };
+        match receiver.try_recv() {
+            Ok(Ok(merged)) => {
+                self.ratings_upload_receiver = None;
+                let changed: Vec<_> = merged
+                    .iter()
+                    .filter(|(appid, apiname, rating)| self.user_achievement_ratings.get(&(*appid, apiname.clone())) != Some(rating))
+                    .cloned()
+                    .collect();
+                let steam_id = self.config.steam_id.clone();
+                self.db_worker.execute("save synced achievement ratings", move |conn| {
+                    for (appid, apiname, rating) in &changed {
+                        set_achievement_rating(conn, &steam_id, *appid, apiname, *rating)?;
+                    }
+                    Ok(())
+                });
+                let count = merged.len();
+                self.user_achievement_ratings = merged.into_iter().map(|(appid, apiname, rating)| ((appid, apiname), rating)).collect();
+                if count > 0 {
+                    self.cloud_sync_state = CloudSyncState::Success(tr_args("cloud.linked_ratings", &[("count", &count)]));
+                }
+            }
+            Ok(Err(e)) => {
+                self.ratings_upload_receiver = None;
+                self.cloud_sync_state = CloudSyncState::Error(tr_args("cloud.linked_ratings_failed", &[("error", &e)]));
+            }
+            Err(std::sync::mpsc::TryRecvError::Empty) => {}
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                self.ratings_upload_receiver = None;
+            }
+        }
+    }
+    
     /// Unlink from cloud (remove saved token)
     pub(crate) fn unlink_cloud(&mut self) {
         self.config.cloud_token = None;
+        self.auto_upload.clear();
         let _ = self.config.save();
         self.cloud_status = None;
         self.cloud_sync_state = CloudSyncState::NotLinked;
@@ -72,8 +129,16 @@ impl SteamOverachieverApp {
                             self.cloud_sync_state = CloudSyncState::Uploading(progress);
                             return;
                         }
-                        CloudOpResult::UploadSuccess => {
-                            self.cloud_sync_state = CloudSyncState::Success("Data uploaded successfully!".to_string());
+                        CloudOpResult::UploadSuccess | CloudOpResult::DeltaUploadSuccess { .. } => {
+                            let message = match result {
+                                CloudOpResult::DeltaUploadSuccess { changed_games: 0 } => tr("cloud.up_to_date").to_string(),
+                                CloudOpResult::DeltaUploadSuccess { changed_games } => tr_args("cloud.uploaded_changed", &[("count", &changed_games)]),
+                                _ => tr("cloud.uploaded").to_string(),
+                            };
+                            if self.auto_upload.is_in_flight() {
+                                self.auto_upload_succeeded(&message);
+                            }
+                            self.cloud_sync_state = CloudSyncState::Success(message);
                             // Start async status refresh
                             if let Some(token) = &self.config.cloud_token {
                                 self.cloud_op_receiver = Some(crate::cloud_sync::start_status_check(token.clone()));
@@ -81,66 +146,23 @@ impl SteamOverachieverApp {
                             }
                         }
                         CloudOpResult::DownloadSuccess(data) => {
-                            // Import into local database
-                            let conn = match open_connection() {
-                                Ok(c) => c,
-                                Err(e) => {
-                                    self.cloud_sync_state = CloudSyncState::Error(format!("Failed to open database: {}", e));
-                                    self.cloud_op_receiver = None;
-                                    return;
-                                }
-                            };
-                            
-                            // Update steam_id from downloaded data if different
-                            let steam_id = data.steam_id.clone();
-                            if self.config.steam_id != steam_id {
-                                self.config.steam_id = steam_id.clone();
-                                let _ = self.config.save();
-                            }
-                            
-                            let games_count = data.games.len();
-                            let achievements_count = data.achievements.len();
-                            
-                            if let Err(e) = import_cloud_sync_data(&conn, &data) {
-                                self.cloud_sync_state = CloudSyncState::Error(format!("Failed to import data: {}", e));
-                                self.cloud_op_receiver = None;
-                                return;
-                            }
-                            
-                            // Reload data from database
-                            self.games = get_all_games(&conn, &steam_id).unwrap_or_default();
-                            self.run_history = get_run_history(&conn, &steam_id).unwrap_or_default();
-                            self.achievement_history = get_achievement_history(&conn, &steam_id).unwrap_or_default();
-                            self.log_entries = get_log_entries(&conn, &steam_id, 30).unwrap_or_default();
-                            
-                            self.sort_games();
-                            
-                            // Reload TTB cache from database (in case user had cached TTB data before)
-                            self.load_ttb_cache();
-                            
-                            // Fetch TTB times from server for all games and cache locally
-                            let appids: Vec<u64> = self.games.iter().map(|g| g.appid).collect();
-                            if !appids.is_empty() {
-                                if let Ok(ttb_times) = crate::cloud_sync::fetch_ttb_batch(&appids) {
-                                    // Cache each TTB time locally
-                                    for times in ttb_times {
-                                        if let Ok(conn) = open_connection() {
-                                            let _ = cache_ttb_times(&conn, &times);
-                                        }
-                                        self.ttb_cache.insert(times.appid, times);
+                            // Show what a merge would change before touching local data
+                            self.db_query(
+                                move |conn| diff_cloud_sync_data(conn, &data).map(|diff| (data, diff)),
+                                |app, result| match result {
+                                    Ok(import) => {
+                                        app.pending_cloud_import = Some(import);
+                                        app.cloud_sync_state = CloudSyncState::Idle;
                                     }
-                                }
-                            }
-                            
-                            self.cloud_sync_state = CloudSyncState::Success(format!(
-                                "Downloaded {} games, {} achievements!", 
-                                games_count, 
-                                achievements_count
-                            ));
+                                    Err(e) => {
+                                        app.cloud_sync_state = CloudSyncState::Error(tr_args("cloud.compare_failed", &[("error", &e)]));
+                                    }
+                                },
+                            );
                         }
                         CloudOpResult::DeleteSuccess => {
                             self.cloud_status = None;
-                            self.cloud_sync_state = CloudSyncState::Success("Cloud data deleted successfully!".to_string());
+                            self.cloud_sync_state = CloudSyncState::Success(tr("cloud.deleted").to_string());
                         }
                         CloudOpResult::StatusChecked(status) => {
                             self.cloud_status = Some(status);
@@ -158,7 +180,11 @@ impl SteamOverachieverApp {
                         self.config.cloud_token = None;
                         let _ = self.config.save();
                         self.cloud_sync_state = CloudSyncState::NotLinked;
+                        self.auto_upload.clear();
                     } else {
+                        if self.auto_upload.is_in_flight() {
+                            self.auto_upload_failed(e.clone());
+                        }
                         self.cloud_sync_state = CloudSyncState::Error(e);
                     }
                     self.cloud_op_receiver = None;
@@ -167,13 +193,54 @@ impl SteamOverachieverApp {
                     // Still waiting
                 }
                 Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    self.cloud_sync_state = CloudSyncState::Error("Operation failed unexpectedly".to_string());
+                    if self.auto_upload.is_in_flight() {
+                        self.auto_upload_failed(tr("cloud.upload_stopped").to_string());
+                    }
+                    self.cloud_sync_state = CloudSyncState::Error(tr("cloud.operation_failed").to_string());
                     self.cloud_op_receiver = None;
                 }
             }
         }
     }
     
+    /// Merge a confirmed cloud download into the local database and reload
+    pub(crate) fn apply_cloud_import(&mut self) {
+        let Some((data, _)) = self.pending_cloud_import.take() else {
+            return;
+        };
+
+        // Update steam_id from downloaded data if different
+        let steam_id = data.steam_id.clone();
+        if self.config.steam_id != steam_id {
+            self.config.steam_id = steam_id.clone();
+            let _ = self.config.save();
+        }
+        
+        let games_count = data.games.len();
+        let achievements_count = data.achievements.len();
+        
+        let appids: Vec<u64> = data.games.iter().map(|g| g.appid).collect();
+        self.db_query(
+            move |conn| {
+                crate::backup::backup_or_log("cloud-import");
+                merge_cloud_sync_data(conn, &data)
+            },
+            move |app, result| {
+                if let Err(e) = result {
+                    app.cloud_sync_state = CloudSyncState::Error(tr_args("cloud.import_failed", &[("error", &e)]));
+                    return;
+                }
+                app.reload_from_database();
+                // Fetch TTB times from server for all merged games and cache locally
+                app.start_ttb_batch_download(appids);
+                app.cloud_sync_state = CloudSyncState::Success(tr_args(
+                    "cloud.merged",
+                    &[("games", &games_count), ("achievements", &achievements_count)],
+                ));
+            },
+        );
+    }
+    
     #[allow(dead_code)]
     pub(crate) fn check_cloud_status(&mut self) {
         let token = match &self.config.cloud_token {
@@ -188,6 +255,38 @@ impl SteamOverachieverApp {
         self.cloud_op_receiver = Some(crate::cloud_sync::start_status_check(token));
     }
     
+    /// The local library as sync data (cloud upload and `.overach` export), handed to `then`
+    /// once the achievements are read on the DB worker
+    pub(crate) fn gather_sync_data(&self, then: impl FnOnce(&mut Self, Result<CloudSyncData, String>) + Send + 'static) {
+        let steam_id = self.config.steam_id.clone();
+        
+        // Filter out private games if hide_private_games is enabled
+        let games = if self.config.hide_private_games {
+            self.games.iter().filter(|g| !g.steam_private).cloned().collect()
+        } else {
+            self.games.clone()
+        };
+        let run_history = self.run_history.clone();
+        let achievement_history = self.achievement_history.clone();
+        let goals = self.goals.clone();
+
+        self.db_query(
+            move |conn| {
+                let achievements = get_all_achievements_for_export(conn, &steam_id)?;
+                Ok(CloudSyncData {
+                    steam_id,
+                    games,
+                    achievements,
+                    run_history,
+                    achievement_history,
+                    goals,
+                    exported_at: chrono::Utc::now(),
+                })
+            },
+            |app, data| then(app, data.map_err(|e| tr_args("cloud.achievements_failed", &[("error", &e)]))),
+        );
+    }
+    
     pub(crate) fn upload_to_cloud(&mut self) {
         let token = match &self.config.cloud_token {
             Some(t) => t.clone(),
@@ -197,51 +296,29 @@ impl SteamOverachieverApp {
             }
         };
         
-        let steam_id = self.config.steam_id.clone();
-        
         self.cloud_sync_state = CloudSyncState::Uploading(crate::cloud_sync::UploadProgress::default());
         
-        // Gather data from local database (this is fast, so we do it synchronously)
-        let conn = match open_connection() {
-            Ok(c) => c,
-            Err(e) => {
-                self.cloud_sync_state = CloudSyncState::Error(format!("Failed to open database: {}", e));
-                return;
-            }
-        };
-        
-        let achievements = match get_all_achievements_for_export(&conn, &steam_id) {
-            Ok(a) => a,
-            Err(e) => {
-                self.cloud_sync_state = CloudSyncState::Error(format!("Failed to get achievements: {}", e));
-                return;
-            }
-        };
-        
-        // Filter out private games from upload if hide_private_games is enabled
-        let games_to_upload = if self.config.hide_private_games {
-            self.games.iter().filter(|g| !g.steam_private).cloned().collect()
-        } else {
-            self.games.clone()
-        };
-
-        let data = CloudSyncData {
-            steam_id: steam_id.clone(),
-            games: games_to_upload,
-            achievements,
-            run_history: self.run_history.clone(),
-            achievement_history: self.achievement_history.clone(),
-            exported_at: chrono::Utc::now(),
-        };
-        
-        // Collect install sizes from ACF files (for community database)
-        let install_sizes: Vec<(u64, u64)> = get_installed_games_with_sizes()
-            .into_iter()
-            .filter_map(|info| info.size_on_disk.map(|size| (info.appid, size)))
-            .collect();
-        
-        // Start async upload (includes size submission)
-        self.cloud_op_receiver = Some(crate::cloud_sync::start_upload_with_sizes(token, data, install_sizes));
+        self.gather_sync_data(move |app, data| {
+            let data = match data {
+                Ok(d) => d,
+                Err(e) => {
+                    if app.auto_upload.is_in_flight() {
+                        app.auto_upload_failed(e.clone());
+                    }
+                    app.cloud_sync_state = CloudSyncState::Error(e);
+                    return;
+                }
+            };
+            
+            // Collect install sizes from ACF files (for community database)
+            let install_sizes: Vec<(u64, u64)> = get_installed_games_with_sizes()
+                .into_iter()
+                .filter_map(|info| info.size_on_disk.map(|size| (info.appid, size)))
+                .collect();
+            
+            // Start async upload (includes size submission)
+            app.cloud_op_receiver = Some(crate::cloud_sync::start_upload_with_sizes(token, data, install_sizes));
+        });
     }
     
     pub(crate) fn download_from_cloud(&mut self) {
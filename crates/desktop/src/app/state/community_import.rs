@@ -0,0 +1,117 @@
+//! Steam Community XML import - bootstrap the library without a Steam Web API key
+
+use std::path::Path;
+use std::sync::mpsc::channel;
+use std::thread;
+
+use overachiever_core::{tr, tr_args};
+
+use crate::app::SteamOverachieverApp;
+use crate::db::{ensure_user, get_all_games, import_community_games};
+use crate::steam_community::{self, CommunityGamesList};
+
+impl SteamOverachieverApp {
+    /// Fetch the public games XML for the configured Steam ID in the background
+    pub(crate) fn start_community_import(&mut self) {
+        let Some(steam_id) = self.config.steam_id_u64() else {
+            self.status = tr("community_import.need_steam_id").to_string();
+            return;
+        };
+        let (tx, rx) = channel();
+        self.community_import_receiver = Some(rx);
+        self.status = tr("community_import.importing").to_string();
+
+        thread::spawn(move || {
+            let result = steam_community::fetch_games_xml(steam_id).and_then(|xml| steam_community::parse_games_xml(&xml));
+            let _ = tx.send(result);
+        });
+    }
+
+    /// Process the background Steam Community import result
+    pub(crate) fn community_import_tick(&mut self) {
+        let Some(ref receiver) = self.community_import_receiver else {
+            return;
+        };
+        match receiver.try_recv() {
+            Ok(Ok(list)) => {
+                self.community_import_receiver = None;
+                self.apply_community_import(list);
+            }
+            Ok(Err(e)) => {
+                self.community_import_receiver = None;
+                self.status = tr_args("community_import.failed", &[("error", &e)]);
+            }
+            Err(std::sync::mpsc::TryRecvError::Empty) => {}
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                self.community_import_receiver = None;
+            }
+        }
+    }
+
+    /// Import games XML and library files dropped onto the window
+    pub(crate) fn handle_dropped_import_files(&mut self, ctx: &eframe::egui::Context) {
+        let dropped = ctx.input(|i| i.raw.dropped_files.clone());
+        for file in dropped {
+            let Some(path) = file.path else {
+                continue;
+            };
+            if path.extension().map(|e| e.eq_ignore_ascii_case("xml")).unwrap_or(false) {
+                self.import_community_xml_file(&path);
+            } else if crate::library_file::is_library_file(&path) {
+                self.open_library_file(&path);
+            }
+        }
+    }
+
+    /// Import a games XML saved from a Steam Community profile
+    pub(crate) fn import_community_xml_file(&mut self, path: &Path) {
+        let result = std::fs::read_to_string(path)
+            .map_err(|e| tr_args("community_import.read_failed", &[("path", &path.display()), ("error", &e)]))
+            .and_then(|xml| steam_community::parse_games_xml(&xml));
+        match result {
+            Ok(list) => self.apply_community_import(list),
+            Err(e) => self.status = tr_args("community_import.failed", &[("error", &e)]),
+        }
+    }
+
+    /// Save imported games to the database and reload the library
+    fn apply_community_import(&mut self, list: CommunityGamesList) {
+        // Adopt the XML's Steam ID when none is configured yet
+        if let Some(xml_steam_id) = &list.steam_id {
+            if self.config.steam_id.trim().is_empty() {
+                self.config.steam_id = xml_steam_id.clone();
+                let _ = self.config.save();
+            } else if self.config.steam_id.trim() != xml_steam_id {
+                self.status = tr_args(
+                    "community_import.wrong_account",
+                    &[("xml", &xml_steam_id), ("configured", &self.config.steam_id)],
+                );
+                return;
+            }
+        }
+        if self.config.steam_id.trim().is_empty() {
+            self.status = tr("community_import.steam_id_first").to_string();
+            return;
+        }
+
+        let steam_id = self.config.steam_id.clone();
+        let imported = list.games.len();
+        self.db_query(
+            move |conn| {
+                ensure_user(conn, &steam_id)?;
+                let added = import_community_games(conn, &steam_id, &list.games)?;
+                Ok((added, get_all_games(conn, &steam_id)?))
+            },
+            move |app, result| match result {
+                Ok((added, games)) => {
+                    app.games = games;
+                    app.sort_games();
+                    app.status = tr_args("community_import.done", &[("count", &imported), ("new", &added)]);
+                }
+                Err(e) => {
+                    app.status = tr_args("community_import.failed", &[("error", &e)]);
+                }
+            },
+        );
+    }
+}
@@ -0,0 +1,44 @@
+//! Community completion stats (average completion, 100% rate, median playtime among all
+//! Overachiever users) - fetched on demand when a game row is expanded
+
+use std::sync::mpsc::channel;
+use std::thread;
+use crate::app::SteamOverachieverApp;
+use crate::cloud_sync;
+
+impl SteamOverachieverApp {
+    /// Start fetching a game's community stats (one request at a time, each game once per session)
+    pub(crate) fn start_community_stats_fetch(&mut self, appid: u64) {
+        if self.community_stats_receiver.is_some() || !self.community_stats_requested.insert(appid) {
+            return;
+        }
+
+        let (tx, rx) = channel();
+        self.community_stats_receiver = Some(rx);
+        thread::spawn(move || {
+            let _ = tx.send((appid, cloud_sync::fetch_community_game_stats(appid)));
+        });
+    }
+
+    /// Check for a finished community stats fetch
+    pub(crate) fn community_stats_tick(&mut self) {
+        let Some(receiver) = &self.community_stats_receiver else {
+            return;
+        };
+        match receiver.try_recv() {
+            Ok((appid, result)) => {
+                match result {
+                    Ok(stats) => {
+                        self.community_game_stats.insert(appid, stats);
+                    }
+                    Err(e) => eprintln!("Failed to fetch community stats for {}: {}", appid, e),
+                }
+                self.community_stats_receiver = None;
+            }
+            Err(std::sync::mpsc::TryRecvError::Empty) => {}
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                self.community_stats_receiver = None;
+            }
+        }
+    }
+}
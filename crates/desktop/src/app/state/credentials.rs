@@ -0,0 +1,68 @@
+//! Steam API key and Steam ID check when they are entered in settings
+
+use std::sync::mpsc::channel;
+use std::thread;
+
+use eframe::egui;
+
+use crate::app::SteamOverachieverApp;
+use crate::db::set_user_profile;
+use crate::steam_api::fetch_player_summary;
+
+impl SteamOverachieverApp {
+    /// Look up the configured Steam ID with the configured key in the background.
+    /// A check still running is superseded (its result is dropped with the old receiver).
+    pub(crate) fn start_credentials_check(&mut self) {
+        let steam_key = self.config.steam_web_api_key.trim().to_string();
+        let steam_id = self.config.steam_id.trim().to_string();
+        if steam_key.is_empty() || steam_id.is_empty() {
+            self.credentials_check = None;
+            self.credentials_check_receiver = None;
+            return;
+        }
+        let (tx, rx) = channel();
+        self.credentials_check_receiver = Some(rx);
+        thread::spawn(move || {
+            let _ = tx.send(fetch_player_summary(&steam_key, &steam_id));
+        });
+    }
+
+    /// Pick up the check result and remember the resolved persona name and avatar
+    pub(crate) fn credentials_check_tick(&mut self) {
+        let Some(receiver) = &self.credentials_check_receiver else {
+            return;
+        };
+        match receiver.try_recv() {
+            Ok(result) => {
+                if let Ok(summary) = &result {
+                    // A pasted profile URL or custom name becomes the resolved Steam ID
+                    if summary.steam_id != self.config.steam_id {
+                        self.config.steam_id = summary.steam_id.clone();
+                        let _ = self.config.save();
+                    }
+                    let (steam_id, persona_name, avatar_url) =
+                        (summary.steam_id.clone(), summary.persona_name.clone(), summary.avatar_url.clone());
+                    self.db_worker.execute("save Steam profile", move |conn| {
+                        set_user_profile(conn, &steam_id, &persona_name, avatar_url.as_deref())
+                    });
+                    self.steam_persona = Some((summary.persona_name.clone(), summary.avatar_url.clone()));
+                }
+                self.credentials_check = Some(result);
+                self.credentials_check_receiver = None;
+            }
+            Err(std::sync::mpsc::TryRecvError::Empty) => {}
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                self.credentials_check_receiver = None;
+            }
+        }
+    }
+
+    /// The Steam avatar from the icon cache (downloaded on first use)
+    pub(crate) fn steam_avatar_source(&self, ctx: &egui::Context, avatar_url: &str) -> egui::ImageSource<'static> {
+        let cache_uri = format!("bytes://steam_avatar/{}", avatar_url.replace(['/', ':', '.'], "_"));
+        match self.icon_cache.image_uri(ctx, avatar_url, cache_uri) {
+            Some(uri) => egui::ImageSource::Uri(uri.into()),
+            None => egui::ImageSource::Uri(avatar_url.to_string().into()),
+        }
+    }
+}
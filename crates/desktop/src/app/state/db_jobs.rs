@@ -0,0 +1,93 @@
+//! Results of database jobs sent to the DB worker from the UI
+
+use std::sync::mpsc::TryRecvError;
+use std::sync::Arc;
+
+use rusqlite::Connection;
+
+use crate::app::SteamOverachieverApp;
+use crate::db::get_game_achievements;
+
+/// A finished query, applied to the app on the UI thread
+pub(crate) type DbResult = Box<dyn FnOnce(&mut SteamOverachieverApp) + Send>;
+
+impl SteamOverachieverApp {
+    /// Run `job` on the DB worker and `apply` its result to the app in the next frame
+    /// (from `db_jobs_tick`), so the render thread never waits for the database
+    pub(crate) fn db_query<T: Send + 'static>(
+        &self,
+        job: impl FnOnce(&mut Connection) -> rusqlite::Result<T> + Send + 'static,
+        apply: impl FnOnce(&mut Self, rusqlite::Result<T>) + Send + 'static,
+    ) {
+        self.db_worker.query_then(job, self.apply_in_next_frame(apply));
+    }
+
+    /// Send a result from the DB worker back to the UI thread, where `apply` gets it
+    pub(crate) fn apply_in_next_frame<T: Send + 'static>(
+        &self,
+        apply: impl FnOnce(&mut Self, T) + Send + 'static,
+    ) -> impl FnOnce(T) + Send + 'static {
+        let results = self.db_results_tx.clone();
+        // Held until the result is sent, see `db_jobs_running`
+        let running = Arc::clone(&self.db_jobs_token);
+        move |result| {
+            let _ = results.send(Box::new(move |app: &mut SteamOverachieverApp| apply(app, result)));
+            drop(running);
+        }
+    }
+
+    /// Whether `db_query` jobs are queued or running (the UI keeps repainting to pick them up)
+    pub(crate) fn db_jobs_running(&self) -> bool {
+        Arc::strong_count(&self.db_jobs_token) > 1
+    }
+
+    /// Load a game's achievements on the DB worker unless they are cached or already loading
+    pub(crate) fn load_achievements(&mut self, appid: u64) {
+        if self.achievements_cache.contains_key(&appid) || self.achievement_loads.contains_key(&appid) {
+            return;
+        }
+        let steam_id = self.config.steam_id.clone();
+        let rx = self.db_worker.query(move |conn| get_game_achievements(conn, &steam_id, appid));
+        self.achievement_loads.insert(appid, rx);
+    }
+
+    /// Apply finished `db_query` results and move finished achievement loads into the cache
+    pub(crate) fn db_jobs_tick(&mut self) {
+        // Checked before draining: a job finishing in between is picked up next frame
+        self.db_jobs_were_running = self.db_jobs_running();
+        while let Ok(apply) = self.db_results_rx.try_recv() {
+            apply(self);
+        }
+
+        let mut finished = Vec::new();
+        for (&appid, rx) in &self.achievement_loads {
+            match rx.try_recv() {
+                Ok(result) => finished.push((appid, Some(result))),
+                Err(TryRecvError::Disconnected) => finished.push((appid, None)),
+                Err(TryRecvError::Empty) => {}
+            }
+        }
+
+        for (appid, result) in finished {
+            self.achievement_loads.remove(&appid);
+            match result {
+                Some(Ok(achs)) => {
+                    // Fetch icons now so the expanded row (and later offline views) show them instantly
+                    let game_icon = self.games.iter()
+                        .find(|g| g.appid == appid)
+                        .and_then(|g| g.img_icon_url.as_ref())
+                        .filter(|hash| !hash.is_empty())
+                        .map(|hash| format!("https://media.steampowered.com/steamcommunity/public/images/apps/{}/{}.jpg", appid, hash));
+                    self.icon_cache.prefetch(
+                        achs.iter()
+                            .flat_map(|a| [a.icon.clone(), a.icon_gray.clone()])
+                            .chain(game_icon)
+                    );
+                    self.achievements_cache.insert(appid, achs);
+                }
+                Some(Err(e)) => eprintln!("Failed to load achievements for {}: {}", appid, e),
+                None => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Opening `overachiever://` links and library files - from the command line at startup
+//! or handed over by another instance
+
+use eframe::egui;
+use overachiever_core::{tr_args, StatsPanelPlatform};
+
+use crate::app::{CentralTab, SteamOverachieverApp};
+use crate::deep_link::{DeepLink, InstanceMessage};
+
+impl SteamOverachieverApp {
+    /// Jump to the game (and achievement) a link points at
+    pub(crate) fn open_deep_link(&mut self, link: DeepLink) {
+        let (appid, apiname) = match link {
+            DeepLink::Game { appid } => (appid, String::new()),
+            DeepLink::Achievement { appid, apiname } => (appid, apiname),
+        };
+        if !self.games.iter().any(|g| g.appid == appid) {
+            self.status = tr_args("deep_link.not_in_library", &[("appid", &appid)]);
+            return;
+        }
+        self.central_tab = CentralTab::Library;
+        self.navigate_to_achievement(appid, apiname);
+    }
+
+    /// Open the startup link or file and handle messages from other instances
+    pub(crate) fn deep_link_tick(&mut self, ctx: &egui::Context) {
+        if let Some(link) = self.pending_deep_link.take() {
+            self.open_deep_link(link);
+        }
+        if let Some(path) = self.pending_library_file.take() {
+            self.open_library_file(&path);
+        }
+        let Some(receiver) = &self.instance_receiver else {
+            return;
+        };
+        let messages: Vec<InstanceMessage> = receiver.try_iter().collect();
+        for message in messages {
+            // Either way the user expects this window in front
+            ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
+            ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+            match message {
+                InstanceMessage::Focus => {}
+                InstanceMessage::Open(link) => self.open_deep_link(link),
+                InstanceMessage::ImportFile(path) => self.open_library_file(&path),
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! Community difficulty curves and comments - fetched on demand when a game row is expanded.
+//! Average ratings of all owned games are also batch-fetched at startup and cached in SQLite.
+
+use std::sync::mpsc::channel;
+use std::thread;
+use crate::app::SteamOverachieverApp;
+use crate::cloud_sync;
+use crate::db::{cache_community_ratings, get_cached_community_ratings, open_connection, CachedCommunityRatings};
+
+/// Cached community ratings are re-fetched after this long
+const RATINGS_CACHE_TTL_HOURS: i64 = 24;
+
+/// Games per batch ratings request (the server's limit)
+const RATINGS_BATCH_SIZE: usize = 500;
+
+impl SteamOverachieverApp {
+    /// Load cached community ratings (on the DB worker), then batch-fetch those of games
+    /// missing from the cache or expired
+    pub(crate) fn load_community_ratings(&mut self) {
+        self.db_query(|conn| get_cached_community_ratings(conn), |app, cached| match cached {
+            Ok(cached) => app.fetch_community_ratings(cached),
+            Err(e) => eprintln!("Failed to load cached community ratings: {}", e),
+        });
+    }
+
+    fn fetch_community_ratings(&mut self, cached: CachedCommunityRatings) {
+        let expires_before = chrono::Utc::now() - chrono::Duration::hours(RATINGS_CACHE_TTL_HOURS);
+
+        let mut to_fetch = Vec::new();
+        for game in self.games.iter().filter(|g| g.achievements_total.is_some_and(|t| t > 0)) {
+            match cached.get(&game.appid) {
+                Some((cached_at, _)) if *cached_at >= expires_before => {}
+                _ => to_fetch.push(game.appid),
+            }
+        }
+        for (appid, (_, ratings)) in cached {
+            self.difficulty_curves.entry(appid).or_insert(ratings);
+        }
+
+        if to_fetch.is_empty() || self.ratings_batch_receiver.is_some() {
+            return;
+        }
+        let (tx, rx) = channel();
+        self.ratings_batch_receiver = Some(rx);
+        thread::spawn(move || {
+            for chunk in to_fetch.chunks(RATINGS_BATCH_SIZE) {
+                let result = cloud_sync::fetch_ratings_batch(chunk);
+                if let Ok(games) = &result {
+                    if let Ok(conn) = open_connection() {
+                        if let Err(e) = cache_community_ratings(&conn, games) {
+                            eprintln!("Failed to cache community ratings: {}", e);
+                        }
+                    }
+                }
+                let failed = result.is_err();
+                if tx.send(result).is_err() || failed {
+                    break;
+                }
+            }
+        });
+    }
+
+    /// Apply finished batch rating fetches
+    pub(crate) fn ratings_batch_tick(&mut self) {
+        let Some(receiver) = &self.ratings_batch_receiver else {
+            return;
+        };
+        loop {
+            match receiver.try_recv() {
+                Ok(Ok(games)) => {
+                    for game in games {
+                        self.difficulty_curves.insert(game.appid, game.achievements);
+                    }
+                }
+                Ok(Err(e)) => eprintln!("Failed to fetch community ratings: {}", e),
+                Err(std::sync::mpsc::TryRecvError::Empty) => break,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    self.ratings_batch_receiver = None;
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Start fetching a game's difficulty curve (one request at a time, each game once per session)
+    pub(crate) fn start_difficulty_fetch(&mut self, appid: u64) {
+        if self.difficulty_receiver.is_some() || !self.difficulty_requested.insert(appid) {
+            return;
+        }
+
+        let (tx, rx) = channel();
+        self.difficulty_receiver = Some(rx);
+        thread::spawn(move || {
+            let _ = tx.send((appid, cloud_sync::fetch_difficulty_curve(appid), cloud_sync::fetch_game_comments(appid)));
+        });
+    }
+
+    /// Check for a finished difficulty curve fetch
+    pub(crate) fn difficulty_tick(&mut self) {
+        let Some(receiver) = &self.difficulty_receiver else {
+            return;
+        };
+        match receiver.try_recv() {
+            Ok((appid, curve, comments)) => {
+                match curve {
+                    Ok(curve) => {
+                        // Keep the ratings cache in step with what the expanded row shows
+                        let ratings = overachiever_core::GameAchievementRatings { appid, achievements: curve.clone() };
+                        self.db_worker.execute("cache community ratings", move |conn| cache_community_ratings(conn, &[ratings]));
+                        self.difficulty_curves.insert(appid, curve);
+                    }
+                    Err(e) => eprintln!("Failed to fetch difficulty curve for {}: {}", appid, e),
+                }
+                match comments {
+                    Ok(comments) => {
+                        self.achievement_comments.insert(appid, comments);
+                    }
+                    Err(e) => eprintln!("Failed to fetch comments for {}: {}", appid, e),
+                }
+                self.difficulty_receiver = None;
+            }
+            Err(std::sync::mpsc::TryRecvError::Empty) => {}
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                self.difficulty_receiver = None;
+            }
+        }
+    }
+
+    /// Report a comment as abusive (once per session)
+    pub(crate) fn report_achievement_comment(&mut self, comment_id: i64) {
+        let Some(token) = &self.config.cloud_token else {
+            return;
+        };
+        if self.reported_comments.insert(comment_id) {
+            cloud_sync::report_comment(token, comment_id);
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Backlog goals - storage and schedule checks
+
+use chrono::{DateTime, Utc};
+use overachiever_core::{goal_progress, Goal};
+
+use crate::app::SteamOverachieverApp;
+use crate::db::{delete_goal, insert_goal};
+
+impl SteamOverachieverApp {
+    /// Add a goal and store it locally (it's uploaded with the next cloud sync)
+    pub(crate) fn add_goal(&mut self, goal: Goal) {
+        let steam_id = self.config.steam_id.clone();
+        let stored = goal.clone();
+        self.db_worker.execute("save goal", move |conn| insert_goal(conn, &steam_id, &stored));
+        self.goals.push(goal);
+    }
+
+    /// Remove the goal created at `created_at`
+    pub(crate) fn remove_goal(&mut self, created_at: DateTime<Utc>) {
+        let steam_id = self.config.steam_id.clone();
+        self.db_worker.execute("delete goal", move |conn| delete_goal(conn, &steam_id, created_at));
+        self.goals.retain(|g| g.created_at != created_at);
+    }
+
+    /// Goals that are behind schedule right now (for the status bar reminder)
+    pub(crate) fn goals_behind_schedule(&self) -> Vec<&Goal> {
+        let perfect_dates = self.achievement_records.as_ref()
+            .map(|r| r.perfect_game_dates.as_slice())
+            .unwrap_or_default();
+        let avg_completion = self.achievement_history.last()
+            .map(|h| h.avg_completion_percent)
+            .unwrap_or(0.0);
+        let now = Utc::now();
+        self.goals.iter()
+            .filter(|goal| {
+                goal_progress(goal, perfect_dates, avg_completion, now, &self.config.time_display)
+                    .is_behind(&goal.kind)
+            })
+            .collect()
+    }
+}
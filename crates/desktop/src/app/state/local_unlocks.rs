@@ -0,0 +1,79 @@
+//! Local unlock detection - keeps the Steam stats cache watcher in line with the settings
+//! and applies the unlocks it reports like a scan would
+
+use overachiever_core::tr_args;
+
+use crate::app::SteamOverachieverApp;
+use crate::db::apply_local_unlocks;
+use crate::steam_local_stats::{LocalStatsChange, LocalStatsWatcher};
+
+impl SteamOverachieverApp {
+    /// Start/stop the stats cache watcher to match the config and apply what it picked up
+    pub(crate) fn local_unlocks_tick(&mut self) {
+        if !self.config.local_unlock_watch || self.config.steam_id.is_empty() {
+            self.local_stats_watcher = None;
+            self.local_stats_error = None;
+            return;
+        }
+
+        // Switching profiles watches the other account's files
+        if self.local_stats_watcher.as_ref().is_some_and(|w| w.steam_id != self.config.steam_id) {
+            self.local_stats_watcher = None;
+        }
+        if self.local_stats_watcher.is_none() {
+            // Not retried until the setting is toggled
+            if self.local_stats_error.is_some() {
+                return;
+            }
+            match LocalStatsWatcher::start(&self.config.steam_id) {
+                Ok(watcher) => self.local_stats_watcher = Some(watcher),
+                Err(e) => {
+                    self.local_stats_error = Some(e);
+                    return;
+                }
+            }
+        }
+
+        let changes = self.local_stats_watcher.as_ref().map(|w| w.poll()).unwrap_or_default();
+        for change in changes {
+            self.apply_local_stats_change(change);
+        }
+    }
+
+    /// Store unlocks the client reported for a game (on the DB worker) and show them
+    fn apply_local_stats_change(&mut self, change: LocalStatsChange) {
+        let appid = change.appid;
+        let unlocks: Vec<(String, i64)> = change.unlocks.into_iter().map(|u| (u.apiname, u.unlocktime)).collect();
+        let steam_id = self.config.steam_id.clone();
+        self.db_query(
+            move |conn| apply_local_unlocks(conn, &steam_id, appid, &unlocks),
+            move |app, newly| match newly {
+                Ok(newly) => app.show_local_unlocks(appid, newly),
+                Err(e) => eprintln!("Failed to store local unlocks for {}: {}", appid, e),
+            },
+        );
+    }
+
+    /// Update the game's row, log and records for unlocks stored from the stats cache
+    fn show_local_unlocks(&mut self, appid: u64, newly: Vec<String>) {
+        let Some(game) = self.games.iter().find(|g| g.appid == appid) else {
+            return;
+        };
+        if newly.is_empty() {
+            return;
+        }
+
+        let total = game.achievements_total.unwrap_or(0);
+        let unlocked = (game.achievements_unlocked.unwrap_or(0) + newly.len() as i32).min(total);
+        let message = match newly.as_slice() {
+            [name] => tr_args("local_unlocks.one", &[("name", name), ("game", &game.name)]),
+            _ => tr_args("local_unlocks.many", &[("count", &newly.len()), ("game", &game.name)]),
+        };
+        self.game_updated(appid, unlocked, total);
+        // Reloaded from the database the next time the game is expanded
+        self.achievements_cache.remove(&appid);
+        self.reload_log_entries();
+        self.status = message.clone();
+        self.show_toast(message);
+    }
+}
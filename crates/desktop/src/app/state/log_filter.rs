@@ -0,0 +1,52 @@
+//! Activity log filter - one game and/or a period, queried from the database next to the
+//! unfiltered log (which the overlay and other views keep using)
+
+use overachiever_core::LogFilter;
+
+use crate::app::SteamOverachieverApp;
+use crate::db::get_log_entries;
+
+/// Entries in the unfiltered activity log
+pub(crate) const LOG_ENTRIES_LIMIT: i32 = 30;
+
+/// Entries shown while filtering (a whole vacation's worth of unlocks)
+const FILTERED_LOG_ENTRIES_LIMIT: i32 = 1000;
+
+impl SteamOverachieverApp {
+    /// Reload the activity log, and its filtered view if a filter is set (on the DB worker)
+    pub(crate) fn reload_log_entries(&mut self) {
+        let steam_id = self.config.steam_id.clone();
+        let filter = self.log_filter.clone();
+        let (since, until) = filter.utc_range(&self.config.time_display);
+        self.db_query(
+            move |conn| {
+                let entries = get_log_entries(conn, &steam_id, LOG_ENTRIES_LIMIT, None, None, None)?;
+                let filtered = if filter.is_active() {
+                    Some(get_log_entries(conn, &steam_id, FILTERED_LOG_ENTRIES_LIMIT, filter.appid, since, until)?)
+                } else {
+                    None
+                };
+                Ok((filter, entries, filtered))
+            },
+            |app, result| match result {
+                // A filter changed meanwhile has its own reload on the way
+                Ok((filter, entries, filtered)) => {
+                    app.log_entries = entries;
+                    if filter == app.log_filter {
+                        app.filtered_log_entries = filtered;
+                    }
+                }
+                Err(e) => eprintln!("Failed to load the activity log: {}", e),
+            },
+        );
+    }
+
+    /// Change the activity log filter and reload its entries
+    pub(crate) fn apply_log_filter(&mut self, filter: LogFilter) {
+        if filter == self.log_filter {
+            return;
+        }
+        self.log_filter = filter;
+        self.reload_log_entries();
+    }
+}
@@ -3,5 +3,35 @@
 mod sorting;
 mod progress;
 mod cloud_sync;
+mod auto_upload;
 mod ttb;
 mod tags;
+mod records;
+mod rival;
+mod sessions;
+mod power;
+mod community_import;
+mod admin;
+mod backups;
+mod difficulty;
+mod community_stats;
+mod rarity;
+mod startup_view;
+mod wishlist;
+mod benchmark;
+mod goals;
+mod overlay;
+mod presence;
+mod local_unlocks;
+mod steam_visibility;
+mod update_check;
+mod snapshots;
+mod deep_links;
+mod credentials;
+mod log_filter;
+mod db_jobs;
+
+pub(crate) use sorting::ResortThrottle;
+pub(crate) use log_filter::LOG_ENTRIES_LIMIT;
+pub(crate) use auto_upload::AutoUploadQueue;
+pub(crate) use db_jobs::DbResult;
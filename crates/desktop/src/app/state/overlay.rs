@@ -0,0 +1,80 @@
+//! Stream overlay - keeps the local overlay server running and its data current
+
+use overachiever_core::{tr_args, LogEntry};
+
+use crate::app::SteamOverachieverApp;
+use crate::overlay_server::{OverlayGame, OverlayServer, OverlaySnapshot, OverlayUnlock};
+
+/// Unlocks listed on the overlay
+const OVERLAY_RECENT_UNLOCKS: usize = 5;
+
+impl SteamOverachieverApp {
+    /// Start/stop the overlay server to match the config and push fresh data to it
+    pub(crate) fn overlay_tick(&mut self) {
+        if !self.config.overlay_server_enabled {
+            self.overlay_server = None;
+            self.overlay_server_error = None;
+            return;
+        }
+
+        let port = self.config.overlay_server_port;
+        if self.overlay_server.as_ref().is_some_and(|s| s.port != port) {
+            self.overlay_server = None;
+        }
+        // A failed start is not retried until the setting changes (which clears the error)
+        if self.overlay_server.is_none() && self.overlay_server_error.is_none() {
+            match OverlayServer::start(port) {
+                Ok(server) => self.overlay_server = Some(server),
+                Err(e) => self.overlay_server_error = Some(tr_args("overlay.listen_failed", &[("port", &port), ("error", &e)])),
+            }
+        }
+
+        if let Some(server) = &self.overlay_server {
+            server.update(self.overlay_snapshot());
+        }
+    }
+
+    /// Current game (or the most recently played one), latest unlocks and average completion
+    fn overlay_snapshot(&self) -> OverlaySnapshot {
+        let game = self
+            .currently_playing
+            .and_then(|appid| self.games.iter().find(|g| g.appid == appid))
+            .map(|g| (g, true))
+            .or_else(|| {
+                self.games
+                    .iter()
+                    .filter(|g| g.rtime_last_played.is_some_and(|t| t > 0))
+                    .max_by_key(|g| g.rtime_last_played)
+                    .map(|g| (g, false))
+            })
+            .map(|(g, playing)| OverlayGame {
+                appid: g.appid,
+                name: g.name.clone(),
+                playing,
+                unlocked: g.achievements_unlocked,
+                total: g.achievements_total,
+                percent: g.completion_percent(),
+            });
+
+        let recent_unlocks = self
+            .log_entries
+            .iter()
+            .filter_map(|entry| match entry {
+                LogEntry::Achievement { game_name, achievement_name, timestamp, achievement_icon, .. } => Some(OverlayUnlock {
+                    game_name: game_name.clone(),
+                    achievement_name: achievement_name.clone(),
+                    icon_url: achievement_icon.clone(),
+                    unlocked_at: *timestamp,
+                }),
+                _ => None,
+            })
+            .take(OVERLAY_RECENT_UNLOCKS)
+            .collect();
+
+        OverlaySnapshot {
+            game,
+            recent_unlocks,
+            avg_completion: self.achievement_history.last().map(|h| h.avg_completion_percent),
+        }
+    }
+}
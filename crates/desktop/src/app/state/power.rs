@@ -0,0 +1,75 @@
+//! Power management - throttles background scans on battery, under load or while playing
+
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
+
+use overachiever_core::tr_args;
+
+use crate::app::SteamOverachieverApp;
+use crate::config::PowerMode;
+use crate::power::{read_power_status, Throttle, BATTERY_SLOWDOWN};
+
+/// How often the power state is re-read
+const POWER_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+impl SteamOverachieverApp {
+    /// Re-read battery/load state periodically
+    pub(crate) fn power_tick(&mut self) {
+        // The full scan thread can't see the app state, it waits on this flag
+        let paused = self.background_throttle() == Throttle::Paused;
+        self.scan_paused.store(paused, Ordering::Relaxed);
+        // Icon prefetching is background work too (no extra delay at full speed)
+        self.icon_cache.set_background_delay(self.throttled_scan_delay(0));
+
+        if let Some(last) = self.power_last_check {
+            if last.elapsed() < POWER_CHECK_INTERVAL {
+                return;
+            }
+        }
+        self.power_status = read_power_status();
+        self.power_last_check = Some(Instant::now());
+    }
+
+    /// Whether background scans wait for the running game (per Steam presence) to close
+    pub(crate) fn paused_for_game(&self) -> bool {
+        self.config.pause_scans_while_playing && self.currently_playing.is_some()
+    }
+
+    /// Name of the game Steam reports as running (its appid if it isn't in the library)
+    pub(crate) fn playing_game_label(&self) -> Option<String> {
+        let appid = self.currently_playing?;
+        let name = self.games.iter().find(|g| g.appid == appid).map(|g| g.name.clone());
+        Some(name.unwrap_or_else(|| tr_args("power.unknown_app", &[("appid", &appid)])))
+    }
+
+    /// Why background scans are paused, for status lines
+    pub(crate) fn scan_pause_reason(&self) -> String {
+        if self.paused_for_game() {
+            tr_args("power.playing", &[("game", &self.playing_game_label().unwrap_or_default())])
+        } else {
+            self.power_status.describe()
+        }
+    }
+
+    /// How background scans should currently run (respects the manual override,
+    /// which only covers the power state, not a running game)
+    pub(crate) fn background_throttle(&self) -> Throttle {
+        if self.paused_for_game() {
+            return Throttle::Paused;
+        }
+        match self.config.power_mode {
+            PowerMode::FullSpeed => Throttle::Full,
+            PowerMode::Auto => self.power_status.throttle(),
+        }
+    }
+
+    /// Delay between background scan items, stretched while throttled.
+    /// Returns None when background work is paused.
+    pub(crate) fn throttled_scan_delay(&self, base_secs: u64) -> Option<Duration> {
+        match self.background_throttle() {
+            Throttle::Full => Some(Duration::from_secs(base_secs)),
+            Throttle::Slow => Some(Duration::from_secs(base_secs.max(1) * BATTERY_SLOWDOWN as u64)),
+            Throttle::Paused => None,
+        }
+    }
+}
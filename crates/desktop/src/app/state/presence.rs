@@ -0,0 +1,38 @@
+//! Presence sharing - keeps the presence connection in line with the settings
+
+use overachiever_core::PresenceGame;
+
+use crate::app::SteamOverachieverApp;
+use crate::presence_client::PresenceClient;
+
+impl SteamOverachieverApp {
+    /// Start/stop presence sharing to match the config and report the current game
+    pub(crate) fn presence_tick(&mut self) {
+        let token = match (&self.config.cloud_token, self.config.share_presence) {
+            (Some(token), true) => token.clone(),
+            _ => {
+                self.presence_client = None;
+                self.presence_users.clear();
+                return;
+            }
+        };
+
+        // Relinking gives a new token
+        if self.presence_client.as_ref().is_some_and(|c| c.token != token) {
+            self.presence_client = None;
+        }
+        let client = self.presence_client.get_or_insert_with(|| PresenceClient::start(token));
+
+        let game = self
+            .currently_playing
+            .and_then(|appid| self.games.iter().find(|g| g.appid == appid))
+            .map(|g| PresenceGame {
+                appid: g.appid,
+                name: g.name.clone(),
+                achievements_unlocked: g.achievements_unlocked,
+                achievements_total: g.achievements_total,
+            });
+        client.set_game(game);
+        self.presence_users = client.others();
+    }
+}
@@ -1,18 +1,34 @@
 //! Progress tracking and background operations
 
 use crate::db::{
-    backfill_run_history_unplayed, get_achievement_history, get_last_update, get_log_entries,
-    get_run_history, has_completed_initial_scan, insert_achievement_history, open_connection,
-    record_initial_scan_complete, update_latest_run_history_unplayed,
+    backfill_achievement_history, backfill_run_history_unplayed, get_achievement_history, get_last_update,
+    get_run_history, has_backfilled_history, has_completed_initial_scan,
+    insert_achievement_history, insert_scan_snapshot, record_history_backfilled, record_initial_scan_complete,
+    update_latest_run_history_unplayed,
 };
-use crate::steam_api::{FetchProgress, ScrapeProgress, UpdateProgress};
+use crate::steam_api::{FetchProgress, ScrapeProgress, ScrapeTarget, UpdateProgress};
 use crate::ui::{AppState, ProgressReceiver, FLASH_DURATION};
+use overachiever_core::{tr, tr_args, GameSnapshot, StatsPanelPlatform};
 
 use std::sync::mpsc::{channel, Sender};
 use std::thread;
 
 use crate::app::SteamOverachieverApp;
 
+/// Minimum time between refreshes of the same game (avoids hammering the Steam API)
+const SINGLE_REFRESH_COOLDOWN_SECS: i64 = 60;
+
+/// Library totals behind an achievement_history entry
+pub(crate) struct HistoryStats {
+    pub(crate) total_achievements: i32,
+    pub(crate) unlocked_achievements: i32,
+    pub(crate) games_with_ach: i32,
+    // Unplayed games that have achievements (run_history.unplayed_games)
+    pub(crate) unplayed_with_ach: i32,
+    // Average over played, not abandoned games
+    pub(crate) avg_completion: f32,
+}
+
 impl SteamOverachieverApp {
     #[allow(dead_code)]
     pub(crate) fn start_fetch(&mut self) {
@@ -21,7 +37,7 @@ impl SteamOverachieverApp {
         }
         
         self.state = AppState::FetchRequesting;
-        self.status = "Starting fetch...".to_string();
+        self.status = tr("progress.starting_fetch").to_string();
         
         let (tx, rx): (Sender<FetchProgress>, _) = channel();
         self.receiver = Some(ProgressReceiver::Fetch(rx));
@@ -34,19 +50,29 @@ impl SteamOverachieverApp {
     }
     
     pub(crate) fn start_scrape(&mut self) {
+        let target = if self.force_full_scan { ScrapeTarget::All } else { ScrapeTarget::Unscraped };
+        self.start_scrape_target(target);
+    }
+    
+    /// Re-scrape only games whose achievement data is older than the stale threshold
+    pub(crate) fn start_stale_refresh(&mut self) {
+        self.start_scrape_target(ScrapeTarget::StaleOlderThan { days: self.config.stale_scrape_days });
+    }
+    
+    fn start_scrape_target(&mut self, target: ScrapeTarget) {
         if self.state.is_busy() {
             return;
         }
         
         self.state = AppState::Scraping { current: 0, total: 0 };
-        self.status = "Starting achievement scrape...".to_string();
+        self.status = tr("progress.starting_scrape").to_string();
         
-        let force = self.force_full_scan;
         let (tx, rx): (Sender<ScrapeProgress>, _) = channel();
         self.receiver = Some(ProgressReceiver::Scrape(rx));
         
+        let paused = self.scan_paused.clone();
         thread::spawn(move || {
-            if let Err(e) = crate::steam_api::scrape_achievements_with_progress(tx.clone(), force) {
+            if let Err(e) = crate::steam_api::scrape_achievements_with_progress(tx.clone(), target, paused) {
                 let _ = tx.send(ScrapeProgress::Error(e.to_string()));
             }
         });
@@ -58,7 +84,7 @@ impl SteamOverachieverApp {
         }
         
         self.state = AppState::UpdateFetchingGames;
-        self.status = "Starting update...".to_string();
+        self.status = tr("progress.starting_update").to_string();
         
         let (tx, rx): (Sender<UpdateProgress>, _) = channel();
         self.receiver = Some(ProgressReceiver::Update(rx));
@@ -78,7 +104,7 @@ impl SteamOverachieverApp {
         
         self.single_game_refreshing = Some(appid);
         self.state = AppState::Idle; // Keep idle state but track the refresh separately
-        self.status = format!("Refreshing game {}...", appid);
+        self.status = self.single_game_refresh_status(appid);
         
         let (tx, rx): (Sender<crate::steam_api::SingleGameRefreshProgress>, _) = channel();
         self.receiver = Some(ProgressReceiver::SingleGameRefresh(rx));
@@ -91,19 +117,45 @@ impl SteamOverachieverApp {
         
         true
     }
-    
-    /// Check if the last update was more than 2 weeks ago
-    pub(crate) fn is_update_stale(&self) -> bool {
-        match self.last_update_time {
-            Some(last_update) => {
-                let two_weeks_ago = chrono::Utc::now() - chrono::Duration::weeks(2);
-                last_update < two_weeks_ago
+
+    /// Refresh a game now, or queue it behind the refresh (or scan) that's running.
+    /// Returns false if it's already refreshing or queued.
+    pub(crate) fn queue_single_game_refresh(&mut self, appid: u64) -> bool {
+        if self.single_game_refreshing == Some(appid) || self.single_game_refresh_queue.contains(&appid) {
+            return false;
+        }
+        if self.single_game_refresh_queue.is_empty() && self.start_single_game_refresh(appid) {
+            return true;
+        }
+        self.single_game_refresh_queue.push_back(appid);
+        true
+    }
+
+    /// Start the next queued single game refresh once nothing else is running
+    pub(crate) fn single_game_refresh_tick(&mut self) {
+        if self.state.is_busy() || self.single_game_refreshing.is_some() {
+            return;
+        }
+        while let Some(appid) = self.single_game_refresh_queue.pop_front() {
+            // A scan may have refreshed it while it waited
+            if !self.is_refresh_on_cooldown(appid) {
+                self.start_single_game_refresh(appid);
+                return;
             }
-            None => true, // Never updated, consider it stale
+        }
+    }
+
+    fn single_game_refresh_status(&self, appid: u64) -> String {
+        match self.single_game_refresh_queue.len() {
+            0 => tr_args("progress.refreshing", &[("appid", &appid)]),
+            queued => tr_args("progress.refreshing_queued", &[("appid", &appid), ("queued", &queued)]),
         }
     }
     
     pub(crate) fn check_progress(&mut self) {
+        // Scan updates from previous frames, batched into one sort
+        self.flush_pending_resort();
+
         let receiver = match self.receiver.take() {
             Some(r) => r,
             None => return,
@@ -115,35 +167,33 @@ impl SteamOverachieverApp {
                     match progress {
                         FetchProgress::Requesting => {
                             self.state = AppState::FetchRequesting;
-                            self.status = "Requesting...".to_string();
+                            self.status = tr("progress.requesting").to_string();
                         }
                         FetchProgress::Downloading => {
                             self.state = AppState::FetchDownloading;
-                            self.status = "Downloading...".to_string();
+                            self.status = tr("progress.downloading").to_string();
                         }
                         FetchProgress::Processing => {
                             self.state = AppState::FetchProcessing;
-                            self.status = "Processing...".to_string();
+                            self.status = tr("progress.processing").to_string();
                         }
                         FetchProgress::Saving => {
                             self.state = AppState::FetchSaving;
-                            self.status = "Saving to database...".to_string();
+                            self.status = tr("progress.saving").to_string();
                         }
                         FetchProgress::Done { games, total } => {
                             self.games = games;
                             self.sort_games();
-                            if let Ok(conn) = open_connection() {
-                                self.run_history = get_run_history(&conn, &self.config.steam_id).unwrap_or_default();
-                                // Mark initial scan complete so tracking starts on next run
-                                let _ = record_initial_scan_complete(&conn);
-                            }
+                            self.reload_run_history();
+                            // Mark initial scan complete so tracking starts on next run
+                            self.db_worker.execute("record the initial scan", record_initial_scan_complete);
 
-                            self.status = format!("Fetched {} games!", total);
+                            self.status = tr_args("progress.fetched", &[("count", &total)]);
                             self.state = AppState::Idle;
                             return;
                         }
                         FetchProgress::Error(e) => {
-                            self.status = format!("Error: {}", e);
+                            self.status = tr_args("progress.error", &[("error", &e)]);
                             self.state = AppState::Idle;
                             return;
                         }
@@ -156,54 +206,54 @@ impl SteamOverachieverApp {
                     match progress {
                         ScrapeProgress::FetchingGames => {
                             self.state = AppState::FetchRequesting;
-                            self.status = "Fetching games...".to_string();
+                            self.status = tr("progress.fetching_games").to_string();
                         }
                         ScrapeProgress::Starting { total } => {
                             self.state = AppState::Scraping { current: 0, total };
-                            self.status = format!("Fetching from Steam Api: 0 / {} games...", total);
+                            self.status = tr_args("progress.scrape_start", &[("total", &total)]);
                         }
                         ScrapeProgress::Scraping { current, total, game_name } => {
                             self.state = AppState::Scraping { current, total };
-                            self.status = format!("Fetching from Steam Api: {} / {}: {}", current, total, game_name);
+                            self.status = tr_args("progress.scrape", &[("current", &current), ("total", &total), ("game", &game_name)]);
+                        }
+                        ScrapeProgress::Paused { current, total } => {
+                            self.state = AppState::Scraping { current, total };
+                            self.status = tr_args(
+                                "progress.scrape_paused",
+                                &[("current", &current), ("total", &total), ("reason", &self.scan_pause_reason())],
+                            );
                         }
                         ScrapeProgress::GameUpdated { appid, unlocked, total } => {
-                            // Update the game in our list immediately
                             if let Some(game) = self.games.iter_mut().find(|g| g.appid == appid) {
-                                game.achievements_unlocked = Some(unlocked);
-                                game.achievements_total = Some(total);
                                 game.last_achievement_scrape = Some(chrono::Utc::now());
                             }
-                            // Track this game for flash animation
-                            self.updated_games.insert(appid, std::time::Instant::now());
-                            // Re-sort to place updated row in correct position
-                            self.sort_games();
+                            self.game_updated(appid, unlocked, total);
                         }
                         ScrapeProgress::Done { games } => {
                             self.games = games;
                             self.sort_games();
 
                             // Reload run history since we fetched games as well
-                            if let Ok(conn) = open_connection() {
-                                self.run_history = get_run_history(&conn, &self.config.steam_id).unwrap_or_default();
-                            }
+                            self.reload_run_history();
 
-                            // Calculate and save achievement stats (before marking initial scan complete)
+                            // Calculate and save achievement stats (before marking initial scan complete,
+                            // the DB worker runs both in this order)
                             self.save_achievement_history();
 
                             // Mark initial scan complete so tracking starts on next run
-                            if let Ok(conn) = open_connection() {
-                                let _ = record_initial_scan_complete(&conn);
-                            }
+                            self.db_worker.execute("record the initial scan", record_initial_scan_complete);
+                            self.backfill_history_once();
 
                             // Refresh installed games detection
                             self.refresh_installed_games();
 
-                            self.status = "Full scan complete!".to_string();
+                            self.status = tr("progress.full_scan_done").to_string();
                             self.state = AppState::Idle;
+                            self.queue_auto_upload();
                             return;
                         }
                         ScrapeProgress::Error(e) => {
-                            self.status = format!("Error: {}", e);
+                            self.status = tr_args("progress.error", &[("error", &e)]);
                             self.state = AppState::Idle;
                             return;
                         }
@@ -216,55 +266,47 @@ impl SteamOverachieverApp {
                     match progress {
                         UpdateProgress::FetchingGames => {
                             self.state = AppState::UpdateFetchingGames;
-                            self.status = "Fetching games...".to_string();
+                            self.status = tr("progress.fetching_games").to_string();
                         }
                         UpdateProgress::FetchingRecentlyPlayed => {
                             self.state = AppState::UpdateFetchingRecentlyPlayed;
-                            self.status = "Fetching recently played games...".to_string();
+                            self.status = tr("progress.fetching_recent").to_string();
                         }
                         UpdateProgress::ScrapingAchievements { current, total, game_name } => {
                             self.state = AppState::UpdateScraping { current, total };
-                            self.status = format!("Updating {} / {}: {}", current, total, game_name);
+                            self.status = tr_args("progress.updating", &[("current", &current), ("total", &total), ("game", &game_name)]);
                         }
                         UpdateProgress::GameUpdated { appid, unlocked, total } => {
-                            // Update the game in our list immediately
                             if let Some(game) = self.games.iter_mut().find(|g| g.appid == appid) {
-                                game.achievements_unlocked = Some(unlocked);
-                                game.achievements_total = Some(total);
                                 game.last_achievement_scrape = Some(chrono::Utc::now());
                             }
-                            // Track this game for flash animation
-                            self.updated_games.insert(appid, std::time::Instant::now());
-                            // Re-sort to place updated row in correct position
-                            self.sort_games();
+                            self.game_updated(appid, unlocked, total);
                         }
                         UpdateProgress::Done { games, updated_count } => {
                             self.games = games;
                             self.sort_games();
 
                             // Reload run history
-                            if let Ok(conn) = open_connection() {
-                                self.run_history = get_run_history(&conn, &self.config.steam_id).unwrap_or_default();
-                                self.last_update_time = get_last_update(&conn).unwrap_or(None);
-                            }
+                            self.reload_run_history();
 
-                            // Calculate and save achievement stats (before marking initial scan complete)
+                            // Calculate and save achievement stats (before marking initial scan complete,
+                            // the DB worker runs both in this order)
                             self.save_achievement_history();
 
                             // Mark initial scan complete so tracking starts on next run
-                            if let Ok(conn) = open_connection() {
-                                let _ = record_initial_scan_complete(&conn);
-                            }
+                            self.db_worker.execute("record the initial scan", record_initial_scan_complete);
+                            self.backfill_history_once();
 
                             // Refresh installed games detection
                             self.refresh_installed_games();
 
-                            self.status = format!("Update complete! {} games updated.", updated_count);
+                            self.status = tr_args("progress.update_done", &[("count", &updated_count)]);
                             self.state = AppState::Idle;
+                            self.queue_auto_upload();
                             return;
                         }
                         UpdateProgress::Error(e) => {
-                            self.status = format!("Error: {}", e);
+                            self.status = tr_args("progress.error", &[("error", &e)]);
                             self.state = AppState::Idle;
                             return;
                         }
@@ -276,27 +318,28 @@ impl SteamOverachieverApp {
                 while let Ok(progress) = rx.try_recv() {
                     match progress {
                         crate::steam_api::SingleGameRefreshProgress::Refreshing { appid } => {
-                            self.status = format!("Refreshing game {}...", appid);
+                            self.status = self.single_game_refresh_status(appid);
                         }
                         crate::steam_api::SingleGameRefreshProgress::Done { appid, game, achievements } => {
                             // Update the game in our list
                             if let Some(g) = self.games.iter_mut().find(|g| g.appid == appid) {
-                                *g = game;
+                                *g = *game;
                             }
                             // Update achievements cache
                             self.achievements_cache.insert(appid, achievements);
+                            self.achievement_records_dirty = true;
                             // Track this game for flash animation
                             self.updated_games.insert(appid, std::time::Instant::now());
                             // Re-sort to place updated row in correct position
                             self.sort_games();
                             self.single_game_refreshing = None;
-                            self.status = "Refresh complete!".to_string();
+                            self.status = tr("progress.refresh_done").to_string();
                             self.state = AppState::Idle;
                             return;
                         }
                         crate::steam_api::SingleGameRefreshProgress::Error(e) => {
                             self.single_game_refreshing = None;
-                            self.status = format!("Refresh error: {}", e);
+                            self.status = tr_args("progress.refresh_error", &[("error", &e)]);
                             self.state = AppState::Idle;
                             return;
                         }
@@ -311,10 +354,39 @@ impl SteamOverachieverApp {
         }
     }
     
+    /// Show a game's new achievement counts: update its row, flash it and re-sort (throttled)
+    pub(crate) fn game_updated(&mut self, appid: u64, unlocked: i32, total: i32) {
+        if let Some(game) = self.games.iter_mut().find(|g| g.appid == appid) {
+            game.achievements_unlocked = Some(unlocked);
+            game.achievements_total = Some(total);
+        }
+        // Track this game for flash animation
+        self.updated_games.insert(appid, std::time::Instant::now());
+        // Re-sort to place updated row in correct position (throttled)
+        self.resort_throttle.request();
+        self.achievement_records_dirty = true;
+    }
+    
     pub(crate) fn games_needing_scrape(&self) -> usize {
         self.games.iter().filter(|g| g.last_achievement_scrape.is_none()).count()
     }
     
+    /// Games scraped before but not within the stale threshold
+    pub(crate) fn games_with_stale_scrape(&self) -> usize {
+        let cutoff = chrono::Utc::now() - chrono::Duration::days(self.config.stale_scrape_days as i64);
+        self.games.iter().filter(|g| g.last_achievement_scrape.is_some_and(|t| t < cutoff)).count()
+    }
+    
+    /// Whether a game was scraped too recently to refresh it again
+    pub(crate) fn is_refresh_on_cooldown(&self, appid: u64) -> bool {
+        let cooldown = chrono::Duration::seconds(SINGLE_REFRESH_COOLDOWN_SECS);
+        self.games
+            .iter()
+            .find(|g| g.appid == appid)
+            .and_then(|g| g.last_achievement_scrape)
+            .is_some_and(|t| chrono::Utc::now() - t < cooldown)
+    }
+    
     /// Returns the flash intensity (0.0 to 1.0) for a game, or None if not flashing
     pub(crate) fn get_flash_intensity(&self, appid: u64) -> Option<f32> {
         if let Some(update_time) = self.updated_games.get(&appid) {
@@ -363,15 +435,50 @@ impl SteamOverachieverApp {
         self.installed_games = crate::steam_library::get_installed_games();
     }
     
-    /// Calculate and save achievement statistics to history
-    pub(crate) fn save_achievement_history(&mut self) {
+    /// Reload the run history and the time of the last update (on the DB worker)
+    fn reload_run_history(&mut self) {
+        let steam_id = self.config.steam_id.clone();
+        self.db_query(
+            move |conn| Ok((get_run_history(conn, &steam_id)?, get_last_update(conn)?)),
+            |app, result| match result {
+                Ok((run_history, last_update)) => {
+                    app.run_history = run_history;
+                    app.last_update_time = last_update;
+                }
+                Err(e) => eprintln!("Failed to load run history: {}", e),
+            },
+        );
+    }
+
+    /// Fill the progress graph backwards from unlock times after the first scan with achievements
+    fn backfill_history_once(&mut self) {
+        let steam_id = self.config.steam_id.clone();
+        self.db_query(
+            move |conn| {
+                if has_backfilled_history(conn) || backfill_achievement_history(conn, &steam_id)? == 0 {
+                    return Ok(None);
+                }
+                record_history_backfilled(conn)?;
+                get_achievement_history(conn, &steam_id).map(Some)
+            },
+            |app, result| match result {
+                Ok(Some(history)) => app.achievement_history = history,
+                Ok(None) => {}
+                Err(e) => eprintln!("Failed to backfill achievement history: {}", e),
+            },
+        );
+    }
+    
+    /// Totals for an achievement history entry (None without any achievement data)
+    pub(crate) fn history_stats(&self) -> Option<HistoryStats> {
         // Calculate stats from games with achievements
-        let games_with_ach: Vec<_> = self.games.iter()
+        let stats_games = self.stats_games();
+        let games_with_ach: Vec<_> = stats_games.iter()
             .filter(|g| g.achievements_total.map(|t| t > 0).unwrap_or(false))
             .collect();
         
         if games_with_ach.is_empty() {
-            return;
+            return None;
         }
         
         let total_achievements: i32 = games_with_ach.iter()
@@ -387,9 +494,9 @@ impl SteamOverachieverApp {
             .filter(|g| g.playtime_forever == 0)
             .count() as i32;
         
-        // Only count played games (playtime > 0) for avg completion
+        // Only count played games (playtime > 0) for avg completion, abandoned ones don't count
         let completion_percents: Vec<f32> = games_with_ach.iter()
-            .filter(|g| g.playtime_forever > 0)
+            .filter(|g| g.playtime_forever > 0 && !g.abandoned)
             .filter_map(|g| g.completion_percent())
             .collect();
         
@@ -398,28 +505,57 @@ impl SteamOverachieverApp {
         } else {
             completion_percents.iter().sum::<f32>() / completion_percents.len() as f32
         };
+
+        Some(HistoryStats {
+            total_achievements,
+            unlocked_achievements,
+            games_with_ach: games_with_ach.len() as i32,
+            unplayed_with_ach,
+            avg_completion,
+        })
+    }
+
+    /// Calculate and save achievement statistics to history
+    pub(crate) fn save_achievement_history(&mut self) {
+        let Some(stats) = self.history_stats() else {
+            return;
+        };
         
-        if let Ok(conn) = open_connection() {
-            // Only record tracking data after the initial scan has established a baseline
-            if has_completed_initial_scan(&conn) {
-                // Update the unplayed count in the most recent run_history entry
-                let _ = update_latest_run_history_unplayed(&conn, &self.config.steam_id, unplayed_with_ach);
+        let steam_id = self.config.steam_id.clone();
+        // Per-game state for the snapshot comparison (the first scan is the baseline)
+        let snapshot: Vec<GameSnapshot> = self.games.iter().map(GameSnapshot::from_game).collect();
+        self.db_query(
+            move |conn| {
+                // Only record tracking data after the initial scan has established a baseline
+                if has_completed_initial_scan(conn) {
+                    // Update the unplayed count in the most recent run_history entry
+                    update_latest_run_history_unplayed(conn, &steam_id, stats.unplayed_with_ach)?;
 
-                // Backfill historical entries that have 0 unplayed (from before this feature)
-                let _ = backfill_run_history_unplayed(&conn, &self.config.steam_id, unplayed_with_ach);
+                    // Backfill historical entries that have 0 unplayed (from before this feature)
+                    backfill_run_history_unplayed(conn, &steam_id, stats.unplayed_with_ach)?;
 
-                let _ = insert_achievement_history(
-                    &conn,
-                    &self.config.steam_id,
-                    total_achievements,
-                    unlocked_achievements,
-                    games_with_ach.len() as i32,
-                    avg_completion,
-                );
-            }
-            self.run_history = get_run_history(&conn, &self.config.steam_id).unwrap_or_default();
-            self.achievement_history = get_achievement_history(&conn, &self.config.steam_id).unwrap_or_default();
-            self.log_entries = get_log_entries(&conn, &self.config.steam_id, 30).unwrap_or_default();
-        }
+                    insert_achievement_history(
+                        conn,
+                        &steam_id,
+                        stats.total_achievements,
+                        stats.unlocked_achievements,
+                        stats.games_with_ach,
+                        stats.avg_completion,
+                    )?;
+                }
+                insert_scan_snapshot(conn, &steam_id, &snapshot)?;
+                Ok((get_run_history(conn, &steam_id)?, get_achievement_history(conn, &steam_id)?))
+            },
+            |app, result| match result {
+                Ok((run_history, achievement_history)) => {
+                    app.run_history = run_history;
+                    app.achievement_history = achievement_history;
+                }
+                Err(e) => eprintln!("Failed to save achievement history: {}", e),
+            },
+        );
+
+        self.reload_log_entries();
+        self.achievement_records_dirty = true;
     }
 }
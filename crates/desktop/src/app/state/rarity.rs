@@ -0,0 +1,70 @@
+//! Global rarity history - snapshots the server takes of each game's unlock percentages,
+//! fetched on demand when a game row is expanded. Without a Steam API key the scrape
+//! can't fetch the percentages itself, so the latest snapshot fills them in instead.
+
+use std::collections::HashMap;
+use std::sync::mpsc::channel;
+use std::thread;
+use crate::app::SteamOverachieverApp;
+use crate::cloud_sync;
+
+impl SteamOverachieverApp {
+    /// Start fetching a game's rarity history (one request at a time, each game once per session)
+    pub(crate) fn start_rarity_fetch(&mut self, appid: u64) {
+        if self.rarity_receiver.is_some() || !self.rarity_requested.insert(appid) {
+            return;
+        }
+
+        let (tx, rx) = channel();
+        self.rarity_receiver = Some(rx);
+        thread::spawn(move || {
+            let _ = tx.send((appid, cloud_sync::fetch_rarity_history(appid)));
+        });
+    }
+
+    /// Check for a finished rarity history fetch
+    pub(crate) fn rarity_tick(&mut self) {
+        let Some(receiver) = &self.rarity_receiver else {
+            return;
+        };
+        match receiver.try_recv() {
+            Ok((appid, result)) => {
+                match result {
+                    Ok(history) => {
+                        if !self.config.has_steam_credentials() {
+                            self.apply_latest_rarity(appid, &history);
+                        }
+                        self.rarity_history.insert(appid, history);
+                    }
+                    Err(e) => eprintln!("Failed to fetch rarity history for {}: {}", appid, e),
+                }
+                self.rarity_receiver = None;
+            }
+            Err(std::sync::mpsc::TryRecvError::Empty) => {}
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                self.rarity_receiver = None;
+            }
+        }
+    }
+
+    /// Use the latest server snapshot as the global percentages of a game's achievements
+    fn apply_latest_rarity(&mut self, appid: u64, history: &[overachiever_core::AchievementRarityHistory]) {
+        let latest: HashMap<String, f32> = history
+            .iter()
+            .filter_map(|h| h.latest().map(|percent| (h.apiname.clone(), percent)))
+            .collect();
+        if latest.is_empty() {
+            return;
+        }
+        if let Some(achievements) = self.achievements_cache.get_mut(&appid) {
+            for ach in achievements.iter_mut() {
+                if let Some(percent) = latest.get(&ach.apiname) {
+                    ach.global_percent = Some(*percent);
+                }
+            }
+        }
+        self.db_worker.execute("save global percentages", move |conn| {
+            crate::db::save_global_percentages(conn, appid, &latest)
+        });
+    }
+}
@@ -0,0 +1,84 @@
+//! Personal records and milestone celebrations
+
+use chrono::{Timelike, Utc};
+use overachiever_core::{compute_records, tr, tr_args, StatsPanelPlatform, UnlockRecord};
+
+use crate::app::SteamOverachieverApp;
+use crate::db::get_unlock_records;
+
+impl SteamOverachieverApp {
+    /// Recompute records from the achievements table (read on the DB worker).
+    /// When `celebrate` is set, milestones that weren't reached before are queued for the celebration popup.
+    pub(crate) fn refresh_achievement_records(&mut self, celebrate: bool) {
+        let steam_id = self.config.steam_id.clone();
+        self.db_query(
+            move |conn| get_unlock_records(conn, &steam_id),
+            move |app, unlocks| match unlocks {
+                Ok(unlocks) => app.apply_achievement_records(&unlocks, celebrate),
+                Err(e) => eprintln!("Failed to load unlocks: {}", e),
+            },
+        );
+    }
+
+    /// Recompute the records (celebrating new milestones) once unlocks changed and no scan
+    /// or single game refresh is running anymore, instead of after every game
+    pub(crate) fn records_tick(&mut self) {
+        let scanning = self.state.is_busy()
+            || self.single_game_refreshing.is_some()
+            || !self.single_game_refresh_queue.is_empty();
+        if !self.achievement_records_dirty || scanning {
+            return;
+        }
+        self.achievement_records_dirty = false;
+        self.refresh_achievement_records(true);
+    }
+
+    fn apply_achievement_records(&mut self, unlocks: &[UnlockRecord], celebrate: bool) {
+        let records = compute_records(&self.stats_games(), unlocks, &self.config.time_display);
+        self.unlock_times = unlocks.iter().map(|u| u.unlocktime).collect();
+
+        if celebrate {
+            if let Some(previous) = &self.achievement_records {
+                let new_milestones: Vec<_> = records
+                    .milestones
+                    .iter()
+                    .filter(|m| !previous.milestones.iter().any(|p| p.kind == m.kind))
+                    .cloned()
+                    .collect();
+                self.new_milestones.extend(new_milestones);
+            }
+        }
+
+        self.achievement_records = Some(records);
+    }
+
+    /// Show a desktop notification (once per day) when today's streak is about to break
+    pub(crate) fn streak_reminder_tick(&mut self) {
+        if !self.config.streak_reminder {
+            return;
+        }
+        let now = self.config.time_display.to_display_time(Utc::now());
+        let today = now.date();
+        if self.streak_reminded_on == Some(today) || now.hour() < self.config.streak_reminder_hour {
+            return;
+        }
+        let Some(records) = &self.achievement_records else {
+            return;
+        };
+        let status = records.streak_status(today);
+        if !status.at_risk() {
+            return;
+        }
+
+        self.streak_reminded_on = Some(today);
+        let body = tr_args("records.streak_reminder_body", &[("days", &status.current)]);
+        let summary = tr("records.streak_reminder_title");
+        std::thread::spawn(move || {
+            let _ = notify_rust::Notification::new()
+                .appname("Overachiever")
+                .summary(summary)
+                .body(&body)
+                .show();
+        });
+    }
+}
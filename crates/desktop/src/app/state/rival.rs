@@ -0,0 +1,78 @@
+//! Rival comparison - a second (public) account scraped read-only for head-to-head progress
+
+use std::sync::mpsc::channel;
+use std::thread;
+use overachiever_core::{tr, tr_args};
+
+use crate::app::SteamOverachieverApp;
+use crate::db::get_all_games;
+use crate::steam_api::{scrape_rival_with_progress, RivalScrapeProgress};
+
+impl SteamOverachieverApp {
+    /// Load the rival's stored games on the DB worker (empty when no rival is set)
+    pub(crate) fn load_rival_games(&mut self) {
+        let rival = self.config.rival_steam_id.trim().to_string();
+        if rival.is_empty() {
+            self.rival_games = Vec::new();
+            return;
+        }
+        self.db_query(
+            move |conn| get_all_games(conn, &rival),
+            |app, games| match games {
+                Ok(games) => app.rival_games = games,
+                Err(e) => eprintln!("Failed to load rival games: {}", e),
+            },
+        );
+    }
+
+    /// Fetch the rival's games and scrape the ones we both own in the background
+    pub(crate) fn start_rival_scrape(&mut self) {
+        if self.rival_receiver.is_some() || self.config.rival_steam_id.trim().is_empty() {
+            return;
+        }
+        let (tx, rx) = channel();
+        self.rival_receiver = Some(rx);
+        self.rival_status = Some(tr("rival.fetching_games").to_string());
+
+        let rival_steam_id = self.config.rival_steam_id.clone();
+        thread::spawn(move || {
+            if let Err(e) = scrape_rival_with_progress(tx.clone(), rival_steam_id) {
+                let _ = tx.send(RivalScrapeProgress::Error(e.to_string()));
+            }
+        });
+    }
+
+    /// Process progress of the rival scrape
+    pub(crate) fn rival_tick(&mut self) {
+        let Some(receiver) = &self.rival_receiver else {
+            return;
+        };
+        while let Ok(progress) = receiver.try_recv() {
+            match progress {
+                RivalScrapeProgress::FetchingGames => {
+                    self.rival_status = Some(tr("rival.fetching_games").to_string());
+                }
+                RivalScrapeProgress::Scraping { current, total, game_name } => {
+                    self.rival_status = Some(tr_args("rival.scraping", &[("current", &current), ("total", &total), ("game", &game_name)]));
+                }
+                RivalScrapeProgress::Done { games } => {
+                    self.rival_games = games;
+                    self.rival_status = None;
+                    self.rival_receiver = None;
+                    return;
+                }
+                RivalScrapeProgress::Error(e) => {
+                    self.rival_status = Some(tr_args("rival.scrape_failed", &[("error", &e)]));
+                    self.rival_receiver = None;
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Rival's (unlocked, total) achievements for a game, if both of us own it and it was scraped
+    pub(crate) fn rival_progress(&self, appid: u64) -> Option<(i32, i32)> {
+        let game = self.rival_games.iter().find(|g| g.appid == appid)?;
+        Some((game.achievements_unlocked?, game.achievements_total.filter(|&t| t > 0)?))
+    }
+}
@@ -0,0 +1,137 @@
+//! Play session tracking - polls Steam's "currently playing" status
+
+use std::sync::mpsc::channel;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::app::{LaunchWatch, SteamOverachieverApp};
+use crate::db::{get_game_sessions, record_session_poll};
+
+/// Without session polling, refresh a launched game this long after launch
+const LAUNCH_REFRESH_FALLBACK: Duration = Duration::from_secs(60 * 60);
+/// Stop watching a launched game that was never seen running after this long
+const LAUNCH_WATCH_MAX: Duration = Duration::from_secs(12 * 60 * 60);
+
+impl SteamOverachieverApp {
+    /// Poll GetPlayerSummaries on the configured interval and record sessions
+    pub(crate) fn session_poll_tick(&mut self) {
+        // Check if we have a pending result
+        if let Some(ref receiver) = self.session_poll_receiver {
+            match receiver.try_recv() {
+                Ok(Ok(current_appid)) => {
+                    self.session_poll_receiver = None;
+                    let now = chrono::Utc::now().timestamp();
+                    // Allow one missed poll before a session counts as interrupted
+                    let max_gap = (self.config.session_poll_interval_secs * 2) as i64;
+                    let steam_id = self.config.steam_id.clone();
+                    self.db_worker.execute("record session poll", move |conn| {
+                        record_session_poll(conn, &steam_id, current_appid, now, max_gap)
+                    });
+                    self.currently_playing = current_appid;
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Session poll failed: {}", e);
+                    self.session_poll_receiver = None;
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => return,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    self.session_poll_receiver = None;
+                }
+            }
+        }
+
+        let interval = self.config.session_poll_interval_secs;
+        if interval == 0 || !self.config.has_steam_credentials() {
+            // Polling was turned off mid-session: the game isn't tracked anymore, so the
+            // open session ends at its last sighting
+            if self.currently_playing.take().is_some() {
+                let now = chrono::Utc::now().timestamp();
+                let steam_id = self.config.steam_id.clone();
+                self.db_worker.execute("end session", move |conn| record_session_poll(conn, &steam_id, None, now, 0));
+            }
+            return;
+        }
+        if let Some(last) = self.session_last_poll {
+            if last.elapsed() < Duration::from_secs(interval) {
+                return;
+            }
+        }
+
+        let Some(steam_id) = self.config.steam_id_u64() else {
+            return;
+        };
+        let steam_key = self.config.steam_web_api_key.clone();
+        let (tx, rx) = channel();
+        self.session_poll_receiver = Some(rx);
+        self.session_last_poll = Some(Instant::now());
+
+        thread::spawn(move || {
+            let result = crate::steam_api::fetch_currently_playing(&steam_key, steam_id).map_err(|e| e.to_string());
+            let _ = tx.send(result);
+        });
+    }
+
+    /// Start watching a game launched with Play so new unlocks show up when the session ends
+    pub(crate) fn watch_launched_game(&mut self, appid: u64) {
+        let playtime_at_launch = self.games.iter().find(|g| g.appid == appid).map(|g| g.playtime_forever).unwrap_or(0);
+        self.launch_watches.insert(appid, LaunchWatch {
+            launched_at: Instant::now(),
+            playtime_at_launch,
+            seen_running: false,
+        });
+        // Poll soon so the running game is noticed
+        self.session_last_poll = None;
+    }
+
+    /// Refresh launched games whose session appears to have ended
+    pub(crate) fn launch_refresh_tick(&mut self) {
+        if self.launch_watches.is_empty() {
+            return;
+        }
+        let polling = self.config.session_poll_interval_secs > 0 && self.config.has_steam_credentials();
+        let currently_playing = self.currently_playing;
+
+        let mut ended = Vec::new();
+        let mut expired = Vec::new();
+        for (&appid, watch) in self.launch_watches.iter_mut() {
+            let running = currently_playing == Some(appid);
+            watch.seen_running |= running;
+            let playtime_grew = self
+                .games
+                .iter()
+                .find(|g| g.appid == appid)
+                .is_some_and(|g| g.playtime_forever > watch.playtime_at_launch);
+
+            if running {
+                continue;
+            }
+            if watch.seen_running || playtime_grew || (!polling && watch.launched_at.elapsed() >= LAUNCH_REFRESH_FALLBACK) {
+                ended.push(appid);
+            } else if watch.launched_at.elapsed() >= LAUNCH_WATCH_MAX {
+                expired.push(appid);
+            }
+        }
+
+        for appid in expired {
+            self.launch_watches.remove(&appid);
+        }
+        // One refresh at a time; the rest are retried on later frames
+        if let Some(appid) = ended.into_iter().find(|&appid| !self.is_refresh_on_cooldown(appid)) {
+            if self.start_single_game_refresh(appid) {
+                self.launch_watches.remove(&appid);
+            }
+        }
+    }
+
+    /// Open the session history window for a game
+    pub(crate) fn open_sessions_window(&mut self, appid: u64, game_name: String) {
+        let steam_id = self.config.steam_id.clone();
+        self.db_query(
+            move |conn| get_game_sessions(conn, &steam_id, appid),
+            move |app, sessions| match sessions {
+                Ok(sessions) => app.sessions_window = Some((appid, game_name, sessions)),
+                Err(e) => eprintln!("Failed to load sessions for {}: {}", appid, e),
+            },
+        );
+    }
+}
@@ -0,0 +1,77 @@
+//! Scheduled history snapshots - keeps the history graphs on a regular cadence
+//! when no update ran for a while, using the cached library
+
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+
+use crate::app::SteamOverachieverApp;
+use crate::db::{
+    get_achievement_history, get_run_history, has_completed_initial_scan, insert_achievement_history,
+    insert_run_history, update_latest_run_history_unplayed,
+};
+
+/// How often to check whether a snapshot is due
+const CHECK_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+impl SteamOverachieverApp {
+    /// Newest run or achievement history entry (updates write both)
+    fn last_history_entry(&self) -> Option<DateTime<Utc>> {
+        let last_run = self.run_history.last().map(|rh| rh.run_at);
+        let last_achievements = self.achievement_history.last().map(|ah| ah.recorded_at);
+        last_run.max(last_achievements)
+    }
+
+    /// Record a history snapshot from cached data once `snapshot_interval_days` passed without one
+    pub(crate) fn snapshot_tick(&mut self) {
+        let days = self.config.snapshot_interval_days;
+        if days == 0 || self.state.is_busy() || self.games.is_empty() {
+            return;
+        }
+        if self.snapshot_last_check.is_some_and(|last| last.elapsed() < CHECK_INTERVAL) {
+            return;
+        }
+        self.snapshot_last_check = Some(Instant::now());
+
+        // Without any history there's no baseline yet - the first update records one
+        let Some(last) = self.last_history_entry() else {
+            return;
+        };
+        if Utc::now() - last < chrono::Duration::days(days as i64) {
+            return;
+        }
+        let Some(stats) = self.history_stats() else {
+            return;
+        };
+
+        let steam_id = self.config.steam_id.clone();
+        let games_total = self.games.len() as i32;
+        let unplayed_total = self.games.iter().filter(|g| g.playtime_forever == 0).count() as i32;
+        self.db_query(
+            move |conn| {
+                if !has_completed_initial_scan(conn) {
+                    return Ok(None);
+                }
+                insert_run_history(conn, &steam_id, games_total, unplayed_total)?;
+                update_latest_run_history_unplayed(conn, &steam_id, stats.unplayed_with_ach)?;
+                insert_achievement_history(
+                    conn,
+                    &steam_id,
+                    stats.total_achievements,
+                    stats.unlocked_achievements,
+                    stats.games_with_ach,
+                    stats.avg_completion,
+                )?;
+                Ok(Some((get_run_history(conn, &steam_id)?, get_achievement_history(conn, &steam_id)?)))
+            },
+            |app, recorded| match recorded {
+                Ok(Some((run_history, achievement_history))) => {
+                    app.run_history = run_history;
+                    app.achievement_history = achievement_history;
+                }
+                Ok(None) => {}
+                Err(e) => eprintln!("Scheduled history snapshot failed: {}", e),
+            },
+        );
+    }
+}
@@ -1,60 +1,177 @@
 //! Game sorting logic
 
+use std::time::{Duration, Instant};
+
 use overachiever_core::ui::{SortColumn, SortOrder};
+use overachiever_core::{compare_tie_break, sort_games};
 use crate::app::SteamOverachieverApp;
 
+/// Minimum time between re-sorts while a scan streams in game updates
+const RESORT_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Coalesces re-sort requests during scans: every GameUpdated asks for a re-sort,
+/// but the list is sorted at most once per `RESORT_INTERVAL`
+#[derive(Debug, Default)]
+pub(crate) struct ResortThrottle {
+    pending: bool,
+    last_sort: Option<Instant>,
+}
+
+impl ResortThrottle {
+    /// Ask for a re-sort (done by the next `take_due` after the interval)
+    pub(crate) fn request(&mut self) {
+        self.pending = true;
+    }
+
+    /// Whether a requested re-sort is due now; consumes the request if so
+    pub(crate) fn take_due(&mut self, now: Instant) -> bool {
+        if !self.pending || self.last_sort.is_some_and(|last| now.duration_since(last) < RESORT_INTERVAL) {
+            return false;
+        }
+        self.pending = false;
+        self.last_sort = Some(now);
+        true
+    }
+}
+
 impl SteamOverachieverApp {
+    /// Re-sort if a scan requested it and the throttle interval has passed
+    pub(crate) fn flush_pending_resort(&mut self) {
+        if self.resort_throttle.take_due(Instant::now()) {
+            self.sort_games();
+        }
+    }
+
     /// Sort games in place based on current sort settings
     pub(crate) fn sort_games(&mut self) {
         let order = self.sort_order;
+        let tie_breaker = self.config.sort_tie_breaker;
         match self.sort_column {
-            SortColumn::Name => {
-                self.games.sort_by(|a, b| {
-                    let cmp = a.name.to_lowercase().cmp(&b.name.to_lowercase());
-                    if order == SortOrder::Descending { cmp.reverse() } else { cmp }
-                });
-            }
-            SortColumn::LastPlayed => {
-                self.games.sort_by(|a, b| {
-                    let cmp = a.rtime_last_played.unwrap_or(0).cmp(&b.rtime_last_played.unwrap_or(0));
-                    if order == SortOrder::Descending { cmp.reverse() } else { cmp }
-                });
-            }
-            SortColumn::Playtime => {
-                self.games.sort_by(|a, b| {
-                    let cmp = a.playtime_forever.cmp(&b.playtime_forever);
-                    if order == SortOrder::Descending { cmp.reverse() } else { cmp }
-                });
-            }
-            SortColumn::AchievementsTotal => {
-                self.games.sort_by(|a, b| {
-                    let a_total = a.achievements_total.unwrap_or(-1);
-                    let b_total = b.achievements_total.unwrap_or(-1);
-                    let cmp = a_total.cmp(&b_total);
-                    if order == SortOrder::Descending { cmp.reverse() } else { cmp }
-                });
-            }
-            SortColumn::AchievementsPercent => {
-                self.games.sort_by(|a, b| {
-                    let a_pct = a.completion_percent().unwrap_or(-1.0);
-                    let b_pct = b.completion_percent().unwrap_or(-1.0);
-                    let cmp = a_pct.partial_cmp(&b_pct).unwrap_or(std::cmp::Ordering::Equal);
-                    if order == SortOrder::Descending { cmp.reverse() } else { cmp }
-                });
-            }
             SortColumn::TimeToBeat => {
                 let cache = &self.ttb_cache;
                 self.games.sort_by(|a, b| {
                     let a_ttb = cache.get(&a.appid).and_then(|t| t.main).unwrap_or(-1.0);
                     let b_ttb = cache.get(&b.appid).and_then(|t| t.main).unwrap_or(-1.0);
                     let cmp = a_ttb.partial_cmp(&b_ttb).unwrap_or(std::cmp::Ordering::Equal);
-                    if order == SortOrder::Descending { cmp.reverse() } else { cmp }
+                    let cmp = if order == SortOrder::Descending { cmp.reverse() } else { cmp };
+                    cmp.then_with(|| compare_tie_break(a, b, SortColumn::TimeToBeat, tie_breaker))
                 });
             }
             SortColumn::Votes => {
                 // Votes sorting is handled in set_sort in games_table.rs (needs filter_tags context)
                 // This is just for the initial sort_games call which won't use Votes
             }
+            column => sort_games(&mut self.games, column, order, tie_breaker),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use overachiever_core::{Game, SortTieBreaker};
+
+    fn game(appid: u64, unlocked: i32) -> Game {
+        Game {
+            appid,
+            name: format!("Game {}", appid),
+            playtime_forever: 0,
+            rtime_last_played: None,
+            img_icon_url: None,
+            added_at: chrono::Utc::now(),
+            achievements_total: Some(100),
+            achievements_unlocked: Some(unlocked),
+            last_achievement_scrape: None,
+            avg_user_ttb_main_seconds: None,
+            avg_user_ttb_extra_seconds: None,
+            avg_user_ttb_completionist_seconds: None,
+            user_ttb_report_count: 0,
+            my_ttb_main_seconds: None,
+            my_ttb_extra_seconds: None,
+            my_ttb_completionist_seconds: None,
+            my_ttb_reported_at: None,
+            hidden: false,
+            steam_hidden: false,
+            steam_private: false,
+            app_type: None,
+            abandoned: false,
+            platform_playtime: None,
+            owned: true,
+        }
+    }
+
+    /// Apply one scan update per game, as the progress handler does
+    fn simulate_scan(games: &mut [Game], mut after_update: impl FnMut(&mut [Game], Instant)) {
+        let start = Instant::now();
+        for appid in 0..games.len() as u64 {
+            if let Some(game) = games.iter_mut().find(|g| g.appid == appid) {
+                game.achievements_unlocked = Some((appid * 37 % 101) as i32);
+            }
+            // Updates arrive every 5 ms of (simulated) scan time
+            after_update(games, start + Duration::from_millis(5 * appid));
+        }
+    }
+
+    #[test]
+    fn throttle_coalesces_requests() {
+        let mut throttle = ResortThrottle::default();
+        let start = Instant::now();
+        assert!(!throttle.take_due(start));
+
+        throttle.request();
+        assert!(throttle.take_due(start));
+        throttle.request();
+        throttle.request();
+        assert!(!throttle.take_due(start + RESORT_INTERVAL / 2));
+        assert!(throttle.take_due(start + RESORT_INTERVAL));
+        assert!(!throttle.take_due(start + RESORT_INTERVAL * 3));
+    }
+
+    /// Sort a simulated scan of `games`, either after every update or through the throttle.
+    /// Returns the final order and the number of sorts.
+    fn scan_sorted(games: u64, throttled: bool) -> (Vec<u64>, u32) {
+        let mut library: Vec<Game> = (0..games).map(|appid| game(appid, 0)).collect();
+        let column = SortColumn::AchievementsPercent;
+        let mut throttle = ResortThrottle::default();
+        let mut sorts = 0;
+        simulate_scan(&mut library, |games, now| {
+            throttle.request();
+            if !throttled || throttle.take_due(now) {
+                sort_games(games, column, SortOrder::Descending, SortTieBreaker::default());
+                sorts += 1;
+            }
+        });
+        if throttled {
+            // The scan's Done message always sorts the final list
+            sort_games(&mut library, column, SortOrder::Descending, SortTieBreaker::default());
+            sorts += 1;
+        }
+        (library.iter().map(|g| g.appid).collect(), sorts)
+    }
+
+    #[test]
+    fn throttled_scan_sorts_once_per_interval() {
+        let (order, sorts) = scan_sorted(5000, true);
+        // 5000 updates 5 ms apart span 25 s: one sort per 250 ms interval, plus the final one
+        assert_eq!(sorts, 101);
+
+        // Same final order as sorting the finished scan
+        let mut finished: Vec<Game> = (0..5000).map(|appid| game(appid, (appid * 37 % 101) as i32)).collect();
+        sort_games(&mut finished, SortColumn::AchievementsPercent, SortOrder::Descending, SortTieBreaker::default());
+        assert_eq!(order, finished.iter().map(|g| g.appid).collect::<Vec<_>>());
+    }
+
+    /// Benchmark: a full scan of a 5000-game library sorted by completion
+    /// (`cargo test -- --ignored`; timing depends on the machine)
+    #[test]
+    #[ignore]
+    fn throttled_resort_beats_sorting_every_update() {
+        let naive_start = Instant::now();
+        scan_sorted(5000, false);
+        let naive = naive_start.elapsed();
+
+        let throttled_start = Instant::now();
+        scan_sorted(5000, true);
+        assert!(throttled_start.elapsed() < naive);
+    }
+}
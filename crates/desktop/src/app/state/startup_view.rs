@@ -0,0 +1,52 @@
+//! Startup view selection - which panel and table setup the app opens with
+
+use overachiever_core::SidebarPanel;
+
+use crate::app::SteamOverachieverApp;
+use crate::config::StartupView;
+use crate::ui::{SortColumn, SortOrder, TriFilter};
+
+impl SteamOverachieverApp {
+    /// Apply the configured startup view (called once from `new`)
+    pub(crate) fn apply_startup_view(&mut self) {
+        let (view, sidebar_expanded) = match self.config.startup_view {
+            StartupView::LastUsed => (self.config.last_view, self.config.last_sidebar_expanded),
+            view => (view, self.config.startup_sidebar_expanded),
+        };
+
+        self.show_stats_panel = sidebar_expanded;
+        match view {
+            StartupView::Stats => {
+                self.sidebar_panel = SidebarPanel::Stats;
+                self.show_stats_panel = true;
+            }
+            StartupView::Log => {
+                self.sidebar_panel = SidebarPanel::Log;
+                self.show_stats_panel = true;
+            }
+            StartupView::Backlog => {
+                self.filter_achievements = TriFilter::With;
+                self.filter_playtime = TriFilter::With;
+                self.sort_column = SortColumn::AchievementsPercent;
+                self.sort_order = SortOrder::Descending;
+            }
+            StartupView::Library | StartupView::LastUsed => {}
+        }
+    }
+
+    /// Record the current view so StartupView::LastUsed can restore it
+    pub(crate) fn remember_last_view(&mut self) {
+        let is_backlog = self.filter_achievements == TriFilter::With
+            && self.filter_playtime == TriFilter::With
+            && self.sort_column == SortColumn::AchievementsPercent
+            && self.sort_order == SortOrder::Descending;
+
+        self.config.last_view = match (self.show_stats_panel, self.sidebar_panel) {
+            _ if is_backlog => StartupView::Backlog,
+            (true, SidebarPanel::Stats) => StartupView::Stats,
+            (true, SidebarPanel::Log) => StartupView::Log,
+            (false, _) => StartupView::Library,
+        };
+        self.config.last_sidebar_expanded = self.show_stats_panel;
+    }
+}
@@ -0,0 +1,98 @@
+//! Steam hidden/private flags - read from the local Steam config, or derived from the public profile
+
+use std::sync::mpsc::channel;
+use std::thread;
+
+use overachiever_core::tr_args;
+
+use crate::app::SteamOverachieverApp;
+use crate::db::{get_all_games, record_synced_private_games};
+use crate::steam_community;
+use crate::steam_config::{has_local_steam_config, sync_private_games_from_public_list, sync_steam_hidden_games};
+
+impl SteamOverachieverApp {
+    /// Refresh steam_hidden/steam_private. Reads localconfig.vdf when Steam is installed here,
+    /// otherwise compares the library with the public profile games list in the background.
+    pub(crate) fn sync_steam_visibility(&mut self) {
+        let steam_id = self.config.steam_id.clone();
+        if steam_id.is_empty() {
+            return;
+        }
+
+        if has_local_steam_config(&steam_id) {
+            self.db_query(
+                move |conn| {
+                    let count = sync_steam_hidden_games(conn, &steam_id)?;
+                    record_synced_private_games(conn)?;
+                    // Reload games to pick up the flags
+                    Ok((count, get_all_games(conn, &steam_id)?))
+                },
+                |app, synced| match synced {
+                    Ok((count, games)) => {
+                        app.status = tr_args("steam_visibility.synced", &[("count", &count)]);
+                        app.games = games;
+                        app.sort_games();
+                    }
+                    Err(e) => {
+                        app.status = tr_args("steam_visibility.sync_failed", &[("error", &e)]);
+                        eprintln!("Failed to sync Steam hidden games: {}", e);
+                    }
+                },
+            );
+            return;
+        }
+
+        let Some(steam_id64) = self.config.steam_id_u64() else {
+            return;
+        };
+        if self.steam_visibility_receiver.is_some() {
+            return;
+        }
+        let (tx, rx) = channel();
+        self.steam_visibility_receiver = Some(rx);
+        thread::spawn(move || {
+            let _ = tx.send(steam_community::fetch_public_appids(steam_id64));
+        });
+    }
+
+    /// Apply the public profile games list once it has been fetched
+    pub(crate) fn steam_visibility_tick(&mut self) {
+        let Some(ref receiver) = self.steam_visibility_receiver else {
+            return;
+        };
+        match receiver.try_recv() {
+            Ok(Ok(public_appids)) => {
+                self.steam_visibility_receiver = None;
+                let steam_id = self.config.steam_id.clone();
+                self.db_query(
+                    move |conn| {
+                        let count = sync_private_games_from_public_list(conn, &steam_id, &public_appids)?;
+                        record_synced_private_games(conn)?;
+                        Ok((count, public_appids))
+                    },
+                    |app, synced| match synced {
+                        Ok((count, public_appids)) => {
+                            // Update in place - a reload could clobber an update that is running
+                            if !public_appids.is_empty() {
+                                for game in &mut app.games {
+                                    game.steam_private = !public_appids.contains(&game.appid);
+                                }
+                            }
+                            app.status = tr_args("steam_visibility.private_found", &[("count", &count)]);
+                        }
+                        Err(e) => app.status = tr_args("steam_visibility.store_failed", &[("error", &e)]),
+                    },
+                );
+            }
+            Ok(Err(e)) => {
+                self.steam_visibility_receiver = None;
+                eprintln!("Failed to read private games from Steam profile: {}", e);
+                self.status = tr_args("steam_visibility.read_failed", &[("error", &e)]);
+            }
+            Err(std::sync::mpsc::TryRecvError::Empty) => {}
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                self.steam_visibility_receiver = None;
+            }
+        }
+    }
+}
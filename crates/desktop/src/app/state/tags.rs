@@ -1,18 +1,48 @@
 //! Tags (SteamSpy) scanning and management
 
+use std::collections::HashMap;
 use std::thread;
-use std::time::{Duration, Instant};
+use std::time::Instant;
 use std::sync::mpsc::channel;
+use overachiever_core::{tr, tr_args};
+
 use crate::{cloud_sync, steamspy};
 use crate::app::SteamOverachieverApp;
 use crate::ui::AppState;
 
 impl SteamOverachieverApp {
+    /// Load tag aliases/parents from backend on startup (before any tags)
+    pub(crate) fn load_tag_mappings(&mut self) {
+        match cloud_sync::fetch_tag_mappings() {
+            Ok(mappings) => {
+                self.tag_map = overachiever_core::TagMap::new(&mappings);
+            }
+            Err(e) => {
+                eprintln!("Failed to load tag mappings: {}", e);
+            }
+        }
+    }
+
+    /// Switch to new tag mappings and re-apply them to the loaded tags and filters
+    pub(crate) fn set_tag_map(&mut self, tag_map: overachiever_core::TagMap) {
+        self.tag_map = tag_map;
+        for tags in self.tags_cache.values_mut() {
+            *tags = self.tag_map.apply(tags);
+        }
+        self.available_tags = self.tag_map.apply_names(&self.available_tags);
+        let filter_tags = self.tag_map.apply_names(&self.filter_tags);
+        self.filter_tags = filter_tags;
+        let filter_excluded_tags = self.tag_map.apply_names(&self.filter_excluded_tags);
+        self.filter_excluded_tags = filter_excluded_tags;
+        let hidden_tags = self.tag_map.apply_names(&self.hidden_tags);
+        self.hidden_tags = hidden_tags;
+    }
+
     /// Load available tags from backend on startup
     pub(crate) fn load_available_tags(&mut self) {
         match cloud_sync::fetch_tag_names() {
             Ok(tags) => {
-                self.available_tags = tags;
+                self.available_tags = self.tag_map.apply_names(&tags);
             }
             Err(e) => {
                 eprintln!("Failed to load tag names: {}", e);
@@ -31,12 +61,13 @@ impl SteamOverachieverApp {
         for chunk in appids.chunks(500) {
             match cloud_sync::fetch_tags_batch(chunk) {
                 Ok(tags) => {
-                    // Group tags by appid
+                    // Group tags by appid, then collapse aliases
+                    let mut grouped: HashMap<u64, Vec<(String, u32)>> = HashMap::new();
                     for tag in tags {
-                        self.tags_cache
-                            .entry(tag.appid)
-                            .or_insert_with(Vec::new)
-                            .push((tag.tag_name, tag.vote_count));
+                        grouped.entry(tag.appid).or_default().push((tag.tag_name, tag.vote_count));
+                    }
+                    for (appid, tags) in grouped {
+                        self.tags_cache.insert(appid, self.tag_map.apply(&tags));
                     }
                 }
                 Err(e) => {
@@ -46,32 +77,68 @@ impl SteamOverachieverApp {
         }
     }
 
+    /// Post fetched tags to the backend one at a time. A rate limited post stays
+    /// at the front of the queue and is sent again once the server's Retry-After passed.
+    fn tags_submit_tick(&mut self) {
+        if let Some(receiver) = &self.tags_submit_receiver {
+            match receiver.try_recv() {
+                Ok(result) => {
+                    self.tags_submit_receiver = None;
+                    match result {
+                        Err(cloud_sync::SubmitTagsError::RateLimited(wait)) => {
+                            self.tags_submit_retry_at = Some(Instant::now() + wait);
+                            return;
+                        }
+                        Err(cloud_sync::SubmitTagsError::Failed(e)) => eprintln!("Tags submit failed: {}", e),
+                        Ok(_) => {}
+                    }
+                    self.tags_submit_queue.pop_front();
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => return,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => self.tags_submit_receiver = None,
+            }
+        }
+
+        if self.tags_submit_retry_at.is_some_and(|at| Instant::now() < at) {
+            return;
+        }
+        self.tags_submit_retry_at = None;
+        let (Some((appid, tags)), Some(token)) = (self.tags_submit_queue.front(), &self.config.cloud_token) else {
+            return;
+        };
+        let (appid, tags, token) = (*appid, tags.clone(), token.clone());
+        let (tx, rx) = channel();
+        self.tags_submit_receiver = Some(rx);
+        thread::spawn(move || {
+            let _ = tx.send(cloud_sync::submit_tags(&token, appid, &tags));
+        });
+    }
+
     /// Process tag fetch queue (called each frame when admin mode is on)
     pub(crate) fn tags_fetch_tick(&mut self) {
+        self.tags_submit_tick();
+
         // Check if we have a pending result
         if let Some(ref receiver) = self.tags_receiver {
             match receiver.try_recv() {
                 Ok(Ok((appid, tags))) => {
                     let is_scanning = matches!(self.state, AppState::TagsScanning { .. });
 
-                    // Cache locally
-                    self.tags_cache.insert(appid, tags.clone());
+                    // Cache locally (the raw SteamSpy tags are what gets submitted)
+                    let mapped = self.tag_map.apply(&tags);
 
                     // Update available_tags with any new tags
-                    for (tag_name, _) in &tags {
+                    for (tag_name, _) in &mapped {
                         if !self.available_tags.contains(tag_name) {
                             self.available_tags.push(tag_name.clone());
                             self.available_tags.sort();
                         }
                     }
+                    self.tags_cache.insert(appid, mapped);
 
-                    // POST to backend (fire and forget)
-                    if let Some(token) = &self.config.cloud_token {
-                        let token = token.clone();
-                        let tags_for_post = tags.clone();
-                        thread::spawn(move || {
-                            let _ = cloud_sync::submit_tags(&token, appid, &tags_for_post);
-                        });
+                    // POST to backend (queued, see tags_submit_tick)
+                    if self.config.cloud_token.is_some() {
+                        self.tags_submit_queue.push_back((appid, tags));
                     }
 
                     self.tags_fetching = None;
@@ -82,9 +149,9 @@ impl SteamOverachieverApp {
                         self.tags_scan_total = 0;
                         if is_scanning {
                             self.state = AppState::Idle;
-                            self.status = "Tags scan complete!".to_string();
+                            self.status = tr("tags.scan_done").to_string();
                         } else {
-                            self.status = format!("Tags loaded for appid {}", appid);
+                            self.status = tr_args("tags.loaded", &[("appid", &appid)]);
                         }
                     }
                 }
@@ -99,9 +166,9 @@ impl SteamOverachieverApp {
                         self.tags_scan_total = 0;
                         if is_scanning {
                             self.state = AppState::Idle;
-                            self.status = "Tags scan complete!".to_string();
+                            self.status = tr("tags.scan_done").to_string();
                         } else {
-                            self.status = format!("Tags error: {}", e);
+                            self.status = tr_args("tags.error", &[("error", &e)]);
                         }
                     }
                 }
@@ -126,9 +193,15 @@ impl SteamOverachieverApp {
             return;
         }
 
-        // Check rate limit between fetches (configurable via tags_scan_delay_secs)
+        // Check rate limit between fetches (configurable via tags_scan_delay_secs, stretched on battery)
+        let Some(delay) = self.throttled_scan_delay(self.config.tags_scan_delay_secs) else {
+            if matches!(self.state, AppState::TagsScanning { .. }) {
+                self.status = tr_args("tags.scan_paused", &[("reason", &self.scan_pause_reason())]);
+            }
+            return;
+        };
         if let Some(last) = self.tags_last_fetch {
-            if last.elapsed() < Duration::from_secs(self.config.tags_scan_delay_secs) {
+            if last.elapsed() < delay {
                 return;
             }
         }
@@ -142,9 +215,9 @@ impl SteamOverachieverApp {
             if let AppState::TagsScanning { current: _, total } = self.state {
                 let new_current = total - self.tags_fetch_queue.len() as i32;
                 self.state = AppState::TagsScanning { current: new_current, total };
-                self.status = format!("Tags Scan: {} / {} games", new_current, total);
+                self.status = tr_args("tags.scan_progress", &[("current", &new_current), ("total", &total)]);
             } else {
-                self.status = format!("Fetching tags for appid {}...", appid);
+                self.status = tr_args("tags.fetching", &[("appid", &appid)]);
             }
 
             let (tx, rx) = channel();
@@ -185,7 +258,7 @@ impl SteamOverachieverApp {
             self.tags_fetch_queue = games_to_fetch;
             self.tags_scan_total = total;
             self.state = AppState::TagsScanning { current: 0, total };
-            self.status = format!("Tags Scan: 0 / {} games", total);
+            self.status = tr_args("tags.scan_progress", &[("current", &0), ("total", &total)]);
         }
     }
 
@@ -197,7 +270,7 @@ impl SteamOverachieverApp {
         self.tags_scan_total = 0;
         if matches!(self.state, AppState::TagsScanning { .. }) {
             self.state = AppState::Idle;
-            self.status = "Tags scan cancelled".to_string();
+            self.status = tr("tags.scan_cancelled").to_string();
         }
     }
 }
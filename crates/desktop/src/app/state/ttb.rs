@@ -1,15 +1,16 @@
 //! TTB (Time To Beat) scanning and management
 
+use std::collections::VecDeque;
 use std::io::Write;
 use std::sync::mpsc::channel;
 use std::thread;
-use std::time::{Duration, Instant};
+use std::time::Instant;
 
-use crate::db::{cache_ttb_times, get_cached_ttb, get_games_without_ttb, open_connection};
+use crate::db::{cache_ttb_times, get_cached_ttb, get_games_without_ttb};
 use crate::ttb;
-use overachiever_core::TtbTimes;
+use overachiever_core::{tr, tr_args, TtbDialogState, TtbTimes};
 
-use crate::app::SteamOverachieverApp;
+use crate::app::{SteamOverachieverApp, TtbBulkReport};
 use crate::ui::AppState;
 
 /// Helper function for logging TTB operations to a file
@@ -24,6 +25,125 @@ fn ttb_log(msg: &str) {
 }
 
 impl SteamOverachieverApp {
+    /// Save a personal TTB report (seconds per mode) to the local database and reload games
+    /// (on the DB worker, the outcome ends up in the status bar)
+    pub(crate) fn save_ttb_report(&mut self, appid: u64, times: (Option<i32>, Option<i32>, Option<i32>)) {
+        let (main_secs, extra_secs, comp_secs) = times;
+        let steam_id = self.config.steam_id.clone();
+        self.db_query(
+            move |conn| {
+                // Set user_ttb_report_count to 1 to indicate we have a user report (changes color from blue to gold)
+                conn.execute(
+                    "UPDATE games SET 
+                        my_ttb_main_seconds = ?1,
+                        my_ttb_extra_seconds = ?2,
+                        my_ttb_completionist_seconds = ?3,
+                        my_ttb_reported_at = ?4,
+                        user_ttb_report_count = 1
+                    WHERE appid = ?5 AND steam_id = ?6",
+                    rusqlite::params![main_secs, extra_secs, comp_secs, chrono::Utc::now().to_rfc3339(), appid as i64, &steam_id],
+                )?;
+                // Reload games to get updated TTB data
+                crate::db::get_all_games(conn, &steam_id)
+            },
+            |app, result| match result {
+                Ok(games) => {
+                    app.games = games;
+                    app.sort_games();
+                    app.status = tr("ttb.report_saved").to_string();
+                }
+                Err(e) => {
+                    eprintln!("Failed to save TTB report: {}", e);
+                    app.status = tr_args("ttb.report_failed", &[("error", &e)]);
+                }
+            },
+        );
+    }
+
+    /// 100% completed games without a personal TTB report, most recently played first
+    pub(crate) fn finished_games_without_ttb_report(&self) -> Vec<u64> {
+        let mut games: Vec<_> = self.games.iter()
+            .filter(|g| !g.hidden && g.my_ttb_reported_at.is_none())
+            .filter(|g| g.completion_percent().is_some_and(|pct| pct >= 100.0))
+            .collect();
+        games.sort_by_key(|g| std::cmp::Reverse(g.rtime_last_played));
+        games.into_iter().map(|g| g.appid).collect()
+    }
+
+    /// Input state for the next queued game of the bulk report, prefilled from its playtime
+    fn next_ttb_bulk_dialog(&self, queue: &mut VecDeque<u64>) -> Option<TtbDialogState> {
+        std::iter::from_fn(|| queue.pop_front()).find_map(|appid| {
+            let game = self.games.iter().find(|g| g.appid == appid)?;
+            let mut dialog = TtbDialogState::new(appid, game.name.clone(), None, None);
+            dialog.prefill_completed_game(game);
+            Some(dialog)
+        })
+    }
+
+    /// Start walking through finished games to report their times
+    pub(crate) fn start_ttb_bulk_report(&mut self) {
+        let mut queue: VecDeque<u64> = self.finished_games_without_ttb_report().into();
+        let total = queue.len();
+        let Some(dialog) = self.next_ttb_bulk_dialog(&mut queue) else {
+            self.status = tr("ttb.none_to_report").to_string();
+            return;
+        };
+        self.ttb_bulk_report = Some(TtbBulkReport { queue, total, submitted: 0, dialog });
+    }
+
+    /// Submit (or skip) the current game of the bulk report and move on to the next one
+    pub(crate) fn advance_ttb_bulk_report(&mut self, submit: bool) {
+        let Some(mut report) = self.ttb_bulk_report.take() else {
+            return;
+        };
+
+        if submit {
+            let appid = report.dialog.appid;
+            let times = report.dialog.get_times();
+            if times == (None, None, None) {
+                // Nothing entered - keep the game open
+                self.ttb_bulk_report = Some(report);
+                return;
+            }
+            self.save_ttb_report(appid, times);
+            report.submitted += 1;
+
+            if let Some(token) = self.config.cloud_token.clone() {
+                let game_name = report.dialog.game_name.clone();
+                let to_hours = |secs: Option<i32>| secs.map(|s| s as f32 / 3600.0);
+                let ttb = TtbTimes {
+                    appid,
+                    main: to_hours(times.0),
+                    main_extra: to_hours(times.1),
+                    completionist: to_hours(times.2),
+                    updated_at: chrono::Utc::now(),
+                };
+                thread::spawn(move || {
+                    if let Err(e) = post_ttb_to_backend(&token, appid, &game_name, &ttb) {
+                        ttb_log(&format!("Bulk report upload failed for {}: {}", appid, e));
+                    }
+                });
+            }
+        }
+
+        match self.next_ttb_bulk_dialog(&mut report.queue) {
+            Some(dialog) => {
+                report.dialog = dialog;
+                self.ttb_bulk_report = Some(report);
+            }
+            None => {
+                self.status = tr_args("ttb.bulk_done", &[("reported", &report.submitted), ("total", &report.total)]);
+            }
+        }
+    }
+
+    /// Stop the bulk report (already submitted games are kept)
+    pub(crate) fn stop_ttb_bulk_report(&mut self) {
+        if let Some(report) = self.ttb_bulk_report.take() {
+            self.status = tr_args("ttb.bulk_done", &[("reported", &report.submitted), ("total", &report.total)]);
+        }
+    }
+
     /// Count games that don't have TTB data cached (for admin mode scan button)
     /// Excludes games in the TTB blacklist
     pub(crate) fn games_needing_ttb_admin(&self) -> usize {
@@ -41,20 +161,27 @@ impl SteamOverachieverApp {
         }
 
         // Get games without TTB from database, filtering out blacklisted games
-        if let Ok(conn) = open_connection() {
-            if let Ok(games) = get_games_without_ttb(&conn, &self.config.steam_id) {
-                // Filter out blacklisted games
-                self.ttb_scan_queue = games.into_iter()
-                    .filter(|(appid, _)| !self.ttb_blacklist.contains(appid))
-                    .collect();
-
-                if !self.ttb_scan_queue.is_empty() {
-                    let total = self.ttb_scan_queue.len() as i32;
-                    self.state = AppState::TtbScanning { current: 0, total };
-                    self.status = format!("TTB Scan: 0 / {} games", total);
+        let steam_id = self.config.steam_id.clone();
+        self.db_query(
+            move |conn| get_games_without_ttb(conn, &steam_id),
+            |app, games| match games {
+                // Started twice before the first query came back
+                Ok(_) if !app.ttb_scan_queue.is_empty() => {}
+                Ok(games) => {
+                    // Filter out blacklisted games
+                    app.ttb_scan_queue = games.into_iter()
+                        .filter(|(appid, _)| !app.ttb_blacklist.contains(appid))
+                        .collect();
+
+                    if !app.ttb_scan_queue.is_empty() {
+                        let total = app.ttb_scan_queue.len() as i32;
+                        app.state = AppState::TtbScanning { current: 0, total };
+                        app.status = tr_args("ttb.scan_start", &[("total", &total)]);
+                    }
                 }
-            }
-        }
+                Err(e) => eprintln!("Failed to load games without TTB: {}", e),
+            },
+        );
     }
 
     /// Stop the TTB scan
@@ -64,7 +191,7 @@ impl SteamOverachieverApp {
         self.ttb_receiver = None;
         if matches!(self.state, AppState::TtbScanning { .. }) {
             self.state = AppState::Idle;
-            self.status = "TTB scan cancelled".to_string();
+            self.status = tr("ttb.scan_cancelled").to_string();
         }
     }
 
@@ -83,9 +210,8 @@ impl SteamOverachieverApp {
                     ttb_log(&format!("Fetched times for appid={}: main={:?}", appid, times.main));
 
                     // Cache locally
-                    if let Ok(conn) = open_connection() {
-                        let _ = cache_ttb_times(&conn, &times);
-                    }
+                    let cached = times.clone();
+                    self.db_worker.execute("cache TTB times", move |conn| cache_ttb_times(conn, &cached));
                     self.ttb_cache.insert(appid, times.clone());
 
                     // POST to backend (fire and forget)
@@ -107,10 +233,10 @@ impl SteamOverachieverApp {
                     if self.ttb_scan_queue.is_empty() {
                         if is_scanning {
                             // Scan complete - now download any remaining TTB from backend
-                            self.start_ttb_batch_download();
-                            self.status = "TTB scan complete! Downloading from server...".to_string();
+                            self.start_missing_ttb_download();
+                            self.status = tr("ttb.scan_done_downloading").to_string();
                         } else {
-                            self.status = format!("TTB loaded for {}", game_name);
+                            self.status = tr_args("ttb.loaded", &[("game", &game_name)]);
                         }
                         self.state = AppState::Idle;
                     }
@@ -125,10 +251,10 @@ impl SteamOverachieverApp {
                     if self.ttb_scan_queue.is_empty() {
                         if is_scanning {
                             // Scan complete - now download any remaining TTB from backend
-                            self.start_ttb_batch_download();
-                            self.status = "TTB scan complete! Downloading from server...".to_string();
+                            self.start_missing_ttb_download();
+                            self.status = tr("ttb.scan_done_downloading").to_string();
                         } else {
-                            self.status = format!("TTB error: {}", e);
+                            self.status = tr_args("ttb.error", &[("error", &e)]);
                         }
                         self.state = AppState::Idle;
                     }
@@ -156,9 +282,15 @@ impl SteamOverachieverApp {
             return;
         }
 
-        // Check rate limit between fetches (configurable via ttb_scan_delay_secs)
+        // Check rate limit between fetches (configurable via ttb_scan_delay_secs, stretched on battery)
+        let Some(delay) = self.throttled_scan_delay(self.config.ttb_scan_delay_secs) else {
+            if matches!(self.state, AppState::TtbScanning { .. }) {
+                self.status = tr_args("ttb.scan_paused", &[("reason", &self.scan_pause_reason())]);
+            }
+            return;
+        };
         if let Some(last) = self.ttb_last_fetch {
-            if last.elapsed() < Duration::from_secs(self.config.ttb_scan_delay_secs) {
+            if last.elapsed() < delay {
                 return;
             }
         }
@@ -171,16 +303,16 @@ impl SteamOverachieverApp {
             let total = self.ttb_scan_queue.len() as i32 + 1;
             let current = total - self.ttb_scan_queue.len() as i32;
             self.state = AppState::TtbScanning { current, total };
-            self.status = format!("TTB: {} / {} - {}", current, total, game_name);
+            self.status = tr_args("ttb.scan_progress", &[("current", &current), ("total", &total), ("game", &game_name)]);
 
             // Spawn background thread for the fetch
             let (tx, rx) = channel();
             self.ttb_receiver = Some(rx);
 
-            // Clean the game name for search (remove dashes, colons, apostrophe+s, etc.)
-            let search_query = ttb::clean_game_name_for_search(&game_name);
+            // Cleaned name first, then alternate titles (editions, aliases, sequel numbers)
+            let match_name = game_name.clone();
             thread::spawn(move || {
-                let result = ttb::fetch_ttb_times(appid, &search_query);
+                let result = ttb::fetch_ttb_times(appid, &match_name);
                 let _ = tx.send(result.map(|times| (appid, game_name, times)).map_err(|e| e.to_string()));
             });
         }
@@ -197,7 +329,7 @@ impl SteamOverachieverApp {
         ttb_log(&format!("Fetching single game: appid={}, name={}, query={}", appid, game_name, search_query));
 
         self.ttb_fetching = Some(appid);
-        self.status = format!("Fetching TTB for {}...", game_name);
+        self.status = tr_args("ttb.fetching", &[("game", &game_name)]);
 
         // Spawn background thread for the fetch
         let (tx, rx) = channel();
@@ -211,20 +343,27 @@ impl SteamOverachieverApp {
         });
     }
 
-    /// Load TTB cache from local database on startup
+    /// Load TTB cache from local database (on the DB worker)
     pub(crate) fn load_ttb_cache(&mut self) {
-        if let Ok(conn) = open_connection() {
-            let mut count = 0;
-            for game in &self.games {
-                if let Ok(Some(times)) = get_cached_ttb(&conn, game.appid) {
-                    self.ttb_cache.insert(game.appid, times);
-                    count += 1;
+        let appids: Vec<u64> = self.games.iter().map(|g| g.appid).collect();
+        self.db_query(
+            move |conn| {
+                let mut cached = Vec::new();
+                for appid in appids {
+                    if let Some(times) = get_cached_ttb(conn, appid)? {
+                        cached.push(times);
+                    }
                 }
-            }
-            ttb_log(&format!("Loaded {} TTB entries from cache", count));
-        } else {
-            ttb_log("ERROR: Failed to open database connection for TTB cache");
-        }
+                Ok(cached)
+            },
+            |app, cached| match cached {
+                Ok(cached) => {
+                    ttb_log(&format!("Loaded {} TTB entries from cache", cached.len()));
+                    app.ttb_cache.extend(cached.into_iter().map(|times| (times.appid, times)));
+                }
+                Err(e) => ttb_log(&format!("ERROR: Failed to load TTB cache: {}", e)),
+            },
+        );
     }
 
     /// Load TTB blacklist from backend on startup
@@ -268,7 +407,7 @@ impl SteamOverachieverApp {
         }
 
         ttb_log("Starting full TTB download from backend...");
-        self.status = "Downloading all TTB times from server...".to_string();
+        self.status = tr("ttb.downloading_all").to_string();
 
         let (tx, rx) = channel();
         self.ttb_batch_receiver = Some(rx);
@@ -280,13 +419,20 @@ impl SteamOverachieverApp {
     }
 
     /// Start downloading TTB data from backend for games still missing local TTB
-    fn start_ttb_batch_download(&mut self) {
+    fn start_missing_ttb_download(&mut self) {
         // Collect appids of games that still don't have TTB data locally
         let missing_appids: Vec<u64> = self.games.iter()
             .map(|g| g.appid)
             .filter(|appid| !self.ttb_cache.contains_key(appid))
             .collect();
+        self.start_ttb_batch_download(missing_appids);
+    }
 
+    /// Start downloading TTB data from backend for the given games
+    pub(crate) fn start_ttb_batch_download(&mut self, missing_appids: Vec<u64>) {
+        if self.ttb_batch_receiver.is_some() {
+            return;
+        }
         if missing_appids.is_empty() {
             ttb_log("No games missing TTB data - skipping backend download");
             return;
@@ -315,24 +461,23 @@ impl SteamOverachieverApp {
                 let count = ttb_times.len();
                 ttb_log(&format!("Downloaded {} TTB entries from backend", count));
 
-                if let Ok(conn) = open_connection() {
-                    for times in ttb_times {
-                        let _ = cache_ttb_times(&conn, &times);
-                        self.ttb_cache.insert(times.appid, times);
-                    }
-                }
+                let cached = ttb_times.clone();
+                self.db_worker.execute("cache TTB times", move |conn| {
+                    cached.iter().try_for_each(|times| cache_ttb_times(conn, times))
+                });
+                self.ttb_cache.extend(ttb_times.into_iter().map(|times| (times.appid, times)));
 
                 self.ttb_batch_receiver = None;
                 if count > 0 {
-                    self.status = format!("TTB scan complete! Downloaded {} entries from server", count);
+                    self.status = tr_args("ttb.scan_done_downloaded", &[("count", &count)]);
                 } else {
-                    self.status = "TTB scan complete!".to_string();
+                    self.status = tr("ttb.scan_done").to_string();
                 }
             }
             Ok(Err(e)) => {
                 ttb_log(&format!("TTB batch download failed: {}", e));
                 self.ttb_batch_receiver = None;
-                self.status = "TTB scan complete!".to_string();
+                self.status = tr("ttb.scan_done").to_string();
             }
             Err(std::sync::mpsc::TryRecvError::Empty) => {
                 // Still waiting
@@ -365,7 +510,7 @@ impl SteamOverachieverApp {
 
 /// POST TTB times to backend API
 fn post_ttb_to_backend(token: &str, appid: u64, game_name: &str, times: &TtbTimes) -> Result<(), String> {
-    let client = reqwest::blocking::Client::new();
+    let client = crate::http::client();
 
     #[derive(serde::Serialize)]
     struct TtbSubmit {
@@ -387,7 +532,7 @@ fn post_ttb_to_backend(token: &str, appid: u64, game_name: &str, times: &TtbTime
     ttb_log(&format!("Posting to backend: appid={}, game={}, main={:?}", appid, game_name, times.main));
 
     let response = client
-        .post("https://overachiever.space/api/ttb")
+        .post(format!("{}/api/ttb", crate::http::server_url()))
         .header("Authorization", format!("Bearer {}", token))
         .json(&body)
         .send()
@@ -0,0 +1,63 @@
+//! "What's new" after an update, and the background check for a newer release
+
+use std::cmp::Ordering;
+use std::sync::mpsc::channel;
+use std::thread;
+use crate::app::SteamOverachieverApp;
+use crate::changelog::{self, CURRENT_VERSION};
+
+impl SteamOverachieverApp {
+    /// Open the "What's new" dialog on the first start of a new version
+    pub(crate) fn open_whats_new_after_update(&mut self) {
+        let entries = match self.config.last_seen_version.as_deref() {
+            Some(seen) if changelog::compare_versions(seen, CURRENT_VERSION) != Ordering::Less => return,
+            Some(seen) => changelog::entries_since(seen),
+            // Updated from a build before the dialog existed: show the current version's changes
+            None if self.config.has_steam_credentials() => changelog::released_entries().into_iter().take(1).collect(),
+            // Fresh install, nothing is new yet
+            None => Vec::new(),
+        };
+        if !entries.is_empty() {
+            self.whats_new = Some(entries);
+        }
+        self.config.last_seen_version = Some(CURRENT_VERSION.to_string());
+        let _ = self.config.save();
+    }
+
+    /// Open the dialog with the whole changelog
+    pub(crate) fn open_changelog(&mut self) {
+        self.whats_new = Some(changelog::released_entries());
+    }
+
+    /// Ask GitHub for a newer release in the background
+    pub(crate) fn start_update_check(&mut self) {
+        if self.update_check_receiver.is_some() {
+            return;
+        }
+        let (tx, rx) = channel();
+        self.update_check_receiver = Some(rx);
+        thread::spawn(move || {
+            let _ = tx.send(changelog::check_latest_release());
+        });
+    }
+
+    /// Pick up the result of the release check
+    pub(crate) fn update_check_tick(&mut self) {
+        let Some(receiver) = &self.update_check_receiver else {
+            return;
+        };
+        match receiver.try_recv() {
+            Ok(result) => {
+                match result {
+                    Ok(release) => self.latest_release = release,
+                    Err(e) => eprintln!("Update check failed: {}", e),
+                }
+                self.update_check_receiver = None;
+            }
+            Err(std::sync::mpsc::TryRecvError::Empty) => {}
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                self.update_check_receiver = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Wishlist fetching - Steam wishlist plus server TTB/tags/completion data
+
+use std::sync::mpsc::channel;
+use std::thread;
+
+use crate::app::SteamOverachieverApp;
+use crate::db::replace_wishlist;
+
+impl SteamOverachieverApp {
+    /// Fetch the wishlist in the background (no-op while a fetch is running)
+    pub(crate) fn start_wishlist_fetch(&mut self) {
+        if self.wishlist_receiver.is_some() || !self.config.has_steam_credentials() {
+            return;
+        }
+        let Some(steam_id) = self.config.steam_id_u64() else {
+            return;
+        };
+
+        self.wishlist_fetched = true;
+        self.wishlist_error = None;
+        let steam_key = self.config.steam_web_api_key.clone();
+        let (tx, rx) = channel();
+        self.wishlist_receiver = Some(rx);
+        thread::spawn(move || {
+            let _ = tx.send(crate::steam_wishlist::fetch_wishlist_details(&steam_key, steam_id));
+        });
+    }
+
+    /// Check for a finished wishlist fetch
+    pub(crate) fn wishlist_tick(&mut self) {
+        let Some(receiver) = &self.wishlist_receiver else {
+            return;
+        };
+        match receiver.try_recv() {
+            Ok(Ok(details)) => {
+                let steam_id = self.config.steam_id.clone();
+                let items = details.items.clone();
+                self.db_query(
+                    move |conn| replace_wishlist(conn, &steam_id, &items),
+                    |_, saved| {
+                        if let Err(e) = saved {
+                            eprintln!("Failed to save wishlist: {}", e);
+                        }
+                    },
+                );
+                for ttb in details.ttb {
+                    self.ttb_cache.insert(ttb.appid, ttb);
+                }
+                // Replace (not append) tags of wishlisted games so refreshes don't duplicate them
+                for item in &details.items {
+                    self.tags_cache.remove(&item.appid);
+                }
+                for tag in details.tags {
+                    self.tags_cache.entry(tag.appid).or_default().push((tag.tag_name, tag.vote_count));
+                }
+                for item in &details.items {
+                    if let Some(tags) = self.tags_cache.get_mut(&item.appid) {
+                        *tags = self.tag_map.apply(tags);
+                    }
+                }
+                self.wishlist_completion = details.completion.into_iter().map(|s| (s.appid, s)).collect();
+                self.wishlist = details.items;
+                self.wishlist_receiver = None;
+            }
+            Ok(Err(e)) => {
+                eprintln!("Failed to fetch wishlist: {}", e);
+                self.wishlist_error = Some(e);
+                self.wishlist_receiver = None;
+            }
+            Err(std::sync::mpsc::TryRecvError::Empty) => {}
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                self.wishlist_receiver = None;
+            }
+        }
+    }
+}
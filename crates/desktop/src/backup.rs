@@ -0,0 +1,157 @@
+//! Automatic database backups
+//!
+//! Before destructive operations (cloud import, migrations after an update, achievement
+//! schema changes, restoring a backup) the database is snapshotted into `backups/` in the
+//! data directory as `steam_overachiever_<timestamp>_<reason>.db`. Only the newest
+//! `Config::backups_to_keep` copies are kept.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
+use rusqlite::{Connection, OpenFlags};
+
+use crate::config::Config;
+
+const BACKUP_DIR: &str = "backups";
+const BACKUP_PREFIX: &str = "steam_overachiever_";
+const TIMESTAMP_FORMAT: &str = "%Y%m%d_%H%M%S";
+
+/// app_settings key holding the app version that last opened the database
+const DB_APP_VERSION_KEY: &str = "db_app_version";
+
+/// Schema change backups are taken once per run, not once per changed game
+static SCHEMA_CHANGE_BACKED_UP: AtomicBool = AtomicBool::new(false);
+
+/// A backup file in the backups folder
+#[derive(Debug, Clone)]
+pub struct BackupFile {
+    pub path: PathBuf,
+    pub created: DateTime<Local>,
+    /// Why it was taken ("cloud-import", "upgrade", ...)
+    pub reason: String,
+    pub size: u64,
+}
+
+pub fn backup_dir() -> PathBuf {
+    crate::data_dir::data_dir().join(BACKUP_DIR)
+}
+
+/// Snapshot the database into the backups folder and drop the oldest copies.
+/// Returns None when there is no database yet.
+pub fn backup_database(reason: &str) -> Result<Option<PathBuf>, String> {
+    let db_path = crate::db::get_db_path();
+    if !db_path.exists() {
+        return Ok(None);
+    }
+    let dir = backup_dir();
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+
+    let mut dest = dir.join(format!("{}{}_{}.db", BACKUP_PREFIX, Local::now().format(TIMESTAMP_FORMAT), reason));
+    // Two backups within the same second (e.g. restore right after an import)
+    let mut n = 2;
+    while dest.exists() {
+        dest = dir.join(format!("{}{}_{}-{}.db", BACKUP_PREFIX, Local::now().format(TIMESTAMP_FORMAT), reason, n));
+        n += 1;
+    }
+
+    // VACUUM INTO writes a consistent copy even while other connections are open
+    let conn = Connection::open(&db_path).map_err(|e| format!("Failed to open database: {}", e))?;
+    conn.execute("VACUUM INTO ?1", [dest.to_string_lossy()])
+        .map_err(|e| format!("Failed to back up database: {}", e))?;
+
+    rotate(Config::load().backups_to_keep as usize);
+    Ok(Some(dest))
+}
+
+/// Back up, logging failures instead of returning them (for callers that go ahead anyway)
+pub fn backup_or_log(reason: &str) {
+    if let Err(e) = backup_database(reason) {
+        eprintln!("Database backup ({}) failed: {}", reason, e);
+    }
+}
+
+/// Back up before the first achievement schema change of this run
+pub fn backup_before_schema_change() {
+    if !SCHEMA_CHANGE_BACKED_UP.swap(true, Ordering::Relaxed) {
+        backup_or_log("schema-change");
+    }
+}
+
+/// Back up before a new app version runs its migrations (call before the database is opened)
+pub fn backup_before_migrations() {
+    let version = env!("CARGO_PKG_VERSION");
+    let db_path = crate::db::get_db_path();
+    if !db_path.exists() {
+        return;
+    }
+    // Read without init_tables - opening normally would already migrate
+    let last_version: Option<String> = Connection::open(&db_path).ok().and_then(|conn| {
+        conn.query_row("SELECT value FROM app_settings WHERE key = ?1", [DB_APP_VERSION_KEY], |row| row.get(0))
+            .ok()
+    });
+    if last_version.as_deref() == Some(version) {
+        return;
+    }
+    backup_or_log("upgrade");
+    if let Ok(conn) = crate::db::open_connection() {
+        let _ = conn.execute(
+            "INSERT OR REPLACE INTO app_settings (key, value) VALUES (?1, ?2)",
+            [DB_APP_VERSION_KEY, version],
+        );
+    }
+}
+
+/// Backups in the backups folder, newest first
+pub fn list_backups() -> Vec<BackupFile> {
+    let Ok(entries) = std::fs::read_dir(backup_dir()) else {
+        return Vec::new();
+    };
+    let mut backups: Vec<BackupFile> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| parse_backup(&entry.path()))
+        .collect();
+    backups.sort_by(|a, b| b.created.cmp(&a.created).then_with(|| b.path.cmp(&a.path)));
+    backups
+}
+
+fn parse_backup(path: &Path) -> Option<BackupFile> {
+    let stem = path.file_stem()?.to_str()?.strip_prefix(BACKUP_PREFIX)?;
+    if path.extension()? != "db" {
+        return None;
+    }
+    // <date>_<time>_<reason>
+    let (timestamp, reason) = stem.get(..15).zip(stem.get(16..))?;
+    let created = NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT).ok()?;
+    Some(BackupFile {
+        path: path.to_path_buf(),
+        created: Local.from_local_datetime(&created).earliest()?,
+        reason: reason.to_string(),
+        size: std::fs::metadata(path).map(|m| m.len()).unwrap_or(0),
+    })
+}
+
+/// Delete all but the newest `keep` backups
+fn rotate(keep: usize) {
+    for old in list_backups().into_iter().skip(keep.max(1)) {
+        if let Err(e) = std::fs::remove_file(&old.path) {
+            eprintln!("Failed to remove old backup {}: {}", old.path.display(), e);
+        }
+    }
+}
+
+/// Replace the database with a backup (the current database is backed up first)
+pub fn restore_backup(backup: &Path) -> Result<(), String> {
+    // Make sure the file is a readable database before touching anything
+    Connection::open_with_flags(backup, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .and_then(|conn| conn.query_row("SELECT COUNT(*) FROM games", [], |row| row.get::<_, i64>(0)))
+        .map_err(|e| format!("Not a valid backup: {}", e))?;
+
+    // Copy aside first - rotating after the safety backup may delete the one being restored
+    let db_path = crate::db::get_db_path();
+    let staged = db_path.with_extension("db.restore");
+    std::fs::copy(backup, &staged).map_err(|e| format!("Failed to copy backup: {}", e))?;
+    backup_database("before-restore")?;
+    std::fs::rename(&staged, &db_path).map_err(|e| format!("Failed to restore backup: {}", e))?;
+    Ok(())
+}
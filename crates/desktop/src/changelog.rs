@@ -0,0 +1,128 @@
+//! Embedded changelog (parsed from CHANGELOG.md) and the GitHub release check
+//! behind the "What's new" dialog
+
+use std::cmp::Ordering;
+
+/// CHANGELOG.md at build time - "### <version> - <date>" headings followed by "* " items
+const CHANGELOG_MD: &str = include_str!("../../../CHANGELOG.md");
+
+/// Version of this build
+pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Latest release of the desktop app
+const LATEST_RELEASE_URL: &str = "https://api.github.com/repos/kumorig/steam-overachiever-v3/releases/latest";
+
+/// One released version and its changes
+#[derive(Debug, Clone)]
+pub struct ChangelogEntry {
+    pub version: String,
+    pub date: Option<String>,
+    pub items: Vec<String>,
+}
+
+/// A newer release found on GitHub
+#[derive(Debug, Clone)]
+pub struct LatestRelease {
+    pub version: String,
+    /// Release page with the downloads
+    pub url: String,
+}
+
+/// Parse the markdown changelog (newest first, like the file)
+fn parse_changelog(markdown: &str) -> Vec<ChangelogEntry> {
+    let mut entries: Vec<ChangelogEntry> = Vec::new();
+    for line in markdown.lines() {
+        if let Some(heading) = line.strip_prefix("### ") {
+            let (version, date) = match heading.split_once(" - ") {
+                Some((version, date)) => (version, Some(date.trim().to_string())),
+                None => (heading, None),
+            };
+            let version = version.split_whitespace().next().unwrap_or_default().to_string();
+            entries.push(ChangelogEntry { version, date, items: Vec::new() });
+        } else if let Some(entry) = entries.last_mut() {
+            let trimmed = line.trim();
+            let nested = line.starts_with("  ");
+            let Some(item) = trimmed.strip_prefix("* ").or_else(|| trimmed.strip_prefix("- ")) else {
+                continue;
+            };
+            // Markdown emphasis doesn't render in the dialog
+            let item = item.replace("**", "").replace('`', "");
+            match entry.items.last_mut() {
+                Some(parent) if nested => {
+                    parent.push_str("\n  • ");
+                    parent.push_str(&item);
+                }
+                _ => entry.items.push(item),
+            }
+        }
+    }
+    entries
+}
+
+/// Compare dotted version numbers ("0.3.10" > "0.3.9"); a leading "v" is ignored
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let parts = |v: &str| -> Vec<u64> {
+        v.trim_start_matches('v')
+            .split('.')
+            .map(|part| part.chars().take_while(|c| c.is_ascii_digit()).collect::<String>().parse().unwrap_or(0))
+            .collect()
+    };
+    let (a, b) = (parts(a), parts(b));
+    for i in 0..a.len().max(b.len()) {
+        let ordering = a.get(i).copied().unwrap_or(0).cmp(&b.get(i).copied().unwrap_or(0));
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
+
+/// Changelog entries up to the running version, newest first
+pub fn released_entries() -> Vec<ChangelogEntry> {
+    parse_changelog(CHANGELOG_MD)
+        .into_iter()
+        .filter(|entry| compare_versions(&entry.version, CURRENT_VERSION) != Ordering::Greater)
+        .collect()
+}
+
+/// Entries released after `last_seen` (what's new since the user last looked)
+pub fn entries_since(last_seen: &str) -> Vec<ChangelogEntry> {
+    released_entries()
+        .into_iter()
+        .filter(|entry| compare_versions(&entry.version, last_seen) == Ordering::Greater)
+        .collect()
+}
+
+/// Ask GitHub for the latest release, Some if it's newer than this build
+pub fn check_latest_release() -> Result<Option<LatestRelease>, String> {
+    #[derive(serde::Deserialize)]
+    struct Release {
+        tag_name: String,
+        html_url: String,
+    }
+
+    let client = crate::http::client_builder()
+        .timeout(std::time::Duration::from_secs(15))
+        .build()
+        .map_err(|e| e.to_string())?;
+    let response = client
+        .get(LATEST_RELEASE_URL)
+        // GitHub rejects API requests without a user agent
+        .header(reqwest::header::USER_AGENT, format!("overachiever/{}", CURRENT_VERSION))
+        .header(reqwest::header::ACCEPT, "application/vnd.github+json")
+        .send()
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("GitHub returned {}", response.status()));
+    }
+
+    let release: Release = response.json()
+        .map_err(|e| format!("Failed to parse release: {}", e))?;
+    let version = release.tag_name.trim_start_matches('v').to_string();
+    if compare_versions(&version, CURRENT_VERSION) == Ordering::Greater {
+        Ok(Some(LatestRelease { version, url: release.html_url }))
+    } else {
+        Ok(None)
+    }
+}
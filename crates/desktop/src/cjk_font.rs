@@ -39,7 +39,7 @@ where
     fs::create_dir_all(FONT_DIR).map_err(|e| format!("Failed to create assets directory: {}", e))?;
 
     // Download the ZIP file
-    let mut response = reqwest::blocking::get(SOURCE_HAN_SANS_URL)
+    let mut response = crate::http::get(SOURCE_HAN_SANS_URL)
         .map_err(|e| format!("Failed to download font: {}", e))?;
 
     let total_size = response.content_length();
@@ -7,14 +7,15 @@
 //! 4. Desktop captures JWT, saves to config
 //! 5. All sync operations use JWT
 
-use overachiever_core::{CloudSyncData, CloudSyncStatus};
+use overachiever_core::{CloudSyncData, CloudSyncDelta, CloudSyncStatus, SyncManifest};
 use std::io::{BufRead, BufReader, Write};
 use std::net::TcpListener;
 use std::sync::mpsc;
 use std::thread;
 use std::time::Duration;
 
-const DEFAULT_SERVER_URL: &str = "https://overachiever.space";
+use crate::http::server_url;
+
 const CALLBACK_PORT: u16 = 23847; // Random high port for OAuth callback
 
 #[derive(Debug, Clone, PartialEq)]
@@ -51,6 +52,8 @@ pub struct AuthResult {
 #[derive(Debug, Clone)]
 pub enum CloudOpResult {
     UploadSuccess,
+    /// Only the changed games were uploaded
+    DeltaUploadSuccess { changed_games: usize },
     UploadProgress(UploadProgress),
     DownloadSuccess(CloudSyncData),
     DeleteSuccess,
@@ -77,7 +80,7 @@ pub fn start_steam_login() -> Result<mpsc::Receiver<Result<AuthResult, String>>,
     let callback_url = format!("http://localhost:{}/callback", CALLBACK_PORT);
     let login_url = format!(
         "{}/auth/steam?redirect_uri={}",
-        DEFAULT_SERVER_URL,
+        server_url(),
         urlencoding::encode(&callback_url)
     );
     
@@ -181,11 +184,21 @@ fn parse_callback_request(request: &str) -> Result<AuthResult, String> {
     }
 }
 
+/// WebSocket endpoint of the server (presence sharing)
+pub fn websocket_url() -> String {
+    let url = server_url();
+    let url = match url.strip_prefix("http://") {
+        Some(rest) => format!("ws://{}", rest),
+        None => url.replacen("https://", "wss://", 1),
+    };
+    format!("{}/ws", url)
+}
+
 /// Check if user has data in the cloud
 pub fn check_cloud_status(token: &str) -> Result<CloudSyncStatus, String> {
-    let url = format!("{}/api/sync/status", DEFAULT_SERVER_URL);
+    let url = format!("{}/api/sync/status", server_url());
     
-    let client = reqwest::blocking::Client::new();
+    let client = crate::http::client();
     let response = client
         .get(&url)
         .header("Authorization", format!("Bearer {}", token))
@@ -207,20 +220,62 @@ pub fn check_cloud_status(token: &str) -> Result<CloudSyncStatus, String> {
 pub fn upload_to_cloud<F>(token: &str, data: &CloudSyncData, progress_callback: F) -> Result<(), String> 
 where
     F: Fn(usize, usize) + Send + 'static,
+{
+    post_upload(token, "/api/sync/upload", data, progress_callback)
+}
+
+/// What the server already has, for delta uploads (None = server without delta sync)
+pub fn fetch_sync_manifest(token: &str) -> Result<Option<SyncManifest>, String> {
+    let url = format!("{}/api/sync/manifest", server_url());
+    
+    let client = crate::http::client();
+    let response = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .map_err(|e| format!("Network error: {}", e))?;
+    
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+        return Err(format!("Server error {}: {}", status, body));
+    }
+    
+    response.json::<SyncManifest>()
+        .map(Some)
+        .map_err(|e| format!("Failed to parse response: {}", e))
+}
+
+/// Upload only what changed since the server's manifest (merged server-side)
+pub fn upload_delta_to_cloud<F>(token: &str, delta: &CloudSyncDelta, progress_callback: F) -> Result<(), String>
+where
+    F: Fn(usize, usize) + Send + 'static,
+{
+    post_upload(token, "/api/sync/delta", delta, progress_callback)
+}
+
+/// POST a sync payload, reporting (bytes_sent, total_bytes) progress
+fn post_upload<T, F>(token: &str, path: &str, payload: &T, progress_callback: F) -> Result<(), String>
+where
+    T: serde::Serialize,
+    F: Fn(usize, usize) + Send + 'static,
 {
     use std::error::Error;
     
-    let url = format!("{}/api/sync/upload", DEFAULT_SERVER_URL);
+    let url = format!("{}{}", server_url(), path);
     
     // Serialize data first to get total size
-    let json_bytes = serde_json::to_vec(data)
+    let json_bytes = serde_json::to_vec(payload)
         .map_err(|e| format!("Failed to serialize data: {}", e))?;
     let total_bytes = json_bytes.len();
     
     // Report initial progress (0%)
     progress_callback(0, total_bytes);
     
-    let client = reqwest::blocking::Client::builder()
+    let client = crate::http::client_builder()
         .timeout(Duration::from_secs(120)) // 2 minute timeout for uploads
         .build()
         .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
@@ -257,9 +312,9 @@ where
 
 /// Download all data from cloud
 pub fn download_from_cloud(token: &str) -> Result<CloudSyncData, String> {
-    let url = format!("{}/api/sync/download", DEFAULT_SERVER_URL);
+    let url = format!("{}/api/sync/download", server_url());
     
-    let client = reqwest::blocking::Client::new();
+    let client = crate::http::client();
     let response = client
         .get(&url)
         .header("Authorization", format!("Bearer {}", token))
@@ -278,9 +333,9 @@ pub fn download_from_cloud(token: &str) -> Result<CloudSyncData, String> {
 
 /// Delete all data from cloud
 pub fn delete_from_cloud(token: &str) -> Result<(), String> {
-    let url = format!("{}/api/sync/data", DEFAULT_SERVER_URL);
+    let url = format!("{}/api/sync/data", server_url());
     
-    let client = reqwest::blocking::Client::new();
+    let client = crate::http::client();
     let response = client
         .delete(&url)
         .header("Authorization", format!("Bearer {}", token))
@@ -323,8 +378,18 @@ pub fn start_upload_with_sizes(
             })));
         };
         
-        let result = upload_to_cloud(&token, &data, progress_callback)
-            .map(|_| CloudOpResult::UploadSuccess);
+        // Upload only the changes when the server supports it, everything otherwise
+        let result = match fetch_sync_manifest(&token) {
+            Ok(Some(manifest)) => {
+                let delta = CloudSyncDelta::from_data(&data, &manifest);
+                let changed_games = delta.games.len();
+                upload_delta_to_cloud(&token, &delta, progress_callback)
+                    .map(|_| CloudOpResult::DeltaUploadSuccess { changed_games })
+            }
+            Ok(None) => upload_to_cloud(&token, &data, progress_callback)
+                .map(|_| CloudOpResult::UploadSuccess),
+            Err(e) => Err(e),
+        };
         
         // After successful upload, also submit install sizes (best effort, don't fail upload)
         if result.is_ok() && !install_sizes.is_empty() {
@@ -384,13 +449,13 @@ pub fn start_status_check(token: String) -> mpsc::Receiver<Result<CloudOpResult,
 
 /// Submit an achievement rating to the server (fire-and-forget)
 pub fn submit_achievement_rating(token: &str, appid: u64, apiname: &str, rating: u8) {
-    let url = format!("{}/api/achievement/rating", DEFAULT_SERVER_URL);
+    let url = format!("{}/api/achievement/rating", server_url());
     let token = token.to_string();
     let apiname = apiname.to_string();
     
     // Fire-and-forget in background thread
     thread::spawn(move || {
-        let client = reqwest::blocking::Client::new();
+        let client = crate::http::client();
         let body = serde_json::json!({
             "appid": appid,
             "apiname": apiname,
@@ -419,9 +484,9 @@ pub fn submit_achievement_rating(token: &str, appid: u64, apiname: &str, rating:
 
 /// Fetch all achievement ratings for the user from the server
 pub fn fetch_user_achievement_ratings(token: &str) -> Result<Vec<(u64, String, u8)>, String> {
-    let url = format!("{}/api/achievement/ratings", DEFAULT_SERVER_URL);
+    let url = format!("{}/api/achievement/ratings", server_url());
     
-    let client = reqwest::blocking::Client::new();
+    let client = crate::http::client();
     let response = client
         .get(&url)
         .header("Authorization", format!("Bearer {}", token))
@@ -452,6 +517,214 @@ pub fn fetch_user_achievement_ratings(token: &str) -> Result<Vec<(u64, String, u
     Ok(result.ratings.into_iter().map(|r| (r.appid, r.apiname, r.rating)).collect())
 }
 
+/// (appid, apiname, rating) triples as returned by the ratings endpoints
+pub type RatingTriples = Vec<(u64, String, u8)>;
+
+/// Upload all local achievement ratings in one request.
+/// The server keeps whichever copy has the newer updated_at (RFC 3339) and
+/// returns the merged set of ratings.
+pub fn upload_achievement_ratings_batch(token: &str, ratings: &[(u64, String, u8, String)]) -> Result<RatingTriples, String> {
+    let url = format!("{}/api/achievement/ratings/batch", server_url());
+    
+    let items: Vec<serde_json::Value> = ratings
+        .iter()
+        .map(|(appid, apiname, rating, updated_at)| serde_json::json!({
+            "appid": appid,
+            "apiname": apiname,
+            "rating": rating,
+            "updated_at": updated_at,
+        }))
+        .collect();
+    
+    let client = crate::http::client_builder()
+        .timeout(std::time::Duration::from_secs(60))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+    let response = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "ratings": items }))
+        .send()
+        .map_err(|e| format!("Network error: {}", e))?;
+    
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+        return Err(format!("Server error {}: {}", status, body));
+    }
+    
+    #[derive(serde::Deserialize)]
+    struct RatingItem {
+        appid: u64,
+        apiname: String,
+        rating: u8,
+    }
+    
+    #[derive(serde::Deserialize)]
+    struct BatchResponse {
+        ratings: Vec<RatingItem>,
+    }
+    
+    let result: BatchResponse = response.json()
+        .map_err(|e| format!("Failed to parse response: {}", e))?;
+    
+    Ok(result.ratings.into_iter().map(|r| (r.appid, r.apiname, r.rating)).collect())
+}
+
+/// (appid, difficulty curve, comments) result of a background community fetch
+pub type DifficultyResult = (
+    u64,
+    Result<Vec<overachiever_core::AchievementDifficulty>, String>,
+    Result<Vec<overachiever_core::AchievementComment>, String>,
+);
+
+/// Fetch the community difficulty of each rated achievement in a game (public, no auth required)
+pub fn fetch_difficulty_curve(appid: u64) -> Result<Vec<overachiever_core::AchievementDifficulty>, String> {
+    let url = format!("{}/api/achievement/difficulty/{}", server_url(), appid);
+
+    let client = crate::http::client();
+    let response = client
+        .get(&url)
+        .send()
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+        return Err(format!("Server error {}: {}", status, body));
+    }
+
+    response.json()
+        .map_err(|e| format!("Failed to parse response: {}", e))
+}
+
+/// (appid, rarity history) result of a background rarity fetch
+pub type RarityResult = (u64, Result<Vec<overachiever_core::AchievementRarityHistory>, String>);
+
+/// Fetch the global rarity snapshots the server took of a game's achievements (public, no auth required)
+pub fn fetch_rarity_history(appid: u64) -> Result<Vec<overachiever_core::AchievementRarityHistory>, String> {
+    let url = format!("{}/api/rarity/{}", server_url(), appid);
+
+    let client = crate::http::client();
+    let response = client
+        .get(&url)
+        .send()
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+        return Err(format!("Server error {}: {}", status, body));
+    }
+
+    response.json()
+        .map_err(|e| format!("Failed to parse response: {}", e))
+}
+
+/// (appid, community stats) result of a background community fetch
+pub type CommunityStatsResult = (u64, Result<Option<overachiever_core::CommunityGameStats>, String>);
+
+/// Fetch anonymous community completion stats for a game (public, no auth required).
+/// None when too few users played it.
+pub fn fetch_community_game_stats(appid: u64) -> Result<Option<overachiever_core::CommunityGameStats>, String> {
+    let url = format!("{}/api/community/game-stats/{}", server_url(), appid);
+
+    let client = crate::http::client();
+    let response = client
+        .get(&url)
+        .send()
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+        return Err(format!("Server error {}: {}", status, body));
+    }
+
+    response.json()
+        .map_err(|e| format!("Failed to parse response: {}", e))
+}
+
+/// Fetch average achievement ratings and counts for a batch of games (public, no auth required).
+/// Every requested game is in the result, unrated ones with no achievements.
+pub fn fetch_ratings_batch(appids: &[u64]) -> Result<Vec<overachiever_core::GameAchievementRatings>, String> {
+    if appids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let url = format!("{}/api/community/ratings/batch", server_url());
+
+    #[derive(serde::Serialize)]
+    struct BatchRequest<'a> {
+        appids: &'a [u64],
+    }
+
+    #[derive(serde::Deserialize)]
+    struct BatchResponse {
+        games: Vec<overachiever_core::GameAchievementRatings>,
+    }
+
+    let client = crate::http::client();
+    let response = client
+        .post(&url)
+        .json(&BatchRequest { appids })
+        .send()
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+        return Err(format!("Server error {}: {}", status, body));
+    }
+
+    let result: BatchResponse = response.json()
+        .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+    Ok(result.games)
+}
+
+/// Fetch the visible community comments on a game's achievements (public, no auth required)
+pub fn fetch_game_comments(appid: u64) -> Result<Vec<overachiever_core::AchievementComment>, String> {
+    let url = format!("{}/api/achievement/comments/{}", server_url(), appid);
+
+    let client = crate::http::client();
+    let response = client
+        .get(&url)
+        .send()
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+        return Err(format!("Server error {}: {}", status, body));
+    }
+
+    response.json()
+        .map_err(|e| format!("Failed to parse response: {}", e))
+}
+
+/// Report an abusive comment for admin review (fire-and-forget)
+pub fn report_comment(token: &str, comment_id: i64) {
+    let url = format!("{}/api/report", server_url());
+    let token = token.to_string();
+
+    thread::spawn(move || {
+        let client = crate::http::client();
+        let body = serde_json::json!({ "comment_id": comment_id });
+
+        match client
+            .post(&url)
+            .header("Authorization", format!("Bearer {}", token))
+            .json(&body)
+            .send()
+        {
+            Ok(resp) if resp.status().is_success() => {}
+            Ok(resp) => eprintln!("Failed to report comment: HTTP {}", resp.status()),
+            Err(e) => eprintln!("Failed to report comment: {}", e),
+        }
+    });
+}
+
 // ============================================================================
 // Size on Disk Sync
 // ============================================================================
@@ -463,7 +736,7 @@ pub fn submit_size_on_disk(token: &str, sizes: &[(u64, u64)]) -> Result<usize, S
         return Ok(0);
     }
     
-    let url = format!("{}/api/size-on-disk", DEFAULT_SERVER_URL);
+    let url = format!("{}/api/size-on-disk", server_url());
     
     #[derive(serde::Serialize)]
     struct SizeInfo {
@@ -483,7 +756,7 @@ pub fn submit_size_on_disk(token: &str, sizes: &[(u64, u64)]) -> Result<usize, S
         }).collect(),
     };
     
-    let client = reqwest::blocking::Client::new();
+    let client = crate::http::client();
     let response = client
         .post(&url)
         .header("Authorization", format!("Bearer {}", token))
@@ -516,9 +789,9 @@ pub fn submit_size_on_disk(token: &str, sizes: &[(u64, u64)]) -> Result<usize, S
 
 /// Fetch the TTB blacklist from the server (public, no auth required)
 pub fn fetch_ttb_blacklist() -> Result<Vec<u64>, String> {
-    let url = format!("{}/api/ttb/blacklist", DEFAULT_SERVER_URL);
+    let url = format!("{}/api/ttb/blacklist", server_url());
 
-    let client = reqwest::blocking::Client::new();
+    let client = crate::http::client();
     let response = client
         .get(&url)
         .send()
@@ -543,9 +816,9 @@ pub fn fetch_ttb_blacklist() -> Result<Vec<u64>, String> {
 
 /// Add a game to the TTB blacklist (admin only)
 pub fn add_to_ttb_blacklist(token: &str, appid: u64, game_name: &str, reason: Option<&str>) -> Result<(), String> {
-    let url = format!("{}/api/ttb/blacklist", DEFAULT_SERVER_URL);
+    let url = format!("{}/api/ttb/blacklist", server_url());
 
-    let client = reqwest::blocking::Client::new();
+    let client = crate::http::client();
     let body = serde_json::json!({
         "appid": appid,
         "game_name": game_name,
@@ -570,9 +843,9 @@ pub fn add_to_ttb_blacklist(token: &str, appid: u64, game_name: &str, reason: Op
 
 /// Remove a game from the TTB blacklist (admin only)
 pub fn remove_from_ttb_blacklist(token: &str, appid: u64) -> Result<(), String> {
-    let url = format!("{}/api/ttb/blacklist/{}", DEFAULT_SERVER_URL, appid);
+    let url = format!("{}/api/ttb/blacklist/{}", server_url(), appid);
 
-    let client = reqwest::blocking::Client::new();
+    let client = crate::http::client();
     let response = client
         .delete(&url)
         .header("Authorization", format!("Bearer {}", token))
@@ -594,9 +867,9 @@ pub fn remove_from_ttb_blacklist(token: &str, appid: u64) -> Result<(), String>
 
 /// Fetch all available tag names from the server
 pub fn fetch_tag_names() -> Result<Vec<String>, String> {
-    let url = format!("{}/api/tags", DEFAULT_SERVER_URL);
+    let url = format!("{}/api/tags", server_url());
 
-    let client = reqwest::blocking::Client::new();
+    let client = crate::http::client();
     let response = client
         .get(&url)
         .send()
@@ -619,23 +892,13 @@ pub fn fetch_tag_names() -> Result<Vec<String>, String> {
     Ok(result.tags)
 }
 
-/// Fetch tags for a batch of games from the server
-pub fn fetch_tags_batch(appids: &[u64]) -> Result<Vec<overachiever_core::GameTag>, String> {
-    if appids.is_empty() {
-        return Ok(vec![]);
-    }
-
-    let url = format!("{}/api/tags/batch", DEFAULT_SERVER_URL);
-
-    #[derive(serde::Serialize)]
-    struct BatchRequest {
-        appids: Vec<u64>,
-    }
+/// Fetch the admin-maintained tag aliases and parents
+pub fn fetch_tag_mappings() -> Result<Vec<overachiever_core::TagMapping>, String> {
+    let url = format!("{}/api/tags/mappings", server_url());
 
-    let client = reqwest::blocking::Client::new();
+    let client = crate::http::client();
     let response = client
-        .post(&url)
-        .json(&BatchRequest { appids: appids.to_vec() })
+        .get(&url)
         .send()
         .map_err(|e| format!("Network error: {}", e))?;
 
@@ -645,20 +908,39 @@ pub fn fetch_tags_batch(appids: &[u64]) -> Result<Vec<overachiever_core::GameTag
         return Err(format!("Server error {}: {}", status, body));
     }
 
+    response.json()
+        .map_err(|e| format!("Failed to parse response: {}", e))
+}
+
+/// Fetch tags for a batch of games from the server (answered from the local ETag cache when unchanged)
+pub fn fetch_tags_batch(appids: &[u64]) -> Result<Vec<overachiever_core::GameTag>, String> {
+    if appids.is_empty() {
+        return Ok(vec![]);
+    }
+
     #[derive(serde::Deserialize)]
     struct BatchResponse {
         tags: Vec<overachiever_core::GameTag>,
     }
 
-    let result: BatchResponse = response.json()
+    let body = post_appids_batch_cached("/api/tags/batch", appids)?;
+    let result: BatchResponse = serde_json::from_str(&body)
         .map_err(|e| format!("Failed to parse response: {}", e))?;
 
     Ok(result.tags)
 }
 
+/// Why a tag submission didn't go through
+#[derive(Debug)]
+pub enum SubmitTagsError {
+    /// The server's rate limit was hit - send it again after this long (its Retry-After)
+    RateLimited(Duration),
+    Failed(String),
+}
+
 /// Submit tags for a game to the server (admin only)
-pub fn submit_tags(token: &str, appid: u64, tags: &[(String, u32)]) -> Result<usize, String> {
-    let url = format!("{}/api/tags", DEFAULT_SERVER_URL);
+pub fn submit_tags(token: &str, appid: u64, tags: &[(String, u32)]) -> Result<usize, SubmitTagsError> {
+    let url = format!("{}/api/tags", server_url());
 
     #[derive(serde::Serialize)]
     struct SubmitRequest {
@@ -666,18 +948,27 @@ pub fn submit_tags(token: &str, appid: u64, tags: &[(String, u32)]) -> Result<us
         tags: Vec<(String, u32)>,
     }
 
-    let client = reqwest::blocking::Client::new();
+    let client = crate::http::client();
     let response = client
         .post(&url)
         .header("Authorization", format!("Bearer {}", token))
         .json(&SubmitRequest { appid, tags: tags.to_vec() })
         .send()
-        .map_err(|e| format!("Network error: {}", e))?;
-
+        .map_err(|e| SubmitTagsError::Failed(format!("Network error: {}", e)))?;
+
+    if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse().ok())
+            .unwrap_or(60);
+        return Err(SubmitTagsError::RateLimited(Duration::from_secs(retry_after)));
+    }
     if !response.status().is_success() {
         let status = response.status();
         let body = response.text().unwrap_or_default();
-        return Err(format!("Server error {}: {}", status, body));
+        return Err(SubmitTagsError::Failed(format!("Server error {}: {}", status, body)));
     }
 
     #[derive(serde::Deserialize)]
@@ -688,48 +979,104 @@ pub fn submit_tags(token: &str, appid: u64, tags: &[(String, u32)]) -> Result<us
     }
 
     let result: SubmitResponse = response.json()
-        .map_err(|e| format!("Failed to parse response: {}", e))?;
+        .map_err(|e| SubmitTagsError::Failed(format!("Failed to parse response: {}", e)))?;
 
     Ok(result.count)
 }
 
-/// Fetch TTB times for a batch of games from the server
+/// Fetch TTB times for a batch of games from the server (answered from the local ETag cache when unchanged)
 pub fn fetch_ttb_batch(appids: &[u64]) -> Result<Vec<overachiever_core::TtbTimes>, String> {
     if appids.is_empty() {
         return Ok(vec![]);
     }
 
-    let url = format!("{}/api/ttb/batch", DEFAULT_SERVER_URL);
+    let body = post_appids_batch_cached("/api/ttb/batch", appids)?;
+    serde_json::from_str(&body)
+        .map_err(|e| format!("Failed to parse response: {}", e))
+}
+
+/// Fetch community completion averages for a batch of games (answered from the local ETag cache when unchanged)
+pub fn fetch_completion_batch(appids: &[u64]) -> Result<Vec<overachiever_core::GameCompletionStats>, String> {
+    if appids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    #[derive(serde::Deserialize)]
+    struct BatchResponse {
+        stats: Vec<overachiever_core::GameCompletionStats>,
+    }
+
+    let body = post_appids_batch_cached("/api/games/completion/batch", appids)?;
+    let result: BatchResponse = serde_json::from_str(&body)
+        .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+    Ok(result.stats)
+}
+
+/// POST `{ "appids": [...] }` with If-None-Match, returning the response body.
+/// Bodies are cached in SQLite per endpoint and appid set, so a 304 reuses the cached copy.
+fn post_appids_batch_cached(path: &str, appids: &[u64]) -> Result<String, String> {
+    use std::hash::{DefaultHasher, Hash, Hasher};
+
+    let url = format!("{}{}", server_url(), path);
+
+    let mut hasher = DefaultHasher::new();
+    appids.hash(&mut hasher);
+    let cache_key = format!("{}:{:016x}", path, hasher.finish());
+
+    let conn = crate::db::open_connection().ok();
+    let cached = conn
+        .as_ref()
+        .and_then(|conn| crate::db::get_http_cache(conn, &cache_key).ok().flatten());
 
     #[derive(serde::Serialize)]
-    struct BatchRequest {
-        appids: Vec<u64>,
+    struct BatchRequest<'a> {
+        appids: &'a [u64],
     }
 
-    let client = reqwest::blocking::Client::new();
-    let response = client
-        .post(&url)
-        .json(&BatchRequest { appids: appids.to_vec() })
+    let client = crate::http::client();
+    let mut request = client.post(&url).json(&BatchRequest { appids });
+    if let Some((etag, _)) = &cached {
+        request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+    }
+    let response = request
         .send()
         .map_err(|e| format!("Network error: {}", e))?;
 
+    if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+        if let Some((_, body)) = cached {
+            return Ok(body);
+        }
+    }
+
     if !response.status().is_success() {
         let status = response.status();
         let body = response.text().unwrap_or_default();
         return Err(format!("Server error {}: {}", status, body));
     }
 
-    let times: Vec<overachiever_core::TtbTimes> = response.json()
-        .map_err(|e| format!("Failed to parse response: {}", e))?;
+    let etag = response
+        .headers()
+        .get(reqwest::header::ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string());
+    let body = response.text()
+        .map_err(|e| format!("Failed to read response: {}", e))?;
+
+    if let (Some(conn), Some(etag)) = (&conn, etag) {
+        if let Err(e) = crate::db::save_http_cache(conn, &cache_key, &etag, &body) {
+            eprintln!("Failed to cache {} response: {}", path, e);
+        }
+    }
 
-    Ok(times)
+    Ok(body)
 }
 
 /// Fetch ALL TTB times from the server (no appid filter)
 pub fn fetch_all_ttb() -> Result<Vec<overachiever_core::TtbTimes>, String> {
-    let url = format!("{}/api/ttb/all", DEFAULT_SERVER_URL);
+    let url = format!("{}/api/ttb/all", server_url());
 
-    let client = reqwest::blocking::Client::new();
+    let client = crate::http::client();
     let response = client
         .get(&url)
         .send()
@@ -746,3 +1093,134 @@ pub fn fetch_all_ttb() -> Result<Vec<overachiever_core::TtbTimes>, String> {
 
     Ok(times)
 }
+
+// ============================================================================
+// Admin Dashboard API (admin only)
+// ============================================================================
+
+/// Everything shown in the admin window, fetched together
+#[derive(Debug, Clone, Default)]
+pub struct AdminDashboard {
+    pub stats: overachiever_core::AdminStats,
+    pub users: Vec<overachiever_core::AdminUser>,
+    pub pending_ttb: Vec<overachiever_core::PendingTtbSubmission>,
+    pub pending_tags: Vec<overachiever_core::PendingTagSubmission>,
+    pub reported_comments: Vec<overachiever_core::ReportedComment>,
+    pub tag_mappings: Vec<overachiever_core::TagMapping>,
+}
+
+/// GET an admin endpoint and parse the JSON response
+fn admin_get<T: serde::de::DeserializeOwned>(client: &reqwest::blocking::Client, token: &str, path: &str) -> Result<T, String> {
+    let url = format!("{}/api/admin/{}", server_url(), path);
+
+    let response = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+        return Err(format!("Server error {}: {}", status, body));
+    }
+
+    response.json::<T>()
+        .map_err(|e| format!("Failed to parse response: {}", e))
+}
+
+/// Fetch stats, users and review queues for the admin window
+pub fn fetch_admin_dashboard(token: &str) -> Result<AdminDashboard, String> {
+    let client = crate::http::client();
+    Ok(AdminDashboard {
+        stats: admin_get(&client, token, "stats")?,
+        users: admin_get(&client, token, "users")?,
+        pending_ttb: admin_get(&client, token, "ttb/pending")?,
+        pending_tags: admin_get(&client, token, "tags/pending")?,
+        reported_comments: admin_get(&client, token, "comments/reported")?,
+        tag_mappings: fetch_tag_mappings()?,
+    })
+}
+
+/// Approve or reject a pending submission (`kind` is "ttb" or "tags")
+pub fn moderate_submission(token: &str, kind: &str, appid: u64, approve: bool) -> Result<(), String> {
+    let client = crate::http::client();
+    let request = if approve {
+        client.post(format!("{}/api/admin/{}/{}/approve", server_url(), kind, appid))
+    } else {
+        client.delete(format!("{}/api/admin/{}/{}", server_url(), kind, appid))
+    };
+
+    let response = request
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+        return Err(format!("Server error {}: {}", status, body));
+    }
+
+    Ok(())
+}
+
+/// Remove a reported comment, or dismiss its reports and keep it
+pub fn moderate_comment(token: &str, comment_id: i64, remove: bool) -> Result<(), String> {
+    let client = crate::http::client();
+    let request = if remove {
+        client.delete(format!("{}/api/admin/comments/{}", server_url(), comment_id))
+    } else {
+        client.post(format!("{}/api/admin/comments/{}/dismiss", server_url(), comment_id))
+    };
+
+    let response = request
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+        return Err(format!("Server error {}: {}", status, body));
+    }
+
+    Ok(())
+}
+
+/// Add or replace a tag alias/parent
+pub fn set_tag_mapping(token: &str, mapping: &overachiever_core::TagMapping) -> Result<(), String> {
+    let client = crate::http::client();
+    let response = client
+        .put(format!("{}/api/admin/tags/mappings", server_url()))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(mapping)
+        .send()
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+        return Err(format!("Server error {}: {}", status, body));
+    }
+
+    Ok(())
+}
+
+/// Remove a tag alias/parent
+pub fn delete_tag_mapping(token: &str, tag: &str) -> Result<(), String> {
+    let client = crate::http::client();
+    let response = client
+        .delete(format!("{}/api/admin/tags/mappings/{}", server_url(), urlencoding::encode(tag)))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+        return Err(format!("Server error {}: {}", status, body));
+    }
+
+    Ok(())
+}
@@ -1,27 +1,45 @@
 //! Configuration management using config.toml
 
-use overachiever_core::GdprConsent;
+use overachiever_core::{AchievementSort, GdprConsent, GroupBy, Language, SortTieBreaker, TimeDisplaySettings};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 
-/// Get the path to the config file in the app's data directory
-fn get_config_path() -> PathBuf {
-    if let Some(proj_dirs) = directories::ProjectDirs::from("", "", "Overachiever") {
-        let data_dir = proj_dirs.data_dir();
-        // Create the directory if it doesn't exist
-        if let Err(e) = std::fs::create_dir_all(data_dir) {
-            eprintln!("Failed to create data directory: {}", e);
-            // Fall back to current directory
-            return PathBuf::from("config.toml");
+/// Current config file format version (bump together with a new `MIGRATIONS` step)
+const CONFIG_VERSION: u32 = 1;
+
+/// Migration steps on the raw TOML table, `MIGRATIONS[n]` upgrades a version `n` file to `n + 1`
+const MIGRATIONS: &[fn(&mut toml::Table)] = &[migrate_v0_to_v1];
+
+/// v0 (unversioned): the Steam ID and API key were stored as typed, including
+/// whitespace picked up when pasting them
+fn migrate_v0_to_v1(table: &mut toml::Table) {
+    for key in ["steam_id", "steam_web_api_key"] {
+        if let Some(toml::Value::String(value)) = table.get_mut(key) {
+            *value = value.trim().to_string();
         }
-        data_dir.join("config.toml")
-    } else {
-        // Fallback to current directory if we can't get the app data dir
-        PathBuf::from("config.toml")
     }
 }
 
+/// Copy the config file next to itself as `config.toml.<suffix>`, returns the backup path
+fn backup_config_file(path: &std::path::Path, suffix: &str) -> Option<PathBuf> {
+    let backup = path.with_extension(format!("toml.{}", suffix));
+    fs::copy(path, &backup).ok().map(|_| backup)
+}
+
+/// A config value that is present but can't be used as is
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigIssue {
+    /// Name of the setting, as shown in the settings window
+    pub field: &'static str,
+    pub message: String,
+}
+
+/// Get the path to the config file in the app's data directory
+fn get_config_path() -> PathBuf {
+    crate::data_dir::data_dir().join("config.toml")
+}
+
 /// Font source selection
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 pub enum FontSource {
@@ -78,8 +96,98 @@ impl CjkFontWeight {
     }
 }
 
+/// UI theme selection
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum ThemeMode {
+    #[default]
+    Dark,
+    Light,
+}
+
+impl ThemeMode {
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Dark => "Dark",
+            Self::Light => "Light",
+        }
+    }
+
+    /// All available themes
+    pub fn all() -> &'static [ThemeMode] {
+        &[Self::Dark, Self::Light]
+    }
+}
+
+/// How background scans react to battery and system load
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum PowerMode {
+    /// Slow down on battery, pause on low battery or high CPU load
+    #[default]
+    Auto,
+    /// Always run at full speed
+    FullSpeed,
+}
+
+impl PowerMode {
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Auto => "Adapt to battery & load",
+            Self::FullSpeed => "Always full speed",
+        }
+    }
+
+    /// All available modes
+    pub fn all() -> &'static [PowerMode] {
+        &[Self::Auto, Self::FullSpeed]
+    }
+}
+
+/// Which view opens at startup
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum StartupView {
+    /// Games table with default sorting and filters
+    #[default]
+    Library,
+    /// Sidebar opened on the stats panel
+    Stats,
+    /// Played, unfinished games with achievements - closest to 100% first
+    Backlog,
+    /// Sidebar opened on the activity log
+    Log,
+    /// Whatever was open when the app was last closed
+    LastUsed,
+}
+
+impl StartupView {
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Library => "Library",
+            Self::Stats => "Stats",
+            Self::Backlog => "Backlog",
+            Self::Log => "Log",
+            Self::LastUsed => "Last used",
+        }
+    }
+
+    /// All available views
+    pub fn all() -> &'static [StartupView] {
+        &[Self::Library, Self::Stats, Self::Backlog, Self::Log, Self::LastUsed]
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
+    /// File format version, used to run migrations (missing = written before versioning)
+    #[serde(default)]
+    pub config_version: u32,
+
+    /// Why the config file couldn't be loaded (not saved, shown in the settings window)
+    #[serde(skip)]
+    pub load_error: Option<String>,
+
     /// Steam Web API key
     #[serde(default)]
     pub steam_web_api_key: String,
@@ -88,10 +196,18 @@ pub struct Config {
     #[serde(default)]
     pub steam_id: String,
 
-    /// Server URL for hybrid/remote modes
+    /// Backend base URL (empty = https://overachiever.space)
     #[serde(default)]
     pub server_url: String,
 
+    /// Steam Web API base URL (empty = https://api.steampowered.com)
+    #[serde(default)]
+    pub steam_api_url: String,
+
+    /// HTTP(S) or SOCKS5 proxy for all requests (empty = system proxy settings)
+    #[serde(default)]
+    pub proxy_url: String,
+
     /// GDPR consent status (for hybrid/remote modes)
     #[serde(default)]
     pub gdpr_consent: GdprConsent,
@@ -100,6 +216,10 @@ pub struct Config {
     #[serde(default)]
     pub cloud_token: Option<String>,
 
+    /// Upload to the cloud after each Update and Full Scan (only while linked, default: false)
+    #[serde(default)]
+    pub auto_upload_after_scan: bool,
+
     /// Debug: output recently played response to file
     #[serde(default)]
     pub debug_recently_played: bool,
@@ -155,6 +275,158 @@ pub struct Config {
     /// Hide private games from the games table (default: true)
     #[serde(default = "default_true")]
     pub hide_private_games: bool,
+
+    /// UI theme (dark or light)
+    #[serde(default)]
+    pub theme_mode: ThemeMode,
+
+    /// Custom accent color as RGB (None = theme default)
+    #[serde(default)]
+    pub accent_color: Option<[u8; 3]>,
+
+    /// Completion percent at which games count as done (default: 100)
+    #[serde(default = "default_complete_threshold")]
+    pub complete_threshold: f32,
+
+    /// Completion percent at which games are colored as near complete (default: 80)
+    #[serde(default = "default_near_complete_threshold")]
+    pub near_complete_threshold: f32,
+
+    /// Poll "currently playing" status every N seconds to record play sessions (0 = disabled, default: 120)
+    #[serde(default = "default_session_poll_interval_secs")]
+    pub session_poll_interval_secs: u64,
+
+    /// Background scan power management (auto throttle or always full speed)
+    #[serde(default)]
+    pub power_mode: PowerMode,
+
+    /// Pause background scans while Steam reports a game running (needs session tracking, default: true)
+    #[serde(default = "default_true")]
+    pub pause_scans_while_playing: bool,
+
+    /// Pick up unlocks from the Steam client's local stats cache as they happen (experimental, default: false)
+    #[serde(default)]
+    pub local_unlock_watch: bool,
+
+    /// View shown at startup
+    #[serde(default)]
+    pub startup_view: StartupView,
+
+    /// Whether the sidebar starts expanded (Stats and Log views always expand it)
+    #[serde(default = "default_true")]
+    pub startup_sidebar_expanded: bool,
+
+    /// View open when the app was last closed (used by StartupView::LastUsed)
+    #[serde(default)]
+    pub last_view: StartupView,
+
+    /// Sidebar state when the app was last closed (used by StartupView::LastUsed)
+    #[serde(default = "default_true")]
+    pub last_sidebar_expanded: bool,
+
+    /// Time zone and timestamp style for unlock times
+    #[serde(default)]
+    pub time_display: TimeDisplaySettings,
+
+    /// UI language
+    #[serde(default)]
+    pub language: Language,
+
+    /// Achievement data older than this many days is shown as stale and picked up by "Refresh stale"
+    #[serde(default = "default_stale_scrape_days")]
+    pub stale_scrape_days: u32,
+
+    /// Disk budget for cached icons in MB (least recently used icons are evicted beyond it)
+    #[serde(default = "default_icon_cache_max_mb")]
+    pub icon_cache_max_mb: u64,
+
+    /// Budget for icon bytes kept in memory in MB (least recently shown icons are dropped beyond it)
+    #[serde(default = "default_icon_memory_max_mb")]
+    pub icon_memory_max_mb: u64,
+
+    /// Show a desktop notification in the evening when today's unlock streak is about to break
+    #[serde(default)]
+    pub streak_reminder: bool,
+
+    /// Hour of the day (display time zone) from which the streak reminder may fire (default: 20)
+    #[serde(default = "default_streak_reminder_hour")]
+    pub streak_reminder_hour: u32,
+
+    /// Order of the achievement list in expanded game rows
+    #[serde(default)]
+    pub achievement_sort: AchievementSort,
+
+    /// Show descriptions of locked hidden achievements without clicking them (default: false)
+    #[serde(default)]
+    pub reveal_spoilers: bool,
+
+    /// How games the sort column ranks equal are ordered
+    #[serde(default)]
+    pub sort_tie_breaker: SortTieBreaker,
+
+    /// Grouping of the games table into collapsible sections
+    #[serde(default)]
+    pub group_by: GroupBy,
+
+    /// Show a games table column with the time played on a Steam Deck
+    #[serde(default)]
+    pub show_deck_playtime_column: bool,
+
+    /// Leave soundtracks, tools, demos etc. out of all statistics
+    #[serde(default)]
+    pub exclude_non_games_from_stats: bool,
+
+    /// Count games played without owning them (free weekends, Family Sharing) in the statistics
+    #[serde(default)]
+    pub include_unowned_in_stats: bool,
+
+    /// Serve a stream overlay (OBS browser source) on localhost
+    #[serde(default)]
+    pub overlay_server_enabled: bool,
+
+    /// Port of the stream overlay server (default: 4747)
+    #[serde(default = "default_overlay_server_port")]
+    pub overlay_server_port: u16,
+
+    /// Show the small always-on-top window with completion, today's unlocks and the streak
+    #[serde(default)]
+    pub mini_window: bool,
+
+    /// Share the currently played game with other linked users (and see theirs)
+    #[serde(default)]
+    pub share_presence: bool,
+
+    /// Games the stalled games review was told to keep (not suggested again)
+    #[serde(default)]
+    pub stalled_review_kept: Vec<u64>,
+
+    /// Version whose changes the user has seen ("What's new" opens after updating past it)
+    #[serde(default)]
+    pub last_seen_version: Option<String>,
+
+    /// Check GitHub for a newer release on startup
+    #[serde(default = "default_true")]
+    pub check_for_updates: bool,
+
+    /// SteamID64 of a public account to compare achievements against (empty = no rival)
+    #[serde(default)]
+    pub rival_steam_id: String,
+
+    /// Database backups kept in the backups folder (oldest are deleted)
+    #[serde(default = "default_backups_to_keep")]
+    pub backups_to_keep: u32,
+
+    /// Days between scheduled history snapshots taken from cached data when no update ran (0 = off)
+    #[serde(default = "default_snapshot_interval_days")]
+    pub snapshot_interval_days: u32,
+}
+
+fn default_backups_to_keep() -> u32 {
+    10
+}
+
+fn default_snapshot_interval_days() -> u32 {
+    7
 }
 
 fn default_name_column_width() -> f32 {
@@ -173,18 +445,55 @@ fn default_tags_scan_delay_secs() -> u64 {
     5
 }
 
+fn default_stale_scrape_days() -> u32 {
+    30
+}
+
+fn default_icon_cache_max_mb() -> u64 {
+    crate::icon_cache::DEFAULT_MAX_CACHE_MB
+}
+
+fn default_icon_memory_max_mb() -> u64 {
+    crate::icon_cache::DEFAULT_MAX_MEMORY_MB
+}
+
+fn default_streak_reminder_hour() -> u32 {
+    20
+}
+
 fn default_true() -> bool {
     true
 }
 
+fn default_session_poll_interval_secs() -> u64 {
+    120
+}
+
+fn default_overlay_server_port() -> u16 {
+    4747
+}
+
+fn default_complete_threshold() -> f32 {
+    overachiever_core::DEFAULT_COMPLETE_THRESHOLD
+}
+
+fn default_near_complete_threshold() -> f32 {
+    overachiever_core::DEFAULT_NEAR_COMPLETE_THRESHOLD
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
+            config_version: CONFIG_VERSION,
+            load_error: None,
             steam_web_api_key: String::new(),
             steam_id: String::new(),
             server_url: String::new(),
+            steam_api_url: String::new(),
+            proxy_url: String::new(),
             gdpr_consent: GdprConsent::Unset,
             cloud_token: None,
+            auto_upload_after_scan: false,
             debug_recently_played: false,
             font_source: FontSource::default(),
             cjk_font_weight: CjkFontWeight::default(),
@@ -199,20 +508,85 @@ impl Default for Config {
             ttb_scan_delay_secs: default_ttb_scan_delay_secs(),
             tags_scan_delay_secs: default_tags_scan_delay_secs(),
             hide_private_games: true,
+            theme_mode: ThemeMode::default(),
+            accent_color: None,
+            complete_threshold: default_complete_threshold(),
+            near_complete_threshold: default_near_complete_threshold(),
+            session_poll_interval_secs: default_session_poll_interval_secs(),
+            power_mode: PowerMode::default(),
+            pause_scans_while_playing: true,
+            local_unlock_watch: false,
+            startup_view: StartupView::default(),
+            startup_sidebar_expanded: true,
+            last_view: StartupView::default(),
+            last_sidebar_expanded: true,
+            time_display: TimeDisplaySettings::default(),
+            language: Language::default(),
+            stale_scrape_days: default_stale_scrape_days(),
+            icon_cache_max_mb: default_icon_cache_max_mb(),
+            icon_memory_max_mb: default_icon_memory_max_mb(),
+            streak_reminder: false,
+            streak_reminder_hour: default_streak_reminder_hour(),
+            achievement_sort: AchievementSort::default(),
+            reveal_spoilers: false,
+            sort_tie_breaker: SortTieBreaker::default(),
+            group_by: GroupBy::default(),
+            show_deck_playtime_column: false,
+            exclude_non_games_from_stats: false,
+            include_unowned_in_stats: false,
+            overlay_server_enabled: false,
+            overlay_server_port: default_overlay_server_port(),
+            mini_window: false,
+            share_presence: false,
+            stalled_review_kept: Vec::new(),
+            last_seen_version: None,
+            check_for_updates: true,
+            rival_steam_id: String::new(),
+            backups_to_keep: default_backups_to_keep(),
+            snapshot_interval_days: default_snapshot_interval_days(),
         }
     }
 }
 
 impl Config {
-    /// Load config from file, creating default if it doesn't exist
+    /// Load config from file, creating default if it doesn't exist.
+    /// Older files are migrated (keeping a backup); files from a newer version are backed up
+    /// before this version saves over them, since settings it doesn't know would be lost;
+    /// unreadable files are backed up before being replaced with defaults, and the error is
+    /// kept in `load_error`.
     pub fn load() -> Self {
         let config_path = get_config_path();
         if config_path.exists() {
             match fs::read_to_string(&config_path) {
-                Ok(content) => match toml::from_str(&content) {
-                    Ok(config) => return config,
+                Ok(content) => match Self::parse(&content) {
+                    Ok((mut config, from_version)) => {
+                        if from_version < CONFIG_VERSION {
+                            backup_config_file(&config_path, &format!("v{}.bak", from_version));
+                            let _ = config.save();
+                        } else if from_version > CONFIG_VERSION {
+                            // Without a backup, config_version stays ahead and `save` refuses
+                            config.load_error = Some(match backup_config_file(&config_path, &format!("v{}.bak", from_version)) {
+                                Some(backup) => {
+                                    config.config_version = CONFIG_VERSION;
+                                    format!("config.toml was written by a newer version of Overachiever, settings this version doesn't know are not kept.\nThe original file was kept as {}", backup.display())
+                                }
+                                None => "config.toml was written by a newer version of Overachiever and could not be backed up, so settings changes are not saved.".to_string(),
+                            });
+                        }
+                        return config;
+                    }
                     Err(e) => {
                         eprintln!("Error parsing config.toml at {:?}: {}", config_path, e);
+                        let backup = backup_config_file(&config_path, "invalid.bak");
+                        let config = Config {
+                            load_error: Some(match backup {
+                                Some(backup) => format!("Settings were reset because config.toml could not be read: {}\nThe old file was kept as {}", e, backup.display()),
+                                None => format!("Settings were reset because config.toml could not be read: {}", e),
+                            }),
+                            ..Config::default()
+                        };
+                        let _ = config.save();
+                        return config;
                     }
                 },
                 Err(e) => {
@@ -227,14 +601,85 @@ impl Config {
         config
     }
 
-    /// Save config to file
+    /// Parse config file contents, running the migration steps for older versions.
+    /// Returns the config and the version the file was written with.
+    fn parse(content: &str) -> Result<(Self, u32), toml::de::Error> {
+        let mut table: toml::Table = toml::from_str(content)?;
+        let from_version = table
+            .get("config_version")
+            .and_then(|v| v.as_integer())
+            .unwrap_or(0)
+            .clamp(0, u32::MAX as i64) as u32;
+        if from_version < CONFIG_VERSION {
+            for step in &MIGRATIONS[from_version as usize..] {
+                step(&mut table);
+            }
+            table.insert("config_version".to_string(), toml::Value::Integer(CONFIG_VERSION as i64));
+        }
+        let config = toml::Value::Table(table).try_into()?;
+        Ok((config, from_version))
+    }
+
+    /// Save config to file, keeping the previous file as config.toml.bak.
+    /// Refuses while the config is from a newer version that wasn't backed up (see `load`).
     pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.config_version > CONFIG_VERSION {
+            return Err(format!("config.toml is from a newer version (v{}), not overwriting it", self.config_version).into());
+        }
         let config_path = get_config_path();
         let content = toml::to_string_pretty(self)?;
+        if let Ok(previous) = fs::read_to_string(&config_path) {
+            if previous == content {
+                return Ok(());
+            }
+            let _ = fs::write(config_path.with_extension("toml.bak"), previous);
+        }
         fs::write(&config_path, content)?;
         Ok(())
     }
 
+    /// Check values that deserialize fine but can't be used as is
+    pub fn validate(&self) -> Vec<ConfigIssue> {
+        let mut issues = Vec::new();
+        let mut issue = |field: &'static str, message: &str| issues.push(ConfigIssue { field, message: message.to_string() });
+
+        let steam_id = self.steam_id.trim();
+        let is_steam_id64 = steam_id.len() == 17 && steam_id.starts_with("7656119") && steam_id.chars().all(|c| c.is_ascii_digit());
+        if !steam_id.is_empty() && !is_steam_id64 {
+            issue("Steam ID", "should be a 17-digit SteamID64 starting with 7656119, not a profile name or URL");
+        }
+        let api_key = self.steam_web_api_key.trim();
+        let is_api_key = api_key.len() == 32 && api_key.chars().all(|c| c.is_ascii_hexdigit());
+        if !api_key.is_empty() && !is_api_key {
+            issue("API Key", "Steam Web API keys are 32 hexadecimal characters");
+        }
+        if !self.server_url.is_empty() && !self.server_url.starts_with("http://") && !self.server_url.starts_with("https://") {
+            issue("Server URL", "must start with http:// or https://");
+        }
+        if !self.steam_api_url.is_empty() && !self.steam_api_url.starts_with("http://") && !self.steam_api_url.starts_with("https://") {
+            issue("Steam API URL", "must start with http:// or https://");
+        }
+        let proxy = self.proxy_url.trim();
+        if !proxy.is_empty() && !crate::http::PROXY_SCHEMES.iter().any(|scheme| proxy.starts_with(scheme)) {
+            issue("Proxy", "must start with http://, https://, socks5:// or socks5h://");
+        }
+        if !(8.0..=32.0).contains(&self.font_size) {
+            issue("Font size", "must be between 8 and 32 pt");
+        }
+        if !(0.0..=100.0).contains(&self.complete_threshold) {
+            issue("Done threshold", "must be a percentage between 0 and 100");
+        }
+        if !(0.0..=100.0).contains(&self.near_complete_threshold) {
+            issue("Near complete threshold", "must be a percentage between 0 and 100");
+        } else if self.near_complete_threshold >= self.complete_threshold {
+            issue("Near complete threshold", "must be below the done threshold");
+        }
+        if self.streak_reminder_hour > 23 {
+            issue("Streak reminder", "hour must be between 0 and 23");
+        }
+        issues
+    }
+
     /// Check if config is valid (steam credentials required)
     pub fn is_valid(&self) -> bool {
         !self.steam_web_api_key.is_empty() && !self.steam_id.is_empty()
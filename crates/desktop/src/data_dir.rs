@@ -0,0 +1,211 @@
+//! Where the config, database and icon cache live
+//!
+//! By default that's the OS app data directory. Portable mode (the `--portable` flag, or a
+//! `portable` marker file next to the executable) keeps everything in a `data` folder next
+//! to the executable instead, for running off a USB stick. The data directory can also be
+//! relocated from the settings window, which leaves a pointer file in the default location.
+//! The directory is resolved once at startup, so switching restarts the app right away
+//! (anything written to the old directory after the copy would be lost).
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use rusqlite::Connection;
+
+/// Marker file next to the executable that turns on portable mode
+const PORTABLE_MARKER: &str = "portable";
+
+/// Folder next to the executable holding the data in portable mode
+const PORTABLE_DATA_DIR: &str = "data";
+
+/// File in the default data directory pointing at a relocated one
+const LOCATION_FILE: &str = "data_location.txt";
+
+/// How the data directory was chosen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataDirMode {
+    /// OS app data directory
+    Default,
+    /// Next to the executable
+    Portable,
+    /// Relocated from the settings window
+    Custom,
+}
+
+static DATA_DIR: OnceLock<(PathBuf, DataDirMode)> = OnceLock::new();
+
+fn exe_dir() -> Option<PathBuf> {
+    std::env::current_exe().ok()?.parent().map(|p| p.to_path_buf())
+}
+
+/// OS app data directory (also where the relocation pointer is kept)
+fn default_data_dir() -> PathBuf {
+    directories::ProjectDirs::from("", "", "Overachiever")
+        .map(|p| p.data_dir().to_path_buf())
+        // Fall back to the current directory if we can't get the app data dir
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+fn portable_data_dir() -> Option<PathBuf> {
+    exe_dir().map(|dir| dir.join(PORTABLE_DATA_DIR))
+}
+
+fn portable_marker_exists() -> bool {
+    exe_dir().is_some_and(|dir| dir.join(PORTABLE_MARKER).exists())
+}
+
+fn relocated_data_dir() -> Option<PathBuf> {
+    let location = fs::read_to_string(default_data_dir().join(LOCATION_FILE)).ok()?;
+    let location = location.trim();
+    (!location.is_empty()).then(|| PathBuf::from(location))
+}
+
+fn resolve(portable_flag: bool) -> (PathBuf, DataDirMode) {
+    if portable_flag || portable_marker_exists() {
+        if let Some(dir) = portable_data_dir() {
+            return (dir, DataDirMode::Portable);
+        }
+    }
+    if let Some(dir) = relocated_data_dir() {
+        return (dir, DataDirMode::Custom);
+    }
+    (default_data_dir(), DataDirMode::Default)
+}
+
+/// Resolve the data directory from the command line (call once, before anything is loaded)
+pub fn init(portable_flag: bool) {
+    let _ = DATA_DIR.set(resolve(portable_flag));
+}
+
+fn current() -> &'static (PathBuf, DataDirMode) {
+    DATA_DIR.get_or_init(|| resolve(false))
+}
+
+/// The data directory, created if it doesn't exist yet
+pub fn data_dir() -> PathBuf {
+    let dir = &current().0;
+    if let Err(e) = fs::create_dir_all(dir) {
+        eprintln!("Failed to create data directory: {}", e);
+    }
+    dir.clone()
+}
+
+pub fn data_dir_mode() -> DataDirMode {
+    current().1
+}
+
+/// Files that aren't copied by name: the relocation pointer, the running instance's port
+/// (a new instance would hand itself over to this one), and the live database with its
+/// journal files (copied with `copy_database` instead)
+fn skip_copy(name: &str) -> bool {
+    let database = crate::db::DB_FILE_NAME;
+    name == LOCATION_FILE || name == crate::deep_link::PORT_FILE || name.strip_prefix(database).is_some()
+}
+
+/// Copy everything in `from` into `to`, overwriting older copies there
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else if !skip_copy(&entry.file_name().to_string_lossy()) {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Copy the database into `to`. The app keeps it open, so a file copy could be torn;
+/// VACUUM INTO writes a consistent copy (as the backups do).
+fn copy_database(to: &Path) -> Result<(), String> {
+    let source = crate::db::get_db_path();
+    if !source.exists() {
+        return Ok(());
+    }
+    let target = to.join(crate::db::DB_FILE_NAME);
+    // VACUUM INTO refuses to overwrite, and stale journals would be replayed into the copy
+    for suffix in ["", "-wal", "-shm", "-journal"] {
+        let mut path = target.clone().into_os_string();
+        path.push(suffix);
+        match fs::remove_file(&path) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(format!("Failed to replace the database in {}: {}", to.display(), e)),
+        }
+    }
+    let conn = Connection::open(&source).map_err(|e| format!("Failed to open database: {}", e))?;
+    conn.execute("VACUUM INTO ?1", [target.to_string_lossy()])
+        .map_err(|e| format!("Failed to copy database: {}", e))?;
+    Ok(())
+}
+
+/// Copy the whole data directory into `to`
+fn copy_data(to: &Path) -> Result<(), String> {
+    copy_dir(&current().0, to).map_err(|e| format!("Failed to copy data: {}", e))?;
+    copy_database(to)
+}
+
+/// Start a new instance (which picks up the new data directory). The caller closes this one
+/// right after, so nothing more is written to the old directory.
+pub fn restart() -> Result<(), String> {
+    let exe = std::env::current_exe().map_err(|e| format!("Failed to find the executable: {}", e))?;
+    std::process::Command::new(exe)
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to restart: {}", e))
+}
+
+/// Copy the current data to `target` and use it from the next start on (call `restart` next)
+///
+/// The old directory is left as it was, so nothing is lost if the copy is incomplete.
+pub fn relocate_data_dir(target: &Path) -> Result<(), String> {
+    let current = &current().0;
+    if target == current.as_path() {
+        return Err("That is already the data directory".to_string());
+    }
+    if target.starts_with(current) {
+        return Err("The new location can't be inside the current data directory".to_string());
+    }
+    copy_data(target)?;
+
+    let default_dir = default_data_dir();
+    fs::create_dir_all(&default_dir).map_err(|e| format!("Failed to save the new location: {}", e))?;
+    let location_file = default_dir.join(LOCATION_FILE);
+    if target == default_dir.as_path() {
+        // Moving back to the default location just drops the pointer
+        let _ = fs::remove_file(location_file);
+        Ok(())
+    } else {
+        fs::write(location_file, target.to_string_lossy().as_bytes())
+            .map_err(|e| format!("Failed to save the new location: {}", e))
+    }
+}
+
+/// Copy the current data next to the executable and turn on portable mode (call `restart` next)
+pub fn enable_portable_mode() -> Result<(), String> {
+    let (Some(exe_dir), Some(target)) = (exe_dir(), portable_data_dir()) else {
+        return Err("Couldn't find the executable's directory".to_string());
+    };
+    copy_data(&target)?;
+    fs::write(exe_dir.join(PORTABLE_MARKER), b"")
+        .map_err(|e| format!("Failed to write the portable marker: {}", e))
+}
+
+/// Turn off portable mode (the data next to the executable is copied back first; call
+/// `restart` next)
+pub fn disable_portable_mode() -> Result<(), String> {
+    let Some(exe_dir) = exe_dir() else {
+        return Err("Couldn't find the executable's directory".to_string());
+    };
+    let target = relocated_data_dir().unwrap_or_else(default_data_dir);
+    copy_data(&target)?;
+    match fs::remove_file(exe_dir.join(PORTABLE_MARKER)) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!("Failed to remove the portable marker: {}", e)),
+    }
+}
@@ -2,11 +2,16 @@ use rusqlite::{Connection, Result};
 use overachiever_core::{
     Game, RunHistory, SteamGame, Achievement, AchievementHistory,
     GameAchievement, AchievementSchema, RecentAchievement, FirstPlay, LogEntry,
-    CloudSyncData, SyncAchievement, TtbTimes
+    CloudSyncData, SyncAchievement, TtbTimes, UnlockRecord, GameSession, WishlistItem, Goal, GameSnapshot,
+    AchievementDifficulty, GameAchievementRatings, PlatformPlaytime
 };
-use chrono::Utc;
+use chrono::{DateTime, Datelike, Utc};
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
+mod worker;
+pub use worker::DbWorker;
+
 // Helper functions for u64 <-> i64 conversion for SQLite
 // rusqlite 0.38+ removed ToSql/FromSql for u64
 #[inline]
@@ -19,21 +24,12 @@ fn appid_from_sql(val: i64) -> u64 {
     val as u64
 }
 
+/// Database file in the data directory
+pub(crate) const DB_FILE_NAME: &str = "steam_overachiever.db";
+
 /// Get the path to the database file in the app's data directory
-fn get_db_path() -> PathBuf {
-    if let Some(proj_dirs) = directories::ProjectDirs::from("", "", "Overachiever") {
-        let data_dir = proj_dirs.data_dir();
-        // Create the directory if it doesn't exist
-        if let Err(e) = std::fs::create_dir_all(data_dir) {
-            eprintln!("Failed to create data directory: {}", e);
-            // Fall back to current directory
-            return PathBuf::from("steam_overachiever.db");
-        }
-        data_dir.join("steam_overachiever.db")
-    } else {
-        // Fallback to current directory if we can't get the app data dir
-        PathBuf::from("steam_overachiever.db")
-    }
+pub(crate) fn get_db_path() -> PathBuf {
+    crate::data_dir::data_dir().join(DB_FILE_NAME)
 }
 
 pub fn open_connection() -> Result<Connection> {
@@ -154,6 +150,22 @@ fn init_tables(conn: &Connection) -> Result<()> {
     
     // Migration: add steam_private to games table
     migrate_add_steam_private(conn)?;
+    
+    // Migration: add store app type (game, music, demo, ...) to games table
+    migrate_add_app_type(conn)?;
+    migrate_add_abandoned(conn)?;
+    
+    // Migration: add global_percent (rarity) to achievements table
+    migrate_add_global_percent(conn)?;
+
+    // Migration: add the schema's hidden (spoiler) flag to achievements table
+    migrate_add_achievement_hidden(conn)?;
+
+    // Migration: add per-platform playtime to games table
+    migrate_add_platform_playtime(conn)?;
+
+    // Migration: add ownership flag (free weekend / family shared games) to games table
+    migrate_add_owned(conn)?;
 
     // First plays table with steam_id
     conn.execute(
@@ -195,12 +207,115 @@ fn init_tables(conn: &Connection) -> Result<()> {
         [],
     )?;
 
+    // Community achievement ratings (batch-fetched from the server) - not user-specific.
+    // The second table records when each game was fetched, also for games without ratings.
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS community_ratings_cache (
+            appid INTEGER NOT NULL,
+            apiname TEXT NOT NULL,
+            avg_rating REAL NOT NULL,
+            rating_count INTEGER NOT NULL,
+            PRIMARY KEY (appid, apiname)
+        )",
+        [],
+    )?;
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS community_ratings_fetched (
+            appid INTEGER PRIMARY KEY,
+            cached_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+    migrate_add_rating_votes(conn)?;
+
+    // Play sessions detected from GetPlayerSummaries polling
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS sessions (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            steam_id TEXT NOT NULL,
+            appid INTEGER NOT NULL,
+            started_at INTEGER NOT NULL,
+            last_seen_at INTEGER NOT NULL,
+            ended INTEGER NOT NULL DEFAULT 0
+        )",
+        [],
+    )?;
+
+    // Achievements added/removed by the developer, detected on rescrape
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS schema_changes (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            steam_id TEXT NOT NULL,
+            appid INTEGER NOT NULL,
+            detected_at INTEGER NOT NULL,
+            added_names TEXT NOT NULL,
+            removed_names TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    // Server batch responses (tags, TTB) cached with their ETag - not user-specific
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS http_cache (
+            cache_key TEXT PRIMARY KEY,
+            etag TEXT NOT NULL,
+            body TEXT NOT NULL,
+            cached_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    // Steam wishlist, replaced on every fetch
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS wishlist (
+            steam_id TEXT NOT NULL,
+            appid INTEGER NOT NULL,
+            name TEXT NOT NULL,
+            priority INTEGER NOT NULL,
+            date_added INTEGER,
+            fetched_at TEXT NOT NULL,
+            PRIMARY KEY (steam_id, appid)
+        )",
+        [],
+    )?;
+
+    // Backlog goals, the goal itself is stored as JSON (synced through the cloud as is)
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS goals (
+            steam_id TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            goal TEXT NOT NULL,
+            PRIMARY KEY (steam_id, created_at)
+        )",
+        [],
+    )?;
+
+    // Per-game state at the end of each scan, for comparing two scans
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS scan_snapshots (
+            steam_id TEXT NOT NULL,
+            taken_at TEXT NOT NULL,
+            appid INTEGER NOT NULL,
+            name TEXT NOT NULL,
+            playtime_forever INTEGER NOT NULL,
+            achievements_unlocked INTEGER,
+            achievements_total INTEGER,
+            PRIMARY KEY (steam_id, taken_at, appid)
+        )",
+        [],
+    )?;
+
+    // Full-text index over achievement names and descriptions
+    migrate_add_achievement_search(conn)?;
+
     // Create indexes for common queries
     let _ = conn.execute("CREATE INDEX IF NOT EXISTS idx_games_steam_id ON games(steam_id)", []);
     let _ = conn.execute("CREATE INDEX IF NOT EXISTS idx_achievements_steam_id ON achievements(steam_id)", []);
     let _ = conn.execute("CREATE INDEX IF NOT EXISTS idx_run_history_steam_id ON run_history(steam_id)", []);
     let _ = conn.execute("CREATE INDEX IF NOT EXISTS idx_achievement_history_steam_id ON achievement_history(steam_id)", []);
     let _ = conn.execute("CREATE INDEX IF NOT EXISTS idx_user_achievement_ratings_steam_id ON user_achievement_ratings(steam_id)", []);
+    let _ = conn.execute("CREATE INDEX IF NOT EXISTS idx_sessions_steam_id_appid ON sessions(steam_id, appid)", []);
+    let _ = conn.execute("CREATE INDEX IF NOT EXISTS idx_schema_changes_steam_id ON schema_changes(steam_id, detected_at)", []);
 
     Ok(())
 }
@@ -507,6 +622,40 @@ fn migrate_add_steam_hidden(conn: &Connection) -> Result<()> {
     Ok(())
 }
 
+fn migrate_add_app_type(conn: &Connection) -> Result<()> {
+    let has_column: bool = conn
+        .query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('games') WHERE name = 'app_type'",
+            [],
+            |row| row.get::<_, i32>(0),
+        )
+        .map(|count| count > 0)
+        .unwrap_or(false);
+
+    if !has_column {
+        let _ = conn.execute("ALTER TABLE games ADD COLUMN app_type TEXT", []);
+    }
+
+    Ok(())
+}
+
+fn migrate_add_abandoned(conn: &Connection) -> Result<()> {
+    let has_column: bool = conn
+        .query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('games') WHERE name = 'abandoned'",
+            [],
+            |row| row.get::<_, i32>(0),
+        )
+        .map(|count| count > 0)
+        .unwrap_or(false);
+
+    if !has_column {
+        let _ = conn.execute("ALTER TABLE games ADD COLUMN abandoned INTEGER NOT NULL DEFAULT 0", []);
+    }
+
+    Ok(())
+}
+
 fn migrate_add_steam_private(conn: &Connection) -> Result<()> {
     let has_column: bool = conn
         .query_row(
@@ -527,6 +676,149 @@ fn migrate_add_steam_private(conn: &Connection) -> Result<()> {
     Ok(())
 }
 
+/// Add global_percent column to achievements table (global unlock rarity)
+fn migrate_add_global_percent(conn: &Connection) -> Result<()> {
+    let has_column: bool = conn
+        .query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('achievements') WHERE name = 'global_percent'",
+            [],
+            |row| row.get::<_, i32>(0),
+        )
+        .map(|count| count > 0)
+        .unwrap_or(false);
+
+    if !has_column {
+        let _ = conn.execute(
+            "ALTER TABLE achievements ADD COLUMN global_percent REAL",
+            [],
+        );
+    }
+
+    Ok(())
+}
+
+/// Add hidden column to achievements table (Steam hides the achievement until it's unlocked).
+/// Filled in by the next scan of each game.
+fn migrate_add_achievement_hidden(conn: &Connection) -> Result<()> {
+    let has_column: bool = conn
+        .query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('achievements') WHERE name = 'hidden'",
+            [],
+            |row| row.get::<_, i32>(0),
+        )
+        .map(|count| count > 0)
+        .unwrap_or(false);
+
+    if !has_column {
+        let _ = conn.execute(
+            "ALTER TABLE achievements ADD COLUMN hidden INTEGER NOT NULL DEFAULT 0",
+            [],
+        );
+    }
+
+    Ok(())
+}
+
+/// Add the ownership flag to games table (existing games count as owned until the next update)
+fn migrate_add_owned(conn: &Connection) -> Result<()> {
+    let has_column: bool = conn
+        .query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('games') WHERE name = 'owned'",
+            [],
+            |row| row.get::<_, i32>(0),
+        )
+        .map(|count| count > 0)
+        .unwrap_or(false);
+
+    if !has_column {
+        conn.execute("ALTER TABLE games ADD COLUMN owned INTEGER NOT NULL DEFAULT 1", [])?;
+    }
+
+    Ok(())
+}
+
+/// Add per-platform playtime columns (minutes, NULL until fetched) to games table
+fn migrate_add_platform_playtime(conn: &Connection) -> Result<()> {
+    for column in ["playtime_windows", "playtime_mac", "playtime_linux", "playtime_deck"] {
+        let has_column: bool = conn
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('games') WHERE name = ?1",
+                [column],
+                |row| row.get::<_, i32>(0),
+            )
+            .map(|count| count > 0)
+            .unwrap_or(false);
+
+        if !has_column {
+            conn.execute(&format!("ALTER TABLE games ADD COLUMN {} INTEGER", column), [])?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Add the vote distribution (JSON array of 5 counts) to cached community ratings.
+/// Games cached before are fetched again so their tooltips get the distribution.
+fn migrate_add_rating_votes(conn: &Connection) -> Result<()> {
+    let has_column: bool = conn
+        .query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('community_ratings_cache') WHERE name = 'votes'",
+            [],
+            |row| row.get::<_, i32>(0),
+        )
+        .map(|count| count > 0)
+        .unwrap_or(false);
+
+    if !has_column {
+        conn.execute("ALTER TABLE community_ratings_cache ADD COLUMN votes TEXT", [])?;
+        conn.execute("DELETE FROM community_ratings_fetched", [])?;
+    }
+
+    Ok(())
+}
+
+/// Create the FTS5 index for library-wide achievement search, kept in sync by triggers.
+///
+/// The index is contentless and keyed by the achievements rowid. INSERT OR REPLACE
+/// doesn't fire delete triggers, which can leave orphaned entries behind - searches
+/// join back to achievements, so those never show up.
+fn migrate_add_achievement_search(conn: &Connection) -> Result<()> {
+    let exists: bool = conn
+        .query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'achievement_fts'",
+            [],
+            |row| row.get::<_, i32>(0),
+        )
+        .map(|count| count > 0)
+        .unwrap_or(false);
+
+    if !exists {
+        conn.execute_batch(
+            "CREATE VIRTUAL TABLE achievement_fts USING fts5(
+                name, description,
+                content = '', contentless_delete = 1,
+                tokenize = 'unicode61 remove_diacritics 2'
+            );
+            INSERT INTO achievement_fts (rowid, name, description)
+                SELECT rowid, name, description FROM achievements;",
+        )?;
+    }
+
+    conn.execute_batch(
+        "CREATE TRIGGER IF NOT EXISTS achievement_fts_insert AFTER INSERT ON achievements BEGIN
+            INSERT OR REPLACE INTO achievement_fts (rowid, name, description) VALUES (new.rowid, new.name, new.description);
+        END;
+        CREATE TRIGGER IF NOT EXISTS achievement_fts_update AFTER UPDATE OF name, description ON achievements BEGIN
+            INSERT OR REPLACE INTO achievement_fts (rowid, name, description) VALUES (new.rowid, new.name, new.description);
+        END;
+        CREATE TRIGGER IF NOT EXISTS achievement_fts_delete AFTER DELETE ON achievements BEGIN
+            DELETE FROM achievement_fts WHERE rowid = old.rowid;
+        END;",
+    )?;
+
+    Ok(())
+}
+
 /// Update migrated data with the actual steam_id
 pub fn finalize_migration(conn: &Connection, steam_id: &str) -> Result<()> {
     conn.execute(
@@ -563,6 +855,40 @@ pub fn ensure_user(conn: &Connection, steam_id: &str) -> Result<()> {
     Ok(())
 }
 
+/// Store the persona name and avatar resolved for a user
+pub fn set_user_profile(conn: &Connection, steam_id: &str, display_name: &str, avatar_url: Option<&str>) -> Result<()> {
+    ensure_user(conn, steam_id)?;
+    conn.execute(
+        "UPDATE users SET display_name = ?2, avatar_url = ?3 WHERE steam_id = ?1",
+        rusqlite::params![steam_id, display_name, avatar_url],
+    )?;
+    Ok(())
+}
+
+/// Stored (display_name, avatar_url) of a user
+pub fn get_user_profile(conn: &Connection, steam_id: &str) -> Result<(Option<String>, Option<String>)> {
+    match conn.query_row(
+        "SELECT display_name, avatar_url FROM users WHERE steam_id = ?1",
+        [steam_id],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    ) {
+        Ok(profile) => Ok(profile),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok((None, None)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Profiles with local data: (steam_id, display_name), most recently used first
+pub fn get_local_profiles(conn: &Connection) -> Result<Vec<(String, Option<String>)>> {
+    let mut stmt = conn.prepare(
+        "SELECT u.steam_id, u.display_name FROM users u
+         WHERE EXISTS (SELECT 1 FROM games g WHERE g.steam_id = u.steam_id)
+         ORDER BY u.last_seen DESC"
+    )?;
+    let profiles = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+    profiles.collect()
+}
+
 pub fn upsert_games(conn: &Connection, steam_id: &str, games: &[SteamGame], track_changes: bool) -> Result<()> {
     let now = Utc::now().to_rfc3339();
     for game in games {
@@ -585,13 +911,18 @@ pub fn upsert_games(conn: &Connection, steam_id: &str, games: &[SteamGame], trac
 
         
         conn.execute(
-            "INSERT INTO games (steam_id, appid, name, playtime_forever, rtime_last_played, img_icon_url, added_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            "INSERT INTO games (steam_id, appid, name, playtime_forever, rtime_last_played, img_icon_url, added_at,
+             playtime_windows, playtime_mac, playtime_linux, playtime_deck)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
              ON CONFLICT(steam_id, appid) DO UPDATE SET
              name = excluded.name,
              playtime_forever = excluded.playtime_forever,
              rtime_last_played = COALESCE(excluded.rtime_last_played, games.rtime_last_played),
-             img_icon_url = excluded.img_icon_url",
+             img_icon_url = excluded.img_icon_url,
+             playtime_windows = COALESCE(excluded.playtime_windows, games.playtime_windows),
+             playtime_mac = COALESCE(excluded.playtime_mac, games.playtime_mac),
+             playtime_linux = COALESCE(excluded.playtime_linux, games.playtime_linux),
+             playtime_deck = COALESCE(excluded.playtime_deck, games.playtime_deck)",
             (
                 steam_id,
                 appid_to_sql(game.appid),
@@ -600,6 +931,10 @@ pub fn upsert_games(conn: &Connection, steam_id: &str, games: &[SteamGame], trac
                 game.rtime_last_played,
                 &game.img_icon_url,
                 &now,
+                game.playtime_windows_forever,
+                game.playtime_mac_forever,
+                game.playtime_linux_forever,
+                game.playtime_deck_forever,
             ),
         )?;
 
@@ -607,10 +942,61 @@ pub fn upsert_games(conn: &Connection, steam_id: &str, games: &[SteamGame], trac
     Ok(())
 }
 
+/// Stored playtime (minutes) per game
+pub fn get_playtimes(conn: &Connection, steam_id: &str) -> Result<HashMap<u64, u32>> {
+    conn.prepare("SELECT appid, playtime_forever FROM games WHERE steam_id = ?1")?
+        .query_map([steam_id], |row| Ok((appid_from_sql(row.get(0)?), row.get(1)?)))?
+        .collect()
+}
+
+/// Import games from a Steam Community profile XML (no API key needed).
+/// Never lowers playtime or clears data from a previous API fetch. Returns the number of new games.
+pub fn import_community_games(conn: &Connection, steam_id: &str, games: &[SteamGame]) -> Result<usize> {
+    let now = Utc::now().to_rfc3339();
+    let mut added = 0;
+    for game in games {
+        let exists: bool = conn.query_row(
+            "SELECT COUNT(*) FROM games WHERE steam_id = ?1 AND appid = ?2",
+            rusqlite::params![steam_id, appid_to_sql(game.appid)],
+            |row| row.get::<_, i64>(0),
+        )? > 0;
+        conn.execute(
+            "INSERT INTO games (steam_id, appid, name, playtime_forever, added_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(steam_id, appid) DO UPDATE SET
+             name = excluded.name,
+             playtime_forever = MAX(games.playtime_forever, excluded.playtime_forever)",
+            (steam_id, appid_to_sql(game.appid), &game.name, game.playtime_forever, &now),
+        )?;
+        if !exists {
+            added += 1;
+        }
+    }
+    Ok(added)
+}
+
+/// Per-platform playtime from the four columns starting at `first` (None if never fetched)
+fn platform_playtime_from_row(row: &rusqlite::Row, first: usize) -> Result<Option<PlatformPlaytime>> {
+    let windows: Option<u32> = row.get(first)?;
+    let mac: Option<u32> = row.get(first + 1)?;
+    let linux: Option<u32> = row.get(first + 2)?;
+    let deck: Option<u32> = row.get(first + 3)?;
+    if windows.is_none() && mac.is_none() && linux.is_none() && deck.is_none() {
+        return Ok(None);
+    }
+    Ok(Some(PlatformPlaytime {
+        windows: windows.unwrap_or(0),
+        mac: mac.unwrap_or(0),
+        linux: linux.unwrap_or(0),
+        deck: deck.unwrap_or(0),
+    }))
+}
+
 pub fn get_all_games(conn: &Connection, steam_id: &str) -> Result<Vec<Game>> {
     let mut stmt = conn.prepare(
         "SELECT appid, name, playtime_forever, rtime_last_played, img_icon_url, added_at,
-         achievements_total, achievements_unlocked, last_achievement_scrape, hidden, steam_hidden, steam_private
+         achievements_total, achievements_unlocked, last_achievement_scrape, hidden, steam_hidden, steam_private, app_type, abandoned,
+         playtime_windows, playtime_mac, playtime_linux, playtime_deck, owned
          FROM games WHERE steam_id = ?1 ORDER BY name"
     )?;
     
@@ -648,6 +1034,10 @@ pub fn get_all_games(conn: &Connection, steam_id: &str) -> Result<Vec<Game>> {
             hidden: row.get::<_, Option<i32>>(9)?.map(|v| v != 0).unwrap_or(false),
             steam_hidden: row.get::<_, Option<i32>>(10)?.map(|v| v != 0).unwrap_or(false),
             steam_private: row.get::<_, Option<i32>>(11)?.map(|v| v != 0).unwrap_or(false),
+            app_type: row.get(12)?,
+            abandoned: row.get::<_, Option<i32>>(13)?.map(|v| v != 0).unwrap_or(false),
+            platform_playtime: platform_playtime_from_row(row, 14)?,
+            owned: row.get::<_, Option<i32>>(18)?.map(|v| v != 0).unwrap_or(true),
         })
     })?.collect::<Result<Vec<_>>>()?;
     
@@ -666,6 +1056,84 @@ pub fn update_game_achievements(conn: &Connection, steam_id: &str, appid: u64, a
     Ok(())
 }
 
+/// Mark achievements the Steam client reports as unlocked (apiname, unlocktime) and update the
+/// game's unlocked count. Returns the names of those that weren't unlocked yet.
+pub fn apply_local_unlocks(conn: &Connection, steam_id: &str, appid: u64, unlocks: &[(String, i64)]) -> Result<Vec<String>> {
+    let tx = conn.unchecked_transaction()?;
+    let mut newly = Vec::new();
+    {
+        let mut update = tx.prepare_cached(
+            "UPDATE achievements SET achieved = 1, unlocktime = ?1
+             WHERE steam_id = ?2 AND appid = ?3 AND apiname = ?4 AND achieved = 0
+             RETURNING name",
+        )?;
+        for (apiname, unlocktime) in unlocks {
+            let unlocktime = (*unlocktime > 0).then_some(*unlocktime);
+            let mut rows = update.query((unlocktime, steam_id, appid_to_sql(appid), apiname))?;
+            if let Some(row) = rows.next()? {
+                newly.push(row.get(0)?);
+            }
+        }
+    }
+    if !newly.is_empty() {
+        tx.execute(
+            "UPDATE games SET achievements_unlocked =
+                (SELECT COUNT(*) FROM achievements WHERE steam_id = ?1 AND appid = ?2 AND achieved = 1)
+             WHERE steam_id = ?1 AND appid = ?2",
+            (steam_id, appid_to_sql(appid)),
+        )?;
+    }
+    tx.commit()?;
+    Ok(newly)
+}
+
+/// Store the store app type of a game
+pub fn set_game_app_type(conn: &Connection, steam_id: &str, appid: u64, app_type: &str) -> Result<()> {
+    conn.execute(
+        "UPDATE games SET app_type = ?1 WHERE steam_id = ?2 AND appid = ?3",
+        (app_type, steam_id, appid_to_sql(appid)),
+    )?;
+    Ok(())
+}
+
+/// Flag which games are owned: those in GetOwnedGames. The others were only seen in
+/// recently played (free weekends, Family Sharing) and are left out of the statistics.
+pub fn set_owned_games(conn: &Connection, steam_id: &str, owned: &HashSet<u64>) -> Result<()> {
+    let tx = conn.unchecked_transaction()?;
+    {
+        let mut select = tx.prepare("SELECT appid, owned FROM games WHERE steam_id = ?1")?;
+        let stored = select
+            .query_map([steam_id], |row| Ok((appid_from_sql(row.get(0)?), row.get::<_, i32>(1)? != 0)))?
+            .collect::<Result<Vec<_>>>()?;
+        let mut update = tx.prepare("UPDATE games SET owned = ?1 WHERE steam_id = ?2 AND appid = ?3")?;
+        for (appid, was_owned) in stored {
+            let is_owned = owned.contains(&appid);
+            if is_owned != was_owned {
+                update.execute(rusqlite::params![is_owned as i32, steam_id, appid_to_sql(appid)])?;
+            }
+        }
+    }
+    tx.commit()
+}
+
+/// Manually hide a game from the library (or show it again)
+pub fn set_game_hidden(conn: &Connection, steam_id: &str, appid: u64, hidden: bool) -> Result<()> {
+    conn.execute(
+        "UPDATE games SET hidden = ?1 WHERE steam_id = ?2 AND appid = ?3",
+        (hidden as i32, steam_id, appid_to_sql(appid)),
+    )?;
+    Ok(())
+}
+
+/// Mark a game as abandoned (or take it back up)
+pub fn set_game_abandoned(conn: &Connection, steam_id: &str, appid: u64, abandoned: bool) -> Result<()> {
+    conn.execute(
+        "UPDATE games SET abandoned = ?1 WHERE steam_id = ?2 AND appid = ?3",
+        (abandoned as i32, steam_id, appid_to_sql(appid)),
+    )?;
+    Ok(())
+}
+
 pub fn mark_game_no_achievements(conn: &Connection, steam_id: &str, appid: u64) -> Result<()> {
     let now = Utc::now().to_rfc3339();
     conn.execute(
@@ -678,7 +1146,8 @@ pub fn mark_game_no_achievements(conn: &Connection, steam_id: &str, appid: u64)
 pub fn get_games_needing_achievement_scrape(conn: &Connection, steam_id: &str) -> Result<Vec<Game>> {
     let mut stmt = conn.prepare(
         "SELECT appid, name, playtime_forever, rtime_last_played, img_icon_url, added_at,
-         achievements_total, achievements_unlocked, last_achievement_scrape, hidden, steam_hidden, steam_private
+         achievements_total, achievements_unlocked, last_achievement_scrape, hidden, steam_hidden, steam_private, app_type, abandoned,
+         playtime_windows, playtime_mac, playtime_linux, playtime_deck, owned
          FROM games WHERE steam_id = ?1 AND last_achievement_scrape IS NULL ORDER BY name"
     )?;
     
@@ -709,6 +1178,10 @@ pub fn get_games_needing_achievement_scrape(conn: &Connection, steam_id: &str) -
             hidden: row.get::<_, Option<i32>>(9)?.map(|v| v != 0).unwrap_or(false),
             steam_hidden: row.get::<_, Option<i32>>(10)?.map(|v| v != 0).unwrap_or(false),
             steam_private: row.get::<_, Option<i32>>(11)?.map(|v| v != 0).unwrap_or(false),
+            app_type: row.get(12)?,
+            abandoned: row.get::<_, Option<i32>>(13)?.map(|v| v != 0).unwrap_or(false),
+            platform_playtime: platform_playtime_from_row(row, 14)?,
+            owned: row.get::<_, Option<i32>>(18)?.map(|v| v != 0).unwrap_or(true),
         })
     })?.collect::<Result<Vec<_>>>()?;
     
@@ -756,16 +1229,6 @@ pub fn update_latest_run_history_unplayed(conn: &Connection, steam_id: &str, unp
     Ok(())
 }
 
-/// Update the total_games count for the most recent run_history entry
-/// Used when recently played games add new games not in GetOwnedGames (e.g., some F2P games)
-pub fn update_run_history_total(conn: &Connection, steam_id: &str, total_games: i32) -> Result<()> {
-    conn.execute(
-        "UPDATE run_history SET total_games = ?1 WHERE steam_id = ?2 AND id = (SELECT MAX(id) FROM run_history WHERE steam_id = ?2)",
-        (total_games, steam_id),
-    )?;
-    Ok(())
-}
-
 /// Backfill unplayed_games for run_history entries that still have 0
 /// Only updates entries with unplayed_games = 0 (from before this feature was added)
 pub fn backfill_run_history_unplayed(conn: &Connection, steam_id: &str, current_unplayed: i32) -> Result<()> {
@@ -809,18 +1272,125 @@ pub fn get_achievement_history(conn: &Connection, steam_id: &str) -> Result<Vec<
     Ok(history)
 }
 
-/// Record the last time an Update was run
-pub fn record_last_update(conn: &Connection) -> Result<()> {
+/// Check if achievement history was already backfilled from unlock times
+pub fn has_backfilled_history(conn: &Connection) -> bool {
+    conn.query_row(
+        "SELECT value FROM app_settings WHERE key = 'history_backfilled'",
+        [],
+        |row| row.get::<_, String>(0),
+    ).is_ok()
+}
+
+/// Record that achievement history was backfilled from unlock times
+pub fn record_history_backfilled(conn: &Connection) -> Result<()> {
     let now = Utc::now().to_rfc3339();
     conn.execute(
-        "INSERT OR REPLACE INTO app_settings (key, value) VALUES ('last_update', ?1)",
+        "INSERT OR REPLACE INTO app_settings (key, value) VALUES ('history_backfilled', ?1)",
         [&now],
     )?;
     Ok(())
 }
 
-/// Check if private games have been synced from Steam before
-#[allow(dead_code)]
+/// Synthesize achievement history from unlock times: one entry per month end from the first
+/// unlock, plus one for now. Only fills in before the earliest recorded entry, so it can't
+/// duplicate real history. Returns the number of entries inserted.
+///
+/// Game totals aren't known historically, so every entry uses the current library: totals
+/// are today's, and avg completion is over today's played games with the unlocks up to then.
+pub fn backfill_achievement_history(conn: &Connection, steam_id: &str) -> Result<usize> {
+    let earliest: Option<String> = conn.query_row(
+        "SELECT MIN(recorded_at) FROM achievement_history WHERE steam_id = ?1",
+        [steam_id],
+        |row| row.get(0),
+    )?;
+    let now = Utc::now();
+    let until = earliest
+        .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+        .map(|dt| dt.with_timezone(&Utc));
+
+    // (appid, total, played) of games with achievements
+    let mut stmt = conn.prepare(
+        "SELECT appid, achievements_total, playtime_forever FROM games
+         WHERE steam_id = ?1 AND achievements_total > 0"
+    )?;
+    let games: Vec<(u64, i32, bool)> = stmt
+        .query_map([steam_id], |row| Ok((appid_from_sql(row.get(0)?), row.get(1)?, row.get::<_, i64>(2)? > 0)))?
+        .collect::<Result<_>>()?;
+    if games.is_empty() {
+        return Ok(0);
+    }
+    let total: i32 = games.iter().map(|(_, t, _)| t).sum();
+    let played: Vec<(u64, i32)> = games.iter().filter(|(_, _, p)| *p).map(|(a, t, _)| (*a, *t)).collect();
+
+    let mut stmt = conn.prepare(
+        "SELECT appid, unlocktime FROM achievements
+         WHERE steam_id = ?1 AND achieved = 1 AND unlocktime IS NOT NULL AND unlocktime > 0
+         ORDER BY unlocktime"
+    )?;
+    let unlocks: Vec<(u64, i64)> = stmt
+        .query_map([steam_id], |row| Ok((appid_from_sql(row.get(0)?), row.get(1)?)))?
+        .collect::<Result<_>>()?;
+    let Some(&(_, first)) = unlocks.first() else {
+        return Ok(0);
+    };
+    let Some(first) = DateTime::from_timestamp(first, 0) else {
+        return Ok(0);
+    };
+
+    // Month ends (last second of each month, UTC) from the first unlock on, then now
+    let mut points = Vec::new();
+    let mut month = first.date_naive().with_day(1).unwrap_or(first.date_naive());
+    while let Some(next) = month.checked_add_months(chrono::Months::new(1)) {
+        let end = next.and_hms_opt(0, 0, 0).map(|dt| dt.and_utc() - chrono::Duration::seconds(1));
+        match end {
+            Some(end) if end < now => points.push(end),
+            _ => break,
+        }
+        month = next;
+    }
+    points.push(now);
+
+    let tx = conn.unchecked_transaction()?;
+    let mut inserted = 0;
+    let mut per_game: HashMap<u64, i32> = HashMap::new();
+    let mut next_unlock = 0;
+    for point in points.into_iter().filter(|p| until.is_none_or(|until| *p < until)) {
+        while next_unlock < unlocks.len() && unlocks[next_unlock].1 <= point.timestamp() {
+            *per_game.entry(unlocks[next_unlock].0).or_default() += 1;
+            next_unlock += 1;
+        }
+        let avg_pct = if played.is_empty() {
+            0.0
+        } else {
+            played
+                .iter()
+                .map(|(appid, total)| per_game.get(appid).copied().unwrap_or(0) as f32 / *total as f32 * 100.0)
+                .sum::<f32>()
+                / played.len() as f32
+        };
+        tx.execute(
+            "INSERT INTO achievement_history (steam_id, recorded_at, total_achievements, unlocked_achievements, games_with_achievements, avg_completion_percent) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            (steam_id, point.to_rfc3339(), total, next_unlock as i32, games.len() as i32, avg_pct),
+        )?;
+        inserted += 1;
+    }
+    tx.commit()?;
+
+    Ok(inserted)
+}
+
+/// Record the last time an Update was run
+pub fn record_last_update(conn: &Connection) -> Result<()> {
+    let now = Utc::now().to_rfc3339();
+    conn.execute(
+        "INSERT OR REPLACE INTO app_settings (key, value) VALUES ('last_update', ?1)",
+        [&now],
+    )?;
+    Ok(())
+}
+
+/// Check if private games have been synced from Steam before
+#[allow(dead_code)]
 pub fn has_synced_private_games(conn: &Connection) -> bool {
     conn.query_row(
         "SELECT value FROM app_settings WHERE key = 'synced_private_games'",
@@ -901,6 +1471,8 @@ pub fn save_game_achievements(
     schema: &[AchievementSchema],
     player_achievements: &[Achievement],
 ) -> Result<()> {
+    record_schema_changes(conn, steam_id, appid, schema)?;
+    
     // Build a map of player achievements for quick lookup
     let player_map: std::collections::HashMap<&str, &Achievement> = player_achievements
         .iter()
@@ -913,15 +1485,16 @@ pub fn save_game_achievements(
         let unlocktime = player.and_then(|p| if p.unlocktime > 0 { Some(p.unlocktime as i64) } else { None });
         
         conn.execute(
-            "INSERT INTO achievements (steam_id, appid, apiname, name, description, icon, icon_gray, achieved, unlocktime)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+            "INSERT INTO achievements (steam_id, appid, apiname, name, description, icon, icon_gray, achieved, unlocktime, hidden)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
              ON CONFLICT(steam_id, appid, apiname) DO UPDATE SET
              name = excluded.name,
              description = excluded.description,
              icon = excluded.icon,
              icon_gray = excluded.icon_gray,
              achieved = excluded.achieved,
-             unlocktime = excluded.unlocktime",
+             unlocktime = excluded.unlocktime,
+             hidden = excluded.hidden",
             (
                 steam_id,
                 appid_to_sql(appid),
@@ -932,6 +1505,7 @@ pub fn save_game_achievements(
                 &ach.icongray,
                 achieved as i32,
                 unlocktime,
+                (ach.hidden != 0) as i32,
             ),
         )?;
     }
@@ -939,10 +1513,103 @@ pub fn save_game_achievements(
     Ok(())
 }
 
+/// Diff a freshly fetched schema against stored achievements; record added/removed
+/// achievements in schema_changes and drop the removed ones
+fn record_schema_changes(conn: &Connection, steam_id: &str, appid: u64, schema: &[AchievementSchema]) -> Result<()> {
+    // An empty schema usually means the fetch failed, not that everything was removed
+    if schema.is_empty() {
+        return Ok(());
+    }
+    
+    let mut stmt = conn.prepare("SELECT apiname, name FROM achievements WHERE steam_id = ?1 AND appid = ?2")?;
+    let existing: Vec<(String, String)> = stmt
+        .query_map(rusqlite::params![steam_id, appid_to_sql(appid)], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<Vec<_>>>()?;
+    
+    // First scrape of this game - nothing to compare against
+    if existing.is_empty() {
+        return Ok(());
+    }
+    
+    let schema_names: std::collections::HashSet<&str> = schema.iter().map(|a| a.name.as_str()).collect();
+    let existing_names: std::collections::HashSet<&str> = existing.iter().map(|(apiname, _)| apiname.as_str()).collect();
+    
+    let added: Vec<&str> = schema
+        .iter()
+        .filter(|a| !existing_names.contains(a.name.as_str()))
+        .map(|a| a.display_name.as_str())
+        .collect();
+    let removed: Vec<&(String, String)> = existing
+        .iter()
+        .filter(|(apiname, _)| !schema_names.contains(apiname.as_str()))
+        .collect();
+    
+    if added.is_empty() && removed.is_empty() {
+        return Ok(());
+    }
+    
+    crate::backup::backup_before_schema_change();
+    for (apiname, _) in &removed {
+        conn.execute(
+            "DELETE FROM achievements WHERE steam_id = ?1 AND appid = ?2 AND apiname = ?3",
+            rusqlite::params![steam_id, appid_to_sql(appid), apiname],
+        )?;
+    }
+    
+    let removed_names: Vec<&str> = removed.iter().map(|(_, name)| name.as_str()).collect();
+    conn.execute(
+        "INSERT INTO schema_changes (steam_id, appid, detected_at, added_names, removed_names) VALUES (?1, ?2, ?3, ?4, ?5)",
+        rusqlite::params![steam_id, appid_to_sql(appid), Utc::now().timestamp(), added.join("\n"), removed_names.join("\n")],
+    )?;
+    
+    Ok(())
+}
+
+/// Get recent achievement schema changes (newest first), optionally of one game and within `since..until`
+pub fn get_recent_schema_changes(
+    conn: &Connection,
+    steam_id: &str,
+    limit: i32,
+    appid: Option<u64>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+) -> Result<Vec<LogEntry>> {
+    let mut stmt = conn.prepare(
+        "SELECT c.appid, g.name, c.detected_at, c.added_names, c.removed_names, g.img_icon_url
+         FROM schema_changes c
+         JOIN games g ON c.steam_id = g.steam_id AND c.appid = g.appid
+         WHERE c.steam_id = ?1
+           AND (?3 IS NULL OR c.appid = ?3)
+           AND (?4 IS NULL OR c.detected_at >= ?4)
+           AND (?5 IS NULL OR c.detected_at < ?5)
+         ORDER BY c.detected_at DESC
+         LIMIT ?2"
+    )?;
+    
+    let split = |names: String| -> Vec<String> {
+        names.split('\n').filter(|n| !n.is_empty()).map(|n| n.to_string()).collect()
+    };
+    
+    let params = rusqlite::params![steam_id, limit, appid.map(appid_to_sql), since.map(|t| t.timestamp()), until.map(|t| t.timestamp())];
+    let changes = stmt.query_map(params, |row| {
+        let detected_at: i64 = row.get(2)?;
+        Ok(LogEntry::SchemaChange {
+            appid: appid_from_sql(row.get(0)?),
+            game_name: row.get(1)?,
+            added: split(row.get(3)?),
+            removed: split(row.get(4)?),
+            timestamp: chrono::DateTime::from_timestamp(detected_at, 0).unwrap_or_else(Utc::now),
+            game_icon_url: row.get(5)?,
+        })
+    })?.collect::<Result<Vec<_>>>()?;
+    
+    Ok(changes)
+}
+
 /// Load achievements for a specific game
 pub fn get_game_achievements(conn: &Connection, steam_id: &str, appid: u64) -> Result<Vec<GameAchievement>> {
-    let mut stmt = conn.prepare(
-        "SELECT appid, apiname, name, description, icon, icon_gray, achieved, unlocktime
+    let mut stmt = conn.prepare_cached(
+        "SELECT appid, apiname, name, description, icon, icon_gray, achieved, unlocktime, global_percent, hidden
          FROM achievements WHERE steam_id = ?1 AND appid = ?2 ORDER BY name"
     )?;
     
@@ -964,24 +1631,37 @@ pub fn get_game_achievements(conn: &Connection, steam_id: &str, appid: u64) -> R
             achieved: row.get::<_, i32>(6)? == 1,
             unlocktime,
             is_game_finishing: false,
+            global_percent: row.get::<_, Option<f64>>(8)?.map(|p| p as f32),
+            hidden: row.get::<_, i32>(9)? == 1,
         })
     })?.collect::<Result<Vec<_>>>()?;
     
     Ok(achievements)
 }
 
-/// Get recently unlocked achievements (with game name)
-pub fn get_recent_achievements(conn: &Connection, steam_id: &str, limit: i32) -> Result<Vec<RecentAchievement>> {
+/// Get recently unlocked achievements (with game name), optionally of one game and within `since..until`
+pub fn get_recent_achievements(
+    conn: &Connection,
+    steam_id: &str,
+    limit: i32,
+    appid: Option<u64>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+) -> Result<Vec<RecentAchievement>> {
     let mut stmt = conn.prepare(
         "SELECT a.appid, g.name, a.apiname, a.name, a.unlocktime, a.icon, g.img_icon_url
          FROM achievements a
          JOIN games g ON a.steam_id = g.steam_id AND a.appid = g.appid
          WHERE a.steam_id = ?1 AND a.achieved = 1 AND a.unlocktime IS NOT NULL
+           AND (?3 IS NULL OR a.appid = ?3)
+           AND (?4 IS NULL OR a.unlocktime >= ?4)
+           AND (?5 IS NULL OR a.unlocktime < ?5)
          ORDER BY a.unlocktime DESC
          LIMIT ?2"
     )?;
     
-    let achievements = stmt.query_map(rusqlite::params![steam_id, limit], |row| {
+    let params = rusqlite::params![steam_id, limit, appid.map(appid_to_sql), since.map(|t| t.timestamp()), until.map(|t| t.timestamp())];
+    let achievements = stmt.query_map(params, |row| {
         let unlocktime_unix: i64 = row.get(4)?;
         let unlocktime = chrono::DateTime::from_timestamp(unlocktime_unix, 0)
             .map(|dt| dt.with_timezone(&Utc))
@@ -1001,6 +1681,169 @@ pub fn get_recent_achievements(conn: &Connection, steam_id: &str, limit: i32) ->
     Ok(achievements)
 }
 
+/// Save global unlock percentages for a game's achievements (shared across users)
+pub fn save_global_percentages(conn: &Connection, appid: u64, percentages: &std::collections::HashMap<String, f32>) -> Result<()> {
+    let mut stmt = conn.prepare("UPDATE achievements SET global_percent = ?1 WHERE appid = ?2 AND apiname = ?3")?;
+    for (apiname, percent) in percentages {
+        stmt.execute(rusqlite::params![*percent as f64, appid_to_sql(appid), apiname])?;
+    }
+    Ok(())
+}
+
+/// Get all unlocked achievements with game names and rarity (for records/milestones)
+pub fn get_unlock_records(conn: &Connection, steam_id: &str) -> Result<Vec<UnlockRecord>> {
+    let mut stmt = conn.prepare(
+        "SELECT a.appid, g.name, a.apiname, a.name, a.unlocktime, a.global_percent
+         FROM achievements a
+         JOIN games g ON a.steam_id = g.steam_id AND a.appid = g.appid
+         WHERE a.steam_id = ?1 AND a.achieved = 1 AND a.unlocktime IS NOT NULL AND a.unlocktime > 0
+         ORDER BY a.unlocktime"
+    )?;
+    
+    let records = stmt.query_map([steam_id], |row| {
+        let unlocktime_unix: i64 = row.get(4)?;
+        let unlocktime = chrono::DateTime::from_timestamp(unlocktime_unix, 0)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(Utc::now);
+        let global_percent: Option<f64> = row.get(5)?;
+        
+        Ok(UnlockRecord {
+            appid: appid_from_sql(row.get(0)?),
+            game_name: row.get(1)?,
+            apiname: row.get(2)?,
+            achievement_name: row.get(3)?,
+            unlocktime,
+            global_percent: global_percent.map(|p| p as f32),
+        })
+    })?.collect::<Result<Vec<_>>>()?;
+    
+    Ok(records)
+}
+
+/// A locked achievement in a nearly completed game (for the "Final stretch" view)
+#[derive(Debug, Clone)]
+pub struct RemainingAchievement {
+    pub appid: u64,
+    pub game_name: String,
+    pub apiname: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub icon_gray: String,
+    pub global_percent: Option<f32>,
+    pub game_unlocked: i32,
+    pub game_total: i32,
+}
+
+/// URLs of every achievement icon (locked and unlocked) and game icon in the library
+pub fn get_all_icon_urls(conn: &Connection, steam_id: &str) -> Result<Vec<String>> {
+    let mut urls: Vec<String> = conn
+        .prepare(
+            "SELECT icon FROM achievements WHERE steam_id = ?1
+             UNION SELECT icon_gray FROM achievements WHERE steam_id = ?1"
+        )?
+        .query_map([steam_id], |row| row.get(0))?
+        .collect::<Result<_>>()?;
+
+    let mut stmt = conn.prepare(
+        "SELECT appid, img_icon_url FROM games WHERE steam_id = ?1 AND img_icon_url IS NOT NULL AND img_icon_url != ''"
+    )?;
+    let game_icons = stmt.query_map([steam_id], |row| {
+        let appid = appid_from_sql(row.get(0)?);
+        let hash: String = row.get(1)?;
+        Ok(format!("https://media.steampowered.com/steamcommunity/public/images/apps/{}/{}.jpg", appid, hash))
+    })?;
+    for url in game_icons {
+        urls.push(url?);
+    }
+
+    Ok(urls)
+}
+
+/// Get locked achievements for games whose completion is in [min_percent, 100)
+pub fn get_remaining_achievements(conn: &Connection, steam_id: &str, min_percent: f32) -> Result<Vec<RemainingAchievement>> {
+    let mut stmt = conn.prepare(
+        "SELECT a.appid, g.name, a.apiname, a.name, a.description, a.icon_gray, a.global_percent,
+                g.achievements_unlocked, g.achievements_total
+         FROM achievements a
+         JOIN games g ON a.steam_id = g.steam_id AND a.appid = g.appid
+         WHERE a.steam_id = ?1 AND a.achieved = 0
+           AND g.achievements_total > 0
+           AND g.achievements_unlocked < g.achievements_total
+           AND g.achievements_unlocked * 100.0 >= ?2 * g.achievements_total"
+    )?;
+    
+    let remaining = stmt.query_map(rusqlite::params![steam_id, min_percent as f64], |row| {
+        let global_percent: Option<f64> = row.get(6)?;
+        Ok(RemainingAchievement {
+            appid: appid_from_sql(row.get(0)?),
+            game_name: row.get(1)?,
+            apiname: row.get(2)?,
+            name: row.get(3)?,
+            description: row.get(4)?,
+            icon_gray: row.get(5)?,
+            global_percent: global_percent.map(|p| p as f32),
+            game_unlocked: row.get(7)?,
+            game_total: row.get(8)?,
+        })
+    })?.collect::<Result<Vec<_>>>()?;
+    
+    Ok(remaining)
+}
+
+/// An achievement matching a library-wide search
+#[derive(Debug, Clone)]
+pub struct AchievementSearchHit {
+    pub appid: u64,
+    pub game_name: String,
+    pub apiname: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub achieved: bool,
+    pub icon: String,
+    pub icon_gray: String,
+}
+
+/// Turn free text into an FTS5 query: every word must match as a prefix
+fn fts_query(text: &str) -> Option<String> {
+    let terms: Vec<String> = text
+        .split_whitespace()
+        .map(|word| format!("\"{}\"*", word.replace('"', "\"\"")))
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+/// Search achievement names and descriptions across the whole library, best matches first
+pub fn search_achievements(conn: &Connection, steam_id: &str, text: &str, limit: usize) -> Result<Vec<AchievementSearchHit>> {
+    let Some(query) = fts_query(text) else {
+        return Ok(Vec::new());
+    };
+
+    let mut stmt = conn.prepare_cached(
+        "SELECT a.appid, g.name, a.apiname, a.name, a.description, a.achieved, a.icon, a.icon_gray
+         FROM achievement_fts f
+         JOIN achievements a ON a.rowid = f.rowid
+         JOIN games g ON a.steam_id = g.steam_id AND a.appid = g.appid
+         WHERE achievement_fts MATCH ?1 AND a.steam_id = ?2
+         ORDER BY f.rank
+         LIMIT ?3"
+    )?;
+
+    let hits = stmt.query_map(rusqlite::params![query, steam_id, limit as i64], |row| {
+        Ok(AchievementSearchHit {
+            appid: appid_from_sql(row.get(0)?),
+            game_name: row.get(1)?,
+            apiname: row.get(2)?,
+            name: row.get(3)?,
+            description: row.get(4)?,
+            achieved: row.get::<_, i32>(5)? != 0,
+            icon: row.get(6)?,
+            icon_gray: row.get(7)?,
+        })
+    })?.collect::<Result<Vec<_>>>()?;
+
+    Ok(hits)
+}
+
 /// Record a first play event for a game
 pub fn record_first_play(conn: &Connection, steam_id: &str, appid: u64, played_at: i64) -> Result<()> {
     conn.execute(
@@ -1010,18 +1853,29 @@ pub fn record_first_play(conn: &Connection, steam_id: &str, appid: u64, played_a
     Ok(())
 }
 
-/// Get recent first play events
-pub fn get_recent_first_plays(conn: &Connection, steam_id: &str, limit: i32) -> Result<Vec<FirstPlay>> {
+/// Get recent first play events, optionally of one game and within `since..until`
+pub fn get_recent_first_plays(
+    conn: &Connection,
+    steam_id: &str,
+    limit: i32,
+    appid: Option<u64>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+) -> Result<Vec<FirstPlay>> {
     let mut stmt = conn.prepare(
         "SELECT f.appid, g.name, f.played_at, g.img_icon_url
          FROM first_plays f
          JOIN games g ON f.steam_id = g.steam_id AND f.appid = g.appid
          WHERE f.steam_id = ?1
+           AND (?3 IS NULL OR f.appid = ?3)
+           AND (?4 IS NULL OR f.played_at >= ?4)
+           AND (?5 IS NULL OR f.played_at < ?5)
          ORDER BY f.played_at DESC
          LIMIT ?2"
     )?;
     
-    let first_plays = stmt.query_map(rusqlite::params![steam_id, limit], |row| {
+    let params = rusqlite::params![steam_id, limit, appid.map(appid_to_sql), since.map(|t| t.timestamp()), until.map(|t| t.timestamp())];
+    let first_plays = stmt.query_map(params, |row| {
         let played_at_unix: i64 = row.get(2)?;
         let played_at = chrono::DateTime::from_timestamp(played_at_unix, 0)
             .map(|dt| dt.with_timezone(&Utc))
@@ -1038,16 +1892,24 @@ pub fn get_recent_first_plays(conn: &Connection, steam_id: &str, limit: i32) ->
     Ok(first_plays)
 }
 
-/// Get combined log entries (achievements + first plays), sorted by timestamp descending
-pub fn get_log_entries(conn: &Connection, steam_id: &str, limit: i32) -> Result<Vec<LogEntry>> {
+/// Get combined log entries (achievements + first plays + schema changes), sorted by timestamp descending.
+/// `appid` keeps a single game's entries, `since`/`until` those at or after / before the given times.
+pub fn get_log_entries(
+    conn: &Connection,
+    steam_id: &str,
+    limit: i32,
+    appid: Option<u64>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+) -> Result<Vec<LogEntry>> {
     // Get achievements
-    let achievements = get_recent_achievements(conn, steam_id, limit)?;
+    let achievements = get_recent_achievements(conn, steam_id, limit, appid, since, until)?;
     
     // Get first plays
-    let first_plays = get_recent_first_plays(conn, steam_id, limit)?;
+    let first_plays = get_recent_first_plays(conn, steam_id, limit, appid, since, until)?;
     
     // Combine and sort by timestamp
-    let mut entries: Vec<LogEntry> = Vec::new();
+    let mut entries: Vec<LogEntry> = get_recent_schema_changes(conn, steam_id, limit, appid, since, until)?;
     
     for ach in achievements {
         entries.push(LogEntry::Achievement {
@@ -1105,24 +1967,122 @@ pub fn get_all_achievements_for_export(conn: &Connection, steam_id: &str) -> Res
     Ok(achievements)
 }
 
-/// Import cloud sync data into local database (overwrites existing data for this user)
-pub fn import_cloud_sync_data(conn: &Connection, data: &CloudSyncData) -> Result<()> {
+/// What merging a cloud download into the local database would change
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CloudMergeDiff {
+    /// Unlocked in the cloud but not locally
+    pub cloud_only_unlocks: usize,
+    /// Unlocked locally but not in the cloud
+    pub local_only_unlocks: usize,
+    /// Games only in the cloud data
+    pub cloud_only_games: usize,
+    /// Games only in the local database
+    pub local_only_games: usize,
+    /// Games where the cloud has more playtime
+    pub cloud_more_playtime: usize,
+    /// History snapshots (runs and achievement progress) only in the cloud
+    pub cloud_only_history: usize,
+}
+
+impl CloudMergeDiff {
+    /// True when merging would not change anything locally
+    pub fn is_empty(&self) -> bool {
+        self.cloud_only_unlocks == 0 && self.cloud_only_games == 0 && self.cloud_more_playtime == 0 && self.cloud_only_history == 0
+    }
+}
+
+/// History snapshots closer together than this are treated as the same entry
+/// (the server stores microseconds, local timestamps have nanoseconds)
+const HISTORY_DEDUPE_SECS: i64 = 1;
+
+fn has_history_at(existing: &[i64], timestamp: i64) -> bool {
+    existing.iter().any(|t| (t - timestamp).abs() < HISTORY_DEDUPE_SECS)
+}
+
+/// Compare downloaded cloud data with the local database without changing anything
+pub fn diff_cloud_sync_data(conn: &Connection, data: &CloudSyncData) -> Result<CloudMergeDiff> {
     let steam_id = &data.steam_id;
-    
-    // Start transaction
-    conn.execute("BEGIN TRANSACTION", [])?;
-    
-    // Delete existing data for this user
-    conn.execute("DELETE FROM games WHERE steam_id = ?1", [steam_id])?;
-    conn.execute("DELETE FROM achievements WHERE steam_id = ?1", [steam_id])?;
-    conn.execute("DELETE FROM run_history WHERE steam_id = ?1", [steam_id])?;
-    conn.execute("DELETE FROM achievement_history WHERE steam_id = ?1", [steam_id])?;
-    
-    // Import games
+    let mut diff = CloudMergeDiff::default();
+
+    let local_unlocked: HashSet<(u64, String)> = conn
+        .prepare("SELECT appid, apiname FROM achievements WHERE steam_id = ?1 AND achieved = 1")?
+        .query_map([steam_id], |row| Ok((appid_from_sql(row.get(0)?), row.get(1)?)))?
+        .collect::<Result<_>>()?;
+    let cloud_unlocked: HashSet<(u64, String)> = data
+        .achievements
+        .iter()
+        .filter(|a| a.achieved)
+        .map(|a| (a.appid, a.apiname.clone()))
+        .collect();
+    diff.cloud_only_unlocks = cloud_unlocked.difference(&local_unlocked).count();
+    diff.local_only_unlocks = local_unlocked.difference(&cloud_unlocked).count();
+
+    let local_playtime = get_playtimes(conn, steam_id)?;
+    let cloud_appids: HashSet<u64> = data.games.iter().map(|g| g.appid).collect();
     for game in &data.games {
-        conn.execute(
+        match local_playtime.get(&game.appid) {
+            Some(&local) if game.playtime_forever > local => diff.cloud_more_playtime += 1,
+            Some(_) => {}
+            None => diff.cloud_only_games += 1,
+        }
+    }
+    diff.local_only_games = local_playtime.keys().filter(|appid| !cloud_appids.contains(appid)).count();
+
+    let local_runs = history_timestamps(conn, "run_history", "run_at", steam_id)?;
+    let local_progress = history_timestamps(conn, "achievement_history", "recorded_at", steam_id)?;
+    diff.cloud_only_history = data.run_history.iter().filter(|rh| !has_history_at(&local_runs, rh.run_at.timestamp())).count()
+        + data.achievement_history.iter().filter(|ah| !has_history_at(&local_progress, ah.recorded_at.timestamp())).count();
+
+    Ok(diff)
+}
+
+/// Unix timestamps of a user's history rows
+fn history_timestamps(conn: &Connection, table: &str, column: &str, steam_id: &str) -> Result<Vec<i64>> {
+    let timestamps = conn
+        .prepare(&format!("SELECT {} FROM {} WHERE steam_id = ?1", column, table))?
+        .query_map([steam_id], |row| row.get::<_, String>(0))?
+        .filter_map(|r| r.ok())
+        .filter_map(|s| DateTime::parse_from_rfc3339(&s).ok())
+        .map(|dt| dt.timestamp())
+        .collect();
+    Ok(timestamps)
+}
+
+/// Merge downloaded cloud data into the local database.
+///
+/// Nothing local is deleted: games keep the higher playtime, achievements stay
+/// unlocked if either side unlocked them (newest unlock time wins, as on the server -
+/// see `merge_sync_achievement`), and history
+/// snapshots are unioned with duplicates skipped.
+pub fn merge_cloud_sync_data(conn: &mut Connection, data: &CloudSyncData) -> Result<()> {
+    let steam_id = &data.steam_id;
+    let local_runs = history_timestamps(conn, "run_history", "run_at", steam_id)?;
+    let local_progress = history_timestamps(conn, "achievement_history", "recorded_at", steam_id)?;
+
+    let tx = conn.transaction()?;
+
+    // Games: max playtime / last played wins, local-only flags (hidden, private) are untouched.
+    // MAX() is NULL if either side is, so COALESCE falls back to whichever value exists.
+    // Unlock counts are recounted from the merged achievements below.
+    for game in &data.games {
+        tx.execute(
             "INSERT INTO games (steam_id, appid, name, playtime_forever, rtime_last_played, img_icon_url, added_at, achievements_total, achievements_unlocked, last_achievement_scrape, avg_user_ttb_main_seconds, avg_user_ttb_extra_seconds, avg_user_ttb_completionist_seconds, user_ttb_report_count, my_ttb_main_seconds, my_ttb_extra_seconds, my_ttb_completionist_seconds, my_ttb_reported_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)",
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)
+             ON CONFLICT(steam_id, appid) DO UPDATE SET
+                playtime_forever = MAX(playtime_forever, excluded.playtime_forever),
+                rtime_last_played = COALESCE(MAX(rtime_last_played, excluded.rtime_last_played), rtime_last_played, excluded.rtime_last_played),
+                img_icon_url = COALESCE(img_icon_url, excluded.img_icon_url),
+                added_at = MIN(added_at, excluded.added_at),
+                achievements_total = COALESCE(MAX(achievements_total, excluded.achievements_total), achievements_total, excluded.achievements_total),
+                achievements_unlocked = COALESCE(achievements_unlocked, excluded.achievements_unlocked),
+                avg_user_ttb_main_seconds = COALESCE(excluded.avg_user_ttb_main_seconds, avg_user_ttb_main_seconds),
+                avg_user_ttb_extra_seconds = COALESCE(excluded.avg_user_ttb_extra_seconds, avg_user_ttb_extra_seconds),
+                avg_user_ttb_completionist_seconds = COALESCE(excluded.avg_user_ttb_completionist_seconds, avg_user_ttb_completionist_seconds),
+                user_ttb_report_count = MAX(user_ttb_report_count, excluded.user_ttb_report_count),
+                my_ttb_main_seconds = CASE WHEN excluded.my_ttb_reported_at > COALESCE(my_ttb_reported_at, '') THEN excluded.my_ttb_main_seconds ELSE my_ttb_main_seconds END,
+                my_ttb_extra_seconds = CASE WHEN excluded.my_ttb_reported_at > COALESCE(my_ttb_reported_at, '') THEN excluded.my_ttb_extra_seconds ELSE my_ttb_extra_seconds END,
+                my_ttb_completionist_seconds = CASE WHEN excluded.my_ttb_reported_at > COALESCE(my_ttb_reported_at, '') THEN excluded.my_ttb_completionist_seconds ELSE my_ttb_completionist_seconds END,
+                my_ttb_reported_at = COALESCE(MAX(my_ttb_reported_at, excluded.my_ttb_reported_at), my_ttb_reported_at, excluded.my_ttb_reported_at)",
             rusqlite::params![
                 steam_id,
                 appid_to_sql(game.appid),
@@ -1145,19 +2105,16 @@ pub fn import_cloud_sync_data(conn: &Connection, data: &CloudSyncData) -> Result
             ],
         )?;
     }
-    
-    // Import achievements (lightweight - only sync achieved status, not full metadata)
-    // The metadata (name, description, icons) will be populated by local scrape
+
+    // Achievements: unlocked on either side stays unlocked, newest unlock time wins.
+    // New rows only carry the achieved status - metadata comes from the next scrape.
     for ach in &data.achievements {
-        // Use INSERT OR REPLACE to update existing or insert new
-        conn.execute(
-            "INSERT OR REPLACE INTO achievements (steam_id, appid, apiname, name, description, icon, icon_gray, achieved, unlocktime)
-             VALUES (?1, ?2, ?3, 
-                COALESCE((SELECT name FROM achievements WHERE steam_id = ?1 AND appid = ?2 AND apiname = ?3), ''),
-                COALESCE((SELECT description FROM achievements WHERE steam_id = ?1 AND appid = ?2 AND apiname = ?3), NULL),
-                COALESCE((SELECT icon FROM achievements WHERE steam_id = ?1 AND appid = ?2 AND apiname = ?3), ''),
-                COALESCE((SELECT icon_gray FROM achievements WHERE steam_id = ?1 AND appid = ?2 AND apiname = ?3), ''),
-                ?4, ?5)",
+        tx.execute(
+            "INSERT INTO achievements (steam_id, appid, apiname, name, description, icon, icon_gray, achieved, unlocktime)
+             VALUES (?1, ?2, ?3, '', NULL, '', '', ?4, ?5)
+             ON CONFLICT(steam_id, appid, apiname) DO UPDATE SET
+                achieved = MAX(achieved, excluded.achieved),
+                unlocktime = COALESCE(MAX(unlocktime, excluded.unlocktime), unlocktime, excluded.unlocktime)",
             rusqlite::params![
                 steam_id,
                 appid_to_sql(ach.appid),
@@ -1167,18 +2124,29 @@ pub fn import_cloud_sync_data(conn: &Connection, data: &CloudSyncData) -> Result
             ],
         )?;
     }
-    
-    // Clear last_achievement_scrape for games that have achievements
-    // This will trigger a re-scrape to populate icon URLs and metadata
-    conn.execute(
-        "UPDATE games SET last_achievement_scrape = NULL 
-         WHERE steam_id = ?1 AND appid IN (SELECT DISTINCT appid FROM achievements WHERE steam_id = ?1)",
+
+    // Unlock counts follow the merged achievement rows, for every game of the profile.
+    // Games without any rows (never scraped on either side) keep the count they had.
+    tx.execute(
+        "UPDATE games SET achievements_unlocked = CASE
+            WHEN EXISTS (SELECT 1 FROM achievements a WHERE a.steam_id = games.steam_id AND a.appid = games.appid)
+            THEN (SELECT COUNT(*) FROM achievements a WHERE a.steam_id = games.steam_id AND a.appid = games.appid AND a.achieved = 1)
+            ELSE achievements_unlocked
+         END
+         WHERE steam_id = ?1",
         [steam_id],
     )?;
-    
-    // Import run history
-    for rh in &data.run_history {
-        conn.execute(
+
+    // Rescrape games that got achievements without metadata (icons, names)
+    tx.execute(
+        "UPDATE games SET last_achievement_scrape = NULL
+         WHERE steam_id = ?1 AND appid IN (SELECT DISTINCT appid FROM achievements WHERE steam_id = ?1 AND icon = '')",
+        [steam_id],
+    )?;
+
+    // History: union, skipping snapshots already present locally
+    for rh in data.run_history.iter().filter(|rh| !has_history_at(&local_runs, rh.run_at.timestamp())) {
+        tx.execute(
             "INSERT INTO run_history (steam_id, run_at, total_games, unplayed_games, unplayed_games_total)
              VALUES (?1, ?2, ?3, ?4, ?5)",
             rusqlite::params![
@@ -1190,10 +2158,12 @@ pub fn import_cloud_sync_data(conn: &Connection, data: &CloudSyncData) -> Result
             ],
         )?;
     }
-    
-    // Import achievement history
-    for ah in &data.achievement_history {
-        conn.execute(
+    // Goals: union by creation time
+    for goal in &data.goals {
+        insert_goal(&tx, steam_id, goal)?;
+    }
+    for ah in data.achievement_history.iter().filter(|ah| !has_history_at(&local_progress, ah.recorded_at.timestamp())) {
+        tx.execute(
             "INSERT INTO achievement_history (steam_id, recorded_at, total_achievements, unlocked_achievements, games_with_achievements, avg_completion_percent)
              VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
             rusqlite::params![
@@ -1206,11 +2176,8 @@ pub fn import_cloud_sync_data(conn: &Connection, data: &CloudSyncData) -> Result
             ],
         )?;
     }
-    
-    // Commit transaction
-    conn.execute("COMMIT", [])?;
-    
-    Ok(())
+
+    tx.commit()
 }
 
 /// Save or update a user's achievement rating
@@ -1256,6 +2223,19 @@ pub fn get_all_achievement_ratings(conn: &Connection, steam_id: &str) -> Result<
     Ok(ratings)
 }
 
+/// Get all achievement ratings with their last change time (for batch upload)
+pub fn get_all_achievement_ratings_with_times(conn: &Connection, steam_id: &str) -> Result<Vec<(u64, String, u8, String)>> {
+    let mut stmt = conn.prepare(
+        "SELECT appid, apiname, rating, updated_at FROM user_achievement_ratings WHERE steam_id = ?1"
+    )?;
+
+    let ratings = stmt.query_map([steam_id], |row| {
+        Ok((appid_from_sql(row.get(0)?), row.get(1)?, row.get(2)?, row.get(3)?))
+    })?.collect::<Result<Vec<_>>>()?;
+
+    Ok(ratings)
+}
+
 // ============================================================================
 // TTB (Time To Beat) Cache Functions
 // ============================================================================
@@ -1315,3 +2295,486 @@ pub fn get_games_without_ttb(conn: &Connection, steam_id: &str) -> Result<Vec<(u
     Ok(games)
 }
 
+// ============================================================================
+// Community Achievement Ratings Cache
+// ============================================================================
+
+/// Replace the cached community ratings of the given games
+pub fn cache_community_ratings(conn: &Connection, games: &[GameAchievementRatings]) -> Result<()> {
+    let now = Utc::now().to_rfc3339();
+    let tx = conn.unchecked_transaction()?;
+    for game in games {
+        tx.execute("DELETE FROM community_ratings_cache WHERE appid = ?1", [appid_to_sql(game.appid)])?;
+        for ach in &game.achievements {
+            tx.execute(
+                "INSERT INTO community_ratings_cache (appid, apiname, avg_rating, rating_count, votes) VALUES (?1, ?2, ?3, ?4, ?5)",
+                rusqlite::params![
+                    appid_to_sql(game.appid),
+                    ach.apiname,
+                    ach.avg_rating,
+                    ach.rating_count,
+                    serde_json::to_string(&ach.votes).ok()
+                ],
+            )?;
+        }
+        tx.execute(
+            "INSERT OR REPLACE INTO community_ratings_fetched (appid, cached_at) VALUES (?1, ?2)",
+            rusqlite::params![appid_to_sql(game.appid), now],
+        )?;
+    }
+    tx.commit()
+}
+
+/// Cached community ratings per game, with the time the game was fetched
+pub type CachedCommunityRatings = HashMap<u64, (DateTime<Utc>, Vec<AchievementDifficulty>)>;
+
+/// All cached community ratings with the time each game was fetched
+pub fn get_cached_community_ratings(conn: &Connection) -> Result<CachedCommunityRatings> {
+    let mut cached = CachedCommunityRatings::new();
+
+    let mut stmt = conn.prepare("SELECT appid, cached_at FROM community_ratings_fetched")?;
+    let fetched = stmt.query_map([], |row| {
+        Ok((appid_from_sql(row.get(0)?), row.get::<_, String>(1)?))
+    })?;
+    for entry in fetched {
+        let (appid, cached_at) = entry?;
+        // Unparseable timestamps count as expired
+        let cached_at = chrono::DateTime::parse_from_rfc3339(&cached_at)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+        cached.insert(appid, (cached_at, Vec::new()));
+    }
+
+    let mut stmt = conn.prepare(
+        "SELECT appid, apiname, avg_rating, rating_count, votes FROM community_ratings_cache ORDER BY appid, avg_rating"
+    )?;
+    let ratings = stmt.query_map([], |row| {
+        Ok((
+            appid_from_sql(row.get(0)?),
+            AchievementDifficulty {
+                apiname: row.get(1)?,
+                avg_rating: row.get(2)?,
+                rating_count: row.get(3)?,
+                votes: row
+                    .get::<_, Option<String>>(4)?
+                    .and_then(|votes| serde_json::from_str(&votes).ok())
+                    .unwrap_or_default(),
+            },
+        ))
+    })?;
+    for entry in ratings {
+        let (appid, rating) = entry?;
+        if let Some((_, achievements)) = cached.get_mut(&appid) {
+            achievements.push(rating);
+        }
+    }
+
+    Ok(cached)
+}
+
+// ============================================================================
+// HTTP Response Cache (ETag)
+// ============================================================================
+
+/// Get a cached server response as (etag, body)
+pub fn get_http_cache(conn: &Connection, cache_key: &str) -> Result<Option<(String, String)>> {
+    let result = conn.query_row(
+        "SELECT etag, body FROM http_cache WHERE cache_key = ?1",
+        [cache_key],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    );
+
+    match result {
+        Ok(entry) => Ok(Some(entry)),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Store a server response together with its ETag
+pub fn save_http_cache(conn: &Connection, cache_key: &str, etag: &str, body: &str) -> Result<()> {
+    let now = Utc::now().to_rfc3339();
+    conn.execute(
+        "INSERT OR REPLACE INTO http_cache (cache_key, etag, body, cached_at) VALUES (?1, ?2, ?3, ?4)",
+        rusqlite::params![cache_key, etag, body, now],
+    )?;
+    Ok(())
+}
+
+// ============================================================================
+// Play Sessions
+// ============================================================================
+
+/// Record the result of a "currently playing" poll.
+/// Extends the open session if the same game is still running, otherwise closes it
+/// and opens a new one. Sessions not seen for `max_gap_secs` are closed at their last sighting.
+pub fn record_session_poll(conn: &Connection, steam_id: &str, current_appid: Option<u64>, now: i64, max_gap_secs: i64) -> Result<()> {
+    let open: Option<(i64, u64, i64)> = conn
+        .query_row(
+            "SELECT id, appid, last_seen_at FROM sessions WHERE steam_id = ?1 AND ended = 0 ORDER BY started_at DESC LIMIT 1",
+            [steam_id],
+            |row| Ok((row.get(0)?, appid_from_sql(row.get(1)?), row.get(2)?)),
+        )
+        .ok();
+
+    if let Some((id, appid, last_seen_at)) = open {
+        let is_continuous = now - last_seen_at <= max_gap_secs;
+        if current_appid == Some(appid) && is_continuous {
+            conn.execute("UPDATE sessions SET last_seen_at = ?1 WHERE id = ?2", rusqlite::params![now, id])?;
+            return Ok(());
+        }
+        // Game changed, stopped, or we lost track of it: close at the last sighting (or now if continuous)
+        let ended_at = if is_continuous { now } else { last_seen_at };
+        conn.execute("UPDATE sessions SET ended = 1, last_seen_at = ?1 WHERE id = ?2", rusqlite::params![ended_at, id])?;
+    }
+
+    if let Some(appid) = current_appid {
+        conn.execute(
+            "INSERT INTO sessions (steam_id, appid, started_at, last_seen_at, ended) VALUES (?1, ?2, ?3, ?3, 0)",
+            rusqlite::params![steam_id, appid_to_sql(appid), now],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Get play sessions for a game (newest first)
+pub fn get_game_sessions(conn: &Connection, steam_id: &str, appid: u64) -> Result<Vec<GameSession>> {
+    let mut stmt = conn.prepare(
+        "SELECT appid, started_at, last_seen_at, ended FROM sessions
+         WHERE steam_id = ?1 AND appid = ?2 ORDER BY started_at DESC"
+    )?;
+
+    let sessions = stmt.query_map(rusqlite::params![steam_id, appid_to_sql(appid)], |row| {
+        let started_at: i64 = row.get(1)?;
+        let last_seen_at: i64 = row.get(2)?;
+        Ok(GameSession {
+            appid: appid_from_sql(row.get(0)?),
+            started_at: chrono::DateTime::from_timestamp(started_at, 0).unwrap_or_else(Utc::now),
+            last_seen_at: chrono::DateTime::from_timestamp(last_seen_at, 0).unwrap_or_else(Utc::now),
+            ended: row.get::<_, i32>(3)? == 1,
+        })
+    })?.collect::<Result<Vec<_>>>()?;
+
+    Ok(sessions)
+}
+
+/// Get all play sessions of a user (oldest first)
+pub fn get_all_sessions(conn: &Connection, steam_id: &str) -> Result<Vec<GameSession>> {
+    let mut stmt = conn.prepare(
+        "SELECT appid, started_at, last_seen_at, ended FROM sessions
+         WHERE steam_id = ?1 ORDER BY started_at"
+    )?;
+
+    let sessions = stmt.query_map([steam_id], |row| {
+        let started_at: i64 = row.get(1)?;
+        let last_seen_at: i64 = row.get(2)?;
+        Ok(GameSession {
+            appid: appid_from_sql(row.get(0)?),
+            started_at: chrono::DateTime::from_timestamp(started_at, 0).unwrap_or_else(Utc::now),
+            last_seen_at: chrono::DateTime::from_timestamp(last_seen_at, 0).unwrap_or_else(Utc::now),
+            ended: row.get::<_, i32>(3)? == 1,
+        })
+    })?.collect::<Result<Vec<_>>>()?;
+
+    Ok(sessions)
+}
+
+/// Replace the stored wishlist with a freshly fetched one
+pub fn replace_wishlist(conn: &mut Connection, steam_id: &str, items: &[WishlistItem]) -> Result<()> {
+    let now = Utc::now().to_rfc3339();
+    let tx = conn.transaction()?;
+    tx.execute("DELETE FROM wishlist WHERE steam_id = ?1", [steam_id])?;
+    for item in items {
+        tx.execute(
+            "INSERT INTO wishlist (steam_id, appid, name, priority, date_added, fetched_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            rusqlite::params![
+                steam_id,
+                appid_to_sql(item.appid),
+                item.name,
+                item.priority,
+                item.date_added.map(|d| d.timestamp()),
+                now,
+            ],
+        )?;
+    }
+    tx.commit()
+}
+
+/// Get the stored wishlist in wishlist order
+pub fn get_wishlist(conn: &Connection, steam_id: &str) -> Result<Vec<WishlistItem>> {
+    let mut stmt = conn.prepare(
+        "SELECT appid, name, priority, date_added FROM wishlist WHERE steam_id = ?1
+         ORDER BY priority = 0, priority, appid"
+    )?;
+    let items = stmt.query_map([steam_id], |row| {
+        Ok(WishlistItem {
+            appid: appid_from_sql(row.get(0)?),
+            name: row.get(1)?,
+            priority: row.get(2)?,
+            date_added: row.get::<_, Option<i64>>(3)?.and_then(|t| chrono::DateTime::from_timestamp(t, 0)),
+        })
+    })?;
+    items.collect()
+}
+
+/// Store a goal (a goal with the same creation time is kept as is)
+pub fn insert_goal(conn: &Connection, steam_id: &str, goal: &Goal) -> Result<()> {
+    let json = serde_json::to_string(goal).map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+    conn.execute(
+        "INSERT OR IGNORE INTO goals (steam_id, created_at, goal) VALUES (?1, ?2, ?3)",
+        rusqlite::params![steam_id, goal.created_at.to_rfc3339(), json],
+    )?;
+    Ok(())
+}
+
+/// Delete a goal by its creation time
+pub fn delete_goal(conn: &Connection, steam_id: &str, created_at: DateTime<Utc>) -> Result<()> {
+    conn.execute(
+        "DELETE FROM goals WHERE steam_id = ?1 AND created_at = ?2",
+        rusqlite::params![steam_id, created_at.to_rfc3339()],
+    )?;
+    Ok(())
+}
+
+/// Get a user's goals, oldest first (rows that no longer parse are skipped)
+pub fn get_goals(conn: &Connection, steam_id: &str) -> Result<Vec<Goal>> {
+    let mut stmt = conn.prepare("SELECT goal FROM goals WHERE steam_id = ?1 ORDER BY created_at")?;
+    let goals = stmt
+        .query_map([steam_id], |row| row.get::<_, String>(0))?
+        .filter_map(|r| r.ok())
+        .filter_map(|json| serde_json::from_str(&json).ok())
+        .collect();
+    Ok(goals)
+}
+
+/// Store the per-game state of a finished scan. Skipped (returns false) when nothing
+/// changed since the latest snapshot, so repeated scans don't pile up identical rows.
+pub fn insert_scan_snapshot(conn: &Connection, steam_id: &str, games: &[GameSnapshot]) -> Result<bool> {
+    if let Some(latest) = get_scan_snapshot_times(conn, steam_id)?.last() {
+        let mut previous = get_scan_snapshot(conn, steam_id, *latest)?;
+        let mut current = games.to_vec();
+        previous.sort_by_key(|g| g.appid);
+        current.sort_by_key(|g| g.appid);
+        if previous == current {
+            return Ok(false);
+        }
+    }
+
+    let taken_at = Utc::now().to_rfc3339();
+    let tx = conn.unchecked_transaction()?;
+    {
+        let mut stmt = tx.prepare(
+            "INSERT OR REPLACE INTO scan_snapshots
+             (steam_id, taken_at, appid, name, playtime_forever, achievements_unlocked, achievements_total)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)"
+        )?;
+        for game in games {
+            stmt.execute(rusqlite::params![
+                steam_id,
+                taken_at,
+                appid_to_sql(game.appid),
+                game.name,
+                game.playtime_forever,
+                game.achievements_unlocked,
+                game.achievements_total,
+            ])?;
+        }
+    }
+    tx.commit()?;
+    Ok(true)
+}
+
+/// Times snapshots were taken, oldest first
+pub fn get_scan_snapshot_times(conn: &Connection, steam_id: &str) -> Result<Vec<DateTime<Utc>>> {
+    let mut stmt = conn.prepare("SELECT DISTINCT taken_at FROM scan_snapshots WHERE steam_id = ?1 ORDER BY taken_at")?;
+    let times = stmt
+        .query_map([steam_id], |row| row.get::<_, String>(0))?
+        .filter_map(|r| r.ok())
+        .filter_map(|ts| DateTime::parse_from_rfc3339(&ts).ok())
+        .map(|dt| dt.with_timezone(&Utc))
+        .collect();
+    Ok(times)
+}
+
+/// Per-game state of the snapshot taken at `taken_at`
+pub fn get_scan_snapshot(conn: &Connection, steam_id: &str, taken_at: DateTime<Utc>) -> Result<Vec<GameSnapshot>> {
+    let mut stmt = conn.prepare(
+        "SELECT appid, name, playtime_forever, achievements_unlocked, achievements_total
+         FROM scan_snapshots WHERE steam_id = ?1 AND taken_at = ?2"
+    )?;
+    let games = stmt.query_map(rusqlite::params![steam_id, taken_at.to_rfc3339()], |row| {
+        Ok(GameSnapshot {
+            appid: appid_from_sql(row.get(0)?),
+            name: row.get(1)?,
+            playtime_forever: row.get(2)?,
+            achievements_unlocked: row.get(3)?,
+            achievements_total: row.get(4)?,
+        })
+    })?.collect::<Result<Vec<_>>>()?;
+    Ok(games)
+}
+
+/// Copy the database to `dest` with personal data stripped, for attaching to bug reports.
+///
+/// Steam IDs are replaced by stable fake IDs, user/game/achievement/wishlist names and
+/// descriptions by placeholders. Goals and cached API responses are dropped. Row counts,
+/// keys, numbers and timestamps are kept otherwise so problems stay reproducible.
+/// Returns the number of profiles anonymized.
+pub fn export_anonymized(conn: &Connection, dest: &std::path::Path) -> Result<usize> {
+    // VACUUM INTO refuses to overwrite
+    let _ = std::fs::remove_file(dest);
+    conn.execute("VACUUM INTO ?1", [dest.to_string_lossy()])?;
+
+    let mut copy = Connection::open(dest)?;
+    let tx = copy.transaction()?;
+
+    // Every table with a steam_id column, so new tables are covered automatically
+    let steam_id_tables: Vec<String> = tx
+        .prepare(
+            "SELECT m.name FROM sqlite_master m
+             WHERE m.type = 'table' AND EXISTS (SELECT 1 FROM pragma_table_info(m.name) p WHERE p.name = 'steam_id')",
+        )?
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<_>>()?;
+
+    tx.execute("CREATE TEMP TABLE anon_ids (real TEXT PRIMARY KEY, fake TEXT NOT NULL)", [])?;
+    for table in &steam_id_tables {
+        tx.execute(&format!("INSERT OR IGNORE INTO anon_ids (real, fake) SELECT DISTINCT steam_id, '' FROM {}", table), [])?;
+    }
+    // Fake IDs are valid-looking Steam64 IDs for account 1, 2, ...
+    tx.execute("UPDATE anon_ids SET fake = CAST(76561197960265728 + rowid AS TEXT)", [])?;
+    let profiles: i64 = tx.query_row("SELECT COUNT(*) FROM anon_ids", [], |row| row.get(0))?;
+    for table in &steam_id_tables {
+        tx.execute(
+            &format!("UPDATE {} SET steam_id = (SELECT fake FROM anon_ids WHERE real = {}.steam_id)", table, table),
+            [],
+        )?;
+    }
+    tx.execute("DROP TABLE anon_ids", [])?;
+
+    // The search index holds achievement text and rowids that VACUUM may renumber
+    tx.execute_batch(
+        "DROP TRIGGER IF EXISTS achievement_fts_insert;
+         DROP TRIGGER IF EXISTS achievement_fts_update;
+         DROP TRIGGER IF EXISTS achievement_fts_delete;
+         DROP TABLE IF EXISTS achievement_fts;",
+    )?;
+
+    tx.execute("UPDATE users SET display_name = NULL, avatar_url = NULL", [])?;
+    tx.execute("UPDATE games SET name = 'Game ' || appid", [])?;
+    tx.execute("UPDATE scan_snapshots SET name = 'Game ' || appid", [])?;
+    tx.execute("UPDATE wishlist SET name = 'Game ' || appid", [])?;
+    // Goals are free-form JSON, cached responses hold raw API bodies (names, profile data)
+    tx.execute("DELETE FROM goals", [])?;
+    tx.execute("DELETE FROM http_cache", [])?;
+    tx.execute(
+        "UPDATE achievements SET name = 'Achievement ' || apiname,
+         description = CASE WHEN description IS NULL THEN NULL ELSE 'Description' END",
+        [],
+    )?;
+
+    // Schema changes store newline-separated achievement names - keep the counts
+    let changes: Vec<(i64, String, String)> = tx
+        .prepare("SELECT id, added_names, removed_names FROM schema_changes")?
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+        .collect::<Result<_>>()?;
+    let placeholder = |names: &str| -> String {
+        names.lines().enumerate().map(|(i, _)| format!("Achievement {}", i + 1)).collect::<Vec<_>>().join("\n")
+    };
+    for (id, added, removed) in changes {
+        tx.execute(
+            "UPDATE schema_changes SET added_names = ?1, removed_names = ?2 WHERE id = ?3",
+            rusqlite::params![placeholder(&added), placeholder(&removed), id],
+        )?;
+    }
+
+    tx.commit()?;
+    // Drop freed pages so the original text can't be recovered from the file
+    copy.execute("VACUUM", [])?;
+    Ok(profiles as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use overachiever_core::{game_sync_checksum, merge_sync_achievement};
+
+    fn sync_data(achievements: Vec<SyncAchievement>) -> CloudSyncData {
+        let game: Game = serde_json::from_value(serde_json::json!({
+            "appid": 10,
+            "name": "Portal",
+            "playtime_forever": 60,
+            "rtime_last_played": null,
+            "img_icon_url": null,
+            "added_at": "2024-01-01T00:00:00Z",
+            "achievements_total": 3,
+            "achievements_unlocked": 2,
+            "last_achievement_scrape": null,
+            "avg_user_ttb_main_seconds": null,
+            "avg_user_ttb_extra_seconds": null,
+            "avg_user_ttb_completionist_seconds": null,
+            "user_ttb_report_count": 0,
+            "my_ttb_main_seconds": null,
+            "my_ttb_extra_seconds": null,
+            "my_ttb_completionist_seconds": null,
+            "my_ttb_reported_at": null,
+        }))
+        .unwrap();
+        CloudSyncData {
+            steam_id: "1".to_string(),
+            games: vec![game],
+            achievements,
+            run_history: Vec::new(),
+            achievement_history: Vec::new(),
+            goals: Vec::new(),
+            exported_at: Utc::now(),
+        }
+    }
+
+    fn ach(apiname: &str, achieved: bool, unlocktime: Option<i64>) -> SyncAchievement {
+        SyncAchievement {
+            appid: 10,
+            apiname: apiname.to_string(),
+            achieved,
+            unlocktime: unlocktime.and_then(|ts| DateTime::from_timestamp(ts, 0)),
+        }
+    }
+
+    /// The local merge must follow the same rule as the server's, or the checksums never agree
+    #[test]
+    fn cloud_merge_matches_the_server_merge() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        init_tables(&conn).unwrap();
+        ensure_user(&conn, "1").unwrap();
+
+        let local = vec![ach("A", true, Some(2_000)), ach("B", false, None), ach("C", true, Some(500))];
+        let cloud = vec![ach("A", true, Some(1_000)), ach("B", true, Some(3_000)), ach("C", true, None)];
+        merge_cloud_sync_data(&mut conn, &sync_data(local.clone())).unwrap();
+        merge_cloud_sync_data(&mut conn, &sync_data(cloud.clone())).unwrap();
+
+        let merged_here = get_all_achievements_for_export(&conn, "1").unwrap();
+        let merged_on_server: Vec<SyncAchievement> = cloud.iter().zip(&local).map(|(c, l)| merge_sync_achievement(c, l)).collect();
+        let game = get_all_games(&conn, "1").unwrap().remove(0);
+        assert_eq!(merged_here[0].unlocktime, DateTime::from_timestamp(2_000, 0));
+        assert_eq!(
+            game_sync_checksum(&game, &merged_here.iter().collect::<Vec<_>>()),
+            game_sync_checksum(&game, &merged_on_server.iter().collect::<Vec<_>>())
+        );
+    }
+
+    /// A stale unlock count on either side must not survive the merge
+    #[test]
+    fn cloud_merge_recounts_unlocks_from_the_merged_rows() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        init_tables(&conn).unwrap();
+        ensure_user(&conn, "1").unwrap();
+
+        // Both sides claim 2 unlocks, but only one achievement is unlocked in the rows
+        merge_cloud_sync_data(&mut conn, &sync_data(vec![ach("A", true, Some(1_000)), ach("B", false, None)])).unwrap();
+        merge_cloud_sync_data(&mut conn, &sync_data(vec![ach("A", true, Some(1_000)), ach("C", false, None)])).unwrap();
+
+        let game = get_all_games(&conn, "1").unwrap().remove(0);
+        assert_eq!(game.achievements_unlocked, Some(1));
+    }
+}
@@ -0,0 +1,114 @@
+//! Dedicated database thread for queries triggered from the UI
+//!
+//! Opening a connection runs every migration check, and large libraries make even simple
+//! queries take a few milliseconds. The worker keeps one connection (with its prepared
+//! statement cache) on its own thread, so the render thread only sends jobs and polls
+//! the answers.
+
+use rusqlite::{Connection, Result};
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+/// Prepared statements kept per connection (rusqlite's default is 16)
+const STATEMENT_CACHE_CAPACITY: usize = 64;
+
+type DbJob = Box<dyn FnOnce(&mut Connection) + Send>;
+
+/// Handle to the database thread, jobs run one at a time in the order they were sent.
+/// Clones share the same thread (profile windows use the main window's worker).
+#[derive(Clone)]
+pub struct DbWorker {
+    jobs: Sender<DbJob>,
+}
+
+impl DbWorker {
+    /// Start the database thread. The connection is opened on the thread, so a
+    /// failure drops the jobs (their receivers disconnect) instead of blocking startup.
+    pub fn spawn() -> Self {
+        let (jobs, receiver) = channel::<DbJob>();
+        let spawned = std::thread::Builder::new()
+            .name("db-worker".to_string())
+            .spawn(move || {
+                let mut conn = match super::open_connection() {
+                    Ok(conn) => conn,
+                    Err(e) => {
+                        eprintln!("DB worker could not open the database: {}", e);
+                        for _ in receiver {}
+                        return;
+                    }
+                };
+                conn.set_prepared_statement_cache_capacity(STATEMENT_CACHE_CAPACITY);
+                for job in receiver {
+                    job(&mut conn);
+                }
+            });
+        if let Err(e) = spawned {
+            eprintln!("Failed to start DB worker: {}", e);
+        }
+        Self { jobs }
+    }
+
+    /// Run a write without waiting for it, errors are logged as "Failed to {what}"
+    pub fn execute(&self, what: &'static str, job: impl FnOnce(&Connection) -> Result<()> + Send + 'static) {
+        self.send(Box::new(move |conn| {
+            if let Err(e) = job(conn) {
+                eprintln!("Failed to {}: {}", what, e);
+            }
+        }));
+    }
+
+    /// Run a query, the result arrives on the returned receiver. A disconnected
+    /// receiver means the worker could not run the job.
+    pub fn query<T: Send + 'static>(
+        &self,
+        job: impl FnOnce(&Connection) -> Result<T> + Send + 'static,
+    ) -> Receiver<Result<T>> {
+        let (tx, rx) = channel();
+        self.send(Box::new(move |conn| {
+            let _ = tx.send(job(conn));
+        }));
+        rx
+    }
+
+    /// Run a query and hand its result to `done` (on the worker thread). The job gets
+    /// the connection mutably, for transactions.
+    pub fn query_then<T>(
+        &self,
+        job: impl FnOnce(&mut Connection) -> Result<T> + Send + 'static,
+        done: impl FnOnce(Result<T>) + Send + 'static,
+    ) {
+        self.send(Box::new(move |conn| done(job(conn))));
+    }
+
+    /// Close the connection, let `replace` swap the database file (restoring a backup)
+    /// and reopen it. Jobs sent afterwards see the new file.
+    pub fn replace_database(
+        &self,
+        replace: impl FnOnce() -> std::result::Result<(), String> + Send + 'static,
+        done: impl FnOnce(std::result::Result<(), String>) + Send + 'static,
+    ) {
+        self.send(Box::new(move |conn| {
+            let closed = Connection::open_in_memory().map(|memory| std::mem::replace(conn, memory));
+            let result = match closed {
+                Ok(old) => {
+                    drop(old);
+                    replace()
+                }
+                Err(e) => Err(format!("Failed to close the database: {}", e)),
+            };
+            match super::open_connection() {
+                Ok(reopened) => {
+                    reopened.set_prepared_statement_cache_capacity(STATEMENT_CACHE_CAPACITY);
+                    *conn = reopened;
+                }
+                Err(e) => eprintln!("DB worker could not reopen the database: {}", e),
+            }
+            done(result);
+        }));
+    }
+
+    fn send(&self, job: DbJob) {
+        if self.jobs.send(job).is_err() {
+            eprintln!("DB worker is not running");
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! `overachiever://` deep links and single-instance handling
+//!
+//! Links look like `overachiever://game/<appid>` or
+//! `overachiever://game/<appid>/achievement/<apiname>`. The OS starts a new process with
+//! the link as argument; if an instance is already running, the link is handed to it over
+//! a localhost socket (port kept in `instance.port` in the data directory) and the new
+//! process exits. Starting the app a second time without a link just focuses the first one,
+//! and a `.overach` library file it was started with is handed over for import.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver};
+use std::thread;
+use std::time::Duration;
+
+pub const SCHEME: &str = "overachiever";
+
+/// File in the data directory holding the running instance's IPC port
+pub(crate) const PORT_FILE: &str = "instance.port";
+
+/// Sent instead of a link when a second instance starts without one
+const FOCUS_MESSAGE: &str = "focus";
+
+/// Prefix of the message handing over a library file to import
+const IMPORT_PREFIX: &str = "import ";
+
+/// Where a deep link points
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeepLink {
+    Game { appid: u64 },
+    Achievement { appid: u64, apiname: String },
+}
+
+impl DeepLink {
+    /// Parse `overachiever://game/<appid>[/achievement/<apiname>]` (trailing slashes and
+    /// query strings are ignored)
+    pub fn parse(url: &str) -> Option<Self> {
+        let rest = url.trim().strip_prefix(SCHEME)?.strip_prefix("://")?;
+        let path = rest.split(['?', '#']).next().unwrap_or_default();
+        let mut parts = path.split('/').filter(|p| !p.is_empty());
+        if parts.next()? != "game" {
+            return None;
+        }
+        let appid = parts.next()?.parse().ok()?;
+        match (parts.next(), parts.next()) {
+            (None, _) => Some(DeepLink::Game { appid }),
+            (Some("achievement"), Some(apiname)) => Some(DeepLink::Achievement {
+                appid,
+                apiname: urlencoding::decode(apiname).ok()?.into_owned(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Message from another instance
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstanceMessage {
+    /// Started again without a link - bring the window to front
+    Focus,
+    Open(DeepLink),
+    /// Started with a library file - preview its import
+    ImportFile(PathBuf),
+}
+
+/// Message handing `path` to the running instance (see `forward_to_running_instance`)
+pub fn import_message(path: &Path) -> String {
+    format!("{}{}", IMPORT_PREFIX, path.display())
+}
+
+/// The first deep link among the command line arguments
+pub fn link_from_args(args: &[String]) -> Option<DeepLink> {
+    args.iter().skip(1).find_map(|arg| DeepLink::parse(arg))
+}
+
+/// Hand `message` (a link, an `import_message`, or None for a focus request) to an already
+/// running instance. Returns true if one took it, i.e. this process should exit.
+pub fn forward_to_running_instance(message: Option<&str>) -> bool {
+    let port_file = crate::data_dir::data_dir().join(PORT_FILE);
+    let Some(port) = std::fs::read_to_string(port_file).ok().and_then(|p| p.trim().parse::<u16>().ok()) else {
+        return false;
+    };
+    // A stale port file (crashed instance) just fails to connect
+    let Ok(mut stream) = TcpStream::connect_timeout(&(Ipv4Addr::LOCALHOST, port).into(), Duration::from_millis(500)) else {
+        return false;
+    };
+    let message = message.unwrap_or(FOCUS_MESSAGE);
+    writeln!(stream, "{}", message).and_then(|_| stream.flush()).is_ok()
+}
+
+/// Listen for other instances in the background (this process becomes the running instance).
+/// `ctx` is woken up for each message.
+pub fn listen_for_instances(ctx: eframe::egui::Context) -> std::io::Result<Receiver<InstanceMessage>> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+    let port = listener.local_addr()?.port();
+    std::fs::write(crate::data_dir::data_dir().join(PORT_FILE), port.to_string())?;
+
+    let (tx, rx) = channel();
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let _ = stream.set_read_timeout(Some(Duration::from_secs(2)));
+            let mut line = String::new();
+            if BufReader::new(stream).read_line(&mut line).is_err() {
+                continue;
+            }
+            let message = match line.trim() {
+                FOCUS_MESSAGE => Some(InstanceMessage::Focus),
+                line => match line.strip_prefix(IMPORT_PREFIX) {
+                    Some(path) => Some(InstanceMessage::ImportFile(PathBuf::from(path))),
+                    None => DeepLink::parse(line).map(InstanceMessage::Open),
+                },
+            };
+            if let Some(message) = message {
+                if tx.send(message).is_err() {
+                    break;
+                }
+                ctx.request_repaint();
+            }
+        }
+    });
+    Ok(rx)
+}
+
+/// Make the OS open `overachiever://` links with this executable (per user, no admin rights).
+/// Windows: registry under HKCU\Software\Classes. Linux: a .desktop file registered with xdg-mime.
+/// Nothing is written when the scheme already opens this executable.
+pub fn register_url_scheme() -> Result<(), String> {
+    let exe = std::env::current_exe().map_err(|e| e.to_string())?;
+    let portable = if crate::data_dir::data_dir_mode() == crate::data_dir::DataDirMode::Portable {
+        " --portable"
+    } else {
+        ""
+    };
+
+    #[cfg(windows)]
+    {
+        use winreg::enums::HKEY_CURRENT_USER;
+        use winreg::RegKey;
+
+        let command_line = format!("\"{}\"{} \"%1\"", exe.display(), portable);
+        let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+        let current: Option<String> = hkcu
+            .open_subkey(format!("Software\\Classes\\{}\\shell\\open\\command", SCHEME))
+            .and_then(|key| key.get_value(""))
+            .ok();
+        if current.as_deref() == Some(command_line.as_str()) {
+            return Ok(());
+        }
+        let (key, _) = hkcu
+            .create_subkey(format!("Software\\Classes\\{}", SCHEME))
+            .map_err(|e| e.to_string())?;
+        key.set_value("", &"URL:Overachiever").map_err(|e| e.to_string())?;
+        key.set_value("URL Protocol", &"").map_err(|e| e.to_string())?;
+        let (command, _) = key.create_subkey("shell\\open\\command").map_err(|e| e.to_string())?;
+        command.set_value("", &command_line).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let applications = directories::BaseDirs::new()
+            .ok_or("No home directory")?
+            .data_dir()
+            .join("applications");
+        let desktop_file = format!("{}-url-handler.desktop", SCHEME);
+        let entry = format!(
+            "[Desktop Entry]\nType=Application\nName=Overachiever\nExec=\"{}\"{} %u\nMimeType=x-scheme-handler/{};\nNoDisplay=true\n",
+            exe.display(),
+            portable,
+            SCHEME
+        );
+        if std::fs::read_to_string(applications.join(&desktop_file)).is_ok_and(|current| current == entry) {
+            return Ok(());
+        }
+        std::fs::create_dir_all(&applications).map_err(|e| e.to_string())?;
+        std::fs::write(applications.join(&desktop_file), entry).map_err(|e| e.to_string())?;
+        std::process::Command::new("xdg-mime")
+            .args(["default", &desktop_file, &format!("x-scheme-handler/{}", SCHEME)])
+            .status()
+            .map_err(|e| format!("Failed to run xdg-mime: {}", e))?;
+        Ok(())
+    }
+
+    #[cfg(not(any(windows, target_os = "linux")))]
+    {
+        let _ = (exe, portable);
+        Err("URL scheme registration is only supported on Windows and Linux".to_string())
+    }
+}
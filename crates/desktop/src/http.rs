@@ -0,0 +1,83 @@
+//! Shared HTTP setup for all outgoing requests: the configured proxy and the
+//! (optionally overridden) Steam Web API and overachiever.space base URLs.
+//!
+//! Set once at startup from the config and again whenever the network settings change.
+
+use std::sync::RwLock;
+
+use reqwest::blocking::{Client, ClientBuilder, Response};
+
+use crate::config::Config;
+
+pub const DEFAULT_STEAM_API_URL: &str = "https://api.steampowered.com";
+pub const DEFAULT_SERVER_URL: &str = "https://overachiever.space";
+
+/// Proxy URL schemes reqwest understands
+pub const PROXY_SCHEMES: [&str; 4] = ["http://", "https://", "socks5://", "socks5h://"];
+
+struct NetworkSettings {
+    proxy_url: String,
+    steam_api_url: String,
+    server_url: String,
+}
+
+static NETWORK: RwLock<NetworkSettings> = RwLock::new(NetworkSettings {
+    proxy_url: String::new(),
+    steam_api_url: String::new(),
+    server_url: String::new(),
+});
+
+/// Apply the network settings from `config` to all clients built from now on
+pub fn configure(config: &Config) {
+    if let Ok(mut network) = NETWORK.write() {
+        network.proxy_url = config.proxy_url.trim().to_string();
+        network.steam_api_url = config.steam_api_url.trim().trim_end_matches('/').to_string();
+        network.server_url = config.server_url.trim().trim_end_matches('/').to_string();
+    }
+}
+
+/// Client builder with the configured proxy (without one, reqwest uses the system/environment proxy)
+pub fn client_builder() -> ClientBuilder {
+    let builder = Client::builder();
+    let proxy_url = NETWORK.read().map(|n| n.proxy_url.clone()).unwrap_or_default();
+    if proxy_url.is_empty() {
+        return builder;
+    }
+    match reqwest::Proxy::all(&proxy_url) {
+        Ok(proxy) => builder.proxy(proxy),
+        Err(e) => {
+            eprintln!("Ignoring invalid proxy {}: {}", proxy_url, e);
+            builder
+        }
+    }
+}
+
+/// Client with the configured proxy and no other options
+pub fn client() -> Client {
+    client_builder().build().unwrap_or_else(|e| {
+        eprintln!("Failed to build HTTP client: {}", e);
+        Client::new()
+    })
+}
+
+/// `reqwest::blocking::get` through the configured proxy
+pub fn get(url: &str) -> reqwest::Result<Response> {
+    client().get(url).send()
+}
+
+/// Steam Web API URL for `path` (e.g. "/ISteamUser/GetPlayerSummaries/v0002/")
+pub fn steam_api_url(path: &str) -> String {
+    let base = NETWORK.read().map(|n| n.steam_api_url.clone()).unwrap_or_default();
+    let base = if base.is_empty() { DEFAULT_STEAM_API_URL } else { base.as_str() };
+    format!("{}{}", base, path)
+}
+
+/// Base URL of the overachiever backend (no trailing slash)
+pub fn server_url() -> String {
+    let base = NETWORK.read().map(|n| n.server_url.clone()).unwrap_or_default();
+    if base.is_empty() {
+        DEFAULT_SERVER_URL.to_string()
+    } else {
+        base
+    }
+}
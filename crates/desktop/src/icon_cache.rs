@@ -1,42 +1,395 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Default disk budget for cached icons
+pub const DEFAULT_MAX_CACHE_MB: u64 = 256;
+
+/// Evict down to this fraction of the budget so eviction doesn't run on every download
+const EVICT_TARGET_RATIO: f64 = 0.9;
+
+/// Rough size of one icon (64x64 JPEG), for estimating bulk downloads
+const ESTIMATED_ICON_BYTES: u64 = 5 * 1024;
+
+/// How often a paused bulk download checks whether it may continue
+const PAUSED_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Default budget for icon bytes handed to egui
+pub const DEFAULT_MAX_MEMORY_MB: u64 = 64;
 
 /// Get the path to the icon cache directory in the app's data directory
 fn get_cache_dir() -> PathBuf {
-    if let Some(proj_dirs) = directories::ProjectDirs::from("", "", "Overachiever") {
-        let data_dir = proj_dirs.data_dir();
-        let cache_dir = data_dir.join("icon_cache");
-        // Create the directory if it doesn't exist
-        if let Err(e) = std::fs::create_dir_all(&cache_dir) {
-            eprintln!("Failed to create icon cache directory: {}", e);
-            // Fall back to current directory
-            return PathBuf::from("icon_cache");
-        }
-        cache_dir
-    } else {
-        // Fallback to current directory if we can't get the app data dir
-        PathBuf::from("icon_cache")
+    let cache_dir = crate::data_dir::data_dir().join("icon_cache");
+    // Create the directory if it doesn't exist
+    if let Err(e) = std::fs::create_dir_all(&cache_dir) {
+        eprintln!("Failed to create icon cache directory: {}", e);
+        // Fall back to current directory
+        return PathBuf::from("icon_cache");
     }
+    cache_dir
 }
 
 /// Icon cache manager that downloads and caches achievement icons locally
+///
+/// Files are evicted least-recently-used first (by modification time, which is
+/// bumped the first time an icon is read each session) once the cache grows
+/// past its size budget.
 pub struct IconCache {
     cache_dir: PathBuf,
     /// Set of URLs currently being downloaded (to avoid duplicate downloads)
     downloading: Arc<Mutex<HashSet<String>>>,
+    /// Icons already marked as used this session
+    touched: Mutex<HashSet<PathBuf>>,
+    /// Approximate size of the cache directory in bytes
+    total_bytes: Arc<AtomicU64>,
+    /// Size budget in bytes
+    max_bytes: Arc<AtomicU64>,
+    /// Lookups answered from disk / that had to download (for the benchmark report)
+    hits: AtomicU64,
+    misses: AtomicU64,
+    /// Icon bytes currently registered with egui
+    memory: Mutex<MemoryCache>,
+    /// Delay between prefetched downloads, None while background work is paused
+    /// (follows the power throttle of the background scans)
+    background_delay: Arc<Mutex<Option<Duration>>>,
+}
+
+/// Least-recently-used set of `bytes://` URIs registered with egui via `include_bytes`.
+/// egui keeps the bytes (and decoded textures) until told to forget them, so evicted
+/// URIs are forgotten explicitly.
+#[derive(Default)]
+struct MemoryCache {
+    /// uri -> (size in bytes, last use)
+    entries: HashMap<String, (u64, u64)>,
+    /// last use -> uri, oldest first
+    order: BTreeMap<u64, String>,
+    /// Use counter (newer = larger)
+    clock: u64,
+    bytes: u64,
+    max_bytes: u64,
+    hits: u64,
+    loads: u64,
+    evictions: u64,
+}
+
+impl MemoryCache {
+    /// Mark `uri` as used, false if it isn't loaded
+    fn touch(&mut self, uri: &str) -> bool {
+        let Some((_, last_used)) = self.entries.get_mut(uri) else {
+            return false;
+        };
+        self.clock += 1;
+        self.order.remove(last_used);
+        *last_used = self.clock;
+        self.order.insert(self.clock, uri.to_string());
+        self.hits += 1;
+        true
+    }
+
+    fn insert(&mut self, uri: String, size: u64) {
+        self.clock += 1;
+        self.bytes += size;
+        self.loads += 1;
+        self.order.insert(self.clock, uri.clone());
+        self.entries.insert(uri, (size, self.clock));
+    }
+
+    /// Drop the oldest URIs beyond the budget (never `keep`, the one just loaded), returning them
+    fn evict(&mut self, keep: &str) -> Vec<String> {
+        let mut evicted = Vec::new();
+        while self.bytes > self.max_bytes {
+            let Some((&tick, uri)) = self.order.iter().find(|(_, uri)| uri.as_str() != keep) else {
+                break;
+            };
+            let uri = uri.clone();
+            self.order.remove(&tick);
+            if let Some((size, _)) = self.entries.remove(&uri) {
+                self.bytes = self.bytes.saturating_sub(size);
+            }
+            self.evictions += 1;
+            evicted.push(uri);
+        }
+        evicted
+    }
+}
+
+/// In-memory icon numbers for the Debug settings tab
+pub struct MemoryStats {
+    pub icons: usize,
+    pub bytes: u64,
+    pub max_bytes: u64,
+    pub hits: u64,
+    pub loads: u64,
+    pub evictions: u64,
+}
+
+/// Icons a "download all icons" run would fetch, to check against the size budget first
+pub struct DownloadPlan {
+    pending: Vec<(String, PathBuf)>,
+    /// Icons requested but already cached
+    pub already_cached: usize,
+}
+
+impl DownloadPlan {
+    /// Icons still to download
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Approximate disk space the icons still to download need
+    pub fn estimated_bytes(&self) -> u64 {
+        self.pending.len() as u64 * ESTIMATED_ICON_BYTES
+    }
+}
+
+/// Progress of a "download all icons" run, shared with its worker thread.
+/// Icons already on disk are skipped, so running it again resumes an interrupted run.
+#[derive(Default)]
+pub struct BulkDownload {
+    /// Icons that weren't cached when the run started
+    pub total: usize,
+    /// Icons requested but already cached
+    pub already_cached: usize,
+    done: AtomicUsize,
+    failed: AtomicUsize,
+    cancelled: AtomicBool,
+    finished: AtomicBool,
+    /// Waiting for background work to be allowed again
+    paused: AtomicBool,
+    /// Stopped because the cache reached its size budget
+    budget_reached: AtomicBool,
+}
+
+impl BulkDownload {
+    /// (downloaded, failed) so far
+    pub fn progress(&self) -> (usize, usize) {
+        (self.done.load(Ordering::Relaxed), self.failed.load(Ordering::Relaxed))
+    }
+
+    /// Stop after the current icon
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::Relaxed)
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    pub fn budget_reached(&self) -> bool {
+        self.budget_reached.load(Ordering::Relaxed)
+    }
 }
 
 impl IconCache {
     pub fn new() -> Self {
         let cache_dir = get_cache_dir();
+        let total_bytes = Arc::new(AtomicU64::new(0));
+        
+        // Measure the existing cache in the background so startup isn't blocked
+        {
+            let cache_dir = cache_dir.clone();
+            let total_bytes = total_bytes.clone();
+            thread::spawn(move || {
+                let size = cached_files(&cache_dir).iter().map(|(_, len, _)| len).sum();
+                total_bytes.store(size, Ordering::Relaxed);
+            });
+        }
         
         Self {
             cache_dir,
             downloading: Arc::new(Mutex::new(HashSet::new())),
+            touched: Mutex::new(HashSet::new()),
+            total_bytes,
+            max_bytes: Arc::new(AtomicU64::new(DEFAULT_MAX_CACHE_MB * 1024 * 1024)),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            memory: Mutex::new(MemoryCache {
+                max_bytes: DEFAULT_MAX_MEMORY_MB * 1024 * 1024,
+                ..Default::default()
+            }),
+            background_delay: Arc::new(Mutex::new(Some(Duration::ZERO))),
+        }
+    }
+    
+    /// Set the size budget, evicting right away if the cache is already over it
+    pub fn set_max_mb(&self, max_mb: u64) {
+        self.max_bytes.store(max_mb.max(1) * 1024 * 1024, Ordering::Relaxed);
+        let cache_dir = self.cache_dir.clone();
+        let total_bytes = self.total_bytes.clone();
+        let max_bytes = self.max_bytes.clone();
+        thread::spawn(move || evict_if_needed(&cache_dir, &total_bytes, &max_bytes));
+    }
+    
+    /// Throttle background downloads like the background scans (None pauses them).
+    /// Icons of rows on screen are still downloaded right away.
+    pub fn set_background_delay(&self, delay: Option<Duration>) {
+        *self.background_delay.lock().unwrap() = delay;
+    }
+
+    /// Set the in-memory budget; takes effect (evicting as needed) with the next icon loaded
+    pub fn set_max_memory_mb(&self, max_mb: u64) {
+        self.memory.lock().unwrap().max_bytes = max_mb.max(1) * 1024 * 1024;
+    }
+
+    pub fn memory_stats(&self) -> MemoryStats {
+        let memory = self.memory.lock().unwrap();
+        MemoryStats {
+            icons: memory.entries.len(),
+            bytes: memory.bytes,
+            max_bytes: memory.max_bytes,
+            hits: memory.hits,
+            loads: memory.loads,
+            evictions: memory.evictions,
+        }
+    }
+
+    /// Lookups answered from disk and lookups that missed, since startup
+    pub fn hit_stats(&self) -> (u64, u64) {
+        (self.hits.load(Ordering::Relaxed), self.misses.load(Ordering::Relaxed))
+    }
+    
+    /// Approximate size of the cache on disk in bytes
+    pub fn disk_usage(&self) -> u64 {
+        self.total_bytes.load(Ordering::Relaxed)
+    }
+    
+    /// Download icons that aren't cached yet in a single background thread
+    /// (used for rows about to be shown, so icons appear instantly later and offline).
+    /// Slowed down while throttled; stops when background work is paused.
+    pub fn prefetch(&self, urls: impl IntoIterator<Item = String>) {
+        if self.background_delay.lock().unwrap().is_none() {
+            return;
+        }
+        let pending: Vec<(String, PathBuf)> = {
+            let mut set = self.downloading.lock().unwrap();
+            urls.into_iter()
+                .filter(|url| !url.is_empty())
+                .map(|url| {
+                    let path = self.get_cache_path(&url);
+                    (url, path)
+                })
+                .filter(|(url, path)| !path.exists() && set.insert(url.clone()))
+                .collect()
+        };
+        if pending.is_empty() {
+            return;
+        }
+        
+        let downloading = self.downloading.clone();
+        let cache_dir = self.cache_dir.clone();
+        let total_bytes = self.total_bytes.clone();
+        let max_bytes = self.max_bytes.clone();
+        let background_delay = self.background_delay.clone();
+        thread::spawn(move || {
+            let mut pending = pending.into_iter();
+            for (url, cache_path) in pending.by_ref() {
+                let Some(delay) = *background_delay.lock().unwrap() else {
+                    downloading.lock().unwrap().remove(&url);
+                    break;
+                };
+                thread::sleep(delay);
+                download_to(&url, &cache_path, &total_bytes);
+                downloading.lock().unwrap().remove(&url);
+            }
+            // Paused: the rest is fetched once the rows are shown again
+            {
+                let mut set = downloading.lock().unwrap();
+                for (url, _) in pending {
+                    set.remove(&url);
+                }
+            }
+            evict_if_needed(&cache_dir, &total_bytes, &max_bytes);
+        });
+    }
+    
+    /// Space left before the cache reaches its size budget
+    pub fn free_bytes(&self) -> u64 {
+        self.max_bytes.load(Ordering::Relaxed).saturating_sub(self.disk_usage())
+    }
+
+    /// Sort the requested icons into cached and still to download
+    pub fn plan_download(&self, urls: impl IntoIterator<Item = String>) -> DownloadPlan {
+        let mut seen = HashSet::new();
+        let mut already_cached = 0;
+        let mut pending: Vec<(String, PathBuf)> = Vec::new();
+        for url in urls {
+            if url.is_empty() || !seen.insert(url.clone()) {
+                continue;
+            }
+            let path = self.get_cache_path(&url);
+            if path.exists() {
+                already_cached += 1;
+            } else {
+                pending.push((url, path));
+            }
         }
+        DownloadPlan { pending, already_cached }
+    }
+
+    /// Download the planned icons one at a time, reporting progress. Follows the power
+    /// throttle of the background scans and stops once the cache reaches its size budget,
+    /// rather than evicting icons that were just downloaded.
+    pub fn download_all(&self, plan: DownloadPlan) -> Arc<BulkDownload> {
+        let DownloadPlan { pending, already_cached } = plan;
+        let job = Arc::new(BulkDownload {
+            total: pending.len(),
+            already_cached,
+            ..Default::default()
+        });
+        let worker_job = job.clone();
+        let downloading = self.downloading.clone();
+        let cache_dir = self.cache_dir.clone();
+        let total_bytes = self.total_bytes.clone();
+        let max_bytes = self.max_bytes.clone();
+        let background_delay = self.background_delay.clone();
+        thread::spawn(move || {
+            for (url, cache_path) in pending {
+                // Wait while background work is paused
+                let delay = loop {
+                    let delay = *background_delay.lock().unwrap();
+                    worker_job.paused.store(delay.is_none(), Ordering::Relaxed);
+                    match delay {
+                        Some(delay) => break delay,
+                        None if worker_job.is_cancelled() => break Duration::ZERO,
+                        None => thread::sleep(PAUSED_POLL_INTERVAL),
+                    }
+                };
+                if worker_job.is_cancelled() {
+                    break;
+                }
+                if total_bytes.load(Ordering::Relaxed) >= max_bytes.load(Ordering::Relaxed) {
+                    worker_job.budget_reached.store(true, Ordering::Relaxed);
+                    break;
+                }
+                thread::sleep(delay);
+                // Fetched meanwhile by a visible row, or being fetched right now
+                let claimed = !cache_path.exists() && downloading.lock().unwrap().insert(url.clone());
+                let ok = if claimed {
+                    let ok = download_to(&url, &cache_path, &total_bytes);
+                    downloading.lock().unwrap().remove(&url);
+                    ok
+                } else {
+                    true
+                };
+                let counter = if ok { &worker_job.done } else { &worker_job.failed };
+                counter.fetch_add(1, Ordering::Relaxed);
+            }
+            evict_if_needed(&cache_dir, &total_bytes, &max_bytes);
+            worker_job.finished.store(true, Ordering::Relaxed);
+        });
+        job
     }
     
     /// Get the local path for a cached icon, or None if not yet cached
@@ -65,14 +418,26 @@ impl IconCache {
         let cache_path = self.get_cache_path(url);
         
         if cache_path.exists() {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            self.mark_used(&cache_path);
             Some(cache_path)
         } else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
             // Trigger background download
             self.trigger_download(url.to_string(), cache_path);
             None
         }
     }
     
+    /// Bump the file's modification time once per session so eviction keeps it
+    fn mark_used(&self, path: &Path) {
+        if self.touched.lock().unwrap().insert(path.to_path_buf()) {
+            if let Ok(file) = fs::File::options().append(true).open(path) {
+                let _ = file.set_modified(SystemTime::now());
+            }
+        }
+    }
+    
     /// Load cached icon bytes, or return None and trigger download
     pub fn get_icon_bytes(&self, url: &str) -> Option<Vec<u8>> {
         if let Some(path) = self.get_cached_path(url) {
@@ -82,6 +447,28 @@ impl IconCache {
         }
     }
     
+    /// Register the cached icon for `url` with egui under `uri` and return `uri`, or None
+    /// (download triggered) if it isn't on disk yet. Already registered icons aren't read
+    /// again; the least recently used ones are forgotten once over the memory budget.
+    pub fn image_uri(&self, ctx: &eframe::egui::Context, url: &str, uri: String) -> Option<String> {
+        if self.memory.lock().unwrap().touch(&uri) {
+            return Some(uri);
+        }
+        let bytes = self.get_icon_bytes(url)?;
+        let size = bytes.len() as u64;
+        ctx.include_bytes(uri.clone(), bytes);
+
+        let evicted = {
+            let mut memory = self.memory.lock().unwrap();
+            memory.insert(uri.clone(), size);
+            memory.evict(&uri)
+        };
+        for old in evicted {
+            ctx.forget_image(&old);
+        }
+        Some(uri)
+    }
+
     /// Get the URI for an icon - returns original URL (caching happens in background)
     #[allow(dead_code)]
     pub fn get_icon_uri(&self, url: &str) -> String {
@@ -113,6 +500,9 @@ impl IconCache {
     /// Trigger a background download of an icon
     fn trigger_download(&self, url: String, cache_path: PathBuf) {
         let downloading = self.downloading.clone();
+        let cache_dir = self.cache_dir.clone();
+        let total_bytes = self.total_bytes.clone();
+        let max_bytes = self.max_bytes.clone();
         
         // Check if already downloading
         {
@@ -125,15 +515,12 @@ impl IconCache {
         
         // Download in background thread
         thread::spawn(move || {
-            if let Ok(response) = reqwest::blocking::get(&url) {
-                if let Ok(bytes) = response.bytes() {
-                    let _ = fs::write(&cache_path, &bytes);
-                }
-            }
+            download_to(&url, &cache_path, &total_bytes);
             
             // Remove from downloading set
-            let mut set = downloading.lock().unwrap();
-            set.remove(&url);
+            downloading.lock().unwrap().remove(&url);
+            
+            evict_if_needed(&cache_dir, &total_bytes, &max_bytes);
         });
     }
     
@@ -150,6 +537,65 @@ impl Default for IconCache {
     }
 }
 
+/// Download an icon into the cache, adding its size to the running total.
+/// Written to a temporary file first so an interrupted download never leaves a truncated icon.
+fn download_to(url: &str, cache_path: &Path, total_bytes: &AtomicU64) -> bool {
+    let Ok(response) = crate::http::get(url) else {
+        return false;
+    };
+    if !response.status().is_success() {
+        return false;
+    }
+    let Ok(bytes) = response.bytes() else {
+        return false;
+    };
+    let part_path = cache_path.with_extension("part");
+    if fs::write(&part_path, &bytes).is_err() || fs::rename(&part_path, cache_path).is_err() {
+        let _ = fs::remove_file(&part_path);
+        return false;
+    }
+    total_bytes.fetch_add(bytes.len() as u64, Ordering::Relaxed);
+    true
+}
+
+/// All cached files with their size and last-used time
+fn cached_files(cache_dir: &Path) -> Vec<(PathBuf, u64, SystemTime)> {
+    let Ok(entries) = fs::read_dir(cache_dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let meta = entry.metadata().ok()?;
+            meta.is_file().then(|| (entry.path(), meta.len(), meta.modified().unwrap_or(SystemTime::UNIX_EPOCH)))
+        })
+        .collect()
+}
+
+/// Delete least-recently-used icons until the cache fits its budget
+fn evict_if_needed(cache_dir: &Path, total_bytes: &AtomicU64, max_bytes: &AtomicU64) {
+    let max = max_bytes.load(Ordering::Relaxed);
+    if total_bytes.load(Ordering::Relaxed) <= max {
+        return;
+    }
+    
+    let mut files = cached_files(cache_dir);
+    let mut total: u64 = files.iter().map(|(_, len, _)| len).sum();
+    let target = (max as f64 * EVICT_TARGET_RATIO) as u64;
+    if total > max {
+        files.sort_by_key(|(_, _, modified)| *modified);
+        for (path, len, _) in files {
+            if total <= target {
+                break;
+            }
+            if fs::remove_file(&path).is_ok() {
+                total = total.saturating_sub(len);
+            }
+        }
+    }
+    total_bytes.store(total, Ordering::Relaxed);
+}
+
 /// Simple hash function for creating unique filenames
 fn simple_hash(s: &str) -> u64 {
     let mut hash: u64 = 5381;
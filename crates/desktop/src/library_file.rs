@@ -0,0 +1,167 @@
+//! `.overach` library files: the synced library (games, achievements, history, goals) as
+//! one JSON file to move between machines or keep as a portable backup.
+//!
+//! Importing merges like a cloud download (nothing local is removed) after a preview.
+//! Files open with the app through a per-user file association on Windows and Linux;
+//! macOS only associates file types declared in the app bundle's Info.plist
+//! (CFBundleDocumentTypes), so there files are dragged onto the window instead.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use overachiever_core::CloudSyncData;
+use serde::{Deserialize, Serialize};
+
+pub const EXTENSION: &str = "overach";
+
+/// Identifies the JSON as a library file
+const FORMAT: &str = "overachiever-library";
+
+/// Bumped on incompatible changes; newer files are refused with a hint to update
+pub const FORMAT_VERSION: u32 = 1;
+
+/// MIME type of the file type (Linux shared-mime-info, Windows "Content Type")
+const MIME_TYPE: &str = "application/x-overachiever-library";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryFile {
+    pub format: String,
+    pub version: u32,
+    /// App version that wrote the file
+    pub app_version: String,
+    pub exported_at: DateTime<Utc>,
+    pub data: CloudSyncData,
+}
+
+/// Just the header, parsed before the data so old/foreign files get a clear error
+#[derive(Deserialize)]
+struct Header {
+    format: String,
+    version: u32,
+    #[serde(default)]
+    app_version: String,
+}
+
+/// Whether `path` looks like a library file (by extension)
+pub fn is_library_file(path: &Path) -> bool {
+    path.extension().is_some_and(|e| e.eq_ignore_ascii_case(EXTENSION))
+}
+
+/// The first library file among the command line arguments
+pub fn path_from_args(args: &[String]) -> Option<PathBuf> {
+    args.iter().skip(1).map(PathBuf::from).find(|p| is_library_file(p) && p.is_file())
+}
+
+/// Write `data` as a library file
+pub fn write(path: &Path, data: CloudSyncData) -> Result<(), String> {
+    let file = LibraryFile {
+        format: FORMAT.to_string(),
+        version: FORMAT_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        exported_at: Utc::now(),
+        data,
+    };
+    let json = serde_json::to_vec(&file).map_err(|e| format!("Failed to serialize library: {}", e))?;
+    std::fs::write(path, json).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Read and check a library file
+pub fn read(path: &Path) -> Result<LibraryFile, String> {
+    let bytes = std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let header: Header = serde_json::from_slice(&bytes).map_err(|_| "Not an Overachiever library file".to_string())?;
+    if header.format != FORMAT {
+        return Err("Not an Overachiever library file".to_string());
+    }
+    if header.version > FORMAT_VERSION {
+        return Err(format!(
+            "This file was exported by a newer version ({}, format {}) - update Overachiever to import it",
+            header.app_version, header.version
+        ));
+    }
+    serde_json::from_slice(&bytes).map_err(|e| format!("Damaged library file: {}", e))
+}
+
+/// Open `.overach` files with this executable (per user, no admin rights).
+/// Windows: ProgID under HKCU\Software\Classes. Linux: a shared-mime-info type plus a .desktop handler.
+/// Nothing is written when `.overach` files already open with this executable.
+pub fn register_file_association() -> Result<(), String> {
+    let exe = std::env::current_exe().map_err(|e| e.to_string())?;
+    let portable = if crate::data_dir::data_dir_mode() == crate::data_dir::DataDirMode::Portable {
+        " --portable"
+    } else {
+        ""
+    };
+
+    #[cfg(windows)]
+    {
+        use winreg::enums::HKEY_CURRENT_USER;
+        use winreg::RegKey;
+
+        const PROG_ID: &str = "Overachiever.Library";
+        let command_line = format!("\"{}\"{} \"%1\"", exe.display(), portable);
+        let classes = RegKey::predef(HKEY_CURRENT_USER)
+            .create_subkey("Software\\Classes")
+            .map_err(|e| e.to_string())?
+            .0;
+        let current_prog_id: Option<String> = classes.open_subkey(format!(".{}", EXTENSION)).and_then(|key| key.get_value("")).ok();
+        let current_command: Option<String> = classes
+            .open_subkey(format!("{}\\shell\\open\\command", PROG_ID))
+            .and_then(|key| key.get_value(""))
+            .ok();
+        if current_prog_id.as_deref() == Some(PROG_ID) && current_command.as_deref() == Some(command_line.as_str()) {
+            return Ok(());
+        }
+        let (extension, _) = classes.create_subkey(format!(".{}", EXTENSION)).map_err(|e| e.to_string())?;
+        extension.set_value("", &PROG_ID).map_err(|e| e.to_string())?;
+        extension.set_value("Content Type", &MIME_TYPE).map_err(|e| e.to_string())?;
+        let (prog_id, _) = classes.create_subkey(PROG_ID).map_err(|e| e.to_string())?;
+        prog_id.set_value("", &"Overachiever library").map_err(|e| e.to_string())?;
+        let (command, _) = prog_id.create_subkey("shell\\open\\command").map_err(|e| e.to_string())?;
+        command.set_value("", &command_line).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let data_home = directories::BaseDirs::new().ok_or("No home directory")?.data_dir().to_path_buf();
+
+        let packages = data_home.join("mime").join("packages");
+        let mime_xml = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<mime-info xmlns=\"http://www.freedesktop.org/standards/shared-mime-info\">\n  <mime-type type=\"{}\">\n    <comment>Overachiever library</comment>\n    <glob pattern=\"*.{}\"/>\n  </mime-type>\n</mime-info>\n",
+            MIME_TYPE, EXTENSION
+        );
+        let applications = data_home.join("applications");
+        let desktop_file = "overachiever-library.desktop";
+        let entry = format!(
+            "[Desktop Entry]\nType=Application\nName=Overachiever\nExec=\"{}\"{} %f\nMimeType={};\nNoDisplay=true\n",
+            exe.display(),
+            portable,
+            MIME_TYPE
+        );
+        let unchanged = |path: std::path::PathBuf, content: &str| std::fs::read_to_string(path).is_ok_and(|current| current == content);
+        if unchanged(packages.join("overachiever-library.xml"), &mime_xml) && unchanged(applications.join(desktop_file), &entry) {
+            return Ok(());
+        }
+
+        std::fs::create_dir_all(&packages).map_err(|e| e.to_string())?;
+        std::fs::write(packages.join("overachiever-library.xml"), mime_xml).map_err(|e| e.to_string())?;
+        std::process::Command::new("update-mime-database")
+            .arg(data_home.join("mime"))
+            .status()
+            .map_err(|e| format!("Failed to run update-mime-database: {}", e))?;
+
+        std::fs::create_dir_all(&applications).map_err(|e| e.to_string())?;
+        std::fs::write(applications.join(desktop_file), entry).map_err(|e| e.to_string())?;
+        std::process::Command::new("xdg-mime")
+            .args(["default", desktop_file, MIME_TYPE])
+            .status()
+            .map_err(|e| format!("Failed to run xdg-mime: {}", e))?;
+        Ok(())
+    }
+
+    #[cfg(not(any(windows, target_os = "linux")))]
+    {
+        let _ = (exe, portable, MIME_TYPE);
+        Err("File associations come from the app bundle on this platform - drag .overach files onto the window instead".to_string())
+    }
+}
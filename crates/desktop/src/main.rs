@@ -2,14 +2,27 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 mod app;
+mod backup;
+mod changelog;
 mod cjk_font;
 mod cloud_sync;
 mod config;
+mod data_dir;
 mod db;
+mod deep_link;
 mod fonts;
+mod http;
 mod icon_cache;
+mod library_file;
+mod overlay_server;
+mod power;
+mod presence_client;
 mod steam_api;
+mod steam_cloud;
+mod steam_community;
 mod steam_library;
+mod steam_local_stats;
+mod steam_wishlist;
 mod steam_config;
 mod steamspy;
 mod ttb;
@@ -21,6 +34,15 @@ use eframe::egui;
 fn main() -> eframe::Result<()> {
     let args: Vec<String> = std::env::args().collect();
 
+    // Portable mode keeps the config, database and icon cache next to the executable
+    data_dir::init(args.iter().any(|a| a == "--portable"));
+
+    // Proxy and custom endpoints for every request made from here on
+    http::configure(&config::Config::load());
+
+    // Snapshot the database before a new version migrates it
+    backup::backup_before_migrations();
+
     if args.iter().any(|a| a == "--update") {
         // Headless update mode: run update, save stats, exit
         std::process::exit(run_headless_update());
@@ -36,7 +58,19 @@ fn main() -> eframe::Result<()> {
         std::process::exit(remove_schedule());
     }
 
-    run_gui()
+    // Single instance: links, library files (or a second start) go to the running instance, if any
+    let library_file = library_file::path_from_args(&args);
+    let message = args
+        .iter()
+        .skip(1)
+        .find(|a| deep_link::DeepLink::parse(a).is_some())
+        .cloned()
+        .or_else(|| library_file.as_deref().map(deep_link::import_message));
+    if deep_link::forward_to_running_instance(message.as_deref()) {
+        return Ok(());
+    }
+
+    run_gui(deep_link::link_from_args(&args), library_file)
 }
 
 /// Headless update: run the same update logic as the GUI but without a window
@@ -83,6 +117,8 @@ fn run_headless_update() -> i32 {
     if let Ok(conn) = db::open_connection() {
         let games_with_ach: Vec<_> = games.iter()
             .filter(|g| g.achievements_total.map(|t| t > 0).unwrap_or(false))
+            .filter(|g| !cfg.exclude_non_games_from_stats || g.is_game_app())
+            .filter(|g| cfg.include_unowned_in_stats || g.owned)
             .collect();
 
         if !games_with_ach.is_empty() {
@@ -91,7 +127,7 @@ fn run_headless_update() -> i32 {
             let unplayed = games_with_ach.iter().filter(|g| g.playtime_forever == 0).count() as i32;
 
             let pcts: Vec<f32> = games_with_ach.iter()
-                .filter(|g| g.playtime_forever > 0)
+                .filter(|g| g.playtime_forever > 0 && !g.abandoned)
                 .filter_map(|g| g.completion_percent())
                 .collect();
             let avg = if pcts.is_empty() { 0.0 } else { pcts.iter().sum::<f32>() / pcts.len() as f32 };
@@ -100,6 +136,9 @@ fn run_headless_update() -> i32 {
             let _ = db::backfill_run_history_unplayed(&conn, &cfg.steam_id, unplayed);
             let _ = db::insert_achievement_history(&conn, &cfg.steam_id, total, unlocked, games_with_ach.len() as i32, avg);
         }
+
+        let snapshot: Vec<_> = games.iter().map(overachiever_core::GameSnapshot::from_game).collect();
+        let _ = db::insert_scan_snapshot(&conn, &cfg.steam_id, &snapshot);
     }
 
     println!("Update complete. {} games updated.", updated_count);
@@ -186,7 +225,7 @@ fn attach_console() {
     unsafe { windows_sys::Win32::System::Console::AttachConsole(u32::MAX); }
 }
 
-fn run_gui() -> eframe::Result<()> {
+fn run_gui(initial_link: Option<deep_link::DeepLink>, initial_file: Option<std::path::PathBuf>) -> eframe::Result<()> {
     // Load icon for window
     let icon_data = include_bytes!("../../../assets/icon.png");
     let icon_image = image::load_from_memory(icon_data).expect("Failed to load icon");
@@ -201,6 +240,17 @@ fn run_gui() -> eframe::Result<()> {
     // Load config to get saved window state
     let config = config::Config::load();
 
+    // Open overachiever:// links with this executable, and .overach library files
+    // (failures are shown in the status bar once the app is up)
+    let url_scheme = deep_link::register_url_scheme();
+    if let Err(e) = &url_scheme {
+        eprintln!("Failed to register the {}:// URL scheme: {}", deep_link::SCHEME, e);
+    }
+    let file_association = library_file::register_file_association();
+    if let Err(e) = &file_association {
+        eprintln!("Failed to register the .{} file type: {}", library_file::EXTENSION, e);
+    }
+
     // Build viewport with saved or default size/position
     let mut viewport = egui::ViewportBuilder::default()
         .with_inner_size([
@@ -230,11 +280,26 @@ fn run_gui() -> eframe::Result<()> {
         Box::new(|cc| {
             egui_extras::install_image_loaders(&cc.egui_ctx);
 
-            // Load config and apply font settings
+            // Load config and apply font, theme and language settings
             let config = config::Config::load();
             app::panels::top::fonts::apply_font_settings(&cc.egui_ctx, &config);
+            app::panels::top::theme::apply_theme_settings(&cc.egui_ctx, &config);
+            overachiever_core::set_language(config.language);
+
+            let mut app = SteamOverachieverApp::new();
+            match deep_link::listen_for_instances(cc.egui_ctx.clone()) {
+                Ok(receiver) => app.instance_receiver = Some(receiver),
+                Err(e) => eprintln!("Failed to listen for other instances: {}", e),
+            }
+            app.pending_deep_link = initial_link;
+            app.pending_library_file = initial_file;
+            if let Err(e) = url_scheme {
+                app.status = overachiever_core::tr_args("status.url_scheme_failed", &[("scheme", &deep_link::SCHEME), ("error", &e)]);
+            } else if let Err(e) = file_association {
+                app.status = overachiever_core::tr_args("status.file_type_failed", &[("extension", &library_file::EXTENSION), ("error", &e)]);
+            }
 
-            Ok(Box::new(SteamOverachieverApp::new()))
+            Ok(Box::new(app))
         }),
     )
 }
@@ -0,0 +1,203 @@
+//! Local HTTP server for stream overlays (OBS browser sources)
+//!
+//! Serves a small HTML page on `http://127.0.0.1:<port>/` that polls `/overlay.json`
+//! and shows the current game, its completion and the latest unlocks.
+
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use serde::Serialize;
+
+/// How often the overlay page asks for new data
+const OVERLAY_POLL_MS: u32 = 2000;
+
+/// Game shown at the top of the overlay
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OverlayGame {
+    pub appid: u64,
+    pub name: String,
+    /// Currently running (false = most recently played)
+    pub playing: bool,
+    pub unlocked: Option<i32>,
+    pub total: Option<i32>,
+    pub percent: Option<f32>,
+}
+
+/// A recent unlock
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OverlayUnlock {
+    pub game_name: String,
+    pub achievement_name: String,
+    pub icon_url: String,
+    pub unlocked_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Everything the overlay page shows
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct OverlaySnapshot {
+    pub game: Option<OverlayGame>,
+    pub recent_unlocks: Vec<OverlayUnlock>,
+    /// Average completion across played games with achievements
+    pub avg_completion: Option<f32>,
+}
+
+/// Running overlay server; stops when dropped
+pub struct OverlayServer {
+    pub port: u16,
+    snapshot: Arc<Mutex<OverlaySnapshot>>,
+    stop: Arc<AtomicBool>,
+}
+
+impl OverlayServer {
+    /// Bind to localhost on `port` and serve in a background thread
+    pub fn start(port: u16) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(("127.0.0.1", port))?;
+        // Non-blocking accept so the thread notices when it should stop
+        listener.set_nonblocking(true)?;
+
+        let snapshot = Arc::new(Mutex::new(OverlaySnapshot::default()));
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_snapshot = snapshot.clone();
+        let thread_stop = stop.clone();
+        thread::spawn(move || {
+            while !thread_stop.load(Ordering::Relaxed) {
+                match listener.accept() {
+                    Ok((stream, _)) => {
+                        let _ = handle_connection(stream, &thread_snapshot);
+                    }
+                    Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                        thread::sleep(Duration::from_millis(50));
+                    }
+                    Err(e) => {
+                        eprintln!("Overlay server accept failed: {}", e);
+                        thread::sleep(Duration::from_millis(500));
+                    }
+                }
+            }
+        });
+
+        Ok(Self { port, snapshot, stop })
+    }
+
+    /// URL to add as an OBS browser source
+    pub fn url(&self) -> String {
+        format!("http://127.0.0.1:{}/", self.port)
+    }
+
+    /// Replace the data served to the overlay
+    pub fn update(&self, snapshot: OverlaySnapshot) {
+        if let Ok(mut current) = self.snapshot.lock() {
+            *current = snapshot;
+        }
+    }
+}
+
+impl Drop for OverlayServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+fn handle_connection(mut stream: TcpStream, snapshot: &Mutex<OverlaySnapshot>) -> std::io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(Duration::from_secs(2)))?;
+
+    // Only the request line matters: "GET /path HTTP/1.1"
+    let mut buf = [0u8; 1024];
+    let len = stream.read(&mut buf)?;
+    let request = String::from_utf8_lossy(&buf[..len]);
+    let mut parts = request.lines().next().unwrap_or_default().split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let path = parts.next().unwrap_or_default().split('?').next().unwrap_or_default();
+
+    let (status, content_type, body) = match (method, path) {
+        ("GET", "/") | ("GET", "/overlay") => ("200 OK", "text/html; charset=utf-8", overlay_html()),
+        ("GET", "/overlay.json") => {
+            let json = snapshot.lock().ok().and_then(|s| serde_json::to_string(&*s).ok()).unwrap_or_default();
+            ("200 OK", "application/json", json)
+        }
+        ("GET", _) => ("404 Not Found", "text/plain", "Not found".to_string()),
+        _ => ("405 Method Not Allowed", "text/plain", "Method not allowed".to_string()),
+    };
+
+    // No CORS header: the overlay page fetches its data from the same origin, and other
+    // websites open in the streamer's browser must not be able to read it
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes())?;
+    stream.flush()
+}
+
+fn overlay_html() -> String {
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Overachiever overlay</title>
+<style>
+body {{ margin: 0; background: transparent; font-family: "Segoe UI", system-ui, sans-serif; color: #fff; text-shadow: 0 1px 3px #000; }}
+#overlay {{ display: inline-block; min-width: 320px; padding: 12px 16px; background: rgba(20, 22, 28, 0.75); border-radius: 10px; }}
+#game {{ font-size: 20px; font-weight: 600; }}
+#progress {{ height: 6px; margin: 6px 0 4px; background: rgba(255, 255, 255, 0.2); border-radius: 3px; overflow: hidden; }}
+#bar {{ height: 100%; width: 0; background: #f0b43c; transition: width 0.5s; }}
+#meta {{ font-size: 13px; opacity: 0.8; }}
+.unlock {{ display: flex; align-items: center; gap: 8px; margin-top: 8px; font-size: 14px; }}
+.unlock img {{ width: 32px; height: 32px; border-radius: 4px; }}
+.unlock small {{ display: block; opacity: 0.7; }}
+.new {{ animation: flash 2s; }}
+@keyframes flash {{ from {{ background: rgba(240, 180, 60, 0.6); }} to {{ background: transparent; }} }}
+</style>
+</head>
+<body>
+<div id="overlay">
+<div id="game">Overachiever</div>
+<div id="progress"><div id="bar"></div></div>
+<div id="meta"></div>
+<div id="unlocks"></div>
+</div>
+<script>
+let lastUnlock = null;
+function text(tag, value) {{ const el = document.createElement(tag); el.textContent = value; return el; }}
+async function refresh() {{
+  try {{
+    const data = await (await fetch("/overlay.json", {{ cache: "no-store" }})).json();
+    const game = data.game;
+    document.getElementById("game").textContent = game ? (game.playing ? "▶ " : "") + game.name : "Overachiever";
+    document.getElementById("bar").style.width = (game && game.percent != null ? game.percent : 0) + "%";
+    const meta = [];
+    if (game && game.total) meta.push(game.unlocked + " / " + game.total + " (" + game.percent.toFixed(0) + "%)");
+    if (data.avg_completion != null) meta.push("Avg completion " + data.avg_completion.toFixed(1) + "%");
+    document.getElementById("meta").textContent = meta.join(" · ");
+    const list = document.getElementById("unlocks");
+    list.replaceChildren();
+    data.recent_unlocks.forEach((u, i) => {{
+      const row = document.createElement("div");
+      row.className = "unlock" + (i === 0 && lastUnlock !== null && lastUnlock !== u.unlocked_at ? " new" : "");
+      if (u.icon_url) {{ const img = document.createElement("img"); img.src = u.icon_url; row.appendChild(img); }}
+      const label = text("div", u.achievement_name);
+      label.appendChild(text("small", u.game_name));
+      row.appendChild(label);
+      list.appendChild(row);
+    }});
+    if (data.recent_unlocks.length) lastUnlock = data.recent_unlocks[0].unlocked_at;
+  }} catch (e) {{}}
+}}
+refresh();
+setInterval(refresh, {poll_ms});
+</script>
+</body>
+</html>
+"#,
+        poll_ms = OVERLAY_POLL_MS
+    )
+}
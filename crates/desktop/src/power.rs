@@ -0,0 +1,162 @@
+//! Power state detection - battery and system load, used to throttle background scans
+
+use overachiever_core::{tr, tr_args};
+
+/// CPU load (0-100) above which background scans are paused
+pub const HIGH_LOAD_PERCENT: f32 = 85.0;
+
+/// Battery level (0-100) below which background scans are paused
+pub const LOW_BATTERY_PERCENT: u8 = 20;
+
+/// How much slower background scans run while on battery
+pub const BATTERY_SLOWDOWN: u32 = 4;
+
+/// Snapshot of the system power state
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PowerStatus {
+    /// Running on battery (not plugged in)
+    pub on_battery: bool,
+    /// Battery charge percent (None if no battery or unknown)
+    pub battery_percent: Option<u8>,
+    /// System CPU load percent (None if unknown)
+    pub cpu_load: Option<f32>,
+}
+
+/// How background work should run given the current power state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Throttle {
+    /// Run at normal speed
+    Full,
+    /// Run, but with longer delays between items
+    Slow,
+    /// Don't start new work
+    Paused,
+}
+
+impl PowerStatus {
+    /// Decide how background scans should run
+    pub fn throttle(&self) -> Throttle {
+        if self.cpu_load.map(|l| l >= HIGH_LOAD_PERCENT).unwrap_or(false) {
+            return Throttle::Paused;
+        }
+        if self.on_battery {
+            if self.battery_percent.map(|p| p < LOW_BATTERY_PERCENT).unwrap_or(false) {
+                return Throttle::Paused;
+            }
+            return Throttle::Slow;
+        }
+        Throttle::Full
+    }
+
+    /// Short human readable reason for the current throttle
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if self.on_battery {
+            match self.battery_percent {
+                Some(p) => parts.push(tr_args("power.on_battery_percent", &[("percent", &p)])),
+                None => parts.push(tr("power.on_battery").to_string()),
+            }
+        }
+        if let Some(load) = self.cpu_load {
+            if load >= HIGH_LOAD_PERCENT {
+                parts.push(tr_args("power.high_load", &[("percent", &format!("{:.0}", load))]));
+            }
+        }
+        if parts.is_empty() {
+            tr("power.plugged_in").to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
+/// Read the current power state
+pub fn read_power_status() -> PowerStatus {
+    let (on_battery, battery_percent) = read_battery();
+    PowerStatus { on_battery, battery_percent, cpu_load: read_cpu_load() }
+}
+
+#[cfg(windows)]
+fn read_battery() -> (bool, Option<u8>) {
+    use windows_sys::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+
+    let mut status: SYSTEM_POWER_STATUS = unsafe { std::mem::zeroed() };
+    if unsafe { GetSystemPowerStatus(&mut status) } == 0 {
+        return (false, None);
+    }
+    // ACLineStatus: 0 = offline, 1 = online, 255 = unknown
+    // BatteryFlag 128 = no system battery, BatteryLifePercent 255 = unknown
+    let has_battery = status.BatteryFlag != 128;
+    let on_battery = has_battery && status.ACLineStatus == 0;
+    let percent = (has_battery && status.BatteryLifePercent <= 100).then_some(status.BatteryLifePercent);
+    (on_battery, percent)
+}
+
+#[cfg(target_os = "linux")]
+fn read_battery() -> (bool, Option<u8>) {
+    let Ok(entries) = std::fs::read_dir("/sys/class/power_supply") else {
+        return (false, None);
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let kind = std::fs::read_to_string(path.join("type")).unwrap_or_default();
+        if kind.trim() != "Battery" {
+            continue;
+        }
+        let status = std::fs::read_to_string(path.join("status")).unwrap_or_default();
+        let percent = std::fs::read_to_string(path.join("capacity"))
+            .ok()
+            .and_then(|c| c.trim().parse::<u8>().ok());
+        return (status.trim() == "Discharging", percent);
+    }
+    (false, None)
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+fn read_battery() -> (bool, Option<u8>) {
+    (false, None)
+}
+
+/// System-wide CPU usage since the previous call (None on the first call)
+#[cfg(windows)]
+fn read_cpu_load() -> Option<f32> {
+    use std::sync::Mutex;
+    use windows_sys::Win32::Foundation::FILETIME;
+    use windows_sys::Win32::System::Threading::GetSystemTimes;
+
+    static PREVIOUS: Mutex<Option<(u64, u64)>> = Mutex::new(None);
+
+    let to_u64 = |ft: FILETIME| ((ft.dwHighDateTime as u64) << 32) | ft.dwLowDateTime as u64;
+    let mut idle: FILETIME = unsafe { std::mem::zeroed() };
+    let mut kernel: FILETIME = unsafe { std::mem::zeroed() };
+    let mut user: FILETIME = unsafe { std::mem::zeroed() };
+    if unsafe { GetSystemTimes(&mut idle, &mut kernel, &mut user) } == 0 {
+        return None;
+    }
+    // Kernel time includes idle time
+    let idle = to_u64(idle);
+    let total = to_u64(kernel) + to_u64(user);
+
+    let mut previous = PREVIOUS.lock().ok()?;
+    let load = previous.and_then(|(prev_idle, prev_total)| {
+        let total_delta = total.saturating_sub(prev_total);
+        let idle_delta = idle.saturating_sub(prev_idle);
+        (total_delta > 0).then(|| 100.0 * (1.0 - idle_delta as f32 / total_delta as f32))
+    });
+    *previous = Some((idle, total));
+    load
+}
+
+/// 1-minute load average relative to the number of cores
+#[cfg(target_os = "linux")]
+fn read_cpu_load() -> Option<f32> {
+    let loadavg = std::fs::read_to_string("/proc/loadavg").ok()?;
+    let one_minute: f32 = loadavg.split_whitespace().next()?.parse().ok()?;
+    let cores = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1) as f32;
+    Some((100.0 * one_minute / cores).min(100.0))
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+fn read_cpu_load() -> Option<f32> {
+    None
+}
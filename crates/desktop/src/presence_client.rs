@@ -0,0 +1,184 @@
+//! Presence sharing over the backend WebSocket
+//!
+//! While running, reports the current game every minute (and right away when it
+//! changes) and keeps the list of other users sharing what they play. Reconnects
+//! after network errors and stale connections; stops sharing when dropped.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use futures_util::{SinkExt, StreamExt};
+use overachiever_core::{
+    ClientMessage, PresenceGame, ServerMessage, UserPresence, HEARTBEAT_INTERVAL_SECS, HEARTBEAT_TIMEOUT_SECS,
+    PROTOCOL_VERSION,
+};
+use tokio_tungstenite::tungstenite::Message;
+
+/// Presence is re-sent this often (the server drops it after a few minutes without updates)
+const UPDATE_INTERVAL: Duration = Duration::from_secs(60);
+/// Wait before reconnecting after the connection failed
+const RECONNECT_DELAY: Duration = Duration::from_secs(30);
+/// How often the connection task checks for a changed game or a stop request
+const TICK: Duration = Duration::from_secs(1);
+
+#[derive(Default)]
+struct Shared {
+    game: Option<PresenceGame>,
+    others: Vec<UserPresence>,
+    error: Option<String>,
+}
+
+/// Running presence connection; stops sharing when dropped
+pub struct PresenceClient {
+    /// Token the connection authenticated with (restart when it changes)
+    pub token: String,
+    shared: Arc<Mutex<Shared>>,
+    stop: Arc<AtomicBool>,
+}
+
+impl PresenceClient {
+    /// Connect in a background thread
+    pub fn start(token: String) -> Self {
+        let shared = Arc::new(Mutex::new(Shared::default()));
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_shared = shared.clone();
+        let thread_stop = stop.clone();
+        let thread_token = token.clone();
+        thread::spawn(move || {
+            let runtime = match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+                Ok(runtime) => runtime,
+                Err(e) => {
+                    lock(&thread_shared).error = Some(e.to_string());
+                    return;
+                }
+            };
+            runtime.block_on(run(thread_token, thread_shared, thread_stop));
+        });
+        Self { token, shared, stop }
+    }
+
+    /// Set the game to share (None = not playing)
+    pub fn set_game(&self, game: Option<PresenceGame>) {
+        lock(&self.shared).game = game;
+    }
+
+    /// Other users currently playing something
+    pub fn others(&self) -> Vec<UserPresence> {
+        lock(&self.shared).others.clone()
+    }
+
+    /// Last connection error (cleared once connected again)
+    pub fn error(&self) -> Option<String> {
+        lock(&self.shared).error.clone()
+    }
+}
+
+impl Drop for PresenceClient {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+fn lock(shared: &Mutex<Shared>) -> std::sync::MutexGuard<'_, Shared> {
+    shared.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Keep a connection up until stopped
+async fn run(token: String, shared: Arc<Mutex<Shared>>, stop: Arc<AtomicBool>) {
+    while !stop.load(Ordering::Relaxed) {
+        match connection(&token, &shared, &stop).await {
+            Ok(()) => return,
+            Err(e) => {
+                let mut state = lock(&shared);
+                state.error = Some(e);
+                state.others.clear();
+            }
+        }
+        let retry_at = Instant::now() + RECONNECT_DELAY;
+        while Instant::now() < retry_at && !stop.load(Ordering::Relaxed) {
+            tokio::time::sleep(TICK).await;
+        }
+    }
+}
+
+/// One connection: authenticate, then send presence until stopped (Ok) or disconnected (Err)
+async fn connection(token: &str, shared: &Mutex<Shared>, stop: &AtomicBool) -> Result<(), String> {
+    let (socket, _) = tokio_tungstenite::connect_async(crate::cloud_sync::websocket_url())
+        .await
+        .map_err(|e| format!("Connection failed: {}", e))?;
+    let (mut sender, mut receiver) = socket.split();
+
+    send(&mut sender, &ClientMessage::Hello { version: PROTOCOL_VERSION }).await?;
+    send(&mut sender, &ClientMessage::Authenticate { token: token.to_string() }).await?;
+
+    let mut sent_game: Option<Option<PresenceGame>> = None;
+    let mut last_sent = Instant::now();
+    let mut last_received = Instant::now();
+    let mut heartbeat = Duration::from_secs(HEARTBEAT_INTERVAL_SECS);
+    let mut tick = tokio::time::interval(TICK);
+    loop {
+        tokio::select! {
+            _ = tick.tick() => {
+                if stop.load(Ordering::Relaxed) {
+                    let _ = send(&mut sender, &ClientMessage::ClearPresence).await;
+                    let _ = sender.close().await;
+                    return Ok(());
+                }
+                if last_received.elapsed() >= Duration::from_secs(HEARTBEAT_TIMEOUT_SECS) {
+                    return Err("Connection stale, no reply from the server".to_string());
+                }
+                let game = lock(shared).game.clone();
+                if sent_game.as_ref() != Some(&game) || last_sent.elapsed() >= UPDATE_INTERVAL {
+                    send(&mut sender, &ClientMessage::UpdatePresence { game: game.clone() }).await?;
+                    sent_game = Some(game);
+                    last_sent = Instant::now();
+                } else if last_sent.elapsed() >= heartbeat {
+                    send(&mut sender, &ClientMessage::Ping).await?;
+                    last_sent = Instant::now();
+                }
+            }
+            message = receiver.next() => {
+                let text = match message {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_))) | None => return Err("Disconnected".to_string()),
+                    Some(Ok(_)) => continue,
+                    Some(Err(e)) => return Err(e.to_string()),
+                };
+                last_received = Instant::now();
+                match serde_json::from_str::<ServerMessage>(&text) {
+                    Ok(ServerMessage::Welcome { heartbeat_secs, .. }) => {
+                        heartbeat = Duration::from_secs(heartbeat_secs.max(1));
+                    }
+                    Ok(ServerMessage::Closing { reason }) => {
+                        if reason.should_reconnect() {
+                            return Err(format!("Closed by the server: {}", reason));
+                        }
+                        // Reconnecting won't help, stop until restarted
+                        lock(shared).error = Some(format!("Closed by the server: {}", reason));
+                        return Ok(());
+                    }
+                    Ok(ServerMessage::Presence { users }) => {
+                        let mut state = lock(shared);
+                        state.others = users;
+                        state.error = None;
+                    }
+                    Ok(ServerMessage::AuthError { reason }) => {
+                        return Err(format!("Not signed in: {}", reason));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+async fn send<S>(sender: &mut S, message: &ClientMessage) -> Result<(), String>
+where
+    S: SinkExt<Message> + Unpin,
+    S::Error: std::fmt::Display,
+{
+    let text = serde_json::to_string(message).map_err(|e| e.to_string())?;
+    sender.send(Message::Text(text)).await.map_err(|e| e.to_string())
+}
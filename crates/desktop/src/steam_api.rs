@@ -1,11 +1,23 @@
 use crate::config::Config;
+use crate::http::steam_api_url;
 use overachiever_core::{Game, SteamGame, Achievement, AchievementSchema};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::time::Duration;
 
-const API_OWNED_GAMES: &str = "https://api.steampowered.com/IPlayerService/GetOwnedGames/v1/";
-const API_RECENTLY_PLAYED: &str = "https://api.steampowered.com/IPlayerService/GetRecentlyPlayedGames/v1/";
-const API_ACHIEVEMENTS: &str = "http://api.steampowered.com/ISteamUserStats/GetPlayerAchievements/v0001/";
-const API_SCHEMA: &str = "http://api.steampowered.com/ISteamUserStats/GetSchemaForGame/v2/";
+const API_OWNED_GAMES: &str = "/IPlayerService/GetOwnedGames/v1/";
+const API_RECENTLY_PLAYED: &str = "/IPlayerService/GetRecentlyPlayedGames/v1/";
+const API_ACHIEVEMENTS: &str = "/ISteamUserStats/GetPlayerAchievements/v0001/";
+const API_SCHEMA: &str = "/ISteamUserStats/GetSchemaForGame/v2/";
+const API_PLAYER_SUMMARIES: &str = "/ISteamUser/GetPlayerSummaries/v0002/";
+const API_RESOLVE_VANITY: &str = "/ISteamUser/ResolveVanityURL/v0001/";
+const API_GLOBAL_PERCENTAGES: &str = "/ISteamUserStats/GetGlobalAchievementPercentagesForApp/v0002/";
+const API_STORE_APPDETAILS: &str = "https://store.steampowered.com/api/appdetails";
+
+/// How often a paused full scan checks whether it may continue
+const SCRAPE_PAUSE_POLL: Duration = Duration::from_secs(2);
 
 #[derive(Clone)]
 pub enum FetchProgress {
@@ -22,11 +34,24 @@ pub enum ScrapeProgress {
     FetchingGames,
     Starting { total: i32 },
     Scraping { current: i32, total: i32, game_name: String },
+    /// Waiting before game `current` until background work may run again
+    Paused { current: i32, total: i32 },
     GameUpdated { appid: u64, unlocked: i32, total: i32 },
     Done { games: Vec<Game> },
     Error(String),
 }
 
+/// Which games a full scan scrapes achievements for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScrapeTarget {
+    /// Games never scraped yet
+    Unscraped,
+    /// Every game (forced full scan)
+    All,
+    /// Games never scraped or last scraped more than N days ago
+    StaleOlderThan { days: u32 },
+}
+
 #[derive(Clone)]
 pub enum UpdateProgress {
     FetchingGames,
@@ -37,12 +62,20 @@ pub enum UpdateProgress {
     Error(String),
 }
 
+#[derive(Clone)]
+pub enum RivalScrapeProgress {
+    FetchingGames,
+    Scraping { current: i32, total: i32, game_name: String },
+    Done { games: Vec<Game> },
+    Error(String),
+}
+
 #[derive(Clone)]
 pub enum SingleGameRefreshProgress {
     Refreshing { appid: u64 },
     Done { 
         appid: u64, 
-        game: Game,
+        game: Box<Game>,
         achievements: Vec<overachiever_core::GameAchievement>,
     },
     Error(String),
@@ -65,7 +98,7 @@ pub fn fetch_owned_games_with_progress(progress_tx: Sender<FetchProgress>) -> Re
     
     let url = format!(
         "{}?key={}&input_json={}&format=json",
-        API_OWNED_GAMES,
+        steam_api_url(API_OWNED_GAMES),
         steam_key,
         urlencoding::encode(&input.to_string())
     );
@@ -73,7 +106,7 @@ pub fn fetch_owned_games_with_progress(progress_tx: Sender<FetchProgress>) -> Re
     // Stage 1: Requesting
     let _ = progress_tx.send(FetchProgress::Requesting);
     
-    let response = reqwest::blocking::get(&url)?;
+    let response = crate::http::get(&url)?;
     
     // Stage 2: Downloading
     let _ = progress_tx.send(FetchProgress::Downloading);
@@ -102,6 +135,7 @@ pub fn fetch_owned_games_with_progress(progress_tx: Sender<FetchProgress>) -> Re
     let conn = crate::db::open_connection()?;
     let track_changes = crate::db::has_completed_initial_scan(&conn);
     crate::db::upsert_games(&conn, &config.steam_id, &games, track_changes)?;
+    mark_owned_games(&conn, &config.steam_id, &games)?;
     if track_changes {
         crate::db::insert_run_history(&conn, &config.steam_id, total, unplayed)?;
     }
@@ -113,7 +147,12 @@ pub fn fetch_owned_games_with_progress(progress_tx: Sender<FetchProgress>) -> Re
     Ok(())
 }
 
-pub fn scrape_achievements_with_progress(progress_tx: Sender<ScrapeProgress>, force: bool) -> Result<(), Box<dyn std::error::Error>> {
+/// `paused` is checked between games, the scan waits while it is set (game running, low battery)
+pub fn scrape_achievements_with_progress(
+    progress_tx: Sender<ScrapeProgress>,
+    target: ScrapeTarget,
+    paused: Arc<AtomicBool>,
+) -> Result<(), Box<dyn std::error::Error>> {
     let config = Config::load();
     if !config.has_steam_credentials() {
         let _ = progress_tx.send(ScrapeProgress::Error("Please configure steam_web_api_key and steam_id in config.toml".to_string()));
@@ -133,12 +172,12 @@ pub fn scrape_achievements_with_progress(progress_tx: Sender<ScrapeProgress>, fo
     
     let url = format!(
         "{}?key={}&input_json={}&format=json",
-        API_OWNED_GAMES,
+        steam_api_url(API_OWNED_GAMES),
         steam_key,
         urlencoding::encode(&input.to_string())
     );
     
-    let response = reqwest::blocking::get(&url)?;
+    let response = crate::http::get(&url)?;
     let body: serde_json::Value = response.json()?;
     
     let games: Vec<SteamGame> = body["response"]["games"]
@@ -159,32 +198,38 @@ pub fn scrape_achievements_with_progress(progress_tx: Sender<ScrapeProgress>, fo
         crate::db::insert_run_history(&conn, &config.steam_id, total_games, unplayed_games)?;
     }
 
-    // Step 1.5: Fetch recently played games (to capture F2P games not in GetOwnedGames)
+    // Step 1.5: Fetch recently played games (also those played without owning them, e.g. free weekends).
+    // Those aren't in the library total, they're flagged unowned instead.
     let recent_games = fetch_recently_played_games(steam_key, steam_id, config.debug_recently_played)?;
     if !recent_games.is_empty() {
         crate::db::upsert_games(&conn, &config.steam_id, &recent_games, track_changes)?;
-
-        if track_changes {
-            // Recalculate total games after adding recently played
-            let all_games_after_upsert = crate::db::get_all_games(&conn, &config.steam_id)?;
-            let new_total = all_games_after_upsert.len() as i32;
-            if new_total > total_games {
-                crate::db::update_run_history_total(&conn, &config.steam_id, new_total)?;
-            }
-        }
     }
+    mark_owned_games(&conn, &config.steam_id, &games)?;
 
-    // Step 2: Scrape achievements - either just unscraped games or all games if force is true
-    let games_to_scrape = if force {
-        crate::db::get_all_games(&conn, &config.steam_id)?
-    } else {
-        crate::db::get_games_needing_achievement_scrape(&conn, &config.steam_id)?
+    // Step 2: Scrape achievements - unscraped games, stale games, or all games when forced
+    let games_to_scrape = match target {
+        ScrapeTarget::Unscraped => crate::db::get_games_needing_achievement_scrape(&conn, &config.steam_id)?,
+        ScrapeTarget::All => crate::db::get_all_games(&conn, &config.steam_id)?,
+        ScrapeTarget::StaleOlderThan { days } => {
+            let cutoff = chrono::Utc::now() - chrono::Duration::days(days as i64);
+            crate::db::get_all_games(&conn, &config.steam_id)?
+                .into_iter()
+                .filter(|g| g.last_achievement_scrape.is_none_or(|t| t < cutoff))
+                .collect()
+        }
     };
     let total = games_to_scrape.len() as i32;
     
     let _ = progress_tx.send(ScrapeProgress::Starting { total });
     
     for (i, game) in games_to_scrape.iter().enumerate() {
+        if paused.load(Ordering::Relaxed) {
+            let _ = progress_tx.send(ScrapeProgress::Paused { current: i as i32 + 1, total });
+            while paused.load(Ordering::Relaxed) {
+                std::thread::sleep(SCRAPE_PAUSE_POLL);
+            }
+        }
+
         let _ = progress_tx.send(ScrapeProgress::Scraping {
             current: i as i32 + 1,
             total,
@@ -194,13 +239,13 @@ pub fn scrape_achievements_with_progress(progress_tx: Sender<ScrapeProgress>, fo
         // Fetch player achievements
         let url = format!(
             "{}?appid={}&key={}&steamid={}&format=json",
-            API_ACHIEVEMENTS,
+            steam_api_url(API_ACHIEVEMENTS),
             game.appid,
             steam_key,
             steam_id
         );
         
-        match reqwest::blocking::get(&url) {
+        match crate::http::get(&url) {
             Ok(response) => {
                 if let Ok(body) = response.text() {
                     if let Ok(json) = serde_json::from_str::<serde_json::Value>(&body) {
@@ -215,12 +260,12 @@ pub fn scrape_achievements_with_progress(progress_tx: Sender<ScrapeProgress>, fo
                             // Also fetch achievement schema for names and icons
                             let schema_url = format!(
                                 "{}?appid={}&key={}&format=json",
-                                API_SCHEMA,
+                                steam_api_url(API_SCHEMA),
                                 game.appid,
                                 steam_key
                             );
                             
-                            if let Ok(schema_response) = reqwest::blocking::get(&schema_url) {
+                            if let Ok(schema_response) = crate::http::get(&schema_url) {
                                 if let Ok(schema_body) = schema_response.text() {
                                     if let Ok(schema_json) = serde_json::from_str::<serde_json::Value>(&schema_body) {
                                         if let Some(schema_arr) = schema_json["game"]["availableGameStats"]["achievements"].as_array() {
@@ -230,6 +275,7 @@ pub fn scrape_achievements_with_progress(progress_tx: Sender<ScrapeProgress>, fo
                                                 .collect();
                                             // Save detailed achievements to DB
                                             let _ = crate::db::save_game_achievements(&conn, &config.steam_id, game.appid, &schema, &achievements);
+                                            save_global_percentages(&conn, game.appid);
                                         }
                                     }
                                 }
@@ -258,6 +304,13 @@ pub fn scrape_achievements_with_progress(progress_tx: Sender<ScrapeProgress>, fo
             }
         }
         
+        // Store app type (soundtrack, tool, demo...) - fetched once per game
+        if game.app_type.is_none() {
+            if let Some(app_type) = fetch_app_type(game.appid) {
+                let _ = crate::db::set_game_app_type(&conn, &config.steam_id, game.appid, &app_type);
+            }
+        }
+        
         // Small delay to avoid rate limiting
         std::thread::sleep(std::time::Duration::from_millis(100));
     }
@@ -269,6 +322,170 @@ pub fn scrape_achievements_with_progress(progress_tx: Sender<ScrapeProgress>, fo
     Ok(())
 }
 
+/// Fetch the game the user is currently playing (None if not in-game or profile is private)
+pub fn fetch_currently_playing(steam_key: &str, steam_id: u64) -> Result<Option<u64>, Box<dyn std::error::Error>> {
+    let url = format!("{}?key={}&steamids={}&format=json", steam_api_url(API_PLAYER_SUMMARIES), steam_key, steam_id);
+    let client = crate::http::client_builder()
+        .timeout(std::time::Duration::from_secs(15))
+        .build()?;
+    let response = client.get(&url).send()?;
+    if !response.status().is_success() {
+        return Err(format!("Steam API error: {}", response.status()).into());
+    }
+    let body: serde_json::Value = response.json()?;
+
+    // gameid is a string in the response
+    let appid = body["response"]["players"][0]["gameid"]
+        .as_str()
+        .and_then(|id| id.parse::<u64>().ok());
+    Ok(appid)
+}
+
+/// Persona name and avatar of a Steam account, from GetPlayerSummaries
+#[derive(Clone, Debug)]
+pub struct PlayerSummary {
+    pub steam_id: String,
+    pub persona_name: String,
+    pub avatar_url: Option<String>,
+    /// Public profile; otherwise Steam only returns its games to a key of the same account
+    pub public_profile: bool,
+}
+
+/// What was entered as the Steam ID: the 64-bit ID itself, or a custom profile URL name
+#[derive(Debug, PartialEq)]
+enum SteamIdInput<'a> {
+    Id(&'a str),
+    Vanity(&'a str),
+}
+
+/// Accept a Steam ID, a custom URL name, or a profile URL of either kind
+/// (steamcommunity.com/profiles/<id> or steamcommunity.com/id/<name>)
+fn parse_steam_id_input(input: &str) -> Result<SteamIdInput<'_>, String> {
+    let input = input.trim().trim_end_matches('/');
+    let path = input
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_start_matches("www.");
+    let path = path.strip_prefix("steamcommunity.com/").unwrap_or(path);
+    let parsed = match path.split('/').collect::<Vec<_>>().as_slice() {
+        ["profiles", id, ..] => SteamIdInput::Id(id),
+        ["id", name, ..] => SteamIdInput::Vanity(name),
+        [id] if id.chars().all(|c| c.is_ascii_digit()) => SteamIdInput::Id(id),
+        [name] if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') => {
+            SteamIdInput::Vanity(name)
+        }
+        _ => return Err("Enter a Steam ID, a profile URL or your custom profile name".to_string()),
+    };
+    if let SteamIdInput::Id(id) = parsed {
+        if id.len() != 17 || !id.starts_with("7656119") || !id.chars().all(|c| c.is_ascii_digit()) {
+            return Err("The Steam ID should be the 17-digit number starting with 7656119".to_string());
+        }
+    }
+    Ok(parsed)
+}
+
+/// GET a Steam Web API URL, turning a rejected key into a readable error
+fn steam_api_get(url: &str) -> Result<serde_json::Value, String> {
+    let client = crate::http::client_builder()
+        .timeout(std::time::Duration::from_secs(15))
+        .build()
+        .map_err(|e| e.to_string())?;
+    let response = client.get(url).send().map_err(|e| format!("Could not reach Steam: {}", e))?;
+    match response.status() {
+        reqwest::StatusCode::FORBIDDEN | reqwest::StatusCode::UNAUTHORIZED => {
+            Err("Steam rejected the API key - check it at steamcommunity.com/dev/apikey".to_string())
+        }
+        status if !status.is_success() => Err(format!("Steam API error: {}", status)),
+        _ => response.json().map_err(|e| format!("Unexpected Steam response: {}", e)),
+    }
+}
+
+/// Resolve a Steam ID, profile URL or custom profile name to the 64-bit Steam ID
+pub fn resolve_steam_id(steam_key: &str, input: &str) -> Result<String, String> {
+    let vanity = match parse_steam_id_input(input)? {
+        SteamIdInput::Id(id) => return Ok(id.to_string()),
+        SteamIdInput::Vanity(name) => name,
+    };
+    let url = format!("{}?key={}&vanityurl={}&format=json", steam_api_url(API_RESOLVE_VANITY), steam_key, vanity);
+    let body = steam_api_get(&url)?;
+    // success is 1 when found, 42 when no profile has this custom URL
+    match (body["response"]["success"].as_i64(), body["response"]["steamid"].as_str()) {
+        (Some(1), Some(steam_id)) => Ok(steam_id.to_string()),
+        _ => Err(format!("No Steam profile has the custom URL \"{}\"", vanity)),
+    }
+}
+
+/// Check an API key and Steam ID together by looking up the profile, with an error
+/// message that says which of the two is wrong. `steam_id` may also be a profile URL
+/// or custom profile name; the summary carries the resolved ID.
+pub fn fetch_player_summary(steam_key: &str, steam_id: &str) -> Result<PlayerSummary, String> {
+    let steam_key = steam_key.trim();
+    if steam_key.len() != 32 || !steam_key.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err("The API key should be 32 characters of 0-9 and A-F".to_string());
+    }
+    let steam_id = resolve_steam_id(steam_key, steam_id)?;
+
+    let url = format!("{}?key={}&steamids={}&format=json", steam_api_url(API_PLAYER_SUMMARIES), steam_key, steam_id);
+    let body = steam_api_get(&url)?;
+
+    let player = &body["response"]["players"][0];
+    let Some(persona_name) = player["personaname"].as_str() else {
+        return Err("The API key works, but there is no Steam account with this Steam ID".to_string());
+    };
+    Ok(PlayerSummary {
+        steam_id,
+        persona_name: persona_name.to_string(),
+        avatar_url: player["avatarmedium"].as_str().map(str::to_string),
+        // 3 = public, anything else is private or friends only
+        public_profile: player["communityvisibilitystate"].as_i64() == Some(3),
+    })
+}
+
+/// Fetch global unlock percentages for a game (apiname -> percent)
+pub fn fetch_global_percentages(appid: u64) -> Result<HashMap<String, f32>, Box<dyn std::error::Error>> {
+    let url = format!("{}?gameid={}&format=json", steam_api_url(API_GLOBAL_PERCENTAGES), appid);
+    let body: serde_json::Value = crate::http::get(&url)?.json()?;
+    
+    let percentages = body["achievementpercentages"]["achievements"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|a| {
+                    let name = a["name"].as_str()?;
+                    // The API returns percent as either a number or a string
+                    let percent = a["percent"].as_f64()
+                        .or_else(|| a["percent"].as_str().and_then(|p| p.parse().ok()))?;
+                    Some((name.to_string(), percent as f32))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    
+    Ok(percentages)
+}
+
+/// Fetch the store app type of an app ("game", "music", "demo", "application", ...).
+/// Returns None on errors and for apps the store doesn't know (e.g. delisted),
+/// so they are retried on the next scrape.
+pub fn fetch_app_type(appid: u64) -> Option<String> {
+    let url = format!("{}?appids={}&filters=basic", API_STORE_APPDETAILS, appid);
+    let body: serde_json::Value = crate::http::get(&url).ok()?.json().ok()?;
+    
+    // Response format: { "appid": { "success": true, "data": { "type": "game", ... } } }
+    let app_data = &body[appid.to_string()];
+    if !app_data["success"].as_bool()? {
+        return None;
+    }
+    app_data["data"]["type"].as_str().map(|t| t.to_lowercase())
+}
+
+/// Fetch and store global unlock percentages for a game (best effort, errors are ignored)
+fn save_global_percentages(conn: &rusqlite::Connection, appid: u64) {
+    if let Ok(percentages) = fetch_global_percentages(appid) {
+        let _ = crate::db::save_global_percentages(conn, appid, &percentages);
+    }
+}
+
 /// Fetch recently played games from Steam API (returns full game info)
 pub fn fetch_recently_played_games(steam_key: &str, steam_id: u64, debug_output: bool) -> Result<Vec<SteamGame>, Box<dyn std::error::Error>> {
     let input = serde_json::json!({
@@ -278,12 +495,12 @@ pub fn fetch_recently_played_games(steam_key: &str, steam_id: u64, debug_output:
     
     let url = format!(
         "{}?key={}&input_json={}&format=json",
-        API_RECENTLY_PLAYED,
+        steam_api_url(API_RECENTLY_PLAYED),
         steam_key,
         urlencoding::encode(&input.to_string())
     );
     
-    let response = reqwest::blocking::get(&url)?;
+    let response = crate::http::get(&url)?;
     let body: serde_json::Value = response.json()?;
     
     // Debug output if enabled
@@ -293,7 +510,7 @@ pub fn fetch_recently_played_games(steam_key: &str, steam_id: u64, debug_output:
         let mut debug_content = format!("=== Recently Played API Debug ===\n");
         debug_content.push_str(&format!("Timestamp: {}\n", timestamp));
         debug_content.push_str(&format!("Steam ID: {}\n", steam_id));
-        debug_content.push_str(&format!("API URL: {}\n\n", API_RECENTLY_PLAYED));
+        debug_content.push_str(&format!("API URL: {}\n\n", steam_api_url(API_RECENTLY_PLAYED)));
         debug_content.push_str("=== Raw Response ===\n");
         debug_content.push_str(&serde_json::to_string_pretty(&body).unwrap_or_else(|_| body.to_string()));
         debug_content.push_str("\n\n=== Games List ===\n");
@@ -333,7 +550,32 @@ pub fn fetch_recently_played_games(steam_key: &str, steam_id: u64, debug_output:
     Ok(games)
 }
 
+/// Games whose playtime differs from the stored value (new games count once they've been played).
+/// Catches games played since the last update that have dropped out of the recently played window.
+pub fn playtime_changed_appids(stored: &HashMap<u64, u32>, fetched: &[SteamGame]) -> Vec<u64> {
+    fetched
+        .iter()
+        .filter(|g| match stored.get(&g.appid) {
+            Some(&playtime) => playtime != g.playtime_forever,
+            None => g.playtime_forever > 0,
+        })
+        .map(|g| g.appid)
+        .collect()
+}
+
+/// Flag the games missing from GetOwnedGames as unowned. Skipped when the list came back
+/// empty (as for a private game list) so the whole library isn't flagged.
+fn mark_owned_games(conn: &rusqlite::Connection, steam_id: &str, owned_games: &[SteamGame]) -> Result<(), Box<dyn std::error::Error>> {
+    if owned_games.is_empty() {
+        return Ok(());
+    }
+    let owned: HashSet<u64> = owned_games.iter().map(|g| g.appid).collect();
+    crate::db::set_owned_games(conn, steam_id, &owned)?;
+    Ok(())
+}
+
 /// Run the Update flow: fetch games, get recently played, scrape achievements for recent games
+/// and for any game whose playtime changed since the last update
 pub fn run_update_with_progress(progress_tx: Sender<UpdateProgress>) -> Result<(), Box<dyn std::error::Error>> {
     // Helper to log to ttb_log.txt
     fn update_log(msg: &str) {
@@ -370,13 +612,13 @@ pub fn run_update_with_progress(progress_tx: Sender<UpdateProgress>) -> Result<(
 
     let url = format!(
         "{}?key={}&input_json={}&format=json",
-        API_OWNED_GAMES,
+        steam_api_url(API_OWNED_GAMES),
         steam_key,
         urlencoding::encode(&input.to_string())
     );
 
     update_log(&format!("Making HTTP request to: {}", &url[..url.find("key=").unwrap_or(0) + 10])); // Log URL without full key
-    let client = reqwest::blocking::Client::builder()
+    let client = crate::http::client_builder()
         .timeout(std::time::Duration::from_secs(30))
         .build()?;
     let response = match client.get(&url).send() {
@@ -430,6 +672,15 @@ pub fn run_update_with_progress(progress_tx: Sender<UpdateProgress>) -> Result<(
     let conn = crate::db::open_connection()?;
     let track_changes = crate::db::has_completed_initial_scan(&conn);
     update_log(&format!("Track changes: {} (initial scan completed: {})", track_changes, track_changes));
+    // Differential pass: compare playtime against the stored values before they're overwritten
+    // (before the initial scan every played game would count as changed - the full scan covers those)
+    let playtime_changed: Vec<u64> = if track_changes {
+        let stored = crate::db::get_playtimes(&conn, &config.steam_id)?;
+        playtime_changed_appids(&stored, &games)
+    } else {
+        Vec::new()
+    };
+    update_log(&format!("{} games with changed playtime", playtime_changed.len()));
     update_log("Upserting games to database...");
     crate::db::upsert_games(&conn, &config.steam_id, &games, track_changes)?;
     let total_games = games.len() as i32;
@@ -452,9 +703,10 @@ pub fn run_update_with_progress(progress_tx: Sender<UpdateProgress>) -> Result<(
         }
     }
     
-    if recent_games.is_empty() {
+    if recent_games.is_empty() && playtime_changed.is_empty() {
         update_log("No recently played games");
-        // No recently played games, we're done
+        mark_owned_games(&conn, &config.steam_id, &games)?;
+        // Nothing played since the last update, we're done
         let games = crate::db::get_all_games(&conn, &config.steam_id)?;
         let _ = progress_tx.send(UpdateProgress::Done { games, updated_count: 0 });
         
@@ -463,25 +715,21 @@ pub fn run_update_with_progress(progress_tx: Sender<UpdateProgress>) -> Result<(
         return Ok(());
     }
     
-    // Upsert recently played games (in case any are missing from owned games)
+    // Upsert recently played games (games played without owning them get flagged unowned,
+    // so they don't count towards the library total)
     update_log("Upserting recently played games to database...");
     crate::db::upsert_games(&conn, &config.steam_id, &recent_games, track_changes)?;
-    
-    // Recalculate total games after adding recently played (some F2P games might not be in GetOwnedGames)
+    mark_owned_games(&conn, &config.steam_id, &games)?;
     let all_games_after_upsert = crate::db::get_all_games(&conn, &config.steam_id)?;
-    let new_total = all_games_after_upsert.len() as i32;
-    if track_changes && new_total > total_games {
-        // Update the run_history entry with the correct total
-        crate::db::update_run_history_total(&conn, &config.steam_id, new_total)?;
-    }
     
-    // Get appids for filtering
-    let recent_appids: Vec<u64> = recent_games.iter().map(|g| g.appid).collect();
+    // Get appids for filtering: recently played plus everything whose playtime changed
+    let mut scrape_appids: HashSet<u64> = recent_games.iter().map(|g| g.appid).collect();
+    scrape_appids.extend(playtime_changed.iter().copied());
     
-    // Step 3: Scrape achievements for recently played games
-    let games_to_scrape: Vec<Game> = crate::db::get_all_games(&conn, &config.steam_id)?
+    // Step 3: Scrape achievements for recently played and changed games
+    let games_to_scrape: Vec<Game> = all_games_after_upsert
         .into_iter()
-        .filter(|g| recent_appids.contains(&g.appid))
+        .filter(|g| scrape_appids.contains(&g.appid))
         .collect();
     
     let total = games_to_scrape.len() as i32;
@@ -495,13 +743,13 @@ pub fn run_update_with_progress(progress_tx: Sender<UpdateProgress>) -> Result<(
         
         let url = format!(
             "{}?appid={}&key={}&steamid={}&format=json",
-            API_ACHIEVEMENTS,
+            steam_api_url(API_ACHIEVEMENTS),
             game.appid,
             steam_key,
             steam_id
         );
         
-        match reqwest::blocking::get(&url) {
+        match crate::http::get(&url) {
             Ok(response) => {
                 if let Ok(body) = response.text() {
                     if let Ok(json) = serde_json::from_str::<serde_json::Value>(&body) {
@@ -516,12 +764,12 @@ pub fn run_update_with_progress(progress_tx: Sender<UpdateProgress>) -> Result<(
                             // Also fetch achievement schema for names and icons
                             let schema_url = format!(
                                 "{}?appid={}&key={}&format=json",
-                                API_SCHEMA,
+                                steam_api_url(API_SCHEMA),
                                 game.appid,
                                 steam_key
                             );
                             
-                            if let Ok(schema_response) = reqwest::blocking::get(&schema_url) {
+                            if let Ok(schema_response) = crate::http::get(&schema_url) {
                                 if let Ok(schema_body) = schema_response.text() {
                                     if let Ok(schema_json) = serde_json::from_str::<serde_json::Value>(&schema_body) {
                                         if let Some(schema_arr) = schema_json["game"]["availableGameStats"]["achievements"].as_array() {
@@ -531,6 +779,7 @@ pub fn run_update_with_progress(progress_tx: Sender<UpdateProgress>) -> Result<(
                                                 .collect();
                                             // Save detailed achievements to DB
                                             let _ = crate::db::save_game_achievements(&conn, &config.steam_id, game.appid, &schema, &achievements);
+                                            save_global_percentages(&conn, game.appid);
                                         }
                                     }
                                 }
@@ -590,13 +839,13 @@ pub fn refresh_single_game(progress_tx: Sender<SingleGameRefreshProgress>, appid
     // Fetch player achievements
     let url = format!(
         "{}?appid={}&key={}&steamid={}&format=json",
-        API_ACHIEVEMENTS,
+        steam_api_url(API_ACHIEVEMENTS),
         appid,
         steam_key,
         steam_id
     );
     
-    match reqwest::blocking::get(&url) {
+    match crate::http::get(&url) {
         Ok(response) => {
             if let Ok(body) = response.text() {
                 if let Ok(json) = serde_json::from_str::<serde_json::Value>(&body) {
@@ -609,12 +858,12 @@ pub fn refresh_single_game(progress_tx: Sender<SingleGameRefreshProgress>, appid
                         // Also fetch achievement schema for names and icons
                         let schema_url = format!(
                             "{}?appid={}&key={}&format=json",
-                            API_SCHEMA,
+                            steam_api_url(API_SCHEMA),
                             appid,
                             steam_key
                         );
                         
-                        if let Ok(schema_response) = reqwest::blocking::get(&schema_url) {
+                        if let Ok(schema_response) = crate::http::get(&schema_url) {
                             if let Ok(schema_body) = schema_response.text() {
                                 if let Ok(schema_json) = serde_json::from_str::<serde_json::Value>(&schema_body) {
                                     if let Some(schema_arr) = schema_json["game"]["availableGameStats"]["achievements"].as_array() {
@@ -624,6 +873,7 @@ pub fn refresh_single_game(progress_tx: Sender<SingleGameRefreshProgress>, appid
                                             .collect();
                                         // Save detailed achievements to DB
                                         let _ = crate::db::save_game_achievements(&conn, &config.steam_id, appid, &schema, &achievements);
+                                        save_global_percentages(&conn, appid);
                                     }
                                 }
                             }
@@ -637,7 +887,7 @@ pub fn refresh_single_game(progress_tx: Sender<SingleGameRefreshProgress>, appid
                             let game_achievements = crate::db::get_game_achievements(&conn, &config.steam_id, appid)?;
                             let _ = progress_tx.send(SingleGameRefreshProgress::Done { 
                                 appid, 
-                                game,
+                                game: Box::new(game),
                                 achievements: game_achievements,
                             });
                         } else {
@@ -650,7 +900,7 @@ pub fn refresh_single_game(progress_tx: Sender<SingleGameRefreshProgress>, appid
                         if let Some(game) = games.into_iter().find(|g| g.appid == appid) {
                             let _ = progress_tx.send(SingleGameRefreshProgress::Done { 
                                 appid, 
-                                game,
+                                game: Box::new(game),
                                 achievements: vec![],
                             });
                         } else {
@@ -671,3 +921,102 @@ pub fn refresh_single_game(progress_tx: Sender<SingleGameRefreshProgress>, appid
     
     Ok(())
 }
+
+/// Scrape a rival account (public profile only) for head-to-head comparison.
+/// Read-only: stores the rival's games and achievement counts under their own steam_id,
+/// but only scrapes games both accounts own, and only when new or played since the last scrape.
+pub fn scrape_rival_with_progress(progress_tx: Sender<RivalScrapeProgress>, rival_steam_id: String) -> Result<(), Box<dyn std::error::Error>> {
+    let config = Config::load();
+    if !config.has_steam_credentials() {
+        let _ = progress_tx.send(RivalScrapeProgress::Error("Please configure steam_web_api_key and steam_id in config.toml".to_string()));
+        return Ok(());
+    }
+    let Ok(rival_id) = rival_steam_id.trim().parse::<u64>() else {
+        let _ = progress_tx.send(RivalScrapeProgress::Error("Rival Steam ID must be a SteamID64".to_string()));
+        return Ok(());
+    };
+    let steam_key = &config.steam_web_api_key;
+
+    let _ = progress_tx.send(RivalScrapeProgress::FetchingGames);
+
+    let input = serde_json::json!({
+        "steamid": rival_id,
+        "include_appinfo": 1,
+        "include_played_free_games": 1
+    });
+    let url = format!(
+        "{}?key={}&input_json={}&format=json",
+        steam_api_url(API_OWNED_GAMES),
+        steam_key,
+        urlencoding::encode(&input.to_string())
+    );
+    let body: serde_json::Value = crate::http::get(&url)?.json()?;
+
+    // Private game details come back as an empty response
+    let Some(games_arr) = body["response"]["games"].as_array() else {
+        let _ = progress_tx.send(RivalScrapeProgress::Error("Rival's game details are private".to_string()));
+        return Ok(());
+    };
+    let games: Vec<SteamGame> = games_arr
+        .iter()
+        .filter_map(|g| serde_json::from_value(g.clone()).ok())
+        .collect();
+
+    let conn = crate::db::open_connection()?;
+    let rival_key = rival_id.to_string();
+    let stored_playtimes = crate::db::get_playtimes(&conn, &rival_key)?;
+    let changed: HashSet<u64> = playtime_changed_appids(&stored_playtimes, &games).into_iter().collect();
+    // No first plays or run history for the rival - their data is only for comparison
+    crate::db::upsert_games(&conn, &rival_key, &games, false)?;
+
+    // Only games we own with achievements (or not scraped yet) are worth comparing
+    let own: HashSet<u64> = crate::db::get_all_games(&conn, &config.steam_id)?
+        .into_iter()
+        .filter(|g| g.achievements_total.is_none_or(|total| total > 0))
+        .map(|g| g.appid)
+        .collect();
+    let games_to_scrape: Vec<Game> = crate::db::get_all_games(&conn, &rival_key)?
+        .into_iter()
+        .filter(|g| own.contains(&g.appid))
+        .filter(|g| g.last_achievement_scrape.is_none() || changed.contains(&g.appid))
+        .collect();
+    let total = games_to_scrape.len() as i32;
+
+    for (i, game) in games_to_scrape.iter().enumerate() {
+        let _ = progress_tx.send(RivalScrapeProgress::Scraping {
+            current: i as i32 + 1,
+            total,
+            game_name: game.name.clone(),
+        });
+
+        let url = format!(
+            "{}?appid={}&key={}&steamid={}&format=json",
+            steam_api_url(API_ACHIEVEMENTS),
+            game.appid,
+            steam_key,
+            rival_id
+        );
+        if let Ok(json) = crate::http::get(&url).and_then(|r| r.json::<serde_json::Value>()) {
+            match json["playerstats"]["achievements"].as_array() {
+                Some(achievements_arr) => {
+                    let achievements: Vec<Achievement> = achievements_arr
+                        .iter()
+                        .filter_map(|a| serde_json::from_value(a.clone()).ok())
+                        .collect();
+                    let _ = crate::db::update_game_achievements(&conn, &rival_key, game.appid, &achievements);
+                }
+                None => {
+                    let _ = crate::db::mark_game_no_achievements(&conn, &rival_key, game.appid);
+                }
+            }
+        }
+
+        // Small delay to avoid rate limiting
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+
+    let games = crate::db::get_all_games(&conn, &rival_key)?;
+    let _ = progress_tx.send(RivalScrapeProgress::Done { games });
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! Steam Cloud save state - reads remotecache.vdf for a game's last sync info
+//!
+//! Location: <Steam>/userdata/<account_id>/<appid>/remotecache.vdf
+//! Format:   "<appid>" { "ChangeNumber" ".." "<file name>" { "size" ".." "localtime" ".."
+//!           "time" ".." "remotetime" ".." "syncstate" ".." } ... }
+//! "localtime" is when the file last changed on disk, "remotetime" when Steam Cloud
+//! last had it - a newer localtime means the change hasn't been uploaded.
+
+use std::fs;
+
+use chrono::{DateTime, Utc};
+use overachiever_core::CloudSaveStatus;
+
+/// Steam64 IDs are the account ID plus this offset
+const STEAM64_BASE: u64 = 76561197960265728;
+
+/// Read the cloud save state for a game (None if Steam has no cloud data for it locally)
+pub fn read_cloud_save_status(steam_id: &str, appid: u64) -> Option<CloudSaveStatus> {
+    let account_id = steam_id.parse::<u64>().ok()?.checked_sub(STEAM64_BASE)?;
+    let path = crate::steam_config::get_steam_userdata_path()?
+        .join(account_id.to_string())
+        .join(appid.to_string())
+        .join("remotecache.vdf");
+    let content = fs::read_to_string(path).ok()?;
+    Some(parse_remotecache(&content, appid))
+}
+
+/// Parse remotecache.vdf (simple line-based VDF parsing, like the other Steam file readers)
+fn parse_remotecache(content: &str, appid: u64) -> CloudSaveStatus {
+    let mut status = CloudSaveStatus {
+        appid,
+        file_count: 0,
+        unsynced_files: 0,
+        last_synced: None,
+    };
+
+    let mut depth = 0;
+    let mut local_time: Option<i64> = None;
+    let mut remote_time: Option<i64> = None;
+    for line in content.lines() {
+        let line = line.trim();
+        match line {
+            "{" => {
+                depth += 1;
+                local_time = None;
+                remote_time = None;
+            }
+            "}" => {
+                // Closing a file block (depth 2 = inside "<appid>" { "<file>" { .. } })
+                if depth == 2 {
+                    status.file_count += 1;
+                    if let (Some(local), Some(remote)) = (local_time, remote_time) {
+                        if local > remote {
+                            status.unsynced_files += 1;
+                        }
+                    }
+                    let synced = remote_time.and_then(|t| DateTime::<Utc>::from_timestamp(t, 0));
+                    if synced > status.last_synced {
+                        status.last_synced = synced;
+                    }
+                }
+                depth -= 1;
+            }
+            _ if depth == 2 => {
+                // Key/value line: "localtime"		"1700000000"
+                let parts: Vec<&str> = line.split('"').collect();
+                if parts.len() >= 4 {
+                    match parts[1] {
+                        "localtime" => local_time = parts[3].parse().ok(),
+                        "remotetime" => remote_time = parts[3].parse().ok(),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // A file that was never uploaded has remotetime 0
+    if status.last_synced == DateTime::<Utc>::from_timestamp(0, 0) {
+        status.last_synced = None;
+    }
+    status
+}
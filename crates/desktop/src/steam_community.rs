@@ -0,0 +1,103 @@
+//! Steam Community profile XML import - bootstraps the games list without a Web API key
+//!
+//! Profile games XML: https://steamcommunity.com/profiles/{steamid64}/games?tab=all&xml=1
+//! Returns: <gamesList><steamID64>..</steamID64><games><game><appID>..</appID><name>..</name>
+//!          <hoursOnRecord>1,234.5</hoursOnRecord></game>...</games></gamesList>
+//! Only works for profiles whose game details are public.
+
+use std::collections::HashSet;
+
+use overachiever_core::SteamGame;
+
+const COMMUNITY_PROFILE_URL: &str = "https://steamcommunity.com/profiles";
+
+/// Games parsed from a profile games XML
+#[derive(Debug, Clone)]
+pub struct CommunityGamesList {
+    /// SteamID64 the list belongs to (if present in the XML)
+    pub steam_id: Option<String>,
+    pub games: Vec<SteamGame>,
+}
+
+/// Fetch the games XML for a public profile
+pub fn fetch_games_xml(steam_id: u64) -> Result<String, String> {
+    let url = format!("{}/{}/games?tab=all&xml=1", COMMUNITY_PROFILE_URL, steam_id);
+
+    let client = crate::http::client_builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let response = client
+        .get(&url)
+        .header("User-Agent", "Overachiever/1.0")
+        .send()
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Steam Community returned status: {}", response.status()));
+    }
+
+    response.text().map_err(|e| format!("Failed to read response: {}", e))
+}
+
+/// Parse a profile games XML into games (playtime converted from hours to minutes)
+pub fn parse_games_xml(xml: &str) -> Result<CommunityGamesList, String> {
+    let doc = roxmltree::Document::parse(xml).map_err(|e| format!("Invalid XML: {}", e))?;
+    let root = doc.root_element();
+
+    // Private profiles return <response><error>...</error></response>
+    if let Some(error) = child_text(root, "error") {
+        return Err(format!("Steam Community: {}", error));
+    }
+    if root.tag_name().name() != "gamesList" {
+        return Err(format!("Unexpected XML root <{}>, expected <gamesList>", root.tag_name().name()));
+    }
+
+    let steam_id = child_text(root, "steamID64").map(|s| s.to_string());
+    let games = root
+        .children()
+        .find(|n| n.has_tag_name("games"))
+        .map(|games| {
+            games
+                .children()
+                .filter(|n| n.has_tag_name("game"))
+                .filter_map(|game| {
+                    let appid = child_text(game, "appID")?.parse().ok()?;
+                    let name = child_text(game, "name").unwrap_or_default().to_string();
+                    let hours: f64 = child_text(game, "hoursOnRecord")
+                        .and_then(|h| h.replace(',', "").parse().ok())
+                        .unwrap_or(0.0);
+                    Some(SteamGame {
+                        appid,
+                        name,
+                        playtime_forever: (hours * 60.0).round() as u32,
+                        playtime_windows_forever: None,
+                        playtime_mac_forever: None,
+                        playtime_linux_forever: None,
+                        playtime_deck_forever: None,
+                        rtime_last_played: None,
+                        img_icon_url: None,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(CommunityGamesList { steam_id, games })
+}
+
+/// App IDs on the profile's public games list (private games are left out for other viewers)
+pub fn fetch_public_appids(steam_id: u64) -> Result<HashSet<u64>, String> {
+    let list = fetch_games_xml(steam_id).and_then(|xml| parse_games_xml(&xml))?;
+    Ok(list.games.iter().map(|g| g.appid).collect())
+}
+
+/// Trimmed text of the first child element with the given tag name
+fn child_text<'a>(node: roxmltree::Node<'a, '_>, tag: &str) -> Option<&'a str> {
+    node.children()
+        .find(|n| n.has_tag_name(tag))
+        .and_then(|n| n.text())
+        .map(|t| t.trim())
+        .filter(|t| !t.is_empty())
+}
@@ -17,7 +17,7 @@ fn steam_config_log(msg: &str) {
 }
 
 /// Get Steam's userdata path
-fn get_steam_userdata_path() -> Option<PathBuf> {
+pub(crate) fn get_steam_userdata_path() -> Option<PathBuf> {
     // Try to find Steam installation
     let steam_paths = [
         "C:\\Program Files (x86)\\Steam",
@@ -37,6 +37,23 @@ fn get_steam_userdata_path() -> Option<PathBuf> {
         }
     }
     
+    // Linux and macOS installs live in the home directory
+    #[cfg(not(windows))]
+    if let Some(home) = directories::BaseDirs::new().map(|d| d.home_dir().to_path_buf()) {
+        let home_paths = [
+            home.join(".steam").join("steam"),
+            home.join(".local").join("share").join("Steam"),
+            home.join(".var").join("app").join("com.valvesoftware.Steam").join(".local").join("share").join("Steam"),
+            home.join("Library").join("Application Support").join("Steam"),
+        ];
+        for path in &home_paths {
+            let userdata = path.join("userdata");
+            if userdata.exists() {
+                return Some(userdata);
+            }
+        }
+    }
+
     // Try reading from registry (Windows)
     #[cfg(windows)]
     {
@@ -142,6 +159,43 @@ fn parse_steam_game_lists_from_vdf(content: &str, steam_id: &str) -> SteamGameLi
     lists
 }
 
+/// Path of the user's localconfig.vdf, if Steam is installed on this machine and the user has logged in here
+fn localconfig_path(steam_id: &str) -> Option<PathBuf> {
+    let userdata_path = match get_steam_userdata_path() {
+        Some(path) => {
+            steam_config_log(&format!("Found Steam userdata path: {:?}", path));
+            path
+        },
+        None => {
+            steam_config_log("Could not find Steam userdata directory");
+            return None;
+        }
+    };
+    
+    // Convert Steam64 ID to account ID to find the right folder
+    let steam64_id: u64 = steam_id.parse().unwrap_or(0);
+    if steam64_id < 76561197960265728 {
+        steam_config_log("Invalid Steam ID");
+        return None;
+    }
+    
+    let account_id = steam64_id - 76561197960265728;
+    let path = userdata_path.join(account_id.to_string()).join("config").join("localconfig.vdf");
+    
+    steam_config_log(&format!("Checking: {:?}", path));
+    
+    if !path.exists() {
+        steam_config_log("  File does not exist");
+        return None;
+    }
+    Some(path)
+}
+
+/// Whether hidden/private lists can be read from a local Steam install
+pub fn has_local_steam_config(steam_id: &str) -> bool {
+    localconfig_path(steam_id).is_some()
+}
+
 /// Get lists of hidden and private game AppIDs from Steam's localconfig.vdf
 ///
 /// Steam stores both lists in userdata/<user_id>/config/localconfig.vdf:
@@ -161,34 +215,9 @@ pub fn get_steam_game_lists(steam_id: Option<&str>) -> SteamGameLists {
         }
     };
     
-    let userdata_path = match get_steam_userdata_path() {
-        Some(path) => {
-            steam_config_log(&format!("Found Steam userdata path: {:?}", path));
-            path
-        },
-        None => {
-            steam_config_log("Could not find Steam userdata directory");
-            return empty_lists;
-        }
-    };
-    
-    // Convert Steam64 ID to account ID to find the right folder
-    let steam64_id: u64 = steam_id_str.parse().unwrap_or(0);
-    if steam64_id == 0 {
-        steam_config_log("Invalid Steam ID");
-        return empty_lists;
-    }
-    
-    let account_id = steam64_id - 76561197960265728;
-    let user_folder = userdata_path.join(account_id.to_string());
-    let localconfig_path = user_folder.join("config").join("localconfig.vdf");
-    
-    steam_config_log(&format!("Checking: {:?}", localconfig_path));
-    
-    if !localconfig_path.exists() {
-        steam_config_log("  File does not exist");
+    let Some(localconfig_path) = localconfig_path(steam_id_str) else {
         return empty_lists;
-    }
+    };
     
     // Read VDF file
     let content = match fs::read_to_string(&localconfig_path) {
@@ -253,3 +282,35 @@ pub fn sync_steam_hidden_games(
     
     Ok(count)
 }
+
+/// Update steam_private from the public profile games list (for machines without a local Steam install).
+/// Private games are left out of what other people see, so any library game missing from the
+/// public list is private. Hidden games can't be told apart this way, so steam_hidden is left alone.
+pub fn sync_private_games_from_public_list(
+    conn: &rusqlite::Connection,
+    steam_id: &str,
+    public_appids: &HashSet<u64>,
+) -> rusqlite::Result<usize> {
+    // An empty list means the games list isn't visible at all - marking everything private would be wrong
+    if public_appids.is_empty() {
+        return Ok(0);
+    }
+
+    let mut stmt = conn.prepare("SELECT appid FROM games WHERE steam_id = ?1")?;
+    let library: Vec<i64> = stmt.query_map([steam_id], |row| row.get(0))?.collect::<rusqlite::Result<_>>()?;
+
+    let mut count = 0;
+    for appid in library {
+        let private = !public_appids.contains(&(appid as u64));
+        conn.execute(
+            "UPDATE games SET steam_private = ?1 WHERE steam_id = ?2 AND appid = ?3",
+            (private as i32, steam_id, appid),
+        )?;
+        if private {
+            count += 1;
+        }
+    }
+    steam_config_log(&format!("Marked {} games private from the public profile games list", count));
+
+    Ok(count)
+}
@@ -0,0 +1,335 @@
+//! Steam client stats cache - picks up unlocks from the files the Steam client keeps in
+//! appcache/stats, so they show up within seconds instead of on the next API scan
+//!
+//! `UserGameStatsSchema_<appid>.bin` maps each achievement to a bit of a stat, and
+//! `UserGameStats_<accountid>_<appid>.bin` holds those bits plus the unlock times.
+//! Both are binary KeyValues. The format is undocumented, so anything unexpected
+//! just means no local unlocks for that game.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::steam_config::get_steam_userdata_path;
+
+/// How often the stats folder is checked for changed files
+const POLL_INTERVAL: Duration = Duration::from_secs(3);
+/// Steam64 ID of account 0
+const STEAM64_BASE: u64 = 76561197960265728;
+
+/// An unlocked achievement as recorded by the Steam client
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalUnlock {
+    pub apiname: String,
+    /// Unix time, 0 when the client didn't record one
+    pub unlocktime: i64,
+}
+
+/// Unlocks read from a game's stats file after it changed
+pub struct LocalStatsChange {
+    pub appid: u64,
+    pub unlocks: Vec<LocalUnlock>,
+}
+
+/// Running watcher over the stats folder; stops when dropped
+pub struct LocalStatsWatcher {
+    /// Steam ID the watcher reads files for (restart when it changes)
+    pub steam_id: String,
+    receiver: Receiver<LocalStatsChange>,
+    stop: Arc<AtomicBool>,
+}
+
+impl LocalStatsWatcher {
+    /// Start watching in a background thread. Files present now are taken as the baseline,
+    /// only later writes are reported.
+    pub fn start(steam_id: &str) -> Result<Self, String> {
+        let account_id = steam_id
+            .parse::<u64>()
+            .ok()
+            .and_then(|id| id.checked_sub(STEAM64_BASE))
+            .ok_or("Invalid Steam ID")?;
+        let dir = stats_dir().ok_or("Steam's stats cache was not found on this computer")?;
+
+        let (tx, rx) = channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+        thread::spawn(move || {
+            let prefix = format!("UserGameStats_{}_", account_id);
+            let mut seen = modified_stats_files(&dir, &prefix);
+            while !thread_stop.load(Ordering::Relaxed) {
+                thread::sleep(POLL_INTERVAL);
+                for (appid, modified) in modified_stats_files(&dir, &prefix) {
+                    if seen.insert(appid, modified) == Some(modified) {
+                        continue;
+                    }
+                    let Some(unlocks) = read_local_unlocks(&dir, account_id, appid) else {
+                        continue;
+                    };
+                    if tx.send(LocalStatsChange { appid, unlocks }).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+
+        Ok(Self { steam_id: steam_id.to_string(), receiver: rx, stop })
+    }
+
+    /// Changes reported since the last call
+    pub fn poll(&self) -> Vec<LocalStatsChange> {
+        self.receiver.try_iter().collect()
+    }
+}
+
+impl Drop for LocalStatsWatcher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// `<steam>/appcache/stats`, next to the userdata folder
+fn stats_dir() -> Option<PathBuf> {
+    let dir = get_steam_userdata_path()?.parent()?.join("appcache").join("stats");
+    dir.is_dir().then_some(dir)
+}
+
+/// Modification times of the account's stats files, by appid
+fn modified_stats_files(dir: &Path, prefix: &str) -> HashMap<u64, SystemTime> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return HashMap::new();
+    };
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name();
+            let appid = name.to_str()?.strip_prefix(prefix)?.strip_suffix(".bin")?.parse().ok()?;
+            Some((appid, entry.metadata().ok()?.modified().ok()?))
+        })
+        .collect()
+}
+
+/// Achievements the client has marked unlocked for a game
+fn read_local_unlocks(dir: &Path, account_id: u64, appid: u64) -> Option<Vec<LocalUnlock>> {
+    let schema = std::fs::read(dir.join(format!("UserGameStatsSchema_{}.bin", appid))).ok()?;
+    let stats = std::fs::read(dir.join(format!("UserGameStats_{}_{}.bin", account_id, appid))).ok()?;
+    let names = achievement_bits(&parse_binary_kv(&schema)?);
+    let unlocks = unlocked_bits(&parse_binary_kv(&stats)?)
+        .into_iter()
+        .filter_map(|(stat, bit, unlocktime)| {
+            let apiname = names.get(&(stat, bit))?.clone();
+            Some(LocalUnlock { apiname, unlocktime })
+        })
+        .collect();
+    Some(unlocks)
+}
+
+/// Achievement api names by (stat id, bit) from a schema file:
+/// `<appid> { stats { <stat> { bits { <n> { name, bit } } } } }`
+fn achievement_bits(schema: &[(String, KvValue)]) -> HashMap<(String, u32), String> {
+    let mut names = HashMap::new();
+    let Some((_, root)) = schema.first() else {
+        return names;
+    };
+    for (stat, entry) in root.get("stats").map(KvValue::entries).unwrap_or_default() {
+        for (key, bit) in entry.get("bits").map(KvValue::entries).unwrap_or_default() {
+            let Some(name) = bit.get("name").and_then(KvValue::as_str) else {
+                continue;
+            };
+            let index = bit.get("bit").and_then(KvValue::as_int).or_else(|| key.parse().ok());
+            if let Some(index) = index.and_then(|i| u32::try_from(i).ok()).filter(|i| *i < 32) {
+                names.insert((stat.clone(), index), name.to_string());
+            }
+        }
+    }
+    names
+}
+
+/// Set achievement bits with their unlock times from a user stats file:
+/// `cache { <stat> { data, AchievementTimes { <bit> } } }`
+fn unlocked_bits(stats: &[(String, KvValue)]) -> Vec<(String, u32, i64)> {
+    let Some(cache) = stats.iter().find(|(key, _)| key == "cache").map(|(_, v)| v) else {
+        return Vec::new();
+    };
+    let mut bits = Vec::new();
+    for (stat, entry) in cache.entries() {
+        let Some(data) = entry.get("data").and_then(KvValue::as_int) else {
+            continue;
+        };
+        let times = entry.get("AchievementTimes");
+        for bit in (0..32).filter(|bit| data as u32 & (1 << bit) != 0) {
+            let unlocktime = times.and_then(|t| t.get(&bit.to_string())).and_then(KvValue::as_int).unwrap_or(0);
+            bits.push((stat.clone(), bit, unlocktime));
+        }
+    }
+    bits
+}
+
+/// A binary KeyValues value
+#[derive(Debug)]
+enum KvValue {
+    Table(Vec<(String, KvValue)>),
+    Str(String),
+    Int(i64),
+    Float(f32),
+}
+
+impl KvValue {
+    fn get(&self, key: &str) -> Option<&KvValue> {
+        match self {
+            KvValue::Table(entries) => entries.iter().find(|(k, _)| k.eq_ignore_ascii_case(key)).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn entries(&self) -> &[(String, KvValue)] {
+        match self {
+            KvValue::Table(entries) => entries,
+            _ => &[],
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            KvValue::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Integers are sometimes written as strings
+    fn as_int(&self) -> Option<i64> {
+        match self {
+            KvValue::Int(i) => Some(*i),
+            KvValue::Float(f) => Some(*f as i64),
+            KvValue::Str(s) => s.parse().ok(),
+            KvValue::Table(_) => None,
+        }
+    }
+}
+
+/// Parse a binary KeyValues file into its top-level entries
+fn parse_binary_kv(data: &[u8]) -> Option<Vec<(String, KvValue)>> {
+    KvReader { data, pos: 0 }.table(true)
+}
+
+struct KvReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl KvReader<'_> {
+    fn take(&mut self, len: usize) -> Option<&[u8]> {
+        let bytes = self.data.get(self.pos..self.pos.checked_add(len)?)?;
+        self.pos += len;
+        Some(bytes)
+    }
+
+    fn byte(&mut self) -> Option<u8> {
+        self.take(1).map(|b| b[0])
+    }
+
+    fn string(&mut self) -> Option<String> {
+        let len = self.data.get(self.pos..)?.iter().position(|b| *b == 0)?;
+        let s = String::from_utf8_lossy(self.take(len)?).into_owned();
+        self.pos += 1;
+        Some(s)
+    }
+
+    fn array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.take(N)?.try_into().ok()
+    }
+
+    /// Entries up to the end marker (or the end of the data for the top level)
+    fn table(&mut self, top_level: bool) -> Option<Vec<(String, KvValue)>> {
+        let mut entries = Vec::new();
+        loop {
+            let kind = match self.byte() {
+                Some(0x08) => return Some(entries),
+                Some(kind) => kind,
+                None if top_level => return Some(entries),
+                None => return None,
+            };
+            let key = self.string()?;
+            let value = match kind {
+                0x00 => KvValue::Table(self.table(false)?),
+                0x01 => KvValue::Str(self.string()?),
+                // int32, pointer, color
+                0x02 | 0x04 | 0x06 => KvValue::Int(i32::from_le_bytes(self.array()?) as i64),
+                0x03 => KvValue::Float(f32::from_le_bytes(self.array()?)),
+                0x07 => KvValue::Int(u64::from_le_bytes(self.array()?) as i64),
+                0x0A => KvValue::Int(i64::from_le_bytes(self.array()?)),
+                _ => return None,
+            };
+            entries.push((key, value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(out: &mut Vec<u8>, kind: u8, name: &str) {
+        out.push(kind);
+        out.extend_from_slice(name.as_bytes());
+        out.push(0);
+    }
+
+    fn string(out: &mut Vec<u8>, name: &str, value: &str) {
+        key(out, 0x01, name);
+        out.extend_from_slice(value.as_bytes());
+        out.push(0);
+    }
+
+    fn int(out: &mut Vec<u8>, name: &str, value: i32) {
+        key(out, 0x02, name);
+        out.extend_from_slice(&value.to_le_bytes());
+    }
+
+    #[test]
+    fn maps_set_bits_to_api_names() {
+        // 440 { stats { 1 { type "4" bits { 0 { name WIN bit 0 } 3 { name LOSE bit 3 } } } } }
+        let mut schema = Vec::new();
+        key(&mut schema, 0x00, "440");
+        key(&mut schema, 0x00, "stats");
+        key(&mut schema, 0x00, "1");
+        string(&mut schema, "type", "4");
+        key(&mut schema, 0x00, "bits");
+        key(&mut schema, 0x00, "0");
+        string(&mut schema, "name", "WIN");
+        int(&mut schema, "bit", 0);
+        schema.push(0x08);
+        key(&mut schema, 0x00, "3");
+        string(&mut schema, "name", "LOSE");
+        int(&mut schema, "bit", 3);
+        schema.extend_from_slice(&[0x08; 5]);
+
+        // cache { crc 7 1 { data 0b1001 AchievementTimes { 3 1700000000 } } }
+        let mut stats = Vec::new();
+        key(&mut stats, 0x00, "cache");
+        int(&mut stats, "crc", 7);
+        key(&mut stats, 0x00, "1");
+        int(&mut stats, "data", 0b1001);
+        key(&mut stats, 0x00, "AchievementTimes");
+        int(&mut stats, "3", 1_700_000_000);
+        stats.extend_from_slice(&[0x08; 4]);
+
+        let names = achievement_bits(&parse_binary_kv(&schema).unwrap());
+        assert_eq!(names.get(&("1".to_string(), 3)).map(String::as_str), Some("LOSE"));
+
+        let bits = unlocked_bits(&parse_binary_kv(&stats).unwrap());
+        assert_eq!(bits, vec![("1".to_string(), 0, 0), ("1".to_string(), 3, 1_700_000_000)]);
+    }
+
+    #[test]
+    fn rejects_truncated_data() {
+        let mut data = Vec::new();
+        key(&mut data, 0x00, "cache");
+        int(&mut data, "data", 1);
+        data.truncate(data.len() - 2);
+        assert!(parse_binary_kv(&data).is_none());
+    }
+}
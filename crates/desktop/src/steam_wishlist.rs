@@ -0,0 +1,128 @@
+//! Steam wishlist import
+//!
+//! Wishlist:  IWishlistService/GetWishlist/v1?steamid=..
+//!            -> {"response":{"items":[{"appid":..,"priority":..,"date_added":..}]}}
+//! Names:     IStoreBrowseService/GetItems/v1?input_json={"ids":[{"appid":..}],"context":{..}}
+//!            -> {"response":{"store_items":[{"appid":..,"name":".."}]}}
+//! Only works for profiles whose wishlist is public (or with the owner's API key).
+
+use std::collections::HashMap;
+
+use overachiever_core::WishlistItem;
+
+use crate::http::steam_api_url;
+
+const API_WISHLIST: &str = "/IWishlistService/GetWishlist/v1/";
+const API_STORE_ITEMS: &str = "/IStoreBrowseService/GetItems/v1/";
+
+/// Store item lookups per request
+const STORE_ITEMS_CHUNK: usize = 100;
+
+fn client() -> Result<reqwest::blocking::Client, String> {
+    crate::http::client_builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))
+}
+
+fn get_json(client: &reqwest::blocking::Client, url: &str) -> Result<serde_json::Value, String> {
+    let response = client
+        .get(url)
+        .header("User-Agent", "Overachiever/1.0")
+        .send()
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Steam returned status: {}", response.status()));
+    }
+
+    response.json().map_err(|e| format!("Failed to parse response: {}", e))
+}
+
+/// Fetch a user's wishlist with game names, in wishlist order
+pub fn fetch_wishlist(steam_key: &str, steam_id: u64) -> Result<Vec<WishlistItem>, String> {
+    let client = client()?;
+
+    let url = format!("{}?key={}&steamid={}", steam_api_url(API_WISHLIST), steam_key, steam_id);
+    let body = get_json(&client, &url)?;
+    let mut items: Vec<WishlistItem> = body["response"]["items"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|item| {
+                    Some(WishlistItem {
+                        appid: item["appid"].as_u64()?,
+                        name: String::new(),
+                        priority: item["priority"].as_u64().unwrap_or(0) as u32,
+                        date_added: item["date_added"]
+                            .as_i64()
+                            .and_then(|t| chrono::DateTime::from_timestamp(t, 0)),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let appids: Vec<u64> = items.iter().map(|i| i.appid).collect();
+    let names = fetch_store_names(&client, &appids)?;
+    for item in &mut items {
+        item.name = names.get(&item.appid).cloned().unwrap_or_else(|| format!("App {}", item.appid));
+    }
+
+    // Priority 0 means "unranked" - keep those after the ranked ones
+    items.sort_by_key(|i| (i.priority == 0, i.priority, i.appid));
+    Ok(items)
+}
+
+/// Look up store names for a list of apps
+fn fetch_store_names(client: &reqwest::blocking::Client, appids: &[u64]) -> Result<HashMap<u64, String>, String> {
+    let mut names = HashMap::new();
+    for chunk in appids.chunks(STORE_ITEMS_CHUNK) {
+        let input = serde_json::json!({
+            "ids": chunk.iter().map(|appid| serde_json::json!({ "appid": appid })).collect::<Vec<_>>(),
+            "context": { "language": "english", "country_code": "US" },
+        });
+        let url = format!("{}?input_json={}", steam_api_url(API_STORE_ITEMS), urlencoding::encode(&input.to_string()));
+        let body = get_json(client, &url)?;
+        if let Some(store_items) = body["response"]["store_items"].as_array() {
+            for item in store_items {
+                if let (Some(appid), Some(name)) = (item["appid"].as_u64(), item["name"].as_str()) {
+                    names.insert(appid, name.to_string());
+                }
+            }
+        }
+    }
+    Ok(names)
+}
+
+/// Wishlist plus everything the server knows about the wishlisted games
+pub struct WishlistDetails {
+    pub items: Vec<WishlistItem>,
+    pub ttb: Vec<overachiever_core::TtbTimes>,
+    pub tags: Vec<overachiever_core::GameTag>,
+    pub completion: Vec<overachiever_core::GameCompletionStats>,
+}
+
+/// Fetch the wishlist and look up TTB, tags and community completion for it.
+/// Server lookups are best effort - the wishlist is still returned if they fail.
+pub fn fetch_wishlist_details(steam_key: &str, steam_id: u64) -> Result<WishlistDetails, String> {
+    let items = fetch_wishlist(steam_key, steam_id)?;
+    let appids: Vec<u64> = items.iter().map(|i| i.appid).collect();
+
+    let mut details = WishlistDetails { items, ttb: Vec::new(), tags: Vec::new(), completion: Vec::new() };
+    for chunk in appids.chunks(500) {
+        match crate::cloud_sync::fetch_ttb_batch(chunk) {
+            Ok(ttb) => details.ttb.extend(ttb),
+            Err(e) => eprintln!("Failed to load wishlist TTB: {}", e),
+        }
+        match crate::cloud_sync::fetch_tags_batch(chunk) {
+            Ok(tags) => details.tags.extend(tags),
+            Err(e) => eprintln!("Failed to load wishlist tags: {}", e),
+        }
+        match crate::cloud_sync::fetch_completion_batch(chunk) {
+            Ok(stats) => details.completion.extend(stats),
+            Err(e) => eprintln!("Failed to load wishlist completion stats: {}", e),
+        }
+    }
+    Ok(details)
+}
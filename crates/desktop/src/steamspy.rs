@@ -21,7 +21,7 @@ pub struct SteamSpyResponse {
 pub fn fetch_tags(appid: u64) -> Result<Vec<(String, u32)>, String> {
     let url = format!("{}?request=appdetails&appid={}", STEAMSPY_API_URL, appid);
 
-    let client = reqwest::blocking::Client::builder()
+    let client = crate::http::client_builder()
         .timeout(std::time::Duration::from_secs(30))
         .build()
         .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
@@ -0,0 +1,244 @@
+//! Game name normalization for HowLongToBeat searches
+//!
+//! Steam titles often differ from HLTB's: trademark symbols, edition suffixes
+//! ("Game of the Year Edition"), roman vs. arabic sequel numbers. The search tries
+//! the cleaned name first and falls back to alternate titles.
+
+/// Edition suffixes HLTB lists under the base game (compared word by word, case-insensitive)
+const EDITION_SUFFIXES: &[&str] = &[
+    "digital deluxe edition",
+    "game of the year enhanced",
+    "game of the year edition",
+    "game of the year",
+    "goty edition",
+    "goty",
+    "definitive edition",
+    "complete edition",
+    "ultimate edition",
+    "deluxe edition",
+    "gold edition",
+    "enhanced edition",
+    "anniversary edition",
+    "legendary edition",
+    "standard edition",
+    "prepare to die edition",
+];
+
+/// Steam titles HLTB knows under another name (keys are cleaned, edition-stripped and lowercase)
+const TITLE_ALIASES: &[(&str, &str)] = &[
+    ("final fantasy vii remake intergrade", "Final Fantasy VII Remake"),
+    ("grand theft auto v enhanced", "Grand Theft Auto V"),
+    ("grand theft auto v legacy", "Grand Theft Auto V"),
+    ("the elder scrolls v skyrim special edition", "The Elder Scrolls V Skyrim"),
+    ("counter strike global offensive", "Counter-Strike Global Offensive"),
+];
+
+/// Roman numerals for sequel numbers, index + 1 is the value ("I" alone is too ambiguous)
+const ROMAN_NUMERALS: &[&str] = &[
+    "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X",
+    "XI", "XII", "XIII", "XIV", "XV", "XVI", "XVII", "XVIII", "XIX", "XX",
+];
+
+/// Clean a game name for HLTB search:
+/// - Remove apostrophe+s (e.g., "Devil's Kiss" → "Devil Kiss")
+/// - Remove periods and digit grouping ("S.T.A.L.K.E.R." → "STALKER", "40,000" → "40000")
+/// - Replace dashes, colons, trademark signs and other symbols with spaces (keep letters
+///   and digits of any script, like Japanese/Korean)
+/// - Strip edition suffixes ("Definitive Edition", "GOTY", ...)
+/// - Normalize multiple spaces to single space
+pub fn clean_game_name_for_search(name: &str) -> String {
+    strip_editions(&clean_symbols(name))
+}
+
+/// Queries to try in order until HLTB finds the game: a known alias, the cleaned name,
+/// the name with its edition, and the sequel number written the other way
+pub fn search_queries(name: &str) -> Vec<String> {
+    let with_edition = clean_symbols(name);
+    let cleaned = strip_editions(&with_edition);
+
+    let mut queries = Vec::new();
+    if let Some(alias) = alias_for(&cleaned).or_else(|| alias_for(&with_edition)) {
+        queries.push(alias.to_string());
+    }
+    queries.push(cleaned.clone());
+    queries.push(with_edition);
+    if let Some(swapped) = swap_numerals(&cleaned) {
+        queries.push(swapped);
+    }
+
+    let mut unique: Vec<String> = Vec::new();
+    for query in queries {
+        if !query.is_empty() && !unique.iter().any(|q| q.eq_ignore_ascii_case(&query)) {
+            unique.push(query);
+        }
+    }
+    unique
+}
+
+/// Whether two titles name the same game once symbols, editions, case and the way
+/// the sequel number is written are ignored
+pub fn names_match(a: &str, b: &str) -> bool {
+    let key = |name: &str| {
+        let cleaned = clean_game_name_for_search(name);
+        let arabic = roman_to_arabic(&cleaned).unwrap_or(cleaned);
+        arabic.to_lowercase()
+    };
+    key(a) == key(b)
+}
+
+fn clean_symbols(name: &str) -> String {
+    let without_apostrophe_s = name.replace("'s", "").replace('\u{2019}', "'").replace("'s", "");
+
+    let chars: Vec<char> = without_apostrophe_s.chars().collect();
+    let mut cleaned = String::with_capacity(without_apostrophe_s.len());
+    for (i, &c) in chars.iter().enumerate() {
+        let between_digits = i > 0
+            && chars[i - 1].is_ascii_digit()
+            && chars.get(i + 1).is_some_and(|n| n.is_ascii_digit());
+        if c == '.' || (c == ',' && between_digits) {
+            continue;
+        }
+        // Letters and digits of any script stay, every symbol becomes a word break
+        if c.is_alphanumeric() || c == ' ' {
+            cleaned.push(c);
+        } else {
+            cleaned.push(' ');
+        }
+    }
+
+    cleaned.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Remove trailing edition suffixes (repeatedly, for "... Definitive Edition GOTY"),
+/// never leaving an empty name
+fn strip_editions(cleaned: &str) -> String {
+    let mut words: Vec<&str> = cleaned.split(' ').collect();
+    'strip: loop {
+        for suffix in EDITION_SUFFIXES {
+            let suffix_words: Vec<&str> = suffix.split(' ').collect();
+            if words.len() > suffix_words.len()
+                && words[words.len() - suffix_words.len()..]
+                    .iter()
+                    .zip(&suffix_words)
+                    .all(|(w, s)| w.eq_ignore_ascii_case(s))
+            {
+                words.truncate(words.len() - suffix_words.len());
+                continue 'strip;
+            }
+        }
+        break;
+    }
+    words.join(" ")
+}
+
+fn alias_for(cleaned: &str) -> Option<&'static str> {
+    let key = cleaned.to_lowercase();
+    TITLE_ALIASES.iter().find(|(from, _)| *from == key).map(|(_, to)| *to)
+}
+
+/// The name with roman sequel numbers written as digits, or digits as roman numerals
+/// when there were none
+fn swap_numerals(cleaned: &str) -> Option<String> {
+    roman_to_arabic(cleaned).or_else(|| arabic_to_roman(cleaned))
+}
+
+fn roman_to_arabic(cleaned: &str) -> Option<String> {
+    let mut changed = false;
+    let words: Vec<String> = cleaned
+        .split(' ')
+        .map(|word| match ROMAN_NUMERALS.iter().skip(1).position(|r| *r == word) {
+            Some(i) => {
+                changed = true;
+                (i + 2).to_string()
+            }
+            None => word.to_string(),
+        })
+        .collect();
+    changed.then(|| words.join(" "))
+}
+
+fn arabic_to_roman(cleaned: &str) -> Option<String> {
+    let mut changed = false;
+    let words: Vec<String> = cleaned
+        .split(' ')
+        .map(|word| match word.parse::<usize>() {
+            Ok(n) if (2..=ROMAN_NUMERALS.len()).contains(&n) && !word.starts_with('0') => {
+                changed = true;
+                ROMAN_NUMERALS[n - 1].to_string()
+            }
+            _ => word.to_string(),
+        })
+        .collect();
+    changed.then(|| words.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Steam titles that missed on HLTB, and the query that finds them
+    const CORPUS: &[(&str, &str)] = &[
+        ("The Witcher 3: Wild Hunt - Game of the Year Edition", "The Witcher 3 Wild Hunt"),
+        ("DARK SOULS™ III", "DARK SOULS III"),
+        ("Batman™: Arkham Knight", "Batman Arkham Knight"),
+        ("Middle-earth™: Shadow of War™ Definitive Edition", "Middle earth Shadow of War"),
+        ("Sid Meier's Civilization® VI", "Sid Meier Civilization VI"),
+        ("Sekiro™: Shadows Die Twice - GOTY Edition", "Sekiro Shadows Die Twice"),
+        ("Sekiro™: Shadows Die Twice – GOTY Edition", "Sekiro Shadows Die Twice"),
+        ("Borderlands Game of the Year Enhanced", "Borderlands"),
+        ("Ori and the Blind Forest: Definitive Edition", "Ori and the Blind Forest"),
+        ("DARK SOULS™: Prepare To Die™ Edition", "DARK SOULS"),
+        ("S.T.A.L.K.E.R.: Shadow of Chernobyl", "STALKER Shadow of Chernobyl"),
+        ("Warhammer 40,000: Space Marine", "Warhammer 40000 Space Marine"),
+        ("Tom Clancy’s Splinter Cell Blacklist", "Tom Clancy Splinter Cell Blacklist"),
+        ("Plants vs. Zombies GOTY Edition", "Plants vs Zombies"),
+        ("Mass Effect™ Legendary Edition", "Mass Effect"),
+        ("ペルソナ５ ザ・ロイヤル", "ペルソナ５ ザ ロイヤル"),
+    ];
+
+    #[test]
+    fn cleans_corpus_names() {
+        for (name, expected) in CORPUS {
+            assert_eq!(clean_game_name_for_search(name), *expected, "cleaning {:?}", name);
+        }
+    }
+
+    #[test]
+    fn keeps_name_that_is_only_an_edition() {
+        assert_eq!(clean_game_name_for_search("GOTY"), "GOTY");
+        assert_eq!(clean_game_name_for_search("Definitive Edition"), "Definitive Edition");
+    }
+
+    #[test]
+    fn falls_back_to_edition_and_numerals() {
+        assert_eq!(
+            search_queries("Dark Souls II: Scholar of the First Sin"),
+            vec!["Dark Souls II Scholar of the First Sin", "Dark Souls 2 Scholar of the First Sin"]
+        );
+        assert_eq!(
+            search_queries("Final Fantasy 7 Complete Edition"),
+            vec!["Final Fantasy 7", "Final Fantasy 7 Complete Edition", "Final Fantasy VII"]
+        );
+    }
+
+    #[test]
+    fn tries_alias_first() {
+        let queries = search_queries("FINAL FANTASY VII REMAKE INTERGRADE");
+        assert_eq!(queries[0], "Final Fantasy VII Remake");
+        assert_eq!(search_queries("The Elder Scrolls V: Skyrim Special Edition")[0], "The Elder Scrolls V Skyrim");
+    }
+
+    #[test]
+    fn leaves_plain_names_alone() {
+        assert_eq!(search_queries("Hades"), vec!["Hades"]);
+        assert_eq!(search_queries("Left 4 Dead"), vec!["Left 4 Dead", "Left IV Dead"]);
+    }
+
+    #[test]
+    fn matches_titles_across_spellings() {
+        assert!(names_match("DARK SOULS™ III", "Dark Souls 3"));
+        assert!(names_match("Ori and the Blind Forest: Definitive Edition", "Ori and the Blind Forest"));
+        assert!(!names_match("Dark Souls II", "Dark Souls III"));
+        assert!(!names_match("Doom", "Doom Eternal"));
+    }
+}
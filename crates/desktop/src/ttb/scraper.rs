@@ -1,5 +1,6 @@
 //! HLTB scraper - fetches time-to-beat data from HowLongToBeat using headless Chrome
 
+use super::{names_match, search_queries};
 use overachiever_core::TtbTimes;
 use chrono::Utc;
 use headless_chrome::{Browser, LaunchOptions};
@@ -322,14 +323,31 @@ pub fn search_game(name: &str) -> Result<Vec<HltbResult>, TtbError> {
 
 
 /// Find best matching game from search results
-pub fn find_best_match(_game_name: &str, results: &[HltbResult]) -> Option<HltbResult> {
-    // HLTB search orders results by relevance, so just use the first one
-    results.first().cloned()
+pub fn find_best_match(game_name: &str, results: &[HltbResult]) -> Option<HltbResult> {
+    // HLTB search orders results by relevance, so use the first one unless another has the same title
+    results
+        .iter()
+        .find(|r| names_match(game_name, &r.name))
+        .or_else(|| results.first())
+        .cloned()
 }
 
-/// Fetch TTB times for a game by name
+/// Fetch TTB times for a game by name, trying alternate titles (see `search_queries`)
+/// until HLTB finds it
 pub fn fetch_ttb_times(appid: u64, game_name: &str) -> Result<TtbTimes, TtbError> {
-    fetch_ttb_times_with_query(appid, game_name, game_name)
+    let mut last_error = TtbError::NotFound;
+    for query in search_queries(game_name) {
+        match fetch_ttb_times_with_query(appid, game_name, &query) {
+            Ok(times) => return Ok(times),
+            // Another query won't help when the browser itself fails
+            Err(e @ TtbError::Browser(_)) => return Err(e),
+            Err(e) => {
+                ttb_log(&format!("No match for query '{}': {}", query, e));
+                last_error = e;
+            }
+        }
+    }
+    Err(last_error)
 }
 
 /// Fetch TTB times using a custom search query
@@ -61,7 +61,7 @@ impl AppState {
 }
 
 // Re-export shared types from core
-pub use overachiever_core::{SortColumn, SortOrder, TriFilter};
+pub use overachiever_core::{AchievementSort, RangeFilter, SortColumn, SortOrder, SortTieBreaker, TriFilter};
 
 /// Progress messages for TTB scan (reserved for future async implementation)
 #[allow(dead_code)]
@@ -4,7 +4,7 @@ use eframe::egui;
 use overachiever_core::{
     Game, GameAchievement, UserProfile, RunHistory, AchievementHistory, 
     LogEntry, GdprConsent, SidebarPanel, SortColumn, SortOrder, TriFilter,
-    TtbTimes, sort_games,
+    RangeFilter, AchievementSort, GroupBy, TtbTimes, ProfileCustomization, AchievementDifficulty, TimeDisplaySettings, sort_games, SortTieBreaker, TagMatchMode, game_row_checksum,
 };
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
@@ -14,6 +14,7 @@ use crate::ws_client::WsClient;
 use crate::storage::{
     get_token_from_url, get_token_from_storage, save_token_to_storage, clear_token_from_storage,
     get_ws_url_from_location, get_gdpr_consent_from_storage, get_short_id_from_url,
+    get_time_display_from_storage,
 };
 use crate::http_client::BuildInfo;
 
@@ -21,12 +22,15 @@ use crate::http_client::BuildInfo;
 // Types
 // ============================================================================
 
+/// Finished difficulty curve fetches waiting to be moved into the cache
+pub type PendingDifficulty = Rc<RefCell<Vec<(u64, Vec<AchievementDifficulty>)>>>;
+
 #[derive(Clone, PartialEq)]
 pub enum ConnectionState {
     Disconnected,
     Connecting,
     Connected,
-    Authenticated(UserProfile),
+    Authenticated(Box<UserProfile>),
     Error(String),
 }
 
@@ -66,21 +70,31 @@ pub struct WasmApp {
     pub(crate) sort_column: SortColumn,
     pub(crate) sort_order: SortOrder,
     pub(crate) expanded_rows: HashSet<u64>,
+    pub(crate) achievement_sort: AchievementSort,
+    pub(crate) reveal_spoilers: bool,
+    pub(crate) group_by: GroupBy,
     pub(crate) achievements_cache: HashMap<u64, Vec<GameAchievement>>,
     pub(crate) user_achievement_ratings: HashMap<(u64, String), u8>, // (appid, apiname) -> rating
     pub(crate) pending_ratings: Rc<RefCell<Option<Vec<(u64, String, u8)>>>>, // Incoming ratings from server
     pub(crate) filter_name: String,
     pub(crate) filter_achievements: TriFilter,
     pub(crate) filter_playtime: TriFilter,
+    pub(crate) filter_playtime_range: RangeFilter,
     pub(crate) filter_ttb: TriFilter,
+    pub(crate) filter_ttb_range: RangeFilter,
     pub(crate) filter_hidden: TriFilter,
     pub(crate) filter_tags: Vec<String>,
+    pub(crate) filter_excluded_tags: Vec<String>,
+    pub(crate) tag_match_mode: TagMatchMode,
     pub(crate) tag_search_input: String,
+    pub(crate) tag_search_selected_index: Option<usize>,
     pub(crate) available_tags: Vec<String>,
     pub(crate) game_tags_cache: HashMap<u64, HashMap<String, u32>>, // appid -> (tag_name -> vote_count)
     pub(crate) show_login: bool,
     pub(crate) include_unplayed_in_avg: bool,
     pub(crate) show_stats_panel: bool,
+    /// Central panel shows the stats dashboard instead of the games table
+    pub(crate) show_stats_dashboard: bool,
     pub(crate) show_profile_menu: bool,
     pub(crate) sidebar_panel: SidebarPanel,
     pub(crate) games_graph_tab: usize,
@@ -123,9 +137,32 @@ pub struct WasmApp {
     // Pending tags data (from async fetch)
     pub(crate) pending_available_tags: Option<std::rc::Rc<std::cell::RefCell<Option<Vec<String>>>>>,
     pub(crate) pending_game_tags: Option<std::rc::Rc<std::cell::RefCell<Vec<overachiever_core::GameTag>>>>,
+    pub(crate) pending_tag_map: Option<std::rc::Rc<std::cell::RefCell<Option<overachiever_core::TagMap>>>>,
+    
+    // Tag aliases/parents from the backend (applied to available tags and the game tags cache)
+    pub(crate) tag_map: overachiever_core::TagMap,
     
     // List of all users (for display on login screen)
     pub(crate) all_users: Rc<RefCell<Vec<UserProfile>>>,
+    
+    // Offline cache (IndexedDB): scope key, restored data, and achievements shown until refetched
+    pub(crate) cache_scope: String,
+    pub(crate) pending_offline_cache: Rc<RefCell<Option<crate::idb_cache::CachedLibrary>>>,
+    pub(crate) offline_achievements: HashMap<u64, Vec<GameAchievement>>,
+    pub(crate) showing_cached_library: bool,
+    
+    // Profile customization: draft edited in the profile menu, pending save result, last save message
+    pub(crate) profile_draft: Option<ProfileCustomization>,
+    pub(crate) pending_profile_save: Rc<RefCell<Option<Result<ProfileCustomization, String>>>>,
+    pub(crate) profile_save_status: Option<String>,
+    
+    // Community difficulty curves per game, games already requested, and finished fetches
+    pub(crate) difficulty_curves: HashMap<u64, Vec<AchievementDifficulty>>,
+    pub(crate) difficulty_requested: HashSet<u64>,
+    pub(crate) pending_difficulty: PendingDifficulty,
+    
+    // Time zone and timestamp style preferences (persisted in localStorage)
+    pub(crate) time_display: TimeDisplaySettings,
 }
 
 impl WasmApp {
@@ -159,6 +196,8 @@ impl WasmApp {
             "Connecting...".to_string()
         };
         
+        let cache_scope = crate::idb_cache::cache_scope(viewing_short_id.as_deref());
+        
         let mut app = Self {
             server_url,
             ws_client: None,
@@ -173,21 +212,30 @@ impl WasmApp {
             sort_column: SortColumn::Name,
             sort_order: SortOrder::Ascending,
             expanded_rows: HashSet::new(),
+            achievement_sort: AchievementSort::default(),
+            reveal_spoilers: false,
+            group_by: GroupBy::default(),
             achievements_cache: HashMap::new(),
             user_achievement_ratings: HashMap::new(),
             pending_ratings: Rc::new(RefCell::new(None)),
             filter_name: String::new(),
             filter_achievements: TriFilter::All,
             filter_playtime: TriFilter::All,
+            filter_playtime_range: RangeFilter::default(),
             filter_ttb: TriFilter::All,
+            filter_ttb_range: RangeFilter::default(),
             filter_hidden: TriFilter::Without,  // Default: hide hidden games
             filter_tags: Vec::new(),
+            filter_excluded_tags: Vec::new(),
+            tag_match_mode: TagMatchMode::All,
             tag_search_input: String::new(),
+            tag_search_selected_index: None,
             available_tags: Vec::new(),
             game_tags_cache: HashMap::new(),
             show_login: false,
             include_unplayed_in_avg: false,
             show_stats_panel,
+            show_stats_dashboard: false,
             show_profile_menu: false,
             sidebar_panel: SidebarPanel::Stats,
             games_graph_tab: 0,
@@ -205,17 +253,34 @@ impl WasmApp {
             ttb_cache: HashMap::new(),
             pending_ttb_cache: None,
             pending_available_tags: None,
+            pending_tag_map: None,
+            tag_map: overachiever_core::TagMap::default(),
             pending_game_tags: None,
             all_users: Rc::new(RefCell::new(Vec::new())),
+            cache_scope,
+            pending_offline_cache: Rc::new(RefCell::new(None)),
+            offline_achievements: HashMap::new(),
+            showing_cached_library: false,
+            profile_draft: None,
+            pending_profile_save: Rc::new(RefCell::new(None)),
+            profile_save_status: None,
+            difficulty_curves: HashMap::new(),
+            difficulty_requested: HashSet::new(),
+            pending_difficulty: Rc::new(RefCell::new(Vec::new())),
+            time_display: get_time_display_from_storage(),
         };
         
+        // Restore the library from IndexedDB while connecting
+        app.load_offline_cache();
+        
         // Fetch build info asynchronously
         app.fetch_build_info();
         
         // Fetch user list for login screen
         app.fetch_all_users();
         
-        // Fetch available tags
+        // Fetch tag aliases and available tags
+        app.fetch_tag_mappings();
         app.fetch_available_tags();
         
         // Auto-connect on startup
@@ -223,6 +288,55 @@ impl WasmApp {
         app
     }
     
+    /// Load cached library data from IndexedDB (async)
+    fn load_offline_cache(&self) {
+        let pending = self.pending_offline_cache.clone();
+        let scope = self.cache_scope.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            let cached = crate::idb_cache::load_library(&scope).await;
+            *pending.borrow_mut() = Some(cached);
+        });
+    }
+    
+    /// Apply cached library data unless the server already delivered fresh data
+    fn process_pending_offline_cache(&mut self) {
+        let Some(cached) = self.pending_offline_cache.borrow_mut().take() else {
+            return;
+        };
+        
+        self.offline_achievements = cached.achievements;
+        for (appid, apiname, rating) in cached.ratings {
+            self.user_achievement_ratings.entry((appid, apiname)).or_insert(rating);
+        }
+        
+        if !self.games_loaded && !cached.games.is_empty() {
+            self.games = cached.games;
+            self.games_loaded = true;
+            self.showing_cached_library = true;
+            sort_games(&mut self.games, self.sort_column, self.sort_order, SortTieBreaker::default());
+            self.status = format!("Showing {} cached games, waiting for server...", self.games.len());
+        }
+    }
+    
+    /// Persist the games list to the offline cache
+    pub(crate) fn cache_games(&mut self) {
+        self.showing_cached_library = false;
+        crate::idb_cache::save_games(&self.cache_scope, &self.games);
+    }
+    
+    /// Persist achievements for a game to the offline cache
+    pub(crate) fn cache_achievements(&mut self, appid: u64, achievements: &[GameAchievement]) {
+        self.offline_achievements.insert(appid, achievements.to_vec());
+        crate::idb_cache::save_achievements(&self.cache_scope, &self.offline_achievements);
+    }
+    
+    /// Persist ratings to the offline cache
+    pub(crate) fn cache_ratings(&self) {
+        if !self.is_guest_view() {
+            crate::idb_cache::save_ratings(&self.cache_scope, &self.user_achievement_ratings);
+        }
+    }
+    
     /// Returns true if we're viewing another user's library (guest mode)
     pub fn is_guest_view(&self) -> bool {
         self.viewing_short_id.is_some()
@@ -260,12 +374,43 @@ impl WasmApp {
         });
     }
     
+    /// Fetch tag aliases/parents from the backend
+    fn fetch_tag_mappings(&mut self) {
+        let pending = std::rc::Rc::new(std::cell::RefCell::new(None));
+        self.pending_tag_map = Some(pending.clone());
+        
+        wasm_bindgen_futures::spawn_local(async move {
+            match crate::http_client::fetch_tag_mappings().await {
+                Ok(mappings) => {
+                    *pending.borrow_mut() = Some(overachiever_core::TagMap::new(&mappings));
+                }
+                Err(e) => {
+                    web_sys::console::log_1(&format!("Failed to fetch tag mappings: {}", e).into());
+                }
+            }
+        });
+    }
+    
+    /// Process pending tag mappings, re-applying them to tags loaded before they arrived
+    fn process_pending_tag_map(&mut self) {
+        let Some(tag_map) = self.pending_tag_map.as_ref().and_then(|pending| pending.borrow_mut().take()) else {
+            return;
+        };
+        self.pending_tag_map = None;
+        self.tag_map = tag_map;
+        self.available_tags = self.tag_map.apply_names(&self.available_tags);
+        for tags in self.game_tags_cache.values_mut() {
+            let list: Vec<(String, u32)> = tags.drain().collect();
+            tags.extend(self.tag_map.apply(&list));
+        }
+    }
+    
     /// Fetch available tag names from the backend
     fn fetch_available_tags(&mut self) {
         // Try to load from cache first
         if let Some(cached_tags) = crate::storage::get_cached_tags() {
             web_sys::console::log_1(&format!("Loaded {} tags from cache", cached_tags.len()).into());
-            self.available_tags = cached_tags;
+            self.available_tags = self.tag_map.apply_names(&cached_tags);
             // Still fetch in background to keep cache fresh
             Self::update_tags_cache_in_background();
             return;
@@ -333,7 +478,7 @@ impl WasmApp {
     fn process_pending_available_tags(&mut self) {
         let should_clear = if let Some(pending) = &self.pending_available_tags {
             if let Some(tags) = pending.borrow_mut().take() {
-                self.available_tags = tags;
+                self.available_tags = self.tag_map.apply_names(&tags);
                 true
             } else {
                 false
@@ -352,12 +497,15 @@ impl WasmApp {
         let should_clear = if let Some(pending) = &self.pending_game_tags {
             let tags = pending.borrow();
             if !tags.is_empty() {
-                // Build cache: appid -> (tag_name -> vote_count)
-                self.game_tags_cache.clear();
+                // Build cache: appid -> (tag_name -> vote_count), with aliases collapsed
+                let mut grouped: HashMap<u64, Vec<(String, u32)>> = HashMap::new();
                 for tag in tags.iter() {
-                    let entry = self.game_tags_cache.entry(tag.appid).or_insert_with(HashMap::new);
-                    entry.insert(tag.tag_name.clone(), tag.vote_count);
+                    grouped.entry(tag.appid).or_default().push((tag.tag_name.clone(), tag.vote_count));
                 }
+                self.game_tags_cache = grouped
+                    .into_iter()
+                    .map(|(appid, tags)| (appid, self.tag_map.apply(&tags).into_iter().collect()))
+                    .collect();
                 web_sys::console::log_1(&format!("Applied tags for {} games to cache", self.game_tags_cache.len()).into());
                 true
             } else {
@@ -404,6 +552,17 @@ impl WasmApp {
     }
     
     pub(crate) fn check_ws_state(&mut self) {
+        // Nothing heard for the heartbeat timeout: reconnect (and resync) on the next frame
+        let stale = self.ws_client.as_ref().is_some_and(|client| {
+            client.state() == crate::ws_client::WsState::Open && client.heartbeat_tick()
+        });
+        if stale {
+            self.ws_client = None;
+            self.connection_state = ConnectionState::Disconnected;
+            self.status = "Connection lost, reconnecting...".to_string();
+            return;
+        }
+        
         if let Some(client) = &self.ws_client {
             use crate::ws_client::WsState;
             match client.state() {
@@ -426,7 +585,11 @@ impl WasmApp {
                 }
                 WsState::Error(e) => {
                     self.connection_state = ConnectionState::Error(e.clone());
-                    self.status = format!("Connection error: {}", e);
+                    self.status = if self.showing_cached_library {
+                        format!("Offline - showing cached library ({})", e)
+                    } else {
+                        format!("Connection error: {}", e)
+                    };
                 }
                 WsState::Closed => {
                     if !matches!(self.connection_state, ConnectionState::Disconnected | ConnectionState::Error(_)) {
@@ -446,6 +609,9 @@ impl WasmApp {
             let pending = self.pending_ratings.clone();
             
             wasm_bindgen_futures::spawn_local(async move {
+                // Submit ratings queued while offline first so the fetch includes them
+                crate::http_client::flush_queued_ratings().await;
+                
                 match crate::http_client::fetch_user_achievement_ratings(&token).await {
                     Ok(ratings) => {
                         *pending.borrow_mut() = Some(ratings);
@@ -462,13 +628,71 @@ impl WasmApp {
     
     /// Process any pending ratings from async fetch
     fn process_pending_ratings(&mut self) {
-        if let Some(ratings) = self.pending_ratings.borrow_mut().take() {
-            for (appid, apiname, rating) in ratings {
-                self.user_achievement_ratings.insert((appid, apiname), rating);
+        let Some(ratings) = self.pending_ratings.borrow_mut().take() else {
+            return;
+        };
+        for (appid, apiname, rating) in ratings {
+            self.user_achievement_ratings.insert((appid, apiname), rating);
+        }
+        self.cache_ratings();
+    }
+    
+    /// Save the profile customization draft (async)
+    pub(crate) fn save_profile_customization(&mut self) {
+        let (Some(token), Some(draft)) = (self.auth_token.clone(), self.profile_draft.clone()) else {
+            return;
+        };
+        self.profile_save_status = Some("Saving...".to_string());
+        let pending = self.pending_profile_save.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = crate::http_client::save_profile_customization(&token, &draft).await;
+            *pending.borrow_mut() = Some(result);
+        });
+    }
+    
+    /// Apply a finished profile customization save to our own profile and the user list
+    fn process_pending_profile_save(&mut self) {
+        let Some(result) = self.pending_profile_save.borrow_mut().take() else {
+            return;
+        };
+        match result {
+            Ok(customization) => {
+                if let ConnectionState::Authenticated(ref mut user) = self.connection_state {
+                    user.customization = customization.clone();
+                    if let Some(listed) = self.all_users.borrow_mut().iter_mut().find(|u| u.steam_id == user.steam_id) {
+                        listed.customization = customization.clone();
+                    }
+                }
+                self.profile_draft = Some(customization);
+                self.profile_save_status = Some("Profile saved".to_string());
+            }
+            Err(e) => {
+                self.profile_save_status = Some(e);
             }
         }
     }
     
+    /// Fetch a game's community difficulty curve (each game once per session)
+    pub(crate) fn fetch_difficulty_curve(&mut self, appid: u64) {
+        if !self.difficulty_requested.insert(appid) {
+            return;
+        }
+        let pending = self.pending_difficulty.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            match crate::http_client::fetch_difficulty_curve(appid).await {
+                Ok(curve) => pending.borrow_mut().push((appid, curve)),
+                Err(e) => web_sys::console::warn_1(&format!("Failed to fetch difficulty curve for {}: {}", appid, e).into()),
+            }
+        });
+    }
+    
+    /// Move finished difficulty curve fetches into the cache
+    fn process_pending_difficulty(&mut self) {
+        for (appid, curve) in self.pending_difficulty.borrow_mut().drain(..) {
+            self.difficulty_curves.insert(appid, curve);
+        }
+    }
+    
     /// Fetch TTB times for all games from the backend
     fn fetch_ttb_times(&mut self) {
         let appids: Vec<u64> = self.games.iter().map(|g| g.appid).collect();
@@ -539,16 +763,21 @@ impl WasmApp {
         for msg in messages {
             match msg {
                 overachiever_core::ServerMessage::Authenticated { user } => {
-                    self.connection_state = ConnectionState::Authenticated(user.clone());
+                    self.connection_state = ConnectionState::Authenticated(Box::new(user.clone()));
                     self.status = format!("Logged in as {}", user.display_name);
                     
                     if let Some(token) = &self.auth_token {
                         save_token_to_storage(token);
                     }
                     
-                    // Auto-fetch games and history after auth
+                    // Auto-fetch games and history after auth - after a reconnect (or from the
+                    // offline cache) only the games that changed since
                     if let Some(client) = &self.ws_client {
-                        client.fetch_games();
+                        if self.games_loaded && !self.games.is_empty() {
+                            client.resync(self.games.iter().map(|g| (g.appid, game_row_checksum(g))).collect());
+                        } else {
+                            client.fetch_games();
+                        }
                         client.fetch_history();
                     }
                     
@@ -568,7 +797,8 @@ impl WasmApp {
                     self.games_loaded = true;
                     self.app_state = AppState::Idle;
                     self.status = format!("Loaded {} games", self.games.len());
-                    sort_games(&mut self.games, self.sort_column, self.sort_order);
+                    sort_games(&mut self.games, self.sort_column, self.sort_order, SortTieBreaker::default());
+                    self.cache_games();
                     // Refresh history (run_history may have been recorded on sync)
                     if let Some(client) = &self.ws_client {
                         client.fetch_history();
@@ -578,7 +808,45 @@ impl WasmApp {
                     // Fetch game tags from backend
                     self.fetch_game_tags();
                 }
+                overachiever_core::ServerMessage::GamesDelta { changed, removed } => {
+                    let changed_count = changed.len();
+                    self.games.retain(|g| !removed.contains(&g.appid));
+                    for game in changed {
+                        // Achievements of a changed game are fetched again when it's expanded
+                        self.achievements_cache.remove(&game.appid);
+                        match self.games.iter_mut().find(|g| g.appid == game.appid) {
+                            Some(existing) => *existing = game,
+                            None => self.games.push(game),
+                        }
+                    }
+                    self.games_loaded = true;
+                    self.app_state = AppState::Idle;
+                    self.status = if changed_count == 0 && removed.is_empty() {
+                        format!("Loaded {} games (up to date)", self.games.len())
+                    } else {
+                        format!("Loaded {} games ({} updated)", self.games.len(), changed_count + removed.len())
+                    };
+                    sort_games(&mut self.games, self.sort_column, self.sort_order, SortTieBreaker::default());
+                    self.cache_games();
+                    self.fetch_ttb_times();
+                    self.fetch_game_tags();
+                }
+                overachiever_core::ServerMessage::Welcome { heartbeat_secs, .. } => {
+                    if let Some(client) = &self.ws_client {
+                        client.set_heartbeat(heartbeat_secs);
+                    }
+                }
+                overachiever_core::ServerMessage::Closing { reason } => {
+                    if reason.should_reconnect() {
+                        self.status = format!("Server closed the connection ({}), reconnecting...", reason);
+                    } else {
+                        // Stays in the error state, reconnecting wouldn't help
+                        self.connection_state = ConnectionState::Error(reason.to_string());
+                        self.status = format!("Disconnected: {}", reason);
+                    }
+                }
                 overachiever_core::ServerMessage::Achievements { appid, achievements } => {
+                    self.cache_achievements(appid, &achievements);
                     self.achievements_cache.insert(appid, achievements);
                 }
                 overachiever_core::ServerMessage::Error { message } => {
@@ -596,7 +864,8 @@ impl WasmApp {
                     self.games = games;
                     self.app_state = AppState::Idle;
                     self.status = format!("Sync complete! Updated {} games, {} achievements", result.games_updated, result.achievements_updated);
-                    sort_games(&mut self.games, self.sort_column, self.sort_order);
+                    sort_games(&mut self.games, self.sort_column, self.sort_order, SortTieBreaker::default());
+                    self.cache_games();
                     // Refresh history
                     if let Some(client) = &self.ws_client {
                         client.fetch_history();
@@ -608,12 +877,14 @@ impl WasmApp {
                         *g = game;
                     }
                     // Update achievements cache
+                    self.cache_achievements(appid, &achievements);
                     self.achievements_cache.insert(appid, achievements);
                     // Clear the refresh state
                     self.single_game_refreshing = None;
                     self.status = "Refresh complete!".to_string();
                     // Re-sort games
-                    sort_games(&mut self.games, self.sort_column, self.sort_order);
+                    sort_games(&mut self.games, self.sort_column, self.sort_order, SortTieBreaker::default());
+                    self.cache_games();
                 }
                 overachiever_core::ServerMessage::History { run_history, achievement_history, log_entries } => {
                     web_sys::console::log_1(&format!("Received History: {} run_history, {} achievement_history, {} log_entries", 
@@ -628,7 +899,8 @@ impl WasmApp {
                     self.games = games;
                     self.games_loaded = true;
                     self.status = format!("Viewing {}'s library", user.display_name);
-                    sort_games(&mut self.games, self.sort_column, self.sort_order);
+                    sort_games(&mut self.games, self.sort_column, self.sort_order, SortTieBreaker::default());
+                    self.cache_games();
                     
                     // Fetch history for guest view
                     if let Some(ref short_id) = self.viewing_short_id.clone() {
@@ -653,7 +925,7 @@ impl WasmApp {
                     }
                     self.status = "TTB report submitted successfully!".to_string();
                     // Re-sort games in case TTB was the sort column
-                    sort_games(&mut self.games, self.sort_column, self.sort_order);
+                    sort_games(&mut self.games, self.sort_column, self.sort_order, SortTieBreaker::default());
                 }
                 overachiever_core::ServerMessage::ShowTtbDialog { appid, game_name, completion_message } => {
                     // Auto-trigger TTB dialog (e.g., on 100% completion)
@@ -684,8 +956,12 @@ impl eframe::App for WasmApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         self.check_ws_state();
         self.check_messages();
+        self.process_pending_offline_cache();
         self.process_pending_ratings();
+        self.process_pending_profile_save();
+        self.process_pending_difficulty();
         self.process_pending_ttb_cache();
+        self.process_pending_tag_map();
         self.process_pending_available_tags();
         self.process_pending_game_tags();
         
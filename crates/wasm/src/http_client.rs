@@ -4,6 +4,7 @@
 
 use gloo_net::http::Request;
 use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsCast;
 
 /// Submit an achievement rating via REST API
 pub async fn submit_achievement_rating(
@@ -31,7 +32,7 @@ pub async fn submit_achievement_rating(
         .map_err(|e| format!("Failed to serialize request: {}", e))?
         .send()
         .await
-        .map_err(|e| format!("Failed to send request: {}", e))?;
+        .map_err(|e| format!("{}{}", SEND_ERROR_PREFIX, e))?;
     
     if !response.ok() {
         let status = response.status();
@@ -45,6 +46,39 @@ pub async fn submit_achievement_rating(
         .map_err(|e| format!("Failed to parse response: {}", e))
 }
 
+/// Error prefix for requests that never reached the server
+const SEND_ERROR_PREFIX: &str = "Failed to send request: ";
+
+/// True if the error means the server was unreachable (worth retrying later)
+pub fn is_network_error(error: &str) -> bool {
+    error.starts_with(SEND_ERROR_PREFIX)
+}
+
+/// Ask the service worker to retry queued ratings when connectivity returns
+/// (no-op where Background Sync isn't supported - the queue is then flushed on next login)
+pub fn request_background_sync() {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let request_sync = js_sys::Reflect::get(&window, &"requestRatingSync".into())
+        .ok()
+        .and_then(|f| f.dyn_into::<js_sys::Function>().ok());
+    if let Some(request_sync) = request_sync {
+        let _ = request_sync.call0(&window);
+    }
+}
+
+/// Resubmit ratings queued while offline, re-queueing those that still can't be sent
+pub async fn flush_queued_ratings() {
+    for queued in crate::idb_cache::take_queued_ratings().await {
+        match submit_achievement_rating(&queued.token, queued.appid, &queued.apiname, queued.rating).await {
+            Ok(_) => {}
+            Err(e) if is_network_error(&e) => crate::idb_cache::queue_rating(queued),
+            Err(e) => web_sys::console::error_1(&format!("Dropping queued rating: {}", e).into()),
+        }
+    }
+}
+
 /// Submit an achievement comment via REST API
 #[allow(dead_code)]
 pub async fn submit_achievement_comment(
@@ -190,12 +224,12 @@ pub async fn fetch_build_info() -> Result<BuildInfo, String> {
         .map_err(|e| format!("Failed to parse build info: {}", e))
 }
 
-/// Fetch list of all users from the backend
+/// Fetch the most recently seen users listed in the public directory
 pub async fn fetch_all_users(server_url: &str) -> Result<Vec<overachiever_core::UserProfile>, String> {
     // Convert WebSocket URL to HTTP
     let http_url = server_url.replace("ws://", "http://").replace("wss://", "https://");
     let base_url = http_url.trim_end_matches("/ws");
-    let url = format!("{}/api/users", base_url);
+    let url = format!("{}/api/users?sort=last_seen&limit=100", base_url);
     
     let response = Request::get(&url)
         .send()
@@ -207,11 +241,71 @@ pub async fn fetch_all_users(server_url: &str) -> Result<Vec<overachiever_core::
     }
     
     response
-        .json::<Vec<overachiever_core::UserProfile>>()
+        .json::<overachiever_core::UserPage>()
         .await
+        .map(|page| page.users)
         .map_err(|e| format!("Failed to parse users: {}", e))
 }
 
+/// Save the user's public profile customization, returns the stored version
+pub async fn save_profile_customization(
+    token: &str,
+    customization: &overachiever_core::ProfileCustomization,
+) -> Result<overachiever_core::ProfileCustomization, String> {
+    let origin = web_sys::window()
+        .and_then(|w| w.location().origin().ok())
+        .unwrap_or_default();
+    
+    let url = format!("{}/api/profile/customization", origin);
+    
+    let response = Request::put(&url)
+        .header("Authorization", &format!("Bearer {}", token))
+        .header("Content-Type", "application/json")
+        .json(customization)
+        .map_err(|e| format!("Failed to serialize request: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("{}{}", SEND_ERROR_PREFIX, e))?;
+    
+    if !response.ok() {
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+        let message = serde_json::from_str::<serde_json::Value>(&text)
+            .ok()
+            .and_then(|v| v["message"].as_str().map(|s| s.to_string()))
+            .unwrap_or(text);
+        return Err(format!("Save failed ({}): {}", status, message));
+    }
+    
+    response
+        .json::<overachiever_core::ProfileCustomization>()
+        .await
+        .map_err(|e| format!("Failed to parse response: {}", e))
+}
+
+/// Fetch the community difficulty of each rated achievement in a game
+pub async fn fetch_difficulty_curve(appid: u64) -> Result<Vec<overachiever_core::AchievementDifficulty>, String> {
+    let origin = web_sys::window()
+        .and_then(|w| w.location().origin().ok())
+        .unwrap_or_default();
+    
+    let url = format!("{}/api/achievement/difficulty/{}", origin, appid);
+    
+    let response = Request::get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("{}{}", SEND_ERROR_PREFIX, e))?;
+    
+    if !response.ok() {
+        return Err(format!("Failed to fetch difficulty curve (status {})", response.status()));
+    }
+    
+    response
+        .json::<Vec<overachiever_core::AchievementDifficulty>>()
+        .await
+        .map_err(|e| format!("Failed to parse difficulty curve: {}", e))
+}
+
 /// Fetch TTB times for multiple games from the backend
 pub async fn fetch_ttb_batch(appids: &[u64]) -> Result<Vec<overachiever_core::TtbTimes>, String> {
     if appids.is_empty() {
@@ -274,6 +368,29 @@ pub async fn fetch_all_tag_names() -> Result<Vec<String>, String> {
     Ok(result.tags)
 }
 
+/// Fetch the admin-maintained tag aliases and parents
+pub async fn fetch_tag_mappings() -> Result<Vec<overachiever_core::TagMapping>, String> {
+    let origin = web_sys::window()
+        .and_then(|w| w.location().origin().ok())
+        .unwrap_or_default();
+    
+    let url = format!("{}/api/tags/mappings", origin);
+    
+    let response = Request::get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch tag mappings: {}", e))?;
+    
+    if !response.ok() {
+        return Err(format!("Failed to fetch tag mappings (status {})", response.status()));
+    }
+    
+    response
+        .json::<Vec<overachiever_core::TagMapping>>()
+        .await
+        .map_err(|e| format!("Failed to parse tag mappings: {}", e))
+}
+
 /// Fetch tags for multiple games from the backend
 pub async fn fetch_tags_batch(appids: &[u64]) -> Result<Vec<overachiever_core::GameTag>, String> {
     if appids.is_empty() {
@@ -0,0 +1,199 @@
+//! IndexedDB-backed offline cache for library data
+//!
+//! Stores games (including icon hashes), achievements (including icon URLs) and
+//! ratings as JSON so a page reload can show the library before the server answers,
+//! and keeps working when the server is unreachable.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use overachiever_core::{Game, GameAchievement};
+use serde::{de::DeserializeOwned, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{IdbDatabase, IdbOpenDbRequest, IdbRequest, IdbTransactionMode};
+
+const DB_NAME: &str = "overachiever";
+const DB_VERSION: u32 = 1;
+const STORE: &str = "cache";
+
+thread_local! {
+    static DB: RefCell<Option<IdbDatabase>> = const { RefCell::new(None) };
+}
+
+/// Library data restored from the offline cache
+#[derive(Default)]
+pub struct CachedLibrary {
+    pub games: Vec<Game>,
+    pub achievements: HashMap<u64, Vec<GameAchievement>>,
+    pub ratings: Vec<(u64, String, u8)>,
+}
+
+/// Cache namespace: own library or a guest-viewed profile
+pub fn cache_scope(viewing_short_id: Option<&str>) -> String {
+    match viewing_short_id {
+        Some(short_id) => format!("guest:{}", short_id),
+        None => "me".to_string(),
+    }
+}
+
+/// Turn an IDBRequest into a future resolving to its result
+fn request_future(request: &IdbRequest) -> JsFuture {
+    let promise = js_sys::Promise::new(&mut |resolve, reject| {
+        let req = request.clone();
+        let onsuccess = Closure::once_into_js(move |_: web_sys::Event| {
+            let _ = resolve.call1(&JsValue::UNDEFINED, &req.result().unwrap_or(JsValue::UNDEFINED));
+        });
+        let onerror = Closure::once_into_js(move |_: web_sys::Event| {
+            let _ = reject.call1(&JsValue::UNDEFINED, &JsValue::from_str("IndexedDB request failed"));
+        });
+        request.set_onsuccess(Some(onsuccess.unchecked_ref()));
+        request.set_onerror(Some(onerror.unchecked_ref()));
+    });
+    JsFuture::from(promise)
+}
+
+/// Open (or reuse) the cache database
+async fn open_db() -> Result<IdbDatabase, String> {
+    if let Some(db) = DB.with(|db| db.borrow().clone()) {
+        return Ok(db);
+    }
+
+    let factory = web_sys::window()
+        .and_then(|w| w.indexed_db().ok().flatten())
+        .ok_or_else(|| "IndexedDB not available".to_string())?;
+    let open_request: IdbOpenDbRequest = factory
+        .open_with_u32(DB_NAME, DB_VERSION)
+        .map_err(|e| format!("Failed to open IndexedDB: {:?}", e))?;
+
+    let onupgradeneeded = Closure::once_into_js(move |event: web_sys::Event| {
+        let db = event
+            .target()
+            .and_then(|t| t.dyn_into::<IdbOpenDbRequest>().ok())
+            .and_then(|r| r.result().ok())
+            .and_then(|r| r.dyn_into::<IdbDatabase>().ok());
+        if let Some(db) = db {
+            // Data is plain JSON blobs - on version bumps just start over
+            let _ = db.delete_object_store(STORE);
+            let _ = db.create_object_store(STORE);
+        }
+    });
+    open_request.set_onupgradeneeded(Some(onupgradeneeded.unchecked_ref()));
+
+    let db: IdbDatabase = request_future(&open_request)
+        .await
+        .map_err(|e| format!("Failed to open IndexedDB: {:?}", e))?
+        .dyn_into()
+        .map_err(|_| "IndexedDB returned an unexpected object".to_string())?;
+    DB.with(|cached| *cached.borrow_mut() = Some(db.clone()));
+    Ok(db)
+}
+
+/// Read and deserialize a cached value
+async fn get_json<T: DeserializeOwned>(key: &str) -> Option<T> {
+    let db = open_db().await.ok()?;
+    let store = db.transaction_with_str(STORE).ok()?.object_store(STORE).ok()?;
+    let request = store.get(&JsValue::from_str(key)).ok()?;
+    let value = request_future(&request).await.ok()?;
+    serde_json::from_str(&value.as_string()?).ok()
+}
+
+/// Serialize and store a value
+async fn put_json<T: Serialize>(key: &str, value: &T) -> Result<(), String> {
+    let json = serde_json::to_string(value).map_err(|e| e.to_string())?;
+    let db = open_db().await?;
+    let store = db
+        .transaction_with_str_and_mode(STORE, IdbTransactionMode::Readwrite)
+        .and_then(|tx| tx.object_store(STORE))
+        .map_err(|e| format!("IndexedDB transaction failed: {:?}", e))?;
+    let request = store
+        .put_with_key(&JsValue::from_str(&json), &JsValue::from_str(key))
+        .map_err(|e| format!("IndexedDB put failed: {:?}", e))?;
+    request_future(&request).await.map_err(|e| format!("IndexedDB put failed: {:?}", e))?;
+    Ok(())
+}
+
+/// Store a value in the background, logging failures
+fn spawn_put<T: Serialize + 'static>(key: String, value: T) {
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Err(e) = put_json(&key, &value).await {
+            web_sys::console::warn_1(&format!("Offline cache write failed for {}: {}", key, e).into());
+        }
+    });
+}
+
+/// Load everything cached for a scope
+pub async fn load_library(scope: &str) -> CachedLibrary {
+    CachedLibrary {
+        games: get_json(&format!("{}:games", scope)).await.unwrap_or_default(),
+        achievements: get_json(&format!("{}:achievements", scope)).await.unwrap_or_default(),
+        ratings: get_json(&format!("{}:ratings", scope)).await.unwrap_or_default(),
+    }
+}
+
+pub fn save_games(scope: &str, games: &[Game]) {
+    spawn_put(format!("{}:games", scope), games.to_vec());
+}
+
+pub fn save_achievements(scope: &str, achievements: &HashMap<u64, Vec<GameAchievement>>) {
+    spawn_put(format!("{}:achievements", scope), achievements.clone());
+}
+
+pub fn save_ratings(scope: &str, ratings: &HashMap<(u64, String), u8>) {
+    let ratings: Vec<(u64, String, u8)> = ratings.iter().map(|((appid, apiname), rating)| (*appid, apiname.clone(), *rating)).collect();
+    spawn_put(format!("{}:ratings", scope), ratings);
+}
+
+/// Forget everything cached for a scope (e.g. on logout)
+pub fn clear_scope(scope: &str) {
+    let keys: Vec<String> = ["games", "achievements", "ratings"].iter().map(|k| format!("{}:{}", scope, k)).collect();
+    wasm_bindgen_futures::spawn_local(async move {
+        let Ok(db) = open_db().await else {
+            return;
+        };
+        let Ok(store) = db.transaction_with_str_and_mode(STORE, IdbTransactionMode::Readwrite).and_then(|tx| tx.object_store(STORE)) else {
+            return;
+        };
+        for key in keys {
+            let _ = store.delete(&JsValue::from_str(&key));
+        }
+    });
+}
+
+// ============================================================================
+// Rating submission queue (flushed by the app on login, or by the service worker)
+// ============================================================================
+
+/// Key of the queued rating submissions - shared with web/sw.js
+const RATING_QUEUE_KEY: &str = "rating_queue";
+
+/// A rating submission that couldn't reach the server
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct QueuedRating {
+    pub token: String,
+    pub appid: u64,
+    pub apiname: String,
+    pub rating: u8,
+}
+
+/// Queue a rating for later submission (replaces an older queued rating for the same achievement)
+pub fn queue_rating(rating: QueuedRating) {
+    wasm_bindgen_futures::spawn_local(async move {
+        let mut queue: Vec<QueuedRating> = get_json(RATING_QUEUE_KEY).await.unwrap_or_default();
+        queue.retain(|q| !(q.appid == rating.appid && q.apiname == rating.apiname));
+        queue.push(rating);
+        if let Err(e) = put_json(RATING_QUEUE_KEY, &queue).await {
+            web_sys::console::warn_1(&format!("Failed to queue rating: {}", e).into());
+        }
+    });
+}
+
+/// Take all queued ratings, leaving the queue empty
+pub async fn take_queued_ratings() -> Vec<QueuedRating> {
+    let queue: Vec<QueuedRating> = get_json(RATING_QUEUE_KEY).await.unwrap_or_default();
+    if !queue.is_empty() {
+        let _ = put_json(RATING_QUEUE_KEY, &Vec::<QueuedRating>::new()).await;
+    }
+    queue
+}
@@ -7,6 +7,7 @@
 
 mod app;
 mod gdpr;
+mod idb_cache;
 mod panels;
 mod platforms;
 mod steam_images;
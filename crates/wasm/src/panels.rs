@@ -3,13 +3,14 @@
 use eframe::egui;
 use egui_phosphor::regular;
 use overachiever_core::{
-    GdprConsent, SidebarPanel, StatsPanelConfig,
-    render_stats_content, render_log_content, render_filter_bar, render_games_table,
-    get_filtered_indices,
+    GdprConsent, SidebarPanel, StatsPanelConfig, UserProfile,
+    render_stats_content, render_stats_dashboard, render_log_content, render_filter_bar, render_games_table,
+    get_filtered_indices, time_zone_names, tr, DisplayTimeZone, TimestampStyle,
 };
 
 use crate::app::{WasmApp, ConnectionState};
-use crate::storage::{get_auth_url, clear_token_from_storage, clear_gdpr_consent_from_storage};
+use crate::steam_images::{game_banner_url, proxy_steam_image_url};
+use crate::storage::{get_auth_url, clear_token_from_storage, clear_gdpr_consent_from_storage, save_time_display_to_storage};
 
 impl WasmApp {
     // ========================================================================
@@ -90,9 +91,11 @@ impl WasmApp {
                         if ui.button(format!("{} Logout", regular::SIGN_OUT)).clicked() {
                             self.auth_token = None;
                             clear_token_from_storage();
+                            crate::idb_cache::clear_scope(&self.cache_scope);
                             self.connection_state = ConnectionState::Disconnected;
                             self.games.clear();
                             self.games_loaded = false;
+                            self.offline_achievements.clear();
                         }
                         
                         // User profile button - opens profile menu
@@ -281,9 +284,32 @@ impl WasmApp {
             } else {
                 format!("Games Library ({} games)", self.games.len())
             };
-            ui.heading(heading_text);
+            let profile = match (&self.viewing_user, &self.connection_state) {
+                (Some(user), _) => Some(user),
+                (None, ConnectionState::Authenticated(user)) => Some(&**user),
+                _ => None,
+            };
+            match profile.filter(|user| user.customization.is_customized()) {
+                Some(user) => render_profile_header(ui, user, &heading_text),
+                None => {
+                    ui.heading(heading_text);
+                }
+            }
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut self.show_stats_dashboard, false, format!("{} Library", regular::LIST));
+                ui.selectable_value(&mut self.show_stats_dashboard, true, format!("{} {}", regular::CHART_BAR, tr("dashboard.tab")));
+            });
             ui.separator();
             
+            if self.show_stats_dashboard {
+                // Full-size plots with axes, but no drag/zoom (it fights with page scrolling on touch)
+                let config = StatsPanelConfig { plot_height: None, show_plot_axes: true, ..StatsPanelConfig::wasm() };
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    render_stats_dashboard(ui, self, &config);
+                });
+                return;
+            }
+            
             render_filter_bar(ui, self);
             ui.add_space(4.0);
             
@@ -395,7 +421,15 @@ impl WasmApp {
                                             for (i, user) in users.iter().enumerate() {
                                                 if let Some(short_id) = &user.short_id {
                                                     let profile_url = format!("/{}", short_id);
-                                                    if ui.link(short_id).clicked() {
+                                                    let mut text = egui::RichText::new(short_id);
+                                                    if let Some([r, g, b]) = user.customization.accent_rgb() {
+                                                        text = text.color(egui::Color32::from_rgb(r, g, b));
+                                                    }
+                                                    let mut hover = user.display_name.clone();
+                                                    if let Some(showcase) = &user.customization.showcase_name {
+                                                        hover.push_str(&format!("\n{} {}", regular::TROPHY, showcase));
+                                                    }
+                                                    if ui.link(text).on_hover_text(hover).clicked() {
                                                         if let Some(window) = web_sys::window() {
                                                             let _ = window.location().set_href(&profile_url);
                                                         }
@@ -598,7 +632,15 @@ impl WasmApp {
                         }
                     }
                     
-                    // 2. Privacy Policy button
+                    // 2. Public profile customization
+                    self.render_profile_customization_editor(ui);
+                    ui.add_space(8.0);
+                    
+                    // 3. Date and time display
+                    self.render_time_display_settings(ui);
+                    ui.add_space(8.0);
+                    
+                    // 4. Privacy Policy button
                     if self.gdpr_consent.is_set() {
                         if ui.button(format!("{} Privacy Policy", regular::SHIELD_CHECK))
                             .on_hover_text("View privacy settings and data usage")
@@ -613,7 +655,7 @@ impl WasmApp {
                         ui.add_space(8.0);
                     }
                     
-                    // 3. Hide games feature (desktop only - not implemented in WASM yet)
+                    // 5. Hide games feature (desktop only - not implemented in WASM yet)
                     // This will be added later
                     
                     ui.add_space(4.0);
@@ -622,7 +664,227 @@ impl WasmApp {
         
         if !keep_open {
             self.show_profile_menu = false;
+            self.profile_draft = None;
+            self.profile_save_status = None;
+        }
+    }
+    
+    /// Time zone and relative/absolute timestamp pickers
+    fn render_time_display_settings(&mut self, ui: &mut egui::Ui) {
+        let mut changed = false;
+        let time = &mut self.time_display;
+        
+        egui::CollapsingHeader::new(format!("{} Dates & times", regular::CLOCK))
+            .id_salt("time_display_settings")
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Time zone:");
+                    egui::ComboBox::from_id_salt("time_display_zone")
+                        .selected_text(time.timezone.label())
+                        .width(200.0)
+                        .height(300.0)
+                        .show_ui(ui, |ui| {
+                            for zone in [DisplayTimeZone::Utc, DisplayTimeZone::Local] {
+                                if ui.selectable_label(time.timezone == zone, zone.label()).clicked() {
+                                    time.timezone = zone;
+                                    changed = true;
+                                }
+                            }
+                            ui.separator();
+                            for name in time_zone_names() {
+                                let selected = matches!(&time.timezone, DisplayTimeZone::Named(n) if n == name);
+                                if ui.selectable_label(selected, name).clicked() {
+                                    time.timezone = DisplayTimeZone::Named(name.to_string());
+                                    changed = true;
+                                }
+                            }
+                        });
+                });
+                
+                let style_label = |style: TimestampStyle| match style {
+                    TimestampStyle::Absolute => "Date",
+                    TimestampStyle::Relative => "Relative (3 days ago)",
+                };
+                for (label, id, style) in [
+                    ("Activity log:", "time_display_log_style", &mut time.log_style),
+                    ("Achievements:", "time_display_achievement_style", &mut time.achievement_style),
+                ] {
+                    ui.horizontal(|ui| {
+                        ui.label(label);
+                        egui::ComboBox::from_id_salt(id)
+                            .selected_text(style_label(*style))
+                            .show_ui(ui, |ui| {
+                                for option in [TimestampStyle::Absolute, TimestampStyle::Relative] {
+                                    if ui.selectable_label(*style == option, style_label(option)).clicked() {
+                                        *style = option;
+                                        changed = true;
+                                    }
+                                }
+                            });
+                    });
+                }
+                
+                if ui.checkbox(&mut time.show_time_of_day, "Show time of day in dates").changed() {
+                    changed = true;
+                }
+            });
+        
+        if changed {
+            save_time_display_to_storage(&self.time_display);
+        }
+    }
+    
+    /// Showcase achievement, banner game and accent color pickers
+    fn render_profile_customization_editor(&mut self, ui: &mut egui::Ui) {
+        let current = match &self.connection_state {
+            ConnectionState::Authenticated(user) => user.customization.clone(),
+            _ => return,
+        };
+        let draft = self.profile_draft.get_or_insert(current);
+        let mut fetch_achievements_for = None;
+        
+        ui.label(egui::RichText::new("Public profile").strong());
+        egui::Grid::new("profile_customization_grid")
+            .num_columns(2)
+            .spacing([12.0, 6.0])
+            .show(ui, |ui| {
+                // Accent color
+                ui.label("Accent color:");
+                ui.horizontal(|ui| {
+                    let mut rgb = draft.accent_rgb().unwrap_or([108, 204, 255]);
+                    if ui.color_edit_button_srgb(&mut rgb).changed() {
+                        draft.accent_color = Some(format!("#{:02x}{:02x}{:02x}", rgb[0], rgb[1], rgb[2]));
+                    }
+                    if draft.accent_color.is_some() && ui.small_button(regular::X.to_string()).on_hover_text("Use default color").clicked() {
+                        draft.accent_color = None;
+                    }
+                });
+                ui.end_row();
+                
+                // Favorite game banner (most played first)
+                ui.label("Banner game:");
+                let mut by_playtime: Vec<&overachiever_core::Game> = self.games.iter().collect();
+                by_playtime.sort_by_key(|g| std::cmp::Reverse(g.playtime_forever));
+                let game_name = |appid: Option<u64>| {
+                    appid
+                        .and_then(|id| self.games.iter().find(|g| g.appid == id))
+                        .map(|g| g.name.clone())
+                        .unwrap_or_else(|| "None".to_string())
+                };
+                egui::ComboBox::from_id_salt("profile_banner_game")
+                    .selected_text(game_name(draft.banner_appid))
+                    .width(220.0)
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut draft.banner_appid, None, "None");
+                        for game in &by_playtime {
+                            ui.selectable_value(&mut draft.banner_appid, Some(game.appid), &game.name);
+                        }
+                    });
+                ui.end_row();
+                
+                // Showcase achievement: pick a game with unlocks, then one of its unlocked achievements
+                ui.label("Showcase game:");
+                let previous_appid = draft.showcase_appid;
+                egui::ComboBox::from_id_salt("profile_showcase_game")
+                    .selected_text(game_name(draft.showcase_appid))
+                    .width(220.0)
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut draft.showcase_appid, None, "None");
+                        for game in by_playtime.iter().filter(|g| g.achievements_unlocked.unwrap_or(0) > 0) {
+                            ui.selectable_value(&mut draft.showcase_appid, Some(game.appid), &game.name);
+                        }
+                    });
+                if draft.showcase_appid != previous_appid {
+                    draft.showcase_apiname = None;
+                    draft.showcase_name = None;
+                    draft.showcase_icon = None;
+                    fetch_achievements_for = draft.showcase_appid;
+                }
+                ui.end_row();
+                
+                if let Some(appid) = draft.showcase_appid {
+                    ui.label("Achievement:");
+                    let achievements = self.achievements_cache.get(&appid).or_else(|| self.offline_achievements.get(&appid));
+                    match achievements {
+                        Some(achievements) => {
+                            egui::ComboBox::from_id_salt("profile_showcase_achievement")
+                                .selected_text(draft.showcase_name.clone().unwrap_or_else(|| "Choose...".to_string()))
+                                .width(220.0)
+                                .show_ui(ui, |ui| {
+                                    for ach in achievements.iter().filter(|a| a.achieved) {
+                                        let selected = draft.showcase_apiname.as_deref() == Some(ach.apiname.as_str());
+                                        if ui.selectable_label(selected, &ach.name).clicked() {
+                                            draft.showcase_apiname = Some(ach.apiname.clone());
+                                            draft.showcase_name = Some(ach.name.clone());
+                                            draft.showcase_icon = Some(ach.icon.clone());
+                                        }
+                                    }
+                                });
+                        }
+                        None => {
+                            if ui.button(format!("{} Load achievements", regular::DOWNLOAD_SIMPLE)).clicked() {
+                                fetch_achievements_for = Some(appid);
+                            }
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+        
+        ui.checkbox(&mut draft.listed, "List me in the user directory")
+            .on_hover_text("Show your profile to everyone on the login page");
+        
+        let can_save = draft.showcase_appid.is_none() || draft.showcase_apiname.is_some();
+        ui.horizontal(|ui| {
+            if ui.add_enabled(can_save, egui::Button::new(format!("{} Save profile", regular::FLOPPY_DISK)))
+                .on_disabled_hover_text("Choose a showcase achievement first")
+                .clicked()
+            {
+                self.save_profile_customization();
+            }
+            if let Some(status) = &self.profile_save_status {
+                ui.label(egui::RichText::new(status).small());
+            }
+        });
+        
+        if let Some(appid) = fetch_achievements_for {
+            if let Some(client) = &self.ws_client {
+                client.fetch_achievements(appid);
+            }
         }
     }
 }
 
+/// Profile header with favorite game banner, accent-colored name and showcase achievement
+fn render_profile_header(ui: &mut egui::Ui, user: &UserProfile, heading_text: &str) {
+    let customization = &user.customization;
+    let accent = customization
+        .accent_rgb()
+        .map(|[r, g, b]| egui::Color32::from_rgb(r, g, b))
+        .unwrap_or(ui.visuals().strong_text_color());
+    
+    ui.horizontal(|ui| {
+        if let Some(appid) = customization.banner_appid {
+            // Steam header images are 460x215
+            ui.add(
+                egui::Image::new(game_banner_url(appid))
+                    .fit_to_exact_size(egui::vec2(184.0, 86.0))
+                    .corner_radius(4.0)
+            );
+        }
+        ui.vertical(|ui| {
+            ui.heading(egui::RichText::new(heading_text).color(accent));
+            if let (Some(name), Some(icon)) = (&customization.showcase_name, &customization.showcase_icon) {
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::Image::new(proxy_steam_image_url(icon))
+                            .fit_to_exact_size(egui::vec2(32.0, 32.0))
+                            .corner_radius(4.0)
+                    );
+                    ui.label(egui::RichText::new(format!("{} {}", regular::TROPHY, name)).color(accent));
+                });
+            }
+        });
+    });
+}
+
@@ -2,9 +2,9 @@
 
 use eframe::egui;
 use overachiever_core::{
-    Game, GameAchievement, RunHistory, AchievementHistory, LogEntry,
-    StatsPanelPlatform, GamesTablePlatform, SortColumn, SortOrder, TriFilter,
-    sort_games,
+    AchievementDifficulty, Game, GameAchievement, RunHistory, AchievementHistory, LogEntry,
+    StatsPanelPlatform, GamesTablePlatform, SortColumn, SortOrder, TriFilter, RangeFilter, AchievementSort, GroupBy,
+    TimeDisplaySettings, sort_games, compare_tie_break, SortTieBreaker, TagMatchMode,
 };
 
 use crate::app::{WasmApp, ConnectionState, AppState};
@@ -61,6 +61,12 @@ impl StatsPanelPlatform for WasmApp {
                     Ok(resp) => {
                         web_sys::console::log_1(&format!("Rating submitted: {} stars for {}/{}", rating, resp.appid, resp.apiname).into());
                     }
+                    Err(e) if crate::http_client::is_network_error(&e) => {
+                        // Offline - keep it for background sync / next login
+                        web_sys::console::warn_1(&format!("Rating queued until back online: {}", e).into());
+                        crate::idb_cache::queue_rating(crate::idb_cache::QueuedRating { token, appid, apiname, rating });
+                        crate::http_client::request_background_sync();
+                    }
                     Err(e) => {
                         web_sys::console::error_1(&format!("Failed to submit rating: {}", e).into());
                     }
@@ -75,6 +81,7 @@ impl StatsPanelPlatform for WasmApp {
     
     fn set_user_achievement_rating(&mut self, appid: u64, apiname: String, rating: u8) {
         self.user_achievement_ratings.insert((appid, apiname.clone()), rating);
+        self.cache_ratings();
         // Also submit to server
         self.submit_achievement_rating(appid, apiname, rating);
     }
@@ -104,9 +111,12 @@ impl StatsPanelPlatform for WasmApp {
         self.filter_name.clear();
         self.filter_achievements = TriFilter::All;
         self.filter_playtime = TriFilter::All;
+        self.filter_playtime_range = RangeFilter::default();
         self.filter_ttb = TriFilter::All;
+        self.filter_ttb_range = RangeFilter::default();
         self.filter_hidden = TriFilter::Without;  // Reset to default
         self.filter_tags.clear();
+        self.filter_excluded_tags.clear();
         
         // Expand the game row
         self.expanded_rows.insert(appid);
@@ -123,6 +133,27 @@ impl StatsPanelPlatform for WasmApp {
         self.needs_scroll_to_target = true;
     }
     
+    fn time_display(&self) -> TimeDisplaySettings {
+        self.time_display.clone()
+    }
+    
+    fn get_achievement_avg_rating(&self, appid: u64, apiname: &str) -> Option<(f32, i32)> {
+        self.difficulty_curves
+            .get(&appid)?
+            .iter()
+            .find(|d| d.apiname == apiname)
+            .map(|d| (d.avg_rating, d.rating_count))
+    }
+
+    fn get_achievement_rating_votes(&self, appid: u64, apiname: &str) -> Option<[i32; 5]> {
+        self.difficulty_curves
+            .get(&appid)?
+            .iter()
+            .find(|d| d.apiname == apiname)
+            .map(|d| d.votes)
+            .filter(|votes| votes.iter().any(|&v| v > 0))
+    }
+    
     fn get_log_selected_achievement(&self) -> Option<(u64, String)> {
         self.log_selected_achievement.clone()
     }
@@ -170,10 +201,11 @@ impl GamesTablePlatform for WasmApp {
                     .or_else(|| cache.get(&b.appid).and_then(|t| t.main))
                     .unwrap_or(-1.0);
                 let cmp = a_ttb.partial_cmp(&b_ttb).unwrap_or(std::cmp::Ordering::Equal);
-                if order == SortOrder::Descending { cmp.reverse() } else { cmp }
+                let cmp = if order == SortOrder::Descending { cmp.reverse() } else { cmp };
+                cmp.then_with(|| compare_tie_break(a, b, SortColumn::TimeToBeat, SortTieBreaker::default()))
             });
         } else {
-            sort_games(&mut self.games, self.sort_column, self.sort_order);
+            sort_games(&mut self.games, self.sort_column, self.sort_order, SortTieBreaker::default());
         }
     }
     
@@ -201,6 +233,14 @@ impl GamesTablePlatform for WasmApp {
         self.filter_playtime = filter;
     }
     
+    fn filter_playtime_range(&self) -> RangeFilter {
+        self.filter_playtime_range
+    }
+    
+    fn set_filter_playtime_range(&mut self, range: RangeFilter) {
+        self.filter_playtime_range = range;
+    }
+    
     fn is_expanded(&self, appid: u64) -> bool {
         self.expanded_rows.contains(&appid)
     }
@@ -210,11 +250,20 @@ impl GamesTablePlatform for WasmApp {
             self.expanded_rows.remove(&appid);
         } else {
             self.expanded_rows.insert(appid);
+            // Achievements shown from the offline cache: refresh them from the server
+            if !self.achievements_cache.contains_key(&appid) && self.offline_achievements.contains_key(&appid) {
+                if let Some(client) = &self.ws_client {
+                    match &self.viewing_short_id {
+                        Some(short_id) => client.fetch_guest_achievements(short_id, appid),
+                        None => client.fetch_achievements(appid),
+                    }
+                }
+            }
         }
     }
     
     fn get_cached_achievements(&self, appid: u64) -> Option<&Vec<GameAchievement>> {
-        self.achievements_cache.get(&appid)
+        self.achievements_cache.get(&appid).or_else(|| self.offline_achievements.get(&appid))
     }
     
     fn request_achievements(&mut self, appid: u64) {
@@ -223,6 +272,30 @@ impl GamesTablePlatform for WasmApp {
         }
     }
     
+    fn achievement_sort(&self) -> AchievementSort {
+        self.achievement_sort
+    }
+    
+    fn set_achievement_sort(&mut self, sort: AchievementSort) {
+        self.achievement_sort = sort;
+    }
+    
+    fn group_by(&self) -> GroupBy {
+        self.group_by
+    }
+    
+    fn set_group_by(&mut self, group_by: GroupBy) {
+        self.group_by = group_by;
+    }
+    
+    fn reveal_spoilers(&self) -> bool {
+        self.reveal_spoilers
+    }
+    
+    fn set_reveal_spoilers(&mut self, reveal: bool) {
+        self.reveal_spoilers = reveal;
+    }
+    
     fn get_navigation_target(&self) -> Option<(u64, String)> {
         self.navigation_target.clone()
     }
@@ -295,6 +368,26 @@ impl GamesTablePlatform for WasmApp {
         self.filter_ttb = filter;
     }
     
+    fn filter_ttb_range(&self) -> RangeFilter {
+        self.filter_ttb_range
+    }
+    
+    fn set_filter_ttb_range(&mut self, range: RangeFilter) {
+        self.filter_ttb_range = range;
+    }
+    
+    // ============================================================================
+    // Community Difficulty Curve
+    // ============================================================================
+    
+    fn get_difficulty_curve(&self, appid: u64) -> Option<&[AchievementDifficulty]> {
+        self.difficulty_curves.get(&appid).map(|c| c.as_slice())
+    }
+    
+    fn request_difficulty_curve(&mut self, appid: u64) {
+        self.fetch_difficulty_curve(appid);
+    }
+    
     // ============================================================================
     // Hidden Games Methods
     // ============================================================================
@@ -319,6 +412,22 @@ impl GamesTablePlatform for WasmApp {
         self.filter_tags = tags;
     }
     
+    fn filter_excluded_tags(&self) -> &[String] {
+        &self.filter_excluded_tags
+    }
+    
+    fn set_filter_excluded_tags(&mut self, tags: Vec<String>) {
+        self.filter_excluded_tags = tags;
+    }
+    
+    fn tag_match_mode(&self) -> TagMatchMode {
+        self.tag_match_mode
+    }
+    
+    fn set_tag_match_mode(&mut self, mode: TagMatchMode) {
+        self.tag_match_mode = mode;
+    }
+    
     fn tag_search_input(&self) -> &str {
         &self.tag_search_input
     }
@@ -327,6 +436,14 @@ impl GamesTablePlatform for WasmApp {
         self.tag_search_input = input;
     }
     
+    fn tag_search_selected_index(&self) -> Option<usize> {
+        self.tag_search_selected_index
+    }
+    
+    fn set_tag_search_selected_index(&mut self, index: Option<usize>) {
+        self.tag_search_selected_index = index;
+    }
+    
     fn available_tags(&self) -> &[String] {
         &self.available_tags
     }
@@ -1,45 +1,38 @@
 //! Steam image URL proxying helpers for CORS avoidance
+//!
+//! All Steam CDN images are loaded through the backend's `/api/image-proxy`, which
+//! fetches them server-side and caches them.
 
-/// Convert Steam CDN URLs to proxied URLs to avoid CORS issues
-/// Handles both steamcdn-a.akamaihd.net and media.steampowered.com URLs
-pub fn proxy_steam_image_url(url: &str) -> String {
-    // Get the current origin for relative URLs
+/// Steam CDN host used for game icons and store headers
+const STEAM_CDN: &str = "https://steamcdn-a.akamaihd.net";
+
+/// Route an image URL through the backend image proxy
+fn image_proxy_url(url: &str) -> String {
     let origin = web_sys::window()
         .and_then(|w| w.location().origin().ok())
         .unwrap_or_default();
-    
-    if url.contains("steamcdn-a.akamaihd.net") {
-        // https://steamcdn-a.akamaihd.net/steamcommunity/public/images/apps/...
-        // -> /steam-media/steamcommunity/public/images/apps/...
-        if let Some(path) = url.strip_prefix("https://steamcdn-a.akamaihd.net/") {
-            return format!("{}/steam-media/{}", origin, path);
-        }
-        if let Some(path) = url.strip_prefix("http://steamcdn-a.akamaihd.net/") {
-            return format!("{}/steam-media/{}", origin, path);
-        }
-    }
-    
-    if url.contains("media.steampowered.com") {
-        // https://media.steampowered.com/steamcommunity/public/images/apps/...
-        // -> /steam-media/steamcommunity/public/images/apps/...
-        if let Some(path) = url.strip_prefix("https://media.steampowered.com/") {
-            return format!("{}/steam-media/{}", origin, path);
-        }
-        if let Some(path) = url.strip_prefix("http://media.steampowered.com/") {
-            return format!("{}/steam-media/{}", origin, path);
-        }
+    let encoded: String = js_sys::encode_uri_component(url).into();
+    format!("{}/api/image-proxy?url={}", origin, encoded)
+}
+
+/// Convert Steam CDN URLs to proxied URLs to avoid CORS issues
+/// Non-http(s) URLs (e.g. already proxied or data URIs) are returned unchanged
+pub fn proxy_steam_image_url(url: &str) -> String {
+    if url.starts_with("https://") || url.starts_with("http://") {
+        image_proxy_url(url)
+    } else {
+        url.to_string()
     }
-    
-    // Return original URL if not a Steam CDN URL
-    url.to_string()
 }
 
 /// Build a game icon URL using the proxy
-/// Game icons are at: media.steampowered.com/steamcommunity/public/images/apps/{appid}/{hash}.jpg
+/// Game icons are at: steamcdn-a.akamaihd.net/steamcommunity/public/images/apps/{appid}/{hash}.jpg
 pub fn game_icon_url(appid: u64, icon_hash: &str) -> String {
-    let origin = web_sys::window()
-        .and_then(|w| w.location().origin().ok())
-        .unwrap_or_default();
-    // Use steam-media proxy which routes to steamcdn-a.akamaihd.net
-    format!("{}/steam-media/steamcommunity/public/images/apps/{}/{}.jpg", origin, appid, icon_hash)
+    image_proxy_url(&format!("{}/steamcommunity/public/images/apps/{}/{}.jpg", STEAM_CDN, appid, icon_hash))
+}
+
+/// Build a game banner (store header image) URL using the proxy
+/// Headers are at: steamcdn-a.akamaihd.net/steam/apps/{appid}/header.jpg
+pub fn game_banner_url(appid: u64) -> String {
+    image_proxy_url(&format!("{}/steam/apps/{}/header.jpg", STEAM_CDN, appid))
 }
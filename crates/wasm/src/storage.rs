@@ -1,6 +1,6 @@
 //! Browser storage helpers for tokens and URL parsing
 
-use overachiever_core::GdprConsent;
+use overachiever_core::{GdprConsent, TimeDisplaySettings};
 
 // ============================================================================
 // Token Management
@@ -190,3 +190,31 @@ pub fn clear_cached_tags() {
         let _ = storage.remove_item(TAG_CACHE_VERSION_KEY);
     }
 }
+
+// ============================================================================
+// Time Display Settings
+// ============================================================================
+
+const TIME_DISPLAY_KEY: &str = "overachiever_time_display";
+
+/// Get the time zone / timestamp style preferences from localStorage
+pub fn get_time_display_from_storage() -> TimeDisplaySettings {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok())
+        .flatten()
+        .and_then(|storage| storage.get_item(TIME_DISPLAY_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Save the time zone / timestamp style preferences to localStorage
+pub fn save_time_display_to_storage(settings: &TimeDisplaySettings) {
+    if let Some(storage) = web_sys::window()
+        .and_then(|w| w.local_storage().ok())
+        .flatten()
+    {
+        if let Ok(json) = serde_json::to_string(settings) {
+            let _ = storage.set_item(TIME_DISPLAY_KEY, &json);
+        }
+    }
+}
@@ -1,7 +1,8 @@
 //! WebSocket client for WASM
 
-use overachiever_core::{ClientMessage, ServerMessage};
-use std::cell::RefCell;
+use overachiever_core::{ClientMessage, ServerMessage, HEARTBEAT_INTERVAL_SECS, HEARTBEAT_TIMEOUT_SECS, PROTOCOL_VERSION};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
 use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
@@ -21,6 +22,11 @@ pub struct WsClient {
     ws: WebSocket,
     messages: Rc<RefCell<Vec<ServerMessage>>>,
     state: Rc<RefCell<WsState>>,
+    /// When the last message arrived / was sent (ms since epoch, for the heartbeat)
+    last_received: Rc<Cell<f64>>,
+    last_sent: Rc<Cell<f64>>,
+    /// Quiet time before sending a Ping (ms), set by the server's Welcome
+    heartbeat_ms: Cell<f64>,
 }
 
 impl WsClient {
@@ -30,11 +36,15 @@ impl WsClient {
         
         let messages: Rc<RefCell<Vec<ServerMessage>>> = Rc::new(RefCell::new(Vec::new()));
         let state: Rc<RefCell<WsState>> = Rc::new(RefCell::new(WsState::Connecting));
+        let last_received = Rc::new(Cell::new(js_sys::Date::now()));
+        let last_sent = Rc::new(Cell::new(js_sys::Date::now()));
         
         // Set up onmessage handler
         {
             let messages = messages.clone();
+            let last_received = last_received.clone();
             let onmessage = Closure::<dyn FnMut(_)>::new(move |e: MessageEvent| {
+                last_received.set(js_sys::Date::now());
                 if let Ok(text) = e.data().dyn_into::<js_sys::JsString>() {
                     let text: String = text.into();
                     if let Ok(msg) = serde_json::from_str::<ServerMessage>(&text) {
@@ -46,11 +56,19 @@ impl WsClient {
             onmessage.forget();
         }
         
-        // Set up onopen handler
+        // Set up onopen handler - the handshake goes out before anything else
         {
             let state = state.clone();
+            let socket = ws.clone();
+            let last_received = last_received.clone();
+            let last_sent = last_sent.clone();
             let onopen = Closure::<dyn FnMut()>::new(move || {
                 *state.borrow_mut() = WsState::Open;
+                if let Ok(json) = serde_json::to_string(&ClientMessage::Hello { version: PROTOCOL_VERSION }) {
+                    let _ = socket.send_with_str(&json);
+                }
+                last_received.set(js_sys::Date::now());
+                last_sent.set(js_sys::Date::now());
             });
             ws.set_onopen(Some(onopen.as_ref().unchecked_ref()));
             onopen.forget();
@@ -76,7 +94,14 @@ impl WsClient {
             onclose.forget();
         }
         
-        Ok(Self { ws, messages, state })
+        Ok(Self {
+            ws,
+            messages,
+            state,
+            last_received,
+            last_sent,
+            heartbeat_ms: Cell::new(HEARTBEAT_INTERVAL_SECS as f64 * 1000.0),
+        })
     }
     
     pub fn state(&self) -> WsState {
@@ -90,9 +115,33 @@ impl WsClient {
     fn send(&self, msg: &ClientMessage) {
         if let Ok(json) = serde_json::to_string(msg) {
             let _ = self.ws.send_with_str(&json);
+            self.last_sent.set(js_sys::Date::now());
         }
     }
     
+    /// Use the heartbeat interval the server asked for in `Welcome`
+    pub fn set_heartbeat(&self, secs: u64) {
+        self.heartbeat_ms.set(secs.max(1) as f64 * 1000.0);
+    }
+    
+    /// Send a Ping when we've been quiet for the heartbeat interval (call every frame while open).
+    /// Returns true once nothing was received for the heartbeat timeout - the connection is stale.
+    pub fn heartbeat_tick(&self) -> bool {
+        let now = js_sys::Date::now();
+        if now - self.last_received.get() >= HEARTBEAT_TIMEOUT_SECS as f64 * 1000.0 {
+            return true;
+        }
+        if now - self.last_sent.get() >= self.heartbeat_ms.get() {
+            self.send(&ClientMessage::Ping);
+        }
+        false
+    }
+    
+    /// Ask for the games that changed since our copy (appid -> row checksum)
+    pub fn resync(&self, games: HashMap<u64, u64>) {
+        self.send(&ClientMessage::Resync { games });
+    }
+    
     pub fn authenticate(&self, token: &str) {
         self.send(&ClientMessage::Authenticate { token: token.to_string() });
     }
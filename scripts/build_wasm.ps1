@@ -52,6 +52,10 @@ if ($LASTEXITCODE -ne 0) {
 # Copy index.html to dist
 Copy-Item "web/index.html" "web/dist/index.html" -Force
 
+# Copy PWA service worker and manifest
+Copy-Item "web/sw.js" "web/dist/sw.js" -Force
+Copy-Item "web/manifest.webmanifest" "web/dist/manifest.webmanifest" -Force
+
 # Copy assets folder if exists
 if (Test-Path "assets") {
     Copy-Item -Path "assets" -Destination "web/dist/assets" -Recurse -Force
@@ -0,0 +1,196 @@
+// Overachiever service worker
+//
+// - App shell (index.html, wasm-bindgen output, build info) is served network-first,
+//   falling back to the cache so the client starts offline.
+// - Static assets and proxied Steam images are served cache-first.
+// - Ratings submitted while offline are queued in IndexedDB by the client
+//   (see crates/wasm/src/idb_cache.rs) and resubmitted on the 'sync-ratings' sync event.
+
+const SHELL_CACHE = 'overachiever-shell-v1';
+const ASSET_CACHE = 'overachiever-assets-v1';
+const SHELL_FILES = [
+    './',
+    'index.html',
+    'manifest.webmanifest',
+    'pkg/overachiever_wasm.js',
+    'pkg/overachiever_wasm_bg.wasm',
+    'assets/icon.png',
+];
+
+// IndexedDB layout shared with idb_cache.rs
+const DB_NAME = 'overachiever';
+const DB_VERSION = 1;
+const STORE = 'cache';
+const RATING_QUEUE_KEY = 'rating_queue';
+
+self.addEventListener('install', (event) => {
+    event.waitUntil(
+        caches.open(SHELL_CACHE)
+            .then((cache) => cache.addAll(SHELL_FILES))
+            .then(() => self.skipWaiting())
+    );
+});
+
+self.addEventListener('activate', (event) => {
+    const keep = [SHELL_CACHE, ASSET_CACHE];
+    event.waitUntil(
+        caches.keys()
+            .then((keys) => Promise.all(keys.filter((k) => !keep.includes(k)).map((k) => caches.delete(k))))
+            .then(() => self.clients.claim())
+    );
+});
+
+self.addEventListener('fetch', (event) => {
+    const request = event.request;
+    if (request.method !== 'GET') {
+        return;
+    }
+    const url = new URL(request.url);
+    if (url.origin !== self.location.origin) {
+        return;
+    }
+    // Proxied Steam images don't change, so they are served cache-first like assets
+    if (url.pathname === '/api/image-proxy') {
+        event.respondWith(cacheFirst(request));
+        return;
+    }
+    // Live data is never cached here (the client keeps its own IndexedDB copy)
+    if (url.pathname.startsWith('/api/') || url.pathname.startsWith('/auth/') || url.pathname === '/ws') {
+        return;
+    }
+
+    if (url.pathname.startsWith('/assets/') || url.pathname.startsWith('/steam-media/')) {
+        event.respondWith(cacheFirst(request));
+    } else {
+        event.respondWith(networkFirst(request));
+    }
+});
+
+async function networkFirst(request) {
+    const cache = await caches.open(SHELL_CACHE);
+    try {
+        const response = await fetch(request);
+        if (response.ok) {
+            cache.put(request, response.clone());
+        }
+        return response;
+    } catch (err) {
+        // Deep links like /abc123 fall back to the cached shell
+        const cached = await cache.match(request) || (request.mode === 'navigate' && await cache.match('index.html'));
+        if (cached) {
+            return cached;
+        }
+        throw err;
+    }
+}
+
+async function cacheFirst(request) {
+    const cache = await caches.open(ASSET_CACHE);
+    const cached = await cache.match(request);
+    if (cached) {
+        return cached;
+    }
+    const response = await fetch(request);
+    if (response.ok) {
+        cache.put(request, response.clone());
+    }
+    return response;
+}
+
+// ============================================================================
+// Background sync of queued ratings
+// ============================================================================
+
+self.addEventListener('sync', (event) => {
+    if (event.tag === 'sync-ratings') {
+        event.waitUntil(flushRatingQueue());
+    }
+});
+
+function idbRequest(request) {
+    return new Promise((resolve, reject) => {
+        request.onsuccess = () => resolve(request.result);
+        request.onerror = () => reject(request.error);
+    });
+}
+
+function openDb() {
+    const request = indexedDB.open(DB_NAME, DB_VERSION);
+    request.onupgradeneeded = () => {
+        const db = request.result;
+        if (!db.objectStoreNames.contains(STORE)) {
+            db.createObjectStore(STORE);
+        }
+    };
+    return idbRequest(request);
+}
+
+// Rate limited ratings are sent again after the server's Retry-After, this often and
+// for waits up to this long per sync event - anything more is left to the next sync
+const RATE_LIMIT_RETRIES = 3;
+const MAX_RETRY_WAIT_MS = 60 * 1000;
+
+function postRating(item) {
+    return fetch('/api/achievement/rating', {
+        method: 'POST',
+        headers: {
+            'Authorization': 'Bearer ' + item.token,
+            'Content-Type': 'application/json',
+        },
+        body: JSON.stringify({ appid: item.appid, apiname: item.apiname, rating: item.rating }),
+    });
+}
+
+function retryAfterMs(response) {
+    const seconds = parseInt(response.headers.get('Retry-After'), 10);
+    return Number.isFinite(seconds) && seconds > 0 ? seconds * 1000 : 1000;
+}
+
+async function flushRatingQueue() {
+    const db = await openDb();
+    const read = db.transaction(STORE).objectStore(STORE);
+    const json = await idbRequest(read.get(RATING_QUEUE_KEY));
+    const queue = json ? JSON.parse(json) : [];
+    if (queue.length === 0) {
+        return;
+    }
+
+    const sent = [];
+    let failed = false;
+    for (const item of queue) {
+        try {
+            let response = await postRating(item);
+            for (let retry = 0; response.status === 429 && retry < RATE_LIMIT_RETRIES; retry++) {
+                const wait = retryAfterMs(response);
+                if (wait > MAX_RETRY_WAIT_MS) {
+                    break;
+                }
+                await new Promise((resolve) => setTimeout(resolve, wait));
+                response = await postRating(item);
+            }
+            if (response.status === 429) {
+                // Still limited - keep this and the rest queued for the next sync
+                failed = true;
+                break;
+            }
+            if (!response.ok) {
+                console.warn('Dropping queued rating, server returned', response.status);
+            }
+            sent.push(item);
+        } catch (err) {
+            failed = true;
+        }
+    }
+
+    // Re-read so ratings queued while we were sending are kept
+    const write = db.transaction(STORE, 'readwrite').objectStore(STORE);
+    const latestJson = await idbRequest(write.get(RATING_QUEUE_KEY));
+    const latest = latestJson ? JSON.parse(latestJson) : [];
+    const remaining = latest.filter((q) => !sent.some((s) => s.appid === q.appid && s.apiname === q.apiname && s.rating === q.rating));
+    await idbRequest(write.put(JSON.stringify(remaining), RATING_QUEUE_KEY));
+
+    if (failed) {
+        // Offline or rate limited - reject so the browser retries the sync later
+        throw new Error('Some ratings could not be sent');
+    }
+}